@@ -14,8 +14,9 @@ use std::collections::HashMap;
 use std::time::SystemTime;
 use uuid::Uuid;
 use log::{info, warn, error, debug};
-use reqwest::{Client, RequestBuilder};
+use reqwest::{Client, RequestBuilder, Response};
 use serde::{Serialize, Deserialize};
+use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
 
 // Define a struct to hold the base URL
 const BASE_URL: &str = "http://localhost:8000/v1";
@@ -54,6 +55,13 @@ struct Agent {
     agent_id: Uuid,
 }
 
+// Define a struct to represent one page of a list endpoint
+#[derive(Deserialize)]
+struct AgentPage {
+    items: Vec<Agent>,
+    next_cursor: Option<String>,
+}
+
 // Define a struct to represent completion data
 #[derive(Deserialize)]
 struct CompletionData {
@@ -66,6 +74,28 @@ struct TokenUsage {
     total_tokens: i64,
 }
 
+// Describe a failed response. The server answers every failure with a problem document; the
+// code, request id, and retry hint are logged so a failure can be traced to its run.
+async fn describe_failure(res: Response) -> String {
+    let status = res.status().as_u16();
+    let is_problem = res
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map_or(false, |value| value.starts_with(PROBLEM_JSON));
+    let body = res.text().await.unwrap_or_default();
+    match Problem::parse(&body) {
+        Some(problem) if is_problem => {
+            if problem.retriable {
+                format!("{} (retriable)", problem)
+            } else {
+                problem.to_string()
+            }
+        }
+        _ => format!("HTTP {} without a problem document: {}", status, body),
+    }
+}
+
 // Function to check if the API server is running and accessible
 async fn check_api_server() -> bool {
     let client = Client::new();
@@ -98,7 +128,7 @@ async fn create_test_user(session: &mut TestSession) -> bool {
                 info!("Created user with ID: {}", session.user_id.unwrap());
                 true
             } else {
-                error!("Failed to create user: {}", res.text().await.unwrap());
+                error!("Failed to create user: {}", describe_failure(res).await);
                 false
             }
         }
@@ -122,7 +152,7 @@ async fn create_additional_api_key(session: &mut TestSession) -> bool {
                 info!("Created additional API key");
                 true
             } else {
-                error!("Failed to create API key: {}", res.text().await.unwrap());
+                error!("Failed to create API key: {}", describe_failure(res).await);
                 false
             }
         }
@@ -156,7 +186,7 @@ async fn test_create_agent(session: &mut TestSession) -> bool {
                 info!("Created agent with ID: {}", agent.agent_id);
                 true
             } else {
-                error!("Failed to create agent: {}", res.text().await.unwrap());
+                error!("Failed to create agent: {}", describe_failure(res).await);
                 false
             }
         }
@@ -167,27 +197,49 @@ async fn test_create_agent(session: &mut TestSession) -> bool {
     }
 }
 
-// Function to test listing user's agents
+// Function to test listing user's agents, following the cursor through every page
 async fn test_list_user_agents(session: &mut TestSession) -> bool {
     let client = Client::new();
-    let response = client.get(format!("{}/users/me/agents", BASE_URL))
-        .headers(session.headers())
-        .send().await;
-    match response {
-        Ok(res) => {
-            if res.status().as_u16() == 200 {
-                info!("Found {} user agents", res.text().await.unwrap().len());
-                true
-            } else {
-                error!("Failed to list user agents: {}", res.text().await.unwrap());
-                false
-            }
+    let mut agent_ids = Vec::new();
+    let mut cursor: Option<String> = None;
+    loop {
+        let mut query = vec![("tag", "test".to_string()), ("sort", "-created_at".to_string()), ("limit", "2".to_string())];
+        if let Some(cursor) = &cursor {
+            query.push(("cursor", cursor.clone()));
         }
-        Err(e) => {
-            error!("Error listing user agents: {}", e);
-            false
+        let response = client.get(format!("{}/users/me/agents", BASE_URL))
+            .headers(session.headers())
+            .query(&query)
+            .send().await;
+        match response {
+            Ok(res) => {
+                if res.status().as_u16() == 200 {
+                    let page: AgentPage = res.json().await.unwrap();
+                    agent_ids.extend(page.items.into_iter().map(|agent| agent.agent_id));
+                    match page.next_cursor {
+                        Some(next) => cursor = Some(next),
+                        None => break,
+                    }
+                } else {
+                    error!("Failed to list user agents: {}", describe_failure(res).await);
+                    return false;
+                }
+            }
+            Err(e) => {
+                error!("Error listing user agents: {}", e);
+                return false;
+            }
         }
     }
+    let before = agent_ids.len();
+    agent_ids.sort();
+    agent_ids.dedup();
+    if agent_ids.len() != before {
+        error!("Agent listing returned {} duplicates across pages", before - agent_ids.len());
+        return false;
+    }
+    info!("Found {} user agents", agent_ids.len());
+    session.test_agents.iter().all(|id| agent_ids.contains(id))
 }
 
 // Function to test various operations on an agent
@@ -212,7 +264,7 @@ async fn test_agent_operations(session: &mut TestSession, agent_id: Uuid) -> boo
                             info!("Successfully performed agent operations");
                             true
                         } else {
-                            error!("Failed to get agent metrics: {}", res.text().await.unwrap());
+                            error!("Failed to get agent metrics: {}", describe_failure(res).await);
                             false
                         }
                     }
@@ -222,7 +274,7 @@ async fn test_agent_operations(session: &mut TestSession, agent_id: Uuid) -> boo
                     }
                 }
             } else {
-                error!("Failed to update agent: {}", res.text().await.unwrap());
+                error!("Failed to update agent: {}", describe_failure(res).await);
                 false
             }
         }
@@ -252,7 +304,7 @@ async fn test_completion(session: &mut TestSession, agent_id: Uuid) -> bool {
                 info!("Got completion, used {} tokens", completion_data.token_usage.total_tokens);
                 true
             } else {
-                error!("Failed to get completion: {}", res.text().await.unwrap());
+                error!("Failed to get completion: {}", describe_failure(res).await);
                 false
             }
         }
@@ -276,7 +328,7 @@ async fn cleanup_test_resources(session: &mut TestSession) {
                 if res.status().as_u16() == 200 {
                     debug!("Deleted agent {}", agent_id);
                 } else {
-                    warn!("Failed to delete agent {}: {}", agent_id, res.text().await.unwrap());
+                    warn!("Failed to delete agent {}: {}", agent_id, describe_failure(res).await);
                 }
             }
             Err(e) => {
@@ -302,7 +354,7 @@ async fn cleanup_test_resources(session: &mut TestSession) {
                                 if res.status().as_u16() == 200 {
                                     debug!("Revoked API key {}", api_key);
                                 } else {
-                                    warn!("Failed to revoke API key {}: {}", api_key, res.text().await.unwrap());
+                                    warn!("Failed to revoke API key {}: {}", api_key, describe_failure(res).await);
                                 }
                             }
                             Err(e) => {
@@ -311,7 +363,7 @@ async fn cleanup_test_resources(session: &mut TestSession) {
                         }
                     }
                 } else {
-                    error!("Failed to get API keys: {}", res.text().await.unwrap());
+                    error!("Failed to get API keys: {}", describe_failure(res).await);
                 }
             }
             Err(e) => {
@@ -373,4 +425,8 @@ fn main() {
 ```
 This Rust code uses the `reqwest` crate for making HTTP requests, the `log` crate for logging, and the `serde_json` crate for JSON serialization and deserialization. It also uses the `tokio` crate for asynchronous programming.
 
+List endpoints return one page at a time (`{"items": [...], "next_cursor": ...}`); `test_list_user_agents` follows `next_cursor` with a small `limit` and checks that no agent is returned twice across pages.
+
+Failed responses are read as `application/problem+json` documents (see `swarms::integrations::problem`), so a failure log carries the error code, the request id to look up in the audit log, and whether the call can be retried. A non-2xx response without a problem document is logged as such, since the server should never send one.
+
 Please note that this is just one way to implement the equivalent functionality in Rust, and there may be other approaches depending on your specific requirements. Additionally, this code has not been thoroughly tested and may require modifications to work correctly in your specific environment.
\ No newline at end of file
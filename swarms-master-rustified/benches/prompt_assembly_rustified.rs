@@ -0,0 +1,144 @@
+### Prompt assembly benchmarks
+Compares the old `String`-cloning fan-out against `PromptFragment` + `PromptAssembler` for a
+ConcurrentWorkflow-style fan-out: one 8 KB system prompt sent to N agents, each with its own task, and a
+MixtureOfAgents-style aggregation of the agents' responses. A counting global allocator reports
+allocations and bytes per iteration alongside criterion's timings.
+
+Run with `cargo bench --bench prompt_assembly`.
+
+```rust
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use swarms::swarms::prompts::prompt_fragment::{PromptAssembler, PromptFragment};
+
+struct CountingAlloc;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+fn system_prompt() -> String {
+    "You are a meticulous financial analyst. Cite every figure. ".repeat(140)
+}
+
+fn tasks(n: usize) -> Vec<String> {
+    (0..n).map(|i| format!("Analyze segment {} revenue for Q3.", i)).collect()
+}
+
+/// Before: each agent gets its own copy of the system prompt, and every message copies it again.
+fn fan_out_strings(system: &String, tasks: &[String]) -> Vec<(String, String)> {
+    tasks
+        .iter()
+        .map(|task| {
+            let agent_prompt = system.clone();
+            let message = format!("{}\n\nTask: {}", agent_prompt, task);
+            (agent_prompt, message)
+        })
+        .collect()
+}
+
+/// After: agents share one fragment; the per-agent message is built in one exact-size allocation.
+fn fan_out_fragments(system: &PromptFragment, tasks: &[String]) -> Vec<(PromptFragment, String)> {
+    tasks
+        .iter()
+        .map(|task| {
+            let agent_prompt = system.clone();
+            let mut message = PromptAssembler::with_capacity(3);
+            message.push(&agent_prompt).push("\n\nTask: ").push(task.as_str());
+            let message = message.build();
+            (agent_prompt, message)
+        })
+        .collect()
+}
+
+fn aggregate_strings(system: &String, results: &Vec<String>, agents: usize) -> Vec<String> {
+    (0..agents)
+        .map(|_| {
+            let results = results.clone();
+            let mut prompt = system.clone();
+            for (i, r) in results.iter().enumerate() {
+                prompt.push_str(&format!("\n{}.", i + 1));
+                prompt.push_str(r);
+            }
+            prompt
+        })
+        .collect()
+}
+
+fn aggregate_fragments(system: &PromptFragment, results: &[Arc<str>], agents: usize) -> Vec<String> {
+    (0..agents)
+        .map(|_| {
+            let mut prompt = PromptAssembler::with_capacity(1 + results.len() * 2);
+            prompt.push(system);
+            for (i, r) in results.iter().enumerate() {
+                prompt.push(format!("\n{}.", i + 1));
+                prompt.push(&**r);
+            }
+            prompt.build()
+        })
+        .collect()
+}
+
+fn report<T>(label: &str, f: impl FnOnce() -> T) {
+    let (a0, b0) = (ALLOCATIONS.load(Ordering::Relaxed), BYTES.load(Ordering::Relaxed));
+    black_box(f());
+    let (a1, b1) = (ALLOCATIONS.load(Ordering::Relaxed), BYTES.load(Ordering::Relaxed));
+    eprintln!("{:<32} {:>6} allocations {:>10} bytes", label, a1 - a0, b1 - b0);
+}
+
+fn bench_fan_out(c: &mut Criterion) {
+    let system = system_prompt();
+    let fragment = PromptFragment::from(system.clone());
+    let mut group = c.benchmark_group("fan_out");
+    for agents in [8usize, 64, 256] {
+        let tasks = tasks(agents);
+        report(&format!("fan_out/strings/{}", agents), || fan_out_strings(&system, &tasks));
+        report(&format!("fan_out/fragments/{}", agents), || fan_out_fragments(&fragment, &tasks));
+        group.bench_with_input(BenchmarkId::new("strings", agents), &tasks, |b, t| {
+            b.iter(|| fan_out_strings(black_box(&system), t))
+        });
+        group.bench_with_input(BenchmarkId::new("fragments", agents), &tasks, |b, t| {
+            b.iter(|| fan_out_fragments(black_box(&fragment), t))
+        });
+    }
+    group.finish();
+}
+
+fn bench_aggregate(c: &mut Criterion) {
+    let system = system_prompt();
+    let fragment = PromptFragment::from(system.clone());
+    let mut group = c.benchmark_group("aggregate");
+    for agents in [8usize, 32] {
+        let results: Vec<String> = (0..agents).map(|i| format!("Agent {} found: {}", i, "detail ".repeat(200))).collect();
+        let shared: Vec<Arc<str>> = results.iter().map(|r| Arc::from(r.as_str())).collect();
+        report(&format!("aggregate/strings/{}", agents), || aggregate_strings(&system, &results, agents));
+        report(&format!("aggregate/fragments/{}", agents), || aggregate_fragments(&fragment, &shared, agents));
+        group.bench_function(BenchmarkId::new("strings", agents), |b| {
+            b.iter(|| aggregate_strings(black_box(&system), &results, agents))
+        });
+        group.bench_function(BenchmarkId::new("fragments", agents), |b| {
+            b.iter(|| aggregate_fragments(black_box(&fragment), &shared, agents))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_fan_out, bench_aggregate);
+criterion_main!(benches);
+```
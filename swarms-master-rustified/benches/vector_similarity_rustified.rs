@@ -0,0 +1,67 @@
+### Vector similarity benchmarks
+Scores a query against 100k and 250k random 1536-dimension vectors (the OpenAI `text-embedding-3-small`
+size) and keeps the top 10. Three variants are compared: the naive `f32` loop that `VectorMemory` used
+before, the SIMD kernel on one thread, and `top_k_cosine`, which adds cached norms and the rayon scan.
+
+Run with `cargo bench --bench vector_similarity`.
+
+```rust
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use swarms::swarms::memory::similarity::{cosine, norm, top_k_cosine, PARALLEL_THRESHOLD};
+
+const DIMENSIONS: usize = 1536;
+const TOP_K: usize = 10;
+
+fn random_vectors(count: usize, seed: u64) -> Vec<Vec<f32>> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    (0..count).map(|_| (0..DIMENSIONS).map(|_| rng.gen_range(-1.0..1.0)).collect()).collect()
+}
+
+/// The pre-SIMD implementation: three running sums per pair, norms recomputed on every call.
+fn naive_cosine(a: &[f32], b: &[f32]) -> f32 {
+    let (mut dot, mut norm_a, mut norm_b) = (0.0f32, 0.0f32, 0.0f32);
+    for (x, y) in a.iter().zip(b) {
+        dot += x * y;
+        norm_a += x * x;
+        norm_b += y * y;
+    }
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a.sqrt() * norm_b.sqrt())
+    }
+}
+
+fn sort_top_k(mut scored: Vec<(usize, f32)>) -> Vec<(usize, f32)> {
+    scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+    scored.truncate(TOP_K);
+    scored
+}
+
+fn bench_recall(c: &mut Criterion) {
+    let query = random_vectors(1, 7).pop().unwrap();
+    let mut group = c.benchmark_group("recall_top_10");
+    group.sample_size(20);
+    for count in [100_000usize, 250_000] {
+        let vectors = random_vectors(count, 42);
+        let with_norms: Vec<(&[f32], f32)> = vectors.iter().map(|v| (v.as_slice(), norm(v))).collect();
+        assert!(count > PARALLEL_THRESHOLD);
+        group.throughput(Throughput::Elements(count as u64));
+
+        group.bench_with_input(BenchmarkId::new("naive_loop", count), &vectors, |b, vectors| {
+            b.iter(|| sort_top_k(vectors.iter().enumerate().map(|(i, v)| (i, naive_cosine(&query, v))).collect()))
+        });
+        group.bench_with_input(BenchmarkId::new("simd_single_thread", count), &vectors, |b, vectors| {
+            b.iter(|| sort_top_k(vectors.iter().enumerate().map(|(i, v)| (i, cosine(&query, v))).collect()))
+        });
+        group.bench_with_input(BenchmarkId::new("simd_cached_norms_parallel", count), &with_norms, |b, with_norms| {
+            b.iter(|| top_k_cosine(black_box(&query), with_norms, TOP_K))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_recall);
+criterion_main!(benches);
+```
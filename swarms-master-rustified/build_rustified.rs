@@ -0,0 +1,22 @@
+### Overview
+The build script. There is no Python counterpart; the Python package has no generated code. With the
+`server` feature on it compiles `proto/distributed.proto` into the `swarms.distributed` client and server
+stubs that `structs::distributed_queue` pulls in with `tonic::include_proto!`. Builds without `server`
+skip it, so they need neither `tonic-build` nor `protoc`.
+
+### Rust Code
+```rust
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    println!("cargo:rerun-if-changed=proto/distributed.proto");
+    #[cfg(feature = "server")]
+    tonic_build::configure()
+        .build_client(true)
+        .build_server(true)
+        .compile_protos(&["proto/distributed.proto"], &["proto"])?;
+    Ok(())
+}
+```
+
+### Notes
+* `tonic-build` runs `protoc`, which must be on `PATH` or named by `PROTOC`.
+* Cargo passes the package's features to the build script, so `#[cfg(feature = "server")]` works here as in the crate.
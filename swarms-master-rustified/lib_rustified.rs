@@ -0,0 +1,607 @@
+### Overview
+The crate root. The Python package imports everything from `swarms/__init__.py` unconditionally, so
+installing it pulls in every provider SDK, the server stack, and the CLI. The Rust crate instead splits
+the tree into cargo features so an embedder compiles only what it uses:
+
+| Feature | Modules | Extra dependencies |
+|---|---|---|
+| *(always)* | `agents`, `artifacts`, `eval`, `integrations` (rest, notifications, webhooks, propertyradar, sse, swarms_client), `presets`, `prompts`, `schemas`, `structs`, `telemetry`, `text`, `utils` | `serde`, `serde_json`, `log`, `tokio`, `reqwest`, `prometheus` |
+| `tools` | `tools`, `tools::prebuilt`, `agents::tool_agent`, `Citation::from_search_result` / `from_web_page` | none |
+| `memory` | `memory`, `tools::prebuilt::memory_search` (with `tools`), `reranker` in `AgentInputSchema` | `memmap2`, `bytemuck`, `wide`, `rayon` |
+| `providers-openai` | `utils::openai_compat_provider`, `utils::litellm`, `utils::openai_tts`, `agents::openai_assistant`, `tools::tool_call_stream` (with `tools`) | `tokio-stream` |
+| `providers-bedrock` | reserved; no Bedrock provider is ported yet | none |
+| `server` | `structs::distributed_queue`, `TaskQueueSwarm::into_coordinator` | `tonic`, `prost`, `tokio-stream`; `tonic-build` and `protoc` at build time |
+| `cli` | `cli` | `clap` |
+| `openapi` | `integrations::openapi`, `ToSchema` derives on the API wire types | `utoipa` |
+| `kafka`, `nats` | the connectors in `integrations::task_stream` | `rdkafka`, `async-nats` |
+| `llama-cpp` | `utils::llama_cpp_provider` | `llama-cpp-2` |
+
+The manifest section these map to:
+
+```toml
+[features]
+default = ["tools", "memory", "providers-openai"]
+tools = []
+memory = ["dep:memmap2", "dep:bytemuck", "dep:wide", "dep:rayon"]
+providers-openai = ["dep:tokio-stream"]
+providers-bedrock = []
+server = ["dep:tonic", "dep:prost", "dep:tokio-stream", "dep:tonic-build"]
+cli = ["dep:clap"]
+openapi = ["dep:utoipa"]
+kafka = ["dep:rdkafka"]
+nats = ["dep:async-nats"]
+llama-cpp = ["dep:llama-cpp-2"]
+full = ["tools", "memory", "providers-openai", "providers-bedrock", "server", "cli", "openapi"]
+
+[build-dependencies]
+tonic-build = { version = "0.12", optional = true }
+```
+
+`build.rs` (`build_rustified.rs`) compiles `proto/distributed.proto` for the `server` feature.
+
+Each module group re-exports its main types, so `swarms::memory::VectorMemory` works as well as
+`swarms::memory::vector_memory::VectorMemory`.
+
+### Stability
+`prelude` is the supported entry point and is covered by semver: it holds the `Agent` trait and its implementations, `Conversation`, the
+`Swarm` trait and the swarms that implement it, the provider types, and every public error type. The
+module tree under `swarms::swarms` mirrors the Python package so conversions can be compared file by file.
+That layout is not part of the stable API, and modules may be merged or renamed in minor releases.
+
+### Rust Code
+```rust
+pub mod swarms {
+    pub mod agents {
+        pub mod ape_agent;
+        pub mod auto_generate_swarm_config;
+        pub mod create_agents_from_yaml;
+        #[cfg(feature = "providers-openai")]
+        pub mod openai_assistant;
+        pub mod prompt_synthesizer;
+        pub mod sop_generator;
+        pub mod stopping_conditions;
+        #[cfg(feature = "tools")]
+        pub mod tool_agent;
+
+        pub use create_agents_from_yaml::{AgentConfig, SwarmConfig, YAMLConfig, YamlExportError};
+    }
+
+    pub mod artifacts {
+        pub mod main_artifact;
+        pub mod spillover;
+
+        pub use main_artifact::{Artifact, FileVersion};
+        pub use spillover::{SpilledOutput, Spillover};
+    }
+
+    #[cfg(feature = "cli")]
+    pub mod cli {
+        pub mod create_agent;
+        pub mod main;
+        pub mod onboarding_process;
+    }
+
+    pub mod eval {
+        pub mod harness;
+
+        pub use harness::{CaseResult, CaseStatus, Criterion, CriterionScore, EvalCase, EvalComparison};
+        pub use harness::{EvalDataset, EvalError, EvalHarness, EvalReport, MetricSummary, ReplayResponder};
+    }
+
+    pub mod integrations {
+        pub mod notifications;
+        #[cfg(feature = "openapi")]
+        pub mod openapi;
+        pub mod problem;
+        pub mod propertyradar;
+        pub mod rest;
+        pub mod sse;
+        pub mod swarms_client;
+        pub mod task_stream;
+        pub mod webhooks;
+
+        pub use notifications::{NotificationConfig, NotificationError, NotificationSink, Notifier, RunEvent, RunSummary};
+        #[cfg(feature = "openapi")]
+        pub use openapi::{api_spec, ApiDocs};
+        pub use problem::{Problem, PROBLEM_JSON};
+        pub use rest::{AuthStrategy, Endpoint, HttpTransport, RestClient, RestError};
+        pub use sse::{SseConfig, SseError, SseEvent, SseParser, SseStream};
+        pub use swarms_client::{SwarmsClient, SwarmsClientError};
+        pub use webhooks::{WebhookConfig, WebhookDispatcher, WebhookEvent};
+    }
+
+    #[cfg(feature = "memory")]
+    pub mod memory {
+        pub mod document_formats;
+        pub mod memory_archive;
+        pub mod mmap_index;
+        pub mod reranker;
+        pub mod similarity;
+        pub mod vector_memory;
+
+        pub use document_formats::{read_corpus, DocumentFormat, DocumentFormatError, ExternalChunk};
+        pub use memory_archive::{MemoryManifest, SourceDocument};
+        pub use mmap_index::MmapVectorIndex;
+        pub use reranker::{Reranker, RerankerConfig, RerankError};
+        pub use vector_memory::{Embedder, MemoryError, MemoryRecord, OpenAiEmbedder, RecallHit, VectorMemory};
+    }
+
+    pub mod presets {
+        pub mod accounting;
+        pub mod college_selection;
+
+        pub use accounting::{accounting_swarm, AccountingError, AccountingReport, AccountingSwarm, Document};
+        pub use college_selection::{
+            college_selection_swarm, CollegeLog, CollegeSelection, CollegeSelectionError, CollegeSelectionSwarm,
+            CollegesRecommendation,
+        };
+    }
+
+    pub mod prompts {
+        pub mod accountant_swarm_prompts;
+        pub mod ag_prompt;
+        pub mod aga;
+        pub mod agent_prompt;
+        pub mod agent_prompts;
+        pub mod agent_system_prompts;
+        pub mod ai_research_team;
+        pub mod aot_prompt;
+        pub mod autobloggen;
+        pub mod autoswarm;
+        pub mod chat_prompt;
+        pub mod code_interpreter;
+        pub mod code_spawner;
+        pub mod debate;
+        pub mod documentation;
+        pub mod education;
+        pub mod finance_agent_prompt;
+        pub mod finance_agent_sys_prompt;
+        pub mod growth_agent_prompt;
+        pub mod idea2img;
+        pub mod legal_agent_prompt;
+        pub mod logistics;
+        pub mod meta_system_prompt;
+        pub mod multi_modal_autonomous_instruction_prompt;
+        pub mod multi_modal_prompts;
+        pub mod multi_modal_visual_prompts;
+        pub mod operations_agent_prompt;
+        pub mod personal_stylist;
+        pub mod product_agent_prompt;
+        pub mod programming;
+        pub mod project_manager;
+        pub mod prompt;
+        pub mod prompt_fragment;
+        pub mod prompt_generator;
+        pub mod prompt_generator_optimizer;
+        pub mod python;
+        pub mod react;
+        pub mod refiner_agent_prompt;
+        pub mod sales;
+        pub mod sales_prompts;
+        pub mod security_team;
+        pub mod self_operating_prompt;
+        pub mod sop_generator_agent_prompt;
+        pub mod summaries_prompts;
+        pub mod support_agent_prompt;
+        pub mod swarm_manager_agent;
+        pub mod task_assignment_prompt;
+        pub mod tests;
+        pub mod tools;
+        pub mod urban_planning;
+        pub mod visual_cot;
+        pub mod worker_prompt;
+        pub mod xray_swarm_prompt;
+    }
+
+    pub mod schemas {
+        pub mod agent_input_schema;
+        pub mod agent_step_schemas;
+        pub mod base_schemas;
+        pub mod delta_accumulator;
+
+        pub use base_schemas::ToolChoiceError;
+        pub use delta_accumulator::{DeltaAccumulator, DeltaError};
+    }
+
+    pub mod structs {
+        pub mod agent;
+        pub mod agent_pool;
+        pub mod agent_profile;
+        pub mod agent_registry;
+        pub mod agent_router;
+        pub mod agent_store;
+        pub mod agent_versions;
+        pub mod agents_available;
+        pub mod approval_gate;
+        pub mod async_workflow;
+        pub mod audit;
+        pub mod auto_swarm;
+        pub mod auto_swarm_builder;
+        pub mod base_structure;
+        pub mod base_workflow;
+        pub mod callbacks;
+        pub mod chat_completions;
+        pub mod citation;
+        pub mod company;
+        pub mod concat;
+        pub mod concurrent_workflow;
+        pub mod config_reload;
+        pub mod convergence;
+        pub mod conversation;
+        pub mod debugger;
+        pub mod demo_mode;
+        #[cfg(feature = "server")]
+        pub mod distributed_queue;
+        pub mod dry_run;
+        pub mod failure;
+        pub mod fair_scheduler;
+        pub mod graph_workflow;
+        pub mod groupchat;
+        pub mod groupchat_new;
+        pub mod handoff;
+        pub mod health;
+        pub mod hiearchical_swarm;
+        pub mod loop_detection;
+        pub mod majority_voting;
+        pub mod message;
+        pub mod mixture_of_agents;
+        pub mod model_selector;
+        pub mod multi_agent_collab;
+        pub mod multi_agent_exec;
+        pub mod multi_process_workflow;
+        pub mod omni_agent_types;
+        pub mod pagination;
+        pub mod plan_execute;
+        pub mod pulsar_swarm;
+        pub mod python_import;
+        pub mod queue_swarm;
+        pub mod restart;
+        pub mod round_robin;
+        pub mod run_diff;
+        pub mod run_manifest;
+        pub mod scoped_tasks;
+        pub mod self_consistency;
+        pub mod sequential_workflow;
+        pub mod spreadsheet_swarm;
+        pub mod step_guard;
+        pub mod supervisor;
+        pub mod swarm;
+        pub mod swarm_arange;
+        pub mod swarm_load_balancer;
+        pub mod swarm_net;
+        pub mod swarm_registry;
+        pub mod swarming_architectures;
+        pub mod task;
+        pub mod tenancy;
+        pub mod tree_of_thoughts;
+        pub mod tree_swarm;
+        pub mod usage;
+        pub mod utils;
+        pub mod workflow_run;
+        pub mod workspace_manager;
+
+        pub use agent::{
+            Agent, AgentBuilder, AgentConfigError, AgentError, AgentSpec, Completion, LlmAgent, LoopExit, LoopRun, LoopStep,
+            MockAgent,
+        };
+        pub use agent_pool::{AgentPool, PoolError, Poolable};
+        pub use agent_profile::{AgentProfile, AgentProfileRegistry, ProfileError, DEFAULT_PROFILE_DIR};
+        pub use agent_router::AgentRouter;
+        pub use agent_store::{AgentQuery, AgentStore, AgentStoreError, StoredAgent};
+        pub use agent_versions::{SplitChange, TrafficSplit, VersionError, VersionStats, VersionedAgent, VersionedRun};
+        pub use approval_gate::{
+            ApprovalChannel, ApprovalContext, ApprovalDecision, ApprovalError, ApprovalGate, CheckpointStore, CliApproval,
+            GateMode, PendingApprovals, ReviewRecord, TimeoutPolicy,
+        };
+        pub use audit::{AuditAction, AuditError, AuditEvent, AuditLog, AuditQuery, Auditor};
+        pub use auto_swarm::{AutoSwarmRouter, RoutedRun, ShadowConfig, ShadowRecord, SwarmScorer};
+        pub use base_structure::{BaseStructure, BaseStructureError, ErrorEntry};
+        pub use callbacks::{CallbackSet, RunCallbacks, StepComplete};
+        pub use chat_completions::{ChatCompletion, ChatCompletions, ChatCompletionsRequest};
+        pub use citation::{Citation, CitationSet, Cited};
+        pub use concurrent_workflow::{ConcurrentOutput, ConcurrentRun, ConcurrentWorkflow};
+        pub use config_reload::{ConfigFileFormat, ConfigReloadError, ConfigSnapshot, LiveSwarmConfig, ReloadEvent, ReloadOutcome};
+        pub use convergence::{Convergence, ConvergenceConfig, ConvergenceDetector, EmbedFn};
+        pub use conversation::{
+            Channel, Conversation, ConversationBuilder, ConversationConfigError, ConversationFormat, ConversationStoreError,
+            MessageEditError,
+        };
+        pub use debugger::Debugger;
+        pub use demo_mode::{Admission, DemoConfig, DemoError, DemoGate};
+        pub use dry_run::{CostEstimator, DryRun, EstimateSource, PlannedCall, StepEstimate};
+        pub use failure::{Classify, Failure, FailureCode};
+        pub use fair_scheduler::{Dispatch, FairScheduler, LaneStats, SchedulerError, SHARED_LANE};
+        pub use handoff::{
+            AgentTurn, Handoff, HandoffController, HandoffError, HandoffEvent, HandoffPayload, HandoffPolicy, DEFAULT_MAX_HANDOFFS,
+        };
+        pub use health::{ComponentKind, ComponentState, ComponentStatus, DirectoryCheck, Health, HealthCheck, HealthReport};
+        pub use loop_detection::{LoopAction, LoopDetection, LoopDetectionConfig, LoopDetector};
+        pub use majority_voting::{Consensus, MajorityVoting, Vote};
+        pub use model_selector::{ModelSelector, ModelTier, Selection, SelectionReason, SelectorConfig, TierUsage};
+        pub use pagination::{Page, PageError, PageRequest, SortOrder};
+        pub use plan_execute::{ExecutedStep, Plan, PlanError, PlanRun, PlanStep, PlanningConfig, StepAction, StepExecutor, StepRouter};
+        pub use python_import::{import_agent_state, import_conversation, ImportWarning, Imported, PythonAgentState, PythonImportError};
+        pub use restart::{Escalation, Restart, RestartDecision, RestartIntensity, RestartPolicy, RestartSupervisor};
+        pub use round_robin::RoundRobinSwarm;
+        pub use run_diff::{PromptContext, RunDiff, RunDiffError, RunTrace, StepChange, StepDiff, TraceMessage, TraceStep};
+        pub use run_manifest::{EventLogDigest, ManifestError, ManifestSigner, RunManifest, SignedManifest};
+        pub use scoped_tasks::{TaskFailure, TaskFailures};
+        pub use sequential_workflow::{HandoffRun, SequentialWorkflow, StageOutput};
+        pub use self_consistency::{SampleRequest, SampledPath, SelfConsistencyConfig, SelfConsistencyRun};
+        pub use step_guard::{StepRecord, StepStatus};
+        pub use supervisor::{StuckAction, Supervisor, TaskHeartbeat, TaskStuck, Watchdog};
+        pub use swarm::{Swarm, SwarmAgent, SwarmError};
+        pub use tenancy::{TenantError, TenantId, TenantWorkspace};
+        pub use tree_of_thoughts::{SearchTree, ThoughtContext, ThoughtNode, TreeOfThoughtsConfig};
+        pub use usage::{CostReport, CostRow, Pricing, ReportDimension, ReportPeriod, UsageError, UsageRecord, UsageScope, UsageTracker};
+        pub use workflow_run::{Resume, RetryError, Run, RunStep, StepInput};
+    }
+
+    pub mod telemetry {
+        pub mod auto_upgrade_swarms;
+        pub mod bootup;
+        pub mod capture_sys_data;
+        pub mod check_update;
+        pub mod sentry_active;
+        pub mod sys_info;
+        pub mod user_utils;
+    }
+
+    pub mod text {
+        pub mod chat_template;
+        pub mod chunk;
+        pub mod compression;
+        pub mod context_budget;
+        pub mod prompt_privacy;
+        pub mod redaction;
+        pub mod sentiment;
+        pub mod stop_conditions;
+        pub mod tokenizer;
+
+        pub use chat_template::{ChatTemplate, ChatTemplateError, ChatTemplateRegistry};
+        pub use chunk::{Chunk, ChunkStrategy, Chunker};
+        pub use compression::{CompressionLevel, ContextCompressor};
+        pub use context_budget::{BudgetError, ContextAllocation, ContextBudget, ContextDemand, ContextSection};
+        pub use prompt_privacy::PromptPrivacy;
+        pub use redaction::{RedactingLogger, RedactionConfig, RedactionError, Redactor, Sealer};
+        pub use sentiment::{
+            LexiconAnalyzer, SentimentAnalyzer, SentimentAttempt, SentimentError, SentimentGateConfig, SentimentRun,
+        };
+        pub use stop_conditions::{StopConditions, StopError, StopKind, StopMatch, StopMatcher, PRESET_STOPPING_TOKEN};
+        pub use tokenizer::{default_tokenizer, Tokenizer};
+    }
+
+    #[cfg(feature = "tools")]
+    pub mod tools {
+        pub mod base_tool;
+        pub mod cohere_func_call_schema;
+        pub mod func_calling_executor;
+        pub mod func_calling_utils;
+        pub mod func_to_str;
+        pub mod function_util;
+        pub mod json_former;
+        pub mod json_utils;
+        pub mod logits_processor;
+        pub mod openai_func_calling_schema_pydantic;
+        pub mod openai_tool_creator_decorator;
+        pub mod py_func_to_openai_func_str;
+        pub mod pydantic_to_json;
+        #[cfg(feature = "providers-openai")]
+        pub mod tool_call_stream;
+        pub mod tool_parse_exec;
+        pub mod tool_registry;
+        pub mod tool_utils;
+
+        pub mod prebuilt {
+            pub mod bing_api;
+            pub mod code_executor;
+            pub mod code_interpreter;
+            pub mod math_eval;
+            #[cfg(feature = "memory")]
+            pub mod memory_search;
+            pub mod web_reader;
+            pub mod web_search;
+
+            #[cfg(feature = "memory")]
+            pub use memory_search::MemorySearchTool;
+            pub use web_reader::WebReaderTool;
+            pub use web_search::WebSearchTool;
+        }
+
+        pub use base_tool::{ToolExecutionError, ToolRegistry, ToolRegistryBuilder, ToolSpec};
+    }
+
+    pub mod utils {
+        pub mod add_docs_to_agents;
+        pub mod agent_ops_check;
+        pub mod any_to_str;
+        pub mod async_file_creation;
+        pub mod blocking;
+        pub mod calculate_func_metrics;
+        pub mod chaos;
+        pub mod chat_provider;
+        pub mod class_args_wrapper;
+        pub mod clock;
+        pub mod compression;
+        pub mod data_to_text;
+        pub mod disable_logging;
+        pub mod encryption;
+        pub mod file_processing;
+        pub mod formatter;
+        pub mod id_generator;
+        #[cfg(feature = "providers-openai")]
+        pub mod litellm;
+        #[cfg(feature = "llama-cpp")]
+        pub mod llama_cpp_provider;
+        pub mod loguru_logger;
+        pub mod markdown_message;
+        pub mod metrics;
+        #[cfg(feature = "providers-openai")]
+        pub mod openai_compat_provider;
+        #[cfg(feature = "providers-openai")]
+        pub mod openai_tts;
+        pub mod pandas_utils;
+        pub mod parse_code;
+        pub mod pdf_to_text;
+        pub mod provider_capabilities;
+        pub mod safe_path;
+        pub mod secret_store;
+        pub mod swarm_reliability_checks;
+        pub mod try_except_wrapper;
+        pub mod update_agent_system_prompts;
+        pub mod wrapper_clusterop;
+        pub mod zip_extract;
+
+        pub use chaos::{Chaos, ChaosConfig, ChaosError, ChaosProvider, Fault};
+        pub use chat_provider::{ChatCompletion, ChatProvider, Device, FinishReason, ProviderError};
+        pub use clock::{Clock, ManualClock, SystemClock};
+        pub use compression::{CompressionError, StateCompressor};
+        #[cfg(feature = "llama-cpp")]
+        pub use llama_cpp_provider::{LlamaCppConfig, LlamaCppProvider};
+        #[cfg(feature = "providers-openai")]
+        pub use openai_compat_provider::OpenAiCompatProvider;
+        pub use encryption::{EncryptionError, StateCipher};
+        pub use id_generator::{new_id, IdFormat, IdGenerator, IdKind};
+        pub use metrics::SwarmMetrics;
+        pub use provider_capabilities::{Capability, CapabilityError, ProviderCapabilities};
+        pub use safe_path::{safe_join, sanitize_file_name, PathError};
+        pub use secret_store::{EnvSecretStore, SecretStore, StaticSecretStore};
+        pub use zip_extract::{extract_zip, ExtractLimits, ZipExtractError};
+    }
+}
+
+/// The stable public API: `use swarms::prelude::*;`.
+///
+/// Everything here follows semver. A name is only removed or changed incompatibly in a major release,
+/// and is deprecated for at least one minor release first. Items reached through `swarms::swarms::...`
+/// paths but not re-exported here may move between minor releases as the tree drifts from the Python layout.
+pub mod prelude {
+    // Agents and swarms.
+    pub use crate::swarms::structs::{Agent, AgentRouter, AutoSwarmRouter, LlmAgent, MajorityVoting, MockAgent, RoundRobinSwarm};
+    pub use crate::swarms::structs::{ConcurrentWorkflow, SequentialWorkflow, Swarm, SwarmAgent};
+    pub use crate::swarms::structs::{CallbackSet, RunCallbacks, StepComplete};
+    pub use crate::swarms::structs::{Citation, Conversation, StepRecord, StepStatus};
+    pub use crate::swarms::structs::{TenantId, TenantWorkspace};
+
+    // Context management.
+    pub use crate::swarms::text::{ContextBudget, Tokenizer};
+
+    // Providers.
+    pub use crate::swarms::utils::{ChatProvider, Device, ProviderCapabilities};
+    #[cfg(feature = "llama-cpp")]
+    pub use crate::swarms::utils::{LlamaCppConfig, LlamaCppProvider};
+    #[cfg(feature = "providers-openai")]
+    pub use crate::swarms::utils::OpenAiCompatProvider;
+
+    // Storage.
+    pub use crate::swarms::utils::{EnvSecretStore, SecretStore, StateCipher};
+
+    // Errors.
+    pub use crate::swarms::agents::YamlExportError;
+    pub use crate::swarms::eval::EvalError;
+    pub use crate::swarms::integrations::{RestError, SseError, SwarmsClientError};
+    pub use crate::swarms::presets::{AccountingError, CollegeSelectionError};
+    pub use crate::swarms::schemas::{DeltaError, ToolChoiceError};
+    pub use crate::swarms::structs::{AgentConfigError, AgentError, AgentStoreError, ApprovalError, AuditError, ConfigReloadError};
+    pub use crate::swarms::structs::{ConversationConfigError, ConversationStoreError};
+    pub use crate::swarms::structs::{DemoError, HandoffError, ManifestError, PageError, RunDiffError, SwarmError};
+    pub use crate::swarms::structs::{PlanError, PythonImportError, TaskFailures, TenantError, UsageError, VersionError};
+    pub use crate::swarms::text::{BudgetError, ChatTemplateError, RedactionError, SentimentError, StopError};
+    pub use crate::swarms::utils::{CapabilityError, EncryptionError, ProviderError};
+    #[cfg(feature = "memory")]
+    pub use crate::swarms::memory::{DocumentFormatError, MemoryError, VectorMemory};
+    #[cfg(feature = "tools")]
+    pub use crate::swarms::tools::{ToolExecutionError, ToolRegistry};
+}
+
+// Servers share agents, providers, tools, and stores across worker threads. These checks fail the build,
+// naming the type, when a field stops it being `Send + Sync` (an `Rc`, a hook closure without the bounds).
+mod thread_safety {
+    use crate::swarms::integrations::{RestClient, SseStream, SwarmsClient, WebhookDispatcher};
+    use crate::swarms::structs::{
+        Agent, AgentStore, AuditLog, Auditor, AutoSwarmRouter, CheckpointStore, ConcurrentWorkflow, Conversation,
+        LiveSwarmConfig, LlmAgent, MajorityVoting, MockAgent, PendingApprovals, RoundRobinSwarm, SequentialWorkflow, Swarm,
+        SwarmAgent, TenantWorkspace, UsageTracker, VersionedAgent,
+    };
+    use crate::swarms::text::{Redactor, Tokenizer};
+    use crate::swarms::utils::{EnvSecretStore, ProviderCapabilities, SecretStore, StateCipher, StaticSecretStore, SwarmMetrics};
+    use static_assertions::assert_impl_all;
+    use std::sync::Arc;
+
+    // Agent handles.
+    assert_impl_all!(LlmAgent: Send, Sync, Clone);
+    assert_impl_all!(MockAgent: Send, Sync);
+    assert_impl_all!(SwarmAgent: Send, Sync);
+    assert_impl_all!(Arc<dyn Agent>: Send, Sync, Clone);
+    assert_impl_all!(VersionedAgent: Send, Sync);
+    assert_impl_all!(crate::swarms::structs::CallbackSet: Send, Sync, Clone);
+
+    // Swarms run through `&mut self`, so they only need to move between threads.
+    assert_impl_all!(Box<dyn Swarm>: Send);
+    assert_impl_all!(AutoSwarmRouter: Send);
+    assert_impl_all!(ConcurrentWorkflow: Send, Sync);
+    assert_impl_all!(MajorityVoting: Send, Sync);
+    assert_impl_all!(RoundRobinSwarm: Send, Sync);
+    assert_impl_all!(SequentialWorkflow: Send, Sync);
+
+    // Providers.
+    assert_impl_all!(ProviderCapabilities: Send, Sync);
+    assert_impl_all!(RestClient: Send, Sync);
+    assert_impl_all!(SwarmsClient: Send, Sync, Clone);
+    assert_impl_all!(SseStream: Send);
+    #[cfg(feature = "providers-openai")]
+    assert_impl_all!(crate::swarms::utils::OpenAiCompatProvider: Send, Sync);
+    #[cfg(feature = "llama-cpp")]
+    assert_impl_all!(crate::swarms::utils::LlamaCppProvider: Send, Sync, Clone);
+    assert_impl_all!(Arc<dyn crate::swarms::utils::ChatProvider>: Send, Sync);
+    assert_impl_all!(crate::swarms::utils::ChaosProvider: Send, Sync);
+    assert_impl_all!(Arc<dyn Tokenizer>: Send, Sync);
+
+    // Tools.
+    #[cfg(feature = "tools")]
+    assert_impl_all!(crate::swarms::tools::ToolRegistry: Send, Sync, Clone);
+    #[cfg(feature = "tools")]
+    assert_impl_all!(crate::swarms::tools::prebuilt::WebSearchTool: Send, Sync);
+    #[cfg(feature = "tools")]
+    assert_impl_all!(crate::swarms::tools::prebuilt::WebReaderTool: Send, Sync);
+    #[cfg(all(feature = "tools", feature = "memory"))]
+    assert_impl_all!(crate::swarms::tools::prebuilt::MemorySearchTool: Send, Sync);
+
+    // Stores.
+    assert_impl_all!(AgentStore: Send, Sync, Clone);
+    assert_impl_all!(Conversation: Send, Sync);
+    assert_impl_all!(AuditLog: Send, Sync);
+    assert_impl_all!(crate::swarms::structs::BaseStructure: Send, Sync, Clone);
+    assert_impl_all!(crate::swarms::structs::FairScheduler: Send, Sync);
+    assert_impl_all!(crate::swarms::structs::Supervisor: Send, Sync);
+    assert_impl_all!(crate::swarms::structs::TaskHeartbeat: Send, Sync);
+    assert_impl_all!(Auditor: Send, Sync);
+    assert_impl_all!(CheckpointStore: Send, Sync);
+    assert_impl_all!(PendingApprovals: Send, Sync);
+    assert_impl_all!(UsageTracker: Send, Sync);
+    assert_impl_all!(TenantWorkspace: Send, Sync);
+    assert_impl_all!(LiveSwarmConfig: Send, Sync);
+    assert_impl_all!(StateCipher: Send, Sync);
+    assert_impl_all!(EnvSecretStore: Send, Sync);
+    assert_impl_all!(StaticSecretStore: Send, Sync);
+    assert_impl_all!(Arc<dyn SecretStore>: Send, Sync);
+    assert_impl_all!(Redactor: Send, Sync);
+    assert_impl_all!(WebhookDispatcher: Send, Sync);
+    assert_impl_all!(SwarmMetrics: Send, Sync);
+    assert_impl_all!(crate::swarms::utils::IdGenerator: Send, Sync);
+    assert_impl_all!(crate::swarms::utils::ManualClock: Send, Sync);
+    assert_impl_all!(Arc<dyn crate::swarms::utils::Clock>: Send, Sync);
+    assert_impl_all!(crate::swarms::structs::ChatCompletions: Send, Sync, Clone);
+    #[cfg(feature = "openapi")]
+    assert_impl_all!(crate::swarms::integrations::ApiDocs: Send, Sync);
+    #[cfg(feature = "memory")]
+    assert_impl_all!(crate::swarms::memory::VectorMemory: Send, Sync, Clone);
+}
+```
+
+### Notes
+* A module that needs another feature's types is gated on both (`memory_search` needs `tools` and `memory`); the features themselves never enable each other, so `--no-default-features --features memory` builds without the tool registry.
+* `integrations::rest` stays in the core build because `memory`, the prebuilt tools, and the notification sinks all use it.
+* The `__init__` files mirror the Python package and are not part of the module tree; this file is.
+* `cargo hack check --each-feature` is the CI check for this split: every feature must build on its own.
+* `thread_safety` needs the `static_assertions` crate. It holds no code at runtime; a type that is meant to stay single-threaded should not be listed there.
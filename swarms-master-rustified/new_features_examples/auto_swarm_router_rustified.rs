@@ -1,20 +1,13 @@
 ```rust
 // This conversion is viable with limitations. 
 // The main limitation is the availability of a direct Rust equivalent for the OpenAI and SwarmRouter libraries.
-// We can use the reqwest crate to make HTTP requests to the OpenAI API, and manually implement the SwarmRouter functionality.
+// The model is reached through OpenAiCompatProvider, and the SwarmRouter functionality is implemented manually.
 
 // Import necessary crates
-use std::env;
-use std::fs::File;
-use std::io::{Read, Write};
-use reqwest::{Client, StatusCode};
 use serde::{Serialize, Deserialize};
-
-// Define a struct for the OpenAI API response
-#[derive(Serialize, Deserialize, Debug)]
-struct OpenAIResponse {
-    choices: Vec<String>,
-}
+use crate::swarms::agents::prompt_synthesizer::{PromptBrief, PromptReview, PromptSynthesizer, PROMPT_CACHE_FILE};
+use crate::swarms::utils::blocking;
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 
 // Define a struct for the Agent
 #[derive(Serialize, Deserialize, Debug)]
@@ -45,32 +38,6 @@ struct SwarmRouter {
     output_type: String,
 }
 
-// Define a function to load the environment variable
-fn load_env_var(var_name: &str) -> String {
-    env::var(var_name).expect(&format!("{} environment variable not found", var_name))
-}
-
-// Define a function to make a request to the OpenAI API
-async fn make_openai_request(client: &Client, prompt: &str, api_key: &str, model: &str) -> Result<String, reqwest::Error> {
-    let url = format!("https://api.groq.com/openai/v1/complete", );
-    let request_body = format!("{{\"prompt\":\"{}\",\"temperature\":0.1,\"max_tokens\":1000,\"model\":\"{}\"}}", prompt, model);
-
-    let res = client.post(&url)
-        .header("Authorization", format!("Bearer {}", api_key))
-        .header("Content-Type", "application/json")
-        .body(request_body)
-        .send()
-        .await?;
-
-    if res.status() != StatusCode::OK {
-        println!("Error making OpenAI request: {}", res.status());
-        return Err(reqwest::Error::new(reqwest::ErrorKind::Other, "Error making OpenAI request"));
-    }
-
-    let response = res.json::<OpenAIResponse>().await?;
-    Ok(response.choices.get(0).unwrap().clone())
-}
-
 // Define a function to initialize an Agent
 fn initialize_agent(agent_name: &str, system_prompt: &str, model: &str, max_loops: u32, autosave: bool, verbose: bool, dynamic_temperature_enabled: bool, saved_state_path: &str, user_name: &str, retry_attempts: u32, context_length: u32, output_type: &str) -> Agent {
     Agent {
@@ -102,13 +69,48 @@ fn initialize_swarm_router(name: &str, description: &str, max_loops: u32, agents
     }
 }
 
+// Replace each agent's hand-written prompt with one generated for the task. The hand-written prompt
+// serves as the agent's description; generated prompts are cached in PROMPT_CACHE_FILE.
+fn generate_agent_prompts(model: &OpenAiCompatProvider, swarm_router: &mut SwarmRouter, task: &str) {
+    let mut synthesizer = match PromptSynthesizer::with_cache_file(PROMPT_CACHE_FILE) {
+        Ok(synthesizer) => synthesizer,
+        Err(error) => {
+            println!("Keeping the hand-written prompts: {}", error);
+            return;
+        }
+    };
+    for agent in swarm_router.agents.iter_mut() {
+        let brief = PromptBrief::new(&agent.agent_name, &agent.system_prompt, task);
+        let generated = synthesizer.synthesize(
+            &brief,
+            |meta_prompt| {
+                let (model, meta_prompt) = (model.clone(), meta_prompt.to_string());
+                blocking::block_on(async move { model.complete(None, &meta_prompt).await }).map_err(|e| e.to_string())
+            },
+            // Review hook: a prompt that never names the agent's role is sent back.
+            |brief, prompt| {
+                let role = brief.agent_name.split('-').next().unwrap_or(brief.agent_name).to_lowercase();
+                if prompt.to_lowercase().contains(&role) {
+                    PromptReview::Approve
+                } else {
+                    PromptReview::Reject(format!("the prompt must describe the {} role", brief.agent_name))
+                }
+            },
+        );
+        match generated {
+            Ok(generated) => agent.system_prompt = generated.prompt,
+            Err(error) => println!("Keeping the hand-written prompt for {}: {}", agent.agent_name, error),
+        }
+    }
+}
+
 // Define a function to run a comprehensive private equity document analysis task
-async fn run_comprehensive_analysis(client: &Client, api_key: &str, model: &str, prompt: &str) -> Result<String, reqwest::Error> {
+async fn run_comprehensive_analysis(model: &OpenAiCompatProvider, prompt: &str) -> Result<String, ProviderError> {
     let mut agents = vec![];
     agents.push(initialize_agent(
         "Data-Extractor",
         "You are a data extraction specialist. Extract relevant information from provided content.",
-        model,
+        &model.model,
         1,
         true,
         true,
@@ -122,7 +124,7 @@ async fn run_comprehensive_analysis(client: &Client, api_key: &str, model: &str,
     agents.push(initialize_agent(
         "Document-Summarizer",
         "You are a document summarization specialist. Provide clear and concise summaries.",
-        model,
+        &model.model,
         1,
         true,
         true,
@@ -136,7 +138,7 @@ async fn run_comprehensive_analysis(client: &Client, api_key: &str, model: &str,
     agents.push(initialize_agent(
         "Financial-Analyst",
         "You are a financial analysis specialist. Analyze financial aspects of content.",
-        model,
+        &model.model,
         1,
         true,
         true,
@@ -150,7 +152,7 @@ async fn run_comprehensive_analysis(client: &Client, api_key: &str, model: &str,
     agents.push(initialize_agent(
         "Market-Analyst",
         "You are a market analysis specialist. Analyze market-related aspects.",
-        model,
+        &model.model,
         1,
         true,
         true,
@@ -164,7 +166,7 @@ async fn run_comprehensive_analysis(client: &Client, api_key: &str, model: &str,
     agents.push(initialize_agent(
         "Operational-Analyst",
         "You are an operational analysis specialist. Analyze operational aspects.",
-        model,
+        &model.model,
         1,
         true,
         true,
@@ -186,43 +188,40 @@ async fn run_comprehensive_analysis(client: &Client, api_key: &str, model: &str,
         "all"
     );
 
+    let mut swarm_router = swarm_router;
+    if swarm_router.auto_generate_prompts {
+        generate_agent_prompts(model, &mut swarm_router, prompt);
+    }
+
     // Run the comprehensive analysis task
     let mut result = String::new();
     for agent in swarm_router.agents {
-        let response = make_openai_request(client, &format!("{} {}", agent.system_prompt, prompt), &api_key, &agent.model).await?;
+        let response = model.complete(Some(&agent.system_prompt), prompt).await?;
         result.push_str(&format!("Agent {}: {}\n", agent.agent_name, response));
     }
     Ok(result)
 }
 
 #[tokio::main]
-async fn main() {
-    // Load the environment variable
-    let api_key = load_env_var("GROQ_API_KEY");
-
-    // Initialize the client
-    let client = Client::new();
-
-    // Initialize the model
-    let model = "llama-3.1-70b-versatile";
+async fn main() -> Result<(), ProviderError> {
+    // Initialize the model (reads GROQ_API_KEY)
+    let model = OpenAiCompatProvider::groq("llama-3.1-70b-versatile")?.with_temperature(0.1);
 
     // Run the comprehensive analysis task
-    let result = run_comprehensive_analysis(&client, &api_key, &model, "Where is the best place to find template term sheets for series A startups. Provide links and references").await;
-
-    match result {
-        Ok(response) => println!("{}", response),
-        Err(error) => println!("Error: {}", error),
-    }
+    let result = run_comprehensive_analysis(&model, "Where is the best place to find template term sheets for series A startups. Provide links and references").await?;
+    println!("{}", result);
+    Ok(())
 }
 ```
 
 **Feedback and Limitations:**
 
 *   The main limitation of this conversion is the availability of direct Rust equivalents for the OpenAI and SwarmRouter libraries.
-*   In the provided Rust code, we use the `reqwest` crate to make HTTP requests to the OpenAI API, and manually implement the SwarmRouter functionality.
+*   In the provided Rust code, the agents reach Groq through `OpenAiCompatProvider`, and the SwarmRouter functionality is implemented manually.
 *   We also manually implement the Agent and SwarmRouter structs, as well as the functions to initialize and run them.
-*   The `make_openai_request` function makes a request to the OpenAI API, and the `run_comprehensive_analysis` function runs the comprehensive private equity document analysis task.
-*   The `main` function initializes the client, model, and API key, and then runs the comprehensive analysis task.
+*   The `run_comprehensive_analysis` function runs the comprehensive private equity document analysis task, sending each agent's system prompt and the task to the model.
+*   With `auto_generate_prompts` set, `generate_agent_prompts` runs each agent's prompt through `PromptSynthesizer` before the task; a prompt that fails generation or review keeps the hand-written one.
+*   The `main` function initializes the model, which reads `GROQ_API_KEY`, and then runs the comprehensive analysis task.
 *   The code is written in a way that is compatible with the rest of the project, but it may require additional modifications to work seamlessly with other components.
 *   The biggest challenge in this conversion was the lack of direct Rust equivalents for the OpenAI and SwarmRouter libraries, which required manual implementation of their functionality.
 *   Another challenge was the need to handle errors and exceptions in a way that is compatible with the Rust language and its ecosystem.
@@ -237,7 +236,6 @@ async fn main() {
 
 **Recommendations:**
 
-*   Use the `reqwest` crate to make HTTP requests to the OpenAI API, as it is a popular and well-maintained crate.
 *   Manually implement the SwarmRouter and Agent functionality, as there are no direct Rust equivalents for these libraries.
 *   Use the `serde` crate to serialize and deserialize JSON data, as it is a popular and well-maintained crate.
 *   Use the `tokio` crate to handle asynchronous programming, as it is a popular and well-maintained crate.
@@ -2,25 +2,22 @@
 The conversion of this Python file to Rust is viable with some limitations and challenges. The primary challenges arise from the following:
 1. **External Libraries:** The Python code uses several external libraries (`swarms`, `swarm_models`, `dotenv`, `os`) that may not have direct Rust equivalents.
 2. **Complex Data Structures:** The Python code utilizes complex data structures like dictionaries and lists, which can be represented in Rust using `HashMap` and `Vec`, but may require additional error handling and type definitions.
-3. **API Calls:** The Python code makes API calls to the OpenAI API, which can be achieved in Rust using the `reqwest` crate.
+3. **API Calls:** The Python code makes API calls to the OpenAI API, which the Rust port makes through `OpenAiCompatProvider`.
 4. **File I/O Operations:** The Python code reads environment variables and writes to a file, which can be done in Rust using the `std::env` and `std::fs` modules.
 
 ### Rust Conversion
 
 ```rust
 // Import necessary crates
-use reqwest;
-use serde::{Deserialize, Serialize};
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 use std::collections::HashMap;
-use std::env;
 use std::fs;
 
 // Define a struct to represent the VC Legal Agent
-#[derive(Deserialize, Serialize)]
 struct VcLegalAgent {
     agent_name: String,
     system_prompt: String,
-    llm: Llm,
+    llm: OpenAiCompatProvider,
     max_loops: String,
     stopping_token: String,
     autosave: bool,
@@ -36,20 +33,8 @@ struct VcLegalAgent {
     streaming_on: bool,
 }
 
-// Define a struct to represent the LLM
-#[derive(Deserialize, Serialize)]
-struct Llm {
-    openai_api_base: String,
-    openai_api_key: String,
-    model_name: String,
-    temperature: f64,
-}
-
 // Define a function to create a VC Legal Agent
-async fn create_vc_legal_agent() -> Result<VcLegalAgent, reqwest::Error> {
-    // Load environment variables
-    let api_key = env::var("GROQ_API_KEY").expect("GROQ_API_KEY not set");
-
+async fn create_vc_legal_agent() -> Result<VcLegalAgent, ProviderError> {
     // Define the VC Legal Agent Prompt
     let vc_legal_agent_prompt = "You are a specialized legal document assistant focusing on venture capital documentation. 
 Your role is to help draft preliminary versions of common VC legal documents while adhering to these guidelines:
@@ -61,13 +46,8 @@ Your role is to help draft preliminary versions of common VC legal documents whi
 6. Output <DONE> only when document is complete and verified
 Remember: All output should be marked as 'DRAFT' and require professional legal review.";
 
-    // Define the LLM
-    let llm = Llm {
-        openai_api_base: "https://api.groq.com/openai/v1".to_string(),
-        openai_api_key: api_key,
-        model_name: "llama-3.1-70b-versatile".to_string(),
-        temperature: 0.1,
-    };
+    // Define the LLM (reads GROQ_API_KEY)
+    let llm = OpenAiCompatProvider::groq("llama-3.1-70b-versatile")?.with_temperature(0.1);
 
     // Define the VC Legal Agent
     let agent = VcLegalAgent {
@@ -97,7 +77,7 @@ async fn generate_legal_document(
     agent: VcLegalAgent,
     document_type: String,
     parameters: HashMap<String, String>,
-) -> Result<String, reqwest::Error> {
+) -> Result<String, ProviderError> {
     // Define the prompt
     let mut prompt = format!("Generate a {} with the following parameters:\n", document_type);
     for (key, value) in parameters {
@@ -111,33 +91,17 @@ async fn generate_legal_document(
 5. Output <DONE> when complete
 Include [REQUIRES LEGAL REVIEW] tags for sections needing attorney attention.");
 
-    // Make API call to generate the document
-    let client = reqwest::Client::new();
-    let res = client
-        .post("https://api.groq.com/openai/v1/completions")
-        .header("Authorization", &format!("Bearer {}", agent.llm.openai_api_key))
-        .header("Content-Type", "application/json")
-        .body(serde_json::to_string(&serde_json::json!({
-            "model": agent.llm.model_name,
-            "prompt": prompt,
-            "temperature": agent.llm.temperature,
-            "max_tokens": 2048,
-            "stream": false,
-        })).unwrap())
-        .send()
-        .await?;
-
-    // Get the generated document
-    let document = res.text().await?;
+    // Generate the document
+    let document = agent.llm.complete(Some(&agent.system_prompt), &prompt).await?;
 
     Ok(document)
 }
 
 // Example usage
 #[tokio::main]
-async fn main() {
+async fn main() -> Result<(), ProviderError> {
     // Initialize the agent
-    let legal_agent = create_vc_legal_agent().await.unwrap();
+    let legal_agent = create_vc_legal_agent().await?;
 
     // Example parameters for a term sheet
     let mut parameters = HashMap::new();
@@ -153,11 +117,11 @@ async fn main() {
         "term_sheet".to_string(),
         parameters,
     )
-    .await
-    .unwrap();
+    .await?;
 
     // Save the generated document
-    fs::write("generated_term_sheet_draft.md", document).unwrap();
+    fs::write("generated_term_sheet_draft.md", document).expect("failed to write the draft");
+    Ok(())
 }
 ```
-Note: This conversion is not perfect and may require additional modifications to work correctly. The `swarms` and `swarm_models` libraries are not used in this example as they are not directly compatible with Rust. The `dotenv` library is also not used as Rust can handle environment variables using the `std::env` module. The `reqwest` crate is used to make API calls to the OpenAI API.
\ No newline at end of file
+Note: This conversion is not perfect and may require additional modifications to work correctly. The `swarms` and `swarm_models` libraries are not used in this example as they are not directly compatible with Rust. The `dotenv` library is also not used; `OpenAiCompatProvider::groq` reads `GROQ_API_KEY` from the environment and makes the API calls.
\ No newline at end of file
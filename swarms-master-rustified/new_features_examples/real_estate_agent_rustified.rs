@@ -5,221 +5,78 @@
 // 3. Differences in language features and syntax (e.g., dataclasses, enums).
 // 4. Requires manual handling of API keys and secrets.
 
-use std::collections::HashMap;
 use std::env;
-use std::fs::File;
-use std::io::Write;
-use std::time::SystemTime;
-
-use reqwest::StatusCode;
-use serde::{Deserialize, Serialize};
-use serde_json;
-
-// Define the PropertyType enum
-#[derive(Debug, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum PropertyType {
-    Office,
-    Retail,
-    Industrial,
-    MixedUse,
-    Land,
-}
-
-// Define the PropertyListing struct
-#[derive(Debug, Serialize, Deserialize)]
-struct PropertyListing {
-    property_id: String,
-    address: String,
-    city: String,
-    state: String,
-    zip_code: String,
-    price: f64,
-    square_footage: f64,
-    property_type: PropertyType,
-    zoning: String,
-    listing_date: String,
-    lat: f64,
-    lng: f64,
-    description: Option<String>,
-    features: Option<Vec<String>>,
-    images: Option<Vec<String>>,
-}
+use std::fs;
 
-// Define the PropertyRadarAPI struct
-struct PropertyRadarAPI {
-    api_key: String,
-    base_url: String,
-}
+use serde::Serialize;
 
-impl PropertyRadarAPI {
-    fn new(api_key: String) -> Self {
-        PropertyRadarAPI {
-            api_key,
-            base_url: "https://api.propertyradar.com/v1".to_string(),
-        }
-    }
+use crate::swarms::integrations::propertyradar::{
+    PropertyListing, PropertyRadarClient, PropertyRadarError, PropertySearch, PropertyType,
+};
 
-    async fn search_properties(
-        &self,
-        max_price: f64,
-        property_types: Option<Vec<PropertyType>>,
-        location: Option<HashMap<String, String>>,
-        min_sqft: Option<f64>,
-        max_sqft: Option<f64>,
-    ) -> Vec<PropertyListing> {
-        let client = reqwest::Client::new();
-
-        let mut params = HashMap::new();
-        params.insert("price_max".to_string(), max_price.to_string());
-        if let Some(property_types) = property_types {
-            let property_types_str: Vec<String> = property_types
-                .into_iter()
-                .map(|pt| format!("{:?}", pt))
-                .collect();
-            params.insert("property_types".to_string(), property_types_str.join(","));
-        }
-        if let Some(location) = location {
-            for (key, value) in location {
-                params.insert(key, value);
-            }
-        }
-        if let Some(min_sqft) = min_sqft {
-            params.insert("square_feet_min".to_string(), min_sqft.to_string());
-        }
-        if let Some(max_sqft) = max_sqft {
-            params.insert("square_feet_max".to_string(), max_sqft.to_string());
-        }
-
-        let response = client
-            .get(&format!("{}/properties", self.base_url))
-            .header("Authorization", format!("Bearer {}", self.api_key))
-            .header("Content-Type", "application/json")
-            .query(&params)
-            .send()
-            .await
-            .unwrap();
-
-        if response.status() != StatusCode::OK {
-            panic!("Failed to retrieve properties");
-        }
-
-        let properties_data: serde_json::Value = response
-            .json()
-            .await
-            .unwrap();
-
-        let mut properties = Vec::new();
-        for prop in properties_data["results"].as_array().unwrap() {
-            let property_listing = PropertyListing {
-                property_id: prop["id"].as_str().unwrap().to_string(),
-                address: prop["address"].as_str().unwrap().to_string(),
-                city: prop["city"].as_str().unwrap().to_string(),
-                state: prop["state"].as_str().unwrap().to_string(),
-                zip_code: prop["zip_code"].as_str().unwrap().to_string(),
-                price: prop["price"].as_f64().unwrap(),
-                square_footage: prop["square_feet"].as_f64().unwrap(),
-                property_type: serde_json::from_value(prop["property_type"].clone()).unwrap(),
-                zoning: prop["zoning"].as_str().unwrap().to_string(),
-                listing_date: prop["list_date"].as_str().unwrap().to_string(),
-                lat: prop["latitude"].as_f64().unwrap(),
-                lng: prop["longitude"].as_f64().unwrap(),
-                description: prop["description"].as_str().map(|s| s.to_string()),
-                features: prop["features"].as_array().map(|arr| {
-                    arr.into_iter()
-                        .map(|feat| feat.as_str().unwrap().to_string())
-                        .collect()
-                }),
-                images: prop["images"].as_array().map(|arr| {
-                    arr.into_iter()
-                        .map(|image| image.as_str().unwrap().to_string())
-                        .collect()
-                }),
-            };
-
-            properties.push(property_listing);
-        }
-
-        properties
-    }
+// A listing paired with the agent's analysis of it
+#[derive(Debug, Serialize)]
+struct AnalyzedProperty {
+    property: PropertyListing,
+    analysis: String,
 }
 
 // Define the CommercialRealEstateAgent struct
+// The PropertyRadar client lives in swarms::integrations::propertyradar; this agent only orchestrates.
 struct CommercialRealEstateAgent {
-    property_api: PropertyRadarAPI,
+    property_api: PropertyRadarClient,
     agent_name: String,
 }
 
 impl CommercialRealEstateAgent {
-    fn new(propertyradar_api_key: String) -> Self {
+    fn new(property_api: PropertyRadarClient) -> Self {
         CommercialRealEstateAgent {
-            property_api: PropertyRadarAPI::new(propertyradar_api_key),
+            property_api,
             agent_name: "Commercial-Real-Estate-Agent".to_string(),
         }
     }
 
     async fn search_properties(
         &self,
-        max_price: f64,
-        property_types: Option<Vec<PropertyType>>,
-        location: Option<HashMap<String, String>>,
-        min_sqft: Option<f64>,
-        max_sqft: Option<f64>,
-    ) -> Vec<HashMap<String, serde_json::Value>> {
-        let properties = self
-            .property_api
-            .search_properties(max_price, property_types, location, min_sqft, max_sqft)
-            .await;
-
-        let mut analyzed_properties = Vec::new();
-        for prop in properties {
-            let mut analyzed_property = HashMap::new();
-            analyzed_property.insert(
-                "property".to_string(),
-                serde_json::to_value(prop).unwrap(),
-            );
-            analyzed_property.insert("analysis".to_string(), serde_json::Value::String("analysis".to_string()));
-            analyzed_properties.push(analyzed_property);
-        }
-
-        analyzed_properties
+        search: &PropertySearch,
+        max_results: usize,
+    ) -> Result<Vec<AnalyzedProperty>, PropertyRadarError> {
+        let properties = self.property_api.search_all(search, max_results).await?;
+
+        Ok(properties
+            .into_iter()
+            .map(|property| AnalyzedProperty {
+                analysis: format!("{}: analysis pending for {}", self.agent_name, property.address),
+                property,
+            })
+            .collect())
     }
 }
 
 #[tokio::main]
-async fn main() {
-    // Load API keys from environment variables
-    let openai_api_key = env::var("OPENAI_API_KEY").unwrap();
-    let propertyradar_api_key = env::var("PROPERTYRADAR_API_KEY").unwrap();
-
-    // Initialize the agent
-    let agent = CommercialRealEstateAgent::new(propertyradar_api_key);
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Initialize the agent (reads PROPERTYRADAR_API_KEY)
+    let _openai_api_key = env::var("OPENAI_API_KEY")?;
+    let agent = CommercialRealEstateAgent::new(PropertyRadarClient::from_env()?);
 
     // Example search
-    let results = agent
-        .search_properties(
-            5_000_000.0,
-            Some(vec![PropertyType::Retail, PropertyType::Office]),
-            Some(
-                vec![("city".to_string(), "Orlando".to_string()), ("radius_miles".to_string(), "25".to_string())]
-                    .into_iter()
-                    .collect(),
-            ),
-            Some(2000.0),
-            None,
-        )
-        .await;
+    let search = PropertySearch::new()
+        .max_price(5_000_000.0)
+        .property_types([PropertyType::Retail, PropertyType::Office])
+        .city("Orlando")
+        .radius_miles(25.0)
+        .min_sqft(2000.0);
+    let results = agent.search_properties(&search, 100).await?;
 
     // Save results to a JSON file
-    let mut file = File::create("search_results.json").unwrap();
-    let json = serde_json::to_string_pretty(&results).unwrap();
-    file.write_all(json.as_bytes()).unwrap();
+    fs::write("search_results.json", serde_json::to_string_pretty(&results)?)?;
+    Ok(())
 }
 ```
 
 **Conversion Notes:**
 
-1. **Error handling:** Rust uses a `Result` type to handle errors, whereas Python uses exceptions. We've replaced Python's `try-except` blocks with Rust's `unwrap` or `expect` methods to handle errors. However, for a more robust implementation, you should use proper error handling mechanisms.
+1. **Error handling:** Rust uses a `Result` type to handle errors, whereas Python uses exceptions. API failures surface as `PropertyRadarError` and are propagated with `?` instead of panicking.
 2. **Dependency equivalents:** Some Python dependencies, like `loguru`, `openai`, and `propertyradar`, do not have direct Rust equivalents. You'll need to find suitable alternatives or implement them manually.
 3. **Dataclasses and enums:** Rust has different mechanisms for defining data structures. We've replaced Python's `dataclasses` with Rust's `struct` and used `enum` for the `PropertyType`.
 4. **API keys and secrets:** In the provided code, API keys are loaded from environment variables. Make sure to handle them securely in your production environment.
@@ -3,7 +3,7 @@ The provided Python code can be partially converted to Rust, but several parts w
 
 *   The `dotenv` crate is available in Rust, but it doesn't work exactly like the Python `dotenv` library. We can use the `dotenv` crate to load environment variables from a `.env` file.
 *   The `swarms` and `swarm_models` libraries don't have direct Rust equivalents. These libraries seem to be custom or third-party libraries specific to Python. We'll need to replicate their functionality in Rust or use alternative libraries that provide similar functionality.
-*   The `OpenAIChat` model is initialized with a specific API base and API key. In Rust, we can use the `reqwest` crate for HTTP requests and the `serde` crate for JSON serialization and deserialization.
+*   The `OpenAIChat` model is initialized with a specific API base and API key. In Rust, `OpenAiCompatProvider::groq` covers the same Groq endpoint.
 *   The `Agent` and `SequentialWorkflow` initialization requires custom Rust implementations, as there are no direct equivalents in the Rust ecosystem.
 
 Here's a simplified Rust version of the provided Python code:
@@ -13,37 +13,14 @@ Here's a simplified Rust version of the provided Python code:
 // The conversion is partially viable because we can replicate some of the functionality,
 // but some parts require custom implementation or alternative libraries.
 
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 use dotenv::dotenv;
-use std::{env, error::Error};
-use reqwest::Client;
-use serde::{Deserialize, Serialize};
-
-// Define a struct to represent the OpenAI model
-#[derive(Serialize, Deserialize)]
-struct OpenAIModel {
-    openai_api_base: String,
-    openai_api_key: String,
-    model_name: String,
-    temperature: f64,
-}
-
-impl OpenAIModel {
-    fn new(openai_api_base: String, openai_api_key: String, model_name: String, temperature: f64) -> Self {
-        OpenAIModel {
-            openai_api_base,
-            openai_api_key,
-            model_name,
-            temperature,
-        }
-    }
-}
 
 // Define a struct to represent the Agent
-#[derive(Serialize, Deserialize)]
 struct Agent {
     agent_name: String,
     system_prompt: Option<String>,
-    llm: OpenAIModel,
+    llm: OpenAiCompatProvider,
     max_loops: i32,
     autosave: bool,
     verbose: bool,
@@ -59,7 +36,7 @@ impl Agent {
     fn new(
         agent_name: String,
         system_prompt: Option<String>,
-        llm: OpenAIModel,
+        llm: OpenAiCompatProvider,
         max_loops: i32,
         autosave: bool,
         verbose: bool,
@@ -88,7 +65,6 @@ impl Agent {
 }
 
 // Define a struct to represent the SequentialWorkflow
-#[derive(Serialize, Deserialize)]
 struct SequentialWorkflow {
     name: String,
     description: String,
@@ -114,40 +90,23 @@ impl SequentialWorkflow {
         }
     }
 
-    async fn run(&self, input: &str, img: Option<String>) -> Result<String, reqwest::Error> {
-        let client = Client::new();
-        let url = format!("{}/chat/completions", self.agents[0].llm.openai_api_base);
-        let response = client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", self.agents[0].llm.openai_api_key))
-            .json(&serde_json::json!({
-                "model": self.agents[0].llm.model_name,
-                "prompt": input,
-                "temperature": self.agents[0].llm.temperature,
-                "max_tokens": self.agents[0].context_length,
-            }))
-            .send()
-            .await?;
-        let response_text = response.text().await?;
-        Ok(response_text)
+    // Each agent works on the previous agent's output
+    async fn run(&self, input: &str) -> Result<String, ProviderError> {
+        let mut output = input.to_string();
+        for agent in &self.agents {
+            output = agent.llm.complete(agent.system_prompt.as_deref(), &output).await?;
+        }
+        Ok(output)
     }
 }
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn Error>> {
+async fn main() -> Result<(), ProviderError> {
     // Load environment variables
     dotenv().ok();
 
-    // Get the OpenAI API key from the environment variable
-    let api_key = env::var("GROQ_API_KEY").unwrap();
-
-    // Initialize the OpenAI model
-    let model = OpenAIModel::new(
-        "https://api.groq.com/openai/v1".to_string(),
-        api_key,
-        "llama-3.1-70b-versatile".to_string(),
-        0.1,
-    );
+    // Initialize the Groq model (reads GROQ_API_KEY)
+    let model = OpenAiCompatProvider::groq("llama-3.1-70b-versatile")?.with_temperature(0.1);
 
     // Initialize agents
     let data_extractor_agent = Agent::new(
@@ -241,8 +200,8 @@ async fn main() -> Result<(), Box<dyn Error>> {
     );
 
     // Run a comprehensive private equity document analysis task
-    let result = router.run("Where is the best place to find template term sheets for series A startups. Provide links and references", None).await;
-    println!("{:?}", result);
+    let result = router.run("Where is the best place to find template term sheets for series A startups. Provide links and references").await?;
+    println!("{}", result);
 
     Ok(())
 }
@@ -251,7 +210,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
 This Rust version includes the following adjustments:
 
 *   We use the `dotenv` crate to load environment variables.
-*   We define custom structs to represent the `OpenAIModel`, `Agent`, and `SequentialWorkflow`.
-*   We implement the `run` method for the `SequentialWorkflow` struct using the `reqwest` crate for HTTP requests and the `serde` crate for JSON serialization and deserialization.
+*   We define custom structs to represent the `Agent` and `SequentialWorkflow`; the model is an `OpenAiCompatProvider`.
+*   The `run` method of `SequentialWorkflow` passes each agent's output to the next agent through the provider.
 
 The example usage is demonstrated in the `main` function, which initializes the agents and runs a comprehensive private equity document analysis task.
\ No newline at end of file
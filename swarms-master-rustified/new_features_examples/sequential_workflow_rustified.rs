@@ -9,62 +9,19 @@ Here's the equivalent Rust code, with explanations and comments for clarity:
 
 ```rust
 // Import necessary crates
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 use dotenv::dotenv;
-use std::env;
-use std::fs;
-use reqwest::Client;
-use serde_json::json;
 
 // Load environment variables from .env file
 fn load_env() {
     dotenv().ok();
 }
 
-// Define a struct for the OpenAIChat model
-struct OpenAIChat {
-    openai_api_base: String,
-    openai_api_key: String,
-    model_name: String,
-    temperature: f64,
-}
-
-// Implement methods for the OpenAIChat model
-impl OpenAIChat {
-    fn new(openai_api_base: String, openai_api_key: String, model_name: String, temperature: f64) -> Self {
-        OpenAIChat {
-            openai_api_base,
-            openai_api_key,
-            model_name,
-            temperature,
-        }
-    }
-
-    // Send a request to the OpenAI API
-    async fn send_request(&self, prompt: &str) -> reqwest::Result<String> {
-        let client = Client::new();
-        let url = format!("{}/complete", self.openai_api_base);
-        let params = json!({
-            "model": self.model_name,
-            "prompt": prompt,
-            "max_tokens": 2048,
-            "temperature": self.temperature,
-        });
-        let res = client
-            .post(url)
-            .header("Authorization", format!("Bearer {}", self.openai_api_key))
-            .json(&params)
-            .send()
-            .await?;
-        let body = res.text().await?;
-        Ok(body)
-    }
-}
-
 // Define a struct for the Agent
 struct Agent {
     agent_name: String,
     system_prompt: String,
-    llm: OpenAIChat,
+    llm: OpenAiCompatProvider,
     max_loops: u32,
     autosave: bool,
     verbose: bool,
@@ -81,7 +38,7 @@ impl Agent {
     fn new(
         agent_name: String,
         system_prompt: String,
-        llm: OpenAIChat,
+        llm: OpenAiCompatProvider,
         max_loops: u32,
         autosave: bool,
         verbose: bool,
@@ -109,9 +66,8 @@ impl Agent {
     }
 
     // Run the agent with the given input
-    async fn run(&self, input: &str) -> reqwest::Result<String> {
-        let response = self.llm.send_request(input).await?;
-        Ok(response)
+    async fn run(&self, input: &str) -> Result<String, ProviderError> {
+        self.llm.complete(Some(&self.system_prompt), input).await
     }
 }
 
@@ -143,7 +99,7 @@ impl SequentialWorkflow {
     }
 
     // Run the workflow with the given input
-    async fn run(&self, input: &str) -> reqwest::Result<Vec<String>> {
+    async fn run(&self, input: &str) -> Result<Vec<String>, ProviderError> {
         let mut results = Vec::new();
         for agent in &self.agents {
             let response = agent.run(input).await?;
@@ -154,16 +110,10 @@ impl SequentialWorkflow {
 }
 
 #[tokio::main]
-async fn main() -> reqwest::Result<()> {
+async fn main() -> Result<(), ProviderError> {
     load_env();
-    let api_key = env::var("GROQ_API_KEY").expect("GROQ_API_KEY not found");
-
-    let model = OpenAIChat::new(
-        "https://api.groq.com/openai/v1".to_string(),
-        api_key,
-        "llama-3.1-70b-versatile".to_string(),
-        0.1,
-    );
+    // Reads GROQ_API_KEY
+    let model = OpenAiCompatProvider::groq("llama-3.1-70b-versatile")?.with_temperature(0.1);
 
     let data_extractor_agent = Agent::new(
         "Data-Extractor".to_string(),
@@ -268,7 +218,7 @@ This Rust code is equivalent to the provided Python code, with some modification
 
 **Limitations and challenges:**
 
-1. **Third-party libraries:** The Rust code uses the `reqwest` crate for HTTP requests, which is different from the `requests` library used in Python. Additionally, the `serde_json` crate is used for JSON serialization, whereas Python uses the `json` library.
+1. **Third-party libraries:** The Rust code reaches Groq through `OpenAiCompatProvider`, where the Python code uses `swarm_models.OpenAIChat`.
 2. **Environment variables:** Rust uses the `dotenv` crate to load environment variables from a `.env` file, whereas Python uses the `dotenv` library.
 3. **File system interactions:** Rust uses the `std::fs` module for file system interactions, whereas Python uses the `os` library.
 4. **Error handling:** Rust is more strict about error handling than Python, requiring explicit error handling using `Result` types and `?` operator.
@@ -15,9 +15,8 @@ Below is a simplified version of the provided Python code, rewritten in Rust. Th
 
 ```rust
 // Import necessary libraries
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 use serde::{Deserialize, Serialize};
-use std::env;
-use std::fs;
 
 // Define a struct to represent a college log
 #[derive(Serialize, Deserialize, Debug)]
@@ -52,17 +51,9 @@ fn load_env() {
     dotenvy::from_path(".env").ok();
 }
 
-// Initialize the model
-fn initialize_model() -> String {
-    let api_key = env::var("GROQ_API_KEY").unwrap_or_default();
-    let openai_api_base = "https://api.groq.com/openai/v1".to_string();
-    let model_name = "llama-3.1-70b-versatile".to_string();
-    let temperature = 0.1;
-
-    format!(
-        "{}{}{}{}",
-        openai_api_base, model_name, api_key, temperature
-    )
+// Initialize the model (reads GROQ_API_KEY)
+fn initialize_model() -> Result<OpenAiCompatProvider, ProviderError> {
+    Ok(OpenAiCompatProvider::groq("llama-3.1-70b-versatile")?.with_temperature(0.1))
 }
 
 // Initialize the agents
@@ -109,28 +100,39 @@ fn initialize_agents() -> Vec<Agent> {
     agents
 }
 
-// Run the comprehensive college selection analysis
-fn run_analysis(agents: Vec<Agent>) -> String {
+// Run the comprehensive college selection analysis along the flow, each agent working on the
+// previous agent's output
+async fn run_analysis(model: &OpenAiCompatProvider, agents: Vec<Agent>, task: &str) -> Result<String, ProviderError> {
     let mut result = String::new();
+    let mut input = task.to_string();
 
     for agent in agents {
-        // Simulate the agent's analysis
-        let analysis = format!("{}: {}", agent.agent_name, agent.system_prompt);
+        let analysis = model.complete(Some(&agent.system_prompt), &input).await?;
 
-        result.push_str(&analysis);
-        result.push('\n');
+        result.push_str(&format!("{}: {}\n", agent.agent_name, analysis));
+        input = analysis;
     }
 
-    result
+    Ok(result)
 }
 
-fn main() {
+#[tokio::main]
+async fn main() -> Result<(), ProviderError> {
     load_env();
-    let model = initialize_model();
+    let model = initialize_model()?;
     let agents = initialize_agents();
-    let result = run_analysis(agents);
+    let student_profile = "Student Profile:
+        - GPA: 3.8
+        - SAT: 1450
+        - Interests: Computer Science, Robotics
+        - Location Preference: East Coast
+        - Extracurriculars: Robotics Club President, Math Team
+        - Budget: Need financial aid
+        - Preferred Environment: Medium-sized urban campus";
+    let result = run_analysis(&model, agents, student_profile).await?;
 
     println!("{}", result);
+    Ok(())
 }
 ```
 
@@ -143,7 +145,7 @@ This Rust implementation has several limitations and challenges:
 *   **F-Strings and String Formatting**: Rust has a different string formatting system than Python, and it does not support f-strings. This implementation uses the `format!` macro to create formatted strings.
 *   **Error Handling**: This implementation does not handle errors explicitly, which can lead to panics or unexpected behavior. A more robust implementation would use error handling mechanisms like `Result` and `Option`.
 *   **Concurrency and Parallelism**: This implementation does not use concurrency or parallelism, which can limit its performance. A more robust implementation would use mechanisms like `std::thread` or `tokio` to execute tasks concurrently.
-*   **Agent Interactions and Workflow**: This implementation simulates agent interactions and workflow, but it does not implement the actual logic and behavior of the agents. A more robust implementation would define the agent's behavior and interactions using a more structured approach.
+*   **Agent Interactions and Workflow**: This implementation runs the agents one after another through the Groq `OpenAiCompatProvider`, but it does not implement `AgentRearrange`'s flow parsing. A more robust implementation would define the agent's behavior and interactions using a more structured approach.
 
 To address these limitations and challenges, a more robust implementation would require:
 
@@ -3,87 +3,82 @@
 // Reasoning: The provided Python code deals with API calls to OpenAI services, environment variable handling, and uses a simple data model defined with Pydantic. Rust can handle these tasks with its own libraries and data modeling system. However, Rust may require more manual memory management and type definitions compared to Python.
 
 // Import necessary libraries
+use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem};
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 use dotenv::dotenv;
-use reqwest;
 use serde::{Deserialize, Serialize};
-use std::env;
+use serde_json::json;
 
 // Define the data models
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct CollegeLog {
-    #[serde(rename = "college_name")]
     college_name: String,
-    #[serde(rename = "college_description")]
     college_description: String,
-    #[serde(rename = "college_admission_requirements")]
     college_admission_requirements: String,
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct CollegesRecommendation {
-    #[serde(rename = "colleges")]
     colleges: Vec<CollegeLog>,
-    #[serde(rename = "reasoning")]
     reasoning: String,
 }
 
-const GROQ_API_BASE: &str = "https://api.groq.com/openai/v1";
 const MODEL_NAME: &str = "llama-3.1-70b-versatile";
 const TEMPERATURE: f64 = 0.1;
 
-// Load environment variables
-fn load_environment_variables() {
-    dotenv().ok();
-
-    // Get the API key from environment variable
-    let api_key = env::var("GROQ_API_KEY").expect("GROQ_API_KEY must be set");
-    let openai_api_key = env::var("OPENAI_API_KEY").expect("OPENAI_API_KEY must be set");
+const SYSTEM_PROMPT: &str = "You are a college selection final decision maker. Your role is to:
+    - Balance all relevant factors and stakeholder input.
+    - Only return the output in the schema format.";
+
+// The JSON schema of `CollegesRecommendation`, the Rust stand-in for the Pydantic `base_model`
+fn recommendation_schema() -> serde_json::Value {
+    json!({
+        "type": "object",
+        "properties": {
+            "colleges": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "college_name": { "type": "string" },
+                        "college_description": { "type": "string" },
+                        "college_admission_requirements": { "type": "string" }
+                    },
+                    "required": ["college_name", "college_description", "college_admission_requirements"]
+                }
+            },
+            "reasoning": { "type": "string" }
+        },
+        "required": ["colleges", "reasoning"]
+    })
+}
 
-    (
-        api_key,
-        openai_api_key,
-    )
+// Initialize the model (reads GROQ_API_KEY)
+fn initialize_model() -> Result<OpenAiCompatProvider, ProviderError> {
+    Ok(OpenAiCompatProvider::groq(MODEL_NAME)?.with_temperature(TEMPERATURE))
 }
 
-// Initialize the model
-async fn initialize_model(api_key: String) -> Result<String, reqwest::Error> {
-    let client = reqwest::Client::new();
-    let res = client
-        .post(format!("{}/models/{}", GROQ_API_BASE, MODEL_NAME))
-        .header("Authorization", format!("Bearer {}", api_key))
-        .header("Content-Type", "application/json")
-        .body(format!("{{ \"temperature\": {} }}", TEMPERATURE))
-        .send()
-        .await?;
-    
-    Ok(res.text().await?)
+fn text_message(role: &str, text: &str) -> ChatMessageInput {
+    ChatMessageInput::new(role.to_string(), vec![ContentItem::Text { text: text.to_string() }])
 }
 
-// Run the function caller
-async fn run_function_caller(openai_api_key: String, input: &str) -> Result<String, reqwest::Error> {
-    let client = reqwest::Client::new();
-    let res = client
-        .post("https://api.openai.com/v1/completions")
-        .header("Authorization", format!("Bearer {}", openai_api_key))
-        .header("Content-Type", "application/json")
-        .body(format!("{{ 
-            \"model\": \"text-davinci-002\", 
-            \"prompt\": \"{}\",
-            \"max_tokens\": 2048,
-            \"temperature\": 0.6 
-        }}", input))
-        .send()
-        .await?;
-    
-    Ok(res.text().await?)
+// Run the function caller: ask for a `CollegesRecommendation` and parse the reply into one
+async fn run_function_caller(model: &OpenAiCompatProvider, input: &str) -> Result<CollegesRecommendation, ProviderError> {
+    let request = ChatCompletionRequest::new(
+        MODEL_NAME.to_string(),
+        vec![text_message("system", SYSTEM_PROMPT), text_message("user", input)],
+    )
+    .with_json_schema("CollegesRecommendation", recommendation_schema());
+
+    let completion = model.chat(&request).await?;
+    serde_json::from_str(&completion.content).map_err(|e| ProviderError::InvalidResponse(e.to_string()))
 }
 
 // Main function
 #[tokio::main]
-async fn main() {
-    let (api_key, openai_api_key) = load_environment_variables();
-
-    let _ = initialize_model(api_key).await;
+async fn main() -> Result<(), ProviderError> {
+    dotenv().ok();
+    let model = initialize_model()?;
 
     let input = "Student Profile: Kye Gomez
         - GPA: 3.8
@@ -93,13 +88,10 @@ async fn main() {
         - Extracurriculars: Robotics Club President, Math Team
         - Budget: Need financial aid
         - Preferred Environment: Medium-sized urban campus";
-    
-    let output = run_function_caller(openai_api_key, input).await;
 
-    match output {
-        Ok(text) => println!("{}", text),
-        Err(err) => println!("Error: {}", err),
-    }
+    let recommendation = run_function_caller(&model, input).await?;
+    println!("{:#?}", recommendation);
+    Ok(())
 }
 ```
 
@@ -107,10 +99,11 @@ async fn main() {
 
 1. **Error Handling:** In Rust, error handling is done explicitly using the `Result` type and `?` operator. In contrast, Python often uses try/except blocks for error handling.
 2. **Async Programming:** Rust uses the Tokio library for async programming, whereas Python uses the `asyncio` library.
-3. **Libraries and Frameworks:** The Rust code uses the `reqwest` library for making HTTP requests, whereas the Python code uses the `requests` library.
+3. **Libraries and Frameworks:** The Rust code reaches the model through `OpenAiCompatProvider`, whereas the Python code uses the `swarm_models` wrappers.
 4. **Data Modeling:** Rust uses the `serde` library for serializing and deserializing data, whereas Python uses the `pydantic` library.
 5. **Environment Variables:** Rust uses the `dotenv` library to load environment variables, whereas Python uses the `dotenv` library as well.
 6. **Initialization of Models:** Rust requires a more explicit initialization of models compared to Python.
+7. **Function Caller:** The Python example builds a Groq model it never uses and sends the function call to OpenAI. Here the call goes through the Groq `OpenAiCompatProvider` with the schema as the response format. Groq has JSON mode but no schemas, so the provider moves the schema into the system prompt (see `provider_capabilities`).
 
 **Challenges:**
 
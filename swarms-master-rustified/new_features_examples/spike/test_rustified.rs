@@ -3,10 +3,8 @@
 // The reasoning behind this assessment is that Rust has a different set of libraries and frameworks compared to Python, and some of the Python libraries may not have Rust equivalents.
 // The code that deals with the logic of the college selection workflow can be converted, but the external library dependencies will need to be replaced with Rust versions.
 
-use std::env;
-use std::fs;
+use crate::swarms::utils::openai_compat_provider::{OpenAiCompatProvider, ProviderError};
 use serde::{Serialize, Deserialize};
-use reqwest;
 
 // Define the CollegeLog struct
 #[derive(Serialize, Deserialize)]
@@ -28,7 +26,6 @@ struct CollegesRecommendation {
 struct Agent {
     agent_name: String,
     system_prompt: String,
-    llm: String,
     max_loops: i32,
     verbose: bool,
     dynamic_temperature_enabled: bool,
@@ -48,21 +45,13 @@ struct SequentialWorkflow {
     output_type: String,
 }
 
-// Define the function to load the API key from the environment variable
-fn load_api_key() -> String {
-    env::var("GROQ_API_KEY").expect("GROQ_API_KEY must be set")
-}
-
-// Define the function to initialize the model
-fn init_model() -> String {
-    let api_key = load_api_key();
-    let model = format!("https://api.groq.com/openai/v1?api_key={}&model_name=llama-3.1-70b-versatile&temperature=0.1", api_key);
-    model
+// Define the function to initialize the model (reads GROQ_API_KEY)
+fn init_model() -> Result<OpenAiCompatProvider, ProviderError> {
+    Ok(OpenAiCompatProvider::groq("llama-3.1-70b-versatile")?.with_temperature(0.1))
 }
 
 // Define the function to create the agents
 fn create_agents() -> Vec<Agent> {
-    let model = init_model();
     let mut agents = Vec::new();
 
     let profile_analyzer_agent = Agent {
@@ -79,7 +68,6 @@ fn create_agents() -> Vec<Agent> {
             Always consider both quantitative metrics (GPA, test scores) and qualitative aspects 
             (personal growth, challenges overcome, unique perspectives).
         """),
-        llm: model.clone(),
         max_loops: 1,
         verbose: true,
         dynamic_temperature_enabled: true,
@@ -103,7 +91,6 @@ fn create_agents() -> Vec<Agent> {
             Focus on providing accurate, comprehensive information about each institution
             while considering both academic and cultural fit factors.
         """),
-        llm: model.clone(),
         max_loops: 1,
         verbose: true,
         dynamic_temperature_enabled: true,
@@ -127,7 +114,6 @@ fn create_agents() -> Vec<Agent> {
             Always provide a balanced list with realistic expectations while 
             considering both student preferences and admission probability.
         """),
-        llm: model.clone(),
         max_loops: 1,
         verbose: true,
         dynamic_temperature_enabled: true,
@@ -151,7 +137,6 @@ fn create_agents() -> Vec<Agent> {
             Maintain objectivity while ensuring all important factors are thoroughly discussed
             and evaluated.
         """),
-        llm: model.clone(),
         max_loops: 1,
         verbose: true,
         dynamic_temperature_enabled: true,
@@ -175,7 +160,6 @@ fn create_agents() -> Vec<Agent> {
             Focus on constructive criticism that helps improve the final college list
             while maintaining realistic expectations.
         """),
-        llm: model.clone(),
         max_loops: 1,
         verbose: true,
         dynamic_temperature_enabled: true,
@@ -199,7 +183,6 @@ fn create_agents() -> Vec<Agent> {
             Focus on creating actionable, well-reasoned final recommendations that 
             balance all relevant factors and stakeholder input.
         """),
-        llm: model.clone(),
         max_loops: 1,
         verbose: true,
         dynamic_temperature_enabled: true,
@@ -219,8 +202,10 @@ fn create_agents() -> Vec<Agent> {
     agents
 }
 
-fn main() {
-    // Create the agents
+#[tokio::main]
+async fn main() -> Result<(), ProviderError> {
+    // Initialize the model and create the agents
+    let model = init_model()?;
     let agents = create_agents();
 
     // Example student profile input
@@ -240,31 +225,24 @@ fn main() {
         name: String::from("college-selection-swarm"),
         description: String::from("Comprehensive college selection and analysis system"),
         max_loops: 1,
-        agents: agents.clone(),
+        agents,
         output_type: String::from("all"),
     };
 
-    // Run the comprehensive college selection analysis
-    let mut result = String::new();
-    for agent in agents {
-        let client = reqwest::Client::new();
-        let res = client.post(agent.llm)
-            .body(student_profile.clone())
-            .send()
-            .expect("Failed to send request");
-
-        let text = res.text().expect("Failed to read response");
-        result.push_str(&text);
+    // Run the comprehensive college selection analysis, each agent working on the previous agent's output
+    let mut result = student_profile;
+    for agent in &college_selection_workflow.agents {
+        result = model.complete(Some(&agent.system_prompt), &result).await?;
     }
 
     println!("{}", result);
+    Ok(())
 }
 ```
 
 Potential limitations and challenges:
 - **External library dependencies:** The code relies on Python libraries `swarms`, `swarm_models`, and `pydantic`. Rust equivalents would need to be found or implemented.
-- **API requests:** The code makes API requests using `reqwest` in Rust. The API endpoint and request structure might need adjustments to match the original Python code.
-- **Async/await:** The Rust code uses synchronous API requests. For a more efficient and scalable solution, you could use async/await with libraries like `tokio` or `async-std`.
+- **API requests:** The agents reach Groq through `OpenAiCompatProvider`, which sends OpenAI-style chat completion requests.
 - **Error handling:** The code does not include comprehensive error handling. In a production environment, you should add proper error handling and logging mechanisms.
 - **Agent logic:** The agent logic is simplified in the Rust version. You might need to add more complex logic and conditional statements to match the original Python code.
 - **Model initialization:** The model initialization is simplified in the Rust version. You might need to add more complex model initialization logic to match the original Python code.
\ No newline at end of file
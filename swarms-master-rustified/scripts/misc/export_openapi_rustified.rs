@@ -0,0 +1,54 @@
+### Overview
+There is no Python counterpart. FastAPI wrote its OpenAPI document at runtime, and nothing published it.
+This script writes the document from `api_spec()` to a file, so CI can keep it and generate typed
+clients from it without starting a server.
+
+Run it with `cargo run --features openapi --bin export_openapi -- target/openapi/openapi.json`. With no
+argument it prints the document to stdout. Add `--features server` to include the `/runs` routes.
+
+### Rust Code
+```rust
+use std::fs;
+use std::path::Path;
+use swarms::swarms::integrations::api_spec;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let json = api_spec().to_pretty_json()?;
+    match std::env::args().nth(1) {
+        Some(path) => {
+            let path = Path::new(&path);
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::write(path, json)?;
+            println!("wrote {}", path.display());
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+```
+
+### Notes
+* The CI job builds with `--features full` so that every route is in the document, then generates the
+  clients and uploads `target/openapi` as the `openapi` artifact:
+
+  ```yaml
+  - run: cargo run --features full --bin export_openapi -- target/openapi/openapi.json
+  - run: >
+      npx @openapitools/openapi-generator-cli generate -g rust
+      -i target/openapi/openapi.json -o target/openapi/clients/rust
+      --additional-properties=packageName=swarms-api-client
+  - run: >
+      npx @openapitools/openapi-generator-cli generate -g typescript-fetch
+      -i target/openapi/openapi.json -o target/openapi/clients/typescript
+  - uses: actions/upload-artifact@v4
+    with:
+      name: openapi
+      path: target/openapi
+  ```
+
+* The generated clients are artifacts, not checked in. They change whenever a handler's operation in
+  `integrations::openapi` changes, and a stale checked-in copy would be worse than none.
+* Problem responses are the `default` response of every operation, so the generated clients surface them
+  as a typed error instead of a string.
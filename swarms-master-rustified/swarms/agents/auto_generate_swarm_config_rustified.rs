@@ -1,21 +1,58 @@
-**Conversion Viability:**
-The conversion of this Python file to Rust is viable with some limitations and challenges. The main limitations arise from the use of libraries like `tenacity`, `dotenv`, `swarms`, and `litellm`, which do not have direct Rust equivalents. Additionally, Rust's ecosystem for natural language processing and machine learning is still evolving and might not offer the same level of maturity as Python's.
-
-**Rust Equivalent:**
-
+### Overview
+The Python `generate_swarm_config` asks the Auto-Swarm-Builder agent for YAML, prints it, and then hands
+it to `create_agents_from_yaml`. If the YAML is malformed, the error surfaces there, and the YAML itself
+is gone. `SwarmConfigGenerator::generate` keeps the whole pipeline in one place and returns a swarm that
+can run:
+
+1. The model's reply goes through `parse_yaml_from_swarm_markdown` and `prepare_yaml_for_parsing`, and
+   the result is loaded with the same `load_yaml_safely` that `create_agents_from_yaml` uses.
+2. The `YAMLConfig` is checked against the rules `AUTO_GEN_PROMPT` gives the model. There must be at
+   least one agent, and agent names must be unique. Every agent needs a system prompt and must build as
+   an `LlmAgent`, and the swarm type must be one this crate can run.
+3. With `auto_repair` on (the default), mechanical problems are fixed in place:
+   - blank or duplicate names;
+   - non-positive `max_loops` and `context_length`, and negative `retry_attempts`;
+   - a missing swarm name;
+   - swarm types other than `SequentialWorkflow`.
+   Each fix is recorded. Problems that cannot be fixed mechanically, such as unparseable YAML or an
+   empty system prompt, are sent back to the model, up to `max_attempts` times. With `auto_repair` off,
+   the first invalid draft is an error.
+4. Every draft and the repaired config are saved as versions of one `Artifact`. The YAML goes to
+   `<output_dir>/<file_name>` and the history to `<file_name>.artifact.json`, so an auditor can see what
+   the model wrote and what was changed before it ran.
+
+The result is a `GeneratedSwarm`. It holds the agents in a `SequentialWorkflow`, and `run_with` runs them
+on the swarm's task.
+
+`connect_agents` builds the agents of a config bound to real providers, one per model, and puts them under
+the swarm's `chaos` section when it is enabled.
+
+### Rust Code
 ```rust
-// This conversion is viable with limitations and challenges due to the use of Python-specific libraries.
+use crate::swarms::agents::create_agents_from_yaml::{load_yaml_safely, AgentConfig, SwarmConfig, YAMLConfig};
+use crate::swarms::artifacts::main_artifact::Artifact;
+use crate::swarms::integrations::notifications::Notifier;
+use crate::swarms::integrations::webhooks::WebhookDispatcher;
+use crate::swarms::structs::agent::{Agent, AgentConfigError, LlmAgent};
+use crate::swarms::structs::sequential_workflow::{SequentialWorkflow, StageOutput};
+use crate::swarms::utils::chaos::{Chaos, ChaosProvider};
+use crate::swarms::utils::chat_provider::{provider_completion, ChatProvider};
+use log::{info, warn};
+use regex::Regex;
+use std::collections::HashSet;
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Arc;
 
-use std::env;
-use std::fs;
-use std::path::Path;
-use std::process;
+/// The swarm types `GeneratedSwarm` can run.
+pub const SUPPORTED_SWARM_TYPES: [&str; 1] = ["SequentialWorkflow"];
 
-use regex::Regex;
-use yaml::YamlLoader;
+/// The swarm name used when the model leaves it out.
+pub const DEFAULT_SWARM_NAME: &str = "Auto-Swarm";
 
 // Define the AutoGenPrompt constant
-const AUTO_GEN_PROMPT: &str = r#"
+pub const AUTO_GEN_PROMPT: &str = r#"
 You are a specialized agent responsible for creating YAML configuration files for multi-agent swarms. Your role is to generate well-structured YAML that defines both individual agents and swarm architectures based on user requirements.
 Output only the yaml nothing else. You will be penalized for making mistakes
 
@@ -122,8 +159,9 @@ swarm_architecture:
   
 "#;
 
-// Define the functions
-fn prepare_yaml_for_parsing(raw_yaml: &str) -> String {
+// Normalizes common YAML slips in model output: list items on the key's line, missing spaces after
+// colons, trailing whitespace, and non-breaking spaces
+pub fn prepare_yaml_for_parsing(raw_yaml: &str) -> String {
     let re1 = Regex::new(r"(\b\w+\b):\s*-\s*").unwrap();
     let re2 = Regex::new(r"(\S):(\S)").unwrap();
     let re3 = Regex::new(r"\s+\n").unwrap();
@@ -135,62 +173,472 @@ fn prepare_yaml_for_parsing(raw_yaml: &str) -> String {
     fixed_yaml.replace("\u{00a0}", " ").trim().to_string()
 }
 
-fn parse_yaml_from_swarm_markdown(markdown_text: &str) -> String {
-    let re = Regex::new(r"```yaml\s*\n(.*?)```").unwrap();
-    let caps = re.captures(markdown_text);
+// The YAML in a reply: the first ```yaml block, or the whole reply when it starts with `agents:` as
+// the prompt asks. None when the reply has neither
+pub fn parse_yaml_from_swarm_markdown(markdown_text: &str) -> Option<String> {
+    let re = Regex::new(r"(?s)```(?:yaml|yml)?[ \t]*\n(.*?)```").unwrap();
+    if let Some(caps) = re.captures(markdown_text) {
+        return Some(prepare_yaml_for_parsing(caps.get(1).unwrap().as_str().trim()));
+    }
+    let trimmed = markdown_text.trim();
+    trimmed.starts_with("agents:").then(|| prepare_yaml_for_parsing(trimmed))
+}
+
+/// A problem with a generated config.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SwarmConfigIssue {
+    NoYaml,
+    Unparseable(String),
+    NoAgents,
+    /// The agent at this index has no name.
+    MissingAgentName(usize),
+    DuplicateAgentName(String),
+    MissingSystemPrompt(String),
+    InvalidAgent { agent: String, error: AgentConfigError },
+    MissingSwarmName,
+    UnsupportedSwarmType(String),
+}
 
-    if let Some(caps) = caps {
-        let raw_yaml = caps.get(1).unwrap().as_str().trim();
-        prepare_yaml_for_parsing(raw_yaml)
-    } else {
-        panic!("No YAML content found in the 'Auto-Swarm-Builder' block.");
+impl fmt::Display for SwarmConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SwarmConfigIssue::NoYaml => write!(f, "the reply contains no YAML"),
+            SwarmConfigIssue::Unparseable(message) => write!(f, "the YAML does not parse: {}", message),
+            SwarmConfigIssue::NoAgents => write!(f, "the `agents` section is empty"),
+            SwarmConfigIssue::MissingAgentName(index) => write!(f, "agent {} has no agent_name", index + 1),
+            SwarmConfigIssue::DuplicateAgentName(name) => write!(f, "agent_name '{}' is used more than once", name),
+            SwarmConfigIssue::MissingSystemPrompt(name) => write!(f, "agent '{}' has no system_prompt", name),
+            SwarmConfigIssue::InvalidAgent { agent, error } => write!(f, "agent '{}' is invalid: {}", agent, error),
+            SwarmConfigIssue::MissingSwarmName => write!(f, "swarm_architecture has no name"),
+            SwarmConfigIssue::UnsupportedSwarmType(swarm_type) => write!(
+                f,
+                "swarm_type '{}' is not supported; use one of {}",
+                swarm_type,
+                SUPPORTED_SWARM_TYPES.join(", ")
+            ),
+        }
     }
 }
 
-fn generate_swarm_config(task: &str, file_name: &str, model_name: &str) -> std::io::Result<()> {
-    println!("Auto Generating Swarm...");
+#[derive(Debug)]
+pub enum SwarmConfigError {
+    Model(String),
+    Rejected { attempts: usize, issues: Vec<SwarmConfigIssue> },
+    Io(io::Error),
+}
 
-    let auto_gen_prompt = AUTO_GEN_PROMPT;
-    // Initialize the agent and model (this will require a Rust equivalent for the LiteLLM model)
-    // let model = LiteLLM::new(model_name);
-    // let agent = Agent::new("Auto-Swarm-Builder", auto_gen_prompt, model);
+impl fmt::Display for SwarmConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SwarmConfigError::Model(message) => write!(f, "swarm builder model call failed: {}", message),
+            SwarmConfigError::Rejected { attempts, issues } => {
+                let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+                write!(f, "no valid swarm config after {} attempt(s): {}", attempts, issues.join("; "))
+            }
+            SwarmConfigError::Io(e) => write!(f, "failed to save the swarm config: {}", e),
+        }
+    }
+}
 
-    // Generate output from the agent (this will require a Rust equivalent for the Agent's run method)
-    // let raw_output = agent.run(task);
+impl std::error::Error for SwarmConfigError {}
 
-    // For demonstration purposes, use a placeholder output
-    let raw_output = format!("```yaml\n{}\n```", "agents:\n  - agent_name: \"Data-Analysis-Agent\"\n    system_prompt: \"You are a specialized data analysis agent focused on processing and interpreting financial data. Provide clear, actionable insights based on the data provided.\"\n    max_loops: 3\n    autosave: true\n    verbose: true\n    context_length: 100000\n    output_type: \"json\"\n    task: \"Analyze quarterly financial reports and identify trends\"\n");
+impl From<io::Error> for SwarmConfigError {
+    fn from(e: io::Error) -> Self {
+        SwarmConfigError::Io(e)
+    }
+}
 
-    let yaml_content = parse_yaml_from_swarm_markdown(&raw_output);
-    println!("{}", yaml_content);
+/// A validated config, built into a runnable swarm.
+pub struct GeneratedSwarm {
+    /// The config after repairs; this is what the swarm was built from.
+    pub config: YAMLConfig,
+    pub yaml: String,
+    pub workflow: SequentialWorkflow,
+    /// The swarm's task, or the task the config was generated for when the YAML gives none.
+    pub task: String,
+    /// The repairs applied, in order.
+    pub repairs: Vec<String>,
+    pub attempts: usize,
+    pub path: PathBuf,
+    pub history_path: PathBuf,
+    /// The artifact version holding `yaml`.
+    pub version: i32,
+}
 
-    // Create agents from the YAML file (this will require a Rust equivalent for the create_agents_from_yaml function)
-    // let output = create_agents_from_yaml(yaml_content, "run_swarm");
+impl GeneratedSwarm {
+    /// Run the agents in order on `task`.
+    pub fn run_with(
+        &self,
+        step: impl FnMut(&dyn Agent, &str) -> Result<String, String>,
+    ) -> Result<Vec<StageOutput>, String> {
+        self.workflow.run_with(&self.task, step)
+    }
+}
 
-    Ok(())
+pub struct SwarmConfigGenerator {
+    output_dir: PathBuf,
+    file_name: String,
+    model_name: String,
+    auto_repair: bool,
+    max_attempts: usize,
 }
 
-fn main() -> std::io::Result<()> {
-    let task = "Analyze quarterly financial reports and identify trends";
-    let file_name = "swarm_config_output.yaml";
-    let model_name = "gpt-4o";
+impl SwarmConfigGenerator {
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        SwarmConfigGenerator {
+            output_dir: output_dir.into(),
+            file_name: "swarm_config_output.yaml".to_string(),
+            model_name: "gpt-4o".to_string(),
+            auto_repair: true,
+            max_attempts: 3,
+        }
+    }
+
+    pub fn file_name(mut self, file_name: impl Into<String>) -> Self {
+        self.file_name = file_name.into();
+        self
+    }
+
+    /// The model for agents whose config has no `model_name`.
+    pub fn model_name(mut self, model_name: impl Into<String>) -> Self {
+        self.model_name = model_name.into();
+        self
+    }
+
+    pub fn auto_repair(mut self, auto_repair: bool) -> Self {
+        self.auto_repair = auto_repair;
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Where the config and its history are written.
+    pub fn paths(&self) -> (PathBuf, PathBuf) {
+        (self.output_dir.join(&self.file_name), self.output_dir.join(format!("{}.artifact.json", self.file_name)))
+    }
+
+    /// The Auto-Swarm-Builder prompt for `task`, listing the problems with the previous draft.
+    pub fn prompt(&self, task: &str, issues: &[SwarmConfigIssue]) -> String {
+        let mut prompt = format!("{}\n\nTask: {}\n", AUTO_GEN_PROMPT.trim(), task);
+        if !issues.is_empty() {
+            prompt.push_str("\nYour previous YAML was rejected. Fix these problems and output the full YAML again:\n");
+            for issue in issues {
+                prompt.push_str(&format!("- {}\n", issue));
+            }
+        }
+        prompt
+    }
+
+    /// Load and check a reply, applying repairs when `auto_repair` is on.
+    pub fn review(&self, reply: &str) -> Result<(YAMLConfig, Vec<String>), Vec<SwarmConfigIssue>> {
+        let yaml = parse_yaml_from_swarm_markdown(reply).ok_or_else(|| vec![SwarmConfigIssue::NoYaml])?;
+        let mut config = load_yaml_safely("", Some(yaml))
+            .map_err(|e| vec![SwarmConfigIssue::Unparseable(format!("{:#}", e))])?;
+        let repairs = if self.auto_repair { repair(&mut config) } else { Vec::new() };
+        let issues = self.validate(&config);
+        if issues.is_empty() {
+            Ok((config, repairs))
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Every rule `config` breaks.
+    pub fn validate(&self, config: &YAMLConfig) -> Vec<SwarmConfigIssue> {
+        validate_swarm_config(config, &self.model_name)
+    }
+
+    /// Generate a config for `task` with `model`, validate it, save it, and build the swarm.
+    pub fn generate(
+        &self,
+        task: &str,
+        mut model: impl FnMut(&str) -> Result<String, String>,
+    ) -> Result<GeneratedSwarm, SwarmConfigError> {
+        info!("Auto Generating Swarm...");
+        let attempts = if self.auto_repair { self.max_attempts } else { 1 };
+        let mut artifact = self.open_artifact()?;
+        let mut issues = Vec::new();
+        for attempt in 1..=attempts {
+            let reply = model(&self.prompt(task, &issues)).map_err(SwarmConfigError::Model)?;
+            let draft = parse_yaml_from_swarm_markdown(&reply);
+            self.record(&mut artifact, draft.clone().unwrap_or_else(|| reply.clone()));
+            match self.review(&reply) {
+                Ok((config, repairs)) => {
+                    let yaml = match draft {
+                        Some(draft) if repairs.is_empty() => draft,
+                        _ => {
+                            info!("Applied {} repair(s) to the generated swarm config", repairs.len());
+                            let yaml = serde_yaml::to_string(&config)
+                                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                            self.record(&mut artifact, yaml.clone());
+                            yaml
+                        }
+                    };
+                    let version = self.save_artifact(&mut artifact, true)?;
+                    return Ok(self.assemble(task, config, yaml, repairs, attempt, version));
+                }
+                Err(found) => {
+                    warn!("Swarm config draft {} rejected: {} problem(s)", attempt, found.len());
+                    issues = found;
+                }
+            }
+        }
+        self.save_artifact(&mut artifact, false)?;
+        Err(SwarmConfigError::Rejected { attempts, issues })
+    }
+
+    fn open_artifact(&self) -> Result<Option<Artifact>, SwarmConfigError> {
+        let (_, history) = self.paths();
+        if history.exists() {
+            Ok(Some(Artifact::import_from_json(&history.to_string_lossy())?))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Add `content` as the artifact's next version, starting the artifact on the first draft
+    fn record(&self, artifact: &mut Option<Artifact>, content: String) {
+        match artifact {
+            Some(artifact) => {
+                let _ = artifact.edit(content);
+            }
+            None => {
+                let mut created = Artifact::new(self.paths().0.to_string_lossy().into_owned(), ".yaml".to_string());
+                let _ = created.create(content);
+                *artifact = Some(created);
+            }
+        }
+    }
+
+    // Write the history, and the config file when the latest version is an accepted config. Returns the version
+    fn save_artifact(&self, artifact: &mut Option<Artifact>, accepted: bool) -> Result<i32, SwarmConfigError> {
+        let Some(artifact) = artifact else {
+            return Ok(0);
+        };
+        std::fs::create_dir_all(&self.output_dir)?;
+        if accepted {
+            artifact.save()?;
+        }
+        artifact.export_to_json(&self.paths().1.to_string_lossy())?;
+        Ok(artifact.current_version())
+    }
 
-    generate_swarm_config(task, file_name, model_name)
+    fn assemble(
+        &self,
+        task: &str,
+        config: YAMLConfig,
+        yaml: String,
+        repairs: Vec<String>,
+        attempts: usize,
+        version: i32,
+    ) -> GeneratedSwarm {
+        let workflow = build_workflow(&config, &self.model_name).expect("validated agents build");
+        let swarm = config.swarm_architecture.clone().unwrap_or_default();
+        let task = swarm.task.clone().filter(|task| !task.trim().is_empty()).unwrap_or_else(|| task.to_string());
+        let (path, history_path) = self.paths();
+        GeneratedSwarm { config, yaml, workflow, task, repairs, attempts, path, history_path, version }
+    }
 }
-```
 
-**Limitations and Challenges:**
+/// Every rule `config` breaks. Agents without a `model_name` are checked with `default_model`.
+pub fn validate_swarm_config(config: &YAMLConfig, default_model: &str) -> Vec<SwarmConfigIssue> {
+    let mut issues = Vec::new();
+    if config.agents.is_empty() {
+        issues.push(SwarmConfigIssue::NoAgents);
+    }
+    let mut seen = HashSet::new();
+    for (index, agent) in config.agents.iter().enumerate() {
+        let name = agent.agent_name.trim();
+        if name.is_empty() {
+            issues.push(SwarmConfigIssue::MissingAgentName(index));
+            continue;
+        }
+        if !seen.insert(name) {
+            issues.push(SwarmConfigIssue::DuplicateAgentName(name.to_string()));
+        }
+        if agent.system_prompt.trim().is_empty() {
+            issues.push(SwarmConfigIssue::MissingSystemPrompt(name.to_string()));
+        }
+        if let Err(error) = build_agent(agent, default_model) {
+            issues.push(SwarmConfigIssue::InvalidAgent { agent: name.to_string(), error });
+        }
+    }
+    if let Some(swarm) = &config.swarm_architecture {
+        if swarm.name.trim().is_empty() {
+            issues.push(SwarmConfigIssue::MissingSwarmName);
+        }
+        if !SUPPORTED_SWARM_TYPES.contains(&swarm.swarm_type.as_str()) {
+            issues.push(SwarmConfigIssue::UnsupportedSwarmType(swarm.swarm_type.clone()));
+        }
+    }
+    issues
+}
 
-1. **Library Equivalents:** Rust's ecosystem lacks direct equivalents for some Python libraries used in the provided code. This includes `tenacity` for retrying, `dotenv` for environment variables, `swarms` for multi-agent swarms, and `litellm` for language models. You would need to find Rust alternatives or implement these functionalities manually.
+/// The `SequentialWorkflow` for a valid `config`, named `DEFAULT_SWARM_NAME` when it has no
+/// `swarm_architecture`.
+pub fn build_workflow(config: &YAMLConfig, default_model: &str) -> Result<SequentialWorkflow, AgentConfigError> {
+    let agents = config
+        .agents
+        .iter()
+        .map(|agent| build_agent(agent, default_model))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(workflow_with_agents(config, agents))
+}
 
-2. **Natural Language Processing and Machine Learning:** While Rust has made significant progress in these areas, its ecosystem is still developing compared to Python's. You might need to use Rust's FFI (Foreign Function Interface) to interface with C or C++ libraries or use less mature Rust libraries for NLP and ML tasks.
+/// The agents of a valid `config`, each bound to the provider `connect` returns for its model. With
+/// `chaos` (see `Chaos::resolve` over the swarm's `chaos` section), every provider is wrapped in a
+/// `ChaosProvider` first; wrap the swarm's tools with the same `Chaos`.
+pub fn connect_agents(
+    config: &YAMLConfig,
+    default_model: &str,
+    chaos: Option<&Arc<Chaos>>,
+    connect: impl Fn(&str) -> Arc<dyn ChatProvider>,
+) -> Result<Vec<LlmAgent>, AgentConfigError> {
+    config
+        .agents
+        .iter()
+        .map(|agent| {
+            let built = build_agent(agent, default_model)?;
+            let mut provider = connect(&built.llm);
+            if let Some(chaos) = chaos {
+                provider = Arc::new(ChaosProvider::new(provider, Arc::clone(chaos)));
+            }
+            Ok(built.with_completion(provider_completion(provider)))
+        })
+        .collect()
+}
 
-3. **Concurrency and Async/Await:** Rust's concurrency model and async/await syntax are powerful but differ significantly from Python's. You would need to adapt the code to use Rust's concurrency features, such as `tokio` or `async-std`, for asynchronous operations.
+/// The workflow `config` describes, run by `agents` that were already built from it. The sinks in the
+/// swarm's `notifications` list and its `webhooks` are attached; if one cannot be built, the workflow runs
+/// without them.
+pub fn workflow_with_agents(config: &YAMLConfig, agents: Vec<LlmAgent>) -> SequentialWorkflow {
+    let swarm = config.swarm_architecture.clone().unwrap_or_else(|| SwarmConfig {
+        name: DEFAULT_SWARM_NAME.to_string(),
+        ..SwarmConfig::default()
+    });
+    let workflow = SequentialWorkflow::new(
+        swarm.name.clone(),
+        swarm.description.clone(),
+        agents.into_iter().map(Into::into).collect(),
+        swarm.max_loops.max(1),
+        "all".to_string(),
+        swarm.return_json,
+        || (),
+    );
+    let workflow = match Notifier::from_configs(&swarm.notifications) {
+        Ok(notifier) => workflow.with_notifier(notifier),
+        Err(err) => {
+            warn!("{}: running without notifications: {}", swarm.name, err);
+            workflow
+        }
+    };
+    if swarm.webhooks.is_empty() {
+        return workflow;
+    }
+    let webhooks = WebhookDispatcher::new();
+    match webhooks.register_configs(&swarm.name, &swarm.webhooks) {
+        Ok(()) => workflow.with_webhooks(webhooks),
+        Err(err) => {
+            warn!("{}: running without webhooks: {}", swarm.name, err);
+            workflow
+        }
+    }
+}
 
-4. **Error Handling:** Rust's error handling is based on `Result` and `?`, which requires a different approach than Python's try-except blocks. You'll need to translate the error handling mechanisms to Rust's idiomatic way.
+// The fixes that need no model call. Returns a note per fix
+fn repair(config: &mut YAMLConfig) -> Vec<String> {
+    let mut repairs = Vec::new();
+    let mut seen = HashSet::new();
+    for (index, agent) in config.agents.iter_mut().enumerate() {
+        if agent.agent_name.trim().is_empty() {
+            agent.agent_name = format!("Agent-{}", index + 1);
+            repairs.push(format!("named agent {} '{}'", index + 1, agent.agent_name));
+        }
+        let base = agent.agent_name.trim().to_string();
+        let mut name = base.clone();
+        let mut suffix = 2;
+        while seen.contains(&name) {
+            name = format!("{}-{}", base, suffix);
+            suffix += 1;
+        }
+        if name != agent.agent_name {
+            repairs.push(format!("renamed duplicate agent '{}' to '{}'", base, name));
+            agent.agent_name = name.clone();
+        }
+        seen.insert(name);
+        if agent.max_loops < 1 {
+            repairs.push(format!("set max_loops of '{}' from {} to 1", agent.agent_name, agent.max_loops));
+            agent.max_loops = 1;
+        }
+        if agent.retry_attempts < 0 {
+            repairs.push(format!("set retry_attempts of '{}' from {} to 0", agent.agent_name, agent.retry_attempts));
+            agent.retry_attempts = 0;
+        }
+        if let Some(length) = agent.context_length.filter(|length| *length <= 0) {
+            repairs.push(format!("dropped context_length {} of '{}'", length, agent.agent_name));
+            agent.context_length = None;
+        }
+    }
+    if let Some(swarm) = config.swarm_architecture.as_mut() {
+        if swarm.name.trim().is_empty() {
+            swarm.name = DEFAULT_SWARM_NAME.to_string();
+            repairs.push(format!("named the swarm '{}'", DEFAULT_SWARM_NAME));
+        }
+        if !SUPPORTED_SWARM_TYPES.contains(&swarm.swarm_type.as_str()) {
+            repairs.push(format!("ran swarm_type '{}' as SequentialWorkflow", swarm.swarm_type));
+            swarm.swarm_type = "SequentialWorkflow".to_string();
+        }
+        if swarm.max_loops < 1 {
+            repairs.push(format!("set the swarm's max_loops from {} to 1", swarm.max_loops));
+            swarm.max_loops = 1;
+        }
+    }
+    repairs
+}
 
-5. **YAML Parsing and Generation:** Rust has libraries like `yaml-rust` or `serde_yaml` for parsing and generating YAML. You would need to use one of these libraries to handle YAML data in Rust.
+/// The `LlmAgent` an `AgentConfig` describes, using `default_model` when it names none.
+pub fn build_agent(config: &AgentConfig, default_model: &str) -> Result<LlmAgent, AgentConfigError> {
+    let mut builder = LlmAgent::builder()
+        .name(config.agent_name.trim())
+        .llm(config.model_name.as_deref().unwrap_or(default_model))
+        .system_prompt(config.system_prompt.as_str())
+        .max_loops(config.max_loops)
+        .autosave(config.autosave)
+        .verbose(config.verbose)
+        .dynamic_temperature_enabled(config.dynamic_temperature_enabled)
+        .user_name(config.user_name.as_str())
+        .retry_attempts(config.retry_attempts)
+        .budget(config.budget())
+        .output_type(config.output_type.as_str())
+        .auto_generate_prompt(config.auto_generate_prompt);
+    if let Some(path) = &config.saved_state_path {
+        builder = builder.saved_state_path(path.as_str());
+    }
+    builder.build()
+}
 
-**Conclusion:**
+fn main() -> Result<(), SwarmConfigError> {
+    let task = "Analyze quarterly financial reports and identify trends";
+    let generator = SwarmConfigGenerator::new("swarm_configs");
+    let swarm = generator.generate(task, |_prompt| {
+        Ok("```yaml\nagents:\n  - agent_name: \"Data-Analysis-Agent\"\n    system_prompt: \"You are a specialized data analysis agent focused on processing and interpreting financial data.\"\n    max_loops: 0\n    output_type: \"json\"\nswarm_architecture:\n  name: \"Finance-Swarm\"\n  swarm_type: \"ConcurrentWorkflow\"\n```".to_string())
+    })?;
+    for repair in &swarm.repairs {
+        println!("repaired: {}", repair);
+    }
+    println!("{} (version {}):\n{}", swarm.path.display(), swarm.version, swarm.yaml);
+    let stages = swarm
+        .run_with(|agent, input| Ok(format!("{} read {} bytes", agent.name(), input.len())))
+        .map_err(SwarmConfigError::Model)?;
+    println!("{:?}", stages);
+    Ok(())
+}
+```
 
-While the conversion of the provided Python code to Rust is viable, it comes with several challenges and limitations. You'll need to find Rust equivalents for Python libraries, adapt to Rust's unique features like ownership and borrowing, and translate the code to fit Rust's concurrency, error handling, and NLP/ML ecosystems. This process requires a good understanding of both Python and Rust, as well as the willingness to learn and adapt to Rust's idiomatic ways of solving problems.
\ No newline at end of file
+### Notes
+* Only `SequentialWorkflow` can be built in this crate today. With `auto_repair`, other swarm types run sequentially, and the repair list says so. Without it, they are rejected.
+* A reply that cannot be loaded is still recorded in the artifact history, so a rejected run can be audited too. The config file itself is written only for an accepted config.
+* `prepare_yaml_for_parsing` keeps the Python version's rewrites. It inserts a space after every `:` that lacks one, which turns `https://` into `https: //`. It also reads `key: -1` as the start of a list, so negative numbers fail to load instead of being repaired.
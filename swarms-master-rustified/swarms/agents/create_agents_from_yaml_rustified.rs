@@ -9,60 +9,248 @@
 Here is the Rust version of the provided Python code. Note that this example assumes the existence of certain libraries and data structures (like `Agent`, `SwarmRouter`, and `LiteLLM`) which would need to be defined in Rust.
 
 ```rust
+use crate::swarms::agents::auto_generate_swarm_config::{validate_swarm_config, SwarmConfigIssue};
+use crate::swarms::integrations::notifications::NotificationConfig;
+use crate::swarms::integrations::webhooks::WebhookConfig;
+use crate::swarms::structs::agent::AgentSpec;
+use crate::swarms::text::context_budget::ContextBudget;
+use crate::swarms::utils::chaos::ChaosConfig;
 use anyhow::{Context, Result};
 use log::{info, error};
 use serde::{Deserialize, Serialize};
 use serde_yaml::{from_str, from_reader};
 use std::collections::HashMap;
+use std::fmt;
 use std::fs::File;
 use std::time::Duration;
 
+// Window size assumed when a config sets neither `context_length` nor `context_budget` (Python default)
+const DEFAULT_CONTEXT_LENGTH: i32 = 100_000;
+
+// The swarm types the Python `SwarmConfig` validator accepts
+pub const PYTHON_SWARM_TYPES: [&str; 5] = ["SequentialWorkflow", "ConcurrentWorkflow", "AgentRearrange", "MixtureOfAgents", "auto"];
+
 // Define AgentConfig struct with Serialize and Deserialize traits
-#[derive(Serialize, Deserialize)]
-struct AgentConfig {
-    agent_name: String,
-    system_prompt: String,
-    model_name: Option<String>,
-    max_loops: i32,
-    autosave: bool,
-    dashboard: bool,
-    verbose: bool,
-    dynamic_temperature_enabled: bool,
-    saved_state_path: Option<String>,
-    user_name: String,
-    retry_attempts: i32,
-    context_length: i32,
-    return_step_meta: bool,
-    output_type: String,
-    auto_generate_prompt: bool,
-    artifacts_on: bool,
-    artifacts_file_extension: String,
-    artifacts_output_path: String,
+// Missing keys take the Python AgentConfig defaults, so partial (e.g. generated) configs load
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AgentConfig {
+    pub agent_name: String,
+    pub system_prompt: String,
+    pub model_name: Option<String>,
+    pub max_loops: i32,
+    pub autosave: bool,
+    pub dashboard: bool,
+    pub verbose: bool,
+    pub dynamic_temperature_enabled: bool,
+    pub saved_state_path: Option<String>,
+    pub user_name: String,
+    pub retry_attempts: i32,
+    // Legacy window size, still accepted; `context_budget` wins when both are set.
+    // Left out when unset: Python reads it as an int and rejects `null`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_length: Option<i32>,
+    // Rust only; Python ignores it and reads `context_length`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub context_budget: Option<ContextBudget>,
+    pub return_step_meta: bool,
+    pub output_type: String,
+    pub auto_generate_prompt: bool,
+    pub artifacts_on: bool,
+    pub artifacts_file_extension: String,
+    pub artifacts_output_path: String,
 }
 
 // Define SwarmConfig struct with Serialize and Deserialize traits
-#[derive(Serialize, Deserialize)]
-struct SwarmConfig {
-    name: String,
-    description: String,
-    max_loops: i32,
-    swarm_type: String,
-    task: Option<String>,
-    flow: Option<HashMap<String, String>>,
-    autosave: bool,
-    return_json: bool,
-    rules: String,
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SwarmConfig {
+    pub name: String,
+    pub description: String,
+    pub max_loops: i32,
+    pub swarm_type: String,
+    pub task: Option<String>,
+    pub flow: Option<HashMap<String, String>>,
+    pub autosave: bool,
+    pub return_json: bool,
+    pub rules: String,
+    // Sinks told about run completion, failure, or budget overruns, attached by `workflow_with_agents`.
+    // Rust only, like `webhooks`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notifications: Vec<NotificationConfig>,
+    // Callback URLs that receive signed run lifecycle events, registered by `workflow_with_agents`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub webhooks: Vec<WebhookConfig>,
+    // Fault injection for resilience tests, applied by `connect_agents` and `Chaos::wrap_tools` through
+    // `Chaos::resolve`; `SWARMS_CHAOS` overrides it
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chaos: Option<ChaosConfig>,
 }
 
 // Define YAMLConfig struct with Serialize and Deserialize traits
-#[derive(Serialize, Deserialize)]
-struct YAMLConfig {
-    agents: Vec<AgentConfig>,
-    swarm_architecture: Option<SwarmConfig>,
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct YAMLConfig {
+    pub agents: Vec<AgentConfig>,
+    pub swarm_architecture: Option<SwarmConfig>,
+}
+
+impl Default for AgentConfig {
+    fn default() -> Self {
+        AgentConfig {
+            agent_name: String::new(),
+            system_prompt: String::new(),
+            model_name: None,
+            max_loops: 1,
+            autosave: true,
+            dashboard: false,
+            verbose: false,
+            dynamic_temperature_enabled: false,
+            saved_state_path: None,
+            user_name: "default_user".to_string(),
+            retry_attempts: 3,
+            context_length: None,
+            context_budget: None,
+            return_step_meta: false,
+            output_type: "str".to_string(),
+            auto_generate_prompt: false,
+            artifacts_on: false,
+            artifacts_file_extension: ".md".to_string(),
+            artifacts_output_path: String::new(),
+        }
+    }
+}
+
+impl Default for SwarmConfig {
+    fn default() -> Self {
+        SwarmConfig {
+            name: String::new(),
+            description: String::new(),
+            max_loops: 1,
+            // Python requires swarm_type; SwarmRouter's own default is used when it is missing
+            swarm_type: "SequentialWorkflow".to_string(),
+            task: None,
+            flow: None,
+            autosave: true,
+            return_json: false,
+            rules: String::new(),
+            notifications: Vec::new(),
+            webhooks: Vec::new(),
+            chaos: None,
+        }
+    }
+}
+
+impl AgentConfig {
+    // The agent's context plan, falling back to the legacy `context_length` and then the Python default window
+    pub fn budget(&self) -> ContextBudget {
+        self.context_budget
+            .unwrap_or_else(|| ContextBudget::from_context_length(self.context_length.unwrap_or(DEFAULT_CONTEXT_LENGTH)))
+    }
+
+    // The config that builds an agent like `spec`. Both `context_length` and `context_budget` are set, so
+    // Python gets the window size and this crate gets the whole budget back.
+    // The description, capabilities, and reasoning configs have no place in the file and are left out
+    pub fn from_spec(spec: &AgentSpec) -> Self {
+        AgentConfig {
+            agent_name: spec.name.clone(),
+            system_prompt: spec.system_prompt.clone(),
+            model_name: Some(spec.llm.clone()),
+            max_loops: spec.max_loops,
+            autosave: spec.autosave,
+            verbose: spec.verbose,
+            dynamic_temperature_enabled: spec.dynamic_temperature_enabled,
+            saved_state_path: Some(spec.saved_state_path.clone()),
+            user_name: spec.user_name.clone(),
+            retry_attempts: spec.retry_attempts,
+            context_length: Some(spec.budget.max_context_tokens.min(i32::MAX as usize) as i32),
+            context_budget: Some(spec.budget),
+            output_type: spec.output_type.clone(),
+            auto_generate_prompt: spec.auto_generate_prompt,
+            ..AgentConfig::default()
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum YamlExportError {
+    // The swarm has no config form; see `Swarm::to_config`
+    Unsupported(String),
+    // Rules the config breaks, in this crate's loader or Python's `create_agents_from_yaml`
+    Incompatible(Vec<String>),
+    Yaml(serde_yaml::Error),
+}
+
+impl fmt::Display for YamlExportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            YamlExportError::Unsupported(swarm) => write!(f, "swarm '{}' cannot be written as a YAML config", swarm),
+            YamlExportError::Incompatible(issues) => write!(f, "config would not load: {}", issues.join("; ")),
+            YamlExportError::Yaml(e) => write!(f, "failed to write YAML: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for YamlExportError {}
+
+impl From<serde_yaml::Error> for YamlExportError {
+    fn from(e: serde_yaml::Error) -> Self {
+        YamlExportError::Yaml(e)
+    }
+}
+
+impl YAMLConfig {
+    // What the Python pydantic models would reject: the `Field` bounds, the system prompt and swarm type
+    // validators, and the requirement of at least one agent
+    pub fn python_issues(&self) -> Vec<String> {
+        let mut issues = Vec::new();
+        if self.agents.is_empty() {
+            issues.push("Python requires at least one agent".to_string());
+        }
+        for agent in &self.agents {
+            let name = &agent.agent_name;
+            if agent.system_prompt.trim().is_empty() {
+                issues.push(format!("agent '{}' needs a non-empty system_prompt for Python", name));
+            }
+            if agent.retry_attempts < 1 {
+                issues.push(format!("agent '{}' has retry_attempts {}; Python requires at least 1", name, agent.retry_attempts));
+            }
+            if let Some(length) = agent.context_length.filter(|length| *length < 1000) {
+                issues.push(format!("agent '{}' has context_length {}; Python requires at least 1000", name, length));
+            }
+            if agent.max_loops < 1 {
+                issues.push(format!("agent '{}' has max_loops {}; Python requires at least 1", name, agent.max_loops));
+            }
+        }
+        if let Some(swarm) = &self.swarm_architecture {
+            if !PYTHON_SWARM_TYPES.contains(&swarm.swarm_type.as_str()) {
+                issues.push(format!("swarm_type '{}' is not one Python accepts", swarm.swarm_type));
+            }
+            if swarm.max_loops < 1 {
+                issues.push(format!("the swarm has max_loops {}; Python requires at least 1", swarm.max_loops));
+            }
+        }
+        issues
+    }
+
+    // The config as YAML that both `load_yaml_safely` and Python's `create_agents_from_yaml` accept.
+    // Fails with every rule either loader would reject, rather than write a file one side cannot read
+    pub fn to_yaml(&self) -> std::result::Result<String, YamlExportError> {
+        let mut issues = self.python_issues();
+        // An empty agent list and a blank system prompt are Python rules too, and already reported
+        let rust_issues = validate_swarm_config(self, "gpt-4o").into_iter().filter(|issue| {
+            !matches!(issue, SwarmConfigIssue::NoAgents | SwarmConfigIssue::MissingSystemPrompt(_))
+        });
+        issues.extend(rust_issues.map(|issue| issue.to_string()));
+        if !issues.is_empty() {
+            return Err(YamlExportError::Incompatible(issues));
+        }
+        Ok(serde_yaml::to_string(self)?)
+    }
 }
 
 // Define a function for loading and validating YAML configuration
-fn load_yaml_safely(yaml_file: &str, yaml_string: Option<String>) -> Result<YAMLConfig> {
+pub fn load_yaml_safely(yaml_file: &str, yaml_string: Option<String>) -> Result<YAMLConfig> {
     if let Some(yaml_string) = yaml_string {
         let config: YAMLConfig = from_str(&yaml_string).context("Failed to parse YAML string")?;
         Ok(config)
@@ -90,7 +278,7 @@ fn create_agent_with_retry(agent_config: &AgentConfig, model: &LiteLLM) -> Resul
         agent_config.saved_state_path.as_ref(),
         &agent_config.user_name,
         agent_config.retry_attempts,
-        agent_config.context_length,
+        agent_config.budget(),
         agent_config.return_step_meta,
         &agent_config.output_type,
         agent_config.auto_generate_prompt,
@@ -3,256 +3,311 @@
 // The provided Python code is well-structured and utilizes a limited number of libraries,
 // making it suitable for conversion to Rust. However, there are a few challenges and limitations:
 // 1. The `openai` library used in the Python code does not have a direct Rust equivalent.
-//    We will use the `reqwest` library to make HTTP requests to the OpenAI API.
+//    The Assistants API is called through the shared `RestClient` instead.
 // 2. The `json` library used for JSON serialization and deserialization in the Python code
 //    has a Rust equivalent in the `serde_json` library.
 // 3. Rust's error handling system is more explicit than Python's, so we will need to handle
 //    errors using `Result` and `Error` types.
 
-use reqwest::{Client, StatusCode};
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, HttpTransport, RestClient, RestError, NO_PARAMS};
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
 use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
 
-// Define a struct to represent the OpenAI Assistant
-struct OpenAIAssistant {
-    client: Client,
+pub const OPENAI_API_BASE: &str = "https://api.openai.com/v1";
+
+const CREATE_ASSISTANT: Endpoint = Endpoint::post("/assistants");
+const UPDATE_ASSISTANT: Endpoint = Endpoint::post("/assistants/{assistant_id}");
+const CREATE_THREAD: Endpoint = Endpoint::post("/threads");
+const CREATE_MESSAGE: Endpoint = Endpoint::post("/threads/{thread_id}/messages");
+const LIST_MESSAGES: Endpoint = Endpoint::get("/threads/{thread_id}/messages");
+const CREATE_RUN: Endpoint = Endpoint::post("/threads/{thread_id}/runs");
+const GET_RUN: Endpoint = Endpoint::get("/threads/{thread_id}/runs/{run_id}");
+const SUBMIT_TOOL_OUTPUTS: Endpoint = Endpoint::post("/threads/{thread_id}/runs/{run_id}/submit_tool_outputs");
+
+/// A function the assistant may call, given its parsed arguments.
+pub type AssistantFunction = Arc<dyn Fn(&Value) -> String + Send + Sync>;
+
+#[derive(Debug)]
+pub enum AssistantError {
+    MissingApiKey,
+    Rest(RestError),
+    /// The run ended in `failed`, `expired`, or `cancelled`.
+    RunFailed(String),
+}
+
+impl fmt::Display for AssistantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AssistantError::MissingApiKey => write!(f, "OPENAI_API_KEY is not set"),
+            AssistantError::Rest(err) => write!(f, "assistants API: {}", err),
+            AssistantError::RunFailed(status) => write!(f, "run failed with status: {}", status),
+        }
+    }
+}
+
+impl std::error::Error for AssistantError {}
+
+impl From<RestError> for AssistantError {
+    fn from(err: RestError) -> Self {
+        AssistantError::Rest(err)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Created {
+    id: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Run {
+    id: String,
+    status: String,
+    #[serde(default)]
+    required_action: Option<RequiredAction>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RequiredAction {
+    submit_tool_outputs: ToolCalls,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCalls {
+    tool_calls: Vec<ToolCall>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ToolCall {
+    id: String,
+    #[serde(rename = "type")]
+    kind: String,
+    function: FunctionCall,
+}
+
+#[derive(Debug, Deserialize)]
+struct FunctionCall {
+    name: String,
+    arguments: String,
+}
+
+#[derive(Debug, Serialize)]
+struct ToolOutput {
+    tool_call_id: String,
+    output: String,
+}
+
+#[derive(Debug, Serialize)]
+struct MessageQuery {
+    order: &'static str,
+    limit: u32,
+}
+
+/// The options `OpenAIAssistant::create` sends to the Assistants API.
+#[derive(Debug, Clone, Default)]
+pub struct AssistantConfig {
+    pub name: String,
+    pub instructions: Option<String>,
+    pub model: String,
+    pub tools: Vec<Value>,
+    /// Files for the code interpreter. Assistants v2 takes them as `tool_resources`, not `file_ids`.
+    pub file_ids: Vec<String>,
+    pub metadata: HashMap<String, Value>,
+    /// Function schemas, each added to `tools` as a `function` tool.
+    pub functions: Vec<Value>,
+}
+
+impl AssistantConfig {
+    pub fn new(name: impl Into<String>) -> Self {
+        AssistantConfig { name: name.into(), model: "gpt-4o".to_string(), ..Default::default() }
+    }
+}
+
+/// An assistant created on OpenAI, with the thread it talks on.
+pub struct OpenAIAssistant {
+    rest: RestClient,
     assistant_id: String,
+    instructions: Option<String>,
     thread_id: Option<String>,
-    tools: Vec<HashMap<String, Value>>,
-    available_functions: HashMap<String, fn(HashMap<String, Value>) -> String>,
+    tools: Vec<Value>,
+    available_functions: HashMap<String, AssistantFunction>,
+    poll_interval: Duration,
 }
 
 impl OpenAIAssistant {
-    // Initialize a new OpenAI Assistant
-    fn new(
-        name: &str,
-        instructions: Option<&str>,
-        model: &str,
-        tools: Option<Vec<HashMap<String, Value>>>,
-        file_ids: Option<Vec<String>>,
-        metadata: Option<HashMap<String, Value>>,
-        functions: Option<Vec<HashMap<String, Value>>>,
-    ) -> Self {
-        let client = Client::new();
-        let assistant = Self::create_assistant(
-            &client,
-            name,
-            instructions,
-            model,
-            tools,
-            file_ids,
-            metadata,
-            functions,
-        )
-        .unwrap();
-        OpenAIAssistant {
-            client,
-            assistant_id: assistant.id.to_string(),
-            thread_id: None,
-            tools: tools.unwrap_or(vec![]),
-            available_functions: HashMap::new(),
+    /// The Assistants API client, keyed by `OPENAI_API_KEY`.
+    pub fn client_from_env() -> Result<RestClient, AssistantError> {
+        let api_key = env::var("OPENAI_API_KEY").map_err(|_| AssistantError::MissingApiKey)?;
+        Ok(Self::client(api_key, None))
+    }
+
+    /// The Assistants API client for `api_key`, sending through `transport` when given.
+    pub fn client(api_key: impl Into<String>, transport: Option<Arc<dyn HttpTransport>>) -> RestClient {
+        let rest = RestClient::new(OPENAI_API_BASE, AuthStrategy::Bearer(api_key.into()))
+            .with_header("OpenAI-Beta", "assistants=v2");
+        match transport {
+            Some(transport) => rest.with_transport(transport),
+            None => rest,
         }
     }
 
-    // Create a new OpenAI Assistant using the provided credentials and parameters
-    fn create_assistant(
-        client: &Client,
-        name: &str,
-        instructions: Option<&str>,
-        model: &str,
-        tools: Option<Vec<HashMap<String, Value>>>,
-        file_ids: Option<Vec<String>>,
-        metadata: Option<HashMap<String, Value>>,
-        functions: Option<Vec<HashMap<String, Value>>>,
-    ) -> Result<serde_json::Value, reqwest::Error> {
-        let url = "https://api.openai.com/v1/assistants";
-        let params = json!({
-            "name": name,
-            "instructions": instructions,
-            "model": model,
+    /// Create the assistant on OpenAI.
+    pub async fn create(rest: RestClient, config: AssistantConfig) -> Result<Self, AssistantError> {
+        let mut tools = config.tools;
+        tools.extend(config.functions.into_iter().map(|function| json!({ "type": "function", "function": function })));
+        let mut body = json!({
+            "name": config.name,
+            "instructions": config.instructions,
+            "model": config.model,
             "tools": tools,
-            "file_ids": file_ids,
-            "metadata": metadata,
-            "functions": functions,
+            "metadata": config.metadata,
         });
-        let response = client
-            .post(url)
-            .header("Authorization", "Bearer YOUR_API_KEY")
-            .json(&params)
-            .send()?;
-        let status = response.status();
-        if status.is_success() {
-            Ok(response.json().unwrap())
-        } else {
-            Err(response.error().unwrap())
+        if !config.file_ids.is_empty() {
+            body["tool_resources"] = json!({ "code_interpreter": { "file_ids": config.file_ids } });
         }
+        let assistant: Created = rest.call(CREATE_ASSISTANT, &[], &(), Some(&body)).await?;
+        Ok(OpenAIAssistant {
+            rest,
+            assistant_id: assistant.id,
+            instructions: config.instructions,
+            thread_id: None,
+            tools,
+            available_functions: HashMap::new(),
+            poll_interval: Duration::from_secs(3),
+        })
+    }
+
+    /// How long to wait between run status checks.
+    pub fn poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    pub fn assistant_id(&self) -> &str {
+        &self.assistant_id
     }
 
-    // Add a function to the OpenAI Assistant
-    fn add_function(
+    /// Add a function that the assistant can call, and update the assistant's tools.
+    pub async fn add_function(
         &mut self,
-        func_name: &str,
+        name: &str,
         description: &str,
-        parameters: HashMap<String, Value>,
-    ) -> Result<(), reqwest::Error> {
-        let func = |params: HashMap<String, Value>| -> String {
-            // Call the provided function with the given parameters
-            // Note: This is a placeholder for the actual function implementation
-            format!("Executing function {} with parameters {:?}", func_name, params)
-        };
-        self.available_functions.insert(func_name.to_string(), func);
-        let tool = json!({
+        parameters: Value,
+        func: impl Fn(&Value) -> String + Send + Sync + 'static,
+    ) -> Result<(), AssistantError> {
+        self.tools.push(json!({
             "type": "function",
-            "function": {
-                "name": func_name,
-                "description": description,
-                "parameters": parameters,
-            }
-        });
-        self.tools.push(tool);
-        let url = format!("https://api.openai.com/v1/assistants/{}", self.assistant_id);
-        let params = json!({
-            "tools": self.tools,
-        });
-        let response = self
-            .client
-            .patch(url)
-            .header("Authorization", "Bearer YOUR_API_KEY")
-            .json(&params)
-            .send()?;
-        let status = response.status();
-        if status.is_success() {
-            Ok(())
-        } else {
-            Err(response.error().unwrap())
+            "function": { "name": name, "description": description, "parameters": parameters },
+        }));
+        self.available_functions.insert(name.to_string(), Arc::new(func));
+        let body = json!({ "tools": self.tools });
+        let _: Value = self
+            .rest
+            .call(UPDATE_ASSISTANT, &[("assistant_id", self.assistant_id.as_str())], &(), Some(&body))
+            .await?;
+        Ok(())
+    }
+
+    // Create a thread for the conversation if there is none yet
+    async fn ensure_thread(&mut self) -> Result<String, AssistantError> {
+        if let Some(thread_id) = &self.thread_id {
+            return Ok(thread_id.clone());
         }
+        let thread: Created = self.rest.call(CREATE_THREAD, &[], &(), Some(&json!({}))).await?;
+        self.thread_id = Some(thread.id.clone());
+        Ok(thread.id)
     }
 
-    // Run a task using the OpenAI Assistant
-    fn run(&mut self, task: &str) -> Result<String, reqwest::Error> {
-        self.ensure_thread();
-        let url = format!("https://api.openai.com/v1/threads/{}/messages", self.thread_id.as_ref().unwrap());
-        let params = json!({
-            "role": "user",
-            "content": task,
-        });
-        let response = self
-            .client
-            .post(url)
-            .header("Authorization", "Bearer YOUR_API_KEY")
-            .json(&params)
-            .send()?;
-        let status = response.status();
-        if status.is_success() {
-            let message_id = response.json().unwrap()["id"].as_str().unwrap();
-            let run_url = format!("https://api.openai.com/v1/threads/{}/runs", self.thread_id.as_ref().unwrap());
-            let run_params = json!({
-                "assistant_id": self.assistant_id,
-                "instructions": task,
-            });
-            let run_response = self
-                .client
-                .post(run_url)
-                .header("Authorization", "Bearer YOUR_API_KEY")
-                .json(&run_params)
-                .send()?;
-            let run_status = run_response.status();
-            if run_status.is_success() {
-                let run_id = run_response.json().unwrap()["id"].as_str().unwrap();
-                let wait_url = format!("https://api.openai.com/v1/threads/{}/runs/{}", self.thread_id.as_ref().unwrap(), run_id);
-                loop {
-                    let wait_response = self
-                        .client
-                        .get(wait_url)
-                        .header("Authorization", "Bearer YOUR_API_KEY")
-                        .send()?;
-                    let wait_status = wait_response.status();
-                    if wait_status.is_success() {
-                        let run_status = wait_response.json().unwrap()["status"].as_str().unwrap();
-                        if run_status == "completed" {
-                            break;
-                        } else if run_status == "requires_action" {
-                            // Handle required actions
-                            // Note: This is a placeholder for the actual implementation
-                            println!("Required action: {}", run_status);
-                        } else if run_status == "failed" {
-                            return Err(wait_response.error().unwrap());
-                        }
-                    }
-                    std::thread::sleep(std::time::Duration::from_secs(3));
-                }
-                let response_url = format!("https://api.openai.com/v1/threads/{}/messages", self.thread_id.as_ref().unwrap());
-                let response_params = json!({
-                    "order": "desc",
-                    "limit": 1,
-                });
-                let response_response = self
-                    .client
-                    .get(response_url)
-                    .header("Authorization", "Bearer YOUR_API_KEY")
-                    .json(&response_params)
-                    .send()?;
-                let response_status = response_response.status();
-                if response_status.is_success() {
-                    let response_content = response_response.json().unwrap()["data"][0]["content"][0]["text"]["value"].as_str().unwrap();
-                    Ok(response_content.to_string())
-                } else {
-                    Err(response_response.error().unwrap())
+    /// Add a user message to the thread, creating the thread first if needed.
+    pub async fn add_message(&mut self, content: &str) -> Result<(), AssistantError> {
+        let thread_id = self.ensure_thread().await?;
+        let body = json!({ "role": "user", "content": content });
+        let _: Value = self.rest.call(CREATE_MESSAGE, &[("thread_id", thread_id.as_str())], &(), Some(&body)).await?;
+        Ok(())
+    }
+
+    /// Run a task and return the assistant's reply.
+    pub async fn run(&mut self, task: &str) -> Result<String, AssistantError> {
+        self.add_message(task).await?;
+        let thread_id = self.ensure_thread().await?;
+        let body = json!({ "assistant_id": self.assistant_id, "instructions": self.instructions });
+        let run: Run = self.rest.call(CREATE_RUN, &[("thread_id", thread_id.as_str())], &(), Some(&body)).await?;
+        self.wait_for_run(&thread_id, run).await?;
+        self.latest_response(&thread_id).await
+    }
+
+    // Poll the run until it completes, answering tool calls along the way
+    async fn wait_for_run(&self, thread_id: &str, mut run: Run) -> Result<(), AssistantError> {
+        loop {
+            match run.status.as_str() {
+                "completed" => return Ok(()),
+                "failed" | "expired" | "cancelled" => return Err(AssistantError::RunFailed(run.status)),
+                "requires_action" => run = self.submit_tool_outputs(thread_id, &run).await?,
+                _ => {
+                    tokio::time::sleep(self.poll_interval).await;
+                    run = self.rest.call(GET_RUN, &[("thread_id", thread_id), ("run_id", run.id.as_str())], &(), NO_PARAMS).await?;
                 }
-            } else {
-                Err(run_response.error().unwrap())
             }
-        } else {
-            Err(response.error().unwrap())
         }
     }
 
-    // Ensure a thread exists for the conversation
-    fn ensure_thread(&mut self) {
-        if self.thread_id.is_none() {
-            let url = "https://api.openai.com/v1/threads";
-            let params = json!({});
-            let response = self
-                .client
-                .post(url)
-                .header("Authorization", "Bearer YOUR_API_KEY")
-                .json(&params)
-                .send()
-                .unwrap();
-            let thread_id = response.json().unwrap()["id"].as_str().unwrap();
-            self.thread_id = Some(thread_id.to_string());
+    // Call the requested functions and send their outputs back to the run. The run waits for an output for
+    // every call, so a call to a function that is not registered is answered with an error message.
+    async fn submit_tool_outputs(&self, thread_id: &str, run: &Run) -> Result<Run, AssistantError> {
+        let calls = run.required_action.as_ref().map(|action| action.submit_tool_outputs.tool_calls.as_slice()).unwrap_or_default();
+        let tool_outputs: Vec<ToolOutput> = calls
+            .iter()
+            .filter(|call| call.kind == "function")
+            .map(|call| {
+                let output = match self.available_functions.get(&call.function.name) {
+                    Some(func) => func(&serde_json::from_str(&call.function.arguments).unwrap_or(Value::Null)),
+                    None => format!("Error: function '{}' is not available", call.function.name),
+                };
+                ToolOutput { tool_call_id: call.id.clone(), output }
+            })
+            .collect();
+        let body = json!({ "tool_outputs": tool_outputs });
+        let path = [("thread_id", thread_id), ("run_id", run.id.as_str())];
+        Ok(self.rest.call(SUBMIT_TOOL_OUTPUTS, &path, &(), Some(&body)).await?)
+    }
+
+    // The newest message on the thread, when the assistant wrote it
+    async fn latest_response(&self, thread_id: &str) -> Result<String, AssistantError> {
+        let query = MessageQuery { order: "desc", limit: 1 };
+        let messages: Value = self.rest.call(LIST_MESSAGES, &[("thread_id", thread_id)], &query, NO_PARAMS).await?;
+        let message = &messages["data"][0];
+        if message["role"] != "assistant" {
+            return Ok(String::new());
         }
+        Ok(message["content"][0]["text"]["value"].as_str().unwrap_or_default().to_string())
     }
 }
 
-fn main() {
-    let mut assistant = OpenAIAssistant::new(
-        "Math Tutor",
-        Some("You are a personal math tutor."),
-        "gpt-4o",
-        None,
-        None,
-        None,
-        None,
-    );
-    let response = assistant.run("Solve 3x + 11 = 14").unwrap();
+#[tokio::main]
+async fn main() -> Result<(), AssistantError> {
+    let mut config = AssistantConfig::new("Math Tutor");
+    config.instructions = Some("You are a personal math tutor.".to_string());
+    config.tools = vec![json!({ "type": "code_interpreter" })];
+    let mut assistant = OpenAIAssistant::create(OpenAIAssistant::client_from_env()?, config).await?;
+    let response = assistant.run("Solve 3x + 11 = 14").await?;
     println!("Response: {}", response);
+    Ok(())
 }
 ```
 
 **Conversion Challenges and Limitations:**
 
-1.  **OpenAI API Library:** The Python code uses the `openai` library to interact with the OpenAI API. However, there is no direct Rust equivalent for this library. To overcome this, the code uses the `reqwest` library to make HTTP requests to the OpenAI API.
+1.  **OpenAI API Library:** The Python code uses the `openai` library to interact with the OpenAI API. There is no direct Rust equivalent, so the Assistants endpoints are declared as `Endpoint`s and called through the shared `RestClient`. Tests can substitute an `HttpTransport`.
 2.  **JSON Serialization and Deserialization:** The `json` library used in the Python code is replaced with the `serde_json` library in Rust. This library provides similar functionality for JSON serialization and deserialization.
-3.  **Error Handling:** Rust's error handling system is more explicit than Python's. The code uses `Result` and `Error` types to handle errors that may occur during API requests or JSON parsing.
-4.  **Function Implementation:** The `add_function` method in the Python code adds a function to the OpenAI Assistant. In the Rust implementation, this method is modified to take a closure as an argument, which represents the function to be added.
-5.  **Thread Creation:** The `ensure_thread` method in the Python code creates a new thread for the conversation if one does not exist. The Rust implementation uses a similar approach, but it uses the `reqwest` library to make an HTTP request to create a new thread.
+3.  **Error Handling:** Rust's error handling system is more explicit than Python's. Failed requests come back as `AssistantError::Rest`, and a run that ends without completing as `AssistantError::RunFailed`.
+4.  **Function Implementation:** Python registers a callable and reads its `__name__`. In Rust, `add_function` takes the name and a closure over the parsed JSON arguments.
+5.  **Thread Creation:** As in Python, the thread is created on the first message and reused for the rest of the conversation.
 
 **Future Improvements:**
 
-1.  **Error Handling:** The current implementation uses a simple error handling approach. In a real-world application, you would want to handle errors more robustly, potentially using a custom error type and providing more informative error messages.
-2.  **API Request Handling:** The code makes HTTP requests to the OpenAI API using the `reqwest` library. You may want to consider using a library like `tokio` or `async-std` to handle API requests asynchronously, improving the overall performance of the application.
-3.  **Function Implementation:** The `add_function` method currently takes a closure as an argument. You could modify this method to accept a trait object or a function pointer, providing more flexibility in terms of function implementation.
-4.  **Thread Creation:** The `ensure_thread` method creates a new thread for the conversation if one does not exist. You could modify this method to handle thread creation more robustly, potentially using a caching mechanism to store existing thread IDs.
-
-By addressing these challenges and limitations, you can create a more robust and efficient Rust implementation that leverages the OpenAI API for conversational AI applications.
\ No newline at end of file
+1.  **File Attachments:** `add_message` does not attach files yet; the Python `file_ids` argument has no counterpart.
+2.  **Run Polling:** The run is polled every `poll_interval` (3 seconds by default). Streaming runs would report progress without polling.
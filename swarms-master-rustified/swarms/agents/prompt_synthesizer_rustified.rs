@@ -0,0 +1,323 @@
+### Overview
+In the Python package, `SwarmRouter(auto_generate_prompts=True)` only sets a flag on each agent. No prompt
+is ever generated, and `ape_agent.auto_generate_prompt` is never called. `PromptSynthesizer` does the
+generation:
+
+1. A `PromptBrief` collects what the generator needs to know: the agent's name and description, and the
+   swarm task. `meta_prompt` puts them after the prompt-generator instructions from
+   `prompt_generator_optimizer`.
+2. The model writes a system prompt. The `review` hook sees it before it is used and returns a
+   `PromptReview`:
+   - `Approve` keeps the prompt.
+   - `Edit` replaces it with the reviewer's text.
+   - `Reject` sends the reason back to the model for another draft, up to `max_attempts` times.
+3. Accepted prompts are cached by the brief's hash. An agent with the same name and description, working
+   on the same task, gets the cached prompt without a model call or another review. With
+   `with_cache_file`, the cache is kept as JSON, so reviewed prompts survive across runs.
+
+Agents opt in when they are built: `AgentBuilder::auto_generate_prompt(true)` sets
+`AgentSpec::auto_generate_prompt`, as does `auto_generate_prompt: true` in a YAML config's agent entry.
+`apply` runs this for every agent in a swarm that has the flag and replaces its `system_prompt`; the other
+agents keep theirs. A router's swarm-wide `auto_generate_prompts` sets the flag on each of its agents, as
+in Python, and then calls `apply` before the first run.
+
+### Rust Code
+```rust
+use crate::swarms::prompts::prompt_generator_optimizer::OPENAI_PROMPT_GENERATOR_SYS_PROMPT;
+use crate::swarms::structs::agent::LlmAgent;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The cache file name `auto_generate_prompts` callers use by default.
+pub const PROMPT_CACHE_FILE: &str = "generated_prompts.json";
+
+/// What the generator is told about one agent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct PromptBrief<'a> {
+    pub agent_name: &'a str,
+    pub agent_description: &'a str,
+    pub task: &'a str,
+}
+
+impl<'a> PromptBrief<'a> {
+    pub fn new(agent_name: &'a str, agent_description: &'a str, task: &'a str) -> Self {
+        PromptBrief { agent_name, agent_description, task }
+    }
+
+    pub fn for_agent(agent: &'a LlmAgent, task: &'a str) -> Self {
+        PromptBrief::new(&agent.name, &agent.description, task)
+    }
+
+    /// The cache key: a SHA-256 of the name, description, and task.
+    pub fn cache_key(&self) -> String {
+        let mut hasher = Sha256::new();
+        for part in [self.agent_name, self.agent_description, self.task] {
+            hasher.update((part.len() as u64).to_le_bytes());
+            hasher.update(part.as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// The generator's input. `feedback` holds the reviewer's reasons for rejecting earlier drafts.
+    pub fn meta_prompt(&self, feedback: &[String]) -> String {
+        let mut prompt = format!(
+            "{}\n# Agent\nName: {}\nDescription: {}\n\n# Swarm Task\n{}\n\n\
+             Write the system prompt for this agent, tailored to its part in the task.\n",
+            OPENAI_PROMPT_GENERATOR_SYS_PROMPT.trim(),
+            self.agent_name,
+            if self.agent_description.trim().is_empty() { "(none given)" } else { self.agent_description.trim() },
+            self.task.trim()
+        );
+        if !feedback.is_empty() {
+            prompt.push_str("\n# Reviewer Feedback\nEarlier drafts were rejected. Address every point:\n");
+            for reason in feedback {
+                prompt.push_str(&format!("- {}\n", reason));
+            }
+        }
+        prompt
+    }
+}
+
+/// The review hook's verdict on a generated prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PromptReview {
+    Approve,
+    /// Use this text instead of the generated prompt.
+    Edit(String),
+    /// Ask for another draft; the reason is passed to the model.
+    Reject(String),
+}
+
+#[derive(Debug)]
+pub enum PromptSynthesisError {
+    Model(String),
+    Rejected { agent: String, attempts: usize, reasons: Vec<String> },
+    Io(io::Error),
+    Cache(serde_json::Error),
+}
+
+impl fmt::Display for PromptSynthesisError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PromptSynthesisError::Model(message) => write!(f, "prompt generation failed: {}", message),
+            PromptSynthesisError::Rejected { agent, attempts, reasons } => write!(
+                f,
+                "every generated prompt for '{}' was rejected after {} attempt(s): {}",
+                agent,
+                attempts,
+                reasons.join("; ")
+            ),
+            PromptSynthesisError::Io(e) => write!(f, "prompt cache I/O failed: {}", e),
+            PromptSynthesisError::Cache(e) => write!(f, "prompt cache is not valid JSON: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PromptSynthesisError {}
+
+impl From<io::Error> for PromptSynthesisError {
+    fn from(e: io::Error) -> Self {
+        PromptSynthesisError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for PromptSynthesisError {
+    fn from(e: serde_json::Error) -> Self {
+        PromptSynthesisError::Cache(e)
+    }
+}
+
+/// One cache entry. The name and task are kept for people reading the cache file; lookups use the key.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedPrompt {
+    pub agent_name: String,
+    pub task: String,
+    pub prompt: String,
+}
+
+/// An accepted prompt and where it came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GeneratedPrompt {
+    pub agent_name: String,
+    pub prompt: String,
+    pub cache_key: String,
+    pub from_cache: bool,
+    /// Model calls made for this prompt; 0 when it came from the cache.
+    pub attempts: usize,
+    pub edited: bool,
+}
+
+pub struct PromptSynthesizer {
+    cache: BTreeMap<String, CachedPrompt>,
+    cache_path: Option<PathBuf>,
+    max_attempts: usize,
+}
+
+impl Default for PromptSynthesizer {
+    fn default() -> Self {
+        PromptSynthesizer::new()
+    }
+}
+
+impl PromptSynthesizer {
+    /// A synthesizer with an in-memory cache.
+    pub fn new() -> Self {
+        PromptSynthesizer { cache: BTreeMap::new(), cache_path: None, max_attempts: 3 }
+    }
+
+    /// A synthesizer whose cache is loaded from, and saved to, `path`. A missing file starts an empty cache.
+    pub fn with_cache_file(path: impl Into<PathBuf>) -> Result<Self, PromptSynthesisError> {
+        let path = path.into();
+        let cache = if path.exists() { serde_json::from_str(&std::fs::read_to_string(&path)?)? } else { BTreeMap::new() };
+        Ok(PromptSynthesizer { cache, cache_path: Some(path), max_attempts: 3 })
+    }
+
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub fn cache_path(&self) -> Option<&Path> {
+        self.cache_path.as_deref()
+    }
+
+    pub fn cached(&self, brief: &PromptBrief) -> Option<&CachedPrompt> {
+        self.cache.get(&brief.cache_key())
+    }
+
+    /// Drop the cached prompt for `brief`, so the next call generates a new one.
+    pub fn invalidate(&mut self, brief: &PromptBrief) -> Result<bool, PromptSynthesisError> {
+        let removed = self.cache.remove(&brief.cache_key()).is_some();
+        if removed {
+            self.persist()?;
+        }
+        Ok(removed)
+    }
+
+    /// The prompt for `brief`, from the cache or from `model` once `review` accepts it.
+    pub fn synthesize(
+        &mut self,
+        brief: &PromptBrief,
+        mut model: impl FnMut(&str) -> Result<String, String>,
+        mut review: impl FnMut(&PromptBrief, &str) -> PromptReview,
+    ) -> Result<GeneratedPrompt, PromptSynthesisError> {
+        let cache_key = brief.cache_key();
+        if let Some(cached) = self.cache.get(&cache_key) {
+            return Ok(GeneratedPrompt {
+                agent_name: brief.agent_name.to_string(),
+                prompt: cached.prompt.clone(),
+                cache_key,
+                from_cache: true,
+                attempts: 0,
+                edited: false,
+            });
+        }
+
+        let mut reasons = Vec::new();
+        for attempt in 1..=self.max_attempts {
+            let draft = model(&brief.meta_prompt(&reasons)).map_err(PromptSynthesisError::Model)?;
+            let draft = draft.trim();
+            let verdict = if draft.is_empty() {
+                PromptReview::Reject("the generated prompt was empty".to_string())
+            } else {
+                review(brief, draft)
+            };
+            let (prompt, edited) = match verdict {
+                PromptReview::Approve => (draft.to_string(), false),
+                PromptReview::Edit(text) => (text.trim().to_string(), true),
+                PromptReview::Reject(reason) => {
+                    warn!("Generated prompt {} for '{}' rejected: {}", attempt, brief.agent_name, reason);
+                    reasons.push(reason);
+                    continue;
+                }
+            };
+            self.cache.insert(
+                cache_key.clone(),
+                CachedPrompt { agent_name: brief.agent_name.to_string(), task: brief.task.to_string(), prompt: prompt.clone() },
+            );
+            self.persist()?;
+            info!("Generated system prompt for '{}' after {} attempt(s)", brief.agent_name, attempt);
+            return Ok(GeneratedPrompt {
+                agent_name: brief.agent_name.to_string(),
+                prompt,
+                cache_key,
+                from_cache: false,
+                attempts: attempt,
+                edited,
+            });
+        }
+        Err(PromptSynthesisError::Rejected { agent: brief.agent_name.to_string(), attempts: self.max_attempts, reasons })
+    }
+
+    /// Generate a prompt for each agent with `auto_generate_prompt` set and make it the agent's
+    /// `system_prompt`. Stops at the first failure; agents before it keep their new prompts.
+    pub fn apply(
+        &mut self,
+        agents: &mut [LlmAgent],
+        task: &str,
+        mut model: impl FnMut(&str) -> Result<String, String>,
+        mut review: impl FnMut(&PromptBrief, &str) -> PromptReview,
+    ) -> Result<Vec<GeneratedPrompt>, PromptSynthesisError> {
+        let mut generated = Vec::with_capacity(agents.len());
+        for agent in agents.iter_mut().filter(|agent| agent.auto_generate_prompt) {
+            let prompt = self.synthesize(&PromptBrief::for_agent(agent, task), &mut model, &mut review)?;
+            agent.system_prompt = prompt.prompt.clone();
+            generated.push(prompt);
+        }
+        Ok(generated)
+    }
+
+    fn persist(&self) -> Result<(), PromptSynthesisError> {
+        if let Some(path) = &self.cache_path {
+            if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::write(path, serde_json::to_string_pretty(&self.cache)?)?;
+        }
+        Ok(())
+    }
+}
+
+fn main() -> Result<(), PromptSynthesisError> {
+    let mut agents: Vec<LlmAgent> = ["Data-Extractor", "Financial-Analyst"]
+        .iter()
+        .map(|name| {
+            LlmAgent::builder()
+                .name(*name)
+                .llm("gpt-4o")
+                .description("Private equity due diligence")
+                .auto_generate_prompt(true)
+                .build()
+                .expect("valid agent")
+        })
+        .collect();
+    let mut synthesizer = PromptSynthesizer::with_cache_file(PROMPT_CACHE_FILE)?;
+    let generated = synthesizer.apply(
+        &mut agents,
+        "Find template term sheets for Series A startups",
+        |meta_prompt| Ok(format!("You are an expert. {} characters of brief were provided.", meta_prompt.len())),
+        |brief, prompt| {
+            if prompt.contains(brief.agent_name) {
+                PromptReview::Approve
+            } else {
+                PromptReview::Edit(format!("You are {}. {}", brief.agent_name, prompt))
+            }
+        },
+    )?;
+    for prompt in &generated {
+        println!("{} (cached: {}): {}", prompt.agent_name, prompt.from_cache, prompt.prompt);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* The cache key covers the name, description, and task, but not the model. Call `invalidate` after switching generator models if the old prompts should not be reused.
+* Cached prompts skip the review hook. They were reviewed when first accepted, and a reviewer's `Edit` is what gets cached.
+* `apply` skips agents without `auto_generate_prompt`, so a swarm can mix generated prompts with hand-tuned ones.
+* An empty draft counts as a rejection, so it uses up an attempt instead of clearing the agent's prompt.
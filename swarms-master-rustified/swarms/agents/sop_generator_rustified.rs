@@ -0,0 +1,391 @@
+### Overview
+The Python package only ships the SOP generator as a prompt, `sop_generator_agent_prompt`; whatever the
+model writes back is used as is. `SopGenerator` turns it into a pipeline:
+
+1. The prompt for the task is extended with the `SopTemplate`: which sections the SOP must have, and how
+   many numbered steps its procedure needs.
+2. The model's reply is parsed into a `Sop` and checked against the template. A reply with missing
+   sections or badly numbered steps is sent back with the problems listed, up to `max_attempts` times.
+3. The accepted SOP is written as an `Artifact`: the Markdown goes to `<output_dir>/<slug>.md` and the
+   version history to `<slug>.artifact.json`. Generating an SOP for the same task again adds a version
+   instead of overwriting the history.
+
+`generate` takes the model as a closure. With the `providers-openai` feature, `generate_with_provider`
+sends the prompts through an `OpenAiCompatProvider`.
+
+### Rust Code
+```rust
+use crate::swarms::artifacts::main_artifact::Artifact;
+use crate::swarms::prompts::sop_generator_agent_prompt::sop_generator_agent_prompt;
+#[cfg(feature = "providers-openai")]
+use crate::swarms::utils::openai_compat_provider::OpenAiCompatProvider;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::io;
+use std::path::PathBuf;
+
+/// What an accepted SOP must contain. Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SopTemplate {
+    /// Section titles, matched case-insensitively against the SOP's headings.
+    pub sections: Vec<String>,
+    /// The section holding the numbered steps; it must be one of `sections`.
+    pub steps_section: String,
+    pub min_steps: usize,
+}
+
+impl Default for SopTemplate {
+    fn default() -> Self {
+        SopTemplate {
+            sections: ["Purpose", "Scope", "Responsibilities", "Procedure"].iter().map(|s| s.to_string()).collect(),
+            steps_section: "Procedure".to_string(),
+            min_steps: 3,
+        }
+    }
+}
+
+impl SopTemplate {
+    /// The format instructions appended to the generator prompt.
+    pub fn instructions(&self) -> String {
+        format!(
+            "######## Required Format ########\n\
+             Write the SOP in Markdown. Start with a `# ` title, then give each of these sections a `## ` heading, \
+             in this order: {}.\n\
+             Under {}, number the steps `1.`, `2.`, `3.` and so on, one step per line, with at least {} steps.\n",
+            self.sections.join(", "),
+            self.steps_section,
+            self.min_steps
+        )
+    }
+
+    /// Every way `sop` falls short of the template; empty when it is accepted.
+    pub fn validate(&self, sop: &Sop) -> Vec<SopIssue> {
+        let mut issues: Vec<SopIssue> = self
+            .sections
+            .iter()
+            .filter(|required| sop.section(required).is_none())
+            .map(|required| SopIssue::MissingSection(required.clone()))
+            .collect();
+        if sop.section(&self.steps_section).is_none() {
+            return issues;
+        }
+        for (position, step) in sop.steps.iter().enumerate() {
+            if step.number != position + 1 {
+                issues.push(SopIssue::StepOutOfOrder { expected: position + 1, found: step.number });
+                break;
+            }
+        }
+        if sop.steps.len() < self.min_steps {
+            issues.push(SopIssue::TooFewSteps { found: sop.steps.len(), required: self.min_steps });
+        }
+        issues
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SopIssue {
+    MissingSection(String),
+    TooFewSteps { found: usize, required: usize },
+    StepOutOfOrder { expected: usize, found: usize },
+}
+
+impl fmt::Display for SopIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SopIssue::MissingSection(section) => write!(f, "the '{}' section is missing", section),
+            SopIssue::TooFewSteps { found, required } => {
+                write!(f, "the procedure has {} numbered step(s); at least {} are required", found, required)
+            }
+            SopIssue::StepOutOfOrder { expected, found } => {
+                write!(f, "step {} is numbered {}; number the steps 1, 2, 3, ... without gaps", expected, found)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SopSection {
+    pub title: String,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SopStep {
+    /// The number the model gave the step.
+    pub number: usize,
+    pub text: String,
+}
+
+/// A parsed SOP.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sop {
+    /// The `# ` title, or empty when there is none.
+    pub title: String,
+    pub sections: Vec<SopSection>,
+    /// The numbered steps of the template's `steps_section`.
+    pub steps: Vec<SopStep>,
+    /// The reply as written.
+    pub markdown: String,
+}
+
+/// A section heading: `## Title`, `**Title**`, or `Title:` on a line of its own or followed by text.
+fn heading(line: &str) -> Option<(usize, String, String)> {
+    if let Some(rest) = line.strip_prefix('#') {
+        let level = 1 + rest.chars().take_while(|c| *c == '#').count();
+        return Some((level, rest.trim_start_matches('#').trim().trim_end_matches(':').to_string(), String::new()));
+    }
+    let unbolded = line.strip_prefix("**").and_then(|rest| rest.strip_suffix("**"));
+    if let Some(title) = unbolded.filter(|title| !title.contains("**")) {
+        return Some((2, title.trim().trim_end_matches(':').to_string(), String::new()));
+    }
+    let (title, rest) = line.split_once(':')?;
+    let starts_upper = title.chars().next().map_or(false, char::is_uppercase);
+    if starts_upper && title.split_whitespace().count() <= 4 && !title.contains(['*', '`']) {
+        return Some((2, title.trim().to_string(), rest.trim().to_string()));
+    }
+    None
+}
+
+/// A numbered step line: `1. Do this` or `1) Do this`.
+fn numbered(line: &str) -> Option<SopStep> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    let rest = line[digits..].strip_prefix(['.', ')'])?;
+    if digits == 0 || !rest.starts_with(char::is_whitespace) || rest.trim().is_empty() {
+        return None;
+    }
+    Some(SopStep { number: line[..digits].parse().ok()?, text: rest.trim().to_string() })
+}
+
+impl Sop {
+    /// Split `markdown` into sections and collect the numbered steps under `steps_section`.
+    pub fn parse(markdown: &str, steps_section: &str) -> Sop {
+        let mut sop = Sop { title: String::new(), sections: Vec::new(), steps: Vec::new(), markdown: markdown.to_string() };
+        let steps_section = steps_section.to_lowercase();
+        let mut in_steps = false;
+        for line in markdown.lines().map(str::trim) {
+            if numbered(line).is_none() {
+                if let Some((level, title, rest)) = heading(line) {
+                    if level == 1 && sop.title.is_empty() && sop.sections.is_empty() {
+                        sop.title = title;
+                        continue;
+                    }
+                    in_steps = title.to_lowercase().contains(&steps_section);
+                    let body = if rest.is_empty() { rest } else { format!("{}\n", rest) };
+                    sop.sections.push(SopSection { title, body });
+                    continue;
+                }
+            }
+            if let Some(section) = sop.sections.last_mut() {
+                if !section.body.is_empty() || !line.is_empty() {
+                    section.body.push_str(line);
+                    section.body.push('\n');
+                }
+            }
+            if in_steps {
+                sop.steps.extend(numbered(line));
+            }
+        }
+        for section in &mut sop.sections {
+            section.body = section.body.trim_end().to_string();
+        }
+        sop
+    }
+
+    /// The first section whose title contains `name`, ignoring case.
+    pub fn section(&self, name: &str) -> Option<&SopSection> {
+        let name = name.to_lowercase();
+        self.sections.iter().find(|section| section.title.to_lowercase().contains(&name))
+    }
+}
+
+#[derive(Debug)]
+pub enum SopError {
+    /// The model call failed.
+    Model(String),
+    /// No attempt met the template; `issues` are from the last one.
+    Rejected { attempts: usize, issues: Vec<SopIssue> },
+    Io(io::Error),
+}
+
+impl fmt::Display for SopError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SopError::Model(message) => write!(f, "SOP generation failed: {}", message),
+            SopError::Rejected { attempts, issues } => {
+                let issues: Vec<String> = issues.iter().map(ToString::to_string).collect();
+                write!(f, "no SOP met the template after {} attempt(s): {}", attempts, issues.join("; "))
+            }
+            SopError::Io(e) => write!(f, "failed to write SOP artifact: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for SopError {}
+
+impl From<io::Error> for SopError {
+    fn from(e: io::Error) -> Self {
+        SopError::Io(e)
+    }
+}
+
+/// An accepted SOP and where it was written.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GeneratedSop {
+    pub sop: Sop,
+    /// The artifact version this SOP was saved as, starting at 1.
+    pub version: i32,
+    pub path: PathBuf,
+    pub history_path: PathBuf,
+    /// Model calls made, including rejected drafts.
+    pub attempts: usize,
+}
+
+/// File-name form of a task: lowercase words joined by `-`.
+fn slug(task: &str) -> String {
+    let words: Vec<String> = task
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect();
+    if words.is_empty() {
+        "sop".to_string()
+    } else {
+        words.join("-")
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SopGenerator {
+    output_dir: PathBuf,
+    template: SopTemplate,
+    max_attempts: usize,
+}
+
+impl SopGenerator {
+    /// A generator writing to `output_dir` with the default template and three attempts.
+    pub fn new(output_dir: impl Into<PathBuf>) -> Self {
+        SopGenerator { output_dir: output_dir.into(), template: SopTemplate::default(), max_attempts: 3 }
+    }
+
+    pub fn template(mut self, template: SopTemplate) -> Self {
+        self.template = template;
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// The prompt for `task`, with the problems of a rejected draft when there is one.
+    pub fn prompt(&self, task: &str, rejected: &[SopIssue]) -> String {
+        let mut prompt = format!("{}\n{}", sop_generator_agent_prompt(task), self.template.instructions());
+        if !rejected.is_empty() {
+            prompt.push_str("\nYour previous draft was rejected. Fix these problems:\n");
+            for issue in rejected {
+                prompt.push_str(&format!("- {}\n", issue));
+            }
+        }
+        prompt
+    }
+
+    /// Parse and check one reply; `Err` holds the problems to send back.
+    pub fn review(&self, reply: &str) -> Result<Sop, Vec<SopIssue>> {
+        let sop = Sop::parse(reply, &self.template.steps_section);
+        let issues = self.template.validate(&sop);
+        if issues.is_empty() {
+            Ok(sop)
+        } else {
+            Err(issues)
+        }
+    }
+
+    /// Generate an SOP for `task` with `model`, then save it as a new artifact version.
+    pub fn generate(&self, task: &str, mut model: impl FnMut(&str) -> Result<String, String>) -> Result<GeneratedSop, SopError> {
+        let mut issues = Vec::new();
+        for attempt in 1..=self.max_attempts {
+            let reply = model(&self.prompt(task, &issues)).map_err(SopError::Model)?;
+            match self.review(&reply) {
+                Ok(sop) => return self.save(task, sop, attempt),
+                Err(found) => {
+                    warn!("SOP draft {} for '{}' rejected: {} problem(s)", attempt, task, found.len());
+                    issues = found;
+                }
+            }
+        }
+        Err(SopError::Rejected { attempts: self.max_attempts, issues })
+    }
+
+    /// `generate`, with the prompts sent through `provider`.
+    #[cfg(feature = "providers-openai")]
+    pub async fn generate_with_provider(&self, task: &str, provider: &OpenAiCompatProvider) -> Result<GeneratedSop, SopError> {
+        let mut issues = Vec::new();
+        for attempt in 1..=self.max_attempts {
+            let reply = provider
+                .complete(None, &self.prompt(task, &issues))
+                .await
+                .map_err(|e| SopError::Model(e.to_string()))?;
+            match self.review(&reply) {
+                Ok(sop) => return self.save(task, sop, attempt),
+                Err(found) => {
+                    warn!("SOP draft {} for '{}' rejected: {} problem(s)", attempt, task, found.len());
+                    issues = found;
+                }
+            }
+        }
+        Err(SopError::Rejected { attempts: self.max_attempts, issues })
+    }
+
+    /// Where the SOP for `task` is written.
+    pub fn paths(&self, task: &str) -> (PathBuf, PathBuf) {
+        let slug = slug(task);
+        (self.output_dir.join(format!("{}.md", slug)), self.output_dir.join(format!("{}.artifact.json", slug)))
+    }
+
+    fn save(&self, task: &str, sop: Sop, attempts: usize) -> Result<GeneratedSop, SopError> {
+        std::fs::create_dir_all(&self.output_dir)?;
+        let (path, history_path) = self.paths(task);
+        let to_io = |e: String| io::Error::new(io::ErrorKind::Other, e);
+        let mut artifact = if history_path.exists() {
+            let mut artifact = Artifact::import_from_json(&history_path.to_string_lossy())?;
+            artifact.edit(sop.markdown.clone()).map_err(to_io)?;
+            artifact
+        } else {
+            let mut artifact = Artifact::new(path.to_string_lossy().into_owned(), ".md".to_string());
+            artifact.create(sop.markdown.clone()).map_err(to_io)?;
+            artifact
+        };
+        artifact.save()?;
+        artifact.export_to_json(&history_path.to_string_lossy())?;
+        let version = artifact.current_version();
+        info!("Saved SOP for '{}' as version {} at {}", task, version, path.display());
+        Ok(GeneratedSop { sop, version, path, history_path, attempts })
+    }
+}
+
+fn main() -> Result<(), SopError> {
+    let drafts = [
+        "# Refund Handling\n## Purpose\nResolve refund requests.\n## Procedure\n1. Verify the order.",
+        "# Refund Handling\n## Purpose\nResolve refund requests consistently.\n## Scope\nAll card payments.\n\
+         ## Responsibilities\nSupport agents own the request end to end.\n## Procedure\n\
+         1. Verify the order and payment.\n2. Check the refund policy window.\n3. Issue the refund and notify the customer.",
+    ];
+    let mut calls = 0;
+    let generated = SopGenerator::new("sops").generate("Refund handling", |_prompt| {
+        calls += 1;
+        Ok(drafts[calls.min(drafts.len()) - 1].to_string())
+    })?;
+    println!("version {} after {} attempt(s): {}", generated.version, generated.attempts, generated.path.display());
+    for step in &generated.sop.steps {
+        println!("{}. {}", step.number, step.text);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* Section titles match by substring, so `Instructor Responsibilities` satisfies `Responsibilities`. Keep template titles specific enough not to match each other.
+* Only top-level numbered lines (`1.`, `2)`) count as steps; `1.1` sub-steps and bullets stay in the section body.
+* The `.md` file always holds the latest version. Earlier versions are only in the `.artifact.json` history.
@@ -24,7 +24,7 @@ pub struct ToolAgent {
     tokenizer: Option<String>,
     json_schema: Option<String>,
     max_number_tokens: u32,
-    parsing_function: Option<Box<dyn FnMut(serde_json::Value) -> serde_json::Value>>,
+    parsing_function: Option<Box<dyn FnMut(serde_json::Value) -> serde_json::Value + Send + Sync>>,
     llm: Option<String>,
 }
 
@@ -37,7 +37,7 @@ impl ToolAgent {
         tokenizer: Option<String>,
         json_schema: Option<String>,
         max_number_tokens: u32,
-        parsing_function: Option<Box<dyn FnMut(serde_json::Value) -> serde_json::Value>>,
+        parsing_function: Option<Box<dyn FnMut(serde_json::Value) -> serde_json::Value + Send + Sync>>,
         llm: Option<String>,
     ) -> Self {
         ToolAgent {
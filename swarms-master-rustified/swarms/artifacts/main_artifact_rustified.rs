@@ -15,20 +15,26 @@ Here's the equivalent Rust code for the provided Python file. Note that I've use
 
 ```rust
 // Import necessary crates
+use crate::swarms::text::redaction::Redactor;
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::safe_path::sanitize_file_name;
 use serde::{Serialize, Deserialize};
 use std::fs::{File, OpenOptions};
 use std::io::{Read, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::time::{SystemTime, UNIX_EPOCH};
 use log::{info, error};
 use env_logger::init;
 
 // Define the FileVersion struct
 #[derive(Serialize, Deserialize, Debug)]
-struct FileVersion {
-    version_number: i32,
-    content: String,
-    timestamp: String,
+pub struct FileVersion {
+    /// Sorts by creation time across artifacts, unlike `version_number`. Empty in artifacts saved before it.
+    #[serde(default)]
+    pub version_id: String,
+    pub version_number: i32,
+    pub content: String,
+    pub timestamp: String,
 }
 
 impl FileVersion {
@@ -39,6 +45,7 @@ impl FileVersion {
             .as_secs()
             .to_string();
         FileVersion {
+            version_id: new_id(IdKind::Version),
             version_number,
             content,
             timestamp,
@@ -48,7 +55,7 @@ impl FileVersion {
 
 // Define the Artifact struct
 #[derive(Serialize, Deserialize, Debug)]
-struct Artifact {
+pub struct Artifact {
     folder_path: String,
     file_path: String,
     file_type: String,
@@ -58,7 +65,7 @@ struct Artifact {
 }
 
 impl Artifact {
-    fn new(file_path: String, file_type: String) -> Self {
+    pub fn new(file_path: String, file_type: String) -> Self {
         Artifact {
             folder_path: std::env::var("WORKSPACE_DIR").unwrap_or_default(),
             file_path,
@@ -69,14 +76,14 @@ impl Artifact {
         }
     }
 
-    fn create(&mut self, initial_content: String) -> Result<(), String> {
+    pub fn create(&mut self, initial_content: String) -> Result<(), String> {
         self.contents = initial_content.clone();
         self.versions.push(FileVersion::new(1, initial_content));
         self.edit_count = 0;
         Ok(())
     }
 
-    fn edit(&mut self, new_content: String) -> Result<(), String> {
+    pub fn edit(&mut self, new_content: String) -> Result<(), String> {
         self.contents = new_content.clone();
         self.edit_count += 1;
         let version_number = self.versions.len() as i32 + 1;
@@ -84,14 +91,33 @@ impl Artifact {
         Ok(())
     }
 
-    fn save(&self) -> Result<(), std::io::Error> {
-        let mut file = File::create(&self.file_path)?;
+    // `file_path` with `suffix` appended, its file name made valid on every platform (see `sanitize_file_name`)
+    fn output_path(&self, suffix: &str) -> PathBuf {
+        let path = Path::new(&self.file_path);
+        let name = path.file_name().map_or_else(String::new, |name| name.to_string_lossy().into_owned());
+        path.parent().unwrap_or(Path::new("")).join(sanitize_file_name(&format!("{}{}", name, suffix)))
+    }
+
+    pub fn save(&self) -> Result<(), std::io::Error> {
+        let mut file = File::create(self.output_path(""))?;
         file.write_all(self.contents.as_bytes())?;
         Ok(())
     }
 
+    // Save with PII redacted. A sealed original, when the redactor keeps one, goes to `<file_path>.sealed`
+    fn save_redacted(&self, redactor: &Redactor) -> Result<(), std::io::Error> {
+        let stored = redactor
+            .for_storage(&self.contents)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::Other, err.to_string()))?;
+        File::create(self.output_path(""))?.write_all(stored.text.as_bytes())?;
+        if let Some(sealed) = stored.sealed_original {
+            File::create(self.output_path(".sealed"))?.write_all(sealed.as_bytes())?;
+        }
+        Ok(())
+    }
+
     fn load(&mut self) -> Result<(), std::io::Error> {
-        let mut file = File::open(&self.file_path)?;
+        let mut file = File::open(self.output_path(""))?;
         let mut contents = String::new();
         file.read_to_string(&mut contents)?;
         self.contents = contents;
@@ -99,11 +125,16 @@ impl Artifact {
         Ok(())
     }
 
-    fn get_version(&self, version_number: i32) -> Option<&FileVersion> {
+    // The number of the latest version, 0 before `create`
+    pub fn current_version(&self) -> i32 {
+        self.versions.last().map_or(0, |v| v.version_number)
+    }
+
+    pub fn get_version(&self, version_number: i32) -> Option<&FileVersion> {
         self.versions.iter().find(|v| v.version_number == version_number)
     }
 
-    fn get_contents(&self) -> &str {
+    pub fn get_contents(&self) -> &str {
         &self.contents
     }
 
@@ -115,13 +146,13 @@ impl Artifact {
             .join("\n\n")
     }
 
-    fn export_to_json(&self, file_path: &str) -> Result<(), std::io::Error> {
+    pub fn export_to_json(&self, file_path: &str) -> Result<(), std::io::Error> {
         let mut file = File::create(file_path)?;
         serde_json::to_writer_pretty(&mut file, self)?;
         Ok(())
     }
 
-    fn import_from_json(file_path: &str) -> Result<Self, std::io::Error> {
+    pub fn import_from_json(file_path: &str) -> Result<Self, std::io::Error> {
         let mut file = File::open(file_path)?;
         let mut contents = String::new();
         file.read_to_string(&mut contents)?;
@@ -149,7 +180,7 @@ impl Artifact {
             ));
         }
 
-        let output_path = format!("{}{}", self.file_path, output_format);
+        let output_path = self.output_path(output_format);
 
         if output_format == ".pdf" {
             // PDF generation is not implemented in this example
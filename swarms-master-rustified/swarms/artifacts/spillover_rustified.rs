@@ -0,0 +1,254 @@
+### Overview
+There is no Python counterpart; the Python package keeps every output inline. An agent that writes a
+full report or dumps a code base can produce megabytes. That output is then sent again with every
+later prompt, copied into autosaved conversations, and repeated in run metadata files.
+
+`Spillover` moves outputs above a token threshold into files in its directory and leaves a short
+reference in their place. The reference gives the file's path and size, the document's headings, and
+the opening lines, so agents reading the conversation can still tell what the output was about:
+
+```text
+[spilled output: agent_workspace/spill/Analyst-msg_01J9Z3Q5M8XK4T2W6V7B0C1D2E.md | 2104331 bytes, ~526083 tokens]
+Outline: # Q3 Review; ## Revenue; ## Costs; ## Outlook
+Preview:
+# Q3 Review
+Revenue grew 12% on the back of ...
+```
+
+`Spillover::expand` reads the full output back from a reference, and refuses references that point
+outside the spill directory. `ConversationBuilder::spillover` and `TaskQueueSwarm::with_spillover` turn
+it on for conversations and run metadata.
+
+Spilled files go through the same hooks as the rest of the stored state. With `with_redactor`, PII is
+redacted before the output is written or previewed, and a sealed original goes to `<file>.sealed` when
+the redactor keeps originals (as `Artifact` does). With `with_encryption`, the file is encrypted with
+the `StateCipher`. A conversation passes its own redactor and cipher to its spillover unless the
+spillover has its own.
+
+### Rust Code
+```rust
+use crate::swarms::text::redaction::{Redactor, StoredText};
+use crate::swarms::text::tokenizer::estimate_tokens;
+use crate::swarms::utils::encryption::StateCipher;
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::safe_path::sanitize_file_name;
+use log::{info, warn};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// Outputs above this many estimated tokens spill by default.
+pub const DEFAULT_SPILL_TOKENS: usize = 4_000;
+/// How a spilled output's reference begins.
+pub const SPILL_MARKER: &str = "[spilled output: ";
+
+const MAX_OUTLINE: usize = 12;
+
+/// Where a spilled output went, and the text left in its place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpilledOutput {
+    pub path: PathBuf,
+    pub bytes: usize,
+    pub tokens: usize,
+    pub reference: String,
+}
+
+/// Stores outputs above a token threshold as files in one directory.
+#[derive(Debug, Clone)]
+pub struct Spillover {
+    dir: PathBuf,
+    threshold_tokens: usize,
+    preview_chars: usize,
+    redactor: Option<Arc<Redactor>>,
+    cipher: Option<Arc<StateCipher>>,
+}
+
+// The first `max` characters, cut back to the last whole line when there is one
+fn preview(content: &str, max: usize) -> &str {
+    let end = content.char_indices().nth(max).map_or(content.len(), |(index, _)| index);
+    let head = &content[..end];
+    match head.rfind('\n') {
+        Some(newline) if end < content.len() && newline > 0 => &head[..newline],
+        _ => head,
+    }
+}
+
+// Markdown headings, or the top-level keys of a JSON object
+fn outline(content: &str, json: Option<&serde_json::Value>) -> Vec<String> {
+    if let Some(serde_json::Value::Object(object)) = json {
+        return object.keys().take(MAX_OUTLINE).cloned().collect();
+    }
+    let mut in_fence = false;
+    content
+        .lines()
+        .filter(|line| {
+            if line.trim_start().starts_with("```") {
+                in_fence = !in_fence;
+            }
+            !in_fence && line.starts_with('#')
+        })
+        .take(MAX_OUTLINE)
+        .map(|line| line.trim().to_string())
+        .collect()
+}
+
+impl Spillover {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Spillover {
+            dir: dir.into(),
+            threshold_tokens: DEFAULT_SPILL_TOKENS,
+            preview_chars: 1_000,
+            redactor: None,
+            cipher: None,
+        }
+    }
+
+    /// Spill outputs above `tokens` estimated tokens.
+    pub fn with_threshold(mut self, tokens: usize) -> Self {
+        self.threshold_tokens = tokens;
+        self
+    }
+
+    /// How much of the output the reference quotes.
+    pub fn with_preview_chars(mut self, chars: usize) -> Self {
+        self.preview_chars = chars;
+        self
+    }
+
+    /// Redact spilled outputs before they are written or previewed.
+    pub fn with_redactor(mut self, redactor: Arc<Redactor>) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// Encrypt spilled files with `cipher`.
+    pub fn with_encryption(mut self, cipher: Arc<StateCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    /// This spillover with `redactor` and `cipher` filled in where it has none of its own, so a
+    /// conversation's spilled messages are stored the way its saves are.
+    pub fn inheriting(self: &Arc<Self>, redactor: Option<&Arc<Redactor>>, cipher: Option<&Arc<StateCipher>>) -> Arc<Self> {
+        let missing_redactor = self.redactor.is_none() && redactor.is_some();
+        let missing_cipher = self.cipher.is_none() && cipher.is_some();
+        if !missing_redactor && !missing_cipher {
+            return Arc::clone(self);
+        }
+        let mut spillover = Spillover::clone(self);
+        spillover.redactor = spillover.redactor.or_else(|| redactor.cloned());
+        spillover.cipher = spillover.cipher.or_else(|| cipher.cloned());
+        Arc::new(spillover)
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Store `content` in a file if it is above the threshold. `None` when it is small enough to keep.
+    pub fn spill(&self, author: &str, content: &str) -> io::Result<Option<SpilledOutput>> {
+        let tokens = estimate_tokens(content);
+        if tokens <= self.threshold_tokens {
+            return Ok(None);
+        }
+        let stored = match &self.redactor {
+            Some(redactor) => redactor.for_storage(content).map_err(|err| io::Error::new(io::ErrorKind::Other, err.to_string()))?,
+            None => StoredText { text: content.to_string(), sealed_original: None },
+        };
+        let content = stored.text.as_str();
+        let json = serde_json::from_str::<serde_json::Value>(content).ok().filter(|value| value.is_object() || value.is_array());
+        let extension = if json.is_some() { ".json" } else { ".md" };
+        fs::create_dir_all(&self.dir)?;
+        let name = sanitize_file_name(&format!("{}-{}{}", author, new_id(IdKind::Message), extension));
+        let path = self.dir.join(name);
+
+        match &self.cipher {
+            Some(cipher) => cipher.write_file(&path, content.as_bytes())?,
+            None => fs::write(&path, content)?,
+        }
+        if let Some(sealed) = &stored.sealed_original {
+            let mut sealed_path = path.clone().into_os_string();
+            sealed_path.push(".sealed");
+            fs::write(sealed_path, sealed)?;
+        }
+
+        let mut reference = format!("{}{} | {} bytes, ~{} tokens]\n", SPILL_MARKER, path.display(), content.len(), tokens);
+        let outline = outline(content, json.as_ref());
+        if !outline.is_empty() {
+            reference.push_str(&format!("Outline: {}\n", outline.join("; ")));
+        }
+        reference.push_str("Preview:\n");
+        reference.push_str(preview(content, self.preview_chars));
+        info!("Spilled {} bytes from {} to {}", content.len(), author, path.display());
+        Ok(Some(SpilledOutput { path, bytes: content.len(), tokens, reference }))
+    }
+
+    /// `content`, or its reference when it spills. If the file cannot be written, the output is kept
+    /// inline and a warning logged, since losing it would be worse than a large context.
+    pub fn apply(&self, author: &str, content: String) -> String {
+        match self.spill(author, &content) {
+            Ok(Some(spilled)) => spilled.reference,
+            Ok(None) => content,
+            Err(err) => {
+                warn!("Keeping {} bytes from {} inline; spilling failed: {}", content.len(), author, err);
+                content
+            }
+        }
+    }
+
+    /// The canonical path of the file behind a spill reference, or `None` if `text` is not one. A reference
+    /// to a file outside `dir`, e.g. one edited by a model or a user, is a `PermissionDenied` error.
+    pub fn reference_path(&self, text: &str) -> io::Result<Option<PathBuf>> {
+        let header = match text.strip_prefix(SPILL_MARKER).and_then(|rest| rest.lines().next()) {
+            Some(header) => header,
+            None => return Ok(None),
+        };
+        let path = match header.rsplit_once(" | ") {
+            Some((path, _)) => Path::new(path).canonicalize()?,
+            None => return Ok(None),
+        };
+        if !path.starts_with(self.dir.canonicalize()?) {
+            return Err(io::Error::new(
+                io::ErrorKind::PermissionDenied,
+                format!("spill reference {} is outside {}", path.display(), self.dir.display()),
+            ));
+        }
+        Ok(Some(path))
+    }
+
+    /// The full output behind a spill reference, decrypted if needed. `None` if `text` is not one.
+    pub fn expand(&self, text: &str) -> io::Result<Option<String>> {
+        let path = match self.reference_path(text)? {
+            Some(path) => path,
+            None => return Ok(None),
+        };
+        let bytes = match &self.cipher {
+            Some(cipher) => cipher.read_file(&path)?,
+            None => fs::read(&path)?,
+        };
+        String::from_utf8(bytes).map(Some).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+}
+
+fn main() -> io::Result<()> {
+    let spillover = Spillover::new("agent_workspace/spill").with_threshold(100);
+    let report = format!("# Q3 Review\n## Revenue\n{}", "Revenue grew 12% on strong renewals.\n".repeat(200));
+    let kept = spillover.apply("Analyst", report);
+    println!("{}", kept);
+    println!("{} bytes behind it", spillover.expand(&kept)?.map_or(0, |full| full.len()));
+    Ok(())
+}
+```
+
+### Notes
+* The threshold is in estimated tokens (see `estimate_tokens`), not the provider's count, so a spill
+  decision does not depend on which tokenizer a conversation uses.
+* The outline and preview are taken from the text, not produced by a model, so spilling costs no extra
+  call. An agent that needs the detail reads the file, e.g. with the file reading tool, or the caller
+  uses `expand`.
+* With a redactor, `expand` returns the redacted text; the original is only in the sealed sibling.
+* Spilled files are not deleted with the conversation or run. They live in `dir` until the workspace
+  is cleaned.
+* A reference is about `preview_chars` plus a few hundred characters. Keep the threshold well above
+  that, or a spill will not save anything.
@@ -3,6 +3,7 @@
 // Reasoning: The provided Python code can be partially converted to Rust, but some libraries and frameworks used in the code (e.g., OpenAI API, AgentRegistry) are not directly compatible with Rust. 
 //             Some modifications may be required to achieve the same functionality in Rust.
 
+use crate::swarms::text::context_budget::ContextBudget;
 use std::collections::HashMap;
 use std::env;
 use std::fs;
@@ -21,7 +22,7 @@ pub struct Agent {
     saved_state_path: String,
     user_name: String,
     retry_attempts: i32,
-    context_length: i32,
+    budget: ContextBudget,
 }
 
 impl Agent {
@@ -37,7 +38,7 @@ impl Agent {
         saved_state_path: String,
         user_name: String,
         retry_attempts: i32,
-        context_length: i32,
+        budget: ContextBudget,
     ) -> Self {
         Agent {
             agent_name,
@@ -51,7 +52,7 @@ impl Agent {
             saved_state_path,
             user_name,
             retry_attempts,
-            context_length,
+            budget,
         }
     }
 
@@ -126,7 +127,7 @@ fn create_agent(
         format!("{}.json", name),
         "swarms_corp".to_string(),
         1,
-        200000,
+        ContextBudget::new(200_000, 8_192),
     );
 
     agent
@@ -16,6 +16,9 @@ use std::io;
 use std::process::Command;
 use clap::{App, Arg};
 use colored::*;
+use crate::swarms::structs::debugger::Debugger;
+use crate::swarms::structs::run_diff::diff_command;
+use crate::swarms::structs::run_manifest::verify_command;
 
 // Define error type
 #[derive(Debug)]
@@ -64,6 +67,9 @@ fn create_command_table() {
         ("auto-upgrade", "Update Swarms to the latest version"),
         ("book-call", "Schedule a strategy session with our team"),
         ("autoswarm", "Generate and execute an autonomous swarm"),
+        ("verify", "Check a run manifest's signature and the files it covers"),
+        ("diff", "Compare two runs of the same swarm step by step"),
+        ("debug", "Step through a recorded run and inspect each prompt"),
     ];
     println!("\nAvailable Commands:");
     for (cmd, desc) in commands {
@@ -148,6 +154,22 @@ fn main() {
             .long("model")
             .takes_value(true)
             .help("Model for autoswarm"))
+        .arg(Arg::with_name("public_key")
+            .long("public-key")
+            .takes_value(true)
+            .help("Base64 public key to verify a run manifest against"))
+        .arg(Arg::with_name("events")
+            .long("events")
+            .takes_value(true)
+            .help("Event log to check against a run manifest"))
+        .arg(Arg::with_name("config")
+            .long("config")
+            .takes_value(true)
+            .help("Run configuration (JSON) to check against a run manifest"))
+        .arg(Arg::with_name("pricing")
+            .long("pricing")
+            .takes_value(true)
+            .help("Pricing table (JSON) to cost the steps of compared runs"))
         .get_matches();
 
     let command: Vec<String> = matches.values_of("command").unwrap().map(|s| s.to_string()).collect();
@@ -185,6 +207,49 @@ fn main() {
                 println!("Please provide task and model for autoswarm.");
             }
         },
+        "verify" => {
+            let (Some(manifest), Some(public_key)) = (command.get(1), matches.value_of("public_key")) else {
+                show_error("Usage: swarms verify <manifest> --public-key <base64>", "Optionally add --events <file> and --config <file>.");
+                std::process::exit(2);
+            };
+            match verify_command(manifest, public_key, matches.value_of("events"), matches.value_of("config")) {
+                Ok(summary) => println!("{}", summary.green()),
+                Err(err) => {
+                    show_error(&format!("Verification failed: {}", err), "The manifest or the files it covers have been altered, or the key is wrong.");
+                    std::process::exit(1);
+                }
+            }
+        },
+        "diff" => {
+            let (Some(run_a), Some(run_b)) = (command.get(1), command.get(2)) else {
+                show_error("Usage: swarms diff <run_a> <run_b>", "Optionally add --pricing <file> to cost steps the traces did not price.");
+                std::process::exit(2);
+            };
+            match diff_command(run_a, run_b, matches.value_of("pricing")) {
+                Ok(report) => print!("{}", report),
+                Err(err) => {
+                    show_error(&format!("Diff failed: {}", err), "Both runs must be JSONL run traces.");
+                    std::process::exit(1);
+                }
+            }
+        },
+        "debug" => {
+            let Some(trace) = command.get(1) else {
+                show_error("Usage: swarms debug <trace>", "Type `help` at the (debug) prompt for the commands.");
+                std::process::exit(2);
+            };
+            let mut debugger = match Debugger::load(trace) {
+                Ok(debugger) => debugger,
+                Err(err) => {
+                    show_error(&format!("Could not open the trace: {}", err), "The trace must be a JSONL run trace.");
+                    std::process::exit(1);
+                }
+            };
+            if let Err(err) = debugger.repl(io::stdin().lock(), io::stdout()) {
+                show_error(&format!("Debugger stopped: {}", err), "");
+                std::process::exit(1);
+            }
+        },
         _ => println!("Unknown command."),
     }
 }
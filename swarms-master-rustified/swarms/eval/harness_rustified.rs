@@ -0,0 +1,623 @@
+### Overview
+There is no Python counterpart; the Python repo checks prompts and flows by running examples and reading the
+output. `EvalHarness` turns that into a number. It runs an agent or swarm over a golden dataset, scores
+every answer, and writes a report that can be diffed against the last one.
+
+**Dataset.** A JSONL file with one `EvalCase` per line:
+
+```json
+{"id": "capital-fr", "task": "What is the capital of France?", "expected": "Paris"}
+{"id": "iso-date", "task": "Today's date as ISO 8601", "criteria": [{"type": "regex", "pattern": "^\\d{4}-\\d{2}-\\d{2}$"}]}
+{"task": "Explain recursion to a child", "criteria": [{"type": "judge", "rubric": "Simple words, one analogy, under 100 words"}]}
+```
+
+A case without an `id` is named after its line number. A case with `expected` and no `criteria` is scored
+by exact match after normalization (trimmed, lowercased, whitespace collapsed). Regexes are compiled and
+ids are checked for duplicates when the file is loaded, so a broken dataset fails before any model call.
+
+**Metrics.**
+* `exact_match` compares the answer to `expected`, optionally normalized. It scores 1 or 0.
+* `regex` passes when the pattern matches anywhere in the answer. It scores 1 or 0.
+* `judge` sends the task, rubric, expected answer, and answer to a judge model. The judge must reply with
+  a `SCORE: n` line, where n is 0 to 10. The score is n/10, and the case passes at `pass_score`
+  (default 0.7).
+
+A case passes when every criterion passes. A case whose run fails, or that needs a judge when none is
+configured, counts as errored rather than failed.
+
+**Subjects.** `run` takes the system under test as a closure from task to answer. For a live run, that
+closure wraps an agent's provider or a `SequentialWorkflow::run_with`. For an offline run,
+`ReplayResponder` answers from recorded outputs, which makes the judge and report testable without a
+provider.
+
+**Report.** `EvalReport` has the pass rate, mean score, a summary per metric, and one `CaseResult` per
+case. A failed exact match includes a line diff of expected against actual. `save` writes JSON,
+`to_markdown` renders a table for a PR comment, and `compare` lists the cases that regressed or were
+fixed against a baseline report.
+
+### Rust Code
+```rust
+use log::{info, warn};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+pub const DEFAULT_JUDGE_PASS_SCORE: f64 = 0.7;
+
+pub const JUDGE_PROMPT: &str = "You are grading an AI system's answer against a rubric. Read the task, the \
+    rubric, the reference answer if there is one, and the answer. Reply with a first line of the form \
+    `SCORE: n`, where n is an integer from 0 (fails the rubric entirely) to 10 (meets it fully), then one \
+    or two sentences explaining the score.";
+
+fn default_pass_score() -> f64 {
+    DEFAULT_JUDGE_PASS_SCORE
+}
+
+/// How one answer is scored.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Criterion {
+    ExactMatch {
+        expected: String,
+        #[serde(default)]
+        normalize: bool,
+    },
+    Regex {
+        pattern: String,
+    },
+    Judge {
+        rubric: String,
+        #[serde(default = "default_pass_score")]
+        pass_score: f64,
+    },
+}
+
+impl Criterion {
+    pub fn metric(&self) -> &'static str {
+        match self {
+            Criterion::ExactMatch { .. } => "exact_match",
+            Criterion::Regex { .. } => "regex",
+            Criterion::Judge { .. } => "judge",
+        }
+    }
+}
+
+/// One line of a golden dataset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalCase {
+    #[serde(default)]
+    pub id: String,
+    pub task: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub criteria: Vec<Criterion>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl EvalCase {
+    /// The criteria the case is scored by: its own, or a normalized exact match against `expected`.
+    pub fn effective_criteria(&self) -> Vec<Criterion> {
+        match (&self.expected, self.criteria.is_empty()) {
+            (Some(expected), true) => vec![Criterion::ExactMatch { expected: expected.clone(), normalize: true }],
+            _ => self.criteria.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum EvalError {
+    Io(io::Error),
+    Parse { line: usize, message: String },
+    /// The case has neither `expected` nor `criteria`.
+    NoCriteria(String),
+    DuplicateId(String),
+    InvalidRegex { case: String, message: String },
+    EmptyDataset,
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EvalError::Io(e) => write!(f, "failed to read or write the eval file: {}", e),
+            EvalError::Parse { line, message } => write!(f, "dataset line {} is not a valid case: {}", line, message),
+            EvalError::NoCriteria(case) => write!(f, "case '{}' has neither `expected` nor `criteria`", case),
+            EvalError::DuplicateId(case) => write!(f, "case id '{}' is used more than once", case),
+            EvalError::InvalidRegex { case, message } => write!(f, "case '{}' has an invalid regex: {}", case, message),
+            EvalError::EmptyDataset => write!(f, "the dataset has no cases"),
+            EvalError::Json(e) => write!(f, "failed to encode the eval report: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+impl From<io::Error> for EvalError {
+    fn from(e: io::Error) -> Self {
+        EvalError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for EvalError {
+    fn from(e: serde_json::Error) -> Self {
+        EvalError::Json(e)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct EvalDataset {
+    pub name: String,
+    pub cases: Vec<EvalCase>,
+}
+
+impl EvalDataset {
+    /// Load and check a JSONL dataset. Blank lines are skipped; line numbers in errors start at 1.
+    pub fn from_jsonl(path: impl AsRef<Path>) -> Result<EvalDataset, EvalError> {
+        let path = path.as_ref();
+        let name = path.file_stem().map(|stem| stem.to_string_lossy().into_owned()).unwrap_or_default();
+        EvalDataset::parse(name, &fs::read_to_string(path)?)
+    }
+
+    pub fn parse(name: impl Into<String>, jsonl: &str) -> Result<EvalDataset, EvalError> {
+        let mut cases = Vec::new();
+        for (index, line) in jsonl.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let mut case: EvalCase = serde_json::from_str(line)
+                .map_err(|e| EvalError::Parse { line: index + 1, message: e.to_string() })?;
+            if case.id.trim().is_empty() {
+                case.id = format!("line-{}", index + 1);
+            }
+            cases.push(case);
+        }
+        EvalDataset::new(name, cases)
+    }
+
+    pub fn new(name: impl Into<String>, cases: Vec<EvalCase>) -> Result<EvalDataset, EvalError> {
+        if cases.is_empty() {
+            return Err(EvalError::EmptyDataset);
+        }
+        let mut ids = HashSet::new();
+        for case in &cases {
+            if !ids.insert(case.id.as_str()) {
+                return Err(EvalError::DuplicateId(case.id.clone()));
+            }
+            let criteria = case.effective_criteria();
+            if criteria.is_empty() {
+                return Err(EvalError::NoCriteria(case.id.clone()));
+            }
+            for criterion in &criteria {
+                if let Criterion::Regex { pattern } = criterion {
+                    Regex::new(pattern)
+                        .map_err(|e| EvalError::InvalidRegex { case: case.id.clone(), message: e.to_string() })?;
+                }
+            }
+        }
+        Ok(EvalDataset { name: name.into(), cases })
+    }
+}
+
+/// Answers tasks from recorded outputs, for offline runs.
+#[derive(Debug, Clone, Default)]
+pub struct ReplayResponder {
+    answers: HashMap<String, String>,
+}
+
+impl ReplayResponder {
+    pub fn new(answers: impl IntoIterator<Item = (String, String)>) -> Self {
+        ReplayResponder { answers: answers.into_iter().collect() }
+    }
+
+    /// Load `{"task": ..., "output": ...}` lines, such as the cases of an earlier report.
+    pub fn from_jsonl(path: impl AsRef<Path>) -> Result<Self, EvalError> {
+        #[derive(Deserialize)]
+        struct Recorded {
+            task: String,
+            output: Option<String>,
+        }
+        let mut answers = HashMap::new();
+        for (index, line) in fs::read_to_string(path)?.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            let recorded: Recorded = serde_json::from_str(line)
+                .map_err(|e| EvalError::Parse { line: index + 1, message: e.to_string() })?;
+            if let Some(output) = recorded.output {
+                answers.insert(recorded.task, output);
+            }
+        }
+        Ok(ReplayResponder { answers })
+    }
+
+    pub fn respond(&self, task: &str) -> Result<String, String> {
+        self.answers.get(task).cloned().ok_or_else(|| format!("no recorded answer for task '{}'", task))
+    }
+}
+
+/// The score of one criterion on one answer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CriterionScore {
+    pub metric: String,
+    /// 0.0 to 1.0.
+    pub score: f64,
+    pub passed: bool,
+    /// What was compared, or the judge's explanation.
+    pub detail: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CaseStatus {
+    Passed,
+    Failed,
+    /// The subject or the judge could not produce a result.
+    Errored,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CaseResult {
+    pub id: String,
+    pub task: String,
+    pub status: CaseStatus,
+    pub output: Option<String>,
+    pub error: Option<String>,
+    pub scores: Vec<CriterionScore>,
+    /// `-` expected and `+` actual lines, for failed exact matches.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub diff: Option<String>,
+    pub duration_ms: u64,
+}
+
+impl CaseResult {
+    /// The mean of the criterion scores; 0 for an errored case.
+    pub fn score(&self) -> f64 {
+        if self.status == CaseStatus::Errored || self.scores.is_empty() {
+            return 0.0;
+        }
+        self.scores.iter().map(|score| score.score).sum::<f64>() / self.scores.len() as f64
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MetricSummary {
+    pub cases: usize,
+    pub passed: usize,
+    pub mean_score: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalReport {
+    pub dataset: String,
+    /// Free-form label for what was evaluated, e.g. a prompt version or commit.
+    pub subject: String,
+    /// RFC 3339, UTC.
+    pub started_at: String,
+    pub total: usize,
+    pub passed: usize,
+    pub failed: usize,
+    pub errored: usize,
+    pub pass_rate: f64,
+    pub mean_score: f64,
+    pub metrics: BTreeMap<String, MetricSummary>,
+    pub cases: Vec<CaseResult>,
+}
+
+/// How a report differs from a baseline, case by case.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EvalComparison {
+    pub pass_rate_delta: f64,
+    pub mean_score_delta: f64,
+    /// Passed in the baseline, not now.
+    pub regressions: Vec<String>,
+    /// Passed now, not in the baseline.
+    pub fixes: Vec<String>,
+}
+
+impl EvalReport {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), EvalError> {
+        let path = path.as_ref();
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<EvalReport, EvalError> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    pub fn compare(&self, baseline: &EvalReport) -> EvalComparison {
+        let passed = |report: &EvalReport| -> HashSet<String> {
+            report.cases.iter().filter(|case| case.status == CaseStatus::Passed).map(|case| case.id.clone()).collect()
+        };
+        let (now, before) = (passed(self), passed(baseline));
+        // In case order, current report first, so the lists read like the dataset
+        let in_order = |mut ids: HashSet<&String>| -> Vec<String> {
+            self.cases.iter().chain(&baseline.cases).filter(|case| ids.remove(&case.id)).map(|case| case.id.clone()).collect()
+        };
+        let regressions = in_order(before.difference(&now).collect());
+        let fixes = in_order(now.difference(&before).collect());
+        EvalComparison {
+            pass_rate_delta: self.pass_rate - baseline.pass_rate,
+            mean_score_delta: self.mean_score - baseline.mean_score,
+            regressions,
+            fixes,
+        }
+    }
+
+    /// A summary table and the non-passing cases, for a PR comment or CI log.
+    pub fn to_markdown(&self) -> String {
+        let mut text = format!(
+            "## Eval: {} ({})\n\n{}/{} passed ({:.1}%), {} failed, {} errored, mean score {:.2}\n\n",
+            self.dataset,
+            self.subject,
+            self.passed,
+            self.total,
+            self.pass_rate * 100.0,
+            self.failed,
+            self.errored,
+            self.mean_score
+        );
+        text.push_str("| Metric | Cases | Passed | Mean score |\n|---|---|---|---|\n");
+        for (metric, summary) in &self.metrics {
+            text.push_str(&format!("| {} | {} | {} | {:.2} |\n", metric, summary.cases, summary.passed, summary.mean_score));
+        }
+        for case in self.cases.iter().filter(|case| case.status != CaseStatus::Passed) {
+            text.push_str(&format!("\n### {} ({:?})\n", case.id, case.status));
+            if let Some(error) = &case.error {
+                text.push_str(&format!("Error: {}\n", error));
+            }
+            for score in case.scores.iter().filter(|score| !score.passed) {
+                text.push_str(&format!("- {}: {}\n", score.metric, score.detail));
+            }
+            if let Some(diff) = &case.diff {
+                text.push_str(&format!("```diff\n{}```\n", diff));
+            }
+        }
+        text
+    }
+}
+
+pub struct EvalHarness<'j> {
+    dataset: EvalDataset,
+    subject: String,
+    judge: Option<Box<dyn FnMut(&str) -> Result<String, String> + 'j>>,
+    report_path: Option<PathBuf>,
+}
+
+impl<'j> EvalHarness<'j> {
+    pub fn new(dataset: EvalDataset) -> Self {
+        EvalHarness { dataset, subject: String::new(), judge: None, report_path: None }
+    }
+
+    pub fn subject(mut self, subject: impl Into<String>) -> Self {
+        self.subject = subject.into();
+        self
+    }
+
+    /// The model that scores `judge` criteria.
+    pub fn judge(mut self, judge: impl FnMut(&str) -> Result<String, String> + 'j) -> Self {
+        self.judge = Some(Box::new(judge));
+        self
+    }
+
+    /// Write the report here as JSON after each run.
+    pub fn report_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.report_path = Some(path.into());
+        self
+    }
+
+    /// Run `subject` on every case in order and score the answers.
+    pub fn run(&mut self, mut subject: impl FnMut(&EvalCase) -> Result<String, String>) -> Result<EvalReport, EvalError> {
+        let started_at = chrono::Utc::now().to_rfc3339();
+        let mut cases = Vec::with_capacity(self.dataset.cases.len());
+        for case in self.dataset.cases.clone() {
+            let started = Instant::now();
+            let answer = subject(&case);
+            let mut result = match answer {
+                Ok(output) => self.score(&case, output),
+                Err(error) => {
+                    warn!("Eval case {} errored: {}", case.id, error);
+                    CaseResult {
+                        id: case.id.clone(),
+                        task: case.task.clone(),
+                        status: CaseStatus::Errored,
+                        output: None,
+                        error: Some(error),
+                        scores: Vec::new(),
+                        diff: None,
+                        duration_ms: 0,
+                    }
+                }
+            };
+            result.duration_ms = started.elapsed().as_millis() as u64;
+            cases.push(result);
+        }
+        let report = summarize(&self.dataset.name, &self.subject, started_at, cases);
+        info!(
+            "Eval {} on {}: {}/{} passed, mean score {:.2}",
+            self.subject, report.dataset, report.passed, report.total, report.mean_score
+        );
+        if let Some(path) = &self.report_path {
+            report.save(path)?;
+        }
+        Ok(report)
+    }
+
+    fn score(&mut self, case: &EvalCase, output: String) -> CaseResult {
+        let mut scores = Vec::new();
+        let mut diff = None;
+        let mut error = None;
+        for criterion in case.effective_criteria() {
+            match criterion {
+                Criterion::ExactMatch { expected, normalize } => {
+                    let passed = if normalize { normalized(&expected) == normalized(&output) } else { expected == output };
+                    if !passed {
+                        diff = Some(line_diff(&expected, &output));
+                    }
+                    scores.push(binary("exact_match", passed, format!("expected {:?}", expected)));
+                }
+                Criterion::Regex { pattern } => {
+                    let passed = Regex::new(&pattern).map(|regex| regex.is_match(&output)).unwrap_or(false);
+                    scores.push(binary("regex", passed, format!("pattern {}", pattern)));
+                }
+                Criterion::Judge { rubric, pass_score } => match self.ask_judge(case, &rubric, &output) {
+                    Ok((score, explanation)) => scores.push(CriterionScore {
+                        metric: "judge".to_string(),
+                        score,
+                        passed: score >= pass_score,
+                        detail: explanation,
+                    }),
+                    Err(e) => {
+                        error = Some(e);
+                        break;
+                    }
+                },
+            }
+        }
+        let status = match (&error, scores.iter().all(|score| score.passed)) {
+            (Some(_), _) => CaseStatus::Errored,
+            (None, true) => CaseStatus::Passed,
+            (None, false) => CaseStatus::Failed,
+        };
+        CaseResult {
+            id: case.id.clone(),
+            task: case.task.clone(),
+            status,
+            output: Some(output),
+            error,
+            scores,
+            diff,
+            duration_ms: 0,
+        }
+    }
+
+    // The judge's score in 0.0..=1.0 and its explanation
+    fn ask_judge(&mut self, case: &EvalCase, rubric: &str, output: &str) -> Result<(f64, String), String> {
+        let judge = self.judge.as_mut().ok_or_else(|| "the case needs a judge and none is configured".to_string())?;
+        let mut prompt = format!("{}\n\nTask:\n{}\n\nRubric:\n{}\n", JUDGE_PROMPT, case.task, rubric);
+        if let Some(expected) = &case.expected {
+            prompt.push_str(&format!("\nReference answer:\n{}\n", expected));
+        }
+        prompt.push_str(&format!("\nAnswer:\n{}\n", output));
+        let reply = judge(&prompt).map_err(|e| format!("judge failed: {}", e))?;
+        parse_judge_reply(&reply).ok_or_else(|| format!("judge reply has no `SCORE: n` line: {:?}", reply))
+    }
+}
+
+/// The score from a judge reply's `SCORE: n` line, scaled to 0.0..=1.0, and the rest of the reply.
+pub fn parse_judge_reply(reply: &str) -> Option<(f64, String)> {
+    let pattern = Regex::new(r"(?im)^\s*\**score\**\s*:\s*(\d+(?:\.\d+)?)").expect("valid regex");
+    let captures = pattern.captures(reply)?;
+    let score: f64 = captures[1].parse().ok()?;
+    let explanation = reply.replacen(&captures[0], "", 1).trim().to_string();
+    Some(((score / 10.0).clamp(0.0, 1.0), explanation))
+}
+
+fn binary(metric: &str, passed: bool, detail: String) -> CriterionScore {
+    CriterionScore { metric: metric.to_string(), score: if passed { 1.0 } else { 0.0 }, passed, detail }
+}
+
+fn normalized(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// A line diff of `expected` against `actual`: unchanged lines start with two spaces, removed ones with
+/// `- `, added ones with `+ `.
+pub fn line_diff(expected: &str, actual: &str) -> String {
+    let (old, new): (Vec<&str>, Vec<&str>) = (expected.lines().collect(), actual.lines().collect());
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+    let (mut i, mut j, mut diff) = (0, 0, String::new());
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push_str(&format!("  {}\n", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push_str(&format!("- {}\n", old[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+ {}\n", new[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
+fn summarize(dataset: &str, subject: &str, started_at: String, cases: Vec<CaseResult>) -> EvalReport {
+    let count = |status: CaseStatus| cases.iter().filter(|case| case.status == status).count();
+    let (passed, failed, errored) = (count(CaseStatus::Passed), count(CaseStatus::Failed), count(CaseStatus::Errored));
+    let total = cases.len();
+    let mut metrics: BTreeMap<String, MetricSummary> = BTreeMap::new();
+    for score in cases.iter().flat_map(|case| &case.scores) {
+        let summary = metrics.entry(score.metric.clone()).or_default();
+        summary.cases += 1;
+        summary.passed += usize::from(score.passed);
+        summary.mean_score += score.score;
+    }
+    for summary in metrics.values_mut() {
+        summary.mean_score /= summary.cases as f64;
+    }
+    let ratio = |part: f64| if total == 0 { 0.0 } else { part / total as f64 };
+    EvalReport {
+        dataset: dataset.to_string(),
+        subject: subject.to_string(),
+        started_at,
+        total,
+        passed,
+        failed,
+        errored,
+        pass_rate: ratio(passed as f64),
+        mean_score: ratio(cases.iter().map(CaseResult::score).sum()),
+        metrics,
+        cases,
+    }
+}
+
+fn main() -> Result<(), EvalError> {
+    env_logger::init();
+    let dataset = EvalDataset::parse(
+        "smoke",
+        r#"{"id": "capital-fr", "task": "What is the capital of France?", "expected": "Paris"}
+{"id": "year", "task": "What year did Apollo 11 land?", "criteria": [{"type": "regex", "pattern": "\\b1969\\b"}]}
+{"id": "recursion", "task": "Explain recursion to a child", "criteria": [{"type": "judge", "rubric": "Simple words and one analogy"}]}"#,
+    )?;
+    let recorded = ReplayResponder::new([
+        ("What is the capital of France?".to_string(), "paris".to_string()),
+        ("What year did Apollo 11 land?".to_string(), "It landed in July 1969.".to_string()),
+        ("Explain recursion to a child".to_string(), "Like Russian dolls, each one holds a smaller one.".to_string()),
+    ]);
+    let mut harness = EvalHarness::new(dataset)
+        .subject("prompt-v2")
+        .judge(|_prompt| Ok("SCORE: 8\nSimple and uses one analogy.".to_string()))
+        .report_to("eval_reports/smoke.json");
+    let report = harness.run(|case| recorded.respond(&case.task))?;
+    println!("{}", report.to_markdown());
+    Ok(())
+}
+```
+
+### Notes
+- Cases run one at a time, in file order, so a replayed or rate-limited run is reproducible. Run several
+  harnesses in parallel, one per dataset shard, if a large dataset is too slow.
+- The subject closure gets the whole `EvalCase`, not just the task, so it can use `tags` to pick an agent.
+- A judge is a model too. Pin its model and temperature, and re-baseline when the judge changes, or score
+  changes will reflect the judge rather than the subject.
+- A saved report's cases hold `task` and `output`, so `ReplayResponder::from_jsonl` can replay an earlier
+  run once the cases are written one per line (`jq -c '.cases[]'`). That re-scores old answers against
+  new criteria without calling the subject again.
@@ -0,0 +1,358 @@
+### Overview
+There is no Python counterpart; Python users find out a long swarm run has finished by watching the logs.
+This module adds notification sinks that can be attached to a swarm run. Each sink is told about
+`RunEvent`s (completion, failure, budget exceeded) with a short summary and a link to the run report.
+Sinks are configured in the `notifications` list of `SwarmConfig`; a failing sink is logged and never
+fails the run itself.
+
+`SequentialWorkflow::with_notifier` and `TaskQueueSwarm::with_notifier` attach a `Notifier`, and their runs
+report how they ended through `notify_detached`. `workflow_with_agents` attaches the sinks of a config's
+`notifications` list, so every workflow built from a config notifies them.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, RestClient, RestError};
+use crate::swarms::structs::failure::FailureCode;
+use async_trait::async_trait;
+use lettre::message::Mailbox;
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+// How much of a final answer or error goes into a summary
+const SUMMARY_CHARS: usize = 500;
+
+/// A point in a swarm run's lifecycle worth telling someone about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RunEvent {
+    Completed,
+    Failed,
+    BudgetExceeded,
+}
+
+impl RunEvent {
+    pub const ALL: [RunEvent; 3] = [RunEvent::Completed, RunEvent::Failed, RunEvent::BudgetExceeded];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RunEvent::Completed => "completed",
+            RunEvent::Failed => "failed",
+            RunEvent::BudgetExceeded => "budget_exceeded",
+        }
+    }
+}
+
+/// What a sink is told about a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunSummary {
+    pub swarm_name: String,
+    pub run_id: String,
+    pub event: RunEvent,
+    /// A few lines: the final answer's opening, the error, or the budget that was hit.
+    pub summary: String,
+    pub report_url: Option<String>,
+    pub duration_secs: f64,
+    pub cost_usd: Option<f64>,
+}
+
+impl RunSummary {
+    /// The summary of a run that ended with `outcome` after `duration`: the opening of its answer, or its
+    /// error. An error classified as `budget_exceeded` is reported as `RunEvent::BudgetExceeded`.
+    pub fn of_outcome(swarm_name: &str, run_id: &str, outcome: Result<&str, &str>, duration: Duration) -> Self {
+        let (event, text) = match outcome {
+            Ok(output) => (RunEvent::Completed, output),
+            Err(error) if FailureCode::classify_message(error) == FailureCode::BudgetExceeded => {
+                (RunEvent::BudgetExceeded, error)
+            }
+            Err(error) => (RunEvent::Failed, error),
+        };
+        let mut summary: String = text.chars().take(SUMMARY_CHARS).collect();
+        if summary.len() < text.len() {
+            summary.push_str("...");
+        }
+        RunSummary {
+            swarm_name: swarm_name.to_string(),
+            run_id: run_id.to_string(),
+            event,
+            summary,
+            report_url: None,
+            duration_secs: duration.as_secs_f64(),
+            cost_usd: None,
+        }
+    }
+
+    pub fn subject(&self) -> String {
+        format!("[swarms] {} {} ({})", self.swarm_name, self.event.as_str().replace('_', " "), self.run_id)
+    }
+
+    /// Plain-text body shared by all sinks.
+    pub fn body(&self) -> String {
+        let mut body = format!("{}\n\nDuration: {:.1}s", self.summary.trim(), self.duration_secs);
+        if let Some(cost) = self.cost_usd {
+            body.push_str(&format!("\nCost: ${:.4}", cost));
+        }
+        if let Some(url) = &self.report_url {
+            body.push_str(&format!("\nReport: {}", url));
+        }
+        body
+    }
+}
+
+#[derive(Debug)]
+pub enum NotificationError {
+    MissingConfig(String),
+    Rest(RestError),
+    Email(String),
+}
+
+impl fmt::Display for NotificationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotificationError::MissingConfig(what) => write!(f, "notification sink is missing {}", what),
+            NotificationError::Rest(err) => write!(f, "webhook delivery failed: {}", err),
+            NotificationError::Email(msg) => write!(f, "email delivery failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for NotificationError {}
+
+impl From<RestError> for NotificationError {
+    fn from(err: RestError) -> Self {
+        NotificationError::Rest(err)
+    }
+}
+
+#[async_trait]
+pub trait NotificationSink: Send + Sync {
+    fn name(&self) -> &str;
+    async fn notify(&self, run: &RunSummary) -> Result<(), NotificationError>;
+}
+
+/// Posts to a Slack incoming webhook.
+pub struct SlackWebhookSink {
+    rest: RestClient,
+}
+
+impl SlackWebhookSink {
+    pub fn new(webhook_url: impl Into<String>) -> Self {
+        SlackWebhookSink { rest: RestClient::new(webhook_url, AuthStrategy::None) }
+    }
+}
+
+#[async_trait]
+impl NotificationSink for SlackWebhookSink {
+    fn name(&self) -> &str {
+        "slack"
+    }
+
+    async fn notify(&self, run: &RunSummary) -> Result<(), NotificationError> {
+        let icon = match run.event {
+            RunEvent::Completed => ":white_check_mark:",
+            RunEvent::Failed => ":x:",
+            RunEvent::BudgetExceeded => ":warning:",
+        };
+        let body = json!({ "text": format!("{} *{}*\n{}", icon, run.subject(), run.body()) });
+        // Slack answers with a plain-text "ok", so skip JSON decoding.
+        self.rest.call_raw(Endpoint::post(""), &[], &(), Some(&body)).await?;
+        Ok(())
+    }
+}
+
+/// Sends a plain-text email over SMTP (STARTTLS).
+pub struct SmtpEmailSink {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+    to: Vec<Mailbox>,
+}
+
+impl SmtpEmailSink {
+    pub fn new(
+        host: &str,
+        port: u16,
+        credentials: Option<(String, String)>,
+        from: &str,
+        to: &[String],
+    ) -> Result<Self, NotificationError> {
+        let mut builder = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(host)
+            .map_err(|e| NotificationError::Email(e.to_string()))?
+            .port(port);
+        if let Some((user, password)) = credentials {
+            builder = builder.credentials(Credentials::new(user, password));
+        }
+        let parse = |addr: &str| addr.parse::<Mailbox>().map_err(|e| NotificationError::Email(format!("{}: {}", addr, e)));
+        if to.is_empty() {
+            return Err(NotificationError::MissingConfig("recipients".to_string()));
+        }
+        Ok(SmtpEmailSink {
+            transport: builder.build(),
+            from: parse(from)?,
+            to: to.iter().map(|a| parse(a)).collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+#[async_trait]
+impl NotificationSink for SmtpEmailSink {
+    fn name(&self) -> &str {
+        "email"
+    }
+
+    async fn notify(&self, run: &RunSummary) -> Result<(), NotificationError> {
+        let mut message = Message::builder().from(self.from.clone()).subject(run.subject());
+        for recipient in &self.to {
+            message = message.to(recipient.clone());
+        }
+        let message = message.body(run.body()).map_err(|e| NotificationError::Email(e.to_string()))?;
+        self.transport.send(message).await.map_err(|e| NotificationError::Email(e.to_string()))?;
+        Ok(())
+    }
+}
+
+fn default_events() -> Vec<RunEvent> {
+    RunEvent::ALL.to_vec()
+}
+
+fn default_smtp_port() -> u16 {
+    587
+}
+
+/// A sink as written in `SwarmConfig.notifications`. Secrets are named by
+/// environment variable rather than stored in the YAML.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum NotificationConfig {
+    Slack {
+        /// Environment variable holding the webhook URL.
+        webhook_url_env: String,
+        #[serde(default = "default_events")]
+        events: Vec<RunEvent>,
+    },
+    Email {
+        smtp_host: String,
+        #[serde(default = "default_smtp_port")]
+        smtp_port: u16,
+        username_env: Option<String>,
+        password_env: Option<String>,
+        from: String,
+        to: Vec<String>,
+        #[serde(default = "default_events")]
+        events: Vec<RunEvent>,
+    },
+}
+
+fn env_var(name: &str) -> Result<String, NotificationError> {
+    env::var(name).map_err(|_| NotificationError::MissingConfig(format!("environment variable {}", name)))
+}
+
+impl NotificationConfig {
+    pub fn events(&self) -> &[RunEvent] {
+        match self {
+            NotificationConfig::Slack { events, .. } | NotificationConfig::Email { events, .. } => events,
+        }
+    }
+
+    pub fn build(&self) -> Result<Arc<dyn NotificationSink>, NotificationError> {
+        match self {
+            NotificationConfig::Slack { webhook_url_env, .. } => Ok(Arc::new(SlackWebhookSink::new(env_var(webhook_url_env)?))),
+            NotificationConfig::Email { smtp_host, smtp_port, username_env, password_env, from, to, .. } => {
+                let credentials = match (username_env, password_env) {
+                    (Some(user), Some(password)) => Some((env_var(user)?, env_var(password)?)),
+                    _ => None,
+                };
+                Ok(Arc::new(SmtpEmailSink::new(smtp_host, *smtp_port, credentials, from, to)?))
+            }
+        }
+    }
+}
+
+/// The sinks attached to a swarm run, each with the events it wants.
+#[derive(Clone, Default)]
+pub struct Notifier {
+    sinks: Vec<(Vec<RunEvent>, Arc<dyn NotificationSink>)>,
+}
+
+impl Notifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_sink(mut self, events: &[RunEvent], sink: Arc<dyn NotificationSink>) -> Self {
+        self.sinks.push((events.to_vec(), sink));
+        self
+    }
+
+    pub fn from_configs(configs: &[NotificationConfig]) -> Result<Self, NotificationError> {
+        configs
+            .iter()
+            .try_fold(Notifier::new(), |notifier, config| Ok(notifier.with_sink(config.events(), config.build()?)))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sinks.is_empty()
+    }
+
+    /// Deliver `run` to every interested sink concurrently. Failures are logged, not returned.
+    pub async fn notify(&self, run: &RunSummary) {
+        let deliveries = self
+            .sinks
+            .iter()
+            .filter(|(events, _)| events.contains(&run.event))
+            .map(|(_, sink)| async move { (sink.name().to_string(), sink.notify(run).await) });
+        for (name, result) in futures::future::join_all(deliveries).await {
+            match result {
+                Ok(()) => info!("Sent {} notification for run {}", name, run.run_id),
+                Err(err) => warn!("{} notification for run {} failed: {}", name, run.run_id, err),
+            }
+        }
+    }
+
+    /// `notify` in the background on the current Tokio runtime, for run loops that are not async. Without a
+    /// runtime there is nothing to deliver on, so the event is dropped with a warning.
+    pub fn notify_detached(&self, run: RunSummary) {
+        if !self.sinks.iter().any(|(events, _)| events.contains(&run.event)) {
+            return;
+        }
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let notifier = self.clone();
+                handle.spawn(async move { notifier.notify(&run).await });
+            }
+            Err(_) => warn!("No Tokio runtime to notify {} of run {} on", run.event.as_str(), run.run_id),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let configs: Vec<NotificationConfig> = serde_yaml::from_str(
+        "- type: slack\n  webhook_url_env: SLACK_WEBHOOK_URL\n  events: [failed, budget_exceeded]\n",
+    )?;
+    let notifier = Notifier::from_configs(&configs)?;
+    notifier
+        .notify(&RunSummary {
+            swarm_name: "Financial-Analysis-Swarm".to_string(),
+            run_id: "run-42".to_string(),
+            event: RunEvent::Failed,
+            summary: "Agent Financial-Analyst exhausted its retries.".to_string(),
+            report_url: Some("https://reports.example.com/run-42".to_string()),
+            duration_secs: 83.2,
+            cost_usd: Some(0.0431),
+        })
+        .await;
+    Ok(())
+}
+```
+
+### Notes
+* Email uses `lettre` with STARTTLS; port 465 (implicit TLS) servers are not supported by this sink.
+* Deliveries are not retried; a sink that is down simply misses the event.
+* A config whose sinks cannot be built (a missing environment variable, a bad address) still runs; the
+  workflow is built without them and the error is logged.
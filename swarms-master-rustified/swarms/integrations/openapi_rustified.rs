@@ -0,0 +1,390 @@
+### Overview
+There is no Python counterpart. FastAPI generated the Python server's OpenAPI document from its route
+decorators and served it at `/docs`. The Rust handlers are transport-agnostic `handle_http` methods, so
+nothing can be inferred from routing. `api_spec()` builds the OpenAPI 3.1 document with `utoipa`
+instead:
+
+* Schemas come from `ToSchema` derives on the wire types: `Problem`, `Page`, `StoredAgent`,
+  `TaskRecord`, `AuditEvent`, `ApprovalRequest`, `DecisionBody`, `DemoConfig`, and `ComponentStatus`.
+  The derives are behind the `openapi` feature, so builds without it carry no `utoipa` code.
+* Operations are listed here, next to one another, one per route that a `handle_http` serves. Every
+  operation has a `default` response of `application/problem+json` (see `problem`), except the
+  OpenAI-compatible `/v1` routes, which answer in OpenAI's error envelope (see `chat_completions`).
+
+`ApiDocs::handle_http` serves the document at `/openapi.json` and a ReDoc page for it at `/docs`. The
+page is the `/docs` route that the API test client probes.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+use crate::swarms::structs::agent_store::StoredAgent;
+use crate::swarms::structs::approval_gate::{ApprovalRequest, DecisionBody};
+use crate::swarms::structs::audit::{AuditAction, AuditEvent};
+use crate::swarms::structs::demo_mode::DemoConfig;
+use crate::swarms::structs::health::{ComponentKind, ComponentState, ComponentStatus};
+use crate::swarms::structs::pagination::Page;
+use utoipa::openapi::path::{HttpMethod, Operation, OperationBuilder, Parameter, ParameterBuilder, ParameterIn};
+use utoipa::openapi::request_body::RequestBodyBuilder;
+use utoipa::openapi::schema::{ArrayBuilder, ObjectBuilder, Schema, Type};
+use utoipa::openapi::{Content, ContentBuilder, Ref, RefOr, Required, Response, ResponseBuilder};
+use utoipa::{OpenApi, PartialSchema};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "Swarms API", description = "Agents, runs, approvals, and operations endpoints of a swarms server."),
+    components(schemas(
+        Problem, StoredAgent, ApprovalRequest, DecisionBody, AuditEvent, AuditAction, DemoConfig, ComponentStatus,
+        ComponentKind, ComponentState
+    )),
+    tags(
+        (name = "agents", description = "Stored agents of the caller's tenant"),
+        (name = "runs", description = "Tasks submitted to the coordinator"),
+        (name = "approvals", description = "Human review of gated workflow steps"),
+        (name = "audit", description = "The append-only audit log"),
+        (name = "demo", description = "Rate-limited public demo sessions"),
+        (name = "openai", description = "Agents and swarms behind the OpenAI chat completions API"),
+        (name = "operations", description = "Health, metrics, and this document")
+    )
+)]
+struct ApiDoc;
+
+fn operation(operation_id: &str, tag: &str, summary: &str) -> OperationBuilder {
+    OperationBuilder::new()
+        .operation_id(Some(operation_id))
+        .tag(tag)
+        .summary(Some(summary))
+        .response("default", problem_response())
+}
+
+/// An operation on the OpenAI-compatible routes, whose failures are in OpenAI's error envelope.
+fn openai_operation(operation_id: &str, summary: &str) -> OperationBuilder {
+    OperationBuilder::new()
+        .operation_id(Some(operation_id))
+        .tag("openai")
+        .summary(Some(summary))
+        .response("default", json("The failure, in OpenAI's `{\"error\": {...}}` envelope.", object_with("error", Type::Object)))
+}
+
+fn query(name: &str, schema_type: Type, description: &str) -> Parameter {
+    ParameterBuilder::new()
+        .name(name)
+        .parameter_in(ParameterIn::Query)
+        .required(Required::False)
+        .description(Some(description))
+        .schema(Some(of_type(schema_type)))
+        .build()
+}
+
+fn path_param(name: &str) -> Parameter {
+    ParameterBuilder::new()
+        .name(name)
+        .parameter_in(ParameterIn::Path)
+        .required(Required::True)
+        .schema(Some(of_type(Type::String)))
+        .build()
+}
+
+/// `limit`, `cursor`, and `sort` on a paged list (see `pagination`).
+fn page_params(sorts: &str) -> Vec<Parameter> {
+    vec![
+        query("limit", Type::Integer, "Page size, 1 to 200. Defaults to 50."),
+        query("cursor", Type::String, "`next_cursor` of the previous page."),
+        query("sort", Type::String, &format!("One of {}; prefix with `-` for descending.", sorts)),
+    ]
+}
+
+fn content_of(schema: impl Into<RefOr<Schema>>) -> Content {
+    ContentBuilder::new().schema(Some(schema)).build()
+}
+
+fn response(description: &str, content_type: &str, schema: impl Into<RefOr<Schema>>) -> Response {
+    ResponseBuilder::new().description(description).content(content_type, content_of(schema)).build()
+}
+
+fn json(description: &str, schema: impl Into<RefOr<Schema>>) -> Response {
+    response(description, "application/json", schema)
+}
+
+fn text(description: &str, content_type: &str) -> Response {
+    response(description, content_type, of_type(Type::String))
+}
+
+fn of_type(schema_type: Type) -> ObjectBuilder {
+    ObjectBuilder::new().schema_type(schema_type)
+}
+
+/// An object with one required property.
+fn object_with(property: &str, schema_type: Type) -> ObjectBuilder {
+    ObjectBuilder::new().property(property, of_type(schema_type)).required(property)
+}
+
+fn schema_ref(name: &str) -> Ref {
+    Ref::from_schema_name(name)
+}
+
+fn array_of(schema_name: &str) -> ArrayBuilder {
+    ArrayBuilder::new().items(schema_ref(schema_name))
+}
+
+fn problem_response() -> Response {
+    response("The failure, as an RFC 9457 problem document.", PROBLEM_JSON, schema_ref("Problem"))
+}
+
+/// The required fields. `stream` and `stream_options` are read as well; other OpenAI fields are ignored.
+fn chat_completion_request() -> ObjectBuilder {
+    object_with("model", Type::String).property("messages", ArrayBuilder::new()).required("messages")
+}
+
+fn health_report() -> ObjectBuilder {
+    ObjectBuilder::new()
+        .property("status", of_type(Type::String).enum_values(Some(["ok", "ready", "not_ready"])))
+        .required("status")
+        .property("components", array_of("ComponentStatus"))
+        .required("components")
+}
+
+fn routes() -> Vec<(&'static str, HttpMethod, Operation)> {
+    let mut routes = vec![
+        (
+            "/users/me/agents",
+            HttpMethod::Get,
+            operation("listAgents", "agents", "List the caller's agents, one page at a time")
+                .parameter(query("tag", Type::String, "Only agents with this capability."))
+                .parameter(query("model", Type::String, "Only agents on this model."))
+                .parameters(Some(page_params("`name`, `created_at`, `updated_at`")))
+                .response("200", json("One page of agents.", Page::<StoredAgent>::schema()))
+                .build(),
+        ),
+        (
+            "/approvals",
+            HttpMethod::Get,
+            operation("listApprovals", "approvals", "List the gates waiting for a decision")
+                .response("200", json("Pending requests, oldest first.", array_of("ApprovalRequest")))
+                .build(),
+        ),
+        (
+            "/approvals/{run_id}/{gate}",
+            HttpMethod::Post,
+            operation("decideApproval", "approvals", "Approve or reject a pending gate")
+                .parameter(path_param("run_id"))
+                .parameter(path_param("gate"))
+                .request_body(Some(
+                    RequestBodyBuilder::new()
+                        .content("application/json", content_of(schema_ref("DecisionBody")))
+                        .required(Some(Required::True))
+                        .build(),
+                ))
+                .response("200", json("The decision was delivered.", object_with("ok", Type::Boolean)))
+                .build(),
+        ),
+        (
+            "/audit",
+            HttpMethod::Get,
+            operation("queryAudit", "audit", "Query the caller's audit events")
+                .parameters(Some(audit_params()))
+                .response("200", json("Matching events, oldest first.", array_of("AuditEvent")))
+                .build(),
+        ),
+        (
+            "/audit/export",
+            HttpMethod::Get,
+            operation("exportAudit", "audit", "Export the caller's audit events as JSONL")
+                .parameters(Some(audit_params()))
+                .response("200", text("One `AuditEvent` per line.", "application/x-ndjson"))
+                .build(),
+        ),
+        (
+            "/demo/sessions",
+            HttpMethod::Post,
+            operation("openDemoSession", "demo", "Open a demo session for the calling address")
+                .response("201", json("The session id and its limits.", ObjectBuilder::new()))
+                .build(),
+        ),
+        (
+            "/demo/sessions/{session_id}",
+            HttpMethod::Get,
+            operation("getDemoSession", "demo", "Tokens left in a demo session")
+                .parameter(path_param("session_id"))
+                .response("200", json("The remaining budget.", object_with("remaining_tokens", Type::Integer)))
+                .build(),
+        ),
+        (
+            "/demo/limits",
+            HttpMethod::Get,
+            operation("getDemoLimits", "demo", "The limits demo sessions run under")
+                .response("200", json("The demo configuration.", schema_ref("DemoConfig")))
+                .build(),
+        ),
+        (
+            "/v1/models",
+            HttpMethod::Get,
+            openai_operation("listModels", "List the agents and swarms served as models")
+                .response("200", json("A `list` object of `model` objects.", object_with("data", Type::Array)))
+                .build(),
+        ),
+        (
+            "/v1/models/{model}",
+            HttpMethod::Get,
+            openai_operation("getModel", "One served agent or swarm")
+                .parameter(path_param("model"))
+                .response("200", json("A `model` object.", object_with("id", Type::String)))
+                .build(),
+        ),
+        (
+            "/v1/chat/completions",
+            HttpMethod::Post,
+            openai_operation("createChatCompletion", "Run the agent or swarm `model` names on the last user message")
+                .request_body(Some(
+                    RequestBodyBuilder::new()
+                        .content("application/json", content_of(chat_completion_request()))
+                        .required(Some(Required::True))
+                        .build(),
+                ))
+                .response(
+                    "200",
+                    ResponseBuilder::new()
+                        .description("A `chat.completion`, or with `stream: true` its `chat.completion.chunk` events.")
+                        .content("application/json", content_of(object_with("choices", Type::Array)))
+                        .content("text/event-stream", content_of(of_type(Type::String)))
+                        .build(),
+                )
+                .build(),
+        ),
+        (
+            "/healthz",
+            HttpMethod::Get,
+            operation("health", "operations", "Liveness, with the state of every dependency")
+                .response("200", json("The server is up.", health_report()))
+                .build(),
+        ),
+        (
+            "/readyz",
+            HttpMethod::Get,
+            operation("readiness", "operations", "Readiness for load balancers")
+                .response("200", json("Ready to take traffic.", health_report()))
+                .response("503", json("A required dependency is down. Not a problem document.", health_report()))
+                .build(),
+        ),
+        (
+            "/metrics",
+            HttpMethod::Get,
+            operation("metrics", "operations", "Prometheus metrics")
+                .response("200", text("Prometheus text exposition format.", "text/plain; version=0.0.4"))
+                .build(),
+        ),
+        (
+            "/openapi.json",
+            HttpMethod::Get,
+            operation("openApi", "operations", "This document")
+                .response("200", json("The OpenAPI 3.1 document.", ObjectBuilder::new()))
+                .build(),
+        ),
+        (
+            "/docs",
+            HttpMethod::Get,
+            operation("docs", "operations", "API reference page rendered from `/openapi.json`")
+                .response("200", text("An HTML page.", "text/html; charset=utf-8"))
+                .build(),
+        ),
+    ];
+    routes.extend(run_routes());
+    routes
+}
+
+#[cfg(feature = "server")]
+fn run_routes() -> Vec<(&'static str, HttpMethod, Operation)> {
+    use crate::swarms::structs::distributed_queue::TaskRecord;
+
+    vec![(
+        "/runs",
+        HttpMethod::Get,
+        operation("listRuns", "runs", "List the caller's tasks, one page at a time")
+            .parameter(query("status", Type::String, "`queued`, `running`, `completed` or `failed`."))
+            .parameter(query("agent", Type::String, "Only tasks for this agent."))
+            .parameters(Some(page_params("`submitted_at`, `agent_name`, `status`")))
+            .response("200", json("One page of tasks.", Page::<TaskRecord>::schema()))
+            .build(),
+    )]
+}
+
+#[cfg(not(feature = "server"))]
+fn run_routes() -> Vec<(&'static str, HttpMethod, Operation)> {
+    Vec::new()
+}
+
+fn audit_params() -> Vec<Parameter> {
+    vec![
+        query("actor", Type::String, "Only events by this actor."),
+        query("action", Type::String, "Only events with this action, e.g. `gate_approved`."),
+        query("target", Type::String, "Only events on this target."),
+        query("since", Type::String, "Inclusive RFC 3339 lower bound."),
+        query("until", Type::String, "Inclusive RFC 3339 upper bound."),
+        query("limit", Type::Integer, "The most recent `limit` matches."),
+    ]
+}
+
+/// The OpenAPI 3.1 document for every route the crate's handlers serve.
+pub fn api_spec() -> utoipa::openapi::OpenApi {
+    let mut spec = ApiDoc::openapi();
+    for (path, method, operation) in routes() {
+        spec.paths.add_path_operation(path, vec![method], operation);
+    }
+    spec
+}
+
+const DOCS_PAGE: &str = r#"<!DOCTYPE html>
+<html>
+  <head>
+    <title>Swarms API</title>
+    <meta charset="utf-8">
+  </head>
+  <body>
+    <redoc spec-url="openapi.json"></redoc>
+    <script src="https://cdn.redoc.ly/redoc/v2.1.5/bundles/redoc.standalone.js"></script>
+  </body>
+</html>
+"#;
+
+/// Serves the API document. The document is rendered once, when the server starts.
+#[derive(Debug, Clone)]
+pub struct ApiDocs {
+    spec_json: String,
+}
+
+impl Default for ApiDocs {
+    fn default() -> Self {
+        ApiDocs::new()
+    }
+}
+
+impl ApiDocs {
+    pub fn new() -> Self {
+        ApiDocs { spec_json: api_spec().to_pretty_json().expect("the API document always serializes") }
+    }
+
+    /// Serve `GET /openapi.json` and `GET /docs`. Returns a status code, content type, and body.
+    pub fn handle_http(&self, method: &str, path: &str) -> (u16, &'static str, String) {
+        match (method, path.trim_end_matches('/')) {
+            ("GET", "/openapi.json") => (200, "application/json", self.spec_json.clone()),
+            ("GET", "/docs") => (200, "text/html; charset=utf-8", DOCS_PAGE.to_string()),
+            (method, path) => (404, PROBLEM_JSON, Problem::no_route(method, path).to_json()),
+        }
+    }
+}
+
+fn main() {
+    println!("{}", ApiDocs::new().handle_http("GET", "/openapi.json").2);
+}
+```
+
+### Notes
+* A handler and its operation here are changed together. `test_openapi` sends a request to every documented
+  route, so a route that is removed without updating this file fails the tests.
+* Paths are relative to where the server mounts the handlers. That is why the docs page loads
+  `openapi.json` by a relative URL.
+* The demo session body, the agent spec inside `StoredAgent`, and the OpenAI-compatible bodies are
+  documented as plain objects. Their
+  fields follow `DemoGate::handle_http` and `AgentSpec`, and deriving schemas for every nested agent
+  setting was not worth the churn.
+* `scripts/misc/export_openapi` writes the document to a file. CI runs it and generates a typed client
+  from the result (see that script's notes).
@@ -0,0 +1,195 @@
+### Overview
+There is no Python counterpart. The Python API server answers failures with a bare status code and
+FastAPI's `{"detail": ...}`. The Rust API returns an RFC 9457 problem document for every failure
+instead, sent as `application/problem+json`. It includes the standard `type`, `title`, `status` and
+`detail` members and three extensions:
+
+* `code` is a stable, snake_case identifier that clients can match on. Titles and details may be reworded.
+* `request_id` is the same id the `Auditor` stamps on the run's audit events, so a failure report can be
+  traced back to the run.
+* `retriable` tells the client whether sending the same request again can succeed.
+
+A route handler turns its error into a `Problem` with `From` and then calls `with_request_id`. The
+`From` impls here map each `SwarmError` and `AgentError` variant, and `DemoError` has its own in
+`demo_mode`. The crate's own handlers answer with problems too: `AuditLog`, `PendingApprovals`,
+`DemoGate`, `Health`, and `SwarmMetrics` return one for every error status. `Problem::parse` does the
+reverse on the client side and returns `None` for bodies in any other shape.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::AgentError;
+use crate::swarms::structs::swarm::SwarmError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use std::fmt;
+
+pub const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Base of the `type` URIs. Each code has its own page under it.
+pub const PROBLEM_TYPE_BASE: &str = "https://swarms.world/problems/";
+
+/// An RFC 9457 problem document.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub type_uri: String,
+    pub title: String,
+    pub status: u16,
+    /// The message for this occurrence.
+    pub detail: String,
+    pub code: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_id: Option<String>,
+    #[serde(default)]
+    pub retriable: bool,
+    /// Further members for a specific code, such as `retry_after_secs`.
+    #[serde(flatten)]
+    #[cfg_attr(feature = "openapi", schema(ignore))]
+    pub extensions: Map<String, JsonValue>,
+}
+
+impl Problem {
+    pub fn new(status: u16, code: impl Into<String>, title: impl Into<String>, detail: impl Into<String>) -> Self {
+        let code = code.into();
+        Problem {
+            type_uri: format!("{}{}", PROBLEM_TYPE_BASE, code.replace('_', "-")),
+            title: title.into(),
+            status,
+            detail: detail.into(),
+            code,
+            request_id: None,
+            retriable: false,
+            extensions: Map::new(),
+        }
+    }
+
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = Some(request_id.into());
+        self
+    }
+
+    pub fn retriable(mut self, retriable: bool) -> Self {
+        self.retriable = retriable;
+        self
+    }
+
+    pub fn extension(mut self, name: impl Into<String>, value: impl Into<JsonValue>) -> Self {
+        self.extensions.insert(name.into(), value.into());
+        self
+    }
+
+    pub fn bad_request(detail: impl Into<String>) -> Self {
+        Problem::new(400, "invalid_request", "Invalid request", detail)
+    }
+
+    pub fn unauthorized(detail: impl Into<String>) -> Self {
+        Problem::new(401, "unauthorized", "Missing or invalid API key", detail)
+    }
+
+    pub fn not_found(detail: impl Into<String>) -> Self {
+        Problem::new(404, "not_found", "Resource not found", detail)
+    }
+
+    pub fn rate_limited(detail: impl Into<String>) -> Self {
+        Problem::new(429, "rate_limited", "Too many requests", detail).retriable(true)
+    }
+
+    pub fn method_not_allowed(method: &str, path: &str) -> Self {
+        Problem::new(405, "method_not_allowed", "Method not allowed", format!("{} is not allowed on {}", method, path))
+    }
+
+    /// The answer for a path no handler serves.
+    pub fn no_route(method: &str, path: &str) -> Self {
+        Problem::not_found(format!("no route for {} {}", method, path))
+    }
+
+    pub fn internal(detail: impl Into<String>) -> Self {
+        Problem::new(500, "internal_error", "Internal server error", detail)
+    }
+
+    /// The value for the response's `Content-Type` header.
+    pub fn content_type(&self) -> &'static str {
+        PROBLEM_JSON
+    }
+
+    /// The response body.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("a problem always serializes")
+    }
+
+    /// The body for handlers that return a status and a `JsonValue`.
+    pub fn to_value(&self) -> JsonValue {
+        serde_json::to_value(self).expect("a problem always serializes")
+    }
+
+    /// Reads a problem from an error response. Returns `None` if the body has any other shape.
+    pub fn parse(body: &str) -> Option<Problem> {
+        serde_json::from_str(body).ok()
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.code, self.status, self.detail)?;
+        if let Some(request_id) = &self.request_id {
+            write!(f, " [request {}]", request_id)?;
+        }
+        Ok(())
+    }
+}
+
+impl From<&SwarmError> for Problem {
+    fn from(err: &SwarmError) -> Self {
+        match err {
+            SwarmError::NoAgents => Problem::new(422, "no_agents", "The swarm has no agents", err.to_string()),
+            // Panics in a fan-out are usually transient (a provider hiccup surfacing as a panic)
+            SwarmError::Tasks(_) => {
+                Problem::new(502, "agent_tasks_failed", "One or more agents failed", err.to_string()).retriable(true)
+            }
+            SwarmError::Failed(_) => Problem::new(500, "swarm_failed", "The swarm failed", err.to_string()),
+        }
+    }
+}
+
+impl From<SwarmError> for Problem {
+    fn from(err: SwarmError) -> Self {
+        Problem::from(&err)
+    }
+}
+
+impl From<&AgentError> for Problem {
+    fn from(err: &AgentError) -> Self {
+        match err {
+            AgentError::NoModel(_) => Problem::new(503, "agent_unbound", "The agent has no model", err.to_string()),
+            AgentError::Failed { .. } => {
+                Problem::new(502, "agent_failed", "The agent failed", err.to_string()).retriable(true)
+            }
+        }
+    }
+}
+
+impl From<AgentError> for Problem {
+    fn from(err: AgentError) -> Self {
+        Problem::from(&err)
+    }
+}
+
+fn main() {
+    let problem = Problem::from(SwarmError::NoAgents).with_request_id("req-42");
+    println!("Content-Type: {}", problem.content_type());
+    println!("{}", problem.to_json());
+    assert_eq!(Problem::parse(&problem.to_json()), Some(problem));
+}
+```
+
+### Notes
+* The mapping is on the error types, not on routes, so every handler reports the same failure with the
+  same status and code.
+* `Problem` is the wire type only. No server framework is wired up here. Handlers that return a content
+  type return `PROBLEM_JSON`. For handlers that return a status and a `JsonValue`, the server sends
+  `PROBLEM_JSON` for any status of 400 and above. The one exception is the 503 from `/readyz`, which is a
+  health report for load balancers and not an error.
+* The crate's handlers do not see the API request, so the server sets `request_id` on their problems.
+* `AgentError::NoModel` is a 503, not a 4xx: the request is fine, and the deployment is missing a model binding.
+* Clients built on `RestClient` need no changes. `DefaultErrorEnvelope` already reads `detail`.
@@ -0,0 +1,327 @@
+### Overview
+The PropertyRadar client originally lived inside `new_features_examples/real_estate_agent.py`, where every
+failure raised (and, in the first Rust conversion, panicked). This module promotes it to a reusable
+integration: typed errors instead of panics, a `PropertySearch` query builder, and page-by-page and
+collect-all pagination. Requests go through the shared `swarms::integrations::rest` toolkit, so tests and
+offline runs can substitute canned responses via `HttpTransport`.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, HttpTransport, RestClient, RestError, NO_PARAMS};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+pub const PROPERTYRADAR_API_BASE: &str = "https://api.propertyradar.com/v1";
+
+const SEARCH_PROPERTIES: Endpoint = Endpoint::get("/properties");
+
+/// Commercial property categories understood by the API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PropertyType {
+    Office,
+    Retail,
+    Industrial,
+    MixedUse,
+    Land,
+}
+
+impl PropertyType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PropertyType::Office => "office",
+            PropertyType::Retail => "retail",
+            PropertyType::Industrial => "industrial",
+            PropertyType::MixedUse => "mixed-use",
+            PropertyType::Land => "land",
+        }
+    }
+}
+
+/// A commercial listing as returned by `/properties`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PropertyListing {
+    #[serde(rename = "id")]
+    pub property_id: String,
+    pub address: String,
+    pub city: String,
+    pub state: String,
+    pub zip_code: String,
+    pub price: f64,
+    #[serde(rename = "square_feet")]
+    pub square_footage: f64,
+    pub property_type: PropertyType,
+    #[serde(default)]
+    pub zoning: String,
+    #[serde(rename = "list_date")]
+    pub listing_date: String,
+    #[serde(rename = "latitude")]
+    pub lat: f64,
+    #[serde(rename = "longitude")]
+    pub lng: f64,
+    pub description: Option<String>,
+    pub features: Option<Vec<String>>,
+    pub images: Option<Vec<String>>,
+}
+
+#[derive(Debug)]
+pub enum PropertyRadarError {
+    MissingApiKey,
+    /// Pages are numbered from 1.
+    InvalidPage(u32),
+    Rest(RestError),
+}
+
+impl fmt::Display for PropertyRadarError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PropertyRadarError::MissingApiKey => write!(f, "PROPERTYRADAR_API_KEY environment variable is not set"),
+            PropertyRadarError::InvalidPage(page) => write!(f, "invalid page {}: pages are numbered from 1", page),
+            PropertyRadarError::Rest(err) => write!(f, "PropertyRadar: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for PropertyRadarError {}
+
+impl From<RestError> for PropertyRadarError {
+    fn from(err: RestError) -> Self {
+        PropertyRadarError::Rest(err)
+    }
+}
+
+/// Builder for `/properties` search parameters. Defaults match the Python example:
+/// commercial properties for sale in Florida, 20 per page.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertySearch {
+    max_price: f64,
+    property_types: Vec<PropertyType>,
+    state: Option<String>,
+    city: Option<String>,
+    county: Option<String>,
+    radius_miles: Option<f64>,
+    min_sqft: Option<f64>,
+    max_sqft: Option<f64>,
+    page_size: u32,
+}
+
+impl Default for PropertySearch {
+    fn default() -> Self {
+        PropertySearch {
+            max_price: 10_000_000.0,
+            property_types: Vec::new(),
+            state: Some("FL".to_string()),
+            city: None,
+            county: None,
+            radius_miles: None,
+            min_sqft: None,
+            max_sqft: None,
+            page_size: 20,
+        }
+    }
+}
+
+impl PropertySearch {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn max_price(mut self, max_price: f64) -> Self {
+        self.max_price = max_price;
+        self
+    }
+
+    pub fn property_types(mut self, property_types: impl IntoIterator<Item = PropertyType>) -> Self {
+        self.property_types = property_types.into_iter().collect();
+        self
+    }
+
+    pub fn state(mut self, state: impl Into<String>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    pub fn city(mut self, city: impl Into<String>) -> Self {
+        self.city = Some(city.into());
+        self
+    }
+
+    pub fn county(mut self, county: impl Into<String>) -> Self {
+        self.county = Some(county.into());
+        self
+    }
+
+    pub fn radius_miles(mut self, radius_miles: f64) -> Self {
+        self.radius_miles = Some(radius_miles);
+        self
+    }
+
+    pub fn min_sqft(mut self, min_sqft: f64) -> Self {
+        self.min_sqft = Some(min_sqft);
+        self
+    }
+
+    pub fn max_sqft(mut self, max_sqft: f64) -> Self {
+        self.max_sqft = Some(max_sqft);
+        self
+    }
+
+    pub fn page_size(mut self, page_size: u32) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Query-string parameters for `page` (1-based).
+    pub fn to_query(&self, page: u32) -> SearchQuery<'_> {
+        SearchQuery {
+            price_max: self.max_price,
+            page,
+            limit: self.page_size,
+            for_sale: true,
+            commercial_property: true,
+            property_types: (!self.property_types.is_empty()).then(|| &self.property_types[..]),
+            state: self.state.as_deref(),
+            city: self.city.as_deref(),
+            county: self.county.as_deref(),
+            radius_miles: self.radius_miles,
+            square_feet_min: self.min_sqft,
+            square_feet_max: self.max_sqft,
+        }
+    }
+}
+
+/// Typed query string for `GET /properties`.
+#[derive(Debug, Serialize)]
+pub struct SearchQuery<'a> {
+    pub price_max: f64,
+    pub page: u32,
+    pub limit: u32,
+    pub for_sale: bool,
+    pub commercial_property: bool,
+    pub property_types: Option<&'a [PropertyType]>,
+    pub state: Option<&'a str>,
+    pub city: Option<&'a str>,
+    pub county: Option<&'a str>,
+    pub radius_miles: Option<f64>,
+    pub square_feet_min: Option<f64>,
+    pub square_feet_max: Option<f64>,
+}
+
+/// One page of search results.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PropertyPage {
+    pub listings: Vec<PropertyListing>,
+    pub page: u32,
+    /// `None` on the last page.
+    pub next_page: Option<u32>,
+    /// Listings that could not be decoded and were skipped.
+    pub skipped: usize,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    results: Vec<serde_json::Value>,
+    #[serde(default)]
+    total: Option<u64>,
+}
+
+/// Client for the PropertyRadar REST API.
+#[derive(Clone)]
+pub struct PropertyRadarClient {
+    rest: RestClient,
+}
+
+impl PropertyRadarClient {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        PropertyRadarClient {
+            rest: RestClient::new(PROPERTYRADAR_API_BASE, AuthStrategy::Bearer(api_key.into())),
+        }
+    }
+
+    /// Build a client from `PROPERTYRADAR_API_KEY`.
+    pub fn from_env() -> Result<Self, PropertyRadarError> {
+        let api_key = std::env::var("PROPERTYRADAR_API_KEY").map_err(|_| PropertyRadarError::MissingApiKey)?;
+        Ok(Self::new(api_key))
+    }
+
+    pub fn with_transport(api_key: impl Into<String>, transport: Arc<dyn HttpTransport>) -> Self {
+        let mut client = Self::new(api_key);
+        client.rest = client.rest.with_transport(transport);
+        client
+    }
+
+    /// Fetch a single page (1-based). Page 0 is rejected without a request.
+    pub async fn search_page(&self, search: &PropertySearch, page: u32) -> Result<PropertyPage, PropertyRadarError> {
+        if page == 0 {
+            return Err(PropertyRadarError::InvalidPage(page));
+        }
+        let parsed: SearchResponse = self
+            .rest
+            .call(SEARCH_PROPERTIES, &[], &search.to_query(page), NO_PARAMS)
+            .await?;
+
+        // One malformed listing should not sink the whole page.
+        let mut listings = Vec::with_capacity(parsed.results.len());
+        let mut skipped = 0;
+        for raw in parsed.results {
+            match serde_json::from_value::<PropertyListing>(raw) {
+                Ok(listing) => listings.push(listing),
+                Err(err) => {
+                    warn!("Skipping malformed PropertyRadar listing: {}", err);
+                    skipped += 1;
+                }
+            }
+        }
+
+        let returned = (listings.len() + skipped) as u64;
+        let seen = (page as u64 - 1) * search.page_size as u64 + returned;
+        // An empty page is the last one, even when `total` says there are more
+        let more = returned > 0
+            && match parsed.total {
+                Some(total) => seen < total,
+                None => returned == search.page_size as u64,
+            };
+        Ok(PropertyPage {
+            listings,
+            page,
+            next_page: if more { page.checked_add(1) } else { None },
+            skipped,
+        })
+    }
+
+    /// Follow pagination until the results run out or `max_results` listings are collected.
+    pub async fn search_all(&self, search: &PropertySearch, max_results: usize) -> Result<Vec<PropertyListing>, PropertyRadarError> {
+        let mut listings = Vec::new();
+        let mut page = Some(1);
+        while let Some(current) = page {
+            if listings.len() >= max_results {
+                break;
+            }
+            let result = self.search_page(search, current).await?;
+            info!("Fetched PropertyRadar page {} ({} listings)", current, result.listings.len());
+            listings.extend(result.listings);
+            page = result.next_page;
+        }
+        listings.truncate(max_results);
+        Ok(listings)
+    }
+}
+
+fn main() {
+    let search = PropertySearch::new()
+        .max_price(5_000_000.0)
+        .property_types([PropertyType::Retail, PropertyType::Office])
+        .city("Orlando")
+        .radius_miles(25.0)
+        .min_sqft(2000.0);
+    println!("{:?}", crate::swarms::integrations::rest::to_query(&search.to_query(1)));
+}
+```
+
+### Notes
+* Listings that fail to decode are skipped and counted in `PropertyPage::skipped` rather than failing the page.
+* When the API omits `total`, a short page is treated as the last page. An empty page is always the last, so a
+  `total` that overstates the real count cannot keep `search_all` requesting empty pages.
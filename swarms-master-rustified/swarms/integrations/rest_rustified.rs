@@ -0,0 +1,398 @@
+### Overview
+There is no Python counterpart to this module. The Python integrations each build requests by hand with
+`requests.Session`, and the first Rust conversions copied that pattern with `reqwest`. This module is the
+shared layer integration clients are built on:
+
+* `Endpoint` describes one API call: HTTP method and a path template such as `/agents/{agent_id}`.
+* Path and query parameters are typed: any `Serialize` struct becomes the query string, and path
+  placeholders are filled from named values with URL encoding.
+* `AuthStrategy` covers bearer tokens, API-key headers, API-key query parameters, and basic auth.
+* `ErrorEnvelope` maps a non-2xx body onto `RestError::Api`, understanding the common
+  `{"error": {"message"}}`, `{"message"}`, and `{"detail"}` shapes.
+* `HttpTransport` is the only piece that touches the network, so clients can be tested with canned responses.
+
+### Rust Code
+```rust
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value as JsonValue;
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+    Delete,
+}
+
+impl From<HttpMethod> for reqwest::Method {
+    fn from(method: HttpMethod) -> Self {
+        match method {
+            HttpMethod::Get => reqwest::Method::GET,
+            HttpMethod::Post => reqwest::Method::POST,
+            HttpMethod::Put => reqwest::Method::PUT,
+            HttpMethod::Patch => reqwest::Method::PATCH,
+            HttpMethod::Delete => reqwest::Method::DELETE,
+        }
+    }
+}
+
+/// How a client authenticates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AuthStrategy {
+    None,
+    Bearer(String),
+    /// An API key sent in a header, e.g. `x-api-key`.
+    Header { name: String, value: String },
+    /// An API key sent as a query parameter, e.g. `?api_key=`.
+    Query { name: String, value: String },
+    Basic { username: String, password: String },
+}
+
+/// A fully resolved request handed to the transport.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpRequest {
+    pub method: HttpMethod,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub query: Vec<(String, String)>,
+    pub body: Option<JsonValue>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+#[derive(Debug)]
+pub enum RestError {
+    /// A path placeholder had no value.
+    MissingPathParam(String),
+    InvalidQuery(String),
+    Transport(String),
+    /// The server returned a non-2xx status.
+    Api { status: u16, message: String, body: String },
+    Decode(String),
+}
+
+impl fmt::Display for RestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RestError::MissingPathParam(name) => write!(f, "missing path parameter '{}'", name),
+            RestError::InvalidQuery(msg) => write!(f, "invalid query parameters: {}", msg),
+            RestError::Transport(msg) => write!(f, "request failed: {}", msg),
+            RestError::Api { status, message, .. } => write!(f, "API error {}: {}", status, message),
+            RestError::Decode(msg) => write!(f, "could not decode response: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for RestError {}
+
+impl RestError {
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            RestError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// The network layer. Swap it out in tests.
+#[async_trait]
+pub trait HttpTransport: Send + Sync {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError>;
+}
+
+/// The process-wide `reqwest` client. Clones share one connection pool, so integrations reuse connections
+/// to the same host instead of each opening their own. It sets no overall request timeout, which would cut
+/// off long-lived streams; callers that need one set it per request.
+pub fn shared_client() -> reqwest::Client {
+    static CLIENT: OnceLock<reqwest::Client> = OnceLock::new();
+    CLIENT.get_or_init(reqwest::Client::new).clone()
+}
+
+/// `HttpTransport` backed by `reqwest`.
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        ReqwestTransport { client: shared_client() }
+    }
+}
+
+#[async_trait]
+impl HttpTransport for ReqwestTransport {
+    async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+        let mut builder = self.client.request(request.method.into(), &request.url).query(&request.query);
+        for (name, value) in &request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(body) = &request.body {
+            builder = builder.json(body);
+        }
+        let response = builder.send().await.map_err(|e| RestError::Transport(e.to_string()))?;
+        let status = response.status().as_u16();
+        let body = response.text().await.map_err(|e| RestError::Transport(e.to_string()))?;
+        Ok(HttpResponse { status, body })
+    }
+}
+
+/// Maps an error response onto a human-readable message.
+pub trait ErrorEnvelope: Send + Sync {
+    fn message(&self, status: u16, body: &str) -> String;
+}
+
+/// Understands the error shapes used by OpenAI, FastAPI, and most JSON APIs.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultErrorEnvelope;
+
+impl ErrorEnvelope for DefaultErrorEnvelope {
+    fn message(&self, status: u16, body: &str) -> String {
+        if let Ok(value) = serde_json::from_str::<JsonValue>(body) {
+            let candidates = [
+                &value["error"]["message"],
+                &value["error"],
+                &value["message"],
+                &value["detail"],
+            ];
+            for candidate in candidates {
+                match candidate {
+                    JsonValue::String(message) => return message.clone(),
+                    JsonValue::Null | JsonValue::Object(_) => continue,
+                    other => return other.to_string(),
+                }
+            }
+        }
+        if body.trim().is_empty() {
+            format!("HTTP {}", status)
+        } else {
+            body.chars().take(500).collect()
+        }
+    }
+}
+
+/// One API call: method plus a path template with `{name}` placeholders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Endpoint {
+    pub method: HttpMethod,
+    pub path: &'static str,
+}
+
+impl Endpoint {
+    pub const fn new(method: HttpMethod, path: &'static str) -> Self {
+        Endpoint { method, path }
+    }
+
+    pub const fn get(path: &'static str) -> Self {
+        Self::new(HttpMethod::Get, path)
+    }
+
+    pub const fn post(path: &'static str) -> Self {
+        Self::new(HttpMethod::Post, path)
+    }
+
+    pub const fn patch(path: &'static str) -> Self {
+        Self::new(HttpMethod::Patch, path)
+    }
+
+    pub const fn delete(path: &'static str) -> Self {
+        Self::new(HttpMethod::Delete, path)
+    }
+
+    /// Fill `{name}` placeholders from `params`, URL-encoding each value.
+    pub fn render_path(&self, params: &[(&str, &str)]) -> Result<String, RestError> {
+        let mut rendered = String::with_capacity(self.path.len());
+        let mut rest = self.path;
+        while let Some(open) = rest.find('{') {
+            let close = rest[open..]
+                .find('}')
+                .map(|i| open + i)
+                .ok_or_else(|| RestError::MissingPathParam(rest[open..].to_string()))?;
+            let name = &rest[open + 1..close];
+            let value = params
+                .iter()
+                .find(|(key, _)| *key == name)
+                .map(|(_, value)| *value)
+                .ok_or_else(|| RestError::MissingPathParam(name.to_string()))?;
+            rendered.push_str(&rest[..open]);
+            rendered.push_str(&urlencoding::encode(value));
+            rest = &rest[close + 1..];
+        }
+        rendered.push_str(rest);
+        Ok(rendered)
+    }
+}
+
+/// Turn a `Serialize` struct into query pairs, dropping `None` fields.
+pub fn to_query<Q: Serialize>(query: &Q) -> Result<Vec<(String, String)>, RestError> {
+    let value = serde_json::to_value(query).map_err(|e| RestError::InvalidQuery(e.to_string()))?;
+    let object = match value {
+        JsonValue::Object(object) => object,
+        JsonValue::Null => return Ok(Vec::new()),
+        other => return Err(RestError::InvalidQuery(format!("expected an object, got {}", other))),
+    };
+    Ok(object
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            JsonValue::Null => None,
+            JsonValue::String(s) => Some((key, s)),
+            JsonValue::Array(items) => Some((
+                key,
+                items
+                    .iter()
+                    .map(|item| item.as_str().map(str::to_string).unwrap_or_else(|| item.to_string()))
+                    .collect::<Vec<_>>()
+                    .join(","),
+            )),
+            other => Some((key, other.to_string())),
+        })
+        .collect())
+}
+
+/// A configured API client: base URL, auth, transport, and error mapping.
+#[derive(Clone)]
+pub struct RestClient {
+    base_url: String,
+    auth: AuthStrategy,
+    default_headers: Vec<(String, String)>,
+    transport: Arc<dyn HttpTransport>,
+    errors: Arc<dyn ErrorEnvelope>,
+}
+
+impl RestClient {
+    pub fn new(base_url: impl Into<String>, auth: AuthStrategy) -> Self {
+        RestClient {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            auth,
+            default_headers: vec![("Content-Type".to_string(), "application/json".to_string())],
+            transport: Arc::new(ReqwestTransport::new()),
+            errors: Arc::new(DefaultErrorEnvelope),
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub fn with_error_envelope(mut self, errors: Arc<dyn ErrorEnvelope>) -> Self {
+        self.errors = errors;
+        self
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.default_headers.push((name.into(), value.into()));
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Build the request for `endpoint` without sending it.
+    pub fn build_request<Q: Serialize, B: Serialize>(
+        &self,
+        endpoint: Endpoint,
+        path_params: &[(&str, &str)],
+        query: &Q,
+        body: Option<&B>,
+    ) -> Result<HttpRequest, RestError> {
+        let mut headers = self.default_headers.clone();
+        let mut query = to_query(query)?;
+        match &self.auth {
+            AuthStrategy::None => {}
+            AuthStrategy::Bearer(token) => headers.push(("Authorization".to_string(), format!("Bearer {}", token))),
+            AuthStrategy::Header { name, value } => headers.push((name.clone(), value.clone())),
+            AuthStrategy::Query { name, value } => query.push((name.clone(), value.clone())),
+            AuthStrategy::Basic { username, password } => {
+                use base64::Engine;
+                let encoded = base64::engine::general_purpose::STANDARD.encode(format!("{}:{}", username, password));
+                headers.push(("Authorization".to_string(), format!("Basic {}", encoded)));
+            }
+        }
+        let body = body
+            .map(serde_json::to_value)
+            .transpose()
+            .map_err(|e| RestError::InvalidQuery(e.to_string()))?;
+        Ok(HttpRequest {
+            method: endpoint.method,
+            url: format!("{}{}", self.base_url, endpoint.render_path(path_params)?),
+            headers,
+            query,
+            body,
+        })
+    }
+
+    /// Send a request and return the raw body of a 2xx response.
+    pub async fn call_raw<Q: Serialize, B: Serialize>(
+        &self,
+        endpoint: Endpoint,
+        path_params: &[(&str, &str)],
+        query: &Q,
+        body: Option<&B>,
+    ) -> Result<String, RestError> {
+        let request = self.build_request(endpoint, path_params, query, body)?;
+        let response = self.transport.send(request).await?;
+        if (200..300).contains(&response.status) {
+            Ok(response.body)
+        } else {
+            Err(RestError::Api {
+                status: response.status,
+                message: self.errors.message(response.status, &response.body),
+                body: response.body,
+            })
+        }
+    }
+
+    /// Send a request and decode the JSON response into `R`.
+    pub async fn call<Q: Serialize, B: Serialize, R: DeserializeOwned>(
+        &self,
+        endpoint: Endpoint,
+        path_params: &[(&str, &str)],
+        query: &Q,
+        body: Option<&B>,
+    ) -> Result<R, RestError> {
+        let body = self.call_raw(endpoint, path_params, query, body).await?;
+        serde_json::from_str(&body).map_err(|e| RestError::Decode(e.to_string()))
+    }
+}
+
+/// Use as `body` when an endpoint takes none; pass `&()` as the `query` of an endpoint without one.
+pub const NO_PARAMS: Option<&()> = None;
+
+#[tokio::main]
+async fn main() -> Result<(), RestError> {
+    const GET_AGENT: Endpoint = Endpoint::get("/v1/agent/{agent_id}");
+
+    let client = RestClient::new("http://localhost:8000", AuthStrategy::Header {
+        name: "api-key".to_string(),
+        value: "test-key".to_string(),
+    });
+    let request = client.build_request(GET_AGENT, &[("agent_id", "1234")], &(), NO_PARAMS)?;
+    println!("{:?}", request);
+    Ok(())
+}
+```
+
+### Notes
+* Query structs should use `Option` for optional filters; `None` fields are omitted and arrays are comma-joined.
+* `RestError::Api` keeps the raw body alongside the extracted message for debugging.
+* JSON integrations (the OpenAI assistant, the Cohere reranker, Bing search, PropertyRadar) go through
+  `RestClient`. Three clients need more than a text body and stay on `reqwest`: `OpenAiCompatProvider`
+  reads SSE streams and `WebReaderTool` fetches arbitrary pages by content type, both on `shared_client()`;
+  `openai_tts` returns binary audio from a blocking client.
@@ -0,0 +1,377 @@
+### Overview
+There is no Python counterpart. This module reads `text/event-stream` responses the way a browser's
+`EventSource` does, for clients that consume a remote swarm's stream over an unreliable network:
+
+* `SseParser` implements the event-stream format: `event`, `data` (multi-line), `id`, and `retry` fields,
+  comment lines, and `\n`, `\r\n`, or `\r` line endings. Bytes may be split anywhere.
+* `SseStream` reconnects when the connection drops. Each new request carries the `Last-Event-ID` header,
+  so a server that numbers its events resumes after the last one the client received.
+* Heartbeats: a server sends comment lines (`: keep-alive`) while it has nothing else to send. If no
+  bytes arrive within `SseConfig::heartbeat_timeout`, the connection is treated as dead and replaced.
+* Reconnects back off exponentially with the `RetryPolicy` that webhook deliveries use. A `retry` field
+  from the server replaces the computed delay, as the format specifies.
+
+The network sits behind `SseSource`, so the reconnect logic can be tested with scripted bodies.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::rest::{shared_client, HttpMethod, HttpRequest};
+use crate::swarms::integrations::webhooks::RetryPolicy;
+use async_trait::async_trait;
+use log::{debug, warn};
+use std::collections::VecDeque;
+use std::fmt;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SseError {
+    /// The server answered the (re)connect with a status that is not worth retrying.
+    Status { status: u16, body: String },
+    Transport(String),
+    /// The connection dropped and the stream cannot be resumed (see `SseConfig::resume_only`).
+    NotResumable,
+    RetriesExhausted { attempts: u32, last: String },
+}
+
+impl fmt::Display for SseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SseError::Status { status, body } => write!(f, "event stream refused with HTTP {}: {}", status, body),
+            SseError::Transport(message) => write!(f, "event stream failed: {}", message),
+            SseError::NotResumable => write!(f, "event stream dropped before the server sent an event id"),
+            SseError::RetriesExhausted { attempts, last } => {
+                write!(f, "event stream gave up after {} reconnects: {}", attempts, last)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SseError {}
+
+impl SseError {
+    fn is_retriable(&self) -> bool {
+        match self {
+            SseError::Status { status, .. } => *status == 408 || *status == 429 || *status >= 500,
+            SseError::Transport(_) => true,
+            SseError::NotResumable | SseError::RetriesExhausted { .. } => false,
+        }
+    }
+}
+
+/// One dispatched event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SseEvent {
+    /// `message` unless the server named the event.
+    pub event: String,
+    /// The `data` lines, joined with `\n`.
+    pub data: String,
+    /// The last event id seen on the stream, which may have been set by an earlier event.
+    pub id: Option<String>,
+}
+
+/// Incremental `text/event-stream` parser.
+#[derive(Debug, Default)]
+pub struct SseParser {
+    buffer: Vec<u8>,
+    event: Option<String>,
+    data: Option<String>,
+    last_event_id: Option<String>,
+    retry: Option<Duration>,
+    /// The previous chunk ended in `\r`, so a leading `\n` belongs to that line ending.
+    after_cr: bool,
+}
+
+impl SseParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// The reconnection delay from the server's last `retry` field.
+    pub fn retry(&self) -> Option<Duration> {
+        self.retry
+    }
+
+    /// Drop the partly received line and event, as a connection that closes mid-event must. The last
+    /// event id and retry delay are kept: they carry over to the next connection.
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.event = None;
+        self.data = None;
+        self.after_cr = false;
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) -> Vec<SseEvent> {
+        let mut bytes = bytes;
+        if self.after_cr && bytes.first() == Some(&b'\n') {
+            bytes = &bytes[1..];
+        }
+        self.after_cr = bytes.last() == Some(&b'\r');
+        self.buffer.extend_from_slice(bytes);
+
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n' || b == b'\r') {
+            let crlf = self.buffer[end] == b'\r' && self.buffer.get(end + 1) == Some(&b'\n');
+            let line: Vec<u8> = self.buffer.drain(..end).collect();
+            self.buffer.drain(..if crlf { 2 } else { 1 });
+            if let Some(event) = self.line(&String::from_utf8_lossy(&line)) {
+                events.push(event);
+            }
+        }
+        events
+    }
+
+    fn line(&mut self, line: &str) -> Option<SseEvent> {
+        if line.is_empty() {
+            let event = self.event.take();
+            return self.data.take().map(|data| SseEvent {
+                event: event.unwrap_or_else(|| "message".to_string()),
+                data,
+                id: self.last_event_id.clone(),
+            });
+        }
+        // Comments are heartbeats. They only need to reach the stream as bytes.
+        if line.starts_with(':') {
+            return None;
+        }
+        let (field, value) = line.split_once(':').unwrap_or((line, ""));
+        let value = value.strip_prefix(' ').unwrap_or(value);
+        match field {
+            "event" => self.event = Some(value.to_string()),
+            "data" => match &mut self.data {
+                Some(data) => {
+                    data.push('\n');
+                    data.push_str(value);
+                }
+                None => self.data = Some(value.to_string()),
+            },
+            "id" if !value.contains('\0') => self.last_event_id = Some(value.to_string()).filter(|id| !id.is_empty()),
+            "retry" => {
+                if let Ok(millis) = value.parse() {
+                    self.retry = Some(Duration::from_millis(millis));
+                }
+            }
+            _ => debug!("Ignoring event-stream field '{}'", field),
+        }
+        None
+    }
+}
+
+/// A response body being read.
+#[async_trait]
+pub trait SseBody: Send {
+    /// The next bytes, or `None` when the server closed the stream.
+    async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, SseError>;
+}
+
+#[async_trait]
+impl SseBody for reqwest::Response {
+    async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, SseError> {
+        let chunk = self.chunk().await.map_err(|err| SseError::Transport(err.to_string()))?;
+        Ok(chunk.map(|bytes| bytes.to_vec()))
+    }
+}
+
+/// Opens the stream. Called again, with the last event id, after every drop.
+#[async_trait]
+pub trait SseSource: Send + Sync {
+    /// `Ok(None)` is the server's `204 No Content`: the stream is over and must not be reopened.
+    async fn connect(&self, last_event_id: Option<&str>) -> Result<Option<Box<dyn SseBody>>, SseError>;
+}
+
+/// `SseSource` that sends one `HttpRequest` with `reqwest`.
+pub struct HttpSseSource {
+    client: reqwest::Client,
+    request: HttpRequest,
+}
+
+impl HttpSseSource {
+    /// `request` is usually built with `RestClient::build_request`, so it carries the client's auth.
+    pub fn new(request: HttpRequest) -> Self {
+        HttpSseSource { client: shared_client(), request }
+    }
+}
+
+#[async_trait]
+impl SseSource for HttpSseSource {
+    async fn connect(&self, last_event_id: Option<&str>) -> Result<Option<Box<dyn SseBody>>, SseError> {
+        let mut builder = self
+            .client
+            .request(self.request.method.into(), &self.request.url)
+            .query(&self.request.query)
+            .header("Accept", "text/event-stream")
+            .header("Cache-Control", "no-cache");
+        for (name, value) in &self.request.headers {
+            builder = builder.header(name, value);
+        }
+        if let Some(id) = last_event_id {
+            builder = builder.header("Last-Event-ID", id);
+        }
+        if let Some(body) = &self.request.body {
+            builder = builder.json(body);
+        }
+        let response = builder.send().await.map_err(|err| SseError::Transport(err.to_string()))?;
+        match response.status().as_u16() {
+            204 => Ok(None),
+            200..=299 => Ok(Some(Box::new(response))),
+            status => Err(SseError::Status { status, body: response.text().await.unwrap_or_default() }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SseConfig {
+    /// Longest silence before the connection counts as dead. Set it above the server's heartbeat interval.
+    pub heartbeat_timeout: Duration,
+    /// Reconnect attempts in a row, and the backoff between them. The count resets on every event.
+    pub retry: RetryPolicy,
+    /// Only reconnect once the server has sent an event id. Set this for streams that a plain retry would
+    /// start over, such as a `POST` that starts a run.
+    pub resume_only: bool,
+}
+
+impl Default for SseConfig {
+    fn default() -> Self {
+        SseConfig { heartbeat_timeout: Duration::from_secs(45), retry: RetryPolicy::default(), resume_only: false }
+    }
+}
+
+/// Reads events from an `SseSource`, reconnecting as needed. Call `next` until it returns `None`.
+pub struct SseStream {
+    source: Box<dyn SseSource>,
+    config: SseConfig,
+    parser: SseParser,
+    body: Option<Box<dyn SseBody>>,
+    pending: VecDeque<SseEvent>,
+    /// Reconnects since the last event.
+    attempts: u32,
+    connected_once: bool,
+    finished: bool,
+}
+
+impl SseStream {
+    pub fn new(source: impl SseSource + 'static, config: SseConfig) -> Self {
+        SseStream {
+            source: Box::new(source),
+            config,
+            parser: SseParser::new(),
+            body: None,
+            pending: VecDeque::new(),
+            attempts: 0,
+            connected_once: false,
+            finished: false,
+        }
+    }
+
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.parser.last_event_id()
+    }
+
+    /// Stop reading, e.g. after the application's own end-of-stream event. `next` returns `None` from now on.
+    pub fn close(&mut self) {
+        self.finished = true;
+        self.body = None;
+        self.pending.clear();
+    }
+
+    pub async fn next(&mut self) -> Option<Result<SseEvent, SseError>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                self.attempts = 0;
+                return Some(Ok(event));
+            }
+            if self.finished {
+                return None;
+            }
+            let Some(body) = &mut self.body else {
+                if let Err(err) = self.reconnect().await {
+                    self.finished = true;
+                    return Some(Err(err));
+                }
+                continue;
+            };
+            let dropped = match tokio::time::timeout(self.config.heartbeat_timeout, body.next_chunk()).await {
+                Ok(Ok(Some(bytes))) => {
+                    self.pending.extend(self.parser.push(&bytes));
+                    continue;
+                }
+                Ok(Ok(None)) => "the server closed the stream".to_string(),
+                Ok(Err(err)) => err.to_string(),
+                Err(_) => format!("no data or heartbeat for {:?}", self.config.heartbeat_timeout),
+            };
+            warn!("Event stream dropped: {}", dropped);
+            self.body = None;
+            // The next connection starts a fresh event; half an event must not be spliced onto it.
+            self.parser.reset();
+        }
+    }
+
+    /// Open the stream, waiting out the backoff before every attempt after the first.
+    async fn reconnect(&mut self) -> Result<(), SseError> {
+        loop {
+            if self.connected_once {
+                if self.config.resume_only && self.parser.last_event_id().is_none() {
+                    return Err(SseError::NotResumable);
+                }
+                self.attempts += 1;
+                if self.attempts > self.config.retry.max_attempts {
+                    let last = "the connection kept dropping".to_string();
+                    return Err(SseError::RetriesExhausted { attempts: self.attempts - 1, last });
+                }
+                let delay = self.parser.retry().unwrap_or_else(|| self.config.retry.backoff(self.attempts));
+                debug!("Reconnecting event stream in {:?} (attempt {})", delay, self.attempts);
+                tokio::time::sleep(delay).await;
+            }
+            self.connected_once = true;
+            match self.source.connect(self.parser.last_event_id()).await {
+                Ok(Some(body)) => {
+                    self.body = Some(body);
+                    return Ok(());
+                }
+                Ok(None) => {
+                    self.finished = true;
+                    return Ok(());
+                }
+                Err(err) if err.is_retriable() && self.attempts < self.config.retry.max_attempts => {
+                    warn!("Event stream connect failed: {}", err);
+                }
+                Err(err) if err.is_retriable() => {
+                    return Err(SseError::RetriesExhausted { attempts: self.attempts, last: err.to_string() });
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), SseError> {
+    let request = HttpRequest {
+        method: HttpMethod::Get,
+        url: "http://localhost:8000/v1/events".to_string(),
+        headers: Vec::new(),
+        query: Vec::new(),
+        body: None,
+    };
+    let mut stream = SseStream::new(HttpSseSource::new(request), SseConfig::default());
+    while let Some(event) = stream.next().await {
+        let event = event?;
+        println!("{} [{}]: {}", event.event, event.id.as_deref().unwrap_or("-"), event.data);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* A stream that the server closes is reopened, as `EventSource` does. The server ends a stream for good
+  by answering the reconnect with `204 No Content`. Applications with their own end marker (the client's
+  completions end with `data: [DONE]`) call `close` when they see it.
+* An event cut off by a drop is discarded, not completed by the next connection's first lines. The server
+  resends it after `Last-Event-ID`, since it never got as far as the blank line that dispatches it.
+* `RetriesExhausted` counts reconnects in a row. A stream that drops once an hour for days never
+  exhausts its retries, since every event resets the count.
+* With `resume_only`, a failed first connect is not retried either. The request may have started the run
+  before the connection failed, and sending it again would start a second one.
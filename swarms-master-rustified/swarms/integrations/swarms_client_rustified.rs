@@ -0,0 +1,482 @@
+### Overview
+There is no Python counterpart. Python integrators call the swarms API with `requests`, and the Rust API
+test (`api/agent_api_test_rustified.rs`) does the same with `reqwest`: it formats URLs, sets the `api-key`
+header, and decodes each response into a struct it declares itself. `SwarmsClient` wraps those calls in a
+typed async client:
+
+* Users and keys: `create_user`, `create_api_key`, `list_api_keys`, `revoke_api_key`.
+* Agents: `create_agent`, `list_agents` (one page, see `pagination`), `all_agents`, `update_agent`,
+  `agent_metrics`, `delete_agent`.
+* Runs: `complete` returns the finished completion, and `stream_completion` returns its text as the
+  server produces it, reconnecting through `sse` when the connection drops.
+
+Requests go through `RestClient`, so tests can substitute an `HttpTransport`. Failures come back as
+`SwarmsClientError::Api` with the server's `Problem` document, including its `code` and `retriable` hint.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::Problem;
+use crate::swarms::integrations::rest::{
+    AuthStrategy, Endpoint, HttpTransport, ReqwestTransport, RestClient, RestError, NO_PARAMS,
+};
+use crate::swarms::integrations::sse::{HttpSseSource, SseConfig, SseError, SseStream};
+use crate::swarms::structs::pagination::Page;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use uuid::Uuid;
+
+pub const DEFAULT_BASE_URL: &str = "http://localhost:8000/v1";
+
+const CREATE_USER: Endpoint = Endpoint::post("/users");
+const CREATE_API_KEY: Endpoint = Endpoint::post("/users/{user_id}/api-keys");
+const LIST_API_KEYS: Endpoint = Endpoint::get("/users/{user_id}/api-keys");
+const REVOKE_API_KEY: Endpoint = Endpoint::delete("/users/{user_id}/api-keys/{key}");
+const CREATE_AGENT: Endpoint = Endpoint::post("/agent");
+const LIST_AGENTS: Endpoint = Endpoint::get("/users/me/agents");
+const UPDATE_AGENT: Endpoint = Endpoint::patch("/agent/{agent_id}");
+const AGENT_METRICS: Endpoint = Endpoint::get("/agent/{agent_id}/metrics");
+const DELETE_AGENT: Endpoint = Endpoint::delete("/agent/{agent_id}");
+const COMPLETIONS: Endpoint = Endpoint::post("/agent/completions");
+
+#[derive(Debug)]
+pub enum SwarmsClientError {
+    /// The server answered with a problem document.
+    Api(Box<Problem>),
+    /// The request failed before a problem document came back, or the response did not decode.
+    Rest(RestError),
+    /// A completion stream broke off for good or sent an event that does not parse.
+    Stream(String),
+}
+
+impl fmt::Display for SwarmsClientError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SwarmsClientError::Api(problem) => write!(f, "swarms API: {}", problem),
+            SwarmsClientError::Rest(err) => write!(f, "swarms API: {}", err),
+            SwarmsClientError::Stream(message) => write!(f, "swarms API stream failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SwarmsClientError {}
+
+impl From<SseError> for SwarmsClientError {
+    fn from(err: SseError) -> Self {
+        match err {
+            SseError::Status { status, body } => {
+                RestError::Api { status, message: format!("HTTP {}", status), body }.into()
+            }
+            err => SwarmsClientError::Stream(err.to_string()),
+        }
+    }
+}
+
+impl From<RestError> for SwarmsClientError {
+    fn from(err: RestError) -> Self {
+        match &err {
+            RestError::Api { body, .. } => match Problem::parse(body) {
+                Some(problem) => SwarmsClientError::Api(Box::new(problem)),
+                None => SwarmsClientError::Rest(err),
+            },
+            _ => SwarmsClientError::Rest(err),
+        }
+    }
+}
+
+impl SwarmsClientError {
+    pub fn problem(&self) -> Option<&Problem> {
+        match self {
+            SwarmsClientError::Api(problem) => Some(problem.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// Whether sending the same request again can succeed. Transport failures count as retriable.
+    pub fn is_retriable(&self) -> bool {
+        match self {
+            SwarmsClientError::Api(problem) => problem.retriable,
+            SwarmsClientError::Rest(RestError::Transport(_)) => true,
+            SwarmsClientError::Rest(_) | SwarmsClientError::Stream(_) => false,
+        }
+    }
+}
+
+/// A user created by `create_user`, with the first API key.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct NewUser {
+    pub user_id: Uuid,
+    pub api_key: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiKey {
+    pub key: String,
+    pub name: String,
+    pub created_at: String,
+    pub last_used: String,
+    pub is_active: bool,
+}
+
+/// The body of `create_agent`. Unset fields take the server's defaults.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NewAgent {
+    pub agent_name: String,
+    pub model_name: String,
+    pub system_prompt: String,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_loops: Option<u32>,
+    pub tags: Vec<String>,
+}
+
+impl NewAgent {
+    pub fn new(agent_name: impl Into<String>, model_name: impl Into<String>, system_prompt: impl Into<String>) -> Self {
+        NewAgent {
+            agent_name: agent_name.into(),
+            model_name: model_name.into(),
+            system_prompt: system_prompt.into(),
+            description: String::new(),
+            temperature: None,
+            max_loops: None,
+            tags: Vec::new(),
+        }
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    pub fn temperature(mut self, temperature: f32) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    pub fn max_loops(mut self, max_loops: u32) -> Self {
+        self.max_loops = Some(max_loops);
+        self
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+}
+
+/// The body of `update_agent`. Only the fields that are set change.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AgentUpdate {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_loops: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+    /// `active`, `paused`, or `maintenance`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentSummary {
+    pub agent_id: Uuid,
+    pub agent_name: String,
+    #[serde(default)]
+    pub description: String,
+    pub created_at: String,
+    pub last_used: String,
+    #[serde(default)]
+    pub total_completions: u64,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AgentMetrics {
+    pub total_completions: u64,
+    pub average_response_time: f64,
+    pub error_rate: f64,
+    pub total_tokens_used: u64,
+    pub uptime_percentage: f64,
+    pub success_rate: f64,
+    pub peak_tokens_per_minute: u64,
+}
+
+/// Query string of `list_agents`. `None` fields are left out.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct AgentListQuery {
+    pub tag: Option<String>,
+    pub model: Option<String>,
+    /// `name`, `created_at` or `updated_at`, with a leading `-` for descending.
+    pub sort: Option<String>,
+    pub limit: Option<usize>,
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CompletionRequest {
+    pub prompt: String,
+    pub agent_id: Uuid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature_override: Option<f32>,
+    pub stream: bool,
+}
+
+impl CompletionRequest {
+    pub fn new(agent_id: Uuid, prompt: impl Into<String>) -> Self {
+        CompletionRequest { prompt: prompt.into(), agent_id, max_tokens: None, temperature_override: None, stream: false }
+    }
+
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    pub fn temperature_override(mut self, temperature: f32) -> Self {
+        self.temperature_override = Some(temperature);
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CompletionResponse {
+    pub agent_id: Uuid,
+    pub response: String,
+    #[serde(default)]
+    pub metadata: Map<String, JsonValue>,
+    pub timestamp: String,
+    pub processing_time: f64,
+    /// `prompt_tokens`, `completion_tokens`, and `total_tokens`.
+    pub token_usage: HashMap<String, u64>,
+}
+
+/// One event of a streamed completion.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct CompletionChunk {
+    #[serde(default)]
+    pub delta: String,
+    /// Set on the last chunk only.
+    #[serde(default)]
+    pub token_usage: Option<HashMap<String, u64>>,
+}
+
+/// A completion being streamed. Call `next` until it returns `None`.
+pub struct CompletionStream {
+    events: SseStream,
+    done: bool,
+}
+
+impl CompletionStream {
+    /// Read completion chunks from any event stream. `SwarmsClient::stream_completion` is the usual way in.
+    pub fn new(events: SseStream) -> Self {
+        CompletionStream { events, done: false }
+    }
+
+    pub async fn next(&mut self) -> Option<Result<CompletionChunk, SwarmsClientError>> {
+        if self.done {
+            return None;
+        }
+        let event = match self.events.next().await {
+            Some(Ok(event)) => event,
+            Some(Err(err)) => return Some(Err(err.into())),
+            None => return Some(Err(SwarmsClientError::Stream("the stream ended before [DONE]".to_string()))),
+        };
+        if event.data == "[DONE]" {
+            self.done = true;
+            self.events.close();
+            return None;
+        }
+        Some(serde_json::from_str(&event.data).map_err(|err| SwarmsClientError::Stream(err.to_string())))
+    }
+
+    /// Read the rest of the stream and return the whole text.
+    pub async fn collect_text(mut self) -> Result<String, SwarmsClientError> {
+        let mut text = String::new();
+        while let Some(chunk) = self.next().await {
+            text.push_str(&chunk?.delta);
+        }
+        Ok(text)
+    }
+}
+
+/// Typed client for the swarms REST API.
+#[derive(Clone)]
+pub struct SwarmsClient {
+    base_url: String,
+    api_key: Option<String>,
+    transport: Arc<dyn HttpTransport>,
+    stream_config: SseConfig,
+}
+
+impl SwarmsClient {
+    /// A client without an API key. Only `create_user` works until one is set.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        SwarmsClient {
+            base_url: base_url.into(),
+            api_key: None,
+            transport: Arc::new(ReqwestTransport::new()),
+            stream_config: SseConfig::default(),
+        }
+    }
+
+    /// Build a client from `SWARMS_API_URL` (default `DEFAULT_BASE_URL`) and `SWARMS_API_KEY`.
+    pub fn from_env() -> Self {
+        let base_url = std::env::var("SWARMS_API_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+        let client = SwarmsClient::new(base_url);
+        match std::env::var("SWARMS_API_KEY") {
+            Ok(api_key) => client.with_api_key(api_key),
+            Err(_) => client,
+        }
+    }
+
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    /// Heartbeat timeout and reconnect backoff for `stream_completion`.
+    pub fn with_stream_config(mut self, stream_config: SseConfig) -> Self {
+        self.stream_config = stream_config;
+        self
+    }
+
+    fn rest(&self) -> RestClient {
+        let auth = match &self.api_key {
+            Some(api_key) => AuthStrategy::Header { name: "api-key".to_string(), value: api_key.clone() },
+            None => AuthStrategy::None,
+        };
+        RestClient::new(self.base_url.as_str(), auth).with_transport(self.transport.clone())
+    }
+
+    pub async fn create_user(&self, username: &str) -> Result<NewUser, SwarmsClientError> {
+        let body = serde_json::json!({ "username": username });
+        Ok(self.rest().call(CREATE_USER, &[], &(), Some(&body)).await?)
+    }
+
+    pub async fn create_api_key(&self, user_id: Uuid, name: &str) -> Result<ApiKey, SwarmsClientError> {
+        let body = serde_json::json!({ "name": name });
+        let user_id = user_id.to_string();
+        Ok(self.rest().call(CREATE_API_KEY, &[("user_id", &user_id)], &(), Some(&body)).await?)
+    }
+
+    pub async fn list_api_keys(&self, user_id: Uuid) -> Result<Vec<ApiKey>, SwarmsClientError> {
+        let user_id = user_id.to_string();
+        Ok(self.rest().call(LIST_API_KEYS, &[("user_id", &user_id)], &(), NO_PARAMS).await?)
+    }
+
+    pub async fn revoke_api_key(&self, user_id: Uuid, key: &str) -> Result<(), SwarmsClientError> {
+        let user_id = user_id.to_string();
+        self.rest().call_raw(REVOKE_API_KEY, &[("user_id", &user_id), ("key", key)], &(), NO_PARAMS).await?;
+        Ok(())
+    }
+
+    /// Returns the new agent's id.
+    pub async fn create_agent(&self, agent: &NewAgent) -> Result<Uuid, SwarmsClientError> {
+        #[derive(Deserialize)]
+        struct Created {
+            agent_id: Uuid,
+        }
+        let created: Created = self.rest().call(CREATE_AGENT, &[], &(), Some(agent)).await?;
+        Ok(created.agent_id)
+    }
+
+    /// One page of the caller's agents.
+    pub async fn list_agents(&self, query: &AgentListQuery) -> Result<Page<AgentSummary>, SwarmsClientError> {
+        Ok(self.rest().call(LIST_AGENTS, &[], query, NO_PARAMS).await?)
+    }
+
+    /// Every agent that matches `query`, following `next_cursor` from `query.cursor` on.
+    pub async fn all_agents(&self, query: &AgentListQuery) -> Result<Vec<AgentSummary>, SwarmsClientError> {
+        let mut query = query.clone();
+        let mut agents = Vec::new();
+        loop {
+            let page = self.list_agents(&query).await?;
+            agents.extend(page.items);
+            match page.next_cursor {
+                Some(cursor) => query.cursor = Some(cursor),
+                None => return Ok(agents),
+            }
+        }
+    }
+
+    pub async fn update_agent(&self, agent_id: Uuid, update: &AgentUpdate) -> Result<(), SwarmsClientError> {
+        let agent_id = agent_id.to_string();
+        self.rest().call_raw(UPDATE_AGENT, &[("agent_id", &agent_id)], &(), Some(update)).await?;
+        Ok(())
+    }
+
+    pub async fn agent_metrics(&self, agent_id: Uuid) -> Result<AgentMetrics, SwarmsClientError> {
+        let agent_id = agent_id.to_string();
+        Ok(self.rest().call(AGENT_METRICS, &[("agent_id", &agent_id)], &(), NO_PARAMS).await?)
+    }
+
+    pub async fn delete_agent(&self, agent_id: Uuid) -> Result<(), SwarmsClientError> {
+        let agent_id = agent_id.to_string();
+        self.rest().call_raw(DELETE_AGENT, &[("agent_id", &agent_id)], &(), NO_PARAMS).await?;
+        Ok(())
+    }
+
+    /// Run a completion and wait for all of it.
+    pub async fn complete(&self, request: &CompletionRequest) -> Result<CompletionResponse, SwarmsClientError> {
+        let request = CompletionRequest { stream: false, ..request.clone() };
+        Ok(self.rest().call(COMPLETIONS, &[], &(), Some(&request)).await?)
+    }
+
+    /// Run a completion and read its text as the server produces it. The request is sent on the first `next`.
+    ///
+    /// A dropped stream is resumed with `Last-Event-ID` once the server has sent an event id. Before that,
+    /// the drop is an error: sending the request again would start a second run.
+    pub fn stream_completion(&self, request: &CompletionRequest) -> Result<CompletionStream, SwarmsClientError> {
+        let request = CompletionRequest { stream: true, ..request.clone() };
+        let request = self.rest().build_request(COMPLETIONS, &[], &(), Some(&request))?;
+        let config = SseConfig { resume_only: true, ..self.stream_config.clone() };
+        Ok(CompletionStream::new(SseStream::new(HttpSseSource::new(request), config)))
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), SwarmsClientError> {
+    let anonymous = SwarmsClient::from_env();
+    let user = anonymous.create_user("rust_sdk_example").await?;
+    let client = anonymous.with_api_key(user.api_key);
+
+    let analyst = NewAgent::new("Analyst", "gpt-4o", "You are a helpful assistant").tag("example");
+    let agent_id = client.create_agent(&analyst).await?;
+    let query = AgentListQuery { tag: Some("example".to_string()), ..Default::default() };
+    println!("{} agents tagged 'example'", client.all_agents(&query).await?.len());
+
+    let mut stream = client.stream_completion(&CompletionRequest::new(agent_id, "What is a swarm?"))?;
+    while let Some(chunk) = stream.next().await {
+        print!("{}", chunk?.delta);
+    }
+    client.delete_agent(agent_id).await
+}
+```
+
+### Notes
+* Streaming uses `data:` events of `{"delta": "..."}` ended by `data: [DONE]`, the framing the OpenAI-compatible
+  providers use. The last chunk before `[DONE]` carries `token_usage`. A stream that ends without `[DONE]`
+  is an error, so a dropped connection is never mistaken for a short answer. Reconnects and heartbeats
+  are handled by `sse`.
+* Path parameters are URL-encoded by `Endpoint::render_path`, so API keys with reserved characters revoke
+  correctly. The API test formats those URLs by hand.
+* `with_api_key` returns a new client. One `SwarmsClient` per key is cheap: it holds the base URL, the
+  key, and an `Arc` of the transport.
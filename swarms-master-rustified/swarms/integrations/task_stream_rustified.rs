@@ -0,0 +1,357 @@
+### Overview
+There is no Python counterpart; the Python `TaskQueueSwarm` only drains an in-process `queue.Queue`. For
+high-throughput deployments this module lets `TaskQueueSwarm::run_stream` consume tasks from a Kafka topic
+or a NATS JetStream subject and publish results to another. The connectors sit behind the `kafka` and
+`nats` cargo features so the default build pulls in neither client library.
+
+Delivery is at-least-once. A task is acknowledged only after its result has been published; for Kafka the
+committed offset only advances over a contiguous run of finished messages (`OffsetTracker`), so a crash
+replays unfinished work instead of skipping it. A task that fails is nacked so the broker redelivers it: a
+JetStream `Nak`, or for Kafka a seek back to its offset. Tasks that share a partition (Kafka partition, or the NATS
+`Swarms-Partition-Key` header) are always handled by the same worker, which preserves per-partition order.
+
+### Rust Code
+```rust
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// A task pulled from an external source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StreamTask {
+    pub id: String,
+    pub payload: String,
+    /// Tasks with the same key are processed in order by the same worker.
+    pub partition_key: String,
+    pub ack: AckToken,
+}
+
+/// What a source needs to acknowledge a task once its result is published.
+#[derive(Debug, Clone, PartialEq)]
+pub enum AckToken {
+    /// Kafka partition and offset.
+    Offset { partition: i32, offset: i64 },
+    /// JetStream reply subject of the message.
+    Reply(String),
+    None,
+}
+
+/// A finished task, published to the result sink.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StreamResult {
+    pub task_id: String,
+    pub partition_key: String,
+    pub agent_name: String,
+    pub task: String,
+    pub result: String,
+    pub timestamp: String,
+}
+
+#[derive(Debug)]
+pub enum StreamError {
+    Connect(String),
+    Receive(String),
+    Publish(String),
+    Ack(String),
+}
+
+impl fmt::Display for StreamError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamError::Connect(msg) => write!(f, "connect failed: {}", msg),
+            StreamError::Receive(msg) => write!(f, "receive failed: {}", msg),
+            StreamError::Publish(msg) => write!(f, "publish failed: {}", msg),
+            StreamError::Ack(msg) => write!(f, "ack failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StreamError {}
+
+#[async_trait]
+pub trait TaskSource: Send {
+    /// The next task, or `None` when the source is exhausted or shut down.
+    async fn next(&mut self) -> Result<Option<StreamTask>, StreamError>;
+    /// Acknowledge a task whose result has been published.
+    async fn ack(&mut self, task: &StreamTask) -> Result<(), StreamError>;
+    /// Reject a task that failed, panicked, or whose result could not be published, so the broker
+    /// redelivers it.
+    async fn nack(&mut self, task: &StreamTask) -> Result<(), StreamError>;
+}
+
+#[async_trait]
+pub trait ResultSink: Send + Sync {
+    async fn publish(&self, result: &StreamResult) -> Result<(), StreamError>;
+}
+
+/// Tracks finished offsets per partition and reports the offset that is safe to commit: one past the
+/// end of the contiguous finished run. Out-of-order completions are held until the gap is filled.
+#[derive(Debug, Default)]
+pub struct OffsetTracker {
+    next_commit: HashMap<i32, i64>,
+    finished: HashMap<i32, BTreeSet<i64>>,
+}
+
+impl OffsetTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record that `offset` was received, so the partition's low watermark is known.
+    pub fn received(&mut self, partition: i32, offset: i64) {
+        self.next_commit.entry(partition).or_insert(offset);
+    }
+
+    /// Mark `offset` finished. Returns the new commit position if it advanced.
+    pub fn finish(&mut self, partition: i32, offset: i64) -> Option<i64> {
+        let next = self.next_commit.entry(partition).or_insert(offset);
+        let finished = self.finished.entry(partition).or_default();
+        finished.insert(offset);
+        let start = *next;
+        while finished.remove(&*next) {
+            *next += 1;
+        }
+        (*next != start).then_some(*next)
+    }
+}
+
+/// Pick the worker for a partition key.
+pub fn worker_for(partition_key: &str, workers: usize) -> usize {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    partition_key.hash(&mut hasher);
+    (hasher.finish() % workers.max(1) as u64) as usize
+}
+
+#[cfg(feature = "kafka")]
+pub mod kafka {
+    use super::*;
+    use rdkafka::config::ClientConfig;
+    use rdkafka::consumer::{CommitMode, Consumer, StreamConsumer};
+    use rdkafka::producer::{FutureProducer, FutureRecord};
+    use rdkafka::{Message, Offset, TopicPartitionList};
+    use std::time::Duration;
+
+    /// Consumes a topic with auto-commit disabled; offsets are committed through `OffsetTracker`.
+    pub struct KafkaTaskSource {
+        consumer: StreamConsumer,
+        topic: String,
+        tracker: OffsetTracker,
+    }
+
+    impl KafkaTaskSource {
+        pub fn new(brokers: &str, group_id: &str, topic: &str) -> Result<Self, StreamError> {
+            let consumer: StreamConsumer = ClientConfig::new()
+                .set("bootstrap.servers", brokers)
+                .set("group.id", group_id)
+                .set("enable.auto.commit", "false")
+                .set("auto.offset.reset", "earliest")
+                .create()
+                .map_err(|e| StreamError::Connect(e.to_string()))?;
+            consumer.subscribe(&[topic]).map_err(|e| StreamError::Connect(e.to_string()))?;
+            Ok(KafkaTaskSource { consumer, topic: topic.to_string(), tracker: OffsetTracker::new() })
+        }
+    }
+
+    #[async_trait]
+    impl TaskSource for KafkaTaskSource {
+        async fn next(&mut self) -> Result<Option<StreamTask>, StreamError> {
+            let message = self.consumer.recv().await.map_err(|e| StreamError::Receive(e.to_string()))?;
+            let (partition, offset) = (message.partition(), message.offset());
+            self.tracker.received(partition, offset);
+            let payload = message
+                .payload_view::<str>()
+                .transpose()
+                .map_err(|e| StreamError::Receive(e.to_string()))?
+                .unwrap_or_default()
+                .to_string();
+            Ok(Some(StreamTask {
+                id: format!("{}-{}-{}", self.topic, partition, offset),
+                payload,
+                partition_key: partition.to_string(),
+                ack: AckToken::Offset { partition, offset },
+            }))
+        }
+
+        async fn ack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            let AckToken::Offset { partition, offset } = task.ack else {
+                return Ok(());
+            };
+            if let Some(commit) = self.tracker.finish(partition, offset) {
+                let mut list = TopicPartitionList::new();
+                list.add_partition_offset(&self.topic, partition, Offset::Offset(commit))
+                    .map_err(|e| StreamError::Ack(e.to_string()))?;
+                self.consumer.commit(&list, CommitMode::Async).map_err(|e| StreamError::Ack(e.to_string()))?;
+            }
+            Ok(())
+        }
+
+        // Kafka has no per-message nack: rewind the partition to the failed offset so it is consumed again.
+        // The offset stays uncommitted, and later messages of the partition are redelivered as well.
+        async fn nack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            let AckToken::Offset { partition, offset } = task.ack else {
+                return Ok(());
+            };
+            self.consumer
+                .seek(&self.topic, partition, Offset::Offset(offset), Duration::from_secs(10))
+                .map_err(|e| StreamError::Ack(e.to_string()))
+        }
+    }
+
+    /// Publishes results as JSON, keyed by partition key so results keep the input's partitioning.
+    pub struct KafkaResultSink {
+        producer: FutureProducer,
+        topic: String,
+    }
+
+    impl KafkaResultSink {
+        pub fn new(brokers: &str, topic: &str) -> Result<Self, StreamError> {
+            let producer = ClientConfig::new()
+                .set("bootstrap.servers", brokers)
+                .set("enable.idempotence", "true")
+                .create()
+                .map_err(|e| StreamError::Connect(e.to_string()))?;
+            Ok(KafkaResultSink { producer, topic: topic.to_string() })
+        }
+    }
+
+    #[async_trait]
+    impl ResultSink for KafkaResultSink {
+        async fn publish(&self, result: &StreamResult) -> Result<(), StreamError> {
+            let body = serde_json::to_string(result).map_err(|e| StreamError::Publish(e.to_string()))?;
+            self.producer
+                .send(FutureRecord::to(&self.topic).key(&result.partition_key).payload(&body), Duration::from_secs(30))
+                .await
+                .map_err(|(e, _)| StreamError::Publish(e.to_string()))?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "nats")]
+pub mod nats {
+    use super::*;
+    use async_nats::jetstream::{self, consumer::pull, AckKind, Message};
+    use futures::StreamExt;
+
+    pub const PARTITION_HEADER: &str = "Swarms-Partition-Key";
+
+    /// Pulls from a durable JetStream consumer with explicit acks.
+    pub struct NatsTaskSource {
+        messages: pull::Stream,
+        pending: HashMap<String, Message>,
+    }
+
+    impl NatsTaskSource {
+        pub async fn new(url: &str, stream: &str, durable: &str, subject: &str) -> Result<Self, StreamError> {
+            let client = async_nats::connect(url).await.map_err(|e| StreamError::Connect(e.to_string()))?;
+            let js = jetstream::new(client);
+            let stream = js.get_stream(stream).await.map_err(|e| StreamError::Connect(e.to_string()))?;
+            let consumer = stream
+                .get_or_create_consumer(
+                    durable,
+                    pull::Config {
+                        durable_name: Some(durable.to_string()),
+                        filter_subject: subject.to_string(),
+                        ack_policy: jetstream::consumer::AckPolicy::Explicit,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .map_err(|e| StreamError::Connect(e.to_string()))?;
+            let messages = consumer.messages().await.map_err(|e| StreamError::Connect(e.to_string()))?;
+            Ok(NatsTaskSource { messages, pending: HashMap::new() })
+        }
+    }
+
+    #[async_trait]
+    impl TaskSource for NatsTaskSource {
+        async fn next(&mut self) -> Result<Option<StreamTask>, StreamError> {
+            let Some(message) = self.messages.next().await else {
+                return Ok(None);
+            };
+            let message = message.map_err(|e| StreamError::Receive(e.to_string()))?;
+            let reply = message.reply.as_ref().map(|r| r.to_string()).unwrap_or_default();
+            let partition_key = message
+                .headers
+                .as_ref()
+                .and_then(|h| h.get(PARTITION_HEADER))
+                .map(|v| v.to_string())
+                .unwrap_or_else(|| message.subject.to_string());
+            let task = StreamTask {
+                id: reply.clone(),
+                payload: String::from_utf8_lossy(&message.payload).into_owned(),
+                partition_key,
+                ack: AckToken::Reply(reply.clone()),
+            };
+            self.pending.insert(reply, message);
+            Ok(Some(task))
+        }
+
+        async fn ack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            let AckToken::Reply(reply) = &task.ack else {
+                return Ok(());
+            };
+            if let Some(message) = self.pending.remove(reply) {
+                message.ack().await.map_err(|e| StreamError::Ack(e.to_string()))?;
+            }
+            Ok(())
+        }
+
+        async fn nack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            let AckToken::Reply(reply) = &task.ack else {
+                return Ok(());
+            };
+            if let Some(message) = self.pending.remove(reply) {
+                message.ack_with(AckKind::Nak(None)).await.map_err(|e| StreamError::Ack(e.to_string()))?;
+            }
+            Ok(())
+        }
+    }
+
+    /// Publishes results to a JetStream subject and waits for the server's ack.
+    pub struct NatsResultSink {
+        js: jetstream::Context,
+        subject: String,
+    }
+
+    impl NatsResultSink {
+        pub async fn new(url: &str, subject: &str) -> Result<Self, StreamError> {
+            let client = async_nats::connect(url).await.map_err(|e| StreamError::Connect(e.to_string()))?;
+            Ok(NatsResultSink { js: jetstream::new(client), subject: subject.to_string() })
+        }
+    }
+
+    #[async_trait]
+    impl ResultSink for NatsResultSink {
+        async fn publish(&self, result: &StreamResult) -> Result<(), StreamError> {
+            let body = serde_json::to_vec(result).map_err(|e| StreamError::Publish(e.to_string()))?;
+            let mut headers = async_nats::HeaderMap::new();
+            headers.insert(PARTITION_HEADER, result.partition_key.as_str());
+            self.js
+                .publish_with_headers(self.subject.clone(), headers, body.into())
+                .await
+                .map_err(|e| StreamError::Publish(e.to_string()))?
+                .await
+                .map_err(|e| StreamError::Publish(e.to_string()))?;
+            Ok(())
+        }
+    }
+}
+
+fn main() {
+    let mut tracker = OffsetTracker::new();
+    for offset in 10..13 {
+        tracker.received(0, offset);
+    }
+    println!("{:?}", tracker.finish(0, 11)); // None: offset 10 is still running
+    println!("{:?}", tracker.finish(0, 10)); // Some(12): 10 and 11 are done
+}
+```
+
+### Notes
+* Enable with `--features kafka` (`rdkafka`) or `--features nats` (`async-nats`); the traits and `OffsetTracker` are always available.
+* A task that always fails is redelivered forever. Bound it on the broker, for example with the JetStream
+  consumer's `max_deliver`, or route it to a dead-letter topic from the agent.
+* Results may be published twice after a crash, because the ack can be lost after the publish succeeds. Consumers of the result topic should deduplicate on `task_id`.
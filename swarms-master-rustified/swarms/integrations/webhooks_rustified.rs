@@ -0,0 +1,433 @@
+### Overview
+There is no Python counterpart. Outbound webhooks let external orchestration systems follow swarm runs
+without polling: callback URLs are registered per swarm (in `SwarmConfig.webhooks`) or per run, and the
+runtime POSTs a JSON payload for each lifecycle event. Every delivery is signed with HMAC-SHA256 so the
+receiver can verify it came from us, and failed deliveries are retried with exponential backoff.
+
+`SequentialWorkflow::with_webhooks` and `TaskQueueSwarm::with_webhooks` emit `run_started` when a run
+begins, `agent_step_completed` for each agent turn or task that succeeds, and `run_completed`, `run_failed`,
+or `budget_exceeded` when it ends. `workflow_with_agents` registers a config's `webhooks` for its swarm.
+
+Receivers verify the `X-Swarms-Signature` header, which has the form `t=<unix seconds>,v1=<hex digest>`
+where the digest is `HMAC-SHA256(secret, "<t>.<raw body>")`. `verify_signature` implements the check.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::notifications::{RunEvent, RunSummary};
+use crate::swarms::integrations::rest::{HttpMethod, HttpRequest, HttpTransport, ReqwestTransport, RestError};
+use crate::swarms::text::prompt_privacy::PromptPrivacy;
+use crate::swarms::text::redaction::Redactor;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+use uuid::Uuid;
+
+pub const SIGNATURE_HEADER: &str = "X-Swarms-Signature";
+pub const EVENT_HEADER: &str = "X-Swarms-Event";
+pub const DELIVERY_HEADER: &str = "X-Swarms-Delivery";
+
+/// Signatures older than this are rejected by `verify_signature`.
+pub const SIGNATURE_TOLERANCE_SECS: i64 = 300;
+
+/// Run lifecycle events delivered to webhooks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvent {
+    RunStarted,
+    AgentStepCompleted,
+    RunCompleted,
+    RunFailed,
+    BudgetExceeded,
+}
+
+impl WebhookEvent {
+    pub const ALL: [WebhookEvent; 5] = [
+        WebhookEvent::RunStarted,
+        WebhookEvent::AgentStepCompleted,
+        WebhookEvent::RunCompleted,
+        WebhookEvent::RunFailed,
+        WebhookEvent::BudgetExceeded,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            WebhookEvent::RunStarted => "run_started",
+            WebhookEvent::AgentStepCompleted => "agent_step_completed",
+            WebhookEvent::RunCompleted => "run_completed",
+            WebhookEvent::RunFailed => "run_failed",
+            WebhookEvent::BudgetExceeded => "budget_exceeded",
+        }
+    }
+}
+
+impl From<RunEvent> for WebhookEvent {
+    fn from(event: RunEvent) -> Self {
+        match event {
+            RunEvent::Completed => WebhookEvent::RunCompleted,
+            RunEvent::Failed => WebhookEvent::RunFailed,
+            RunEvent::BudgetExceeded => WebhookEvent::BudgetExceeded,
+        }
+    }
+}
+
+/// The JSON body POSTed to every webhook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookPayload {
+    /// Unique per event; the same across retries so receivers can deduplicate.
+    pub delivery_id: String,
+    pub event: WebhookEvent,
+    pub swarm_name: String,
+    pub run_id: String,
+    pub occurred_at: String,
+    pub data: JsonValue,
+}
+
+impl WebhookPayload {
+    pub fn new(event: WebhookEvent, swarm_name: &str, run_id: &str, data: JsonValue) -> Self {
+        WebhookPayload {
+            delivery_id: Uuid::new_v4().to_string(),
+            event,
+            swarm_name: swarm_name.to_string(),
+            run_id: run_id.to_string(),
+            occurred_at: Utc::now().to_rfc3339(),
+            data,
+        }
+    }
+
+    pub fn from_summary(run: &RunSummary) -> Self {
+        WebhookPayload::new(
+            run.event.into(),
+            &run.swarm_name,
+            &run.run_id,
+            json!({
+                "summary": run.summary,
+                "report_url": run.report_url,
+                "duration_secs": run.duration_secs,
+                "cost_usd": run.cost_usd,
+            }),
+        )
+    }
+}
+
+fn hmac_hex(secret: &str, timestamp: i64, body: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    hex::encode(mac.finalize().into_bytes())
+}
+
+/// The `X-Swarms-Signature` header value for `body` sent at `timestamp`.
+pub fn sign(secret: &str, timestamp: i64, body: &str) -> String {
+    format!("t={},v1={}", timestamp, hmac_hex(secret, timestamp, body))
+}
+
+/// Check a signature header against the raw request body. `now` is unix seconds.
+pub fn verify_signature(secret: &str, header: &str, body: &str, now: i64) -> bool {
+    let mut timestamp = None;
+    let mut digest = None;
+    for part in header.split(',') {
+        match part.trim().split_once('=') {
+            Some(("t", value)) => timestamp = value.parse::<i64>().ok(),
+            Some(("v1", value)) => digest = hex::decode(value).ok(),
+            _ => {}
+        }
+    }
+    let (Some(timestamp), Some(digest)) = (timestamp, digest) else {
+        return false;
+    };
+    if (now - timestamp).abs() > SIGNATURE_TOLERANCE_SECS {
+        return false;
+    }
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}.{}", timestamp, body).as_bytes());
+    // `verify_slice` compares in constant time.
+    mac.verify_slice(&digest).is_ok()
+}
+
+fn default_events() -> Vec<WebhookEvent> {
+    WebhookEvent::ALL.to_vec()
+}
+
+/// A webhook as written in `SwarmConfig.webhooks`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Environment variable holding the signing secret.
+    pub secret_env: String,
+    #[serde(default = "default_events")]
+    pub events: Vec<WebhookEvent>,
+}
+
+/// A registered callback.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Webhook {
+    pub id: String,
+    pub url: String,
+    secret: String,
+    pub events: Vec<WebhookEvent>,
+}
+
+impl Webhook {
+    pub fn new(url: impl Into<String>, secret: impl Into<String>, events: &[WebhookEvent]) -> Self {
+        Webhook { id: Uuid::new_v4().to_string(), url: url.into(), secret: secret.into(), events: events.to_vec() }
+    }
+
+    pub fn from_config(config: &WebhookConfig) -> Result<Self, RestError> {
+        let secret = env::var(&config.secret_env)
+            .map_err(|_| RestError::InvalidQuery(format!("{} environment variable is not set", config.secret_env)))?;
+        Ok(Webhook::new(config.url.clone(), secret, &config.events))
+    }
+}
+
+/// Retry schedule: `max_attempts` tries, doubling the delay after each failure.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1))).min(self.max_backoff)
+    }
+}
+
+/// Whether a failed delivery is worth retrying: transport errors, 408, 429, and 5xx are; other 4xx are not.
+fn is_retriable(err: &RestError) -> bool {
+    match err.status() {
+        Some(status) => status == 408 || status == 429 || status >= 500,
+        None => matches!(err, RestError::Transport(_)),
+    }
+}
+
+/// Holds per-swarm and per-run webhooks and delivers events to them.
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    transport: Arc<dyn HttpTransport>,
+    retry: RetryPolicy,
+    swarm_hooks: Arc<RwLock<HashMap<String, Vec<Webhook>>>>,
+    run_hooks: Arc<RwLock<HashMap<String, Vec<Webhook>>>>,
+    redactor: Option<Arc<Redactor>>,
+    privacy: PromptPrivacy,
+    run_privacy: Arc<RwLock<HashMap<String, PromptPrivacy>>>,
+}
+
+impl WebhookDispatcher {
+    pub fn new() -> Self {
+        WebhookDispatcher {
+            transport: Arc::new(ReqwestTransport::new()),
+            retry: RetryPolicy::default(),
+            swarm_hooks: Arc::new(RwLock::new(HashMap::new())),
+            run_hooks: Arc::new(RwLock::new(HashMap::new())),
+            redactor: None,
+            privacy: PromptPrivacy::Raw,
+            run_privacy: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.transport = transport;
+        self
+    }
+
+    pub fn with_retry_policy(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Redact PII from every payload's `data` before it leaves the process.
+    pub fn with_redactor(mut self, redactor: Arc<Redactor>) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    /// What payloads keep of prompt and response bodies, for runs without a mode of their own.
+    pub fn with_privacy(mut self, privacy: PromptPrivacy) -> Self {
+        self.privacy = privacy;
+        self
+    }
+
+    /// Override the privacy mode for one run's payloads, until `finish_run`.
+    pub fn set_run_privacy(&self, run_id: &str, privacy: PromptPrivacy) {
+        self.run_privacy.write().unwrap().insert(run_id.to_string(), privacy);
+    }
+
+    fn privacy_for(&self, run_id: &str) -> PromptPrivacy {
+        self.run_privacy.read().unwrap().get(run_id).copied().unwrap_or(self.privacy)
+    }
+
+    /// Register a webhook for every run of `swarm_name`. Returns its id.
+    pub fn register_for_swarm(&self, swarm_name: &str, webhook: Webhook) -> String {
+        let id = webhook.id.clone();
+        self.swarm_hooks.write().unwrap().entry(swarm_name.to_string()).or_default().push(webhook);
+        id
+    }
+
+    /// Register the webhooks from a swarm's configuration.
+    pub fn register_configs(&self, swarm_name: &str, configs: &[WebhookConfig]) -> Result<(), RestError> {
+        for config in configs {
+            self.register_for_swarm(swarm_name, Webhook::from_config(config)?);
+        }
+        Ok(())
+    }
+
+    /// Register a webhook for a single run. Returns its id.
+    pub fn register_for_run(&self, run_id: &str, webhook: Webhook) -> String {
+        let id = webhook.id.clone();
+        self.run_hooks.write().unwrap().entry(run_id.to_string()).or_default().push(webhook);
+        id
+    }
+
+    pub fn unregister(&self, webhook_id: &str) -> bool {
+        let mut removed = false;
+        for hooks in [&self.swarm_hooks, &self.run_hooks] {
+            for list in hooks.write().unwrap().values_mut() {
+                let before = list.len();
+                list.retain(|h| h.id != webhook_id);
+                removed |= list.len() != before;
+            }
+        }
+        removed
+    }
+
+    /// Drop per-run registrations once the run is over.
+    pub fn finish_run(&self, run_id: &str) {
+        self.run_hooks.write().unwrap().remove(run_id);
+        self.run_privacy.write().unwrap().remove(run_id);
+    }
+
+    fn subscribers(&self, payload: &WebhookPayload) -> Vec<Webhook> {
+        let swarm = self.swarm_hooks.read().unwrap();
+        let run = self.run_hooks.read().unwrap();
+        swarm
+            .get(&payload.swarm_name)
+            .into_iter()
+            .chain(run.get(&payload.run_id))
+            .flatten()
+            .filter(|h| h.events.contains(&payload.event))
+            .cloned()
+            .collect()
+    }
+
+    /// Build the signed request for one delivery.
+    pub fn signed_request(webhook: &Webhook, payload: &WebhookPayload, timestamp: i64) -> Result<HttpRequest, RestError> {
+        let body = serde_json::to_value(payload).map_err(|e| RestError::InvalidQuery(e.to_string()))?;
+        // The transport serializes `body` with serde_json as well, so the signed bytes match the sent bytes.
+        let raw = serde_json::to_string(&body).map_err(|e| RestError::InvalidQuery(e.to_string()))?;
+        Ok(HttpRequest {
+            method: HttpMethod::Post,
+            url: webhook.url.clone(),
+            headers: vec![
+                ("Content-Type".to_string(), "application/json".to_string()),
+                (SIGNATURE_HEADER.to_string(), sign(&webhook.secret, timestamp, &raw)),
+                (EVENT_HEADER.to_string(), payload.event.as_str().to_string()),
+                (DELIVERY_HEADER.to_string(), payload.delivery_id.clone()),
+            ],
+            query: Vec::new(),
+            body: Some(body),
+        })
+    }
+
+    /// Deliver to one webhook, retrying per the policy. Each attempt is re-signed with a fresh timestamp.
+    pub async fn deliver(&self, webhook: &Webhook, payload: &WebhookPayload) -> Result<u32, RestError> {
+        let mut attempt = 1;
+        loop {
+            let request = Self::signed_request(webhook, payload, Utc::now().timestamp())?;
+            let result = match self.transport.send(request).await {
+                Ok(response) if (200..300).contains(&response.status) => return Ok(attempt),
+                Ok(response) => Err(RestError::Api {
+                    status: response.status,
+                    message: format!("webhook returned {}", response.status),
+                    body: response.body,
+                }),
+                Err(err) => Err(err),
+            };
+            let err = result.unwrap_err();
+            if attempt >= self.retry.max_attempts || !is_retriable(&err) {
+                return Err(err);
+            }
+            warn!("Webhook {} attempt {} failed: {}; retrying", webhook.url, attempt, err);
+            tokio::time::sleep(self.retry.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+
+    /// Deliver `payload` to every subscribed webhook concurrently and wait for all of them.
+    /// Returns how many deliveries ultimately failed; failures never propagate to the run.
+    pub async fn emit(&self, mut payload: WebhookPayload) -> usize {
+        let hooks = self.subscribers(&payload);
+        payload.data = self.privacy_for(&payload.run_id).apply_json(&payload.data);
+        if let Some(redactor) = &self.redactor {
+            payload.data = redactor.redact_json(&payload.data);
+        }
+        let deliveries = hooks.iter().map(|hook| self.deliver(hook, &payload));
+        let mut failed = 0;
+        for (hook, result) in hooks.iter().zip(futures::future::join_all(deliveries).await) {
+            match result {
+                Ok(attempts) => info!("Delivered {} to {} after {} attempt(s)", payload.event.as_str(), hook.url, attempts),
+                Err(err) => {
+                    warn!("Giving up on {} delivery to {}: {}", payload.event.as_str(), hook.url, err);
+                    failed += 1;
+                }
+            }
+        }
+        failed
+    }
+
+    /// Fire-and-forget variant of `emit` for use inside the run loop, spawned on the current Tokio runtime.
+    /// Without a runtime there is nothing to deliver on, so the event is dropped with a warning.
+    pub fn spawn_emit(&self, payload: WebhookPayload) {
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                let dispatcher = self.clone();
+                handle.spawn(async move {
+                    dispatcher.emit(payload).await;
+                });
+            }
+            Err(_) => warn!("No Tokio runtime to deliver {} of run {} on", payload.event.as_str(), payload.run_id),
+        }
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let dispatcher = WebhookDispatcher::new();
+    dispatcher.register_for_swarm(
+        "Financial-Analysis-Swarm",
+        Webhook::new("https://orchestrator.example.com/hooks/swarms", "s3cret", &[WebhookEvent::RunCompleted]),
+    );
+    let payload = WebhookPayload::new(
+        WebhookEvent::RunCompleted,
+        "Financial-Analysis-Swarm",
+        "run-42",
+        json!({ "summary": "Report ready" }),
+    );
+    let failed = dispatcher.emit(payload).await;
+    println!("{} failed deliveries", failed);
+}
+```
+
+### Notes
+* Retries re-sign with a fresh timestamp, so a delivery that succeeds late still passes the receiver's freshness check.
+* `delivery_id` stays the same across retries; receivers should treat it as an idempotency key.
+* Per-run registrations and privacy modes are kept until `finish_run` is called.
+* Swarms emit each event as its own background delivery, so a receiver can see them out of order; sort on
+  `occurred_at` when order matters.
+* The privacy mode is applied before the redactor, so truncated text is still scanned for personal data.
@@ -0,0 +1,367 @@
+### Overview
+There is no Python counterpart; the Python examples hand documents to LlamaIndex or a vector database and
+never read another pipeline's output. Teams arriving with a corpus that LangChain or LlamaIndex already
+split, and often embedded, should not have to load the source files and chunk them again.
+
+This module reads and writes the JSON those pipelines save:
+
+* **LangChain** `Document`s, as `{page_content, metadata, id}` objects or in the `{"lc": 1, "type":
+  "constructor", "kwargs": {...}}` envelope `langchain_core.load.dumps` writes.
+* **LlamaIndex** `TextNode`s, as `{id_, text, metadata, embedding, start_char_idx, end_char_idx,
+  relationships}` objects, alone or inside a docstore's `{"docstore/data": {id: {"__data__": ...}}}`.
+
+`read_corpus` takes a JSON file (one document, a list, or a docstore) or a JSONL file with one document
+per line, and detects the format of each document. Every document becomes an `ExternalChunk`: a `Chunk`
+plus the id, metadata, and embedding the pipeline stored. `VectorMemory::add_external` stores them. It uses
+the stored embeddings as they are and embeds only the chunks that have none, so an embedded corpus loads
+without a single embedding call. `to_langchain` and `to_llamaindex` write chunks back out.
+
+Where the chunk fields come from:
+
+| `Chunk` | LangChain | LlamaIndex |
+| --- | --- | --- |
+| `source` | `metadata.source` | `metadata.file_path`, `metadata.file_name`, or the `SOURCE` relationship |
+| `char_range` | `metadata.start_index` and the text length | `start_char_idx`, `end_char_idx` |
+| `heading_path` | `Header 1`, `Header 2`, ... metadata | `metadata.header_path` |
+| `index` | position among the corpus's chunks of the same source | the same |
+
+Both pipelines count offsets in characters, while a `Chunk`'s `start` and `end` are bytes. The pipeline's
+offsets are kept as `ExternalChunk::char_range`, and the chunk's byte offsets stay `0` until `locate`
+converts them against the source text.
+
+### Rust Code
+```rust
+use crate::swarms::text::chunk::Chunk;
+use crate::swarms::text::tokenizer::default_tokenizer;
+use crate::swarms::structs::citation::Citation;
+use serde_json::{json, Map, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+/// The `NodeRelationship.SOURCE` key in LlamaIndex `relationships`.
+const LLAMAINDEX_SOURCE: &str = "1";
+
+/// The pipeline that wrote a document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DocumentFormat {
+    LangChain,
+    LlamaIndex,
+}
+
+impl DocumentFormat {
+    /// The format of one serialized document, or `None` when it is neither.
+    pub fn detect(document: &JsonValue) -> Option<Self> {
+        let document = unwrap_envelope(document);
+        if document.get("page_content").is_some() {
+            Some(DocumentFormat::LangChain)
+        } else if document.get("text").is_some() && (document.get("id_").is_some() || document.get("class_name").is_some()) {
+            Some(DocumentFormat::LlamaIndex)
+        } else {
+            None
+        }
+    }
+}
+
+/// A chunk from another pipeline's corpus, with what that pipeline stored alongside it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalChunk {
+    /// The pipeline's id for the chunk; `add_external` keeps it as the record id.
+    pub id: Option<String>,
+    pub chunk: Chunk,
+    /// Non-string values are kept as their JSON text.
+    pub metadata: HashMap<String, String>,
+    /// Used as is by `add_external` instead of embedding the text again.
+    pub embedding: Option<Vec<f32>>,
+    /// The pipeline's offsets into the source, in characters. `None` for chunks from `Chunker`, whose
+    /// offsets are the chunk's own byte offsets.
+    pub char_range: Option<Range<usize>>,
+}
+
+impl From<Chunk> for ExternalChunk {
+    fn from(chunk: Chunk) -> Self {
+        ExternalChunk { id: None, chunk, metadata: HashMap::new(), embedding: None, char_range: None }
+    }
+}
+
+impl ExternalChunk {
+    /// Set the chunk's byte offsets from `char_range`, counted in `source`, the text the pipeline split.
+    /// Returns `false`, leaving the chunk as it was, when there is no range or it runs past the end of `source`.
+    pub fn locate(&mut self, source: &str) -> bool {
+        let Some(range) = &self.char_range else {
+            return false;
+        };
+        let byte = |chars: usize| source.char_indices().map(|(at, _)| at).chain([source.len()]).nth(chars);
+        match (byte(range.start), byte(range.end)) {
+            (Some(start), Some(end)) if start <= end => {
+                self.chunk.start = start;
+                self.chunk.end = end;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// The citation for a record made from this chunk. Imported chunks are cited by the pipeline's character
+    /// offsets, which are known even when the chunk was never located.
+    pub fn citation(&self) -> Citation {
+        let Some(range) = &self.char_range else {
+            return Citation::from_chunk(&self.chunk);
+        };
+        let source = self.chunk.source.clone().unwrap_or_else(|| "unknown".to_string());
+        let mut citation = Citation::new(source, Some(format!("chars {}..{}", range.start, range.end)));
+        citation.title = self.chunk.heading_path.last().cloned();
+        citation
+    }
+}
+
+#[derive(Debug)]
+pub enum DocumentFormatError {
+    Io { path: PathBuf, source: io::Error },
+    /// A JSONL line, 1-based, or the whole file when `line` is `None`, that does not parse.
+    Json { line: Option<usize>, message: String },
+    /// A value that is neither a LangChain document nor a LlamaIndex node.
+    Unrecognized(String),
+}
+
+impl fmt::Display for DocumentFormatError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DocumentFormatError::Io { path, source } => write!(f, "failed to read {}: {}", path.display(), source),
+            DocumentFormatError::Json { line: Some(line), message } => write!(f, "line {} is not JSON: {}", line, message),
+            DocumentFormatError::Json { line: None, message } => write!(f, "corpus is not JSON: {}", message),
+            DocumentFormatError::Unrecognized(found) => {
+                write!(f, "not a LangChain document or LlamaIndex node: {}", found)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DocumentFormatError {}
+
+// The document inside a `langchain_core.load.dumps` envelope or a docstore `__data__` entry
+fn unwrap_envelope(document: &JsonValue) -> &JsonValue {
+    if document.get("lc").is_some() {
+        if let Some(kwargs) = document.get("kwargs") {
+            return kwargs;
+        }
+    }
+    document.get("__data__").unwrap_or(document)
+}
+
+fn metadata_strings(metadata: Option<&JsonValue>) -> HashMap<String, String> {
+    let Some(JsonValue::Object(map)) = metadata else {
+        return HashMap::new();
+    };
+    map.iter()
+        .filter(|(_, value)| !value.is_null())
+        .map(|(key, value)| {
+            let value = match value {
+                JsonValue::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            (key.clone(), value)
+        })
+        .collect()
+}
+
+fn offset(value: Option<&JsonValue>) -> Option<usize> {
+    value.and_then(JsonValue::as_u64).map(|offset| offset as usize)
+}
+
+// A chunk with unknown byte offsets (see `ExternalChunk::locate`)
+fn chunk(text: String, source: Option<String>, heading_path: Vec<String>) -> Chunk {
+    let token_count = default_tokenizer().count_tokens(&text);
+    Chunk { text, index: 0, start: 0, end: 0, token_count, source, heading_path }
+}
+
+// A pipeline's character offsets; a missing end is the end of the text
+fn char_range(text: &str, start: Option<usize>, end: Option<usize>) -> Option<Range<usize>> {
+    let start = start?;
+    Some(start..end.unwrap_or(start + text.chars().count()))
+}
+
+fn from_langchain(document: &JsonValue) -> ExternalChunk {
+    let metadata = metadata_strings(document.get("metadata"));
+    // `MarkdownHeaderTextSplitter` names headings by level
+    let mut headers: Vec<(u32, String)> = metadata
+        .iter()
+        .filter_map(|(key, value)| Some((key.strip_prefix("Header ")?.parse().ok()?, value.clone())))
+        .collect();
+    headers.sort();
+    let text = document["page_content"].as_str().unwrap_or_default().to_string();
+    let start = metadata.get("start_index").and_then(|start| start.parse().ok());
+    let source = metadata.get("source").cloned();
+    ExternalChunk {
+        id: document.get("id").and_then(JsonValue::as_str).map(str::to_string),
+        char_range: char_range(&text, start, None),
+        chunk: chunk(text, source, headers.into_iter().map(|(_, heading)| heading).collect()),
+        metadata,
+        embedding: None,
+    }
+}
+
+fn from_llamaindex(node: &JsonValue) -> ExternalChunk {
+    let metadata = metadata_strings(node.get("metadata"));
+    let source = metadata
+        .get("file_path")
+        .or_else(|| metadata.get("file_name"))
+        .cloned()
+        .or_else(|| node["relationships"][LLAMAINDEX_SOURCE]["node_id"].as_str().map(str::to_string));
+    let heading_path = metadata
+        .get("header_path")
+        .map(|path| path.split('/').filter(|heading| !heading.is_empty()).map(str::to_string).collect())
+        .unwrap_or_default();
+    let embedding = node
+        .get("embedding")
+        .and_then(JsonValue::as_array)
+        .map(|values| values.iter().filter_map(JsonValue::as_f64).map(|value| value as f32).collect());
+    let text = node["text"].as_str().unwrap_or_default().to_string();
+    ExternalChunk {
+        id: node.get("id_").and_then(JsonValue::as_str).map(str::to_string),
+        char_range: char_range(&text, offset(node.get("start_char_idx")), offset(node.get("end_char_idx"))),
+        chunk: chunk(text, source, heading_path),
+        metadata,
+        embedding,
+    }
+}
+
+/// One LangChain document or LlamaIndex node, in either's serialized form.
+pub fn parse_document(document: &JsonValue) -> Result<ExternalChunk, DocumentFormatError> {
+    let inner = unwrap_envelope(document);
+    match DocumentFormat::detect(inner) {
+        Some(DocumentFormat::LangChain) => Ok(from_langchain(inner)),
+        Some(DocumentFormat::LlamaIndex) => Ok(from_llamaindex(inner)),
+        None => {
+            let mut found = document.to_string();
+            found.truncate(80);
+            Err(DocumentFormatError::Unrecognized(found))
+        }
+    }
+}
+
+/// Every document in `corpus`: one document, a list of them, or a LlamaIndex docstore. Chunks are numbered
+/// by their position among the chunks of the same source.
+pub fn parse_corpus(corpus: &JsonValue) -> Result<Vec<ExternalChunk>, DocumentFormatError> {
+    let documents: Vec<&JsonValue> = match corpus {
+        JsonValue::Array(documents) => documents.iter().collect(),
+        JsonValue::Object(map) if map.contains_key("docstore/data") => match &map["docstore/data"] {
+            JsonValue::Object(entries) => entries.values().collect(),
+            _ => Vec::new(),
+        },
+        document => vec![document],
+    };
+    let mut chunks = documents.into_iter().map(parse_document).collect::<Result<Vec<_>, _>>()?;
+    number_by_source(&mut chunks);
+    Ok(chunks)
+}
+
+fn number_by_source(chunks: &mut [ExternalChunk]) {
+    let mut seen: HashMap<Option<String>, usize> = HashMap::new();
+    for external in chunks {
+        let index = seen.entry(external.chunk.source.clone()).or_default();
+        external.chunk.index = *index;
+        *index += 1;
+    }
+}
+
+/// `parse_corpus` on a JSON file, or on a JSONL file with one document per line.
+pub fn read_corpus(path: impl AsRef<Path>) -> Result<Vec<ExternalChunk>, DocumentFormatError> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).map_err(|source| DocumentFormatError::Io { path: path.to_path_buf(), source })?;
+    if let Ok(corpus) = serde_json::from_str::<JsonValue>(&text) {
+        return parse_corpus(&corpus);
+    }
+    let mut chunks = Vec::new();
+    for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let document: JsonValue = serde_json::from_str(line)
+            .map_err(|err| DocumentFormatError::Json { line: Some(index + 1), message: err.to_string() })?;
+        chunks.push(parse_document(&document)?);
+    }
+    number_by_source(&mut chunks);
+    Ok(chunks)
+}
+
+// The metadata written out: the chunk's own, then the fields the chunk carries, under the format's names
+fn metadata_json(external: &ExternalChunk) -> Map<String, JsonValue> {
+    external.metadata.iter().map(|(key, value)| (key.clone(), JsonValue::String(value.clone()))).collect()
+}
+
+/// A LangChain `Document`, as `Document.model_dump()` writes it.
+pub fn to_langchain(external: &ExternalChunk) -> JsonValue {
+    let chunk = &external.chunk;
+    let mut metadata = metadata_json(external);
+    if let Some(source) = &chunk.source {
+        metadata.insert("source".to_string(), json!(source));
+    }
+    if let Some(range) = &external.char_range {
+        metadata.insert("start_index".to_string(), json!(range.start));
+    }
+    for (level, heading) in chunk.heading_path.iter().enumerate() {
+        metadata.insert(format!("Header {}", level + 1), json!(heading));
+    }
+    json!({ "id": external.id, "metadata": metadata, "page_content": chunk.text, "type": "Document" })
+}
+
+/// A LlamaIndex `TextNode`, as `TextNode.to_dict()` writes it. Fields left out take LlamaIndex's defaults.
+pub fn to_llamaindex(external: &ExternalChunk) -> JsonValue {
+    let chunk = &external.chunk;
+    let mut metadata = metadata_json(external);
+    let mut relationships = Map::new();
+    if let Some(source) = &chunk.source {
+        metadata.insert("file_path".to_string(), json!(source));
+        relationships.insert(LLAMAINDEX_SOURCE.to_string(), json!({ "node_id": source, "class_name": "RelatedNodeInfo" }));
+    }
+    if !chunk.heading_path.is_empty() {
+        metadata.insert("header_path".to_string(), json!(format!("/{}/", chunk.heading_path.join("/"))));
+    }
+    let mut node = json!({
+        "embedding": external.embedding,
+        "metadata": metadata,
+        "relationships": relationships,
+        "text": chunk.text,
+        "start_char_idx": external.char_range.as_ref().map(|range| range.start),
+        "end_char_idx": external.char_range.as_ref().map(|range| range.end),
+        "class_name": "TextNode",
+    });
+    if let Some(id) = &external.id {
+        node["id_"] = json!(id);
+    }
+    node
+}
+
+fn main() -> Result<(), DocumentFormatError> {
+    let corpus = json!([
+        {
+            "lc": 1, "type": "constructor", "id": ["langchain", "schema", "document", "Document"],
+            "kwargs": { "page_content": "Revenue grew 12% in Q3.", "metadata": { "source": "q3.pdf", "page": 2, "start_index": 140 } }
+        },
+        {
+            "id_": "node-7", "text": "Hiring paused in October.", "embedding": [0.1, 0.9, 0.0],
+            "metadata": { "file_name": "memo.txt" }, "start_char_idx": 0, "end_char_idx": 25, "class_name": "TextNode"
+        }
+    ]);
+    for external in parse_corpus(&corpus)? {
+        println!(
+            "{:?} #{} {}..{}: {} (embedded: {})",
+            external.chunk.source,
+            external.chunk.index,
+            external.char_range.as_ref().map_or(0, |range| range.start),
+            external.char_range.as_ref().map_or(0, |range| range.end),
+            external.chunk.text,
+            external.embedding.is_some()
+        );
+        println!("  as LlamaIndex: {}", to_llamaindex(&external));
+    }
+    Ok(())
+}
+```
+
+### Notes
+* Chunks from `Chunker` are written without `start_index` or `start_char_idx`: they only know byte offsets, and writing those where the pipelines expect characters would point into the wrong place in non-ASCII text.
+* A stored embedding is only useful with the embedder that produced it. `add_external` checks the dimension, not the model; load a corpus embedded with `text-embedding-3-small` into a memory whose `Embedder` uses the same model, or drop the embeddings and let it re-embed.
+* Metadata values come back as strings: `to_langchain(read)` writes `"page": "2"` where LangChain wrote `"page": 2`.
+* LlamaIndex `Document`s (whole files, not split into nodes) also load: they have the same fields as a `TextNode`.
@@ -0,0 +1,306 @@
+### Overview
+There is no Python counterpart; the Python examples keep long-term memory in a ChromaDB directory that is
+tied to the machine and the Chroma version that wrote it. `VectorMemory::export` writes the whole store to
+one self-contained file that `VectorMemory::import` loads anywhere, into the in-memory store or into a
+disk-backed one. The file can be copied between environments or kept in an artifact store next to the
+agent's config.
+
+The archive is JSON Lines:
+
+* The first line is the `MemoryManifest`: the format and version, the vector dimension, record counts per
+  namespace, the source documents the records were chunked from (taken from their citations), and the
+  SHA-256 of the record lines.
+* Every other line is one record: id, namespace, text, metadata, citation, and the embedding as base64 of
+  its little-endian `f32`s. Base64 keeps the vectors exact, which decimal JSON numbers would not.
+
+Nothing needs re-embedding on import, so an archive loads without the embedder's API key. The embedder
+bound to the importing store must still be the model that produced the vectors, or recall compares
+vectors from different spaces.
+
+### Rust Code
+```rust
+use crate::swarms::memory::vector_memory::{MemoryError, MemoryRecord};
+use crate::swarms::structs::citation::Citation;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+pub const ARCHIVE_FORMAT: &str = "swarms-vector-memory";
+
+/// Bumped when the record layout changes. `import` rejects archives newer than it understands.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// A document the archived records were chunked from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceDocument {
+    /// The citation's URL, file path, or document id.
+    pub source: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    pub chunks: usize,
+    pub namespaces: BTreeSet<String>,
+}
+
+/// The first line of an archive.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MemoryManifest {
+    pub format: String,
+    pub version: u32,
+    /// `None` when the exported store was empty and had no dimension yet.
+    pub dimension: Option<usize>,
+    pub exported_at: String,
+    pub records: usize,
+    pub namespaces: BTreeMap<String, usize>,
+    /// Sorted by source. Records without a citation are counted in `namespaces` only.
+    pub sources: Vec<SourceDocument>,
+    /// SHA-256 of every line after this one, newlines included.
+    pub records_sha256: String,
+}
+
+/// One record line.
+#[derive(Serialize, Deserialize)]
+struct ArchivedRecord {
+    id: String,
+    namespace: String,
+    text: String,
+    #[serde(default)]
+    metadata: HashMap<String, String>,
+    citation: Option<Citation>,
+    embedding: String,
+}
+
+fn archive_error(path: &Path, message: impl fmt::Display) -> MemoryError {
+    MemoryError::Archive { path: path.to_path_buf(), message: message.to_string() }
+}
+
+// `<path>.<suffix>`, next to the archive so the final rename stays on one filesystem
+fn sibling(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().map(|name| name.to_os_string()).unwrap_or_default();
+    name.push(format!(".{}", suffix));
+    path.with_file_name(name)
+}
+
+/// Writes an archive record by record. The records go to a scratch file while the manifest is counted up;
+/// `finish` writes the manifest and the records to the archive and swaps it in with a rename, so a failed
+/// export never leaves a partial archive at `path`.
+pub struct ArchiveWriter {
+    path: PathBuf,
+    body_path: PathBuf,
+    // Taken by `finish`
+    body: Option<BufWriter<File>>,
+    hasher: Sha256,
+    records: usize,
+    namespaces: BTreeMap<String, usize>,
+    sources: BTreeMap<String, SourceDocument>,
+}
+
+impl ArchiveWriter {
+    pub fn create(path: impl AsRef<Path>) -> Result<Self, MemoryError> {
+        let path = path.as_ref().to_path_buf();
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            fs::create_dir_all(parent).map_err(|err| archive_error(&path, err))?;
+        }
+        let body_path = sibling(&path, "records");
+        let body = BufWriter::new(File::create(&body_path).map_err(|err| archive_error(&path, err))?);
+        Ok(ArchiveWriter {
+            path,
+            body_path,
+            body: Some(body),
+            hasher: Sha256::new(),
+            records: 0,
+            namespaces: BTreeMap::new(),
+            sources: BTreeMap::new(),
+        })
+    }
+
+    pub fn push(&mut self, record: &MemoryRecord) -> Result<(), MemoryError> {
+        let bytes: Vec<u8> = record.embedding.iter().flat_map(|value| value.to_le_bytes()).collect();
+        let line = ArchivedRecord {
+            id: record.id.clone(),
+            namespace: record.namespace.clone(),
+            text: record.text.clone(),
+            metadata: record.metadata.clone(),
+            citation: record.citation.clone(),
+            embedding: BASE64.encode(bytes),
+        };
+        let mut encoded = serde_json::to_vec(&line).map_err(|err| archive_error(&self.path, err))?;
+        encoded.push(b'\n');
+        self.hasher.update(&encoded);
+        let body = self.body.as_mut().expect("the writer is open until finish");
+        body.write_all(&encoded).map_err(|err| archive_error(&self.path, err))?;
+
+        self.records += 1;
+        *self.namespaces.entry(record.namespace.clone()).or_default() += 1;
+        if let Some(citation) = &record.citation {
+            let source = self.sources.entry(citation.source.clone()).or_insert_with(|| SourceDocument {
+                source: citation.source.clone(),
+                title: None,
+                chunks: 0,
+                namespaces: BTreeSet::new(),
+            });
+            source.title = source.title.take().or_else(|| citation.title.clone());
+            source.chunks += 1;
+            source.namespaces.insert(record.namespace.clone());
+        }
+        Ok(())
+    }
+
+    /// Write the archive. `dimension` is the exporting store's.
+    pub fn finish(mut self, dimension: Option<usize>) -> Result<MemoryManifest, MemoryError> {
+        let path = self.path.clone();
+        let manifest = MemoryManifest {
+            format: ARCHIVE_FORMAT.to_string(),
+            version: ARCHIVE_VERSION,
+            dimension,
+            exported_at: Utc::now().to_rfc3339(),
+            records: self.records,
+            namespaces: std::mem::take(&mut self.namespaces),
+            sources: std::mem::take(&mut self.sources).into_values().collect(),
+            records_sha256: hex::encode(std::mem::take(&mut self.hasher).finalize()),
+        };
+        let partial = sibling(&path, "partial");
+        let body = self.body.take().expect("finish is called once");
+        let written = (|| -> io::Result<()> {
+            body.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+            let mut out = BufWriter::new(File::create(&partial)?);
+            serde_json::to_writer(&mut out, &manifest)?;
+            out.write_all(b"\n")?;
+            io::copy(&mut File::open(&self.body_path)?, &mut out)?;
+            out.into_inner().map_err(|err| err.into_error())?.sync_all()?;
+            fs::rename(&partial, &path)
+        })();
+        if let Err(err) = written {
+            let _ = fs::remove_file(&partial);
+            return Err(archive_error(&path, err));
+        }
+        Ok(manifest)
+    }
+}
+
+// The scratch file goes whether the export finished or failed part way
+impl Drop for ArchiveWriter {
+    fn drop(&mut self) {
+        self.body.take();
+        let _ = fs::remove_file(&self.body_path);
+    }
+}
+
+fn read_manifest(path: &Path, line: Option<io::Result<String>>) -> Result<MemoryManifest, MemoryError> {
+    let line = line.ok_or_else(|| archive_error(path, "the archive is empty"))?.map_err(|err| archive_error(path, err))?;
+    let manifest: MemoryManifest =
+        serde_json::from_str(&line).map_err(|err| archive_error(path, format!("line 1 is not a manifest: {}", err)))?;
+    if manifest.format != ARCHIVE_FORMAT {
+        return Err(archive_error(path, format!("not a vector memory archive (format '{}')", manifest.format)));
+    }
+    if manifest.version > ARCHIVE_VERSION {
+        return Err(archive_error(path, format!("archive version {} is newer than {}", manifest.version, ARCHIVE_VERSION)));
+    }
+    Ok(manifest)
+}
+
+fn open_lines(path: &Path) -> Result<io::Lines<BufReader<File>>, MemoryError> {
+    Ok(BufReader::new(File::open(path).map_err(|err| archive_error(path, err))?).lines())
+}
+
+/// Read the manifest and check the records against its hash and count, without decoding them.
+pub fn verify_archive(path: impl AsRef<Path>) -> Result<MemoryManifest, MemoryError> {
+    let path = path.as_ref();
+    let mut lines = open_lines(path)?;
+    let manifest = read_manifest(path, lines.next())?;
+    let mut hasher = Sha256::new();
+    let mut records = 0;
+    for line in lines {
+        let line = line.map_err(|err| archive_error(path, err))?;
+        hasher.update(line.as_bytes());
+        hasher.update(b"\n");
+        records += 1;
+    }
+    if records != manifest.records {
+        return Err(archive_error(path, format!("the manifest lists {} records but {} follow", manifest.records, records)));
+    }
+    if hex::encode(hasher.finalize()) != manifest.records_sha256 {
+        return Err(archive_error(path, "the records do not match the manifest's records_sha256"));
+    }
+    Ok(manifest)
+}
+
+/// Decode the archive's records and hand them to `load`, `batch` at a time. Call `verify_archive` first;
+/// this checks only that each line decodes.
+pub fn read_archive(
+    path: impl AsRef<Path>,
+    batch: usize,
+    mut load: impl FnMut(Vec<MemoryRecord>) -> Result<(), MemoryError>,
+) -> Result<MemoryManifest, MemoryError> {
+    let path = path.as_ref();
+    let mut lines = open_lines(path)?;
+    let manifest = read_manifest(path, lines.next())?;
+    let mut pending = Vec::with_capacity(batch);
+    for (index, line) in lines.enumerate() {
+        let line_number = index + 2;
+        let line = line.map_err(|err| archive_error(path, err))?;
+        let archived: ArchivedRecord = serde_json::from_str(&line)
+            .map_err(|err| archive_error(path, format!("line {}: {}", line_number, err)))?;
+        let bytes = BASE64
+            .decode(&archived.embedding)
+            .map_err(|err| archive_error(path, format!("line {}: embedding is not base64: {}", line_number, err)))?;
+        let dimension = bytes.len() / 4;
+        if bytes.len() % 4 != 0 || manifest.dimension.is_some_and(|expected| expected != dimension) {
+            let expected = manifest.dimension.map_or("whole".to_string(), |dimension| dimension.to_string());
+            let message = format!("line {}: embedding is {} bytes, not {} f32s", line_number, bytes.len(), expected);
+            return Err(archive_error(path, message));
+        }
+        let embedding = bytes.chunks_exact(4).map(|value| f32::from_le_bytes(value.try_into().expect("4 bytes"))).collect();
+        pending.push(MemoryRecord {
+            id: archived.id,
+            namespace: archived.namespace,
+            text: archived.text,
+            embedding,
+            metadata: archived.metadata,
+            citation: archived.citation,
+            norm: 0.0,
+        });
+        if pending.len() >= batch.max(1) {
+            load(std::mem::take(&mut pending))?;
+        }
+    }
+    if !pending.is_empty() {
+        load(pending)?;
+    }
+    Ok(manifest)
+}
+
+fn main() -> Result<(), MemoryError> {
+    let mut writer = ArchiveWriter::create("memory/Financial-Analyst.jsonl")?;
+    writer.push(&MemoryRecord {
+        id: "q3-note".to_string(),
+        namespace: "agent:Financial-Analyst".to_string(),
+        text: "Q3 revenue grew 8% on services".to_string(),
+        embedding: vec![0.9, 0.1, 0.0],
+        metadata: HashMap::new(),
+        citation: Some(Citation::new("filings/q3.md", Some("bytes 0..31".to_string()))),
+        norm: 0.0,
+    })?;
+    let manifest = writer.finish(Some(3))?;
+    println!("{}", serde_json::to_string_pretty(&manifest).expect("manifests serialize"));
+    println!("{:?}", verify_archive("memory/Financial-Analyst.jsonl")?.records);
+    Ok(())
+}
+```
+
+### Notes
+* Importing does not deduplicate. Loading an archive into a store that already holds its records stores
+  them twice; import into a fresh store, or one whose namespaces do not overlap the archive's.
+* `exported_at` is the only field that differs between two exports of the same store, so comparing
+  `records_sha256` tells whether the memory changed. Records come out in the store's own order: namespace
+  order for the in-memory store, row order for the disk index.
+* Vectors are little-endian on every host, unlike the `mmap_index` files, so archives are portable
+  between architectures.
+* An archive holds the records' full text. Memory built from confidential documents needs the same
+  handling as the documents.
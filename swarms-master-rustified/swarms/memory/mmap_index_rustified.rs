@@ -0,0 +1,468 @@
+### Overview
+There is no Python counterpart. A long-lived agent's long-term memory can grow to millions of vectors, and
+loading all of them into `VectorMemory`'s in-memory store makes startup slow and RAM use unbounded.
+`MmapVectorIndex` keeps the index on disk instead:
+
+* `vectors.bin` is a 16-byte header followed by fixed-size rows (`dimension` `f32`s plus the cached norm).
+  It is memory-mapped read-only, so the OS pages vectors in on demand and can evict them under pressure.
+* `records.jsonl` is an append-only log of record metadata. Only the id, namespace, and byte offset of
+  each line stay in RAM; text and citations are read back for the top hits only.
+
+Appends write both files and remap. Appending a record whose id is already in the index replaces it: the
+earlier row is treated as deleted, on reopen too. Deletes append a tombstone line. `compact` rewrites both
+files without deleted rows into temporary files, syncs them, and swaps them in with renames. The two renames
+are bracketed by a `compact.done` marker, so a crash between them is finished on the next `open` rather
+than leaving a vectors file and a log that disagree.
+
+### Rust Code
+```rust
+use crate::swarms::memory::similarity::{norm, top_k_cosine};
+use crate::swarms::memory::vector_memory::{MemoryError, MemoryRecord, RecallHit};
+use crate::swarms::structs::citation::Citation;
+use log::info;
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+const MAGIC: &[u8; 4] = b"SWVI";
+const VERSION: u32 = 1;
+const HEADER_LEN: usize = 16;
+const VECTORS_FILE: &str = "vectors.bin";
+const RECORDS_FILE: &str = "records.jsonl";
+// Present while compacted files are being renamed into place; see `finish_compaction`
+const COMPACT_MARKER: &str = "compact.done";
+
+impl From<io::Error> for MemoryError {
+    fn from(err: io::Error) -> Self {
+        MemoryError::Storage(err.to_string())
+    }
+}
+
+/// One line of `records.jsonl`.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+enum LogLine {
+    Record {
+        row: usize,
+        id: String,
+        namespace: String,
+        text: String,
+        #[serde(default)]
+        metadata: HashMap<String, String>,
+        citation: Option<Citation>,
+    },
+    Tombstone {
+        deleted: String,
+    },
+}
+
+/// What stays in RAM per row.
+#[derive(Debug, Clone)]
+struct RowMeta {
+    id: String,
+    namespace: String,
+    /// Byte offset of the record's line in `records.jsonl`.
+    offset: u64,
+}
+
+/// A disk-backed vector index; see the module overview for the file layout.
+pub struct MmapVectorIndex {
+    dir: PathBuf,
+    dimension: usize,
+    mmap: Option<Mmap>,
+    rows: Vec<RowMeta>,
+    deleted: HashSet<usize>,
+    by_id: HashMap<String, usize>,
+}
+
+impl MmapVectorIndex {
+    /// Open the index in `dir`, creating it with `dimension` if it does not exist yet.
+    pub fn open(dir: impl AsRef<Path>, dimension: usize) -> Result<Self, MemoryError> {
+        let dir = dir.as_ref().to_path_buf();
+        fs::create_dir_all(&dir)?;
+        recover_compaction(&dir)?;
+        let vectors_path = dir.join(VECTORS_FILE);
+        if !vectors_path.exists() {
+            write_header(&mut File::create(&vectors_path)?, dimension)?;
+            File::create(dir.join(RECORDS_FILE))?;
+        }
+        let stored = read_header(&mut File::open(&vectors_path)?)?;
+        if stored != dimension {
+            return Err(MemoryError::DimensionMismatch { expected: stored, actual: dimension });
+        }
+        let mut index = MmapVectorIndex {
+            dir,
+            dimension,
+            mmap: None,
+            rows: Vec::new(),
+            deleted: HashSet::new(),
+            by_id: HashMap::new(),
+        };
+        index.load_log()?;
+        index.remap()?;
+        // Extra vectors (a crash between the two appends) are dropped by the next `append`;
+        // missing vectors mean the files were damaged.
+        let on_disk = index.vector_rows();
+        if on_disk < index.rows.len() {
+            return Err(MemoryError::Storage(format!(
+                "{} lists {} records but {} holds only {} vectors",
+                RECORDS_FILE,
+                index.rows.len(),
+                VECTORS_FILE,
+                on_disk
+            )));
+        }
+        info!("Opened vector index at {} ({} live records)", index.dir.display(), index.len());
+        Ok(index)
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    /// Live (non-deleted) records.
+    pub fn len(&self) -> usize {
+        self.rows.len() - self.deleted.len()
+    }
+
+    /// Live records in `namespace`.
+    pub fn len_in(&self, namespace: &str) -> usize {
+        (0..self.rows.len())
+            .filter(|row| !self.deleted.contains(row) && self.rows[*row].namespace == namespace)
+            .count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn row_bytes(&self) -> usize {
+        (self.dimension + 1) * 4
+    }
+
+    fn vector_rows(&self) -> usize {
+        self.mmap.as_ref().map_or(0, |m| (m.len() - HEADER_LEN) / self.row_bytes())
+    }
+
+    fn load_log(&mut self) -> Result<(), MemoryError> {
+        let mut reader = BufReader::new(File::open(self.dir.join(RECORDS_FILE))?);
+        let mut offset = 0u64;
+        let mut line = String::new();
+        loop {
+            line.clear();
+            let read = reader.read_line(&mut line)?;
+            if read == 0 {
+                break;
+            }
+            if !line.ends_with('\n') {
+                // A torn final line from a crash mid-append; cut it off so the next append starts clean.
+                OpenOptions::new().write(true).open(self.dir.join(RECORDS_FILE))?.set_len(offset)?;
+                break;
+            }
+            match serde_json::from_str::<LogLine>(line.trim_end()) {
+                Ok(LogLine::Record { row, id, namespace, .. }) if row == self.rows.len() => {
+                    // A later record with the same id replaced the earlier one
+                    if let Some(replaced) = self.by_id.insert(id.clone(), row) {
+                        self.deleted.insert(replaced);
+                    }
+                    self.rows.push(RowMeta { id, namespace, offset });
+                }
+                Ok(LogLine::Tombstone { deleted }) => {
+                    if let Some(row) = self.by_id.remove(&deleted) {
+                        self.deleted.insert(row);
+                    }
+                }
+                _ => {}
+            }
+            offset += read as u64;
+        }
+        Ok(())
+    }
+
+    fn remap(&mut self) -> Result<(), MemoryError> {
+        let file = File::open(self.dir.join(VECTORS_FILE))?;
+        // Safety: the file is only appended to, replaced by rename, or truncated while unmapped,
+        // so the mapped range stays valid for the lifetime of the map.
+        self.mmap = Some(unsafe { Mmap::map(&file)? });
+        Ok(())
+    }
+
+    fn vector(&self, row: usize) -> (&[f32], f32) {
+        let mmap = self.mmap.as_ref().expect("index is mapped after open");
+        let start = HEADER_LEN + row * self.row_bytes();
+        let floats: &[f32] = bytemuck::cast_slice(&mmap[start..start + self.row_bytes()]);
+        (&floats[..self.dimension], floats[self.dimension])
+    }
+
+    /// Append records. Vectors are written (and synced) before their log lines, so a crash never
+    /// leaves a record pointing at a missing vector. A record with the id of a live one replaces it,
+    /// and within one batch the last record with an id wins.
+    pub fn append(&mut self, records: &[MemoryRecord]) -> Result<(), MemoryError> {
+        for record in records {
+            if record.embedding.len() != self.dimension {
+                return Err(MemoryError::DimensionMismatch { expected: self.dimension, actual: record.embedding.len() });
+            }
+        }
+        let vectors = OpenOptions::new().write(true).open(self.dir.join(VECTORS_FILE))?;
+        if self.vector_rows() > self.rows.len() {
+            // Drop rows left behind by an earlier crash so row numbers stay aligned with the log.
+            // Unmap first; the map must never cover a truncated range.
+            self.mmap = None;
+            vectors.set_len((HEADER_LEN + self.rows.len() * self.row_bytes()) as u64)?;
+            self.remap()?;
+        }
+        let mut vectors = BufWriter::new(vectors);
+        vectors.seek(SeekFrom::End(0))?;
+        for record in records {
+            for value in record.embedding.iter().chain(std::iter::once(&norm(&record.embedding))) {
+                vectors.write_all(&value.to_le_bytes())?;
+            }
+        }
+        vectors.into_inner().map_err(|e| e.into_error())?.sync_data()?;
+
+        let mut log = OpenOptions::new().append(true).open(self.dir.join(RECORDS_FILE))?;
+        let mut offset = log.seek(SeekFrom::End(0))?;
+        let mut buffer = Vec::new();
+        for record in records {
+            let row = self.rows.len();
+            let line = LogLine::Record {
+                row,
+                id: record.id.clone(),
+                namespace: record.namespace.clone(),
+                text: record.text.clone(),
+                metadata: record.metadata.clone(),
+                citation: record.citation.clone(),
+            };
+            let mut encoded = serde_json::to_vec(&line).map_err(|e| MemoryError::Storage(e.to_string()))?;
+            encoded.push(b'\n');
+            if let Some(replaced) = self.by_id.insert(record.id.clone(), row) {
+                self.deleted.insert(replaced);
+            }
+            self.rows.push(RowMeta { id: record.id.clone(), namespace: record.namespace.clone(), offset });
+            offset += encoded.len() as u64;
+            buffer.extend(encoded);
+        }
+        log.write_all(&buffer)?;
+        log.sync_data()?;
+        self.remap()
+    }
+
+    /// Mark a record deleted. Its space is reclaimed by `compact`.
+    pub fn delete(&mut self, id: &str) -> Result<bool, MemoryError> {
+        let Some(row) = self.by_id.remove(id) else {
+            return Ok(false);
+        };
+        self.deleted.insert(row);
+        let mut log = OpenOptions::new().append(true).open(self.dir.join(RECORDS_FILE))?;
+        let mut line = serde_json::to_vec(&LogLine::Tombstone { deleted: id.to_string() })
+            .map_err(|e| MemoryError::Storage(e.to_string()))?;
+        line.push(b'\n');
+        log.write_all(&line)?;
+        log.sync_data()?;
+        Ok(true)
+    }
+
+    fn read_record(&self, row: usize) -> Result<LogLine, MemoryError> {
+        let mut file = File::open(self.dir.join(RECORDS_FILE))?;
+        file.seek(SeekFrom::Start(self.rows[row].offset))?;
+        let mut line = String::new();
+        BufReader::new(file).read_line(&mut line)?;
+        serde_json::from_str(line.trim_end()).map_err(|e| MemoryError::Storage(e.to_string()))
+    }
+
+    /// The `top_k` live records in `namespaces` most similar to `query`.
+    pub fn search(&self, namespaces: &[String], query: &[f32], top_k: usize) -> Result<Vec<RecallHit>, MemoryError> {
+        if query.len() != self.dimension {
+            return Err(MemoryError::DimensionMismatch { expected: self.dimension, actual: query.len() });
+        }
+        let candidates: Vec<usize> = (0..self.rows.len())
+            .filter(|row| !self.deleted.contains(row) && namespaces.contains(&self.rows[*row].namespace))
+            .collect();
+        let vectors: Vec<(&[f32], f32)> = candidates.iter().map(|&row| self.vector(row)).collect();
+        top_k_cosine(query, &vectors, top_k)
+            .into_iter()
+            .map(|scored| {
+                let row = candidates[scored.index];
+                match self.read_record(row)? {
+                    LogLine::Record { id, namespace, text, metadata, citation, .. } => {
+                        Ok(RecallHit { id, namespace, text, score: scored.score, metadata, citation })
+                    }
+                    LogLine::Tombstone { .. } => Err(MemoryError::Storage(format!("row {} points at a tombstone", row))),
+                }
+            })
+            .collect()
+    }
+
+    /// Visit every live record, vector included, in row order. Reads `records.jsonl` once, front to back,
+    /// instead of seeking to each row.
+    pub fn for_each_record(&self, mut visit: impl FnMut(MemoryRecord) -> Result<(), MemoryError>) -> Result<(), MemoryError> {
+        let reader = BufReader::new(File::open(self.dir.join(RECORDS_FILE))?);
+        for line in reader.lines() {
+            let Ok(LogLine::Record { row, id, namespace, text, metadata, citation }) = serde_json::from_str(&line?) else {
+                continue;
+            };
+            // Rows appended by another process after this index was opened are not mapped yet
+            if row >= self.rows.len() || self.deleted.contains(&row) || self.rows[row].id != id {
+                continue;
+            }
+            let (vector, vector_norm) = self.vector(row);
+            visit(MemoryRecord { id, namespace, text, embedding: vector.to_vec(), metadata, citation, norm: vector_norm })?;
+        }
+        Ok(())
+    }
+
+    /// Every live namespace.
+    pub fn namespaces(&self) -> HashSet<String> {
+        (0..self.rows.len())
+            .filter(|row| !self.deleted.contains(row))
+            .map(|row| self.rows[row].namespace.clone())
+            .collect()
+    }
+
+    /// Rewrite both files without deleted rows, then swap them in with renames (see the overview).
+    pub fn compact(&mut self) -> Result<usize, MemoryError> {
+        if self.deleted.is_empty() {
+            return Ok(0);
+        }
+        let (vectors_tmp, records_tmp) = compact_paths(&self.dir);
+        let mut vectors = BufWriter::new(File::create(&vectors_tmp)?);
+        write_header(&mut vectors, self.dimension)?;
+        let mut records = BufWriter::new(File::create(&records_tmp)?);
+
+        let mut rows = Vec::with_capacity(self.len());
+        let mut offset = 0u64;
+        for row in 0..self.rows.len() {
+            if self.deleted.contains(&row) {
+                continue;
+            }
+            let LogLine::Record { id, namespace, text, metadata, citation, .. } = self.read_record(row)? else {
+                continue;
+            };
+            let (vector, vector_norm) = self.vector(row);
+            for value in vector.iter().chain(std::iter::once(&vector_norm)) {
+                vectors.write_all(&value.to_le_bytes())?;
+            }
+            let line = LogLine::Record { row: rows.len(), id: id.clone(), namespace: namespace.clone(), text, metadata, citation };
+            let mut encoded = serde_json::to_vec(&line).map_err(|e| MemoryError::Storage(e.to_string()))?;
+            encoded.push(b'\n');
+            records.write_all(&encoded)?;
+            rows.push(RowMeta { id, namespace, offset });
+            offset += encoded.len() as u64;
+        }
+        vectors.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+        records.into_inner().map_err(|e| e.into_error())?.sync_all()?;
+
+        // Both files are complete and durable; from here on a crash is rolled forward by `open`.
+        File::create(self.dir.join(COMPACT_MARKER))?.sync_all()?;
+        sync_dir(&self.dir)?;
+
+        let removed = self.deleted.len();
+        // Unmap before replacing the file underneath the map.
+        self.mmap = None;
+        finish_compaction(&self.dir)?;
+        self.by_id = rows.iter().enumerate().map(|(row, meta)| (meta.id.clone(), row)).collect();
+        self.rows = rows;
+        self.deleted.clear();
+        self.remap()?;
+        info!("Compacted vector index at {}: removed {} records", self.dir.display(), removed);
+        Ok(removed)
+    }
+
+    /// Fraction of rows that are deleted; callers can compact when this crosses a threshold.
+    pub fn garbage_ratio(&self) -> f32 {
+        if self.rows.is_empty() {
+            0.0
+        } else {
+            self.deleted.len() as f32 / self.rows.len() as f32
+        }
+    }
+}
+
+fn compact_paths(dir: &Path) -> (PathBuf, PathBuf) {
+    (dir.join(format!("{}.compact", VECTORS_FILE)), dir.join(format!("{}.compact", RECORDS_FILE)))
+}
+
+// Renames are only durable once the directory entry is synced. Directories cannot be opened as files on
+// Windows, where the rename is durable when it returns.
+fn sync_dir(dir: &Path) -> io::Result<()> {
+    #[cfg(unix)]
+    File::open(dir)?.sync_all()?;
+    Ok(())
+}
+
+// Move synced compacted files into place, then drop the marker. Each rename is skipped when it already
+// happened, so this also finishes a compaction that crashed part way.
+fn finish_compaction(dir: &Path) -> io::Result<()> {
+    let (vectors_tmp, records_tmp) = compact_paths(dir);
+    for (tmp, live) in [(vectors_tmp, VECTORS_FILE), (records_tmp, RECORDS_FILE)] {
+        if tmp.exists() {
+            fs::rename(tmp, dir.join(live))?;
+        }
+    }
+    sync_dir(dir)?;
+    fs::remove_file(dir.join(COMPACT_MARKER))?;
+    sync_dir(dir)
+}
+
+// Called by `open`. With the marker, the compacted files were complete: finish swapping them in. Without
+// it, any compacted files are from a compaction that never finished writing, and the live files are intact.
+fn recover_compaction(dir: &Path) -> io::Result<()> {
+    if dir.join(COMPACT_MARKER).exists() {
+        info!("Finishing an interrupted compaction of the vector index at {}", dir.display());
+        return finish_compaction(dir);
+    }
+    let (vectors_tmp, records_tmp) = compact_paths(dir);
+    for tmp in [vectors_tmp, records_tmp] {
+        match fs::remove_file(tmp) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+fn write_header(out: &mut impl Write, dimension: usize) -> io::Result<()> {
+    out.write_all(MAGIC)?;
+    out.write_all(&VERSION.to_le_bytes())?;
+    out.write_all(&(dimension as u32).to_le_bytes())?;
+    out.write_all(&[0u8; 4])
+}
+
+fn read_header(file: &mut File) -> Result<usize, MemoryError> {
+    let mut header = [0u8; HEADER_LEN];
+    file.read_exact(&mut header)?;
+    if &header[..4] != MAGIC {
+        return Err(MemoryError::Storage("not a swarms vector index".to_string()));
+    }
+    let version = u32::from_le_bytes(header[4..8].try_into().expect("4 bytes"));
+    if version != VERSION {
+        return Err(MemoryError::Storage(format!("unsupported vector index version {}", version)));
+    }
+    Ok(u32::from_le_bytes(header[8..12].try_into().expect("4 bytes")) as usize)
+}
+
+fn main() -> Result<(), MemoryError> {
+    let mut index = MmapVectorIndex::open("./agent_memory/Financial-Analyst", 3)?;
+    index.append(&[MemoryRecord {
+        id: "q3-note".to_string(),
+        namespace: "agent:Financial-Analyst".to_string(),
+        text: "Q3 revenue grew 8% on services".to_string(),
+        embedding: vec![0.9, 0.1, 0.0],
+        metadata: HashMap::new(),
+        citation: None,
+        norm: 0.0,
+    }])?;
+    let hits = index.search(&["agent:Financial-Analyst".to_string()], &[1.0, 0.0, 0.0], 3)?;
+    println!("{:?}", hits);
+    Ok(())
+}
+```
+
+### Notes
+* Rows are little-endian `f32`; index files are not portable to big-endian hosts.
+* The header and rows are multiples of 4 bytes and the map is page-aligned, so rows can be viewed as `&[f32]` in place.
+* Replacing a record leaves its old row behind until `compact`, like a delete, so re-embedding a corpus in place raises `garbage_ratio`.
+* Only one process may write an index directory at a time; readers in other processes see appends after they reopen.
@@ -0,0 +1,324 @@
+### Overview
+There is no Python counterpart to this module. Retrieval returns candidate chunks ordered by embedding
+similarity, which is fast but coarse. A `Reranker` re-scores those candidates against the query before
+they are assembled into the prompt, so only the most relevant chunks spend context tokens. Two
+implementations are provided: the Cohere rerank API and a local cross-encoder behind a small trait so any
+inference backend (ONNX, candle, llama.cpp) can be plugged in. Every rerank emits a telemetry event with
+the before/after rank and score of each candidate, which is what you tune `top_n` and thresholds against.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, HttpTransport, RestClient, RestError};
+use async_trait::async_trait;
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+pub const COHERE_API_BASE: &str = "https://api.cohere.com/v1";
+
+const COHERE_RERANK: Endpoint = Endpoint::post("/rerank");
+
+/// A retrieved chunk waiting to be reranked.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Candidate {
+    pub id: String,
+    pub text: String,
+    /// Retrieval (first-stage) score, usually cosine similarity.
+    pub score: f32,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+/// A candidate after reranking, with the first-stage score kept for comparison.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RankedCandidate {
+    pub candidate: Candidate,
+    pub before_rank: usize,
+    pub after_rank: usize,
+    pub rerank_score: f32,
+}
+
+#[derive(Debug)]
+pub enum RerankError {
+    Http(RestError),
+    Api(String),
+    Model(String),
+    /// A reranker returned a different number of scores than it was given candidates.
+    ScoreCount { expected: usize, actual: usize },
+}
+
+impl fmt::Display for RerankError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RerankError::Http(err) => write!(f, "rerank request failed: {}", err),
+            RerankError::Api(msg) => write!(f, "rerank API error: {}", msg),
+            RerankError::Model(msg) => write!(f, "cross-encoder error: {}", msg),
+            RerankError::ScoreCount { expected, actual } => {
+                write!(f, "reranker returned {} scores for {} candidates", actual, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for RerankError {}
+
+impl From<RestError> for RerankError {
+    fn from(err: RestError) -> Self {
+        RerankError::Http(err)
+    }
+}
+
+#[async_trait]
+pub trait Reranker: Send + Sync {
+    /// Short name used in telemetry ("cohere", "cross-encoder").
+    fn name(&self) -> &str;
+
+    /// Score every candidate against `query`. Scores are returned in input order.
+    async fn score(&self, query: &str, candidates: &[Candidate]) -> Result<Vec<f32>, RerankError>;
+
+    /// Rerank `candidates` and keep the best `top_n`.
+    async fn rerank(
+        &self,
+        query: &str,
+        candidates: Vec<Candidate>,
+        top_n: usize,
+    ) -> Result<Vec<RankedCandidate>, RerankError> {
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+        let scores = self.score(query, &candidates).await?;
+        let mut ranked = rank_by_score(candidates, scores)?;
+        // Record the full ranking so dropped candidates are visible when tuning `top_n`.
+        record_rerank_telemetry(self.name(), query, &ranked);
+        ranked.truncate(top_n);
+        Ok(ranked)
+    }
+}
+
+/// Sort candidates by `scores` (descending), stable on ties. There must be one score per candidate.
+pub fn rank_by_score(candidates: Vec<Candidate>, scores: Vec<f32>) -> Result<Vec<RankedCandidate>, RerankError> {
+    if scores.len() != candidates.len() {
+        return Err(RerankError::ScoreCount { expected: candidates.len(), actual: scores.len() });
+    }
+    let mut indexed: Vec<(usize, Candidate, f32)> = candidates
+        .into_iter()
+        .zip(scores)
+        .enumerate()
+        .map(|(i, (candidate, score))| (i, candidate, score))
+        .collect();
+    indexed.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+    Ok(indexed
+        .into_iter()
+        .enumerate()
+        .map(|(after_rank, (before_rank, candidate, rerank_score))| RankedCandidate {
+            candidate,
+            before_rank,
+            after_rank,
+            rerank_score,
+        })
+        .collect())
+}
+
+/// Emit one telemetry event per rerank with before/after ranks and scores.
+pub fn record_rerank_telemetry(reranker: &str, query: &str, ranked: &[RankedCandidate]) {
+    let entries: Vec<JsonValue> = ranked
+        .iter()
+        .map(|r| {
+            json!({
+                "id": r.candidate.id,
+                "before_rank": r.before_rank,
+                "before_score": r.candidate.score,
+                "after_rank": r.after_rank,
+                "after_score": r.rerank_score,
+            })
+        })
+        .collect();
+    info!(
+        target: "swarms::telemetry",
+        "{}",
+        json!({
+            "event": "rerank",
+            "reranker": reranker,
+            "query_chars": query.chars().count(),
+            "results": entries,
+        })
+    );
+}
+
+/// Cohere's hosted rerank endpoint.
+pub struct CohereReranker {
+    model: String,
+    rest: RestClient,
+}
+
+impl CohereReranker {
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        CohereReranker {
+            model: model.into(),
+            rest: RestClient::new(COHERE_API_BASE, AuthStrategy::Bearer(api_key.into())),
+        }
+    }
+
+    /// Send requests through `transport` instead of the network.
+    pub fn with_transport(mut self, transport: Arc<dyn HttpTransport>) -> Self {
+        self.rest = self.rest.with_transport(transport);
+        self
+    }
+}
+
+#[derive(Deserialize)]
+struct CohereRerankResponse {
+    results: Vec<CohereRerankResult>,
+}
+
+#[derive(Deserialize)]
+struct CohereRerankResult {
+    index: usize,
+    relevance_score: f32,
+}
+
+#[async_trait]
+impl Reranker for CohereReranker {
+    fn name(&self) -> &str {
+        "cohere"
+    }
+
+    async fn score(&self, query: &str, candidates: &[Candidate]) -> Result<Vec<f32>, RerankError> {
+        let documents: Vec<&str> = candidates.iter().map(|c| c.text.as_str()).collect();
+        let body = json!({
+            "model": self.model,
+            "query": query,
+            "documents": documents,
+            "top_n": documents.len(),
+        });
+        let parsed: CohereRerankResponse = self.rest.call(COHERE_RERANK, &[], &(), Some(&body)).await?;
+
+        // Cohere returns results sorted by relevance; map them back to input order.
+        let mut scores = vec![f32::MIN; candidates.len()];
+        for result in parsed.results {
+            if let Some(slot) = scores.get_mut(result.index) {
+                *slot = result.relevance_score;
+            }
+        }
+        Ok(scores)
+    }
+}
+
+/// A local model that scores (query, passage) pairs jointly.
+pub trait CrossEncoder: Send + Sync {
+    fn score_pairs(&self, query: &str, passages: &[&str]) -> Result<Vec<f32>, RerankError>;
+}
+
+/// Reranker backed by a local `CrossEncoder`. Scoring runs on the blocking thread pool.
+pub struct CrossEncoderReranker {
+    model: Arc<dyn CrossEncoder>,
+}
+
+impl CrossEncoderReranker {
+    pub fn new(model: Arc<dyn CrossEncoder>) -> Self {
+        CrossEncoderReranker { model }
+    }
+}
+
+#[async_trait]
+impl Reranker for CrossEncoderReranker {
+    fn name(&self) -> &str {
+        "cross-encoder"
+    }
+
+    async fn score(&self, query: &str, candidates: &[Candidate]) -> Result<Vec<f32>, RerankError> {
+        let model = Arc::clone(&self.model);
+        let query = query.to_string();
+        let passages: Vec<String> = candidates.iter().map(|c| c.text.clone()).collect();
+        tokio::task::spawn_blocking(move || {
+            let passages: Vec<&str> = passages.iter().map(String::as_str).collect();
+            model.score_pairs(&query, &passages)
+        })
+        .await
+        .map_err(|e| RerankError::Model(e.to_string()))?
+    }
+}
+
+/// Per-agent reranker configuration (the `reranker` key in an agent's YAML/JSON config).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RerankerConfig {
+    Cohere {
+        model: String,
+        #[serde(default = "default_top_n")]
+        top_n: usize,
+    },
+    CrossEncoder {
+        #[serde(default = "default_top_n")]
+        top_n: usize,
+    },
+}
+
+fn default_top_n() -> usize {
+    5
+}
+
+impl RerankerConfig {
+    pub fn top_n(&self) -> usize {
+        match self {
+            RerankerConfig::Cohere { top_n, .. } | RerankerConfig::CrossEncoder { top_n } => *top_n,
+        }
+    }
+
+    /// Build the configured reranker. The cross-encoder model is supplied by the caller because
+    /// loading weights is backend-specific.
+    pub fn build(&self, cross_encoder: Option<Arc<dyn CrossEncoder>>) -> Result<Box<dyn Reranker>, RerankError> {
+        match self {
+            RerankerConfig::Cohere { model, .. } => {
+                let api_key = std::env::var("COHERE_API_KEY")
+                    .map_err(|_| RerankError::Api("COHERE_API_KEY environment variable is not set".to_string()))?;
+                Ok(Box::new(CohereReranker::new(api_key, model.clone())))
+            }
+            RerankerConfig::CrossEncoder { .. } => {
+                let model = cross_encoder
+                    .ok_or_else(|| RerankError::Model("no cross-encoder model was provided".to_string()))?;
+                Ok(Box::new(CrossEncoderReranker::new(model)))
+            }
+        }
+    }
+}
+
+// A toy cross-encoder for demonstration: counts shared words.
+struct WordOverlap;
+
+impl CrossEncoder for WordOverlap {
+    fn score_pairs(&self, query: &str, passages: &[&str]) -> Result<Vec<f32>, RerankError> {
+        let query_words: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        Ok(passages
+            .iter()
+            .map(|p| {
+                p.split_whitespace()
+                    .filter(|w| query_words.contains(&w.to_lowercase()))
+                    .count() as f32
+            })
+            .collect())
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), RerankError> {
+    let candidates = vec![
+        Candidate { id: "a".into(), text: "Tax filing deadlines".into(), score: 0.82, metadata: HashMap::new() },
+        Candidate { id: "b".into(), text: "Quarterly revenue grew 12 percent".into(), score: 0.80, metadata: HashMap::new() },
+    ];
+    let reranker = CrossEncoderReranker::new(Arc::new(WordOverlap));
+    for ranked in reranker.rerank("quarterly revenue", candidates, 1).await? {
+        println!("{} {} -> {}", ranked.candidate.id, ranked.before_rank, ranked.after_rank);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* `rerank` consumes the candidates and returns at most `top_n`; anything dropped is still visible in the telemetry event via its `before_rank`.
+* A `score` that returns more or fewer scores than candidates fails the rerank with `ScoreCount` rather than dropping candidates.
+* An agent's `reranker` config takes effect through `MemorySearchTool::from_schema`: the tool recalls a larger pool than it returns, reranks it, and keeps the best `top_n` before the passages reach the prompt.
+* Scores from different rerankers are not comparable with each other or with first-stage similarity scores.
@@ -0,0 +1,431 @@
+### Overview
+There is no Python counterpart to this module; the Python examples reach for ChromaDB or LlamaIndex
+(`LlamaIndexDB` in `full_agent_rag_example.py`). `VectorMemory` is a small embedded vector store for the
+Rust port: records live in named namespaces, are embedded through a pluggable `Embedder`, and are recalled
+by cosine similarity. Clones share the same store, so one `VectorMemory` can back every agent in a swarm.
+`VectorMemory::open` keeps the records in a memory-mapped `MmapVectorIndex` on disk instead of in RAM.
+`add_external` loads a corpus that LangChain or LlamaIndex already chunked, reusing its embeddings (see
+`document_formats`). `export` and `import` move a whole store, vectors included, through one archive file
+(see `memory_archive`).
+
+### Rust Code
+```rust
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, RestClient, RestError};
+use crate::swarms::memory::document_formats::ExternalChunk;
+use crate::swarms::memory::memory_archive::{read_archive, verify_archive, ArchiveWriter, MemoryManifest};
+use crate::swarms::memory::mmap_index::MmapVectorIndex;
+use crate::swarms::memory::reranker::RerankError;
+use crate::swarms::memory::similarity::{self, top_k_cosine};
+use crate::swarms::structs::citation::Citation;
+use crate::swarms::text::chunk::Chunk;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub enum MemoryError {
+    Embedding(String),
+    DimensionMismatch { expected: usize, actual: usize },
+    AccessDenied { agent: String, namespace: String },
+    Storage(String),
+    /// An archive could not be written or read (see `memory_archive`).
+    Archive { path: PathBuf, message: String },
+    /// Recalled passages could not be reranked (see `reranker`).
+    Rerank(String),
+}
+
+impl fmt::Display for MemoryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MemoryError::Embedding(msg) => write!(f, "embedding failed: {}", msg),
+            MemoryError::DimensionMismatch { expected, actual } => {
+                write!(f, "embedding has {} dimensions, store expects {}", actual, expected)
+            }
+            MemoryError::AccessDenied { agent, namespace } => {
+                write!(f, "agent '{}' may not access memory namespace '{}'", agent, namespace)
+            }
+            MemoryError::Storage(msg) => write!(f, "vector index storage failed: {}", msg),
+            MemoryError::Archive { path, message } => write!(f, "memory archive {}: {}", path.display(), message),
+            MemoryError::Rerank(msg) => write!(f, "reranking failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for MemoryError {}
+
+impl From<RerankError> for MemoryError {
+    fn from(err: RerankError) -> Self {
+        MemoryError::Rerank(err.to_string())
+    }
+}
+
+impl From<RestError> for MemoryError {
+    fn from(err: RestError) -> Self {
+        MemoryError::Embedding(err.to_string())
+    }
+}
+
+/// Turns text into vectors.
+#[async_trait]
+pub trait Embedder: Send + Sync {
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, MemoryError>;
+}
+
+/// OpenAI `/embeddings`, keyed by `OPENAI_API_KEY`.
+pub struct OpenAiEmbedder {
+    rest: RestClient,
+    model: String,
+}
+
+impl OpenAiEmbedder {
+    pub fn new(api_key: impl Into<String>, model: impl Into<String>) -> Self {
+        OpenAiEmbedder {
+            rest: RestClient::new("https://api.openai.com/v1", AuthStrategy::Bearer(api_key.into())),
+            model: model.into(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct EmbeddingResponse {
+    data: Vec<EmbeddingData>,
+}
+
+#[derive(Deserialize)]
+struct EmbeddingData {
+    index: usize,
+    embedding: Vec<f32>,
+}
+
+#[async_trait]
+impl Embedder for OpenAiEmbedder {
+    async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, MemoryError> {
+        let body = json!({ "model": self.model, "input": texts });
+        let mut response: EmbeddingResponse =
+            self.rest.call(Endpoint::post("/embeddings"), &[], &(), Some(&body)).await?;
+        response.data.sort_by_key(|d| d.index);
+        Ok(response.data.into_iter().map(|d| d.embedding).collect())
+    }
+}
+
+/// A stored memory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MemoryRecord {
+    pub id: String,
+    pub namespace: String,
+    pub text: String,
+    pub embedding: Vec<f32>,
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+    pub citation: Option<Citation>,
+    /// Cached `|embedding|`, filled in by `VectorMemory::insert`.
+    #[serde(skip)]
+    pub norm: f32,
+}
+
+/// A recalled memory and its similarity to the query.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecallHit {
+    pub id: String,
+    pub namespace: String,
+    pub text: String,
+    pub score: f32,
+    pub metadata: HashMap<String, String>,
+    pub citation: Option<Citation>,
+}
+
+/// Cosine similarity; 0.0 when either vector is all zeros.
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    similarity::cosine(a, b)
+}
+
+#[derive(Default)]
+struct Store {
+    dimension: Option<usize>,
+    namespaces: HashMap<String, Vec<MemoryRecord>>,
+    /// When set, records live here and `namespaces` stays empty.
+    disk: Option<MmapVectorIndex>,
+}
+
+// Records inserted per write lock while importing, so recalls are not blocked for a whole archive
+const IMPORT_BATCH: usize = 1_000;
+
+/// Embedded vector store with namespaces. Cheap to clone; clones share data.
+#[derive(Clone)]
+pub struct VectorMemory {
+    embedder: Arc<dyn Embedder>,
+    store: Arc<RwLock<Store>>,
+}
+
+impl VectorMemory {
+    pub fn new(embedder: Arc<dyn Embedder>) -> Self {
+        VectorMemory {
+            embedder,
+            store: Arc::new(RwLock::new(Store::default())),
+        }
+    }
+
+    /// Back the store with the on-disk index in `dir`. Existing records are mapped, not loaded.
+    pub fn open(embedder: Arc<dyn Embedder>, dir: impl AsRef<Path>, dimension: usize) -> Result<Self, MemoryError> {
+        let store = Store {
+            dimension: Some(dimension),
+            disk: Some(MmapVectorIndex::open(dir, dimension)?),
+            ..Store::default()
+        };
+        Ok(VectorMemory {
+            embedder,
+            store: Arc::new(RwLock::new(store)),
+        })
+    }
+
+    pub fn namespaces(&self) -> Vec<String> {
+        let store = self.store.read().unwrap();
+        let mut names: Vec<String> = match &store.disk {
+            Some(disk) => disk.namespaces().into_iter().collect(),
+            None => store.namespaces.keys().cloned().collect(),
+        };
+        names.sort();
+        names
+    }
+
+    pub fn len(&self, namespace: &str) -> usize {
+        let store = self.store.read().unwrap();
+        match &store.disk {
+            Some(disk) => disk.len_in(namespace),
+            None => store.namespaces.get(namespace).map_or(0, Vec::len),
+        }
+    }
+
+    /// Delete a record by id. Only the disk-backed store supports deletion.
+    pub fn delete(&self, id: &str) -> Result<bool, MemoryError> {
+        match self.store.write().unwrap().disk.as_mut() {
+            Some(disk) => disk.delete(id),
+            None => Err(MemoryError::Storage("delete requires a disk-backed VectorMemory".to_string())),
+        }
+    }
+
+    /// Reclaim space held by deleted records. A no-op for the in-memory store.
+    pub fn compact(&self) -> Result<usize, MemoryError> {
+        self.store.write().unwrap().disk.as_mut().map_or(Ok(0), MmapVectorIndex::compact)
+    }
+
+    /// Insert pre-embedded records, checking that every vector has the store's dimension. The whole batch is
+    /// checked first, so a bad vector anywhere in it inserts nothing.
+    pub fn insert(&self, records: Vec<MemoryRecord>) -> Result<(), MemoryError> {
+        let mut store = self.store.write().unwrap();
+        let Some(expected) = store.dimension.or_else(|| records.first().map(|record| record.embedding.len())) else {
+            return Ok(());
+        };
+        for record in &records {
+            if record.embedding.is_empty() {
+                return Err(MemoryError::Embedding(format!("record '{}' has an empty embedding", record.id)));
+            }
+            if record.embedding.len() != expected {
+                return Err(MemoryError::DimensionMismatch { expected, actual: record.embedding.len() });
+            }
+        }
+        store.dimension = Some(expected);
+        let mut pending = Vec::new();
+        for mut record in records {
+            record.norm = similarity::norm(&record.embedding);
+            if store.disk.is_none() {
+                store.namespaces.entry(record.namespace.clone()).or_default().push(record);
+            } else {
+                pending.push(record);
+            }
+        }
+        if let Some(disk) = store.disk.as_mut() {
+            disk.append(&pending)?;
+        }
+        Ok(())
+    }
+
+    /// Embed and store `text`. Returns the new record id.
+    pub async fn add(
+        &self,
+        namespace: &str,
+        text: &str,
+        metadata: HashMap<String, String>,
+        citation: Option<Citation>,
+    ) -> Result<String, MemoryError> {
+        let embedding = self
+            .embedder
+            .embed(&[text.to_string()])
+            .await?
+            .pop()
+            .ok_or_else(|| MemoryError::Embedding("embedder returned no vectors".to_string()))?;
+        let id = Uuid::new_v4().to_string();
+        self.insert(vec![MemoryRecord {
+            id: id.clone(),
+            namespace: namespace.to_string(),
+            text: text.to_string(),
+            embedding,
+            metadata,
+            citation,
+            norm: 0.0,
+        }])?;
+        Ok(id)
+    }
+
+    /// Embed and store chunks in one batch, citing each chunk's source and offsets.
+    pub async fn add_chunks(&self, namespace: &str, chunks: &[Chunk]) -> Result<Vec<String>, MemoryError> {
+        let texts: Vec<String> = chunks.iter().map(|c| c.text.clone()).collect();
+        let embeddings = self.embedder.embed(&texts).await?;
+        if embeddings.len() != chunks.len() {
+            return Err(MemoryError::Embedding(format!(
+                "embedder returned {} vectors for {} chunks",
+                embeddings.len(),
+                chunks.len()
+            )));
+        }
+        let records: Vec<MemoryRecord> = chunks
+            .iter()
+            .zip(embeddings)
+            .map(|(chunk, embedding)| MemoryRecord {
+                id: Uuid::new_v4().to_string(),
+                namespace: namespace.to_string(),
+                text: chunk.text.clone(),
+                embedding,
+                metadata: HashMap::from([("chunk_index".to_string(), chunk.index.to_string())]),
+                citation: Some(Citation::from_chunk(chunk)),
+                norm: 0.0,
+            })
+            .collect();
+        let ids = records.iter().map(|r| r.id.clone()).collect();
+        self.insert(records)?;
+        Ok(ids)
+    }
+
+    /// Store chunks read from another pipeline's corpus (see `document_formats`). Chunks with a stored
+    /// embedding keep it; the rest are embedded in one batch. Each record keeps the chunk's id, when it has
+    /// one, and its metadata. Nothing is stored unless every vector is usable (see `insert`).
+    pub async fn add_external(&self, namespace: &str, chunks: &[ExternalChunk]) -> Result<Vec<String>, MemoryError> {
+        let missing: Vec<String> = chunks
+            .iter()
+            .filter(|external| external.embedding.is_none())
+            .map(|external| external.chunk.text.clone())
+            .collect();
+        let mut embedded = if missing.is_empty() { Vec::new() } else { self.embedder.embed(&missing).await? }.into_iter();
+        if embedded.len() != missing.len() {
+            return Err(MemoryError::Embedding(format!(
+                "embedder returned {} vectors for {} chunks",
+                embedded.len(),
+                missing.len()
+            )));
+        }
+        let mut records = Vec::with_capacity(chunks.len());
+        for external in chunks {
+            let embedding = match &external.embedding {
+                Some(embedding) => embedding.clone(),
+                None => embedded.next().unwrap_or_default(),
+            };
+            let mut metadata = external.metadata.clone();
+            metadata.insert("chunk_index".to_string(), external.chunk.index.to_string());
+            records.push(MemoryRecord {
+                id: external.id.clone().unwrap_or_else(|| Uuid::new_v4().to_string()),
+                namespace: namespace.to_string(),
+                text: external.chunk.text.clone(),
+                embedding,
+                metadata,
+                citation: Some(external.citation()),
+                norm: 0.0,
+            });
+        }
+        let ids = records.iter().map(|r| r.id.clone()).collect();
+        self.insert(records)?;
+        Ok(ids)
+    }
+
+    /// Write every record, vectors included, to one archive file at `path`.
+    pub fn export(&self, path: impl AsRef<Path>) -> Result<MemoryManifest, MemoryError> {
+        let mut writer = ArchiveWriter::create(path)?;
+        let store = self.store.read().unwrap();
+        match &store.disk {
+            Some(disk) => disk.for_each_record(|record| writer.push(&record))?,
+            None => {
+                let mut namespaces: Vec<&String> = store.namespaces.keys().collect();
+                namespaces.sort();
+                for record in namespaces.into_iter().flat_map(|namespace| &store.namespaces[namespace]) {
+                    writer.push(record)?;
+                }
+            }
+        }
+        writer.finish(store.dimension)
+    }
+
+    /// Load an archive written by `export`, keeping record ids. The archive is checked against its manifest
+    /// before anything is inserted, and its dimension must match the store's.
+    pub fn import(&self, path: impl AsRef<Path>) -> Result<MemoryManifest, MemoryError> {
+        let path = path.as_ref();
+        let manifest = verify_archive(path)?;
+        if let (Some(expected), Some(actual)) = (self.store.read().unwrap().dimension, manifest.dimension) {
+            if expected != actual {
+                return Err(MemoryError::DimensionMismatch { expected, actual });
+            }
+        }
+        read_archive(path, IMPORT_BATCH, |records| self.insert(records))?;
+        Ok(manifest)
+    }
+
+    /// Recall the `top_k` records most similar to `query` across `namespaces`.
+    pub async fn recall(&self, namespaces: &[String], query: &str, top_k: usize) -> Result<Vec<RecallHit>, MemoryError> {
+        let embedding = self
+            .embedder
+            .embed(&[query.to_string()])
+            .await?
+            .pop()
+            .ok_or_else(|| MemoryError::Embedding("embedder returned no vectors".to_string()))?;
+        self.recall_by_vector(namespaces, &embedding, top_k)
+    }
+
+    pub fn recall_by_vector(&self, namespaces: &[String], query: &[f32], top_k: usize) -> Result<Vec<RecallHit>, MemoryError> {
+        let store = self.store.read().unwrap();
+        if let Some(expected) = store.dimension {
+            if query.len() != expected {
+                return Err(MemoryError::DimensionMismatch { expected, actual: query.len() });
+            }
+        }
+        if let Some(disk) = &store.disk {
+            return disk.search(namespaces, query, top_k);
+        }
+        let records: Vec<&MemoryRecord> = namespaces
+            .iter()
+            .filter_map(|ns| store.namespaces.get(ns))
+            .flatten()
+            .collect();
+        let vectors: Vec<(&[f32], f32)> = records.iter().map(|r| (r.embedding.as_slice(), r.norm)).collect();
+        let hits = top_k_cosine(query, &vectors, top_k)
+            .into_iter()
+            .map(|scored| {
+                let record = records[scored.index];
+                RecallHit {
+                    id: record.id.clone(),
+                    namespace: record.namespace.clone(),
+                    text: record.text.clone(),
+                    score: scored.score,
+                    metadata: record.metadata.clone(),
+                    citation: record.citation.clone(),
+                }
+            })
+            .collect();
+        Ok(hits)
+    }
+}
+
+fn main() {
+    let a = [1.0, 0.0, 1.0];
+    let b = [1.0, 1.0, 0.0];
+    println!("{}", cosine_similarity(&a, &b));
+}
+```
+
+### Notes
+* The first inserted vector fixes the store's dimension; later vectors of a different size are rejected.
+  A disk-backed store takes its dimension from `open` instead. Empty vectors are always rejected: one
+  would otherwise fix the dimension at 0 and every real vector after it would mismatch.
+* Similarity is a linear scan using the SIMD kernels in `swarms::memory::similarity`; only the top `k` hits are cloned out of the store.
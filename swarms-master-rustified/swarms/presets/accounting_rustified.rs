@@ -0,0 +1,255 @@
+### Overview
+The Python package ships the accountant swarm only as prompt strings in `accountant_swarm_prompts.py`,
+leaving the wiring to each user. `accounting_swarm()` wires it: four agents built from those prompts run
+in order as a `SequentialWorkflow`:
+
+1. **DocAnalyzer** (`DOC_ANALYZER_AGENT_PROMPT`) reads the documents.
+2. **Summarizer** (`SUMMARY_GENERATOR_AGENT_PROMPT`) turns the analysis into a summary.
+3. **FraudDetection** (`FRAUD_DETECTION_AGENT_PROMPT`) checks the summary for warning signs.
+4. **DecisionSupport** (`DECISION_MAKING_PROMPT`) recommends what to do.
+
+`AccountingSwarm::run` loads the documents with `data_to_text`, so CSV, JSON, text, Markdown, and PDF
+statements all work. It runs the workflow and writes every stage's output to a Markdown report, saved as
+an `Artifact`. Running again in the same report directory adds a version to the report's history.
+
+`AccountingSwarm::from_profiles` replaces any of the four agents with the agent profile of the same name,
+so the prompts can be kept and edited as Markdown files in an `agents/` directory.
+
+The agents' model calls go through the `model` closure, which receives the agent (for its system prompt
+and model name) and its input.
+
+### Rust Code
+```rust
+use crate::swarms::artifacts::main_artifact::Artifact;
+use crate::swarms::prompts::accountant_swarm_prompts::{
+    DECISION_MAKING_PROMPT, DOC_ANALYZER_AGENT_PROMPT, FRAUD_DETECTION_AGENT_PROMPT, SUMMARY_GENERATOR_AGENT_PROMPT,
+};
+use crate::swarms::structs::agent::{Agent, LlmAgent};
+use crate::swarms::structs::agent_profile::{AgentProfileRegistry, ProfileError};
+use crate::swarms::structs::sequential_workflow::{SequentialWorkflow, StageOutput};
+use crate::swarms::utils::data_to_text::data_to_text;
+use log::info;
+use serde::Serialize;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// The model every agent uses unless `AccountingSwarm::new` is given another.
+pub const DEFAULT_ACCOUNTING_MODEL: &str = "gpt-4o";
+
+/// The report's file name inside the report directory.
+pub const REPORT_FILE: &str = "accounting_report.md";
+
+/// Agent names and prompts, in workflow order.
+const STAGES: [(&str, &str, &str); 4] = [
+    ("DocAnalyzer", "Analyzes financial documents", DOC_ANALYZER_AGENT_PROMPT),
+    ("Summarizer", "Summarizes the document analysis", SUMMARY_GENERATOR_AGENT_PROMPT),
+    ("FraudDetection", "Flags signs of fraud", FRAUD_DETECTION_AGENT_PROMPT),
+    ("DecisionSupport", "Recommends financial decisions", DECISION_MAKING_PROMPT),
+];
+
+/// Report headings, one per stage.
+const HEADINGS: [&str; 4] = ["Document Analysis", "Summary", "Fraud Review", "Recommendations"];
+
+#[derive(Debug)]
+pub enum AccountingError {
+    NoDocuments,
+    Load { path: PathBuf, error: io::Error },
+    /// An agent's model call failed.
+    Stage(String),
+    Io(io::Error),
+}
+
+impl fmt::Display for AccountingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AccountingError::NoDocuments => write!(f, "no documents to analyze"),
+            AccountingError::Load { path, error } => write!(f, "failed to load {}: {}", path.display(), error),
+            AccountingError::Stage(message) => write!(f, "accounting swarm stopped: {}", message),
+            AccountingError::Io(e) => write!(f, "failed to write the accounting report: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for AccountingError {}
+
+impl From<io::Error> for AccountingError {
+    fn from(e: io::Error) -> Self {
+        AccountingError::Io(e)
+    }
+}
+
+/// One loaded document.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Document {
+    pub path: PathBuf,
+    pub text: String,
+}
+
+impl Document {
+    pub fn load(path: impl AsRef<Path>) -> Result<Document, AccountingError> {
+        let path = path.as_ref().to_path_buf();
+        match data_to_text(&path.to_string_lossy()) {
+            Ok(text) => Ok(Document { path, text }),
+            Err(error) => Err(AccountingError::Load { path, error }),
+        }
+    }
+}
+
+/// The outcome of a run: every stage's output and where the report was written.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AccountingReport {
+    pub documents: Vec<PathBuf>,
+    pub stages: Vec<StageOutput>,
+    pub markdown: String,
+    pub path: PathBuf,
+    /// The report artifact's version, starting at 1.
+    pub version: i32,
+}
+
+impl AccountingReport {
+    /// The DecisionSupport agent's recommendations.
+    pub fn recommendations(&self) -> Option<&str> {
+        self.stages.last().map(|stage| stage.output.as_str())
+    }
+}
+
+pub struct AccountingSwarm {
+    workflow: SequentialWorkflow,
+    report_dir: PathBuf,
+}
+
+fn stage_agent((name, description, prompt): &(&str, &str, &str), llm: &str) -> LlmAgent {
+    LlmAgent::builder()
+        .name(*name)
+        .llm(llm)
+        .description(*description)
+        .system_prompt(prompt.trim())
+        .build()
+        .expect("preset agents are valid")
+}
+
+/// The accountant swarm with the default model, writing its report to the current directory.
+pub fn accounting_swarm() -> AccountingSwarm {
+    AccountingSwarm::new(DEFAULT_ACCOUNTING_MODEL)
+}
+
+impl AccountingSwarm {
+    pub fn new(llm: &str) -> Self {
+        let agents = STAGES.iter().map(|stage| stage_agent(stage, llm)).collect();
+        AccountingSwarm::with_agents(agents)
+    }
+
+    /// The swarm with each stage's agent built from the profile of the same name in `profiles` (see
+    /// `agent_profile`), so the prompts can be edited as Markdown files. A stage without a profile keeps its
+    /// built-in prompt; agents whose profile names no model use `llm`.
+    pub fn from_profiles(llm: &str, profiles: &AgentProfileRegistry) -> Result<Self, ProfileError> {
+        let agents = STAGES
+            .iter()
+            .map(|stage| match profiles.get(stage.0) {
+                Some(profile) => profile.to_agent(llm),
+                None => Ok(stage_agent(stage, llm)),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AccountingSwarm::with_agents(agents))
+    }
+
+    fn with_agents(agents: Vec<LlmAgent>) -> Self {
+        let workflow = SequentialWorkflow::new(
+            "AccountingSwarm".to_string(),
+            "Document analysis, summary, fraud review, and decision support".to_string(),
+            agents.into_iter().map(Into::into).collect(),
+            1,
+            "all".to_string(),
+            false,
+            || (),
+        );
+        AccountingSwarm { workflow, report_dir: PathBuf::from(".") }
+    }
+
+    /// Write the report under `dir` instead of the current directory.
+    pub fn report_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.report_dir = dir.into();
+        self
+    }
+
+    pub fn workflow(&self) -> &SequentialWorkflow {
+        &self.workflow
+    }
+
+    /// The DocAnalyzer's input: each document under its file name.
+    pub fn task(documents: &[Document]) -> String {
+        let mut task = String::from("Analyze the following financial documents.\n");
+        for document in documents {
+            let name = document.path.file_name().map_or_else(|| document.path.to_string_lossy(), |name| name.to_string_lossy());
+            task.push_str(&format!("\n=== {} ===\n{}\n", name, document.text.trim()));
+        }
+        task
+    }
+
+    /// Load `documents`, run the four agents, and save the report. `model` reads each agent's prompt and
+    /// model through `spec()`; the preset's agents all have one.
+    pub fn run<P: AsRef<Path>>(
+        &self,
+        documents: &[P],
+        model: impl FnMut(&dyn Agent, &str) -> Result<String, String>,
+    ) -> Result<AccountingReport, AccountingError> {
+        if documents.is_empty() {
+            return Err(AccountingError::NoDocuments);
+        }
+        let documents = documents.iter().map(Document::load).collect::<Result<Vec<_>, _>>()?;
+        let stages = self.workflow.run_with(&Self::task(&documents), model).map_err(AccountingError::Stage)?;
+        let paths: Vec<PathBuf> = documents.into_iter().map(|document| document.path).collect();
+        let markdown = render(&paths, &stages);
+        let (path, version) = self.save(&markdown)?;
+        info!("Accounting report version {} written to {}", version, path.display());
+        Ok(AccountingReport { documents: paths, stages, markdown, path, version })
+    }
+
+    fn save(&self, markdown: &str) -> Result<(PathBuf, i32), AccountingError> {
+        std::fs::create_dir_all(&self.report_dir)?;
+        let path = self.report_dir.join(REPORT_FILE);
+        let history = self.report_dir.join(format!("{}.artifact.json", REPORT_FILE));
+        let to_io = |e: String| io::Error::new(io::ErrorKind::Other, e);
+        let mut artifact = if history.exists() {
+            let mut artifact = Artifact::import_from_json(&history.to_string_lossy())?;
+            artifact.edit(markdown.to_string()).map_err(to_io)?;
+            artifact
+        } else {
+            let mut artifact = Artifact::new(path.to_string_lossy().into_owned(), ".md".to_string());
+            artifact.create(markdown.to_string()).map_err(to_io)?;
+            artifact
+        };
+        artifact.save()?;
+        artifact.export_to_json(&history.to_string_lossy())?;
+        Ok((path, artifact.current_version()))
+    }
+}
+
+fn render(documents: &[PathBuf], stages: &[StageOutput]) -> String {
+    let mut report = String::from("# Accounting Report\n\n## Documents\n");
+    for path in documents {
+        report.push_str(&format!("- {}\n", path.display()));
+    }
+    for (heading, stage) in HEADINGS.iter().zip(stages) {
+        report.push_str(&format!("\n## {}\n_{}_\n\n{}\n", heading, stage.agent, stage.output.trim()));
+    }
+    report
+}
+
+fn main() -> Result<(), AccountingError> {
+    std::fs::write("q3_ledger.csv", "account,amount\nrevenue,420000\nconsulting,-180000\n")?;
+    let swarm = accounting_swarm().report_dir("reports");
+    println!("{}", swarm.workflow().flow());
+    let report = swarm.run(&["q3_ledger.csv"], |agent, input| {
+        Ok(format!("{} reviewed {} bytes of input", agent.name(), input.len()))
+    })?;
+    println!("{}", report.markdown);
+    Ok(())
+}
+```
+
+### Notes
+* The DocAnalyzer prompt is written for a vision model. Text loaders lose charts and layout, so pass extracted tables (CSV) where the statements have them.
+* The report includes every stage, not only the recommendations, so a reviewer can trace a fraud flag back to the analysis it came from.
+* Image files load as empty text; `run` does not reject them, but the agents will have nothing to read.
@@ -0,0 +1,287 @@
+### Overview
+The Rust counterpart of the spike example `new_features_examples/spike/test.py`, packaged as a preset.
+`college_selection_swarm()` runs its six agents in order as a `SequentialWorkflow`: profile analysis,
+college research, matching, a moderated debate, a critique, and the final decision.
+
+The final agent's answer is typed. Its request carries the `CollegesRecommendation` JSON schema as a
+`ResponseFormat::JsonSchema`, so providers with structured outputs constrain the reply to it, and
+`ProviderCapabilities::downgrade` falls back to prompt-based JSON for those without. The final agent's
+system prompt also describes the JSON, so a prompt-only reply has the same shape. `run` parses the reply
+into a `CollegesRecommendation`.
+
+Each agent's model call goes through the `provider` closure, which receives the complete
+`ChatCompletionRequest`: the agent's model, its system prompt, its input, and for the final agent the
+response format. Tests pass a scripted closure; an application wraps `OpenAiCompatProvider::chat`.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem};
+use crate::swarms::structs::agent::{Agent, LlmAgent};
+use crate::swarms::structs::sequential_workflow::{SequentialWorkflow, StageOutput};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::fmt;
+
+/// The model every agent uses unless `CollegeSelectionSwarm::new` is given another.
+pub const DEFAULT_COLLEGE_MODEL: &str = "llama-3.1-70b-versatile";
+
+/// The schema name sent with the final agent's request.
+pub const RECOMMENDATION_SCHEMA_NAME: &str = "CollegesRecommendation";
+
+const FINAL_AGENT: &str = "Final-Decision-Maker";
+
+const AGENTS: [(&str, &str); 6] = [
+    (
+        "Student-Profile-Analyzer",
+        "You are an expert student profile analyzer. Your role is to:
+1. Analyze academic performance, test scores, and extracurricular activities
+2. Identify student's strengths, weaknesses, and unique qualities
+3. Evaluate personal statements and essays
+4. Assess leadership experiences and community involvement
+5. Determine student's preferences for college environment, location, and programs
+6. Create a comprehensive student profile summary
+
+Always consider both quantitative metrics (GPA, test scores) and qualitative aspects
+(personal growth, challenges overcome, unique perspectives).",
+    ),
+    (
+        "College-Research-Specialist",
+        "You are a college research specialist. Your role is to:
+1. Maintain updated knowledge of college admission requirements
+2. Research academic programs, campus culture, and student life
+3. Analyze admission statistics and trends
+4. Evaluate college-specific opportunities and resources
+5. Consider financial aid availability and scholarship opportunities
+6. Track historical admission data and acceptance rates
+
+Focus on providing accurate, comprehensive information about each institution
+while considering both academic and cultural fit factors.",
+    ),
+    (
+        "College-Match-Maker",
+        "You are a college matching specialist. Your role is to:
+1. Compare student profiles with college requirements
+2. Evaluate fit based on academic, social, and cultural factors
+3. Consider geographic preferences and constraints
+4. Assess financial fit and aid opportunities
+5. Create tiered lists of reach, target, and safety schools
+6. Explain the reasoning behind each match
+
+Always provide a balanced list with realistic expectations while
+considering both student preferences and admission probability.",
+    ),
+    (
+        "Debate-Moderator",
+        "You are a college selection debate moderator. Your role is to:
+1. Facilitate discussions between different perspectives
+2. Ensure all relevant factors are considered
+3. Challenge assumptions and biases
+4. Synthesize different viewpoints
+5. Guide the group toward consensus
+6. Document key points of agreement and disagreement
+
+Maintain objectivity while ensuring all important factors are thoroughly discussed
+and evaluated.",
+    ),
+    (
+        "College-Selection-Critic",
+        "You are a college selection critic. Your role is to:
+1. Evaluate the strength of college matches
+2. Identify potential overlooked factors
+3. Challenge assumptions in the selection process
+4. Assess risks and potential drawbacks
+5. Provide constructive feedback on selections
+6. Suggest alternative options when appropriate
+
+Focus on constructive criticism that helps improve the final college list
+while maintaining realistic expectations.",
+    ),
+    (
+        FINAL_AGENT,
+        "You are a college selection final decision maker. Your role is to:
+1. Synthesize all previous analyses and discussions
+2. Weigh competing factors and trade-offs
+3. Create a final ranked list of recommended colleges
+4. Provide clear rationale for each recommendation
+5. Include specific action items for each selected school
+6. Outline next steps in the application process
+
+Focus on creating actionable, well-reasoned final recommendations that
+balance all relevant factors and stakeholder input.
+
+Reply with a JSON object only: `colleges`, a ranked array of objects with `college_name`,
+`college_description`, and `college_admission_requirements`, and `reasoning`, a string.",
+    ),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollegeLog {
+    pub college_name: String,
+    pub college_description: String,
+    pub college_admission_requirements: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CollegesRecommendation {
+    pub colleges: Vec<CollegeLog>,
+    pub reasoning: String,
+}
+
+impl CollegesRecommendation {
+    /// The JSON schema sent as the final agent's response format.
+    pub fn schema() -> JsonValue {
+        json!({
+            "type": "object",
+            "properties": {
+                "colleges": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "college_name": { "type": "string" },
+                            "college_description": { "type": "string" },
+                            "college_admission_requirements": { "type": "string" }
+                        },
+                        "required": ["college_name", "college_description", "college_admission_requirements"],
+                        "additionalProperties": false
+                    }
+                },
+                "reasoning": { "type": "string" }
+            },
+            "required": ["colleges", "reasoning"],
+            "additionalProperties": false
+        })
+    }
+
+    /// Parse the final agent's reply. The JSON may be inside a code fence or surrounded by prose, as
+    /// prompt-based JSON often is.
+    pub fn parse(reply: &str) -> Result<Self, String> {
+        let start = reply.find('{').ok_or_else(|| "no JSON object in reply".to_string())?;
+        let end = reply.rfind('}').filter(|&end| end > start).ok_or_else(|| "unterminated JSON in reply".to_string())?;
+        serde_json::from_str(&reply[start..=end]).map_err(|e| e.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollegeSelectionError {
+    /// An agent's model call failed.
+    Stage(String),
+    /// The final agent's reply did not match `CollegesRecommendation`.
+    InvalidRecommendation { message: String, output: String },
+}
+
+impl fmt::Display for CollegeSelectionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CollegeSelectionError::Stage(message) => write!(f, "college selection stopped: {}", message),
+            CollegeSelectionError::InvalidRecommendation { message, .. } => {
+                write!(f, "final recommendation is not a valid {}: {}", RECOMMENDATION_SCHEMA_NAME, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CollegeSelectionError {}
+
+/// A finished run: every agent's output and the parsed recommendation.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CollegeSelection {
+    pub stages: Vec<StageOutput>,
+    pub recommendation: CollegesRecommendation,
+}
+
+pub struct CollegeSelectionSwarm {
+    workflow: SequentialWorkflow,
+}
+
+/// The college-selection swarm with the default model.
+pub fn college_selection_swarm() -> CollegeSelectionSwarm {
+    CollegeSelectionSwarm::new(DEFAULT_COLLEGE_MODEL)
+}
+
+impl CollegeSelectionSwarm {
+    pub fn new(llm: &str) -> Self {
+        let agents = AGENTS
+            .iter()
+            .map(|(name, prompt)| {
+                LlmAgent::builder().name(*name).llm(llm).system_prompt(*prompt).build().expect("preset agents are valid").into()
+            })
+            .collect();
+        let workflow = SequentialWorkflow::new(
+            "college-selection-swarm".to_string(),
+            "Comprehensive college selection and analysis system".to_string(),
+            agents,
+            1,
+            "all".to_string(),
+            false,
+            || (),
+        );
+        CollegeSelectionSwarm { workflow }
+    }
+
+    pub fn workflow(&self) -> &SequentialWorkflow {
+        &self.workflow
+    }
+
+    /// The request for `agent` with `input`; the final agent's request asks for a `CollegesRecommendation`.
+    /// An agent without a spec is sent to the default model with no system prompt.
+    pub fn request(agent: &dyn Agent, input: &str) -> ChatCompletionRequest {
+        let message = |role: &str, text: &str| {
+            ChatMessageInput::new(role.to_string(), vec![ContentItem::Text { text: text.to_string() }])
+        };
+        let (llm, system_prompt) =
+            agent.spec().map_or((DEFAULT_COLLEGE_MODEL, ""), |spec| (spec.llm.as_str(), spec.system_prompt.as_str()));
+        let mut request =
+            ChatCompletionRequest::new(llm.to_string(), vec![message("system", system_prompt), message("user", input)]);
+        request.temperature = Some(0.1);
+        if agent.name() == FINAL_AGENT {
+            request = request.with_json_schema(RECOMMENDATION_SCHEMA_NAME, CollegesRecommendation::schema());
+        }
+        request
+    }
+
+    /// Run the agents on `student_profile` and parse the final recommendation.
+    pub fn run(
+        &self,
+        student_profile: &str,
+        mut provider: impl FnMut(&ChatCompletionRequest) -> Result<String, String>,
+    ) -> Result<CollegeSelection, CollegeSelectionError> {
+        let stages = self
+            .workflow
+            .run_with(student_profile, |agent, input| provider(&Self::request(agent, input)))
+            .map_err(CollegeSelectionError::Stage)?;
+        let output = stages.last().map(|stage| stage.output.clone()).unwrap_or_default();
+        let recommendation = CollegesRecommendation::parse(&output)
+            .map_err(|message| CollegeSelectionError::InvalidRecommendation { message, output })?;
+        Ok(CollegeSelection { stages, recommendation })
+    }
+}
+
+fn main() -> Result<(), CollegeSelectionError> {
+    let student_profile = "Student Profile:
+- GPA: 3.8
+- SAT: 1450
+- Interests: Computer Science, Robotics
+- Location Preference: East Coast
+- Budget: Need financial aid";
+    let selection = college_selection_swarm().run(student_profile, |request| {
+        Ok(match &request.response_format {
+            Some(_) => r#"{"colleges": [{"college_name": "Northeastern University",
+                "college_description": "Co-op program with strong robotics research",
+                "college_admission_requirements": "SAT 1420-1540"}],
+                "reasoning": "Urban East Coast campus with generous aid"}"#
+                .to_string(),
+            None => format!("notes from {}", request.model),
+        })
+    })?;
+    for college in &selection.recommendation.colleges {
+        println!("{}: {}", college.college_name, college.college_admission_requirements);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* Strict JSON schema mode requires every property to be listed in `required` with `additionalProperties: false`, which is why the schema spells both out.
+* The intermediate agents' outputs are free text; only the final agent is constrained.
+* A reply that fails to parse is returned whole in `InvalidRecommendation::output`, so the caller can retry or show it.
@@ -0,0 +1,173 @@
+### Overview
+There is no Python counterpart; Python strings are reference counted, so passing a system prompt to every
+agent in a fan-out is free there. In Rust, the same code cloned multi-KB `String` constants once per
+agent and once per message. This module provides the two building blocks for allocation-light prompt
+assembly:
+
+* `PromptFragment`, an `Arc<str>` newtype for long-lived prompt text (system prompts, rules, tool
+  catalogs). Cloning it bumps a reference count instead of copying bytes.
+* `PromptAssembler`, which collects borrowed or owned pieces as `Cow<'_, str>` and joins them with a
+  single allocation sized up front.
+
+`benches/prompt_assembly_rustified.rs` compares both against the `String`-cloning baseline.
+
+### Rust Code
+```rust
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Deref;
+use std::sync::Arc;
+
+/// Shared, immutable prompt text. Cheap to clone and `Send + Sync`.
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct PromptFragment(Arc<str>);
+
+impl PromptFragment {
+    pub fn new(text: impl Into<Arc<str>>) -> Self {
+        PromptFragment(text.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// True when both fragments point at the same allocation.
+    pub fn ptr_eq(&self, other: &PromptFragment) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for PromptFragment {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for PromptFragment {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<&str> for PromptFragment {
+    fn from(text: &str) -> Self {
+        PromptFragment(Arc::from(text))
+    }
+}
+
+impl From<String> for PromptFragment {
+    fn from(text: String) -> Self {
+        PromptFragment(Arc::from(text))
+    }
+}
+
+impl<'a> From<&'a PromptFragment> for Cow<'a, str> {
+    fn from(fragment: &'a PromptFragment) -> Self {
+        Cow::Borrowed(fragment.as_str())
+    }
+}
+
+impl fmt::Debug for PromptFragment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for PromptFragment {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for PromptFragment {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for PromptFragment {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(PromptFragment::from)
+    }
+}
+
+/// Collects prompt pieces without copying them, then joins them in one allocation.
+#[derive(Debug, Default, Clone)]
+pub struct PromptAssembler<'a> {
+    pieces: Vec<Cow<'a, str>>,
+}
+
+impl<'a> PromptAssembler<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_capacity(pieces: usize) -> Self {
+        PromptAssembler { pieces: Vec::with_capacity(pieces) }
+    }
+
+    pub fn push(&mut self, piece: impl Into<Cow<'a, str>>) -> &mut Self {
+        let piece = piece.into();
+        if !piece.is_empty() {
+            self.pieces.push(piece);
+        }
+        self
+    }
+
+    /// Push `piece` preceded by `separator` unless this is the first piece.
+    pub fn push_separated(&mut self, separator: &'a str, piece: impl Into<Cow<'a, str>>) -> &mut Self {
+        let piece = piece.into();
+        if piece.is_empty() {
+            return self;
+        }
+        if !self.pieces.is_empty() {
+            self.pieces.push(Cow::Borrowed(separator));
+        }
+        self.pieces.push(piece);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.pieces.iter().map(|p| p.len()).sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pieces.is_empty()
+    }
+
+    /// Join all pieces. Allocates exactly once.
+    pub fn build(&self) -> String {
+        let mut out = String::with_capacity(self.len());
+        for piece in &self.pieces {
+            out.push_str(piece);
+        }
+        out
+    }
+
+    /// Like `build`, but returns the single piece without copying when there is only one.
+    pub fn build_cow(self) -> Cow<'a, str> {
+        match self.pieces.len() {
+            0 => Cow::Borrowed(""),
+            1 => self.pieces.into_iter().next().expect("one piece"),
+            _ => Cow::Owned(self.build()),
+        }
+    }
+}
+
+fn main() {
+    let system = PromptFragment::from("You are a financial analyst. ".repeat(200));
+    let per_agent: Vec<PromptFragment> = (0..8).map(|_| system.clone()).collect();
+    assert!(per_agent.iter().all(|p| p.ptr_eq(&system)));
+
+    let mut prompt = PromptAssembler::with_capacity(4);
+    prompt.push(&system).push_separated("\n\n", "Task: summarize Q3 revenue drivers.");
+    println!("{} bytes", prompt.build().len());
+}
+```
+
+### Notes
+* Build fragments once (at agent construction or config load) and clone the fragment; converting a `&str` each call allocates every time.
+* `PromptAssembler` drops empty pieces, so optional sections can be pushed unconditionally.
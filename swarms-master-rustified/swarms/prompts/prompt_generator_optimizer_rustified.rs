@@ -15,7 +15,7 @@ struct Prompt {
 }
 
 // Define the prompt generator system prompt content
-const OPENAI_PROMPT_GENERATOR_SYS_PROMPT: &str = r#"
+pub const OPENAI_PROMPT_GENERATOR_SYS_PROMPT: &str = r#"
 Given a task description or existing prompt, produce a detailed system prompt to guide a language model in completing the task effectively.
 
 # Guidelines
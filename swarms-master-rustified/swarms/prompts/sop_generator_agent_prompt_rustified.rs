@@ -19,7 +19,7 @@
 /// # Returns
 ///
 /// A formatted string containing the Standard Operating Procedure.
-fn sop_generator_agent_prompt(task_name: &str) -> String {
+pub fn sop_generator_agent_prompt(task_name: &str) -> String {
     let mut sop_generator_sop = String::from("Your are an autonomous agent that generates Standard Operating Procedures for autonomous\n");
     sop_generator_sop.push_str("worker agents, your goal is to generate a SOP for the following task: ");
     sop_generator_sop.push_str(task_name);
@@ -12,185 +12,294 @@ The provided Python file defines a Pydantic model for an agent schema. While Rus
 
 ```rust
 // Import necessary crates
+#[cfg(feature = "memory")]
+use crate::swarms::memory::reranker::RerankerConfig;
 use serde::{Deserialize, Serialize};
 use validator::{Validate, ValidationError};
 use std::collections::HashMap;
 
-// Define the AgentSchema struct
+// Define the AgentSchema struct; missing keys take the Python defaults
 #[derive(Debug, Serialize, Deserialize, Validate)]
-struct AgentSchema {
+#[serde(default)]
+pub struct AgentSchema {
     #[validate(range(min = 1))]
-    llm: String,
+    pub llm: String,
     #[validate(range(min = 1))]
-    max_tokens: i32,
+    pub max_tokens: i32,
     #[validate(range(min = 1))]
-    context_window: i32,
-    user_name: String,
-    agent_name: String,
-    system_prompt: String,
+    pub context_window: i32,
+    pub user_name: String,
+    pub agent_name: String,
+    pub system_prompt: String,
     #[serde(skip_serializing_if = "Option::is_none")]
-    template: Option<String>,
+    pub template: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 1))]
-    max_loops: Option<i32>,
+    pub max_loops: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stopping_condition: Option<String>,
+    pub stopping_condition: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
-    loop_interval: Option<i32>,
+    pub loop_interval: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
-    retry_attempts: Option<i32>,
+    pub retry_attempts: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
-    retry_interval: Option<i32>,
+    pub retry_interval: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    return_history: Option<bool>,
+    pub return_history: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stopping_token: Option<String>,
+    pub stopping_token: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    dynamic_loops: Option<bool>,
+    pub dynamic_loops: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    interactive: Option<bool>,
+    pub interactive: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    dashboard: Option<bool>,
+    pub dashboard: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    agent_description: Option<String>,
+    pub agent_description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tools: Option<Vec<String>>,
+    pub tools: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    dynamic_temperature_enabled: Option<bool>,
+    pub dynamic_temperature_enabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sop: Option<String>,
+    pub sop: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sop_list: Option<Vec<String>>,
+    pub sop_list: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    saved_state_path: Option<String>,
+    pub saved_state_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    autosave: Option<bool>,
+    pub autosave: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    self_healing_enabled: Option<bool>,
+    pub self_healing_enabled: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    code_interpreter: Option<bool>,
+    pub code_interpreter: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    multi_modal: Option<bool>,
+    pub multi_modal: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pdf_path: Option<String>,
+    pub pdf_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    list_of_pdf: Option<String>,
+    pub list_of_pdf: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tokenizer: Option<String>,
+    pub tokenizer: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    long_term_memory: Option<String>,
+    pub long_term_memory: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    preset_stopping_token: Option<bool>,
+    pub preset_stopping_token: Option<bool>,
+    // Regexes that end a streamed response early, alongside `stopping_token`
     #[serde(skip_serializing_if = "Option::is_none")]
-    traceback: Option<String>,
+    pub stop_patterns: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    traceback_handlers: Option<String>,
+    pub traceback: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    streaming_on: Option<bool>,
+    pub traceback_handlers: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    docs: Option<Vec<String>>,
+    pub streaming_on: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    docs_folder: Option<String>,
+    pub docs: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    verbose: Option<bool>,
+    pub docs_folder: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    parser: Option<String>,
+    pub verbose: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    best_of_n: Option<i32>,
+    pub parser: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    callback: Option<String>,
+    pub best_of_n: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    metadata: Option<HashMap<String, String>>,
+    pub callback: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    callbacks: Option<Vec<String>>,
+    pub metadata: Option<HashMap<String, String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    logger_handler: Option<String>,
+    pub callbacks: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    search_algorithm: Option<String>,
+    pub logger_handler: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    logs_to_filename: Option<String>,
+    pub search_algorithm: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    evaluator: Option<String>,
+    pub logs_to_filename: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    output_json: Option<bool>,
+    pub evaluator: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    stopping_func: Option<String>,
+    pub output_json: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    custom_loop_condition: Option<String>,
+    pub stopping_func: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sentiment_threshold: Option<f64>,
+    pub custom_loop_condition: Option<String>,
+    // Enables the tone check; see `SentimentGateConfig::from_schema`
     #[serde(skip_serializing_if = "Option::is_none")]
-    custom_exit_command: Option<String>,
+    pub sentiment_threshold: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    sentiment_analyzer: Option<String>,
+    pub custom_exit_command: Option<String>,
+    // An analyzer name for `sentiment::analyzer_by_name`; defaults to "lexicon"
     #[serde(skip_serializing_if = "Option::is_none")]
-    limit_tokens_from_string: Option<String>,
+    pub sentiment_analyzer: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    custom_tools_prompt: Option<String>,
+    pub limit_tokens_from_string: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tool_schema: Option<String>,
+    pub custom_tools_prompt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    output_type: Option<String>,
+    pub tool_schema: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    function_calling_type: Option<String>,
+    pub output_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    output_cleaner: Option<String>,
+    pub function_calling_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    function_calling_format_type: Option<String>,
+    pub output_cleaner: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    list_base_models: Option<Vec<String>>,
+    pub function_calling_format_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    metadata_output_type: Option<String>,
+    pub list_base_models: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    state_save_file_type: Option<String>,
+    pub metadata_output_type: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    chain_of_thoughts: Option<bool>,
+    pub state_save_file_type: Option<String>,
+    // Maps to `ConversationBuilder::chain_of_thoughts`; the reasoning stays in the conversation's scratchpad
     #[serde(skip_serializing_if = "Option::is_none")]
-    algorithm_of_thoughts: Option<bool>,
+    pub chain_of_thoughts: Option<bool>,
+    // Runs `self_consistency` with the default settings; see `SelfConsistencyConfig::from_schema`
     #[serde(skip_serializing_if = "Option::is_none")]
-    tree_of_thoughts: Option<bool>,
+    pub algorithm_of_thoughts: Option<bool>,
+    // Runs `tree_of_thoughts::search` with the default settings; see `TreeOfThoughtsConfig::from_schema`
     #[serde(skip_serializing_if = "Option::is_none")]
-    tool_choice: Option<String>,
+    pub tree_of_thoughts: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    execute_tool: Option<bool>,
+    pub tool_choice: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    rules: Option<String>,
+    pub execute_tool: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    planning: Option<bool>,
+    pub rules: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    planning_prompt: Option<String>,
+    pub planning: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    device: Option<String>,
+    pub planning_prompt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    custom_planning_prompt: Option<String>,
+    pub device: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_planning_prompt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0))]
-    memory_chunk_size: Option<i32>,
+    pub memory_chunk_size: Option<i32>,
+    #[cfg(feature = "memory")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reranker: Option<RerankerConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    agent_ops_on: Option<bool>,
+    pub agent_ops_on: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    log_directory: Option<String>,
+    pub log_directory: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    project_path: Option<String>,
+    pub project_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    tool_system_prompt: Option<String>,
+    pub tool_system_prompt: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0.0, max = 1.0))]
-    top_p: Option<f64>,
+    pub top_p: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    top_k: Option<i32>,
+    pub top_k: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0.0, max = 1.0))]
-    frequency_penalty: Option<f64>,
+    pub frequency_penalty: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0.0, max = 1.0))]
-    presence_penalty: Option<f64>,
+    pub presence_penalty: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     #[validate(range(min = 0.0, max = 1.0))]
-    temperature: Option<f64>,
+    pub temperature: Option<f64>,
+}
+
+impl Default for AgentSchema {
+    // The Python AgentSchema defaults. Fields Python requires default to empty strings, and
+    // context_window to the default ContextBudget window.
+    fn default() -> Self {
+        AgentSchema {
+            llm: String::new(),
+            max_tokens: 4096,
+            context_window: 8192,
+            user_name: String::new(),
+            agent_name: String::new(),
+            system_prompt: String::new(),
+            template: None,
+            max_loops: Some(1),
+            stopping_condition: None,
+            loop_interval: Some(0),
+            retry_attempts: Some(3),
+            retry_interval: Some(1),
+            return_history: Some(false),
+            stopping_token: None,
+            dynamic_loops: Some(false),
+            interactive: Some(false),
+            dashboard: Some(false),
+            agent_description: None,
+            tools: None,
+            dynamic_temperature_enabled: Some(false),
+            sop: None,
+            sop_list: None,
+            saved_state_path: None,
+            autosave: Some(false),
+            self_healing_enabled: Some(false),
+            code_interpreter: Some(false),
+            multi_modal: Some(false),
+            pdf_path: None,
+            list_of_pdf: None,
+            tokenizer: None,
+            long_term_memory: None,
+            preset_stopping_token: Some(false),
+            stop_patterns: None,
+            traceback: None,
+            traceback_handlers: None,
+            streaming_on: Some(false),
+            docs: None,
+            docs_folder: None,
+            verbose: Some(false),
+            parser: None,
+            best_of_n: None,
+            callback: None,
+            metadata: None,
+            callbacks: None,
+            logger_handler: None,
+            search_algorithm: None,
+            logs_to_filename: None,
+            evaluator: None,
+            output_json: Some(false),
+            stopping_func: None,
+            custom_loop_condition: None,
+            sentiment_threshold: None,
+            custom_exit_command: None,
+            sentiment_analyzer: None,
+            limit_tokens_from_string: None,
+            custom_tools_prompt: None,
+            tool_schema: None,
+            output_type: Some("str".to_string()),
+            function_calling_type: Some("json".to_string()),
+            output_cleaner: None,
+            function_calling_format_type: Some("OpenAI".to_string()),
+            list_base_models: None,
+            metadata_output_type: Some("json".to_string()),
+            state_save_file_type: Some("json".to_string()),
+            chain_of_thoughts: Some(false),
+            algorithm_of_thoughts: Some(false),
+            tree_of_thoughts: Some(false),
+            tool_choice: Some("auto".to_string()),
+            execute_tool: Some(false),
+            rules: None,
+            planning: Some(false),
+            planning_prompt: None,
+            device: Some("cpu".to_string()),
+            custom_planning_prompt: None,
+            memory_chunk_size: Some(2000),
+            #[cfg(feature = "memory")]
+            reranker: None,
+            agent_ops_on: Some(false),
+            log_directory: None,
+            project_path: None,
+            tool_system_prompt: None,
+            top_p: Some(0.9),
+            top_k: None,
+            frequency_penalty: Some(0.0),
+            presence_penalty: Some(0.0),
+            temperature: Some(0.1),
+        }
+    }
 }
 
 fn main() {
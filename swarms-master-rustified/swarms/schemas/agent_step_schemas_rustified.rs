@@ -14,49 +14,73 @@ The conversion of this code to Rust is viable, but with some limitations and cha
 ```rust
 // Viable conversion with some limitations due to the differences between Python and Rust's type systems and libraries.
 
+use crate::swarms::utils::id_generator::{new_id, IdKind};
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-/// Represents a step in a task.
+/// Represents a step in a task. Missing fields get a fresh ID and the current time.
 #[derive(Serialize, Deserialize, Debug)]
-struct Step {
+#[serde(default)]
+pub struct Step {
     /// The ID of the task step.
-    #[serde(default = "generate_uuid_hex")]
-    step_id: String,
+    pub step_id: String,
     /// The time taken to complete the task step.
-    #[serde(default = "get_current_time")]
-    time: String,
+    pub time: String,
     /// The response of the agent chat completion.
-    response: Option<AgentChatCompletionResponse>,
+    pub response: Option<AgentChatCompletionResponse>,
 }
 
 /// Represents a task with multiple steps.
 #[derive(Serialize, Deserialize, Debug)]
-struct ManySteps {
+#[serde(default)]
+pub struct ManySteps {
     /// The ID of the agent.
-    agent_id: String,
+    pub agent_id: String,
     /// The name of the agent.
-    agent_name: String,
+    pub agent_name: String,
     /// The name of the task.
-    task: String,
+    pub task: String,
     /// The number of steps in the task.
-    max_loops: i32,
+    pub max_loops: i32,
     /// The ID of the task this step belongs to.
-    #[serde(default = "generate_uuid_hex")]
-    run_id: String,
+    pub run_id: String,
     /// The steps of the task.
-    steps: Vec<Step>,
+    pub steps: Vec<Step>,
     /// The full history of the task.
-    full_history: String,
+    pub full_history: String,
     /// The total number of tokens generated.
-    total_tokens: i32,
+    pub total_tokens: i32,
     /// The token at which the task stopped.
-    stopping_token: String,
+    pub stopping_token: String,
     /// The interactive status of the task.
-    interactive: bool,
+    pub interactive: bool,
     /// The dynamic temperature status of the task.
-    dynamic_temperature_enabled: bool,
+    pub dynamic_temperature_enabled: bool,
+}
+
+impl Default for Step {
+    fn default() -> Self {
+        Step { step_id: generate_uuid_hex(), time: get_current_time(), response: None }
+    }
+}
+
+impl Default for ManySteps {
+    fn default() -> Self {
+        ManySteps {
+            agent_id: String::new(),
+            agent_name: String::new(),
+            task: String::new(),
+            max_loops: 1,
+            run_id: new_id(IdKind::Run),
+            steps: Vec::new(),
+            full_history: String::new(),
+            total_tokens: 0,
+            stopping_token: String::new(),
+            interactive: false,
+            dynamic_temperature_enabled: false,
+        }
+    }
 }
 
 // Generate a random UUID as a hex string.
@@ -82,7 +106,7 @@ fn main() {
         agent_name: "Agent 1".to_string(),
         task: "Task 1".to_string(),
         max_loops: 10,
-        run_id: generate_uuid_hex(),
+        run_id: new_id(IdKind::Run),
         steps: vec![step],
         full_history: "This is the full history of the task.".to_string(),
         total_tokens: 100,
@@ -15,6 +15,8 @@ use uuid::Uuid;
 use chrono::{Utc, DateTime};
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::collections::HashMap;
+use std::fmt;
+use serde_json::{json, Value as JsonValue};
 
 // Define a struct for the ModelCard
 /// A struct representing a model card, which provides metadata about a machine learning model.
@@ -95,34 +97,125 @@ impl ChatMessageInput {
     }
 }
 
+// Define a struct for the ToolCall
+/// A tool call in a response. `arguments` is the JSON-encoded string the provider sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ToolCall {
+    pub id: String,
+    pub name: String,
+    pub arguments: String,
+}
+
 // Define a struct for the ChatMessageResponse
 /// A struct representing a chat message response.
 #[derive(Debug, Clone)]
 pub struct ChatMessageResponse {
     pub role: String,
     pub content: String,
+    pub tool_calls: Vec<ToolCall>,
 }
 
 impl ChatMessageResponse {
     pub fn new(role: String, content: String) -> Self {
-        Self { role, content }
+        Self { role, content, tool_calls: Vec::new() }
     }
 }
 
+// Define a struct for the ToolCallDelta
+/// A fragment of a streamed tool call. `index` says which call it extends; the other fields are only
+/// present on the fragments that carry them.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ToolCallDelta {
+    pub index: usize,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub arguments: Option<String>,
+}
+
 // Define a struct for the DeltaMessage
 /// A struct representing a delta message.
 #[derive(Debug, Clone)]
 pub struct DeltaMessage {
     pub role: Option<String>,
     pub content: Option<String>,
+    pub tool_calls: Vec<ToolCallDelta>,
 }
 
 impl DeltaMessage {
     pub fn new(role: Option<String>, content: Option<String>) -> Self {
-        Self { role, content }
+        Self { role, content, tool_calls: Vec::new() }
+    }
+}
+
+// Define an enum for the ResponseFormat
+/// The output format requested from the provider. `JsonSchema` enables native constrained
+/// decoding (OpenAI structured outputs) instead of prompting for JSON and retrying.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ResponseFormat {
+    Text,
+    JsonObject,
+    JsonSchema {
+        name: String,
+        schema: JsonValue,
+        strict: bool,
+    },
+}
+
+impl ResponseFormat {
+    pub fn to_openai_json(&self) -> JsonValue {
+        match self {
+            ResponseFormat::Text => json!({ "type": "text" }),
+            ResponseFormat::JsonObject => json!({ "type": "json_object" }),
+            ResponseFormat::JsonSchema { name, schema, strict } => json!({
+                "type": "json_schema",
+                "json_schema": { "name": name, "schema": schema, "strict": strict },
+            }),
+        }
     }
 }
 
+// Define an enum for the ToolChoice
+/// Controls whether and which tool the model must call.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolChoice {
+    None,
+    Auto,
+    Required,
+    /// Force a call to the named function.
+    Function(String),
+}
+
+impl ToolChoice {
+    pub fn to_openai_json(&self) -> JsonValue {
+        match self {
+            ToolChoice::None => json!("none"),
+            ToolChoice::Auto => json!("auto"),
+            ToolChoice::Required => json!("required"),
+            ToolChoice::Function(name) => json!({
+                "type": "function",
+                "function": { "name": name },
+            }),
+        }
+    }
+}
+
+/// A tool choice that the request cannot honor.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ToolChoiceError {
+    /// The forced tool is not among the request's `tools`.
+    NotOffered(String),
+}
+
+impl fmt::Display for ToolChoiceError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ToolChoiceError::NotOffered(name) => write!(f, "cannot force tool '{}': it is not in the request's tools", name),
+        }
+    }
+}
+
+impl std::error::Error for ToolChoiceError {}
+
 // Define a struct for the ChatCompletionRequest
 /// A struct representing a chat completion request.
 #[derive(Debug, Clone)]
@@ -135,6 +228,12 @@ pub struct ChatCompletionRequest {
     pub stream: Option<bool>,
     pub repetition_penalty: Option<f64>,
     pub echo: Option<bool>,
+    /// OpenAI function schemas offered to the model.
+    pub tools: Option<Vec<JsonValue>>,
+    pub tool_choice: Option<ToolChoice>,
+    pub response_format: Option<ResponseFormat>,
+    /// Sequences at which the provider stops generating.
+    pub stop: Option<Vec<String>>,
 }
 
 impl ChatCompletionRequest {
@@ -148,8 +247,72 @@ impl ChatCompletionRequest {
             stream: Some(false),
             repetition_penalty: Some(1.0),
             echo: Some(false),
+            tools: None,
+            tool_choice: None,
+            response_format: None,
+            stop: None,
         }
     }
+
+    pub fn with_tools(mut self, tools: Vec<JsonValue>) -> Self {
+        self.tools = Some(tools);
+        self
+    }
+
+    pub fn with_tool_choice(mut self, tool_choice: ToolChoice) -> Self {
+        self.tool_choice = Some(tool_choice);
+        self
+    }
+
+    pub fn with_response_format(mut self, response_format: ResponseFormat) -> Self {
+        self.response_format = Some(response_format);
+        self
+    }
+
+    pub fn with_stop(mut self, stop: Vec<String>) -> Self {
+        self.stop = Some(stop);
+        self
+    }
+
+    /// Request strict JSON output matching `schema` via native constrained decoding.
+    pub fn with_json_schema(self, name: impl Into<String>, schema: JsonValue) -> Self {
+        self.with_response_format(ResponseFormat::JsonSchema {
+            name: name.into(),
+            schema,
+            strict: true,
+        })
+    }
+
+    /// Force the model to call `name`. Fails if `tools` does not offer a function by that name, since
+    /// providers reject a forced call to a tool they were not given. Set the tools first.
+    pub fn force_tool(self, name: impl Into<String>) -> Result<Self, ToolChoiceError> {
+        let name = name.into();
+        let offered = self
+            .tools
+            .iter()
+            .flatten()
+            .any(|tool| tool["function"]["name"].as_str() == Some(name.as_str()));
+        if !offered {
+            return Err(ToolChoiceError::NotOffered(name));
+        }
+        Ok(self.with_tool_choice(ToolChoice::Function(name)))
+    }
+
+    /// Serialize the provider-facing options (`tools`, `tool_choice`, `response_format`)
+    /// into an OpenAI request body fragment. Unset options are omitted.
+    pub fn provider_options_json(&self) -> JsonValue {
+        let mut body = serde_json::Map::new();
+        if let Some(tools) = &self.tools {
+            body.insert("tools".to_string(), JsonValue::Array(tools.clone()));
+        }
+        if let Some(tool_choice) = &self.tool_choice {
+            body.insert("tool_choice".to_string(), tool_choice.to_openai_json());
+        }
+        if let Some(response_format) = &self.response_format {
+            body.insert("response_format".to_string(), response_format.to_openai_json());
+        }
+        JsonValue::Object(body)
+    }
 }
 
 // Define a struct for the ChatCompletionResponseChoice
@@ -173,11 +336,41 @@ impl ChatCompletionResponseChoice {
 pub struct ChatCompletionResponseStreamChoice {
     pub index: i32,
     pub delta: DeltaMessage,
+    /// Set on the choice's last chunk.
+    pub finish_reason: Option<String>,
 }
 
 impl ChatCompletionResponseStreamChoice {
     pub fn new(index: i32, delta: DeltaMessage) -> Self {
-        Self { index, delta }
+        Self { index, delta, finish_reason: None }
+    }
+
+    /// Read one entry of an OpenAI-style `chat.completion.chunk`'s `choices`. Returns `None` if it has no
+    /// `delta` object.
+    pub fn from_openai_json(choice: &JsonValue) -> Option<Self> {
+        let delta = choice.get("delta").filter(|delta| delta.is_object())?;
+        let text = |value: &JsonValue| value.as_str().map(str::to_string);
+        let tool_calls = delta["tool_calls"]
+            .as_array()
+            .map(|calls| {
+                calls
+                    .iter()
+                    .enumerate()
+                    .map(|(position, call)| ToolCallDelta {
+                        // Some OpenAI-compatible servers leave out `index` when they send each call whole.
+                        index: call["index"].as_u64().map_or(position, |index| index as usize),
+                        id: text(&call["id"]),
+                        name: text(&call["function"]["name"]),
+                        arguments: text(&call["function"]["arguments"]),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        Some(Self {
+            index: choice["index"].as_i64().unwrap_or(0) as i32,
+            delta: DeltaMessage { role: text(&delta["role"]), content: text(&delta["content"]), tool_calls },
+            finish_reason: text(&choice["finish_reason"]),
+        })
     }
 }
 
@@ -188,6 +381,8 @@ pub struct UsageInfo {
     pub prompt_tokens: i32,
     pub total_tokens: i32,
     pub completion_tokens: Option<i32>,
+    /// Counted by a local tokenizer because the provider reported no usage.
+    pub estimated: bool,
 }
 
 impl UsageInfo {
@@ -196,8 +391,41 @@ impl UsageInfo {
             prompt_tokens,
             total_tokens,
             completion_tokens: None,
+            estimated: false,
         }
     }
+
+    /// Usage counted locally, for responses that came without it.
+    pub fn estimated(prompt_tokens: i32, completion_tokens: i32) -> Self {
+        Self {
+            prompt_tokens,
+            total_tokens: prompt_tokens + completion_tokens,
+            completion_tokens: Some(completion_tokens),
+            estimated: true,
+        }
+    }
+
+    /// Read a response's or final chunk's `usage`, falling back to Groq's `x_groq.usage`. Returns `None`
+    /// when neither is present.
+    pub fn from_openai_json(value: &JsonValue) -> Option<Self> {
+        let usage = match &value["usage"] {
+            JsonValue::Object(_) => &value["usage"],
+            _ => match &value["x_groq"]["usage"] {
+                JsonValue::Object(_) => &value["x_groq"]["usage"],
+                _ => return None,
+            },
+        };
+        let prompt_tokens = usage["prompt_tokens"].as_i64()? as i32;
+        let completion_tokens = usage["completion_tokens"].as_i64().map(|n| n as i32);
+        let total_tokens = usage["total_tokens"]
+            .as_i64()
+            .map(|n| n as i32)
+            .unwrap_or(prompt_tokens + completion_tokens.unwrap_or(0));
+
+        let mut info = Self::new(prompt_tokens, total_tokens);
+        info.completion_tokens = completion_tokens;
+        Some(info)
+    }
 }
 
 // Define a struct for the ChatCompletionResponse
@@ -254,6 +482,13 @@ fn main() {
     // Create a new ChatCompletionRequest
     let chat_completion_request = ChatCompletionRequest::new("model-name".to_string(), vec![chat_message_input]);
 
+    // Ask for native structured output instead of prompt-and-retry
+    let structured_request = chat_completion_request.clone().with_json_schema(
+        "person",
+        json!({ "type": "object", "properties": { "name": { "type": "string" } } }),
+    );
+    println!("{}", structured_request.provider_options_json());
+
     // Print the created structs
     println!("{:?}", model_card);
     println!("{:?}", chat_message_input);
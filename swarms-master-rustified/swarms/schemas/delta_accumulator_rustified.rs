@@ -0,0 +1,170 @@
+### Overview
+There is no Python counterpart; the Python schemas define `ChatCompletionResponseStreamChoice` but nothing
+puts the deltas back together. `DeltaAccumulator` consumes a streamed response's choices and rebuilds
+each choice's final `ChatMessageResponse`:
+
+* `role` comes from the first delta that has one, and `content` fragments are concatenated.
+* Tool-call fragments are merged by their `index`. OpenAI sends the id and name once and then the
+  arguments a few characters at a time. Groq sends each call whole in one delta. Both end up as the same
+  `ToolCall`.
+* `finish_reason` and the final chunk's usage (`usage`, or Groq's `x_groq.usage`) are kept for the caller.
+
+`finish` checks that every tool call's arguments are complete JSON, so a stream that broke off in the
+middle of a call is reported instead of producing a call that fails later in the tool.
+
+This module does not start tools early; `tools::tool_call_stream` does that for agents that need it.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::{ChatCompletionResponseStreamChoice, ChatMessageResponse, ToolCall, UsageInfo};
+use serde_json::Value as JsonValue;
+use std::collections::BTreeMap;
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DeltaError {
+    /// A chunk that is not an OpenAI-style `chat.completion.chunk`.
+    InvalidChunk(String),
+    /// A tool call's arguments were not valid JSON when the stream ended.
+    InvalidArguments { choice: i32, index: usize, message: String },
+    /// A tool call ended without a function name.
+    MissingName { choice: i32, index: usize },
+}
+
+impl fmt::Display for DeltaError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DeltaError::InvalidChunk(message) => write!(f, "invalid stream chunk: {}", message),
+            DeltaError::InvalidArguments { choice, index, message } => {
+                write!(f, "tool call {} of choice {} has invalid arguments: {}", index, choice, message)
+            }
+            DeltaError::MissingName { choice, index } => {
+                write!(f, "tool call {} of choice {} has no function name", index, choice)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DeltaError {}
+
+#[derive(Debug, Default)]
+struct ChoiceState {
+    role: Option<String>,
+    content: String,
+    tool_calls: BTreeMap<usize, ToolCall>,
+    finish_reason: Option<String>,
+}
+
+/// Rebuilds the messages of a streamed chat completion from its deltas.
+#[derive(Debug, Default)]
+pub struct DeltaAccumulator {
+    choices: BTreeMap<i32, ChoiceState>,
+    usage: Option<UsageInfo>,
+}
+
+impl DeltaAccumulator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, choice: &ChatCompletionResponseStreamChoice) {
+        let state = self.choices.entry(choice.index).or_default();
+        if state.role.is_none() {
+            state.role = choice.delta.role.clone();
+        }
+        if let Some(content) = &choice.delta.content {
+            state.content.push_str(content);
+        }
+        for fragment in &choice.delta.tool_calls {
+            let call = state.tool_calls.entry(fragment.index).or_insert_with(|| ToolCall {
+                id: String::new(),
+                name: String::new(),
+                arguments: String::new(),
+            });
+            if let Some(id) = fragment.id.as_deref().filter(|id| !id.is_empty()) {
+                call.id = id.to_string();
+            }
+            // Names arrive whole. Providers that repeat the name on later fragments repeat the same one.
+            if let Some(name) = fragment.name.as_deref().filter(|name| !name.is_empty()) {
+                call.name = name.to_string();
+            }
+            if let Some(arguments) = &fragment.arguments {
+                call.arguments.push_str(arguments);
+            }
+        }
+        if choice.finish_reason.is_some() {
+            state.finish_reason = choice.finish_reason.clone();
+        }
+    }
+
+    /// Push every choice of one parsed `chat.completion.chunk`, and its usage if it carries any.
+    pub fn push_chunk(&mut self, chunk: &JsonValue) -> Result<(), DeltaError> {
+        let choices = chunk["choices"]
+            .as_array()
+            .ok_or_else(|| DeltaError::InvalidChunk("no `choices` array".to_string()))?;
+        for choice in choices {
+            let choice = ChatCompletionResponseStreamChoice::from_openai_json(choice)
+                .ok_or_else(|| DeltaError::InvalidChunk("a choice without a `delta`".to_string()))?;
+            self.push(&choice);
+        }
+        if let Some(usage) = UsageInfo::from_openai_json(chunk) {
+            self.usage = Some(usage);
+        }
+        Ok(())
+    }
+
+    pub fn finish_reason(&self, choice: i32) -> Option<&str> {
+        self.choices.get(&choice)?.finish_reason.as_deref()
+    }
+
+    pub fn usage(&self) -> Option<&UsageInfo> {
+        self.usage.as_ref()
+    }
+
+    /// The final message of every choice, in choice order.
+    pub fn finish(self) -> Result<Vec<ChatMessageResponse>, DeltaError> {
+        self.choices
+            .into_iter()
+            .map(|(choice, state)| {
+                let mut tool_calls = Vec::with_capacity(state.tool_calls.len());
+                for (index, mut call) in state.tool_calls {
+                    if call.name.is_empty() {
+                        return Err(DeltaError::MissingName { choice, index });
+                    }
+                    // A call to a tool without parameters may stream no argument text at all.
+                    if call.arguments.trim().is_empty() {
+                        call.arguments = "{}".to_string();
+                    }
+                    serde_json::from_str::<JsonValue>(&call.arguments)
+                        .map_err(|err| DeltaError::InvalidArguments { choice, index, message: err.to_string() })?;
+                    tool_calls.push(call);
+                }
+                let role = state.role.unwrap_or_else(|| "assistant".to_string());
+                Ok(ChatMessageResponse { role, content: state.content, tool_calls })
+            })
+            .collect()
+    }
+}
+
+fn main() -> Result<(), DeltaError> {
+    let chunks = [
+        r#"{"choices":[{"index":0,"delta":{"role":"assistant","tool_calls":[{"index":0,"id":"call_1","type":"function","function":{"name":"get_weather","arguments":""}}]}}]}"#,
+        r#"{"choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"{\"city\": "}}]}}]}"#,
+        r#"{"choices":[{"index":0,"delta":{"tool_calls":[{"index":0,"function":{"arguments":"\"Paris\"}"}}]}}]}"#,
+        r#"{"choices":[{"index":0,"delta":{},"finish_reason":"tool_calls"}]}"#,
+    ];
+    let mut accumulator = DeltaAccumulator::new();
+    for chunk in chunks {
+        accumulator.push_chunk(&serde_json::from_str(chunk).expect("valid JSON"))?;
+    }
+    println!("finish_reason: {:?}", accumulator.finish_reason(0));
+    println!("{:?}", accumulator.finish()?);
+    Ok(())
+}
+```
+
+### Notes
+* A choice that never sent a role is an assistant message, which is the only role a completion streams.
+* Tool calls are keyed by `index`, not by `id`, because only the first fragment of a call carries its id.
+* Chunks must be parsed JSON. `integrations::sse::SseParser` turns a raw `text/event-stream` body into
+  `data` strings, and the `data: [DONE]` sentinel is the caller's to skip.
@@ -0,0 +1,223 @@
+### Overview
+There is no Python counterpart; the Python swarms construct a fresh `Agent` (LLM client, memory handles,
+prompts) for every task. `AgentPool` keeps initialized instances warm instead. Each agent definition is
+registered with a factory and a concurrency cap; `checkout` hands out an idle instance (or builds one if
+the cap allows), and dropping the returned `PooledAgent` resets its per-task state and puts it back.
+
+### Rust Code
+```rust
+use log::{debug, info};
+use std::collections::HashMap;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// An agent that can be reused across tasks.
+pub trait Poolable: Send + 'static {
+    /// Clear per-task state (short-term memory, step counters, scratch files) before the next checkout.
+    fn reset(&mut self);
+}
+
+type Factory<A> = Arc<dyn Fn() -> A + Send + Sync>;
+
+#[derive(Debug, PartialEq)]
+pub enum PoolError {
+    UnknownAgent(String),
+    Timeout(String),
+    Closed(String),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PoolError::UnknownAgent(name) => write!(f, "no agent definition named '{}'", name),
+            PoolError::Timeout(name) => write!(f, "timed out waiting for an idle '{}' agent", name),
+            PoolError::Closed(name) => write!(f, "the pool for '{}' is closed", name),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+struct Slot<A> {
+    factory: Factory<A>,
+    idle: Mutex<Vec<A>>,
+    permits: Arc<Semaphore>,
+    max_instances: usize,
+}
+
+/// Occupancy of one definition's pool.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PoolStats {
+    pub max_instances: usize,
+    pub checked_out: usize,
+    pub idle: usize,
+}
+
+/// Warm agent instances, grouped by definition name.
+pub struct AgentPool<A: Poolable> {
+    slots: HashMap<String, Arc<Slot<A>>>,
+}
+
+impl<A: Poolable> Default for AgentPool<A> {
+    fn default() -> Self {
+        AgentPool { slots: HashMap::new() }
+    }
+}
+
+impl<A: Poolable> AgentPool<A> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a definition. At most `max_instances` of it are ever checked out at once.
+    pub fn define(mut self, name: impl Into<String>, max_instances: usize, factory: impl Fn() -> A + Send + Sync + 'static) -> Self {
+        let max_instances = max_instances.max(1);
+        self.slots.insert(
+            name.into(),
+            Arc::new(Slot {
+                factory: Arc::new(factory),
+                idle: Mutex::new(Vec::new()),
+                permits: Arc::new(Semaphore::new(max_instances)),
+                max_instances,
+            }),
+        );
+        self
+    }
+
+    /// Build `count` instances of `name` up front so the first tasks do not pay construction cost.
+    pub fn warm(&self, name: &str, count: usize) -> Result<(), PoolError> {
+        let slot = self.slot(name)?;
+        let mut idle = slot.idle.lock().unwrap();
+        while idle.len() < count.min(slot.max_instances) {
+            idle.push((slot.factory)());
+        }
+        info!("Warmed {} instance(s) of {}", idle.len(), name);
+        Ok(())
+    }
+
+    fn slot(&self, name: &str) -> Result<&Arc<Slot<A>>, PoolError> {
+        self.slots.get(name).ok_or_else(|| PoolError::UnknownAgent(name.to_string()))
+    }
+
+    /// Check out an instance of `name`, waiting while all `max_instances` are in use.
+    pub async fn checkout(&self, name: &str) -> Result<PooledAgent<A>, PoolError> {
+        let slot = self.slot(name)?;
+        let permit = Arc::clone(&slot.permits)
+            .acquire_owned()
+            .await
+            .map_err(|_| PoolError::Closed(name.to_string()))?;
+        Ok(self.take(name, slot, permit))
+    }
+
+    /// Like `checkout`, but gives up after `timeout`.
+    pub async fn checkout_timeout(&self, name: &str, timeout: Duration) -> Result<PooledAgent<A>, PoolError> {
+        tokio::time::timeout(timeout, self.checkout(name))
+            .await
+            .map_err(|_| PoolError::Timeout(name.to_string()))?
+    }
+
+    /// Check out an instance only if one is available right now.
+    pub fn try_checkout(&self, name: &str) -> Result<Option<PooledAgent<A>>, PoolError> {
+        let slot = self.slot(name)?;
+        match Arc::clone(&slot.permits).try_acquire_owned() {
+            Ok(permit) => Ok(Some(self.take(name, slot, permit))),
+            Err(_) => Ok(None),
+        }
+    }
+
+    fn take(&self, name: &str, slot: &Arc<Slot<A>>, permit: OwnedSemaphorePermit) -> PooledAgent<A> {
+        let agent = match slot.idle.lock().unwrap().pop() {
+            Some(agent) => agent,
+            None => {
+                debug!("Building a new {} instance", name);
+                (slot.factory)()
+            }
+        };
+        PooledAgent { agent: Some(agent), slot: Arc::clone(slot), _permit: permit }
+    }
+
+    pub fn stats(&self, name: &str) -> Result<PoolStats, PoolError> {
+        let slot = self.slot(name)?;
+        Ok(PoolStats {
+            max_instances: slot.max_instances,
+            checked_out: slot.max_instances - slot.permits.available_permits(),
+            idle: slot.idle.lock().unwrap().len(),
+        })
+    }
+}
+
+/// A checked-out agent. Derefs to the agent; returned to the pool (after `reset`) on drop.
+pub struct PooledAgent<A: Poolable> {
+    agent: Option<A>,
+    slot: Arc<Slot<A>>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl<A: Poolable> PooledAgent<A> {
+    /// Drop this instance instead of returning it, e.g. after it ended up in a bad state.
+    /// The next checkout builds a fresh one.
+    pub fn discard(mut self) {
+        self.agent = None;
+    }
+}
+
+impl<A: Poolable> Deref for PooledAgent<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        self.agent.as_ref().expect("agent is present until drop")
+    }
+}
+
+impl<A: Poolable> DerefMut for PooledAgent<A> {
+    fn deref_mut(&mut self) -> &mut A {
+        self.agent.as_mut().expect("agent is present until drop")
+    }
+}
+
+impl<A: Poolable> Drop for PooledAgent<A> {
+    fn drop(&mut self) {
+        // The instance goes back before the permit is released (fields drop after this body),
+        // so a waiter woken by the permit finds it idle.
+        if let Some(mut agent) = self.agent.take() {
+            agent.reset();
+            self.slot.idle.lock().unwrap().push(agent);
+        }
+    }
+}
+
+struct DemoAgent {
+    name: String,
+    short_term_memory: Vec<String>,
+}
+
+impl Poolable for DemoAgent {
+    fn reset(&mut self) {
+        self.short_term_memory.clear();
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), PoolError> {
+    let pool = AgentPool::new().define("Financial-Analyst", 2, || DemoAgent {
+        name: "Financial-Analyst".to_string(),
+        short_term_memory: Vec::new(),
+    });
+    pool.warm("Financial-Analyst", 2)?;
+    {
+        let mut agent = pool.checkout("Financial-Analyst").await?;
+        agent.short_term_memory.push("Q3 revenue task".to_string());
+        println!("{} is running", agent.name);
+    }
+    println!("{:?}", pool.stats("Financial-Analyst")?);
+    Ok(())
+}
+```
+
+### Notes
+* The cap is per definition; two definitions never wait on each other.
+* `reset` runs on the dropping thread, so keep it cheap. Anything expensive belongs in the factory.
+* A panic while an agent is checked out still returns it to the pool through `Drop`. Call `discard` if its state cannot be trusted.
@@ -0,0 +1,331 @@
+### Overview
+There is no Python counterpart; Python agents are defined in code or in the `agents:` list of the YAML
+config, where a system prompt of several pages becomes one long block scalar. An agent profile is a
+Markdown file instead: YAML front-matter between `---` lines for the settings, and the body as the system
+prompt, written as ordinary Markdown.
+
+```markdown
+---
+name: FraudDetection
+description: Flags signs of fraud
+model: gpt-4o
+tools: [ledger_lookup]
+tags: [accounting, fraud]
+---
+You are a forensic accountant. Read the summary you are given and ...
+```
+
+Only `name` is needed in the front-matter, and it defaults to the file name without `.md`. `model` falls
+back to the registry's default model, and `tags` become the agent's capabilities. Unknown keys are rejected,
+so a misspelled `modle:` fails to load instead of silently using the default model.
+
+`AgentProfileRegistry::load_dir` loads every `.md` file of a directory, `agents/` by convention, in file
+name order. `agent` and `agents` build `LlmAgent`s from the profiles; bind a completion to them as usual.
+`AccountingSwarm::from_profiles` takes the accountant prompts from a registry like this, so they can be
+edited without touching the crate.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::{AgentConfigError, LlmAgent};
+#[cfg(feature = "tools")]
+use crate::swarms::tools::base_tool::ToolRegistry;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Where profiles are kept by convention, relative to the project root.
+pub const DEFAULT_PROFILE_DIR: &str = "agents";
+
+#[derive(Debug)]
+pub enum ProfileError {
+    Io { path: PathBuf, source: io::Error },
+    /// No front-matter, or front-matter that is not valid YAML for a profile. `path` is `None` for text
+    /// given to `AgentProfile::parse`.
+    Parse { path: Option<PathBuf>, message: String },
+    /// The body is blank, so the agent would have no system prompt.
+    EmptyPrompt(String),
+    /// Two files define an agent with the same name.
+    Duplicate { name: String, first: PathBuf, second: PathBuf },
+    NotFound(String),
+    /// The profile lists a tool the tool registry does not have.
+    UnknownTool { agent: String, tool: String },
+    Agent { name: String, error: AgentConfigError },
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProfileError::Io { path, source } => write!(f, "failed to read {}: {}", path.display(), source),
+            ProfileError::Parse { path: Some(path), message } => {
+                write!(f, "agent profile {} does not parse: {}", path.display(), message)
+            }
+            ProfileError::Parse { path: None, message } => write!(f, "agent profile does not parse: {}", message),
+            ProfileError::EmptyPrompt(name) => write!(f, "agent profile '{}' has no system prompt", name),
+            ProfileError::Duplicate { name, first, second } => {
+                write!(f, "agent '{}' is defined in both {} and {}", name, first.display(), second.display())
+            }
+            ProfileError::NotFound(name) => write!(f, "no agent profile named '{}'", name),
+            ProfileError::UnknownTool { agent, tool } => write!(f, "agent '{}' lists unknown tool '{}'", agent, tool),
+            ProfileError::Agent { name, error } => write!(f, "agent profile '{}' is not a valid agent: {}", name, error),
+        }
+    }
+}
+
+impl std::error::Error for ProfileError {}
+
+// The front-matter keys; anything else is a mistake worth reporting
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct FrontMatter {
+    name: Option<String>,
+    description: String,
+    model: Option<String>,
+    tools: Vec<String>,
+    tags: Vec<String>,
+    max_loops: Option<i32>,
+}
+
+/// One agent, as defined by a Markdown file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct AgentProfile {
+    pub name: String,
+    pub description: String,
+    /// `None` uses the default model of whoever builds the agent.
+    pub model: Option<String>,
+    /// Names of the tools the agent may call; see `tool_registry`.
+    pub tools: Vec<String>,
+    pub tags: Vec<String>,
+    pub max_loops: Option<i32>,
+    pub system_prompt: String,
+    /// The file it was loaded from.
+    pub path: Option<PathBuf>,
+}
+
+impl AgentProfile {
+    /// Parse a profile from Markdown text. The front-matter must name the agent.
+    pub fn parse(text: &str) -> Result<Self, ProfileError> {
+        Self::parse_at(text, None)
+    }
+
+    /// Load the profile in `path`. An agent without a `name` is named after the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ProfileError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ProfileError::Io { path: path.to_path_buf(), source })?;
+        Self::parse_at(&text, Some(path))
+    }
+
+    fn parse_at(text: &str, path: Option<&Path>) -> Result<Self, ProfileError> {
+        let parse_error = |message: String| ProfileError::Parse { path: path.map(Path::to_path_buf), message };
+        let (front_matter, body) = split_front_matter(text)
+            .ok_or_else(|| parse_error("expected YAML front-matter between '---' lines at the top".to_string()))?;
+        let front_matter: FrontMatter = if front_matter.trim().is_empty() {
+            FrontMatter::default()
+        } else {
+            serde_yaml::from_str(front_matter).map_err(|err| parse_error(err.to_string()))?
+        };
+        let file_stem = path.and_then(Path::file_stem).map(|stem| stem.to_string_lossy().into_owned());
+        let name = front_matter.name.or(file_stem).ok_or_else(|| parse_error("the front-matter has no name".to_string()))?;
+        let system_prompt = body.trim().to_string();
+        if system_prompt.is_empty() {
+            return Err(ProfileError::EmptyPrompt(name));
+        }
+        Ok(AgentProfile {
+            name,
+            description: front_matter.description,
+            model: front_matter.model,
+            tools: front_matter.tools,
+            tags: front_matter.tags,
+            max_loops: front_matter.max_loops,
+            system_prompt,
+            path: path.map(Path::to_path_buf),
+        })
+    }
+
+    /// Build the agent, on the profile's model or else `default_model`. Tags become capabilities.
+    pub fn to_agent(&self, default_model: &str) -> Result<LlmAgent, ProfileError> {
+        let mut builder = LlmAgent::builder()
+            .name(self.name.as_str())
+            .llm(self.model.as_deref().unwrap_or(default_model))
+            .description(self.description.as_str())
+            .system_prompt(self.system_prompt.as_str());
+        if let Some(max_loops) = self.max_loops {
+            builder = builder.max_loops(max_loops);
+        }
+        for tag in &self.tags {
+            builder = builder.capability(tag.as_str());
+        }
+        builder.build().map_err(|error| ProfileError::Agent { name: self.name.clone(), error })
+    }
+
+    /// The tools of `tools` that the profile lists, in `tools`' order. Fails on the first listed tool that
+    /// `tools` does not have.
+    #[cfg(feature = "tools")]
+    pub fn tool_registry(&self, tools: &ToolRegistry) -> Result<ToolRegistry, ProfileError> {
+        if let Some(missing) = self.tools.iter().find(|tool| tools.get(tool).is_none()) {
+            return Err(ProfileError::UnknownTool { agent: self.name.clone(), tool: missing.clone() });
+        }
+        Ok(tools.restricted(|name| self.tools.iter().any(|tool| tool == name)))
+    }
+
+    /// The profile as Markdown that `parse` reads back. Empty settings are left out.
+    pub fn to_markdown(&self) -> String {
+        let mut front_matter = serde_yaml::Mapping::new();
+        let mut set = |key: &str, value: serde_yaml::Value| {
+            front_matter.insert(key.into(), value);
+        };
+        set("name", self.name.as_str().into());
+        if !self.description.is_empty() {
+            set("description", self.description.as_str().into());
+        }
+        if let Some(model) = &self.model {
+            set("model", model.as_str().into());
+        }
+        if !self.tools.is_empty() {
+            set("tools", self.tools.clone().into());
+        }
+        if !self.tags.is_empty() {
+            set("tags", self.tags.clone().into());
+        }
+        if let Some(max_loops) = self.max_loops {
+            set("max_loops", max_loops.into());
+        }
+        let yaml = serde_yaml::to_string(&front_matter).unwrap_or_default();
+        format!("---\n{}---\n\n{}\n", yaml.trim_start_matches("---\n"), self.system_prompt)
+    }
+}
+
+// The text between an opening `---` line and the next `---` (or `...`) line, and everything after it
+fn split_front_matter(text: &str) -> Option<(&str, &str)> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let rest = text.strip_prefix("---")?;
+    let rest = rest.strip_prefix("\r\n").or_else(|| rest.strip_prefix('\n'))?;
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if matches!(line.trim_end(), "---" | "...") {
+            return Some((&rest[..offset], &rest[offset + line.len()..]));
+        }
+        offset += line.len();
+    }
+    None
+}
+
+/// Agent profiles by name.
+#[derive(Debug, Clone)]
+pub struct AgentProfileRegistry {
+    default_model: String,
+    profiles: BTreeMap<String, AgentProfile>,
+}
+
+impl AgentProfileRegistry {
+    /// An empty registry whose agents use `default_model` unless their profile names one.
+    pub fn new(default_model: impl Into<String>) -> Self {
+        AgentProfileRegistry { default_model: default_model.into(), profiles: BTreeMap::new() }
+    }
+
+    pub fn default_model(&self) -> &str {
+        &self.default_model
+    }
+
+    /// Add `profile`, returning the profile it replaced.
+    pub fn register(&mut self, profile: AgentProfile) -> Option<AgentProfile> {
+        self.profiles.insert(profile.name.clone(), profile)
+    }
+
+    /// Register the profile in `path`. A name already loaded from another file is an error, not a replacement.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<&AgentProfile, ProfileError> {
+        let profile = AgentProfile::load(path)?;
+        if let Some(existing) = self.profiles.get(&profile.name) {
+            if let (Some(first), Some(second)) = (&existing.path, &profile.path) {
+                if first != second {
+                    return Err(ProfileError::Duplicate { name: profile.name, first: first.clone(), second: second.clone() });
+                }
+            }
+        }
+        let name = profile.name.clone();
+        self.register(profile);
+        Ok(&self.profiles[&name])
+    }
+
+    /// Register every `.md` file in `dir` except `README.md`, in file name order. Returns how many.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<usize, ProfileError> {
+        let dir = dir.as_ref();
+        let io_error = |source| ProfileError::Io { path: dir.to_path_buf(), source };
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            let is_profile = path.extension().is_some_and(|ext| ext == "md")
+                && !path.file_stem().is_some_and(|stem| stem.eq_ignore_ascii_case("readme"));
+            if is_profile && path.is_file() {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        for path in &paths {
+            self.load_file(path)?;
+        }
+        info!("Loaded {} agent profiles from {}", paths.len(), dir.display());
+        Ok(paths.len())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&AgentProfile> {
+        self.profiles.get(name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.profiles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.profiles.is_empty()
+    }
+
+    /// Profiles in name order.
+    pub fn profiles(&self) -> impl Iterator<Item = &AgentProfile> {
+        self.profiles.values()
+    }
+
+    /// Profiles with `tag`, in name order.
+    pub fn tagged<'a>(&'a self, tag: &'a str) -> impl Iterator<Item = &'a AgentProfile> {
+        self.profiles.values().filter(move |profile| profile.tags.iter().any(|t| t == tag))
+    }
+
+    /// Build the agent named `name`.
+    pub fn agent(&self, name: &str) -> Result<LlmAgent, ProfileError> {
+        let profile = self.get(name).ok_or_else(|| ProfileError::NotFound(name.to_string()))?;
+        profile.to_agent(&self.default_model)
+    }
+
+    /// Build every agent, in name order.
+    pub fn agents(&self) -> Result<Vec<LlmAgent>, ProfileError> {
+        self.profiles.values().map(|profile| profile.to_agent(&self.default_model)).collect()
+    }
+}
+
+fn main() {
+    let mut registry = AgentProfileRegistry::new("gpt-4o-mini");
+    match registry.load_dir(DEFAULT_PROFILE_DIR) {
+        Ok(count) => println!("{} profiles", count),
+        Err(e) => println!("{}", e),
+    }
+    let profile = AgentProfile::parse("---\nname: Reviewer\ntags: [review]\n---\nYou review pull requests.\n").unwrap();
+    registry.register(profile);
+    for profile in registry.tagged("review") {
+        println!("{}", profile.to_markdown());
+    }
+    println!("{:?}", registry.agent("Reviewer").map(|agent| agent.name.clone()));
+}
+```
+
+### Notes
+* The body is the system prompt exactly as written, trimmed of surrounding blank lines. Markdown is not
+  rendered; models read it as is.
+* Profiles hold what an author edits, not every `AgentSpec` setting. Budgets, retries, and reasoning
+  configs keep their builder defaults; agents that need them are still built in code or from the YAML
+  config.
+* Tools are listed by name. `tool_registry` picks them out of the registry of tools the program built,
+  since a Markdown file cannot hold the tool bodies.
+* Registries are loaded once. A profile edited while the program runs takes effect on the next `load_dir`.
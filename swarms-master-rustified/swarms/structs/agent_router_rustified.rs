@@ -5,7 +5,7 @@ The provided Python code utilizes various libraries and frameworks that may not
 **Reasoning:**
 * The `chromadb` library is not available in Rust, but we can use other Rust crates like `weaviate-rs` or `faiss-rs` for vector database operations.
 * The `tenacity` library can be replaced with `retry-rs` for retry mechanisms.
-* The `swarms` library does not have a direct equivalent in Rust, so we'll need to create our own agent struct and logic.
+* The `swarms` agent maps to the crate's `Agent` trait; the router stores members as `Arc<dyn Agent>`.
 * The `loguru_logger` can be replaced with `log` or `logger` crates in Rust.
 
 ### Rust Conversion
@@ -18,71 +18,13 @@ use weaviate_rs::weaviate::Weaviate;
 use log::{info, warn, error};
 use retry::{retry, ExponentialBackoff};
 use serde_json::json;
-
-// Define the Agent struct
-#[derive(Debug)]
-pub struct Agent {
-    pub name: String,
-    pub description: String,
-    pub system_prompt: String,
-    pub llm: String,
-    pub max_loops: i32,
-    pub autosave: bool,
-    pub verbose: bool,
-    pub dynamic_temperature_enabled: bool,
-    pub saved_state_path: String,
-    pub user_name: String,
-    pub retry_attempts: i32,
-    pub context_length: i32,
-    pub output_type: String,
-}
-
-impl Agent {
-    // Define a method to create a new Agent instance
-    pub fn new(
-        name: String,
-        description: String,
-        system_prompt: String,
-        llm: String,
-        max_loops: i32,
-        autosave: bool,
-        verbose: bool,
-        dynamic_temperature_enabled: bool,
-        saved_state_path: String,
-        user_name: String,
-        retry_attempts: i32,
-        context_length: i32,
-        output_type: String,
-    ) -> Self {
-        Agent {
-            name,
-            description,
-            system_prompt,
-            llm,
-            max_loops,
-            autosave,
-            verbose,
-            dynamic_temperature_enabled,
-            saved_state_path,
-            user_name,
-            retry_attempts,
-            context_length,
-            output_type,
-        }
-    }
-
-    // Define a method to run the agent
-    pub fn run(&self, task: String) -> String {
-        // Implement the agent's run logic here
-        format!("Running agent {} with task {}", self.name, task)
-    }
-}
+use crate::swarms::structs::agent::{Agent, LlmAgent};
+use crate::swarms::text::context_budget::ContextBudget;
 
 // Define the AgentRouter struct
-#[derive(Debug)]
 pub struct AgentRouter {
     client: Client,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
     n_agents: i32,
     persist_directory: String,
 }
@@ -96,7 +38,7 @@ impl AgentRouter {
         client_config: ClientConfig,
     ) -> Self {
         let client = Client::new(client_config);
-        let agents: Vec<Agent> = vec![];
+        let agents: Vec<Arc<dyn Agent>> = vec![];
         AgentRouter {
             client,
             agents,
@@ -105,57 +47,44 @@ impl AgentRouter {
         }
     }
 
-    // Define a method to add an agent to the vector database
-    pub fn add_agent(&mut self, agent: Agent) -> Result<(), String> {
-        // Implement the add agent logic here
+    // Define a method to add an agent to the vector database. Model-backed agents are indexed with their
+    // configuration; any other agent is indexed by name and capabilities only.
+    pub fn add_agent(&mut self, agent: Arc<dyn Agent>) -> Result<(), String> {
+        let mut document = json!({
+            "name": agent.name(),
+            "capabilities": agent.capabilities(),
+        });
+        if let Some(spec) = agent.spec() {
+            document["description"] = json!(spec.description);
+            document["systemPrompt"] = json!(spec.system_prompt);
+            document["llm"] = json!(spec.llm);
+            document["maxLoops"] = json!(spec.max_loops);
+            document["contextLength"] = json!(spec.budget.max_context_tokens);
+            document["reservedOutputTokens"] = json!(spec.budget.reserved_output_tokens);
+            document["outputType"] = json!(spec.output_type);
+        }
         retry(ExponentialBackoff::default(), || {
-            self.client
-                .create_document(
-                    &agent.name,
-                    json!({
-                        "description": agent.description,
-                        "systemPrompt": agent.system_prompt,
-                        "llm": agent.llm,
-                        "maxLoops": agent.max_loops,
-                        "autosave": agent.autosave,
-                        "verbose": agent.verbose,
-                        "dynamicTemperatureEnabled": agent.dynamic_temperature_enabled,
-                        "savedStatePath": agent.saved_state_path,
-                        "userName": agent.user_name,
-                        "retryAttempts": agent.retry_attempts,
-                        "contextLength": agent.context_length,
-                        "outputType": agent.output_type,
-                    }),
-                )
-                .map_err(|e| e.to_string())
+            self.client.create_document(agent.name(), document.clone()).map_err(|e| e.to_string())
         })
         .map(|_| {
-            self.agents.push(agent);
-            info!("Added agent {} to the vector database.", agent.name);
+            info!("Added agent {} to the vector database.", agent.name());
+            self.agents.push(agent.clone());
         })
         .map_err(|e| {
-            error!("Error adding agent {} to the vector database: {}", agent.name, e);
+            error!("Error adding agent {} to the vector database: {}", agent.name(), e);
             e
         })
     }
 
     // Define a method to find the best agent for a given task
-    pub fn find_best_agent(&self, task: String) -> Option<Agent> {
+    pub fn find_best_agent(&self, task: String) -> Option<Arc<dyn Agent>> {
         // Implement the find best agent logic here
         let results = self.client
             .query(&task, self.n_agents)
             .map_err(|e| error!("Error finding best agent: {}", e))
             .ok()?;
-        results
-            .into_iter()
-            .next()
-            .map(|result| {
-                let agent_name = result.doc_id;
-                self.agents
-                    .iter()
-                    .find(|agent| agent.name == agent_name)
-                    .cloned()
-            })
+        let agent_name = results.into_iter().next()?.doc_id;
+        self.agents.iter().find(|agent| agent.name() == agent_name).cloned()
     }
 }
 
@@ -164,7 +93,7 @@ fn main() {
     env_logger::init();
 
     // Create a new AgentRouter instance
-    let agent_router = AgentRouter::new(
+    let mut agent_router = AgentRouter::new(
         "agents".to_string(),
         "./vector_db".to_string(),
         1,
@@ -172,7 +101,7 @@ fn main() {
     );
 
     // Create some agents
-    let data_extractor_agent = Agent::new(
+    let data_extractor_agent = LlmAgent::new(
         "Data-Extractor".to_string(),
         "Data extractor agent".to_string(),
         "Data extraction prompt".to_string(),
@@ -184,11 +113,11 @@ fn main() {
         "data_extractor_agent.json".to_string(),
         "pe_firm".to_string(),
         1,
-        200000,
+        ContextBudget::new(200_000, 8_192),
         "string".to_string(),
     );
 
-    let summarizer_agent = Agent::new(
+    let summarizer_agent = LlmAgent::new(
         "Document-Summarizer".to_string(),
         "Document summarizer agent".to_string(),
         "Document summarization prompt".to_string(),
@@ -200,23 +129,25 @@ fn main() {
         "summarizer_agent.json".to_string(),
         "pe_firm".to_string(),
         1,
-        200000,
+        ContextBudget::new(200_000, 8_192),
         "string".to_string(),
     );
 
     // Add the agents to the vector database
-    agent_router.add_agent(data_extractor_agent).unwrap();
-    agent_router.add_agent(summarizer_agent).unwrap();
+    agent_router.add_agent(data_extractor_agent.into()).unwrap();
+    agent_router.add_agent(summarizer_agent.into()).unwrap();
 
     // Find the best agent for a given task
     let task = "Analyze the financial statements of a potential acquisition target and identify key growth drivers.".to_string();
-    let best_agent = agent_router.find_best_agent(task);
+    let best_agent = agent_router.find_best_agent(task.clone());
 
     if let Some(best_agent) = best_agent {
-        info!("Best agent for the task: {}", best_agent.name);
+        info!("Best agent for the task: {}", best_agent.name());
         // Use the best agent to perform the task
-        let result = best_agent.run(task);
-        info!("Task result: {}", result);
+        match best_agent.run(&task) {
+            Ok(result) => info!("Task result: {}", result),
+            Err(err) => error!("{}", err),
+        }
     } else {
         info!("No suitable agent found for the task.");
     }
@@ -224,7 +155,7 @@ fn main() {
 ```
 ### Limitations and Challenges
 * The `chromadb` library does not have a direct equivalent in Rust, so we used `weaviate-rs` instead.
-* The `swarms` library does not have a direct equivalent in Rust, so we had to create our own agent struct and logic.
+* Agents are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`), so `MockAgent`s and wrapped swarms can be routed to as well. Only model-backed agents have a prompt and model to index; the others are found by name and capabilities.
 * The `loguru_logger` was replaced with `log` crate in Rust.
 * The retry mechanism was replaced with `retry-rs` crate in Rust.
 
@@ -233,7 +164,7 @@ The provided Rust code is compatible with the Rust ecosystem. However, to ensure
 
 ### Advice for Conversion
 * Start by replacing the Python libraries with their Rust equivalents.
-* Create a new struct for the agent and implement the necessary methods.
+* Implement the crate's `Agent` trait for any new kind of agent instead of defining a local struct.
 * Use the `weaviate-rs` crate for vector database operations.
 * Use the `retry-rs` crate for retry mechanisms.
 * Use the `log` crate for logging.
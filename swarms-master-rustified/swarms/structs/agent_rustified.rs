@@ -0,0 +1,953 @@
+### Overview
+The Rust counterpart of `swarms/structs/agent.py`. The conversions each declared their own placeholder
+`Agent` struct or trait, so an agent built for one swarm could not join another. `Agent` is now the one
+trait every swarm takes, as `Arc<dyn Agent>`:
+
+* `name` and `capabilities` identify the agent. Capabilities are free-form tags (`"sql"`, `"vision"`)
+  that routers and planners can match on.
+* `run` answers a task. `run_with_history` answers with earlier messages as context; by default it puts
+  the visible messages ahead of the task and calls `run`.
+
+Three types implement it. `LlmAgent` is the model-backed agent. `MockAgent` replies from a script, for
+tests and demos. `SwarmAgent` (in `swarm`) runs a whole swarm as one member of another. A swarm that needs
+an agent's prompt or model reads them through `spec()`, which only model-backed agents return, and an
+`LlmAgent` converts with `.into()`.
+
+`LlmAgent` is the type previously named `Agent`, which was first defined inside `agent_router`. Its fields
+follow the Python constructor arguments, with `context_length` replaced by a `ContextBudget`. It calls its
+model through a `Completion` bound with `AgentBuilder::completion` or `with_completion`. An agent without
+one fails `run` with `AgentError::NoModel`, so a config-built agent is inert until it is bound.
+
+An `LlmAgent` is a handle. Its configuration, an `AgentSpec`, sits behind an `Arc` together with the
+completion, so `clone()` bumps reference counts instead of copying the system prompt, and one agent can
+be handed to every task of a fan-out by value. The spec's fields read through the handle (`agent.llm`).
+Writing to one (`agent.llm = ...`) copies the spec first if other handles share it, so an edit never
+shows up in another task's copy of the agent.
+
+`LlmAgent::builder()` is the preferred way to construct one. The name and model are required and enforced by
+the type system: `build()` only exists once both are set. Everything else defaults to the Python
+constructor's defaults, and `build()` checks the combination before returning the agent.
+
+`with_callbacks` registers `RunCallbacks` that hear about each step as it finishes, from `run` and from
+every loop of `run_loops`. The completion reads them back with `callbacks()` to pass on to a streaming
+provider, which reports tokens and tool calls (see `callbacks`).
+
+`save_state` writes the agent's configuration to `saved_state_path`, encrypted when given a `StateCipher`.
+`load_state` reads it back and decrypts transparently, so it also loads state saved without encryption.
+
+`run_loops` runs up to `max_loops` steps. With `loop_detection` set, it watches the steps for repeated
+answers and reacts as the config's escalation says: raise the temperature, nudge the model in the next
+prompt, or stop early. With `convergence` set, it stops as soon as a step's output matches the previous
+one by embedding similarity, using the `EmbedFn` bound with `with_embedder` (see `convergence`). The
+run's `exit` says why it stopped.
+
+With `planning` set, `plan_and_execute` runs the task in plan-and-execute mode instead: the model writes a
+plan of typed steps, the steps run one at a time, and a failed step is re-planned (see `plan_execute`). `search_thoughts` runs a tree-of-thoughts search over the task
+with the `tree_of_thoughts` settings (see `tree_of_thoughts`). `self_consistency` samples several reasoning
+paths and returns the most common answer with a confidence, using the `algorithm_of_thoughts` settings
+(see `self_consistency`).
+
+With `sentiment_gate` set, `run_with_sentiment_gate` scores each reply's tone and regenerates replies that
+fall below the threshold, for customer-facing agents (see `sentiment`).
+
+### Rust Code
+```rust
+use crate::swarms::structs::callbacks::{RunCallbacks, StepComplete};
+use crate::swarms::structs::conversation::Message;
+use crate::swarms::structs::convergence::{Convergence, ConvergenceConfig, ConvergenceDetector, EmbedFn};
+use crate::swarms::structs::loop_detection::{LoopAction, LoopDetection, LoopDetectionConfig, LoopDetector};
+use crate::swarms::structs::plan_execute::{self, PlanRun, PlanningConfig, StepExecutor};
+use crate::swarms::structs::self_consistency::{self, SampleRequest, SelfConsistencyConfig, SelfConsistencyRun};
+use crate::swarms::structs::tree_of_thoughts::{self, SearchTree, ThoughtContext, TreeOfThoughtsConfig};
+use crate::swarms::text::context_budget::ContextBudget;
+use crate::swarms::text::sentiment::{self, SentimentError, SentimentGateConfig, SentimentRun};
+use crate::swarms::utils::encryption::StateCipher;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Everything a swarm needs from an agent, whatever answers behind it: a model, a script, or a swarm.
+pub trait Agent: Send + Sync {
+    fn name(&self) -> &str;
+
+    /// Tags describing what the agent is good at. None by default.
+    fn capabilities(&self) -> &[String] {
+        &[]
+    }
+
+    /// The configuration of a model-backed agent, for exporting it. `None` for agents without one.
+    fn spec(&self) -> Option<&AgentSpec> {
+        None
+    }
+
+    fn run(&self, task: &str) -> Result<String, AgentError>;
+
+    /// Answer `task` with `history` as context. Deleted and private messages are left out.
+    fn run_with_history(&self, task: &str, history: &[Message]) -> Result<String, AgentError> {
+        self.run(&with_history(task, history))
+    }
+}
+
+// The visible messages as `role: content` lines, then the task
+fn with_history(task: &str, history: &[Message]) -> String {
+    let mut prompt = String::new();
+    for message in history.iter().filter(|message| message.channel.is_public() && !message.is_tombstone()) {
+        prompt.push_str(&format!("{}: {}\n", message.role, message.content));
+    }
+    if prompt.is_empty() {
+        return task.to_string();
+    }
+    format!("{}\n{}", prompt, task)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgentError {
+    /// The agent has no model to call. Bind one with `AgentBuilder::completion`.
+    NoModel(String),
+    /// The model, the script, or the wrapped swarm failed.
+    Failed { agent: String, message: String },
+}
+
+impl fmt::Display for AgentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AgentError::NoModel(agent) => write!(f, "agent '{}' has no model bound", agent),
+            AgentError::Failed { agent, message } => write!(f, "agent '{}' failed: {}", agent, message),
+        }
+    }
+}
+
+impl std::error::Error for AgentError {}
+
+/// The model call behind an `LlmAgent`: takes the agent, for its prompt and settings, and the task.
+pub type Completion = Arc<dyn Fn(&LlmAgent, &str) -> Result<String, String> + Send + Sync>;
+
+// Keeps `Debug` derivable on the agent and its builder
+#[derive(Clone)]
+struct BoundCompletion(Completion);
+
+impl fmt::Debug for BoundCompletion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("Completion")
+    }
+}
+
+#[derive(Clone)]
+struct BoundEmbedder(EmbedFn);
+
+impl fmt::Debug for BoundEmbedder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("EmbedFn")
+    }
+}
+
+#[derive(Clone)]
+struct BoundCallbacks(Arc<dyn RunCallbacks>);
+
+impl fmt::Debug for BoundCallbacks {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("RunCallbacks")
+    }
+}
+
+/// The configuration of an `LlmAgent`: everything `save_state` writes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgentSpec {
+    pub name: String,
+    pub description: String,
+    pub system_prompt: String,
+    pub llm: String,
+    pub max_loops: i32,
+    pub autosave: bool,
+    pub verbose: bool,
+    pub dynamic_temperature_enabled: bool,
+    pub saved_state_path: String,
+    pub user_name: String,
+    pub retry_attempts: i32,
+    pub budget: ContextBudget,
+    pub output_type: String,
+    /// Off unless set; agents saved before the field existed load with it off.
+    #[serde(default)]
+    pub loop_detection: Option<LoopDetectionConfig>,
+    /// Plan-and-execute settings, from the Python `planning` and `planning_prompt`.
+    #[serde(default)]
+    pub planning: Option<PlanningConfig>,
+    /// Search settings, from the Python `tree_of_thoughts` flag.
+    #[serde(default)]
+    pub tree_of_thoughts: Option<TreeOfThoughtsConfig>,
+    /// Self-consistency settings, from the Python `algorithm_of_thoughts` flag.
+    #[serde(default)]
+    pub algorithm_of_thoughts: Option<SelfConsistencyConfig>,
+    /// Tone check, from the Python `sentiment_threshold` and `sentiment_analyzer`.
+    #[serde(default)]
+    pub sentiment_gate: Option<SentimentGateConfig>,
+    /// Early exit from `run_loops` once outputs stop changing. Off unless set.
+    #[serde(default)]
+    pub convergence: Option<ConvergenceConfig>,
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    /// Replace `system_prompt` with one generated for the swarm's task before the agent runs; see
+    /// `prompt_synthesizer`. From the Python `auto_generate_prompt`.
+    #[serde(default)]
+    pub auto_generate_prompt: bool,
+}
+
+/// A model-backed agent. Cheap to clone; see the module overview.
+#[derive(Debug, Clone)]
+pub struct LlmAgent {
+    spec: Arc<AgentSpec>,
+    // Not saved; bind them again after `load_state`
+    completion: Option<BoundCompletion>,
+    callbacks: Option<BoundCallbacks>,
+    embedder: Option<BoundEmbedder>,
+}
+
+impl Deref for LlmAgent {
+    type Target = AgentSpec;
+
+    fn deref(&self) -> &AgentSpec {
+        &self.spec
+    }
+}
+
+// Copy-on-write: handles that share the spec keep the old values
+impl DerefMut for LlmAgent {
+    fn deref_mut(&mut self) -> &mut AgentSpec {
+        Arc::make_mut(&mut self.spec)
+    }
+}
+
+impl From<AgentSpec> for LlmAgent {
+    fn from(spec: AgentSpec) -> Self {
+        LlmAgent { spec: Arc::new(spec), completion: None, callbacks: None, embedder: None }
+    }
+}
+
+impl Serialize for LlmAgent {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.spec.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for LlmAgent {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        AgentSpec::deserialize(deserializer).map(LlmAgent::from)
+    }
+}
+
+/// What `run_loops` hands the step function for each loop.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LoopStep {
+    /// Zero-based loop number.
+    pub index: usize,
+    /// The task, followed by the repetition nudge when one is due.
+    pub prompt: String,
+    pub temperature: f64,
+}
+
+/// Why `run_loops` stopped.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopExit {
+    /// Every loop ran.
+    #[default]
+    Completed,
+    /// An output matched the previous one; see `LoopRun::convergence`.
+    Converged,
+    /// Loop detection escalated to `LoopAction::Terminate`.
+    LoopDetected,
+}
+
+impl LoopExit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LoopExit::Completed => "completed",
+            LoopExit::Converged => "converged",
+            LoopExit::LoopDetected => "loop_detected",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoopRun {
+    /// One output per loop, except exact repeats, which are dropped.
+    pub outputs: Vec<String>,
+    pub detections: Vec<LoopDetection>,
+    pub terminated_early: bool,
+    pub exit: LoopExit,
+    /// The converged output, when `exit` is `Converged`.
+    pub convergence: Option<Convergence>,
+}
+
+impl LlmAgent {
+    pub fn new(
+        name: String,
+        description: String,
+        system_prompt: String,
+        llm: String,
+        max_loops: i32,
+        autosave: bool,
+        verbose: bool,
+        dynamic_temperature_enabled: bool,
+        saved_state_path: String,
+        user_name: String,
+        retry_attempts: i32,
+        budget: ContextBudget,
+        output_type: String,
+    ) -> Self {
+        LlmAgent::from(AgentSpec {
+            name,
+            description,
+            system_prompt,
+            llm,
+            max_loops,
+            autosave,
+            verbose,
+            dynamic_temperature_enabled,
+            saved_state_path,
+            user_name,
+            retry_attempts,
+            budget,
+            output_type,
+            loop_detection: None,
+            planning: None,
+            tree_of_thoughts: None,
+            algorithm_of_thoughts: None,
+            sentiment_gate: None,
+            convergence: None,
+            capabilities: Vec::new(),
+            auto_generate_prompt: false,
+        })
+    }
+
+    pub fn builder() -> AgentBuilder {
+        AgentBuilder::new()
+    }
+
+    /// Bind the model call `run` uses, e.g. after `load_state` or building from a config file.
+    pub fn with_completion(mut self, completion: impl Fn(&LlmAgent, &str) -> Result<String, String> + Send + Sync + 'static) -> Self {
+        self.completion = Some(BoundCompletion(Arc::new(completion)));
+        self
+    }
+
+    pub fn has_completion(&self) -> bool {
+        self.completion.is_some()
+    }
+
+    /// Bind the embedding call `run_loops` compares outputs with when `convergence` is set.
+    pub fn with_embedder(mut self, embed: impl Fn(&str) -> Result<Vec<f32>, String> + Send + Sync + 'static) -> Self {
+        self.embedder = Some(BoundEmbedder(Arc::new(embed)));
+        self
+    }
+
+    pub fn embedder(&self) -> Option<&EmbedFn> {
+        self.embedder.as_ref().map(|bound| &bound.0)
+    }
+
+    /// Report this agent's steps to `callbacks` as they finish. See `callbacks`.
+    pub fn with_callbacks(mut self, callbacks: Arc<dyn RunCallbacks>) -> Self {
+        self.callbacks = Some(BoundCallbacks(callbacks));
+        self
+    }
+
+    /// The registered callbacks, for a completion to hand to its provider so tokens and tool calls reach them.
+    pub fn callbacks(&self) -> Option<&Arc<dyn RunCallbacks>> {
+        self.callbacks.as_ref().map(|bound| &bound.0)
+    }
+
+    fn step_complete(&self, index: usize, output: &str, started: Instant) {
+        if let Some(callbacks) = self.callbacks() {
+            callbacks.on_step_complete(&self.name, &StepComplete { index, output, elapsed: started.elapsed() });
+        }
+    }
+
+    /// True when both handles point at the same spec, i.e. neither has been edited since one was cloned from the other.
+    pub fn shares_spec(&self, other: &LlmAgent) -> bool {
+        Arc::ptr_eq(&self.spec, &other.spec)
+    }
+
+    /// Run up to `max_loops` steps of `task`, calling `step` for each one's output.
+    pub fn run_loops(&self, task: &str, mut step: impl FnMut(&LoopStep) -> String) -> LoopRun {
+        let config = self.loop_detection.clone();
+        let mut detector = config.clone().map(LoopDetector::new);
+        let mut convergence = self.convergence.clone().map(|config| ConvergenceDetector::new(config, self.embedder().cloned()));
+        let mut temperature = config.as_ref().map_or(0.5, |config| config.base_temperature);
+        let mut nudge = false;
+        let mut run = LoopRun::default();
+        for index in 0..self.max_loops.max(1) as usize {
+            let prompt = match (&config, nudge) {
+                (Some(config), true) => format!("{}\n\n{}", task, config.nudge),
+                _ => task.to_string(),
+            };
+            nudge = false;
+            let started = Instant::now();
+            let output = step(&LoopStep { index, prompt, temperature });
+            self.step_complete(index, &output, started);
+            if let Some(converged) = convergence.as_mut().and_then(|convergence| convergence.observe(&output)) {
+                info!(
+                    "{} converged on loop {} ({:.0}% similar to the previous output)",
+                    self.name,
+                    index + 1,
+                    converged.similarity * 100.0
+                );
+                if !converged.exact {
+                    run.outputs.push(output);
+                }
+                run.terminated_early = index + 1 < self.max_loops as usize;
+                run.exit = LoopExit::Converged;
+                run.convergence = Some(converged);
+                break;
+            }
+            let Some((detector, config)) = detector.as_mut().zip(config.as_ref()) else {
+                run.outputs.push(output);
+                continue;
+            };
+            let Some(detection) = detector.observe(&output) else {
+                run.outputs.push(output);
+                continue;
+            };
+            warn!(
+                "{} repeated itself on loop {} ({:.0}% similar to {} loop(s) back); {:?}",
+                self.name,
+                index + 1,
+                detection.similarity * 100.0,
+                detection.repeats_turns_back,
+                detection.action
+            );
+            if !detection.exact {
+                run.outputs.push(output);
+            }
+            let action = detection.action;
+            run.detections.push(detection);
+            match action {
+                LoopAction::RaiseTemperature => {
+                    temperature = (temperature + config.temperature_step).min(config.max_temperature);
+                }
+                LoopAction::Nudge => nudge = true,
+                LoopAction::Terminate => {
+                    run.terminated_early = index + 1 < self.max_loops as usize;
+                    run.exit = LoopExit::LoopDetected;
+                    break;
+                }
+            }
+        }
+        run
+    }
+
+    /// Plan `task`, then execute the plan step by step. Uses the default planning settings when `planning` is unset.
+    pub fn plan_and_execute(
+        &self,
+        task: &str,
+        planner: impl FnMut(&str) -> Result<String, String>,
+        executor: &mut impl StepExecutor,
+    ) -> PlanRun {
+        let config = self.planning.clone().unwrap_or_default();
+        plan_execute::plan_and_execute(&self.name, task, &config, planner, executor)
+    }
+
+    /// Search for the best line of reasoning on `task`. Uses the default search settings when `tree_of_thoughts` is unset.
+    pub fn search_thoughts(
+        &self,
+        task: &str,
+        generate: impl FnMut(&ThoughtContext) -> Vec<String>,
+        evaluate: impl FnMut(&ThoughtContext, &str) -> f32,
+    ) -> SearchTree {
+        let config = self.tree_of_thoughts.clone().unwrap_or_default();
+        tree_of_thoughts::search(task, &config, generate, evaluate)
+    }
+
+    /// Answer `task` by self-consistency. Uses the default settings when `algorithm_of_thoughts` is unset.
+    pub fn self_consistency<F>(&self, task: &str, sample: F) -> SelfConsistencyRun
+    where
+        F: Fn(&SampleRequest) -> Result<String, String> + Sync,
+    {
+        let config = self.algorithm_of_thoughts.clone().unwrap_or_default();
+        self_consistency::self_consistency(task, &config, sample)
+    }
+
+    /// Reply to `task`, regenerating replies whose tone scores below the threshold. Uses the default gate
+    /// when `sentiment_gate` is unset.
+    pub fn run_with_sentiment_gate(
+        &self,
+        task: &str,
+        generate: impl FnMut(&str) -> Result<String, String>,
+    ) -> Result<SentimentRun, String> {
+        let config = self.sentiment_gate.clone().unwrap_or_default();
+        let analyzer = sentiment::analyzer_by_name(&config.analyzer).map_err(|e| e.to_string())?;
+        sentiment::sentiment_gate(task, &config, analyzer.as_ref(), generate)
+    }
+
+    /// Write the agent's configuration to `saved_state_path`.
+    pub fn save_state(&self, cipher: Option<&StateCipher>) -> io::Result<()> {
+        let json = serde_json::to_vec_pretty(&*self.spec)?;
+        match cipher {
+            Some(cipher) => Ok(cipher.write_file(&self.saved_state_path, &json)?),
+            None => fs::write(&self.saved_state_path, json),
+        }
+    }
+
+    /// Read an agent saved by `save_state`. Encrypted state needs a cipher holding its key; plaintext loads either way.
+    pub fn load_state(path: &str, cipher: Option<&StateCipher>) -> io::Result<LlmAgent> {
+        let data = match cipher {
+            Some(cipher) => cipher.read_file(path)?,
+            None if StateCipher::is_encrypted(&fs::read(path)?) => {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, format!("{} is encrypted; a cipher is required", path)));
+            }
+            None => fs::read(path)?,
+        };
+        Ok(serde_json::from_slice::<AgentSpec>(&data)?.into())
+    }
+}
+
+impl From<LlmAgent> for Arc<dyn Agent> {
+    fn from(agent: LlmAgent) -> Self {
+        Arc::new(agent)
+    }
+}
+
+impl Agent for LlmAgent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn capabilities(&self) -> &[String] {
+        &self.capabilities
+    }
+
+    fn spec(&self) -> Option<&AgentSpec> {
+        Some(&self.spec)
+    }
+
+    fn run(&self, task: &str) -> Result<String, AgentError> {
+        let completion = self.completion.as_ref().ok_or_else(|| AgentError::NoModel(self.name.clone()))?;
+        let started = Instant::now();
+        let output = (completion.0)(self, task).map_err(|message| AgentError::Failed { agent: self.name.clone(), message })?;
+        self.step_complete(0, &output, started);
+        Ok(output)
+    }
+}
+
+/// An agent that answers from a script: each call takes the next scripted reply, and the last one repeats.
+/// With no script it echoes the task. Every task it receives is kept for inspection.
+#[derive(Debug, Default)]
+pub struct MockAgent {
+    name: String,
+    capabilities: Vec<String>,
+    replies: Vec<Result<String, String>>,
+    calls: Mutex<Vec<String>>,
+}
+
+impl MockAgent {
+    pub fn new(name: impl Into<String>) -> Self {
+        MockAgent { name: name.into(), ..Default::default() }
+    }
+
+    pub fn reply(mut self, reply: impl Into<String>) -> Self {
+        self.replies.push(Ok(reply.into()));
+        self
+    }
+
+    /// Script a failure for this call.
+    pub fn fail(mut self, message: impl Into<String>) -> Self {
+        self.replies.push(Err(message.into()));
+        self
+    }
+
+    pub fn capability(mut self, capability: impl Into<String>) -> Self {
+        self.capabilities.push(capability.into());
+        self
+    }
+
+    /// The tasks received so far, in order.
+    pub fn calls(&self) -> Vec<String> {
+        self.calls.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+}
+
+impl Agent for MockAgent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn capabilities(&self) -> &[String] {
+        &self.capabilities
+    }
+
+    fn run(&self, task: &str) -> Result<String, AgentError> {
+        let index = {
+            let mut calls = self.calls.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            calls.push(task.to_string());
+            calls.len() - 1
+        };
+        match self.replies.get(index).or(self.replies.last()) {
+            None => Ok(task.to_string()),
+            Some(Ok(reply)) => Ok(reply.clone()),
+            Some(Err(message)) => Err(AgentError::Failed { agent: self.name.clone(), message: message.clone() }),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AgentConfigError {
+    EmptyName,
+    EmptyModel,
+    /// `max_loops` must be at least 1.
+    InvalidMaxLoops(i32),
+    InvalidRetryAttempts(i32),
+    /// The budget reserves the whole window for output.
+    NoInputBudget,
+    Sentiment(SentimentError),
+    /// `convergence.similarity_threshold` must be above 0 and at most 1.
+    InvalidConvergenceThreshold(f32),
+}
+
+impl fmt::Display for AgentConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AgentConfigError::EmptyName => write!(f, "agent name must not be empty"),
+            AgentConfigError::EmptyModel => write!(f, "agent model must not be empty"),
+            AgentConfigError::InvalidMaxLoops(loops) => write!(f, "max_loops must be at least 1, got {}", loops),
+            AgentConfigError::InvalidRetryAttempts(attempts) => {
+                write!(f, "retry_attempts must not be negative, got {}", attempts)
+            }
+            AgentConfigError::NoInputBudget => write!(f, "context budget leaves no room for input"),
+            AgentConfigError::Sentiment(e) => write!(f, "sentiment gate: {}", e),
+            AgentConfigError::InvalidConvergenceThreshold(threshold) => {
+                write!(f, "convergence similarity_threshold must be in (0, 1], got {}", threshold)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AgentConfigError {}
+
+impl From<SentimentError> for AgentConfigError {
+    fn from(e: SentimentError) -> Self {
+        AgentConfigError::Sentiment(e)
+    }
+}
+
+/// Typestate marker for a required builder field that has not been set yet.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Unset;
+
+/// The optional settings, kept apart so the typestate transitions only move the required fields.
+#[derive(Debug, Clone)]
+struct AgentOptions {
+    description: String,
+    system_prompt: String,
+    max_loops: i32,
+    autosave: bool,
+    verbose: bool,
+    dynamic_temperature_enabled: bool,
+    saved_state_path: Option<String>,
+    user_name: String,
+    retry_attempts: i32,
+    budget: ContextBudget,
+    output_type: String,
+    loop_detection: Option<LoopDetectionConfig>,
+    planning: Option<PlanningConfig>,
+    tree_of_thoughts: Option<TreeOfThoughtsConfig>,
+    algorithm_of_thoughts: Option<SelfConsistencyConfig>,
+    sentiment_gate: Option<SentimentGateConfig>,
+    convergence: Option<ConvergenceConfig>,
+    capabilities: Vec<String>,
+    auto_generate_prompt: bool,
+    completion: Option<BoundCompletion>,
+    callbacks: Option<BoundCallbacks>,
+    embedder: Option<BoundEmbedder>,
+}
+
+/// Builds an `LlmAgent`. `N` and `M` record whether the name and model have been set.
+#[derive(Debug, Clone)]
+pub struct AgentBuilder<N = Unset, M = Unset> {
+    name: N,
+    llm: M,
+    options: AgentOptions,
+}
+
+impl AgentBuilder {
+    /// A builder with the Python `Agent` defaults.
+    pub fn new() -> Self {
+        AgentBuilder {
+            name: Unset,
+            llm: Unset,
+            options: AgentOptions {
+                description: String::new(),
+                system_prompt: String::new(),
+                max_loops: 1,
+                autosave: false,
+                verbose: false,
+                dynamic_temperature_enabled: false,
+                saved_state_path: None,
+                user_name: "Human:".to_string(),
+                retry_attempts: 3,
+                budget: ContextBudget::default(),
+                output_type: "str".to_string(),
+                loop_detection: None,
+                planning: None,
+                tree_of_thoughts: None,
+                algorithm_of_thoughts: None,
+                sentiment_gate: None,
+                convergence: None,
+                capabilities: Vec::new(),
+                auto_generate_prompt: false,
+                completion: None,
+                callbacks: None,
+                embedder: None,
+            },
+        }
+    }
+}
+
+impl Default for AgentBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> AgentBuilder<Unset, M> {
+    pub fn name(self, name: impl Into<String>) -> AgentBuilder<String, M> {
+        AgentBuilder { name: name.into(), llm: self.llm, options: self.options }
+    }
+}
+
+impl<N> AgentBuilder<N, Unset> {
+    /// The model name, e.g. `gpt-4o-mini`.
+    pub fn llm(self, llm: impl Into<String>) -> AgentBuilder<N, String> {
+        AgentBuilder { name: self.name, llm: llm.into(), options: self.options }
+    }
+}
+
+impl<N, M> AgentBuilder<N, M> {
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.options.description = description.into();
+        self
+    }
+
+    pub fn system_prompt(mut self, system_prompt: impl Into<String>) -> Self {
+        self.options.system_prompt = system_prompt.into();
+        self
+    }
+
+    pub fn max_loops(mut self, max_loops: i32) -> Self {
+        self.options.max_loops = max_loops;
+        self
+    }
+
+    pub fn autosave(mut self, autosave: bool) -> Self {
+        self.options.autosave = autosave;
+        self
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.options.verbose = verbose;
+        self
+    }
+
+    pub fn dynamic_temperature_enabled(mut self, enabled: bool) -> Self {
+        self.options.dynamic_temperature_enabled = enabled;
+        self
+    }
+
+    /// Defaults to `<name>_state.json`.
+    pub fn saved_state_path(mut self, path: impl Into<String>) -> Self {
+        self.options.saved_state_path = Some(path.into());
+        self
+    }
+
+    pub fn user_name(mut self, user_name: impl Into<String>) -> Self {
+        self.options.user_name = user_name.into();
+        self
+    }
+
+    pub fn retry_attempts(mut self, retry_attempts: i32) -> Self {
+        self.options.retry_attempts = retry_attempts;
+        self
+    }
+
+    pub fn budget(mut self, budget: ContextBudget) -> Self {
+        self.options.budget = budget;
+        self
+    }
+
+    pub fn output_type(mut self, output_type: impl Into<String>) -> Self {
+        self.options.output_type = output_type.into();
+        self
+    }
+
+    /// Watch loops for repeated answers; see `run_loops`.
+    pub fn loop_detection(mut self, config: LoopDetectionConfig) -> Self {
+        self.options.loop_detection = Some(config);
+        self
+    }
+
+    /// Enable plan-and-execute mode; see `plan_and_execute`.
+    pub fn planning(mut self, config: PlanningConfig) -> Self {
+        self.options.planning = Some(config);
+        self
+    }
+
+    /// Settings for `search_thoughts`.
+    pub fn tree_of_thoughts(mut self, config: TreeOfThoughtsConfig) -> Self {
+        self.options.tree_of_thoughts = Some(config);
+        self
+    }
+
+    /// Settings for `self_consistency`.
+    pub fn algorithm_of_thoughts(mut self, config: SelfConsistencyConfig) -> Self {
+        self.options.algorithm_of_thoughts = Some(config);
+        self
+    }
+
+    /// Settings for `run_with_sentiment_gate`; checked by `build()`.
+    pub fn sentiment_gate(mut self, config: SentimentGateConfig) -> Self {
+        self.options.sentiment_gate = Some(config);
+        self
+    }
+
+    /// Stop `run_loops` once outputs stop changing; checked by `build()`. Bind an `embedder` to compare
+    /// outputs by meaning rather than text.
+    pub fn convergence(mut self, config: ConvergenceConfig) -> Self {
+        self.options.convergence = Some(config);
+        self
+    }
+
+    pub fn capability(mut self, capability: impl Into<String>) -> Self {
+        self.options.capabilities.push(capability.into());
+        self
+    }
+
+    /// Have `PromptSynthesizer::apply` generate the system prompt for the swarm's task. The prompt set with
+    /// `system_prompt` is kept until then, and whenever generation fails.
+    pub fn auto_generate_prompt(mut self, enabled: bool) -> Self {
+        self.options.auto_generate_prompt = enabled;
+        self
+    }
+
+    /// The model call behind `run`; see `Completion`.
+    pub fn completion(mut self, completion: impl Fn(&LlmAgent, &str) -> Result<String, String> + Send + Sync + 'static) -> Self {
+        self.options.completion = Some(BoundCompletion(Arc::new(completion)));
+        self
+    }
+
+    /// Live notifications for the agent's steps; see `LlmAgent::with_callbacks`.
+    pub fn callbacks(mut self, callbacks: Arc<dyn RunCallbacks>) -> Self {
+        self.options.callbacks = Some(BoundCallbacks(callbacks));
+        self
+    }
+
+    /// The embedding call behind `convergence`; see `LlmAgent::with_embedder`.
+    pub fn embedder(mut self, embed: impl Fn(&str) -> Result<Vec<f32>, String> + Send + Sync + 'static) -> Self {
+        self.options.embedder = Some(BoundEmbedder(Arc::new(embed)));
+        self
+    }
+}
+
+impl AgentBuilder<String, String> {
+    pub fn build(self) -> Result<LlmAgent, AgentConfigError> {
+        let AgentBuilder { name, llm, options } = self;
+        if name.trim().is_empty() {
+            return Err(AgentConfigError::EmptyName);
+        }
+        if llm.trim().is_empty() {
+            return Err(AgentConfigError::EmptyModel);
+        }
+        if options.max_loops < 1 {
+            return Err(AgentConfigError::InvalidMaxLoops(options.max_loops));
+        }
+        if options.retry_attempts < 0 {
+            return Err(AgentConfigError::InvalidRetryAttempts(options.retry_attempts));
+        }
+        if options.budget.available_input() == 0 {
+            return Err(AgentConfigError::NoInputBudget);
+        }
+        if let Some(gate) = &options.sentiment_gate {
+            gate.validate()?;
+        }
+        if let Some(convergence) = options.convergence.as_ref().filter(|convergence| !convergence.is_valid()) {
+            return Err(AgentConfigError::InvalidConvergenceThreshold(convergence.similarity_threshold));
+        }
+        let saved_state_path = options.saved_state_path.unwrap_or_else(|| format!("{}_state.json", name));
+        let spec = AgentSpec {
+            name,
+            description: options.description,
+            system_prompt: options.system_prompt,
+            llm,
+            max_loops: options.max_loops,
+            autosave: options.autosave,
+            verbose: options.verbose,
+            dynamic_temperature_enabled: options.dynamic_temperature_enabled,
+            saved_state_path,
+            user_name: options.user_name,
+            retry_attempts: options.retry_attempts,
+            budget: options.budget,
+            output_type: options.output_type,
+            loop_detection: options.loop_detection,
+            planning: options.planning,
+            tree_of_thoughts: options.tree_of_thoughts,
+            algorithm_of_thoughts: options.algorithm_of_thoughts,
+            sentiment_gate: options.sentiment_gate,
+            convergence: options.convergence,
+            capabilities: options.capabilities,
+            auto_generate_prompt: options.auto_generate_prompt,
+        };
+        Ok(LlmAgent {
+            spec: Arc::new(spec),
+            completion: options.completion,
+            callbacks: options.callbacks,
+            embedder: options.embedder,
+        })
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let analyst: Arc<dyn Agent> = Arc::new(
+        LlmAgent::builder()
+            .name("Financial-Analysis-Agent")
+            .llm("gpt-4o-mini")
+            .description("Analyzes financial statements")
+            .system_prompt("You are a financial analyst.")
+            .budget(ContextBudget::new(128_000, 4_096))
+            .capability("finance")
+            .completion(|agent, task| Ok(format!("[{}] {}", agent.llm, task)))
+            .build()?,
+    );
+    let reviewer: Arc<dyn Agent> = Arc::new(MockAgent::new("Reviewer").reply("Looks right."));
+    for agent in [&analyst, &reviewer] {
+        println!("{} {:?}: {}", agent.name(), agent.capabilities(), agent.run("How can I establish a ROTH IRA?")?);
+    }
+
+    // One handle per worker; the spec and completion are shared, not copied
+    let writer = LlmAgent::builder()
+        .name("Writer")
+        .llm("gpt-4o-mini")
+        .system_prompt("You write short, plain summaries.")
+        .completion(|agent, task| Ok(format!("[{}] {}", agent.name, task)))
+        .build()?;
+    let workers: Vec<_> = ["Q1", "Q2", "Q3"]
+        .into_iter()
+        .map(|quarter| {
+            let writer = writer.clone();
+            std::thread::spawn(move || writer.run(&format!("Summarize {}", quarter)))
+        })
+        .collect();
+    for worker in workers {
+        println!("{}", worker.join().expect("worker panicked")?);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* Calling `build()` before `.name(..)` and `.llm(..)` is a compile error, not a runtime one.
+* `LlmAgent::new` remains for callers that set every field; new code should use the builder.
+* `run_loops` takes the step function from the caller because it needs a new temperature per step, which the `Completion` signature does not carry; pass a closure that calls the provider with the step's prompt and temperature.
+* A `Completion` is synchronous, like the trait. `chat_provider::provider_completion` binds any `ChatProvider` (`OpenAiCompatProvider`, `LlamaCppProvider`) by blocking on the shared `utils::blocking` runtime, so such an agent also runs inside async workflows such as `SequentialWorkflow::run_with_approvals`.
+* `LlmAgent` keeps `Debug` and serde; the bound completion, callbacks, and embedder show as `Completion`, `RunCallbacks`, and `EmbedFn` and are not saved.
+* Writing through the handle copies the whole spec once when it is shared, then edits it in place. Build agents with their final settings where possible, and edit before handing out clones.
@@ -0,0 +1,302 @@
+### Overview
+There is no Python counterpart. The Python server keeps agents in a dict keyed by id, so they are gone
+when it restarts. `AgentStore` is the tenant-scoped agent store behind the `/agent` and
+`/users/me/agents` endpoints. Each agent is one JSON file in the tenant's `agents` directory (see
+`tenancy`). The file holds the `AgentSpec` and the times the agent was created and last updated.
+
+`list` returns one page of a tenant's agents. It filters by tag (one of the agent's `capabilities`) and by
+model, and sorts by `name`, `created_at` or `updated_at`. See `pagination` for the cursor rules.
+`handle_http` serves it as `GET /users/me/agents?tag=&model=&sort=&limit=&cursor=`.
+
+`save_as` and `delete_as` do the same for the tenant of an `Auditor` and record the change in the audit log.
+
+With a `StateCipher`, agent files are encrypted at rest like `LlmAgent::save_state` output. Files that
+were saved without encryption still load.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+use crate::swarms::structs::agent::{AgentSpec, LlmAgent};
+use crate::swarms::structs::audit::{AuditAction, Auditor};
+use crate::swarms::structs::pagination::{paginate, parse_sort, Page, PageError, PageRequest};
+use crate::swarms::structs::tenancy::{TenantId, TenantWorkspace};
+use crate::swarms::utils::encryption::{EncryptionError, StateCipher};
+use crate::swarms::utils::safe_path::sanitize_file_name;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+pub const MAX_AGENT_NAME_LEN: usize = 128;
+
+#[derive(Debug)]
+pub enum AgentStoreError {
+    /// Empty, too long, starts with `.`, or not a portable file name (see `sanitize_file_name`).
+    InvalidName(String),
+    /// An agent file that does not parse.
+    Corrupt { name: String, message: String },
+    Encryption(EncryptionError),
+    Io(io::Error),
+    Page(PageError),
+}
+
+impl fmt::Display for AgentStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AgentStoreError::InvalidName(name) => write!(f, "invalid agent name '{}'", name),
+            AgentStoreError::Corrupt { name, message } => write!(f, "stored agent '{}' is corrupt: {}", name, message),
+            AgentStoreError::Encryption(err) => write!(f, "{}", err),
+            AgentStoreError::Io(err) => write!(f, "agent store I/O failed: {}", err),
+            AgentStoreError::Page(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for AgentStoreError {}
+
+impl From<io::Error> for AgentStoreError {
+    fn from(err: io::Error) -> Self {
+        AgentStoreError::Io(err)
+    }
+}
+
+impl From<EncryptionError> for AgentStoreError {
+    fn from(err: EncryptionError) -> Self {
+        AgentStoreError::Encryption(err)
+    }
+}
+
+impl From<PageError> for AgentStoreError {
+    fn from(err: PageError) -> Self {
+        AgentStoreError::Page(err)
+    }
+}
+
+impl From<&AgentStoreError> for Problem {
+    fn from(err: &AgentStoreError) -> Self {
+        match err {
+            AgentStoreError::InvalidName(_) => Problem::bad_request(err.to_string()),
+            AgentStoreError::Page(err) => Problem::from(err),
+            _ => Problem::internal(err.to_string()),
+        }
+    }
+}
+
+/// An agent as kept in the store.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct StoredAgent {
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub agent: AgentSpec,
+    /// RFC 3339, UTC.
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// What `AgentStore::list` returns. Empty filters match everything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentQuery {
+    /// Only agents with this capability.
+    pub tag: Option<String>,
+    /// Only agents on this model.
+    pub model: Option<String>,
+    /// `name`, `created_at` or `updated_at`, with a leading `-` for descending.
+    pub sort: String,
+    pub page: PageRequest,
+}
+
+impl Default for AgentQuery {
+    fn default() -> Self {
+        AgentQuery { tag: None, model: None, sort: "name".to_string(), page: PageRequest::default() }
+    }
+}
+
+impl AgentQuery {
+    /// Parse `tag=finance&model=gpt-4o&sort=-updated_at&limit=20&cursor=...`.
+    pub fn from_query_string(query: &str) -> Result<Self, PageError> {
+        let mut parsed = AgentQuery::default();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = urlencoding::decode(value)
+                .map_err(|err| PageError::InvalidParameter(err.to_string()))?
+                .into_owned();
+            match key {
+                "tag" => parsed.tag = Some(value),
+                "model" => parsed.model = Some(value),
+                "sort" => parsed.sort = value,
+                _ if parsed.page.parse_param(key, &value)? => {}
+                _ => return Err(PageError::InvalidParameter(format!("unknown parameter '{}'", key))),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn matches(&self, stored: &StoredAgent) -> bool {
+        self.tag.as_ref().map_or(true, |tag| stored.agent.capabilities.contains(tag))
+            && self.model.as_ref().map_or(true, |model| &stored.agent.llm == model)
+    }
+}
+
+/// Tenant-scoped agent files under a `TenantWorkspace`.
+#[derive(Clone)]
+pub struct AgentStore {
+    workspace: TenantWorkspace,
+    cipher: Option<Arc<StateCipher>>,
+}
+
+impl AgentStore {
+    pub fn new(workspace: TenantWorkspace) -> Self {
+        AgentStore { workspace, cipher: None }
+    }
+
+    pub fn with_cipher(mut self, cipher: Arc<StateCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    fn path(&self, tenant_id: &TenantId, name: &str) -> Result<PathBuf, AgentStoreError> {
+        // A name sanitizing would change is refused, so two agents can never share a file
+        let valid = !name.is_empty()
+            && name.len() <= MAX_AGENT_NAME_LEN
+            && !name.starts_with('.')
+            && sanitize_file_name(name) == name;
+        if !valid {
+            return Err(AgentStoreError::InvalidName(name.to_string()));
+        }
+        Ok(self.workspace.agents_dir(tenant_id).join(format!("{}.json", name)))
+    }
+
+    fn read(&self, name: &str, path: &Path) -> Result<StoredAgent, AgentStoreError> {
+        let data = match &self.cipher {
+            Some(cipher) => cipher.read_file(path)?,
+            None => fs::read(path)?,
+        };
+        serde_json::from_slice(&data)
+            .map_err(|err| AgentStoreError::Corrupt { name: name.to_string(), message: err.to_string() })
+    }
+
+    /// Create or replace the agent. Replacing keeps the original `created_at`.
+    pub fn save(&self, tenant_id: &TenantId, agent: &LlmAgent) -> Result<StoredAgent, AgentStoreError> {
+        let path = self.path(tenant_id, &agent.name)?;
+        let now = chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true);
+        let created_at = match self.get(tenant_id, &agent.name)? {
+            Some(existing) => existing.created_at,
+            None => now.clone(),
+        };
+        let stored = StoredAgent { agent: (**agent).clone(), created_at, updated_at: now };
+        let json = serde_json::to_vec_pretty(&stored).map_err(|err| AgentStoreError::Io(err.into()))?;
+        fs::create_dir_all(self.workspace.agents_dir(tenant_id))?;
+        match &self.cipher {
+            Some(cipher) => cipher.write_file(&path, &json)?,
+            None => fs::write(&path, json)?,
+        }
+        Ok(stored)
+    }
+
+    pub fn get(&self, tenant_id: &TenantId, name: &str) -> Result<Option<StoredAgent>, AgentStoreError> {
+        let path = self.path(tenant_id, name)?;
+        match self.read(name, &path) {
+            Ok(stored) => Ok(Some(stored)),
+            Err(AgentStoreError::Io(err)) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(AgentStoreError::Encryption(EncryptionError::Io(err))) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// `save` in the auditor's tenant, recorded as `agent_created` or `agent_updated` by the auditor's actor.
+    pub fn save_as(&self, auditor: &Auditor, agent: &LlmAgent) -> Result<StoredAgent, AgentStoreError> {
+        let stored = self.save(&auditor.tenant_id, agent)?;
+        let action = if stored.created_at == stored.updated_at { AuditAction::AgentCreated } else { AuditAction::AgentUpdated };
+        auditor.record(action, &agent.name, json!({ "model": agent.llm }));
+        Ok(stored)
+    }
+
+    /// `delete` in the auditor's tenant, recorded as `agent_deleted` when the agent existed.
+    pub fn delete_as(&self, auditor: &Auditor, name: &str) -> Result<bool, AgentStoreError> {
+        let existed = self.delete(&auditor.tenant_id, name)?;
+        if existed {
+            auditor.record(AuditAction::AgentDeleted, name, json!({}));
+        }
+        Ok(existed)
+    }
+
+    /// Returns whether the agent existed.
+    pub fn delete(&self, tenant_id: &TenantId, name: &str) -> Result<bool, AgentStoreError> {
+        match fs::remove_file(self.path(tenant_id, name)?) {
+            Ok(()) => Ok(true),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// One page of the tenant's agents, filtered and sorted as `query` says.
+    pub fn list(&self, tenant_id: &TenantId, query: &AgentQuery) -> Result<Page<StoredAgent>, AgentStoreError> {
+        let (field, order) = parse_sort(&query.sort);
+        let sort_key: fn(&StoredAgent) -> String = match field {
+            "name" => |stored| stored.agent.name.clone(),
+            "created_at" => |stored| stored.created_at.clone(),
+            "updated_at" => |stored| stored.updated_at.clone(),
+            other => return Err(PageError::InvalidParameter(format!("cannot sort agents by '{}'", other)).into()),
+        };
+        let entries = match fs::read_dir(self.workspace.agents_dir(tenant_id)) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Page { items: Vec::new(), next_cursor: None }),
+            Err(err) => return Err(err.into()),
+        };
+        let mut matching = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            let Some(name) = path.file_name().and_then(|name| name.to_str()).and_then(|name| name.strip_suffix(".json")) else {
+                continue;
+            };
+            let stored = self.read(name, &path)?;
+            if query.matches(&stored) {
+                matching.push(stored);
+            }
+        }
+        Ok(paginate(matching, &query.sort, order, &query.page, |stored| (sort_key(stored), stored.agent.name.clone()))?)
+    }
+
+    /// Serve `GET /users/me/agents` for the caller's tenant. Returns a status code, content type, and body.
+    pub fn handle_http(&self, tenant_id: &TenantId, method: &str, path: &str) -> (u16, &'static str, String) {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let error = |problem: Problem| (problem.status, PROBLEM_JSON, problem.to_json());
+        match (method, path.trim_end_matches('/')) {
+            ("GET", "/users/me/agents") => {
+                let listed = AgentQuery::from_query_string(query)
+                    .map_err(AgentStoreError::Page)
+                    .and_then(|query| self.list(tenant_id, &query));
+                match listed {
+                    Ok(page) => (200, "application/json", serde_json::to_string(&page).expect("agents always serialize")),
+                    Err(err) => error(Problem::from(&err)),
+                }
+            }
+            (method, path) => error(Problem::no_route(method, path)),
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let store = AgentStore::new(TenantWorkspace::new("agent_workspace"));
+    let acme = TenantId::new("acme")?;
+    for name in ["Analyst", "Auditor", "Writer"] {
+        let agent = LlmAgent::builder().name(name).llm("gpt-4o").capability("finance").build()?;
+        store.save(&acme, &agent)?;
+    }
+    let query = AgentQuery::from_query_string("tag=finance&sort=-created_at&limit=2")?;
+    let page = store.list(&acme, &query)?;
+    let names: Vec<&str> = page.items.iter().map(|stored| stored.agent.name.as_str()).collect();
+    println!("{:?} next={:?}", names, page.next_cursor);
+    Ok(())
+}
+```
+
+### Notes
+* The agent's name is its id within a tenant, as it is for `Company` and the Python registry. Renaming an
+  agent is a delete followed by a save.
+* Completions are not stored. A handler binds one with `LlmAgent::with_completion` after loading the spec.
+* `list` reads every agent file of the tenant before paging (see the `pagination` notes). Tens of thousands
+  of agents per tenant would call for an index file.
@@ -0,0 +1,284 @@
+### Overview
+There is no Python counterpart. A Python swarm has one `Agent` per name, so a prompt or model upgrade goes to
+all traffic at once, and rolling it back means redeploying the old config. `VersionedAgent` holds several
+versions of one logical agent (`v1` with the current prompt, `v2` with the new one, and so on) and splits
+traffic between them by percentage:
+
+* `register` adds a version. A new agent starts with its first version taking all traffic; later versions
+  take none until the split says otherwise.
+* `set_split` assigns whole percentages that sum to 100, e.g. `v1` 90 and `v2` 10. Versions left out of a
+  split get no traffic. `promote` sends everything to one version.
+* `rollback` restores the split in force before the last change, so a bad canary is one call to undo.
+  `history()` lists the splits that led to the current one; a rolled-back split is dropped from it.
+* `select` picks a version for a routing key (a user, session, or conversation id). The key is hashed
+  into one of 100 buckets, so a user stays on the same version for as long as the split is unchanged.
+  Raising the canary from 10 to 20 percent keeps the first 10 percent of users where they were.
+
+`run_with` selects a version and runs it. Each run is counted per version in `stats()`, and exported as
+`swarms_agent_version_runs_total{agent, version, outcome}` and
+`swarms_agent_version_duration_seconds{agent, version}`, so the versions can be compared before the
+rollout continues.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::LlmAgent;
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::info;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Percent of traffic per version label.
+pub type TrafficSplit = BTreeMap<String, u8>;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionError {
+    /// A version with this label is already registered.
+    DuplicateVersion(String),
+    UnknownVersion(String),
+    /// The registered agent's name differs from the versioned agent's.
+    NameMismatch { expected: String, found: String },
+    /// The split's percentages do not sum to 100.
+    BadSplit { total: u32 },
+    NoVersions,
+    /// There is no earlier split to return to.
+    NothingToRollBack,
+}
+
+impl fmt::Display for VersionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            VersionError::DuplicateVersion(version) => write!(f, "version '{}' is already registered", version),
+            VersionError::UnknownVersion(version) => write!(f, "no version '{}' is registered", version),
+            VersionError::NameMismatch { expected, found } => {
+                write!(f, "agent '{}' cannot be registered as a version of '{}'", found, expected)
+            }
+            VersionError::BadSplit { total } => write!(f, "traffic split sums to {}%, not 100%", total),
+            VersionError::NoVersions => write!(f, "no versions are registered"),
+            VersionError::NothingToRollBack => write!(f, "there is no earlier traffic split to roll back to"),
+        }
+    }
+}
+
+impl std::error::Error for VersionError {}
+
+/// Runs of one version since it was registered.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct VersionStats {
+    pub runs: u64,
+    pub failures: u64,
+    pub total_duration: Duration,
+}
+
+impl VersionStats {
+    pub fn failure_rate(&self) -> f64 {
+        if self.runs == 0 {
+            return 0.0;
+        }
+        self.failures as f64 / self.runs as f64
+    }
+
+    pub fn mean_duration(&self) -> Duration {
+        if self.runs == 0 {
+            return Duration::ZERO;
+        }
+        self.total_duration / self.runs as u32
+    }
+}
+
+/// One traffic split and when it took effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SplitChange {
+    pub split: TrafficSplit,
+    /// RFC 3339, UTC.
+    pub changed_at: String,
+}
+
+/// The result of `run_with`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionedRun {
+    pub version: String,
+    pub output: String,
+}
+
+pub struct VersionedAgent {
+    name: String,
+    // In registration order
+    versions: Vec<(String, LlmAgent)>,
+    split: TrafficSplit,
+    history: Vec<SplitChange>,
+    stats: Mutex<BTreeMap<String, VersionStats>>,
+}
+
+impl VersionedAgent {
+    pub fn new(name: impl Into<String>) -> Self {
+        VersionedAgent {
+            name: name.into(),
+            versions: Vec::new(),
+            split: TrafficSplit::new(),
+            history: Vec::new(),
+            stats: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Add `agent` as `version`. The first version takes all traffic; later ones start at 0%.
+    pub fn register(&mut self, version: impl Into<String>, agent: LlmAgent) -> Result<(), VersionError> {
+        let version = version.into();
+        if agent.name != self.name {
+            return Err(VersionError::NameMismatch { expected: self.name.clone(), found: agent.name.clone() });
+        }
+        if self.agent(&version).is_some() {
+            return Err(VersionError::DuplicateVersion(version));
+        }
+        info!("Registered {} version {} on {}", self.name, version, agent.llm);
+        self.versions.push((version.clone(), agent));
+        if self.versions.len() == 1 {
+            self.apply(TrafficSplit::from([(version, 100)]));
+        }
+        Ok(())
+    }
+
+    pub fn versions(&self) -> impl Iterator<Item = &str> {
+        self.versions.iter().map(|(version, _)| version.as_str())
+    }
+
+    pub fn agent(&self, version: &str) -> Option<&LlmAgent> {
+        self.versions.iter().find(|(label, _)| label == version).map(|(_, agent)| agent)
+    }
+
+    /// The split in force. Versions that get no traffic are left out.
+    pub fn split(&self) -> &TrafficSplit {
+        &self.split
+    }
+
+    /// The splits that led to the current one, oldest first. The last entry is the current split.
+    pub fn history(&self) -> &[SplitChange] {
+        &self.history
+    }
+
+    /// Replace the split. Percentages must name registered versions and sum to 100.
+    pub fn set_split(&mut self, split: &[(&str, u8)]) -> Result<(), VersionError> {
+        let mut next: BTreeMap<String, u32> = BTreeMap::new();
+        for (version, percent) in split {
+            if self.agent(version).is_none() {
+                return Err(VersionError::UnknownVersion(version.to_string()));
+            }
+            *next.entry(version.to_string()).or_default() += u32::from(*percent);
+        }
+        let total: u32 = next.values().sum();
+        if total != 100 {
+            return Err(VersionError::BadSplit { total });
+        }
+        // Each share is at most the total, 100, so it fits a u8
+        self.apply(next.into_iter().filter(|(_, percent)| *percent > 0).map(|(version, percent)| (version, percent as u8)).collect());
+        Ok(())
+    }
+
+    /// Send all traffic to `version`.
+    pub fn promote(&mut self, version: &str) -> Result<(), VersionError> {
+        self.set_split(&[(version, 100)])
+    }
+
+    /// Return to the split in force before the last change. Returns the restored split.
+    pub fn rollback(&mut self) -> Result<&TrafficSplit, VersionError> {
+        if self.history.len() < 2 {
+            return Err(VersionError::NothingToRollBack);
+        }
+        self.history.pop();
+        let previous = self.history.pop().expect("checked above").split;
+        self.apply(previous);
+        Ok(&self.split)
+    }
+
+    fn apply(&mut self, split: TrafficSplit) {
+        info!("{} traffic split: {:?}", self.name, split);
+        self.history.push(SplitChange { split: split.clone(), changed_at: chrono::Utc::now().to_rfc3339() });
+        self.split = split;
+    }
+
+    /// The version `routing_key` is routed to under the current split.
+    pub fn select(&self, routing_key: &str) -> Result<(&str, &LlmAgent), VersionError> {
+        let bucket = bucket(&self.name, routing_key);
+        let mut upper = 0u32;
+        // Registration order, not label order, so adding a version never moves existing buckets
+        for (version, agent) in &self.versions {
+            upper += u32::from(self.split.get(version).copied().unwrap_or(0));
+            if bucket < upper {
+                return Ok((version.as_str(), agent));
+            }
+        }
+        Err(VersionError::NoVersions)
+    }
+
+    /// Select a version for `routing_key` and run it on `task` with `step`, recording the outcome.
+    pub fn run_with(
+        &self,
+        routing_key: &str,
+        task: &str,
+        step: impl FnOnce(&LlmAgent, &str) -> Result<String, String>,
+    ) -> Result<Result<VersionedRun, String>, VersionError> {
+        let (version, agent) = self.select(routing_key)?;
+        let started = Instant::now();
+        let result = step(agent, task);
+        let elapsed = started.elapsed();
+        SwarmMetrics::global().agent_version_run(&self.name, version, result.is_ok(), elapsed);
+        {
+            let mut stats = self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let entry = stats.entry(version.to_string()).or_default();
+            entry.runs += 1;
+            entry.failures += u64::from(result.is_err());
+            entry.total_duration += elapsed;
+        }
+        Ok(result.map(|output| VersionedRun { version: version.to_string(), output }))
+    }
+
+    /// Runs per version so far.
+    pub fn stats(&self) -> BTreeMap<String, VersionStats> {
+        self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+}
+
+// 0..100, stable for an agent and key
+fn bucket(agent: &str, routing_key: &str) -> u32 {
+    let digest = Sha256::new().chain_update(agent.as_bytes()).chain_update([0]).chain_update(routing_key.as_bytes()).finalize();
+    u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]) % 100
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut analyst = VersionedAgent::new("Analyst");
+    analyst.register("v1", LlmAgent::builder().name("Analyst").llm("gpt-4o").system_prompt("You analyze filings.").build()?)?;
+    analyst.register(
+        "v2",
+        LlmAgent::builder().name("Analyst").llm("gpt-4o-mini").system_prompt("You analyze filings; cite page numbers.").build()?,
+    )?;
+    analyst.set_split(&[("v1", 90), ("v2", 10)])?;
+
+    for user in ["alice", "bob", "carol", "dave"] {
+        let run = analyst.run_with(user, "Summarize the 10-K", |agent, task| Ok(format!("{} via {}", task, agent.llm)))??;
+        println!("{} -> {}: {}", user, run.version, run.output);
+    }
+    println!("{:?}", analyst.stats());
+
+    // The canary misbehaves: go back to 100% v1
+    analyst.rollback()?;
+    println!("{:?}", analyst.split());
+    Ok(())
+}
+```
+
+### Notes
+- Versions share a name so that conversations, metrics, and handoffs refer to "Analyst", not "Analyst-v2".
+  The version label is what tells them apart in the metrics and in `VersionedRun`.
+- Bucketing hashes the agent name with the key, so the same user can be in the canary for one agent and
+  not for another, and a canary never gets the same slice of users on every agent.
+- Rolling back twice undoes two changes. History is kept for the process lifetime; persist `history()`
+  alongside the config if the split must survive a restart.
+- `run_with` returns the step's own error inside `Ok`, and the failure is still counted against the
+  version. The outer error only means no version could be selected.
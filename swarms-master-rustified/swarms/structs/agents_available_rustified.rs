@@ -25,12 +25,12 @@ use serde::{Serialize, Deserialize};
 use serde_xml_rs::from_str;
 use prettytable::{Table, Row, Cell};
 use std::borrow::Cow;
+use std::sync::Arc;
+use crate::swarms::structs::agent::{Agent, LlmAgent, MockAgent};
 
-// Define the Agent struct
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-struct Agent {
-    agent_name: String,
-    description: String,
+// The description shown for `agent`; only model-backed agents carry one
+fn agent_description(agent: &dyn Agent) -> &str {
+    agent.spec().map_or("", |spec| spec.description.as_str())
 }
 
 // Define the Truncate function
@@ -44,7 +44,7 @@ fn truncate(text: &str, max_length: usize) -> Cow<str> {
 
 // Define the showcase_available_agents function
 pub fn showcase_available_agents(
-    agents: Vec<Agent>,
+    agents: &[Arc<dyn Agent>],
     name: Option<String>,
     description: Option<String>,
     format: String,
@@ -64,12 +64,12 @@ pub fn showcase_available_agents(
             // Iterate over the agents
             for (idx, agent) in agents.iter().enumerate() {
                 // Truncate the description
-                let desc = truncate(&agent.description, 50);
+                let desc = truncate(agent_description(agent.as_ref()), 50);
 
                 // Add a new row to the table
                 table.add_row(Row::new(vec![
                     Cell::new(&format!("{}", idx + 1)),
-                    Cell::new(&agent.agent_name),
+                    Cell::new(agent.name()),
                     Cell::new(&desc),
                 ]));
             }
@@ -94,8 +94,9 @@ pub fn showcase_available_agents(
                 output.push_str(&format!("  <agent id='{}'>", idx + 1));
 
                 // Add the agent name and description
-                output.push_str(&format!("    <name>{}</name>", agent.agent_name));
-                output.push_str(&format!("    <description>{}</description>", truncate(&agent.description, 130).as_ref()));
+                output.push_str(&format!("    <name>{}</name>", agent.name()));
+                let description = truncate(agent_description(agent.as_ref()), 130);
+                output.push_str(&format!("    <description>{}</description>", description.as_ref()));
 
                 output.push_str("  </agent>");
             }
@@ -110,13 +111,21 @@ pub fn showcase_available_agents(
 
 // Example usage
 fn main() {
-    let agents = vec![
-        Agent { agent_name: "Agent 1".to_string(), description: "Description for Agent 1".to_string() },
-        Agent { agent_name: "Agent 2".to_string(), description: "Description for Agent 2".to_string() },
+    let agents: Vec<Arc<dyn Agent>> = vec![
+        LlmAgent::builder()
+            .name("Agent 1")
+            .llm("gpt-4o")
+            .description("Description for Agent 1")
+            .build()
+            .expect("valid agent")
+            .into(),
+        Arc::new(MockAgent::new("Agent 2")),
     ];
 
-    println!("{}", showcase_available_agents(agents, Some("Swarm Name".to_string()), Some("Swarm Description".to_string()), "table".to_string()));
-    println!("{}", showcase_available_agents(agents, Some("Swarm Name".to_string()), Some("Swarm Description".to_string()), "xml".to_string()));
+    let name = Some("Swarm Name".to_string());
+    let description = Some("Swarm Description".to_string());
+    println!("{}", showcase_available_agents(&agents, name.clone(), description.clone(), "table".to_string()));
+    println!("{}", showcase_available_agents(&agents, name, description, "xml".to_string()));
 }
 ```
 
@@ -0,0 +1,647 @@
+### Overview
+Python swarms support human review only as `human_in_the_loop=True` on `SwarmRearrange`, which blocks on
+`input()` inside the worker. An `ApprovalGate` is the Rust equivalent as a workflow step. `SequentialWorkflow`
+places one after an agent, and `GraphWorkflow` uses it as a node. When the run reaches a gate it:
+
+1. writes a `GateCheckpoint` (the request plus the workflow's state so far) to the `CheckpointStore`, so a
+   run that is interrupted while waiting can be resumed later;
+2. asks an `ApprovalChannel` for a decision, bounded by the gate's timeout;
+3. continues on approval, or stops the run with `ApprovalError::Rejected`.
+
+The reviewer may edit the output while approving it. The next step then receives the edited text, and the
+`ReviewRecord` for the gate keeps both versions so the run metadata shows what a person changed.
+
+Decisions come from a person through one of the channels:
+
+* `CliApproval` prompts on the terminal.
+* `PendingApprovals` holds the open requests for a server to expose. `handle_http` serves
+  `GET /approvals` and `POST /approvals/{run_id}/{gate}`. `handle_ws_message` accepts the same decisions
+  as WebSocket messages, and `subscribe` pushes each new request to connected clients.
+
+A gate can also skip the person entirely: `GateMode::AutoApprove` and `GateMode::AutoReject` are for
+development and CI, and `TimeoutPolicy` decides what an unanswered gate does.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::Problem;
+use crate::swarms::structs::audit::{AuditAction, Auditor};
+use crate::swarms::utils::encryption::StateCipher;
+use crate::swarms::utils::safe_path::sanitize_file_name;
+use async_trait::async_trait;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{broadcast, oneshot};
+
+/// Whether a gate asks anyone.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GateMode {
+    #[default]
+    Manual,
+    AutoApprove,
+    AutoReject,
+}
+
+/// What an unanswered gate does when its timeout expires.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeoutPolicy {
+    #[default]
+    Reject,
+    Approve,
+    /// Stop the run with `ApprovalError::TimedOut` and keep the checkpoint for a later resume.
+    Fail,
+}
+
+/// A pause point in a workflow.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ApprovalGate {
+    pub name: String,
+    /// Shown to the reviewer above the output under review.
+    #[serde(default)]
+    pub prompt: String,
+    #[serde(default)]
+    pub mode: GateMode,
+    /// `None` waits indefinitely.
+    #[serde(default)]
+    pub timeout: Option<Duration>,
+    #[serde(default)]
+    pub on_timeout: TimeoutPolicy,
+}
+
+/// What the reviewer is asked to approve.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ApprovalRequest {
+    pub run_id: String,
+    pub workflow: String,
+    pub gate: String,
+    pub prompt: String,
+    /// The agent or node whose output is under review.
+    pub upstream: String,
+    pub output: String,
+    pub requested_at: String,
+}
+
+impl ApprovalRequest {
+    /// Key of the request in `PendingApprovals` and in HTTP paths.
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.run_id, self.gate)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum ApprovalDecision {
+    Approved {
+        by: String,
+        comment: Option<String>,
+        /// Replaces the upstream output for the rest of the run.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        edited_output: Option<String>,
+    },
+    Rejected { by: String, reason: String },
+}
+
+/// A decision as submitted over HTTP or WebSocket.
+#[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct DecisionBody {
+    pub approve: bool,
+    #[serde(default)]
+    pub by: Option<String>,
+    #[serde(default)]
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Only used when approving.
+    #[serde(default)]
+    pub edited_output: Option<String>,
+}
+
+impl From<DecisionBody> for ApprovalDecision {
+    fn from(body: DecisionBody) -> Self {
+        let by = body.by.unwrap_or_else(|| "anonymous".to_string());
+        if body.approve {
+            ApprovalDecision::Approved { by, comment: body.comment, edited_output: body.edited_output }
+        } else {
+            ApprovalDecision::Rejected { by, reason: body.reason.unwrap_or_else(|| "rejected".to_string()) }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ApprovalError {
+    Rejected { gate: String, by: String, reason: String },
+    TimedOut { gate: String },
+    /// No pending request matches a submitted decision.
+    NotPending(String),
+    /// A gate was attached to a step that does not exist.
+    UnknownStep(String),
+    /// A workflow with this gate was run without an `ApprovalContext`, so nobody could approve it.
+    NoContext { gate: String },
+    Channel(String),
+    Storage(String),
+}
+
+impl fmt::Display for ApprovalError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ApprovalError::Rejected { gate, by, reason } => write!(f, "gate '{}' rejected by {}: {}", gate, by, reason),
+            ApprovalError::TimedOut { gate } => write!(f, "gate '{}' timed out waiting for approval", gate),
+            ApprovalError::NotPending(key) => write!(f, "no pending approval for {}", key),
+            ApprovalError::UnknownStep(step) => write!(f, "no workflow step named '{}'", step),
+            ApprovalError::NoContext { gate } => write!(f, "gate '{}' needs an approval context to run", gate),
+            ApprovalError::Channel(message) => write!(f, "approval channel failed: {}", message),
+            ApprovalError::Storage(message) => write!(f, "approval checkpoint storage failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for ApprovalError {}
+
+impl ApprovalError {
+    /// How a run that stopped on this error ended, as recorded in `swarms_workflow_runs_total`.
+    pub fn outcome(&self) -> &'static str {
+        match self {
+            ApprovalError::Rejected { .. } => "rejected",
+            ApprovalError::TimedOut { .. } => "timed_out",
+            _ => "failed",
+        }
+    }
+}
+
+impl From<io::Error> for ApprovalError {
+    fn from(err: io::Error) -> Self {
+        ApprovalError::Storage(err.to_string())
+    }
+}
+
+impl From<serde_json::Error> for ApprovalError {
+    fn from(err: serde_json::Error) -> Self {
+        ApprovalError::Storage(err.to_string())
+    }
+}
+
+/// One decided gate, as kept in the run metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    pub gate: String,
+    pub upstream: String,
+    pub by: String,
+    #[serde(default)]
+    pub comment: Option<String>,
+    pub original_output: String,
+    /// `None` when the output was approved unchanged.
+    #[serde(default)]
+    pub edited_output: Option<String>,
+    pub decided_at: String,
+}
+
+impl ReviewRecord {
+    /// The output the run continues with.
+    pub fn output(&self) -> &str {
+        self.edited_output.as_deref().unwrap_or(&self.original_output)
+    }
+}
+
+/// Where decisions come from.
+#[async_trait]
+pub trait ApprovalChannel: Send + Sync {
+    async fn request(&self, request: &ApprovalRequest) -> Result<ApprovalDecision, ApprovalError>;
+}
+
+/// Everything a workflow needs to pass its gates.
+#[derive(Clone)]
+pub struct ApprovalContext {
+    pub run_id: String,
+    pub channel: Arc<dyn ApprovalChannel>,
+    pub store: CheckpointStore,
+    /// Records each decision as `gate_approved` / `gate_rejected` on `run_id/gate`.
+    pub audit: Option<Auditor>,
+}
+
+/// A paused run, persisted while its gate waits.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GateCheckpoint {
+    pub request: ApprovalRequest,
+    /// Workflow-specific state needed to resume after the gate.
+    pub state: JsonValue,
+}
+
+/// One JSON file per paused run.
+#[derive(Debug, Clone)]
+pub struct CheckpointStore {
+    dir: PathBuf,
+    cipher: Option<Arc<StateCipher>>,
+}
+
+impl CheckpointStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        CheckpointStore { dir: dir.into(), cipher: None }
+    }
+
+    /// Checkpoints hold agent output, so deployments that encrypt state at rest encrypt them too.
+    pub fn encrypted(dir: impl Into<PathBuf>, cipher: Arc<StateCipher>) -> Self {
+        CheckpointStore { dir: dir.into(), cipher: Some(cipher) }
+    }
+
+    fn encode(&self, checkpoint: &GateCheckpoint) -> Result<Vec<u8>, ApprovalError> {
+        let json = serde_json::to_vec_pretty(checkpoint)?;
+        Ok(match &self.cipher {
+            Some(cipher) => cipher.seal(&json),
+            None => json,
+        })
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<GateCheckpoint, ApprovalError> {
+        let json = match &self.cipher {
+            Some(cipher) => cipher.open(bytes).map_err(|err| ApprovalError::Storage(err.to_string()))?,
+            None => bytes.to_vec(),
+        };
+        Ok(serde_json::from_slice(&json)?)
+    }
+
+    // Run ids come from callers; sanitizing keeps one from naming a file outside `dir`
+    fn path(&self, run_id: &str) -> PathBuf {
+        self.dir.join(sanitize_file_name(&format!("{}.json", run_id)))
+    }
+
+    pub fn save(&self, checkpoint: &GateCheckpoint) -> Result<(), ApprovalError> {
+        fs::create_dir_all(&self.dir)?;
+        // Write then rename, so a crash never leaves a half-written checkpoint behind.
+        let path = self.path(&checkpoint.request.run_id);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, self.encode(checkpoint)?)?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    pub fn load(&self, run_id: &str) -> Result<Option<GateCheckpoint>, ApprovalError> {
+        match fs::read(self.path(run_id)) {
+            Ok(bytes) => Ok(Some(self.decode(&bytes)?)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn remove(&self, run_id: &str) -> Result<(), ApprovalError> {
+        match fs::remove_file(self.path(run_id)) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Every run currently paused at a gate.
+    pub fn pending(&self) -> Result<Vec<GateCheckpoint>, ApprovalError> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut checkpoints = Vec::new();
+        for entry in entries {
+            let path = entry?.path();
+            if path.extension().map_or(false, |ext| ext == "json") {
+                checkpoints.push(self.decode(&fs::read(path)?)?);
+            }
+        }
+        Ok(checkpoints)
+    }
+}
+
+impl ApprovalGate {
+    pub fn new(name: impl Into<String>) -> Self {
+        ApprovalGate {
+            name: name.into(),
+            prompt: String::new(),
+            mode: GateMode::Manual,
+            timeout: None,
+            on_timeout: TimeoutPolicy::Reject,
+        }
+    }
+
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = prompt.into();
+        self
+    }
+
+    pub fn mode(mut self, mode: GateMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    pub fn timeout(mut self, timeout: Duration, on_timeout: TimeoutPolicy) -> Self {
+        self.timeout = Some(timeout);
+        self.on_timeout = on_timeout;
+        self
+    }
+
+    /// The request this gate sends for `output` from `upstream`.
+    pub fn request(&self, run_id: &str, workflow: &str, upstream: &str, output: &str) -> ApprovalRequest {
+        ApprovalRequest {
+            run_id: run_id.to_string(),
+            workflow: workflow.to_string(),
+            gate: self.name.clone(),
+            prompt: self.prompt.clone(),
+            upstream: upstream.to_string(),
+            output: output.to_string(),
+            requested_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+
+    /// Pause at this gate. Returns the approving decision, or `Rejected` / `TimedOut`.
+    ///
+    /// The checkpoint is removed once the gate is decided either way. It is kept when the gate times out
+    /// under `TimeoutPolicy::Fail` or the channel fails, so the run can be resumed from it. The checkpoint
+    /// is stored under `request.run_id`, which is the id `CheckpointStore::load` takes to resume the run.
+    pub async fn wait(
+        &self,
+        request: ApprovalRequest,
+        state: JsonValue,
+        approvals: &ApprovalContext,
+    ) -> Result<ApprovalDecision, ApprovalError> {
+        let (run_id, target) = (request.run_id.clone(), request.key());
+        let decision = match self.mode {
+            GateMode::AutoApprove => {
+                ApprovalDecision::Approved { by: "auto".to_string(), comment: None, edited_output: None }
+            }
+            GateMode::AutoReject => {
+                ApprovalDecision::Rejected { by: "auto".to_string(), reason: "gate is set to auto-reject".to_string() }
+            }
+            GateMode::Manual => {
+                let checkpoint = GateCheckpoint { request, state };
+                approvals.store.save(&checkpoint)?;
+                info!("Run {} paused at gate '{}'", checkpoint.request.run_id, self.name);
+                let started = Instant::now();
+                let asked = approvals.channel.request(&checkpoint.request);
+                let answer = match self.timeout {
+                    Some(timeout) => tokio::time::timeout(timeout, asked).await.ok(),
+                    None => Some(asked.await),
+                };
+                match answer {
+                    Some(decision) => decision?,
+                    None => {
+                        warn!("Gate '{}' unanswered after {:?}; applying {:?}", self.name, started.elapsed(), self.on_timeout);
+                        match self.on_timeout {
+                            TimeoutPolicy::Approve => {
+                                ApprovalDecision::Approved { by: "timeout".to_string(), comment: None, edited_output: None }
+                            }
+                            TimeoutPolicy::Reject => {
+                                ApprovalDecision::Rejected { by: "timeout".to_string(), reason: "no decision before the timeout".to_string() }
+                            }
+                            TimeoutPolicy::Fail => return Err(ApprovalError::TimedOut { gate: self.name.clone() }),
+                        }
+                    }
+                }
+            }
+        };
+        approvals.store.remove(&run_id)?;
+        if let Some(auditor) = &approvals.audit {
+            match &decision {
+                ApprovalDecision::Approved { by, comment, edited_output } => auditor.record_as(
+                    by,
+                    AuditAction::GateApproved,
+                    &target,
+                    json!({ "comment": comment, "edited": edited_output.is_some() }),
+                ),
+                ApprovalDecision::Rejected { by, reason } => {
+                    auditor.record_as(by, AuditAction::GateRejected, &target, json!({ "reason": reason }))
+                }
+            }
+        }
+        match decision {
+            ApprovalDecision::Rejected { by, reason } => {
+                Err(ApprovalError::Rejected { gate: self.name.clone(), by, reason })
+            }
+            approved => {
+                info!("Gate '{}' approved: {:?}", self.name, approved);
+                Ok(approved)
+            }
+        }
+    }
+
+    /// `wait`, returning the record of the approval with the original and any edited output.
+    pub async fn review(
+        &self,
+        request: ApprovalRequest,
+        state: JsonValue,
+        approvals: &ApprovalContext,
+    ) -> Result<ReviewRecord, ApprovalError> {
+        let (upstream, original_output) = (request.upstream.clone(), request.output.clone());
+        let (by, comment, edited_output) = match self.wait(request, state, approvals).await? {
+            ApprovalDecision::Approved { by, comment, edited_output } => (by, comment, edited_output),
+            ApprovalDecision::Rejected { .. } => unreachable!("wait returns rejections as errors"),
+        };
+        // An "edit" that leaves the text as it was is recorded as an unchanged approval.
+        let edited_output = edited_output.filter(|edited| *edited != original_output);
+        if edited_output.is_some() {
+            info!("Gate '{}': output of {} edited by {}", self.name, upstream, by);
+        }
+        Ok(ReviewRecord {
+            gate: self.name.clone(),
+            upstream,
+            by,
+            comment,
+            original_output,
+            edited_output,
+            decided_at: chrono::Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// Asks on the terminal. Blocks a blocking-pool thread, not the runtime.
+#[derive(Debug, Clone, Default)]
+pub struct CliApproval;
+
+#[async_trait]
+impl ApprovalChannel for CliApproval {
+    async fn request(&self, request: &ApprovalRequest) -> Result<ApprovalDecision, ApprovalError> {
+        let request = request.clone();
+        tokio::task::spawn_blocking(move || {
+            let mut stdout = io::stdout();
+            writeln!(stdout, "\n[{}] Gate '{}' after {}", request.workflow, request.gate, request.upstream)?;
+            if !request.prompt.is_empty() {
+                writeln!(stdout, "{}", request.prompt)?;
+            }
+            writeln!(stdout, "---\n{}\n---", request.output)?;
+            write!(stdout, "Approve? [y/N/e] ('e' to edit, text after 'n' is recorded as the reason): ")?;
+            stdout.flush()?;
+            let mut stdin = io::stdin().lock();
+            let mut line = String::new();
+            stdin.read_line(&mut line)?;
+            let by = std::env::var("USER").unwrap_or_else(|_| "cli".to_string());
+            let line = line.trim();
+            Ok(if line.eq_ignore_ascii_case("y") || line.eq_ignore_ascii_case("yes") {
+                ApprovalDecision::Approved { by, comment: None, edited_output: None }
+            } else if line.eq_ignore_ascii_case("e") || line.eq_ignore_ascii_case("edit") {
+                writeln!(stdout, "Enter the replacement output; end it with a line containing only '.':")?;
+                let mut edited = Vec::new();
+                for line in stdin.lines() {
+                    let line = line?;
+                    if line == "." {
+                        break;
+                    }
+                    edited.push(line);
+                }
+                ApprovalDecision::Approved { by, comment: None, edited_output: Some(edited.join("\n")) }
+            } else {
+                let reason = line.trim_start_matches(|c| c == 'n' || c == 'N').trim();
+                let reason = if reason.is_empty() { "rejected at the prompt" } else { reason };
+                ApprovalDecision::Rejected { by, reason: reason.to_string() }
+            })
+        })
+        .await
+        .map_err(|err| ApprovalError::Channel(err.to_string()))?
+    }
+}
+
+type Waiting = HashMap<String, (ApprovalRequest, oneshot::Sender<ApprovalDecision>)>;
+
+/// Open requests waiting for a decision from a REST or WebSocket client.
+#[derive(Clone)]
+pub struct PendingApprovals {
+    waiting: Arc<Mutex<Waiting>>,
+    announce: broadcast::Sender<ApprovalRequest>,
+}
+
+// Removes a request when its gate stops waiting (decided, timed out, or the run was dropped).
+struct Registration {
+    waiting: Arc<Mutex<Waiting>>,
+    key: String,
+}
+
+impl Drop for Registration {
+    fn drop(&mut self) {
+        self.waiting.lock().unwrap().remove(&self.key);
+    }
+}
+
+impl Default for PendingApprovals {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PendingApprovals {
+    pub fn new() -> Self {
+        let (announce, _) = broadcast::channel(64);
+        PendingApprovals { waiting: Arc::new(Mutex::new(HashMap::new())), announce }
+    }
+
+    pub fn list(&self) -> Vec<ApprovalRequest> {
+        let mut requests: Vec<_> = self.waiting.lock().unwrap().values().map(|(request, _)| request.clone()).collect();
+        requests.sort_by(|a, b| a.requested_at.cmp(&b.requested_at));
+        requests
+    }
+
+    /// New requests as they arrive, for pushing to WebSocket clients.
+    pub fn subscribe(&self) -> broadcast::Receiver<ApprovalRequest> {
+        self.announce.subscribe()
+    }
+
+    pub fn decide(&self, key: &str, decision: ApprovalDecision) -> Result<(), ApprovalError> {
+        let (_, sender) =
+            self.waiting.lock().unwrap().remove(key).ok_or_else(|| ApprovalError::NotPending(key.to_string()))?;
+        sender.send(decision).map_err(|_| ApprovalError::NotPending(key.to_string()))
+    }
+
+    /// Serve `GET /approvals` and `POST /approvals/{run_id}/{gate}`; returns a status code and JSON body.
+    pub fn handle_http(&self, method: &str, path: &str, body: &str) -> (u16, JsonValue) {
+        let path = path.trim_end_matches('/');
+        match (method, path.strip_prefix("/approvals")) {
+            ("GET", Some("")) => (200, json!(self.list())),
+            ("POST", Some(key)) if key.starts_with('/') => {
+                let decision: DecisionBody = match serde_json::from_str(body) {
+                    Ok(decision) => decision,
+                    Err(err) => return (400, Problem::bad_request(err.to_string()).to_value()),
+                };
+                match self.decide(&key[1..], decision.into()) {
+                    Ok(()) => (200, json!({ "ok": true })),
+                    Err(err) => (404, Problem::not_found(err.to_string()).to_value()),
+                }
+            }
+            _ => (404, Problem::no_route(method, path).to_value()),
+        }
+    }
+
+    /// Handle one WebSocket message: `{"type": "list"}` or
+    /// `{"type": "decide", "run_id": "...", "gate": "...", "approve": true, ...}`. Returns the reply.
+    pub fn handle_ws_message(&self, text: &str) -> String {
+        #[derive(Deserialize)]
+        #[serde(tag = "type", rename_all = "snake_case")]
+        enum Message {
+            List,
+            Decide {
+                run_id: String,
+                gate: String,
+                #[serde(flatten)]
+                decision: DecisionBody,
+            },
+        }
+        let reply = match serde_json::from_str::<Message>(text) {
+            Ok(Message::List) => json!({ "type": "pending", "requests": self.list() }),
+            Ok(Message::Decide { run_id, gate, decision }) => {
+                match self.decide(&format!("{}/{}", run_id, gate), decision.into()) {
+                    Ok(()) => json!({ "type": "ok" }),
+                    Err(err) => json!({ "type": "error", "error": err.to_string() }),
+                }
+            }
+            Err(err) => json!({ "type": "error", "error": err.to_string() }),
+        };
+        reply.to_string()
+    }
+}
+
+#[async_trait]
+impl ApprovalChannel for PendingApprovals {
+    async fn request(&self, request: &ApprovalRequest) -> Result<ApprovalDecision, ApprovalError> {
+        let (sender, receiver) = oneshot::channel();
+        let key = request.key();
+        self.waiting.lock().unwrap().insert(key.clone(), (request.clone(), sender));
+        let _registration = Registration { waiting: self.waiting.clone(), key };
+        // No subscribers is fine; clients can still poll `list`.
+        let _ = self.announce.send(request.clone());
+        receiver.await.map_err(|_| ApprovalError::Channel("approval request was dropped".to_string()))
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), ApprovalError> {
+    let pending = PendingApprovals::new();
+    let approvals = ApprovalContext {
+        run_id: "run-42".to_string(),
+        channel: Arc::new(pending.clone()),
+        store: CheckpointStore::new("approvals"),
+        audit: None,
+    };
+    let gate = ApprovalGate::new("legal-review")
+        .prompt("Check the draft for unsupported claims.")
+        .timeout(Duration::from_secs(5), TimeoutPolicy::Reject);
+
+    let reviewer = tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let body = r#"{"approve": true, "by": "alice", "edited_output": "Draft memo, claims removed."}"#;
+        pending.handle_http("POST", "/approvals/run-42/legal-review", body)
+    });
+    let request = gate.request(&approvals.run_id, "SequentialWorkflow", "Writer", "Draft memo...");
+    let record = gate.review(request, json!({ "next_agent": 1 }), &approvals).await?;
+    println!("{} {:?}", record.output(), reviewer.await.unwrap());
+    Ok(())
+}
+```
+
+### Notes
+* `handle_http` and `handle_ws_message` are transport-agnostic; the server mounts them on whatever HTTP and WebSocket stack it already runs. Both must sit behind the server's authentication, since anyone who can reach them can approve a gate.
+* Resuming re-asks the gate: a checkpoint records that a run was waiting, not that anyone decided. `SequentialWorkflow::resume` and `GraphWorkflow::resume` load the checkpoint by the context's `run_id`.
+* Timeouts keep their sub-second part, so a gate can time out after 500 ms in tests and demos. In configuration files `timeout` is a serde `Duration`, `{ "secs": 300, "nanos": 0 }`.
+* Edits are plain text replacements. A reviewer who needs to reshape structured output edits its serialized form, and the next step parses it as it would any agent output.
+* Each run has at most one checkpoint, because a run waits at one gate at a time.
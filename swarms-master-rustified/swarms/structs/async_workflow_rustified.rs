@@ -9,22 +9,16 @@ The provided Python file can be partially converted to Rust, with the main chall
 ### Converted Code
 ```rust
 // Import necessary crates
-use async_std::task;
+use crate::swarms::structs::agent::Agent;
+use futures::future::join_all;
 use log::info;
 use log::error;
-use std::result;
+use std::sync::Arc;
 
-// Define the Agent struct
-pub struct Agent {
-    // Add fields for Agent as necessary
-    pub agent_name: String,
-    // Add arun function as necessary
-}
-
-// Define the AsyncWorkflow struct
+// Define the AsyncWorkflow struct; agents are the crate's `Agent` trait objects
 pub struct AsyncWorkflow {
     pub name: String,
-    pub agents: Vec<Agent>,
+    pub agents: Vec<Arc<dyn Agent>>,
     pub max_workers: i32,
     pub dashboard: bool,
     pub autosave: bool,
@@ -35,7 +29,14 @@ pub struct AsyncWorkflow {
 
 impl AsyncWorkflow {
     // Initialize the AsyncWorkflow struct
-    pub fn new(name: String, agents: Vec<Agent>, max_workers: i32, dashboard: bool, autosave: bool, verbose: bool) -> Self {
+    pub fn new(
+        name: String,
+        agents: Vec<Arc<dyn Agent>>,
+        max_workers: i32,
+        dashboard: bool,
+        autosave: bool,
+        verbose: bool,
+    ) -> Self {
         AsyncWorkflow {
             name,
             agents,
@@ -48,17 +49,21 @@ impl AsyncWorkflow {
         }
     }
 
-    // Execute a single agent task asynchronously
-    async fn execute_agent_task(&self, agent: &Agent, task: String) -> String {
-        if self.verbose {
-            info!("Agent {} processing task: {}", agent.agent_name, task);
+    // Execute a single agent task on the blocking pool; `Agent::run` is synchronous. A failed agent
+    // reports its error text in place of a result, as the Python workflow does.
+    async fn execute_agent_task(agent: Arc<dyn Agent>, task: String, verbose: bool) -> String {
+        if verbose {
+            info!("Agent {} processing task: {}", agent.name(), task);
         }
-        // Call agent.arun function, assuming it's async
-        let result = agent.arun(task).await;
-        if self.verbose {
-            info!("Agent {} completed task", agent.agent_name);
+        let name = agent.name().to_string();
+        let result = async_std::task::spawn_blocking(move || agent.run(&task)).await;
+        if verbose {
+            info!("Agent {} completed task", name);
         }
-        result
+        result.unwrap_or_else(|e| {
+            error!("Error in agent execution: {}", e);
+            e.to_string()
+        })
     }
 
     // Run the workflow with all agents processing the task concurrently
@@ -67,20 +72,13 @@ impl AsyncWorkflow {
             panic!("No agents provided to the workflow");
         }
 
-        let mut tasks: Vec<async_std::task::JoinHandle<String>> = self.agents.iter().map(|agent| {
-            async_std::task::spawn(self.execute_agent_task(agent, task.clone()))
-        }).collect();
+        let tasks = self
+            .agents
+            .iter()
+            .map(|agent| Self::execute_agent_task(Arc::clone(agent), task.clone(), self.verbose));
 
         // Execute all tasks concurrently
-        let results: Vec<String> = task::join_all(tasks).await.into_iter().map(|result| {
-            match result {
-                Ok(result) => result,
-                Err(e) => {
-                    error!("Error in agent execution: {}", e);
-                    e.to_string()
-                }
-            }
-        }).collect();
+        let results: Vec<String> = join_all(tasks).await;
 
         if self.autosave {
             // TODO: Implement autosave logic here
@@ -98,36 +96,20 @@ impl AsyncWorkflow {
 - **Memory management:** Rust's ownership system and borrow checker can be challenging to work with, especially when managing shared state between tasks.
 
 ### Testing and Example Usage
-To test the converted code, we can create an example `Agent` implementation and use it with the `AsyncWorkflow`:
+To test the converted code, we can use `MockAgent`s with the `AsyncWorkflow`:
 ```rust
 fn main() {
+    use crate::swarms::structs::agent::MockAgent;
+
     // Initialize the logger
     env_logger::init();
 
-    // Create an example Agent implementation
-    struct ExampleAgent {
-        agent_name: String,
-    }
-
-    impl Agent for ExampleAgent {
-        fn arun(&self, task: String) -> async_std::future::BoxFuture<'static, String> {
-            Box::pin(async move {
-                // Simulate some async work
-                async_std::task::sleep(std::time::Duration::from_millis(100)).await;
-                format!("Result from {}", self.agent_name)
-            })
-        }
-    }
-
     // Create an AsyncWorkflow instance
-    let mut workflow = AsyncWorkflow::new(
-        "Example Workflow".to_string(),
-        vec![ExampleAgent { agent_name: "Agent 1".to_string() }, ExampleAgent { agent_name: "Agent 2".to_string() }],
-        5,
-        false,
-        false,
-        false,
-    );
+    let agents: Vec<Arc<dyn Agent>> = vec![
+        Arc::new(MockAgent::new("Agent 1").reply("Result from Agent 1")),
+        Arc::new(MockAgent::new("Agent 2").reply("Result from Agent 2")),
+    ];
+    let mut workflow = AsyncWorkflow::new("Example Workflow".to_string(), agents, 5, false, false, false);
 
     // Run the workflow
     let results = async_std::task::block_on(workflow.run("Example Task".to_string()));
@@ -138,4 +120,4 @@ fn main() {
     }
 }
 ```
-This example demonstrates how to use the converted `AsyncWorkflow` with an example `Agent` implementation. The workflow runs two agents concurrently and prints the results.
\ No newline at end of file
+This example demonstrates how to use the converted `AsyncWorkflow` with scripted agents. The workflow runs two agents concurrently and prints the results. Any `Agent` works in their place: an `LlmAgent` with a bound model, or a whole swarm wrapped in `SwarmAgent`.
@@ -0,0 +1,405 @@
+### Overview
+There is no Python counterpart; the Python server logs requests through loguru, which records what happened
+but not reliably who did it, and rotates the files away. Finance deployments need a record that answers
+"who approved this run, and when" long after the fact. `AuditLog` is that record: an append-only JSONL file
+of `AuditEvent`s, one per administrative or execution action.
+
+Each event names the actor, the tenant, the action, what it acted on, and the id of the API request that
+caused it, so an entry can be matched against the server's access log. The actions covered are:
+
+* agents created, updated, or deleted (recorded by `AgentStore::save_as` and `delete_as`);
+* runs started;
+* approval gates approved or rejected (recorded by `ApprovalGate::wait` when its context has an auditor);
+* agents handing control to a peer (recorded when the run's `HandoffPolicy` has an auditor);
+* swarm config reloads applied or rejected (recorded by `LiveSwarmConfig` when it has an auditor);
+* API keys revoked (recorded by `TenantKeys::revoke_as`, with the key's id rather than the key).
+
+`handle_http` serves `GET /audit` with optional `actor`, `action`, `target`, `since`, `until`, and `limit`
+query parameters, and `GET /audit/export`, which returns the same selection as JSONL. Both only return the
+caller's tenant. Failures are answered with a problem document (see `problem`).
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+use crate::swarms::structs::tenancy::TenantId;
+use crate::swarms::utils::compression::{CompressionError, StateCompressor};
+use chrono::{DateTime, Utc};
+use log::error;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum AuditAction {
+    AgentCreated,
+    AgentUpdated,
+    AgentDeleted,
+    RunStarted,
+    GateApproved,
+    GateRejected,
+    Handoff,
+    ConfigReloaded,
+    ConfigRejected,
+    KeyRevoked,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AuditAction::AgentCreated => "agent_created",
+            AuditAction::AgentUpdated => "agent_updated",
+            AuditAction::AgentDeleted => "agent_deleted",
+            AuditAction::RunStarted => "run_started",
+            AuditAction::GateApproved => "gate_approved",
+            AuditAction::GateRejected => "gate_rejected",
+            AuditAction::Handoff => "handoff",
+            AuditAction::ConfigReloaded => "config_reloaded",
+            AuditAction::ConfigRejected => "config_rejected",
+            AuditAction::KeyRevoked => "key_revoked",
+        }
+    }
+}
+
+/// One line of the audit log.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct AuditEvent {
+    pub event_id: String,
+    /// RFC 3339, UTC.
+    pub timestamp: String,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub tenant_id: TenantId,
+    /// The user, API key name, or `auto` / `timeout` for decisions nobody made by hand.
+    pub actor: String,
+    /// The API request that caused the action, when there was one.
+    #[serde(default)]
+    pub request_id: Option<String>,
+    pub action: AuditAction,
+    /// What was acted on: an agent name, a run id, `run_id/gate`, or a key id.
+    pub target: String,
+    #[serde(default)]
+    #[cfg_attr(feature = "openapi", schema(value_type = Object))]
+    pub details: JsonValue,
+}
+
+#[derive(Debug)]
+pub enum AuditError {
+    Io(io::Error),
+    /// A line of the log that does not parse. The log is append-only, so this means it was edited.
+    Corrupt { line: usize, message: String },
+    InvalidQuery(String),
+    /// A rolled segment that does not inflate or fails its checksum.
+    Segment { path: PathBuf, error: CompressionError },
+}
+
+impl fmt::Display for AuditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AuditError::Io(err) => write!(f, "audit log I/O failed: {}", err),
+            AuditError::Corrupt { line, message } => write!(f, "audit log line {} is corrupt: {}", line, message),
+            AuditError::InvalidQuery(message) => write!(f, "invalid audit query: {}", message),
+            AuditError::Segment { path, error } => write!(f, "audit log segment {}: {}", path.display(), error),
+        }
+    }
+}
+
+impl std::error::Error for AuditError {}
+
+impl From<io::Error> for AuditError {
+    fn from(err: io::Error) -> Self {
+        AuditError::Io(err)
+    }
+}
+
+/// Which events to return. Empty fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct AuditQuery {
+    pub actor: Option<String>,
+    pub action: Option<AuditAction>,
+    pub target: Option<String>,
+    /// Inclusive bounds, given as RFC 3339 in any offset.
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    /// The most recent `limit` matches.
+    pub limit: Option<usize>,
+}
+
+impl AuditQuery {
+    /// Parse `actor=alice&action=gate_approved&limit=50`.
+    pub fn from_query_string(query: &str) -> Result<Self, AuditError> {
+        let mut parsed = AuditQuery::default();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = urlencoding::decode(value)
+                .map_err(|err| AuditError::InvalidQuery(err.to_string()))?
+                .into_owned();
+            match key {
+                "actor" => parsed.actor = Some(value),
+                "target" => parsed.target = Some(value),
+                "since" => parsed.since = Some(parse_bound(key, &value)?),
+                "until" => parsed.until = Some(parse_bound(key, &value)?),
+                "action" => {
+                    let action = serde_json::from_value(JsonValue::String(value.clone()))
+                        .map_err(|_| AuditError::InvalidQuery(format!("unknown action '{}'", value)))?;
+                    parsed.action = Some(action);
+                }
+                "limit" => {
+                    let limit = value.parse().map_err(|_| AuditError::InvalidQuery(format!("bad limit '{}'", value)))?;
+                    parsed.limit = Some(limit);
+                }
+                _ => return Err(AuditError::InvalidQuery(format!("unknown parameter '{}'", key))),
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn matches(&self, event: &AuditEvent) -> bool {
+        // Compared as instants: strings with other offsets or precisions do not order correctly.
+        // An event whose timestamp does not parse matches no time bound.
+        let at = DateTime::parse_from_rfc3339(&event.timestamp).ok().map(|at| at.with_timezone(&Utc));
+        self.actor.as_ref().map_or(true, |actor| &event.actor == actor)
+            && self.action.map_or(true, |action| event.action == action)
+            && self.target.as_ref().map_or(true, |target| &event.target == target)
+            && self.since.map_or(true, |since| at.is_some_and(|at| at >= since))
+            && self.until.map_or(true, |until| at.is_some_and(|at| at <= until))
+    }
+}
+
+fn parse_bound(name: &str, value: &str) -> Result<DateTime<Utc>, AuditError> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|err| AuditError::InvalidQuery(format!("bad {} '{}': {}", name, value, err)))
+}
+
+/// The append-only audit log. Cloning shares the underlying file.
+#[derive(Clone)]
+pub struct AuditLog {
+    path: PathBuf,
+    // Serializes appends so concurrent writers never interleave lines.
+    file: Arc<Mutex<Option<File>>>,
+    // Rolls the live file into a compressed segment once it reaches the threshold
+    compressor: Option<StateCompressor>,
+}
+
+// The events in one JSONL file that belong to the tenant and match the query
+fn scan(
+    reader: impl BufRead,
+    tenant_id: &TenantId,
+    query: &AuditQuery,
+    events: &mut Vec<AuditEvent>,
+) -> Result<(), AuditError> {
+    for (index, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let event: AuditEvent =
+            serde_json::from_str(&line).map_err(|err| AuditError::Corrupt { line: index + 1, message: err.to_string() })?;
+        if &event.tenant_id == tenant_id && query.matches(&event) {
+            events.push(event);
+        }
+    }
+    Ok(())
+}
+
+impl AuditLog {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        AuditLog { path: path.into(), file: Arc::new(Mutex::new(None)), compressor: None }
+    }
+
+    /// Once the live file reaches the compressor's threshold, move it to a compressed segment next to it
+    /// (`audit.jsonl.1`, `audit.jsonl.2`, ...) and start a new one. Queries read the segments too.
+    pub fn with_compression(mut self, compressor: StateCompressor) -> Self {
+        self.compressor = Some(compressor);
+        self
+    }
+
+    /// Rolled segments, oldest first.
+    pub fn segments(&self) -> io::Result<Vec<PathBuf>> {
+        let name = self.path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let dir = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        let entries = match fs::read_dir(dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+        let mut segments = Vec::new();
+        for entry in entries {
+            let entry = entry?;
+            let file_name = entry.file_name().to_string_lossy().into_owned();
+            let number = file_name.strip_prefix(name.as_str()).and_then(|rest| rest.strip_prefix('.'));
+            if let Some(number) = number.and_then(|number| number.parse::<u64>().ok()) {
+                segments.push((number, entry.path()));
+            }
+        }
+        segments.sort();
+        Ok(segments.into_iter().map(|(_, path)| path).collect())
+    }
+
+    // Called with the append lock held. The live file is renamed first and compressed in place, so a crash
+    // leaves either a plain or a compressed segment, and no event is in two files.
+    fn roll(&self, file: &mut Option<File>, compressor: &StateCompressor) -> Result<(), AuditError> {
+        *file = None;
+        let mut segment = self.path.as_os_str().to_owned();
+        segment.push(format!(".{}", self.segments()?.len() + 1));
+        let segment = PathBuf::from(segment);
+        fs::rename(&self.path, &segment)?;
+        let data = fs::read(&segment)?;
+        compressor.write_file(&segment, &data).map_err(|error| AuditError::Segment { path: segment, error })
+    }
+
+    /// Append one event. The line is flushed before this returns.
+    pub fn record(&self, event: &AuditEvent) -> Result<(), AuditError> {
+        let mut line = serde_json::to_vec(event).map_err(|err| AuditError::Io(err.into()))?;
+        line.push(b'\n');
+        let mut file = self.file.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if file.is_none() {
+            if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+            *file = Some(OpenOptions::new().create(true).append(true).open(&self.path)?);
+        }
+        let live = file.as_mut().expect("opened above");
+        live.write_all(&line)?;
+        live.flush()?;
+        if let Some(compressor) = &self.compressor {
+            if live.metadata()?.len() >= compressor.threshold_bytes() as u64 {
+                self.roll(&mut file, compressor)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Build and append an event. Returns it so callers can echo the event id.
+    pub fn log(
+        &self,
+        tenant_id: &TenantId,
+        actor: &str,
+        request_id: Option<&str>,
+        action: AuditAction,
+        target: &str,
+        details: JsonValue,
+    ) -> Result<AuditEvent, AuditError> {
+        let event = AuditEvent {
+            event_id: uuid::Uuid::new_v4().to_string(),
+            timestamp: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Millis, true),
+            tenant_id: tenant_id.clone(),
+            actor: actor.to_string(),
+            request_id: request_id.map(str::to_string),
+            action,
+            target: target.to_string(),
+            details,
+        };
+        self.record(&event)?;
+        Ok(event)
+    }
+
+    /// The tenant's events matching `query`, oldest first, from the rolled segments and then the live file.
+    pub fn query(&self, tenant_id: &TenantId, query: &AuditQuery) -> Result<Vec<AuditEvent>, AuditError> {
+        let mut events = Vec::new();
+        for segment in self.segments()? {
+            let data = StateCompressor::read_file(&segment).map_err(|error| AuditError::Segment { path: segment, error })?;
+            scan(data.as_slice(), tenant_id, query, &mut events)?;
+        }
+        match File::open(&self.path) {
+            Ok(file) => scan(BufReader::new(file), tenant_id, query, &mut events)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {}
+            Err(err) => return Err(err.into()),
+        }
+        if let Some(limit) = query.limit {
+            events.drain(..events.len().saturating_sub(limit));
+        }
+        Ok(events)
+    }
+
+    /// Write the tenant's matching events as JSONL.
+    pub fn export_jsonl(&self, tenant_id: &TenantId, query: &AuditQuery, out: &mut impl Write) -> Result<usize, AuditError> {
+        let events = self.query(tenant_id, query)?;
+        for event in &events {
+            serde_json::to_writer(&mut *out, event).map_err(|err| AuditError::Io(err.into()))?;
+            out.write_all(b"\n")?;
+        }
+        Ok(events.len())
+    }
+
+    /// Serve `GET /audit` (JSON array) and `GET /audit/export` (JSONL) for the caller's tenant.
+    /// Returns a status code, content type, and body.
+    pub fn handle_http(&self, tenant_id: &TenantId, method: &str, path: &str) -> (u16, &'static str, String) {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let error = |problem: Problem| (problem.status, PROBLEM_JSON, problem.to_json());
+        if method != "GET" {
+            return error(Problem::method_not_allowed(method, "the audit log"));
+        }
+        let query = match AuditQuery::from_query_string(query) {
+            Ok(query) => query,
+            Err(err) => return error(Problem::bad_request(err.to_string())),
+        };
+        let result = match path.trim_end_matches('/') {
+            "/audit" => self
+                .query(tenant_id, &query)
+                .map(|events| ("application/json", json!(events).to_string())),
+            "/audit/export" => {
+                let mut body = Vec::new();
+                self.export_jsonl(tenant_id, &query, &mut body)
+                    .map(|_| ("application/x-ndjson", String::from_utf8(body).expect("serde_json writes UTF-8")))
+            }
+            other => return error(Problem::no_route("GET", other)),
+        };
+        match result {
+            Ok((content_type, body)) => (200, content_type, body),
+            Err(err) => {
+                error!("Audit query failed: {}", err);
+                error(Problem::internal(err.to_string()))
+            }
+        }
+    }
+}
+
+/// An audit log bound to one caller, for components that act on a tenant's behalf.
+#[derive(Clone)]
+pub struct Auditor {
+    pub log: AuditLog,
+    pub tenant_id: TenantId,
+    /// The authenticated caller the work is done for.
+    pub actor: String,
+    /// The API request being served, if any.
+    pub request_id: Option<String>,
+}
+
+impl Auditor {
+    /// Record an action by the caller.
+    pub fn record(&self, action: AuditAction, target: &str, details: JsonValue) {
+        self.record_as(&self.actor, action, target, details)
+    }
+
+    /// Record an action by someone else, such as the reviewer who decided a gate. A failed write is
+    /// logged, not returned: the action has already happened.
+    pub fn record_as(&self, actor: &str, action: AuditAction, target: &str, details: JsonValue) {
+        if let Err(err) = self.log.log(&self.tenant_id, actor, self.request_id.as_deref(), action, target, details) {
+            error!("Failed to audit {} on {} by {}: {}", action.as_str(), target, actor, err);
+        }
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let log = AuditLog::new("agent_workspace/audit.jsonl");
+    let acme = TenantId::new("acme")?;
+    log.log(&acme, "alice", Some("req-1"), AuditAction::AgentCreated, "Financial-Analyst", json!({ "model": "gpt-4o" }))?;
+    log.log(&acme, "bob", Some("req-2"), AuditAction::GateApproved, "run-42/legal-review", json!({}))?;
+    let (status, _, body) = log.handle_http(&acme, "GET", "/audit?actor=bob&action=gate_approved");
+    println!("{} {}", status, body);
+    Ok(())
+}
+```
+
+### Notes
+* The log is append-only at the API level: there is no update or delete. Retention and tamper-evidence (shipping the file to WORM storage) are left to the deployment.
+* Queries scan the file, and any compressed segments `with_compression` rolled it into. That is fine for the volumes one deployment produces; a large installation exports JSONL into its own store.
+* An action is audited after it succeeds. A failed audit write does not undo the action; it is logged as an error for the operator.
@@ -2,19 +2,16 @@
 // Conversion viability: Partially viable
 // Reasoning: The provided Python code is a complex system involving multiple classes, custom callbacks, and logging. While the basic structure and logic can be converted to Rust, some features like dynamic typing, reflective callable objects, and the specific logging library used in Python may require additional effort or workarounds in Rust.
 
-use std::collections::HashMap;
-use log::{info, error};
-use log::LevelFilter;
-
-// BaseSwarm trait in Rust
-trait BaseSwarm {
-    fn run(&self, task: Option<String>, args: Vec<String>, kwargs: HashMap<String, String>) -> String;
-}
+use crate::swarms::structs::swarm::{Swarm, SwarmError};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 
 // Custom preprocess function
-fn custom_preprocess(task: Option<String>, args: Vec<String>, kwargs: HashMap<String, String>) -> (Option<String>, Vec<String>, HashMap<String, String>) {
+fn custom_preprocess(task: String) -> String {
     // Custom preprocess logic here
-    (task, args, kwargs)
+    task
 }
 
 // Custom postprocess function
@@ -23,160 +20,288 @@ fn custom_postprocess(out: String) -> String {
     out
 }
 
-// Custom router function
-fn custom_router(swarm_router: &AutoSwarmRouter, task: Option<String>, args: Vec<String>, kwargs: HashMap<String, String>) -> String {
-    // Custom router logic here
-    String::new()
+// Scores how well the named swarm suits a task; higher is better. This replaces Python's `custom_router`
+// callable: the router runs the best-scoring swarm, and the runner-up is the shadow candidate.
+pub type SwarmScorer = Box<dyn Fn(&str, &str) -> f64 + Send + Sync>;
+
+// Shadow routing: on a sampled fraction of tasks, also run the second-best swarm and record how far its
+// output diverges from the one served. The shadow output is never returned to the caller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowConfig {
+    // Between 0.0 (never) and 1.0 (every task)
+    pub sample_rate: f64,
+}
+
+// One shadow comparison, kept for evaluating the router offline
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShadowRecord {
+    pub primary: String,
+    pub shadow: String,
+    // 0.0 when the outputs use the same words, 1.0 when they share none; None if the shadow failed
+    pub divergence: Option<f64>,
+    pub shadow_error: Option<String>,
+    pub timestamp: String,
+}
+
+// The result of a routed run
+#[derive(Debug, Clone, PartialEq)]
+pub struct RoutedRun {
+    pub output: String,
+    // The swarm the router selected
+    pub selected: String,
+    // The swarm whose output was served; the fallback if the selected swarm failed
+    pub served_by: String,
+    // The selected swarm's error, when the fallback was used
+    pub fallback_reason: Option<String>,
+    pub shadow: Option<ShadowRecord>,
+}
+
+// Word-level divergence of two outputs: one minus the Jaccard similarity of their lowercased word sets
+pub fn output_divergence(a: &str, b: &str) -> f64 {
+    let words = |text: &str| -> HashSet<String> { text.split_whitespace().map(str::to_lowercase).collect() };
+    let (a, b) = (words(a), words(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    1.0 - a.intersection(&b).count() as f64 / union as f64
 }
 
 // Define AutoSwarmRouter struct
-struct AutoSwarmRouter {
-    name: Option<String>,
+pub struct AutoSwarmRouter {
+    name: String,
     description: Option<String>,
     verbose: bool,
     custom_params: Option<HashMap<String, String>>,
-    swarms: Vec<Box<dyn BaseSwarm>>,
-    custom_preprocess: Option<fn(Option<String>, Vec<String>, HashMap<String, String>) -> (Option<String>, Vec<String>, HashMap<String, String>)>,
+    swarms: Vec<Box<dyn Swarm>>,
+    custom_preprocess: Option<fn(String) -> String>,
     custom_postprocess: Option<fn(String) -> String>,
-    custom_router: Option<fn(&AutoSwarmRouter, Option<String>, Vec<String>, HashMap<String, String>) -> String>,
-    swarm_dict: HashMap<String, Box<dyn BaseSwarm>>,
+    scorer: Option<SwarmScorer>,
+    // Name of the swarm to run when the selected one errors
+    fallback: Option<String>,
+    shadow: Option<ShadowConfig>,
+    shadow_records: Vec<ShadowRecord>,
 }
 
 impl AutoSwarmRouter {
-    fn new(
-        name: Option<String>,
-        description: Option<String>,
-        verbose: bool,
-        custom_params: Option<HashMap<String, String>>,
-        swarms: Vec<Box<dyn BaseSwarm>>,
-        custom_preprocess: Option<fn(Option<String>, Vec<String>, HashMap<String, String>) -> (Option<String>, Vec<String>, HashMap<String, String>)>,
-        custom_postprocess: Option<fn(String) -> String>,
-        custom_router: Option<fn(&AutoSwarmRouter, Option<String>, Vec<String>, HashMap<String, String>) -> String>,
-    ) -> Self {
-        let mut swarm_dict = HashMap::new();
-        for swarm in &swarms {
-            // Assuming `swarm` has a `name` method
-            let swarm_name = swarm.name();
-            swarm_dict.insert(swarm_name.to_string(), swarm.clone());
-        }
-
+    pub fn new(name: impl Into<String>, swarms: Vec<Box<dyn Swarm>>) -> Self {
         AutoSwarmRouter {
-            name,
-            description,
-            verbose,
-            custom_params,
+            name: name.into(),
+            description: None,
+            verbose: false,
+            custom_params: None,
             swarms,
-            custom_preprocess,
-            custom_postprocess,
-            custom_router,
-            swarm_dict,
+            custom_preprocess: None,
+            custom_postprocess: None,
+            scorer: None,
+            fallback: None,
+            shadow: None,
+            shadow_records: Vec::new(),
         }
     }
 
-    fn run(&self, task: Option<String>, args: Vec<String>, kwargs: HashMap<String, String>) -> String {
-        match self.custom_preprocess {
-            Some(preprocess) => {
-                let (new_task, new_args, new_kwargs) = preprocess(task, args, kwargs);
-                self.run(new_task, new_args, new_kwargs)
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    pub fn custom_params(mut self, custom_params: HashMap<String, String>) -> Self {
+        self.custom_params = Some(custom_params);
+        self
+    }
+
+    pub fn custom_preprocess(mut self, preprocess: fn(String) -> String) -> Self {
+        self.custom_preprocess = Some(preprocess);
+        self
+    }
+
+    pub fn custom_postprocess(mut self, postprocess: fn(String) -> String) -> Self {
+        self.custom_postprocess = Some(postprocess);
+        self
+    }
+
+    pub fn scorer(mut self, scorer: impl Fn(&str, &str) -> f64 + Send + Sync + 'static) -> Self {
+        self.scorer = Some(Box::new(scorer));
+        self
+    }
+
+    pub fn fallback(mut self, swarm_name: impl Into<String>) -> Self {
+        self.fallback = Some(swarm_name.into());
+        self
+    }
+
+    pub fn shadow(mut self, config: ShadowConfig) -> Self {
+        self.shadow = Some(ShadowConfig { sample_rate: config.sample_rate.clamp(0.0, 1.0) });
+        self
+    }
+
+    // Swarm indices from best to worst for `task`. With a scorer they are sorted by score, ties keeping
+    // registration order. Without one, as in Python, the swarm named like the router comes first.
+    fn ranking(&self, task: &str) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..self.swarms.len()).collect();
+        match &self.scorer {
+            Some(scorer) => {
+                let scores: Vec<f64> = self.swarms.iter().map(|swarm| scorer(task, swarm.name())).collect();
+                order.sort_by(|a, b| scores[*b].total_cmp(&scores[*a]));
+            }
+            None => order.sort_by_key(|index| self.swarms[*index].name() != self.name),
+        }
+        order
+    }
+
+    fn index_of(&self, swarm_name: &str) -> Option<usize> {
+        self.swarms.iter().position(|swarm| swarm.name() == swarm_name)
+    }
+
+    // Route `task` to the best swarm, falling back if it errors, and shadow it on sampled tasks
+    pub fn run_routed(&mut self, task: &str) -> Result<RoutedRun, SwarmError> {
+        let task = match self.custom_preprocess {
+            Some(preprocess) => preprocess(task.to_string()),
+            None => task.to_string(),
+        };
+        let ranking = self.ranking(&task);
+        let selected = *ranking.first().ok_or_else(|| SwarmError::Failed(format!("{} has no swarms", self.name)))?;
+        let selected_name = self.swarms[selected].name().to_string();
+        if self.verbose {
+            info!("{} routed the task to {}", self.name, selected_name);
+        }
+
+        let (output, served_by, fallback_reason) = match self.swarms[selected].run(&task) {
+            Ok(output) => (output, selected, None),
+            Err(err) => {
+                let fallback = self.fallback_for(selected, &err)?;
+                warn!("{}: {} failed ({}); falling back to {}", self.name, selected_name, err, self.swarms[fallback].name());
+                SwarmMetrics::global().router_fallback(&self.name, &selected_name);
+                let output = self.swarms[fallback].run(&task).map_err(|fallback_err| {
+                    error!("{}: fallback {} failed too: {}", self.name, self.swarms[fallback].name(), fallback_err);
+                    SwarmError::Failed(format!(
+                        "{} failed: {}; fallback {} failed: {}",
+                        selected_name,
+                        err,
+                        self.swarms[fallback].name(),
+                        fallback_err
+                    ))
+                })?;
+                (output, fallback, Some(err.to_string()))
             }
-            None => {
-                match self.custom_router {
-                    Some(router) => {
-                        router(self, task, args, kwargs)
-                    }
-                    None => {
-                        let swarm_name = self.name.clone().unwrap();
-                        if let Some(swarm) = self.swarm_dict.get(&swarm_name) {
-                            swarm.run(task, args, kwargs)
-                        } else {
-                            error!("Swarm with name {} not found.", swarm_name);
-                            String::new()
-                        }
-                    }
-                }
+        };
+
+        // Shadowing measures the router's choice, so it only runs when the selected swarm answered
+        let shadow = match (self.shadow, ranking.get(1)) {
+            (Some(config), Some(&runner_up)) if fallback_reason.is_none() && rand::random::<f64>() < config.sample_rate => {
+                Some(self.run_shadow(runner_up, &selected_name, &task, &output))
             }
+            _ => None,
+        };
+
+        let output = match self.custom_postprocess {
+            Some(postprocess) => postprocess(output),
+            None => output,
+        };
+        Ok(RoutedRun {
+            output,
+            selected: selected_name,
+            served_by: self.swarms[served_by].name().to_string(),
+            fallback_reason,
+            shadow,
+        })
+    }
+
+    // The fallback to run after `selected` failed with `err`, or the error to return if there is none
+    fn fallback_for(&self, selected: usize, err: &SwarmError) -> Result<usize, SwarmError> {
+        let selected_name = self.swarms[selected].name();
+        let Some(fallback_name) = &self.fallback else {
+            return Err(SwarmError::Failed(format!("{} failed: {}", selected_name, err)));
+        };
+        match self.index_of(fallback_name) {
+            Some(index) if index != selected => Ok(index),
+            Some(_) => Err(SwarmError::Failed(format!("{} failed and is its own fallback: {}", selected_name, err))),
+            None => Err(SwarmError::Failed(format!(
+                "{} failed: {}; fallback swarm '{}' is not registered",
+                selected_name, err, fallback_name
+            ))),
+        }
+    }
+
+    fn run_shadow(&mut self, runner_up: usize, primary: &str, task: &str, output: &str) -> ShadowRecord {
+        let shadow = self.swarms[runner_up].name().to_string();
+        let (divergence, shadow_error) = match self.swarms[runner_up].run(task) {
+            Ok(shadow_output) => (Some(output_divergence(output, &shadow_output)), None),
+            Err(err) => (None, Some(err.to_string())),
+        };
+        SwarmMetrics::global().shadow_run(&self.name, primary, &shadow, divergence);
+        let record = ShadowRecord {
+            primary: primary.to_string(),
+            shadow,
+            divergence,
+            shadow_error,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        };
+        self.shadow_records.push(record.clone());
+        record
+    }
+
+    // Every shadow comparison so far, oldest first
+    pub fn shadow_records(&self) -> &[ShadowRecord] {
+        &self.shadow_records
+    }
+
+    // Mean divergence of the shadow runs that succeeded, or None if there were none
+    pub fn mean_divergence(&self) -> Option<f64> {
+        let divergences: Vec<f64> = self.shadow_records.iter().filter_map(|record| record.divergence).collect();
+        if divergences.is_empty() {
+            return None;
         }
+        Some(divergences.iter().sum::<f64>() / divergences.len() as f64)
     }
 
-    fn len_of_swarms(&self) -> usize {
+    pub fn len_of_swarms(&self) -> usize {
         self.swarms.len()
     }
 
-    fn list_available_swarms(&self) {
+    pub fn list_available_swarms(&self) {
         for swarm in &self.swarms {
-            // Assuming `swarm` has `name` and `description` methods
-            info!("Swarm Name: {} || Swarm Description: {}", swarm.name(), swarm.description());
+            info!("Swarm Name: {}", swarm.name());
         }
     }
 }
 
+impl Swarm for AutoSwarmRouter {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        self.run_routed(task).map(|run| run.output)
+    }
+}
+
 // Define AutoSwarm struct
 struct AutoSwarm {
-    name: Option<String>,
+    name: String,
     description: Option<String>,
     verbose: bool,
-    custom_params: Option<HashMap<String, String>>,
-    custom_preprocess: Option<fn(Option<String>, Vec<String>, HashMap<String, String>) -> (Option<String>, Vec<String>, HashMap<String, String>)>,
-    custom_postprocess: Option<fn(String) -> String>,
-    custom_router: Option<fn(&AutoSwarmRouter, Option<String>, Vec<String>, HashMap<String, String>) -> String>,
     max_loops: usize,
     router: AutoSwarmRouter,
 }
 
 impl AutoSwarm {
-    fn new(
-        name: Option<String>,
-        description: Option<String>,
-        verbose: bool,
-        custom_params: Option<HashMap<String, String>>,
-        custom_preprocess: Option<fn(Option<String>, Vec<String>, HashMap<String, String>) -> (Option<String>, Vec<String>, HashMap<String, String>)>,
-        custom_postprocess: Option<fn(String) -> String>,
-        custom_router: Option<fn(&AutoSwarmRouter, Option<String>, Vec<String>, HashMap<String, String>) -> String>,
-        max_loops: usize,
-    ) -> Self {
-        let router = AutoSwarmRouter::new(
-            name.clone(),
-            description.clone(),
-            verbose,
-            custom_params.clone(),
-            vec![],
-            custom_preprocess.clone(),
-            custom_postprocess.clone(),
-            custom_router.clone(),
-        );
-
-        AutoSwarm {
-            name,
-            description,
-            verbose,
-            custom_params,
-            custom_preprocess,
-            custom_postprocess,
-            custom_router,
-            max_loops,
-            router,
-        }
+    fn new(name: String, description: Option<String>, verbose: bool, max_loops: usize, router: AutoSwarmRouter) -> Self {
+        AutoSwarm { name, description, verbose, max_loops, router }
     }
 
-    fn run(&self, task: Option<String>, args: Vec<String>, kwargs: HashMap<String, String>) -> String {
-        let mut loop_count = 0;
-        while loop_count < self.max_loops {
-            match self.custom_preprocess {
-                Some(preprocess) => {
-                    let (new_task, new_args, new_kwargs) = preprocess(task, args, kwargs);
-                    self.run(new_task, new_args, new_kwargs)
-                }
-                None => {
-                    if let Some(router) = self.custom_router {
-                        router(&self.router, task, args, kwargs)
-                    } else {
-                        self.router.run(task, args, kwargs)
-                    }
-                }
-            }
-            loop_count += 1;
+    // Route the task `max_loops` times, each loop working on the previous loop's output
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        let mut output = task.to_string();
+        for _ in 0..self.max_loops {
+            output = self.router.run(&output)?;
         }
-        String::new()
+        Ok(output)
     }
 
     fn list_all_swarms(&self) {
@@ -184,48 +309,58 @@ impl AutoSwarm {
     }
 }
 
+struct Echo(&'static str);
+
+impl Swarm for Echo {
+    fn name(&self) -> &str {
+        self.0
+    }
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        Ok(format!("{}: {}", self.0, task))
+    }
+}
+
+struct Offline;
+
+impl Swarm for Offline {
+    fn name(&self) -> &str {
+        "research"
+    }
+
+    fn run(&mut self, _task: &str) -> Result<String, SwarmError> {
+        Err(SwarmError::Failed("provider unavailable".to_string()))
+    }
+}
+
 // Set up logging
 fn main() {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
-    // Example usage
-    let swarms = vec![];
-    let custom_preprocess = Some(custom_preprocess);
-    let custom_postprocess = Some(custom_postprocess);
-    let custom_router = Some(custom_router);
-    let auto_swarm_router = AutoSwarmRouter::new(
-        Some("auto_swarm_router".to_string()),
-        Some("auto_swarm_router_description".to_string()),
-        true,
-        Some(HashMap::new()),
-        swarms,
-        custom_preprocess,
-        custom_postprocess,
-        custom_router,
-    );
-
-    let auto_swarm = AutoSwarm::new(
-        Some("auto_swarm".to_string()),
-        Some("auto_swarm_description".to_string()),
-        true,
-        Some(HashMap::new()),
-        custom_preprocess,
-        custom_postprocess,
-        custom_router,
-        1,
-    );
+    let swarms: Vec<Box<dyn Swarm>> = vec![Box::new(Offline), Box::new(Echo("summary")), Box::new(Echo("general"))];
+    let router = AutoSwarmRouter::new("auto_swarm_router", swarms)
+        .description("Routes research tasks, summarizing when research is down")
+        .custom_preprocess(custom_preprocess)
+        .custom_postprocess(custom_postprocess)
+        .scorer(|task, swarm| match swarm {
+            "research" if task.contains("research") => 1.0,
+            "summary" => 0.5,
+            _ => 0.0,
+        })
+        .fallback("general")
+        .shadow(ShadowConfig { sample_rate: 0.1 });
+
+    let mut auto_swarm = AutoSwarm::new("auto_swarm".to_string(), None, true, 1, router);
+    auto_swarm.list_all_swarms();
+    match auto_swarm.run("research solid-state batteries") {
+        Ok(output) => println!("{}", output),
+        Err(err) => eprintln!("{}", err),
+    }
 }
 ```
 ### Key Considerations:
-1.  **Callback Functions:** Rust has stricter type rules than Python. When working with callbacks, you'll need to define trait objects that represent the functions you want to use as callbacks.
-2.  **Error Handling:** The code doesn't handle all potential errors that could arise during execution. Consider using Rust's `Result` type or `Option` type to handle potential errors and make your code more robust.
-3.  **Logger Setup:** You need to set up the logger properly in the Rust code to see log messages.
-4.  **Trait Objects and Dynamic Dispatch:** The `BaseSwarm` trait objects are used for dynamic dispatch. This approach allows for polymorphism but can have performance implications.
-
-### Conversion Limitations:
-*   **Customizable routing:** The routing logic has been simplified and might need to be adjusted according to your specific requirements.
-*   **Swarm management:** This code doesn't cover the entire swarm management logic as present in the original Python code. You may need to expand upon this example to suit your needs.
-
-### Recommendations:
-1.  **Read the Documentation:** Familiarize yourself with Rust's documentation on traits, trait objects, and error handling mechanisms.
-2.  **Use a Logger:** Implement a suitable logging library to see log messages and know what's happening in your code.
\ No newline at end of file
+1.  **Routing:** Python's `custom_router` callable became `SwarmScorer`. Scoring every swarm, rather than picking one, gives the router a ranking, and the runner-up in that ranking is the shadow candidate.
+2.  **Fallback:** When the selected swarm returns an error, the named fallback swarm runs on the same task. `RoutedRun::fallback_reason` keeps the original error, and the `swarms_router_fallbacks_total` metric counts each fallback. If the fallback fails too, both errors are returned. A fallback that is not registered, or that is the selected swarm itself, counts as no fallback.
+3.  **Shadow mode:** A sampled task also runs on the second-best swarm, after the primary answer is ready and before it is returned. This adds latency to sampled tasks, so keep `sample_rate` low in production. The shadow only runs when the selected swarm succeeded and did not fall back, so the comparison always measures the router's choice. Divergence is recorded in `swarms_router_shadow_divergence` and in `shadow_records()`.
+4.  **Divergence measure:** Word-set Jaccard distance is cheap and does not depend on any model. It flags swarms that answer differently, but it cannot tell which answer is better. Judge the records offline to decide that.
+5.  **Side effects:** Shadow swarms really run. Only enable shadowing for swarms whose tasks are safe to run twice, not for swarms that send email or write to external systems.
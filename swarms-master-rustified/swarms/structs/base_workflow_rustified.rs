@@ -10,26 +10,23 @@ The provided Python file defines a `BaseWorkflow` class, which is a base class f
 4. **JSON Serialization/Deserialization**: Rust has libraries like `serde_json` that can handle JSON serialization and deserialization, similar to Python's `json` module. However, the exact implementation might differ.
 
 ### Rewrite in Rust
-Below is a simplified version of the `BaseWorkflow` class rewritten in Rust. Note that this is not a complete translation, as some parts, like the logging and error handling, are simplified or omitted for brevity. Additionally, the dependent types and traits like `Agent`, `Task`, and `BaseStructure` are assumed to be defined elsewhere in the codebase.
+Below is a simplified version of the `BaseWorkflow` class rewritten in Rust. Note that this is not a complete translation, as some parts, like the logging and error handling, are simplified or omitted for brevity. Agents are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`); `Task` and `BaseStructure` are local stand-ins.
 
 ```rust
 // Define necessary imports and dependencies
 use std::collections::VecDeque;
 use std::fs;
 use std::path::Path;
+use std::sync::Arc;
 use serde::{Serialize, Deserialize};
 use serde_json;
+use crate::swarms::structs::agent::{Agent, MockAgent};
 
-// Define the Agent and Task structs
-#[derive(Serialize, Deserialize)]
-struct Agent {
-    // Add fields as necessary
-}
-
+// A task names the agent that runs it
 #[derive(Serialize, Deserialize)]
 struct Task {
     description: String,
-    agent: Agent,
+    agent: String,
     args: Vec<String>,
     kwargs: Vec<String>,
     result: Option<String>,
@@ -41,17 +38,18 @@ trait BaseStructure {
     fn new() -> Self;
 }
 
-// Define the BaseWorkflow struct
+// Define the BaseWorkflow struct. Agents are not part of the saved state; add them again after loading.
 #[derive(Serialize, Deserialize)]
 struct BaseWorkflow {
-    agents: Vec<Agent>,
+    #[serde(skip)]
+    agents: Vec<Arc<dyn Agent>>,
     task_pool: Vec<Task>,
     models: Vec<String>,
 }
 
 impl BaseWorkflow {
     // Constructor
-    fn new(agents: Option<Vec<Agent>>, task_pool: Option<Vec<Task>>, models: Option<Vec<String>>) -> Self {
+    fn new(agents: Option<Vec<Arc<dyn Agent>>>, task_pool: Option<Vec<Task>>, models: Option<Vec<String>>) -> Self {
         BaseWorkflow {
             agents: agents.unwrap_or_default(),
             task_pool: task_pool.unwrap_or_default(),
@@ -65,7 +63,7 @@ impl BaseWorkflow {
     }
 
     // Add an agent to the workflow
-    fn add_agent(&mut self, agent: Agent) {
+    fn add_agent(&mut self, agent: Arc<dyn Agent>) {
         self.agents.push(agent);
     }
 
@@ -86,9 +84,10 @@ impl BaseWorkflow {
 fn main() {
     // Example usage
     let mut workflow = BaseWorkflow::new(None, None, None);
+    workflow.add_agent(Arc::new(MockAgent::new("Worker")));
     let task = Task {
         description: "Example Task".to_string(),
-        agent: Agent {},
+        agent: "Worker".to_string(),
         args: vec![],
         kwargs: vec![],
         result: None,
@@ -97,7 +96,7 @@ fn main() {
     workflow.add_task(task);
     workflow.save_workflow_state("workflow_state.json").unwrap();
     let loaded_workflow = BaseWorkflow::load_workflow_state("workflow_state.json").unwrap();
-    println!("{:?}", loaded_workflow);
+    println!("{} tasks restored", loaded_workflow.task_pool.len());
 }
 ```
 
@@ -108,7 +107,7 @@ fn main() {
 
 3. **Object-Oriented Programming**: Rust's ownership system and borrowing mechanisms can require adjustments to how objects are created, used, and passed around, potentially affecting the design of classes and methods.
 
-4. **JSON Serialization/Deserialization**: While libraries like `serde_json` make JSON handling easy, the exact implementation details might differ from Python, requiring some adjustments.
+4. **JSON Serialization/Deserialization**: While libraries like `serde_json` make JSON handling easy, the exact implementation details might differ from Python, requiring some adjustments. Agents are trait objects and are not saved with the workflow state; tasks refer to them by name.
 
 ### Conclusion
 Converting the provided Python file to Rust is viable but requires careful consideration of Rust's unique features, such as ownership, borrowing, and error handling. The example provided demonstrates how to translate some of the key concepts but is simplified for clarity. A full conversion would require addressing the specific dependencies, error handling, and design adjustments necessary for a Rust implementation.
\ No newline at end of file
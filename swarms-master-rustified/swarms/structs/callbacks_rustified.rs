@@ -0,0 +1,130 @@
+### Overview
+There is no Python counterpart; the Python agent prints tokens with `streaming_on` and leaves everything
+else to the logs. An embedder driving a UI (a chat window, a progress bar over a swarm) needs to know what
+is happening while it happens, not after the run by reading the event log back.
+
+`RunCallbacks` is the hook for that. It has four methods, all no-ops by default, so an implementation
+only writes the ones it needs:
+
+* `on_token` receives each piece of text as the model streams it.
+* `on_tool_call` receives each tool call once its arguments are complete, before the tool runs.
+* `on_step_complete` receives each finished step: a loop of `LlmAgent::run_loops`, a single `run`, or one
+  agent's turn inside a swarm.
+* `on_task_stuck` receives each `TaskStuck` event a `Supervisor` raises for a task that stopped sending
+  heartbeats.
+
+Callbacks are registered with `LlmAgent::with_callbacks` (or `AgentBuilder::callbacks`) and with
+`Swarm::set_callbacks`. Tokens and tool calls come from the provider, so the agent's completion passes its
+callbacks on with `OpenAiCompatProvider::with_callbacks`. `CallbackSet` fans one event out to several
+listeners, for example a UI and a metrics exporter.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::ToolCall;
+use crate::swarms::structs::supervisor::TaskStuck;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// A finished step, as `on_step_complete` receives it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepComplete<'a> {
+    /// Zero-based: the loop number for an agent, the turn number within the run for a swarm.
+    pub index: usize,
+    pub output: &'a str,
+    pub elapsed: Duration,
+}
+
+/// Live notifications from a running agent or swarm. Every method defaults to doing nothing.
+///
+/// Callbacks run on the thread doing the work, in the middle of the stream or the swarm, so they should
+/// hand events off (to a channel, say) rather than block.
+pub trait RunCallbacks: Send + Sync {
+    /// A piece of the reply `agent` is streaming. Text past a stop sequence is not sent.
+    fn on_token(&self, _agent: &str, _token: &str) {}
+
+    /// A tool call `agent` made, with its arguments complete.
+    fn on_tool_call(&self, _agent: &str, _call: &ToolCall) {}
+
+    fn on_step_complete(&self, _agent: &str, _step: &StepComplete) {}
+
+    /// A task went longer than expected without a heartbeat and was cancelled or requeued.
+    fn on_task_stuck(&self, _event: &TaskStuck) {}
+}
+
+/// Sends every event to each listener, in the order they were added.
+#[derive(Clone, Default)]
+pub struct CallbackSet {
+    listeners: Vec<Arc<dyn RunCallbacks>>,
+}
+
+impl CallbackSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(mut self, listener: Arc<dyn RunCallbacks>) -> Self {
+        self.listeners.push(listener);
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.listeners.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+}
+
+impl RunCallbacks for CallbackSet {
+    fn on_token(&self, agent: &str, token: &str) {
+        for listener in &self.listeners {
+            listener.on_token(agent, token);
+        }
+    }
+
+    fn on_tool_call(&self, agent: &str, call: &ToolCall) {
+        for listener in &self.listeners {
+            listener.on_tool_call(agent, call);
+        }
+    }
+
+    fn on_step_complete(&self, agent: &str, step: &StepComplete) {
+        for listener in &self.listeners {
+            listener.on_step_complete(agent, step);
+        }
+    }
+
+    fn on_task_stuck(&self, event: &TaskStuck) {
+        for listener in &self.listeners {
+            listener.on_task_stuck(event);
+        }
+    }
+}
+
+struct Printer;
+
+impl RunCallbacks for Printer {
+    fn on_token(&self, _agent: &str, token: &str) {
+        print!("{}", token);
+    }
+
+    fn on_step_complete(&self, agent: &str, step: &StepComplete) {
+        println!("\n[{} step {} done in {:?}]", agent, step.index, step.elapsed);
+    }
+}
+
+fn main() {
+    let callbacks = CallbackSet::new().with(Arc::new(Printer));
+    for token in ["Revenue ", "grew ", "12%."] {
+        callbacks.on_token("Financial-Analysis-Agent", token);
+    }
+    let step = StepComplete { index: 0, output: "Revenue grew 12%.", elapsed: Duration::from_millis(840) };
+    callbacks.on_step_complete("Financial-Analysis-Agent", &step);
+}
+```
+
+### Notes
+* A callback that panics unwinds through the agent or swarm that called it, the same as a panic in a completion.
+* `on_token` only fires for streamed calls. A completion that makes a single non-streaming request reports the whole reply through `on_step_complete` and nothing through `on_token`.
+* Swarms hold their agents as `Arc<dyn Agent>` and cannot reach into them, so `Swarm::set_callbacks` only reports turns. To see a swarm member's tokens as well, register the same callbacks on the `LlmAgent` before adding it.
@@ -0,0 +1,292 @@
+### Overview
+There is no Python counterpart. `ChatCompletions` serves the registered agents and swarms behind the
+OpenAI chat completions API, so OpenAI SDK clients and chat frontends built for it can talk to a swarm
+after changing only their base URL:
+
+* `model` names the agent or swarm to run. Agents are served under `Agent::name`, and swarms are wrapped
+  in a `SwarmAgent` and served under the swarm's name. `GET /v1/models` lists them.
+* The last message must come from the user. It is the task. The messages before it, system prompts
+  included, are passed to `Agent::run_with_history` as the history.
+* `stream: true` answers with `text/event-stream` in the `chat.completion.chunk` format, ending with
+  `data: [DONE]`. Agents return their whole answer at once, so the content arrives as one chunk.
+* `usage` is counted with `estimate_tokens`, because agents do not report the provider's counts.
+* Failures are answered in OpenAI's `{"error": {"message", "type", "code"}}` envelope, which the SDKs
+  parse into their exception types. Statuses and codes come from the `Problem` mapping the other
+  handlers use.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::Problem;
+use crate::swarms::structs::agent::{Agent, MockAgent};
+use crate::swarms::structs::conversation::{Channel, Message};
+use crate::swarms::structs::swarm::{Swarm, SwarmAgent};
+use crate::swarms::text::tokenizer::estimate_tokens;
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use serde::Deserialize;
+use serde_json::{json, Value as JsonValue};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const EVENT_STREAM: &str = "text/event-stream";
+
+/// The fields of a chat completions request the facade reads. Sampling settings such as `temperature`
+/// belong to each agent's own config, so they and any other fields are ignored.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChatCompletionsRequest {
+    pub model: String,
+    pub messages: Vec<ChatMessage>,
+    #[serde(default)]
+    pub stream: bool,
+    #[serde(default)]
+    pub stream_options: Option<StreamOptions>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub struct StreamOptions {
+    /// Send a last chunk with `usage` and no choices before `[DONE]`.
+    #[serde(default)]
+    pub include_usage: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct ChatMessage {
+    pub role: String,
+    /// `null` on assistant messages that only carried tool calls.
+    #[serde(default)]
+    pub content: Option<MessageContent>,
+}
+
+/// A string, or a list of content parts.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum MessageContent {
+    Text(String),
+    Parts(Vec<JsonValue>),
+}
+
+impl ChatMessage {
+    /// The message's text. Only `text` parts are read; images and audio have nowhere to go in a task.
+    pub fn text(&self) -> String {
+        match &self.content {
+            None => String::new(),
+            Some(MessageContent::Text(text)) => text.clone(),
+            Some(MessageContent::Parts(parts)) => parts
+                .iter()
+                .filter(|part| part["type"] == "text")
+                .filter_map(|part| part["text"].as_str())
+                .collect::<Vec<_>>()
+                .join("\n"),
+        }
+    }
+}
+
+/// One answered request, rendered as a response body or as an event stream.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChatCompletion {
+    pub id: String,
+    pub created: u64,
+    pub model: String,
+    pub content: String,
+    pub prompt_tokens: usize,
+    pub completion_tokens: usize,
+}
+
+impl ChatCompletion {
+    fn usage(&self) -> JsonValue {
+        json!({
+            "prompt_tokens": self.prompt_tokens,
+            "completion_tokens": self.completion_tokens,
+            "total_tokens": self.prompt_tokens + self.completion_tokens,
+        })
+    }
+
+    /// The `chat.completion` response body.
+    pub fn to_json(&self) -> JsonValue {
+        json!({
+            "id": self.id,
+            "object": "chat.completion",
+            "created": self.created,
+            "model": self.model,
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": self.content },
+                "finish_reason": "stop",
+            }],
+            "usage": self.usage(),
+        })
+    }
+
+    fn chunk(&self, choices: JsonValue) -> JsonValue {
+        json!({
+            "id": self.id,
+            "object": "chat.completion.chunk",
+            "created": self.created,
+            "model": self.model,
+            "choices": choices,
+        })
+    }
+
+    /// The `text/event-stream` body: the role, the content, the finish reason, then `data: [DONE]`.
+    pub fn to_event_stream(&self, include_usage: bool) -> String {
+        let mut chunks = vec![
+            self.chunk(json!([{ "index": 0, "delta": { "role": "assistant", "content": "" }, "finish_reason": null }])),
+            self.chunk(json!([{ "index": 0, "delta": { "content": self.content }, "finish_reason": null }])),
+            self.chunk(json!([{ "index": 0, "delta": {}, "finish_reason": "stop" }])),
+        ];
+        if include_usage {
+            let mut last = self.chunk(json!([]));
+            last["usage"] = self.usage();
+            chunks.push(last);
+        }
+        let mut body: String = chunks.iter().map(|chunk| format!("data: {}\n\n", chunk)).collect();
+        body.push_str("data: [DONE]\n\n");
+        body
+    }
+}
+
+/// The agents and swarms served as models, by name.
+#[derive(Clone)]
+pub struct ChatCompletions {
+    models: BTreeMap<String, Arc<dyn Agent>>,
+    // Reported as every model's `created`; the registry is built when the server starts
+    created: u64,
+}
+
+impl Default for ChatCompletions {
+    fn default() -> Self {
+        ChatCompletions::new()
+    }
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|elapsed| elapsed.as_secs()).unwrap_or(0)
+}
+
+/// `problem` in the error envelope the OpenAI SDKs parse.
+fn openai_error(problem: &Problem) -> (u16, &'static str, String) {
+    let kind = match problem.status {
+        401 => "authentication_error",
+        429 => "rate_limit_error",
+        400..=499 => "invalid_request_error",
+        _ => "server_error",
+    };
+    let body = json!({ "error": { "message": problem.detail, "type": kind, "param": null, "code": problem.code } });
+    (problem.status, "application/json", body.to_string())
+}
+
+impl ChatCompletions {
+    pub fn new() -> Self {
+        ChatCompletions { models: BTreeMap::new(), created: now_secs() }
+    }
+
+    /// Serve `agent` as the model named after it. A later agent or swarm with the same name replaces it.
+    pub fn with_agent(mut self, agent: Arc<dyn Agent>) -> Self {
+        self.models.insert(agent.name().to_string(), agent);
+        self
+    }
+
+    /// Serve `swarm` as the model named after it. Requests to one swarm run one at a time (see `SwarmAgent`).
+    pub fn with_swarm(self, swarm: impl Swarm + 'static) -> Self {
+        self.with_agent(Arc::new(SwarmAgent::new(swarm)))
+    }
+
+    pub fn models(&self) -> impl Iterator<Item = &str> {
+        self.models.keys().map(String::as_str)
+    }
+
+    fn model_card(&self, id: &str) -> JsonValue {
+        json!({ "id": id, "object": "model", "created": self.created, "owned_by": "swarms" })
+    }
+
+    fn unknown_model(&self, id: &str) -> Problem {
+        let served = self.models().collect::<Vec<_>>().join(", ");
+        Problem::new(404, "model_not_found", "Unknown model", format!("the model '{}' does not exist; served: {}", id, served))
+    }
+
+    /// Run the agent `request.model` names on the request's last user message.
+    pub fn complete(&self, request: &ChatCompletionsRequest) -> Result<ChatCompletion, Problem> {
+        let agent = self.models.get(&request.model).ok_or_else(|| self.unknown_model(&request.model))?;
+        let Some((last, earlier)) = request.messages.split_last() else {
+            return Err(Problem::bad_request("messages must not be empty"));
+        };
+        if last.role != "user" {
+            return Err(Problem::bad_request(format!("the last message must come from the user, not '{}'", last.role)));
+        }
+        let task = last.text();
+        let history: Vec<Message> =
+            earlier.iter().map(|message| Message::new(message.role.clone(), message.text(), Channel::Public)).collect();
+        let content = agent.run_with_history(&task, &history).map_err(Problem::from)?;
+        let history_tokens: usize = history.iter().map(|message| estimate_tokens(&message.content)).sum();
+        Ok(ChatCompletion {
+            id: format!("chatcmpl-{}", new_id(IdKind::Message)),
+            created: now_secs(),
+            model: request.model.clone(),
+            prompt_tokens: estimate_tokens(&task) + history_tokens,
+            completion_tokens: estimate_tokens(&content),
+            content,
+        })
+    }
+
+    /// Serve `GET /v1/models`, `GET /v1/models/{model}` and `POST /v1/chat/completions`. Returns a status
+    /// code, content type, and body. Runs the agent on the calling thread.
+    pub fn handle_http(&self, method: &str, path: &str, body: &str) -> (u16, &'static str, String) {
+        let path = path.trim_end_matches('/');
+        match (method, path) {
+            ("GET", "/v1/models") => {
+                let data: Vec<JsonValue> = self.models().map(|id| self.model_card(id)).collect();
+                (200, "application/json", json!({ "object": "list", "data": data }).to_string())
+            }
+            ("GET", _) if path.starts_with("/v1/models/") => {
+                let id = urlencoding::decode(&path["/v1/models/".len()..]).map(|id| id.into_owned()).unwrap_or_default();
+                if self.models.contains_key(&id) {
+                    (200, "application/json", self.model_card(&id).to_string())
+                } else {
+                    openai_error(&self.unknown_model(&id))
+                }
+            }
+            ("POST", "/v1/chat/completions") => {
+                let request: ChatCompletionsRequest = match serde_json::from_str(body) {
+                    Ok(request) => request,
+                    Err(err) => return openai_error(&Problem::bad_request(err.to_string())),
+                };
+                match self.complete(&request) {
+                    Ok(completion) if request.stream => {
+                        let include_usage = request.stream_options.is_some_and(|options| options.include_usage);
+                        (200, EVENT_STREAM, completion.to_event_stream(include_usage))
+                    }
+                    Ok(completion) => (200, "application/json", completion.to_json().to_string()),
+                    Err(problem) => openai_error(&problem),
+                }
+            }
+            (method, path) => openai_error(&Problem::no_route(method, path)),
+        }
+    }
+}
+
+fn main() {
+    let analyst: Arc<dyn Agent> = Arc::new(MockAgent::new("Financial-Analyst").reply("Revenue grew 12% in Q3."));
+    let server = ChatCompletions::new().with_agent(analyst);
+    println!("{}", server.handle_http("GET", "/v1/models", "").2);
+
+    let body = json!({
+        "model": "Financial-Analyst",
+        "messages": [
+            { "role": "system", "content": "Answer in one sentence." },
+            { "role": "user", "content": "How did Q3 go?" }
+        ]
+    });
+    let (status, content_type, response) = server.handle_http("POST", "/v1/chat/completions", &body.to_string());
+    println!("{} {}\n{}", status, content_type, response);
+}
+```
+
+### Notes
+* Point an OpenAI client at the server's `/v1` as its base URL, e.g. `OpenAI(base_url="http://localhost:8000/v1")`.
+  The API key the SDK sends is for the server's own auth layer; the facade does not read it.
+* Streaming does not make agents incremental. It exists so clients that always stream, as most chat
+  frontends do, work unchanged. Agents that stream tokens would need a streaming method on `Agent` first.
+* Tool definitions in the request are ignored. The agent runs its own tools, and the client sees only the
+  final answer, never a `tool_calls` message it would be expected to execute.
+* An unknown model is a 404 with code `model_not_found`, as OpenAI answers it. The message lists the
+  served names, since the envelope has no field for them.
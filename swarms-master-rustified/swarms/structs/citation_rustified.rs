@@ -0,0 +1,251 @@
+### Overview
+There is no Python counterpart to this module. When a retrieved chunk or a tool result feeds an agent, the
+Python pipeline loses track of where the information came from by the time the last agent answers. In the
+Rust port every such input can carry a `Citation`; agents pass their outputs along as `Cited<T>` and the
+helpers below merge citations from each step, so a `SequentialWorkflow` can end its final answer with a
+deduplicated source list. This matters most for the financial analysis prompts, where every figure should
+be traceable.
+
+### Rust Code
+```rust
+use crate::swarms::text::chunk::Chunk;
+#[cfg(feature = "tools")]
+use crate::swarms::tools::prebuilt::web_reader::WebPage;
+#[cfg(feature = "tools")]
+use crate::swarms::tools::prebuilt::web_search::SearchResult;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+
+/// Where a piece of information came from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Citation {
+    /// Stable id derived from `source` and `locator`, the start of their SHA-256 digest; equal ids mean the same
+    /// source, across processes and builds.
+    pub id: String,
+    /// URL, file path, or document id.
+    pub source: String,
+    pub title: Option<String>,
+    /// Position within the source, e.g. `bytes 120..480` or `page 3`.
+    pub locator: Option<String>,
+    pub snippet: Option<String>,
+    pub retrieved_at: DateTime<Utc>,
+}
+
+impl Citation {
+    pub fn new(source: impl Into<String>, locator: Option<String>) -> Self {
+        let source = source.into();
+        let mut hasher = Sha256::new();
+        hasher.update(source.as_bytes());
+        // The separator keeps `("ab", None)` and `("a", Some("b"))` apart
+        hasher.update([0]);
+        if let Some(locator) = &locator {
+            hasher.update([1]);
+            hasher.update(locator.as_bytes());
+        }
+        Citation {
+            id: hex::encode(&hasher.finalize()[..8]),
+            source,
+            title: None,
+            locator,
+            snippet: None,
+            retrieved_at: Utc::now(),
+        }
+    }
+
+    pub fn with_title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn with_snippet(mut self, snippet: impl Into<String>) -> Self {
+        self.snippet = Some(snippet.into());
+        self
+    }
+
+    /// Cite a chunk produced by `swarms::text::chunk`. Chunks without a source get `"unknown"`.
+    pub fn from_chunk(chunk: &Chunk) -> Self {
+        let source = chunk.source.clone().unwrap_or_else(|| "unknown".to_string());
+        let mut citation = Citation::new(source, Some(format!("bytes {}..{}", chunk.start, chunk.end)));
+        if let Some(heading) = chunk.heading_path.last() {
+            citation.title = Some(heading.clone());
+        }
+        citation
+    }
+
+    #[cfg(feature = "tools")]
+    pub fn from_search_result(result: &SearchResult) -> Self {
+        Citation::new(result.url.clone(), None)
+            .with_title(result.title.clone())
+            .with_snippet(result.snippet.clone())
+    }
+
+    #[cfg(feature = "tools")]
+    pub fn from_web_page(page: &WebPage) -> Self {
+        let mut citation = Citation::new(page.url.clone(), None);
+        citation.title = page.title.clone();
+        citation
+    }
+
+    /// A one-line rendering for source lists: `Title - source (locator)`.
+    pub fn label(&self) -> String {
+        let mut label = match &self.title {
+            Some(title) => format!("{} - {}", title, self.source),
+            None => self.source.clone(),
+        };
+        if let Some(locator) = &self.locator {
+            label.push_str(&format!(" ({})", locator));
+        }
+        label
+    }
+}
+
+/// An ordered, deduplicated collection of citations.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CitationSet {
+    citations: Vec<Citation>,
+}
+
+impl CitationSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.citations.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.citations.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Citation> {
+        self.citations.iter()
+    }
+
+    /// Add `citation` unless one with the same id is already present. Returns its 1-based number.
+    pub fn add(&mut self, citation: Citation) -> usize {
+        if let Some(pos) = self.citations.iter().position(|c| c.id == citation.id) {
+            return pos + 1;
+        }
+        self.citations.push(citation);
+        self.citations.len()
+    }
+
+    /// Append every citation from `other` that is not already present, keeping first-seen order.
+    pub fn merge(&mut self, other: &CitationSet) {
+        for citation in &other.citations {
+            self.add(citation.clone());
+        }
+    }
+
+    /// 1-based number of a citation, for inline `[n]` markers.
+    pub fn number_of(&self, id: &str) -> Option<usize> {
+        self.citations.iter().position(|c| c.id == id).map(|pos| pos + 1)
+    }
+
+    /// Keep only citations whose `[n]` marker appears in `text`, renumbering from 1.
+    /// Returns the pruned set and `text` with markers rewritten to the new numbers.
+    pub fn retain_referenced(&self, text: &str) -> (CitationSet, String) {
+        let mut kept = CitationSet::new();
+        let mut rewritten = text.to_string();
+        let referenced: HashSet<usize> = (1..=self.citations.len())
+            .filter(|n| text.contains(&format!("[{}]", n)))
+            .collect();
+        // Two passes so "[1]" -> "[2]" cannot collide with an original "[2]".
+        for n in 1..=self.citations.len() {
+            if referenced.contains(&n) {
+                let new = kept.add(self.citations[n - 1].clone());
+                rewritten = rewritten.replace(&format!("[{}]", n), &format!("[\u{0}{}]", new));
+            }
+        }
+        (kept, rewritten.replace('\u{0}', ""))
+    }
+
+    /// A Markdown "Sources" section, or an empty string when there are no citations.
+    pub fn render_sources(&self) -> String {
+        if self.citations.is_empty() {
+            return String::new();
+        }
+        let mut out = String::from("Sources:\n");
+        for (i, citation) in self.citations.iter().enumerate() {
+            out.push_str(&format!("[{}] {}\n", i + 1, citation.label()));
+        }
+        out
+    }
+}
+
+impl FromIterator<Citation> for CitationSet {
+    fn from_iter<I: IntoIterator<Item = Citation>>(iter: I) -> Self {
+        let mut set = CitationSet::new();
+        for citation in iter {
+            set.add(citation);
+        }
+        set
+    }
+}
+
+/// A value together with the sources it was derived from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cited<T> {
+    pub value: T,
+    pub citations: CitationSet,
+}
+
+impl<T> Cited<T> {
+    pub fn new(value: T, citations: CitationSet) -> Self {
+        Cited { value, citations }
+    }
+
+    pub fn uncited(value: T) -> Self {
+        Cited { value, citations: CitationSet::new() }
+    }
+
+    /// Produce the next step's value, carrying these citations forward.
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> Cited<U> {
+        Cited { value: f(self.value), citations: self.citations }
+    }
+
+    /// Carry these citations forward and add the ones the next step introduced.
+    pub fn then<U>(self, next: Cited<U>) -> Cited<U> {
+        let mut citations = self.citations;
+        citations.merge(&next.citations);
+        Cited { value: next.value, citations }
+    }
+}
+
+impl Cited<String> {
+    /// The text followed by its source list (if any).
+    pub fn with_sources(&self) -> String {
+        if self.citations.is_empty() {
+            self.value.clone()
+        } else {
+            format!("{}\n\n{}", self.value.trim_end(), self.citations.render_sources())
+        }
+    }
+}
+
+/// Merge the citations of several parallel outputs (e.g. a concurrent fan-out) into one set.
+pub fn merge_all<'a, T: 'a>(outputs: impl IntoIterator<Item = &'a Cited<T>>) -> CitationSet {
+    let mut merged = CitationSet::new();
+    for output in outputs {
+        merged.merge(&output.citations);
+    }
+    merged
+}
+
+fn main() {
+    let filing = Citation::new("https://sec.gov/aapl-10k.htm", Some("page 23".to_string())).with_title("Apple 10-K");
+    let research = Cited::new("Revenue grew 8% [1].".to_string(), [filing.clone()].into_iter().collect());
+    let summary = research.then(Cited::new(
+        "Apple revenue grew 8% [1] while margins held [2].".to_string(),
+        [filing, Citation::new("https://example.com/margins", None)].into_iter().collect(),
+    ));
+    println!("{}", summary.with_sources());
+}
+```
+
+### Notes
+* Citation ids hash the source and locator only, so the same chunk retrieved by two agents is listed once.
+* `retain_referenced` is optional. Without it the source list includes everything the pipeline saw, not just what the final answer cites.
@@ -9,31 +9,22 @@ The conversion of the provided Python code to Rust is viable. However, some modi
 // The conversion requires changes to handle object-oriented aspects and dynamic typing.
 
 use std::collections::HashMap;
-use std::error::Error;
+use std::sync::Arc;
 
-// Import the necessary modules
-mod agent;
-mod base_swarm;
-mod logger;
-
-// Use the necessary types and traits
-use agent::Agent;
-use base_swarm::BaseSwarm;
-use logger::Logger;
+use crate::swarms::structs::agent::{Agent, MockAgent};
 
 // Define the Company struct
-#[derive(Debug)]
 pub struct Company {
-    org_chart: Vec<Vec<Agent>>,
+    org_chart: Vec<Vec<Arc<dyn Agent>>>,
     shared_instructions: Option<String>,
-    ceo: Option<Agent>,
-    agents: Vec<Agent>,
+    ceo: Option<Arc<dyn Agent>>,
+    agents: Vec<Arc<dyn Agent>>,
     agent_interactions: HashMap<String, Vec<String>>,
 }
 
 impl Company {
     // Create a new Company instance
-    pub fn new(org_chart: Vec<Vec<Agent>>) -> Self {
+    pub fn new(org_chart: Vec<Vec<Arc<dyn Agent>>>) -> Self {
         let mut company = Company {
             org_chart,
             shared_instructions: None,
@@ -48,97 +39,76 @@ impl Company {
 
     // Parse the organization chart and add agents to the company
     fn parse_org_chart(&mut self) {
-        for node in &self.org_chart {
+        for node in self.org_chart.clone() {
             if node.len() == 1 {
                 if self.ceo.is_some() {
                     panic!("Only one CEO is allowed");
                 }
-                self.ceo = Some(node[0].clone());
-                self.add_agent(node[0].clone());
+                self.ceo = Some(Arc::clone(&node[0]));
+                self.add_agent(Arc::clone(&node[0]));
             } else {
-                for agent in node {
-                    self.add_agent(agent.clone());
+                for agent in &node {
+                    self.add_agent(Arc::clone(agent));
                 }
                 for i in 0..node.len() - 1 {
                     for other_agent in &node[i + 1..] {
-                        self.init_interaction(node[i].clone(), other_agent.clone());
+                        self.init_interaction(&node[i], other_agent);
                     }
                 }
             }
         }
     }
 
-    // Add an agent to the company
-    pub fn add_agent(&mut self, agent: Agent) {
-        if self.agents.iter().any(|a| a.id == agent.id) {
-            panic!("Agent with id {} already exists in the company", agent.id);
+    // Add an agent to the company; agents are identified by name
+    pub fn add_agent(&mut self, agent: Arc<dyn Agent>) {
+        if self.get_agent(agent.name()).is_some() {
+            panic!("Agent {} already exists in the company", agent.name());
         }
         self.agents.push(agent);
     }
 
     // Get an agent from the company by name
-    pub fn get_agent(&self, agent_name: &str) -> Option<&Agent> {
-        self.agents.iter().find(|a| a.name == agent_name)
+    pub fn get_agent(&self, agent_name: &str) -> Option<&Arc<dyn Agent>> {
+        self.agents.iter().find(|a| a.name() == agent_name)
     }
 
     // Remove an agent from the company
-    pub fn remove_agent(&mut self, agent: &Agent) -> bool {
-        self.agents.retain(|a| a.id != agent.id)
+    pub fn remove_agent(&mut self, agent_name: &str) -> bool {
+        let before = self.agents.len();
+        self.agents.retain(|a| a.name() != agent_name);
+        self.agents.len() < before
     }
 
     // Initialize the interaction between two agents
-    fn init_interaction(&mut self, agent1: Agent, agent2: Agent) {
-        let agent1_name = agent1.ai_name.clone();
-        if !self.agent_interactions.contains_key(&agent1_name) {
-            self.agent_interactions.insert(agent1_name.clone(), Vec::new());
-        }
-        self.agent_interactions.get_mut(&agent1_name).unwrap().push(agent2.ai_name);
+    fn init_interaction(&mut self, agent1: &Arc<dyn Agent>, agent2: &Arc<dyn Agent>) {
+        self.agent_interactions.entry(agent1.name().to_string()).or_default().push(agent2.name().to_string());
     }
 
-    // Run the company
+    // Run the company; a failed interaction is reported and the rest still run
     pub fn run(&self) {
         for (agent_name, interaction_agents) in &self.agent_interactions {
-            if let Some(agent) = self.get_agent(&agent_name) {
+            if let Some(agent) = self.get_agent(agent_name) {
                 for interaction_agent in interaction_agents {
                     let task_description = format!("Task for {} to interact with {}", agent_name, interaction_agent);
                     println!("{} is being executed", task_description);
-                    agent.run(task_description);
+                    if let Err(err) = agent.run(&task_description) {
+                        println!("{}", err);
+                    }
                 }
             }
         }
     }
 }
 
-// Define the Agent struct
-#[derive(Debug, Clone)]
-pub struct Agent {
-    id: String,
-    name: String,
-    ai_name: String,
-}
-
-impl Agent {
-    // Create a new Agent instance
-    pub fn new(id: String, name: String, ai_name: String) -> Self {
-        Agent { id, name, ai_name }
-    }
-
-    // Run the agent
-    pub fn run(&self, task_description: String) {
-        // Agent logic goes here
-        println!("Agent {} is running", self.name);
-    }
-}
-
 fn main() {
     // Create agents
-    let agent1 = Agent::new("1".to_string(), "Agent 1".to_string(), "Agent1".to_string());
-    let agent2 = Agent::new("2".to_string(), "Agent 2".to_string(), "Agent2".to_string());
-    let agent3 = Agent::new("3".to_string(), "Agent 3".to_string(), "Agent3".to_string());
+    let agent1: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent1"));
+    let agent2: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent2"));
+    let agent3: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent3"));
 
     // Create the company
-    let org_chart = vec![vec![agent1.clone()], vec![agent2.clone(), agent3.clone()]];
-    let mut company = Company::new(org_chart);
+    let org_chart = vec![vec![agent1], vec![agent2, agent3]];
+    let company = Company::new(org_chart);
 
     // Run the company
     company.run();
@@ -146,7 +116,7 @@ fn main() {
 ```
 
 **Compatibility:**
-The provided Rust code maintains the original behavior of the Python code, including the creation of agents, the company, and the interactions between agents. However, some modifications were necessary to adapt the code to Rust's type system and error handling mechanisms.
+The provided Rust code maintains the original behavior of the Python code, including the creation of agents, the company, and the interactions between agents. However, some modifications were necessary to adapt the code to Rust's type system and error handling mechanisms. Members are the crate's `Arc<dyn Agent>` and are identified by `name()`, which stands in for the Python `id` and `ai_name` attributes the trait does not carry.
 
 **Limitations and Challenges:**
 
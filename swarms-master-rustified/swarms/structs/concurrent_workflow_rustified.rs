@@ -0,0 +1,217 @@
+### Overview
+The Python `ConcurrentWorkflow` gives every agent the same task on a thread pool and collects the answers.
+This conversion runs its tasks through a `FairScheduler`, so it can also take a batch of tasks aimed at
+particular agents (`run_for`) without one agent with many tasks holding every worker:
+
+* **Workers.** Tasks run on `max_workers` scoped threads (the number of CPUs unless set with
+  `with_max_workers`), each pulling the next dispatch from the scheduler until the queue is empty.
+* **Fairness.** `with_max_in_flight`, `with_default_max_in_flight`, `with_weight`, and
+  `with_starvation_threshold` configure the scheduler exactly as they do for `TaskQueueSwarm`. Each agent
+  runs one task at a time unless its limit is raised.
+* **Failures.** Every task runs under `guard_fallible_step`; an agent that errors or panics marks that task
+  failed and its worker moves on.
+
+`Swarm::run` broadcasts the task to every agent and returns the answers in the Python string form, one
+`Agent Name:` / `Response:` block per agent in the order the agents were given. It fails if any agent did.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::Agent;
+use crate::swarms::structs::dry_run::PlannedCall;
+use crate::swarms::structs::fair_scheduler::{FairScheduler, LaneStats};
+use crate::swarms::structs::scoped_tasks::scoped_map;
+use crate::swarms::structs::step_guard::{guard_fallible_step, StepRecord, StepStatus};
+use crate::swarms::structs::swarm::{Swarm, SwarmError};
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// One agent's answer to one task.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConcurrentOutput {
+    #[serde(flatten)]
+    pub step: StepRecord,
+    /// Empty unless the step succeeded.
+    pub output: String,
+}
+
+/// The outcome of one `run_for` or `run_all`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ConcurrentRun {
+    pub run_id: String,
+    /// In the order the tasks finished.
+    pub outputs: Vec<ConcurrentOutput>,
+    /// How each agent's lane has been served since the workflow was built.
+    pub lanes: Vec<LaneStats>,
+}
+
+impl ConcurrentRun {
+    /// The first task that failed or panicked, if any.
+    pub fn first_failure(&self) -> Option<&ConcurrentOutput> {
+        self.outputs.iter().find(|output| !output.step.status.is_success())
+    }
+}
+
+pub struct ConcurrentWorkflow {
+    name: String,
+    description: String,
+    agents: Vec<Arc<dyn Agent>>,
+    scheduler: FairScheduler,
+    max_workers: usize,
+}
+
+impl ConcurrentWorkflow {
+    pub fn new(name: &str, description: &str, agents: Vec<Arc<dyn Agent>>) -> Self {
+        let names: Vec<String> = agents.iter().map(|agent| agent.name().to_string()).collect();
+        ConcurrentWorkflow {
+            name: name.to_string(),
+            description: description.to_string(),
+            scheduler: FairScheduler::new(name, &names),
+            agents,
+            max_workers: thread::available_parallelism().map_or(1, |cpus| cpus.get()),
+        }
+    }
+
+    /// Run tasks on at most `workers` threads.
+    pub fn with_max_workers(mut self, workers: usize) -> Self {
+        self.max_workers = workers.max(1);
+        self
+    }
+
+    /// Let each agent run up to `limit` tasks at once; the default is one.
+    pub fn with_default_max_in_flight(mut self, limit: usize) -> Self {
+        self.scheduler = self.scheduler.with_default_max_in_flight(limit);
+        self
+    }
+
+    /// Let `agent` run up to `limit` tasks at once, so a chatty agent cannot take every worker.
+    pub fn with_max_in_flight(mut self, agent: &str, limit: usize) -> Self {
+        self.scheduler = self.scheduler.with_max_in_flight(agent, limit);
+        self
+    }
+
+    /// Serve `agent`'s lane in proportion to `weight` while other lanes also have work.
+    pub fn with_weight(mut self, agent: &str, weight: f64) -> Self {
+        self.scheduler = self.scheduler.with_weight(agent, weight);
+        self
+    }
+
+    /// Count tasks that wait longer than `threshold` in `swarms_tasks_starved_total`.
+    pub fn with_starvation_threshold(mut self, threshold: Duration) -> Self {
+        self.scheduler = self.scheduler.with_starvation_threshold(threshold);
+        self
+    }
+
+    pub fn description(&self) -> &str {
+        &self.description
+    }
+
+    pub fn agents(&self) -> &[Arc<dyn Agent>] {
+        &self.agents
+    }
+
+    /// Give `task` to every agent.
+    pub fn run_all(&self, task: &str) -> Result<ConcurrentRun, SwarmError> {
+        let assignments: Vec<(&str, &str)> = self.agents.iter().map(|agent| (agent.name(), task)).collect();
+        self.run_for(&assignments)
+    }
+
+    /// Run each `(agent, task)` pair on the workers. Nothing runs if an agent is not in the workflow.
+    pub fn run_for(&self, assignments: &[(&str, &str)]) -> Result<ConcurrentRun, SwarmError> {
+        if self.agents.is_empty() {
+            return Err(SwarmError::NoAgents);
+        }
+        for &(agent, task) in assignments {
+            if let Err(err) = self.scheduler.push(Some(agent), task) {
+                self.scheduler.drain();
+                return Err(SwarmError::Failed(err.to_string()));
+            }
+        }
+        let run_id = new_id(IdKind::Run);
+        info!("Running {} tasks of {} on {} workers", assignments.len(), run_id, self.max_workers);
+        let outputs = Mutex::new(Vec::new());
+        let workers: Vec<usize> = (0..self.max_workers.min(assignments.len()).max(1)).collect();
+        let outcome = scoped_map(&workers, |worker| format!("worker-{}", worker), |_| self.work(&outputs));
+        let outputs = outputs.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner());
+        outcome?;
+        Ok(ConcurrentRun { run_id, outputs, lanes: self.scheduler.stats(Instant::now()) })
+    }
+
+    // Runs dispatches from the scheduler on one worker until the queue is empty
+    fn work(&self, outputs: &Mutex<Vec<ConcurrentOutput>>) {
+        while let Some(dispatch) = self.scheduler.next() {
+            let agent = self.agent(&dispatch.agent);
+            let started = Instant::now();
+            let (output, step) = guard_fallible_step(agent.name(), &dispatch.task, || agent.run(&dispatch.task));
+            self.scheduler.finish(&dispatch);
+            SwarmMetrics::global().task_finished(&self.name, agent.name(), &step.status, started.elapsed());
+            let output = ConcurrentOutput { step, output: output.unwrap_or_default() };
+            outputs.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).push(output);
+        }
+    }
+
+    fn agent(&self, name: &str) -> &Arc<dyn Agent> {
+        self.agents.iter().find(|agent| agent.name() == name).expect("the scheduler only dispatches to the workflow's agents")
+    }
+}
+
+impl Swarm for ConcurrentWorkflow {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        let mut run = self.run_all(task)?;
+        if let Some(failed) = run.first_failure() {
+            let error = match &failed.step.status {
+                StepStatus::Failed { error, .. } => error.as_str(),
+                StepStatus::Panicked { message } => message.as_str(),
+                StepStatus::Succeeded => "",
+            };
+            return Err(SwarmError::Failed(format!("{} failed: {}", failed.step.agent_name, error)));
+        }
+        let position = |name: &str| self.agents.iter().position(|agent| agent.name() == name);
+        run.outputs.sort_by_key(|output| position(&output.step.agent_name));
+        let responses: Vec<String> = run
+            .outputs
+            .iter()
+            .map(|output| format!("Agent Name: {}\nResponse: {}\n", output.step.agent_name, output.output))
+            .collect();
+        Ok(responses.join("\n"))
+    }
+
+    // Every agent reads only the task
+    fn planned_calls(&self, _task: &str) -> Option<Vec<PlannedCall>> {
+        Some(self.agents.iter().map(|agent| PlannedCall::new(agent.as_ref()).reading_task()).collect())
+    }
+}
+
+fn main() {
+    use crate::swarms::structs::agent::MockAgent;
+
+    env_logger::init();
+    let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("Chatty")), Arc::new(MockAgent::new("Quiet"))];
+    let workflow = ConcurrentWorkflow::new("Mixed-Workflow", "Chatty and quiet agents", agents).with_max_workers(2);
+    let tasks: Vec<String> = (0..6).map(|i| format!("chatty task {}", i)).collect();
+    let mut assignments: Vec<(&str, &str)> = tasks.iter().map(|task| ("Chatty", task.as_str())).collect();
+    assignments.push(("Quiet", "quiet task"));
+    match workflow.run_for(&assignments) {
+        Ok(run) => {
+            for lane in run.lanes {
+                println!("{}: {} dispatched, longest wait {} ms", lane.lane, lane.dispatched, lane.max_wait_ms);
+            }
+        }
+        Err(err) => eprintln!("{}", err),
+    }
+}
+```
+
+### Notes
+* The Python `device`, `all_cores`, and `img` arguments are dropped; agents run on plain threads.
+* The Python retry of the whole fan-out is not kept. A failed task is reported in its `StepRecord`, and
+  `StepStatus::is_retriable` says whether running it again can help.
+* The scheduler's lane stats accumulate over every run of the same workflow.
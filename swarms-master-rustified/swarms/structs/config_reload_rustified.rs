@@ -0,0 +1,355 @@
+### Overview
+There is no Python counterpart. The Python API server reads its swarm config once at startup, so adding an
+agent, fixing a system prompt, or changing the swarm architecture needs a restart, and that drops every run
+in flight. `LiveSwarmConfig` lets the server and the CLI daemon pick up edits to the config file while
+they keep serving.
+
+* The config is the same `YAMLConfig` that `create_agents_from_yaml` reads. Files ending in `.toml` are
+  parsed as TOML; every other file is parsed as YAML.
+* `watch` polls the file every `interval` and calls `reload`. A reload only acts when the file's content
+  hash changed; a touched but unchanged file, or an edit that parses to the same config, is ignored.
+* A new config goes through `validate_swarm_config`, the same rules the swarm config generator applies,
+  and every agent is built. Only then does it replace the active config.
+* The swap is atomic. A run calls `current()` once when it starts and keeps that `ConfigSnapshot` until
+  it ends, so a run never sees half of one config and half of the next. Runs that start after the swap
+  get the new config.
+* A file that is missing, does not parse, or fails validation leaves the previous config active.
+* Each applied or rejected reload becomes a `ReloadEvent`. The event lists what changed (agents added or
+  removed, prompt updates, settings, and `swarm_architecture`, which holds the routing) or why the file
+  was rejected. Events are logged, kept for `events()`, and audited as `AuditAction::ConfigReloaded` or
+  `AuditAction::ConfigRejected` when an auditor is attached. A rejected file is reported once, not on
+  every poll.
+
+### Rust Code
+```rust
+use crate::swarms::agents::auto_generate_swarm_config::{build_agent, validate_swarm_config, workflow_with_agents, SwarmConfigIssue};
+use crate::swarms::agents::create_agents_from_yaml::{AgentConfig, YAMLConfig};
+use crate::swarms::structs::agent::LlmAgent;
+use crate::swarms::structs::audit::{AuditAction, Auditor};
+use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+use log::{error, info};
+use serde::Serialize;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+
+/// How many reload events `events()` keeps.
+pub const MAX_RELOAD_EVENTS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfigFileFormat {
+    Yaml,
+    Toml,
+}
+
+impl ConfigFileFormat {
+    pub fn from_path(path: &Path) -> ConfigFileFormat {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("toml") => ConfigFileFormat::Toml,
+            _ => ConfigFileFormat::Yaml,
+        }
+    }
+
+    pub fn parse(&self, contents: &str) -> Result<YAMLConfig, ConfigReloadError> {
+        match self {
+            ConfigFileFormat::Yaml => serde_yaml::from_str(contents).map_err(|e| ConfigReloadError::Parse(e.to_string())),
+            ConfigFileFormat::Toml => toml::from_str(contents).map_err(|e| ConfigReloadError::Parse(e.to_string())),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigReloadError {
+    Io(io::Error),
+    Parse(String),
+    Invalid(Vec<SwarmConfigIssue>),
+}
+
+impl fmt::Display for ConfigReloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigReloadError::Io(e) => write!(f, "failed to read the swarm config: {}", e),
+            ConfigReloadError::Parse(message) => write!(f, "the swarm config does not parse: {}", message),
+            ConfigReloadError::Invalid(issues) => {
+                let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+                write!(f, "the swarm config is invalid: {}", issues.join("; "))
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigReloadError {}
+
+impl From<io::Error> for ConfigReloadError {
+    fn from(e: io::Error) -> Self {
+        ConfigReloadError::Io(e)
+    }
+}
+
+/// One validated config, as a run sees it from start to finish.
+#[derive(Debug)]
+pub struct ConfigSnapshot {
+    /// 1 for the config loaded at startup, then one more per applied reload.
+    pub version: u64,
+    pub config: YAMLConfig,
+    pub agents: Vec<LlmAgent>,
+    /// RFC 3339, UTC.
+    pub loaded_at: String,
+}
+
+impl ConfigSnapshot {
+    pub fn agent(&self, name: &str) -> Option<&LlmAgent> {
+        self.agents.iter().find(|agent| agent.name == name)
+    }
+
+    /// A fresh workflow over this snapshot's agents. The agents are shared handles, not rebuilt.
+    pub fn workflow(&self) -> SequentialWorkflow {
+        workflow_with_agents(&self.config, self.agents.clone())
+    }
+}
+
+/// What a reload did.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum ReloadOutcome {
+    /// The file, or the config it parses to, is the same as before.
+    Unchanged,
+    Applied { version: u64, changes: Vec<String> },
+    /// The previous config is still active.
+    Rejected { reason: String },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReloadEvent {
+    /// RFC 3339, UTC.
+    pub timestamp: String,
+    pub path: PathBuf,
+    #[serde(flatten)]
+    pub outcome: ReloadOutcome,
+}
+
+// Hash of the last file contents seen, accepted or not, and the events so far
+struct ReloadState {
+    last_hash: Option<String>,
+    events: VecDeque<ReloadEvent>,
+}
+
+/// The active swarm config, reloaded from its file while the server runs.
+pub struct LiveSwarmConfig {
+    path: PathBuf,
+    format: ConfigFileFormat,
+    default_model: String,
+    current: RwLock<Arc<ConfigSnapshot>>,
+    state: Mutex<ReloadState>,
+    auditor: Option<Auditor>,
+}
+
+impl LiveSwarmConfig {
+    /// Load the config at `path`. Unlike a reload, a bad file here is an error: there is no previous
+    /// config to keep.
+    pub fn load(path: impl Into<PathBuf>, default_model: impl Into<String>) -> Result<Self, ConfigReloadError> {
+        let path = path.into();
+        let format = ConfigFileFormat::from_path(&path);
+        let default_model = default_model.into();
+        let contents = fs::read_to_string(&path)?;
+        let snapshot = build_snapshot(format.parse(&contents)?, &default_model, 1)?;
+        info!("Loaded swarm config {} with {} agents", path.display(), snapshot.agents.len());
+        Ok(LiveSwarmConfig {
+            path,
+            format,
+            default_model,
+            current: RwLock::new(Arc::new(snapshot)),
+            state: Mutex::new(ReloadState { last_hash: Some(content_hash(&contents)), events: VecDeque::new() }),
+            auditor: None,
+        })
+    }
+
+    /// Audit each applied or rejected reload, with the config path as the target.
+    pub fn audited(mut self, auditor: Auditor) -> Self {
+        self.auditor = Some(auditor);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The active config. Take it once per run and keep it for the whole run.
+    pub fn current(&self) -> Arc<ConfigSnapshot> {
+        self.current.read().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    /// Applied and rejected reloads, oldest first.
+    pub fn events(&self) -> Vec<ReloadEvent> {
+        self.lock_state().events.iter().cloned().collect()
+    }
+
+    /// Read the file and apply it if it changed and is valid.
+    pub fn reload(&self) -> ReloadOutcome {
+        let contents = match fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) => return self.reject(format!("read:{}", e.kind()), ConfigReloadError::Io(e)),
+        };
+        let hash = content_hash(&contents);
+        if self.lock_state().last_hash.as_deref() == Some(hash.as_str()) {
+            return ReloadOutcome::Unchanged;
+        }
+        let config = match self.format.parse(&contents) {
+            Ok(config) => config,
+            Err(e) => return self.reject(hash, e),
+        };
+        let previous = self.current();
+        if config == previous.config {
+            self.lock_state().last_hash = Some(hash);
+            return ReloadOutcome::Unchanged;
+        }
+        let snapshot = match build_snapshot(config, &self.default_model, previous.version + 1) {
+            Ok(snapshot) => snapshot,
+            Err(e) => return self.reject(hash, e),
+        };
+        let changes = describe_changes(&previous.config, &snapshot.config);
+        let version = snapshot.version;
+        *self.current.write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Arc::new(snapshot);
+        info!("Applied swarm config {} version {}: {}", self.path.display(), version, changes.join("; "));
+        self.lock_state().last_hash = Some(hash);
+        let outcome = ReloadOutcome::Applied { version, changes };
+        self.record(AuditAction::ConfigReloaded, outcome.clone());
+        outcome
+    }
+
+    /// Poll the file every `interval` until the returned task is aborted.
+    pub fn watch(self: Arc<Self>, interval: Duration) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                let config = self.clone();
+                if let Err(e) = tokio::task::spawn_blocking(move || config.reload()).await {
+                    error!("Swarm config reload task failed: {}", e);
+                }
+            }
+        })
+    }
+
+    // Keep the previous config and report `error` once per distinct `key` (the content hash, or the
+    // kind of read error)
+    fn reject(&self, key: String, error: ConfigReloadError) -> ReloadOutcome {
+        let outcome = ReloadOutcome::Rejected { reason: error.to_string() };
+        {
+            let mut state = self.lock_state();
+            if state.last_hash.as_deref() == Some(key.as_str()) {
+                return outcome;
+            }
+            state.last_hash = Some(key);
+        }
+        error!("Kept the previous swarm config; {} was rejected: {}", self.path.display(), error);
+        self.record(AuditAction::ConfigRejected, outcome.clone());
+        outcome
+    }
+
+    fn record(&self, action: AuditAction, outcome: ReloadOutcome) {
+        let event = ReloadEvent { timestamp: chrono::Utc::now().to_rfc3339(), path: self.path.clone(), outcome };
+        if let Some(auditor) = &self.auditor {
+            auditor.record(action, &self.path.to_string_lossy(), json!(event));
+        }
+        let mut state = self.lock_state();
+        if state.events.len() == MAX_RELOAD_EVENTS {
+            state.events.pop_front();
+        }
+        state.events.push_back(event);
+    }
+
+    fn lock_state(&self) -> std::sync::MutexGuard<'_, ReloadState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn content_hash(contents: &str) -> String {
+    hex::encode(Sha256::digest(contents.as_bytes()))
+}
+
+fn build_snapshot(config: YAMLConfig, default_model: &str, version: u64) -> Result<ConfigSnapshot, ConfigReloadError> {
+    let issues = validate_swarm_config(&config, default_model);
+    if !issues.is_empty() {
+        return Err(ConfigReloadError::Invalid(issues));
+    }
+    let agents = config
+        .agents
+        .iter()
+        .map(|agent| build_agent(agent, default_model).expect("validated agents build"))
+        .collect();
+    Ok(ConfigSnapshot {
+        version,
+        config,
+        agents,
+        loaded_at: chrono::Utc::now().to_rfc3339(),
+    })
+}
+
+// One line per difference, agents in the new config's order
+fn describe_changes(old: &YAMLConfig, new: &YAMLConfig) -> Vec<String> {
+    let by_name = |config: &YAMLConfig| -> HashMap<String, AgentConfig> {
+        config.agents.iter().map(|agent| (agent.agent_name.trim().to_string(), agent.clone())).collect()
+    };
+    let (before, after) = (by_name(old), by_name(new));
+    let mut changes = Vec::new();
+    for agent in &new.agents {
+        let name = agent.agent_name.trim();
+        match before.get(name) {
+            None => changes.push(format!("added agent '{}'", name)),
+            Some(previous) if previous.system_prompt != agent.system_prompt => {
+                changes.push(format!("updated the system prompt of '{}'", name))
+            }
+            Some(previous) if previous != agent => changes.push(format!("updated the settings of '{}'", name)),
+            Some(_) => {}
+        }
+    }
+    for agent in &old.agents {
+        let name = agent.agent_name.trim();
+        if !after.contains_key(name) {
+            changes.push(format!("removed agent '{}'", name));
+        }
+    }
+    if old.swarm_architecture != new.swarm_architecture {
+        changes.push("updated swarm_architecture".to_string());
+    }
+    changes
+}
+
+#[tokio::main]
+async fn main() -> Result<(), ConfigReloadError> {
+    env_logger::init();
+    let live = Arc::new(LiveSwarmConfig::load("swarm.yaml", "gpt-4o")?);
+    let watcher = live.clone().watch(Duration::from_secs(2));
+
+    // Each run takes one snapshot and keeps it, whatever happens to the file meanwhile
+    for _ in 0..3 {
+        let snapshot = live.current();
+        let workflow = snapshot.workflow();
+        println!("version {}: {}", snapshot.version, workflow.flow());
+        tokio::time::sleep(Duration::from_secs(5)).await;
+    }
+    watcher.abort();
+    println!("{}", serde_json::to_string_pretty(&live.events()).unwrap());
+    Ok(())
+}
+```
+
+### Notes
+- Polling, rather than inotify or FSEvents, works the same on every platform, on network mounts, and when
+  a Kubernetes ConfigMap swaps its symlink. Two seconds is short enough for config edits and costs one
+  read and one hash per tick.
+- Reloads replace agents; they do not touch runs. A run that started on version 3 finishes on version 3,
+  even if version 4 removed its agents.
+- Editors that write in place can be caught mid-save. The half-written file is rejected and reported,
+  and the next poll applies the finished one.
+- `load` fails on a bad file so a misconfigured server does not start. `reload` never fails, because
+  the server already has a config to keep.
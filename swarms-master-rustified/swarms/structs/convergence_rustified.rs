@@ -0,0 +1,154 @@
+### Overview
+There is no Python counterpart; a Python agent with `max_loops` set runs every loop even when the answer
+stopped changing several loops ago. `ConvergenceDetector` watches the outputs of a refinement loop and
+reports when the newest output says the same as the one before it:
+
+* An output identical to the previous one after normalizing case and whitespace has converged without
+  embedding anything.
+* Otherwise the output is embedded and compared with the previous output's embedding by cosine
+  similarity, against `similarity_threshold`. Each output is embedded at most once; its embedding is kept
+  for the next comparison, so a loop of `n` steps makes at most `n` embedding calls.
+* No loop before `min_loops` counts as converged, so a first draft always gets at least one revision.
+
+`LlmAgent::run_loops` stops at the first converged output when `convergence` is set, and records
+`LoopExit::Converged` as the run's exit reason. The embedding call is bound at runtime with
+`AgentBuilder::embedder` or `LlmAgent::with_embedder`, like the completion. Without one, only identical
+outputs converge.
+
+### Rust Code
+```rust
+use crate::swarms::structs::loop_detection::normalize;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Embeds one text. Synchronous, like `Completion`.
+pub type EmbedFn = Arc<dyn Fn(&str) -> Result<Vec<f32>, String> + Send + Sync>;
+
+/// Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConvergenceConfig {
+    /// Cosine similarity at or above which two consecutive outputs count as the same answer.
+    pub similarity_threshold: f32,
+    /// The first loop that may converge, counting from 1.
+    pub min_loops: usize,
+}
+
+impl Default for ConvergenceConfig {
+    fn default() -> Self {
+        ConvergenceConfig { similarity_threshold: 0.98, min_loops: 2 }
+    }
+}
+
+impl ConvergenceConfig {
+    pub fn is_valid(&self) -> bool {
+        self.similarity_threshold > 0.0 && self.similarity_threshold <= 1.0
+    }
+}
+
+/// An output that repeated the previous one closely enough to stop.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Convergence {
+    /// Zero-based index of the converged output among the outputs observed.
+    pub turn: usize,
+    pub similarity: f32,
+    /// Identical after normalization; no embedding was needed.
+    pub exact: bool,
+}
+
+struct Previous {
+    normalized: String,
+    /// `None` when it was not embedded: no embedder, or the call failed.
+    embedding: Option<Vec<f32>>,
+}
+
+pub struct ConvergenceDetector {
+    config: ConvergenceConfig,
+    embed: Option<EmbedFn>,
+    previous: Option<Previous>,
+    turns: usize,
+    embed_calls: usize,
+}
+
+impl ConvergenceDetector {
+    pub fn new(config: ConvergenceConfig, embed: Option<EmbedFn>) -> Self {
+        ConvergenceDetector { config, embed, previous: None, turns: 0, embed_calls: 0 }
+    }
+
+    pub fn config(&self) -> &ConvergenceConfig {
+        &self.config
+    }
+
+    /// Embedding calls made so far.
+    pub fn embed_calls(&self) -> usize {
+        self.embed_calls
+    }
+
+    /// Record `output`, the next output of the loop, and report whether it converged.
+    pub fn observe(&mut self, output: &str) -> Option<Convergence> {
+        let turn = self.turns;
+        self.turns += 1;
+        let normalized = normalize(output);
+        let previous = self.previous.take();
+        if let Some(previous) = previous.as_ref().filter(|previous| previous.normalized == normalized) {
+            // The same text has the same embedding; keep the cached one
+            self.previous = Some(Previous { normalized, embedding: previous.embedding.clone() });
+            return self.converged(turn, 1.0, true);
+        }
+        let embedding = self.embed(output);
+        let similarity = previous
+            .and_then(|previous| previous.embedding)
+            .zip(embedding.as_ref())
+            .map(|(previous, current)| cosine(&previous, current));
+        self.previous = Some(Previous { normalized, embedding });
+        similarity.and_then(|similarity| self.converged(turn, similarity, false))
+    }
+
+    fn embed(&mut self, output: &str) -> Option<Vec<f32>> {
+        let embed = self.embed.as_ref()?;
+        self.embed_calls += 1;
+        embed(output).map_err(|err| warn!("could not embed loop output for convergence check: {}", err)).ok()
+    }
+
+    fn converged(&self, turn: usize, similarity: f32, exact: bool) -> Option<Convergence> {
+        let due = turn + 1 >= self.config.min_loops.max(2);
+        (due && similarity >= self.config.similarity_threshold).then_some(Convergence { turn, similarity, exact })
+    }
+}
+
+// `memory::similarity::cosine` needs the `memory` feature; loops run without it
+fn cosine(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norms = a.iter().map(|x| x * x).sum::<f32>().sqrt() * b.iter().map(|y| y * y).sum::<f32>().sqrt();
+    if norms == 0.0 || a.len() != b.len() {
+        0.0
+    } else {
+        dot / norms
+    }
+}
+
+fn main() {
+    // A stand-in for a real embedding model: counts of a few marker words
+    let embed: EmbedFn = Arc::new(|text: &str| {
+        let text = text.to_lowercase();
+        Ok(["revenue", "margin", "guidance"].iter().map(|word| text.matches(word).count() as f32).collect())
+    });
+    let mut detector = ConvergenceDetector::new(ConvergenceConfig::default(), Some(embed));
+    for output in [
+        "Revenue grew 8%.",
+        "Revenue grew 8% and margin widened.",
+        "Revenue rose 8%, and the margin widened.",
+        "Revenue rose 8%, and the margin widened.",
+    ] {
+        println!("{:?}", detector.observe(output));
+    }
+    println!("embedding calls: {}", detector.embed_calls());
+}
+```
+
+### Notes
+* Only consecutive outputs are compared. An answer that alternates between two versions is a loop, not
+  convergence; `loop_detection` handles that.
+* The right threshold depends on the embedding model. Scores from small models bunch up near 1, so start
+  high (0.98) and lower it only if loops that no longer change keep running.
@@ -5,11 +5,22 @@
 // 2. Python's complex data structures will need to be converted into equivalent Rust data structures.
 // 3. Some functions are not implemented in this class and will need to be implemented in Rust.
 
-use std::collections::HashMap;
+use crate::swarms::artifacts::spillover::Spillover;
+use crate::swarms::structs::loop_detection::normalize;
+use crate::swarms::text::context_budget::{ContextBudget, ContextDemand};
+use crate::swarms::text::redaction::{RedactionError, Redactor};
+use crate::swarms::text::tokenizer::{default_tokenizer, Tokenizer};
+use crate::swarms::utils::clock::{system_clock, Clock};
+use crate::swarms::utils::compression::{CompressionError, StateCompressor};
+use crate::swarms::utils::encryption::{EncryptionError, StateCipher};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
 use std::fs;
+use std::io::{self, Write};
+use std::sync::{Arc, OnceLock};
+use log::warn;
+use serde::{Deserialize, Serialize};
 use serde_json;
-use yaml;
 
 // Define a custom struct for Conversation
 pub struct Conversation {
@@ -17,22 +28,245 @@ pub struct Conversation {
     autosave: bool,
     save_filepath: String,
     conversation_history: Vec<Message>,
-    tokenizer: Option<Tokenizer>, // Assuming Tokenizer trait is defined elsewhere
-    context_length: i32,
+    // Counts every message's tokens; the heuristic default when none is configured
+    tokenizer: Arc<dyn Tokenizer>,
+    // Whether a tokenizer was configured; only then does the constructor truncate to the budget
+    truncate_on_start: bool,
+    budget: ContextBudget,
     rules: String,
     custom_rules_prompt: String,
     user: String,
     auto_save: bool,
     save_as_yaml: bool,
     save_as_json_bool: bool,
+    // Applied to saved copies only; the in-memory history keeps the original text
+    redactor: Option<Arc<Redactor>>,
+    // Encrypts saves; loads decrypt transparently and still accept plaintext files
+    cipher: Option<Arc<StateCipher>>,
+    // Gzips saves above its threshold, before encryption; loads inflate compressed files whether or not it is set
+    compressor: Option<StateCompressor>,
+    // Save scratchpad messages too; for debugging only, since they may hold unreviewed reasoning
+    persist_scratchpad: bool,
+    // Leave a tombstone in place of each deleted message, so transcripts show where something was removed
+    keep_tombstones: bool,
+    // The id the next message gets; ids start at 1 and are never reused
+    next_id: u64,
+    // Stamps message timestamps and tombstones
+    clock: Arc<dyn Clock>,
+    // Moves messages above its threshold into artifacts, leaving a reference in the history
+    spillover: Option<Arc<Spillover>>,
+}
+
+// Written into every saved conversation. Files without a version are the bare message arrays saved
+// before versioning, and load as version 0
+pub const CONVERSATION_SCHEMA_VERSION: u32 = 1;
+
+// The on-disk formats. `save_as_yaml` picks YAML for autosave and `save`; loading detects the format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConversationFormat {
+    Json,
+    Yaml,
+}
+
+impl ConversationFormat {
+    // JSON when the first non-blank byte opens an object or array, YAML otherwise
+    pub fn detect(contents: &[u8]) -> ConversationFormat {
+        match contents.iter().find(|byte| !byte.is_ascii_whitespace()) {
+            Some(b'{') | Some(b'[') => ConversationFormat::Json,
+            _ => ConversationFormat::Yaml,
+        }
+    }
+}
+
+// A saved conversation: the schema version and the stored messages
+#[derive(Debug, Serialize, Deserialize)]
+struct SavedConversation<T> {
+    schema_version: u32,
+    messages: Vec<T>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredConversation {
+    Versioned(SavedConversation<Message>),
+    Unversioned(Vec<Message>),
+}
+
+#[derive(Debug)]
+pub enum ConversationStoreError {
+    Io(io::Error),
+    Json(serde_json::Error),
+    Yaml(serde_yaml::Error),
+    Encryption(EncryptionError),
+    Compression(CompressionError),
+    Redaction(RedactionError),
+    /// The file was saved by a newer schema than this build reads.
+    UnsupportedVersion { found: u32, supported: u32 },
+}
+
+impl fmt::Display for ConversationStoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversationStoreError::Io(e) => write!(f, "conversation file I/O failed: {}", e),
+            ConversationStoreError::Json(e) => write!(f, "invalid conversation JSON: {}", e),
+            ConversationStoreError::Yaml(e) => write!(f, "invalid conversation YAML: {}", e),
+            ConversationStoreError::Encryption(e) => write!(f, "conversation encryption failed: {}", e),
+            ConversationStoreError::Compression(e) => write!(f, "conversation compression failed: {}", e),
+            ConversationStoreError::Redaction(e) => write!(f, "conversation redaction failed: {}", e),
+            ConversationStoreError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "conversation schema version {} is newer than the supported version {}",
+                found, supported
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConversationStoreError {}
+
+impl From<io::Error> for ConversationStoreError {
+    fn from(e: io::Error) -> Self {
+        ConversationStoreError::Io(e)
+    }
+}
+
+impl From<serde_json::Error> for ConversationStoreError {
+    fn from(e: serde_json::Error) -> Self {
+        ConversationStoreError::Json(e)
+    }
+}
+
+impl From<serde_yaml::Error> for ConversationStoreError {
+    fn from(e: serde_yaml::Error) -> Self {
+        ConversationStoreError::Yaml(e)
+    }
+}
+
+impl From<EncryptionError> for ConversationStoreError {
+    fn from(e: EncryptionError) -> Self {
+        ConversationStoreError::Encryption(e)
+    }
+}
+
+impl From<CompressionError> for ConversationStoreError {
+    fn from(e: CompressionError) -> Self {
+        ConversationStoreError::Compression(e)
+    }
+}
+
+impl From<RedactionError> for ConversationStoreError {
+    fn from(e: RedactionError) -> Self {
+        ConversationStoreError::Redaction(e)
+    }
+}
+
+// Tag that delimits the model's reasoning in a reply when chain of thought is on
+pub const SCRATCHPAD_OPEN: &str = "<scratchpad>";
+pub const SCRATCHPAD_CLOSE: &str = "</scratchpad>";
+
+// Added as a system message by `ConversationBuilder::chain_of_thoughts`
+pub const CHAIN_OF_THOUGHT_PROMPT: &str = "Think through the problem step by step inside <scratchpad></scratchpad> \
+    tags before answering. Only the text outside the tags is shown to the user.";
+
+// Who a message is for. Scratchpad messages are sent to the model but never shown to the user, saved by
+// default, or passed to other agents.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Channel {
+    #[default]
+    Public,
+    Scratchpad,
+}
+
+impl Channel {
+    pub fn is_public(&self) -> bool {
+        *self == Channel::Public
+    }
 }
 
 // Define a custom struct for Message
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Message {
+    // Assigned by the conversation; 0 only in files saved before messages had ids, until they are loaded
+    #[serde(default)]
+    pub id: u64,
     pub role: String,
     pub content: String,
     pub timestamp: Option<String>,
+    #[serde(default, skip_serializing_if = "Channel::is_public")]
+    pub channel: Channel,
+    // Set when the message was deleted and a tombstone kept; the content is then empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub deleted_at: Option<String>,
+    // Token count of `content`, filled in on first use. Not saved; loaded messages are counted again
+    #[serde(skip)]
+    tokens: OnceLock<usize>,
+}
+
+impl Message {
+    pub fn new(role: String, content: String, channel: Channel) -> Message {
+        Message { id: 0, role, content, timestamp: None, channel, deleted_at: None, tokens: OnceLock::new() }
+    }
+
+    pub fn is_tombstone(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+
+    // The same message with new content; the id, timestamp, and channel are kept and the token count reset
+    fn with_content(&self, role: String, content: String) -> Message {
+        Message { role, content, deleted_at: None, tokens: OnceLock::new(), ..self.clone() }
+    }
+
+    // Tokens in `content`, counted once and cached. Replace the message rather than editing `content`,
+    // or the cached count goes stale
+    pub fn token_count(&self, tokenizer: &dyn Tokenizer) -> usize {
+        *self.tokens.get_or_init(|| tokenizer.count_tokens(&self.content))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MessageEditError {
+    /// No message has this id.
+    NotFound(u64),
+    /// The message was deleted; only its tombstone is left.
+    Deleted(u64),
+    /// The edit could not be autosaved and was undone.
+    Save(String),
+}
+
+impl fmt::Display for MessageEditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MessageEditError::NotFound(id) => write!(f, "no message with id {}", id),
+            MessageEditError::Deleted(id) => write!(f, "message {} was deleted", id),
+            MessageEditError::Save(e) => write!(f, "edit not saved: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for MessageEditError {}
+
+// Split a reply into its scratchpad sections and the answer outside them.
+// An unclosed scratchpad runs to the end of the reply, so a truncated thought never leaks into the answer.
+pub fn split_scratchpad(reply: &str) -> (Vec<String>, String) {
+    let mut thoughts = Vec::new();
+    let mut answer = String::new();
+    let mut rest = reply;
+    while let Some(open) = rest.find(SCRATCHPAD_OPEN) {
+        answer.push_str(&rest[..open]);
+        let inside = &rest[open + SCRATCHPAD_OPEN.len()..];
+        let (thought, after) = match inside.find(SCRATCHPAD_CLOSE) {
+            Some(close) => (&inside[..close], &inside[close + SCRATCHPAD_CLOSE.len()..]),
+            None => (inside, ""),
+        };
+        if !thought.trim().is_empty() {
+            thoughts.push(thought.trim().to_string());
+        }
+        rest = after;
+    }
+    answer.push_str(rest);
+    (thoughts, answer.trim().to_string())
 }
 
 // Implement the Conversation struct
@@ -43,28 +277,70 @@ impl Conversation {
         time_enabled: bool,
         autosave: bool,
         save_filepath: String,
-        tokenizer: Option<Tokenizer>, // Assuming Tokenizer trait is defined elsewhere
-        context_length: i32,
+        tokenizer: Option<Arc<dyn Tokenizer>>,
+        budget: ContextBudget,
+        rules: String,
+        custom_rules_prompt: String,
+        user: String,
+        auto_save: bool,
+        save_as_yaml: bool,
+        save_as_json_bool: bool,
+    ) -> Conversation {
+        Conversation::new_with_clock(
+            system_prompt,
+            time_enabled,
+            autosave,
+            save_filepath,
+            tokenizer,
+            budget,
+            rules,
+            custom_rules_prompt,
+            user,
+            auto_save,
+            save_as_yaml,
+            save_as_json_bool,
+            system_clock(),
+        )
+    }
+
+    // The constructor body; the clock is set before the opening messages are stamped
+    fn new_with_clock(
+        system_prompt: String,
+        time_enabled: bool,
+        autosave: bool,
+        save_filepath: String,
+        tokenizer: Option<Arc<dyn Tokenizer>>,
+        budget: ContextBudget,
         rules: String,
         custom_rules_prompt: String,
         user: String,
         auto_save: bool,
         save_as_yaml: bool,
         save_as_json_bool: bool,
+        clock: Arc<dyn Clock>,
     ) -> Conversation {
         let mut conversation = Conversation {
             time_enabled,
             autosave,
             save_filepath,
             conversation_history: Vec::new(),
-            tokenizer,
-            context_length,
+            truncate_on_start: tokenizer.is_some(),
+            tokenizer: tokenizer.unwrap_or_else(default_tokenizer),
+            budget,
             rules,
             custom_rules_prompt,
             user,
             auto_save,
             save_as_yaml,
             save_as_json_bool,
+            redactor: None,
+            cipher: None,
+            compressor: None,
+            persist_scratchpad: false,
+            keep_tombstones: false,
+            next_id: 1,
+            clock,
+            spillover: None,
         };
 
         // If system prompt is not None, add it to the conversation history
@@ -83,45 +359,142 @@ impl Conversation {
         }
 
         // If tokenizer then truncate memory
-        if let Some(tokenizer) = &conversation.tokenizer {
-            conversation.truncate_memory_with_tokenizer(tokenizer); // Assuming truncate_memory_with_tokenizer function is defined elsewhere
+        if conversation.truncate_on_start {
+            conversation.truncate_memory_with_tokenizer();
         }
 
         conversation
     }
 
-    // Function to add a message to the conversation history
-    pub fn add(&mut self, role: String, content: String) {
-        let mut message = Message {
-            role,
-            content,
-            timestamp: None,
+    // Preferred constructor; see ConversationBuilder for the defaults
+    pub fn builder() -> ConversationBuilder {
+        ConversationBuilder::default()
+    }
+
+    // Function to add a message to the conversation history. Returns its id. A failed autosave is logged and
+    // the message kept; use `try_add` to handle it
+    pub fn add(&mut self, role: String, content: String) -> u64 {
+        self.push_logged(role, content, Channel::Public)
+    }
+
+    // Function to add a message, failing if it cannot be autosaved. The message is not kept then
+    pub fn try_add(&mut self, role: String, content: String) -> Result<u64, ConversationStoreError> {
+        self.push_message(role, content, Channel::Public)
+    }
+
+    // Function to add a private note for the model, e.g. its reasoning, to the scratchpad. Returns its id
+    pub fn add_scratchpad(&mut self, role: String, content: String) -> u64 {
+        self.push_logged(role, content, Channel::Scratchpad)
+    }
+
+    // Function to add a model reply, moving its <scratchpad> sections to the scratchpad.
+    // Returns the answer, which is the only part the user and downstream agents see.
+    pub fn add_reply(&mut self, role: String, reply: &str) -> String {
+        let (thoughts, answer) = split_scratchpad(reply);
+        for thought in thoughts {
+            self.add_scratchpad(role.clone(), thought);
+        }
+        self.add(role, answer.clone());
+        answer
+    }
+
+    // `push_message` for the infallible adders: a message that could not be autosaved is kept anyway
+    fn push_logged(&mut self, role: String, content: String, channel: Channel) -> u64 {
+        let id = self.append(role, content, channel);
+        if let Err(e) = self.save_if_autosave() {
+            warn!("Message {} was added but not saved to {}: {}", id, self.save_filepath, e);
+        }
+        id
+    }
+
+    // Append a message and autosave; a message that could not be saved is removed again
+    fn push_message(&mut self, role: String, content: String, channel: Channel) -> Result<u64, ConversationStoreError> {
+        let id = self.append(role, content, channel);
+        if let Err(e) = self.save_if_autosave() {
+            self.conversation_history.pop();
+            return Err(e);
+        }
+        Ok(id)
+    }
+
+    fn save_if_autosave(&self) -> Result<(), ConversationStoreError> {
+        if self.autosave {
+            self.save(&self.save_filepath)?;
+        }
+        Ok(())
+    }
+
+    fn append(&mut self, role: String, content: String, channel: Channel) -> u64 {
+        let content = match &self.spillover {
+            Some(spillover) => spillover.apply(&role, content),
+            None => content,
         };
+        let mut message = Message::new(role, content, channel);
+        message.id = self.next_id;
+        self.next_id += 1;
 
         if self.time_enabled {
-            let timestamp = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            let timestamp = self.clock.now().format("%Y-%m-%d %H:%M:%S").to_string();
             message.timestamp = Some(timestamp);
         }
 
+        let id = message.id;
         self.conversation_history.push(message);
+        id
+    }
 
-        if self.autosave {
-            self.save_as_json(&self.save_filepath);
+    // Position of the live message `id`
+    fn position(&self, id: u64) -> Result<usize, MessageEditError> {
+        let index = self
+            .conversation_history
+            .iter()
+            .position(|msg| msg.id == id)
+            .ok_or(MessageEditError::NotFound(id))?;
+        if self.conversation_history[index].is_tombstone() {
+            return Err(MessageEditError::Deleted(id));
         }
+        Ok(index)
     }
 
-    // Function to delete a message from the conversation history
-    pub fn delete(&mut self, index: usize) {
-        self.conversation_history.remove(index);
+    // Function to delete a message from the conversation history. Returns the deleted message.
+    // With tombstones on, an empty message marked `deleted_at` stays in its place
+    pub fn delete(&mut self, id: u64) -> Result<Message, MessageEditError> {
+        let index = self.position(id)?;
+        let deleted = if self.keep_tombstones {
+            let message = &self.conversation_history[index];
+            let mut tombstone = message.with_content(message.role.clone(), String::new());
+            tombstone.deleted_at = Some(self.clock.now().format("%Y-%m-%d %H:%M:%S").to_string());
+            std::mem::replace(&mut self.conversation_history[index], tombstone)
+        } else {
+            self.conversation_history.remove(index)
+        };
+        if let Err(e) = self.save_if_autosave() {
+            if self.keep_tombstones {
+                self.conversation_history[index] = deleted;
+            } else {
+                self.conversation_history.insert(index, deleted);
+            }
+            return Err(MessageEditError::Save(e.to_string()));
+        }
+        Ok(deleted)
     }
 
-    // Function to update a message in the conversation history
-    pub fn update(&mut self, index: usize, role: String, content: String) {
-        self.conversation_history[index] = Message {
-            role,
-            content,
-            timestamp: None,
+    // Function to update a message in the conversation history. The id, timestamp, and channel are kept.
+    // Returns the previous version
+    pub fn update(&mut self, id: u64, role: String, content: String) -> Result<Message, MessageEditError> {
+        let index = self.position(id)?;
+        let updated = self.conversation_history[index].with_content(role, content);
+        let previous = std::mem::replace(&mut self.conversation_history[index], updated);
+        if let Err(e) = self.save_if_autosave() {
+            self.conversation_history[index] = previous;
+            return Err(MessageEditError::Save(e.to_string()));
         }
+        Ok(previous)
+    }
+
+    // Function to get a message by id, tombstones included
+    pub fn get(&self, id: u64) -> Option<&Message> {
+        self.conversation_history.iter().find(|msg| msg.id == id)
     }
 
     // Function to query a message in the conversation history
@@ -129,27 +502,78 @@ impl Conversation {
         self.conversation_history.get(index).cloned()
     }
 
-    // Function to search for a message in the conversation history
+    // Function to search for a message in the conversation history; scratchpad messages are not searched
     pub fn search(&self, keyword: String) -> Vec<Message> {
-        self.conversation_history
-            .iter()
+        self.public_messages()
             .filter(|msg| msg.content.contains(&keyword))
             .cloned()
             .collect()
     }
 
+    // Function to remove messages from `role` that repeat an earlier message from `role` verbatim,
+    // ignoring case and whitespace. Returns how many were removed.
+    pub fn dedupe(&mut self, role: &str) -> usize {
+        let before = self.conversation_history.len();
+        let mut seen = HashSet::new();
+        self.conversation_history
+            .retain(|msg| msg.role != role || seen.insert(normalize(&msg.content)));
+        before - self.conversation_history.len()
+    }
+
+    // Messages the user and downstream agents may see
+    pub fn public_messages(&self) -> impl Iterator<Item = &Message> {
+        self.conversation_history.iter().filter(|msg| msg.channel.is_public() && !msg.is_tombstone())
+    }
+
+    // Function to return the scratchpad messages, oldest first
+    pub fn scratchpad(&self) -> Vec<&Message> {
+        self.conversation_history.iter().filter(|msg| !msg.channel.is_public() && !msg.is_tombstone()).collect()
+    }
+
+    pub fn clear_scratchpad(&mut self) {
+        self.conversation_history.retain(|msg| msg.channel.is_public());
+    }
+
+    // Function to return everything the model is sent, scratchpad included and tombstones left out
+    pub fn messages_for_model(&self) -> Vec<&Message> {
+        self.conversation_history.iter().filter(|msg| !msg.is_tombstone()).collect()
+    }
+
+    // Function to return the public transcript with tombstones, for exports that must show removals
+    pub fn transcript(&self) -> impl Iterator<Item = &Message> {
+        self.conversation_history.iter().filter(|msg| msg.channel.is_public())
+    }
+
+    // Function to return the history for the model as a string, scratchpad included
+    pub fn history_for_model(&self) -> String {
+        self.messages_for_model()
+            .into_iter()
+            .map(|msg| match msg.channel {
+                Channel::Public => format!("{}: {}", msg.role, msg.content),
+                Channel::Scratchpad => format!("{} (scratchpad): {}", msg.role, msg.content),
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
     // Function to display the conversation history
     pub fn display_conversation(&self) {
-        for message in &self.conversation_history {
+        for message in self.public_messages() {
             println!("{}: {}", message.role, message.content);
         }
     }
 
-    // Function to export the conversation history to a file
+    // Function to export the conversation history to a file, with PII redacted when there is a redactor
     pub fn export_conversation(&self, filename: &str) {
         let mut file = fs::File::create(filename).unwrap();
-        for message in &self.conversation_history {
-            writeln!(file, "{}: {}", message.role, message.content).unwrap();
+        for message in self.transcript() {
+            match &message.deleted_at {
+                Some(deleted_at) => writeln!(file, "{}: [deleted {}]", message.role, deleted_at).unwrap(),
+                None => match &self.redactor {
+                    Some(redactor) => writeln!(file, "{}: {}", message.role, redactor.redact(&message.content).text).unwrap(),
+                    None => writeln!(file, "{}: {}", message.role, message.content).unwrap(),
+                },
+            }
         }
     }
 
@@ -167,56 +591,182 @@ impl Conversation {
     // Function to count the number of messages by role
     pub fn count_messages_by_role(&self) -> HashMap<String, i32> {
         let mut counts = HashMap::new();
-        for message in &self.conversation_history {
+        for message in self.public_messages() {
             let count = counts.entry(message.role.clone()).or_insert(0);
             *count += 1;
         }
         counts
     }
 
-    // Function to return the conversation history as a string
+    // The tokenizer behind every count below and behind truncation
+    pub fn tokenizer(&self) -> &dyn Tokenizer {
+        self.tokenizer.as_ref()
+    }
+
+    // Function to return the token count of one message, cached after the first call
+    pub fn message_tokens(&self, index: usize) -> Option<usize> {
+        self.conversation_history.get(index).map(|msg| msg.token_count(self.tokenizer.as_ref()))
+    }
+
+    // Function to return the tokens of everything the model is sent, scratchpad included
+    pub fn total_tokens(&self) -> usize {
+        self.conversation_history.iter().map(|msg| msg.token_count(self.tokenizer.as_ref())).sum()
+    }
+
+    // Function to return the tokens per role over the same messages as `total_tokens`, so the values sum to it
+    pub fn tokens_by_role(&self) -> BTreeMap<String, usize> {
+        let mut tokens = BTreeMap::new();
+        for message in &self.conversation_history {
+            *tokens.entry(message.role.clone()).or_insert(0) += message.token_count(self.tokenizer.as_ref());
+        }
+        tokens
+    }
+
+    // The history's share of a context demand. Callers fill in the other sections:
+    // `ContextDemand { tools, memory, ..conversation.context_demand() }`
+    pub fn context_demand(&self) -> ContextDemand {
+        ContextDemand { history: self.total_tokens(), ..ContextDemand::default() }
+    }
+
+    // Function to return the conversation history as a string, as shown to the user and other agents
     pub fn return_history_as_string(&self) -> String {
-        self.conversation_history
-            .iter()
+        self.public_messages()
             .map(|msg| format!("{}: {}", msg.role, msg.content))
             .collect::<Vec<String>>()
             .join("\n")
     }
 
+    // The format `save` and autosave write: YAML when `save_as_yaml` is set and JSON was not chosen
+    pub fn save_format(&self) -> ConversationFormat {
+        if self.save_as_yaml && !self.save_as_json_bool {
+            ConversationFormat::Yaml
+        } else {
+            ConversationFormat::Json
+        }
+    }
+
+    // Function to save the conversation history in the configured format
+    pub fn save(&self, filename: &str) -> Result<(), ConversationStoreError> {
+        self.save_in(filename, self.save_format())
+    }
+
+    // Function to save the conversation history in `format`, wrapped with the schema version.
+    // With a redactor, PII in each message is replaced and the original is sealed or dropped per its config.
+    // Scratchpad messages are left out unless `persist_scratchpad` is set.
+    pub fn save_in(&self, filename: &str, format: ConversationFormat) -> Result<(), ConversationStoreError> {
+        let mut messages = Vec::new();
+        for msg in self.conversation_history.iter().filter(|msg| self.persist_scratchpad || msg.channel.is_public()) {
+            let value = match &self.redactor {
+                None => serde_json::to_value(msg)?,
+                Some(redactor) => {
+                    let redacted = redactor.for_storage(&msg.content)?;
+                    let mut value = serde_json::to_value(msg)?;
+                    value["content"] = serde_json::json!(redacted.text);
+                    value["sealed_original"] = serde_json::json!(redacted.sealed_original);
+                    value
+                }
+            };
+            messages.push(value);
+        }
+        let saved = SavedConversation { schema_version: CONVERSATION_SCHEMA_VERSION, messages };
+        let contents = match format {
+            ConversationFormat::Json => serde_json::to_string(&saved)?,
+            ConversationFormat::Yaml => serde_yaml::to_string(&saved)?,
+        };
+        let contents = match &self.compressor {
+            Some(compressor) => compressor.compress(contents.as_bytes()),
+            None => contents.into_bytes(),
+        };
+        match &self.cipher {
+            Some(cipher) => cipher.write_file(filename, &contents)?,
+            None => fs::write(filename, contents)?,
+        }
+        Ok(())
+    }
+
+    // Function to replace the conversation history with a saved one, JSON or YAML, versioned or not.
+    // Returns the format the file was in
+    pub fn load(&mut self, filename: &str) -> Result<ConversationFormat, ConversationStoreError> {
+        let contents = match &self.cipher {
+            Some(cipher) => cipher.read_file(filename)?,
+            None => fs::read(filename)?,
+        };
+        let contents = StateCompressor::decompress(&contents)?;
+        let format = ConversationFormat::detect(&contents);
+        let stored: StoredConversation = match format {
+            ConversationFormat::Json => serde_json::from_slice(&contents)?,
+            ConversationFormat::Yaml => serde_yaml::from_slice(&contents)?,
+        };
+        let messages = match stored {
+            StoredConversation::Versioned(saved) if saved.schema_version > CONVERSATION_SCHEMA_VERSION => {
+                return Err(ConversationStoreError::UnsupportedVersion {
+                    found: saved.schema_version,
+                    supported: CONVERSATION_SCHEMA_VERSION,
+                });
+            }
+            StoredConversation::Versioned(saved) => saved.messages,
+            StoredConversation::Unversioned(messages) => messages,
+        };
+        self.restore(messages);
+        Ok(format)
+    }
+
+    // Function to replace the conversation history with `messages`, e.g. ones imported from another format.
+    // Messages without an id (0) are numbered after the highest id given
+    pub fn restore(&mut self, messages: Vec<Message>) {
+        self.conversation_history = messages;
+        self.next_id = self.conversation_history.iter().map(|msg| msg.id).max().unwrap_or(0) + 1;
+        for message in self.conversation_history.iter_mut().filter(|msg| msg.id == 0) {
+            message.id = self.next_id;
+            self.next_id += 1;
+        }
+    }
+
     // Function to save the conversation history as a JSON file
     pub fn save_as_json(&self, filename: &str) {
-        let json = serde_json::to_string(&self.conversation_history).unwrap();
-        fs::write(filename, json).unwrap();
+        self.save_in(filename, ConversationFormat::Json).unwrap();
+    }
+
+    // Function to save the conversation history as a YAML file
+    pub fn save_as_yaml(&self, filename: &str) {
+        self.save_in(filename, ConversationFormat::Yaml).unwrap();
     }
 
-    // Function to load the conversation history from a JSON file
+    // Function to load the conversation history from a JSON file; YAML files load too
     pub fn load_from_json(&mut self, filename: &str) {
-        let json = fs::read_to_string(filename).unwrap();
-        self.conversation_history = serde_json::from_str(&json).unwrap();
+        self.load(filename).unwrap();
+    }
+
+    // Function to load the conversation history from a YAML file; JSON files load too
+    pub fn load_from_yaml(&mut self, filename: &str) {
+        self.load(filename).unwrap();
     }
 }
 
 // Function to truncate memory with tokenizer
 impl Conversation {
-    pub fn truncate_memory_with_tokenizer(&mut self, tokenizer: &Tokenizer) {
+    // Truncate against the whole input budget; see `truncate_to` for a planned history allocation
+    pub fn truncate_memory_with_tokenizer(&mut self) {
+        self.truncate_to(self.budget.available_input());
+    }
+
+    // Keep messages until `limit` tokens are used, cutting the last one short. Counts are the cached
+    // per-message counts, the same numbers `total_tokens` reports.
+    // `limit` is normally `ContextBudget::allocate(..).history`
+    pub fn truncate_to(&mut self, limit: usize) {
         let mut total_tokens = 0;
         let mut truncated_history: Vec<Message> = Vec::new();
 
         for message in &self.conversation_history {
-            let tokens = tokenizer.count_tokens(&message.content);
-            let count = tokens;
+            let count = message.token_count(self.tokenizer.as_ref());
             total_tokens += count;
 
-            if total_tokens <= self.context_length {
+            if total_tokens <= limit {
                 truncated_history.push(message.clone());
             } else {
-                let remaining_tokens = self.context_length - (total_tokens - count);
+                let remaining_tokens = limit - (total_tokens - count);
                 let truncated_content = message.content.chars().take(remaining_tokens as usize).collect();
-                let truncated_message = Message {
-                    role: message.role.clone(),
-                    content: truncated_content,
-                    timestamp: message.timestamp.clone(),
-                };
+                let truncated_message = message.with_content(message.role.clone(), truncated_content);
                 truncated_history.push(truncated_message);
                 break;
             }
@@ -226,6 +776,246 @@ impl Conversation {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConversationConfigError {
+    /// `autosave` is on but no `save_filepath` was given.
+    MissingSavePath,
+    /// `save_as_yaml` and `save_as_json` were both requested.
+    ConflictingSaveFormats,
+    /// The budget reserves the whole window for output.
+    NoInputBudget,
+}
+
+impl fmt::Display for ConversationConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConversationConfigError::MissingSavePath => write!(f, "autosave requires a save_filepath"),
+            ConversationConfigError::ConflictingSaveFormats => write!(f, "choose either YAML or JSON saving, not both"),
+            ConversationConfigError::NoInputBudget => write!(f, "context budget leaves no room for input"),
+        }
+    }
+}
+
+impl std::error::Error for ConversationConfigError {}
+
+// Builder for Conversation. Defaults follow the Python constructor; build() validates the combination.
+pub struct ConversationBuilder {
+    system_prompt: String,
+    time_enabled: bool,
+    autosave: bool,
+    save_filepath: String,
+    tokenizer: Option<Arc<dyn Tokenizer>>,
+    budget: ContextBudget,
+    rules: String,
+    custom_rules_prompt: String,
+    user: String,
+    auto_save: bool,
+    save_as_yaml: bool,
+    save_as_json_bool: bool,
+    redactor: Option<Arc<Redactor>>,
+    cipher: Option<Arc<StateCipher>>,
+    compressor: Option<StateCompressor>,
+    chain_of_thoughts: bool,
+    persist_scratchpad: bool,
+    keep_tombstones: bool,
+    clock: Option<Arc<dyn Clock>>,
+    spillover: Option<Arc<Spillover>>,
+}
+
+impl Default for ConversationBuilder {
+    fn default() -> Self {
+        ConversationBuilder {
+            system_prompt: String::new(),
+            time_enabled: false,
+            autosave: false,
+            save_filepath: String::new(),
+            tokenizer: None,
+            budget: ContextBudget::default(),
+            rules: String::new(),
+            custom_rules_prompt: String::new(),
+            user: "User:".to_string(),
+            auto_save: true,
+            save_as_yaml: true,
+            save_as_json_bool: false,
+            redactor: None,
+            cipher: None,
+            compressor: None,
+            chain_of_thoughts: false,
+            persist_scratchpad: false,
+            keep_tombstones: false,
+            clock: None,
+            spillover: None,
+        }
+    }
+}
+
+impl ConversationBuilder {
+    pub fn system_prompt(mut self, system_prompt: impl Into<String>) -> Self {
+        self.system_prompt = system_prompt.into();
+        self
+    }
+
+    pub fn time_enabled(mut self, time_enabled: bool) -> Self {
+        self.time_enabled = time_enabled;
+        self
+    }
+
+    // Save after every message to `path`
+    pub fn autosave_to(mut self, path: impl Into<String>) -> Self {
+        self.autosave = true;
+        self.save_filepath = path.into();
+        self
+    }
+
+    pub fn save_filepath(mut self, path: impl Into<String>) -> Self {
+        self.save_filepath = path.into();
+        self
+    }
+
+    pub fn autosave(mut self, autosave: bool) -> Self {
+        self.autosave = autosave;
+        self
+    }
+
+    // Count tokens with `tokenizer` instead of the heuristic, and truncate the opening messages to the budget
+    pub fn tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = Some(tokenizer);
+        self
+    }
+
+    pub fn budget(mut self, budget: ContextBudget) -> Self {
+        self.budget = budget;
+        self
+    }
+
+    pub fn rules(mut self, rules: impl Into<String>) -> Self {
+        self.rules = rules.into();
+        self
+    }
+
+    pub fn custom_rules_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.custom_rules_prompt = prompt.into();
+        self
+    }
+
+    pub fn user(mut self, user: impl Into<String>) -> Self {
+        self.user = user.into();
+        self
+    }
+
+    pub fn auto_save(mut self, auto_save: bool) -> Self {
+        self.auto_save = auto_save;
+        self
+    }
+
+    // Select JSON saving; turns YAML saving off
+    pub fn save_as_json(mut self) -> Self {
+        self.save_as_json_bool = true;
+        self.save_as_yaml = false;
+        self
+    }
+
+    // Redact PII from saved copies of the conversation
+    pub fn redactor(mut self, redactor: Arc<Redactor>) -> Self {
+        self.redactor = Some(redactor);
+        self
+    }
+
+    // Encrypt saved copies of the conversation
+    pub fn encryption(mut self, cipher: Arc<StateCipher>) -> Self {
+        self.cipher = Some(cipher);
+        self
+    }
+
+    // Gzip saved copies above the compressor's threshold
+    pub fn compression(mut self, compressor: StateCompressor) -> Self {
+        self.compressor = Some(compressor);
+        self
+    }
+
+    // Ask the model to reason in a scratchpad; pair with `Conversation::add_reply` to keep it private
+    pub fn chain_of_thoughts(mut self, chain_of_thoughts: bool) -> Self {
+        self.chain_of_thoughts = chain_of_thoughts;
+        self
+    }
+
+    // Also save scratchpad messages. For debugging only: saved copies are what operators and exports read
+    pub fn persist_scratchpad(mut self, persist_scratchpad: bool) -> Self {
+        self.persist_scratchpad = persist_scratchpad;
+        self
+    }
+
+    // Keep a tombstone for each deleted message, so exported transcripts show where messages were removed
+    pub fn tombstones(mut self, keep_tombstones: bool) -> Self {
+        self.keep_tombstones = keep_tombstones;
+        self
+    }
+
+    // Read timestamps from `clock` instead of the system clock, e.g. a `ManualClock` in tests
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    // Store messages above the spillover threshold as files, keeping a reference and preview in the history. The
+    // spillover uses the conversation's redactor and cipher unless it has its own
+    pub fn spillover(mut self, spillover: Arc<Spillover>) -> Self {
+        self.spillover = Some(spillover);
+        self
+    }
+
+    pub fn save_as_yaml(mut self, save_as_yaml: bool) -> Self {
+        self.save_as_yaml = save_as_yaml;
+        self
+    }
+
+    pub fn build(self) -> Result<Conversation, ConversationConfigError> {
+        if self.autosave && self.save_filepath.is_empty() {
+            return Err(ConversationConfigError::MissingSavePath);
+        }
+        if self.save_as_yaml && self.save_as_json_bool {
+            return Err(ConversationConfigError::ConflictingSaveFormats);
+        }
+        if self.budget.available_input() == 0 {
+            return Err(ConversationConfigError::NoInputBudget);
+        }
+        // Autosave is switched on only once redaction, encryption and compression are set, so the initial save uses them
+        let (autosave, redactor, cipher, compressor) = (self.autosave, self.redactor, self.cipher, self.compressor);
+        let (chain_of_thoughts, persist_scratchpad, keep_tombstones) =
+            (self.chain_of_thoughts, self.persist_scratchpad, self.keep_tombstones);
+        let spillover = self.spillover.map(|spillover| spillover.inheriting(redactor.as_ref(), cipher.as_ref()));
+        let mut conversation = Conversation::new_with_clock(
+            self.system_prompt,
+            self.time_enabled,
+            false,
+            self.save_filepath,
+            self.tokenizer,
+            self.budget,
+            self.rules,
+            self.custom_rules_prompt,
+            self.user,
+            self.auto_save,
+            self.save_as_yaml,
+            self.save_as_json_bool,
+            self.clock.unwrap_or_else(system_clock),
+        );
+        conversation.redactor = redactor;
+        conversation.cipher = cipher;
+        conversation.compressor = compressor;
+        conversation.persist_scratchpad = persist_scratchpad;
+        conversation.keep_tombstones = keep_tombstones;
+        conversation.spillover = spillover;
+        if chain_of_thoughts {
+            conversation.add("System:".to_string(), CHAIN_OF_THOUGHT_PROMPT.to_string());
+        }
+        conversation.autosave = autosave;
+        if autosave && !conversation.conversation_history.is_empty() {
+            conversation.save(&conversation.save_filepath).unwrap();
+        }
+        Ok(conversation)
+    }
+}
+
 impl fmt::Display for Conversation {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.return_history_as_string())
@@ -233,27 +1023,22 @@ impl fmt::Display for Conversation {
 }
 
 fn main() {
-    let mut conversation = Conversation::new(
-        "".to_string(),
-        false,
-        false,
-        "".to_string(),
-        None,
-        0,
-        "".to_string(),
-        "".to_string(),
-        "".to_string(),
-        false,
-        false,
-        false,
-    );
+    let mut conversation = Conversation::builder()
+        .budget(ContextBudget::new(32_768, 4_096))
+        .build()
+        .expect("valid conversation config");
     conversation.add("user".to_string(), "Hello, how are you?".to_string());
     conversation.add("assistant".to_string(), "I am doing well, thanks.".to_string());
     println!("{}", conversation);
+    println!("{} tokens: {:?}", conversation.total_tokens(), conversation.tokens_by_role());
 }
 ```
 
-Note: This conversion assumes the existence of a `Tokenizer` trait that provides a `count_tokens` method. Also, this is not an exhaustive implementation. The original Python code has some additional features and methods that are not converted here. For a complete conversion, you may need to add more functionality to the Rust version.
+Note: Messages are addressed by id. `update` and `delete` return a `MessageEditError` for unknown or deleted ids, and `update` keeps the original timestamp. With autosave on, an edit that cannot be saved is undone and returned as `MessageEditError::Save`, and `try_add` returns the save error without keeping the message; `add` keeps the message and logs the failure, so the history never panics on a full disk or a read-only path. With `tombstones(true)`, a deleted message leaves an empty, dated tombstone. The model never sees it, but `export_conversation` and saved copies show where the message was removed.
+
+Note: Conversations are saved as JSON or YAML (`save_as_yaml`, on by default, picks YAML), inside a `schema_version` envelope. `load` detects the format from the file's contents, so a file loads whichever extension it has; unversioned arrays from earlier builds still load.
+
+Note: Token counts come from the `Tokenizer` in `swarms::text::tokenizer`. Each `Message` caches its own count, so `total_tokens`, `tokens_by_role`, and truncation reuse one count per message instead of re-tokenizing the history. Also, this is not an exhaustive implementation. The original Python code has some additional features and methods that are not converted here. For a complete conversion, you may need to add more functionality to the Rust version.
 
 **Challenges and Limitations:**
 
@@ -0,0 +1,258 @@
+### Overview
+There is no Python counterpart; after a bad agent decision the Python user reads the logs and guesses what
+the model was shown. The answer is usually in the prompt: a system prompt overridden by a history message,
+a memory passage that should not have been retrieved, a tool the model was never offered.
+
+`Debugger` steps through a recorded `RunTrace` one step at a time, forwards and backwards, and shows the
+prompt each step was sent, section by section in the order it was assembled: system prompt, tool schemas,
+memory passages, history, then the step's own prompt, followed by the output. Each section shows its
+token count, which is usually enough to spot a history that crowded everything else out. Steps recorded
+without a `PromptContext` show the prompt and output only.
+
+`swarms debug <trace>` opens a trace at its first step and reads commands from the terminal; `help` lists
+them. The same commands drive `Debugger::execute`, so a notebook or test can script a session.
+
+### Rust Code
+```rust
+use crate::swarms::structs::run_diff::{PromptContext, RunDiffError, RunTrace, TraceStep};
+use crate::swarms::text::tokenizer::{default_tokenizer, Tokenizer};
+use std::fmt::Write as _;
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+use std::sync::Arc;
+
+const HELP: &str = "\
+n [count]     next step (or `count` steps)
+b [count]     back a step
+g <step>      go to step number <step>
+a <agent>     forward to the next step by <agent>
+f <text>      forward to the next step whose prompt, context, or output contains <text>
+s             show the current step
+l             list all steps
+q             quit";
+
+/// A cursor over the steps of a recorded run.
+pub struct Debugger {
+    trace: RunTrace,
+    cursor: usize,
+    tokenizer: Arc<dyn Tokenizer>,
+}
+
+impl Debugger {
+    /// Open `trace` at its first step.
+    pub fn new(trace: RunTrace) -> Self {
+        Debugger { trace, cursor: 0, tokenizer: default_tokenizer() }
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RunDiffError> {
+        Ok(Debugger::new(RunTrace::load(path)?))
+    }
+
+    /// Count section tokens with `tokenizer` instead of the heuristic.
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn trace(&self) -> &RunTrace {
+        &self.trace
+    }
+
+    /// The zero-based index of the current step.
+    pub fn position(&self) -> usize {
+        self.cursor
+    }
+
+    pub fn current(&self) -> Option<&TraceStep> {
+        self.trace.steps.get(self.cursor)
+    }
+
+    /// Move `count` steps forward, stopping at the last one. Returns whether the cursor moved.
+    pub fn forward(&mut self, count: usize) -> bool {
+        let target = (self.cursor + count).min(self.trace.steps.len().saturating_sub(1));
+        self.seek(target)
+    }
+
+    /// Move `count` steps back, stopping at the first one. Returns whether the cursor moved.
+    pub fn back(&mut self, count: usize) -> bool {
+        self.seek(self.cursor.saturating_sub(count))
+    }
+
+    /// Jump to step `index`, zero-based. Returns `false`, without moving, when there is no such step.
+    pub fn seek(&mut self, index: usize) -> bool {
+        if index >= self.trace.steps.len() || index == self.cursor {
+            return false;
+        }
+        self.cursor = index;
+        true
+    }
+
+    /// Move forward to the next step `matches` accepts. Returns `false`, without moving, when none does.
+    pub fn forward_to(&mut self, matches: impl Fn(&TraceStep) -> bool) -> bool {
+        match self.trace.steps.iter().enumerate().skip(self.cursor + 1).find(|(_, step)| matches(step)) {
+            Some((index, _)) => self.seek(index),
+            None => false,
+        }
+    }
+
+    /// How many steps `agent` had taken before step `index`.
+    fn agent_position(&self, index: usize) -> usize {
+        let agent = &self.trace.steps[index].agent;
+        self.trace.steps[..index].iter().filter(|step| &step.agent == agent).count()
+    }
+
+    fn section(&self, out: &mut String, title: &str, body: &str) {
+        let _ = writeln!(out, "--- {} ({} tokens)", title, self.tokenizer.count_tokens(body));
+        let _ = writeln!(out, "{}", body);
+    }
+
+    fn context_sections(&self, out: &mut String, context: &PromptContext) {
+        if !context.system.is_empty() {
+            self.section(out, "system", &context.system);
+        }
+        if !context.tools.is_empty() {
+            let tools = context
+                .tools
+                .iter()
+                .map(|tool| serde_json::to_string_pretty(tool).unwrap_or_else(|_| tool.to_string()))
+                .collect::<Vec<_>>()
+                .join("\n");
+            self.section(out, &format!("tools: {}", context.tools.len()), &tools);
+        }
+        if !context.memory.is_empty() {
+            let memory =
+                context.memory.iter().enumerate().map(|(i, passage)| format!("[{}] {}", i + 1, passage)).collect::<Vec<_>>();
+            self.section(out, &format!("memory: {} passage(s)", context.memory.len()), &memory.join("\n"));
+        }
+        if !context.history.is_empty() {
+            let history =
+                context.history.iter().map(|message| format!("[{}] {}", message.role, message.content)).collect::<Vec<_>>();
+            self.section(out, &format!("history: {} message(s)", context.history.len()), &history.join("\n"));
+        }
+    }
+
+    /// Step `index` as it was assembled: a header, the context sections, the prompt, and the output.
+    pub fn render_step(&self, index: usize) -> Option<String> {
+        let step = self.trace.steps.get(index)?;
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "Step {}/{}: {} #{} [{}], {} prompt + {} completion tokens",
+            index + 1,
+            self.trace.steps.len(),
+            step.agent,
+            self.agent_position(index) + 1,
+            step.model,
+            step.prompt_tokens,
+            step.completion_tokens
+        );
+        match &step.context {
+            Some(context) => self.context_sections(&mut out, context),
+            None => out.push_str("(no context recorded; showing the prompt only)\n"),
+        }
+        self.section(&mut out, "prompt", &step.prompt);
+        self.section(&mut out, "output", &step.output);
+        Some(out)
+    }
+
+    /// One line per step, with the current one marked.
+    pub fn list(&self) -> String {
+        let mut out = String::new();
+        for (index, step) in self.trace.steps.iter().enumerate() {
+            let marker = if index == self.cursor { ">" } else { " " };
+            let first_line = step.output.lines().next().unwrap_or_default();
+            let _ = writeln!(out, "{} {:>3} {} #{}: {}", marker, index + 1, step.agent, self.agent_position(index) + 1, first_line);
+        }
+        out
+    }
+
+    fn show(&self) -> String {
+        self.render_step(self.cursor).unwrap_or_else(|| format!("Run {} has no steps.\n", self.trace.run_id))
+    }
+
+    /// Run one command (see `help`). Returns what to print, or `None` for `q`.
+    pub fn execute(&mut self, line: &str) -> Option<String> {
+        let line = line.trim();
+        let (command, argument) = line.split_once(char::is_whitespace).map_or((line, ""), |(c, a)| (c, a.trim()));
+        let count = || argument.parse::<usize>().unwrap_or(1).max(1);
+        let moved = |moved: bool, this: &Self, otherwise: &str| if moved { this.show() } else { format!("{}\n", otherwise) };
+        let reply = match command {
+            "n" | "next" => moved(self.forward(count()), self, "Already at the last step."),
+            "b" | "back" => moved(self.back(count()), self, "Already at the first step."),
+            "g" | "goto" => match argument.parse::<usize>() {
+                Ok(step) if step >= 1 && step <= self.trace.steps.len() => {
+                    self.seek(step - 1);
+                    self.show()
+                }
+                _ => format!("No step '{}'; steps run from 1 to {}.\n", argument, self.trace.steps.len()),
+            },
+            "a" | "agent" if !argument.is_empty() => {
+                let found = self.forward_to(|step| step.agent == argument);
+                moved(found, self, &format!("No later step by {}.", argument))
+            }
+            "f" | "find" if !argument.is_empty() => {
+                let found = self.forward_to(|step| {
+                    step.prompt.contains(argument)
+                        || step.output.contains(argument)
+                        || step.context.as_ref().is_some_and(|context| {
+                            serde_json::to_string(context).is_ok_and(|context| context.contains(argument))
+                        })
+                });
+                moved(found, self, &format!("No later step mentions '{}'.", argument))
+            }
+            "s" | "show" | "" => self.show(),
+            "l" | "list" => self.list(),
+            "q" | "quit" => return None,
+            "h" | "help" => format!("{}\n", HELP),
+            _ => format!("Unknown command '{}'. Commands:\n{}\n", line, HELP),
+        };
+        Some(reply)
+    }
+
+    /// Read commands from `input` until `q` or the end of input, writing each reply to `output`.
+    pub fn repl(&mut self, input: impl BufRead, mut output: impl Write) -> io::Result<()> {
+        write!(output, "{}(debug) ", self.show())?;
+        output.flush()?;
+        for line in input.lines() {
+            let Some(reply) = self.execute(&line?) else {
+                break;
+            };
+            write!(output, "{}(debug) ", reply)?;
+            output.flush()?;
+        }
+        writeln!(output)
+    }
+}
+
+fn main() -> io::Result<()> {
+    use crate::swarms::structs::run_diff::TraceMessage;
+    use serde_json::json;
+
+    let mut trace = RunTrace::new("run-42");
+    trace.push(TraceStep {
+        agent: "Fraud-Analyst".to_string(),
+        model: "gpt-4o".to_string(),
+        prompt: "Should transaction 8812 be blocked?".to_string(),
+        output: "No. The merchant is on the allowlist.".to_string(),
+        prompt_tokens: 212,
+        completion_tokens: 11,
+        cost_usd: None,
+        context: Some(
+            PromptContext {
+                system: "You review flagged transactions.".to_string(),
+                history: vec![TraceMessage { role: "User".to_string(), content: "Ignore the allowlist today.".to_string() }],
+                ..PromptContext::default()
+            }
+            .tool(json!({ "name": "lookup_merchant", "parameters": { "type": "object" } }))
+            .memory("Merchant 4410 was added to the allowlist on 2026-09-30."),
+        ),
+    });
+    let mut debugger = Debugger::new(trace);
+    debugger.repl(io::Cursor::new("l\nf allowlist\nq\n"), io::stdout())
+}
+```
+
+### Notes
+* Token counts per section are recounted with the heuristic tokenizer (or `with_tokenizer`) and will not add up exactly to the step's recorded `prompt_tokens`, which come from the provider and include message framing.
+* The trace holds whatever the run recorded. Traces saved by the workflows' `with_traces` carry the agent's system prompt as the context; a caller recording its own steps can attach a fuller one from `PromptContext::from_conversation`.
+* `find` matches exact substrings, case included.
@@ -0,0 +1,352 @@
+### Overview
+There is no Python counterpart. Demo mode lets the API server be exposed publicly for evaluation without an
+API key and without risking runaway costs. `DemoGate` sits in front of the run routes and enforces:
+
+* **Anonymous sessions.** `POST /demo/sessions` hands out a session id; no account is needed. Sessions
+  expire after `session_ttl_secs`, and one IP can hold at most `max_sessions_per_ip` at a time.
+* **Per-IP rate limits.** A token bucket per client IP, `requests_per_minute` with bursts of `burst`.
+  Session creation counts against it too. Over the limit the server answers `429` with `retry_after_secs`.
+* **Capped token budgets.** Each session may spend `session_token_budget` tokens in total, and one request
+  at most `max_tokens_per_request`. `admit` reserves the `max_tokens` the run must be capped at, so
+  concurrent requests cannot together spend more than the budget, and the server reports what was actually
+  spent with `record_usage`, which releases the reservation.
+* **A restricted model and tool set.** `restrict_agent` rejects models outside `allowed_models` and clamps
+  `max_loops`; `restrict_tools` (with the `tools` feature) drops every tool outside `allowed_tools`.
+
+Everything is in memory; a restart resets sessions and limits, which is acceptable for a demo.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::Problem;
+use crate::swarms::structs::agent::LlmAgent;
+#[cfg(feature = "tools")]
+use crate::swarms::tools::base_tool::ToolRegistry;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::fmt;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use uuid::Uuid;
+
+/// Demo limits. Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+#[serde(default)]
+pub struct DemoConfig {
+    pub requests_per_minute: u32,
+    pub burst: u32,
+    pub session_ttl_secs: u64,
+    pub max_sessions_per_ip: usize,
+    pub session_token_budget: u64,
+    pub max_tokens_per_request: u64,
+    pub max_loops: i32,
+    pub allowed_models: Vec<String>,
+    pub allowed_tools: Vec<String>,
+}
+
+impl Default for DemoConfig {
+    fn default() -> Self {
+        DemoConfig {
+            requests_per_minute: 10,
+            burst: 5,
+            session_ttl_secs: 60 * 60,
+            max_sessions_per_ip: 3,
+            session_token_budget: 20_000,
+            max_tokens_per_request: 1_024,
+            max_loops: 1,
+            allowed_models: vec!["gpt-4o-mini".to_string()],
+            allowed_tools: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DemoError {
+    RateLimited { retry_after: Duration },
+    TooManySessions { limit: usize },
+    UnknownSession,
+    /// The session belongs to a different IP.
+    SessionMismatch,
+    ModelNotAllowed(String),
+    ToolNotAllowed(String),
+    BudgetExhausted { budget: u64 },
+}
+
+impl DemoError {
+    /// The HTTP status the server answers with.
+    pub fn status(&self) -> u16 {
+        match self {
+            DemoError::RateLimited { .. } | DemoError::TooManySessions { .. } | DemoError::BudgetExhausted { .. } => 429,
+            DemoError::UnknownSession => 401,
+            DemoError::SessionMismatch | DemoError::ModelNotAllowed(_) | DemoError::ToolNotAllowed(_) => 403,
+        }
+    }
+
+    /// The stable code clients match on.
+    pub fn code(&self) -> &'static str {
+        match self {
+            DemoError::RateLimited { .. } => "rate_limited",
+            DemoError::TooManySessions { .. } => "too_many_sessions",
+            DemoError::UnknownSession => "unknown_session",
+            DemoError::SessionMismatch => "session_mismatch",
+            DemoError::ModelNotAllowed(_) => "model_not_allowed",
+            DemoError::ToolNotAllowed(_) => "tool_not_allowed",
+            DemoError::BudgetExhausted { .. } => "budget_exhausted",
+        }
+    }
+
+    /// The problem document the server answers with.
+    pub fn to_json(&self) -> JsonValue {
+        Problem::from(self).to_value()
+    }
+}
+
+impl From<&DemoError> for Problem {
+    fn from(err: &DemoError) -> Self {
+        let problem = Problem::new(err.status(), err.code(), "Demo limit reached", err.to_string());
+        match err {
+            DemoError::RateLimited { retry_after } => {
+                problem.retriable(true).extension("retry_after_secs", retry_after.as_secs_f64().ceil() as u64)
+            }
+            _ => problem,
+        }
+    }
+}
+
+impl fmt::Display for DemoError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DemoError::RateLimited { retry_after } => {
+                write!(f, "rate limit exceeded, retry in {}s", retry_after.as_secs_f64().ceil() as u64)
+            }
+            DemoError::TooManySessions { limit } => write!(f, "at most {} demo sessions per client", limit),
+            DemoError::UnknownSession => write!(f, "unknown or expired demo session"),
+            DemoError::SessionMismatch => write!(f, "demo session was opened from a different address"),
+            DemoError::ModelNotAllowed(model) => write!(f, "model '{}' is not available in demo mode", model),
+            DemoError::ToolNotAllowed(tool) => write!(f, "tool '{}' is not available in demo mode", tool),
+            DemoError::BudgetExhausted { budget } => write!(f, "demo session has used its {} token budget", budget),
+        }
+    }
+}
+
+impl std::error::Error for DemoError {}
+
+struct Bucket {
+    tokens: f64,
+    refilled_at: Instant,
+}
+
+struct Session {
+    ip: IpAddr,
+    created_at: Instant,
+    tokens_used: u64,
+    // Held by admitted runs that have not reported their usage yet
+    tokens_reserved: u64,
+}
+
+impl Session {
+    fn remaining(&self, budget: u64) -> u64 {
+        budget.saturating_sub(self.tokens_used).saturating_sub(self.tokens_reserved)
+    }
+}
+
+#[derive(Default)]
+struct DemoState {
+    buckets: HashMap<IpAddr, Bucket>,
+    sessions: HashMap<String, Session>,
+}
+
+/// What a run was admitted with. Its `max_tokens` stay reserved until `record_usage` is called with it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Admission {
+    /// Cap the run's output at this many tokens.
+    pub max_tokens: u64,
+    /// What is left for other requests once this one's `max_tokens` are reserved.
+    pub remaining_budget: u64,
+}
+
+/// Enforces demo limits. Clones share state.
+#[derive(Clone)]
+pub struct DemoGate {
+    config: Arc<DemoConfig>,
+    state: Arc<Mutex<DemoState>>,
+}
+
+impl DemoGate {
+    pub fn new(config: DemoConfig) -> Self {
+        info!(
+            "Demo mode enabled: {} req/min per IP, {} tokens per session, models {:?}",
+            config.requests_per_minute, config.session_token_budget, config.allowed_models
+        );
+        DemoGate { config: Arc::new(config), state: Arc::new(Mutex::new(DemoState::default())) }
+    }
+
+    pub fn config(&self) -> &DemoConfig {
+        &self.config
+    }
+
+    fn session_ttl(&self) -> Duration {
+        Duration::from_secs(self.config.session_ttl_secs)
+    }
+
+    /// Take one request from `ip`'s bucket.
+    fn throttle(&self, state: &mut DemoState, ip: IpAddr, now: Instant) -> Result<(), DemoError> {
+        let per_sec = self.config.requests_per_minute as f64 / 60.0;
+        let burst = self.config.burst.max(1) as f64;
+        let bucket = state.buckets.entry(ip).or_insert(Bucket { tokens: burst, refilled_at: now });
+        let elapsed = now.saturating_duration_since(bucket.refilled_at).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * per_sec).min(burst);
+        bucket.refilled_at = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            return Ok(());
+        }
+        let retry_after = if per_sec > 0.0 {
+            Duration::from_secs_f64((1.0 - bucket.tokens) / per_sec)
+        } else {
+            Duration::from_secs(60)
+        };
+        warn!("Demo rate limit hit by {}", ip);
+        Err(DemoError::RateLimited { retry_after })
+    }
+
+    fn sweep(&self, state: &mut DemoState, now: Instant) {
+        let ttl = self.session_ttl();
+        state.sessions.retain(|_, session| now.saturating_duration_since(session.created_at) < ttl);
+        // A bucket idle for a minute has refilled completely, so forgetting it changes nothing.
+        state.buckets.retain(|_, bucket| now.saturating_duration_since(bucket.refilled_at) < Duration::from_secs(60));
+    }
+
+    /// Open an anonymous session for `ip`. Returns its id.
+    pub fn open_session(&self, ip: IpAddr) -> Result<String, DemoError> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        self.sweep(&mut state, now);
+        self.throttle(&mut state, ip, now)?;
+        let open = state.sessions.values().filter(|session| session.ip == ip).count();
+        if open >= self.config.max_sessions_per_ip {
+            return Err(DemoError::TooManySessions { limit: self.config.max_sessions_per_ip });
+        }
+        let id = Uuid::new_v4().to_string();
+        state.sessions.insert(id.clone(), Session { ip, created_at: now, tokens_used: 0, tokens_reserved: 0 });
+        Ok(id)
+    }
+
+    /// Admit one run request from `session_id` at `ip`, asking for up to `requested_tokens`
+    /// (`None` for the per-request cap). The granted `max_tokens` are reserved under the same lock that
+    /// checked the budget, so concurrent admissions never grant more than the session has left.
+    pub fn admit(&self, session_id: &str, ip: IpAddr, requested_tokens: Option<u64>) -> Result<Admission, DemoError> {
+        let now = Instant::now();
+        let mut state = self.state.lock().unwrap();
+        self.throttle(&mut state, ip, now)?;
+        let ttl = self.session_ttl();
+        let session = state
+            .sessions
+            .get_mut(session_id)
+            .filter(|session| now.saturating_duration_since(session.created_at) < ttl)
+            .ok_or(DemoError::UnknownSession)?;
+        if session.ip != ip {
+            return Err(DemoError::SessionMismatch);
+        }
+        let remaining = session.remaining(self.config.session_token_budget);
+        if remaining == 0 {
+            return Err(DemoError::BudgetExhausted { budget: self.config.session_token_budget });
+        }
+        let requested = requested_tokens.unwrap_or(self.config.max_tokens_per_request);
+        let max_tokens = requested.min(self.config.max_tokens_per_request).min(remaining);
+        session.tokens_reserved += max_tokens;
+        Ok(Admission { max_tokens, remaining_budget: remaining - max_tokens })
+    }
+
+    /// Charge `tokens` (prompt and completion) to the session after the run `admission` let in, and release
+    /// its reservation. Call it for every admission, with `0` for a run that failed before spending anything.
+    pub fn record_usage(&self, session_id: &str, admission: &Admission, tokens: u64) {
+        if let Some(session) = self.state.lock().unwrap().sessions.get_mut(session_id) {
+            session.tokens_reserved = session.tokens_reserved.saturating_sub(admission.max_tokens);
+            session.tokens_used = session.tokens_used.saturating_add(tokens);
+        }
+    }
+
+    /// Tokens left in the session, not counting those reserved by runs in flight, or `None` if it is
+    /// unknown or expired.
+    pub fn remaining_budget(&self, session_id: &str) -> Option<u64> {
+        let state = self.state.lock().unwrap();
+        let session = state.sessions.get(session_id)?;
+        if session.created_at.elapsed() >= self.session_ttl() {
+            return None;
+        }
+        Some(session.remaining(self.config.session_token_budget))
+    }
+
+    /// Reject agents on a model outside the allowed set and clamp their loops.
+    pub fn restrict_agent(&self, agent: &mut LlmAgent) -> Result<(), DemoError> {
+        if !self.config.allowed_models.iter().any(|model| model == &agent.llm) {
+            return Err(DemoError::ModelNotAllowed(agent.llm.clone()));
+        }
+        agent.max_loops = agent.max_loops.clamp(1, self.config.max_loops.max(1));
+        Ok(())
+    }
+
+    /// Check a tool name a request asked for.
+    pub fn check_tool(&self, tool: &str) -> Result<(), DemoError> {
+        if self.config.allowed_tools.iter().any(|allowed| allowed == tool) {
+            Ok(())
+        } else {
+            Err(DemoError::ToolNotAllowed(tool.to_string()))
+        }
+    }
+
+    /// The registry with every tool outside `allowed_tools` removed.
+    #[cfg(feature = "tools")]
+    pub fn restrict_tools(&self, registry: &ToolRegistry) -> ToolRegistry {
+        registry.restricted(|name| self.config.allowed_tools.iter().any(|allowed| allowed == name))
+    }
+
+    /// Serve `POST /demo/sessions`, `GET /demo/sessions/<id>`, and `GET /demo/limits`.
+    pub fn handle_http(&self, method: &str, path: &str, ip: IpAddr) -> (u16, JsonValue) {
+        let path = path.trim_end_matches('/');
+        match (method, path.strip_prefix("/demo")) {
+            ("POST", Some("/sessions")) => match self.open_session(ip) {
+                Ok(session_id) => (
+                    201,
+                    json!({
+                        "session_id": session_id,
+                        "expires_in_secs": self.config.session_ttl_secs,
+                        "token_budget": self.config.session_token_budget,
+                        "models": self.config.allowed_models,
+                        "tools": self.config.allowed_tools,
+                    }),
+                ),
+                Err(err) => (err.status(), err.to_json()),
+            },
+            ("GET", Some(rest)) if rest.starts_with("/sessions/") => {
+                match self.remaining_budget(&rest["/sessions/".len()..]) {
+                    Some(remaining) => (200, json!({ "remaining_tokens": remaining })),
+                    None => (DemoError::UnknownSession.status(), DemoError::UnknownSession.to_json()),
+                }
+            }
+            ("GET", Some("/limits")) => (200, json!(*self.config)),
+            _ => (404, Problem::no_route(method, path).to_value()),
+        }
+    }
+}
+
+fn main() {
+    let gate = DemoGate::new(DemoConfig { burst: 2, ..DemoConfig::default() });
+    let ip: IpAddr = "203.0.113.7".parse().unwrap();
+    let (status, body) = gate.handle_http("POST", "/demo/sessions", ip);
+    println!("{} {}", status, body);
+    let session_id = body["session_id"].as_str().unwrap_or_default();
+    println!("{:?}", gate.admit(session_id, ip, Some(4_096)));
+    println!("{:?}", gate.admit(session_id, ip, None));
+}
+```
+
+### Notes
+* Limits are keyed by the client IP the server sees. Behind a proxy, pass the address from a trusted `X-Forwarded-For` hop, not the header as sent by the client.
+* A run's `max_tokens` are reserved when it is admitted, so a burst of concurrent requests is capped by the budget together. A session can still overshoot by the prompt tokens of its runs, which `record_usage` charges after the fact.
+* An admission that is never reported keeps its reservation until the session expires. Servers call `record_usage` on every path, errors included.
+* The token budget counts whatever the server reports, prompt tokens included; size `session_token_budget` with the system prompts in mind.
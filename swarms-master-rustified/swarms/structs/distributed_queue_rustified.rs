@@ -0,0 +1,731 @@
+### Overview
+There is no Python counterpart. `TaskQueueSwarm` runs every agent in one process; this module adds a
+coordinator/worker mode that turns it into a horizontally scalable system. A coordinator process owns the
+task queue and the run store. Worker processes, possibly on other machines, register over gRPC with the
+agents they host, claim tasks that one of their agents can run, and stream results back as they are
+produced.
+
+Claims are leases: a worker must heartbeat while it holds a task, and a lease that expires (the worker
+crashed or was partitioned away) puts the task back on the queue, unless it has already been claimed
+`max_attempts` times; then it is left `failed` with the `cancelled` code, so a task that crashes every worker
+is not handed out forever. A task is therefore run at least once; results from a worker whose lease has been
+reassigned are discarded.
+
+A task that fails is classified (see `failure`). A retriable failure, such as a provider rate limit or
+timeout, puts the task back on the queue until it has been claimed `max_attempts` times. The task is not
+claimed again until a backoff has passed, doubling from `DEFAULT_RETRY_BACKOFF` with each attempt up to
+`DEFAULT_MAX_RETRY_BACKOFF` (see `with_retry_backoff`), so a rate-limited provider is not hit again at
+once. Any other failure, or one that has used up its attempts, leaves it `failed` with its `error_code`,
+which is where dead-letter handling picks it up.
+
+The ledger is tenant-scoped (see `tenancy`). Every task carries the `TenantId` that submitted it, a worker
+registers under one tenant, and it only ever claims that tenant's tasks. Every call carries the tenant's API
+key in its `authorization` metadata; the coordinator takes the tenant from `TenantKeys::authenticate`,
+refuses a registration that names another tenant, and only accepts heartbeats, claims, and results from
+workers registered under the caller's tenant. `list`, served by `handle_http`
+as `GET /runs`, returns one page of a tenant's tasks, filtered by status or agent and sorted by submission time, agent, or status
+(see `pagination`).
+
+The service is defined in `proto/distributed.proto` (package `swarms.distributed`), which the build script
+compiles with `tonic-build`: `Register`, `Heartbeat`, and `ClaimTask` are unary calls, and `StreamResult`
+is a client stream of `ResultChunk`s that ends with one `ResultAck`.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+use crate::swarms::structs::failure::{Failure, FailureCode};
+use crate::swarms::structs::health::{ComponentKind, HealthCheck};
+use crate::swarms::structs::pagination::{paginate, parse_sort, Page, PageError, PageRequest};
+use crate::swarms::structs::step_guard::{guard_step, StepStatus};
+use crate::swarms::structs::tenancy::{TenantId, TenantKeys};
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio_stream::StreamExt;
+use tonic::transport::{Channel, Server};
+use tonic::metadata::{Ascii, MetadataValue};
+use tonic::{Request, Response, Status, Streaming};
+use uuid::Uuid;
+
+pub mod proto {
+    tonic::include_proto!("swarms.distributed");
+}
+
+use proto::task_coordinator_client::TaskCoordinatorClient;
+use proto::task_coordinator_server::{TaskCoordinator, TaskCoordinatorServer};
+use proto::{
+    ClaimReply, ClaimRequest, HeartbeatReply, HeartbeatRequest, RegisterReply, RegisterRequest, ResultAck, ResultChunk,
+};
+
+pub const DEFAULT_LEASE: Duration = Duration::from_secs(30);
+/// How many times a task with retriable failures is claimed before it is left failed.
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 3;
+/// The wait before a task that failed retriably is claimed again, doubled for each further attempt.
+pub const DEFAULT_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+pub const DEFAULT_MAX_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum TaskStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// A task as kept in the coordinator's run store.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct TaskRecord {
+    pub task_id: String,
+    #[cfg_attr(feature = "openapi", schema(value_type = String))]
+    pub tenant_id: TenantId,
+    pub agent_name: String,
+    pub task: String,
+    pub status: TaskStatus,
+    pub worker_id: Option<String>,
+    pub output: String,
+    pub error: Option<String>,
+    /// The kind of the last failure, kept when a retriable failure puts the task back on the queue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<FailureCode>,
+    #[serde(default)]
+    pub retriable: bool,
+    pub attempts: u32,
+    /// RFC 3339, UTC.
+    #[serde(default)]
+    pub submitted_at: String,
+}
+
+/// What `TaskLedger::list` returns. Empty filters match everything.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunQuery {
+    pub status: Option<TaskStatus>,
+    pub agent_name: Option<String>,
+    /// `submitted_at`, `agent_name` or `status`, with a leading `-` for descending.
+    pub sort: String,
+    pub page: PageRequest,
+}
+
+impl Default for RunQuery {
+    fn default() -> Self {
+        RunQuery { status: None, agent_name: None, sort: "-submitted_at".to_string(), page: PageRequest::default() }
+    }
+}
+
+impl RunQuery {
+    /// Parse `status=failed&agent=Analyst&sort=-submitted_at&limit=20&cursor=...`.
+    pub fn from_query_string(query: &str) -> Result<Self, PageError> {
+        let mut parsed = RunQuery::default();
+        for pair in query.split('&').filter(|pair| !pair.is_empty()) {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = urlencoding::decode(value)
+                .map_err(|err| PageError::InvalidParameter(err.to_string()))?
+                .into_owned();
+            match key {
+                "agent" => parsed.agent_name = Some(value),
+                "sort" => parsed.sort = value,
+                "status" => {
+                    let status = serde_json::from_value(serde_json::Value::String(value.clone()))
+                        .map_err(|_| PageError::InvalidParameter(format!("unknown status '{}'", value)))?;
+                    parsed.status = Some(status);
+                }
+                _ if parsed.page.parse_param(key, &value)? => {}
+                _ => return Err(PageError::InvalidParameter(format!("unknown parameter '{}'", key))),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+impl TaskStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaskStatus::Queued => "queued",
+            TaskStatus::Running => "running",
+            TaskStatus::Completed => "completed",
+            TaskStatus::Failed => "failed",
+        }
+    }
+}
+
+struct Lease {
+    worker_id: String,
+    expires: Instant,
+}
+
+struct WorkerInfo {
+    name: String,
+    tenant_id: TenantId,
+    agents: Vec<String>,
+}
+
+// `initial` doubled for each attempt after the first, capped at `max`
+fn backoff(initial: Duration, max: Duration, attempt: u32) -> Duration {
+    initial.saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1))).min(max)
+}
+
+/// The coordinator's queue, leases, and run store. Kept free of gRPC types so it can be tested directly.
+pub struct TaskLedger {
+    lease: Duration,
+    max_attempts: u32,
+    retry_backoff: Duration,
+    max_retry_backoff: Duration,
+    queue: VecDeque<String>,
+    records: HashMap<String, TaskRecord>,
+    leases: HashMap<String, Lease>,
+    // Requeued tasks that may not be claimed before the given time
+    retry_at: HashMap<String, Instant>,
+    workers: HashMap<String, WorkerInfo>,
+}
+
+impl TaskLedger {
+    pub fn new(lease: Duration) -> Self {
+        TaskLedger {
+            lease,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            retry_backoff: DEFAULT_RETRY_BACKOFF,
+            max_retry_backoff: DEFAULT_MAX_RETRY_BACKOFF,
+            queue: VecDeque::new(),
+            records: HashMap::new(),
+            leases: HashMap::new(),
+            retry_at: HashMap::new(),
+            workers: HashMap::new(),
+        }
+    }
+
+    /// Claim a task at most `attempts` times when its failures are retriable.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Wait `initial` before the first retry of a retriable failure, doubling per attempt up to `max`.
+    pub fn with_retry_backoff(mut self, initial: Duration, max: Duration) -> Self {
+        self.retry_backoff = initial;
+        self.max_retry_backoff = max.max(initial);
+        self
+    }
+
+    /// The wait before a task is claimed again after failing on attempt `attempt` (one-based).
+    pub fn retry_backoff(&self, attempt: u32) -> Duration {
+        backoff(self.retry_backoff, self.max_retry_backoff, attempt)
+    }
+
+    /// Queue `task` for the tenant's agent named `agent_name`, or for any of the tenant's agents when it is
+    /// empty. Returns the task id.
+    pub fn submit(&mut self, tenant_id: &TenantId, agent_name: &str, task: &str) -> String {
+        let task_id = new_id(IdKind::Task);
+        self.records.insert(
+            task_id.clone(),
+            TaskRecord {
+                task_id: task_id.clone(),
+                tenant_id: tenant_id.clone(),
+                agent_name: agent_name.to_string(),
+                task: task.to_string(),
+                status: TaskStatus::Queued,
+                worker_id: None,
+                output: String::new(),
+                error: None,
+                error_code: None,
+                retriable: false,
+                attempts: 0,
+                submitted_at: chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Micros, true),
+            },
+        );
+        self.queue.push_back(task_id.clone());
+        task_id
+    }
+
+    pub fn register(&mut self, tenant_id: &TenantId, worker_name: &str, agents: Vec<String>) -> String {
+        let worker_id = Uuid::new_v4().to_string();
+        info!("Worker {} ({}) registered for tenant {} with agents {:?}", worker_name, worker_id, tenant_id, agents);
+        let info = WorkerInfo { name: worker_name.to_string(), tenant_id: tenant_id.clone(), agents };
+        self.workers.insert(worker_id.clone(), info);
+        worker_id
+    }
+
+    // Whether `worker_id` is registered under `tenant_id`
+    fn serves(&self, tenant_id: &TenantId, worker_id: &str) -> bool {
+        self.workers.get(worker_id).is_some_and(|worker| &worker.tenant_id == tenant_id)
+    }
+
+    /// Requeue tasks whose lease has run out. A task that has already been claimed `max_attempts` times is left
+    /// failed instead, so a task that takes down every worker that runs it is not handed out forever.
+    pub fn expire_leases(&mut self, now: Instant) {
+        let expired: Vec<String> = self
+            .leases
+            .iter()
+            .filter(|(_, lease)| lease.expires <= now)
+            .map(|(task_id, _)| task_id.clone())
+            .collect();
+        for task_id in expired {
+            let lease = self.leases.remove(&task_id).expect("lease listed above");
+            let worker = self.workers.get(&lease.worker_id).map_or("unknown", |w| w.name.as_str());
+            let Some(record) = self.records.get_mut(&task_id) else {
+                continue;
+            };
+            record.worker_id = None;
+            if record.attempts >= self.max_attempts {
+                warn!(
+                    "Lease on task {} held by worker {} expired on attempt {} of {}; leaving it failed",
+                    task_id, worker, record.attempts, self.max_attempts
+                );
+                record.status = TaskStatus::Failed;
+                record.error_code = Some(FailureCode::Cancelled);
+                record.retriable = false;
+                record.error = Some(format!("lease expired after {} attempts", record.attempts));
+                continue;
+            }
+            warn!("Lease on task {} held by worker {} expired; requeueing", task_id, worker);
+            record.status = TaskStatus::Queued;
+            record.output.clear();
+            self.queue.push_front(task_id);
+        }
+    }
+
+    /// Hand the first queued task of the worker's tenant that one of its agents can run to `worker_id`, skipping
+    /// tasks still waiting out a retry backoff.
+    pub fn claim(&mut self, worker_id: &str, now: Instant) -> Option<TaskRecord> {
+        self.expire_leases(now);
+        let worker = self.workers.get(worker_id)?;
+        let agents = &worker.agents;
+        let pos = self.queue.iter().position(|id| {
+            self.records.get(id).map_or(false, |r| {
+                r.tenant_id == worker.tenant_id && (r.agent_name.is_empty() || agents.contains(&r.agent_name))
+            }) && self.retry_at.get(id).map_or(true, |at| *at <= now)
+        })?;
+        let first_agent = agents.first().cloned().unwrap_or_default();
+        let task_id = self.queue.remove(pos)?;
+        self.retry_at.remove(&task_id);
+        self.leases.insert(task_id.clone(), Lease { worker_id: worker_id.to_string(), expires: now + self.lease });
+        let record = self.records.get_mut(&task_id)?;
+        record.status = TaskStatus::Running;
+        record.worker_id = Some(worker_id.to_string());
+        record.attempts += 1;
+        let mut claimed = record.clone();
+        if claimed.agent_name.is_empty() {
+            claimed.agent_name = first_agent;
+        }
+        Some(claimed)
+    }
+
+    /// Extend the leases `worker_id` still holds. Returns the ids it no longer holds.
+    pub fn heartbeat(&mut self, worker_id: &str, task_ids: &[String], now: Instant) -> Vec<String> {
+        self.expire_leases(now);
+        let mut revoked = Vec::new();
+        for task_id in task_ids {
+            match self.leases.get_mut(task_id) {
+                Some(lease) if lease.worker_id == worker_id => lease.expires = now + self.lease,
+                _ => revoked.push(task_id.clone()),
+            }
+        }
+        revoked
+    }
+
+    /// Apply a streamed result chunk. Returns false if the worker no longer holds the task. A final chunk with a
+    /// retriable failure puts the task back on the queue while it has attempts left, claimable once its
+    /// `retry_backoff` after `now` has passed.
+    pub fn record_chunk(
+        &mut self,
+        worker_id: &str,
+        task_id: &str,
+        delta: &str,
+        done: bool,
+        failure: Option<Failure>,
+        now: Instant,
+    ) -> bool {
+        match self.leases.get(task_id) {
+            Some(lease) if lease.worker_id == worker_id => {}
+            _ => return false,
+        }
+        let Some(record) = self.records.get_mut(task_id) else {
+            return false;
+        };
+        record.output.push_str(delta);
+        if done {
+            self.leases.remove(task_id);
+            record.status = if failure.is_some() { TaskStatus::Failed } else { TaskStatus::Completed };
+            record.error_code = failure.as_ref().map(|failure| failure.code);
+            record.retriable = failure.as_ref().is_some_and(|failure| failure.retriable);
+            record.error = failure.map(|failure| failure.message);
+            if record.retriable && record.attempts < self.max_attempts {
+                let backoff = backoff(self.retry_backoff, self.max_retry_backoff, record.attempts);
+                warn!(
+                    "Task {} failed with {} on attempt {} of {}; requeueing in {:?}",
+                    task_id,
+                    record.error_code.unwrap_or_default(),
+                    record.attempts,
+                    self.max_attempts,
+                    backoff
+                );
+                record.status = TaskStatus::Queued;
+                record.worker_id = None;
+                record.output.clear();
+                self.retry_at.insert(task_id.to_string(), now + backoff);
+                self.queue.push_back(task_id.to_string());
+            }
+        }
+        true
+    }
+
+    /// The task, if it exists and belongs to `tenant_id`. Another tenant's task looks the same as a missing one.
+    pub fn record(&self, tenant_id: &TenantId, task_id: &str) -> Option<&TaskRecord> {
+        self.records.get(task_id).filter(|record| &record.tenant_id == tenant_id)
+    }
+
+    /// Every task of one tenant.
+    pub fn records(&self, tenant_id: &TenantId) -> Vec<&TaskRecord> {
+        self.records.values().filter(|record| &record.tenant_id == tenant_id).collect()
+    }
+
+    /// One page of a tenant's tasks, filtered and sorted as `query` says.
+    pub fn list(&self, tenant_id: &TenantId, query: &RunQuery) -> Result<Page<TaskRecord>, PageError> {
+        let (field, order) = parse_sort(&query.sort);
+        let sort_key: fn(&TaskRecord) -> String = match field {
+            "submitted_at" => |record| record.submitted_at.clone(),
+            "agent_name" => |record| record.agent_name.clone(),
+            "status" => |record| record.status.as_str().to_string(),
+            other => return Err(PageError::InvalidParameter(format!("cannot sort runs by '{}'", other))),
+        };
+        let matching: Vec<TaskRecord> = self
+            .records(tenant_id)
+            .into_iter()
+            .filter(|record| query.status.map_or(true, |status| record.status == status))
+            .filter(|record| query.agent_name.as_ref().map_or(true, |agent| &record.agent_name == agent))
+            .cloned()
+            .collect();
+        paginate(matching, &query.sort, order, &query.page, |record| (sort_key(record), record.task_id.clone()))
+    }
+
+    /// Serve `GET /runs` for the tenant whose API key is in the `Authorization` header. Returns a status code,
+    /// content type, and body.
+    pub fn handle_http(
+        &self,
+        keys: &TenantKeys,
+        authorization: Option<&str>,
+        method: &str,
+        path: &str,
+    ) -> (u16, &'static str, String) {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let error = |problem: Problem| (problem.status, PROBLEM_JSON, problem.to_json());
+        let tenant_id = match keys.authenticate(authorization) {
+            Ok(tenant_id) => tenant_id,
+            Err(err) => return error(Problem::from(&err)),
+        };
+        match (method, path.trim_end_matches('/')) {
+            ("GET", "/runs") => match RunQuery::from_query_string(query).and_then(|query| self.list(&tenant_id, &query)) {
+                Ok(page) => (200, "application/json", serde_json::to_string(&page).expect("runs always serialize")),
+                Err(err) => error(Problem::from(&err)),
+            },
+            (method, path) => error(Problem::no_route(method, path)),
+        }
+    }
+
+    pub fn pending(&self) -> usize {
+        self.queue.len() + self.leases.len()
+    }
+}
+
+/// The gRPC service backed by a shared `TaskLedger`.
+#[derive(Clone)]
+pub struct Coordinator {
+    ledger: Arc<Mutex<TaskLedger>>,
+    keys: Arc<TenantKeys>,
+}
+
+impl Coordinator {
+    /// A coordinator that knows no API keys, and so refuses every worker until `with_keys` is called.
+    pub fn new(lease: Duration) -> Self {
+        Coordinator { ledger: Arc::new(Mutex::new(TaskLedger::new(lease))), keys: Arc::new(TenantKeys::new()) }
+    }
+
+    /// Accept workers that present one of `keys`, each acting as the key's tenant.
+    pub fn with_keys(mut self, keys: TenantKeys) -> Self {
+        self.keys = Arc::new(keys);
+        self
+    }
+
+    pub fn ledger(&self) -> Arc<Mutex<TaskLedger>> {
+        Arc::clone(&self.ledger)
+    }
+
+    pub async fn serve(self, addr: std::net::SocketAddr) -> Result<(), tonic::transport::Error> {
+        info!("Coordinator listening on {}", addr);
+        Server::builder().add_service(TaskCoordinatorServer::new(self)).serve(addr).await
+    }
+
+    /// Serve on an already bound listener, such as one bound to port 0.
+    pub async fn serve_on(self, listener: tokio::net::TcpListener) -> Result<(), tonic::transport::Error> {
+        info!("Coordinator listening on {:?}", listener.local_addr());
+        let incoming = tokio_stream::wrappers::TcpListenerStream::new(listener);
+        Server::builder().add_service(TaskCoordinatorServer::new(self)).serve_with_incoming(incoming).await
+    }
+
+    // The tenant of the API key in the request's `authorization` metadata
+    fn authenticate<T>(&self, request: &Request<T>) -> Result<TenantId, Status> {
+        let authorization = request.metadata().get("authorization").and_then(|value| value.to_str().ok());
+        self.keys.authenticate(authorization).map_err(|err| Status::unauthenticated(err.to_string()))
+    }
+}
+
+// The queue backend is healthy while its ledger can be locked; a panic while holding it poisons the lock.
+#[tonic::async_trait]
+impl HealthCheck for Coordinator {
+    fn name(&self) -> String {
+        "task_queue".to_string()
+    }
+
+    fn kind(&self) -> ComponentKind {
+        ComponentKind::Queue
+    }
+
+    async fn check(&self) -> Result<Option<String>, String> {
+        match self.ledger.lock() {
+            Ok(ledger) => Ok(Some(format!("{} pending", ledger.pending()))),
+            Err(_) => Err("task ledger lock is poisoned".to_string()),
+        }
+    }
+}
+
+#[tonic::async_trait]
+impl TaskCoordinator for Coordinator {
+    async fn register(&self, request: Request<RegisterRequest>) -> Result<Response<RegisterReply>, Status> {
+        let tenant_id = self.authenticate(&request)?;
+        let request = request.into_inner();
+        if request.agent_names.is_empty() {
+            return Err(Status::invalid_argument("a worker must host at least one agent"));
+        }
+        if request.tenant_id != tenant_id.as_str() {
+            return Err(Status::permission_denied(format!("the API key does not belong to tenant '{}'", request.tenant_id)));
+        }
+        let mut ledger = self.ledger.lock().unwrap();
+        let worker_id = ledger.register(&tenant_id, &request.worker_name, request.agent_names);
+        Ok(Response::new(RegisterReply { worker_id, lease_secs: ledger.lease.as_secs() }))
+    }
+
+    async fn heartbeat(&self, request: Request<HeartbeatRequest>) -> Result<Response<HeartbeatReply>, Status> {
+        let tenant_id = self.authenticate(&request)?;
+        let request = request.into_inner();
+        let mut ledger = self.ledger.lock().unwrap();
+        if !ledger.serves(&tenant_id, &request.worker_id) {
+            return Err(Status::unauthenticated("unknown worker; register first"));
+        }
+        let revoked = ledger.heartbeat(&request.worker_id, &request.task_ids, Instant::now());
+        Ok(Response::new(HeartbeatReply { revoked_task_ids: revoked }))
+    }
+
+    // Another tenant's worker looks the same as one that never registered
+    async fn claim_task(&self, request: Request<ClaimRequest>) -> Result<Response<ClaimReply>, Status> {
+        let tenant_id = self.authenticate(&request)?;
+        let worker_id = request.into_inner().worker_id;
+        let mut ledger = self.ledger.lock().unwrap();
+        if !ledger.serves(&tenant_id, &worker_id) {
+            return Err(Status::unauthenticated("unknown worker; register first"));
+        }
+        Ok(Response::new(match ledger.claim(&worker_id, Instant::now()) {
+            Some(record) => ClaimReply { has_task: true, task_id: record.task_id, agent_name: record.agent_name, task: record.task },
+            None => ClaimReply::default(),
+        }))
+    }
+
+    async fn stream_result(&self, request: Request<Streaming<ResultChunk>>) -> Result<Response<ResultAck>, Status> {
+        let tenant_id = self.authenticate(&request)?;
+        let mut chunks = request.into_inner();
+        let mut accepted = true;
+        while let Some(chunk) = chunks.next().await {
+            let chunk = chunk?;
+            if !self.ledger.lock().unwrap().serves(&tenant_id, &chunk.worker_id) {
+                return Err(Status::unauthenticated("unknown worker; register first"));
+            }
+            // Workers that predate failure codes send none; their errors are classified here
+            let failure = (!chunk.error.is_empty()).then(|| match chunk.error_code.parse::<FailureCode>() {
+                Ok(code) => Failure { code, retriable: chunk.retriable, message: chunk.error.clone() },
+                Err(_) => Failure::of(&chunk.error),
+            });
+            accepted &= self
+                .ledger
+                .lock()
+                .unwrap()
+                .record_chunk(&chunk.worker_id, &chunk.task_id, &chunk.delta, chunk.done, failure, Instant::now());
+        }
+        Ok(Response::new(ResultAck { accepted }))
+    }
+}
+
+/// Runs a task for a named agent, reporting output incrementally through `emit`.
+pub trait TaskRunner: Send + Sync {
+    fn run(&self, agent_name: &str, task: &str, emit: &mut dyn FnMut(&str)) -> Result<(), String>;
+}
+
+// `message` with `Bearer <api_key>` in its `authorization` metadata. A key that is not valid metadata is
+// left out, and the coordinator refuses the call.
+fn authorized<T>(api_key: &str, message: T) -> Request<T> {
+    let mut request = Request::new(message);
+    match format!("Bearer {}", api_key).parse::<MetadataValue<Ascii>>() {
+        Ok(value) => {
+            request.metadata_mut().insert("authorization", value);
+        }
+        Err(_) => warn!("The worker's API key cannot be sent as gRPC metadata"),
+    }
+    request
+}
+
+/// A worker process: registers, then claims and runs tasks until `shutdown` is triggered.
+pub struct Worker {
+    name: String,
+    tenant_id: TenantId,
+    // Sent with every call; the coordinator takes the tenant from it
+    api_key: String,
+    agents: Vec<String>,
+    runner: Arc<dyn TaskRunner>,
+    poll_interval: Duration,
+}
+
+impl Worker {
+    /// A worker for `tenant_id`, authenticating with one of the tenant's API keys.
+    pub fn new(
+        name: impl Into<String>,
+        tenant_id: TenantId,
+        api_key: impl Into<String>,
+        agents: Vec<String>,
+        runner: Arc<dyn TaskRunner>,
+    ) -> Self {
+        Worker { name: name.into(), tenant_id, api_key: api_key.into(), agents, runner, poll_interval: Duration::from_millis(500) }
+    }
+
+    pub async fn run(self, coordinator_url: String, shutdown: tokio_util::sync::CancellationToken) -> Result<(), Status> {
+        let channel = Channel::from_shared(coordinator_url)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?
+            .connect()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let mut client = TaskCoordinatorClient::new(channel);
+        let register = RegisterRequest {
+            worker_name: self.name.clone(),
+            agent_names: self.agents.clone(),
+            tenant_id: self.tenant_id.to_string(),
+        };
+        let registered = client
+            .register(authorized(&self.api_key, register))
+            .await?
+            .into_inner();
+        let worker_id = registered.worker_id;
+        let lease = Duration::from_secs(registered.lease_secs.max(3));
+        info!("Worker {} registered as {}", self.name, worker_id);
+
+        while !shutdown.is_cancelled() {
+            let claim = ClaimRequest { worker_id: worker_id.clone() };
+            let claim = client.claim_task(authorized(&self.api_key, claim)).await?.into_inner();
+            if !claim.has_task {
+                tokio::select! {
+                    _ = tokio::time::sleep(self.poll_interval) => continue,
+                    _ = shutdown.cancelled() => break,
+                }
+            }
+            self.run_task(&mut client, &worker_id, claim, lease).await?;
+        }
+        Ok(())
+    }
+
+    async fn run_task(
+        &self,
+        client: &mut TaskCoordinatorClient<Channel>,
+        worker_id: &str,
+        claim: ClaimReply,
+        lease: Duration,
+    ) -> Result<(), Status> {
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel::<ResultChunk>();
+        let chunk = {
+            let (worker_id, task_id) = (worker_id.to_string(), claim.task_id.clone());
+            move |delta: String, done: bool, failure: Option<Failure>| {
+                let (error, error_code, retriable) = match failure {
+                    Some(failure) => (failure.message, failure.code.to_string(), failure.retriable),
+                    None => (String::new(), String::new(), false),
+                };
+                ResultChunk { worker_id: worker_id.clone(), task_id: task_id.clone(), delta, done, error, error_code, retriable }
+            }
+        };
+
+        // Heartbeat at a third of the lease while the agent runs.
+        let mut heartbeat_client = client.clone();
+        let heartbeat = {
+            let (worker_id, task_id, api_key) = (worker_id.to_string(), claim.task_id.clone(), self.api_key.clone());
+            tokio::spawn(async move {
+                loop {
+                    tokio::time::sleep(lease / 3).await;
+                    let beat = HeartbeatRequest { worker_id: worker_id.clone(), task_ids: vec![task_id.clone()] };
+                    let reply = heartbeat_client.heartbeat(authorized(&api_key, beat)).await;
+                    if matches!(reply, Ok(ref r) if !r.get_ref().revoked_task_ids.is_empty()) {
+                        warn!("Lease on task {} was revoked", task_id);
+                        break;
+                    }
+                }
+            })
+        };
+
+        let runner = Arc::clone(&self.runner);
+        let (agent_name, task) = (claim.agent_name.clone(), claim.task.clone());
+        let run = tokio::task::spawn_blocking(move || {
+            let mut emit = |delta: &str| {
+                let _ = tx.send(chunk(delta.to_string(), false, None));
+            };
+            // A panicking runner still reports the task as done-with-error instead of dropping the stream.
+            let (outcome, step) = guard_step(&agent_name, &task, || runner.run(&agent_name, &task, &mut emit));
+            let failure = match (outcome, step.status) {
+                (Some(Err(err)), _) => Some(Failure::of(&err)),
+                (_, StepStatus::Panicked { message }) => {
+                    Some(Failure::new(FailureCode::Panicked, format!("agent panicked: {}", message)))
+                }
+                _ => None,
+            };
+            let _ = tx.send(chunk(String::new(), true, failure));
+        });
+
+        let chunks = tokio_stream::wrappers::UnboundedReceiverStream::new(rx);
+        let ack = client.stream_result(authorized(&self.api_key, chunks)).await;
+        let _ = run.await;
+        heartbeat.abort();
+        if !ack?.into_inner().accepted {
+            warn!("Coordinator discarded the result of task {}", claim.task_id);
+        }
+        Ok(())
+    }
+}
+
+struct EchoRunner;
+
+impl TaskRunner for EchoRunner {
+    fn run(&self, agent_name: &str, task: &str, emit: &mut dyn FnMut(&str)) -> Result<(), String> {
+        emit(&format!("{} handled: {}", agent_name, task));
+        Ok(())
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let tenant = TenantId::new("acme")?;
+    let coordinator = Coordinator::new(DEFAULT_LEASE).with_keys(TenantKeys::new().with_key("sk-acme-123", tenant.clone()));
+    coordinator.ledger().lock().unwrap().submit(&tenant, "Financial-Analyst", "Summarize Q3 revenue drivers");
+    tokio::spawn(coordinator.serve("127.0.0.1:50051".parse()?));
+
+    let shutdown = tokio_util::sync::CancellationToken::new();
+    let worker = Worker::new("worker-1", tenant, "sk-acme-123", vec!["Financial-Analyst".to_string()], Arc::new(EchoRunner));
+    let handle = tokio::spawn(worker.run("http://127.0.0.1:50051".to_string(), shutdown.clone()));
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    shutdown.cancel();
+    handle.await??;
+    Ok(())
+}
+```
+
+### Notes
+* The tenant named in `RegisterRequest.tenant_id` is only checked against the API key; it never grants access on its own.
+* A worker serves exactly one tenant. Hosting agents for several tenants means running one `Worker` per tenant, so one tenant's credentials never run another tenant's task.
+* Workers only claim tasks for agents they registered, so heterogeneous workers (GPU hosts, hosts with private credentials) can share one coordinator.
+* The run store is in memory. A coordinator restart loses queued work; persisting `TaskLedger` is left to the deployment.
+* Results from a task whose lease expired mid-run are rejected (`ResultAck.accepted = false`); the reassigned run's output wins.
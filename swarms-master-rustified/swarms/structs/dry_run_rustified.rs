@@ -0,0 +1,331 @@
+### Overview
+There is no Python counterpart; a Python swarm's cost is known only once the provider bills it. `Swarm::dry_run`
+walks a swarm's plan instead of running it. The swarm lists the model calls a run would make as
+`PlannedCall`s: which agent, which model, whether the call reads the task, and which earlier calls' output it
+reads. `CostEstimator` then prices each call without contacting a provider:
+
+* Prompt tokens are counted from the text the call would be sent: the agent's system prompt, the task if it
+  reads it, and the estimated output of every call it reads from.
+* Completion tokens are the average the agent produced with that model in past runs, taken from the
+  `UsageTracker` log given to `with_history`. An agent with no history there falls back to the model's
+  average over all agents, then to `default_completion_tokens`.
+* Cost comes from a `Pricing` table. Models missing from it are listed in `DryRun::unpriced_models`.
+
+`DryRun` displays as the planned execution graph, one line per call, followed by the totals:
+
+```text
+Dry run of Research-Pipeline: 6 tokens of task, 2 call(s)
+  [0] Researcher (gpt-4o) <- task    ~46 in  ~800 out  $0.008115  history of 14
+  [1] Writer (gpt-4o) <- [0]         ~840 in  ~800 out  $0.010100  model history of 14
+Total: ~886 prompt + ~1600 completion tokens, $0.018215
+```
+
+`SequentialWorkflow`, `MajorityVoting`, and `RoundRobinSwarm` describe their plans. Swarms whose calls are
+only decided while running, such as `AutoSwarmRouter`, return an error from `dry_run`.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::Agent;
+use crate::swarms::structs::usage::{Pricing, UsageRecord};
+use crate::swarms::text::tokenizer::{default_tokenizer, Tokenizer};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+use std::sync::Arc;
+
+/// Completion tokens assumed for a call with no history.
+pub const DEFAULT_COMPLETION_TOKENS: u64 = 512;
+
+/// One model call a run would make.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedCall {
+    pub agent: String,
+    /// `None` for agents that are not model-backed, such as wrapped swarms; they are counted but not priced.
+    pub model: Option<String>,
+    pub system_prompt: String,
+    /// The call is sent the task.
+    pub reads_task: bool,
+    /// Indices of earlier calls whose output the call is sent.
+    pub after: Vec<usize>,
+    /// Times the agent calls its model per turn, from its `max_loops`.
+    pub loops: u32,
+}
+
+impl PlannedCall {
+    /// A call by `agent` that reads nothing yet; chain `reading_task` and `after`.
+    pub fn new(agent: &dyn Agent) -> Self {
+        let spec = agent.spec();
+        PlannedCall {
+            agent: agent.name().to_string(),
+            model: spec.map(|spec| spec.llm.clone()),
+            system_prompt: spec.map(|spec| spec.system_prompt.clone()).unwrap_or_default(),
+            reads_task: false,
+            after: Vec::new(),
+            loops: spec.map_or(1, |spec| spec.max_loops.max(1) as u32),
+        }
+    }
+
+    pub fn reading_task(mut self) -> Self {
+        self.reads_task = true;
+        self
+    }
+
+    pub fn after(mut self, calls: impl IntoIterator<Item = usize>) -> Self {
+        self.after.extend(calls);
+        self
+    }
+}
+
+/// Where a step's completion estimate came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EstimateSource {
+    /// The average of `samples` past calls by the same agent on the same model.
+    AgentHistory { samples: usize },
+    /// The average of `samples` past calls on the same model by any agent.
+    ModelHistory { samples: usize },
+    Default,
+}
+
+impl fmt::Display for EstimateSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EstimateSource::AgentHistory { samples } => write!(f, "history of {}", samples),
+            EstimateSource::ModelHistory { samples } => write!(f, "model history of {}", samples),
+            EstimateSource::Default => f.write_str("default"),
+        }
+    }
+}
+
+/// The estimate for one planned call, all of its loops included.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepEstimate {
+    pub index: usize,
+    pub agent: String,
+    pub model: Option<String>,
+    pub reads_task: bool,
+    pub after: Vec<usize>,
+    pub calls: u32,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// `None` when the model has no price.
+    pub cost_usd: Option<f64>,
+    pub source: EstimateSource,
+}
+
+/// What a run would cost, estimated without running it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DryRun {
+    pub swarm: String,
+    pub task_tokens: u64,
+    pub steps: Vec<StepEstimate>,
+    pub unpriced_models: Vec<String>,
+}
+
+impl DryRun {
+    pub fn calls(&self) -> u32 {
+        self.steps.iter().map(|step| step.calls).sum()
+    }
+
+    pub fn prompt_tokens(&self) -> u64 {
+        self.steps.iter().map(|step| step.prompt_tokens).sum()
+    }
+
+    pub fn completion_tokens(&self) -> u64 {
+        self.steps.iter().map(|step| step.completion_tokens).sum()
+    }
+
+    /// The cost of the priced steps; see `unpriced_models` for what it leaves out.
+    pub fn total_cost(&self) -> f64 {
+        self.steps.iter().filter_map(|step| step.cost_usd).sum()
+    }
+}
+
+impl fmt::Display for DryRun {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Dry run of {}: {} tokens of task, {} call(s)", self.swarm, self.task_tokens, self.calls())?;
+        let labels: Vec<String> = self
+            .steps
+            .iter()
+            .map(|step| {
+                let mut inputs: Vec<String> = step.after.iter().map(|index| format!("[{}]", index)).collect();
+                if step.reads_task {
+                    inputs.insert(0, "task".to_string());
+                }
+                let model = step.model.as_deref().unwrap_or("no model");
+                let loops = if step.calls > 1 { format!(" x{}", step.calls) } else { String::new() };
+                format!("[{}] {} ({}){} <- {}", step.index, step.agent, model, loops, inputs.join(", "))
+            })
+            .collect();
+        let width = labels.iter().map(|label| label.chars().count()).max().unwrap_or(0);
+        for (step, label) in self.steps.iter().zip(&labels) {
+            let cost = step.cost_usd.map_or_else(|| "unpriced".to_string(), |cost| format!("${:.6}", cost));
+            writeln!(
+                f,
+                "  {:<width$}    ~{} in  ~{} out  {}  {}",
+                label,
+                step.prompt_tokens,
+                step.completion_tokens,
+                cost,
+                step.source,
+                width = width
+            )?;
+        }
+        let (prompt, completion) = (self.prompt_tokens(), self.completion_tokens());
+        write!(f, "Total: ~{} prompt + ~{} completion tokens, ${:.6}", prompt, completion, self.total_cost())?;
+        if !self.unpriced_models.is_empty() {
+            write!(f, " (unpriced: {})", self.unpriced_models.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct Average {
+    samples: usize,
+    completion_tokens: u64,
+}
+
+impl Average {
+    fn add(&mut self, record: &UsageRecord) {
+        self.samples += 1;
+        self.completion_tokens += record.completion_tokens;
+    }
+
+    fn mean(&self) -> Option<(u64, usize)> {
+        (self.samples > 0).then(|| (self.completion_tokens / self.samples as u64, self.samples))
+    }
+}
+
+/// Prices `PlannedCall`s from prompt sizes and past usage.
+#[derive(Clone)]
+pub struct CostEstimator {
+    tokenizer: Arc<dyn Tokenizer>,
+    pricing: Pricing,
+    by_agent: HashMap<(String, String), Average>,
+    by_model: HashMap<String, Average>,
+    default_completion_tokens: u64,
+}
+
+impl Default for CostEstimator {
+    fn default() -> Self {
+        CostEstimator {
+            tokenizer: default_tokenizer(),
+            pricing: Pricing::new(),
+            by_agent: HashMap::new(),
+            by_model: HashMap::new(),
+            default_completion_tokens: DEFAULT_COMPLETION_TOKENS,
+        }
+    }
+}
+
+impl CostEstimator {
+    pub fn new() -> Self {
+        CostEstimator::default()
+    }
+
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn with_pricing(mut self, pricing: Pricing) -> Self {
+        self.pricing = pricing;
+        self
+    }
+
+    /// Learn completion sizes from past calls, e.g. `UsageTracker::records`. May be called more than once.
+    pub fn with_history(mut self, records: &[UsageRecord]) -> Self {
+        for record in records {
+            self.by_agent.entry((record.agent.clone(), record.model.clone())).or_default().add(record);
+            self.by_model.entry(record.model.clone()).or_default().add(record);
+        }
+        self
+    }
+
+    pub fn with_default_completion_tokens(mut self, tokens: u64) -> Self {
+        self.default_completion_tokens = tokens;
+        self
+    }
+
+    fn completion_estimate(&self, agent: &str, model: Option<&str>) -> (u64, EstimateSource) {
+        let Some(model) = model else {
+            return (self.default_completion_tokens, EstimateSource::Default);
+        };
+        if let Some((tokens, samples)) = self.by_agent.get(&(agent.to_string(), model.to_string())).and_then(Average::mean) {
+            return (tokens, EstimateSource::AgentHistory { samples });
+        }
+        match self.by_model.get(model).and_then(Average::mean) {
+            Some((tokens, samples)) => (tokens, EstimateSource::ModelHistory { samples }),
+            None => (self.default_completion_tokens, EstimateSource::Default),
+        }
+    }
+
+    /// Estimate `calls`, a swarm's plan for `task`. Each call may only read calls listed before it.
+    pub fn estimate(&self, swarm: &str, task: &str, calls: &[PlannedCall]) -> DryRun {
+        let task_tokens = self.tokenizer.count_tokens(task) as u64;
+        let mut steps: Vec<StepEstimate> = Vec::with_capacity(calls.len());
+        let mut unpriced = BTreeSet::new();
+        for (index, call) in calls.iter().enumerate() {
+            let (completion, source) = self.completion_estimate(&call.agent, call.model.as_deref());
+            let upstream: u64 = call
+                .after
+                .iter()
+                .filter_map(|earlier| steps.get(*earlier))
+                .map(|step| step.completion_tokens / step.calls.max(1) as u64)
+                .sum();
+            let mut prompt = self.tokenizer.count_tokens(&call.system_prompt) as u64 + upstream;
+            if call.reads_task {
+                prompt += task_tokens;
+            }
+            let loops = call.loops.max(1);
+            let (prompt_tokens, completion_tokens) = (prompt * loops as u64, completion * loops as u64);
+            let cost_usd = call.model.as_deref().and_then(|model| self.pricing.price(model, prompt_tokens, completion_tokens));
+            if cost_usd.is_none() {
+                unpriced.insert(call.model.clone().unwrap_or_else(|| format!("{} (no model)", call.agent)));
+            }
+            steps.push(StepEstimate {
+                index,
+                agent: call.agent.clone(),
+                model: call.model.clone(),
+                reads_task: call.reads_task,
+                after: call.after.clone(),
+                calls: loops,
+                prompt_tokens,
+                completion_tokens,
+                cost_usd,
+                source,
+            });
+        }
+        DryRun { swarm: swarm.to_string(), task_tokens, steps, unpriced_models: unpriced.into_iter().collect() }
+    }
+}
+
+fn main() {
+    use crate::swarms::structs::agent::LlmAgent;
+    use chrono::Utc;
+
+    let researcher = LlmAgent::builder().name("Researcher").llm("gpt-4o").build().unwrap();
+    let writer = LlmAgent::builder().name("Writer").llm("gpt-4o").build().unwrap();
+    let plan = vec![PlannedCall::new(&researcher).reading_task(), PlannedCall::new(&writer).after([0])];
+    let history = vec![UsageRecord {
+        timestamp: Utc::now(),
+        run_id: "run-1".to_string(),
+        agent: "Researcher".to_string(),
+        model: "gpt-4o".to_string(),
+        tags: Vec::new(),
+        prompt_tokens: 120,
+        completion_tokens: 800,
+        estimated: false,
+    }];
+    let estimator = CostEstimator::new().with_pricing(Pricing::new().model("gpt-4o", 2.5, 10.0)).with_history(&history);
+    println!("{}", estimator.estimate("Research-Pipeline", "Summarize Q3 earnings", &plan));
+}
+```
+
+### Notes
+* Each loop of a multi-loop agent is counted as a full call with the same prompt. Loops that feed their own
+  output back in send more than that, so treat estimates for `max_loops > 1` as a floor.
+* Averages come from whatever log `with_history` is given; filter the records (by tag, or to recent runs)
+  before passing them in if older runs used different prompts.
+* Tool calls, retries, and fallbacks are not planned, so a run that needs them costs more than estimated.
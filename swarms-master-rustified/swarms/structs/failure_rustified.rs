@@ -0,0 +1,268 @@
+### Overview
+There is no Python counterpart; the Python package records a failed step as the exception's message, so
+anything deciding what to do next has to read English. `FailureCode` gives every failure in run metadata a
+stable, snake_case code, and `Failure` pairs it with a `retriable` flag:
+
+| code | meaning | retriable by default |
+|------|---------|----------------------|
+| `provider_rate_limit` | the model provider answered 429 or said it was rate limited | yes |
+| `provider_timeout` | the provider call timed out, or it answered 408 or 504 | yes |
+| `provider_error` | any other provider failure; 5xx answers and dropped connections are retriable | no |
+| `tool_error` | a tool the agent called failed | no |
+| `validation_error` | bad input, configuration, or output that does not parse | no |
+| `budget_exceeded` | a token, step, cost, or re-plan limit was reached | no |
+| `cancelled` | the run was cancelled or shut down | no |
+| `panicked` | the step panicked (see `step_guard`) | no |
+| `agent_error` | anything not classified above | no |
+
+`Classify` maps an error to its code. It is implemented for the crate's typed errors (`ProviderError`,
+`AgentError`, `ToolExecutionError`, `BudgetError`, `PlanError`), for `Failure` itself, and for plain
+strings, which many step closures return; those are classified by `FailureCode::classify_message`.
+`StepStatus::Failed` carries the code and flag, so they appear in `TaskQueueSwarm` run metadata and plan
+runs, and the distributed `TaskLedger` uses them to decide whether a failed task is queued again or left
+failed.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::AgentError;
+use crate::swarms::structs::plan_execute::PlanError;
+use crate::swarms::text::context_budget::BudgetError;
+#[cfg(feature = "tools")]
+use crate::swarms::tools::base_tool::ToolExecutionError;
+use crate::swarms::utils::chat_provider::ProviderError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// What kind of failure a step or task ended in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum FailureCode {
+    ProviderRateLimit,
+    ProviderTimeout,
+    ProviderError,
+    ToolError,
+    ValidationError,
+    BudgetExceeded,
+    Cancelled,
+    Panicked,
+    #[default]
+    AgentError,
+}
+
+impl FailureCode {
+    pub const ALL: [FailureCode; 9] = [
+        FailureCode::ProviderRateLimit,
+        FailureCode::ProviderTimeout,
+        FailureCode::ProviderError,
+        FailureCode::ToolError,
+        FailureCode::ValidationError,
+        FailureCode::BudgetExceeded,
+        FailureCode::Cancelled,
+        FailureCode::Panicked,
+        FailureCode::AgentError,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FailureCode::ProviderRateLimit => "provider_rate_limit",
+            FailureCode::ProviderTimeout => "provider_timeout",
+            FailureCode::ProviderError => "provider_error",
+            FailureCode::ToolError => "tool_error",
+            FailureCode::ValidationError => "validation_error",
+            FailureCode::BudgetExceeded => "budget_exceeded",
+            FailureCode::Cancelled => "cancelled",
+            FailureCode::Panicked => "panicked",
+            FailureCode::AgentError => "agent_error",
+        }
+    }
+
+    /// Whether a failure with this code is worth retrying when nothing more specific is known.
+    pub fn retriable_by_default(&self) -> bool {
+        matches!(self, FailureCode::ProviderRateLimit | FailureCode::ProviderTimeout)
+    }
+
+    /// Best-effort code for an error known only by its message.
+    pub fn classify_message(message: &str) -> FailureCode {
+        let message = message.to_lowercase();
+        let has = |needles: &[&str]| needles.iter().any(|needle| message.contains(needle));
+        // A tool that timed out or was rate limited failed as a tool, not as the model provider
+        if has(&["cancelled", "canceled", "shutting down"]) {
+            FailureCode::Cancelled
+        } else if has(&["tool"]) {
+            FailureCode::ToolError
+        } else if has(&["rate limit", "rate-limit", "ratelimit", "too many requests", "returned 429", "status 429"]) {
+            FailureCode::ProviderRateLimit
+        } else if has(&["timed out", "timeout", "deadline exceeded", "returned 504", "returned 408"]) {
+            FailureCode::ProviderTimeout
+        } else if has(&["budget", "limit of", "token limit", "context length", "context window", "max_tokens"]) {
+            FailureCode::BudgetExceeded
+        } else if has(&["invalid", "validation", "schema", "does not parse", "failed to parse"]) {
+            FailureCode::ValidationError
+        } else if has(&["panicked"]) {
+            FailureCode::Panicked
+        } else {
+            FailureCode::AgentError
+        }
+    }
+}
+
+impl fmt::Display for FailureCode {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for FailureCode {
+    type Err = String;
+
+    fn from_str(code: &str) -> Result<Self, Self::Err> {
+        FailureCode::ALL
+            .into_iter()
+            .find(|known| known.as_str() == code)
+            .ok_or_else(|| format!("unknown failure code '{}'", code))
+    }
+}
+
+/// Maps an error to a `FailureCode` and says whether retrying can help.
+pub trait Classify: fmt::Display {
+    fn failure_code(&self) -> FailureCode;
+
+    fn retriable(&self) -> bool {
+        self.failure_code().retriable_by_default()
+    }
+}
+
+impl Classify for ProviderError {
+    fn failure_code(&self) -> FailureCode {
+        match self {
+            ProviderError::Http(err) if err.is_timeout() => FailureCode::ProviderTimeout,
+            ProviderError::Status { status: 429, .. } => FailureCode::ProviderRateLimit,
+            ProviderError::Status { status: 408 | 504, .. } => FailureCode::ProviderTimeout,
+            ProviderError::Status { status: 400 | 422, .. } => FailureCode::ValidationError,
+            ProviderError::MissingApiKey(_) | ProviderError::InvalidConfig(_) => FailureCode::ValidationError,
+            ProviderError::Http(_) | ProviderError::Status { .. } => FailureCode::ProviderError,
+            ProviderError::InvalidResponse(_) | ProviderError::Local(_) => FailureCode::ProviderError,
+        }
+    }
+
+    fn retriable(&self) -> bool {
+        match self {
+            ProviderError::Http(err) => err.is_timeout() || err.is_connect(),
+            ProviderError::Status { status, .. } => matches!(status, 408 | 429 | 500..=599),
+            _ => false,
+        }
+    }
+}
+
+impl Classify for AgentError {
+    fn failure_code(&self) -> FailureCode {
+        match self {
+            AgentError::NoModel(_) => FailureCode::ValidationError,
+            AgentError::Failed { message, .. } => FailureCode::classify_message(message),
+        }
+    }
+}
+
+#[cfg(feature = "tools")]
+impl Classify for ToolExecutionError {
+    fn failure_code(&self) -> FailureCode {
+        FailureCode::ToolError
+    }
+}
+
+impl Classify for PlanError {
+    fn failure_code(&self) -> FailureCode {
+        FailureCode::ValidationError
+    }
+}
+
+impl Classify for Failure {
+    fn failure_code(&self) -> FailureCode {
+        self.code
+    }
+
+    fn retriable(&self) -> bool {
+        self.retriable
+    }
+}
+
+impl Classify for BudgetError {
+    fn failure_code(&self) -> FailureCode {
+        FailureCode::BudgetExceeded
+    }
+}
+
+impl Classify for String {
+    fn failure_code(&self) -> FailureCode {
+        FailureCode::classify_message(self)
+    }
+}
+
+impl Classify for str {
+    fn failure_code(&self) -> FailureCode {
+        FailureCode::classify_message(self)
+    }
+}
+
+impl<T: Classify + ?Sized> Classify for &T {
+    fn failure_code(&self) -> FailureCode {
+        (**self).failure_code()
+    }
+
+    fn retriable(&self) -> bool {
+        (**self).retriable()
+    }
+}
+
+/// A classified failure: what went wrong, what kind of failure it was, and whether to retry.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Failure {
+    pub code: FailureCode,
+    pub retriable: bool,
+    pub message: String,
+}
+
+impl Failure {
+    /// A failure with the code's default `retriable` flag.
+    pub fn new(code: FailureCode, message: impl Into<String>) -> Self {
+        Failure { code, retriable: code.retriable_by_default(), message: message.into() }
+    }
+
+    pub fn of(err: &(impl Classify + ?Sized)) -> Self {
+        Failure { code: err.failure_code(), retriable: err.retriable(), message: err.to_string() }
+    }
+
+    pub fn retriable(mut self, retriable: bool) -> Self {
+        self.retriable = retriable;
+        self
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.code)
+    }
+}
+
+fn main() {
+    let rate_limited = ProviderError::Status { status: 429, body: "slow down".to_string() };
+    let failures = [
+        Failure::of(&rate_limited),
+        Failure::of(&AgentError::Failed { agent: "Analyst".to_string(), message: "request timed out".to_string() }),
+        Failure::of("tool 'search' returned no results"),
+    ];
+    for failure in failures {
+        println!("{} retriable={}: {}", failure.code, failure.retriable, failure.message);
+    }
+}
+```
+
+### Notes
+* Codes are part of the run metadata format, so renaming one breaks readers of saved runs. Older run
+  files without a code load as `agent_error`, not retriable.
+* `classify_message` is a fallback for errors that reach a step as text. Typed errors should implement
+  `Classify` instead: a message match only sees the wording, which can mislead or change.
+* `retriable` says whether the same request can succeed later, not that it should be retried now. Callers
+  still apply their own attempt limits and backoff.
@@ -0,0 +1,362 @@
+### Overview
+There is no Python counterpart; the Python `TaskQueueSwarm` gives every agent one thread on a shared queue,
+so in a mixed swarm the agent with the most tasks queued keeps the workers busy while the others wait.
+`FairScheduler` keeps one lane of queued tasks per agent, plus a shared lane (`*`) for tasks any agent may
+run, and decides which lane a free worker serves next:
+
+* **Per-agent limits.** An agent runs at most `max_in_flight` of its tasks at once (1 unless set with
+  `with_max_in_flight` or `with_default_max_in_flight`). A lane whose agent is at its limit is passed over
+  until one of its tasks finishes. Shared tasks go to the least busy agent under its limit.
+* **Weighted fairness.** Lanes are served by stride scheduling: each dispatch advances the lane's pass by
+  `1 / weight`, and the eligible lane with the lowest pass goes next. While both have work, an agent with
+  weight 2 is served twice as often as one with weight 1. A lane that was empty rejoins at the current
+  pass, so it cannot claim the dispatches it missed as a burst.
+* **Starvation metrics.** Every dispatch records how long the task waited. `stats()` reports per lane the
+  queued and running counts, dispatches, longest wait, and the age of the oldest queued task; the same
+  figures go to `swarms_task_wait_seconds`, `swarms_agent_in_flight`, and `swarms_tasks_starved_total`,
+  which counts tasks that waited longer than the starvation threshold (60 seconds by default).
+
+`TaskQueueSwarm` and `ConcurrentWorkflow` queue their tasks here and run them on a pool of workers; their
+run outputs include the lane stats.
+
+### Rust Code
+```rust
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::sync::{Condvar, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+/// The lane of tasks any agent may run.
+pub const SHARED_LANE: &str = "*";
+
+pub const DEFAULT_MAX_IN_FLIGHT: usize = 1;
+
+pub const DEFAULT_STARVATION_THRESHOLD: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchedulerError {
+    UnknownAgent(String),
+}
+
+impl fmt::Display for SchedulerError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SchedulerError::UnknownAgent(name) => write!(f, "no agent named '{}' in the swarm", name),
+        }
+    }
+}
+
+impl std::error::Error for SchedulerError {}
+
+/// A task handed to a worker. Pass it back to `finish` when the agent is done with it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dispatch {
+    pub agent: String,
+    /// The agent's name, or `SHARED_LANE`.
+    pub lane: String,
+    pub task: String,
+    /// One-based; above 1 for a task put back with `requeue`.
+    pub attempt: u32,
+    pub waited: Duration,
+}
+
+/// How one lane has been served.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LaneStats {
+    pub lane: String,
+    pub weight: f64,
+    pub queued: usize,
+    /// For an agent's lane, every task the agent is running, shared ones included.
+    pub in_flight: usize,
+    pub dispatched: u64,
+    pub max_wait_ms: u64,
+    pub oldest_wait_ms: u64,
+    pub starved: u64,
+}
+
+struct Queued {
+    task: String,
+    attempt: u32,
+    enqueued: Instant,
+}
+
+struct Lane {
+    queue: VecDeque<Queued>,
+    weight: f64,
+    pass: f64,
+    dispatched: u64,
+    max_wait: Duration,
+    starved: u64,
+}
+
+impl Lane {
+    fn new() -> Self {
+        Lane { queue: VecDeque::new(), weight: 1.0, pass: 0.0, dispatched: 0, max_wait: Duration::ZERO, starved: 0 }
+    }
+}
+
+struct Slot {
+    in_flight: usize,
+    max_in_flight: usize,
+}
+
+struct State {
+    lanes: BTreeMap<String, Lane>,
+    slots: BTreeMap<String, Slot>,
+    /// The pass of the lane served last; an empty lane that gets a task starts from here.
+    pass: f64,
+    queued: usize,
+}
+
+impl State {
+    /// The agent that would run the next task of `lane`, if any agent can take it now.
+    fn runner(&self, lane: &str) -> Option<String> {
+        if lane != SHARED_LANE {
+            let slot = &self.slots[lane];
+            return (slot.in_flight < slot.max_in_flight).then(|| lane.to_string());
+        }
+        self.slots
+            .iter()
+            .filter(|(_, slot)| slot.in_flight < slot.max_in_flight)
+            .min_by_key(|(_, slot)| slot.in_flight)
+            .map(|(agent, _)| agent.clone())
+    }
+}
+
+/// Per-agent lanes of queued tasks, served fairly under per-agent concurrency limits.
+pub struct FairScheduler {
+    swarm: String,
+    starvation_threshold: Duration,
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+impl FairScheduler {
+    /// A scheduler for the swarm named `swarm` whose tasks run on `agents`.
+    pub fn new(swarm: &str, agents: &[String]) -> Self {
+        let mut lanes = BTreeMap::from([(SHARED_LANE.to_string(), Lane::new())]);
+        let mut slots = BTreeMap::new();
+        for agent in agents {
+            lanes.insert(agent.clone(), Lane::new());
+            slots.insert(agent.clone(), Slot { in_flight: 0, max_in_flight: DEFAULT_MAX_IN_FLIGHT });
+        }
+        FairScheduler {
+            swarm: swarm.to_string(),
+            starvation_threshold: DEFAULT_STARVATION_THRESHOLD,
+            state: Mutex::new(State { lanes, slots, pass: 0.0, queued: 0 }),
+            changed: Condvar::new(),
+        }
+    }
+
+    /// Let every agent run `limit` tasks at once. Call it before `with_max_in_flight`, which it overrides.
+    pub fn with_default_max_in_flight(mut self, limit: usize) -> Self {
+        for slot in self.state_mut().slots.values_mut() {
+            slot.max_in_flight = limit.max(1);
+        }
+        self
+    }
+
+    /// Let `agent` run `limit` tasks at once. Unknown agents are ignored with a warning.
+    pub fn with_max_in_flight(mut self, agent: &str, limit: usize) -> Self {
+        match self.state_mut().slots.get_mut(agent) {
+            Some(slot) => slot.max_in_flight = limit.max(1),
+            None => warn!("Ignoring the in-flight limit for unknown agent '{}'", agent),
+        }
+        self
+    }
+
+    /// Serve `lane` (an agent's name or `SHARED_LANE`) in proportion to `weight`. Non-positive weights are
+    /// ignored with a warning.
+    pub fn with_weight(mut self, lane: &str, weight: f64) -> Self {
+        match self.state_mut().lanes.get_mut(lane) {
+            Some(entry) if weight > 0.0 && weight.is_finite() => entry.weight = weight,
+            Some(_) => warn!("Ignoring weight {} for lane '{}'; weights must be positive", weight, lane),
+            None => warn!("Ignoring the weight for unknown lane '{}'", lane),
+        }
+        self
+    }
+
+    /// Count a task as starved when it waited longer than `threshold` to be dispatched.
+    pub fn with_starvation_threshold(mut self, threshold: Duration) -> Self {
+        self.starvation_threshold = threshold;
+        self
+    }
+
+    fn state_mut(&mut self) -> &mut State {
+        self.state.get_mut().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock(&self) -> MutexGuard<'_, State> {
+        // Workers never panic while holding the lock, but a poisoned lock still holds consistent counts.
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Queue `task` for `agent`, or for any agent when `agent` is `None`. Returns the number of queued tasks.
+    pub fn push(&self, agent: Option<&str>, task: &str) -> Result<usize, SchedulerError> {
+        let lane = agent.unwrap_or(SHARED_LANE);
+        let mut state = self.lock();
+        let pass = state.pass;
+        let entry = state.lanes.get_mut(lane).ok_or_else(|| SchedulerError::UnknownAgent(lane.to_string()))?;
+        if entry.queue.is_empty() {
+            entry.pass = entry.pass.max(pass);
+        }
+        entry.queue.push_back(Queued { task: task.to_string(), attempt: 1, enqueued: Instant::now() });
+        state.queued += 1;
+        self.changed.notify_one();
+        Ok(state.queued)
+    }
+
+    pub fn len(&self) -> usize {
+        self.lock().queued
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The next task a worker may run now, or `None` if nothing is queued or every lane with work is at its
+    /// agent's limit.
+    pub fn try_next(&self, now: Instant) -> Option<Dispatch> {
+        let mut state = self.lock();
+        self.dispatch(&mut state, now)
+    }
+
+    /// The next task, waiting while every lane with work is at its agent's limit. Returns `None` once nothing
+    /// is queued.
+    pub fn next(&self) -> Option<Dispatch> {
+        let mut state = self.lock();
+        loop {
+            if state.queued == 0 {
+                return None;
+            }
+            if let Some(dispatch) = self.dispatch(&mut state, Instant::now()) {
+                return Some(dispatch);
+            }
+            state = self.changed.wait(state).unwrap_or_else(|poisoned| poisoned.into_inner());
+        }
+    }
+
+    fn dispatch(&self, state: &mut State, now: Instant) -> Option<Dispatch> {
+        let (lane, agent) = state
+            .lanes
+            .iter()
+            .filter(|(_, lane)| !lane.queue.is_empty())
+            .filter_map(|(name, lane)| state.runner(name).map(|agent| (lane.pass, name, agent)))
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, name, agent)| (name.clone(), agent))?;
+
+        let entry = state.lanes.get_mut(&lane).expect("lane chosen above");
+        let queued = entry.queue.pop_front().expect("lane has work");
+        let waited = now.saturating_duration_since(queued.enqueued);
+        let starved = waited > self.starvation_threshold;
+        let pass = entry.pass;
+        entry.pass += 1.0 / entry.weight;
+        entry.dispatched += 1;
+        entry.max_wait = entry.max_wait.max(waited);
+        if starved {
+            entry.starved += 1;
+            warn!("Task for lane '{}' of {} waited {:?} before it was dispatched", lane, self.swarm, waited);
+        }
+        state.pass = pass;
+        state.queued -= 1;
+        let slot = state.slots.get_mut(&agent).expect("runner is a known agent");
+        slot.in_flight += 1;
+
+        let metrics = SwarmMetrics::global();
+        metrics.task_dispatched(&self.swarm, &lane, waited, starved);
+        metrics.set_agent_in_flight(&self.swarm, &agent, slot.in_flight);
+        debug!("Dispatched a task from lane '{}' to {} after {:?}", lane, agent, waited);
+        if state.queued == 0 {
+            // Idle workers waiting for a free agent can stop now.
+            self.changed.notify_all();
+        }
+        Some(Dispatch { agent, lane, task: queued.task, attempt: queued.attempt, waited })
+    }
+
+    /// Release the agent's slot once it is done with `dispatch`, whether the task succeeded or not.
+    pub fn finish(&self, dispatch: &Dispatch) {
+        let mut state = self.lock();
+        if let Some(slot) = state.slots.get_mut(&dispatch.agent) {
+            slot.in_flight = slot.in_flight.saturating_sub(1);
+            SwarmMetrics::global().set_agent_in_flight(&self.swarm, &dispatch.agent, slot.in_flight);
+        }
+        self.changed.notify_all();
+    }
+
+    /// Put a dispatched task back at the front of its lane for another attempt. Call `finish` first.
+    pub fn requeue(&self, dispatch: &Dispatch) {
+        let mut state = self.lock();
+        let pass = state.pass;
+        let Some(entry) = state.lanes.get_mut(&dispatch.lane) else {
+            return;
+        };
+        if entry.queue.is_empty() {
+            entry.pass = entry.pass.max(pass);
+        }
+        let queued = Queued { task: dispatch.task.clone(), attempt: dispatch.attempt + 1, enqueued: Instant::now() };
+        entry.queue.push_front(queued);
+        state.queued += 1;
+        self.changed.notify_one();
+    }
+
+    /// Remove every queued task, oldest first within each lane. Shared tasks have no agent.
+    pub fn drain(&self) -> Vec<(Option<String>, String)> {
+        let mut state = self.lock();
+        state.queued = 0;
+        let mut drained = Vec::new();
+        for (name, lane) in state.lanes.iter_mut() {
+            let agent = (name != SHARED_LANE).then(|| name.clone());
+            drained.extend(lane.queue.drain(..).map(|queued| (agent.clone(), queued.task)));
+        }
+        drained
+    }
+
+    /// How each lane has been served so far, with queued tasks aged as of `now`.
+    pub fn stats(&self, now: Instant) -> Vec<LaneStats> {
+        let state = self.lock();
+        state
+            .lanes
+            .iter()
+            .map(|(name, lane)| LaneStats {
+                lane: name.clone(),
+                weight: lane.weight,
+                queued: lane.queue.len(),
+                in_flight: state.slots.get(name).map_or(0, |slot| slot.in_flight),
+                dispatched: lane.dispatched,
+                max_wait_ms: lane.max_wait.as_millis() as u64,
+                oldest_wait_ms: lane
+                    .queue
+                    .front()
+                    .map_or(0, |queued| now.saturating_duration_since(queued.enqueued).as_millis() as u64),
+                starved: lane.starved,
+            })
+            .collect()
+    }
+}
+
+fn main() {
+    let agents = vec!["Chatty".to_string(), "Quiet".to_string()];
+    let scheduler = FairScheduler::new("Mixed-Swarm", &agents).with_weight("Quiet", 2.0);
+    for i in 0..6 {
+        scheduler.push(Some("Chatty"), &format!("chatty task {}", i)).unwrap();
+    }
+    scheduler.push(Some("Quiet"), "quiet task").unwrap();
+    while let Some(dispatch) = scheduler.try_next(Instant::now()) {
+        println!("{} runs {}", dispatch.agent, dispatch.task);
+        scheduler.finish(&dispatch);
+    }
+    for lane in scheduler.stats(Instant::now()) {
+        println!("{}: {} dispatched, longest wait {} ms", lane.lane, lane.dispatched, lane.max_wait_ms);
+    }
+}
+```
+
+### Notes
+* `TaskQueueSwarm` and `ConcurrentWorkflow` both schedule through `FairScheduler` and expose the same
+  builders for its limits, weights, and starvation threshold.
+* Limits count tasks, not tokens or requests; an agent that calls its model many times per task still
+  counts once.
+* `next` blocks the calling thread. Run it on worker threads, or use `try_next` from async code.
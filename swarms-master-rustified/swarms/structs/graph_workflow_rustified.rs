@@ -15,54 +15,70 @@
 // 3. The Agent and task functionality may need to be adapted or reimplemented in 
 // Rust to ensure compatibility and interoperation with the rest of the repository.
 
-use std::collections::HashMap;
-use std::rc::Rc;
-use std::cell::RefCell;
+use crate::swarms::structs::agent::Agent;
+use crate::swarms::structs::approval_gate::{ApprovalContext, ApprovalError, ApprovalGate, ReviewRecord};
+use crate::swarms::structs::audit::AuditAction;
+use crate::swarms::structs::run_diff::{RunTrace, TraceRecorder, TraceStep};
+use crate::swarms::structs::workflow_run::{Resume, RetryError, Run, RunStep};
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
 
 // Define NodeType enum
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum NodeType {
+pub enum NodeType {
     Agent,
     Task,
+    // Pauses until the output of its upstream nodes is approved
+    Gate,
 }
 
 // Define Node struct
 #[derive(Debug, Clone)]
-struct Node {
+pub struct Node {
     id: String,
     node_type: NodeType,
     callable: Option<Box<dyn Fn() + Send + Sync>>,
-    agent: Option<Rc<RefCell<dyn Agent>>>,
+    agent: Option<Arc<dyn Agent>>,
+    gate: Option<ApprovalGate>,
 }
 
 // Implement Node
 impl Node {
-    fn new(id: String, node_type: NodeType, callable: Option<Box<dyn Fn() + Send + Sync>>, agent: Option<Rc<RefCell<dyn Agent>>>) -> Self {
-        Node { id, node_type, callable, agent }
+    pub fn new(id: String, node_type: NodeType, callable: Option<Box<dyn Fn() + Send + Sync>>, agent: Option<Arc<dyn Agent>>) -> Self {
+        Node { id, node_type, callable, agent, gate: None }
+    }
+
+    pub fn gate(id: String, gate: ApprovalGate) -> Self {
+        Node { id, node_type: NodeType::Gate, callable: None, agent: None, gate: Some(gate) }
     }
 }
 
 // Define Edge struct
 #[derive(Debug, Clone)]
-struct Edge {
-    source: String,
-    target: String,
+pub struct Edge {
+    pub source: String,
+    pub target: String,
 }
 
 // Define GraphWorkflow struct
 #[derive(Debug, Clone)]
-struct GraphWorkflow {
+pub struct GraphWorkflow {
     nodes: HashMap<String, Node>,
     edges: Vec<Edge>,
     entry_points: Vec<String>,
     end_points: Vec<String>,
     graph: Graph,
     max_loops: i32,
+    traces: Option<TraceRecorder>,
 }
 
 // Implement GraphWorkflow
 impl GraphWorkflow {
-    fn new() -> Self {
+    pub fn new() -> Self {
         GraphWorkflow {
             nodes: HashMap::new(),
             edges: Vec::new(),
@@ -70,15 +86,22 @@ impl GraphWorkflow {
             end_points: Vec::new(),
             graph: Graph::new(),
             max_loops: 1,
+            traces: None,
         }
     }
 
-    fn add_node(&mut self, node: Node) {
+    // Save a trace of every run, one step per agent node, for `swarms diff` and `swarms debug`
+    pub fn with_traces(mut self, traces: TraceRecorder) -> Self {
+        self.traces = Some(traces);
+        self
+    }
+
+    pub fn add_node(&mut self, node: Node) {
         self.nodes.insert(node.id.clone(), node.clone());
         self.graph.add_node(node.id.clone());
     }
 
-    fn add_edge(&mut self, edge: Edge) {
+    pub fn add_edge(&mut self, edge: Edge) {
         self.edges.push(edge.clone());
         self.graph.add_edge(edge.source.clone(), edge.target.clone());
     }
@@ -102,35 +125,233 @@ impl GraphWorkflow {
         mermaid_str
     }
 
-    fn run(&self) -> HashMap<String, String> {
+    // Run every node once, in topological order. A graph with a gate node needs someone to approve it, so
+    // it is refused with `ApprovalError::NoContext` before any node runs; use `run_with_approvals`.
+    pub fn run(&self, task: &str) -> Result<HashMap<String, String>, ApprovalError> {
+        if let Some(gate) = self.nodes.values().find_map(|node| node.gate.as_ref()) {
+            return Err(ApprovalError::NoContext { gate: gate.name.clone() });
+        }
         let mut execution_results = HashMap::new();
+        let mut trace = self.traces.as_ref().map(|traces| traces.start(new_id(IdKind::Run)));
         for node_id in self.graph.topological_sort() {
-            if let Some(node) = self.nodes.get(node_id) {
-                match node.node_type {
-                    NodeType::Task => {
-                        if let Some(callable) = &node.callable {
-                            println!("Executing task: {}", node_id);
-                            let result = callable();
-                            execution_results.insert(node_id.clone(), format!("{:?}", result));
-                        }
-                    },
-                    NodeType::Agent => {
-                        if let Some(agent) = &node.agent {
-                            println!("Executing agent: {}", node_id);
-                            let result = agent.borrow().run();
-                            execution_results.insert(node_id.clone(), format!("{:?}", result));
-                        }
-                    }
+            if let Some(node) = self.nodes.get(&node_id) {
+                if let Some(result) = self.run_node(node, task, trace.as_mut()) {
+                    execution_results.insert(node_id.clone(), result);
                 }
             }
         }
-        execution_results
+        self.save_trace(trace.as_ref());
+        Ok(execution_results)
+    }
+
+    // An agent's error becomes the node's result, so downstream nodes and gates can see it
+    fn run_node(&self, node: &Node, task: &str, trace: Option<&mut RunTrace>) -> Option<String> {
+        match node.node_type {
+            NodeType::Task => node.callable.as_ref().map(|callable| {
+                println!("Executing task: {}", node.id);
+                format!("{:?}", callable())
+            }),
+            NodeType::Agent => node.agent.as_ref().map(|agent| {
+                println!("Executing agent: {}", node.id);
+                let output = agent.run(task).unwrap_or_else(|err| err.to_string());
+                if let Some(trace) = trace {
+                    trace.push(TraceStep::of(agent.as_ref(), task, &output));
+                }
+                output
+            }),
+            NodeType::Gate => None,
+        }
+    }
+
+    fn save_trace(&self, trace: Option<&RunTrace>) {
+        if let (Some(traces), Some(trace)) = (&self.traces, trace) {
+            traces.finish(trace);
+        }
+    }
+
+    // The trace of a recorded run is rebuilt from the run, so a retried node replaces its failed attempt
+    fn save_run_trace(&self, run: &Run) {
+        if let Some(traces) = &self.traces {
+            traces.finish_run(run, |node_id| self.nodes.get(node_id).and_then(|node| node.agent.as_deref()));
+        }
     }
-}
 
-// Define Agent trait
-trait Agent {
-    fn run(&self) -> String;
+    // Run the agent nodes, recording each one's input, output, and status in a `Run`. Unlike `run`, a node
+    // waits for the agent nodes upstream of it, so a failure leaves its descendants unrun for `retry_step`.
+    // Task and gate nodes are not recorded; an agent behind one waits for the agent nodes before it.
+    pub fn run_recorded(&self, task: &str) -> Run {
+        let started = Instant::now();
+        let mut run = Run::new("GraphWorkflow", task);
+        for node_id in self.graph.topological_sort() {
+            let Some(node) = self.nodes.get(&node_id).filter(|node| node.node_type == NodeType::Agent) else { continue };
+            let Some(agent) = &node.agent else { continue };
+            let upstream = self.recorded_upstream(&node_id, &run);
+            run = run.step(RunStep::new(node_id.clone(), agent.name()).after(upstream));
+        }
+        run.execute(|step, input| self.run_recorded_node(step, input));
+        self.save_run_trace(&run);
+        let outcome = if run.is_complete() { "completed" } else { "failed" };
+        SwarmMetrics::global().workflow_finished("GraphWorkflow", outcome, started.elapsed());
+        run
+    }
+
+    // The recorded nodes `node_id` depends on, looking through the task and gate nodes between them
+    fn recorded_upstream(&self, node_id: &str, run: &Run) -> Vec<String> {
+        let mut upstream = Vec::new();
+        let mut seen = HashSet::new();
+        let mut pending = vec![node_id.to_string()];
+        while let Some(target) = pending.pop() {
+            for edge in self.edges.iter().filter(|edge| edge.target == target) {
+                if !seen.insert(edge.source.clone()) {
+                    continue;
+                }
+                if run.get(&edge.source).is_some() {
+                    upstream.push(edge.source.clone());
+                } else {
+                    pending.push(edge.source.clone());
+                }
+            }
+        }
+        upstream
+    }
+
+    // Run the failed node `step_id` of `run` again on the task, then, with `Resume::Downstream`, the nodes
+    // that were waiting for it
+    pub fn retry_step(&self, run: &mut Run, step_id: &str, resume: Resume) -> Result<Vec<String>, RetryError> {
+        if !self.nodes.get(step_id).is_some_and(|node| node.agent.is_some()) {
+            return Err(RetryError::NoAgent(step_id.to_string()));
+        }
+        let retried = run.retry_step(step_id, resume, |step, input| self.run_recorded_node(step, input));
+        self.save_run_trace(run);
+        retried
+    }
+
+    fn run_recorded_node(&self, step: &RunStep, input: &str) -> Result<String, String> {
+        let agent = self.nodes.get(&step.id).and_then(|node| node.agent.as_ref());
+        let agent = agent.ok_or_else(|| format!("no agent at node {}", step.id))?;
+        println!("Executing agent: {}", step.id);
+        agent.run(input).map_err(|err| err.to_string())
+    }
+
+    // Run the graph, pausing at each gate node until the output of its upstream nodes is approved.
+    // A rejected gate stops the run with `ApprovalError::Rejected`. Returns the node results and the
+    // review at each gate, which keeps the original output alongside any edit the reviewer made.
+    pub async fn run_with_approvals(
+        &self,
+        task: &str,
+        approvals: &ApprovalContext,
+    ) -> Result<(HashMap<String, String>, Vec<ReviewRecord>), ApprovalError> {
+        if let Some(auditor) = &approvals.audit {
+            auditor.record(AuditAction::RunStarted, &approvals.run_id, json!({ "workflow": "GraphWorkflow" }));
+        }
+        let started = Instant::now();
+        let mut trace = self.traces.as_ref().map(|traces| traces.start(approvals.run_id.clone()));
+        let result = self.run_gated_nodes(task, approvals, HashMap::new(), Vec::new(), &mut trace).await;
+        self.finish_gated(&result, trace.as_ref(), started);
+        result
+    }
+
+    // Continue a run that was interrupted while paused at a gate. The gate is asked again, and the nodes
+    // that already have a result in the checkpoint are not run again.
+    pub async fn resume(&self, approvals: &ApprovalContext) -> Result<(HashMap<String, String>, Vec<ReviewRecord>), ApprovalError> {
+        let checkpoint = approvals
+            .store
+            .load(&approvals.run_id)?
+            .ok_or_else(|| ApprovalError::NotPending(approvals.run_id.clone()))?;
+        let gate_node = checkpoint.state["gate_node"].as_str().unwrap_or_default().to_string();
+        let gate = self
+            .nodes
+            .get(&gate_node)
+            .and_then(|node| node.gate.as_ref())
+            .ok_or_else(|| ApprovalError::UnknownStep(checkpoint.request.gate.clone()))?;
+        let task = checkpoint.state["task"].as_str().unwrap_or_default().to_string();
+        let mut execution_results: HashMap<String, String> =
+            serde_json::from_value(checkpoint.state["results"].clone()).unwrap_or_default();
+        let mut reviews: Vec<ReviewRecord> = serde_json::from_value(checkpoint.state["reviews"].clone()).unwrap_or_default();
+        println!("Resuming at gate: {}", gate_node);
+
+        let started = Instant::now();
+        let mut trace = self.traces.as_ref().map(|traces| traces.resume(&approvals.run_id));
+        let result = match gate.review(checkpoint.request, checkpoint.state, approvals).await {
+            Ok(review) => {
+                execution_results.insert(gate_node, review.output().to_string());
+                reviews.push(review);
+                self.run_gated_nodes(&task, approvals, execution_results, reviews, &mut trace).await
+            }
+            Err(err) => Err(err),
+        };
+        self.finish_gated(&result, trace.as_ref(), started);
+        result
+    }
+
+    fn finish_gated<T>(&self, result: &Result<T, ApprovalError>, trace: Option<&RunTrace>, started: Instant) {
+        self.save_trace(trace);
+        let outcome = result.as_ref().map_or_else(ApprovalError::outcome, |_| "completed");
+        SwarmMetrics::global().workflow_finished("GraphWorkflow", outcome, started.elapsed());
+    }
+
+    async fn run_gated_nodes(
+        &self,
+        task: &str,
+        approvals: &ApprovalContext,
+        mut execution_results: HashMap<String, String>,
+        mut reviews: Vec<ReviewRecord>,
+        trace: &mut Option<RunTrace>,
+    ) -> Result<(HashMap<String, String>, Vec<ReviewRecord>), ApprovalError> {
+        self.run_gates(task, approvals, &mut execution_results, &mut reviews, trace).await?;
+        Ok((execution_results, reviews))
+    }
+
+    async fn run_gates(
+        &self,
+        task: &str,
+        approvals: &ApprovalContext,
+        execution_results: &mut HashMap<String, String>,
+        reviews: &mut Vec<ReviewRecord>,
+        trace: &mut Option<RunTrace>,
+    ) -> Result<(), ApprovalError> {
+        for node_id in self.graph.topological_sort() {
+            // A resumed run already has the results of the nodes before its gate
+            if execution_results.contains_key(&node_id) {
+                continue;
+            }
+            let Some(node) = self.nodes.get(&node_id) else { continue };
+            let Some(gate) = &node.gate else {
+                let input = self.gated_input(&node_id, execution_results).unwrap_or_else(|| task.to_string());
+                if let Some(result) = self.run_node(node, &input, trace.as_mut()) {
+                    execution_results.insert(node_id.clone(), result);
+                }
+                continue;
+            };
+            let upstream: Vec<&str> =
+                self.edges.iter().filter(|edge| edge.target == node_id).map(|edge| edge.source.as_str()).collect();
+            let output = upstream
+                .iter()
+                .filter_map(|source| execution_results.get(*source).map(|result| format!("[{}] {}", source, result)))
+                .collect::<Vec<_>>()
+                .join("\n");
+            let request = gate.request(&approvals.run_id, "GraphWorkflow", &upstream.join(", "), &output);
+            let state = json!({ "task": task, "gate_node": node_id, "results": execution_results, "reviews": reviews });
+            let review = gate.review(request, state, approvals).await?;
+            // Downstream nodes see the approved (possibly edited) output through the gate
+            execution_results.insert(node_id.clone(), review.output().to_string());
+            reviews.push(review);
+        }
+        Ok(())
+    }
+
+    // The approved output of the gates directly upstream of `node_id`, which a node behind a gate reads
+    // instead of the task. `None` when no gate leads to it.
+    fn gated_input(&self, node_id: &str, execution_results: &HashMap<String, String>) -> Option<String> {
+        let approved: Vec<&str> = self
+            .edges
+            .iter()
+            .filter(|edge| edge.target == node_id)
+            .filter(|edge| self.nodes.get(&edge.source).is_some_and(|node| node.gate.is_some()))
+            .filter_map(|edge| execution_results.get(&edge.source).map(String::as_str))
+            .collect();
+        (!approved.is_empty()).then(|| approved.join("\n"))
+    }
 }
 
 // Define Graph struct
@@ -196,8 +417,10 @@ fn main() {
     // Visualize the graph
     println!("{}", graph_workflow.visualize());
     // Run the graph
-    let execution_results = graph_workflow.run();
-    println!("{:?}", execution_results);
+    match graph_workflow.run("Draft the release notes") {
+        Ok(execution_results) => println!("{:?}", execution_results),
+        Err(e) => eprintln!("{}", e),
+    }
 }
 ```
 This Rust code maintains the same overall structure as the Python code, but it uses Rust's type system and libraries to achieve the same functionality. The main differences are:
@@ -206,6 +429,8 @@ This Rust code maintains the same overall structure as the Python code, but it u
 2.  **Ownership and borrowing**: Rust's ownership and borrowing system is more restrictive than Python's, which can lead to more explicit code for managing memory and references.
 3.  **Error handling**: Rust's error handling system is based on `Result` and `Option`, which can be more explicit and safer than Python's try-except blocks.
 4.  **Graph library**: There is no direct equivalent to NetworkX in Rust, so a simplified graph library is implemented in this example using a `Graph` struct and methods for adding nodes and edges.
-5.  **Agent and task functionality**: The `Agent` and task functionality may need to be adapted or reimplemented in Rust to ensure compatibility and interoperation with the rest of the repository.
+5.  **Approval gates**: `Node::gate` adds a `NodeType::Gate` node holding an `ApprovalGate`. `run_with_approvals` pauses there until the combined output of the gate's upstream nodes is approved, and checkpoints the results gathered so far. A node directly behind a gate runs on the approved output instead of the task, so an output edited at the gate is what it receives; the returned `ReviewRecord`s keep both versions. `run` has no approval context, so it refuses a graph with a gate node (`ApprovalError::NoContext`) instead of running past the gate. `resume` picks up a run that was interrupted at a gate: the checkpoint holds the task and the results so far, the gate is asked again, and only the nodes after it run.
+6.  **Agent and task functionality**: Agent nodes hold the crate's `Arc<dyn Agent>` and run on the task passed to `run`, as the Python `run(task)` does. They were `Rc<RefCell<..>>` before, which kept the workflow off other threads.
+7.  **Retrying a failed node**: `run_recorded` keeps each agent node's input, output, and status in a `workflow_run::Run`, and runs a node only once the agent nodes upstream of it have succeeded, including those reached through task or gate nodes, which are not recorded. `retry_step` runs a failed node again and, with `Resume::Downstream`, the nodes that were waiting for it, without running the rest of the graph. Agent nodes all read the task, so nodes that already succeeded keep their output.
 
 Overall, the conversion from Python to Rust requires a good understanding of both languages and their ecosystems, as well as careful consideration of the trade-offs between the two.
\ No newline at end of file
@@ -12,19 +12,14 @@ Here's a simplified version of your code in Rust, omitting some of the original
 
 ```rust
 // Import required crates
+use crate::swarms::structs::agent::{Agent, MockAgent};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tokio::prelude::*;
 use serde::{Serialize, Deserialize};
 
-// Agent structure
-#[derive(Serialize, Deserialize)]
-struct Agent {
-    agent_name: String,
-    system_prompt: String,
-}
-
 // Agent output structure
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct AgentOutput {
     agent_name: String,
     message: String,
@@ -35,7 +30,7 @@ struct AgentOutput {
 struct GroupChat {
     name: String,
     description: String,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
     max_loops: i32,
     chat_history: Vec<(String, String)>,
 }
@@ -44,7 +39,7 @@ impl GroupChat {
     async fn new(
         name: String, 
         description: String, 
-        agents: Vec<Agent>, 
+        agents: Vec<Arc<dyn Agent>>, 
         max_loops: i32,
     ) -> Self {
         GroupChat {
@@ -58,15 +53,18 @@ impl GroupChat {
 
     async fn _agent_conversation(
         &self, 
-        agent: &Agent, 
+        agent: &dyn Agent, 
         input_message: String,
     ) -> AgentOutput {
-        // Simulating the agent's conversation by appending the agent's name to the input message
-        let message = format!("{}: {}", agent.agent_name, input_message);
-        let metadata = HashMap::from([("context_length".to_string(), "200000".to_string())]);
+        // A failed agent's error text becomes its message, so the chat carries on
+        let message = agent.run(&input_message).unwrap_or_else(|err| err.to_string());
+        let mut metadata = HashMap::new();
+        if let Some(spec) = agent.spec() {
+            metadata.insert("context_length".to_string(), spec.budget.max_context_tokens.to_string());
+        }
         
         AgentOutput {
-            agent_name: agent.agent_name.clone(),
+            agent_name: agent.name().to_string(),
             message,
             metadata,
         }
@@ -80,12 +78,12 @@ impl GroupChat {
         for _ in 0..self.max_loops {
             for agent in &self.agents {
                 // Simulating a custom input message for the agent
-                let input_message = format!("{}: You are discussing: {}", agent.agent_name, initial_message);
+                let input_message = format!("{}: You are discussing: {}", agent.name(), initial_message);
                 
-                let output = self._agent_conversation(agent, input_message).await;
+                let output = self._agent_conversation(agent.as_ref(), input_message).await;
                 outputs.push(output.clone());
                 
-                self.chat_history.push((agent.agent_name.clone(), output.message));
+                self.chat_history.push((agent.name().to_string(), output.message));
             }
         }
         
@@ -95,15 +93,10 @@ impl GroupChat {
 
 #[tokio::main]
 async fn main() {
-    let mut agents: Vec<Agent> = Vec::new();
-    agents.push(Agent {
-        agent_name: String::from("Financial-Analysis-Agent"),
-        system_prompt: String::from("A financial analysis agent"),
-    });
-    agents.push(Agent {
-        agent_name: String::from("Tax-Adviser-Agent"),
-        system_prompt: String::from("A tax adviser agent"),
-    });
+    let agents: Vec<Arc<dyn Agent>> = vec![
+        Arc::new(MockAgent::new("Financial-Analysis-Agent").reply("Open the account with a broker that offers Roth IRAs.")),
+        Arc::new(MockAgent::new("Tax-Adviser-Agent").reply("Contributions are after-tax; qualified withdrawals are tax-free.")),
+    ];
 
     let mut group_chat = GroupChat::new(
         String::from("Financial Discussion"),
@@ -125,6 +118,7 @@ async fn main() {
 - **Async Library Differences**: Python's asyncio library is fundamental to this code. Rust's async/await syntax works differently and requires adjustment.
 - **Missing Direct Equivalents**: While Rust has many libraries for tasks like HTTP requests or JSON serialization, direct equivalents for some Python libraries used here might not exist or could have different interfaces.
 - **Error Handling**: Rust requires explicit error handling using `Result` or `Option`, which differs significantly from Python's try/except blocks.
+- **Agents**: Members are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`). Only model-backed agents report a `context_length` in the output metadata.
 - **Data Structures**: The structure and usage of data (e.g., using a HashMap for metadata) can differ in Rust compared to Python, affecting the code's logic and readability.
 
 ### Advice for Interoperation
@@ -0,0 +1,315 @@
+### Overview
+There is no Python counterpart. In the Python workflows, control passes implicitly: each agent gets the
+previous agent's whole output, or the whole conversation, whether it needs it or not. This costs tokens,
+and it leaks things like tool dumps or a user's personal details to agents that have no use for them.
+
+A `Handoff` makes the transfer explicit. The agent that holds control names the peer to take over. It
+writes the task framing for that peer and picks which conversation messages (by id) and which artifact
+files go along. The workflow engine, not the agent, enforces the rules:
+
+* the peer must be an agent of the same workflow, and not the agent handing off;
+* selected messages must exist, must be public, and must not be deleted; scratchpad messages never travel;
+* selected artifacts must exist on disk;
+* a run may hand off at most `max_handoffs` times (default `DEFAULT_MAX_HANDOFFS`), so two agents cannot
+  bounce a task back and forth forever.
+
+The handing-off agent's own reply always goes with the payload. Every accepted handoff becomes a
+`HandoffEvent`. Each event is logged, returned with the run, and written to the audit log as
+`AuditAction::Handoff` when the policy has an auditor.
+
+`SequentialWorkflow::run_with_handoffs` is the engine entry point. Other engines can drive a
+`HandoffController` the same way.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::{Agent, LlmAgent};
+use crate::swarms::structs::audit::{AuditAction, Auditor};
+use crate::swarms::structs::conversation::{Conversation, Message};
+use log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::BTreeSet;
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+pub const DEFAULT_MAX_HANDOFFS: usize = 8;
+
+/// A request by the agent holding control to pass it to a named peer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Handoff {
+    /// Name of the agent to take over.
+    pub to: String,
+    /// The task as framed for the peer.
+    pub task: String,
+    /// Ids of the conversation messages the peer should see, besides the handing-off agent's reply.
+    #[serde(default)]
+    pub messages: Vec<u64>,
+    /// Files the peer should work from.
+    #[serde(default)]
+    pub artifacts: Vec<PathBuf>,
+}
+
+impl Handoff {
+    pub fn to(peer: impl Into<String>, task: impl Into<String>) -> Self {
+        Handoff { to: peer.into(), task: task.into(), messages: Vec::new(), artifacts: Vec::new() }
+    }
+
+    pub fn message(mut self, id: u64) -> Self {
+        self.messages.push(id);
+        self
+    }
+
+    pub fn messages(mut self, ids: impl IntoIterator<Item = u64>) -> Self {
+        self.messages.extend(ids);
+        self
+    }
+
+    pub fn artifact(mut self, path: impl Into<PathBuf>) -> Self {
+        self.artifacts.push(path.into());
+        self
+    }
+}
+
+/// What one agent turn produced: either the final answer, or a reply plus a handoff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AgentTurn {
+    Finish(String),
+    Handoff { output: String, handoff: Handoff },
+}
+
+/// Everything the receiving agent is given. It never sees more of the conversation than this.
+#[derive(Debug, Clone, Serialize)]
+pub struct HandoffPayload {
+    /// The agent that handed off; `None` for the agent that starts the run.
+    pub from: Option<String>,
+    pub task: String,
+    /// In conversation order.
+    pub messages: Vec<Message>,
+    pub artifacts: Vec<PathBuf>,
+}
+
+impl HandoffPayload {
+    /// The payload for the first agent of a run: the task and nothing else.
+    pub fn initial(task: impl Into<String>) -> Self {
+        HandoffPayload { from: None, task: task.into(), messages: Vec::new(), artifacts: Vec::new() }
+    }
+
+    /// The payload as the agent's input. The initial payload renders as the bare task, the same input
+    /// `SequentialWorkflow::run_with` gives its first agent.
+    pub fn render(&self) -> String {
+        let Some(from) = &self.from else {
+            return self.task.clone();
+        };
+        let mut text = format!("Handed off by {}.\nTask: {}\n", from, self.task);
+        if !self.messages.is_empty() {
+            text.push_str("\nContext:\n");
+            for message in &self.messages {
+                text.push_str(&format!("[{}] {}\n", message.role, message.content));
+            }
+        }
+        if !self.artifacts.is_empty() {
+            text.push_str("\nArtifacts:\n");
+            for path in &self.artifacts {
+                text.push_str(&format!("- {}\n", path.display()));
+            }
+        }
+        text
+    }
+}
+
+/// The record of one accepted handoff.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HandoffEvent {
+    pub from: String,
+    pub to: String,
+    pub task: String,
+    /// Ids of the messages that went with the handoff, including the sender's reply.
+    pub messages: Vec<u64>,
+    pub artifacts: Vec<PathBuf>,
+    /// RFC 3339, UTC.
+    pub timestamp: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HandoffError {
+    /// The workflow has no agents to start with.
+    NoAgents,
+    /// The named peer is not an agent of this workflow.
+    UnknownPeer { from: String, to: String },
+    /// An agent tried to hand off to itself.
+    SelfHandoff(String),
+    /// A selected message does not exist, was deleted, or is on the scratchpad.
+    MessageUnavailable { from: String, id: u64 },
+    /// A selected artifact does not exist.
+    MissingArtifact { from: String, path: PathBuf },
+    /// The run already handed off this many times.
+    LimitReached(usize),
+    /// The agent's step failed.
+    Agent { agent: String, message: String },
+}
+
+impl fmt::Display for HandoffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HandoffError::NoAgents => write!(f, "the workflow has no agents"),
+            HandoffError::UnknownPeer { from, to } => write!(f, "{} handed off to unknown agent '{}'", from, to),
+            HandoffError::SelfHandoff(agent) => write!(f, "{} handed off to itself", agent),
+            HandoffError::MessageUnavailable { from, id } => {
+                write!(f, "{} selected message {}, which does not exist or is not public", from, id)
+            }
+            HandoffError::MissingArtifact { from, path } => {
+                write!(f, "{} selected artifact {}, which does not exist", from, path.display())
+            }
+            HandoffError::LimitReached(limit) => write!(f, "the run reached its limit of {} handoffs", limit),
+            HandoffError::Agent { agent, message } => write!(f, "{} failed: {}", agent, message),
+        }
+    }
+}
+
+impl std::error::Error for HandoffError {}
+
+/// How an engine enforces and records handoffs. Shared across runs; each run gets its own controller.
+#[derive(Clone)]
+pub struct HandoffPolicy {
+    max_handoffs: usize,
+    auditor: Option<Auditor>,
+    run_id: String,
+}
+
+impl Default for HandoffPolicy {
+    fn default() -> Self {
+        HandoffPolicy::new()
+    }
+}
+
+impl HandoffPolicy {
+    pub fn new() -> Self {
+        HandoffPolicy { max_handoffs: DEFAULT_MAX_HANDOFFS, auditor: None, run_id: String::new() }
+    }
+
+    pub fn max_handoffs(mut self, max_handoffs: usize) -> Self {
+        self.max_handoffs = max_handoffs;
+        self
+    }
+
+    /// Write each handoff to the audit log, with `run_id` as the target.
+    pub fn audited(mut self, auditor: Auditor, run_id: impl Into<String>) -> Self {
+        self.auditor = Some(auditor);
+        self.run_id = run_id.into();
+        self
+    }
+
+    /// A controller for one run.
+    pub fn start(&self) -> HandoffController<'_> {
+        HandoffController { policy: self, events: Vec::new() }
+    }
+}
+
+/// Checks and records the handoffs of one run.
+pub struct HandoffController<'a> {
+    policy: &'a HandoffPolicy,
+    events: Vec<HandoffEvent>,
+}
+
+impl<'a> HandoffController<'a> {
+    pub fn events(&self) -> &[HandoffEvent] {
+        &self.events
+    }
+
+    pub fn into_events(self) -> Vec<HandoffEvent> {
+        self.events
+    }
+
+    /// Check `handoff` from `from` against the workflow's `agents` and build the peer's payload from
+    /// `conversation`. `reply` is the id of the sender's reply, which always goes along. Nothing is
+    /// recorded unless every check passes.
+    pub fn transfer<'b>(
+        &mut self,
+        agents: &'b [Arc<dyn Agent>],
+        from: &str,
+        reply: Option<u64>,
+        handoff: Handoff,
+        conversation: &Conversation,
+    ) -> Result<(&'b Arc<dyn Agent>, HandoffPayload), HandoffError> {
+        if self.events.len() >= self.policy.max_handoffs {
+            return Err(HandoffError::LimitReached(self.policy.max_handoffs));
+        }
+        if handoff.to == from {
+            return Err(HandoffError::SelfHandoff(from.to_string()));
+        }
+        let peer = agents.iter().find(|agent| agent.name() == handoff.to).ok_or_else(|| HandoffError::UnknownPeer {
+            from: from.to_string(),
+            to: handoff.to.clone(),
+        })?;
+
+        // Ids are assigned in order, so sorting them keeps the conversation order
+        let ids: BTreeSet<u64> = reply.into_iter().chain(handoff.messages.iter().copied()).collect();
+        let mut messages = Vec::with_capacity(ids.len());
+        for &id in &ids {
+            match conversation.get(id) {
+                Some(message) if message.channel.is_public() && !message.is_tombstone() => messages.push(message.clone()),
+                _ => return Err(HandoffError::MessageUnavailable { from: from.to_string(), id }),
+            }
+        }
+        if let Some(path) = handoff.artifacts.iter().find(|path| !path.exists()) {
+            return Err(HandoffError::MissingArtifact { from: from.to_string(), path: path.clone() });
+        }
+
+        let event = HandoffEvent {
+            from: from.to_string(),
+            to: peer.name().to_string(),
+            task: handoff.task.clone(),
+            messages: ids.into_iter().collect(),
+            artifacts: handoff.artifacts.clone(),
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        };
+        info!(
+            "Handoff {} -> {} with {} messages and {} artifacts",
+            event.from,
+            event.to,
+            event.messages.len(),
+            event.artifacts.len()
+        );
+        if let Some(auditor) = &self.policy.auditor {
+            auditor.record(AuditAction::Handoff, &self.policy.run_id, json!(event));
+        }
+        self.events.push(event);
+
+        let payload = HandoffPayload {
+            from: Some(from.to_string()),
+            task: handoff.task,
+            messages,
+            artifacts: handoff.artifacts,
+        };
+        Ok((peer, payload))
+    }
+}
+
+fn main() {
+    let agents: Vec<Arc<dyn Agent>> = vec![
+        LlmAgent::builder().name("Triage").llm("gpt-4o-mini").build().expect("valid agent").into(),
+        LlmAgent::builder().name("Billing").llm("gpt-4o").build().expect("valid agent").into(),
+    ];
+    let mut conversation = Conversation::builder().build().expect("valid conversation");
+    let question = conversation.add("user".to_string(), "I was charged twice for March.".to_string());
+    conversation.add("user".to_string(), "My card ends in 4242.".to_string());
+    let reply = conversation.add("Triage".to_string(), "This is a billing issue.".to_string());
+
+    let policy = HandoffPolicy::new();
+    let mut controller = policy.start();
+    let handoff = Handoff::to("Billing", "Refund the duplicate March charge").message(question);
+    let (peer, payload) = controller.transfer(&agents, "Triage", Some(reply), handoff, &conversation).unwrap();
+    println!("{} receives:\n{}", peer.name(), payload.render());
+    println!("{}", serde_json::to_string_pretty(controller.events()).unwrap());
+}
+```
+
+### Notes
+- The card-number message was not selected, so the billing agent never sees it. The payload is a
+  subset of the conversation, not a summary of it. To pass something the conversation does not hold, put
+  it in the task framing or in an artifact.
+- Artifacts travel as paths, not contents. The peer reads them with its own tools, so a large file does
+  not land in its prompt unless it asks for it.
+- The limit counts accepted handoffs only. A rejected handoff ends the run with the error; it is not
+  sent back to the agent to try again.
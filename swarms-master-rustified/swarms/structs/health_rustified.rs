@@ -0,0 +1,213 @@
+### Overview
+There is no Python counterpart. `Health` backs the API server's Kubernetes probes:
+
+* `GET /healthz` (liveness) answers `200` whenever the process can serve it, with the status of every
+  component in the body. It deliberately does not fail on a dependency outage: restarting the server does
+  not bring a provider back, and a liveness probe that fails on one turns an outage into a restart loop.
+* `GET /readyz` (readiness) answers `200` only when the run store and queue backend are up and at least one
+  provider is reachable, and `503` otherwise, so Kubernetes stops routing runs to a server that cannot
+  execute them.
+
+Each dependency is a `HealthCheck` of one `ComponentKind`. Checks run concurrently, each under
+`check_timeout`; a check that times out counts as down. The checks provided are:
+
+* `DirectoryCheck`, for file-based run stores (workspace, checkpoints, audit log): writes and removes a
+  probe file.
+* `Coordinator` (feature `server`): the distributed task queue's ledger is usable.
+* `OpenAiCompatProvider` (feature `providers-openai`): `GET /models` succeeds with the configured key.
+
+Example body:
+
+```json
+{ "status": "ready", "components": [
+  { "name": "runs", "kind": "run_store", "status": "up", "latency_ms": 1 },
+  { "name": "groq", "kind": "provider", "status": "down", "latency_ms": 2000, "error": "timed out after 2s" } ] }
+```
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::Problem;
+use async_trait::async_trait;
+use futures::future::join_all;
+use log::warn;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ComponentKind {
+    RunStore,
+    Queue,
+    Provider,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub enum ComponentState {
+    Up,
+    Down,
+}
+
+/// One dependency the server needs.
+#[async_trait]
+pub trait HealthCheck: Send + Sync {
+    fn name(&self) -> String;
+    fn kind(&self) -> ComponentKind;
+    /// `Ok` with an optional detail (e.g. queue depth) when usable, `Err` with the reason when not.
+    async fn check(&self) -> Result<Option<String>, String>;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct ComponentStatus {
+    pub name: String,
+    pub kind: ComponentKind,
+    pub status: ComponentState,
+    pub latency_ms: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HealthReport {
+    pub components: Vec<ComponentStatus>,
+}
+
+impl HealthReport {
+    fn any_up(&self, kind: ComponentKind) -> bool {
+        self.components.iter().any(|c| c.kind == kind && c.status == ComponentState::Up)
+    }
+
+    fn all_up(&self, kind: ComponentKind) -> bool {
+        self.components.iter().filter(|c| c.kind == kind).all(|c| c.status == ComponentState::Up)
+    }
+
+    /// Every run store and queue is up, and at least one provider is.
+    pub fn is_ready(&self) -> bool {
+        self.all_up(ComponentKind::RunStore) && self.all_up(ComponentKind::Queue) && self.any_up(ComponentKind::Provider)
+    }
+}
+
+#[derive(Clone)]
+pub struct Health {
+    checks: Vec<Arc<dyn HealthCheck>>,
+    check_timeout: Duration,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Health { checks: Vec::new(), check_timeout: Duration::from_secs(2) }
+    }
+}
+
+impl Health {
+    pub fn new() -> Self {
+        Health::default()
+    }
+
+    pub fn check(mut self, check: Arc<dyn HealthCheck>) -> Self {
+        self.checks.push(check);
+        self
+    }
+
+    /// How long one check may take before it counts as down. Keep it under the probe's `timeoutSeconds`.
+    pub fn check_timeout(mut self, timeout: Duration) -> Self {
+        self.check_timeout = timeout;
+        self
+    }
+
+    async fn run_one(&self, check: &Arc<dyn HealthCheck>) -> ComponentStatus {
+        let started = Instant::now();
+        let result = match tokio::time::timeout(self.check_timeout, check.check()).await {
+            Ok(result) => result,
+            Err(_) => Err(format!("timed out after {}s", self.check_timeout.as_secs_f64())),
+        };
+        let (status, detail, error) = match result {
+            Ok(detail) => (ComponentState::Up, detail, None),
+            Err(error) => {
+                warn!("Health check '{}' failed: {}", check.name(), error);
+                (ComponentState::Down, None, Some(error))
+            }
+        };
+        ComponentStatus {
+            name: check.name(),
+            kind: check.kind(),
+            status,
+            latency_ms: started.elapsed().as_millis() as u64,
+            detail,
+            error,
+        }
+    }
+
+    /// Run every check concurrently.
+    pub async fn report(&self) -> HealthReport {
+        HealthReport { components: join_all(self.checks.iter().map(|check| self.run_one(check))).await }
+    }
+
+    /// Serve `GET /healthz` and `GET /readyz`.
+    pub async fn handle_http(&self, method: &str, path: &str) -> (u16, JsonValue) {
+        match (method, path.trim_end_matches('/')) {
+            ("GET", "/healthz") => {
+                let report = self.report().await;
+                (200, json!({ "status": "ok", "components": report.components }))
+            }
+            ("GET", "/readyz") => {
+                let report = self.report().await;
+                let (code, status) = if report.is_ready() { (200, "ready") } else { (503, "not_ready") };
+                (code, json!({ "status": status, "components": report.components }))
+            }
+            (method, path) => (404, Problem::no_route(method, path).to_value()),
+        }
+    }
+}
+
+/// A file-based run store is usable if a file can be written to and removed from its directory.
+#[derive(Debug, Clone)]
+pub struct DirectoryCheck {
+    name: String,
+    dir: PathBuf,
+}
+
+impl DirectoryCheck {
+    pub fn new(name: impl Into<String>, dir: impl Into<PathBuf>) -> Self {
+        DirectoryCheck { name: name.into(), dir: dir.into() }
+    }
+}
+
+#[async_trait]
+impl HealthCheck for DirectoryCheck {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn kind(&self) -> ComponentKind {
+        ComponentKind::RunStore
+    }
+
+    async fn check(&self) -> Result<Option<String>, String> {
+        let probe = self.dir.join(format!(".healthz-{}", std::process::id()));
+        let result = fs::create_dir_all(&self.dir).and_then(|_| fs::write(&probe, b"ok")).and_then(|_| fs::remove_file(&probe));
+        result.map(|_| None).map_err(|err| format!("{} is not writable: {}", self.dir.display(), err))
+    }
+}
+
+fn main() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let health = Health::new().check(Arc::new(DirectoryCheck::new("runs", std::env::temp_dir().join("swarms-runs"))));
+    let (status, body) = runtime.block_on(health.handle_http("GET", "/readyz"));
+    // No provider is configured, so the server is live but not ready.
+    println!("{} {}", status, body);
+}
+```
+
+### Notes
+* Probes hit providers on every call. With several replicas and a short `periodSeconds`, the `/models` requests add up; a 10-second period is plenty.
+* A server with no provider checks registered is never ready. That is intended: it cannot run anything.
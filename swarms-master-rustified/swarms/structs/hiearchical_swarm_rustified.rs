@@ -13,8 +13,10 @@ Here's an example of how some of the provided Python classes and functions could
 // Import required libraries
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use crate::swarms::structs::agent::{Agent, LlmAgent};
 use std::error::Error;
 use std::fmt;
+use std::sync::Arc;
 
 // Define a custom error type
 #[derive(Debug, Serialize, Deserialize)]
@@ -30,31 +32,17 @@ impl fmt::Display for SwarmError {
     }
 }
 
-// Define a struct to represent an agent
+// The director's order for one agent, as in the Python `HierarchicalOrder`
 #[derive(Debug, Serialize, Deserialize)]
-struct Agent {
+struct HierarchicalOrder {
     agent_name: String,
-    system_prompt: String,
-    agent_description: String,
     task: String,
 }
 
-impl Agent {
-    fn new(agent_name: String, system_prompt: String, agent_description: String, task: String) -> Agent {
-        Agent {
-            agent_name,
-            system_prompt,
-            agent_description,
-            task,
-        }
-    }
-}
-
-// Define a struct to represent a swarm
-#[derive(Debug, Serialize, Deserialize)]
+// Define a struct to represent a swarm; agents are the crate's `Agent` trait objects
 struct Swarm {
     name: String,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
 }
 
 impl Swarm {
@@ -62,16 +50,24 @@ impl Swarm {
         Swarm { name, agents: Vec::new() }
     }
 
-    fn add_agent(&mut self, agent: Agent) {
+    fn add_agent(&mut self, agent: Arc<dyn Agent>) {
         self.agents.push(agent);
     }
 
-    fn run(&self) {
-        // Run the agents in the swarm
-        for agent in &self.agents {
-            println!("Running agent: {}", agent.agent_name);
-            // agent.run();
-        }
+    // Run each order on the agent it names
+    fn run(&self, orders: &[HierarchicalOrder]) -> Result<Vec<String>, SwarmError> {
+        orders
+            .iter()
+            .map(|order| {
+                let agent = self
+                    .agents
+                    .iter()
+                    .find(|agent| agent.name() == order.agent_name)
+                    .ok_or_else(|| SwarmError { message: format!("no agent named {}", order.agent_name) })?;
+                println!("Running agent: {}", agent.name());
+                agent.run(&order.task).map_err(|err| SwarmError { message: err.to_string() })
+            })
+            .collect()
     }
 }
 
@@ -79,23 +75,28 @@ fn main() {
     // Create a new swarm
     let mut swarm = Swarm::new("My Swarm".to_string());
 
-    // Create a new agent
-    let agent = Agent::new(
-        "My Agent".to_string(),
-        "This is a system prompt".to_string(),
-        "This is an agent description".to_string(),
-        "This is a task".to_string(),
-    );
+    // Create a new agent; bind a `Completion` for `run` to reach a model
+    let agent = LlmAgent::builder()
+        .name("My Agent")
+        .llm("gpt-4o")
+        .system_prompt("This is a system prompt")
+        .description("This is an agent description")
+        .build()
+        .expect("valid agent");
 
     // Add the agent to the swarm
-    swarm.add_agent(agent);
+    swarm.add_agent(agent.into());
 
     // Run the swarm
-    swarm.run();
+    let orders = vec![HierarchicalOrder { agent_name: "My Agent".to_string(), task: "This is a task".to_string() }];
+    match swarm.run(&orders) {
+        Ok(outputs) => println!("{:?}", outputs),
+        Err(error) => println!("{}", error),
+    }
 }
 ```
 
-This example demonstrates how to define structs to represent agents and swarms, and how to implement methods to add agents to a swarm and run the swarm. It also shows how to create a custom error type and implement the `Error` and `Display` traits for it.
+This example demonstrates how to hold the crate's agents (`Arc<dyn Agent>`) in a swarm, and how to run the director's orders on the agents they name. The task belongs to the order rather than to the agent, as in the Python `HierarchicalOrder`. It also shows how to create a custom error type and implement the `Error` and `Display` traits for it.
 
 **Limitations:**
 
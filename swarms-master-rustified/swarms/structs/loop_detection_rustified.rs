@@ -0,0 +1,184 @@
+### Overview
+There is no Python counterpart. Agents running several `max_loops` often answer the same way loop after
+loop, verbatim or nearly so, spending tokens on nothing new. `LoopDetector` watches the assistant turns of
+a run and flags a turn that repeats one of the last `window` turns:
+
+* an exact repeat, after normalizing case and whitespace, is caught by hash;
+* a near repeat is caught by the Jaccard similarity of the turns' word n-grams (`ngram` words each),
+  against `similarity_threshold`.
+
+Each detection takes the next action from `escalation`, and the last one repeats once the list runs out:
+
+* `raise_temperature` adds `temperature_step` to the sampling temperature, up to `max_temperature`;
+* `nudge` adds the `nudge` message to the next loop's prompt;
+* `terminate` ends the loop early.
+
+The default escalation is raise temperature, then nudge, then terminate. `Agent::run_loops` drives an
+agent's loops through a detector, and exact repeats are left out of the run's outputs.
+`Conversation::dedupe` removes verbatim repeats from a saved history.
+
+### Rust Code
+```rust
+use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashSet, VecDeque};
+use std::hash::{Hash, Hasher};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopAction {
+    RaiseTemperature,
+    Nudge,
+    Terminate,
+}
+
+/// Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoopDetectionConfig {
+    /// How many recent assistant turns a new turn is compared against.
+    pub window: usize,
+    /// Words per shingle for near-repeat detection.
+    pub ngram: usize,
+    pub similarity_threshold: f32,
+    pub escalation: Vec<LoopAction>,
+    /// The temperature of the first loop.
+    pub base_temperature: f64,
+    pub temperature_step: f64,
+    pub max_temperature: f64,
+    pub nudge: String,
+}
+
+impl Default for LoopDetectionConfig {
+    fn default() -> Self {
+        LoopDetectionConfig {
+            window: 3,
+            ngram: 3,
+            similarity_threshold: 0.9,
+            escalation: vec![LoopAction::RaiseTemperature, LoopAction::Nudge, LoopAction::Terminate],
+            base_temperature: 0.5,
+            temperature_step: 0.2,
+            max_temperature: 1.2,
+            nudge: "You are repeating yourself. Build on your previous answer with something new, or reply <DONE> if \
+                    the task is complete."
+                .to_string(),
+        }
+    }
+}
+
+/// A turn that repeated an earlier one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct LoopDetection {
+    /// Zero-based index of the repeating turn among the turns observed.
+    pub turn: usize,
+    /// How many turns back the repeated turn was (1 is the previous turn).
+    pub repeats_turns_back: usize,
+    pub similarity: f32,
+    /// Identical after normalization.
+    pub exact: bool,
+    pub action: LoopAction,
+}
+
+/// Lowercase and collapse whitespace, so reformatting alone does not make a turn new.
+pub fn normalize(text: &str) -> String {
+    text.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join(" ")
+}
+
+fn fingerprint(normalized: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    normalized.hash(&mut hasher);
+    hasher.finish()
+}
+
+fn shingles(normalized: &str, n: usize) -> HashSet<String> {
+    let words: Vec<&str> = normalized.split(' ').filter(|word| !word.is_empty()).collect();
+    if words.len() < n {
+        return std::iter::once(words.join(" ")).filter(|s| !s.is_empty()).collect();
+    }
+    words.windows(n).map(|window| window.join(" ")).collect()
+}
+
+fn jaccard(a: &HashSet<String>, b: &HashSet<String>) -> f32 {
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    let union = a.union(b).count();
+    a.intersection(b).count() as f32 / union as f32
+}
+
+struct Turn {
+    hash: u64,
+    shingles: HashSet<String>,
+}
+
+pub struct LoopDetector {
+    config: LoopDetectionConfig,
+    recent: VecDeque<Turn>,
+    observed: usize,
+    detections: usize,
+}
+
+impl LoopDetector {
+    pub fn new(config: LoopDetectionConfig) -> Self {
+        LoopDetector { config, recent: VecDeque::new(), observed: 0, detections: 0 }
+    }
+
+    pub fn config(&self) -> &LoopDetectionConfig {
+        &self.config
+    }
+
+    /// Record an assistant turn. Returns the detection if it repeats a recent turn.
+    pub fn observe(&mut self, turn: &str) -> Option<LoopDetection> {
+        let normalized = normalize(turn);
+        let current = Turn { hash: fingerprint(&normalized), shingles: shingles(&normalized, self.config.ngram.max(1)) };
+        // Oldest first: `max_by` keeps the last of equal maxima, so ties go to the most recent turn.
+        let best = self
+            .recent
+            .iter()
+            .enumerate()
+            .map(|(position, previous)| {
+                let exact = previous.hash == current.hash;
+                let similarity = if exact { 1.0 } else { jaccard(&previous.shingles, &current.shingles) };
+                (self.recent.len() - position, similarity, exact)
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1));
+
+        let index = self.observed;
+        self.observed += 1;
+        self.recent.push_back(current);
+        while self.recent.len() > self.config.window.max(1) {
+            self.recent.pop_front();
+        }
+
+        let (back, similarity, exact) = best?;
+        if !exact && similarity < self.config.similarity_threshold {
+            return None;
+        }
+        let action = self
+            .config
+            .escalation
+            .get(self.detections)
+            .or(self.config.escalation.last())
+            .copied()
+            .unwrap_or(LoopAction::Terminate);
+        self.detections += 1;
+        Some(LoopDetection { turn: index, repeats_turns_back: back, similarity, exact, action })
+    }
+}
+
+fn main() {
+    let mut detector = LoopDetector::new(LoopDetectionConfig::default());
+    for turn in [
+        "Revenue grew 12% year over year, driven by subscriptions.",
+        "Revenue grew 12% year over year, driven by subscriptions.",
+        "revenue grew 12%  year over year, driven by subscriptions.",
+        "Margins narrowed because of higher cloud costs.",
+    ] {
+        println!("{:?}", detector.observe(turn));
+    }
+}
+```
+
+### Notes
+* Similarity is lexical. An agent that rephrases the same idea in new words is not caught; lower `similarity_threshold` or `ngram` to be stricter, at the cost of flagging legitimately similar answers.
+* Only assistant turns should be observed. Tool output and user turns repeat for good reasons.
@@ -5,30 +5,18 @@ Here is the Rust version of the provided Python code:
 
 ```rust
 // Import required crates
+use crate::swarms::structs::agent::Agent;
+use crate::swarms::structs::callbacks::{RunCallbacks, StepComplete};
+use crate::swarms::structs::dry_run::PlannedCall;
+use crate::swarms::structs::scoped_tasks::{scoped_map_settled, settle, TaskFailure, TaskFailures};
+use crate::swarms::structs::swarm::{Swarm, SwarmError};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 use std::sync::{Arc, Mutex};
-use std::thread;
 use std::time::Instant;
 
-// Define the Agent struct
-#[derive(Debug)]
-struct Agent {
-    agent_name: String,
-}
-
-impl Agent {
-    fn new(agent_name: String) -> Self {
-        Agent { agent_name }
-    }
-
-    fn run(&self, task: &str) -> String {
-        // This method should be replaced with the actual implementation of the agent's run method
-        format!("{}: {}", self.agent_name, task)
-    }
-}
-
 // Define the Conversation struct
 #[derive(Debug)]
 struct Conversation {
@@ -59,53 +47,129 @@ struct ConversationMessage {
     agent_name: String,
 }
 
+// How answers are compared when counting votes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Consensus {
+    // Answers must match exactly
+    #[default]
+    Exact,
+    // Case, whitespace, and trailing punctuation are ignored, so "Paris." and "paris" are one vote
+    Normalized,
+}
+
+// One distinct answer and how many voters gave it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Vote {
+    // The answer as first given
+    pub answer: String,
+    pub count: usize,
+}
+
+impl Consensus {
+    fn key(&self, answer: &str) -> String {
+        match self {
+            Consensus::Exact => answer.to_string(),
+            Consensus::Normalized => {
+                let answer = answer.trim().trim_end_matches(['.', '!', '?']);
+                answer.split_whitespace().map(str::to_lowercase).collect::<Vec<_>>().join(" ")
+            }
+        }
+    }
+
+    // Count the votes, most common first; ties keep the order in which the answers first appeared
+    pub fn tally(&self, answers: &[String]) -> Vec<Vote> {
+        let mut positions: HashMap<String, usize> = HashMap::new();
+        let mut votes: Vec<Vote> = Vec::new();
+        for answer in answers {
+            let position = *positions.entry(self.key(answer)).or_insert_with(|| {
+                votes.push(Vote { answer: answer.clone(), count: 0 });
+                votes.len() - 1
+            });
+            votes[position].count += 1;
+        }
+        votes.sort_by(|a, b| b.count.cmp(&a.count));
+        votes
+    }
+
+    // The most common answer, or None when there are no answers
+    pub fn winner(&self, answers: &[String]) -> Option<Vote> {
+        self.tally(answers).into_iter().next()
+    }
+}
+
 // Define the MajorityVoting struct
-#[derive(Debug)]
-struct MajorityVoting {
-    agents: Vec<Agent>,
+pub struct MajorityVoting {
+    name: String,
+    agents: Vec<Arc<dyn Agent>>,
     output_parser: Option<fn(Vec<String>) -> String>,
     autosave: bool,
     verbose: bool,
     conversation: Conversation,
+    consensus: Consensus,
+    callbacks: Option<Arc<dyn RunCallbacks>>,
 }
 
 impl MajorityVoting {
-    fn new(
+    pub fn new(
         name: &str,
         description: &str,
-        agents: Vec<Agent>,
+        agents: Vec<Arc<dyn Agent>>,
         output_parser: Option<fn(Vec<String>) -> String>,
         autosave: bool,
         verbose: bool,
     ) -> Self {
         MajorityVoting {
+            name: name.to_string(),
             agents,
             output_parser,
             autosave,
             verbose,
             conversation: Conversation::new(),
+            consensus: Consensus::default(),
+            callbacks: None,
         }
     }
 
-    fn run(&mut self, task: &str) -> String {
-        // Route to each agent
-        let mut responses: Vec<String> = vec![];
-        let mut handles: Vec<thread::JoinHandle<()>> = vec![];
-
-        for agent in &self.agents {
-            let task_clone = task.to_string();
-            let agent_clone = agent.clone();
-            let mut conversation_clone = self.conversation.clone();
-            let handle = thread::spawn(move || {
-                let response = agent_clone.run(&task_clone);
-                conversation_clone.add(&agent_clone.agent_name, &response);
-                println!("[Agent][Name: {}][Response: {}]", agent_clone.agent_name, response);
-            });
-            handles.push(handle);
-        }
+    // Compare answers with `consensus` instead of exactly
+    pub fn with_consensus(mut self, consensus: Consensus) -> Self {
+        self.consensus = consensus;
+        self
+    }
 
-        for handle in handles {
-            handle.join().unwrap();
+    // Agents that fail or panic are left out of the vote; the vote fails only if every agent did.
+    pub fn run(&mut self, task: &str) -> Result<String, TaskFailures> {
+        // Route to each agent on scoped threads that borrow the agents directly
+        let callbacks = self.callbacks.as_deref();
+        let outcomes = scoped_map_settled(&self.agents, |agent| agent.name().to_string(), |agent| {
+            let started = Instant::now();
+            let response = agent.run(task);
+            if let Ok(response) = &response {
+                println!("[Agent][Name: {}][Response: {}]", agent.name(), response);
+                // Voters run at once, so each turn is reported as the first step of its agent
+                if let Some(callbacks) = callbacks {
+                    let step = StepComplete { index: 0, output: response, elapsed: started.elapsed() };
+                    callbacks.on_step_complete(agent.name(), &step);
+                }
+            }
+            (agent.name().to_string(), response)
+        });
+        // An agent's error counts against it the same way a panic does
+        let outcomes = outcomes.into_iter().enumerate().map(|(index, outcome)| match outcome? {
+            (agent_name, Ok(response)) => Ok((agent_name, response)),
+            (label, Err(err)) => Err(TaskFailure { index, label, message: err.to_string() }),
+        });
+        let (responses, failures): (Vec<_>, Vec<_>) = outcomes.into_iter().partition(Result::is_ok);
+        let responses: Vec<(String, String)> = responses.into_iter().map(Result::unwrap).collect();
+        if !failures.is_empty() {
+            let failures = settle(failures).unwrap_err();
+            if responses.is_empty() {
+                return Err(failures);
+            }
+            println!("[MajorityVoting] voting without failed agents: {}", failures);
+        }
+        for (agent_name, response) in &responses {
+            self.conversation.add(agent_name, response);
         }
 
         // Perform majority voting on the conversation
@@ -118,33 +182,40 @@ impl MajorityVoting {
 
         // If an output parser is provided, parse the responses
         if let Some(output_parser) = self.output_parser {
-            output_parser(responses)
+            Ok(output_parser(responses))
         } else {
-            self.majority_voting(responses)
+            Ok(self.majority_voting(responses))
         }
     }
 
     fn majority_voting(&self, answers: Vec<String>) -> String {
-        let mut counter: HashMap<&str, usize> = HashMap::new();
-        for answer in answers {
-            let answer_str = answer.as_str();
-            *counter.entry(answer_str).or_insert(0) += 1;
+        match self.consensus.winner(&answers) {
+            Some(vote) if !vote.answer.is_empty() => vote.answer,
+            _ => "I don't know".to_string(),
         }
+    }
+}
 
-        let mut max_count = 0;
-        let mut max_answer = "";
-        for (answer, count) in counter {
-            if count > max_count {
-                max_count = count;
-                max_answer = answer;
-            }
-        }
+impl Swarm for MajorityVoting {
+    fn name(&self) -> &str {
+        &self.name
+    }
 
-        if max_answer.is_empty() {
-            "I don't know".to_string()
-        } else {
-            max_answer.to_string()
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        if self.agents.is_empty() {
+            return Err(SwarmError::NoAgents);
         }
+        Ok(MajorityVoting::run(self, task)?)
+    }
+
+    // Every agent answers the task; the vote is counted without a model call
+    fn planned_calls(&self, _task: &str) -> Option<Vec<PlannedCall>> {
+        Some(self.agents.iter().map(|agent| PlannedCall::new(agent.as_ref()).reading_task()).collect())
+    }
+
+    fn set_callbacks(&mut self, callbacks: Arc<dyn RunCallbacks>) -> Result<(), SwarmError> {
+        self.callbacks = Some(callbacks);
+        Ok(())
     }
 }
 
@@ -203,10 +274,12 @@ fn most_frequent<T: Eq + std::hash::Hash>(clist: Vec<T>) -> (T, usize) {
 }
 
 fn main() {
-    let agents = vec![
-        Agent::new("GPT-3".to_string()),
-        Agent::new("Codex".to_string()),
-        Agent::new("Tabnine".to_string()),
+    use crate::swarms::structs::agent::MockAgent;
+
+    let agents: Vec<Arc<dyn Agent>> = vec![
+        Arc::new(MockAgent::new("GPT-3").reply("Paris")),
+        Arc::new(MockAgent::new("Codex").reply("Paris.")),
+        Arc::new(MockAgent::new("Tabnine").reply("Lyon")),
     ];
 
     let mut majority_voting = MajorityVoting::new(
@@ -216,16 +289,19 @@ fn main() {
         None,
         false,
         false,
-    );
+    )
+    .with_consensus(Consensus::Normalized);
 
     let task = "What is the capital of France?";
-    let answer = majority_voting.run(task);
-    println!("The answer is: {}", answer);
+    match majority_voting.run(task) {
+        Ok(answer) => println!("The answer is: {}", answer),
+        Err(failures) => println!("No agent answered: {}", failures),
+    }
 }
 ```
 This code demonstrates the conversion of a Python file to Rust, ensuring compatibility and functionality without breaking interoperation with the rest of the repository. The `MajorityVoting` struct and its methods have been implemented in Rust, along with the necessary helper functions. 
 
-Please note that some functionality like `concurrent.futures` has been replaced with Rust's `std::thread` and `std::sync` modules for achieving concurrency. Also, the `Agent` struct and its methods have been simplified for demonstration purposes. 
+Please note that some functionality like `concurrent.futures` has been replaced with scoped threads (`swarms::structs::scoped_tasks`), so agents are borrowed rather than cloned and a panicking agent is reported instead of aborting the vote. Voters are `Arc<dyn Agent>` (`swarms::structs::agent`), so model-backed agents, `MockAgent`s, and whole swarms wrapped in `SwarmAgent` can vote side by side. 
 
 The code also uses the `regex` crate for regular expressions, which needs to be added to the `Cargo.toml` file:
 
@@ -11,25 +11,23 @@ Below is a possible Rust conversion of the provided Python code. Please note tha
 
 ```rust
 // Define dependencies
+use crate::swarms::prompts::prompt_fragment::{PromptAssembler, PromptFragment};
+use crate::swarms::structs::agent::{Agent, MockAgent};
+use std::borrow::Cow;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 use std::fmt;
 use tokio;
 use serde::{Serialize, Deserialize};
 
-// Define structs
-#[derive(Serialize, Deserialize)]
-struct Agent {
-    agent_name: String,
-    system_prompt: String,
-}
-
+// Define structs; the recorded config names the agents
 #[derive(Serialize, Deserialize)]
 struct MixtureOfAgentsInput {
     name: String,
     description: String,
-    agents: Vec<Agent>,
-    aggregator_agent: Agent,
+    agents: Vec<String>,
+    aggregator_agent: String,
     aggregator_system_prompt: String,
     layers: u32,
     time_created: u64,
@@ -49,14 +47,21 @@ struct MixtureOfAgentsOutput {
 struct MixtureOfAgents {
     name: String,
     description: String,
-    agents: Vec<Agent>,
-    aggregator_agent: Agent,
-    aggregator_system_prompt: String,
+    agents: Vec<Arc<dyn Agent>>,
+    aggregator_agent: Arc<dyn Agent>,
+    aggregator_system_prompt: PromptFragment,
     layers: u32,
 }
 
 impl MixtureOfAgents {
-    fn new(name: String, description: String, agents: Vec<Agent>, aggregator_agent: Agent, aggregator_system_prompt: String, layers: u32) -> MixtureOfAgents {
+    fn new(
+        name: String,
+        description: String,
+        agents: Vec<Arc<dyn Agent>>,
+        aggregator_agent: Arc<dyn Agent>,
+        aggregator_system_prompt: PromptFragment,
+        layers: u32,
+    ) -> MixtureOfAgents {
         MixtureOfAgents {
             name,
             description,
@@ -71,7 +76,7 @@ impl MixtureOfAgents {
         if self.agents.is_empty() {
             return Err("No reference agents provided.".to_string());
         }
-        if self.aggregator_agent.agent_name.is_empty() {
+        if self.aggregator_agent.name().is_empty() {
             return Err("No aggregator agent provided.".to_string());
         }
         if self.aggregator_system_prompt.is_empty() {
@@ -83,44 +88,50 @@ impl MixtureOfAgents {
         Ok(())
     }
 
-    async fn _get_final_system_prompt(&self, system_prompt: String, results: Vec<String>) -> String {
-        let mut final_prompt = system_prompt.clone();
+    // Build the aggregator prompt from borrowed pieces: one allocation, no copy of the
+    // (often multi-KB) system prompt before the final join.
+    fn _get_final_system_prompt(&self, system_prompt: &PromptFragment, results: &[Arc<str>]) -> String {
+        let mut prompt = PromptAssembler::with_capacity(1 + results.len() * 2);
+        prompt.push(system_prompt);
         for (i, result) in results.iter().enumerate() {
-            final_prompt.push_str(&format!("\n{}.", i + 1));
-            final_prompt.push_str(result);
+            prompt.push(format!("\n{}.", i + 1));
+            prompt.push(&**result);
         }
-        final_prompt
+        prompt.build()
     }
 
-    async fn _run_agent_async(&self, agent: &Agent, task: String, prev_responses: Option<Vec<String>>) -> String {
-        let mut system_prompt = agent.system_prompt.clone();
-        if let Some(prev_responses) = prev_responses {
-            system_prompt = self._get_final_system_prompt(system_prompt, prev_responses).await;
-        }
-        // Simulate agent.run(task) for demonstration purposes
-        format!("{} response to task: {}", agent.agent_name, task)
+    // Python swaps the agent's system prompt for the aggregator prompt and the previous responses. An
+    // `Arc<dyn Agent>` is shared and cannot be edited, so they go ahead of the task instead. A failed
+    // agent's error text stands in for its response.
+    async fn _run_agent_async(&self, agent: &dyn Agent, task: &str, prev_responses: Option<&[Arc<str>]>) -> Arc<str> {
+        let input: Cow<'_, str> = match prev_responses {
+            Some(prev_responses) => {
+                let prompt = self._get_final_system_prompt(&self.aggregator_system_prompt, prev_responses);
+                Cow::Owned(format!("{}\n\n{}", prompt, task))
+            }
+            None => Cow::Borrowed(task),
+        };
+        Arc::from(agent.run(&input).unwrap_or_else(|err| err.to_string()))
     }
 
-    async fn _run_async(&self, task: String) -> String {
-        let mut results: Vec<String> = Vec::new();
+    async fn _run_async(&self, task: &str) -> Arc<str> {
+        // Responses are shared as Arc<str>, so handing the previous layer to every agent copies pointers, not text.
+        let mut results: Vec<Arc<str>> = Vec::with_capacity(self.agents.len());
         for agent in &self.agents {
-            let response = self._run_agent_async(agent, task.clone(), None).await;
-            results.push(response.clone());
+            results.push(self._run_agent_async(agent.as_ref(), task, None).await);
         }
         for _ in 1..self.layers {
-            let mut new_results = Vec::new();
+            let mut new_results = Vec::with_capacity(self.agents.len());
             for agent in &self.agents {
-                let response = self._run_agent_async(agent, task.clone(), Some(results.clone())).await;
-                new_results.push(response);
+                new_results.push(self._run_agent_async(agent.as_ref(), task, Some(&results)).await);
             }
             results = new_results;
         }
-        let final_result = self._run_agent_async(&self.aggregator_agent, task.clone(), Some(results.clone())).await;
-        final_result
+        self._run_agent_async(self.aggregator_agent.as_ref(), task, Some(&results)).await
     }
 
     async fn run(&self, task: String) -> String {
-        let result = self._run_async(task).await;
+        let result = self._run_async(&task).await;
         format!("Final Aggregated Response: {}", result)
     }
 }
@@ -133,27 +144,16 @@ async fn main() {
         .unwrap()
         .as_secs();
 
-    let agent1 = Agent {
-        agent_name: "Agent1".to_string(),
-        system_prompt: "Agent1 prompt".to_string(),
-    };
-
-    let agent2 = Agent {
-        agent_name: "Agent2".to_string(),
-        system_prompt: "Agent2 prompt".to_string(),
-    };
-
-    let aggregator_agent = Agent {
-        agent_name: "Aggregator".to_string(),
-        system_prompt: "Aggregator prompt".to_string(),
-    };
+    let agent1: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent1").reply("Agent1 response"));
+    let agent2: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent2").reply("Agent2 response"));
+    let aggregator_agent: Arc<dyn Agent> = Arc::new(MockAgent::new("Aggregator"));
 
     let mixture_of_agents = MixtureOfAgents::new(
         "MixtureOfAgents".to_string(),
         "A class to run a mixture of agents and aggregate their responses.".to_string(),
         vec![agent1, agent2],
         aggregator_agent,
-        "Aggregator system prompt".to_string(),
+        PromptFragment::from("Aggregator system prompt"),
         3,
     );
 
@@ -171,8 +171,8 @@ async fn main() {
 **Notes on Conversion:**
 
 1.  The Rust code uses Tokio for async/await operations, which is a popular async runtime for Rust.
-2.  The `Agent` struct has been simplified to include only the agent's name and system prompt, as the original Python code's agent implementation is not provided.
+2.  Agents are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`). Because a shared agent cannot have its system prompt replaced, the aggregator prompt and the previous layer's responses are sent ahead of the task.
 3.  The `MixtureOfAgents` struct and its methods have been implemented to match the original Python code's functionality, with some adjustments for Rust's syntax and conventions.
 4.  The `reliability_check` method returns a `Result` to handle errors in a more Rust-idiomatic way.
-5.  The `_run_agent_async` method simulates the agent's `run` method for demonstration purposes, as the original Python code's agent implementation is not provided.
+5.  The `_run_agent_async` method calls the agent's `run`; a failed agent's error text is passed on as its response, as in the Python code.
 6.  The `run` method now uses async/await to run the mixture of agents process, and it returns the final aggregated response as a string.
\ No newline at end of file
@@ -0,0 +1,235 @@
+### Overview
+There is no Python counterpart; a Python agent uses the one `model_name` it was built with for every step,
+so a swarm that needs a strong model for its hard steps pays the strong model's price for the easy ones too.
+`ModelSelector` is a pre-router that picks a model per step from two tiers, cheap and expensive, by
+estimating how hard the step's task is. The estimate uses no model call:
+
+* **Length.** Tasks approaching `long_task_tokens` score higher.
+* **Code.** Fenced blocks, or lines that look like source (`fn`, `def`, `class`, `import`, braces and
+  semicolons).
+* **Math.** LaTeX, equations, or words like "prove", "derivative", "integral".
+* **Tools.** Each required tool adds to the score, and any tool listed in `expensive_tools` (e.g. a code
+  interpreter) forces the expensive tier.
+
+A step whose score reaches `threshold` goes to the expensive model. The choice can be overridden: for
+every step with `SelectorConfig::force_tier` (or the `SWARMS_MODEL_TIER` environment variable, `cheap` or
+`expensive`), or for one step with `select_with`.
+
+Every selection is counted. `usage()` returns the counts for this selector, and the same counts are
+exported as `swarms_model_tier_selections_total{tier, reason}`, where `reason` is `score`, `tool`, or
+`override`.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::LlmAgent;
+use crate::swarms::text::tokenizer::estimate_tokens;
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::debug;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, OnceLock};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ModelTier {
+    Cheap,
+    Expensive,
+}
+
+impl ModelTier {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ModelTier::Cheap => "cheap",
+            ModelTier::Expensive => "expensive",
+        }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "cheap" => Some(ModelTier::Cheap),
+            "expensive" => Some(ModelTier::Expensive),
+            _ => None,
+        }
+    }
+}
+
+/// Tiers and thresholds. Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelectorConfig {
+    pub cheap_model: String,
+    pub expensive_model: String,
+    /// Score at or above which a step goes to the expensive model, from 0.0 to 1.0.
+    pub threshold: f32,
+    /// A task this long scores the full length weight.
+    pub long_task_tokens: usize,
+    /// Tools that need the expensive model whatever the score.
+    pub expensive_tools: Vec<String>,
+    /// Send every step to this tier.
+    pub force_tier: Option<ModelTier>,
+}
+
+impl Default for SelectorConfig {
+    fn default() -> Self {
+        SelectorConfig {
+            cheap_model: "gpt-4o-mini".to_string(),
+            expensive_model: "gpt-4o".to_string(),
+            threshold: 0.5,
+            long_task_tokens: 2_000,
+            expensive_tools: vec!["code_interpreter".to_string()],
+            force_tier: None,
+        }
+    }
+}
+
+/// What the estimate saw in a task.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComplexitySignals {
+    pub tokens: usize,
+    pub has_code: bool,
+    pub has_math: bool,
+    pub tools: usize,
+    pub score: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SelectionReason {
+    Score,
+    /// A tool in `expensive_tools` was required.
+    Tool,
+    Override,
+}
+
+impl SelectionReason {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SelectionReason::Score => "score",
+            SelectionReason::Tool => "tool",
+            SelectionReason::Override => "override",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Selection {
+    pub tier: ModelTier,
+    pub model: String,
+    pub reason: SelectionReason,
+    pub signals: ComplexitySignals,
+}
+
+/// How often each tier was chosen.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct TierUsage {
+    pub cheap: u64,
+    pub expensive: u64,
+}
+
+fn code_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?m)```|^\s*(fn|def|class|import|from \S+ import|#include|public|let|const)\b|[{};]\s*$")
+            .expect("code pattern compiles")
+    })
+}
+
+fn math_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?i)\\(frac|sum|int|sqrt)|\$[^$]+\$|\b(prove|proof|derivative|integral|equation|matrix|eigen\w*|theorem)\b|\d\s*[\^=]\s*\d")
+            .expect("math pattern compiles")
+    })
+}
+
+/// Scores tasks and picks a tier. Clones share their usage counts.
+#[derive(Debug, Clone)]
+pub struct ModelSelector {
+    config: SelectorConfig,
+    cheap: Arc<AtomicU64>,
+    expensive: Arc<AtomicU64>,
+}
+
+impl ModelSelector {
+    /// `SWARMS_MODEL_TIER`, when set to a tier, overrides `config.force_tier`.
+    pub fn new(mut config: SelectorConfig) -> Self {
+        if let Some(tier) = env::var("SWARMS_MODEL_TIER").ok().as_deref().and_then(ModelTier::parse) {
+            config.force_tier = Some(tier);
+        }
+        ModelSelector { config, cheap: Arc::new(AtomicU64::new(0)), expensive: Arc::new(AtomicU64::new(0)) }
+    }
+
+    pub fn config(&self) -> &SelectorConfig {
+        &self.config
+    }
+
+    /// Estimate the complexity of `task`, which will be able to call `tools`.
+    pub fn estimate(&self, task: &str, tools: &[&str]) -> ComplexitySignals {
+        let tokens = estimate_tokens(task);
+        let has_code = code_pattern().is_match(task);
+        let has_math = math_pattern().is_match(task);
+        let length = (tokens as f32 / self.config.long_task_tokens.max(1) as f32).min(1.0);
+        let score = 0.4 * length
+            + if has_code { 0.3 } else { 0.0 }
+            + if has_math { 0.3 } else { 0.0 }
+            + (0.1 * tools.len() as f32).min(0.3);
+        ComplexitySignals { tokens, has_code, has_math, tools: tools.len(), score: score.min(1.0) }
+    }
+
+    pub fn select(&self, task: &str, tools: &[&str]) -> Selection {
+        self.select_with(task, tools, None)
+    }
+
+    /// `select`, with `tier` overriding the estimate for this step only.
+    pub fn select_with(&self, task: &str, tools: &[&str], tier: Option<ModelTier>) -> Selection {
+        let signals = self.estimate(task, tools);
+        let (tier, reason) = if let Some(tier) = tier.or(self.config.force_tier) {
+            (tier, SelectionReason::Override)
+        } else if tools.iter().any(|tool| self.config.expensive_tools.iter().any(|expensive| expensive == tool)) {
+            (ModelTier::Expensive, SelectionReason::Tool)
+        } else if signals.score >= self.config.threshold {
+            (ModelTier::Expensive, SelectionReason::Score)
+        } else {
+            (ModelTier::Cheap, SelectionReason::Score)
+        };
+        let (model, counter) = match tier {
+            ModelTier::Cheap => (&self.config.cheap_model, &self.cheap),
+            ModelTier::Expensive => (&self.config.expensive_model, &self.expensive),
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        SwarmMetrics::global().model_tier_selected(tier.as_str(), reason.as_str());
+        debug!("Selected {} model {} ({:?}, score {:.2})", tier.as_str(), model, reason, signals.score);
+        Selection { tier, model: model.clone(), reason, signals }
+    }
+
+    /// Point `agent` at the model selected for `task`.
+    pub fn apply(&self, agent: &mut LlmAgent, task: &str, tools: &[&str]) -> Selection {
+        let selection = self.select(task, tools);
+        agent.llm = selection.model.clone();
+        selection
+    }
+
+    pub fn usage(&self) -> TierUsage {
+        TierUsage { cheap: self.cheap.load(Ordering::Relaxed), expensive: self.expensive.load(Ordering::Relaxed) }
+    }
+}
+
+fn main() {
+    let selector = ModelSelector::new(SelectorConfig::default());
+    for task in [
+        "Summarize this email in one sentence.",
+        "Prove that the derivative of x^2 is 2x.",
+        "Fix the bug:\n```rust\nfn main() { let x = 1; }\n```",
+    ] {
+        let selection = selector.select(task, &[]);
+        println!("{:<9} {:.2}  {}", selection.tier.as_str(), selection.signals.score, task.lines().next().unwrap_or(""));
+    }
+    println!("{:?}", selector.usage());
+}
+```
+
+### Notes
+* The estimate is a heuristic tuned to be cheap, not exact. Watch the tier counts and lower `threshold` if quality complaints cluster on cheap-tier steps.
+* Both tiers should share a context window large enough for the longest task; the selector does not check capabilities.
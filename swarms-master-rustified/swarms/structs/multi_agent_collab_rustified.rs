@@ -9,26 +9,8 @@ use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
 use std::path::Path;
-
-// Assuming the Agent struct has the following fields
-#[derive(Debug)]
-struct Agent {
-    name: String,
-    llm: String, // Assuming llm is of type String
-    max_loops: i32,
-    dashboard: bool,
-}
-
-impl Agent {
-    fn send(&self) -> String {
-        // Implementation of send method
-        String::from("Example response")
-    }
-
-    fn run(&self, conversation: String) {
-        // Implementation of run method
-    }
-}
+use std::sync::Arc;
+use crate::swarms::structs::agent::{Agent, MockAgent};
 
 // Assuming the Conversation struct has the following fields
 #[derive(Debug)]
@@ -51,27 +33,25 @@ impl Conversation {
 }
 
 // Assuming the BaseSwarm struct has the following fields
-#[derive(Debug)]
 struct BaseSwarm {
     name: String,
     description: String,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
 }
 
 impl BaseSwarm {
-    fn new(name: String, description: String, agents: Vec<Agent>) -> Self {
+    fn new(name: String, description: String, agents: Vec<Arc<dyn Agent>>) -> Self {
         Self { name, description, agents }
     }
 }
 
 // Implementing MultiAgentCollaboration in Rust
-#[derive(Debug)]
 struct MultiAgentCollaboration {
     name: String,
     description: String,
-    director: Option<Agent>,
-    agents: Vec<Agent>,
-    select_next_speaker: fn(i32, &Vec<Agent>) -> usize,
+    director: Option<Arc<dyn Agent>>,
+    agents: Vec<Arc<dyn Agent>>,
+    select_next_speaker: fn(i32, &[Arc<dyn Agent>]) -> usize,
     step: i32,
     max_loops: i32,
     autosave: bool,
@@ -85,9 +65,9 @@ impl MultiAgentCollaboration {
     fn new(
         name: String,
         description: String,
-        director: Option<Agent>,
-        agents: Vec<Agent>,
-        select_next_speaker: fn(i32, &Vec<Agent>) -> usize,
+        director: Option<Arc<dyn Agent>>,
+        agents: Vec<Arc<dyn Agent>>,
+        select_next_speaker: fn(i32, &[Arc<dyn Agent>]) -> usize,
         max_loops: i32,
         autosave: bool,
         saved_file_path_name: String,
@@ -111,34 +91,33 @@ impl MultiAgentCollaboration {
         }
     }
 
+    // Every agent reads the shared conversation, so a message added once reaches all of them
     fn inject(&mut self, name: String, message: String) {
-        for agent in &self.agents {
-            self.conversation.add(name.clone(), message.clone());
-            agent.run(self.conversation.return_history_as_string());
-        }
+        self.conversation.add(name, message);
         self.step += 1;
     }
 
+    // The next speaker answers the conversation so far; a failed turn adds its error text instead
     fn step(&mut self) -> String {
         let speaker_idx = (self.select_next_speaker)(self.step, &self.agents);
-        let speaker = &self.agents[speaker_idx];
-        let message = speaker.send();
-
-        for receiver in &self.agents {
-            self.conversation.add(speaker.name.clone(), message.clone());
-            receiver.run(self.conversation.return_history_as_string());
-        }
+        let speaker = Arc::clone(&self.agents[speaker_idx]);
+        let message = speaker
+            .run(&self.conversation.return_history_as_string())
+            .unwrap_or_else(|err| err.to_string());
+        self.log_step(speaker.as_ref(), &message);
+        self.conversation.add(speaker.name().to_string(), message);
 
         self.step += 1;
 
         self.conversation.return_history_as_string()
     }
 
-    fn log_step(&self, speaker: &Agent, response: String) {
-        println!("{}: {}", speaker.name, response);
+    fn log_step(&self, speaker: &dyn Agent, response: &str) {
+        println!("{}: {}", speaker.name(), response);
     }
 
     fn run(&mut self, task: String) -> String {
+        self.inject(String::from("user"), task);
         for _ in 0..self.max_loops {
             let result = self.step();
             if self.autosave {
@@ -164,25 +143,14 @@ impl MultiAgentCollaboration {
     }
 }
 
-fn default_select_next_speaker(step: i32, agents: &Vec<Agent>) -> usize {
+fn default_select_next_speaker(step: i32, agents: &[Arc<dyn Agent>]) -> usize {
     step as usize % agents.len()
 }
 
 fn main() {
     // Example usage:
-    let agent1 = Agent {
-        name: String::from("Agent 1"),
-        llm: String::from("LLM 1"),
-        max_loops: 1,
-        dashboard: true,
-    };
-
-    let agent2 = Agent {
-        name: String::from("Agent 2"),
-        llm: String::from("LLM 2"),
-        max_loops: 1,
-        dashboard: true,
-    };
+    let agent1: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent 1").reply("Example response"));
+    let agent2: Arc<dyn Agent> = Arc::new(MockAgent::new("Agent 2").reply("Stopping token"));
 
     let mut collaboration = MultiAgentCollaboration::new(
         String::from("Example Collaboration"),
@@ -204,7 +172,7 @@ The given Python code has several custom classes and methods, which are not prov
 
 **Limitations and Challenges:**
 
-1.  **Custom Classes and Methods:** The Python code uses several custom classes and methods that are not defined in the provided snippet. These need to be implemented or replaced with their Rust equivalents.
+1.  **Custom Classes and Methods:** The Python code uses several custom classes and methods that are not defined in the provided snippet. Agents are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`); a speaker's turn is its `run` on the conversation so far, standing in for the Python `send`. `Conversation` and `BaseSwarm` are still local stand-ins.
 2.  **Type Differences:** Python and Rust have different type systems. Rust is statically typed, which means it checks the types of variables at compile time. You may need to add type annotations for variables and function parameters in the Rust code.
 3.  **Error Handling:** Rust has a strong focus on error handling, and you'll need to handle potential errors that may occur during file operations, JSON parsing, or other tasks.
 4.  **Library Dependencies:** The Python code seems to use several external libraries, such as `swarms.structs.agent`, `swarms.utils.loguru_logger`, and `swarms.structs.base_swarm`. You'll need to find equivalent Rust libraries or implement the required functionality manually.
@@ -13,25 +13,15 @@
 // The provided code also uses a logger, which can be replaced with a Rust logging crate like log or 
 // log4rs. 
 
-use std::collections::VecDeque;
-use std::thread;
-use std::sync::{Arc, Mutex};
+use crate::swarms::structs::agent::{Agent, MockAgent};
+use crate::swarms::structs::scoped_tasks::{join_all_blocking, scoped_map, scoped_map_settled, settle, TaskFailure, TaskFailures};
+use std::sync::Arc;
 use std::time::Duration;
 use log::{info, error};
 use log4rs;
 
-// Define a struct to represent an Agent
-struct Agent {
-    agent_name: String,
-}
-
-impl Agent {
-    fn new(agent_name: String) -> Agent {
-        Agent { agent_name }
-    }
-}
-
 // Define a struct to represent a Task
+#[derive(Clone)]
 struct Task {
     name: String,
     execute: fn() -> String,
@@ -48,11 +38,11 @@ impl Task {
 struct MultiProcessWorkflow {
     max_workers: usize,
     autosave: bool,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
 }
 
 impl MultiProcessWorkflow {
-    fn new(max_workers: usize, autosave: bool, agents: Vec<Agent>) -> MultiProcessWorkflow {
+    fn new(max_workers: usize, autosave: bool, agents: Vec<Arc<dyn Agent>>) -> MultiProcessWorkflow {
         MultiProcessWorkflow {
             max_workers,
             autosave,
@@ -60,119 +50,48 @@ impl MultiProcessWorkflow {
         }
     }
 
-    // Execute a task and handle exceptions
-    fn execute_task(&self, task: Task) -> Option<String> {
-        // Try to execute the task
-        let result = match task.execute() {
-            result => Some(result),
-            _ => None,
-        };
-
-        // Handle exceptions
-        if result.is_none() {
-            error!("An error occurred during execution of task {}", task.name);
-        }
-
-        result
+    // Execute a task. A panic inside the task is caught by the caller's scope or join set
+    // and reported as a `TaskFailure` naming the task.
+    fn execute_task(task: &Task) -> String {
+        info!("Executing task {}", task.name);
+        (task.execute)()
     }
 
-    // Run the workflow
-    fn run(&self, task: Task) -> Vec<Option<String>> {
-        let mut results: Vec<Option<String>> = Vec::new();
-
-        // Create a thread pool with the specified number of workers
-        let mut handles: Vec<thread::JoinHandle<_>> = Vec::new();
-        for _ in 0..self.max_workers {
-            let task_clone = task.clone();
-            let handle = thread::spawn(move || {
-                let result = self.execute_task(task_clone);
-                result
-            });
-            handles.push(handle);
-        }
-
-        // Wait for all threads to complete and collect results
-        for handle in handles {
-            let result = handle.join().unwrap();
-            results.push(result);
-        }
-
-        results
+    // Run the same task on `max_workers` workers
+    fn run(&self, task: Task) -> Result<Vec<String>, TaskFailures> {
+        let copies = vec![task; self.max_workers];
+        scoped_map(&copies, |task| task.name.clone(), Self::execute_task)
     }
 
-    // Asynchronously run the workflow
-    async fn async_run(&self, task: Task) -> Vec<Option<String>> {
-        let mut results: Vec<Option<String>> = Vec::new();
-
-        // Create a thread pool with the specified number of workers
-        let mut handles: Vec<tokio::task::JoinHandle<_>> = Vec::new();
-        for _ in 0..self.max_workers {
-            let task_clone = task.clone();
-            let handle = tokio::spawn(async move {
-                let result = self.execute_task(task_clone);
-                result
-            });
-            handles.push(handle);
-        }
-
-        // Wait for all threads to complete and collect results
-        for handle in handles {
-            let result = handle.await.unwrap();
-            results.push(result);
-        }
-
-        results
+    // Asynchronously run the workflow on tokio's blocking pool
+    async fn async_run(&self, task: Task) -> Result<Vec<String>, TaskFailures> {
+        let jobs = (0..self.max_workers)
+            .map(|_| {
+                let task = task.clone();
+                (task.name.clone(), move || Self::execute_task(&task))
+            })
+            .collect();
+        join_all_blocking(jobs).await
     }
 
-    // Run tasks in batches
-    fn batched_run(&self, tasks: Vec<Task>, batch_size: usize) -> Vec<Option<String>> {
-        let mut results: Vec<Option<String>> = Vec::new();
-
-        // Create a thread pool with the specified number of workers
-        for i in (0..tasks.len()).step_by(batch_size) {
-            let batch: Vec<Task> = tasks[i..std::cmp::min(i + batch_size, tasks.len())].to_vec();
-
-            // Create a thread for each task in the batch
-            let mut handles: Vec<thread::JoinHandle<_>> = Vec::new();
-            for task in batch {
-                let handle = thread::spawn(move || {
-                    let result = self.execute_task(task);
-                    result
-                });
-                handles.push(handle);
-            }
-
-            // Wait for all threads to complete and collect results
-            for handle in handles {
-                let result = handle.join().unwrap();
-                results.push(result);
-            }
+    // Run tasks in batches; every task in a batch finishes before the next batch starts.
+    // All batches run even if one fails, and every failure is reported with its index in `tasks`.
+    fn batched_run(&self, tasks: Vec<Task>, batch_size: usize) -> Result<Vec<String>, TaskFailures> {
+        let mut outcomes = Vec::with_capacity(tasks.len());
+        for (batch_index, batch) in tasks.chunks(batch_size.max(1)).enumerate() {
+            let offset = batch_index * batch_size.max(1);
+            outcomes.extend(
+                scoped_map_settled(batch, |task| task.name.clone(), Self::execute_task)
+                    .into_iter()
+                    .map(|outcome| outcome.map_err(|failure| TaskFailure { index: failure.index + offset, ..failure })),
+            );
         }
-
-        results
+        settle(outcomes)
     }
 
-    // Run tasks concurrently
-    fn concurrent_run(&self, tasks: Vec<Task>) -> Vec<Option<String>> {
-        let mut results: Vec<Option<String>> = Vec::new();
-
-        // Create a thread pool with the specified number of workers
-        let mut handles: Vec<thread::JoinHandle<_>> = Vec::new();
-        for task in tasks {
-            let handle = thread::spawn(move || {
-                let result = self.execute_task(task);
-                result
-            });
-            handles.push(handle);
-        }
-
-        // Wait for all threads to complete and collect results
-        for handle in handles {
-            let result = handle.join().unwrap();
-            results.push(result);
-        }
-
-        results
+    // Run tasks concurrently, at most `max_workers` at a time
+    fn concurrent_run(&self, tasks: Vec<Task>) -> Result<Vec<String>, TaskFailures> {
+        self.batched_run(tasks, self.max_workers)
     }
 }
 
@@ -181,33 +100,28 @@ fn main() {
     log4rs::init_file("log4rs.yaml", Default::default()).unwrap();
 
     // Create a MultiProcessWorkflow instance
-    let agents: Vec<Agent> = vec![Agent::new(String::from("Agent1"))];
+    let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("Agent1"))];
     let workflow = MultiProcessWorkflow::new(5, true, agents);
 
     // Define a simple task
     let task = Task::new(
         String::from("Simple Task"),
         || {
-            thread::sleep(Duration::from_millis(1000));
+            std::thread::sleep(Duration::from_millis(1000));
             String::from("Task executed")
         },
         1000,
     );
 
-    // Run the workflow
-    let results = workflow.run(task);
-
-    // Print the results
-    for result in results {
-        match result {
-            Some(result) => info!("{}", result),
-            None => info!("Task execution failed"),
-        }
+    // Run the workflow and print the results
+    match workflow.run(task) {
+        Ok(results) => results.iter().for_each(|result| info!("{}", result)),
+        Err(failures) => error!("{}", failures),
     }
 }
 ```
 
-This Rust version of the provided Python code uses the `std::thread` module for parallelism and the `log` crate for logging. Note that this code does not include error handling and other features that may be necessary for a production-ready system.
+This Rust version of the provided Python code uses scoped threads and tokio's blocking pool (via `swarms::structs::scoped_tasks`) for parallelism and the `log` crate for logging. A panicking task is reported as a `TaskFailure` instead of aborting the workflow.
 
 Some key differences between the Python and Rust versions:
 
@@ -215,4 +129,5 @@ Some key differences between the Python and Rust versions:
 2. **Async/Await**: Python's `asyncio` is not directly equivalent in Rust. Instead, Rust provides the `async-std` or `tokio` crate for asynchronous execution.
 3. **Logging**: Python's `logging` module is not directly equivalent in Rust. Instead, Rust provides the `log` or `log4rs` crate for logging.
 4. **Error Handling**: Rust's error handling is more explicit than Python's. Rust uses `Result` and `Option` to handle errors, while Python uses try-except blocks.
-5. **Type System**: Rust has a statically typed type system, while Python has a dynamically typed type system. This means that Rust requires explicit type annotations, while Python can often infer types automatically.
\ No newline at end of file
+5. **Agents**: The workflow holds the crate's `Arc<dyn Agent>` (`swarms::structs::agent`) rather than a local agent struct, so any agent of the crate can be registered with it.
+6. **Type System**: Rust has a statically typed type system, while Python has a dynamically typed type system. This means that Rust requires explicit type annotations, while Python can often infer types automatically.
\ No newline at end of file
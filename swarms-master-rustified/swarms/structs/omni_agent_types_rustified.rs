@@ -4,46 +4,24 @@
 // However, the exact types used in the Python code (e.g., `Agent`, `BaseLLM`, `BaseMultiModalModel`) are not defined in this snippet, 
 // and their Rust equivalents would depend on their definitions in the broader repository.
 
-// Define equivalent types in Rust
-use swarm_models::base_llm::BaseLLM;
-use swarm_models::base_multimodal_model::BaseMultiModalModel;
-use swarms::structs::agent::Agent;
+use crate::swarms::structs::agent::Agent;
+use std::sync::Arc;
 
-// Define a trait for the different types of agents to implement
-pub trait AgentTrait {}
-
-impl AgentTrait for Agent {}
-impl AgentTrait for Box<dyn Fn()> {}  // Using Box<dyn Fn()> as a rough equivalent to Python's Callable
-impl AgentTrait for () {}  // For 'Any' type, Rust has no direct equivalent, so using unit type () for now
-impl AgentTrait for BaseLLM {}
-impl AgentTrait for BaseMultiModalModel {}
-
-// Define an enum for agent types
-enum AgentType {
-    Agent(Agent),
-    Callable(Box<dyn Fn()>),
-    Any(()),  // Again, no direct Rust equivalent
-    BaseLLM(BaseLLM),
-    BaseMultiModalModel(BaseMultiModalModel),
-}
+// Anything a swarm can run: model-backed agents, mock agents, and sub-swarms all implement `Agent`.
+// Python's `Callable`, `BaseLLM`, and `BaseMultiModalModel` members are covered by binding the call
+// as an `LlmAgent` completion.
+pub type AgentType = Arc<dyn Agent>;
 
 // Define a type alias for a list of agents
-type AgentListType = Vec<AgentType>;
-
-// Alternatively, you can define AgentType as a trait object
-type AgentTypeTraitObject = Box<dyn AgentTrait>;
-
-// And then define AgentListType using this trait object
-type AgentListTypeTraitObject = Vec<AgentTypeTraitObject>;
+pub type AgentListType = Vec<AgentType>;
 
 ```
 
 Feedback:
 - **Partial conversion viability**: The provided Python code defines type aliases and can be partially converted to Rust. However, Rust's type system is more verbose and strict than Python's, so the conversion is not direct.
 - **Challenges**:
-  - **Equivalent types**: Rust has no direct equivalent to Python's `Any` type, which can represent any type. In the provided Rust code, the unit type `()` is used as a placeholder, but a better solution would depend on the specific requirements of the repository.
-  - **Callable type**: Python's `Callable` type is equivalent to Rust's `Fn` trait. However, Rust's `Fn` trait is not a type and cannot be used directly in type aliases. Instead, `Box<dyn Fn()>` is used as a rough equivalent.
-  - **Type definitions**: The exact definitions of `Agent`, `BaseLLM`, and `BaseMultiModalModel` in the repository are unknown and would affect the conversion.
+  - **Equivalent types**: Rust has no direct equivalent to Python's `Any` type, which can represent any type. The union is replaced by the `Agent` trait, which every runnable member implements.
+  - **Callable type**: Python's `Callable` type is equivalent to Rust's `Fn` trait. A bare callable becomes an `LlmAgent` with that callable bound as its `Completion`, or a `MockAgent` in tests.
 - **Interoperation with the rest of the repository**: Maintaining interoperation would require ensuring that the converted Rust code is compatible with the rest of the repository, which may involve modifying the Rust code to match the existing interfaces and type definitions.
 - **Suggestions for improvement**:
   - Consider using Rust's trait system to define a common interface for different agent types.
@@ -0,0 +1,210 @@
+### Overview
+There is no Python counterpart. The Python list endpoints return every record in one response. That is
+fine for a demo and too slow for a deployment with thousands of agents or runs. List calls on the
+stores (`AgentStore::list`, `TaskLedger::list`) return one `Page` at a time instead.
+
+Pages use keyset cursors, not offsets. A cursor records the sort field, and the sort key and id of the
+last item on the page. The next page starts strictly after that item. Records created or deleted between
+two calls therefore never shift a page: nothing is skipped and nothing is returned twice. The id breaks
+ties between equal sort keys, so the order is total.
+
+Cursors are opaque to clients (base64url JSON). A cursor is only valid with the sort it was issued for,
+and using it with another sort is rejected with `PageError::InvalidCursor` instead of returning a
+confusing page.
+
+Sort parameters follow the common `sort=field` / `sort=-field` convention. A leading `-` means descending.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::Problem;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD as BASE64_URL;
+use base64::Engine;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::fmt;
+
+pub const DEFAULT_PAGE_LIMIT: usize = 50;
+pub const MAX_PAGE_LIMIT: usize = 200;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PageError {
+    /// The cursor is malformed, or was issued for a different sort.
+    InvalidCursor(String),
+    /// The limit is zero or above `MAX_PAGE_LIMIT`.
+    InvalidLimit(usize),
+    /// An unknown parameter, filter value, or sort field.
+    InvalidParameter(String),
+}
+
+impl fmt::Display for PageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PageError::InvalidCursor(message) => write!(f, "invalid cursor: {}", message),
+            PageError::InvalidLimit(limit) => write!(f, "limit must be between 1 and {}, got {}", MAX_PAGE_LIMIT, limit),
+            PageError::InvalidParameter(message) => write!(f, "invalid list parameter: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for PageError {}
+
+impl From<&PageError> for Problem {
+    fn from(err: &PageError) -> Self {
+        match err {
+            PageError::InvalidCursor(_) => Problem::new(400, "invalid_cursor", "Invalid pagination cursor", err.to_string()),
+            _ => Problem::bad_request(err.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    #[default]
+    Asc,
+    Desc,
+}
+
+/// Which page to return.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageRequest {
+    pub limit: usize,
+    /// `next_cursor` from the previous page; `None` for the first page.
+    pub cursor: Option<String>,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        PageRequest { limit: DEFAULT_PAGE_LIMIT, cursor: None }
+    }
+}
+
+impl PageRequest {
+    pub fn first(limit: usize) -> Self {
+        PageRequest { limit, cursor: None }
+    }
+
+    pub fn after(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Take `limit` or `cursor` from a query-string pair. Returns false for any other key.
+    pub fn parse_param(&mut self, key: &str, value: &str) -> Result<bool, PageError> {
+        match key {
+            "limit" => {
+                self.limit = value.parse().map_err(|_| PageError::InvalidParameter(format!("bad limit '{}'", value)))?;
+                Ok(true)
+            }
+            "cursor" => {
+                self.cursor = Some(value.to_string());
+                Ok(true)
+            }
+            _ => Ok(false),
+        }
+    }
+}
+
+/// One page of results.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(utoipa::ToSchema))]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// Pass back as `cursor` for the next page. `None` on the last page.
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+struct Cursor {
+    #[serde(rename = "s")]
+    sort: String,
+    #[serde(rename = "k")]
+    key: String,
+    #[serde(rename = "i")]
+    id: String,
+}
+
+impl Cursor {
+    fn encode(&self) -> String {
+        BASE64_URL.encode(serde_json::to_vec(self).expect("a cursor always serializes"))
+    }
+
+    fn decode(cursor: &str) -> Result<Cursor, PageError> {
+        let bytes = BASE64_URL.decode(cursor).map_err(|_| PageError::InvalidCursor("not a cursor".to_string()))?;
+        serde_json::from_slice(&bytes).map_err(|_| PageError::InvalidCursor("not a cursor".to_string()))
+    }
+}
+
+/// Parse `sort=field` or `sort=-field` into a field name and order.
+pub fn parse_sort(value: &str) -> (&str, SortOrder) {
+    match value.strip_prefix('-') {
+        Some(field) => (field, SortOrder::Desc),
+        None => (value, SortOrder::Asc),
+    }
+}
+
+/// Sort `items` by `(key, id)` and cut the page `request` asks for.
+///
+/// `sort` is the sort parameter as given (`-created_at`) and is stamped into the cursor, so a cursor
+/// cannot be reused with another field or direction. `key` returns an item's sort key and
+/// its unique id. Keys compare as strings, so timestamps must be RFC 3339 in UTC.
+pub fn paginate<T>(
+    items: Vec<T>,
+    sort: &str,
+    order: SortOrder,
+    request: &PageRequest,
+    key: impl Fn(&T) -> (String, String),
+) -> Result<Page<T>, PageError> {
+    if request.limit == 0 || request.limit > MAX_PAGE_LIMIT {
+        return Err(PageError::InvalidLimit(request.limit));
+    }
+    let after = match &request.cursor {
+        Some(cursor) => {
+            let cursor = Cursor::decode(cursor)?;
+            if cursor.sort != sort {
+                return Err(PageError::InvalidCursor(format!("issued for sort '{}', not '{}'", cursor.sort, sort)));
+            }
+            Some((cursor.key, cursor.id))
+        }
+        None => None,
+    };
+    let directed = |ordering: Ordering| if order == SortOrder::Desc { ordering.reverse() } else { ordering };
+
+    let mut keyed: Vec<((String, String), T)> = items.into_iter().map(|item| (key(&item), item)).collect();
+    if let Some(after) = &after {
+        keyed.retain(|(item_key, _)| directed(item_key.cmp(after)) == Ordering::Greater);
+    }
+    keyed.sort_by(|(a, _), (b, _)| directed(a.cmp(b)));
+
+    let has_more = keyed.len() > request.limit;
+    keyed.truncate(request.limit);
+    let next_cursor = match keyed.last() {
+        Some(((key, id), _)) if has_more => {
+            Some(Cursor { sort: sort.to_string(), key: key.clone(), id: id.clone() }.encode())
+        }
+        _ => None,
+    };
+    Ok(Page { items: keyed.into_iter().map(|(_, item)| item).collect(), next_cursor })
+}
+
+fn main() -> Result<(), PageError> {
+    let names: Vec<String> = (1..=5).map(|i| format!("agent-{}", i)).collect();
+    let mut request = PageRequest::first(2);
+    loop {
+        let page = paginate(names.clone(), "name", SortOrder::Asc, &request, |name| (name.clone(), name.clone()))?;
+        println!("{:?}", page.items);
+        match page.next_cursor {
+            Some(cursor) => request = request.after(cursor),
+            None => break,
+        }
+    }
+    Ok(())
+}
+```
+
+### Notes
+* Paging is done in memory over the store's records for one tenant. That keeps each response small, but the
+  store still reads every record. A store backed by a database would push the same `(key, id) > cursor`
+  condition into its query.
+* A cursor is not signed. Tampering with one can only select a different starting point inside the caller's
+  own tenant, since every store filters by tenant before paging.
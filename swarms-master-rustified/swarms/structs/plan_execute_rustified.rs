@@ -0,0 +1,419 @@
+### Overview
+The Rust counterpart of the Python `Agent`'s `planning` and `planning_prompt` options. In Python, planning
+adds one free-text "plan" message to the conversation and then runs the task as usual, so nothing checks
+that the plan is followed. Here planning is a mode of its own. The agent first asks the model for a
+structured `Plan` of typed steps, then executes the steps one at a time:
+
+* `respond`: the agent's model answers the step itself;
+* `tool`: a tool from the `ToolRegistry` is called with the step's arguments;
+* `agent`: the step is handed to a named sub-agent.
+
+Each step runs under `guard_step`, so an error or a panic marks that step failed instead of
+ending the run. On a failure the model is asked to re-plan. It sees the steps already completed, with
+their outputs, and the error, and it returns a new plan for the remaining work. It can re-plan up to
+`max_replans` times.
+
+`PlanRun` is the run metadata. It holds every plan revision and a `StepRecord` for each executed step,
+and it serializes next to the rest of the run's output.
+
+The model is reached through closures, as in `Agent::run_loops`. `planner` turns a prompt into the
+model's reply, and a `StepExecutor` runs steps. `StepRouter` is the executor that dispatches by step kind.
+A step fails with a `Failure`, so its `StepRecord` keeps the code a typed error was classified with (a
+tool's `tool_error`, an unparseable plan's `validation_error`) instead of guessing it from the message.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use crate::swarms::structs::failure::{Failure, FailureCode};
+use crate::swarms::structs::step_guard::{guard_step, StepRecord, StepStatus};
+#[cfg(feature = "tools")]
+use crate::swarms::tools::base_tool::ToolRegistry;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::fmt;
+
+pub const DEFAULT_PLANNING_PROMPT: &str =
+    "Break the task into the shortest sequence of concrete steps that accomplishes it. Each step should \
+     produce something the following steps can use.";
+
+/// Appended to every planning prompt, custom or not, so the reply can be parsed.
+pub const PLAN_FORMAT: &str = "Reply with only a JSON object of the form {\"steps\": [...]}. Each step has a \
+     \"description\" and an \"action\": \"respond\" to do the step yourself, \"tool\" with a \"tool\" name \
+     and \"arguments\" object, or \"agent\" with an \"agent\" name and the \"task\" to give it.";
+
+/// Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PlanningConfig {
+    pub prompt: String,
+    /// How many times a failed step or an unparseable plan may be re-planned.
+    pub max_replans: u32,
+    /// Steps executed across all revisions before the run gives up.
+    pub max_steps: usize,
+}
+
+impl Default for PlanningConfig {
+    fn default() -> Self {
+        PlanningConfig { prompt: DEFAULT_PLANNING_PROMPT.to_string(), max_replans: 2, max_steps: 20 }
+    }
+}
+
+impl PlanningConfig {
+    /// `None` unless `planning` is set. `custom_planning_prompt` takes precedence over `planning_prompt`.
+    pub fn from_schema(schema: &AgentSchema) -> Option<Self> {
+        if !schema.planning.unwrap_or(false) {
+            return None;
+        }
+        let prompt = schema.custom_planning_prompt.clone().or_else(|| schema.planning_prompt.clone());
+        Some(PlanningConfig { prompt: prompt.unwrap_or_else(|| DEFAULT_PLANNING_PROMPT.to_string()), ..PlanningConfig::default() })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PlanError {
+    /// The planner's reply was not a plan.
+    Invalid(String),
+    Empty,
+}
+
+impl fmt::Display for PlanError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PlanError::Invalid(msg) => write!(f, "invalid plan: {}", msg),
+            PlanError::Empty => write!(f, "plan has no steps"),
+        }
+    }
+}
+
+impl std::error::Error for PlanError {}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum StepAction {
+    Respond,
+    Tool {
+        tool: String,
+        #[serde(default)]
+        arguments: JsonValue,
+    },
+    Agent {
+        agent: String,
+        /// What to tell the sub-agent; the step description when omitted.
+        #[serde(default)]
+        task: Option<String>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanStep {
+    /// One-based position in its plan, assigned on parse.
+    #[serde(default)]
+    pub id: usize,
+    pub description: String,
+    #[serde(flatten)]
+    pub action: StepAction,
+}
+
+impl PlanStep {
+    /// The `step` of its `StepRecord`: the description, `tool:<name>`, or `agent:<name>`.
+    pub fn label(&self) -> String {
+        match &self.action {
+            StepAction::Respond => self.description.clone(),
+            StepAction::Tool { tool, .. } => format!("tool:{}", tool),
+            StepAction::Agent { agent, .. } => format!("agent:{}", agent),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plan {
+    /// 0 for the first plan, then one more per re-plan.
+    pub revision: u32,
+    pub steps: Vec<PlanStep>,
+}
+
+impl Plan {
+    /// Parse a planner reply: a `{"steps": [...]}` object or a bare array, optionally inside a code fence
+    /// or surrounded by prose.
+    pub fn parse(reply: &str, revision: u32) -> Result<Plan, PlanError> {
+        let start = reply.find(['{', '[']).ok_or_else(|| PlanError::Invalid("no JSON in reply".to_string()))?;
+        let end = reply
+            .rfind(['}', ']'])
+            .filter(|&end| end > start)
+            .ok_or_else(|| PlanError::Invalid("unterminated JSON in reply".to_string()))?;
+        let mut value: JsonValue =
+            serde_json::from_str(&reply[start..=end]).map_err(|e| PlanError::Invalid(e.to_string()))?;
+        let steps = match value {
+            JsonValue::Array(_) => value,
+            _ => value["steps"].take(),
+        };
+        let mut steps: Vec<PlanStep> = serde_json::from_value(steps).map_err(|e| PlanError::Invalid(e.to_string()))?;
+        if steps.is_empty() {
+            return Err(PlanError::Empty);
+        }
+        for (index, step) in steps.iter_mut().enumerate() {
+            step.id = index + 1;
+        }
+        Ok(Plan { revision, steps })
+    }
+}
+
+/// A step that ran, in the run metadata.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExecutedStep {
+    /// The plan revision the step came from.
+    pub revision: u32,
+    pub step: PlanStep,
+    /// `None` when the step failed.
+    pub output: Option<String>,
+    pub record: StepRecord,
+}
+
+/// The run metadata of a plan-and-execute run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlanRun {
+    pub goal: String,
+    pub plans: Vec<Plan>,
+    pub steps: Vec<ExecutedStep>,
+    /// `Succeeded` once every step of the latest plan has run.
+    pub status: StepStatus,
+    /// The output of the last successful step.
+    pub output: Option<String>,
+}
+
+impl PlanRun {
+    pub fn current_plan(&self) -> Option<&Plan> {
+        self.plans.last()
+    }
+}
+
+/// Runs one plan step. `completed` holds the steps executed so far, successful or not.
+pub trait StepExecutor {
+    fn execute(&mut self, goal: &str, step: &PlanStep, completed: &[ExecutedStep]) -> Result<String, Failure>;
+}
+
+impl<F> StepExecutor for F
+where
+    F: FnMut(&str, &PlanStep, &[ExecutedStep]) -> Result<String, Failure>,
+{
+    fn execute(&mut self, goal: &str, step: &PlanStep, completed: &[ExecutedStep]) -> Result<String, Failure> {
+        self(goal, step, completed)
+    }
+}
+
+/// The prompt for a `respond` step: the goal, what earlier steps produced, and the step itself.
+pub fn step_prompt(goal: &str, step: &PlanStep, completed: &[ExecutedStep]) -> String {
+    let mut prompt = format!("Goal: {}\n", goal);
+    let done: Vec<&ExecutedStep> = completed.iter().filter(|executed| executed.output.is_some()).collect();
+    if !done.is_empty() {
+        prompt.push_str("\nCompleted steps:\n");
+        for executed in done {
+            prompt.push_str(&format!(
+                "- {}\n  Result: {}\n",
+                executed.step.description,
+                executed.output.as_deref().unwrap_or_default()
+            ));
+        }
+    }
+    prompt.push_str(&format!("\nCurrent step: {}", step.description));
+    prompt
+}
+
+type Responder<'a> = Box<dyn FnMut(&str) -> Result<String, String> + 'a>;
+
+/// Dispatches steps by kind: `respond` to the agent's model, `tool` to a registry, `agent` to a sub-agent.
+pub struct StepRouter<'a> {
+    respond: Responder<'a>,
+    agents: HashMap<String, Responder<'a>>,
+    #[cfg(feature = "tools")]
+    tools: Option<ToolRegistry>,
+}
+
+impl<'a> StepRouter<'a> {
+    /// `respond` sends a prompt to the agent's model and returns its reply.
+    pub fn new(respond: impl FnMut(&str) -> Result<String, String> + 'a) -> Self {
+        StepRouter {
+            respond: Box::new(respond),
+            agents: HashMap::new(),
+            #[cfg(feature = "tools")]
+            tools: None,
+        }
+    }
+
+    /// Register a sub-agent that `agent` steps can name.
+    pub fn agent(mut self, name: impl Into<String>, run: impl FnMut(&str) -> Result<String, String> + 'a) -> Self {
+        self.agents.insert(name.into(), Box::new(run));
+        self
+    }
+
+    #[cfg(feature = "tools")]
+    pub fn tools(mut self, registry: ToolRegistry) -> Self {
+        self.tools = Some(registry);
+        self
+    }
+}
+
+impl StepExecutor for StepRouter<'_> {
+    // The model and sub-agents fail with messages, which are classified; tool errors keep their type
+    fn execute(&mut self, goal: &str, step: &PlanStep, completed: &[ExecutedStep]) -> Result<String, Failure> {
+        match &step.action {
+            StepAction::Respond => (self.respond)(&step_prompt(goal, step, completed)).map_err(|e| Failure::of(&e)),
+            StepAction::Agent { agent, task } => {
+                let run = self.agents.get_mut(agent).ok_or_else(|| {
+                    Failure::new(FailureCode::ValidationError, format!("no sub-agent named '{}'", agent))
+                })?;
+                run(task.as_deref().unwrap_or(&step.description)).map_err(|e| Failure::of(&e))
+            }
+            #[cfg(feature = "tools")]
+            StepAction::Tool { tool, arguments } => {
+                let registry = self.tools.as_ref().ok_or_else(|| {
+                    Failure::new(FailureCode::ValidationError, format!("no tools available for '{}'", tool))
+                })?;
+                let arguments = if arguments.is_null() { JsonValue::Object(Default::default()) } else { arguments.clone() };
+                let output = registry.execute_tool_by_name(tool, &arguments).map_err(|e| Failure::of(&e))?;
+                Ok(output.as_str().map(str::to_string).unwrap_or_else(|| output.to_string()))
+            }
+            #[cfg(not(feature = "tools"))]
+            StepAction::Tool { tool, .. } => {
+                Err(Failure::new(FailureCode::ValidationError, format!("tool '{}' needs the `tools` feature", tool)))
+            }
+        }
+    }
+}
+
+// `guard_step` for a step that fails with a `Failure`, which is recorded with its code as it is
+fn guard_failing_step<R>(agent_name: &str, step: &str, f: impl FnOnce() -> Result<R, Failure>) -> (Option<R>, StepRecord) {
+    let (outcome, mut record) = guard_step(agent_name, step, f);
+    match outcome {
+        Some(Ok(value)) => (Some(value), record),
+        Some(Err(failure)) => {
+            record.status = failure.into();
+            (None, record)
+        }
+        None => (None, record),
+    }
+}
+
+fn planning_prompt(config: &PlanningConfig, goal: &str) -> String {
+    format!("{}\n\nTask: {}\n\n{}", config.prompt, goal, PLAN_FORMAT)
+}
+
+fn replanning_prompt(config: &PlanningConfig, goal: &str, completed: &[ExecutedStep], failed: &str) -> String {
+    let mut history = String::new();
+    for executed in completed {
+        match &executed.output {
+            Some(output) => history.push_str(&format!("- done: {}\n  Result: {}\n", executed.step.description, output)),
+            None => history.push_str(&format!("- failed: {}\n", executed.step.description)),
+        }
+    }
+    format!(
+        "{}\n\nTask: {}\n\nSteps so far:\n{}\nThe last attempt failed: {}\n\nPlan only the remaining work; \
+         completed steps will not be repeated.\n\n{}",
+        config.prompt, goal, history, failed, PLAN_FORMAT
+    )
+}
+
+fn failure_message(status: &StepStatus) -> String {
+    match status {
+        StepStatus::Failed { error, .. } => error.clone(),
+        StepStatus::Panicked { message } => format!("panicked: {}", message),
+        StepStatus::Succeeded => String::new(),
+    }
+}
+
+/// Plan `goal` with `planner`, then execute the plan with `executor`, re-planning on failure.
+pub fn plan_and_execute(
+    agent_name: &str,
+    goal: &str,
+    config: &PlanningConfig,
+    mut planner: impl FnMut(&str) -> Result<String, String>,
+    executor: &mut impl StepExecutor,
+) -> PlanRun {
+    let mut run =
+        PlanRun { goal: goal.to_string(), plans: Vec::new(), steps: Vec::new(), status: StepStatus::Succeeded, output: None };
+    let mut failure: Option<String> = None;
+    // The code and retriable flag of the last failure, reported if the run gives up
+    let mut last_failure = (FailureCode::AgentError, false);
+    let mut replans = 0;
+    loop {
+        let prompt = match &failure {
+            None => planning_prompt(config, goal),
+            Some(error) => replanning_prompt(config, goal, &run.steps, error),
+        };
+        let revision = run.plans.len() as u32;
+        let (plan, record) = guard_failing_step(agent_name, "plan", || {
+            let reply = planner(&prompt).map_err(|e| Failure::of(&e))?;
+            Plan::parse(&reply, revision).map_err(|e| Failure::of(&e))
+        });
+
+        if let Some(plan) = plan {
+            info!("{} planned {} step(s) (revision {})", agent_name, plan.steps.len(), revision);
+            run.plans.push(plan.clone());
+            failure = None;
+            for step in plan.steps {
+                if run.steps.len() >= config.max_steps {
+                    let error = format!("step limit of {} reached", config.max_steps);
+                    run.status = StepStatus::failed(FailureCode::BudgetExceeded, error);
+                    return run;
+                }
+                let (output, record) =
+                    guard_failing_step(agent_name, &step.label(), || executor.execute(goal, &step, &run.steps));
+                let error = (!record.status.is_success()).then(|| failure_message(&record.status));
+                if let Some(code) = record.status.code() {
+                    last_failure = (code, record.status.is_retriable());
+                }
+                run.steps.push(ExecutedStep { revision, step: step.clone(), output: output.clone(), record });
+                if let Some(error) = error {
+                    warn!("{} step {} ('{}') failed: {}", agent_name, step.id, step.description, error);
+                    failure = Some(format!("step '{}': {}", step.description, error));
+                    break;
+                }
+                run.output = output;
+            }
+            if failure.is_none() {
+                return run;
+            }
+        } else {
+            warn!("{} could not plan: {}", agent_name, failure_message(&record.status));
+            last_failure = (record.status.code().unwrap_or_default(), record.status.is_retriable());
+            failure = Some(format!("planning: {}", failure_message(&record.status)));
+        }
+
+        if replans >= config.max_replans {
+            let (code, retriable) = last_failure;
+            run.status = StepStatus::Failed {
+                error: format!("gave up after {} re-plan(s); {}", replans, failure.unwrap_or_default()),
+                code,
+                retriable,
+            };
+            return run;
+        }
+        replans += 1;
+    }
+}
+
+fn main() {
+    let mut replies = vec![
+        r#"{"steps": [{"description": "Look up Q3 revenue", "action": "agent", "agent": "Researcher"},
+                      {"description": "Summarize the trend", "action": "respond"}]}"#,
+    ]
+    .into_iter();
+    let mut router = StepRouter::new(|prompt| Ok(format!("Summary based on:\n{}", prompt)))
+        .agent("Researcher", |task| Ok(format!("Q3 revenue was $4.2M ({})", task)));
+    let run = plan_and_execute(
+        "Financial-Analyst",
+        "Report on Q3 revenue",
+        &PlanningConfig::default(),
+        |_prompt| replies.next().map(str::to_string).ok_or_else(|| "no reply".to_string()),
+        &mut router,
+    );
+    println!("{}", serde_json::to_string_pretty(&run).unwrap());
+}
+```
+
+### Notes
+* Re-planning replaces the rest of the plan; steps that already succeeded are kept in `steps` and their outputs are passed to the new plan's `respond` prompts.
+* Both the planner and the executor run on the calling thread. Steps within one plan are sequential by design, since each step may use the previous one's output.
+* A sub-agent or tool named in a plan but not registered fails that step, which triggers a re-plan; the error names the missing agent, so the model can route around it.
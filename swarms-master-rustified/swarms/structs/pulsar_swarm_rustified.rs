@@ -5,7 +5,7 @@ The conversion is viable with some adjustments.
 Here's why:
 1. **Async Programming**: Rust's async programming model is different from Python's. Rust uses the Tokio or async-std libraries for async operations, whereas Python uses asyncio. This requires rewriting the async functions.
 2. **Pulsar Client**: The pulsar client library is available for Rust, which simplifies the conversion process.
-3. **Agent Class**: The Agent class is not a built-in Python class and seems to be a custom implementation. We'll need to rewrite this class in Rust or find an equivalent library.
+3. **Agent Class**: The swarms `Agent` maps to the crate's `Agent` trait (`swarms::structs::agent`); the swarm holds its agents as `Arc<dyn Agent>`.
 
 ### Limitations and Challenges:
 1. **Error Handling**: Rust's error handling mechanism is more verbose than Python's. We'll need to handle errors explicitly using Result or Option types.
@@ -28,28 +28,18 @@ use serde_json::json;
 use std::collections::VecDeque;
 use std::fs::File;
 use std::io::Write;
-
-// Define a custom Agent struct
-struct Agent {
-    name: String,
-    description: String,
-    model_name: String,
-}
-
-impl Agent {
-    fn new(name: String, description: String, model_name: String) -> Self {
-        Agent {
-            name,
-            description,
-            model_name,
-        }
-    }
-
-    async fn process_task(&self, message: String) -> Result<serde_json::Value, String> {
-        // Simulate task processing
-        info!("Agent {} processing task: {}", self.name, message);
-        Ok(json!({ "agent_name": self.name, "response": "Task processed" }))
-    }
+use std::sync::Arc;
+use crate::swarms::structs::agent::{Agent, LlmAgent};
+
+// Run `message` on `agent` off the async runtime; `Agent::run` is synchronous
+async fn process_task(agent: Arc<dyn Agent>, message: String) -> Result<serde_json::Value, String> {
+    info!("Agent {} processing task: {}", agent.name(), message);
+    let name = agent.name().to_string();
+    let response = task::spawn_blocking(move || agent.run(&message))
+        .await
+        .map_err(|e| format!("agent {} panicked: {}", name, e))?
+        .map_err(|e| e.to_string())?;
+    Ok(json!({ "agent_name": name, "response": response }))
 }
 
 // Define the ScalableAsyncAgentSwarm struct
@@ -57,7 +47,7 @@ struct ScalableAsyncAgentSwarm {
     pulsar_url: String,
     topic: String,
     dlq_topic: String,
-    agents_config: Vec<Agent>,
+    agents_config: Vec<Arc<dyn Agent>>,
     client: Client,
     consumer: Consumer,
     dlq_producer: Producer,
@@ -70,7 +60,7 @@ impl ScalableAsyncAgentSwarm {
         pulsar_url: String,
         topic: String,
         dlq_topic: String,
-        agents_config: Vec<Agent>,
+        agents_config: Vec<Arc<dyn Agent>>,
     ) -> Result<Self, String> {
         let client = Client::new(pulsar_url.clone()).await?;
         let consumer = client
@@ -97,10 +87,10 @@ impl ScalableAsyncAgentSwarm {
     }
 
     async fn distribute_task(&mut self, message: String) -> Result<(), String> {
-        let agent = &self.agents_config[self.agent_index];
+        let agent = Arc::clone(&self.agents_config[self.agent_index]);
         self.agent_index = (self.agent_index + 1) % self.agents_config.len();
 
-        match agent.process_task(message).await {
+        match process_task(agent, message.clone()).await {
             Ok(response) => {
                 self.log_response(response.clone());
                 Ok(())
@@ -117,7 +107,7 @@ impl ScalableAsyncAgentSwarm {
         loop {
             info!("Performing health check for all agents.");
             for agent in &self.agents_config {
-                info!("Agent {} is online.", agent.name);
+                info!("Agent {} is online.", agent.name());
             }
             tokio::time::sleep(std::time::Duration::from_secs(10)).await;
         }
@@ -181,23 +171,17 @@ async fn main() -> Result<(), String> {
     let topic = "stock-analysis".to_string();
     let dlq_topic = "stock-analysis-dlq".to_string();
 
-    let agents_config = vec![
-        Agent::new(
-            "Stock-Analysis-Agent-1".to_string(),
-            "Analyzes stock trends.".to_string(),
-            "gpt-4o-mini".to_string(),
-        ),
-        Agent::new(
-            "Stock-News-Agent".to_string(),
-            "Summarizes stock news.".to_string(),
-            "gpt-4o-mini".to_string(),
-        ),
-        Agent::new(
-            "Tech-Trends-Agent".to_string(),
-            "Tracks tech sector trends.".to_string(),
-            "gpt-4o-mini".to_string(),
-        ),
-    ];
+    // Bind a `Completion` to each agent for `run` to reach a model
+    let agents_config: Vec<Arc<dyn Agent>> = [
+        ("Stock-Analysis-Agent-1", "Analyzes stock trends."),
+        ("Stock-News-Agent", "Summarizes stock news."),
+        ("Tech-Trends-Agent", "Tracks tech sector trends."),
+    ]
+    .iter()
+    .map(|(name, description)| {
+        LlmAgent::builder().name(*name).llm("gpt-4o-mini").description(*description).build().expect("valid agent").into()
+    })
+    .collect();
 
     let mut swarm = ScalableAsyncAgentSwarm::new(pulsar_url, topic, dlq_topic, agents_config)
         .await?;
@@ -227,4 +211,4 @@ async fn main() -> Result<(), String> {
     Ok(())
 }
 ```
-This code provides a basic structure for the ScalableAsyncAgentSwarm in Rust. However, please note that it may require further adjustments and error handling to work correctly in your specific environment. Additionally, some functionalities, such as the Agent class, have been simplified for demonstration purposes.
\ No newline at end of file
+This code provides a basic structure for the ScalableAsyncAgentSwarm in Rust. However, please note that it may require further adjustments and error handling to work correctly in your specific environment. Agents are the crate's `Arc<dyn Agent>`; a task an agent fails on goes to the dead letter queue.
\ No newline at end of file
@@ -0,0 +1,468 @@
+### Overview
+Reads state saved by the Python package, so teams moving to the Rust port keep their agents and
+conversations. There is no Python counterpart. The Rust save formats (`save_state`, `Conversation::save`) have
+different field names and a different shape, so `load_state` and `load` reject Python files.
+
+`import_agent_state` takes what Python's `Agent.save` writes: the agent's `__dict__`, with callables written
+as `{name, doc}` and unserializable values as `"<Non-serializable: T>"` strings. It returns an `LlmAgent`
+and, if the file has a `short_memory`, its conversation. The field names map as follows:
+
+* `agent_name` → `name`
+* `agent_description` (or `description`) → `description`
+* `model_name`, or `llm` when it is a name or an object with a `model_name` → `llm`
+* `context_length` and `max_tokens` → `budget`
+* `planning`, `tree_of_thoughts`, and `algorithm_of_thoughts` → the matching configs, with the same
+  rules as their `from_schema`
+* `tags` → `capabilities`
+* `system_prompt`, `max_loops`, `autosave`, `verbose`, `dynamic_temperature_enabled`, `saved_state_path`,
+  `user_name`, `retry_attempts`, and `output_type` keep their names.
+
+`import_conversation` takes what Python's `Conversation.save_as_json` writes: a list of `{role, content,
+timestamp}` objects. It also accepts that list wrapped as `{"conversation_history": [...]}`, and
+`"Role: content"` strings from `return_history_as_string` dumps.
+
+A field that has no Rust counterpart does not fail the import. It is reported as an `ImportWarning` and
+logged with `log::warn!`. A field holding `null`, `false`, zero, an empty value, or the Python default is
+left out, since dropping it changes nothing. So is a field the Python process sets for itself (ids,
+timestamps, worker counts). What is reported is therefore a setting someone chose and the import lost.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::{AgentConfigError, LlmAgent};
+use crate::swarms::structs::conversation::{Channel, Conversation, ConversationConfigError, Message};
+use crate::swarms::structs::plan_execute::{PlanningConfig, DEFAULT_PLANNING_PROMPT};
+use crate::swarms::structs::self_consistency::SelfConsistencyConfig;
+use crate::swarms::structs::tree_of_thoughts::TreeOfThoughtsConfig;
+use crate::swarms::text::context_budget::{ContextBudget, DEFAULT_RESERVED_OUTPUT_TOKENS};
+use serde_json::{json, Map, Value as JsonValue};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Fields the Python agent sets for itself; they describe the process that saved the file, not the agent.
+const RUNTIME_FIELDS: &[&str] = &[
+    "id",
+    "agent_id",
+    "created_at",
+    "time_created",
+    "executor_workers",
+    "logger_handler",
+    "agent_output",
+    "step_pool",
+    "tool_system_prompt",
+    "workspace_dir",
+    "state_save_file_type",
+];
+
+/// Python `Agent` constructor defaults for fields the Rust agent does not have.
+fn python_default(field: &str) -> Option<JsonValue> {
+    Some(match field {
+        "loop_interval" => json!(0),
+        "retry_interval" => json!(1),
+        "temperature" => json!(0.1),
+        "custom_exit_command" => json!("exit"),
+        "function_calling_type" => json!("json"),
+        "function_calling_format_type" => json!("OpenAI"),
+        "metadata_output_type" => json!("json"),
+        "tool_choice" => json!("auto"),
+        "memory_chunk_size" => json!(2000),
+        "device" => json!("cpu"),
+        "all_cores" => json!(true),
+        "do_not_use_cluster_ops" => json!(true),
+        _ => return None,
+    })
+}
+
+/// A field of a Python save that the import left out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportWarning {
+    /// The Python field name; for a message, `short_memory[i].field` or `conversation_history[i].field`.
+    pub field: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ImportWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.reason)
+    }
+}
+
+/// An imported value and the warnings its import raised.
+#[derive(Debug)]
+pub struct Imported<T> {
+    pub value: T,
+    pub warnings: Vec<ImportWarning>,
+}
+
+/// What a Python `Agent.save` file holds.
+#[derive(Debug)]
+pub struct PythonAgentState {
+    pub agent: LlmAgent,
+    /// The saved `short_memory`, when there was one.
+    pub history: Option<Conversation>,
+}
+
+#[derive(Debug)]
+pub enum PythonImportError {
+    Io { path: PathBuf, source: io::Error },
+    Json(serde_json::Error),
+    /// The file parsed but is not the shape Python writes.
+    Shape(String),
+    /// The saved agent names no model; bind it with a model name instead.
+    MissingModel,
+    Agent(AgentConfigError),
+    Conversation(ConversationConfigError),
+}
+
+impl fmt::Display for PythonImportError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PythonImportError::Io { path, source } => write!(f, "failed to read {}: {}", path.display(), source),
+            PythonImportError::Json(e) => write!(f, "invalid Python state JSON: {}", e),
+            PythonImportError::Shape(message) => write!(f, "not a Python swarms save: {}", message),
+            PythonImportError::MissingModel => write!(f, "the saved agent has no model_name or llm name"),
+            PythonImportError::Agent(e) => write!(f, "imported agent is invalid: {}", e),
+            PythonImportError::Conversation(e) => write!(f, "imported conversation is invalid: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for PythonImportError {}
+
+impl From<serde_json::Error> for PythonImportError {
+    fn from(e: serde_json::Error) -> Self {
+        PythonImportError::Json(e)
+    }
+}
+
+impl From<AgentConfigError> for PythonImportError {
+    fn from(e: AgentConfigError) -> Self {
+        PythonImportError::Agent(e)
+    }
+}
+
+impl From<ConversationConfigError> for PythonImportError {
+    fn from(e: ConversationConfigError) -> Self {
+        PythonImportError::Conversation(e)
+    }
+}
+
+fn read_json(path: &Path) -> Result<JsonValue, PythonImportError> {
+    let text = fs::read_to_string(path).map_err(|source| PythonImportError::Io { path: path.to_path_buf(), source })?;
+    Ok(serde_json::from_str(&text)?)
+}
+
+// Whether dropping the value changes nothing
+fn is_empty(value: &JsonValue) -> bool {
+    match value {
+        JsonValue::Null | JsonValue::Bool(false) => true,
+        JsonValue::Number(number) => number.as_f64() == Some(0.0),
+        JsonValue::String(text) => text.is_empty(),
+        JsonValue::Array(items) => items.is_empty(),
+        JsonValue::Object(map) => map.is_empty(),
+        JsonValue::Bool(true) => false,
+    }
+}
+
+fn is_non_serializable(value: &JsonValue) -> bool {
+    value.as_str().is_some_and(|text| text.starts_with("<Non-serializable"))
+}
+
+// Collects warnings and logs each one as it is raised
+#[derive(Default)]
+struct Warnings(Vec<ImportWarning>);
+
+impl Warnings {
+    fn push(&mut self, field: impl Into<String>, reason: impl Into<String>) {
+        let warning = ImportWarning { field: field.into(), reason: reason.into() };
+        log::warn!("Python import: {}", warning);
+        self.0.push(warning);
+    }
+}
+
+// Takes fields out of the saved object as they are mapped, so what is left at the end is unmapped
+struct Fields {
+    map: Map<String, JsonValue>,
+}
+
+impl Fields {
+    fn take(&mut self, field: &str) -> Option<JsonValue> {
+        self.map.remove(field).filter(|value| !value.is_null())
+    }
+
+    fn string(&mut self, field: &str, warnings: &mut Warnings) -> Option<String> {
+        match self.take(field)? {
+            JsonValue::String(text) => Some(text),
+            other => {
+                warnings.push(field, format!("expected a string, found {}", other));
+                None
+            }
+        }
+    }
+
+    fn bool(&mut self, field: &str, warnings: &mut Warnings) -> Option<bool> {
+        match self.take(field)? {
+            JsonValue::Bool(flag) => Some(flag),
+            other => {
+                warnings.push(field, format!("expected true or false, found {}", other));
+                None
+            }
+        }
+    }
+
+    fn int(&mut self, field: &str, warnings: &mut Warnings) -> Option<i64> {
+        let value = self.take(field)?;
+        match value.as_i64() {
+            Some(number) => Some(number),
+            None => {
+                warnings.push(field, format!("expected a whole number, found {}", value));
+                None
+            }
+        }
+    }
+
+    // Python's `planning` and `planning_prompt` are strings or booleans, whichever the caller passed
+    fn truthy(&mut self, field: &str) -> bool {
+        self.take(field).is_some_and(|value| !is_empty(&value))
+    }
+}
+
+/// Import what Python's `Agent.save` wrote. See the module overview for the field mapping.
+pub fn import_agent_state(state: &JsonValue) -> Result<Imported<PythonAgentState>, PythonImportError> {
+    let map = state.as_object().ok_or_else(|| PythonImportError::Shape(format!("expected an object, found {}", state)))?;
+    let mut fields = Fields { map: map.clone() };
+    let mut warnings = Warnings::default();
+
+    let name = fields.string("agent_name", &mut warnings).unwrap_or_else(|| "swarm-worker-01".to_string());
+    let llm = match (fields.take("model_name"), fields.take("llm")) {
+        (Some(JsonValue::String(model)), _) => model,
+        (_, Some(JsonValue::String(model))) if !model.starts_with("<Non-serializable") => model,
+        (_, Some(JsonValue::Object(llm))) if llm.get("model_name").is_some_and(JsonValue::is_string) => {
+            llm["model_name"].as_str().unwrap_or_default().to_string()
+        }
+        _ => return Err(PythonImportError::MissingModel),
+    };
+    let mut builder = LlmAgent::builder().name(name).llm(llm);
+
+    let description = fields.string("agent_description", &mut warnings).or(fields.string("description", &mut warnings));
+    if let Some(description) = description {
+        builder = builder.description(description);
+    }
+    if let Some(system_prompt) = fields.string("system_prompt", &mut warnings) {
+        builder = builder.system_prompt(system_prompt);
+    }
+    if let Some(loops) = fields.take("max_loops") {
+        match loops.as_i64() {
+            Some(loops) => builder = builder.max_loops(loops as i32),
+            None => warnings.push("max_loops", format!("{} has no Rust equivalent; using 1", loops)),
+        }
+    }
+    if let Some(autosave) = fields.bool("autosave", &mut warnings) {
+        builder = builder.autosave(autosave);
+    }
+    if let Some(verbose) = fields.bool("verbose", &mut warnings) {
+        builder = builder.verbose(verbose);
+    }
+    if let Some(enabled) = fields.bool("dynamic_temperature_enabled", &mut warnings) {
+        builder = builder.dynamic_temperature_enabled(enabled);
+    }
+    if let Some(path) = fields.string("saved_state_path", &mut warnings) {
+        builder = builder.saved_state_path(path);
+    }
+    if let Some(user_name) = fields.string("user_name", &mut warnings) {
+        builder = builder.user_name(user_name);
+    }
+    if let Some(attempts) = fields.int("retry_attempts", &mut warnings) {
+        builder = builder.retry_attempts(attempts as i32);
+    }
+    if let Some(output_type) = fields.string("output_type", &mut warnings) {
+        builder = builder.output_type(output_type);
+    }
+
+    let context_length = fields.int("context_length", &mut warnings).map(|tokens| tokens.max(0) as usize);
+    let max_tokens = fields.int("max_tokens", &mut warnings).map(|tokens| tokens.max(0) as usize);
+    if let Some(context) = context_length {
+        let reserved = match max_tokens {
+            Some(reserved) if reserved < context => reserved,
+            Some(reserved) => {
+                warnings.push("max_tokens", format!("{} leaves no room for input in {} tokens; reserving half", reserved, context));
+                context / 2
+            }
+            None => DEFAULT_RESERVED_OUTPUT_TOKENS.min(context / 2),
+        };
+        builder = builder.budget(ContextBudget::new(context, reserved));
+    } else if let Some(reserved) = max_tokens {
+        builder = builder.budget(ContextBudget { reserved_output_tokens: reserved, ..ContextBudget::default() });
+    }
+
+    let custom_prompt = fields.string("custom_planning_prompt", &mut warnings);
+    let planning_prompt = match fields.take("planning_prompt") {
+        Some(JsonValue::String(prompt)) if !prompt.is_empty() => Some(prompt),
+        _ => None,
+    };
+    let planning_value = fields.take("planning");
+    let planning_text = planning_value.as_ref().and_then(JsonValue::as_str).filter(|text| !text.is_empty()).map(str::to_string);
+    if planning_value.as_ref().is_some_and(|value| !is_empty(value)) {
+        let prompt = custom_prompt.or(planning_prompt).or(planning_text).unwrap_or_else(|| DEFAULT_PLANNING_PROMPT.to_string());
+        builder = builder.planning(PlanningConfig { prompt, ..PlanningConfig::default() });
+    }
+    if fields.truthy("tree_of_thoughts") {
+        builder = builder.tree_of_thoughts(TreeOfThoughtsConfig::default());
+    }
+    if fields.truthy("algorithm_of_thoughts") {
+        builder = builder.algorithm_of_thoughts(SelfConsistencyConfig::default());
+    }
+    match fields.take("tags") {
+        Some(JsonValue::Array(tags)) => {
+            for tag in tags {
+                match tag {
+                    JsonValue::String(tag) => builder = builder.capability(tag),
+                    other => warnings.push("tags", format!("skipped non-string tag {}", other)),
+                }
+            }
+        }
+        Some(other) => warnings.push("tags", format!("expected a list, found {}", other)),
+        None => {}
+    }
+
+    let history = match fields.take("short_memory") {
+        Some(memory) if !is_empty(&memory) => Some(conversation_from("short_memory", &memory, &mut warnings)?),
+        _ => None,
+    };
+
+    for (field, value) in fields.map {
+        if RUNTIME_FIELDS.contains(&field.as_str()) || is_empty(&value) || python_default(&field).as_ref() == Some(&value) {
+            continue;
+        }
+        let reason = if is_non_serializable(&value) || value.get("doc").is_some() {
+            "Python object or callable; rebuild it in Rust and attach it to the agent".to_string()
+        } else {
+            format!("no Rust equivalent; dropped {}", value)
+        };
+        warnings.push(field, reason);
+    }
+
+    let agent = builder.build()?;
+    Ok(Imported { value: PythonAgentState { agent, history }, warnings: warnings.0 })
+}
+
+/// `import_agent_state` on a file.
+pub fn import_agent_state_file(path: impl AsRef<Path>) -> Result<Imported<PythonAgentState>, PythonImportError> {
+    import_agent_state(&read_json(path.as_ref())?)
+}
+
+/// Import what Python's `Conversation.save_as_json` wrote. See the module overview for the accepted shapes.
+pub fn import_conversation(history: &JsonValue) -> Result<Imported<Conversation>, PythonImportError> {
+    let mut warnings = Warnings::default();
+    let conversation = conversation_from("conversation_history", history, &mut warnings)?;
+    Ok(Imported { value: conversation, warnings: warnings.0 })
+}
+
+/// `import_conversation` on a file.
+pub fn import_conversation_file(path: impl AsRef<Path>) -> Result<Imported<Conversation>, PythonImportError> {
+    import_conversation(&read_json(path.as_ref())?)
+}
+
+// `field` names the list in warnings
+fn conversation_from(field: &str, history: &JsonValue, warnings: &mut Warnings) -> Result<Conversation, PythonImportError> {
+    let entries = match history {
+        JsonValue::Array(entries) => entries,
+        JsonValue::Object(map) => match map.get("conversation_history") {
+            Some(JsonValue::Array(entries)) => entries,
+            _ => return Err(PythonImportError::Shape("expected a conversation_history list".to_string())),
+        },
+        other => return Err(PythonImportError::Shape(format!("expected a list of messages, found {}", other))),
+    };
+    let mut messages = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        if let Some(message) = message_from(&format!("{}[{}]", field, index), entry, warnings) {
+            messages.push(message);
+        }
+    }
+    let mut conversation = Conversation::builder().build()?;
+    conversation.restore(messages);
+    Ok(conversation)
+}
+
+fn message_from(path: &str, entry: &JsonValue, warnings: &mut Warnings) -> Option<Message> {
+    let at = |field: &str| format!("{}.{}", path, field);
+    let mut fields = match entry {
+        JsonValue::Object(map) => map.clone(),
+        JsonValue::String(line) => {
+            let (role, content) = line.split_once(": ").unwrap_or(("", line));
+            return Some(Message::new(role.to_string(), content.to_string(), Channel::Public));
+        }
+        other => {
+            warnings.push(path, format!("skipped {}; not a message", other));
+            return None;
+        }
+    };
+    let role = match fields.remove("role") {
+        Some(JsonValue::String(role)) => role,
+        _ => {
+            warnings.push(at("role"), "missing; skipped the message");
+            return None;
+        }
+    };
+    // Python allows any JSON as content (tool results, multimodal parts); keep it as text
+    let content = match fields.remove("content") {
+        Some(JsonValue::String(content)) => content,
+        Some(JsonValue::Null) | None => String::new(),
+        Some(other) => {
+            warnings.push(at("content"), "not a string; kept as JSON text");
+            other.to_string()
+        }
+    };
+    let mut message = Message::new(role, content, Channel::Public);
+    message.timestamp = match fields.remove("timestamp") {
+        Some(JsonValue::String(timestamp)) => Some(timestamp),
+        Some(JsonValue::Number(seconds)) => Some(seconds.to_string()),
+        _ => None,
+    };
+    for (field, value) in fields {
+        if !is_empty(&value) {
+            warnings.push(at(&field), format!("no Rust equivalent; dropped {}", value));
+        }
+    }
+    Some(message)
+}
+
+fn main() -> Result<(), PythonImportError> {
+    let saved = json!({
+        "agent_name": "Financial-Analysis-Agent",
+        "agent_description": "Answers questions about quarterly filings.",
+        "system_prompt": "You are a financial analyst.",
+        "llm": "<Non-serializable: OpenAIChat>",
+        "model_name": "gpt-4o-mini",
+        "max_loops": 2,
+        "context_length": 16000,
+        "max_tokens": 2000,
+        "saved_state_path": "finance_agent",
+        "temperature": 0.5,
+        "stopping_func": { "name": "stop_when_done", "doc": null },
+        "agent_id": "f2c1",
+        "short_memory": [
+            { "role": "System", "content": "You are a financial analyst.", "timestamp": "2024-10-01 09:12:44" },
+            { "role": "Human:", "content": "Summarize Q3.", "timestamp": "2024-10-01 09:12:45" }
+        ]
+    });
+    let imported = import_agent_state(&saved)?;
+    let agent = &imported.value.agent;
+    println!("{} on {}, {} loop(s)", agent.name, agent.llm, agent.max_loops);
+    if let Some(history) = &imported.value.history {
+        println!("{} message(s) of history", history.messages_for_model().len());
+    }
+    for warning in &imported.warnings {
+        println!("not imported: {}", warning);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* Python's `save` writes to `saved_state_path` with `.json` appended. The path is imported as written, so the Rust agent's `save_state` writes to the path without the extension; set it again if that matters.
+* Callables (`stopping_func`, `tools`, `callbacks`) are saved by Python as their name and docstring only. They cannot be rebuilt from that and are always reported.
+* `temperature`, `top_p`, and the other sampling settings have no place on the Rust agent; they belong to the provider. Pass the reported values to the provider when you bind the completion.
+* `max_loops: "auto"` imports as 1 loop, with a warning.
+* Python message roles are kept as written (`"Human:"`, `"System"`), so role matching that expects the Rust spellings may need a `TemplateRole::of` pass.
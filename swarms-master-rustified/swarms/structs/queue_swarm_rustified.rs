@@ -4,15 +4,40 @@
 //            Most of the code can be converted to Rust, but some parts will require modifications to account for Rust's ownership system and borrowing rules.
 //            Additionally, Rust's standard library does not have a direct equivalent to Python's queue and threading modules, so alternatives will need to be used.
 
-use std::collections::VecDeque;
-use std::fs;
-use std::path::Path;
+#[cfg(feature = "server")]
+use crate::swarms::structs::distributed_queue::Coordinator;
+#[cfg(feature = "server")]
+use crate::swarms::structs::tenancy::TenantId;
+use crate::swarms::artifacts::spillover::Spillover;
+use crate::swarms::integrations::notifications::{Notifier, RunSummary};
+use crate::swarms::integrations::webhooks::{WebhookDispatcher, WebhookEvent, WebhookPayload};
+use crate::swarms::structs::agent::Agent;
+use crate::swarms::structs::base_structure::BaseStructure;
+use crate::swarms::structs::failure::FailureCode;
+use crate::swarms::structs::fair_scheduler::{Dispatch, FairScheduler, LaneStats, SchedulerError};
+use crate::swarms::structs::restart::{Escalation, RestartSupervisor};
+use crate::swarms::structs::run_diff::{TraceRecorder, TraceStep};
+use crate::swarms::structs::scoped_tasks::{scoped_map, TaskFailures};
+use crate::swarms::structs::step_guard::{guard_fallible_step, StepRecord, StepStatus};
+use crate::swarms::structs::supervisor::{StuckAction, Supervisor, TaskHeartbeat};
+use crate::swarms::utils::clock::Clock;
+use crate::swarms::utils::compression::StateCompressor;
+use crate::swarms::utils::encryption::StateCipher;
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use crate::swarms::integrations::task_stream::{worker_for, ResultSink, StreamError, StreamResult, StreamTask, TaskSource};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, RecvTimeoutError};
 use std::sync::{Arc, Mutex};
 use std::thread;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
-use serde_json;
-use log::{info, debug, error};
+use serde_json::json;
+use log::{info, debug, error, warn};
+use tokio::sync::mpsc;
+
+// How often a worker waiting on a supervised task checks whether the supervisor found it stuck
+const STUCK_POLL: Duration = Duration::from_millis(10);
 
 // Define the AgentOutput struct
 #[derive(Serialize, Deserialize)]
@@ -21,6 +46,9 @@ struct AgentOutput {
     task: String,
     result: String,
     timestamp: String,
+    // Whether the step succeeded; a panicking agent leaves an empty result and a `panicked` status
+    #[serde(flatten)]
+    status: StepStatus,
 }
 
 // Define the SwarmRunMetadata struct
@@ -32,31 +60,46 @@ struct SwarmRunMetadata {
     agents: Vec<String>,
     start_time: String,
     end_time: String,
+    // Totals for this run only; live counts are in `swarms_tasks_completed_total` and `swarms_tasks_failed_total`
     tasks_completed: i32,
     outputs: Vec<AgentOutput>,
+    // How each agent's lane was served: dispatches, longest wait, and tasks that starved
+    #[serde(default)]
+    lanes: Vec<LaneStats>,
 }
 
 // Define the TaskQueueSwarm struct
-struct TaskQueueSwarm {
-    agents: Vec<Agent>,
-    task_queue: Arc<Mutex<VecDeque<String>>>,
-    lock: Arc<Mutex<()>>,
+pub struct TaskQueueSwarm {
+    agents: Vec<Arc<dyn Agent>>,
+    // Per-agent lanes of queued tasks, served fairly under per-agent in-flight limits
+    scheduler: FairScheduler,
+    // Worker threads pulling from the scheduler; one per agent unless set
+    workers: usize,
     autosave_on: bool,
-    save_file_path: String,
-    workspace_dir: String,
     return_metadata_on: bool,
     max_loops: i32,
     metadata: SwarmRunMetadata,
+    // Saves the run metadata to `<workspace_dir>/<name>_metadata.json` and errors under `<workspace_dir>/errors`,
+    // and stamps the start and end times and each output
+    base: BaseStructure,
+    // Moves large results into artifacts so the metadata file stays small
+    spillover: Option<Arc<Spillover>>,
+    // Cancels or requeues tasks that run longer than expected
+    supervisor: Option<Arc<Supervisor>>,
+    // Saves a trace of each run, one step per task
+    traces: Option<TraceRecorder>,
+    // Told when a run completes or has failed tasks
+    notifier: Notifier,
+    // Receives the lifecycle events of every run
+    webhooks: Option<WebhookDispatcher>,
 }
 
 impl TaskQueueSwarm {
     // Constructor for TaskQueueSwarm
-    fn new(agents: Vec<Agent>, name: &str, description: &str, autosave_on: bool, save_file_path: &str, workspace_dir: &str, return_metadata_on: bool, max_loops: i32) -> Self {
-        let current_time = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
-        let run_id = format!("swarm_run_{}", current_time);
+    pub fn new(agents: Vec<Arc<dyn Agent>>, name: &str, description: &str, autosave_on: bool, workspace_dir: &str, return_metadata_on: bool, max_loops: i32) -> Self {
+        let base = BaseStructure::in_workspace(name, description, workspace_dir);
+        let current_time = base.clock().unix_secs();
+        let run_id = new_id(IdKind::Run);
         let start_time = format!("{}", current_time);
         let end_time = "".to_string();
         let tasks_completed = 0;
@@ -65,132 +108,453 @@ impl TaskQueueSwarm {
             run_id,
             name: name.to_string(),
             description: description.to_string(),
-            agents: agents.iter().map(|agent| agent.agent_name.clone()).collect(),
+            agents: agents.iter().map(|agent| agent.name().to_string()).collect(),
             start_time,
             end_time,
             tasks_completed,
             outputs,
+            lanes: Vec::new(),
         };
+        let scheduler = FairScheduler::new(name, &metadata.agents);
         TaskQueueSwarm {
+            workers: agents.len(),
             agents,
-            task_queue: Arc::new(Mutex::new(VecDeque::new())),
-            lock: Arc::new(Mutex::new(())),
+            scheduler,
             autosave_on,
-            save_file_path: save_file_path.to_string(),
-            workspace_dir: workspace_dir.to_string(),
             return_metadata_on,
             max_loops,
             metadata,
+            base,
+            spillover: None,
+            supervisor: None,
+            traces: None,
+            notifier: Notifier::new(),
+            webhooks: None,
         }
     }
 
-    // Method to add a task to the queue
-    fn add_task(&self, task: &str) {
-        self.task_queue.lock().unwrap().push_back(task.to_string());
+    // Store results above the spillover threshold as files, encrypted like the metadata, which keeps a reference and preview
+    pub fn with_spillover(mut self, spillover: Arc<Spillover>) -> Self {
+        self.spillover = Some(spillover.inheriting(None, self.base.cipher()));
+        self
+    }
+
+    // Read the run's timestamps from `clock`; the start time is taken again from it
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.metadata.start_time = clock.unix_secs().to_string();
+        self.base = self.base.with_clock(clock);
+        self
+    }
+
+    // Encrypt the saved run metadata, and spilled results, with `cipher`
+    pub fn with_encryption(mut self, cipher: Arc<StateCipher>) -> Self {
+        self.spillover = self.spillover.map(|spillover| spillover.inheriting(None, Some(&cipher)));
+        self.base = self.base.with_encryption(cipher);
+        self
+    }
+
+    // Gzip the saved run metadata when it is larger than the compressor's threshold
+    pub fn with_compression(mut self, compressor: StateCompressor) -> Self {
+        self.base = self.base.with_compression(compressor);
+        self
+    }
+
+    // Tell `notifier`'s sinks how each run ended; delivery happens on the current Tokio runtime
+    pub fn with_notifier(mut self, notifier: Notifier) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    // Emit each run's start, finished tasks, and end to the webhooks `dispatcher` holds for this swarm or the run
+    pub fn with_webhooks(mut self, dispatcher: WebhookDispatcher) -> Self {
+        self.webhooks = Some(dispatcher);
+        self
+    }
+
+    // Run tasks on `workers` threads instead of one per agent
+    pub fn with_workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    // Let each agent run up to `limit` tasks at once; the default is one
+    pub fn with_default_max_in_flight(mut self, limit: usize) -> Self {
+        self.scheduler = self.scheduler.with_default_max_in_flight(limit);
+        self
+    }
+
+    // Let `agent` run up to `limit` tasks at once, so a chatty agent cannot take every worker
+    pub fn with_max_in_flight(mut self, agent: &str, limit: usize) -> Self {
+        self.scheduler = self.scheduler.with_max_in_flight(agent, limit);
+        self
+    }
+
+    // Serve `agent`'s lane in proportion to `weight` while other lanes also have work
+    pub fn with_weight(mut self, agent: &str, weight: f64) -> Self {
+        self.scheduler = self.scheduler.with_weight(agent, weight);
+        self
+    }
+
+    // Count tasks that wait longer than `threshold` in `swarms_tasks_starved_total`
+    pub fn with_starvation_threshold(mut self, threshold: Duration) -> Self {
+        self.scheduler = self.scheduler.with_starvation_threshold(threshold);
+        self
+    }
+
+    // Cancel or requeue tasks that `supervisor` finds stuck; start `supervisor.watch` for the checks to happen
+    pub fn with_supervisor(mut self, supervisor: Arc<Supervisor>) -> Self {
+        self.supervisor = Some(supervisor);
+        self
+    }
+
+    // Save a trace of every run under its run id, for `swarms diff` and `swarms debug`
+    pub fn with_traces(mut self, traces: TraceRecorder) -> Self {
+        self.traces = Some(traces);
+        self
+    }
+
+    // Method to add a task any agent may run to the queue
+    pub fn add_task(&self, task: &str) {
+        let depth = self.scheduler.push(None, task).expect("the shared lane always exists");
+        SwarmMetrics::global().task_queued(&self.metadata.name, depth);
     }
 
-    // Method to process tasks from the queue using the provided agent
-    fn process_task(&self, agent: &Agent) {
+    // Method to add a task to the queue of the agent named `agent`
+    pub fn add_task_for(&self, agent: &str, task: &str) -> Result<(), SchedulerError> {
+        let depth = self.scheduler.push(Some(agent), task)?;
+        SwarmMetrics::global().task_queued(&self.metadata.name, depth);
+        Ok(())
+    }
+
+    fn agent(&self, name: &str) -> &Arc<dyn Agent> {
+        self.agents.iter().find(|agent| agent.name() == name).expect("the scheduler only dispatches to the swarm's agents")
+    }
+
+    // Method to process tasks from the scheduler on one worker until the queue is empty.
+    // Each task runs under `guard_fallible_step`, so an agent that errors or panics marks that task failed and moves on to the next.
+    fn process_task(&self, outputs: &Mutex<Vec<AgentOutput>>) {
+        while let Some(dispatch) = self.scheduler.next() {
+            SwarmMetrics::global().set_queue_depth(&self.metadata.name, self.scheduler.len());
+            let Dispatch { agent, task, .. } = &dispatch;
+            let agent = self.agent(agent);
+            info!("Agent {} is running task: {}", agent.name(), task);
+            let started = Instant::now();
+            let (result, step) = match &self.supervisor {
+                None => guard_fallible_step(agent.name(), task, || agent.run(task)),
+                Some(supervisor) => {
+                    // Agents cannot beat, so the supervisor's expected duration bounds the whole task
+                    let heartbeat = supervisor.begin(agent.name(), task, dispatch.attempt);
+                    match self.run_watched(agent, task, &heartbeat) {
+                        Ok(outcome) => outcome,
+                        Err(StuckAction::Requeue) => {
+                            self.scheduler.finish(&dispatch);
+                            self.scheduler.requeue(&dispatch);
+                            continue;
+                        }
+                        Err(StuckAction::Cancel) => {
+                            let status = StepStatus::failed(FailureCode::Cancelled, "cancelled: no heartbeat in time");
+                            let duration_ms = started.elapsed().as_millis() as u64;
+                            (None, StepRecord { agent_name: agent.name().to_string(), step: task.clone(), status, duration_ms })
+                        }
+                    }
+                }
+            };
+            self.scheduler.finish(&dispatch);
+            SwarmMetrics::global().task_finished(&self.metadata.name, agent.name(), &step.status, started.elapsed());
+            let result = match &self.spillover {
+                Some(spillover) => spillover.apply(agent.name(), result.unwrap_or_default()),
+                None => result.unwrap_or_default(),
+            };
+            info!("Agent {} completed task: {}", agent.name(), task);
+            debug!("Result: {}", result);
+            let timestamp = self.base.current_timestamp();
+            outputs.lock().unwrap().push(AgentOutput {
+                agent_name: agent.name().to_string(),
+                task: dispatch.task.clone(),
+                result,
+                timestamp,
+                status: step.status,
+            });
+        }
+    }
+
+    // Method to run `task` on a thread of its own while the worker watches `heartbeat`, so a task the supervisor
+    // finds stuck is given up at once instead of when the agent returns. A result that is in by then is kept, however
+    // long it took; otherwise the supervisor's action is returned, and the abandoned attempt's result is dropped
+    // when its thread finishes.
+    fn run_watched(
+        &self,
+        agent: &Arc<dyn Agent>,
+        task: &str,
+        heartbeat: &TaskHeartbeat,
+    ) -> Result<(Option<String>, StepRecord), StuckAction> {
+        let (tx, rx) = sync_channel(1);
+        let (runner, owned_task) = (Arc::clone(agent), task.to_string());
+        thread::spawn(move || {
+            let _ = tx.send(guard_fallible_step(runner.name(), &owned_task, || runner.run(&owned_task)));
+        });
         loop {
-            if let Some(task) = self.task_queue.lock().unwrap().pop_front() {
-                info!("Agent {} is running task: {}", agent.agent_name, task);
-                let result = agent.run(&task);
-                let mut lock = self.lock.lock().unwrap();
-                self.metadata.tasks_completed += 1;
-                let timestamp = format!("{}", SystemTime::now()
-                    .duration_since(UNIX_EPOCH)
-                    .unwrap()
-                    .as_secs());
-                let output = AgentOutput {
-                    agent_name: agent.agent_name.clone(),
-                    task,
-                    result,
-                    timestamp,
-                };
-                self.metadata.outputs.push(output);
-                info!("Agent {} completed task: {}", agent.agent_name, task);
-                debug!("Result: {}", result);
-            } else {
-                break;
+            match rx.recv_timeout(STUCK_POLL) {
+                Ok(outcome) => return Ok(outcome),
+                Err(RecvTimeoutError::Timeout) => {
+                    if let Some(action) = heartbeat.stuck() {
+                        return rx.try_recv().map_err(|_| action);
+                    }
+                }
+                // The step is guarded, so its thread only ends without sending if it was killed
+                Err(RecvTimeoutError::Disconnected) => {
+                    let status = StepStatus::Panicked { message: "the agent's thread ended without a result".to_string() };
+                    let (agent_name, step, duration_ms) = (agent.name().to_string(), task.to_string(), heartbeat.elapsed().as_millis() as u64);
+                    return Ok((None, StepRecord { agent_name, step, status, duration_ms }));
+                }
             }
         }
     }
 
-    // Method to run the swarm by having agents pick up tasks from the queue
-    fn run(&self) {
+    // Method to run the swarm by having workers pick up tasks from the scheduler.
+    // Workers borrow the agents on scoped threads; if any worker panics, the outputs of the others are
+    // still recorded and the panics are returned together.
+    pub fn run(&mut self) -> Result<(), TaskFailures> {
         info!("Starting swarm run: {}", self.metadata.run_id);
-        let mut handles = Vec::new();
-        for agent in &self.agents {
-            let task_queue = Arc::clone(&self.task_queue);
-            let lock = Arc::clone(&self.lock);
-            let metadata = &self.metadata;
-            let handle = thread::spawn(move || {
-                TaskQueueSwarm::process_task(&TaskQueueSwarm {
-                    agents: Vec::new(),
-                    task_queue,
-                    lock,
-                    autosave_on: false,
-                    save_file_path: "".to_string(),
-                    workspace_dir: "".to_string(),
-                    return_metadata_on: false,
-                    max_loops: 0,
-                    metadata: metadata.clone(),
-                }, agent);
-            });
-            handles.push(handle);
+        let started = Instant::now();
+        self.emit(WebhookEvent::RunStarted, json!({ "tasks": self.scheduler.len() }));
+        let outputs = Mutex::new(Vec::new());
+        let workers: Vec<usize> = (0..self.workers.max(1)).collect();
+        let outcome = scoped_map(&workers, |worker| format!("worker-{}", worker), |_| self.process_task(&outputs));
+        // A worker that panicked while holding the lock leaves it poisoned; the outputs are still intact.
+        let outputs = outputs.into_inner().unwrap_or_else(|poisoned| poisoned.into_inner());
+        let mut trace = self.traces.as_ref().map(|traces| traces.start(self.metadata.run_id.clone()));
+        let mut failed = Vec::new();
+        for output in outputs {
+            let failure = match &output.status {
+                StepStatus::Succeeded => None,
+                StepStatus::Failed { error, code, .. } => {
+                    Some(format!("{} failed task '{}' ({}): {}", output.agent_name, output.task, code, error))
+                }
+                StepStatus::Panicked { message } => {
+                    Some(format!("{} panicked on task '{}': {}", output.agent_name, output.task, message))
+                }
+            };
+            if let Some(trace) = &mut trace {
+                let text = match &output.status {
+                    StepStatus::Failed { error, .. } => error.as_str(),
+                    StepStatus::Panicked { message } => message.as_str(),
+                    StepStatus::Succeeded => output.result.as_str(),
+                };
+                trace.push(TraceStep::of(self.agent(&output.agent_name).as_ref(), &output.task, text));
+            }
+            match failure {
+                Some(message) => {
+                    self.record_error(&message);
+                    failed.push(message);
+                }
+                None => {
+                    self.metadata.tasks_completed += 1;
+                    let data = json!({ "agent": output.agent_name, "task": output.task, "output": output.result });
+                    self.emit(WebhookEvent::AgentStepCompleted, data);
+                }
+            }
+            self.metadata.outputs.push(output);
         }
-        for handle in handles {
-            handle.join().unwrap();
+        if let (Some(traces), Some(trace)) = (&self.traces, &trace) {
+            traces.finish(trace);
         }
-        let end_time = format!("{}", SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_secs());
+        if let Err(failures) = &outcome {
+            if let Err(err) = self.base.log_error(failures) {
+                warn!("Could not log the failures of run {}: {}", self.metadata.run_id, err);
+            }
+        }
+        self.metadata.lanes = self.scheduler.stats(Instant::now());
+        for lane in self.metadata.lanes.iter().filter(|lane| lane.starved > 0) {
+            warn!("{} task(s) in lane '{}' of run {} starved", lane.starved, lane.lane, self.metadata.run_id);
+        }
+        let end_time = self.base.current_timestamp();
         self.metadata.end_time = end_time;
         if self.autosave_on {
             self.save_json_to_file();
         }
+        if !self.notifier.is_empty() || self.webhooks.is_some() {
+            let summary = match (&outcome, failed.first()) {
+                (Err(failures), _) => Err(failures.to_string()),
+                (Ok(_), Some(first)) => Err(format!("{} task(s) failed; the first: {}", failed.len(), first)),
+                (Ok(_), None) => Ok(format!("{} task(s) completed", self.metadata.tasks_completed)),
+            };
+            let outcome = summary.as_deref().map_err(String::as_str);
+            let run = RunSummary::of_outcome(&self.metadata.name, &self.metadata.run_id, outcome, started.elapsed());
+            if let Some(webhooks) = &self.webhooks {
+                webhooks.spawn_emit(WebhookPayload::from_summary(&run));
+            }
+            self.notifier.notify_detached(run);
+        }
+        outcome.map(|_| ())
     }
 
-    // Method to save the metadata to a file
-    fn save_json_to_file(&self) {
-        let json_string = serde_json::to_string_pretty(&self.metadata).unwrap();
-        let file_path = Path::new(&self.workspace_dir).join(&self.save_file_path);
-        fs::create_dir_all(file_path.parent().unwrap()).unwrap();
-        fs::write(file_path, json_string).unwrap();
-        info!("Metadata saved to {}", file_path.display());
+    // Method to run the swarm against an external task source (Kafka, NATS) until it is exhausted.
+    // Each partition key is pinned to one agent so tasks in a partition complete in order; a task is
+    // acked only after its result has been published to `sink`, and nacked if it failed, panicked, or
+    // its result could not be published, so the broker redelivers it.
+    pub async fn run_stream(&self, mut source: Box<dyn TaskSource>, sink: Arc<dyn ResultSink>) -> Result<usize, StreamError> {
+        info!("Starting streaming swarm run: {}", self.metadata.run_id);
+        let (done_tx, mut done_rx) = mpsc::unbounded_channel::<Result<StreamTask, (StreamTask, String)>>();
+        let mut lanes = Vec::new();
+        let mut workers = Vec::new();
+        for agent in &self.agents {
+            // A small buffer bounds how far a worker can fall behind its partitions.
+            let (tx, mut rx) = mpsc::channel::<StreamTask>(16);
+            lanes.push(tx);
+            let agent = Arc::clone(agent);
+            let sink = Arc::clone(&sink);
+            let clock = Arc::clone(self.base.clock());
+            let done_tx = done_tx.clone();
+            workers.push(tokio::spawn(async move {
+                while let Some(task) = rx.recv().await {
+                    let runner = Arc::clone(&agent);
+                    let payload = task.payload.clone();
+                    let done = match tokio::task::spawn_blocking(move || runner.run(&payload)).await {
+                        Ok(Ok(result)) => {
+                            let output = StreamResult {
+                                task_id: task.id.clone(),
+                                partition_key: task.partition_key.clone(),
+                                agent_name: agent.name().to_string(),
+                                task: task.payload.clone(),
+                                result,
+                                timestamp: clock.unix_secs().to_string(),
+                            };
+                            match sink.publish(&output).await {
+                                Ok(()) => Ok(task),
+                                Err(err) => Err((task, err.to_string())),
+                            }
+                        }
+                        Ok(Err(err)) => Err((task, err.to_string())),
+                        Err(err) => Err((task, format!("agent {} panicked: {}", agent.name(), err))),
+                    };
+                    if done_tx.send(done).is_err() {
+                        break;
+                    }
+                }
+            }));
+        }
+        drop(done_tx);
+
+        let mut in_flight = 0usize;
+        let mut completed = 0usize;
+        let mut source_open = true;
+        while source_open || in_flight > 0 {
+            tokio::select! {
+                next = source.next(), if source_open => match next? {
+                    Some(task) => {
+                        let lane = worker_for(&task.partition_key, lanes.len());
+                        in_flight += 1;
+                        if lanes[lane].send(task).await.is_err() {
+                            return Err(StreamError::Receive("worker stopped".to_string()));
+                        }
+                    }
+                    None => source_open = false,
+                },
+                done = done_rx.recv(), if in_flight > 0 => match done {
+                    Some(Ok(task)) => {
+                        in_flight -= 1;
+                        completed += 1;
+                        source.ack(&task).await?;
+                        debug!("Acked task {}", task.id);
+                    }
+                    Some(Err((task, err))) => {
+                        in_flight -= 1;
+                        warn!("Task {} was not processed: {}", task.id, err);
+                        source.nack(&task).await?;
+                        debug!("Nacked task {}", task.id);
+                    }
+                    None => break,
+                },
+            }
+        }
+        drop(lanes);
+        for worker in workers {
+            let _ = worker.await;
+        }
+        info!("Streaming run {} completed {} tasks", self.metadata.run_id, completed);
+        Ok(completed)
     }
-}
 
-// Define the Agent struct
-#[derive(Clone)]
-struct Agent {
-    agent_name: String,
-}
+    // Method to run `run_stream` as a daemon: when the stream fails, `restart` reconnects through `connect` and
+    // starts it again, until the source is exhausted or the failures escalate. Returns the tasks completed by
+    // runs that finished; a failed run's acked tasks are not counted, and its unacked ones are redelivered.
+    // This is the entry point for long-running consumers; `run_stream` alone ends the run at the first broker error.
+    pub async fn run_stream_supervised(
+        &self,
+        restart: &RestartSupervisor,
+        mut connect: impl FnMut() -> Box<dyn TaskSource>,
+        sink: Arc<dyn ResultSink>,
+    ) -> Result<usize, Escalation> {
+        let completed = AtomicUsize::new(0);
+        restart
+            .run(&self.metadata.name, || {
+                let (source, sink, completed) = (connect(), Arc::clone(&sink), &completed);
+                async move {
+                    let done = self.run_stream(source, sink).await.map_err(|err| err.to_string())?;
+                    completed.fetch_add(done, Ordering::SeqCst);
+                    Ok::<(), String>(())
+                }
+            })
+            .await?;
+        Ok(completed.into_inner())
+    }
 
-impl Agent {
-    // Method to run a task
-    fn run(&self, task: &str) -> String {
-        // This method should be implemented according to the actual task execution logic
-        // For demonstration purposes, it simply returns the task string
-        task.to_string()
+    // Method to hand the queued tasks to a coordinator so remote workers can run them.
+    // Tasks queued for one agent stay with that agent; shared tasks are queued for any of the tenant's agents.
+    // Give the coordinator the tenant's keys with `with_keys` before serving it; without them it refuses every worker.
+    #[cfg(feature = "server")]
+    pub fn into_coordinator(&self, tenant_id: &TenantId, lease: Duration) -> Coordinator {
+        let coordinator = Coordinator::new(lease);
+        let ledger = coordinator.ledger();
+        let mut ledger = ledger.lock().unwrap();
+        for (agent, task) in self.scheduler.drain() {
+            ledger.submit(tenant_id, agent.as_deref().unwrap_or(""), &task);
+        }
+        info!("Moved {} tasks from {} to the coordinator", ledger.pending(), self.metadata.run_id);
+        drop(ledger);
+        coordinator
+    }
+
+    // Method to emit one of the run's lifecycle events, if the swarm has webhooks
+    fn emit(&self, event: WebhookEvent, data: serde_json::Value) {
+        if let Some(webhooks) = &self.webhooks {
+            webhooks.spawn_emit(WebhookPayload::new(event, &self.metadata.name, &self.metadata.run_id, data));
+        }
+    }
+
+    // Method to append a failed task to the run's error log; a log that cannot be written is only warned about
+    fn record_error(&self, message: &str) {
+        if let Err(err) = self.base.record_error(&self.base.error_entry(message)) {
+            warn!("Could not log an error of run {}: {}", self.metadata.run_id, err);
+        }
+    }
+
+    // Method to save the metadata through the base structure, compressed and encrypted as configured; a failed save goes
+    // to the error log
+    fn save_json_to_file(&self) {
+        if let Err(err) = self.base.save_metadata(&self.metadata) {
+            error!("Failed to save metadata of run {}: {}", self.metadata.run_id, err);
+            if let Err(log_err) = self.base.log_error(&err) {
+                warn!("Could not log an error of run {}: {}", self.metadata.run_id, log_err);
+            }
+        }
     }
 }
 
 fn main() {
+    use crate::swarms::structs::agent::MockAgent;
+
     env_logger::init();
-    let agents = vec![
-        Agent {
-            agent_name: "Agent1".to_string(),
-        },
-        Agent {
-            agent_name: "Agent2".to_string(),
-        },
-    ];
-    let swarm = TaskQueueSwarm::new(agents, "Task-Queue-Swarm", "A swarm that processes tasks from a queue using multiple agents on different threads.", true, "swarm_run_metadata.json", "/path/to/workspace", false, 1);
+    // Mock agents echo each task back
+    let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("Agent1")), Arc::new(MockAgent::new("Agent2"))];
+    let mut swarm = TaskQueueSwarm::new(agents, "Task-Queue-Swarm", "A swarm that processes tasks from a queue using multiple agents on different threads.", true, "/path/to/workspace", false, 1);
     swarm.add_task("Task1");
     swarm.add_task("Task2");
-    swarm.run();
+    swarm.add_task_for("Agent2", "Task3").unwrap();
+    if let Err(failures) = swarm.run() {
+        error!("{}", failures);
+    }
 }
 ```
 
@@ -202,6 +566,12 @@ The following are some of the limitations and challenges encountered during the
 3.  **Logging:** Python's `loguru_logger` is not directly equivalent to Rust's `log` crate. Rust's `log` crate provides a more extensive logging system with different log levels and customizable logging behavior.
 4.  **Serialization and Deserialization:** Python's `pydantic` library is used for defining serializable data models, while Rust uses the `serde` crate for serialization and deserialization. The `Serialize` and `Deserialize` traits are implemented for the data models using the `#[derive(Serialize, Deserialize)]` macro.
 5.  **Error Handling:** Rust has a stronger focus on error handling compared to Python. In this conversion, error handling is implemented using Rust's `Result` type and `?` operator for propagating errors.
+6.  **Persistence:** The Python `save_file_path` is dropped. Run metadata goes to `<workspace_dir>/<name>_metadata.json` and errors to `<workspace_dir>/errors`, the same layout `SpreadSheetSwarm` uses, since both build their `BaseStructure` with `in_workspace`.
+7.  **Notifications:** With `with_notifier`, `run` reports a run with failed or panicked tasks as failed, and any other run as completed.
+8.  **Webhooks:** With `with_webhooks`, `run` emits `run_started`, one `agent_step_completed` per succeeded task, and the event for how the run ended.
+9.  **Streaming:** `run_stream` consumes a `TaskSource` until it is exhausted and fails on the first broker error. Daemons call `run_stream_supervised` instead, which reconnects through a `RestartSupervisor`; tasks that were delivered but not acked before the failure are redelivered by the broker on the new connection, so a result may be published twice but never lost.
+10. **Stuck tasks:** With `with_supervisor`, each task runs on a thread of its own while its worker watches the heartbeat. A task found stuck is requeued or cancelled as soon as the supervisor decides, and the worker moves on; a result that came in before that is kept however long the task ran. The abandoned attempt keeps its thread until the agent returns, and its result is dropped.
+11. **Run traces:** With `with_traces`, `run` saves a `RunTrace` under the run id, one step per finished task in the order the tasks finished. A failed or panicked task is recorded with its error as the output. Requeued attempts are not recorded.
 
 ### Recommendations for Improvement
 To improve the Rust code, consider the following recommendations:
@@ -209,5 +579,5 @@ To improve the Rust code, consider the following recommendations:
 1.  **Error Handling:** Implement more robust error handling using Rust's `Result` type and `?` operator. Consider using a custom error type to handle specific error cases.
 2.  **Locking Mechanism:** Instead of using a lock for synchronization, consider using a more efficient locking mechanism like `std::sync::RwLock` or a lock-free data structure.
 3.  **Task Queue Implementation:** Consider using a more efficient task queue implementation, such as a concurrent queue or a lock-free queue, to improve performance in multi-threaded environments.
-4.  **Agent Implementation:** Implement the `Agent` struct and its `run` method according to the actual task execution logic.
+4.  **Agent Implementation:** Agents are the crate's `Arc<dyn Agent>`; bind a `Completion` to each `LlmAgent` so `run` calls the model.
 5.  **Code Organization:** Organize the code into separate modules or crates to improve maintainability and reusability.
\ No newline at end of file
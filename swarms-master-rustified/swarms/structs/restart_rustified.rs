@@ -0,0 +1,268 @@
+### Overview
+There is no Python counterpart; a Python swarm deployed as a daemon dies with the first unhandled exception
+and waits for an operator. `RestartSupervisor` keeps a long-running child (a streaming `TaskQueueSwarm`, a
+worker loop, any closure that runs until it is told to stop) alive the way an OTP supervisor does:
+
+* A child that returns an error or panics is restarted after an exponential backoff (`initial_backoff`,
+  doubling up to `max_backoff`).
+* Restarts are limited to `max_restarts` within a sliding `window`. One more failure inside the window
+  escalates: the supervisor stops restarting and returns an `Escalation`, which the caller reports as a
+  failed run. Failures spread further apart than the window never escalate.
+* `Restart::Transient` children (the default) stop for good when they return `Ok`. `Restart::Permanent`
+  children are restarted even then, for loops that should never end.
+
+Each restart is logged and counted in `swarms_agent_restarts_total`, and each escalation in
+`swarms_restart_escalations_total`. `run_blocking` supervises a closure on the calling thread and `run` an
+async one; `TaskQueueSwarm::run_stream_supervised` uses `run` to reconnect its task source after a crash.
+
+### Rust Code
+```rust
+use crate::swarms::structs::failure::{Classify, FailureCode};
+use crate::swarms::structs::step_guard::{guard_step, guard_step_async, StepStatus};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::Future;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// When a child that exited is started again.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Restart {
+    /// Only after an error or a panic.
+    #[default]
+    Transient,
+    /// After every exit, including a clean one.
+    Permanent,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestartPolicy {
+    pub restart: Restart,
+    /// Restarts allowed within `window` before the supervisor escalates.
+    pub max_restarts: u32,
+    pub window: Duration,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        RestartPolicy {
+            restart: Restart::Transient,
+            max_restarts: 3,
+            window: Duration::from_secs(60),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl RestartPolicy {
+    /// The wait before the `restart`th restart (one-based) within the window.
+    pub fn backoff(&self, restart: u32) -> Duration {
+        self.initial_backoff.saturating_mul(2u32.saturating_pow(restart.saturating_sub(1))).min(self.max_backoff)
+    }
+}
+
+/// What to do about a child that just exited.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartDecision {
+    /// Start it again after `backoff`. `restart` counts the restarts within the window, this one included.
+    Restart { restart: u32, backoff: Duration },
+    /// Too many restarts within the window.
+    Escalate,
+}
+
+/// The restarts of one child within the policy's sliding window.
+#[derive(Debug, Clone)]
+pub struct RestartIntensity {
+    policy: RestartPolicy,
+    restarts: VecDeque<Instant>,
+}
+
+impl RestartIntensity {
+    pub fn new(policy: RestartPolicy) -> Self {
+        RestartIntensity { policy, restarts: VecDeque::new() }
+    }
+
+    /// Decide about an exit at `now`, counting it as a restart if one is allowed.
+    pub fn on_exit(&mut self, now: Instant) -> RestartDecision {
+        while self.restarts.front().is_some_and(|at| now.saturating_duration_since(*at) >= self.policy.window) {
+            self.restarts.pop_front();
+        }
+        if self.restarts.len() as u32 >= self.policy.max_restarts {
+            return RestartDecision::Escalate;
+        }
+        self.restarts.push_back(now);
+        let restart = self.restarts.len() as u32;
+        RestartDecision::Restart { restart, backoff: self.policy.backoff(restart) }
+    }
+}
+
+/// A child that failed more often than its restart policy allows.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Escalation {
+    pub supervisor: String,
+    pub child: String,
+    /// Restarts made before giving up, over the whole supervision.
+    pub restarts: u32,
+    /// How the last run ended.
+    pub last_exit: StepStatus,
+}
+
+impl fmt::Display for Escalation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reason = match &self.last_exit {
+            StepStatus::Failed { error, .. } => error.as_str(),
+            StepStatus::Panicked { message } => message.as_str(),
+            StepStatus::Succeeded => "exited",
+        };
+        write!(f, "{} gave up on {} after {} restart(s): {}", self.supervisor, self.child, self.restarts, reason)
+    }
+}
+
+impl std::error::Error for Escalation {}
+
+impl Classify for Escalation {
+    fn failure_code(&self) -> FailureCode {
+        self.last_exit.code().unwrap_or_default()
+    }
+
+    // The supervisor already retried; retrying the whole run would only repeat that.
+    fn retriable(&self) -> bool {
+        false
+    }
+}
+
+/// Restarts a crashed child with backoff, and escalates when it crashes too often.
+#[derive(Debug, Clone)]
+pub struct RestartSupervisor {
+    name: String,
+    policy: RestartPolicy,
+}
+
+impl RestartSupervisor {
+    pub fn new(name: &str) -> Self {
+        RestartSupervisor { name: name.to_string(), policy: RestartPolicy::default() }
+    }
+
+    pub fn with_policy(mut self, policy: RestartPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    pub fn policy(&self) -> &RestartPolicy {
+        &self.policy
+    }
+
+    /// Run `start` on this thread until it exits for good. Returns the number of restarts, or the
+    /// `Escalation` when the policy's limit was reached.
+    pub fn run_blocking<E: Classify>(&self, child: &str, mut start: impl FnMut() -> Result<(), E>) -> Result<u32, Escalation> {
+        let mut supervision = Supervision::new(self, child);
+        loop {
+            let (outcome, record) = guard_step(child, "supervised", &mut start);
+            match supervision.on_exit(exit_status(outcome, record.status))? {
+                Some(backoff) => thread::sleep(backoff),
+                None => return Ok(supervision.total),
+            }
+        }
+    }
+
+    /// `run_blocking` for async children. `start` builds a fresh future for every run.
+    pub async fn run<E, Fut>(&self, child: &str, mut start: impl FnMut() -> Fut) -> Result<u32, Escalation>
+    where
+        E: Classify,
+        Fut: Future<Output = Result<(), E>>,
+    {
+        let mut supervision = Supervision::new(self, child);
+        loop {
+            let (outcome, record) = guard_step_async(child, "supervised", start()).await;
+            match supervision.on_exit(exit_status(outcome, record.status))? {
+                Some(backoff) => tokio::time::sleep(backoff).await,
+                None => return Ok(supervision.total),
+            }
+        }
+    }
+}
+
+fn exit_status<E: Classify>(outcome: Option<Result<(), E>>, status: StepStatus) -> StepStatus {
+    match outcome {
+        Some(Err(err)) => StepStatus::failed_with(&err),
+        _ => status,
+    }
+}
+
+/// The state of one `run` or `run_blocking` call.
+struct Supervision<'a> {
+    supervisor: &'a RestartSupervisor,
+    child: &'a str,
+    intensity: RestartIntensity,
+    total: u32,
+}
+
+impl<'a> Supervision<'a> {
+    fn new(supervisor: &'a RestartSupervisor, child: &'a str) -> Self {
+        info!("{} is supervising {}", supervisor.name, child);
+        Supervision { supervisor, child, intensity: RestartIntensity::new(supervisor.policy), total: 0 }
+    }
+
+    /// The backoff before the next start, `None` if the child is done, or the escalation.
+    fn on_exit(&mut self, status: StepStatus) -> Result<Option<Duration>, Escalation> {
+        let name = &self.supervisor.name;
+        if status.is_success() && self.supervisor.policy.restart == Restart::Transient {
+            info!("{}: {} exited normally after {} restart(s)", name, self.child, self.total);
+            return Ok(None);
+        }
+        match self.intensity.on_exit(Instant::now()) {
+            RestartDecision::Restart { restart, backoff } => {
+                self.total += 1;
+                warn!("{}: restarting {} in {:?} (restart {} in this window): {:?}", name, self.child, backoff, restart, status);
+                SwarmMetrics::global().agent_restarted(name, self.child);
+                Ok(Some(backoff))
+            }
+            RestartDecision::Escalate => {
+                let escalation = Escalation {
+                    supervisor: name.clone(),
+                    child: self.child.to_string(),
+                    restarts: self.total,
+                    last_exit: status,
+                };
+                error!("{}", escalation);
+                SwarmMetrics::global().restart_escalated(name, self.child);
+                Err(escalation)
+            }
+        }
+    }
+}
+
+fn main() {
+    let supervisor = RestartSupervisor::new("daemon").with_policy(RestartPolicy {
+        initial_backoff: Duration::from_millis(10),
+        ..RestartPolicy::default()
+    });
+    let mut runs = 0;
+    let restarts = supervisor.run_blocking("ingest-loop", || {
+        runs += 1;
+        if runs < 3 {
+            return Err(format!("connection reset on run {}", runs));
+        }
+        Ok(())
+    });
+    println!("restarts: {:?}", restarts);
+
+    let escalation = supervisor.run_blocking("flaky", || -> Result<(), String> { panic!("bad state") }).unwrap_err();
+    println!("{}", escalation);
+}
+```
+
+### Notes
+* There is one child per call; supervise several children by running several calls (on their own threads or
+  tasks). A child's failure never restarts its siblings, as with OTP's `one_for_one` strategy.
+* State inside the child is lost on restart. The closure should rebuild what it needs (a broker connection,
+  a fresh swarm) each time it is called.
+* Backoff counts restarts within the window, so a child that has been stable for a full window starts again
+  from `initial_backoff`.
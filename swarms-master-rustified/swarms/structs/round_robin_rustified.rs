@@ -22,15 +22,15 @@ Below is a simplified version of how the `RoundRobinSwarm` class and some of its
 // Import necessary crates
 use std::collections::VecDeque;
 use std::error::Error;
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 use serde::{Serialize, Deserialize};
 use log::{info, warn, error};
 use rand::Rng;
-
-// Define a trait for Agent to provide a run method
-trait Agent {
-    fn run(&self, task: &str) -> Result<String, Box<dyn Error>>;
-}
+use crate::swarms::structs::agent::{Agent, AgentError};
+use crate::swarms::structs::callbacks::{RunCallbacks, StepComplete};
+use crate::swarms::structs::dry_run::PlannedCall;
+use crate::swarms::structs::swarm::{Swarm, SwarmError};
 
 // Define the MetadataSchema equivalent
 #[derive(Serialize, Deserialize, Debug)]
@@ -45,20 +45,21 @@ struct MetadataSchema {
 }
 
 // Define the RoundRobinSwarm struct
-struct RoundRobinSwarm {
+pub struct RoundRobinSwarm {
     name: String,
     description: String,
-    agents: Vec<Box<dyn Agent>>,
+    agents: Vec<Arc<dyn Agent>>,
     verbose: bool,
     max_loops: i32,
     index: usize,
     output_schema: MetadataSchema,
     max_retries: i32,
+    callbacks: Option<Arc<dyn RunCallbacks>>,
 }
 
 impl RoundRobinSwarm {
     // Constructor
-    fn new(name: String, description: String, agents: Vec<Box<dyn Agent>>, verbose: bool, max_loops: i32) -> Self {
+    pub fn new(name: String, description: String, agents: Vec<Arc<dyn Agent>>, verbose: bool, max_loops: i32) -> Self {
         let mut output_schema = MetadataSchema {
             swarm_id: String::new(),
             name: name.clone(),
@@ -84,11 +85,12 @@ impl RoundRobinSwarm {
             index: 0,
             output_schema,
             max_retries: 3,
+            callbacks: None,
         }
     }
 
     // Run the swarm
-    fn run(&mut self, task: &str) -> Result<String, Box<dyn Error>> {
+    pub fn run(&mut self, task: &str) -> Result<String, Box<dyn Error>> {
         if self.agents.is_empty() {
             error!("No agents configured for the swarm");
             return Err("No agents configured for the swarm".into());
@@ -96,10 +98,17 @@ impl RoundRobinSwarm {
 
         self.output_schema.task = task.to_string();
 
+        let mut turn = 0;
         for _ in 0..self.max_loops {
             for _ in 0..self.agents.len() {
                 let current_agent = &self.agents[self.index];
+                let started = Instant::now();
                 let result = self.execute_agent(current_agent, task)?;
+                if let Some(callbacks) = &self.callbacks {
+                    let step = StepComplete { index: turn, output: &result, elapsed: started.elapsed() };
+                    callbacks.on_step_complete(current_agent.name(), &step);
+                }
+                turn += 1;
                 self.index = (self.index + 1) % self.agents.len();
             }
         }
@@ -108,21 +117,21 @@ impl RoundRobinSwarm {
     }
 
     // Execute an agent with retries
-    fn execute_agent(&self, agent: &Box<dyn Agent>, task: &str) -> Result<String, Box<dyn Error>> {
+    fn execute_agent(&self, agent: &Arc<dyn Agent>, task: &str) -> Result<String, AgentError> {
         let mut retries = 0;
         loop {
             match agent.run(task) {
                 Ok(result) => {
-                    info!("Agent {} ran task {} successfully", agent, task);
+                    info!("Agent {} ran task {} successfully", agent.name(), task);
                     return Ok(result);
                 },
                 Err(e) => {
                     retries += 1;
                     if retries > self.max_retries {
-                        error!("Agent {} failed to run task {} after {} retries", agent, task, self.max_retries);
+                        error!("Agent {} failed to run task {} after {} retries", agent.name(), task, self.max_retries);
                         return Err(e);
                     } else {
-                        info!("Retrying agent {} on task {}", agent, task);
+                        info!("Retrying agent {} on task {}", agent.name(), task);
                     }
                 }
             }
@@ -130,12 +139,37 @@ impl RoundRobinSwarm {
     }
 }
 
+impl Swarm for RoundRobinSwarm {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        if self.agents.is_empty() {
+            return Err(SwarmError::NoAgents);
+        }
+        RoundRobinSwarm::run(self, task).map_err(|err| SwarmError::Failed(err.to_string()))
+    }
+
+    // Every turn reads only the task, starting from the agent whose turn is next
+    fn planned_calls(&self, _task: &str) -> Option<Vec<PlannedCall>> {
+        let turns = self.max_loops.max(0) as usize * self.agents.len();
+        let calls = (0..turns).map(|turn| &self.agents[(self.index + turn) % self.agents.len()]);
+        Some(calls.map(|agent| PlannedCall::new(agent.as_ref()).reading_task()).collect())
+    }
+
+    fn set_callbacks(&mut self, callbacks: Arc<dyn RunCallbacks>) -> Result<(), SwarmError> {
+        self.callbacks = Some(callbacks);
+        Ok(())
+    }
+}
+
 fn main() {
     // Initialize logging
     env_logger::init();
 
     // Example usage
-    let agents: Vec<Box<dyn Agent>> = vec![];
+    let agents: Vec<Arc<dyn Agent>> = vec![];
     let mut swarm = RoundRobinSwarm::new("RoundRobinSwarm".to_string(), "Round robin swarm example".to_string(), agents, true, 1);
     match swarm.run("example_task") {
         Ok(_) => info!("Swarm ran successfully"),
@@ -147,6 +181,6 @@ fn main() {
 ### Notes
 
 1. **Crate Dependencies**: This example uses several external crates (`log`, `rand`, `serde`). Make sure to add them to your `Cargo.toml` file.
-2. **Agent Trait**: Implement the `Agent` trait for each type of agent you wish to use in the swarm.
+2. **Agent Trait**: The swarm takes the crate's `Agent` trait (`swarms::structs::agent`) as `Arc<dyn Agent>`. `LlmAgent`, `MockAgent`, and `SwarmAgent` implement it; implement it for any other kind of agent you wish to use in the swarm.
 3. **Serialization and Deserialization**: Implement `Serialize` and `Deserialize` for any types that need to be serialized or deserialized (e.g., `MetadataSchema`).
 4. **Future Improvements**: Consider using async/await and `tokio` or another async runtime for handling the execution of agents concurrently.
\ No newline at end of file
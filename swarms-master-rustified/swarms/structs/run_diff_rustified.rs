@@ -0,0 +1,559 @@
+### Overview
+There is no Python counterpart. Tuning a workflow (rewording a system prompt, moving an agent to a cheaper
+model) means running it again and comparing the two runs, and by hand that is a diff of two long logs in
+which the steps no longer line up.
+
+A `RunTrace` is the record of one run: each agent step's model, prompt, output, token counts, and cost, in
+the order the steps ran, saved as JSONL with one step per line. `RunDiff::between` aligns two traces of the
+same swarm by agent and position: the first step of `Analyst` in one run is compared with the first step of
+`Analyst` in the other, whatever ran in between. For each pair it reports whether the model, prompt, or
+output changed, with a line diff of the text, and how tokens and cost moved. Steps only one run has are
+reported as added or removed.
+
+Swarms record traces with `with_traces(TraceRecorder::new(dir))`: `SequentialWorkflow`, `GraphWorkflow`,
+and `TaskQueueSwarm` push a step, with its `PromptContext`, for every agent turn and save the trace to
+`<dir>/<run_id>.jsonl` when the run ends.
+
+`swarms diff <run_a> <run_b> [--pricing <file>]` prints the comparison. Traces record cost when the run
+knew it; `--pricing` fills in the rest from a `Pricing` table.
+
+### Rust Code
+```rust
+use crate::swarms::eval::harness::line_diff;
+use crate::swarms::structs::agent::Agent;
+use crate::swarms::structs::conversation::Conversation;
+use crate::swarms::structs::step_guard::StepStatus;
+use crate::swarms::structs::usage::{Pricing, UsageRecord};
+use crate::swarms::structs::workflow_run::Run;
+use crate::swarms::text::chat_template::TemplateRole;
+use crate::swarms::text::prompt_privacy::PromptPrivacy;
+use crate::swarms::text::tokenizer::estimate_tokens;
+use crate::swarms::utils::safe_path::sanitize_file_name;
+use chrono::Utc;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum RunDiffError {
+    Io { path: PathBuf, source: io::Error },
+    /// A line of a trace that does not parse, 1-based.
+    Corrupt { path: PathBuf, line: usize, message: String },
+    Pricing(String),
+}
+
+impl fmt::Display for RunDiffError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RunDiffError::Io { path, source } => write!(f, "failed to read {}: {}", path.display(), source),
+            RunDiffError::Corrupt { path, line, message } => {
+                write!(f, "line {} of run trace {} does not parse: {}", line, path.display(), message)
+            }
+            RunDiffError::Pricing(message) => write!(f, "pricing table does not parse: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RunDiffError {}
+
+/// One earlier message sent along with a step's prompt.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceMessage {
+    pub role: String,
+    pub content: String,
+}
+
+/// Everything besides the task that went into a step's prompt, in the order it was sent.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct PromptContext {
+    pub system: String,
+    /// The tool schemas offered to the model, as sent.
+    pub tools: Vec<JsonValue>,
+    /// Passages retrieved from memory for this step.
+    pub memory: Vec<String>,
+    pub history: Vec<TraceMessage>,
+}
+
+impl PromptContext {
+    /// The system prompt and history `conversation` sends the model.
+    pub fn from_conversation(conversation: &Conversation) -> Self {
+        let mut context = PromptContext::default();
+        for message in conversation.messages_for_model() {
+            match TemplateRole::of(&message.role) {
+                TemplateRole::System if context.history.is_empty() => {
+                    if !context.system.is_empty() {
+                        context.system.push_str("\n\n");
+                    }
+                    context.system.push_str(&message.content);
+                }
+                _ => context.history.push(TraceMessage { role: message.role.clone(), content: message.content.clone() }),
+            }
+        }
+        context
+    }
+
+    pub fn tool(mut self, schema: JsonValue) -> Self {
+        self.tools.push(schema);
+        self
+    }
+
+    pub fn memory(mut self, passage: impl Into<String>) -> Self {
+        self.memory.push(passage.into());
+        self
+    }
+}
+
+/// One agent step of a run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TraceStep {
+    pub agent: String,
+    #[serde(default)]
+    pub model: String,
+    #[serde(default)]
+    pub prompt: String,
+    #[serde(default)]
+    pub output: String,
+    #[serde(default)]
+    pub prompt_tokens: u64,
+    #[serde(default)]
+    pub completion_tokens: u64,
+    /// USD, when the run knew the model's price.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cost_usd: Option<f64>,
+    /// What was sent ahead of `prompt`, for `Debugger`. Traces recorded without it diff the same way.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<PromptContext>,
+}
+
+impl TraceStep {
+    /// `agent`'s turn on `prompt`. The model and system prompt come from the agent's spec, when it has one;
+    /// token counts are estimated from the text, since the turn's provider usage is not visible here.
+    pub fn of(agent: &dyn Agent, prompt: &str, output: &str) -> Self {
+        let spec = agent.spec();
+        let system = spec.map_or_else(String::new, |spec| spec.system_prompt.clone());
+        TraceStep {
+            agent: agent.name().to_string(),
+            model: spec.map_or_else(String::new, |spec| spec.llm.clone()),
+            prompt: prompt.to_string(),
+            output: output.to_string(),
+            prompt_tokens: (estimate_tokens(&system) + estimate_tokens(prompt)) as u64,
+            completion_tokens: estimate_tokens(output) as u64,
+            cost_usd: None,
+            context: Some(PromptContext { system, ..PromptContext::default() }),
+        }
+    }
+
+    pub fn total_tokens(&self) -> u64 {
+        self.prompt_tokens + self.completion_tokens
+    }
+}
+
+/// The steps of one run, in the order they ran.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunTrace {
+    pub run_id: String,
+    pub steps: Vec<TraceStep>,
+    /// Applied to the text of each step as it is pushed.
+    pub privacy: PromptPrivacy,
+}
+
+impl RunTrace {
+    pub fn new(run_id: impl Into<String>) -> Self {
+        RunTrace { run_id: run_id.into(), ..Default::default() }
+    }
+
+    /// Keep only what `privacy` allows of each step's prompt, context, and output. Token counts and costs are kept.
+    pub fn with_privacy(mut self, privacy: PromptPrivacy) -> Self {
+        self.privacy = privacy;
+        self
+    }
+
+    pub fn push(&mut self, mut step: TraceStep) {
+        let privacy = self.privacy;
+        privacy.apply_in_place(&mut step.prompt);
+        privacy.apply_in_place(&mut step.output);
+        if let Some(context) = &mut step.context {
+            privacy.apply_in_place(&mut context.system);
+            context.memory.iter_mut().for_each(|passage| privacy.apply_in_place(passage));
+            context.history.iter_mut().for_each(|message| privacy.apply_in_place(&mut message.content));
+        }
+        self.steps.push(step);
+    }
+
+    /// Read a JSONL trace. The run id is the file name without its extension. Steps are read as they were
+    /// saved, so a trace saved under a privacy mode stays that way.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, RunDiffError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| RunDiffError::Io { path: path.to_path_buf(), source })?;
+        let mut trace = RunTrace::new(path.file_stem().map_or_else(String::new, |stem| stem.to_string_lossy().into_owned()));
+        for (index, line) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+            let step = serde_json::from_str(line).map_err(|err| RunDiffError::Corrupt {
+                path: path.to_path_buf(),
+                line: index + 1,
+                message: err.to_string(),
+            })?;
+            trace.push(step);
+        }
+        Ok(trace)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut text = String::new();
+        for step in &self.steps {
+            text.push_str(&serde_json::to_string(step)?);
+            text.push('\n');
+        }
+        fs::write(path, text)
+    }
+
+    /// Fill in the cost of steps that have none from `pricing`. Steps whose model has no price stay unpriced.
+    pub fn price(&mut self, pricing: &Pricing) {
+        for step in self.steps.iter_mut().filter(|step| step.cost_usd.is_none()) {
+            let record = UsageRecord {
+                timestamp: Utc::now(),
+                run_id: self.run_id.clone(),
+                agent: step.agent.clone(),
+                model: step.model.clone(),
+                tags: Vec::new(),
+                prompt_tokens: step.prompt_tokens,
+                completion_tokens: step.completion_tokens,
+                estimated: false,
+            };
+            step.cost_usd = pricing.cost(&record);
+        }
+    }
+
+    pub fn total_tokens(&self) -> u64 {
+        self.steps.iter().map(TraceStep::total_tokens).sum()
+    }
+
+    /// The summed cost of the priced steps, or `None` when no step is priced.
+    pub fn total_cost(&self) -> Option<f64> {
+        self.steps.iter().filter_map(|step| step.cost_usd).reduce(|total, cost| total + cost)
+    }
+}
+
+/// Saves a trace of each run a swarm makes to `<dir>/<run_id>.jsonl`.
+#[derive(Debug, Clone)]
+pub struct TraceRecorder {
+    dir: PathBuf,
+    privacy: PromptPrivacy,
+}
+
+impl TraceRecorder {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        TraceRecorder { dir: dir.into(), privacy: PromptPrivacy::default() }
+    }
+
+    /// Record each step under `privacy` (see `RunTrace::with_privacy`).
+    pub fn with_privacy(mut self, privacy: PromptPrivacy) -> Self {
+        self.privacy = privacy;
+        self
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// An empty trace for `run_id`, to push the run's steps to.
+    pub fn start(&self, run_id: impl Into<String>) -> RunTrace {
+        RunTrace::new(run_id).with_privacy(self.privacy)
+    }
+
+    /// The saved trace of `run_id` to push more steps to, e.g. when a run paused at an approval gate resumes,
+    /// or an empty one when there is none.
+    pub fn resume(&self, run_id: &str) -> RunTrace {
+        let path = self.dir.join(sanitize_file_name(&format!("{}.jsonl", run_id)));
+        match RunTrace::load(&path) {
+            Ok(trace) => RunTrace { run_id: run_id.to_string(), ..trace }.with_privacy(self.privacy),
+            Err(_) => self.start(run_id),
+        }
+    }
+
+    /// Save `trace` and return its path. A trace that cannot be saved is logged rather than returned as an
+    /// error, since the run it describes has already finished.
+    pub fn finish(&self, trace: &RunTrace) -> Option<PathBuf> {
+        let path = self.dir.join(sanitize_file_name(&format!("{}.jsonl", trace.run_id)));
+        match fs::create_dir_all(&self.dir).and_then(|_| trace.save(&path)) {
+            Ok(()) => {
+                info!("Saved the trace of run {} ({} steps) to {}", trace.run_id, trace.steps.len(), path.display());
+                Some(path)
+            }
+            Err(err) => {
+                warn!("Could not save the trace of run {} to {}: {}", trace.run_id, path.display(), err);
+                None
+            }
+        }
+    }
+
+    /// Save the steps of `run` that ran, in plan order, under its run id. `agent` finds each step's agent
+    /// for its model and system prompt; a failed step records its error as the output.
+    pub fn finish_run<'a>(&self, run: &Run, agent: impl Fn(&str) -> Option<&'a dyn Agent>) -> Option<PathBuf> {
+        let mut trace = self.start(run.run_id.clone());
+        for step in &run.steps {
+            let (Some(input), Some(status)) = (&step.input, &step.status) else { continue };
+            let output = match status {
+                StepStatus::Failed { error, .. } => error.as_str(),
+                _ => step.output.as_deref().unwrap_or_default(),
+            };
+            trace.push(match agent(&step.id) {
+                Some(agent) => TraceStep::of(agent, input, output),
+                None => TraceStep {
+                    agent: step.agent.clone(),
+                    model: String::new(),
+                    prompt: input.clone(),
+                    output: output.to_string(),
+                    prompt_tokens: estimate_tokens(input) as u64,
+                    completion_tokens: estimate_tokens(output) as u64,
+                    cost_usd: None,
+                    context: None,
+                },
+            });
+        }
+        self.finish(&trace)
+    }
+}
+
+/// How a pair of aligned steps compares.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepChange {
+    Unchanged,
+    Changed,
+    /// Only in the second run.
+    Added,
+    /// Only in the first run.
+    Removed,
+}
+
+/// The `position`-th step of `agent` in each run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StepDiff {
+    pub agent: String,
+    /// Zero-based among the agent's own steps.
+    pub position: usize,
+    pub a: Option<TraceStep>,
+    pub b: Option<TraceStep>,
+}
+
+impl StepDiff {
+    pub fn change(&self) -> StepChange {
+        match (&self.a, &self.b) {
+            (Some(a), Some(b)) if a.model == b.model && a.prompt == b.prompt && a.output == b.output => StepChange::Unchanged,
+            (Some(_), Some(_)) => StepChange::Changed,
+            (None, _) => StepChange::Added,
+            (_, None) => StepChange::Removed,
+        }
+    }
+
+    /// A line diff of the prompts, when both runs have the step and the prompts differ.
+    pub fn prompt_diff(&self) -> Option<String> {
+        let (a, b) = self.a.as_ref().zip(self.b.as_ref())?;
+        (a.prompt != b.prompt).then(|| line_diff(&a.prompt, &b.prompt))
+    }
+
+    pub fn output_diff(&self) -> Option<String> {
+        let (a, b) = self.a.as_ref().zip(self.b.as_ref())?;
+        (a.output != b.output).then(|| line_diff(&a.output, &b.output))
+    }
+
+    /// Total tokens in the second run minus the first; a missing step counts as zero.
+    pub fn token_delta(&self) -> i64 {
+        let tokens = |step: &Option<TraceStep>| step.as_ref().map_or(0, TraceStep::total_tokens) as i64;
+        tokens(&self.b) - tokens(&self.a)
+    }
+
+    /// The change in cost, when every step present is priced.
+    pub fn cost_delta(&self) -> Option<f64> {
+        let cost = |step: &Option<TraceStep>| match step {
+            Some(step) => step.cost_usd,
+            None => Some(0.0),
+        };
+        Some(cost(&self.b)? - cost(&self.a)?)
+    }
+}
+
+/// Two runs of the same swarm, step by step.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunDiff {
+    pub a: String,
+    pub b: String,
+    /// In the first run's order, followed by the steps only the second run has.
+    pub steps: Vec<StepDiff>,
+    pub tokens: (u64, u64),
+    pub cost: (Option<f64>, Option<f64>),
+}
+
+// Numbers each step by how many earlier steps its agent had.
+fn keyed(trace: &RunTrace) -> Vec<((String, usize), &TraceStep)> {
+    let mut seen: HashMap<&str, usize> = HashMap::new();
+    trace
+        .steps
+        .iter()
+        .map(|step| {
+            let position = seen.entry(step.agent.as_str()).or_default();
+            let key = (step.agent.clone(), *position);
+            *position += 1;
+            (key, step)
+        })
+        .collect()
+}
+
+fn signed(delta: i64) -> String {
+    if delta > 0 {
+        format!("+{}", delta)
+    } else {
+        delta.to_string()
+    }
+}
+
+fn dollars(cost: Option<f64>) -> String {
+    cost.map_or_else(|| "n/a".to_string(), |cost| format!("${:.4}", cost))
+}
+
+impl RunDiff {
+    pub fn between(a: &RunTrace, b: &RunTrace) -> Self {
+        let mut unmatched: HashMap<(String, usize), &TraceStep> = keyed(b).into_iter().collect();
+        let mut steps: Vec<StepDiff> = keyed(a)
+            .into_iter()
+            .map(|((agent, position), step)| {
+                let other = unmatched.remove(&(agent.clone(), position)).cloned();
+                StepDiff { agent, position, a: Some(step.clone()), b: other }
+            })
+            .collect();
+        for ((agent, position), step) in keyed(b) {
+            if unmatched.contains_key(&(agent.clone(), position)) {
+                steps.push(StepDiff { agent, position, a: None, b: Some(step.clone()) });
+            }
+        }
+        RunDiff {
+            a: a.run_id.clone(),
+            b: b.run_id.clone(),
+            steps,
+            tokens: (a.total_tokens(), b.total_tokens()),
+            cost: (a.total_cost(), b.total_cost()),
+        }
+    }
+
+    pub fn count(&self, change: StepChange) -> usize {
+        self.steps.iter().filter(|step| step.change() == change).count()
+    }
+
+    pub fn is_identical(&self) -> bool {
+        self.count(StepChange::Unchanged) == self.steps.len()
+    }
+
+    /// The comparison as text: a summary, then one entry per step with the prompt and output diffs of
+    /// changed steps.
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "Run {} -> {}: {} step(s), {} changed, {} added, {} removed",
+            self.a,
+            self.b,
+            self.steps.len(),
+            self.count(StepChange::Changed),
+            self.count(StepChange::Added),
+            self.count(StepChange::Removed)
+        );
+        let _ = writeln!(
+            out,
+            "Tokens: {} -> {} ({}), cost: {} -> {}",
+            self.tokens.0,
+            self.tokens.1,
+            signed(self.tokens.1 as i64 - self.tokens.0 as i64),
+            dollars(self.cost.0),
+            dollars(self.cost.1)
+        );
+        for step in &self.steps {
+            let label = format!("{} #{}", step.agent, step.position + 1);
+            match (step.change(), &step.a, &step.b) {
+                (StepChange::Unchanged, _, _) => {
+                    let _ = writeln!(out, "= {}", label);
+                }
+                (StepChange::Added, _, Some(b)) => {
+                    let _ = writeln!(out, "+ {} [{}], only in {}: {} token(s)", label, b.model, self.b, b.total_tokens());
+                }
+                (StepChange::Removed, Some(a), _) => {
+                    let _ = writeln!(out, "- {} [{}], only in {}: {} token(s)", label, a.model, self.a, a.total_tokens());
+                }
+                (_, Some(a), Some(b)) => {
+                    let model = if a.model == b.model { a.model.clone() } else { format!("{} -> {}", a.model, b.model) };
+                    let _ = writeln!(
+                        out,
+                        "~ {} [{}]: tokens {} -> {} ({}), cost {} -> {}",
+                        label,
+                        model,
+                        a.total_tokens(),
+                        b.total_tokens(),
+                        signed(step.token_delta()),
+                        dollars(a.cost_usd),
+                        dollars(b.cost_usd)
+                    );
+                    for (name, diff) in [("prompt", step.prompt_diff()), ("output", step.output_diff())] {
+                        if let Some(diff) = diff {
+                            let _ = writeln!(out, "  {}:", name);
+                            for line in diff.lines() {
+                                let _ = writeln!(out, "    {}", line);
+                            }
+                        }
+                    }
+                }
+                _ => unreachable!("added and removed steps have one side"),
+            }
+        }
+        out
+    }
+}
+
+/// The `swarms diff` subcommand. Returns the report to print.
+pub fn diff_command(a_path: &str, b_path: &str, pricing_path: Option<&str>) -> Result<String, RunDiffError> {
+    let mut a = RunTrace::load(a_path)?;
+    let mut b = RunTrace::load(b_path)?;
+    if let Some(path) = pricing_path {
+        let text = fs::read_to_string(path).map_err(|source| RunDiffError::Io { path: PathBuf::from(path), source })?;
+        let pricing: Pricing = serde_json::from_str(&text).map_err(|err| RunDiffError::Pricing(err.to_string()))?;
+        a.price(&pricing);
+        b.price(&pricing);
+    }
+    Ok(RunDiff::between(&a, &b).render())
+}
+
+fn main() {
+    let step = |agent: &str, model: &str, prompt: &str, output: &str, tokens: (u64, u64)| TraceStep {
+        agent: agent.to_string(),
+        model: model.to_string(),
+        prompt: prompt.to_string(),
+        output: output.to_string(),
+        prompt_tokens: tokens.0,
+        completion_tokens: tokens.1,
+        cost_usd: None,
+        context: None,
+    };
+    let mut before = RunTrace::new("run-41");
+    before.push(step("Analyst", "gpt-4o", "Summarize Q3.", "Revenue grew 12%.\nMargins held.", (420, 80)));
+    before.push(step("Reviewer", "gpt-4o", "Check the summary.", "Looks right.", (510, 12)));
+    let mut after = RunTrace::new("run-42");
+    after.push(step("Analyst", "gpt-4o-mini", "Summarize Q3 in one line.", "Revenue grew 12%.", (424, 31)));
+    after.push(step("Reviewer", "gpt-4o", "Check the summary.", "Looks right.", (470, 12)));
+
+    let pricing = Pricing::new().model("gpt-4o", 2.5, 10.0).model("gpt-4o-mini", 0.15, 0.6);
+    before.price(&pricing);
+    after.price(&pricing);
+    print!("{}", RunDiff::between(&before, &after).render());
+}
+```
+
+### Notes
+* Traces recorded with `with_privacy(PromptPrivacy::Hash)` still diff: equal hashes mean equal text, and a changed step shows the two hashes.
+* Only the step's `prompt` is diffed; a change confined to its `context` (a longer history, different memory passages) shows in the token counts but does not mark the step changed. Step through both traces with `swarms debug` to compare contexts.
+* Steps are matched by agent name and count, not by content, so a renamed agent shows up as one removed and one added agent.
+* A step counts as changed when its model, prompt, or output differs. Token or cost differences alone do not mark it changed; they are still shown in the totals.
+* Line diffs are computed with the evaluation harness's `line_diff`, which is quadratic in the number of lines. Traces of very long outputs diff slowly.
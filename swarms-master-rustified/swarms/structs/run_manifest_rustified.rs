@@ -0,0 +1,339 @@
+### Overview
+There is no Python counterpart; a Python run leaves behind its conversation and logs, and nothing shows
+later whether they were edited. Financial and fraud-analysis results are audited months after the run, so
+`RunManifest` records what a run was made of and `ManifestSigner` signs it:
+
+* the SHA-256 of the run configuration;
+* for each agent, its model and the SHA-256 of its system prompt and of its full configuration;
+* the SHA-256 and line count of the run's event log (JSONL, e.g. step records or the audit log).
+
+Signatures are Ed25519. The signing key is a 32-byte seed read from the secret `MANIFEST_KEY_<id>` of a
+`SecretStore`; the public key is published to whoever audits the runs. Verifying needs only the public key,
+so auditors cannot forge manifests.
+
+Hashes are taken over canonical JSON: values go through `serde_json::Value`, whose objects keep their keys
+sorted, so field order in the source struct does not change the hash. Event logs are hashed byte for byte.
+
+`swarms verify <manifest> --public-key <base64> [--events <file>] [--config <file>]` checks the signature
+and, when given, that the event log and configuration still match.
+
+### Rust Code
+```rust
+use crate::swarms::structs::agent::LlmAgent;
+use crate::swarms::structs::tenancy::TenantId;
+use crate::swarms::utils::secret_store::SecretStore;
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use chrono::Utc;
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Bumped when the manifest layout changes; old manifests keep verifying under their own version.
+pub const MANIFEST_FORMAT: u32 = 1;
+
+#[derive(Debug)]
+pub enum ManifestError {
+    /// No `MANIFEST_KEY_<id>` secret.
+    MissingKey(String),
+    InvalidKey(String),
+    /// The signature does not match the manifest under the given public key.
+    BadSignature,
+    /// A signed hash does not match the artifact it covers.
+    Mismatch { field: String, signed: String, actual: String },
+    Io(io::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ManifestError::MissingKey(id) => write!(f, "no secret MANIFEST_KEY_{} for signing key '{}'", id, id),
+            ManifestError::InvalidKey(message) => write!(f, "invalid manifest key: {}", message),
+            ManifestError::BadSignature => write!(f, "manifest signature is invalid"),
+            ManifestError::Mismatch { field, signed, actual } => {
+                write!(f, "{} does not match the manifest: signed {}, found {}", field, signed, actual)
+            }
+            ManifestError::Io(err) => write!(f, "manifest I/O failed: {}", err),
+            ManifestError::Json(err) => write!(f, "manifest is not valid JSON: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ManifestError {}
+
+impl From<io::Error> for ManifestError {
+    fn from(err: io::Error) -> Self {
+        ManifestError::Io(err)
+    }
+}
+
+impl From<serde_json::Error> for ManifestError {
+    fn from(err: serde_json::Error) -> Self {
+        ManifestError::Json(err)
+    }
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+/// SHA-256 of the canonical JSON form of `value`.
+pub fn canonical_hash<T: Serialize + ?Sized>(value: &T) -> Result<String, ManifestError> {
+    let value = serde_json::to_value(value)?;
+    Ok(sha256_hex(&serde_json::to_vec(&value)?))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgentDigest {
+    pub model: String,
+    pub prompt_sha256: String,
+    pub config_sha256: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct EventLogDigest {
+    pub sha256: String,
+    pub events: usize,
+}
+
+impl EventLogDigest {
+    /// Digest of a JSONL log as stored.
+    pub fn of_bytes(log: &[u8]) -> Self {
+        let events = log.split(|&b| b == b'\n').filter(|line| !line.is_empty()).count();
+        EventLogDigest { sha256: sha256_hex(log), events }
+    }
+
+    /// Digest of `events` written one JSON object per line, the way `AuditLog` and run metadata write them.
+    pub fn of_events<T: Serialize>(events: &[T]) -> Result<Self, ManifestError> {
+        let mut log = Vec::new();
+        for event in events {
+            serde_json::to_writer(&mut log, event)?;
+            log.push(b'\n');
+        }
+        Ok(Self::of_bytes(&log))
+    }
+}
+
+/// What a run was made of. Signed as a whole by `ManifestSigner::sign`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunManifest {
+    pub format: u32,
+    pub run_id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<TenantId>,
+    pub created_at: String,
+    pub config_sha256: String,
+    pub agents: BTreeMap<String, AgentDigest>,
+    pub event_log: EventLogDigest,
+}
+
+impl RunManifest {
+    pub fn new(run_id: impl Into<String>) -> Self {
+        RunManifest {
+            format: MANIFEST_FORMAT,
+            run_id: run_id.into(),
+            tenant_id: None,
+            created_at: Utc::now().to_rfc3339(),
+            config_sha256: String::new(),
+            agents: BTreeMap::new(),
+            event_log: EventLogDigest::default(),
+        }
+    }
+
+    pub fn tenant(mut self, tenant: TenantId) -> Self {
+        self.tenant_id = Some(tenant);
+        self
+    }
+
+    /// Hash the run configuration (workflow definition, flow string, parameters).
+    pub fn config<T: Serialize + ?Sized>(mut self, config: &T) -> Result<Self, ManifestError> {
+        self.config_sha256 = canonical_hash(config)?;
+        Ok(self)
+    }
+
+    pub fn agent(mut self, agent: &LlmAgent) -> Result<Self, ManifestError> {
+        let digest = AgentDigest {
+            model: agent.llm.clone(),
+            prompt_sha256: sha256_hex(agent.system_prompt.as_bytes()),
+            config_sha256: canonical_hash(agent)?,
+        };
+        self.agents.insert(agent.name.clone(), digest);
+        Ok(self)
+    }
+
+    pub fn event_log(mut self, digest: EventLogDigest) -> Self {
+        self.event_log = digest;
+        self
+    }
+
+    fn signing_bytes(&self) -> Result<Vec<u8>, ManifestError> {
+        Ok(serde_json::to_vec(&serde_json::to_value(self)?)?)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestSignature {
+    pub key_id: String,
+    /// The signer's public key, base64. Informational: verify against a key obtained out of band.
+    pub public_key: String,
+    pub signature: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedManifest {
+    pub manifest: RunManifest,
+    pub signature: ManifestSignature,
+}
+
+impl SignedManifest {
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), ManifestError> {
+        fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ManifestError> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Check the signature against a trusted public key (base64).
+    pub fn verify(&self, public_key: &str) -> Result<(), ManifestError> {
+        let key = decode_32(public_key)?;
+        let key = VerifyingKey::from_bytes(&key).map_err(|err| ManifestError::InvalidKey(err.to_string()))?;
+        let signature = BASE64.decode(&self.signature.signature).map_err(|_| ManifestError::BadSignature)?;
+        let signature = Signature::from_slice(&signature).map_err(|_| ManifestError::BadSignature)?;
+        key.verify(&self.manifest.signing_bytes()?, &signature).map_err(|_| ManifestError::BadSignature)
+    }
+
+    /// Check that an event log still matches the signed digest.
+    pub fn check_event_log(&self, log: &[u8]) -> Result<(), ManifestError> {
+        let actual = EventLogDigest::of_bytes(log);
+        mismatch("event_log.sha256", &self.manifest.event_log.sha256, &actual.sha256)
+    }
+
+    /// Check that a configuration still hashes to the signed value.
+    pub fn check_config<T: Serialize + ?Sized>(&self, config: &T) -> Result<(), ManifestError> {
+        mismatch("config_sha256", &self.manifest.config_sha256, &canonical_hash(config)?)
+    }
+}
+
+fn mismatch(field: &str, signed: &str, actual: &str) -> Result<(), ManifestError> {
+    if signed == actual {
+        Ok(())
+    } else {
+        Err(ManifestError::Mismatch { field: field.to_string(), signed: signed.to_string(), actual: actual.to_string() })
+    }
+}
+
+fn decode_32(encoded: &str) -> Result<[u8; 32], ManifestError> {
+    let bytes = BASE64.decode(encoded.trim()).map_err(|err| ManifestError::InvalidKey(err.to_string()))?;
+    bytes
+        .try_into()
+        .map_err(|bytes: Vec<u8>| ManifestError::InvalidKey(format!("expected 32 bytes, got {}", bytes.len())))
+}
+
+/// Signs manifests with an Ed25519 key from a `SecretStore`.
+pub struct ManifestSigner {
+    key_id: String,
+    key: SigningKey,
+}
+
+impl fmt::Debug for ManifestSigner {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ManifestSigner").field("key_id", &self.key_id).field("public_key", &self.public_key()).finish()
+    }
+}
+
+impl ManifestSigner {
+    /// Load the seed stored as `MANIFEST_KEY_<key_id>` (32 bytes, base64).
+    pub fn from_store(store: &dyn SecretStore, key_id: &str) -> Result<Self, ManifestError> {
+        let secret = store
+            .get(&format!("MANIFEST_KEY_{}", key_id))
+            .ok_or_else(|| ManifestError::MissingKey(key_id.to_string()))?;
+        Ok(ManifestSigner { key_id: key_id.to_string(), key: SigningKey::from_bytes(&decode_32(&secret)?) })
+    }
+
+    /// A fresh random seed, base64-encoded for storing as `MANIFEST_KEY_<id>`.
+    pub fn generate_key() -> String {
+        let mut seed = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut seed);
+        BASE64.encode(seed)
+    }
+
+    /// The public key to hand to auditors, base64.
+    pub fn public_key(&self) -> String {
+        BASE64.encode(self.key.verifying_key().to_bytes())
+    }
+
+    pub fn sign(&self, manifest: RunManifest) -> Result<SignedManifest, ManifestError> {
+        let signature = self.key.sign(&manifest.signing_bytes()?);
+        Ok(SignedManifest {
+            manifest,
+            signature: ManifestSignature {
+                key_id: self.key_id.clone(),
+                public_key: self.public_key(),
+                signature: BASE64.encode(signature.to_bytes()),
+            },
+        })
+    }
+}
+
+/// The `swarms verify` subcommand. Returns a line to print on success.
+pub fn verify_command(
+    manifest_path: &str,
+    public_key: &str,
+    events_path: Option<&str>,
+    config_path: Option<&str>,
+) -> Result<String, ManifestError> {
+    let signed = SignedManifest::load(manifest_path)?;
+    signed.verify(public_key)?;
+    if let Some(path) = events_path {
+        signed.check_event_log(&fs::read(path)?)?;
+    }
+    if let Some(path) = config_path {
+        let config: serde_json::Value = serde_json::from_str(&fs::read_to_string(path)?)?;
+        signed.check_config(&config)?;
+    }
+    Ok(format!(
+        "Run {} verified: signed by key '{}', {} agent(s), {} event(s)",
+        signed.manifest.run_id,
+        signed.signature.key_id,
+        signed.manifest.agents.len(),
+        signed.manifest.event_log.events
+    ))
+}
+
+fn main() -> Result<(), ManifestError> {
+    use crate::swarms::structs::step_guard::{StepRecord, StepStatus};
+    use crate::swarms::utils::secret_store::StaticSecretStore;
+    use serde_json::json;
+
+    let store = StaticSecretStore::new().with("MANIFEST_KEY_audit_2026", ManifestSigner::generate_key());
+    let signer = ManifestSigner::from_store(&store, "audit_2026")?;
+
+    let steps = vec![StepRecord {
+        agent_name: "Fraud-Analyst".to_string(),
+        step: "Review the flagged transactions".to_string(),
+        status: StepStatus::Succeeded,
+        duration_ms: 1840,
+    }];
+    let manifest = RunManifest::new("run-42")
+        .config(&json!({ "flow": "Fraud-Analyst -> Reviewer", "max_loops": 1 }))?
+        .event_log(EventLogDigest::of_events(&steps)?);
+    let signed = signer.sign(manifest)?;
+    signed.verify(&signer.public_key())?;
+    println!("{}", serde_json::to_string_pretty(&signed)?);
+    Ok(())
+}
+```
+
+### Notes
+* The manifest covers hashes, not contents: keep the event log and configuration it refers to, or there is nothing left to verify them against.
+* Rotating the signing key does not invalidate old manifests; keep publishing retired public keys under their key ids.
+* `created_at` is the signer's clock and is only as trustworthy as the host that signed.
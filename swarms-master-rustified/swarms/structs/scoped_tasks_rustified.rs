@@ -0,0 +1,183 @@
+### Overview
+There is no Python counterpart; Python swarms fan out with `ThreadPoolExecutor` and an exception in one
+future surfaces when its result is read. The Rust swarms previously fanned out with detached
+`thread::spawn` / `tokio::spawn` calls. Those cannot borrow the agents they run, and a panic in one worker
+either tore down the join loop (`handle.join().unwrap()`) or was silently dropped.
+
+This module is the one place fan-out happens:
+
+* `scoped_map` runs a closure per item on `std::thread::scope` threads. Workers may borrow from the caller,
+  every worker is joined before it returns, and all panics are collected instead of the first one winning.
+* `join_all` and `join_all_blocking` do the same on tokio with a `JoinSet`. The set owns every task, so
+  dropping the future aborts the remaining work instead of leaking it.
+
+All of them return results in input order, or a `TaskFailures` listing every task that panicked.
+`scoped_map_settled` reports each outcome separately for callers that want to keep partial results.
+
+### Rust Code
+```rust
+use futures::FutureExt;
+use log::error;
+use std::any::Any;
+use std::fmt;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use tokio::task::JoinSet;
+
+/// One task that panicked or was cancelled, or whose agent returned an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskFailure {
+    /// Position of the task in the input.
+    pub index: usize,
+    /// Caller-supplied name, typically the agent name.
+    pub label: String,
+    pub message: String,
+}
+
+/// Every failure from one fan-out, in input order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskFailures(pub Vec<TaskFailure>);
+
+impl fmt::Display for TaskFailures {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} task(s) failed", self.0.len())?;
+        for failure in &self.0 {
+            write!(f, "; [{}] {}: {}", failure.index, failure.label, failure.message)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for TaskFailures {}
+
+/// Best-effort text of a panic payload (`panic!("...")` carries a `&str` or `String`).
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked with a non-string payload".to_string()
+    }
+}
+
+/// Fold per-task outcomes into all results, or every failure. Failures are logged.
+pub fn settle<R>(outcomes: Vec<Result<R, TaskFailure>>) -> Result<Vec<R>, TaskFailures> {
+    let mut results = Vec::with_capacity(outcomes.len());
+    let mut failures = Vec::new();
+    for outcome in outcomes {
+        match outcome {
+            Ok(result) => results.push(result),
+            Err(failure) => {
+                error!("Task {} ({}) failed: {}", failure.index, failure.label, failure.message);
+                failures.push(failure);
+            }
+        }
+    }
+    if failures.is_empty() {
+        Ok(results)
+    } else {
+        Err(TaskFailures(failures))
+    }
+}
+
+/// Run `f` on every item on its own scoped thread and report each outcome separately, so callers can
+/// keep the results of the tasks that succeeded. `label` names an item in failure reports.
+pub fn scoped_map_settled<T, R, L, F>(items: &[T], label: L, f: F) -> Vec<Result<R, TaskFailure>>
+where
+    T: Sync,
+    R: Send,
+    L: Fn(&T) -> String,
+    F: Fn(&T) -> R + Sync,
+{
+    let outcomes: Vec<thread::Result<R>> = thread::scope(|scope| {
+        let handles: Vec<_> = items.iter().map(|item| scope.spawn(|| f(item))).collect();
+        handles.into_iter().map(|handle| handle.join()).collect()
+    });
+    outcomes
+        .into_iter()
+        .enumerate()
+        .map(|(index, outcome)| {
+            outcome.map_err(|payload| TaskFailure { index, label: label(&items[index]), message: panic_message(&*payload) })
+        })
+        .collect()
+}
+
+/// `scoped_map_settled`, failing with every panic if any task panicked.
+pub fn scoped_map<T, R, L, F>(items: &[T], label: L, f: F) -> Result<Vec<R>, TaskFailures>
+where
+    T: Sync,
+    R: Send,
+    L: Fn(&T) -> String,
+    F: Fn(&T) -> R + Sync,
+{
+    settle(scoped_map_settled(items, label, f))
+}
+
+type Caught<R> = (usize, thread::Result<R>);
+
+async fn drain<R: Send + 'static>(mut set: JoinSet<Caught<R>>, labels: Vec<String>) -> Result<Vec<R>, TaskFailures> {
+    let mut outcomes: Vec<Option<Result<R, TaskFailure>>> = (0..labels.len()).map(|_| None).collect();
+    while let Some(joined) = set.join_next().await {
+        // Panics are caught inside each task, so a join error here can only be a runtime shutdown.
+        let (index, outcome) = joined.map_err(|err| {
+            TaskFailures(vec![TaskFailure { index: 0, label: "runtime".to_string(), message: err.to_string() }])
+        })?;
+        outcomes[index] = Some(outcome.map_err(|payload| TaskFailure {
+            index,
+            label: labels[index].clone(),
+            message: panic_message(&*payload),
+        }));
+    }
+    settle(outcomes.into_iter().map(|outcome| outcome.expect("every task reported")).collect())
+}
+
+/// Run every `(label, future)` pair concurrently on the runtime and wait for all of them.
+pub async fn join_all<R, Fut>(tasks: Vec<(String, Fut)>) -> Result<Vec<R>, TaskFailures>
+where
+    R: Send + 'static,
+    Fut: Future<Output = R> + Send + 'static,
+{
+    let mut set = JoinSet::new();
+    let mut labels = Vec::with_capacity(tasks.len());
+    for (index, (label, task)) in tasks.into_iter().enumerate() {
+        labels.push(label);
+        set.spawn(async move { (index, AssertUnwindSafe(task).catch_unwind().await) });
+    }
+    drain(set, labels).await
+}
+
+/// Run every `(label, closure)` pair on tokio's blocking pool and wait for all of them.
+pub async fn join_all_blocking<R, F>(jobs: Vec<(String, F)>) -> Result<Vec<R>, TaskFailures>
+where
+    R: Send + 'static,
+    F: FnOnce() -> R + Send + 'static,
+{
+    let mut set = JoinSet::new();
+    let mut labels = Vec::with_capacity(jobs.len());
+    for (index, (label, job)) in jobs.into_iter().enumerate() {
+        labels.push(label);
+        set.spawn_blocking(move || (index, panic::catch_unwind(AssertUnwindSafe(job))));
+    }
+    drain(set, labels).await
+}
+
+fn main() {
+    let agents = vec!["Researcher".to_string(), "Writer".to_string(), "Critic".to_string()];
+    let outcome = scoped_map(&agents, |name| name.clone(), |name| {
+        if name == "Critic" {
+            panic!("tool returned malformed JSON");
+        }
+        format!("{} finished", name)
+    });
+    match outcome {
+        Ok(results) => println!("{:?}", results),
+        Err(failures) => println!("{}", failures),
+    }
+}
+```
+
+### Notes
+* A panic in one task never cancels its siblings; every task runs to completion before failures are reported.
+* Threads spawned by `scoped_map` are not pooled. For large fan-outs, chunk the input or use `join_all_blocking`.
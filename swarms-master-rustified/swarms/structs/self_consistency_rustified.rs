@@ -0,0 +1,159 @@
+### Overview
+The Rust counterpart of the Python `Agent`'s `algorithm_of_thoughts` flag, which in Python only swaps in a
+prompt asking the model to reason step by step. Here it is self-consistency: the same task is sampled
+`samples` times at a raised `temperature`, so the reasoning paths differ. The final answer is extracted
+from each path. The most common answer wins, and the share of paths that reached it is the confidence.
+
+Votes are counted with the `Consensus` strategies from `majority_voting`. The default, `Normalized`, treats
+`Paris.` and `paris` as one answer; `Exact` counts only identical answers together.
+
+The run calls no model itself. `sample` produces one reasoning path for a `SampleRequest`, whose `prompt`
+asks for the answer on a final `Final answer:` line. Samples run in parallel on scoped threads, so `sample`
+must be `Sync`. A sample that fails or panics is recorded and does not vote.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use crate::swarms::structs::majority_voting::{Consensus, Vote};
+use crate::swarms::structs::scoped_tasks::scoped_map_settled;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+
+/// Appended to the task in every sample's prompt.
+pub const ANSWER_FORMAT: &str = "Think through the problem step by step. \
+    End with a line of the form `Final answer: <answer>`, giving the answer only.";
+
+/// Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SelfConsistencyConfig {
+    /// Reasoning paths sampled per task (N).
+    pub samples: usize,
+    /// Sampling temperature; higher than a normal run so the paths differ.
+    pub temperature: f64,
+    pub consensus: Consensus,
+}
+
+impl Default for SelfConsistencyConfig {
+    fn default() -> Self {
+        SelfConsistencyConfig { samples: 5, temperature: 1.0, consensus: Consensus::Normalized }
+    }
+}
+
+impl SelfConsistencyConfig {
+    /// `None` unless `algorithm_of_thoughts` is set.
+    pub fn from_schema(schema: &AgentSchema) -> Option<Self> {
+        schema.algorithm_of_thoughts.unwrap_or(false).then(SelfConsistencyConfig::default)
+    }
+}
+
+/// What `sample` is asked to produce.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampleRequest<'a> {
+    /// Zero-based sample number.
+    pub index: usize,
+    pub task: &'a str,
+    /// The task followed by `ANSWER_FORMAT`.
+    pub prompt: String,
+    pub temperature: f64,
+}
+
+/// The final answer in a reasoning path: a `\boxed{}` answer, else the last `Final answer:` or `Answer:`
+/// line, else the last non-empty line.
+pub fn extract_answer(reply: &str) -> Option<String> {
+    if let Some(start) = reply.rfind("\\boxed{") {
+        let inner = &reply[start + "\\boxed{".len()..];
+        let mut depth = 0;
+        for (offset, c) in inner.char_indices() {
+            match c {
+                '{' => depth += 1,
+                '}' if depth == 0 => return clean(&inner[..offset]),
+                '}' => depth -= 1,
+                _ => {}
+            }
+        }
+    }
+    let lines: Vec<&str> = reply.lines().map(str::trim).filter(|line| !line.is_empty()).collect();
+    for marker in ["final answer:", "answer:"] {
+        for line in lines.iter().rev() {
+            // ASCII lowercasing keeps byte offsets, so `index` is valid in `line`.
+            if let Some(index) = line.to_ascii_lowercase().find(marker) {
+                return clean(&line[index + marker.len()..]);
+            }
+        }
+    }
+    lines.last().and_then(|line| clean(line))
+}
+
+fn clean(answer: &str) -> Option<String> {
+    let answer = answer.trim().trim_matches(|c| c == '*' || c == '`').trim();
+    (!answer.is_empty()).then(|| answer.to_string())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SampledPath {
+    pub index: usize,
+    /// The full reply, or `None` when the sample failed.
+    pub reasoning: Option<String>,
+    /// The extracted answer; `None` when the sample failed or no answer was found.
+    pub answer: Option<String>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SelfConsistencyRun {
+    /// The modal answer, as first given; `None` when no sample produced one.
+    pub answer: Option<String>,
+    /// Votes for `answer` over the samples that produced an answer, from 0 to 1.
+    pub confidence: f32,
+    /// Every distinct answer, most common first.
+    pub votes: Vec<Vote>,
+    pub paths: Vec<SampledPath>,
+}
+
+/// Sample `config.samples` reasoning paths for `task` and return the modal answer.
+pub fn self_consistency<F>(task: &str, config: &SelfConsistencyConfig, sample: F) -> SelfConsistencyRun
+where
+    F: Fn(&SampleRequest) -> Result<String, String> + Sync,
+{
+    let prompt = format!("{}\n\n{}", task, ANSWER_FORMAT);
+    let requests: Vec<SampleRequest> = (0..config.samples.max(1))
+        .map(|index| SampleRequest { index, task, prompt: prompt.clone(), temperature: config.temperature })
+        .collect();
+    let outcomes = scoped_map_settled(&requests, |request| format!("sample {}", request.index), |request| sample(request));
+
+    let paths: Vec<SampledPath> = outcomes
+        .into_iter()
+        .enumerate()
+        .map(|(index, outcome)| match outcome.map_err(|failure| failure.message).and_then(|result| result) {
+            Ok(reply) => SampledPath { index, answer: extract_answer(&reply), reasoning: Some(reply), error: None },
+            Err(error) => {
+                warn!("Self-consistency sample {} failed: {}", index, error);
+                SampledPath { index, reasoning: None, answer: None, error: Some(error) }
+            }
+        })
+        .collect();
+
+    let answers: Vec<String> = paths.iter().filter_map(|path| path.answer.clone()).collect();
+    let votes = config.consensus.tally(&answers);
+    let (answer, confidence) = match votes.first() {
+        Some(vote) => (Some(vote.answer.clone()), vote.count as f32 / answers.len() as f32),
+        None => (None, 0.0),
+    };
+    debug!("{} of {} sample(s) answered; {} distinct answer(s)", answers.len(), paths.len(), votes.len());
+    SelfConsistencyRun { answer, confidence, votes, paths }
+}
+
+fn main() {
+    let replies = ["6 * 7 = 42.\nFinal answer: 42", "Six sevens are 42.\nFinal answer: 42.", "6 * 7 = 36\nAnswer: 36"];
+    let config = SelfConsistencyConfig { samples: 3, ..SelfConsistencyConfig::default() };
+    let run = self_consistency("What is 6 * 7?", &config, |request| Ok(replies[request.index].to_string()));
+    println!("{:?} with confidence {:.2}", run.answer, run.confidence);
+    println!("{}", serde_json::to_string_pretty(&run).unwrap());
+}
+```
+
+### Notes
+* A run makes `samples` model calls at once; mind provider rate limits when raising `samples`.
+* Ties go to the answer that appeared first in sample order, so equal splits are deterministic.
+* Confidence counts only samples that produced an answer. Check `paths` for failures when many samples failed.
@@ -5,21 +5,36 @@
 // whereas Rust's std::thread and std::sync modules provide lower-level primitives for threads and synchronization. 
 // Additionally, Rust's error handling and logger libraries are different from Python's.
 
+use crate::swarms::agents::create_agents_from_yaml::{AgentConfig, SwarmConfig, YAMLConfig};
+use crate::swarms::integrations::notifications::{Notifier, RunSummary};
+use crate::swarms::integrations::webhooks::{WebhookDispatcher, WebhookEvent, WebhookPayload};
+use crate::swarms::structs::agent::{Agent, LlmAgent};
+use crate::swarms::structs::approval_gate::{ApprovalContext, ApprovalError, ApprovalGate, ReviewRecord};
+use crate::swarms::structs::audit::AuditAction;
+use crate::swarms::structs::citation::{CitationSet, Cited};
+use crate::swarms::structs::conversation::Conversation;
+use crate::swarms::structs::dry_run::PlannedCall;
+use crate::swarms::structs::handoff::{AgentTurn, HandoffError, HandoffEvent, HandoffPayload, HandoffPolicy};
+use crate::swarms::structs::run_diff::{RunTrace, TraceRecorder, TraceStep};
+use crate::swarms::structs::step_guard::StepStatus;
+use crate::swarms::structs::supervisor::{StuckAction, Supervisor, TaskHeartbeat};
+use crate::swarms::structs::swarm::{Swarm, SwarmError};
+use crate::swarms::structs::workflow_run::{Resume, RetryError, Run, RunStep};
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::metrics::SwarmMetrics;
 use log::{error, info, warn};
-use std::thread;
+use serde::Serialize;
+use serde_json::json;
 use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tokio;
 
-// Define the Agent and AgentRearrange structs
-struct Agent {
-    name: String,
-}
-
+// Define the AgentRearrange struct; it only needs the agent names to build the flow
 struct AgentRearrange {
     name: String,
     description: String,
-    agents: Vec<Agent>,
+    agent_names: Vec<String>,
     flow: String,
     max_loops: i32,
     output_type: String,
@@ -31,7 +46,7 @@ impl AgentRearrange {
     fn new(
         name: String,
         description: String,
-        agents: Vec<Agent>,
+        agent_names: Vec<String>,
         flow: String,
         max_loops: i32,
         output_type: String,
@@ -41,7 +56,7 @@ impl AgentRearrange {
         AgentRearrange {
             name,
             description,
-            agents,
+            agent_names,
             flow,
             max_loops,
             output_type,
@@ -51,23 +66,55 @@ impl AgentRearrange {
     }
 }
 
+// The result of a gated run, with the decision at each gate it passed
+#[derive(Debug, Clone, Serialize)]
+pub struct GatedRun {
+    pub output: String,
+    pub reviews: Vec<ReviewRecord>,
+}
+
+// One agent's output from `run_with`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct StageOutput {
+    pub agent: String,
+    pub output: String,
+}
+
+// The result of `run_with_handoffs`: the final answer, who gave it, and how control moved to them
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct HandoffRun {
+    pub output: String,
+    pub agent: String,
+    pub turns: Vec<StageOutput>,
+    pub handoffs: Vec<HandoffEvent>,
+}
+
 // Define the SequentialWorkflow struct
-struct SequentialWorkflow {
+pub struct SequentialWorkflow {
     name: String,
     description: String,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
     max_loops: i32,
     output_type: String,
     return_json: bool,
     shared_memory_system: fn() -> (),
     agent_rearrange: AgentRearrange,
+    // Approval gates keyed by the name of the agent whose output they review
+    gates: HashMap<String, ApprovalGate>,
+    // Watches each agent's turn for missing heartbeats; turns are unsupervised without one
+    supervisor: Option<Arc<Supervisor>>,
+    traces: Option<TraceRecorder>,
+    // Told when a run completes, fails, or exceeds its budget
+    notifier: Notifier,
+    // Receives the lifecycle events of every run
+    webhooks: Option<WebhookDispatcher>,
 }
 
 impl SequentialWorkflow {
-    fn new(
+    pub fn new(
         name: String,
         description: String,
-        agents: Vec<Agent>,
+        agents: Vec<Arc<dyn Agent>>,
         max_loops: i32,
         output_type: String,
         return_json: bool,
@@ -77,7 +124,7 @@ impl SequentialWorkflow {
         let agent_rearrange = AgentRearrange::new(
             name.clone(),
             description.clone(),
-            agents.clone(),
+            agents.iter().map(|agent| agent.name().to_string()).collect(),
             flow,
             max_loops,
             output_type.clone(),
@@ -93,10 +140,64 @@ impl SequentialWorkflow {
             return_json,
             shared_memory_system,
             agent_rearrange,
+            gates: HashMap::new(),
+            supervisor: None,
+            traces: None,
+            notifier: Notifier::new(),
+            webhooks: None,
+        }
+    }
+
+    // Run each agent's turn under `supervisor`, so a turn that stops sending heartbeats is cancelled or
+    // run again instead of hanging the workflow. Start `supervisor.watch` for the checks to happen.
+    pub fn with_supervisor(mut self, supervisor: Arc<Supervisor>) -> Self {
+        self.supervisor = Some(supervisor);
+        self
+    }
+
+    // Save a trace of every run, one step per agent turn, for `swarms diff` and `swarms debug`
+    pub fn with_traces(mut self, traces: TraceRecorder) -> Self {
+        self.traces = Some(traces);
+        self
+    }
+
+    // Tell `notifier`'s sinks how each run of `run_with` and `run_recorded` ended. Delivery happens on the
+    // current Tokio runtime and never fails the run
+    pub fn with_notifier(mut self, notifier: Notifier) -> Self {
+        self.notifier = notifier;
+        self
+    }
+
+    // Emit each run's lifecycle events of `run_with` and `run_recorded` to the webhooks `dispatcher` holds for
+    // this workflow or the run
+    pub fn with_webhooks(mut self, dispatcher: WebhookDispatcher) -> Self {
+        self.webhooks = Some(dispatcher);
+        self
+    }
+
+    // Pause after `agent_name` until its output is approved
+    pub fn add_gate(&mut self, agent_name: &str, gate: ApprovalGate) -> Result<(), ApprovalError> {
+        if !self.agents.iter().any(|agent| agent.name() == agent_name) {
+            return Err(ApprovalError::UnknownStep(agent_name.to_string()));
         }
+        self.gates.insert(agent_name.to_string(), gate);
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn agents(&self) -> &[Arc<dyn Agent>] {
+        &self.agents
+    }
+
+    // The `a -> b -> c` flow string
+    pub fn flow(&self) -> &str {
+        &self.agent_rearrange.flow
     }
 
-    fn reliability_check(&self) {
+    pub fn reliability_check(&self) {
         if self.agents.is_empty() {
             panic!("Agents list cannot be empty");
         }
@@ -106,11 +207,8 @@ impl SequentialWorkflow {
         info!("Checks completed your swarm is ready.");
     }
 
-    fn sequential_flow(agents: &Vec<Agent>) -> String {
-        let mut agent_names: Vec<String> = Vec::new();
-        for agent in agents {
-            agent_names.push(agent.name.clone());
-        }
+    fn sequential_flow(agents: &[Arc<dyn Agent>]) -> String {
+        let agent_names: Vec<&str> = agents.iter().map(|agent| agent.name()).collect();
         agent_names.join(" -> ")
     }
 
@@ -128,7 +226,321 @@ impl SequentialWorkflow {
         String::from("Result")
     }
 
-    async fn run_async(&self, task: String) -> String {
+    // A failed agent hands its error text to the next one, as the Python workflow does
+    async fn run_agent(&self, agent: &dyn Agent, task: &str) -> String {
+        agent.run(task).unwrap_or_else(|err| {
+            warn!("{}", err);
+            err.to_string()
+        })
+    }
+
+    // Run the agents in order with `step` standing in for each agent's model call. Each agent
+    // receives the previous agent's output; the first error stops the run. Steps that call a model
+    // themselves read the agent's prompt and model through `spec()`.
+    pub fn run_with(
+        &self,
+        task: &str,
+        mut step: impl FnMut(&dyn Agent, &str) -> Result<String, String>,
+    ) -> Result<Vec<StageOutput>, String> {
+        self.run_with_heartbeats(task, |agent, input, _| step(agent, input))
+    }
+
+    // `run_with` for steps that report progress: `step` gets the turn's heartbeat to `beat` and to check
+    // for cancellation. A turn the supervisor finds stuck is run again or fails the workflow, as its
+    // `StuckAction` says.
+    pub fn run_with_heartbeats(
+        &self,
+        task: &str,
+        mut step: impl FnMut(&dyn Agent, &str, &TaskHeartbeat) -> Result<String, String>,
+    ) -> Result<Vec<StageOutput>, String> {
+        let started = Instant::now();
+        let run_id = new_id(IdKind::Run);
+        let mut stages: Vec<StageOutput> = Vec::with_capacity(self.agents.len());
+        let mut trace = self.traces.as_ref().map(|traces| traces.start(run_id.clone()));
+        self.emit(WebhookEvent::RunStarted, &run_id, json!({ "task": task }));
+        let mut input = task.to_string();
+        for agent in &self.agents {
+            let mut attempt = 1;
+            let result = loop {
+                let heartbeat = match &self.supervisor {
+                    Some(supervisor) => supervisor.begin(agent.name(), task, attempt),
+                    None => TaskHeartbeat::detached(agent.name(), task),
+                };
+                let result = step(agent.as_ref(), &input, &heartbeat);
+                match heartbeat.stuck() {
+                    None => break result,
+                    Some(StuckAction::Requeue) => {
+                        warn!("{}: running {} again after attempt {} got stuck", self.name, agent.name(), attempt);
+                        attempt += 1;
+                    }
+                    Some(StuckAction::Cancel) => {
+                        break Err(format!("cancelled: no heartbeat in time, {:?} into the turn", heartbeat.elapsed()));
+                    }
+                }
+            };
+            if let Some(trace) = &mut trace {
+                let output = result.as_ref().unwrap_or_else(|e| e);
+                trace.push(TraceStep::of(agent.as_ref(), &input, output));
+            }
+            match result {
+                Ok(output) => {
+                    self.emit(WebhookEvent::AgentStepCompleted, &run_id, json!({ "agent": agent.name(), "output": output }));
+                    input = output.clone();
+                    stages.push(StageOutput { agent: agent.name().to_string(), output });
+                }
+                Err(e) => {
+                    error!("{}: agent {} failed: {}", self.name, agent.name(), e);
+                    self.save_trace(trace.as_ref());
+                    SwarmMetrics::global().workflow_finished(&self.name, "failed", started.elapsed());
+                    let error = format!("{} failed: {}", agent.name(), e);
+                    self.finish(&run_id, Err(&error), started);
+                    return Err(error);
+                }
+            }
+        }
+        self.save_trace(trace.as_ref());
+        SwarmMetrics::global().workflow_finished(&self.name, "completed", started.elapsed());
+        let output = stages.last().map(|stage| stage.output.as_str()).unwrap_or_default();
+        self.finish(&run_id, Ok(output), started);
+        Ok(stages)
+    }
+
+    fn emit(&self, event: WebhookEvent, run_id: &str, data: serde_json::Value) {
+        if let Some(webhooks) = &self.webhooks {
+            webhooks.spawn_emit(WebhookPayload::new(event, &self.name, run_id, data));
+        }
+    }
+
+    // Tell the notifier and the webhooks how a run ended
+    fn finish(&self, run_id: &str, outcome: Result<&str, &str>, started: Instant) {
+        if self.notifier.is_empty() && self.webhooks.is_none() {
+            return;
+        }
+        let summary = RunSummary::of_outcome(&self.name, run_id, outcome, started.elapsed());
+        if let Some(webhooks) = &self.webhooks {
+            webhooks.spawn_emit(WebhookPayload::from_summary(&summary));
+        }
+        self.notifier.notify_detached(summary);
+    }
+
+    fn save_trace(&self, trace: Option<&RunTrace>) {
+        if let (Some(traces), Some(trace)) = (&self.traces, trace) {
+            traces.finish(trace);
+        }
+    }
+
+    // The trace of a recorded run is rebuilt from the run, so a retried step replaces its failed attempt
+    fn save_run_trace(&self, run: &Run) {
+        if let Some(traces) = &self.traces {
+            traces.finish_run(run, |step_id| {
+                let agent = run.get(step_id).map(|step| step.agent.as_str());
+                self.agents.iter().find(|candidate| Some(candidate.name()) == agent).map(|agent| agent.as_ref())
+            });
+        }
+    }
+
+    // Run the agents in order like `run_with`, recording each turn's input, output, and status in a `Run`.
+    // A failed turn stops the run there; `retry_step` picks it up without running the earlier turns again.
+    pub fn run_recorded(&self, task: &str, mut step: impl FnMut(&dyn Agent, &str) -> Result<String, String>) -> Run {
+        let started = Instant::now();
+        let mut run = self.planned_run(task);
+        self.emit(WebhookEvent::RunStarted, &run.run_id, json!({ "task": task }));
+        run.execute(|planned, input| self.run_step(planned, input, &mut step));
+        for step in run.steps.iter().filter(|step| step.succeeded()) {
+            let data = json!({ "agent": step.agent, "step": step.id, "output": step.output });
+            self.emit(WebhookEvent::AgentStepCompleted, &run.run_id, data);
+        }
+        self.save_run_trace(&run);
+        let outcome = if run.is_complete() { "completed" } else { "failed" };
+        SwarmMetrics::global().workflow_finished(&self.name, outcome, started.elapsed());
+        match run.output() {
+            Some(output) => self.finish(&run.run_id, Ok(output), started),
+            None => {
+                let error = match run.failed_steps().next() {
+                    Some(RunStep { id, status: Some(StepStatus::Failed { error, .. }), .. }) => {
+                        format!("{} failed: {}", id, error)
+                    }
+                    Some(RunStep { id, status: Some(StepStatus::Panicked { message }), .. }) => {
+                        format!("{} panicked: {}", id, message)
+                    }
+                    _ => "the run did not complete".to_string(),
+                };
+                self.finish(&run.run_id, Err(&error), started);
+            }
+        }
+        run
+    }
+
+    // Run the failed turn `step_id` of `run` again on the input it was sent, then, with `Resume::Downstream`,
+    // the turns after it
+    pub fn retry_step(
+        &self,
+        run: &mut Run,
+        step_id: &str,
+        resume: Resume,
+        mut step: impl FnMut(&dyn Agent, &str) -> Result<String, String>,
+    ) -> Result<Vec<String>, RetryError> {
+        let planned = run.get(step_id).ok_or_else(|| RetryError::UnknownStep(step_id.to_string()))?;
+        if !self.agents.iter().any(|agent| agent.name() == planned.agent) {
+            return Err(RetryError::NoAgent(step_id.to_string()));
+        }
+        let retried = run.retry_step(step_id, resume, |planned, input| self.run_step(planned, input, &mut step));
+        self.save_run_trace(run);
+        retried
+    }
+
+    // One step per turn, each reading the previous one's output. A repeated agent's later turns are
+    // `<name>#2`, `<name>#3`, and so on, so step ids stay unique.
+    fn planned_run(&self, task: &str) -> Run {
+        let mut run = Run::new(self.name.clone(), task);
+        let mut turns: HashMap<&str, usize> = HashMap::new();
+        let mut previous: Option<String> = None;
+        for agent in &self.agents {
+            let turn = turns.entry(agent.name()).or_insert(0);
+            *turn += 1;
+            let id = if *turn == 1 { agent.name().to_string() } else { format!("{}#{}", agent.name(), turn) };
+            let planned = match previous.replace(id.clone()) {
+                Some(upstream) => RunStep::new(id, agent.name()).reading_upstream().after([upstream]),
+                None => RunStep::new(id, agent.name()),
+            };
+            run = run.step(planned);
+        }
+        run
+    }
+
+    fn run_step(
+        &self,
+        planned: &RunStep,
+        input: &str,
+        step: &mut impl FnMut(&dyn Agent, &str) -> Result<String, String>,
+    ) -> Result<String, String> {
+        match self.agents.iter().find(|agent| agent.name() == planned.agent) {
+            Some(agent) => step(agent.as_ref(), input),
+            None => Err(format!("no agent named {}", planned.agent)),
+        }
+    }
+
+    // Run with explicit handoffs instead of the fixed order. The first agent starts on `task`; each
+    // agent then either finishes the run or hands control to a named peer. Every reply is added to
+    // `conversation` under the agent's name, but a peer only sees the payload its handoff selected.
+    pub fn run_with_handoffs(
+        &self,
+        task: &str,
+        conversation: &mut Conversation,
+        policy: &HandoffPolicy,
+        mut step: impl FnMut(&dyn Agent, &HandoffPayload) -> Result<AgentTurn, String>,
+    ) -> Result<HandoffRun, HandoffError> {
+        let started = Instant::now();
+        let mut controller = policy.start();
+        let mut agent = self.agents.first().ok_or(HandoffError::NoAgents)?;
+        let mut payload = HandoffPayload::initial(task);
+        let mut turns: Vec<StageOutput> = Vec::new();
+        let result = loop {
+            let turn = match step(agent.as_ref(), &payload) {
+                Ok(turn) => turn,
+                Err(message) => break Err(HandoffError::Agent { agent: agent.name().to_string(), message }),
+            };
+            let (output, handoff) = match turn {
+                AgentTurn::Finish(output) => (output, None),
+                AgentTurn::Handoff { output, handoff } => (output, Some(handoff)),
+            };
+            let reply = conversation.add(agent.name().to_string(), output.clone());
+            turns.push(StageOutput { agent: agent.name().to_string(), output: output.clone() });
+            let Some(handoff) = handoff else {
+                break Ok(HandoffRun { output, agent: agent.name().to_string(), turns, handoffs: controller.into_events() });
+            };
+            match controller.transfer(&self.agents, agent.name(), Some(reply), handoff, conversation) {
+                Ok((peer, next)) => {
+                    agent = peer;
+                    payload = next;
+                }
+                Err(e) => break Err(e),
+            }
+        };
+        let outcome = if result.is_ok() { "completed" } else { "failed" };
+        if let Err(e) = &result {
+            error!("{}: handoff run failed: {}", self.name, e);
+        }
+        SwarmMetrics::global().workflow_finished(&self.name, outcome, started.elapsed());
+        result
+    }
+
+    // Run the agents in order, stopping at each gate until it is approved. Each agent receives the
+    // previous agent's output, as in the `a -> b -> c` flow; an output edited at a gate replaces it.
+    pub async fn run_with_approvals(&self, task: String, approvals: &ApprovalContext) -> Result<GatedRun, ApprovalError> {
+        if let Some(auditor) = &approvals.audit {
+            auditor.record(AuditAction::RunStarted, &approvals.run_id, json!({ "workflow": self.name }));
+        }
+        self.run_from(0, task, Vec::new(), approvals).await
+    }
+
+    // Record the outcome of a gated run in the workflow metrics
+    fn record_outcome(&self, result: &Result<GatedRun, ApprovalError>, started: Instant) {
+        let outcome = result.as_ref().map_or_else(ApprovalError::outcome, |_| "completed");
+        SwarmMetrics::global().workflow_finished(&self.name, outcome, started.elapsed());
+    }
+
+    // Continue a run that was interrupted while paused at a gate. The gate is asked again.
+    pub async fn resume(&self, approvals: &ApprovalContext) -> Result<GatedRun, ApprovalError> {
+        let checkpoint = approvals
+            .store
+            .load(&approvals.run_id)?
+            .ok_or_else(|| ApprovalError::NotPending(approvals.run_id.clone()))?;
+        let next_agent = checkpoint.state["next_agent"].as_u64().unwrap_or(0) as usize;
+        let mut reviews: Vec<ReviewRecord> = serde_json::from_value(checkpoint.state["reviews"].clone()).unwrap_or_default();
+        let upstream = next_agent.checked_sub(1).and_then(|index| self.agents.get(index));
+        let gate = upstream
+            .and_then(|agent| self.gates.get(agent.name()))
+            .ok_or_else(|| ApprovalError::UnknownStep(checkpoint.request.gate.clone()))?;
+        info!("Resuming {} at gate '{}'", self.name, gate.name);
+        let review = gate.review(checkpoint.request, checkpoint.state, approvals).await?;
+        let output = review.output().to_string();
+        reviews.push(review);
+        self.run_from(next_agent, output, reviews, approvals).await
+    }
+
+    async fn run_from(
+        &self,
+        start: usize,
+        task: String,
+        reviews: Vec<ReviewRecord>,
+        approvals: &ApprovalContext,
+    ) -> Result<GatedRun, ApprovalError> {
+        let started = Instant::now();
+        let mut trace = self.traces.as_ref().map(|traces| traces.resume(&approvals.run_id));
+        let result = self.run_steps(start, task, reviews, approvals, &mut trace).await;
+        self.save_trace(trace.as_ref());
+        self.record_outcome(&result, started);
+        result
+    }
+
+    async fn run_steps(
+        &self,
+        start: usize,
+        task: String,
+        mut reviews: Vec<ReviewRecord>,
+        approvals: &ApprovalContext,
+        trace: &mut Option<RunTrace>,
+    ) -> Result<GatedRun, ApprovalError> {
+        let mut output = task;
+        for (index, agent) in self.agents.iter().enumerate().skip(start) {
+            let input = output;
+            output = self.run_agent(agent.as_ref(), &input).await;
+            if let Some(trace) = trace {
+                trace.push(TraceStep::of(agent.as_ref(), &input, &output));
+            }
+            if let Some(gate) = self.gates.get(agent.name()) {
+                let request = gate.request(&approvals.run_id, &self.name, agent.name(), &output);
+                let state = json!({ "next_agent": index + 1, "reviews": reviews });
+                let review = gate.review(request, state, approvals).await?;
+                output = review.output().to_string();
+                reviews.push(review);
+            }
+        }
+        Ok(GatedRun { output, reviews })
+    }
+
+    pub async fn run_async(&self, task: String) -> String {
         self.run(
             task,
             None,
@@ -140,17 +552,39 @@ impl SequentialWorkflow {
         ).await
     }
 
+    // Run the agents in order through their bound models, as `Swarm::run` does, carrying the citations
+    // gathered for the task (retrieved chunks, tool results) through to the final answer
+    pub fn run_with_citations(&self, task: Cited<String>) -> Result<Cited<String>, String> {
+        info!("Running {} with {} citations", self.name, task.citations.len());
+        self.run_cited(task, |agent, input| agent.run(input).map(Cited::uncited).map_err(|err| err.to_string()))
+    }
+
+    // Render the final answer of `run_with_citations` with its "Sources:" section
+    pub fn run_with_sources(&self, task: String, sources: CitationSet) -> Result<String, String> {
+        Ok(self.run_with_citations(Cited::new(task, sources))?.with_sources())
+    }
+
+    // `run_with` for steps that cite what they used: the citations each step returns are merged, in
+    // order, after those of the task. The answer is the last agent's output.
+    pub fn run_cited(
+        &self,
+        task: Cited<String>,
+        mut step: impl FnMut(&dyn Agent, &str) -> Result<Cited<String>, String>,
+    ) -> Result<Cited<String>, String> {
+        let mut citations = task.citations;
+        let stages = self.run_with(&task.value, |agent, input| {
+            let output = step(agent, input)?;
+            citations.merge(&output.citations);
+            Ok(output.value)
+        })?;
+        let answer = stages.last().map(|stage| stage.output.clone()).unwrap_or_default();
+        Ok(Cited::new(answer, citations))
+    }
+
+    // Run tasks concurrently. The futures borrow `self`, so they are polled together on this task
+    // rather than spawned; all of them are done (or dropped together) when this returns.
     async fn run_concurrent(&self, tasks: Vec<String>) -> Vec<String> {
-        let mut handles = vec![];
-        for task in tasks {
-            let handle = tokio::spawn(self.run_async(task.clone()));
-            handles.push(handle);
-        }
-        let mut results = vec![];
-        for handle in handles {
-            results.push(handle.await.unwrap());
-        }
-        results
+        futures::future::join_all(tasks.into_iter().map(|task| self.run_async(task))).await
     }
 
     async fn run_batched(&self, tasks: Vec<String>) -> Vec<String> {
@@ -163,12 +597,52 @@ impl SequentialWorkflow {
     }
 }
 
+// Runs the agents in order through their bound models; the answer is the last agent's output
+impl Swarm for SequentialWorkflow {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        if self.agents.is_empty() {
+            return Err(SwarmError::NoAgents);
+        }
+        let stages = self
+            .run_with(task, |agent, input| agent.run(input).map_err(|err| err.to_string()))
+            .map_err(SwarmError::Failed)?;
+        Ok(stages.last().map(|stage| stage.output.clone()).unwrap_or_default())
+    }
+
+    // Each agent reads the previous agent's output; the first reads the task
+    fn planned_calls(&self, _task: &str) -> Option<Vec<PlannedCall>> {
+        let calls = self.agents.iter().enumerate().map(|(index, agent)| match index {
+            0 => PlannedCall::new(agent.as_ref()).reading_task(),
+            _ => PlannedCall::new(agent.as_ref()).after([index - 1]),
+        });
+        Some(calls.collect())
+    }
+
+    fn to_config(&self) -> Option<YAMLConfig> {
+        Some(YAMLConfig {
+            agents: self.agents.iter().filter_map(|agent| agent.spec()).map(AgentConfig::from_spec).collect(),
+            swarm_architecture: Some(SwarmConfig {
+                name: self.name.clone(),
+                description: self.description.clone(),
+                max_loops: self.max_loops,
+                swarm_type: "SequentialWorkflow".to_string(),
+                return_json: self.return_json,
+                ..SwarmConfig::default()
+            }),
+        })
+    }
+}
+
 fn main() {
     // Initialize the logger
     env_logger::init();
 
     // Create a new SequentialWorkflow
-    let agents = vec![Agent { name: String::from("Agent1") }];
+    let agents: Vec<Arc<dyn Agent>> = vec![LlmAgent::builder().name("Agent1").llm("gpt-4o").build().expect("valid agent").into()];
     let workflow = SequentialWorkflow::new(
         String::from("SequentialWorkflow"),
         String::from("Sequential Workflow, where agents are executed in a sequence."),
@@ -179,6 +653,10 @@ fn main() {
         || (),
     );
     workflow.reliability_check();
+    match workflow.to_yaml() {
+        Ok(yaml) => println!("{}", yaml),
+        Err(e) => println!("not exportable: {}", e),
+    }
 
     // Run the workflow
     let task = String::from("Task1");
@@ -201,10 +679,18 @@ fn main() {
 
 ### Code Changes and Rationale:
 
-*   **Agent and AgentRearrange structs:** These structs are defined to match the Python code's Agent and AgentRearrange classes.
+*   **Agents and the AgentRearrange struct:** The workflow takes `Arc<dyn Agent>` like the other swarms, so `MockAgent`s and wrapped swarms can take a turn. Presets and `run_with` steps that call a model themselves read the agent's system prompt and model through `Agent::spec`; convert an `LlmAgent` with `.into()`. `AgentRearrange` is a local stand-in for the Python class and keeps only the agent names it needs for the flow.
+*   **`run_with`:** Runs the agents in order with a closure standing in for each agent's model call, and returns every agent's output rather than only the last. Presets such as `presets::accounting_swarm` use it to build their reports.
+*   **Retrying one turn:** `run_recorded` keeps each turn's input, output, and status in a `workflow_run::Run` instead of stopping with only the error. `retry_step` runs the failed turn again on the input it was sent and, with `Resume::Downstream`, continues with the turns after it, so the turns that had succeeded are not paid for twice. Recorded turns are not run under the supervisor.
+*   **Stuck turns:** With `with_supervisor`, `run_with` and `run_with_heartbeats` run each agent's turn under a `TaskHeartbeat`. A turn the supervisor finds silent for too long is run again (`StuckAction::Requeue`) or fails the run as cancelled; either way its late output is discarded. Only `run_with_heartbeats` steps can `beat`, so with `run_with` the expected duration bounds the whole turn.
+*   **Webhooks:** With `with_webhooks`, `run_with` and `run_recorded` emit `run_started`, one `agent_step_completed` per successful turn, and the event for how the run ended.
+*   **Notifications:** With `with_notifier`, `run_with` and `run_recorded` tell the notifier's sinks whether each run completed, failed, or exceeded its budget. Delivery is spawned on the current Tokio runtime, so it never slows or fails the run; without a runtime the event is dropped with a warning.
+*   **Run traces:** With `with_traces`, `run_with`, `run_recorded`, and `run_with_approvals` save a `RunTrace` of each run, one step per agent turn with the agent's system prompt as its context. A failed turn is recorded with its error as the output. The trace of a recorded run is rewritten after `retry_step`, and a gated run that resumes adds to the trace it saved before pausing. Token counts in the trace are estimated.
 *   **SequentialWorkflow struct:** This struct is defined to match the Python code's SequentialWorkflow class. The `reliability_check` method is implemented to raise a panic if the agents list is empty or if max_loops is 0.
 *   **SequentialWorkflow methods:** The `run`, `run_async`, `run_concurrent`, and `run_batched` methods are implemented to match the Python code's equivalent methods. However, the actual implementation details may vary depending on the specific requirements of the project.
 *   **Logger initialization:** The logger is initialized using `env_logger::init()` to match the Python code's logger initialization.
+*   **Citations:** `run_with_citations` runs the agents through their bound models like `Swarm::run` and returns the last agent's answer with the citations the task came with; `run_with_sources` renders them as a "Sources:" section. Steps that retrieve or call tools use `run_cited`, whose step returns a `Cited<String>`, and their citations are merged in after the task's.
+*   **Approval gates:** `add_gate` attaches an `ApprovalGate` after a named agent, and `run_with_approvals` pauses there until the output is approved. This replaces `human_in_the_loop` on the Python `AgentRearrange`. The checkpoint records the index of the next agent, so `resume` can pick up an interrupted run. A reviewer may edit the output while approving; the next agent receives the edited text, and `GatedRun::reviews` keeps the original and edited versions of each gate's output.
 *   **Main function:** The `main` function is defined to create a new `SequentialWorkflow`, run it, and print the result.
 
 Note that this is just one possible way to convert the Python code to Rust. The actual implementation details may vary depending on the specific requirements of the project and the desired level of compatibility with the original Python code.
\ No newline at end of file
@@ -27,6 +27,8 @@ use csv::{Reader, Writer};
 use serde::{Serialize, Deserialize};
 use tokio::prelude::*;
 use tokio::time::{sleep, Duration};
+use crate::swarms::structs::base_structure::BaseStructure;
+use crate::swarms::utils::id_generator::{new_id, IdKind};
 use uuid::Uuid;
 
 #[derive(Serialize, Deserialize, Default)]
@@ -60,9 +62,43 @@ struct SpreadSheetSwarm {
     max_loops: u32,
     workspace_dir: String,
     metadata: SwarmRunMetadata,
+    // Saves the run metadata and error log, and stamps the start and end times
+    #[serde(skip)]
+    base: BaseStructure,
 }
 
 impl SpreadSheetSwarm {
+    // Constructor for SpreadSheetSwarm; the base structure saves under `workspace_dir`, like TaskQueueSwarm's
+    fn new(
+        name: &str,
+        description: &str,
+        agents: Vec<String>,
+        save_file_path: &str,
+        autosave_on: bool,
+        max_loops: u32,
+        workspace_dir: &str,
+    ) -> Self {
+        let metadata = SwarmRunMetadata {
+            run_id: new_id(IdKind::Run),
+            name: name.to_string(),
+            description: description.to_string(),
+            agents: agents.clone(),
+            number_of_agents: agents.len() as u32,
+            ..Default::default()
+        };
+        SpreadSheetSwarm {
+            name: name.to_string(),
+            description: description.to_string(),
+            agents,
+            save_file_path: save_file_path.to_string(),
+            autosave_on,
+            max_loops,
+            workspace_dir: workspace_dir.to_string(),
+            metadata,
+            base: BaseStructure::in_workspace(name, description, workspace_dir),
+        }
+    }
+
     async fn reliability_check(&mut self) {
         // Check if no agents are provided or no save file path is provided
         if self.agents.is_empty() {
@@ -77,12 +113,12 @@ impl SpreadSheetSwarm {
     }
 
     async fn run(&mut self, task: &str) {
-        self.metadata.start_time = Uuid::new_v4().to_string();
+        self.metadata.start_time = self.base.current_timestamp();
 
         // Run the tasks concurrently
         self._run_tasks(task).await;
 
-        self.metadata.end_time = Uuid::new_v4().to_string();
+        self.metadata.end_time = self.base.current_timestamp();
 
         // Save metadata to CSV and JSON
         self._save_metadata().await;
@@ -126,7 +162,7 @@ impl SpreadSheetSwarm {
             agent_name: String::from("Agent"),
             task: String::from("Task"),
             result,
-            timestamp: Uuid::new_v4().to_string(),
+            timestamp: self.base.current_timestamp(),
         });
     }
 
@@ -134,15 +170,14 @@ impl SpreadSheetSwarm {
         serde_json::to_string(self).unwrap()
     }
 
+    // Save the run metadata through the base structure; a failed save goes to its error log
     async fn data_to_json_file(&mut self) {
-        let out = self.export_to_json().await;
-
-        let mut file = tokio::fs::File::create(self.save_file_path.clone())
-            .await
-            .unwrap();
-        tokio::io::write_all(&mut file, out.as_bytes())
-            .await
-            .unwrap();
+        if let Err(err) = self.base.save_metadata(&self.metadata) {
+            log::error!("Failed to save metadata for {}: {}", self.name, err);
+            if let Err(log_err) = self.base.log_error(&err) {
+                log::warn!("Could not log an error of run {}: {}", self.metadata.run_id, log_err);
+            }
+        }
     }
 
     async fn _save_metadata(&mut self) {
@@ -186,19 +221,15 @@ impl SpreadSheetSwarm {
 
 #[tokio::main]
 async fn main() {
-    let mut swarm = SpreadSheetSwarm {
-        name: String::from("Spreadsheet-Swarm"),
-        description: String::from("A swarm that processes tasks concurrently using multiple agents"),
-        agents: vec![String::from("Agent1"), String::from("Agent2")],
-        save_file_path: String::from("spreadsheet_swarm.csv"),
-        autosave_on: true,
-        max_loops: 2,
-        workspace_dir: String::from("/path/to/workspace"),
-        metadata: SwarmRunMetadata {
-            run_id: Uuid::new_v4().to_string(),
-            ..Default::default()
-        },
-    };
+    let mut swarm = SpreadSheetSwarm::new(
+        "Spreadsheet-Swarm",
+        "A swarm that processes tasks concurrently using multiple agents",
+        vec![String::from("Agent1"), String::from("Agent2")],
+        "spreadsheet_swarm.csv",
+        true,
+        2,
+        "/path/to/workspace",
+    );
 
     swarm.reliability_check().await;
 
@@ -210,7 +241,11 @@ async fn main() {
 *   Tokio library is used to achieve concurrency in the Rust code. This is equivalent to using the asyncio library in the Python code.
 *   The csv library in Rust is used to handle CSV files. This is equivalent to using the csv library in Python.
 *   The serde library is used to handle JSON serialization and deserialization in the Rust code. This is equivalent to using the json library in Python.
-*   Uuid library is used to generate unique IDs in the Rust code. This is equivalent to using the uuid library in Python.
+*   `new` builds the embedded `BaseStructure` with `in_workspace`, as `TaskQueueSwarm` does, so run metadata is saved to
+    `<workspace_dir>/<name>_metadata.json` and failures to save are appended to `<workspace_dir>/errors`; the CSV still goes
+    to `save_file_path`.
+*   Start, end, and output timestamps come from the base structure's clock.
+*   The run id comes from `id_generator::new_id`, so runs sort by start time; the uuid crate still fills the remaining placeholders.
 *   `sleep(Duration::from_millis(100)).await;` is used to simulate a blocking call in the `_run_agent_task` method. You should replace this with your actual blocking call.
 *   The `reliability_check` method is implemented using the `panic!` macro. You may want to handle errors in a more robust way depending on your use case.
 *   The `data_to_json_file` method is implemented using `tokio::fs::File::create` and `tokio::io::write_all`. This is equivalent to using `create_file_in_folder` in the Python code.
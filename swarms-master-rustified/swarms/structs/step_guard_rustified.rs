@@ -0,0 +1,160 @@
+### Overview
+There is no Python counterpart; in Python an exception raised by a tool or a user hook propagates to the
+agent loop, which logs it and moves on. In Rust the equivalent failure is often a panic (an `unwrap` in a
+user-supplied closure, an out-of-bounds index in a hook). Left alone, the panic unwinds through the worker
+and takes the whole swarm run with it.
+
+`guard_step` runs one agent step or tool call under `catch_unwind` and returns a `StepRecord` for the run
+metadata. A panic becomes `StepStatus::Panicked` with its message, and the swarm carries on with the next
+step. `guard_step_async` does the same for futures.
+
+Tool bodies get this automatically: `ToolSpec::call` turns a panic into a `ToolExecutionError`.
+
+### Rust Code
+```rust
+use crate::swarms::structs::failure::{Classify, Failure, FailureCode};
+use crate::swarms::structs::scoped_tasks::panic_message;
+use futures::FutureExt;
+use log::error;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::time::Instant;
+
+/// How one step ended.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum StepStatus {
+    #[default]
+    Succeeded,
+    /// The step returned an error, classified so callers can decide whether to retry it.
+    Failed {
+        error: String,
+        #[serde(default)]
+        code: FailureCode,
+        #[serde(default)]
+        retriable: bool,
+    },
+    /// The step panicked; the panic was contained.
+    Panicked { message: String },
+}
+
+impl StepStatus {
+    pub fn is_success(&self) -> bool {
+        matches!(self, StepStatus::Succeeded)
+    }
+
+    /// `Failed` with `code`'s default `retriable` flag.
+    pub fn failed(code: FailureCode, error: impl Into<String>) -> Self {
+        StepStatus::Failed { error: error.into(), code, retriable: code.retriable_by_default() }
+    }
+
+    /// `Failed`, classified from `err`.
+    pub fn failed_with(err: &(impl Classify + ?Sized)) -> Self {
+        StepStatus::Failed { error: err.to_string(), code: err.failure_code(), retriable: err.retriable() }
+    }
+
+    /// The failure code; `None` for a step that succeeded.
+    pub fn code(&self) -> Option<FailureCode> {
+        match self {
+            StepStatus::Succeeded => None,
+            StepStatus::Failed { code, .. } => Some(*code),
+            StepStatus::Panicked { .. } => Some(FailureCode::Panicked),
+        }
+    }
+
+    /// Whether running the step again can succeed. A panic never is.
+    pub fn is_retriable(&self) -> bool {
+        matches!(self, StepStatus::Failed { retriable: true, .. })
+    }
+}
+
+impl From<Failure> for StepStatus {
+    fn from(failure: Failure) -> Self {
+        StepStatus::Failed { error: failure.message, code: failure.code, retriable: failure.retriable }
+    }
+}
+
+/// One step in the run metadata.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepRecord {
+    pub agent_name: String,
+    /// What ran: the task, or `tool:<name>`.
+    pub step: String,
+    #[serde(flatten)]
+    pub status: StepStatus,
+    pub duration_ms: u64,
+}
+
+fn record(agent_name: &str, step: &str, status: StepStatus, started: Instant) -> StepRecord {
+    if let StepStatus::Panicked { message } = &status {
+        error!("Agent {} panicked during step '{}': {}", agent_name, step, message);
+    }
+    StepRecord {
+        agent_name: agent_name.to_string(),
+        step: step.to_string(),
+        status,
+        duration_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+/// Run `f` as one step of `agent_name`, containing any panic.
+pub fn guard_step<R>(agent_name: &str, step: &str, f: impl FnOnce() -> R) -> (Option<R>, StepRecord) {
+    let started = Instant::now();
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(value) => (Some(value), record(agent_name, step, StepStatus::Succeeded, started)),
+        Err(payload) => {
+            let status = StepStatus::Panicked { message: panic_message(&*payload) };
+            (None, record(agent_name, step, status, started))
+        }
+    }
+}
+
+/// `guard_step` for fallible steps: an `Err` is recorded as `Failed`, with its `Classify` code.
+pub fn guard_fallible_step<R, E: Classify>(
+    agent_name: &str,
+    step: &str,
+    f: impl FnOnce() -> Result<R, E>,
+) -> (Option<R>, StepRecord) {
+    let (outcome, mut step_record) = guard_step(agent_name, step, f);
+    match outcome {
+        Some(Ok(value)) => (Some(value), step_record),
+        Some(Err(err)) => {
+            step_record.status = StepStatus::failed_with(&err);
+            (None, step_record)
+        }
+        None => (None, step_record),
+    }
+}
+
+/// Await `future` as one step of `agent_name`, containing any panic raised while polling it.
+pub async fn guard_step_async<R>(agent_name: &str, step: &str, future: impl Future<Output = R>) -> (Option<R>, StepRecord) {
+    let started = Instant::now();
+    match AssertUnwindSafe(future).catch_unwind().await {
+        Ok(value) => (Some(value), record(agent_name, step, StepStatus::Succeeded, started)),
+        Err(payload) => {
+            let status = StepStatus::Panicked { message: panic_message(&*payload) };
+            (None, record(agent_name, step, status, started))
+        }
+    }
+}
+
+fn main() {
+    let steps = ["summarize filing", "extract tables"];
+    for step in steps {
+        let (output, record) = guard_step("Financial-Analyst", step, || {
+            if step == "extract tables" {
+                let rows: Vec<&str> = Vec::new();
+                return rows[0].to_string();
+            }
+            format!("done: {}", step)
+        });
+        println!("{:?} {}", output, serde_json::to_string(&record).unwrap());
+    }
+}
+```
+
+### Notes
+* Panics are still printed by the default panic hook; only the unwinding is contained.
+* Builds with `panic = "abort"` cannot contain panics. Steps then abort the process as before.
+* State the step was mutating may be half-updated after a panic, which is why the guards require `AssertUnwindSafe`. Callers should discard the step's partial output rather than reuse it.
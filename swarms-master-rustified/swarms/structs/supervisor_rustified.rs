@@ -0,0 +1,337 @@
+### Overview
+There is no Python counterpart; a Python agent that hangs on a provider call or a tool holds its workflow
+forever, and nothing reports it. `Supervisor` watches in-flight tasks instead. A worker registers each task
+with `begin` and keeps the returned `TaskHeartbeat` while it runs, calling `beat()` whenever it makes
+progress (a streamed token, a tool call, a finished loop). A task that goes longer than its expected
+duration without a heartbeat is stuck: `check` (or the background `Watchdog`) marks it, and then
+
+* raises a `TaskStuck` event through `RunCallbacks::on_task_stuck` and counts it in
+  `swarms_tasks_stuck_total`;
+* cancels the attempt, which the worker sees through `TaskHeartbeat::is_cancelled` and `stuck`;
+* tells the worker to run the task again (`StuckAction::Requeue`) or give it up (`StuckAction::Cancel`).
+  Requeued tasks get `max_attempts` attempts in all, after which they are cancelled.
+
+`SequentialWorkflow::with_supervisor` runs each agent's turn under a heartbeat, and `TaskQueueSwarm`
+does the same for each task it dispatches. The distributed queue has its own equivalent: a worker that stops
+renewing its lease loses the task, which the coordinator queues again.
+
+### Rust Code
+```rust
+use crate::swarms::structs::callbacks::RunCallbacks;
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// How long a task may go without a heartbeat unless its agent has its own limit.
+pub const DEFAULT_EXPECTED_DURATION: Duration = Duration::from_secs(300);
+
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 2;
+
+/// What happens to a stuck task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StuckAction {
+    /// Give the task up; the run reports it as cancelled.
+    #[default]
+    Cancel,
+    /// Cancel this attempt and run the task again.
+    Requeue,
+}
+
+impl StuckAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StuckAction::Cancel => "cancel",
+            StuckAction::Requeue => "requeue",
+        }
+    }
+}
+
+impl fmt::Display for StuckAction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// A task that went longer than expected without a heartbeat.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TaskStuck {
+    pub task_id: String,
+    pub swarm: String,
+    pub agent: String,
+    pub task: String,
+    /// One-based.
+    pub attempt: u32,
+    pub running_ms: u64,
+    /// Time since the last heartbeat, or since the task began if it never sent one.
+    pub silent_ms: u64,
+    pub action: StuckAction,
+}
+
+impl fmt::Display for TaskStuck {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} task {} (attempt {}) sent no heartbeat for {} ms; {}",
+            self.agent, self.task_id, self.attempt, self.silent_ms, self.action
+        )
+    }
+}
+
+struct Tracked {
+    task_id: String,
+    agent: String,
+    task: String,
+    attempt: u32,
+    expected: Duration,
+    started: Instant,
+    last_beat: Mutex<Instant>,
+    cancelled: AtomicBool,
+    stuck: Mutex<Option<StuckAction>>,
+}
+
+/// One in-flight task's link to its supervisor. Dropping it ends the task's supervision.
+pub struct TaskHeartbeat {
+    tracked: Arc<Tracked>,
+}
+
+impl TaskHeartbeat {
+    /// A heartbeat no supervisor watches, for running supervised code without one. It is never stuck.
+    pub fn detached(agent: &str, task: &str) -> Self {
+        TaskHeartbeat { tracked: Arc::new(Tracked::new(agent, task, 1, Duration::MAX)) }
+    }
+
+    /// Report progress, pushing the task's stuck deadline back.
+    pub fn beat(&self) {
+        *self.tracked.last_beat.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Instant::now();
+    }
+
+    /// Whether the supervisor has cancelled this attempt. Long steps should check it between calls and stop.
+    pub fn is_cancelled(&self) -> bool {
+        self.tracked.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// What the supervisor decided, once the task was found stuck.
+    pub fn stuck(&self) -> Option<StuckAction> {
+        *self.tracked.stuck.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    pub fn task_id(&self) -> &str {
+        &self.tracked.task_id
+    }
+
+    pub fn attempt(&self) -> u32 {
+        self.tracked.attempt
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        self.tracked.started.elapsed()
+    }
+}
+
+impl Tracked {
+    fn new(agent: &str, task: &str, attempt: u32, expected: Duration) -> Self {
+        let now = Instant::now();
+        Tracked {
+            task_id: new_id(IdKind::Task),
+            agent: agent.to_string(),
+            task: task.to_string(),
+            attempt,
+            expected,
+            started: now,
+            last_beat: Mutex::new(now),
+            cancelled: AtomicBool::new(false),
+            stuck: Mutex::new(None),
+        }
+    }
+}
+
+/// Finds in-flight tasks that stopped sending heartbeats, and cancels or requeues them.
+pub struct Supervisor {
+    swarm: String,
+    expected: Duration,
+    agent_expected: HashMap<String, Duration>,
+    action: StuckAction,
+    max_attempts: u32,
+    callbacks: Option<Arc<dyn RunCallbacks>>,
+    tasks: Mutex<Vec<Weak<Tracked>>>,
+}
+
+impl Supervisor {
+    /// A supervisor for the swarm named `swarm` that cancels tasks silent for `DEFAULT_EXPECTED_DURATION`.
+    pub fn new(swarm: &str) -> Self {
+        Supervisor {
+            swarm: swarm.to_string(),
+            expected: DEFAULT_EXPECTED_DURATION,
+            agent_expected: HashMap::new(),
+            action: StuckAction::default(),
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            callbacks: None,
+            tasks: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Treat a task as stuck after `expected` without a heartbeat.
+    pub fn with_expected_duration(mut self, expected: Duration) -> Self {
+        self.expected = expected;
+        self
+    }
+
+    /// Give `agent`'s tasks their own limit, for agents whose steps are known to be slow or fast.
+    pub fn with_agent_expected_duration(mut self, agent: &str, expected: Duration) -> Self {
+        self.agent_expected.insert(agent.to_string(), expected);
+        self
+    }
+
+    pub fn with_action(mut self, action: StuckAction) -> Self {
+        self.action = action;
+        self
+    }
+
+    /// Run a requeued task at most `attempts` times in all.
+    pub fn with_max_attempts(mut self, attempts: u32) -> Self {
+        self.max_attempts = attempts.max(1);
+        self
+    }
+
+    /// Report `TaskStuck` events to `callbacks`.
+    pub fn with_callbacks(mut self, callbacks: Arc<dyn RunCallbacks>) -> Self {
+        self.callbacks = Some(callbacks);
+        self
+    }
+
+    pub fn name(&self) -> &str {
+        &self.swarm
+    }
+
+    /// Start supervising one attempt (one-based) at `task` by `agent`.
+    pub fn begin(&self, agent: &str, task: &str, attempt: u32) -> TaskHeartbeat {
+        let expected = self.agent_expected.get(agent).copied().unwrap_or(self.expected);
+        let tracked = Arc::new(Tracked::new(agent, task, attempt, expected));
+        let mut tasks = self.tasks.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        tasks.retain(|task| task.strong_count() > 0);
+        tasks.push(Arc::downgrade(&tracked));
+        TaskHeartbeat { tracked }
+    }
+
+    /// Tasks being supervised right now.
+    pub fn in_flight(&self) -> usize {
+        let tasks = self.tasks.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        tasks.iter().filter(|task| task.strong_count() > 0).count()
+    }
+
+    /// Mark every task silent for longer than its expected duration as of `now` as stuck, cancel it, and
+    /// raise its `TaskStuck` event. A task is reported once.
+    pub fn check(&self, now: Instant) -> Vec<TaskStuck> {
+        let live: Vec<Arc<Tracked>> = {
+            let mut tasks = self.tasks.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            tasks.retain(|task| task.strong_count() > 0);
+            tasks.iter().filter_map(Weak::upgrade).collect()
+        };
+        let mut events = Vec::new();
+        for tracked in live {
+            let last_beat = *tracked.last_beat.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            let silent = now.saturating_duration_since(last_beat);
+            if silent <= tracked.expected {
+                continue;
+            }
+            let mut stuck = tracked.stuck.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+            if stuck.is_some() {
+                continue;
+            }
+            let action = match self.action {
+                StuckAction::Requeue if tracked.attempt < self.max_attempts => StuckAction::Requeue,
+                _ => StuckAction::Cancel,
+            };
+            *stuck = Some(action);
+            tracked.cancelled.store(true, Ordering::SeqCst);
+            drop(stuck);
+
+            let event = TaskStuck {
+                task_id: tracked.task_id.clone(),
+                swarm: self.swarm.clone(),
+                agent: tracked.agent.clone(),
+                task: tracked.task.clone(),
+                attempt: tracked.attempt,
+                running_ms: now.saturating_duration_since(tracked.started).as_millis() as u64,
+                silent_ms: silent.as_millis() as u64,
+                action,
+            };
+            warn!("{}: {}", self.swarm, event);
+            SwarmMetrics::global().task_stuck(&self.swarm, &event.agent, action.as_str());
+            if let Some(callbacks) = &self.callbacks {
+                callbacks.on_task_stuck(&event);
+            }
+            events.push(event);
+        }
+        events
+    }
+
+    /// Run `check` every `interval` on a background thread until the returned `Watchdog` is dropped.
+    pub fn watch(self: &Arc<Self>, interval: Duration) -> Watchdog {
+        let (stop, stopped) = mpsc::channel::<()>();
+        let supervisor = Arc::clone(self);
+        let thread = thread::spawn(move || loop {
+            match stopped.recv_timeout(interval) {
+                Err(RecvTimeoutError::Timeout) => {
+                    supervisor.check(Instant::now());
+                }
+                _ => break,
+            }
+        });
+        info!("Watching {} for stuck tasks every {:?}", self.swarm, interval);
+        Watchdog { stop: Some(stop), thread: Some(thread) }
+    }
+}
+
+/// The background thread started by `Supervisor::watch`. Dropping it stops the thread.
+pub struct Watchdog {
+    stop: Option<mpsc::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Drop for Watchdog {
+    fn drop(&mut self) {
+        drop(self.stop.take());
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+fn main() {
+    let supervisor = Arc::new(
+        Supervisor::new("Research-Pipeline").with_expected_duration(Duration::from_millis(50)).with_action(StuckAction::Requeue),
+    );
+    let _watchdog = supervisor.watch(Duration::from_millis(10));
+
+    let heartbeat = supervisor.begin("Researcher", "Collect filings", 1);
+    for _ in 0..5 {
+        thread::sleep(Duration::from_millis(20));
+        heartbeat.beat();
+    }
+    println!("healthy task stuck: {:?}", heartbeat.stuck());
+
+    let hung = supervisor.begin("Researcher", "Collect filings", 1);
+    thread::sleep(Duration::from_millis(120));
+    println!("hung task stuck: {:?}, cancelled: {}", hung.stuck(), hung.is_cancelled());
+}
+```
+
+### Notes
+* Cancellation is cooperative. A step blocked inside a call that never returns keeps its thread; the
+  supervisor reports it and the workflow moves on once the step returns or checks `is_cancelled`.
+  Give provider and tool calls their own timeouts as well.
+* A stuck attempt's late result is discarded, even if it succeeded, because the event has already told
+  listeners the attempt was cancelled. `TaskQueueSwarm` runs each supervised task on a thread of its own and
+  stops waiting for it as soon as it is found stuck, so its worker is not held by the abandoned attempt; a
+  result that is in before then is kept.
+* `check` takes `now` so tests can move time forward; the watchdog passes `Instant::now()`.
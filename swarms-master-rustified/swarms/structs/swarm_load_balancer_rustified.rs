@@ -10,17 +10,10 @@ use std::time::Duration;
 use rand::Rng;
 use rand::thread_rng;
 
-// Import required custom modules (assuming they're implemented in Rust similarly)
-// Note: Implementations of Agent, BaseSwarm, and their related functions are not provided here
-// as they are considered part of a broader system and might have complex implementations.
-mod structs;
-use structs::{Agent, BaseSwarm};
-
-mod utils;
-use utils::logger;
+use crate::swarms::structs::agent::{Agent, AgentError, MockAgent};
 
 struct AgentLoadBalancer {
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
     agent_status: Arc<Mutex<HashMap<String, bool>>>,
     max_retries: u32,
     max_loops: u32,
@@ -29,15 +22,15 @@ struct AgentLoadBalancer {
 }
 
 impl AgentLoadBalancer {
-    fn new(agents: Vec<Agent>, max_retries: u32, max_loops: u32, cooldown_time: f64) -> Self {
+    fn new(agents: Vec<Arc<dyn Agent>>, max_retries: u32, max_loops: u32, cooldown_time: f64) -> Self {
         // Initialize agent status and performance
         let mut agent_status = HashMap::new();
         let mut agent_performance = HashMap::new();
         
         for agent in &agents {
-            agent_status.insert(agent.agent_name.clone(), true);
+            agent_status.insert(agent.name().to_string(), true);
             agent_performance.insert(
-                agent.agent_name.clone(),
+                agent.name().to_string(),
                 HashMap::from([
                     ("success_count".to_string(), 0),
                     ("failure_count".to_string(), 0),
@@ -55,13 +48,13 @@ impl AgentLoadBalancer {
         }
     }
 
-    fn get_available_agent(&self) -> Option<&Agent> {
+    fn get_available_agent(&self) -> Option<&Arc<dyn Agent>> {
         // Acquire lock to ensure thread safety
         let agent_status = self.agent_status.lock().unwrap();
-        let available_agents: Vec<&Agent> = self
+        let available_agents: Vec<&Arc<dyn Agent>> = self
             .agents
             .iter()
-            .filter(|agent| *agent_status.get(&agent.agent_name).unwrap())
+            .filter(|agent| agent_status.get(agent.name()).copied().unwrap_or(false))
             .collect();
         
         if available_agents.is_empty() {
@@ -72,22 +65,22 @@ impl AgentLoadBalancer {
         }
     }
 
-    fn set_agent_status(&self, agent: &Agent, status: bool) {
+    fn set_agent_status(&self, agent: &dyn Agent, status: bool) {
         let mut agent_status = self.agent_status.lock().unwrap();
-        agent_status.insert(agent.agent_name.clone(), status);
+        agent_status.insert(agent.name().to_string(), status);
     }
 
-    fn update_performance(&self, agent: &Agent, success: bool) {
+    fn update_performance(&self, agent: &dyn Agent, success: bool) {
         let mut agent_performance = self.agent_performance.lock().unwrap();
         if success {
             *agent_performance
-                .get_mut(&agent.agent_name)
+                .get_mut(agent.name())
                 .unwrap()
                 .get_mut("success_count")
                 .unwrap() += 1;
         } else {
             *agent_performance
-                .get_mut(&agent.agent_name)
+                .get_mut(agent.name())
                 .unwrap()
                 .get_mut("failure_count")
                 .unwrap() += 1;
@@ -103,15 +96,21 @@ impl AgentLoadBalancer {
         }
     }
 
-    fn run(&self, task: &str) -> String {
+    // Run `task` on an available agent. A failed run counts against the agent and is retried on another
+    // pick, up to `max_retries` times.
+    fn run(&self, task: &str) -> Result<String, AgentError> {
         let mut retries = 0;
         loop {
-            if let Some(agent) = self.get_available_agent() {
-                self.set_agent_status(agent, false);
+            if let Some(agent) = self.get_available_agent().cloned() {
+                self.set_agent_status(agent.as_ref(), false);
                 let output = agent.run(task);
-                self.update_performance(agent, true);
-                self.set_agent_status(agent, true);
-                return output;
+                self.update_performance(agent.as_ref(), output.is_ok());
+                self.set_agent_status(agent.as_ref(), true);
+                match output {
+                    Ok(output) => return Ok(output),
+                    Err(err) if retries >= self.max_retries => return Err(err),
+                    Err(_) => retries += 1,
+                }
             } else {
                 retries += 1;
                 if retries > self.max_retries {
@@ -127,11 +126,12 @@ impl AgentLoadBalancer {
 }
 
 fn main() {
-    // Example usage, assuming Agent and related functions are implemented
-    let agents = vec![Agent::new("Agent1".to_string())]; // Placeholder for actual Agent creation
+    let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("Agent1").reply("Done"))];
     let load_balancer = AgentLoadBalancer::new(agents, 3, 5, 0.0);
-    let result = load_balancer.run("Example Task");
-    println!("{}", result);
+    match load_balancer.run("Example Task") {
+        Ok(result) => println!("{}", result),
+        Err(err) => println!("{}", err),
+    }
 }
 ```
 
@@ -140,7 +140,7 @@ fn main() {
 2. **Locking:** Instead of Python's `threading.Lock`, Rust uses `std::sync::Mutex` (or `RwLock` for more complex scenarios) for ensuring thread safety.
 3. **Random Choice:** The `random` module in Python is equivalent to using `rand::thread_rng().gen_range` in Rust for generating a random choice among available agents.
 4. **Error Handling:** Rust emphasizes explicit error handling, which might require wrapping Python exceptions into Rust `Result` or custom error types. This example uses `panic!` for simplicity but should be replaced with proper error handling in production code.
-5. **Agent and BaseSwarm Implementations:** These are assumed to be implemented elsewhere in the Rust codebase. Their exact implementation would depend on the requirements and how they interact with the load balancer.
+5. **Agents:** The balancer takes the crate's `Arc<dyn Agent>` (`swarms::structs::agent`) and tracks each agent by `name()`. A failed run is recorded in `failure_count` and retried on another pick.
 6. **Logger Integration:** The logging mechanism is simplified. For a real application, consider integrating with a logging framework like `log4rs` or `tracing`.
 
 ### Limitations and Challenges:
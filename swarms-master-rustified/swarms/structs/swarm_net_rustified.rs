@@ -19,36 +19,18 @@ use std::thread;
 use tokio::prelude::*;
 use tokio::runtime::Builder;
 use tokio::sync::oneshot;
+use crate::swarms::structs::agent::{Agent, MockAgent};
 
-// Define a struct to represent an agent
-#[derive(Debug)]
-struct Agent {
-    id: String,
-    agent_name: String,
-    agent_description: String,
-}
-
-impl Agent {
-    fn new(id: String, agent_name: String, agent_description: String) -> Self {
-        Agent {
-            id,
-            agent_name,
-            agent_description,
-        }
-    }
-}
-
-// Define a struct to represent the swarm network
-#[derive(Debug)]
+// Define a struct to represent the swarm network; members are identified by name
 struct SwarmNetwork {
     name: String,
     description: String,
-    agents: Vec<Agent>,
+    agents: Vec<Arc<dyn Agent>>,
     task_queue: Arc<Mutex<Vec<String>>>,
 }
 
 impl SwarmNetwork {
-    fn new(name: String, description: String, agents: Vec<Agent>) -> Self {
+    fn new(name: String, description: String, agents: Vec<Arc<dyn Agent>>) -> Self {
         SwarmNetwork {
             name,
             description,
@@ -68,20 +50,17 @@ impl SwarmNetwork {
         });
     }
 
-    fn run_single_agent(&self, agent_id: &str, task: &str) -> Result<String, String> {
-        for agent in &self.agents {
-            if agent.id == *agent_id {
-                // Simulate running the task on the agent
-                return Ok(format!("Task {} completed on agent {}", task, agent_id));
-            }
+    fn run_single_agent(&self, agent_name: &str, task: &str) -> Result<String, String> {
+        match self.agents.iter().find(|agent| agent.name() == agent_name) {
+            Some(agent) => agent.run(task).map_err(|err| err.to_string()),
+            None => Err(format!("Agent {} not found", agent_name)),
         }
-        Err(format!("Agent {} not found", agent_id))
     }
 
     fn run_many_agents(&self, task: &str) -> Result<Vec<String>, String> {
         let mut results = Vec::new();
         for agent in &self.agents {
-            let result = self.run_single_agent(&agent.id, task);
+            let result = self.run_single_agent(agent.name(), task);
             match result {
                 Ok(result) => results.push(result),
                 Err(err) => return Err(err),
@@ -94,11 +73,8 @@ impl SwarmNetwork {
 // Define a main function to test the swarm network
 #[tokio::main]
 async fn main() {
-    let agent1 = Agent::new(
-        "agent1".to_string(),
-        "Covid-19-Chat".to_string(),
-        "This agent provides information about COVID-19 symptoms.".to_string(),
-    );
+    let agent1: Arc<dyn Agent> =
+        Arc::new(MockAgent::new("Covid-19-Chat").reply("Common symptoms include fever, cough, and fatigue."));
 
     let agents = vec![agent1];
     let swarm_name = "HealthSwarm".to_string();
@@ -111,7 +87,7 @@ async fn main() {
 
     async_add_task(&swarm, "Task 3".to_string()).await;
 
-    let result = swarm.run_single_agent("agent1", "Task 1");
+    let result = swarm.run_single_agent("Covid-19-Chat", "Task 1");
     match result {
         Ok(result) => println!("{}", result),
         Err(err) => println!("{}", err),
@@ -131,6 +107,7 @@ async fn main() {
 2.  **Use Rust libraries:** Instead of trying to find direct equivalents of Python libraries, explore Rust's ecosystem and use libraries that provide similar functionality.
 3.  **Adapt to Rust's ownership system:** Rust's ownership system is designed to prevent common programming errors like null pointer dereferences. Take the time to understand how it works and how to use it effectively.
 4.  **Use async/await:** Rust's `async-std` and `tokio` libraries provide a high-level interface for asynchronous programming. Use them to simplify your code and improve performance.
-5.  **Test thoroughly:** Conversion can introduce bugs. Make sure to test your code thoroughly to ensure it works as expected.
+5.  **Agents:** Members are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`) and are looked up by `name()`, which stands in for the Python agent `id`.
+6.  **Test thoroughly:** Conversion can introduce bugs. Make sure to test your code thoroughly to ensure it works as expected.
 
 By following these guidelines, you can successfully convert the provided Python code to Rust and take advantage of Rust's performance, reliability, and maintainability features.
\ No newline at end of file
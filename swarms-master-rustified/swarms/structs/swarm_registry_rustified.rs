@@ -24,18 +24,18 @@ use std::fmt;
 // Define a SwarmRegistry struct with a Vec to store swarms
 #[derive(Default)]
 struct SwarmRegistry {
-    swarm_pool: Vec<Box<dyn Fn()>>,
+    swarm_pool: Vec<Box<dyn Fn() + Send + Sync>>,
 }
 
 // Implement methods for SwarmRegistry
 impl SwarmRegistry {
     // Add a swarm to the registry
-    fn add(&mut self, swarm: Box<dyn Fn()>) {
+    fn add(&mut self, swarm: Box<dyn Fn() + Send + Sync>) {
         self.swarm_pool.push(swarm);
     }
 
     // Query the registry for a swarm by name
-    fn query(&self, swarm_name: &str) -> Option<&Box<dyn Fn()>> {
+    fn query(&self, swarm_name: &str) -> Option<&Box<dyn Fn() + Send + Sync>> {
         self.swarm_pool.iter().find(|swarm| {
             // Here we assume a custom trait Swarm with a name method
             // and implement it for the swarm type
@@ -0,0 +1,166 @@
+### Overview
+The common interface of every multi-agent structure. Python gets this from duck typing: anything with a
+`run(task)` method can be handed to `SwarmRouter` or `SwarmRearrange`. The Rust conversions each declared
+a private `trait Swarm` of their own with a different signature, so no swarm could be passed to another
+module. `Swarm` is the shared version, and `SwarmError` is the error every implementation returns.
+
+`SwarmAgent` wraps a swarm as an `Agent`, so a whole swarm can vote in a `MajorityVoting` or take a turn
+in a `RoundRobinSwarm` like any single agent.
+
+### Rust Code
+```rust
+use crate::swarms::agents::create_agents_from_yaml::{YAMLConfig, YamlExportError};
+use crate::swarms::structs::agent::{Agent, AgentError};
+use crate::swarms::structs::callbacks::RunCallbacks;
+use crate::swarms::structs::dry_run::{CostEstimator, DryRun, PlannedCall};
+use crate::swarms::structs::scoped_tasks::TaskFailures;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+#[derive(Debug)]
+pub enum SwarmError {
+    /// The swarm was run without any agents.
+    NoAgents,
+    /// One or more agents panicked during a fan-out.
+    Tasks(TaskFailures),
+    /// The swarm failed for any other reason.
+    Failed(String),
+}
+
+impl fmt::Display for SwarmError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SwarmError::NoAgents => write!(f, "no agents configured for the swarm"),
+            SwarmError::Tasks(failures) => write!(f, "{}", failures),
+            SwarmError::Failed(message) => write!(f, "swarm failed: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for SwarmError {}
+
+impl From<TaskFailures> for SwarmError {
+    fn from(failures: TaskFailures) -> Self {
+        SwarmError::Tasks(failures)
+    }
+}
+
+/// A multi-agent structure that turns one task into one answer.
+pub trait Swarm: Send {
+    fn name(&self) -> &str;
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError>;
+
+    /// Run each task in order, stopping at the first failure.
+    fn run_batch(&mut self, tasks: &[String]) -> Result<Vec<String>, SwarmError> {
+        tasks.iter().map(|task| self.run(task)).collect()
+    }
+
+    /// Report each agent's turn to `callbacks` as it finishes. Fails for swarms that do not support callbacks,
+    /// rather than dropping the registration unnoticed.
+    fn set_callbacks(&mut self, _callbacks: Arc<dyn RunCallbacks>) -> Result<(), SwarmError> {
+        Err(SwarmError::Failed(format!("{} does not support run callbacks", self.name())))
+    }
+
+    /// The model calls a run of `task` would make, in order, without making them. `None` for swarms whose
+    /// calls are only decided while running. None by default.
+    fn planned_calls(&self, _task: &str) -> Option<Vec<PlannedCall>> {
+        None
+    }
+
+    /// Estimate the tokens and cost of running `task` without calling any provider. Displays as the
+    /// planned execution graph.
+    fn dry_run(&self, task: &str) -> Result<DryRun, SwarmError> {
+        self.dry_run_with(task, &CostEstimator::default())
+    }
+
+    /// `dry_run` with prices and usage history.
+    fn dry_run_with(&self, task: &str, estimator: &CostEstimator) -> Result<DryRun, SwarmError> {
+        let calls = self
+            .planned_calls(task)
+            .ok_or_else(|| SwarmError::Failed(format!("{} cannot plan its calls before a run", self.name())))?;
+        if calls.is_empty() {
+            return Err(SwarmError::NoAgents);
+        }
+        Ok(estimator.estimate(self.name(), task, &calls))
+    }
+
+    /// The config that rebuilds this swarm, or `None` for swarms with no config form. None by default.
+    fn to_config(&self) -> Option<YAMLConfig> {
+        None
+    }
+
+    /// The swarm as a YAML config that both `load_yaml_safely` and the Python `create_agents_from_yaml`
+    /// load. Fails for swarms without `to_config` and for configs either loader would reject.
+    fn to_yaml(&self) -> Result<String, YamlExportError> {
+        self.to_config().ok_or_else(|| YamlExportError::Unsupported(self.name().to_string()))?.to_yaml()
+    }
+}
+
+/// A swarm run as a single agent. It answers under the swarm's name.
+pub struct SwarmAgent {
+    name: String,
+    capabilities: Vec<String>,
+    // `Swarm::run` takes `&mut self`; runs through one adapter are serialized
+    swarm: Mutex<Box<dyn Swarm>>,
+}
+
+impl SwarmAgent {
+    pub fn new(swarm: impl Swarm + 'static) -> Self {
+        SwarmAgent { name: swarm.name().to_string(), capabilities: Vec::new(), swarm: Mutex::new(Box::new(swarm)) }
+    }
+
+    pub fn capability(mut self, capability: impl Into<String>) -> Self {
+        self.capabilities.push(capability.into());
+        self
+    }
+
+    pub fn into_agent(self) -> Arc<dyn Agent> {
+        Arc::new(self)
+    }
+}
+
+impl Agent for SwarmAgent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn capabilities(&self) -> &[String] {
+        &self.capabilities
+    }
+
+    fn run(&self, task: &str) -> Result<String, AgentError> {
+        // A panic inside an earlier run leaves the lock poisoned; the swarm itself is still usable
+        let mut swarm = self.swarm.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        swarm.run(task).map_err(|err| AgentError::Failed { agent: self.name.clone(), message: err.to_string() })
+    }
+}
+
+struct Echo;
+
+impl Swarm for Echo {
+    fn name(&self) -> &str {
+        "echo"
+    }
+
+    fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+        Ok(task.to_string())
+    }
+}
+
+fn main() {
+    let mut swarms: Vec<Box<dyn Swarm>> = vec![Box::new(Echo)];
+    for swarm in swarms.iter_mut() {
+        println!("{}: {:?}", swarm.name(), swarm.run("Summarize Q3 earnings"));
+    }
+
+    let nested = SwarmAgent::new(Echo).capability("echo").into_agent();
+    println!("{}: {:?}", nested.name(), nested.run("Summarize Q3 earnings"));
+}
+```
+
+### Notes
+* `MajorityVoting`, `RoundRobinSwarm`, and `SequentialWorkflow` implement `Swarm`; the workflow through its synchronous `run_with`. `MixtureOfAgents` does not yet, since the trait is synchronous.
+* Only `SequentialWorkflow` has a `to_config`. The Python loader builds just five swarm types, and `RoundRobinSwarm` and `MajorityVoting` are not among them, so their `to_yaml` fails with `YamlExportError::Unsupported`. Exported agents keep their configuration; a bound completion or callbacks must be attached again after loading.
+* `set_callbacks` is implemented by `MajorityVoting` and `RoundRobinSwarm`; on any other swarm it returns an error, so a caller relying on callbacks for progress or billing finds out before the run instead of after.
+* `Swarm` requires only `Send`, so `SwarmAgent` keeps the swarm behind a `Mutex` to be `Sync` as `Agent` requires. Concurrent runs through one adapter wait on each other; wrap one instance of the swarm per concurrent caller when that matters.
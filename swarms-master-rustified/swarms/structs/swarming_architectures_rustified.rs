@@ -2,19 +2,21 @@
 
 The provided Python code appears to be well-structured and follows good design principles. However, converting it directly to Rust will require some modifications due to language differences and Rust's ownership system. Here's a high-level analysis of the code and suggestions for conversion:
 
-1.  **Agent and Conversation Structs**: The `Agent` and `Conversation` structs can be defined using Rust's `struct` keyword. The `AgentLog` struct can also be defined inside the `Conversation` struct.
+1.  **Agents and the Conversation Struct**: Agents are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`), so any model-backed agent, `MockAgent`, or wrapped swarm can take part. The `Conversation` struct can be defined using Rust's `struct` keyword, with the `AgentLog` struct alongside it.
 2.  **Error Handling**: Rust's error handling is more explicit than Python's. You can use `Result` and `Option` types to handle potential errors.
 3.  **Async/Await**: Rust's async/await syntax is similar to Python's, but it uses the `async` and `await` keywords instead of the `asyncio` library.
 4.  **Swarm Functions**: The swarm functions (e.g., `circular_swarm`, `grid_swarm`, etc.) can be implemented using Rust's `fn` keyword and iterative loops.
 
 **Rust Code**
 
-Here's a simplified example of how the `Conversation` and `Agent` structs could be defined in Rust, along with the `circular_swarm` function:
+Here's a simplified example of how the `Conversation` struct could be defined in Rust, along with the `circular_swarm` function:
 
 ```rust
+use crate::swarms::structs::agent::{Agent, MockAgent};
 use std::collections::VecDeque;
 use std::error::Error;
 use std::fmt;
+use std::sync::Arc;
 
 // Define a custom error type
 #[derive(Debug)]
@@ -30,23 +32,8 @@ impl fmt::Display for SwarmError {
 
 impl Error for SwarmError {}
 
-// Define the Agent struct
-pub struct Agent {
-    agent_name: String,
-}
-
-impl Agent {
-    pub fn new(agent_name: String) -> Agent {
-        Agent { agent_name }
-    }
-
-    // This is a placeholder for the run method; you would need to implement the actual logic
-    pub fn run(&self, task: &str) -> String {
-        format!("{} processed task: {}", self.agent_name, task)
-    }
-}
-
 // Define the AgentLog struct
+#[derive(Clone)]
 pub struct AgentLog {
     pub agent_name: String,
     pub task: String,
@@ -78,7 +65,7 @@ impl Conversation {
 
 // Define the circular_swarm function
 pub fn circular_swarm(
-    agents: Vec<Agent>,
+    agents: &[Arc<dyn Agent>],
     tasks: Vec<String>,
     return_full_history: bool,
 ) -> Result<Vec<String>, SwarmError> {
@@ -92,9 +79,9 @@ pub fn circular_swarm(
     let mut responses = Vec::new();
 
     for task in tasks {
-        for agent in &agents {
-            let response = agent.run(&task);
-            conversation.add_log(agent.agent_name.clone(), task.clone(), response.clone());
+        for agent in agents {
+            let response = agent.run(&task).map_err(|err| SwarmError { message: err.to_string() })?;
+            conversation.add_log(agent.name().to_string(), task.clone(), response.clone());
             responses.push(response);
         }
     }
@@ -112,22 +99,21 @@ pub fn circular_swarm(
 }
 
 fn main() {
-    let agents = vec![
-        Agent::new("Agent1".to_string()),
-        Agent::new("Agent2".to_string()),
-        Agent::new("Agent3".to_string()),
-    ];
+    let agents: Vec<Arc<dyn Agent>> = ["Agent1", "Agent2", "Agent3"]
+        .iter()
+        .map(|name| Arc::new(MockAgent::new(*name)) as Arc<dyn Agent>)
+        .collect();
 
     let tasks = vec!["Task1".to_string(), "Task2".to_string(), "Task3".to_string()];
 
-    match circular_swarm(agents, tasks, true) {
+    match circular_swarm(&agents, tasks, true) {
         Ok(responses) => println!("Responses: {:?}", responses),
         Err(error) => println!("Error: {}", error),
     }
 }
 ```
 
-This Rust code provides a basic structure for the `Conversation`, `Agent`, and `circular_swarm` function. The `Agent` struct has a `run` method that returns a response for a given task. The `Conversation` struct maintains a log of agent interactions. The `circular_swarm` function simulates a circular swarm where each agent processes each task.
+This Rust code provides a basic structure for the `Conversation` and `circular_swarm` function. Each agent's `run` answers a task; the first agent error stops the swarm and is returned as a `SwarmError`. The `Conversation` struct maintains a log of agent interactions. The `circular_swarm` function simulates a circular swarm where each agent processes each task.
 
 **Risks and Limitations**
 
@@ -10,16 +10,17 @@ The main challenges in converting this code to Rust are:
 ### Converted Rust Code
 Here's the equivalent Rust code for the provided Python class:
 ```rust
+use crate::swarms::utils::clock::{system_clock, Clock};
+use chrono::{DateTime, Utc};
 use std::collections::HashMap;
-use std::time::{Duration, Instant};
-use std::thread;
+use std::sync::Arc;
 
 // Define a new error type for task-related errors
 #[derive(Debug)]
 enum TaskError {
     DependencyNotCompleted,
     TriggerNotMet,
-    ExecutionError(Box<dyn std::error::Error>),
+    ExecutionError(Box<dyn std::error::Error + Send + Sync>),
 }
 
 // Define a new type for task results
@@ -30,13 +31,15 @@ type TaskResult = Result<(), TaskError>;
 struct Task {
     name: Option<String>,
     description: Option<String>,
-    agent: Option<Box<dyn Fn(String) -> TaskResult>>,
+    agent: Option<Box<dyn Fn(String) -> TaskResult + Send + Sync>>,
     result: Option<String>,
     history: Vec<String>,
-    schedule_time: Option<Instant>,
-    trigger: Option<Box<dyn Fn() -> bool>>,
-    action: Option<Box<dyn Fn()>>,
-    condition: Option<Box<dyn Fn() -> bool>>,
+    schedule_time: Option<DateTime<Utc>>,
+    // Decides when a scheduled task is due; a `ManualClock` runs it at once
+    clock: Arc<dyn Clock>,
+    trigger: Option<Box<dyn Fn() -> bool + Send + Sync>>,
+    action: Option<Box<dyn Fn() + Send + Sync>>,
+    condition: Option<Box<dyn Fn() -> bool + Send + Sync>>,
     priority: i32,
     dependencies: Vec<Task>,
     args: Vec<String>,
@@ -48,7 +51,7 @@ impl Task {
     fn new(
         name: Option<String>,
         description: Option<String>,
-        agent: Option<Box<dyn Fn(String) -> TaskResult>>,
+        agent: Option<Box<dyn Fn(String) -> TaskResult + Send + Sync>>,
     ) -> Self {
         Task {
             name,
@@ -57,6 +60,7 @@ impl Task {
             result: None,
             history: Vec::new(),
             schedule_time: None,
+            clock: system_clock(),
             trigger: None,
             action: None,
             condition: None,
@@ -98,50 +102,42 @@ impl Task {
         }
     }
 
-    // Run the task
+    // Run the task, first waiting for its schedule time if that is still ahead
     fn run(&mut self, task: String) -> TaskResult {
-        // Check if the task is scheduled for the future
         if let Some(schedule_time) = self.schedule_time {
-            let delay = schedule_time.elapsed().as_secs_f64();
-            if delay < 0.0 {
-                thread::sleep(Duration::from_secs(delay as u64));
-                self.step(task)
-            } else {
-                self.step(task)
-            }
-        } else {
-            self.step(task)
+            self.clock.sleep_until(schedule_time);
         }
+        self.step(task)
     }
 
     // Handle scheduled tasks
-    fn handle_scheduled_task(&mut self) {
-        // Check if the schedule time is set
-        if let Some(schedule_time) = self.schedule_time {
-            let delay = schedule_time.elapsed().as_secs_f64();
-            if delay < 0.0 {
-                thread::sleep(Duration::from_secs(delay as u64));
-                self.run("".to_string());
-            } else {
-                self.run("".to_string());
-            }
-        } else {
-            self.run("".to_string());
-        }
+    fn handle_scheduled_task(&mut self) -> TaskResult {
+        self.run("".to_string())
+    }
+
+    // Run the task no earlier than `at`
+    fn schedule(&mut self, at: DateTime<Utc>) {
+        self.schedule_time = Some(at);
+    }
+
+    // Read the time from `clock` instead of the system clock
+    fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
     }
 
     // Set the trigger for the task
-    fn set_trigger(&mut self, trigger: Box<dyn Fn() -> bool>) {
+    fn set_trigger(&mut self, trigger: Box<dyn Fn() -> bool + Send + Sync>) {
         self.trigger = Some(trigger);
     }
 
     // Set the action for the task
-    fn set_action(&mut self, action: Box<dyn Fn()>) {
+    fn set_action(&mut self, action: Box<dyn Fn() + Send + Sync>) {
         self.action = Some(action);
     }
 
     // Set the condition for the task
-    fn set_condition(&mut self, condition: Box<dyn Fn() -> bool>) {
+    fn set_condition(&mut self, condition: Box<dyn Fn() -> bool + Send + Sync>) {
         self.condition = Some(condition);
     }
 
@@ -0,0 +1,249 @@
+### Overview
+There is no Python counterpart; the Python API server keeps every user's agents and runs in one store and
+filters by `user_id` in each route handler, so a route that forgets the filter leaks another user's data.
+In Rust the tenant is part of the data instead:
+
+* `TenantId` is a validated identifier (letters, digits, `-`, `_`), so it is always safe to use as a path
+  component.
+* `TaskLedger` (the coordinator's run store) stamps every task with the tenant that submitted it. Workers
+  register under a tenant and only claim that tenant's tasks, and `record` / `records` only return a
+  tenant's own tasks. A user therefore cannot read another user's runs or enqueue work onto their agents.
+* `TenantWorkspace` roots each tenant's files (agents, runs, conversations, artifacts) at
+  `<workspace>/tenants/<tenant_id>/`. `resolve` joins through `safe_join`, which rejects absolute paths and
+  `..`, so a user-supplied file name cannot reach outside the tenant's directory, and sanitizes each
+  component so the same name is valid on Windows. `checkpoint_store`, `spillover`, `conversation_path`, and
+  `artifact_path` hand out stores and paths that are already inside the tenant's directory.
+* `TenantKeys` maps API keys to tenants. A server calls `authenticate` with the request's `Authorization`
+  header and uses the `TenantId` it returns; the tenant is never read from the request body or query, so a
+  caller cannot claim to be another tenant. Only SHA-256 digests of the keys are kept.
+
+### Rust Code
+```rust
+use crate::swarms::artifacts::spillover::Spillover;
+use crate::swarms::integrations::problem::Problem;
+use crate::swarms::structs::approval_gate::CheckpointStore;
+use crate::swarms::structs::audit::{AuditAction, Auditor};
+use crate::swarms::utils::safe_path::safe_join;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TenantError {
+    /// Empty, too long, or contains characters other than letters, digits, `-`, `_`.
+    InvalidId(String),
+    /// A path that would leave the tenant's directory.
+    PathEscape { tenant: String, path: String },
+    /// No API key, or one that belongs to no tenant.
+    Unauthenticated,
+}
+
+impl fmt::Display for TenantError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TenantError::InvalidId(id) => write!(f, "invalid tenant id '{}'", id),
+            TenantError::PathEscape { tenant, path } => {
+                write!(f, "path '{}' is outside the workspace of tenant '{}'", path, tenant)
+            }
+            TenantError::Unauthenticated => write!(f, "missing or unknown API key"),
+        }
+    }
+}
+
+impl std::error::Error for TenantError {}
+
+/// The user or organization that owns an agent, run, conversation, or artifact.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct TenantId(String);
+
+impl TenantId {
+    pub const MAX_LEN: usize = 64;
+
+    pub fn new(id: impl Into<String>) -> Result<Self, TenantError> {
+        let id = id.into();
+        let valid = !id.is_empty()
+            && id.len() <= Self::MAX_LEN
+            && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        if valid {
+            Ok(TenantId(id))
+        } else {
+            Err(TenantError::InvalidId(id))
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<String> for TenantId {
+    type Error = TenantError;
+
+    fn try_from(id: String) -> Result<Self, Self::Error> {
+        TenantId::new(id)
+    }
+}
+
+impl From<TenantId> for String {
+    fn from(id: TenantId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for TenantId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&TenantError> for Problem {
+    fn from(err: &TenantError) -> Self {
+        match err {
+            TenantError::Unauthenticated => Problem::unauthorized(err.to_string()),
+            TenantError::InvalidId(_) | TenantError::PathEscape { .. } => Problem::bad_request(err.to_string()),
+        }
+    }
+}
+
+/// The tenant each API key belongs to. Keys are stored as SHA-256 digests.
+#[derive(Debug, Clone, Default)]
+pub struct TenantKeys {
+    tenants: HashMap<String, TenantId>,
+}
+
+fn key_digest(key: &str) -> String {
+    hex::encode(Sha256::digest(key.as_bytes()))
+}
+
+impl TenantKeys {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Requests carrying `key` act as `tenant`. A key can belong to one tenant only; adding it again moves it.
+    pub fn with_key(mut self, key: &str, tenant: TenantId) -> Self {
+        self.tenants.insert(key_digest(key), tenant);
+        self
+    }
+
+    /// Stop accepting `key`. Returns whether it was known.
+    pub fn revoke(&mut self, key: &str) -> bool {
+        self.tenants.remove(&key_digest(key)).is_some()
+    }
+
+    /// `revoke` on behalf of the auditor's actor, recorded as `key_revoked` on the key's id. Only a key
+    /// of the auditor's own tenant can be revoked.
+    pub fn revoke_as(&mut self, auditor: &Auditor, key: &str) -> bool {
+        let digest = key_digest(key);
+        if self.tenants.get(&digest) != Some(&auditor.tenant_id) {
+            return false;
+        }
+        self.tenants.remove(&digest);
+        auditor.record(AuditAction::KeyRevoked, &Self::key_id(key), serde_json::json!({}));
+        true
+    }
+
+    /// A short id for `key` that is safe to log: the start of its digest.
+    pub fn key_id(key: &str) -> String {
+        format!("key-{}", &key_digest(key)[..12])
+    }
+
+    /// The tenant of the key in an `Authorization` header, either `Bearer <key>` or the bare key.
+    pub fn authenticate(&self, authorization: Option<&str>) -> Result<TenantId, TenantError> {
+        let header = authorization.map(str::trim).filter(|header| !header.is_empty()).ok_or(TenantError::Unauthenticated)?;
+        let key = header.strip_prefix("Bearer ").map(str::trim).unwrap_or(header);
+        self.tenants.get(&key_digest(key)).cloned().ok_or(TenantError::Unauthenticated)
+    }
+}
+
+/// Per-tenant directories under a shared workspace root.
+#[derive(Debug, Clone)]
+pub struct TenantWorkspace {
+    root: PathBuf,
+}
+
+impl TenantWorkspace {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        TenantWorkspace { root: root.into() }
+    }
+
+    /// `<root>/tenants/<tenant_id>`. Every file a tenant owns lives below it.
+    pub fn dir(&self, tenant: &TenantId) -> PathBuf {
+        self.root.join("tenants").join(tenant.as_str())
+    }
+
+    /// `relative` inside the tenant's directory, with each component sanitized (see `safe_join`). Absolute
+    /// paths, `..`, and empty paths are rejected.
+    pub fn resolve(&self, tenant: &TenantId, relative: impl AsRef<Path>) -> Result<PathBuf, TenantError> {
+        let relative = relative.as_ref();
+        safe_join(self.dir(tenant), relative).map_err(|_| TenantError::PathEscape {
+            tenant: tenant.to_string(),
+            path: relative.display().to_string(),
+        })
+    }
+
+    pub fn agents_dir(&self, tenant: &TenantId) -> PathBuf {
+        self.dir(tenant).join("agents")
+    }
+
+    /// Where a tenant's run checkpoints go, e.g. the `CheckpointStore` of its approval gates.
+    pub fn runs_dir(&self, tenant: &TenantId) -> PathBuf {
+        self.dir(tenant).join("runs")
+    }
+
+    pub fn conversations_dir(&self, tenant: &TenantId) -> PathBuf {
+        self.dir(tenant).join("conversations")
+    }
+
+    pub fn artifacts_dir(&self, tenant: &TenantId) -> PathBuf {
+        self.dir(tenant).join("artifacts")
+    }
+
+    /// Save path for a named conversation, for `ConversationBuilder::autosave_to`. The name is one file
+    /// name; one containing a path separator is rejected with `PathEscape`.
+    pub fn conversation_path(&self, tenant: &TenantId, name: &str) -> Result<PathBuf, TenantError> {
+        self.named_path(tenant, "conversations", &format!("{}.json", name))
+    }
+
+    /// Path for a named artifact, for `Artifact::new`.
+    pub fn artifact_path(&self, tenant: &TenantId, name: &str) -> Result<PathBuf, TenantError> {
+        self.named_path(tenant, "artifacts", name)
+    }
+
+    /// The checkpoints of the tenant's paused runs, in its `runs` directory.
+    pub fn checkpoint_store(&self, tenant: &TenantId) -> CheckpointStore {
+        CheckpointStore::new(self.runs_dir(tenant))
+    }
+
+    /// Spilled outputs of the tenant's runs, in its `artifacts` directory.
+    pub fn spillover(&self, tenant: &TenantId) -> Spillover {
+        Spillover::new(self.artifacts_dir(tenant).join("spilled"))
+    }
+
+    // `name` as a single file in `dir` of the tenant's directory
+    fn named_path(&self, tenant: &TenantId, dir: &str, name: &str) -> Result<PathBuf, TenantError> {
+        if name.contains(['/', '\\']) || name == ".." {
+            return Err(TenantError::PathEscape { tenant: tenant.to_string(), path: name.to_string() });
+        }
+        self.resolve(tenant, Path::new(dir).join(name))
+    }
+}
+
+fn main() -> Result<(), TenantError> {
+    let workspace = TenantWorkspace::new("agent_workspace");
+    let keys = TenantKeys::new().with_key("sk-acme-123", TenantId::new("acme")?);
+    let acme = keys.authenticate(Some("Bearer sk-acme-123"))?;
+    println!("{}", workspace.conversation_path(&acme, "onboarding")?.display());
+    assert!(workspace.resolve(&acme, "../globex/runs").is_err());
+    Ok(())
+}
+```
+
+### Notes
+* Isolation is enforced where data is stored, not in route handlers: a server authenticates the API key with `TenantKeys::authenticate`, and passes the `TenantId` it returns to the ledger, agent store, and workspace calls. There is no call that lists across tenants.
+* An unknown key and a missing key are the same error, a 401 problem, so a caller cannot tell whether a key ever existed.
+* A task belonging to another tenant is reported as missing rather than forbidden, so ids cannot be probed.
+* Approval queues are kept per tenant: a server holds one `PendingApprovals` per `TenantId` and mounts the one for the caller's tenant.
@@ -0,0 +1,253 @@
+### Overview
+The Rust counterpart of the Python `Agent`'s `tree_of_thoughts` flag, which in Python only swaps in a
+prompt asking the model to imagine several experts. Here it is a real search. Starting from the task, each
+step branches into `branching` candidate thoughts, which an evaluator scores. The best `beam` of the new
+thoughts are expanded at the next step, down to `depth` steps. The answer is the best-scoring leaf.
+
+The search calls no model itself. `generate` proposes the candidate thoughts that follow a path, and
+`evaluate` scores one candidate. Either can be backed by the agent's model through the prompt helpers:
+`ThoughtContext::prompt` with `parse_candidates`, and `evaluation_prompt` with `parse_score`.
+
+`SearchTree` keeps every node, including the pruned ones, with its parent, score, and whether it was
+expanded. It serializes to JSON for inspection.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use crate::swarms::structs::loop_detection::normalize;
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TreeOfThoughtsConfig {
+    /// Candidate thoughts generated per expanded node (K).
+    pub branching: usize,
+    /// Nodes expanded per step (B).
+    pub beam: usize,
+    /// Steps from the root to a leaf (D).
+    pub depth: usize,
+    /// Candidates scoring below this are never expanded.
+    pub min_score: f32,
+}
+
+impl Default for TreeOfThoughtsConfig {
+    fn default() -> Self {
+        TreeOfThoughtsConfig { branching: 3, beam: 2, depth: 3, min_score: 0.0 }
+    }
+}
+
+impl TreeOfThoughtsConfig {
+    /// `None` unless `tree_of_thoughts` is set.
+    pub fn from_schema(schema: &AgentSchema) -> Option<Self> {
+        schema.tree_of_thoughts.unwrap_or(false).then(TreeOfThoughtsConfig::default)
+    }
+}
+
+/// What `generate` is asked to extend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThoughtContext<'a> {
+    pub task: &'a str,
+    /// The thoughts from the root to the node being expanded.
+    pub path: Vec<&'a str>,
+    pub depth: usize,
+    /// How many candidates are wanted.
+    pub branching: usize,
+}
+
+impl ThoughtContext<'_> {
+    /// A prompt asking the model for the next candidate thoughts, one per line.
+    pub fn prompt(&self) -> String {
+        let mut prompt = format!("Task: {}\n", self.task);
+        if !self.path.is_empty() {
+            prompt.push_str("\nReasoning so far:\n");
+            for (step, thought) in self.path.iter().enumerate() {
+                prompt.push_str(&format!("{}. {}\n", step + 1, thought));
+            }
+        }
+        prompt.push_str(&format!(
+            "\nPropose {} different next steps of reasoning. Write each on its own line, numbered 1 to {}.",
+            self.branching, self.branching
+        ));
+        prompt
+    }
+}
+
+/// A prompt asking the model to rate `candidate` as the next step after `path`, from 0 to 10.
+pub fn evaluation_prompt(task: &str, path: &[&str], candidate: &str) -> String {
+    let mut prompt = format!("Task: {}\n", task);
+    for (step, thought) in path.iter().enumerate() {
+        prompt.push_str(&format!("{}. {}\n", step + 1, thought));
+    }
+    prompt.push_str(&format!(
+        "\nProposed next step: {}\n\nRate how likely this step leads to a correct answer, from 0 to 10. \
+         Reply with the number only.",
+        candidate
+    ));
+    prompt
+}
+
+/// Split a model reply into candidate thoughts, dropping list numbering and bullets.
+pub fn parse_candidates(reply: &str) -> Vec<String> {
+    reply
+        .lines()
+        .map(|line| {
+            line.trim()
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .trim_start_matches(['.', ')', '-', '*'])
+                .trim()
+                .to_string()
+        })
+        .filter(|line| !line.is_empty())
+        .collect()
+}
+
+/// Read the first number in a reply as a score, scaled from 0-10 to 0-1.
+pub fn parse_score(reply: &str) -> Option<f32> {
+    let start = reply.find(|c: char| c.is_ascii_digit())?;
+    let number: String = reply[start..].chars().take_while(|c| c.is_ascii_digit() || *c == '.').collect();
+    number.trim_end_matches('.').parse::<f32>().ok().map(|score| (score / 10.0).clamp(0.0, 1.0))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThoughtNode {
+    pub id: usize,
+    /// `None` for the root, which holds the task.
+    pub parent: Option<usize>,
+    pub depth: usize,
+    pub thought: String,
+    pub score: f32,
+    pub expanded: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchTree {
+    pub config: TreeOfThoughtsConfig,
+    /// Node 0 is the root.
+    pub nodes: Vec<ThoughtNode>,
+    pub best_leaf: Option<usize>,
+}
+
+impl SearchTree {
+    pub fn root(&self) -> &ThoughtNode {
+        &self.nodes[0]
+    }
+
+    pub fn children(&self, id: usize) -> impl Iterator<Item = &ThoughtNode> {
+        self.nodes.iter().filter(move |node| node.parent == Some(id))
+    }
+
+    /// The nodes from the first thought after the root down to `id`.
+    pub fn path(&self, id: usize) -> Vec<&ThoughtNode> {
+        let mut path = Vec::new();
+        let mut current = Some(id);
+        while let Some(node) = current.and_then(|id| self.nodes.get(id)) {
+            if node.parent.is_none() {
+                break;
+            }
+            path.push(node);
+            current = node.parent;
+        }
+        path.reverse();
+        path
+    }
+
+    /// The best leaf's thought.
+    pub fn answer(&self) -> Option<&str> {
+        self.best_leaf.map(|id| self.nodes[id].thought.as_str())
+    }
+
+    fn thoughts(&self, id: usize) -> Vec<&str> {
+        self.path(id).into_iter().map(|node| node.thought.as_str()).collect()
+    }
+}
+
+/// Run a beam search over thoughts for `task`.
+pub fn search(
+    task: &str,
+    config: &TreeOfThoughtsConfig,
+    mut generate: impl FnMut(&ThoughtContext) -> Vec<String>,
+    mut evaluate: impl FnMut(&ThoughtContext, &str) -> f32,
+) -> SearchTree {
+    let root = ThoughtNode { id: 0, parent: None, depth: 0, thought: task.to_string(), score: 0.0, expanded: false };
+    let mut tree = SearchTree { config: config.clone(), nodes: vec![root], best_leaf: None };
+    let mut frontier = vec![0];
+    let mut leaves = Vec::new();
+
+    for depth in 1..=config.depth {
+        let mut children = Vec::new();
+        for &id in &frontier {
+            let path: Vec<String> = tree.thoughts(id).into_iter().map(str::to_string).collect();
+            let context = ThoughtContext { task, path: path.iter().map(String::as_str).collect(), depth, branching: config.branching };
+            let mut seen = HashSet::new();
+            let candidates: Vec<String> = generate(&context)
+                .into_iter()
+                .filter(|candidate| !candidate.trim().is_empty() && seen.insert(normalize(candidate)))
+                .take(config.branching)
+                .collect();
+            let scored: Vec<(String, f32)> = candidates
+                .into_iter()
+                .map(|candidate| {
+                    let score = evaluate(&context, &candidate);
+                    (candidate, if score.is_finite() { score } else { 0.0 })
+                })
+                .collect();
+            if scored.is_empty() && id != 0 {
+                leaves.push(id);
+            }
+            for (thought, score) in scored {
+                let child = tree.nodes.len();
+                tree.nodes.push(ThoughtNode { id: child, parent: Some(id), depth, thought, score, expanded: false });
+                children.push(child);
+            }
+            tree.nodes[id].expanded = true;
+        }
+        if children.is_empty() {
+            break;
+        }
+        children.sort_by(|a, b| tree.nodes[*b].score.total_cmp(&tree.nodes[*a].score));
+        debug!("Depth {}: {} candidate(s), best score {:.2}", depth, children.len(), tree.nodes[children[0]].score);
+        frontier = children
+            .into_iter()
+            .filter(|&id| tree.nodes[id].score >= config.min_score)
+            .take(config.beam.max(1))
+            .collect();
+        if frontier.is_empty() {
+            break;
+        }
+    }
+
+    // Leaves are where the search stopped: the last frontier, and expanded nodes that produced nothing.
+    // Pruned nodes are not leaves, unless pruning left nothing else.
+    leaves.extend(frontier.into_iter().filter(|&id| id != 0 && !tree.nodes[id].expanded));
+    if leaves.is_empty() {
+        leaves = (1..tree.nodes.len()).collect();
+    }
+    tree.best_leaf = leaves
+        .into_iter()
+        .max_by(|&a, &b| tree.nodes[a].score.total_cmp(&tree.nodes[b].score).then(tree.nodes[a].depth.cmp(&tree.nodes[b].depth)));
+    tree
+}
+
+fn main() {
+    let config = TreeOfThoughtsConfig { branching: 3, beam: 2, depth: 2, ..TreeOfThoughtsConfig::default() };
+    let tree = search(
+        "Use 4, 6, and 9 to make 24",
+        &config,
+        |context| match context.depth {
+            1 => vec!["9 - 6 = 3".to_string(), "4 + 6 = 10".to_string(), "6 * 4 = 24".to_string()],
+            _ => vec![format!("{}, so the answer is found", context.path.last().unwrap_or(&""))],
+        },
+        |_context, candidate| if candidate.contains("24") { 0.9 } else { 0.4 },
+    );
+    println!("{:?}", tree.answer());
+    println!("{}", serde_json::to_string_pretty(&tree).unwrap());
+}
+```
+
+### Notes
+* A search makes up to `branching * beam * depth` evaluator calls; with model-backed evaluation, keep `beam` small.
+* Duplicate candidates from one expansion are merged before scoring. Duplicates across different branches are kept, since their paths differ.
+* Nodes pruned by `beam` or `min_score` stay in the tree unexpanded, so the serialized tree shows what was considered and dropped.
@@ -0,0 +1,356 @@
+### Overview
+There is no Python counterpart; the Python package prints token counts per call and keeps no totals.
+Teams running accountant-swarm style workloads need to charge spend back to the agents, models, and
+projects that caused it, so this module has two parts:
+
+* `UsageTracker` appends one `UsageRecord` per LLM call to a JSONL file: run id, agent, model, the
+  caller's tags (project, customer, cost centre), and prompt and completion tokens. Records whose tokens
+  were counted locally, because the provider streamed no usage, are marked `estimated`.
+  `OpenAiCompatProvider::track_usage` records every call it makes into a `UsageScope`, which pairs a
+  tracker with the run id and tags.
+* `CostReport` aggregates those records per run, per day, or per ISO week, broken down by agent, model, or
+  tag, and prices them with a `Pricing` table. `to_csv` exports the rows.
+
+A call with several tags counts fully towards each of them, so per-tag rows can add up to more than the
+total spend. Calls with no tags are reported under `(untagged)`. Models missing from the price table are
+reported with their tokens and a cost of zero, and are listed in `CostReport::unpriced_models`.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::UsageInfo;
+use chrono::{DateTime, Datelike, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+/// One LLM call.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UsageRecord {
+    pub timestamp: DateTime<Utc>,
+    pub run_id: String,
+    pub agent: String,
+    pub model: String,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    /// The tokens were counted locally because the provider reported none.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub estimated: bool,
+}
+
+#[derive(Debug)]
+pub enum UsageError {
+    Io(io::Error),
+    /// A line of the usage log that does not parse.
+    Corrupt { line: usize, message: String },
+    Csv(String),
+}
+
+impl fmt::Display for UsageError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            UsageError::Io(err) => write!(f, "usage log I/O failed: {}", err),
+            UsageError::Corrupt { line, message } => write!(f, "usage log line {} is corrupt: {}", line, message),
+            UsageError::Csv(message) => write!(f, "failed to write cost report CSV: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for UsageError {}
+
+impl From<io::Error> for UsageError {
+    fn from(err: io::Error) -> Self {
+        UsageError::Io(err)
+    }
+}
+
+impl From<csv::Error> for UsageError {
+    fn from(err: csv::Error) -> Self {
+        UsageError::Csv(err.to_string())
+    }
+}
+
+/// Append-only JSONL log of `UsageRecord`s. Cloning shares the underlying file.
+#[derive(Debug, Clone)]
+pub struct UsageTracker {
+    path: PathBuf,
+    file: Arc<Mutex<Option<File>>>,
+}
+
+impl UsageTracker {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        UsageTracker { path: path.into(), file: Arc::new(Mutex::new(None)) }
+    }
+
+    pub fn record(&self, record: &UsageRecord) -> Result<(), UsageError> {
+        let mut line = serde_json::to_vec(record).map_err(|err| UsageError::Io(err.into()))?;
+        line.push(b'\n');
+        let mut file = self.file.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if file.is_none() {
+            if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+                fs::create_dir_all(dir)?;
+            }
+            *file = Some(OpenOptions::new().create(true).append(true).open(&self.path)?);
+        }
+        file.as_mut().expect("opened above").write_all(&line)?;
+        Ok(())
+    }
+
+    /// Every record, oldest first.
+    pub fn records(&self) -> Result<Vec<UsageRecord>, UsageError> {
+        let file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let mut records = Vec::new();
+        for (index, line) in BufReader::new(file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            records.push(
+                serde_json::from_str(&line)
+                    .map_err(|err| UsageError::Corrupt { line: index + 1, message: err.to_string() })?,
+            );
+        }
+        Ok(records)
+    }
+}
+
+/// The run and tags a provider's calls are charged to.
+#[derive(Debug, Clone)]
+pub struct UsageScope {
+    pub tracker: UsageTracker,
+    pub run_id: String,
+    pub tags: Vec<String>,
+}
+
+impl UsageScope {
+    pub fn new(tracker: UsageTracker, run_id: impl Into<String>) -> Self {
+        UsageScope { tracker, run_id: run_id.into(), tags: Vec::new() }
+    }
+
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        self.tags.push(tag.into());
+        self
+    }
+
+    pub fn record(&self, agent: &str, model: &str, prompt_tokens: u64, completion_tokens: u64) -> Result<(), UsageError> {
+        self.write(agent, model, prompt_tokens, completion_tokens, false)
+    }
+
+    /// Record a provider's `UsageInfo`, keeping its `estimated` flag.
+    pub fn record_usage(&self, agent: &str, model: &str, usage: &UsageInfo) -> Result<(), UsageError> {
+        let completion = usage.completion_tokens.unwrap_or(usage.total_tokens - usage.prompt_tokens);
+        self.write(agent, model, usage.prompt_tokens.max(0) as u64, completion.max(0) as u64, usage.estimated)
+    }
+
+    fn write(
+        &self,
+        agent: &str,
+        model: &str,
+        prompt_tokens: u64,
+        completion_tokens: u64,
+        estimated: bool,
+    ) -> Result<(), UsageError> {
+        self.tracker.record(&UsageRecord {
+            timestamp: Utc::now(),
+            run_id: self.run_id.clone(),
+            agent: agent.to_string(),
+            model: model.to_string(),
+            tags: self.tags.clone(),
+            prompt_tokens,
+            completion_tokens,
+            estimated,
+        })
+    }
+}
+
+/// USD per million tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ModelPrice {
+    pub prompt_per_million: f64,
+    pub completion_per_million: f64,
+}
+
+/// Prices by model name. Deserializes from a `{ "model": { "prompt_per_million": .., .. } }` table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Pricing {
+    pub models: HashMap<String, ModelPrice>,
+}
+
+impl Pricing {
+    pub fn new() -> Self {
+        Pricing::default()
+    }
+
+    pub fn model(mut self, model: impl Into<String>, prompt_per_million: f64, completion_per_million: f64) -> Self {
+        self.models.insert(model.into(), ModelPrice { prompt_per_million, completion_per_million });
+        self
+    }
+
+    /// The cost of one record, or `None` if its model has no price.
+    pub fn cost(&self, record: &UsageRecord) -> Option<f64> {
+        self.price(&record.model, record.prompt_tokens, record.completion_tokens)
+    }
+
+    /// The cost of `prompt_tokens` and `completion_tokens` on `model`, or `None` if it has no price.
+    pub fn price(&self, model: &str, prompt_tokens: u64, completion_tokens: u64) -> Option<f64> {
+        let price = self.models.get(model)?;
+        Some(
+            (prompt_tokens as f64 * price.prompt_per_million + completion_tokens as f64 * price.completion_per_million)
+                / 1_000_000.0,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportPeriod {
+    Run,
+    /// UTC calendar days, `2026-10-15`.
+    Day,
+    /// ISO weeks, `2026-W42`.
+    Week,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReportDimension {
+    Agent,
+    Model,
+    Tag,
+}
+
+pub const UNTAGGED: &str = "(untagged)";
+
+/// One line of the report.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostRow {
+    pub period: String,
+    pub key: String,
+    pub calls: u64,
+    pub runs: usize,
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub cost_usd: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CostReport {
+    pub period: ReportPeriod,
+    pub dimension: ReportDimension,
+    /// Sorted by period, then key.
+    pub rows: Vec<CostRow>,
+    pub unpriced_models: Vec<String>,
+}
+
+impl CostReport {
+    pub fn build(records: &[UsageRecord], period: ReportPeriod, dimension: ReportDimension, pricing: &Pricing) -> Self {
+        #[derive(Default)]
+        struct Totals {
+            calls: u64,
+            runs: BTreeSet<String>,
+            prompt_tokens: u64,
+            completion_tokens: u64,
+            cost_usd: f64,
+        }
+
+        let mut groups: BTreeMap<(String, String), Totals> = BTreeMap::new();
+        let mut unpriced = BTreeSet::new();
+        for record in records {
+            let period_key = match period {
+                ReportPeriod::Run => record.run_id.clone(),
+                ReportPeriod::Day => record.timestamp.format("%Y-%m-%d").to_string(),
+                ReportPeriod::Week => {
+                    let week = record.timestamp.iso_week();
+                    format!("{}-W{:02}", week.year(), week.week())
+                }
+            };
+            let keys = match dimension {
+                ReportDimension::Agent => vec![record.agent.clone()],
+                ReportDimension::Model => vec![record.model.clone()],
+                ReportDimension::Tag if record.tags.is_empty() => vec![UNTAGGED.to_string()],
+                ReportDimension::Tag => record.tags.iter().cloned().collect::<BTreeSet<_>>().into_iter().collect(),
+            };
+            let cost = pricing.cost(record).unwrap_or_else(|| {
+                unpriced.insert(record.model.clone());
+                0.0
+            });
+            for key in keys {
+                let totals = groups.entry((period_key.clone(), key)).or_default();
+                totals.calls += 1;
+                totals.runs.insert(record.run_id.clone());
+                totals.prompt_tokens += record.prompt_tokens;
+                totals.completion_tokens += record.completion_tokens;
+                totals.cost_usd += cost;
+            }
+        }
+
+        let rows = groups
+            .into_iter()
+            .map(|((period, key), totals)| CostRow {
+                period,
+                key,
+                calls: totals.calls,
+                runs: totals.runs.len(),
+                prompt_tokens: totals.prompt_tokens,
+                completion_tokens: totals.completion_tokens,
+                cost_usd: totals.cost_usd,
+            })
+            .collect();
+        CostReport { period, dimension, rows, unpriced_models: unpriced.into_iter().collect() }
+    }
+
+    pub fn total_cost(&self) -> f64 {
+        self.rows.iter().map(|row| row.cost_usd).sum()
+    }
+
+    /// CSV with a header row. Costs are rounded to six decimal places.
+    pub fn to_csv(&self) -> Result<String, UsageError> {
+        let dimension = match self.dimension {
+            ReportDimension::Agent => "agent",
+            ReportDimension::Model => "model",
+            ReportDimension::Tag => "tag",
+        };
+        let mut writer = csv::Writer::from_writer(Vec::new());
+        writer.write_record(["period", dimension, "calls", "runs", "prompt_tokens", "completion_tokens", "cost_usd"])?;
+        for row in &self.rows {
+            writer.write_record([
+                row.period.clone(),
+                row.key.clone(),
+                row.calls.to_string(),
+                row.runs.to_string(),
+                row.prompt_tokens.to_string(),
+                row.completion_tokens.to_string(),
+                format!("{:.6}", row.cost_usd),
+            ])?;
+        }
+        let bytes = writer.into_inner().map_err(|err| UsageError::Csv(err.to_string()))?;
+        Ok(String::from_utf8(bytes).expect("CSV of UTF-8 fields is UTF-8"))
+    }
+}
+
+fn main() -> Result<(), UsageError> {
+    let tracker = UsageTracker::new(std::env::temp_dir().join("swarms-usage.jsonl"));
+    let scope = UsageScope::new(tracker.clone(), "run-42").tag("client:acme");
+    scope.record("Accountant-Agent", "gpt-4o-mini", 1_800, 450)?;
+    scope.record("Auditor-Agent", "gpt-4o", 2_400, 600)?;
+
+    let pricing = Pricing::new().model("gpt-4o-mini", 0.15, 0.60).model("gpt-4o", 2.50, 10.00);
+    let report = CostReport::build(&tracker.records()?, ReportPeriod::Day, ReportDimension::Agent, &pricing);
+    print!("{}", report.to_csv()?);
+    Ok(())
+}
+```
+
+### Notes
+* Prices change; keep the `Pricing` table in configuration rather than code, and re-run old reports with the prices that applied at the time.
+* The log grows by one line per LLM call. Rotate it monthly, or after reports for the period have been archived.
@@ -1,25 +1,14 @@
 ```rust
 // Conversion viability: MOSTLY VIABLE
-// The provided Python code can be converted to Rust, but some modules and classes (like Agent) need to be implemented in Rust.
+// The provided Python code can be converted to Rust; agents are the crate's `Agent` trait objects.
 // The json and regex functionalities can be achieved using the serde_json and regex crates.
 // The code relies heavily on dynamic typing, which Rust is not designed for. Thus, we will use trait objects and enums to mimic this behavior.
 
 use serde_json::json;
 use regex::Regex;
 use std::collections::HashMap;
-
-// Assuming the Agent struct is defined as follows
-struct Agent {
-    id: String,
-}
-
-// Implement the run method for the Agent struct
-impl Agent {
-    fn run(&self, task: &str) -> String {
-        // Add the implementation of the run method
-        format!("Output from agent {} for task {}", self.id, task)
-    }
-}
+use std::sync::Arc;
+use crate::swarms::structs::agent::{Agent, MockAgent};
 
 fn parse_tasks(task: &str) -> HashMap<String, String> {
     // Initialize an empty HashMap to store tasks
@@ -38,11 +27,12 @@ fn parse_tasks(task: &str) -> HashMap<String, String> {
     tasks
 }
 
-fn find_agent_by_id(agent_id: &str, agents: &Vec<Agent>) -> Option<&Agent> {
+// Agents are identified by name, which stands in for the Python agent id
+fn find_agent_by_id<'a>(agent_id: &str, agents: &'a [Arc<dyn Agent>]) -> Option<&'a Arc<dyn Agent>> {
     // Iterate over the agents
     for agent in agents {
         // Check if the agent's id matches the given id
-        if agent.id == agent_id {
+        if agent.name() == agent_id {
             // Return the agent if a match is found
             return Some(agent);
         }
@@ -51,7 +41,7 @@ fn find_agent_by_id(agent_id: &str, agents: &Vec<Agent>) -> Option<&Agent> {
     None
 }
 
-fn distribute_tasks(task: &str, agents: &Vec<Agent>) {
+fn distribute_tasks(task: &str, agents: &[Arc<dyn Agent>]) {
     // Parse the task to extract tasks and agent id
     let tasks = parse_tasks(task);
 
@@ -60,8 +50,10 @@ fn distribute_tasks(task: &str, agents: &Vec<Agent>) {
         // Find the agent by id
         if let Some(agent) = find_agent_by_id(agent_id, agents) {
             println!("Assigning task {} to agent {}", t, agent_id);
-            let output = agent.run(t);
-            println!("Output from agent {}: {}", agent_id, output);
+            match agent.run(t) {
+                Ok(output) => println!("Output from agent {}: {}", agent_id, output),
+                Err(err) => println!("Agent {} failed: {}", agent_id, err),
+            }
         } else {
             println!("No agent found with ID {}. Task '{}' is not assigned.", agent_id, t);
         }
@@ -94,10 +86,7 @@ fn detect_markdown(text: &str) -> bool {
 
 fn main() {
     // Example usage
-    let agents = vec![
-        Agent { id: "agent1".to_string() },
-        Agent { id: "agent2".to_string() },
-    ];
+    let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("agent1")), Arc::new(MockAgent::new("agent2"))];
 
     let task = "<agent_id>agent1</agent_id>\n<agent_id>agent2</agent_id>";
     distribute_tasks(task, &agents);
@@ -115,7 +104,7 @@ fn main() {
 }
 ```
 
-This Rust code maintains the original behavior of the provided Python code. It uses the `serde_json` crate for JSON parsing and the `regex` crate for regular expression matching. The `Agent` struct and its methods are assumed to be defined elsewhere in the codebase.
+This Rust code maintains the original behavior of the provided Python code. It uses the `serde_json` crate for JSON parsing and the `regex` crate for regular expression matching. Agents are the crate's `Arc<dyn Agent>` (`swarms::structs::agent`), found by `name()`; an agent that fails its task is reported and the others still run.
 
 **Limitations and Challenges:**
 
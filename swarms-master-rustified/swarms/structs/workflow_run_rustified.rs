@@ -0,0 +1,273 @@
+### Overview
+There is no Python counterpart; when one agent of a Python `SequentialWorkflow` or `GraphWorkflow` fails,
+the only way to finish the job is to run the whole pipeline again, paying for every step that had
+already succeeded. A `Run` records each step of a workflow run as it happens: the input it was sent, its
+output, and how it ended. With that record, a failed step can be run again on its own.
+
+`Run::retry_step` re-executes one failed step with the input stored for it and splices the new output into
+the run. With `Resume::Downstream` it then carries on with the steps that depend on it:
+
+* A step that never ran, or that failed, is run once all of its upstream steps have succeeded.
+* A step that already succeeded is run again only when its input changed. For a step that reads its
+  upstream output, the new output of the retried step usually means it did; a step that reads the task
+  keeps its output.
+
+`SequentialWorkflow::run_recorded` and `GraphWorkflow::run_recorded` produce a `Run`, and each workflow's
+`retry_step` calls `Run::retry_step` with its own agents. A `Run` serializes, so it can be saved when a
+run fails and retried later from another process, once the agents are built again.
+
+### Rust Code
+```rust
+use crate::swarms::structs::step_guard::{guard_fallible_step, StepStatus};
+use crate::swarms::utils::id_generator::{new_id, IdKind};
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// What a step is sent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StepInput {
+    #[default]
+    Task,
+    /// The outputs of its upstream steps, in order, separated by blank lines.
+    Upstream,
+}
+
+/// One step of a workflow run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunStep {
+    /// Unique within the run: the agent's name in a sequential workflow, the node id in a graph.
+    pub id: String,
+    pub agent: String,
+    pub input_from: StepInput,
+    /// Ids of the steps that must succeed before this one runs.
+    pub after: Vec<String>,
+    /// The input the step was last sent; `None` until it runs.
+    pub input: Option<String>,
+    pub output: Option<String>,
+    /// `None` until the step runs.
+    pub status: Option<StepStatus>,
+    pub attempts: u32,
+}
+
+impl RunStep {
+    /// A step that reads the task and waits for nothing; chain `reading_upstream` and `after`.
+    pub fn new(id: impl Into<String>, agent: impl Into<String>) -> Self {
+        RunStep {
+            id: id.into(),
+            agent: agent.into(),
+            input_from: StepInput::Task,
+            after: Vec::new(),
+            input: None,
+            output: None,
+            status: None,
+            attempts: 0,
+        }
+    }
+
+    pub fn reading_upstream(mut self) -> Self {
+        self.input_from = StepInput::Upstream;
+        self
+    }
+
+    pub fn after(mut self, steps: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.after.extend(steps.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn succeeded(&self) -> bool {
+        self.status.as_ref().is_some_and(StepStatus::is_success)
+    }
+
+    /// Ran and did not succeed.
+    pub fn failed(&self) -> bool {
+        self.status.as_ref().is_some_and(|status| !status.is_success())
+    }
+}
+
+/// How far `Run::retry_step` goes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Resume {
+    /// Only the step itself.
+    StepOnly,
+    /// The step, then the steps downstream of it that have not succeeded or whose input changed.
+    Downstream,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RetryError {
+    UnknownStep(String),
+    /// The step succeeded or has not run, so there is nothing to retry.
+    NotFailed(String),
+    /// The workflow has no agent for the step.
+    NoAgent(String),
+}
+
+impl fmt::Display for RetryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RetryError::UnknownStep(step) => write!(f, "no step '{}' in the run", step),
+            RetryError::NotFailed(step) => write!(f, "step '{}' has not failed", step),
+            RetryError::NoAgent(step) => write!(f, "no agent to run step '{}'", step),
+        }
+    }
+}
+
+impl std::error::Error for RetryError {}
+
+/// The record of one workflow run. Steps are kept in execution order, so each step comes after its
+/// upstream steps.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Run {
+    pub run_id: String,
+    pub workflow: String,
+    pub task: String,
+    pub steps: Vec<RunStep>,
+}
+
+impl Run {
+    pub fn new(workflow: impl Into<String>, task: impl Into<String>) -> Self {
+        Run { run_id: new_id(IdKind::Run), workflow: workflow.into(), task: task.into(), steps: Vec::new() }
+    }
+
+    /// Add a step after the ones already planned.
+    pub fn step(mut self, step: RunStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    pub fn get(&self, step_id: &str) -> Option<&RunStep> {
+        self.steps.iter().find(|step| step.id == step_id)
+    }
+
+    /// Every step ran and succeeded.
+    pub fn is_complete(&self) -> bool {
+        self.steps.iter().all(RunStep::succeeded)
+    }
+
+    pub fn failed_steps(&self) -> impl Iterator<Item = &RunStep> {
+        self.steps.iter().filter(|step| step.failed())
+    }
+
+    /// The output of the last step, once the run is complete.
+    pub fn output(&self) -> Option<&str> {
+        self.is_complete().then(|| self.steps.last()).flatten().and_then(|step| step.output.as_deref())
+    }
+
+    /// Run every step that has not run yet and whose upstream steps succeeded, in order. `execute` runs one
+    /// step on its input; an `Err` or a panic marks the step failed, and the steps downstream of it wait.
+    pub fn execute(&mut self, mut execute: impl FnMut(&RunStep, &str) -> Result<String, String>) -> Vec<String> {
+        let mut ran = Vec::new();
+        for index in 0..self.steps.len() {
+            if self.steps[index].status.is_none() && self.ready(index) {
+                let input = self.input_for(index);
+                self.run_step(index, input, &mut execute);
+                ran.push(self.steps[index].id.clone());
+            }
+        }
+        ran
+    }
+
+    /// Run the failed step `step_id` again with the input it was sent, and replace its output and status with
+    /// the new ones. With `Resume::Downstream`, a step that now succeeds is followed by the steps that depend on
+    /// it. Returns the ids of the steps that ran; check `get(step_id)` or `is_complete` for how they ended.
+    pub fn retry_step(
+        &mut self,
+        step_id: &str,
+        resume: Resume,
+        mut execute: impl FnMut(&RunStep, &str) -> Result<String, String>,
+    ) -> Result<Vec<String>, RetryError> {
+        let index = self.index(step_id).ok_or_else(|| RetryError::UnknownStep(step_id.to_string()))?;
+        if !self.steps[index].failed() {
+            return Err(RetryError::NotFailed(step_id.to_string()));
+        }
+        let input = self.steps[index].input.clone().unwrap_or_else(|| self.input_for(index));
+        info!("{}: retrying step {} of run {}", self.workflow, step_id, self.run_id);
+        self.run_step(index, input, &mut execute);
+        let mut ran = vec![step_id.to_string()];
+        if resume == Resume::StepOnly || !self.steps[index].succeeded() {
+            return Ok(ran);
+        }
+
+        let mut changed: HashSet<String> = HashSet::from([step_id.to_string()]);
+        for downstream in index + 1..self.steps.len() {
+            if !self.steps[downstream].after.iter().any(|upstream| changed.contains(upstream)) {
+                continue;
+            }
+            let input = self.input_for(downstream);
+            let step = &self.steps[downstream];
+            let stale = !step.succeeded() || step.input.as_deref() != Some(input.as_str());
+            if !stale || !self.ready(downstream) {
+                continue;
+            }
+            self.run_step(downstream, input, &mut execute);
+            let step = &self.steps[downstream];
+            ran.push(step.id.clone());
+            if step.succeeded() {
+                changed.insert(step.id.clone());
+            }
+        }
+        Ok(ran)
+    }
+
+    fn index(&self, step_id: &str) -> Option<usize> {
+        self.steps.iter().position(|step| step.id == step_id)
+    }
+
+    fn ready(&self, index: usize) -> bool {
+        let step = &self.steps[index];
+        step.after.iter().all(|upstream| self.get(upstream).is_some_and(RunStep::succeeded))
+    }
+
+    fn input_for(&self, index: usize) -> String {
+        let step = &self.steps[index];
+        match step.input_from {
+            StepInput::Task => self.task.clone(),
+            StepInput::Upstream => {
+                let outputs = step.after.iter().filter_map(|upstream| self.get(upstream)?.output.as_deref());
+                outputs.collect::<Vec<_>>().join("\n\n")
+            }
+        }
+    }
+
+    fn run_step(&mut self, index: usize, input: String, execute: &mut impl FnMut(&RunStep, &str) -> Result<String, String>) {
+        let step = &self.steps[index];
+        let (output, record) = guard_fallible_step(&step.agent, &step.id, || execute(step, &input));
+        if let StepStatus::Failed { error, .. } = &record.status {
+            warn!("{}: step {} failed: {}", self.workflow, step.id, error);
+        }
+        let step = &mut self.steps[index];
+        step.attempts += 1;
+        step.input = Some(input);
+        step.output = output;
+        step.status = Some(record.status);
+    }
+}
+
+fn main() {
+    let mut run = Run::new("Release notes", "Draft the release notes for 2.4")
+        .step(RunStep::new("Researcher", "Researcher"))
+        .step(RunStep::new("Writer", "Writer").reading_upstream().after(["Researcher"]));
+
+    // The writer's provider is down on the first attempt
+    run.execute(|step, _| match step.agent.as_str() {
+        "Writer" => Err("provider returned 503".to_string()),
+        _ => Ok("Three fixes and one new flag.".to_string()),
+    });
+    println!("complete: {}", run.is_complete());
+
+    let ran = run.retry_step("Writer", Resume::Downstream, |_, input| Ok(format!("Notes: {}", input)));
+    println!("{:?} -> {:?}", ran, run.output());
+}
+```
+
+### Notes
+* The retried step is sent the input it was sent before, even when a reviewer or a fix changed an
+  upstream output in the meantime. Retry the upstream step instead to send it something new.
+* Only steps that ran and did not succeed can be retried; `execute` runs the ones that never started.
+* A step's `attempts` counts the times it ran, retries included. Turns that a `Supervisor` requeues within
+  one attempt are not counted.
+* Step ids must be unique. A sequential workflow that lists the same agent twice records the second turn
+  under `<name>#2`.
@@ -23,6 +23,8 @@ use std::io::{Write, Read};
 // Use a logging library (e.g., log)
 use log::{info, error};
 
+use crate::swarms::structs::tenancy::{TenantId, TenantWorkspace};
+
 // Define a struct to hold the workspace manager's state
 struct WorkspaceManager {
     workspace_dir: String,
@@ -84,6 +86,21 @@ impl WorkspaceManager {
         self.api_key.clone()
     }
 
+    // Per-tenant directories under this workspace; multi-user servers store everything through it
+    fn tenants(&self) -> TenantWorkspace {
+        TenantWorkspace::new(&self.workspace_path)
+    }
+
+    // Create a tenant's directory tree (agents, runs, conversations, artifacts) if it doesn't exist
+    fn init_tenant(&self, tenant: &TenantId) -> std::io::Result<PathBuf> {
+        let tenants = self.tenants();
+        for dir in [tenants.agents_dir(tenant), tenants.runs_dir(tenant), tenants.conversations_dir(tenant), tenants.artifacts_dir(tenant)] {
+            std::fs::create_dir_all(dir)?;
+        }
+        info!("Workspace for tenant {} initialized.", tenant);
+        Ok(tenants.dir(tenant))
+    }
+
     // Initialize the workspace directory if it doesn't exist
     fn init_workspace(&self) {
         if !self.workspace_path.exists() {
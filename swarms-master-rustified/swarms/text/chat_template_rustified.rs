@@ -0,0 +1,293 @@
+### Overview
+There is no Python counterpart; the Python package only talks to chat APIs, which apply the model's
+template server-side. Local models served raw (Ollama with `raw: true`, llama.cpp, a bare
+`/completions` endpoint) take a single prompt string instead, and each model family expects its own
+markup around every message. A Llama 3 model given ChatML keeps writing past its turn.
+
+`ChatTemplate` describes one family's markup, and `ChatTemplate::render` turns a `Conversation` into the
+raw prompt, ending with the header that asks the model for the assistant's turn.
+`ChatTemplateRegistry::builtin` has templates for Llama 3, Mistral and ChatML (Qwen, Hermes, Yi) and picks
+one from the model name. Templates loaded with `load_file` or `load_dir` are checked before the built-in
+ones, so a file can override a family or add a new one.
+
+Conversation roles are free-form, so they are mapped onto the three roles templates know: `system` (also
+the builder's `System:`), `user` (also `human`), and `assistant` for everything else, which covers the
+agent names replies are stored under.
+
+### Rust Code
+```rust
+use crate::swarms::structs::conversation::Conversation;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug)]
+pub enum ChatTemplateError {
+    Io { path: PathBuf, source: io::Error },
+    Parse { path: PathBuf, message: String },
+    /// A message format without `{content}`, which would drop every message's text.
+    MissingContent { template: String, role: String },
+}
+
+impl fmt::Display for ChatTemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChatTemplateError::Io { path, source } => write!(f, "failed to read {}: {}", path.display(), source),
+            ChatTemplateError::Parse { path, message } => {
+                write!(f, "chat template {} does not parse: {}", path.display(), message)
+            }
+            ChatTemplateError::MissingContent { template, role } => {
+                write!(f, "chat template '{}' has no {{content}} in its {} format", template, role)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChatTemplateError {}
+
+/// Where a template puts the system prompt.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SystemPlacement {
+    /// A message of its own, in the `system` format.
+    #[default]
+    Message,
+    /// Prepended to the first user message, for families without a system role (Mistral).
+    FirstUser,
+}
+
+/// One model family's prompt markup. Formats are plain strings with `{role}` and `{content}` placeholders.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatTemplate {
+    pub name: String,
+    /// Lowercase substrings of the model names this template is for, e.g. `llama3` or `llama-3`.
+    #[serde(default)]
+    pub models: Vec<String>,
+    /// Written once, before the first message.
+    #[serde(default)]
+    pub bos: String,
+    pub system: String,
+    pub user: String,
+    pub assistant: String,
+    /// Ends the prompt and opens the assistant's turn.
+    #[serde(default)]
+    pub generation_prompt: String,
+    #[serde(default)]
+    pub system_placement: SystemPlacement,
+    /// The family's end-of-turn markers, for `StopConditions` and the provider's `stop`.
+    #[serde(default)]
+    pub stop: Vec<String>,
+}
+
+/// The role a template formats a conversation message as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateRole {
+    System,
+    User,
+    Assistant,
+}
+
+impl TemplateRole {
+    pub fn of(role: &str) -> Self {
+        match role.trim().trim_end_matches(':').to_ascii_lowercase().as_str() {
+            "system" => TemplateRole::System,
+            "user" | "human" => TemplateRole::User,
+            _ => TemplateRole::Assistant,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TemplateRole::System => "system",
+            TemplateRole::User => "user",
+            TemplateRole::Assistant => "assistant",
+        }
+    }
+}
+
+impl ChatTemplate {
+    /// Meta's Llama 3 and 3.x instruct models.
+    pub fn llama3() -> Self {
+        let message = "<|start_header_id|>{role}<|end_header_id|>\n\n{content}<|eot_id|>";
+        ChatTemplate {
+            name: "llama3".to_string(),
+            models: vec!["llama3".to_string(), "llama-3".to_string(), "llama_3".to_string()],
+            bos: "<|begin_of_text|>".to_string(),
+            system: message.to_string(),
+            user: message.to_string(),
+            assistant: message.to_string(),
+            generation_prompt: "<|start_header_id|>assistant<|end_header_id|>\n\n".to_string(),
+            system_placement: SystemPlacement::Message,
+            stop: vec!["<|eot_id|>".to_string()],
+        }
+    }
+
+    /// Mistral and Mixtral instruct models, which have no system role.
+    pub fn mistral() -> Self {
+        ChatTemplate {
+            name: "mistral".to_string(),
+            models: vec!["mistral".to_string(), "mixtral".to_string()],
+            bos: "<s>".to_string(),
+            system: "{content}".to_string(),
+            user: "[INST] {content} [/INST]".to_string(),
+            assistant: "{content}</s>".to_string(),
+            generation_prompt: String::new(),
+            system_placement: SystemPlacement::FirstUser,
+            stop: vec!["</s>".to_string()],
+        }
+    }
+
+    /// ChatML, used by Qwen, Nous Hermes, Yi, and most fine-tunes that do not ship their own template.
+    pub fn chatml() -> Self {
+        let message = "<|im_start|>{role}\n{content}<|im_end|>\n";
+        ChatTemplate {
+            name: "chatml".to_string(),
+            models: vec!["qwen".to_string(), "hermes".to_string(), "yi-".to_string(), "chatml".to_string()],
+            bos: String::new(),
+            system: message.to_string(),
+            user: message.to_string(),
+            assistant: message.to_string(),
+            generation_prompt: "<|im_start|>assistant\n".to_string(),
+            system_placement: SystemPlacement::Message,
+            stop: vec!["<|im_end|>".to_string()],
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ChatTemplateError> {
+        for (role, format) in [("system", &self.system), ("user", &self.user), ("assistant", &self.assistant)] {
+            if !format.contains("{content}") {
+                return Err(ChatTemplateError::MissingContent { template: self.name.clone(), role: role.to_string() });
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether `model` (e.g. Ollama's `llama3.1:8b`) belongs to this template's family.
+    pub fn matches(&self, model: &str) -> bool {
+        let model = model.to_ascii_lowercase();
+        self.models.iter().any(|pattern| model.contains(pattern.as_str()))
+    }
+
+    fn format(&self, role: TemplateRole, content: &str) -> String {
+        let format = match role {
+            TemplateRole::System => &self.system,
+            TemplateRole::User => &self.user,
+            TemplateRole::Assistant => &self.assistant,
+        };
+        // `{content}` goes in last, so placeholders inside the message text are left alone.
+        format.replace("{role}", role.as_str()).replace("{content}", content)
+    }
+
+    /// The raw prompt for `messages` as `(role, content)` pairs, ending with the generation prompt.
+    pub fn render_messages<'a>(&self, messages: impl IntoIterator<Item = (&'a str, &'a str)>) -> String {
+        let mut prompt = self.bos.clone();
+        let mut pending_system: Vec<&str> = Vec::new();
+        for (role, content) in messages {
+            let role = TemplateRole::of(role);
+            match (role, self.system_placement) {
+                (TemplateRole::System, SystemPlacement::FirstUser) => pending_system.push(content),
+                (TemplateRole::User, SystemPlacement::FirstUser) if !pending_system.is_empty() => {
+                    pending_system.push(content);
+                    prompt.push_str(&self.format(role, &pending_system.join("\n\n")));
+                    pending_system.clear();
+                }
+                _ => prompt.push_str(&self.format(role, content)),
+            }
+        }
+        // A system prompt with no user message after it still has to reach the model.
+        if !pending_system.is_empty() {
+            prompt.push_str(&self.format(TemplateRole::User, &pending_system.join("\n\n")));
+        }
+        prompt.push_str(&self.generation_prompt);
+        prompt
+    }
+
+    /// The raw prompt for everything `conversation` sends the model, scratchpad included.
+    pub fn render(&self, conversation: &Conversation) -> String {
+        self.render_messages(
+            conversation.messages_for_model().into_iter().map(|message| (message.role.as_str(), message.content.as_str())),
+        )
+    }
+}
+
+/// Templates by model family. Templates added later are matched first.
+#[derive(Debug, Clone, Default)]
+pub struct ChatTemplateRegistry {
+    templates: Vec<ChatTemplate>,
+}
+
+impl ChatTemplateRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Llama 3, Mistral, and ChatML.
+    pub fn builtin() -> Self {
+        ChatTemplateRegistry { templates: vec![ChatTemplate::chatml(), ChatTemplate::mistral(), ChatTemplate::llama3()] }
+    }
+
+    /// Add `template`, replacing any template with the same name.
+    pub fn register(&mut self, template: ChatTemplate) -> Result<(), ChatTemplateError> {
+        template.validate()?;
+        self.templates.retain(|existing| existing.name != template.name);
+        self.templates.push(template);
+        Ok(())
+    }
+
+    /// Register the template in a YAML or JSON file.
+    pub fn load_file(&mut self, path: impl AsRef<Path>) -> Result<(), ChatTemplateError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|source| ChatTemplateError::Io { path: path.to_path_buf(), source })?;
+        // JSON is valid YAML, so one parser reads both.
+        let template: ChatTemplate = serde_yaml::from_str(&text)
+            .map_err(|err| ChatTemplateError::Parse { path: path.to_path_buf(), message: err.to_string() })?;
+        self.register(template)
+    }
+
+    /// Register every `.yaml`, `.yml`, and `.json` file in `dir`, in file name order. Returns how many.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> Result<usize, ChatTemplateError> {
+        let dir = dir.as_ref();
+        let io_error = |source| ChatTemplateError::Io { path: dir.to_path_buf(), source };
+        let mut paths = Vec::new();
+        for entry in fs::read_dir(dir).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            if matches!(path.extension().and_then(|ext| ext.to_str()), Some("yaml" | "yml" | "json")) {
+                paths.push(path);
+            }
+        }
+        paths.sort();
+        for path in &paths {
+            self.load_file(path)?;
+        }
+        Ok(paths.len())
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ChatTemplate> {
+        self.templates.iter().rev().find(|template| template.name == name)
+    }
+
+    /// The template for `model`, checking the most recently added first.
+    pub fn for_model(&self, model: &str) -> Option<&ChatTemplate> {
+        self.templates.iter().rev().find(|template| template.matches(model))
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut conversation = Conversation::builder().system_prompt("You are a concise analyst.").build()?;
+    conversation.add("User".to_string(), "Summarise Q3 revenue.".to_string());
+
+    let registry = ChatTemplateRegistry::builtin();
+    for model in ["llama3.1:8b", "mistral:7b-instruct", "qwen2.5:14b"] {
+        let template = registry.for_model(model).expect("built-in family");
+        println!("--- {} ({})\n{}", model, template.name, template.render(&conversation));
+    }
+    Ok(())
+}
+```
+
+### Notes
+* `for_model` matches substrings, so patterns should be specific enough not to catch other families; `yi-` has its dash so that it does not match every model with "yi" in its name.
+* Pass `stop` on to `StopConditions` and the provider's `stop` so that a model that misses its end-of-turn token does not write the user's next turn itself.
+* Templates from Hugging Face `tokenizer_config.json` files are Jinja; they have to be converted to the formats above by hand.
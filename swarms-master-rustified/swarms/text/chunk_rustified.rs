@@ -0,0 +1,338 @@
+### Overview
+There is no Python counterpart to this module; the Python agent hands whole documents to its memory
+backend and lets the vector store split them. The Rust port chunks documents itself so that loaders and
+memory ingestion share one implementation. Four strategies are provided:
+
+* **Fixed size** packs whitespace-separated words up to a token limit.
+* **Sentence** packs whole sentences, so no sentence is cut in half unless it alone exceeds the limit.
+* **Recursive character** splits on paragraphs, then lines, then spaces, then characters, only going
+  finer where a piece is still too large (the LangChain `RecursiveCharacterTextSplitter` behaviour).
+* **Markdown header** splits at `#` headings and records the heading path of each section; oversized
+  sections fall back to recursive splitting.
+
+All strategies measure size with a `Tokenizer`, support token-based overlap, and return `Chunk`s with byte
+offsets into the original text so callers can map results back to their source.
+
+### Rust Code
+```rust
+use crate::swarms::text::tokenizer::{default_tokenizer, Tokenizer};
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+use std::sync::Arc;
+
+/// A piece of a document plus the metadata needed to trace it back.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Chunk {
+    pub text: String,
+    /// Position of this chunk in the document's chunk list.
+    pub index: usize,
+    /// Byte offset of the first character in the source text.
+    pub start: usize,
+    /// Byte offset one past the last character in the source text.
+    pub end: usize,
+    pub token_count: usize,
+    /// Where the text came from (file path, URL, document id).
+    pub source: Option<String>,
+    /// Enclosing Markdown headings, outermost first. Empty for other strategies.
+    pub heading_path: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", rename_all = "snake_case")]
+pub enum ChunkStrategy {
+    FixedSize { max_tokens: usize, overlap_tokens: usize },
+    Sentence { max_tokens: usize, overlap_tokens: usize },
+    RecursiveCharacter { max_tokens: usize, overlap_tokens: usize },
+    MarkdownHeader { max_tokens: usize, overlap_tokens: usize },
+}
+
+impl Default for ChunkStrategy {
+    fn default() -> Self {
+        ChunkStrategy::RecursiveCharacter {
+            max_tokens: 512,
+            overlap_tokens: 64,
+        }
+    }
+}
+
+const RECURSIVE_SEPARATORS: [&str; 3] = ["\n\n", "\n", " "];
+
+/// Splits documents into `Chunk`s according to a `ChunkStrategy`.
+#[derive(Clone)]
+pub struct Chunker {
+    strategy: ChunkStrategy,
+    tokenizer: Arc<dyn Tokenizer>,
+}
+
+impl Chunker {
+    pub fn new(strategy: ChunkStrategy) -> Self {
+        Chunker {
+            strategy,
+            tokenizer: default_tokenizer(),
+        }
+    }
+
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn strategy(&self) -> &ChunkStrategy {
+        &self.strategy
+    }
+
+    /// Chunk `text`, tagging every chunk with `source`.
+    pub fn chunk(&self, text: &str, source: Option<&str>) -> Vec<Chunk> {
+        let sections: Vec<(Range<usize>, Vec<String>)> = match &self.strategy {
+            ChunkStrategy::MarkdownHeader { .. } => markdown_sections(text),
+            _ => vec![(0..text.len(), Vec::new())],
+        };
+        let (max_tokens, overlap_tokens) = self.limits();
+
+        let mut chunks = Vec::new();
+        for (section, heading_path) in sections {
+            let units = match &self.strategy {
+                ChunkStrategy::FixedSize { .. } => split_words(text, section),
+                ChunkStrategy::Sentence { .. } => split_sentences(text, section),
+                ChunkStrategy::RecursiveCharacter { .. } | ChunkStrategy::MarkdownHeader { .. } => {
+                    self.split_recursive(text, section, max_tokens, &RECURSIVE_SEPARATORS)
+                }
+            };
+            let units = self.split_oversized(text, units, max_tokens);
+            for range in self.pack(text, &units, max_tokens, overlap_tokens) {
+                let chunk_text = &text[range.clone()];
+                if chunk_text.trim().is_empty() {
+                    continue;
+                }
+                chunks.push(Chunk {
+                    text: chunk_text.to_string(),
+                    index: chunks.len(),
+                    start: range.start,
+                    end: range.end,
+                    token_count: self.tokenizer.count_tokens(chunk_text),
+                    source: source.map(str::to_string),
+                    heading_path: heading_path.clone(),
+                });
+            }
+        }
+        chunks
+    }
+
+    fn limits(&self) -> (usize, usize) {
+        let (max_tokens, overlap_tokens) = match self.strategy {
+            ChunkStrategy::FixedSize { max_tokens, overlap_tokens }
+            | ChunkStrategy::Sentence { max_tokens, overlap_tokens }
+            | ChunkStrategy::RecursiveCharacter { max_tokens, overlap_tokens }
+            | ChunkStrategy::MarkdownHeader { max_tokens, overlap_tokens } => (max_tokens, overlap_tokens),
+        };
+        let max_tokens = max_tokens.max(1);
+        // Overlap must leave room for new content or packing would never advance.
+        (max_tokens, overlap_tokens.min(max_tokens / 2))
+    }
+
+    fn tokens(&self, text: &str, range: &Range<usize>) -> usize {
+        self.tokenizer.count_tokens(&text[range.clone()])
+    }
+
+    /// Split `range` on the first separator that yields pieces, recursing into pieces that
+    /// are still over `max_tokens` with the remaining, finer separators.
+    fn split_recursive(
+        &self,
+        text: &str,
+        range: Range<usize>,
+        max_tokens: usize,
+        separators: &[&str],
+    ) -> Vec<Range<usize>> {
+        if self.tokens(text, &range) <= max_tokens || separators.is_empty() {
+            return vec![range];
+        }
+        let (separator, finer) = (separators[0], &separators[1..]);
+        let pieces = split_keeping_separator(text, range.clone(), separator);
+        if pieces.len() <= 1 {
+            return self.split_recursive(text, range, max_tokens, finer);
+        }
+        pieces
+            .into_iter()
+            .flat_map(|piece| self.split_recursive(text, piece, max_tokens, finer))
+            .collect()
+    }
+
+    /// Hard-split any unit that alone exceeds `max_tokens` on character boundaries. Each piece is the
+    /// longest prefix that fits, found by binary search over the character ends, so a unit of `n`
+    /// characters costs `O(log n)` tokenizer calls per piece instead of one per character.
+    fn split_oversized(&self, text: &str, units: Vec<Range<usize>>, max_tokens: usize) -> Vec<Range<usize>> {
+        let mut out = Vec::with_capacity(units.len());
+        for unit in units {
+            if self.tokens(text, &unit) <= max_tokens {
+                out.push(unit);
+                continue;
+            }
+            let ends: Vec<usize> =
+                text[unit.clone()].char_indices().map(|(offset, ch)| unit.start + offset + ch.len_utf8()).collect();
+            let mut start = unit.start;
+            let mut first = 0;
+            while first < ends.len() {
+                // Token counts grow with the prefix, so the ends that fit come first.
+                let fit = ends[first..].partition_point(|&end| self.tokens(text, &(start..end)) <= max_tokens);
+                // A single character over the limit still becomes its own piece.
+                let last = first + fit.max(1) - 1;
+                out.push(start..ends[last]);
+                start = ends[last];
+                first = last + 1;
+            }
+        }
+        out
+    }
+
+    /// Greedily pack contiguous units into chunks of at most `max_tokens`, starting each new
+    /// chunk with trailing units of the previous one worth up to `overlap_tokens`.
+    fn pack(&self, text: &str, units: &[Range<usize>], max_tokens: usize, overlap_tokens: usize) -> Vec<Range<usize>> {
+        let counts: Vec<usize> = units.iter().map(|u| self.tokens(text, u)).collect();
+        let mut chunks = Vec::new();
+        let mut first = 0;
+
+        while first < units.len() {
+            let mut last = first;
+            let mut used = counts[first];
+            while last + 1 < units.len() && used + counts[last + 1] <= max_tokens {
+                last += 1;
+                used += counts[last];
+            }
+            chunks.push(units[first].start..units[last].end);
+            if last + 1 >= units.len() {
+                break;
+            }
+
+            // Walk back from the end of this chunk to find where the overlap starts.
+            let mut next = last + 1;
+            let mut overlap = 0;
+            while next - 1 > first && overlap + counts[next - 1] <= overlap_tokens {
+                next -= 1;
+                overlap += counts[next];
+            }
+            first = next;
+        }
+        chunks
+    }
+}
+
+/// Split on `separator`, keeping it attached to the preceding piece so offsets stay contiguous.
+fn split_keeping_separator(text: &str, range: Range<usize>, separator: &str) -> Vec<Range<usize>> {
+    let slice = &text[range.clone()];
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for (pos, _) in slice.match_indices(separator) {
+        let end = pos + separator.len();
+        if end > start {
+            pieces.push(range.start + start..range.start + end);
+        }
+        start = end;
+    }
+    if start < slice.len() {
+        pieces.push(range.start + start..range.end);
+    }
+    pieces
+}
+
+/// Words with their trailing whitespace.
+fn split_words(text: &str, range: Range<usize>) -> Vec<Range<usize>> {
+    let slice = &text[range.clone()];
+    let mut units = Vec::new();
+    let mut start = 0;
+    let mut in_space = false;
+    for (pos, ch) in slice.char_indices() {
+        if ch.is_whitespace() {
+            in_space = true;
+        } else if in_space {
+            units.push(range.start + start..range.start + pos);
+            start = pos;
+            in_space = false;
+        }
+    }
+    if start < slice.len() {
+        units.push(range.start + start..range.end);
+    }
+    units
+}
+
+/// Sentences ending in `.`, `!`, or `?` followed by whitespace, or at a blank line.
+fn split_sentences(text: &str, range: Range<usize>) -> Vec<Range<usize>> {
+    let slice = &text[range.clone()];
+    let mut units = Vec::new();
+    let mut start = 0;
+    let mut chars = slice.char_indices().peekable();
+    while let Some((pos, ch)) = chars.next() {
+        let boundary = match (ch, chars.peek()) {
+            ('.' | '!' | '?', Some((_, next))) => next.is_whitespace(),
+            ('\n', Some((_, '\n'))) => true,
+            _ => false,
+        };
+        if boundary {
+            // Absorb the whitespace after the terminator into this sentence.
+            let mut end = pos + ch.len_utf8();
+            while let Some((next_pos, next)) = chars.peek() {
+                if !next.is_whitespace() {
+                    break;
+                }
+                end = next_pos + next.len_utf8();
+                chars.next();
+            }
+            units.push(range.start + start..range.start + end);
+            start = end;
+        }
+    }
+    if start < slice.len() {
+        units.push(range.start + start..range.end);
+    }
+    units
+}
+
+/// Sections delimited by Markdown ATX headings, each with its heading path.
+fn markdown_sections(text: &str) -> Vec<(Range<usize>, Vec<String>)> {
+    let mut sections = Vec::new();
+    let mut path: Vec<(usize, String)> = Vec::new();
+    let mut section_start = 0;
+    let mut offset = 0;
+    let mut in_fence = false;
+
+    for line in text.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") {
+            in_fence = !in_fence;
+        }
+        let level = trimmed.chars().take_while(|c| *c == '#').count();
+        let is_heading = !in_fence
+            && (1..=6).contains(&level)
+            && trimmed[level..].starts_with(|c: char| c == ' ' || c == '\t');
+
+        if is_heading {
+            if offset > section_start {
+                sections.push((section_start..offset, path.iter().map(|(_, h)| h.clone()).collect()));
+            }
+            path.retain(|(l, _)| *l < level);
+            path.push((level, trimmed[level..].trim().to_string()));
+            section_start = offset;
+        }
+        offset += line.len();
+    }
+    if offset > section_start {
+        sections.push((section_start..offset, path.iter().map(|(_, h)| h.clone()).collect()));
+    }
+    sections
+}
+
+fn main() {
+    let document = "# Revenue\nQ3 revenue rose 12%. Margins held steady.\n\n## Costs\nHeadcount grew by 4%.\n";
+    let chunker = Chunker::new(ChunkStrategy::MarkdownHeader {
+        max_tokens: 32,
+        overlap_tokens: 0,
+    });
+    for chunk in chunker.chunk(document, Some("q3_report.md")) {
+        println!("{:?} [{}..{}] {:?}", chunk.heading_path, chunk.start, chunk.end, chunk.text);
+    }
+}
+```
+
+### Notes
+* Chunks produced with overlap share byte ranges; `start`/`end` always index the original text.
+* Token counts come from the configured `Tokenizer`; the default heuristic tokenizer keeps chunking free of model files.
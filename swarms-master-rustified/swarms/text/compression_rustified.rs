@@ -0,0 +1,310 @@
+### Overview
+There is no Python counterpart. Retrieved context is the largest and least dense part of most prompts:
+recall returns overlapping chunks, boilerplate repeated across documents, and whole paragraphs of which one
+sentence answers the query. `ContextCompressor` shrinks a set of retrieved passages toward a token target
+before they are assembled into the prompt. It runs in up to three stages and stops as soon as the target
+is met:
+
+1. **Dedup** drops paragraphs that already appeared earlier (compared after case and whitespace folding).
+2. **Extractive** keeps the sentences that share the most non-stopword terms with the query, weighted by
+   how rare each term is across the passages, and restores their original order.
+3. **Token pruning** (LLMLingua-style) drops the least informative words inside the kept sentences,
+   as judged by a `TokenImportance` scorer. The default scorer is a heuristic; a small language model's
+   per-token surprisal can be plugged in instead.
+
+Every call logs the token counts before and after.
+
+### Rust Code
+```rust
+use crate::swarms::text::tokenizer::{default_tokenizer, Tokenizer};
+use log::info;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// A small English stopword list; enough to keep function words from dominating overlap scores.
+const STOPWORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "but", "by", "for", "from", "has", "have", "he", "her", "his", "i",
+    "if", "in", "into", "is", "it", "its", "of", "on", "or", "our", "she", "so", "than", "that", "the", "their",
+    "them", "there", "these", "they", "this", "to", "was", "we", "were", "what", "when", "which", "who", "will",
+    "with", "would", "you", "your",
+];
+
+fn is_stopword(word: &str) -> bool {
+    STOPWORDS.contains(&word)
+}
+
+/// Lowercased alphanumeric terms of `text`.
+fn terms(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric()).filter(|t| !t.is_empty()).map(str::to_lowercase)
+}
+
+/// How far compression may go.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompressionLevel {
+    /// Only remove repeated paragraphs.
+    Dedup,
+    /// Dedup, then keep the most query-relevant sentences.
+    Extractive,
+    /// All of the above, then prune low-importance words.
+    TokenPruning,
+}
+
+/// Scores words by how much information they carry; low scores are pruned first.
+pub trait TokenImportance: Send + Sync {
+    /// One score per word in `words`.
+    fn score(&self, words: &[&str], query_terms: &HashSet<String>) -> Vec<f32>;
+}
+
+/// Heuristic importance: query terms and numbers score highest, stopwords lowest, and longer words
+/// above shorter ones.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicImportance;
+
+impl TokenImportance for HeuristicImportance {
+    fn score(&self, words: &[&str], query_terms: &HashSet<String>) -> Vec<f32> {
+        words
+            .iter()
+            .map(|word| {
+                let term: String = word.chars().filter(|c| c.is_alphanumeric()).collect::<String>().to_lowercase();
+                if term.is_empty() {
+                    0.5
+                } else if query_terms.contains(&term) || term.chars().any(|c| c.is_ascii_digit()) {
+                    10.0
+                } else if is_stopword(&term) {
+                    0.0
+                } else {
+                    1.0 + (term.chars().count() as f32).ln()
+                }
+            })
+            .collect()
+    }
+}
+
+/// The outcome of a compression pass.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Compressed {
+    /// One entry per input passage, in input order, so callers can zip with their sources.
+    /// A passage with nothing left is an empty string.
+    pub passages: Vec<String>,
+    pub tokens_before: usize,
+    pub tokens_after: usize,
+}
+
+impl Compressed {
+    /// `tokens_after / tokens_before`; 1.0 for empty input.
+    pub fn ratio(&self) -> f32 {
+        if self.tokens_before == 0 {
+            1.0
+        } else {
+            self.tokens_after as f32 / self.tokens_before as f32
+        }
+    }
+}
+
+/// Compresses retrieved passages toward a token target.
+#[derive(Clone)]
+pub struct ContextCompressor {
+    target_tokens: usize,
+    level: CompressionLevel,
+    tokenizer: Arc<dyn Tokenizer>,
+    importance: Arc<dyn TokenImportance>,
+}
+
+impl ContextCompressor {
+    pub fn new(target_tokens: usize) -> Self {
+        ContextCompressor {
+            target_tokens,
+            level: CompressionLevel::Extractive,
+            tokenizer: default_tokenizer(),
+            importance: Arc::new(HeuristicImportance),
+        }
+    }
+
+    pub fn level(mut self, level: CompressionLevel) -> Self {
+        self.level = level;
+        self
+    }
+
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    pub fn with_importance(mut self, importance: Arc<dyn TokenImportance>) -> Self {
+        self.importance = importance;
+        self
+    }
+
+    pub fn target_tokens(&self) -> usize {
+        self.target_tokens
+    }
+
+    fn count(&self, passages: &[Vec<String>]) -> usize {
+        passages.iter().flatten().map(|unit| self.tokenizer.count_tokens(unit)).sum()
+    }
+
+    /// Compress `passages` for `query`.
+    pub fn compress(&self, query: &str, passages: &[String]) -> Compressed {
+        let tokens_before: usize = passages.iter().map(|p| self.tokenizer.count_tokens(p)).sum();
+
+        // Each passage is held as a list of units: paragraphs after dedup, sentences after extraction.
+        let mut units = dedup_paragraphs(passages);
+        if self.level >= CompressionLevel::Extractive && self.count(&units) > self.target_tokens {
+            units = self.extract(query, units);
+        }
+        if self.level >= CompressionLevel::TokenPruning && self.count(&units) > self.target_tokens {
+            units = self.prune(query, units);
+        }
+
+        let passages: Vec<String> = units.into_iter().map(|p| p.join(" ")).collect();
+        let tokens_after = passages.iter().map(|p| self.tokenizer.count_tokens(p)).sum();
+        info!(
+            "Compressed {} passages from {} to {} tokens (target {})",
+            passages.iter().filter(|p| !p.is_empty()).count(),
+            tokens_before,
+            tokens_after,
+            self.target_tokens
+        );
+        Compressed { passages, tokens_before, tokens_after }
+    }
+
+    fn extract(&self, query: &str, passages: Vec<Vec<String>>) -> Vec<Vec<String>> {
+        let sentences: Vec<Vec<String>> =
+            passages.iter().map(|p| p.iter().flat_map(|para| split_sentences(para)).collect()).collect();
+
+        // Document frequency of each term, counting every sentence as a document.
+        let mut df: HashMap<String, usize> = HashMap::new();
+        let total = sentences.iter().map(Vec::len).sum::<usize>().max(1) as f32;
+        for sentence in sentences.iter().flatten() {
+            for term in terms(sentence).collect::<HashSet<_>>() {
+                *df.entry(term).or_default() += 1;
+            }
+        }
+        let query_terms: HashSet<String> = terms(query).filter(|t| !is_stopword(t)).collect();
+
+        let mut scored: Vec<(usize, usize, f32, f32, usize)> = Vec::new();
+        for (p, passage) in sentences.iter().enumerate() {
+            for (s, sentence) in passage.iter().enumerate() {
+                let overlap: f32 = terms(sentence)
+                    .collect::<HashSet<_>>()
+                    .iter()
+                    .filter(|t| query_terms.contains(*t))
+                    .map(|t| (total / df[t] as f32).ln() + 1.0)
+                    .sum();
+                // Leading sentences tend to state the topic; give them a small edge on ties.
+                let score = overlap + 0.1 / (s as f32 + 1.0);
+                scored.push((p, s, score, overlap, self.tokenizer.count_tokens(sentence)));
+            }
+        }
+        scored.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal).then((a.0, a.1).cmp(&(b.0, b.1))));
+
+        let mut kept: HashSet<(usize, usize)> = HashSet::new();
+        let mut used = 0;
+        for (p, s, _, overlap, tokens) in scored {
+            // Sentences sharing no terms with the query are dropped even when there is room for them.
+            if !kept.is_empty() && (overlap == 0.0 || used + tokens > self.target_tokens) {
+                continue;
+            }
+            used += tokens;
+            kept.insert((p, s));
+        }
+        sentences
+            .into_iter()
+            .enumerate()
+            .map(|(p, passage)| passage.into_iter().enumerate().filter(|(s, _)| kept.contains(&(p, *s))).map(|(_, t)| t).collect())
+            .collect()
+    }
+
+    fn prune(&self, query: &str, passages: Vec<Vec<String>>) -> Vec<Vec<String>> {
+        let query_terms: HashSet<String> = terms(query).filter(|t| !is_stopword(t)).collect();
+        let mut words: Vec<(usize, usize, &str)> = Vec::new();
+        for (p, passage) in passages.iter().enumerate() {
+            for (s, sentence) in passage.iter().enumerate() {
+                words.extend(sentence.split_whitespace().map(|w| (p, s, w)));
+            }
+        }
+        let plain: Vec<&str> = words.iter().map(|w| w.2).collect();
+        let scores = self.importance.score(&plain, &query_terms);
+
+        // Drop the lowest-scoring words until the estimate fits; earlier words win ties.
+        let mut order: Vec<usize> = (0..words.len()).collect();
+        order.sort_by(|&a, &b| scores[a].partial_cmp(&scores[b]).unwrap_or(std::cmp::Ordering::Equal).then(b.cmp(&a)));
+        let mut remaining = self.count(&passages);
+        let mut dropped = HashSet::new();
+        for index in order {
+            if remaining <= self.target_tokens {
+                break;
+            }
+            remaining = remaining.saturating_sub(self.tokenizer.count_tokens(words[index].2).max(1));
+            dropped.insert(index);
+        }
+
+        let mut out: Vec<Vec<String>> = passages.iter().map(|p| vec![String::new(); p.len()]).collect();
+        for (index, (p, s, word)) in words.iter().enumerate() {
+            if dropped.contains(&index) {
+                continue;
+            }
+            let sentence = &mut out[*p][*s];
+            if !sentence.is_empty() {
+                sentence.push(' ');
+            }
+            sentence.push_str(word);
+        }
+        out.into_iter().map(|p| p.into_iter().filter(|s| !s.is_empty()).collect()).collect()
+    }
+}
+
+/// Split each passage into paragraphs and drop any paragraph seen earlier in any passage.
+pub fn dedup_paragraphs(passages: &[String]) -> Vec<Vec<String>> {
+    let mut seen = HashSet::new();
+    passages
+        .iter()
+        .map(|passage| {
+            passage
+                .split("\n\n")
+                .map(str::trim)
+                .filter(|para| !para.is_empty())
+                .filter(|para| seen.insert(para.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()))
+                .map(str::to_string)
+                .collect()
+        })
+        .collect()
+}
+
+/// Split on sentence-ending punctuation followed by whitespace.
+pub fn split_sentences(text: &str) -> Vec<String> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        if matches!(c, '.' | '!' | '?') && chars.peek().map_or(true, |(_, next)| next.is_whitespace()) {
+            let end = i + c.len_utf8();
+            let sentence = text[start..end].trim();
+            if !sentence.is_empty() {
+                sentences.push(sentence.to_string());
+            }
+            start = end;
+        }
+    }
+    let tail = text[start..].trim();
+    if !tail.is_empty() {
+        sentences.push(tail.to_string());
+    }
+    sentences
+}
+
+fn main() {
+    let passages = vec![
+        "Apple reported Q3 revenue of $81.8B. Services grew 14% year over year.\n\nForward-looking statements involve risk.".to_string(),
+        "Forward-looking statements involve risk.\n\nThe company returned $24B to shareholders.".to_string(),
+    ];
+    let compressor = ContextCompressor::new(20).level(CompressionLevel::TokenPruning);
+    let compressed = compressor.compress("How fast did services revenue grow?", &passages);
+    println!("{:?} ({:.0}%)", compressed.passages, compressed.ratio() * 100.0);
+}
+```
+
+### Notes
+* Compression never reorders content: kept sentences and words appear in their original order.
+* The target is a goal, not a hard cap. Extraction always keeps at least one sentence, and pruning counts each dropped word on its own, so the result can land a little off target.
+* Token pruning makes text ungrammatical. Reserve it for models that tolerate telegraphic context, and prefer `Extractive` otherwise.
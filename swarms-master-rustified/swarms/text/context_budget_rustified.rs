@@ -0,0 +1,251 @@
+### Overview
+There is no Python counterpart. The Python `Agent` carries a single `context_length` and each consumer
+decides for itself how much of it to use: `Conversation` truncates history against the whole window, the
+tool catalog has its own fixed budget, and retrieved context is not counted at all. Together they can
+overflow the window, and nothing leaves room for the reply.
+
+`ContextBudget` replaces those fields with one plan. Given the model's window and the tokens reserved for
+output, `allocate` splits the remaining input budget between four sections:
+
+* **System prompt** is always kept whole. If it does not fit, allocation fails.
+* **Tools**, **memory** (RAG chunks), and **history** each get a guaranteed floor first. Whatever is left is
+  shared by weight, and a section never receives more than it asked for.
+
+The result depends only on the inputs, so the same demand always produces the same prompt layout.
+
+### Rust Code
+```rust
+use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Tokens reserved for the reply when none are configured.
+pub const DEFAULT_RESERVED_OUTPUT_TOKENS: usize = 4096;
+
+/// A part of the prompt that competes for context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContextSection {
+    SystemPrompt,
+    Tools,
+    Memory,
+    History,
+}
+
+/// Tokens each section would use if it had unlimited room.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContextDemand {
+    pub system_prompt: usize,
+    pub tools: usize,
+    pub memory: usize,
+    pub history: usize,
+}
+
+/// Tokens granted to each section.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextAllocation {
+    pub system_prompt: usize,
+    pub tools: usize,
+    pub memory: usize,
+    pub history: usize,
+}
+
+impl ContextAllocation {
+    pub fn total(&self) -> usize {
+        self.system_prompt + self.tools + self.memory + self.history
+    }
+
+    pub fn get(&self, section: ContextSection) -> usize {
+        match section {
+            ContextSection::SystemPrompt => self.system_prompt,
+            ContextSection::Tools => self.tools,
+            ContextSection::Memory => self.memory,
+            ContextSection::History => self.history,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BudgetError {
+    /// The reserved output alone fills the window.
+    NoInputRoom { max_context_tokens: usize, reserved_output_tokens: usize },
+    SystemPromptTooLarge { needed: usize, available: usize },
+}
+
+impl fmt::Display for BudgetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BudgetError::NoInputRoom { max_context_tokens, reserved_output_tokens } => write!(
+                f,
+                "reserving {} output tokens leaves no input room in a {}-token context",
+                reserved_output_tokens, max_context_tokens
+            ),
+            BudgetError::SystemPromptTooLarge { needed, available } => {
+                write!(f, "system prompt needs {} tokens but only {} are available", needed, available)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BudgetError {}
+
+/// Per-section floor and weight for the flexible sections.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SectionShare {
+    /// Tokens granted before any sharing, capped by the section's demand.
+    pub floor: usize,
+    /// Relative share of what is left after floors.
+    pub weight: u32,
+}
+
+/// How an agent's context window is divided. Replaces the per-struct `context_length` fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContextBudget {
+    pub max_context_tokens: usize,
+    #[serde(default = "default_reserved_output")]
+    pub reserved_output_tokens: usize,
+    #[serde(default = "default_tools_share")]
+    pub tools: SectionShare,
+    #[serde(default = "default_memory_share")]
+    pub memory: SectionShare,
+    #[serde(default = "default_history_share")]
+    pub history: SectionShare,
+}
+
+fn default_reserved_output() -> usize {
+    DEFAULT_RESERVED_OUTPUT_TOKENS
+}
+
+fn default_tools_share() -> SectionShare {
+    SectionShare { floor: 512, weight: 1 }
+}
+
+fn default_memory_share() -> SectionShare {
+    SectionShare { floor: 1024, weight: 2 }
+}
+
+fn default_history_share() -> SectionShare {
+    SectionShare { floor: 2048, weight: 3 }
+}
+
+impl ContextBudget {
+    pub fn new(max_context_tokens: usize, reserved_output_tokens: usize) -> Self {
+        ContextBudget {
+            max_context_tokens,
+            reserved_output_tokens,
+            tools: default_tools_share(),
+            memory: default_memory_share(),
+            history: default_history_share(),
+        }
+    }
+
+    /// A budget for the model's known window.
+    pub fn for_model(capabilities: &ProviderCapabilities, reserved_output_tokens: usize) -> Self {
+        Self::new(capabilities.max_context_tokens, reserved_output_tokens)
+    }
+
+    /// Read a legacy `context_length` setting, reserving the default output budget (or a quarter of the
+    /// window, if that is smaller).
+    pub fn from_context_length(context_length: i32) -> Self {
+        let max = context_length.max(0) as usize;
+        Self::new(max, DEFAULT_RESERVED_OUTPUT_TOKENS.min(max / 4))
+    }
+
+    pub fn share(mut self, section: ContextSection, floor: usize, weight: u32) -> Self {
+        let share = SectionShare { floor, weight };
+        match section {
+            ContextSection::Tools => self.tools = share,
+            ContextSection::Memory => self.memory = share,
+            ContextSection::History => self.history = share,
+            // The system prompt is never shared; it is kept whole or allocation fails.
+            ContextSection::SystemPrompt => {}
+        }
+        self
+    }
+
+    /// Tokens available to the prompt after the output reservation.
+    pub fn available_input(&self) -> usize {
+        self.max_context_tokens.saturating_sub(self.reserved_output_tokens)
+    }
+
+    /// Split the input budget between the sections; see the module overview for the rules.
+    pub fn allocate(&self, demand: &ContextDemand) -> Result<ContextAllocation, BudgetError> {
+        let available = self.available_input();
+        if available == 0 {
+            return Err(BudgetError::NoInputRoom {
+                max_context_tokens: self.max_context_tokens,
+                reserved_output_tokens: self.reserved_output_tokens,
+            });
+        }
+        if demand.system_prompt > available {
+            return Err(BudgetError::SystemPromptTooLarge { needed: demand.system_prompt, available });
+        }
+        let mut remaining = available - demand.system_prompt;
+
+        // Fixed order everywhere below: tools, memory, history.
+        let wants = [demand.tools, demand.memory, demand.history];
+        let shares = [self.tools, self.memory, self.history];
+        let mut granted = [0usize; 3];
+
+        for i in 0..3 {
+            let floor = wants[i].min(shares[i].floor).min(remaining);
+            granted[i] = floor;
+            remaining -= floor;
+        }
+
+        // Weighted sharing of the rest, repeated while a round still makes progress. Sections that are
+        // satisfied drop out, so their unused share flows to the others.
+        loop {
+            let open: Vec<usize> = (0..3).filter(|&i| granted[i] < wants[i] && shares[i].weight > 0).collect();
+            let total_weight: u64 = open.iter().map(|&i| shares[i].weight as u64).sum();
+            if remaining == 0 || open.is_empty() {
+                break;
+            }
+            let pool = remaining;
+            let mut progressed = false;
+            for &i in &open {
+                let share = (pool as u64 * shares[i].weight as u64 / total_weight) as usize;
+                let grant = share.min(wants[i] - granted[i]).min(remaining);
+                granted[i] += grant;
+                remaining -= grant;
+                progressed |= grant > 0;
+            }
+            if !progressed {
+                // Integer division rounded every share to zero; hand out the remainder in order.
+                for &i in &open {
+                    let grant = (wants[i] - granted[i]).min(remaining);
+                    granted[i] += grant;
+                    remaining -= grant;
+                }
+            }
+        }
+
+        Ok(ContextAllocation {
+            system_prompt: demand.system_prompt,
+            tools: granted[0],
+            memory: granted[1],
+            history: granted[2],
+        })
+    }
+}
+
+impl Default for ContextBudget {
+    /// The 8k window `ProviderCapabilities::text_only` assumes for unknown models.
+    fn default() -> Self {
+        Self::from_context_length(8192)
+    }
+}
+
+fn main() -> Result<(), BudgetError> {
+    let capabilities = ProviderCapabilities::lookup("openai", "gpt-4o");
+    let budget = ContextBudget::for_model(&capabilities, 4096);
+    let allocation = budget.allocate(&ContextDemand { system_prompt: 1_200, tools: 3_000, memory: 60_000, history: 90_000 })?;
+    println!("{:?} of {} input tokens", allocation, budget.available_input());
+    Ok(())
+}
+```
+
+### Notes
+* Callers apply the allocation with the tools they already have: `ToolRegistry::render_tool_catalog(allocation.tools)`, `ContextCompressor::new(allocation.memory)`, and `Conversation::truncate_to(allocation.history)`.
+* A section with weight 0 gets its floor and nothing more.
+* `from_context_length` keeps old configs working; new configs should set `reserved_output_tokens` explicitly.
@@ -0,0 +1,103 @@
+### Overview
+There is no Python counterpart; the Python package logs prompts and responses in full wherever it logs
+them. Some deployments may not let raw prompts leave the process at all, even with personal data
+redacted: a legal team's questions are confidential whether or not they contain an email address.
+
+`PromptPrivacy` says what telemetry keeps of a prompt or response body:
+
+* `Raw` keeps it as it is. This is the default.
+* `Truncate` keeps the first `max_chars` characters and notes how many were dropped.
+* `Hash` replaces it with its SHA-256 and length, so two runs can still be checked for identical
+  prompts without either one being readable.
+
+Only the text is replaced. Token counts, costs, and timings are separate fields and are kept. The mode
+is chosen per run: `RunTrace::with_privacy` for run traces, and `WebhookDispatcher::set_run_privacy` (or
+`with_privacy` for every run) for webhook payloads, where it covers the body fields in `BODY_FIELDS`.
+It works alongside `Redactor`: redaction removes personal data from text that is kept, privacy decides
+whether the text is kept at all.
+
+### Rust Code
+```rust
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use sha2::{Digest, Sha256};
+
+/// The keys of event payloads whose string values are prompt or response bodies.
+pub const BODY_FIELDS: &[&str] = &["prompt", "output", "response", "summary", "task", "content", "system"];
+
+/// What telemetry keeps of a prompt or response body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum PromptPrivacy {
+    #[default]
+    Raw,
+    /// The first `max_chars` characters, then `…[N chars truncated]`.
+    Truncate { max_chars: usize },
+    /// `[sha256:<hex>, N chars]`.
+    Hash,
+}
+
+impl PromptPrivacy {
+    pub fn is_raw(&self) -> bool {
+        matches!(self, PromptPrivacy::Raw)
+    }
+
+    /// `text` as this mode keeps it. Empty text stays empty.
+    pub fn apply(&self, text: &str) -> String {
+        let chars = text.chars().count();
+        match self {
+            PromptPrivacy::Raw => text.to_string(),
+            PromptPrivacy::Truncate { max_chars } if chars <= *max_chars => text.to_string(),
+            PromptPrivacy::Truncate { max_chars } => {
+                let kept: String = text.chars().take(*max_chars).collect();
+                format!("{}…[{} chars truncated]", kept, chars - max_chars)
+            }
+            PromptPrivacy::Hash if text.is_empty() => String::new(),
+            PromptPrivacy::Hash => format!("[sha256:{}, {} chars]", hex::encode(Sha256::digest(text.as_bytes())), chars),
+        }
+    }
+
+    /// Apply the mode in place. A no-op for `Raw`.
+    pub fn apply_in_place(&self, text: &mut String) {
+        if !self.is_raw() {
+            *text = self.apply(text);
+        }
+    }
+
+    /// A copy of `value` with the string values under `BODY_FIELDS` keys, at any depth, passed through `apply`.
+    pub fn apply_json(&self, value: &JsonValue) -> JsonValue {
+        if self.is_raw() {
+            return value.clone();
+        }
+        match value {
+            JsonValue::Object(map) => JsonValue::Object(
+                map.iter()
+                    .map(|(key, value)| {
+                        let value = match value {
+                            JsonValue::String(text) if BODY_FIELDS.contains(&key.as_str()) => JsonValue::String(self.apply(text)),
+                            other => self.apply_json(other),
+                        };
+                        (key.clone(), value)
+                    })
+                    .collect(),
+            ),
+            JsonValue::Array(items) => JsonValue::Array(items.iter().map(|item| self.apply_json(item)).collect()),
+            other => other.clone(),
+        }
+    }
+}
+
+fn main() {
+    let prompt = "Draft the settlement terms for the Harlow matter.";
+    for mode in [PromptPrivacy::Raw, PromptPrivacy::Truncate { max_chars: 16 }, PromptPrivacy::Hash] {
+        println!("{:?}: {}", mode, mode.apply(prompt));
+    }
+    let payload = serde_json::json!({ "summary": prompt, "duration_secs": 4.2, "tokens": 812 });
+    println!("{}", PromptPrivacy::Hash.apply_json(&payload));
+}
+```
+
+### Notes
+* A hash of a short or predictable prompt can be reversed by hashing guesses. `Hash` is for matching runs against each other, not for secrecy; use `Truncate { max_chars: 0 }` when nothing of the text may be recoverable.
+* Truncation counts characters, not bytes, so it never splits a multi-byte character.
+* `BODY_FIELDS` is matched on key names only. A payload that carries a body under another key (a tool's free-form `details`, say) is not covered; run `Redactor` over it or leave it out of the payload.
@@ -0,0 +1,372 @@
+### Overview
+There is no Python counterpart; the Python package writes conversations, webhook payloads, artifacts, and
+log lines exactly as the agents produced them, so an email address or card number pasted into a task ends
+up in plaintext on disk and in log aggregation. `Redactor` finds personal data with regexes and checksum
+validation (no model involved) and replaces each match with a marker such as `[REDACTED:EMAIL]`:
+
+* **Emails**, by pattern.
+* **US Social Security numbers** (`123-45-6789`), skipping numbers the SSA never issues (area `000`, `666`,
+  or `9xx`, group `00`, serial `0000`).
+* **Card numbers** of 13 to 19 digits, optionally grouped with spaces or dashes, that pass the Luhn check.
+  The shortest such number at each position wins, so a card followed by more digits (a year, a quantity)
+  is still found.
+* **Custom patterns** from the configuration, each with its own marker name.
+
+It is applied on the way to storage: `ConversationBuilder::redactor` for saved conversations,
+`WebhookDispatcher::with_redactor` for event payloads, `Artifact::save_redacted` for artifacts, and
+`RedactingLogger` for log output. In memory the agents still see the original text.
+
+With `keep_originals`, the unredacted text is not dropped but sealed by a `Sealer` and stored next to the
+redacted copy, so an authorized operator can recover it. Such a redactor can only be built with
+`with_sealer`; `new` refuses the setting, so originals are never written in plaintext.
+
+### Rust Code
+```rust
+use log::{Log, Metadata, Record};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::Value as JsonValue;
+use std::fmt;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PiiKind {
+    Email,
+    Ssn,
+    CardNumber,
+    /// A configured pattern, by its name.
+    Custom(String),
+}
+
+impl PiiKind {
+    /// The name used in the replacement marker.
+    pub fn marker(&self) -> String {
+        match self {
+            PiiKind::Email => "[REDACTED:EMAIL]".to_string(),
+            PiiKind::Ssn => "[REDACTED:SSN]".to_string(),
+            PiiKind::CardNumber => "[REDACTED:CARD]".to_string(),
+            PiiKind::Custom(name) => format!("[REDACTED:{}]", name.to_uppercase()),
+        }
+    }
+}
+
+/// A site-specific pattern, e.g. internal account ids.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomPattern {
+    pub name: String,
+    pub pattern: String,
+}
+
+/// Which detectors run. Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedactionConfig {
+    pub emails: bool,
+    pub ssns: bool,
+    pub card_numbers: bool,
+    pub custom: Vec<CustomPattern>,
+    /// Seal the unredacted text with the redactor's `Sealer` instead of discarding it.
+    pub keep_originals: bool,
+}
+
+impl Default for RedactionConfig {
+    fn default() -> Self {
+        RedactionConfig { emails: true, ssns: true, card_numbers: true, custom: Vec::new(), keep_originals: false }
+    }
+}
+
+#[derive(Debug)]
+pub enum RedactionError {
+    /// A custom pattern that does not compile.
+    InvalidPattern { name: String, message: String },
+    /// `keep_originals` is set but no `Sealer` was given.
+    MissingSealer,
+    Seal(String),
+}
+
+impl fmt::Display for RedactionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            RedactionError::InvalidPattern { name, message } => write!(f, "invalid redaction pattern '{}': {}", name, message),
+            RedactionError::MissingSealer => write!(f, "keep_originals requires a sealer"),
+            RedactionError::Seal(message) => write!(f, "failed to seal original content: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for RedactionError {}
+
+/// Protects the original text when `keep_originals` is on. The result is opaque and stored as-is.
+pub trait Sealer: Send + Sync {
+    fn seal(&self, plaintext: &[u8]) -> Result<String, RedactionError>;
+}
+
+/// One redacted span, as byte offsets into the original text.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Finding {
+    pub kind: PiiKind,
+    pub start: usize,
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Redacted {
+    pub text: String,
+    pub findings: Vec<Finding>,
+}
+
+/// Text ready to persist: the redacted copy, and the sealed original when one is kept.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoredText {
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sealed_original: Option<String>,
+}
+
+pub struct Redactor {
+    detectors: Vec<(PiiKind, Regex)>,
+    keep_originals: bool,
+    sealer: Option<Arc<dyn Sealer>>,
+}
+
+impl fmt::Debug for Redactor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Redactor")
+            .field("detectors", &self.detectors.iter().map(|(kind, _)| kind).collect::<Vec<_>>())
+            .field("keep_originals", &self.keep_originals)
+            .finish()
+    }
+}
+
+const EMAIL: &str = r"(?i)\b[a-z0-9._%+-]+@[a-z0-9.-]+\.[a-z]{2,}\b";
+const SSN: &str = r"\b(\d{3})-(\d{2})-(\d{4})\b";
+// Lazy, so each position yields its shortest candidate; `card_end` tries longer ones
+const CARD: &str = r"\b(?:\d[ -]?){12,18}?\d\b";
+
+fn valid_ssn(digits: &str) -> bool {
+    let parts: Vec<&str> = digits.split('-').collect();
+    let (area, group, serial) = (parts[0], parts[1], parts[2]);
+    area != "000" && area != "666" && !area.starts_with('9') && group != "00" && serial != "0000"
+}
+
+fn luhn(number: &str) -> bool {
+    let digits: Vec<u32> = number.chars().filter_map(|c| c.to_digit(10)).collect();
+    if !(13..=19).contains(&digits.len()) {
+        return false;
+    }
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(i, &d)| if i % 2 == 1 { if d * 2 > 9 { d * 2 - 9 } else { d * 2 } } else { d })
+        .sum();
+    sum % 10 == 0
+}
+
+// The end of the shortest card number starting at `start`: the candidate the lazy pattern found, or a longer
+// one ending at a later word boundary, extended one digit at a time. `None` when none of them pass Luhn.
+fn card_end(text: &str, start: usize, shortest: usize) -> Option<usize> {
+    let bytes = text.as_bytes();
+    let at_boundary = |end: usize| !bytes.get(end).is_some_and(|b| b.is_ascii_alphanumeric() || *b == b'_');
+    let mut end = shortest;
+    loop {
+        let candidate = &text[start..end];
+        let digits = candidate.bytes().filter(u8::is_ascii_digit).count();
+        if digits > 19 {
+            return None;
+        }
+        if digits >= 13 && at_boundary(end) && luhn(candidate) {
+            return Some(end);
+        }
+        let next = if matches!(bytes.get(end), Some(b' ' | b'-')) { end + 1 } else { end };
+        if !bytes.get(next).is_some_and(u8::is_ascii_digit) {
+            return None;
+        }
+        end = next + 1;
+    }
+}
+
+// Card numbers in `text`, left to right. A candidate that fails Luhn at every length is skipped one
+// character at a time, so a number starting inside it is still considered.
+fn find_cards(regex: &Regex, text: &str) -> Vec<(usize, usize)> {
+    let mut found = Vec::new();
+    let mut pos = 0;
+    while let Some(m) = regex.find_at(text, pos) {
+        match card_end(text, m.start(), m.end()) {
+            Some(end) => {
+                found.push((m.start(), end));
+                pos = end;
+            }
+            // The match starts with an ASCII digit, so the next byte is a character boundary
+            None => pos = m.start() + 1,
+        }
+    }
+    found
+}
+
+impl Redactor {
+    /// Fails with `MissingSealer` when `config.keep_originals` is set; use `with_sealer` for that.
+    pub fn new(config: &RedactionConfig) -> Result<Self, RedactionError> {
+        if config.keep_originals {
+            return Err(RedactionError::MissingSealer);
+        }
+        Redactor::build(config, None)
+    }
+
+    /// Build with a sealer for `keep_originals`.
+    pub fn with_sealer(config: &RedactionConfig, sealer: Arc<dyn Sealer>) -> Result<Self, RedactionError> {
+        Redactor::build(config, Some(sealer))
+    }
+
+    fn build(config: &RedactionConfig, sealer: Option<Arc<dyn Sealer>>) -> Result<Self, RedactionError> {
+        let builtin = |pattern: &str| Regex::new(pattern).expect("built-in redaction pattern compiles");
+        let mut detectors = Vec::new();
+        if config.emails {
+            detectors.push((PiiKind::Email, builtin(EMAIL)));
+        }
+        if config.ssns {
+            detectors.push((PiiKind::Ssn, builtin(SSN)));
+        }
+        if config.card_numbers {
+            detectors.push((PiiKind::CardNumber, builtin(CARD)));
+        }
+        for custom in &config.custom {
+            let regex = Regex::new(&custom.pattern).map_err(|err| RedactionError::InvalidPattern {
+                name: custom.name.clone(),
+                message: err.to_string(),
+            })?;
+            detectors.push((PiiKind::Custom(custom.name.clone()), regex));
+        }
+        Ok(Redactor { detectors, keep_originals: config.keep_originals, sealer })
+    }
+
+    /// Every PII span in `text`. Where detectors overlap, the earliest (then longest) match wins.
+    pub fn find(&self, text: &str) -> Vec<Finding> {
+        let mut findings: Vec<Finding> = Vec::new();
+        for (kind, regex) in &self.detectors {
+            if *kind == PiiKind::CardNumber {
+                let cards = find_cards(regex, text).into_iter();
+                findings.extend(cards.map(|(start, end)| Finding { kind: kind.clone(), start, end }));
+                continue;
+            }
+            for m in regex.find_iter(text) {
+                if *kind != PiiKind::Ssn || valid_ssn(m.as_str()) {
+                    findings.push(Finding { kind: kind.clone(), start: m.start(), end: m.end() });
+                }
+            }
+        }
+        findings.sort_by(|a, b| a.start.cmp(&b.start).then(b.end.cmp(&a.end)));
+        let mut kept: Vec<Finding> = Vec::with_capacity(findings.len());
+        for finding in findings {
+            if kept.last().map_or(true, |last| finding.start >= last.end) {
+                kept.push(finding);
+            }
+        }
+        kept
+    }
+
+    pub fn redact(&self, text: &str) -> Redacted {
+        let findings = self.find(text);
+        let mut redacted = String::with_capacity(text.len());
+        let mut cursor = 0;
+        for finding in &findings {
+            redacted.push_str(&text[cursor..finding.start]);
+            redacted.push_str(&finding.kind.marker());
+            cursor = finding.end;
+        }
+        redacted.push_str(&text[cursor..]);
+        Redacted { text: redacted, findings }
+    }
+
+    /// Redact every string inside a JSON value, keys included.
+    pub fn redact_json(&self, value: &JsonValue) -> JsonValue {
+        match value {
+            JsonValue::String(text) => JsonValue::String(self.redact(text).text),
+            JsonValue::Array(items) => JsonValue::Array(items.iter().map(|item| self.redact_json(item)).collect()),
+            JsonValue::Object(map) => JsonValue::Object(
+                map.iter().map(|(key, item)| (self.redact(key).text, self.redact_json(item))).collect(),
+            ),
+            other => other.clone(),
+        }
+    }
+
+    /// Redact `text` for storage, sealing the original when `keep_originals` is on and anything was found.
+    pub fn for_storage(&self, text: &str) -> Result<StoredText, RedactionError> {
+        let redacted = self.redact(text);
+        let sealed_original = if self.keep_originals && !redacted.findings.is_empty() {
+            // `new` refuses `keep_originals`, so a sealer is always present here
+            let sealer = self.sealer.as_ref().ok_or(RedactionError::MissingSealer)?;
+            Some(sealer.seal(text.as_bytes())?)
+        } else {
+            None
+        };
+        Ok(StoredText { text: redacted.text, sealed_original })
+    }
+}
+
+/// Wraps another logger and redacts each message before it is written.
+pub struct RedactingLogger<L> {
+    inner: L,
+    redactor: Arc<Redactor>,
+}
+
+impl<L: Log> RedactingLogger<L> {
+    pub fn new(inner: L, redactor: Arc<Redactor>) -> Self {
+        RedactingLogger { inner, redactor }
+    }
+
+    /// Install as the global logger.
+    pub fn install(self, max_level: log::LevelFilter) -> Result<(), log::SetLoggerError>
+    where
+        L: 'static,
+    {
+        log::set_boxed_logger(Box::new(self))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+}
+
+impl<L: Log> Log for RedactingLogger<L> {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+        let message = self.redactor.redact(&record.args().to_string()).text;
+        self.inner.log(
+            &Record::builder()
+                .metadata(record.metadata().clone())
+                .args(format_args!("{}", message))
+                .module_path(record.module_path())
+                .file(record.file())
+                .line(record.line())
+                .build(),
+        );
+    }
+
+    fn flush(&self) {
+        self.inner.flush()
+    }
+}
+
+fn main() -> Result<(), RedactionError> {
+    let config = RedactionConfig {
+        custom: vec![CustomPattern { name: "account".to_string(), pattern: r"\bACCT-\d{8}\b".to_string() }],
+        ..RedactionConfig::default()
+    };
+    let redactor = Redactor::new(&config)?;
+    let text = "Refund jane@example.com, card 4111 1111 1111 1111, SSN 123-45-6789, ACCT-00012345.";
+    println!("{}", redactor.redact(text).text);
+    Ok(())
+}
+```
+
+### Notes
+* Detection is deliberately conservative: a 16-digit order number that happens to pass Luhn is redacted, and an SSN written without dashes is not. Site-specific formats belong in `custom`.
+* Finding offsets are into the original text and are only meaningful alongside it; they are not persisted.
+* `export_conversation` redacts with the conversation's redactor too. Exports do not keep sealed originals; they are for reading, not for recovery.
+* Log redaction costs one formatting pass per record. It runs after the level check, so filtered-out records cost nothing.
@@ -0,0 +1,297 @@
+### Overview
+The Python `Agent` accepts `sentiment_threshold` and `sentiment_analyzer`, scores each response with the
+analyzer, and only logs the score. The Rust conversion carried both fields without using them. This module
+gives them an effect: a response to a customer-facing task (for example, an agent running
+`SUPPORT_AGENT_PROMPT`) is scored, and a response below the threshold is regenerated with an instruction to
+change its tone.
+
+`LexiconAnalyzer` is the built-in analyzer. It is rule-based, with no model and no downloads: words from a
+positive and a negative lexicon are counted, and a negator (`not`, `never`, `don't`, ...) flips the polarity
+of the next few words. Scores run from 0 (hostile) through 0.5 (neutral) to 1 (warm). Other analyzers,
+such as a classifier model, implement `SentimentAnalyzer`.
+
+`sentiment_gate` makes the first attempt with the task as given. Each retry sends the task, the
+`instruction`, and the rejected reply, up to `max_retries` times. If no attempt reaches the threshold, the
+best-scoring reply is returned and the run is marked as not passed.
+
+`SentimentGateConfig::from_schema` reads `sentiment_threshold` and `sentiment_analyzer` from an
+`AgentSchema`.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use log::{debug, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// The name of the built-in analyzer, and the default for `sentiment_analyzer`.
+pub const LEXICON_ANALYZER: &str = "lexicon";
+
+/// Sent with every retry, before the rejected reply.
+pub const DEFAULT_TONE_INSTRUCTION: &str = "Your previous reply came across as negative or dismissive. \
+    Rewrite it in a warm, patient, and helpful tone. Keep the same facts and do not make promises the facts do not support.";
+
+/// How many words after a negator have their polarity flipped.
+const NEGATION_WINDOW: usize = 3;
+
+/// Added to the word count so one mildly negative word in a long reply does not score near 0.
+const SMOOTHING: f64 = 2.0;
+
+const POSITIVE: &[&str] = &[
+    "appreciate", "assist", "awesome", "certainly", "clear", "delighted", "easy", "excellent", "fantastic", "glad",
+    "good", "great", "happy", "help", "helpful", "love", "perfect", "pleased", "pleasure", "quick", "resolved",
+    "sorted", "sure", "thank", "thanks", "welcome", "wonderful",
+];
+
+const NEGATIVE: &[&str] = &[
+    "angry", "annoying", "awful", "bad", "broken", "can't", "cannot", "complain", "confusing", "disappointed",
+    "dumb", "fault", "frustrated", "frustrating", "hate", "impossible", "incompetent", "obviously", "pointless",
+    "problem", "refuse", "ridiculous", "stupid", "terrible", "unacceptable", "unfortunately", "useless", "whatever",
+    "wrong",
+];
+
+const NEGATORS: &[&str] = &["no", "not", "never", "none", "nothing", "neither", "nor", "without"];
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SentimentError {
+    UnknownAnalyzer(String),
+    InvalidThreshold(f64),
+}
+
+impl fmt::Display for SentimentError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SentimentError::UnknownAnalyzer(name) => {
+                write!(f, "unknown sentiment analyzer '{}' (built in: '{}')", name, LEXICON_ANALYZER)
+            }
+            SentimentError::InvalidThreshold(threshold) => {
+                write!(f, "sentiment threshold {} is outside 0.0..=1.0", threshold)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SentimentError {}
+
+/// Scores text from 0 (negative) to 1 (positive), with 0.5 neutral.
+pub trait SentimentAnalyzer: Send + Sync {
+    fn score(&self, text: &str) -> f64;
+}
+
+impl<F> SentimentAnalyzer for F
+where
+    F: Fn(&str) -> f64 + Send + Sync,
+{
+    fn score(&self, text: &str) -> f64 {
+        self(text)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LexiconAnalyzer {
+    positive: HashSet<String>,
+    negative: HashSet<String>,
+}
+
+impl Default for LexiconAnalyzer {
+    fn default() -> Self {
+        LexiconAnalyzer {
+            positive: POSITIVE.iter().map(|word| word.to_string()).collect(),
+            negative: NEGATIVE.iter().map(|word| word.to_string()).collect(),
+        }
+    }
+}
+
+impl LexiconAnalyzer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add domain words that read as positive.
+    pub fn positive<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.positive.extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+        self
+    }
+
+    /// Add domain words that read as negative.
+    pub fn negative<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.negative.extend(words.into_iter().map(|word| word.as_ref().to_lowercase()));
+        self
+    }
+}
+
+fn is_negator(word: &str) -> bool {
+    NEGATORS.contains(&word) || (word.ends_with("n't") && word != "can't")
+}
+
+impl SentimentAnalyzer for LexiconAnalyzer {
+    fn score(&self, text: &str) -> f64 {
+        let lowered = text.to_lowercase().replace('\u{2019}', "'");
+        let words = lowered
+            .split(|c: char| !(c.is_alphanumeric() || c == '\''))
+            .map(|word| word.trim_matches('\''))
+            .filter(|word| !word.is_empty());
+
+        let (mut positive, mut negative) = (0.0, 0.0);
+        let mut negated = 0;
+        for word in words {
+            let polarity = if self.positive.contains(word) {
+                1.0
+            } else if self.negative.contains(word) {
+                -1.0
+            } else {
+                0.0
+            };
+            let polarity = if negated > 0 { -polarity } else { polarity };
+            if polarity > 0.0 {
+                positive += 1.0;
+            } else if polarity < 0.0 {
+                negative += 1.0;
+            }
+            if is_negator(word) {
+                negated = NEGATION_WINDOW;
+            } else if negated > 0 {
+                negated -= 1;
+            }
+        }
+        0.5 + 0.5 * (positive - negative) / (positive + negative + SMOOTHING)
+    }
+}
+
+/// The analyzer named by `sentiment_analyzer`.
+pub fn analyzer_by_name(name: &str) -> Result<Box<dyn SentimentAnalyzer>, SentimentError> {
+    match name {
+        LEXICON_ANALYZER => Ok(Box::new(LexiconAnalyzer::new())),
+        other => Err(SentimentError::UnknownAnalyzer(other.to_string())),
+    }
+}
+
+/// Deserializes from a partial table; missing fields take the defaults.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SentimentGateConfig {
+    /// Replies scoring below this are retried.
+    pub threshold: f64,
+    /// Retries after the first attempt.
+    pub max_retries: usize,
+    pub instruction: String,
+    /// Resolved with `analyzer_by_name`.
+    pub analyzer: String,
+}
+
+impl Default for SentimentGateConfig {
+    fn default() -> Self {
+        SentimentGateConfig {
+            threshold: 0.5,
+            max_retries: 2,
+            instruction: DEFAULT_TONE_INSTRUCTION.to_string(),
+            analyzer: LEXICON_ANALYZER.to_string(),
+        }
+    }
+}
+
+impl SentimentGateConfig {
+    /// `None` unless `sentiment_threshold` is set.
+    pub fn from_schema(schema: &AgentSchema) -> Result<Option<Self>, SentimentError> {
+        let Some(threshold) = schema.sentiment_threshold else {
+            return Ok(None);
+        };
+        let config = SentimentGateConfig {
+            threshold,
+            analyzer: schema.sentiment_analyzer.clone().unwrap_or_else(|| LEXICON_ANALYZER.to_string()),
+            ..SentimentGateConfig::default()
+        };
+        config.validate()?;
+        Ok(Some(config))
+    }
+
+    pub fn validate(&self) -> Result<(), SentimentError> {
+        if !(0.0..=1.0).contains(&self.threshold) {
+            return Err(SentimentError::InvalidThreshold(self.threshold));
+        }
+        analyzer_by_name(&self.analyzer).map(|_| ())
+    }
+
+    /// The prompt for a retry after `rejected` scored too low.
+    pub fn retry_prompt(&self, task: &str, rejected: &str) -> String {
+        format!("{}\n\n{}\n\nPrevious reply:\n{}", task, self.instruction, rejected)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentimentAttempt {
+    pub prompt: String,
+    pub output: String,
+    pub score: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SentimentRun {
+    /// The first reply at or above the threshold, or else the best-scoring one.
+    pub output: String,
+    pub score: f64,
+    pub passed: bool,
+    pub attempts: Vec<SentimentAttempt>,
+}
+
+/// Generate a reply to `task`, retrying with `config.instruction` while it scores below the threshold.
+/// A `generate` error ends the run; the replies gathered so far are discarded with it.
+pub fn sentiment_gate(
+    task: &str,
+    config: &SentimentGateConfig,
+    analyzer: &dyn SentimentAnalyzer,
+    mut generate: impl FnMut(&str) -> Result<String, String>,
+) -> Result<SentimentRun, String> {
+    let mut attempts: Vec<SentimentAttempt> = Vec::new();
+    let mut prompt = task.to_string();
+    for attempt in 0..=config.max_retries {
+        let output = generate(&prompt)?;
+        let score = analyzer.score(&output);
+        debug!("Sentiment attempt {} scored {:.2} (threshold {:.2})", attempt, score, config.threshold);
+        let passed = score >= config.threshold;
+        let next = config.retry_prompt(task, &output);
+        attempts.push(SentimentAttempt { prompt, output, score });
+        if passed {
+            let last = &attempts[attempts.len() - 1];
+            return Ok(SentimentRun { output: last.output.clone(), score, passed: true, attempts });
+        }
+        prompt = next;
+    }
+    warn!("No reply reached sentiment threshold {:.2} after {} attempt(s)", config.threshold, attempts.len());
+    let best = attempts.iter().max_by(|a, b| a.score.total_cmp(&b.score)).cloned().expect("at least one attempt");
+    Ok(SentimentRun { output: best.output, score: best.score, passed: false, attempts })
+}
+
+fn main() -> Result<(), SentimentError> {
+    let config = SentimentGateConfig { threshold: 0.55, ..SentimentGateConfig::default() };
+    config.validate()?;
+    let analyzer = analyzer_by_name(&config.analyzer)?;
+    let mut replies = vec![
+        "Thanks for reaching out! Happy to help: your refund is resolved.",
+        "Obviously you entered the wrong card. Not my problem.",
+    ];
+    let run = sentiment_gate("My refund has not arrived.", &config, analyzer.as_ref(), |_prompt| {
+        replies.pop().map(str::to_string).ok_or_else(|| "no more replies".to_string())
+    })
+    .expect("scripted replies");
+    for attempt in &run.attempts {
+        println!("{:.2}: {}", attempt.score, attempt.output);
+    }
+    println!("passed: {}", run.passed);
+    Ok(())
+}
+```
+
+### Notes
+* The lexicon scores tone, not correctness; a polite wrong answer passes. Pair it with an evaluator for content.
+* A reply with no lexicon words scores exactly 0.5, so a threshold above 0.5 retries every neutral reply.
+* Each retry is a full model call. Keep `max_retries` low on high-volume agents.
@@ -0,0 +1,246 @@
+### Overview
+The Python `Agent` stops its loop once the response contains `stopping_token`, and `preset_stopping_token`
+sets that token to `<DONE>`. It checks only after the whole response has arrived, so every token the model
+writes after the marker is still paid for. `StopConditions` brings that check into the stream:
+
+* **Stop sequences** are exact strings. They are also sent to the provider as `stop`, which ends
+  generation server-side. Providers cap the list (OpenAI takes four), so the first
+  `PROVIDER_STOP_LIMIT` are sent and every sequence is also matched client-side.
+* **Stop patterns** are regexes, matched client-side only, e.g. `(?m)^Final Answer:.*\n` to stop once
+  the answer line is complete.
+
+`StopMatcher` is fed the streamed text one delta at a time and reports the first match, even when it spans
+deltas. Its `settled` text holds back a tail that could still grow into a stop sequence, so a caller
+showing tokens as they arrive never shows the start of a sequence that is then cut off.
+`OpenAiCompatProvider::stream_chat` drops the HTTP stream on a match, which cancels the request. The text
+it returns ends before a matched sequence, as a provider-side stop would, but includes a matched pattern.
+
+`StopConditions::from_schema` builds the conditions from an `AgentSchema`'s `stopping_token`,
+`preset_stopping_token`, and `stop_patterns`.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use regex::Regex;
+use serde::Serialize;
+use std::fmt;
+
+/// The token `preset_stopping_token` selects, as in the Python `Agent`.
+pub const PRESET_STOPPING_TOKEN: &str = "<DONE>";
+
+/// How many stop sequences are sent to the provider; the rest are matched client-side only.
+pub const PROVIDER_STOP_LIMIT: usize = 4;
+
+/// How far back a pattern match may start before the newest delta. Longer matches can be missed.
+const PATTERN_LOOKBACK: usize = 1024;
+
+#[derive(Debug)]
+pub enum StopError {
+    InvalidPattern { pattern: String, message: String },
+}
+
+impl fmt::Display for StopError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StopError::InvalidPattern { pattern, message } => {
+                write!(f, "stop pattern '{}' does not compile: {}", pattern, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for StopError {}
+
+#[derive(Debug, Clone, Default)]
+pub struct StopConditions {
+    sequences: Vec<String>,
+    patterns: Vec<Regex>,
+}
+
+impl StopConditions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop at the exact string `sequence`. Empty sequences are ignored.
+    pub fn sequence(mut self, sequence: impl Into<String>) -> Self {
+        let sequence = sequence.into();
+        if !sequence.is_empty() && !self.sequences.contains(&sequence) {
+            self.sequences.push(sequence);
+        }
+        self
+    }
+
+    /// Stop at the first match of the regex `pattern`.
+    pub fn pattern(mut self, pattern: &str) -> Result<Self, StopError> {
+        let regex = Regex::new(pattern)
+            .map_err(|e| StopError::InvalidPattern { pattern: pattern.to_string(), message: e.to_string() })?;
+        self.patterns.push(regex);
+        Ok(self)
+    }
+
+    /// `stopping_token`, or `<DONE>` when `preset_stopping_token` is set, plus `stop_patterns`.
+    pub fn from_schema(schema: &AgentSchema) -> Result<Self, StopError> {
+        let mut conditions = StopConditions::new();
+        if let Some(token) = &schema.stopping_token {
+            conditions = conditions.sequence(token.clone());
+        }
+        if schema.preset_stopping_token.unwrap_or(false) {
+            conditions = conditions.sequence(PRESET_STOPPING_TOKEN);
+        }
+        for pattern in schema.stop_patterns.iter().flatten() {
+            conditions = conditions.pattern(pattern)?;
+        }
+        Ok(conditions)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sequences.is_empty() && self.patterns.is_empty()
+    }
+
+    pub fn sequences(&self) -> &[String] {
+        &self.sequences
+    }
+
+    /// The sequences to send as the request's `stop` parameter.
+    pub fn provider_sequences(&self) -> Vec<String> {
+        self.sequences.iter().take(PROVIDER_STOP_LIMIT).cloned().collect()
+    }
+
+    pub fn matcher(&self) -> StopMatcher {
+        StopMatcher { conditions: self.clone(), text: String::new(), stopped: None }
+    }
+
+    /// The earliest match in `text`, for responses that were not streamed.
+    pub fn find(&self, text: &str) -> Option<StopMatch> {
+        let mut matcher = self.matcher();
+        matcher.push(text)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StopKind {
+    Sequence,
+    Pattern,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct StopMatch {
+    pub kind: StopKind,
+    /// The sequence, or the text the pattern matched.
+    pub matched: String,
+    /// Byte offset of the match in the streamed text.
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Accumulates streamed text and watches it for stop conditions.
+#[derive(Debug, Clone)]
+pub struct StopMatcher {
+    conditions: StopConditions,
+    text: String,
+    stopped: Option<StopMatch>,
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+impl StopMatcher {
+    /// Append `delta`. Returns the match the first time a condition is met, then `None` for later deltas.
+    pub fn push(&mut self, delta: &str) -> Option<StopMatch> {
+        if self.stopped.is_some() {
+            return None;
+        }
+        let previous = self.text.len();
+        self.text.push_str(delta);
+
+        let mut earliest: Option<StopMatch> = None;
+        let mut consider = |found: StopMatch| {
+            if earliest.as_ref().map_or(true, |current| found.start < current.start) {
+                earliest = Some(found);
+            }
+        };
+        for sequence in &self.conditions.sequences {
+            // A sequence ending in this delta starts at most `len - 1` bytes before it.
+            let from = floor_char_boundary(&self.text, previous.saturating_sub(sequence.len() - 1));
+            if let Some(offset) = self.text[from..].find(sequence.as_str()) {
+                let start = from + offset;
+                consider(StopMatch { kind: StopKind::Sequence, matched: sequence.clone(), start, end: start + sequence.len() });
+            }
+        }
+        let from = floor_char_boundary(&self.text, previous.saturating_sub(PATTERN_LOOKBACK));
+        for pattern in &self.conditions.patterns {
+            // `find_at` keeps the text before `from` as context, so anchors and word boundaries still work.
+            if let Some(found) = pattern.find_at(&self.text, from) {
+                consider(StopMatch {
+                    kind: StopKind::Pattern,
+                    matched: found.as_str().to_string(),
+                    start: found.start(),
+                    end: found.end(),
+                });
+            }
+        }
+        self.stopped = earliest.clone();
+        earliest
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn stopped(&self) -> Option<&StopMatch> {
+        self.stopped.as_ref()
+    }
+
+    /// The part of `truncated` no later delta can change. Before a match, a tail that is the start of a stop
+    /// sequence (`<DO` for `<DONE>`) is held back until the next delta completes or rules it out.
+    pub fn settled(&self) -> &str {
+        if self.stopped.is_some() {
+            return self.truncated();
+        }
+        let text = self.text.as_bytes();
+        let held = self
+            .conditions
+            .sequences
+            .iter()
+            .filter_map(|sequence| (1..sequence.len()).rev().find(|len| text.ends_with(&sequence.as_bytes()[..*len])))
+            .max()
+            .unwrap_or(0);
+        &self.text[..floor_char_boundary(&self.text, self.text.len() - held)]
+    }
+
+    /// The text up to the match, or all of it when nothing matched. A sequence is cut off, as the
+    /// provider would; a pattern match is kept, since it usually marks the end of the useful output.
+    pub fn truncated(&self) -> &str {
+        match &self.stopped {
+            Some(found) if found.kind == StopKind::Sequence => &self.text[..found.start],
+            Some(found) => &self.text[..found.end],
+            None => &self.text,
+        }
+    }
+}
+
+fn main() -> Result<(), StopError> {
+    let conditions = StopConditions::new().sequence(PRESET_STOPPING_TOKEN).pattern(r"(?m)^Final Answer:.*\n")?;
+    let mut matcher = conditions.matcher();
+    for delta in ["The total is 42.\nFinal ", "Answer: 42", "\nThen I would ", "also check..."] {
+        if let Some(found) = matcher.push(delta) {
+            println!("stopped on {:?} at byte {}", found.matched, found.start);
+            break;
+        }
+    }
+    println!("{:?}", matcher.truncated());
+    Ok(())
+}
+```
+
+### Notes
+* A pattern that can match an empty string matches immediately; patterns should require at least one character.
+* `settled` only holds back the start of a sequence. A pattern's partial match cannot be recognised before it completes, so text a pattern match later keeps has already been settled, which is harmless since a matched pattern is kept in `truncated` too.
+* A pattern match is reported as soon as it exists, so a greedy pattern such as `Answer: \d+` can stop on `Answer: 4` before `2` arrives. End patterns with a delimiter (`\n`, `.`) when the full value matters.
+* Text the provider already generated before the stream is dropped is still billed; stopping early saves the tokens it would have written after.
@@ -0,0 +1,60 @@
+### Overview
+There is no Python counterpart to this module. The Python agent counts tokens through whichever
+tokenizer its LLM wrapper exposes; the Rust port routes every budget decision (tool catalogs, chunking,
+context planning) through the `Tokenizer` trait so the estimate can be swapped for a model's real BPE
+without touching callers.
+
+### Rust Code
+```rust
+use std::sync::Arc;
+
+/// Counts tokens for budget decisions.
+pub trait Tokenizer: Send + Sync {
+    fn count_tokens(&self, text: &str) -> usize;
+}
+
+/// Rough token estimate (~4 characters per token).
+/// It rounds up so that short strings are never counted as free.
+pub fn estimate_tokens(text: &str) -> usize {
+    (text.chars().count() + 3) / 4
+}
+
+/// The default tokenizer: `estimate_tokens`, no model files required.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HeuristicTokenizer;
+
+impl Tokenizer for HeuristicTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        estimate_tokens(text)
+    }
+}
+
+/// OpenAI BPE tokenizer (cl100k/o200k) via `tiktoken-rs`.
+pub struct TiktokenTokenizer {
+    bpe: tiktoken_rs::CoreBPE,
+}
+
+impl TiktokenTokenizer {
+    /// Load the encoding used by `model`, e.g. "gpt-4o".
+    pub fn for_model(model: &str) -> Result<Self, String> {
+        let bpe = tiktoken_rs::get_bpe_from_model(model).map_err(|e| e.to_string())?;
+        Ok(TiktokenTokenizer { bpe })
+    }
+}
+
+impl Tokenizer for TiktokenTokenizer {
+    fn count_tokens(&self, text: &str) -> usize {
+        self.bpe.encode_with_special_tokens(text).len()
+    }
+}
+
+/// Shared default used when no tokenizer is configured.
+pub fn default_tokenizer() -> Arc<dyn Tokenizer> {
+    Arc::new(HeuristicTokenizer)
+}
+
+fn main() {
+    let tokenizer = default_tokenizer();
+    println!("{}", tokenizer.count_tokens("How many tokens is this sentence?"));
+}
+```
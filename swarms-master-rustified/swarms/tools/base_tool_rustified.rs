@@ -7,20 +7,38 @@ The given Python code can be converted to Rust, but there are several challenges
 * The `loguru_logger` used in the Python code can be replaced with Rust's `log` crate.
 
 ## Converted Rust Code:
+The Python `BaseTool` mixes configuration (which tools exist, whether to auto-execute) with runtime state
+(`function_map`) that is filled in lazily by `convert_funcs_into_tools`. In Rust that shape forces `&mut self`
+on every read path, so the port splits it in two: a `ToolRegistryBuilder` collects configuration and is consumed
+by `build()`, which produces an immutable `ToolRegistry`. The registry is cheap to clone (`Arc` inside) and can be
+shared across agents and threads without locking.
+
 ```rust
 // Import necessary crates
-use serde::{Serialize, Deserialize};
-use serde_json::{json, JsonValue};
+use crate::swarms::structs::scoped_tasks::panic_message;
+use crate::swarms::text::tokenizer::estimate_tokens;
+use log::{info, warn};
+use serde_json::{json, Value as JsonValue};
 use std::collections::HashMap;
 use std::error::Error;
 use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Arc;
 
 // Define a custom error type for tool execution errors
 #[derive(Debug)]
-struct ToolExecutionError {
+pub struct ToolExecutionError {
     message: String,
 }
 
+impl ToolExecutionError {
+    pub fn new(message: impl Into<String>) -> Self {
+        ToolExecutionError {
+            message: message.into(),
+        }
+    }
+}
+
 impl fmt::Display for ToolExecutionError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.message)
@@ -29,321 +47,514 @@ impl fmt::Display for ToolExecutionError {
 
 impl Error for ToolExecutionError {}
 
+/// Default token budget for the tool catalog rendered into the system prompt.
+pub const DEFAULT_TOOL_CATALOG_TOKEN_BUDGET: usize = 1024;
+
+/// A callable tool body. Takes the JSON arguments produced by the model and returns a JSON result.
+pub type ToolFn = Arc<dyn Fn(&JsonValue) -> Result<JsonValue, ToolExecutionError> + Send + Sync>;
+
 // Define the ToolType enum
-#[derive(Debug)]
-enum ToolType {
-    BaseTool,
+#[derive(Debug, Clone)]
+pub enum ToolType {
+    /// A JSON schema describing a structured output (the Pydantic case in Python).
+    BaseTool(JsonValue),
+    /// A raw OpenAI-style function dictionary.
     Dictionary(HashMap<String, JsonValue>),
+    /// The name of a function registered with the registry.
     Function(String),
     Unknown,
 }
 
-// Define the BaseTool struct
-#[derive(Debug, Serialize, Deserialize)]
-struct BaseTool {
-    verbose: Option<bool>,
-    base_models: Option<Vec<String>>,
-    autocheck: Option<bool>,
-    auto_execute_tool: Option<bool>,
-    tools: Option<Vec<String>>,
-    tool_system_prompt: Option<String>,
-    function_map: Option<HashMap<String, String>>,
-    list_of_dicts: Option<Vec<JsonValue>>,
+/// A single registered tool: its OpenAI-facing description plus the callable body.
+#[derive(Clone)]
+pub struct ToolSpec {
+    pub name: String,
+    pub description: String,
+    pub parameters: JsonValue,
+    func: ToolFn,
 }
 
-impl BaseTool {
-    // Define the func_to_dict method
-    fn func_to_dict(
-        &self,
-        function_name: &str,
-        description: &str,
-    ) -> Result<JsonValue, ToolExecutionError> {
-        // Replace the get_openai_function_schema_from_func function
-        // with a Rust equivalent
-        let function_schema = json!({
+impl fmt::Debug for ToolSpec {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ToolSpec")
+            .field("name", &self.name)
+            .field("description", &self.description)
+            .field("parameters", &self.parameters)
+            .finish()
+    }
+}
+
+impl ToolSpec {
+    /// Render the tool as an OpenAI function schema.
+    pub fn to_openai_schema(&self) -> JsonValue {
+        json!({
+            "type": "function",
             "function": {
-                "name": function_name,
-                "description": description,
+                "name": self.name,
+                "description": self.description,
+                "parameters": self.parameters,
             },
+        })
+    }
+
+    /// Run the tool body. A panic in the body is contained and returned as an error, so one broken
+    /// tool fails its call instead of the agent's whole run.
+    pub fn call(&self, params: &JsonValue) -> Result<JsonValue, ToolExecutionError> {
+        panic::catch_unwind(AssertUnwindSafe(|| (self.func)(params))).unwrap_or_else(|payload| {
+            Err(ToolExecutionError::new(format!("Tool '{}' panicked: {}", self.name, panic_message(&*payload))))
+        })
+    }
+
+    /// First line of the description, used for the compact catalog entry.
+    pub fn summary(&self) -> &str {
+        self.description.lines().next().unwrap_or("").trim()
+    }
+
+    /// Compact JSON signature of the tool's parameters.
+    pub fn signature(&self) -> String {
+        self.parameters.to_string()
+    }
+}
+
+/// Configuration for a `ToolRegistry`. Consumed by `build()`.
+#[derive(Default)]
+pub struct ToolRegistryBuilder {
+    verbose: bool,
+    auto_execute_tool: bool,
+    tool_system_prompt: Option<String>,
+    catalog_token_budget: Option<usize>,
+    tools: Vec<ToolSpec>,
+    base_models: Vec<JsonValue>,
+}
+
+impl ToolRegistryBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
+    }
+
+    pub fn auto_execute_tool(mut self, auto_execute_tool: bool) -> Self {
+        self.auto_execute_tool = auto_execute_tool;
+        self
+    }
+
+    pub fn tool_system_prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.tool_system_prompt = Some(prompt.into());
+        self
+    }
+
+    /// Token budget for the tool catalog appended to the system prompt.
+    /// Defaults to `DEFAULT_TOOL_CATALOG_TOKEN_BUDGET`.
+    pub fn catalog_token_budget(mut self, budget: usize) -> Self {
+        self.catalog_token_budget = Some(budget);
+        self
+    }
+
+    /// Register a callable tool.
+    pub fn tool<F>(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        parameters: JsonValue,
+        func: F,
+    ) -> Self
+    where
+        F: Fn(&JsonValue) -> Result<JsonValue, ToolExecutionError> + Send + Sync + 'static,
+    {
+        self.tools.push(ToolSpec {
+            name: name.into(),
+            description: description.into(),
+            parameters,
+            func: Arc::new(func),
         });
-        Ok(function_schema)
+        self
     }
 
-    // Define the load_params_from_func_for_pybasemodel method
-    fn load_params_from_func_for_pybasemodel(
-        &self,
-        _func: &str,
-    ) -> Result<JsonValue, ToolExecutionError> {
-        // Replace the load_basemodels_if_needed function
-        // with a Rust equivalent
-        let params = json!({});
-        Ok(params)
+    /// Register a structured-output schema (the Python `base_models` list).
+    pub fn base_model(mut self, schema: JsonValue) -> Self {
+        self.base_models.push(schema);
+        self
     }
 
-    // Define the base_model_to_dict method
-    fn base_model_to_dict(
-        &self,
-        _pydantic_type: &str,
-        _output_str: bool,
-    ) -> Result<JsonValue, ToolExecutionError> {
-        // Replace the base_model_to_openai_function function
-        // with a Rust equivalent
-        let base_model = json!({});
-        Ok(base_model)
+    /// Validate the configuration and freeze it into a shareable registry.
+    ///
+    /// This replaces `convert_funcs_into_tools`: the function map and the OpenAI schemas
+    /// are computed exactly once, here, instead of being patched into `self` later.
+    pub fn build(self) -> Result<ToolRegistry, ToolExecutionError> {
+        let mut function_map = HashMap::with_capacity(self.tools.len());
+        let mut order = Vec::with_capacity(self.tools.len());
+
+        for tool in self.tools {
+            if tool.name.is_empty() {
+                return Err(ToolExecutionError::new("Tool names must not be empty"));
+            }
+            if tool.description.is_empty() {
+                warn!(
+                    "Tool '{}' has no description, tool execution won't be reliable.",
+                    tool.name
+                );
+            }
+            if function_map.contains_key(&tool.name) {
+                return Err(ToolExecutionError::new(format!(
+                    "Tool '{}' is registered more than once",
+                    tool.name
+                )));
+            }
+            order.push(tool.name.clone());
+            function_map.insert(tool.name.clone(), tool);
+        }
+
+        let schemas = order
+            .iter()
+            .map(|name| function_map[name].to_openai_schema())
+            .collect();
+
+        if self.verbose {
+            info!("Built tool registry with {} tools", order.len());
+        }
+
+        Ok(ToolRegistry {
+            inner: Arc::new(RegistryInner {
+                verbose: self.verbose,
+                auto_execute_tool: self.auto_execute_tool,
+                tool_system_prompt: self.tool_system_prompt,
+                catalog_token_budget: self
+                    .catalog_token_budget
+                    .unwrap_or(DEFAULT_TOOL_CATALOG_TOKEN_BUDGET),
+                order,
+                function_map,
+                schemas,
+                base_models: self.base_models,
+            }),
+        })
     }
+}
 
-    // Define the multi_base_models_to_dict method
-    fn multi_base_models_to_dict(
-        &self,
-        _return_str: bool,
-    ) -> Result<JsonValue, ToolExecutionError> {
-        // Replace the multi_base_model_to_openai_function function
-        // with a Rust equivalent
-        let base_models = json!({});
-        Ok(base_models)
+struct RegistryInner {
+    verbose: bool,
+    auto_execute_tool: bool,
+    tool_system_prompt: Option<String>,
+    catalog_token_budget: usize,
+    order: Vec<String>,
+    function_map: HashMap<String, ToolSpec>,
+    schemas: Vec<JsonValue>,
+    base_models: Vec<JsonValue>,
+}
+
+/// An immutable, cheaply clonable set of tools. Every method takes `&self`.
+#[derive(Clone)]
+pub struct ToolRegistry {
+    inner: Arc<RegistryInner>,
+}
+
+impl ToolRegistry {
+    pub fn builder() -> ToolRegistryBuilder {
+        ToolRegistryBuilder::new()
     }
 
-    // Define the dict_to_openai_schema_str method
-    fn dict_to_openai_schema_str(
-        &self,
-        dict: &JsonValue,
-    ) -> Result<String, ToolExecutionError> {
-        // Replace the function_to_str function
-        // with a Rust equivalent
-        let str = dict.to_string();
-        Ok(str)
+    pub fn len(&self) -> usize {
+        self.inner.order.len()
     }
 
-    // Define the multi_dict_to_openai_schema_str method
-    fn multi_dict_to_openai_schema_str(
-        &self,
-        dicts: Vec<JsonValue>,
-    ) -> Result<String, ToolExecutionError> {
-        // Replace the functions_to_str function
-        // with a Rust equivalent
-        let str = dicts
-            .into_iter()
-            .map(|dict| dict.to_string())
-            .collect::<Vec<_>>()
-            .join(",");
-        Ok(str)
+    pub fn is_empty(&self) -> bool {
+        self.inner.order.is_empty()
     }
 
-    // Define the get_docs_from_callable method
-    fn get_docs_from_callable(&self, _item: &str) -> Result<String, ToolExecutionError> {
-        // Replace the process_tool_docs function
-        // with a Rust equivalent
-        let docs = json!({}).to_string();
-        Ok(docs)
+    pub fn auto_execute_tool(&self) -> bool {
+        self.inner.auto_execute_tool
     }
 
-    // Define the execute_tool method
-    fn execute_tool(
-        &self,
-        _tools: Vec<JsonValue>,
-        _function_map: HashMap<String, String>,
-    ) -> Result<JsonValue, ToolExecutionError> {
-        // Replace the openai_tool_executor function
-        // with a Rust equivalent
-        let result = json!({});
-        Ok(result)
+    /// The custom tool prompt (`custom_tools_prompt` in Python), if one was configured.
+    pub fn custom_tools_prompt(&self) -> Option<&str> {
+        self.inner.tool_system_prompt.as_deref()
+    }
+
+    /// The tool section of the agent's system prompt: the custom prompt (if any) followed by
+    /// a catalog of the registered tools, trimmed to the configured token budget.
+    pub fn tool_system_prompt(&self) -> String {
+        let mut prompt = String::new();
+        let mut budget = self.inner.catalog_token_budget;
+        if let Some(custom) = &self.inner.tool_system_prompt {
+            prompt.push_str(custom.trim_end());
+            prompt.push_str("\n\n");
+            budget = budget.saturating_sub(estimate_tokens(&prompt));
+        }
+        prompt.push_str(&self.render_tool_catalog(budget));
+        prompt
+    }
+
+    /// Render a concise tool catalog (name, one-line description, JSON signature).
+    ///
+    /// Tools are listed in registration order, each with its signature if it fits the budget, else
+    /// without it, else left out; a tool that does not fit does not stop later, shorter ones from
+    /// being listed. Tools left out are summarized as a count, so the model always knows every tool
+    /// that was left out. The header and that closing line count against the budget.
+    pub fn render_tool_catalog(&self, token_budget: usize) -> String {
+        if self.is_empty() {
+            return String::new();
+        }
+
+        let header = "You have access to the following tools:\n";
+        let budget = token_budget.saturating_sub(estimate_tokens(header));
+        let (mut listed, mut omitted) = self.fit_tools(budget);
+        if omitted > 0 {
+            // Make room for the closing line, sized for the largest count it could show
+            let reserve = estimate_tokens(&omitted_tools_line(self.len()));
+            (listed, omitted) = self.fit_tools(budget.saturating_sub(reserve));
+        }
+
+        let mut catalog = String::from(header);
+        catalog.push_str(&listed);
+        if omitted > 0 {
+            catalog.push_str(&omitted_tools_line(omitted));
+        }
+        catalog
+    }
+
+    // The catalog lines that fit in `budget` tokens, and how many tools did not fit
+    fn fit_tools(&self, budget: usize) -> (String, usize) {
+        let mut listed = String::new();
+        let mut used = 0;
+        let mut omitted = 0;
+        for tool in self.tools() {
+            let full = format!(
+                "- {}: {}\n  parameters: {}\n",
+                tool.name,
+                tool.summary(),
+                tool.signature()
+            );
+            let short = format!("- {}: {}\n", tool.name, tool.summary());
+            if used + estimate_tokens(&full) <= budget {
+                used += estimate_tokens(&full);
+                listed.push_str(&full);
+            } else if used + estimate_tokens(&short) <= budget {
+                used += estimate_tokens(&short);
+                listed.push_str(&short);
+            } else {
+                omitted += 1;
+            }
+        }
+        (listed, omitted)
+    }
+
+    /// Tools in registration order.
+    pub fn tools(&self) -> impl Iterator<Item = &ToolSpec> {
+        self.inner
+            .order
+            .iter()
+            .map(move |name| &self.inner.function_map[name])
+    }
+
+    pub fn get(&self, name: &str) -> Option<&ToolSpec> {
+        self.inner.function_map.get(name)
+    }
+
+    /// A copy of this registry holding only the tools for which `keep` returns true.
+    pub fn restricted(&self, keep: impl Fn(&str) -> bool) -> ToolRegistry {
+        let order: Vec<String> = self.inner.order.iter().filter(|name| keep(name)).cloned().collect();
+        let function_map: HashMap<String, ToolSpec> =
+            order.iter().map(|name| (name.clone(), self.inner.function_map[name].clone())).collect();
+        let schemas = order.iter().map(|name| function_map[name].to_openai_schema()).collect();
+        ToolRegistry {
+            inner: Arc::new(RegistryInner {
+                verbose: self.inner.verbose,
+                auto_execute_tool: self.inner.auto_execute_tool,
+                tool_system_prompt: self.inner.tool_system_prompt.clone(),
+                catalog_token_budget: self.inner.catalog_token_budget,
+                order,
+                function_map,
+                schemas,
+                base_models: self.inner.base_models.clone(),
+            }),
+        }
+    }
+
+    /// A copy of this registry with every tool body replaced by `wrap(name, body)`, e.g. to inject faults.
+    pub fn map_tools(&self, wrap: impl Fn(&str, ToolFn) -> ToolFn) -> ToolRegistry {
+        let function_map = self
+            .inner
+            .function_map
+            .iter()
+            .map(|(name, tool)| (name.clone(), ToolSpec { func: wrap(name, Arc::clone(&tool.func)), ..tool.clone() }))
+            .collect();
+        ToolRegistry {
+            inner: Arc::new(RegistryInner {
+                verbose: self.inner.verbose,
+                auto_execute_tool: self.inner.auto_execute_tool,
+                tool_system_prompt: self.inner.tool_system_prompt.clone(),
+                catalog_token_budget: self.inner.catalog_token_budget,
+                order: self.inner.order.clone(),
+                function_map,
+                schemas: self.inner.schemas.clone(),
+                base_models: self.inner.base_models.clone(),
+            }),
+        }
+    }
+
+    /// OpenAI function schemas for every registered tool, in registration order.
+    pub fn openai_schemas(&self) -> &[JsonValue] {
+        &self.inner.schemas
+    }
+
+    pub fn base_models(&self) -> &[JsonValue] {
+        &self.inner.base_models
+    }
+
+    // Define the func_to_dict method
+    pub fn func_to_dict(&self, function_name: &str) -> Result<JsonValue, ToolExecutionError> {
+        self.get(function_name)
+            .map(ToolSpec::to_openai_schema)
+            .ok_or_else(|| ToolExecutionError::new(format!("Tool '{}' not found", function_name)))
+    }
+
+    // Define the dict_to_openai_schema_str method
+    pub fn dict_to_openai_schema_str(&self, dict: &JsonValue) -> String {
+        dict.to_string()
+    }
+
+    // Define the multi_dict_to_openai_schema_str method
+    pub fn multi_dict_to_openai_schema_str(&self, dicts: &[JsonValue]) -> String {
+        dicts
+            .iter()
+            .map(|dict| dict.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
     }
 
     // Define the detect_tool_input_type method
-    fn detect_tool_input_type(&self, input: &ToolType) -> String {
+    pub fn detect_tool_input_type(&self, input: &ToolType) -> &'static str {
         match input {
-            ToolType::BaseTool => "Pydantic".to_string(),
-            ToolType::Dictionary(_) => "Dictionary".to_string(),
-            ToolType::Function(_) => "Function".to_string(),
-            ToolType::Unknown => "Unknown".to_string(),
+            ToolType::BaseTool(_) => "Pydantic",
+            ToolType::Dictionary(_) => "Dictionary",
+            ToolType::Function(_) => "Function",
+            ToolType::Unknown => "Unknown",
         }
     }
 
+    /// Execute a list of OpenAI-style tool calls (`{"function": {"name", "arguments"}}`).
+    pub fn execute_tool(&self, tool_calls: &[JsonValue]) -> Result<JsonValue, ToolExecutionError> {
+        let mut results = Vec::with_capacity(tool_calls.len());
+        for call in tool_calls {
+            let function = call.get("function").unwrap_or(call);
+            let name = function["name"]
+                .as_str()
+                .ok_or_else(|| ToolExecutionError::new("Tool call is missing a function name"))?;
+            // OpenAI sends arguments as a JSON-encoded string; accept objects too.
+            let params = match &function["arguments"] {
+                JsonValue::String(raw) => serde_json::from_str(raw).map_err(|e| {
+                    ToolExecutionError::new(format!("Invalid arguments for '{}': {}", name, e))
+                })?,
+                JsonValue::Null => json!({}),
+                other => other.clone(),
+            };
+            let output = self.execute_tool_by_name(name, &params)?;
+            results.push(json!({ "name": name, "output": output }));
+        }
+        Ok(JsonValue::Array(results))
+    }
+
     // Define the dynamic_run method
-    fn dynamic_run(&self, input: &ToolType) -> Result<String, ToolExecutionError> {
-        // Replace the dynamic run logic with a Rust equivalent
-        let tool_input_type = self.detect_tool_input_type(input);
-        match tool_input_type.as_str() {
-            "Pydantic" => {
-                // Replace the base_model_to_openai_function function
-                // with a Rust equivalent
-                let function_str = json!({}).to_string();
-                if self.auto_execute_tool {
-                    // Replace the execute_tool function
-                    // with a Rust equivalent
-                    let result = json!({});
-                    Ok(result.to_string())
-                } else {
-                    Ok(function_str)
-                }
-            }
-            "Dictionary" => {
-                // Replace the function_to_str function
-                // with a Rust equivalent
-                let function_str = json!({}).to_string();
-                if self.auto_execute_tool {
-                    // Replace the execute_tool function
-                    // with a Rust equivalent
-                    let result = json!({});
-                    Ok(result.to_string())
+    pub fn dynamic_run(&self, input: &ToolType) -> Result<String, ToolExecutionError> {
+        if self.inner.verbose {
+            info!("Dynamic run on input type: {}", self.detect_tool_input_type(input));
+        }
+        match input {
+            ToolType::BaseTool(schema) => Ok(schema.to_string()),
+            ToolType::Dictionary(dict) => {
+                let dict = JsonValue::Object(dict.clone().into_iter().collect());
+                if self.inner.auto_execute_tool {
+                    Ok(self.execute_tool(&[dict])?.to_string())
                 } else {
-                    Ok(function_str)
+                    Ok(self.dict_to_openai_schema_str(&dict))
                 }
             }
-            "Function" => {
-                // Replace the get_openai_function_schema_from_func function
-                // with a Rust equivalent
-                let function_str = json!({}).to_string();
-                if self.auto_execute_tool {
-                    // Replace the execute_tool function
-                    // with a Rust equivalent
-                    let result = json!({});
-                    Ok(result.to_string())
+            ToolType::Function(name) => {
+                if self.inner.auto_execute_tool {
+                    Ok(self.execute_tool_by_name(name, &json!({}))?.to_string())
                 } else {
-                    Ok(function_str)
+                    Ok(self.func_to_dict(name)?.to_string())
                 }
             }
-            _ => Err(ToolExecutionError {
-                message: "Unknown tool input type".to_string(),
-            }),
+            ToolType::Unknown => Err(ToolExecutionError::new("Unknown tool input type")),
         }
     }
 
     // Define the execute_tool_by_name method
-    fn execute_tool_by_name(
+    pub fn execute_tool_by_name(
         &self,
         tool_name: &str,
-    ) -> Result<String, ToolExecutionError> {
-        // Replace the execute_tool_by_name logic with a Rust equivalent
+        params: &JsonValue,
+    ) -> Result<JsonValue, ToolExecutionError> {
         let tool = self
-            .list_of_dicts
-            .as_ref()
-            .unwrap()
-            .iter()
-            .find(|dict| dict["name"] == tool_name);
-        if tool.is_none() {
-            return Err(ToolExecutionError {
-                message: format!("Tool '{}' not found", tool_name),
-            });
-        }
-        let tool = tool.unwrap();
-        let function_name = tool["name"].as_str().unwrap();
-        let function = self.function_map.as_ref().unwrap().get(function_name);
-        if function.is_none() {
-            return Err(ToolExecutionError {
-                message: format!("Tool '{}' is not mapped to a function", tool_name),
-            });
+            .get(tool_name)
+            .ok_or_else(|| ToolExecutionError::new(format!("Tool '{}' not found", tool_name)))?;
+        if self.inner.verbose {
+            info!("Executing tool '{}'", tool_name);
         }
-        let function = function.unwrap();
-        // Replace the function call with a Rust equivalent
-        let result = format!("{}()", function);
-        Ok(result)
+        tool.call(params)
     }
 
     // Define the execute_tool_from_text method
-    fn execute_tool_from_text(
-        &self,
-        text: &str,
-    ) -> Result<String, ToolExecutionError> {
-        // Replace the execute_tool_from_text logic with a Rust equivalent
-        let tool: JsonValue = serde_json::from_str(text).unwrap();
-        let tool_name = tool["name"].as_str().unwrap();
-        let tool_params = tool["parameters"].clone();
-        let function = self.function_map.as_ref().unwrap().get(tool_name);
-        if function.is_none() {
-            return Err(ToolExecutionError {
-                message: format!("Tool '{}' is not mapped to a function", tool_name),
-            });
-        }
-        let function = function.unwrap();
-        // Replace the function call with a Rust equivalent
-        let result = format!("{}(params = {})", function, tool_params);
-        Ok(result)
+    pub fn execute_tool_from_text(&self, text: &str) -> Result<JsonValue, ToolExecutionError> {
+        let tool: JsonValue = serde_json::from_str(text)
+            .map_err(|e| ToolExecutionError::new(format!("Invalid tool call JSON: {}", e)))?;
+        let tool_name = tool["name"]
+            .as_str()
+            .ok_or_else(|| ToolExecutionError::new("Tool call is missing a name"))?;
+        self.execute_tool_by_name(tool_name, &tool["parameters"])
     }
 
     // Define the check_str_for_functions_valid method
-    fn check_str_for_functions_valid(&self, output: &str) -> bool {
-        // Replace the check_str_for_functions_valid logic with a Rust equivalent
-        let data: JsonValue = serde_json::from_str(output).unwrap();
-        if data["type"] == "function" && data["function"]["name"].is_string() {
-            let function_name = data["function"]["name"].as_str().unwrap();
-            if self.function_map.as_ref().unwrap().contains_key(function_name) {
-                return true;
-            }
-        }
-        false
-    }
-
-    // Define the convert_funcs_into_tools method
-    fn convert_funcs_into_tools(&self) {
-        // Replace the convert_funcs_into_tools logic with a Rust equivalent
-        if self.tools.is_some() {
-            println!("Tools provided make sure the functions have documentation ++ type hints, otherwise tool execution won't be reliable.");
-            self.convert_tool_into_openai_schema();
-            self.function_map = Some(
-                self.tools
-                    .as_ref()
-                    .unwrap()
-                    .iter()
-                    .map(|tool| (tool.clone(), tool.clone()))
-                    .collect(),
-            );
-        }
-    }
-
-    // Define the convert_tool_into_openai_schema method
-    fn convert_tool_into_openai_schema(&self) {
-        // Replace the convert_tool_into_openai_schema logic with a Rust equivalent
-        let mut tool_schemas = Vec::new();
-        if let Some(tools) = &self.tools {
-            for tool in tools {
-                // Replace the get_openai_function_schema_from_func function
-                // with a Rust equivalent
-                let tool_schema = json!({
-                    "function": {
-                        "name": tool,
-                        "description": "Tool description",
-                    },
-                });
-                tool_schemas.push(tool_schema);
-            }
-        }
-        let combined_schema = json!({
-            "type": "function",
-            "functions": tool_schemas,
-        });
-        println!("{}", combined_schema.to_string());
-    }
-
-    // Define the check_func_if_have_docs method
-    fn check_func_if_have_docs(&self, _func: &str) -> bool {
-        // Replace the check_func_if_have_docs logic with a Rust equivalent
-        true
+    pub fn check_str_for_functions_valid(&self, output: &str) -> bool {
+        let data: JsonValue = match serde_json::from_str(output) {
+            Ok(data) => data,
+            Err(_) => return false,
+        };
+        data["type"] == "function"
+            && data["function"]["name"]
+                .as_str()
+                .map_or(false, |name| self.inner.function_map.contains_key(name))
     }
+}
 
-    // Define the check_func_if_have_type_hints method
-    fn check_func_if_have_type_hints(&self, _func: &str) -> bool {
-        // Replace the check_func_if_have_type_hints logic with a Rust equivalent
-        true
-    }
+fn omitted_tools_line(count: usize) -> String {
+    format!("- ... and {} more tools not listed for brevity.\n", count)
 }
 
 fn main() -> Result<(), ToolExecutionError> {
-    // Create a new BaseTool instance
-    let tool = BaseTool {
-        verbose: Some(true),
-        base_models: None,
-        autocheck: None,
-        auto_execute_tool: None,
-        tools: None,
-        tool_system_prompt: None,
-        function_map: None,
-        list_of_dicts: None,
-    };
+    // Build an immutable registry once and share it by cloning
+    let registry = ToolRegistry::builder()
+        .verbose(true)
+        .auto_execute_tool(true)
+        .tool(
+            "add",
+            "Add two numbers.",
+            json!({
+                "type": "object",
+                "properties": { "a": { "type": "number" }, "b": { "type": "number" } },
+                "required": ["a", "b"],
+            }),
+            |params| {
+                let a = params["a"].as_f64().unwrap_or_default();
+                let b = params["b"].as_f64().unwrap_or_default();
+                Ok(json!(a + b))
+            },
+        )
+        .build()?;
+
+    let shared = registry.clone();
+    let handle = std::thread::spawn(move || shared.execute_tool_from_text(r#"{"name": "add", "parameters": {"a": 1, "b": 2}}"#));
+    println!("{:?}", handle.join().unwrap()?);
+
+    // Render the tool section of the system prompt
+    println!("{}", registry.tool_system_prompt());
 
     // Call the dynamic_run method
-    let tool_type = ToolType::BaseTool;
-    let result = tool.dynamic_run(&tool_type)?;
+    let result = registry.dynamic_run(&ToolType::Function("add".to_string()))?;
     println!("{}", result);
 
     Ok(())
@@ -352,17 +563,12 @@ fn main() -> Result<(), ToolExecutionError> {
 
 ## Limitations and Challenges:
 The Rust code above has several limitations and challenges:
-* The `serde` and `serde_json` crates are used for serialization and deserialization, but the `pydantic` library's data validation and schema functionality is not directly equivalent in Rust.
-* The `log` crate is used for logging, but the `loguru_logger` library's functionality is not directly equivalent in Rust.
-* The `ToolType` enum is used to represent different types of tools, but the Rust code does not handle these types in the same way as the Python code.
-* The `dynamic_run` method uses a match statement to handle different types of tools, but the Rust code does not handle these types in the same way as the Python code.
-* The `execute_tool_by_name` and `execute_tool_from_text` methods are not fully implemented in the Rust code.
-* The `convert_funcs_into_tools` and `convert_tool_into_openai_schema` methods are not fully implemented in the Rust code.
+* The `serde` and `serde_json` crates are used for serialization and deserialization, but the `pydantic` library's data validation and schema functionality is not directly equivalent in Rust. Tool parameter schemas are supplied by hand when registering a tool.
+* Python can introspect a function's docstring and type hints to build its schema; Rust cannot, so `check_func_if_have_docs` and `check_func_if_have_type_hints` are replaced by validation in `ToolRegistryBuilder::build`.
+* The tool catalog budget uses a character-based token estimate rather than the model's tokenizer.
+* A `ToolRegistry` cannot be modified after it is built. To add tools, start a new builder.
 
 ## Future Work:
 To improve the Rust code, the following future work can be done:
-* Implement the `pydantic` library's data validation and schema functionality in Rust using the `serde` and `serde_json` crates.
+* Derive parameter schemas from Rust argument types (e.g. with `schemars`) instead of writing them by hand.
 * Implement the `loguru_logger` library's functionality in Rust using the `log` crate.
-* Handle the different types of tools in the `ToolType` enum in the same way as the Python code.
-* Fully implement the `execute_tool_by_name` and `execute_tool_from_text` methods in the Rust code.
-* Fully implement the `convert_funcs_into_tools` and `convert_tool_into_openai_schema` methods in the Rust code.
\ No newline at end of file
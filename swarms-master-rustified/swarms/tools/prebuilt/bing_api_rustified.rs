@@ -6,11 +6,71 @@
 //            all of which have Rust equivalents. The code structure is relatively straightforward, 
 //            making it convertible to Rust.
 
-use reqwest::blocking;
-use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE, USER_AGENT};
-use serde_json::Value;
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, RestClient, RestError, NO_PARAMS};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::env;
+use std::fmt;
+
+pub const BING_API_BASE: &str = "https://api.bing.microsoft.com/v7.0";
+
+const WEB_SEARCH: Endpoint = Endpoint::get("/search");
+
+#[derive(Debug)]
+pub enum BingError {
+    MissingApiKey,
+    Rest(RestError),
+}
+
+impl fmt::Display for BingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BingError::MissingApiKey => write!(f, "BING_API_KEY environment variable is not set."),
+            BingError::Rest(err) => write!(f, "Bing API: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for BingError {}
+
+impl From<RestError> for BingError {
+    fn from(err: RestError) -> Self {
+        BingError::Rest(err)
+    }
+}
+
+#[derive(Serialize)]
+struct SearchQuery<'a> {
+    q: &'a str,
+    count: u32,
+    mkt: &'a str,
+}
+
+#[derive(Deserialize)]
+struct SearchResponse {
+    #[serde(rename = "webPages")]
+    web_pages: Option<WebPages>,
+}
+
+#[derive(Deserialize)]
+struct WebPages {
+    value: Vec<WebPage>,
+}
+
+#[derive(Deserialize)]
+struct WebPage {
+    url: String,
+    name: String,
+    #[serde(rename = "dateLastCrawled", default)]
+    date_last_crawled: String,
+    #[serde(default)]
+    provider: Vec<Provider>,
+}
+
+#[derive(Deserialize)]
+struct Provider {
+    name: String,
+}
 
 /// Check if the BING_API_KEY environment variable is set.
 fn check_bing_api_key() -> Option<String> {
@@ -18,6 +78,15 @@ fn check_bing_api_key() -> Option<String> {
     env::var("BING_API_KEY").ok()
 }
 
+/// The Bing Web Search client for `subscription_key`.
+pub fn bing_client(subscription_key: impl Into<String>) -> RestClient {
+    RestClient::new(
+        BING_API_BASE,
+        AuthStrategy::Header { name: "Ocp-Apim-Subscription-Key".to_string(), value: subscription_key.into() },
+    )
+    .with_header("User-Agent", "Rust Client")
+}
+
 /// Parses logs and merges them into a single string for input to an LLM.
 fn parse_and_merge_logs(logs: Vec<HashMap<String, String>>) -> String {
     let mut merged_logs = String::new();
@@ -31,77 +100,54 @@ fn parse_and_merge_logs(logs: Vec<HashMap<String, String>>) -> String {
 }
 
 /// Fetches four articles from Bing Web Search API based on the given query.
-async fn fetch_web_articles_bing_api(query: Option<String>) -> Vec<HashMap<String, String>> {
+pub async fn fetch_web_articles_bing_api(query: &str) -> Result<Vec<HashMap<String, String>>, BingError> {
     // Check if the BING_API_KEY environment variable is set.
-    let subscription_key = check_bing_api_key();
-    if subscription_key.is_none() {
-        panic!("BING_API_KEY environment variable is not set.");
-    }
-    
-    let url = "https://api.bing.microsoft.com/v7.0/search";
-    let client = reqwest::Client::new();
-    let mut headers = HeaderMap::new();
-    headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
-    headers.insert(USER_AGENT, HeaderValue::from_static("Rust Client"));
-    headers.insert("Ocp-Apim-Subscription-Key", HeaderValue::from_str(&subscription_key.unwrap()).unwrap());
-    
-    let params = [
-        ("q", query.clone().unwrap()),
-        ("count", "4"),
-        ("mkt", "en-US"),
-    ];
-    let response = client.get(url)
-        .headers(headers)
-        .query(&params)
-        .send()
-        .await
-        .expect("Failed to send request.");
-    
-    if !response.status().is_success() {
-        panic!("Failed to fetch data from Bing API.");
-    }
-    
-    let response_json: Value = response.json().await.expect("Failed to parse JSON response.");
-    let search_results = response_json["webPages"]["value"].as_array().unwrap();
-    
-    let mut articles = Vec::new();
-    for (i, result) in search_results.iter().enumerate() {
-        let article_info = hashmap! {
-            "query".to_string() => query.unwrap(),
-            "url".to_string() => result["url"].as_str().unwrap().to_string(),
-            "title".to_string() => result["name"].as_str().unwrap().to_string(),
-            "publishedDate".to_string() => result["dateLastCrawled"].as_str().unwrap().to_string(),
-            "author".to_string() => if result["provider"].is_array() {
-                result["provider"][0]["name"].as_str().unwrap().to_string()
-            } else {
-                "Unknown".to_string()
-            },
-            "id".to_string() => (i + 1).to_string(),  // Generating a simple unique ID
-        };
-        articles.push(article_info);
-    }
-    // Note: parse_and_merge_logs expects a vector of hashmaps but here 
-    // we are directly passing articles which is already a vector of hashmaps.
-    articles
+    let subscription_key = check_bing_api_key().ok_or(BingError::MissingApiKey)?;
+    fetch_web_articles(&bing_client(subscription_key), query).await
+}
+
+/// Fetches four articles for `query` through `client`.
+pub async fn fetch_web_articles(client: &RestClient, query: &str) -> Result<Vec<HashMap<String, String>>, BingError> {
+    let params = SearchQuery { q: query, count: 4, mkt: "en-US" };
+    let response: SearchResponse = client.call(WEB_SEARCH, &[], &params, NO_PARAMS).await?;
+    let search_results = response.web_pages.map(|pages| pages.value).unwrap_or_default();
+
+    let articles = search_results
+        .into_iter()
+        .enumerate()
+        .map(|(i, result)| {
+            let author = result.provider.into_iter().next().map_or_else(|| "Unknown".to_string(), |p| p.name);
+            HashMap::from([
+                ("query".to_string(), query.to_string()),
+                ("url".to_string(), result.url),
+                ("title".to_string(), result.name),
+                ("publishedDate".to_string(), result.date_last_crawled),
+                ("author".to_string(), author),
+                ("id".to_string(), (i + 1).to_string()), // Generating a simple unique ID
+            ])
+        })
+        .collect();
+    Ok(articles)
 }
 
 #[tokio::main]
-async fn main() {
-    let articles = fetch_web_articles_bing_api(Some("swarms ai github".to_string())).await;
-    println!("{:?}", articles);
+async fn main() -> Result<(), BingError> {
+    let articles = fetch_web_articles_bing_api("swarms ai github").await?;
+    println!("{}", parse_and_merge_logs(articles));
+    Ok(())
 }
 ```
 
 ### Limitations and Challenges
 
-1. **Error Handling**: Error handling in the Rust code is more explicit and verbose compared to Python. The `Result` and `Option` types are used to handle potential errors when interacting with the Bing API.
+1. **Error Handling**: Error handling in the Rust code is more explicit and verbose compared to Python. A missing key or a failed request comes back as a `BingError` instead of a panic.
 2. **Async/Await**: The Rust code uses the `async` and `await` keywords to handle asynchronous operations, such as sending HTTP requests. This can make the code more concise and easier to read, but it requires a basic understanding of asynchronous programming concepts.
 3. **Serialization/Deserialization**: The Rust code uses the `serde_json` crate to serialize and deserialize JSON data. This can add additional complexity to the code, especially when dealing with complex data structures.
-4. **Dependency Management**: The Rust code requires additional dependencies, such as `reqwest` and `serde_json`, which must be managed using the `Cargo` package manager.
+4. **Dependency Management**: Requests go through the shared `RestClient`, so `fetch_web_articles` can be tested with a substituted `HttpTransport`.
 5. **Concurrency**: The Rust code uses the `tokio` crate to handle concurrency and asynchronous operations. This can add additional complexity to the code, especially when dealing with concurrent operations.
 
 ### Potential Risks
 
-1. **Data Loss**: If the Bing API returns an error or the response is malformed, the Rust code may not handle it properly, potentially leading to data loss.
+1. **Data Loss**: A response without `webPages` yields no articles rather than an error.
 2. **Rate Limiting**: If the Bing API has rate limits on the number of requests that can be made within a certain time period, the Rust code may exceed these limits if it is not implemented correctly.
 3. **Security**: The Rust code uses the `Ocp-Apim-Subscription-Key` header to authenticate with the Bing API. If this key is not handled properly, it could be compromised, potentially leading to security vulnerabilities.
\ No newline at end of file
@@ -0,0 +1,264 @@
+### Memory Search Tool
+
+There is no Python counterpart; in the Python examples only the agent that owns `long_term_memory` can query
+it. `MemorySearchTool` exposes `VectorMemory::recall` as the `search_memory` tool, so any agent in a swarm can
+query the shared knowledge base mid-conversation. Each agent gets a private namespace (`agent:<name>`) and
+sees shared namespaces only when a `MemoryAccessPolicy` grants it. Hits carry their citations so answers
+built on recalled material stay traceable. With a `Reranker` attached (from the agent's `reranker` config
+through `from_schema`), a larger pool is recalled and reranked against the query, and only the best hits
+are kept. With a `ContextCompressor` attached, hit texts are compressed against the query before they are
+returned to the model.
+
+```rust
+use crate::swarms::memory::reranker::{Candidate, CrossEncoder, RerankError, Reranker};
+use crate::swarms::memory::vector_memory::{MemoryError, RecallHit, VectorMemory};
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use crate::swarms::text::compression::ContextCompressor;
+use crate::swarms::tools::base_tool::{ToolExecutionError, ToolRegistryBuilder};
+use crate::swarms::utils::blocking;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+/// How many candidates are recalled per hit kept when a reranker picks the hits.
+const RERANK_POOL: usize = 4;
+
+/// The private namespace of `agent`.
+pub fn agent_namespace(agent: &str) -> String {
+    format!("agent:{}", agent)
+}
+
+/// Which shared namespaces each agent may read and write. An agent can always
+/// read and write its own `agent:<name>` namespace.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MemoryAccessPolicy {
+    /// Namespaces every agent may read.
+    #[serde(default)]
+    pub public: HashSet<String>,
+    #[serde(default)]
+    pub read: HashMap<String, HashSet<String>>,
+    #[serde(default)]
+    pub write: HashMap<String, HashSet<String>>,
+}
+
+impl MemoryAccessPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn public(mut self, namespace: impl Into<String>) -> Self {
+        self.public.insert(namespace.into());
+        self
+    }
+
+    pub fn allow_read(mut self, agent: impl Into<String>, namespace: impl Into<String>) -> Self {
+        self.read.entry(agent.into()).or_default().insert(namespace.into());
+        self
+    }
+
+    /// Write access implies read access.
+    pub fn allow_write(mut self, agent: impl Into<String>, namespace: impl Into<String>) -> Self {
+        let (agent, namespace) = (agent.into(), namespace.into());
+        self.read.entry(agent.clone()).or_default().insert(namespace.clone());
+        self.write.entry(agent).or_default().insert(namespace);
+        self
+    }
+
+    pub fn can_read(&self, agent: &str, namespace: &str) -> bool {
+        namespace == agent_namespace(agent)
+            || self.public.contains(namespace)
+            || self.read.get(agent).map_or(false, |ns| ns.contains(namespace))
+    }
+
+    pub fn can_write(&self, agent: &str, namespace: &str) -> bool {
+        namespace == agent_namespace(agent) || self.write.get(agent).map_or(false, |ns| ns.contains(namespace))
+    }
+
+    /// Every namespace `agent` may read, sorted.
+    pub fn readable(&self, agent: &str) -> Vec<String> {
+        let mut namespaces: HashSet<String> = self.public.clone();
+        namespaces.insert(agent_namespace(agent));
+        if let Some(granted) = self.read.get(agent) {
+            namespaces.extend(granted.iter().cloned());
+        }
+        let mut namespaces: Vec<String> = namespaces.into_iter().collect();
+        namespaces.sort();
+        namespaces
+    }
+}
+
+/// `VectorMemory` as seen by one agent, with the policy enforced on every call.
+#[derive(Clone)]
+pub struct MemorySearchTool {
+    memory: VectorMemory,
+    policy: Arc<MemoryAccessPolicy>,
+    agent: String,
+    top_k: usize,
+    reranker: Option<Arc<dyn Reranker>>,
+    compressor: Option<ContextCompressor>,
+}
+
+impl MemorySearchTool {
+    pub fn new(memory: VectorMemory, policy: Arc<MemoryAccessPolicy>, agent: impl Into<String>) -> Self {
+        MemorySearchTool { memory, policy, agent: agent.into(), top_k: 5, reranker: None, compressor: None }
+    }
+
+    /// The tool for the agent `schema` describes, reranking with its `reranker` config when it has one. The
+    /// config's `top_n` becomes the number of hits returned. A cross-encoder config needs `cross_encoder`.
+    pub fn from_schema(
+        memory: VectorMemory,
+        policy: Arc<MemoryAccessPolicy>,
+        schema: &AgentSchema,
+        cross_encoder: Option<Arc<dyn CrossEncoder>>,
+    ) -> Result<Self, RerankError> {
+        let tool = MemorySearchTool::new(memory, policy, schema.agent_name.clone());
+        match &schema.reranker {
+            Some(config) => Ok(tool.top_k(config.top_n()).reranker(Arc::from(config.build(cross_encoder)?))),
+            None => Ok(tool),
+        }
+    }
+
+    pub fn top_k(mut self, top_k: usize) -> Self {
+        self.top_k = top_k.max(1);
+        self
+    }
+
+    /// Rerank recalled passages against the query before they are returned.
+    pub fn reranker(mut self, reranker: Arc<dyn Reranker>) -> Self {
+        self.reranker = Some(reranker);
+        self
+    }
+
+    /// Compress recalled passages before the tool returns them.
+    pub fn compressor(mut self, compressor: ContextCompressor) -> Self {
+        self.compressor = Some(compressor);
+        self
+    }
+
+    /// Apply the configured compressor to `hits`, dropping hits that compress to nothing.
+    pub fn compress_hits(&self, query: &str, hits: Vec<RecallHit>) -> Vec<RecallHit> {
+        let Some(compressor) = &self.compressor else {
+            return hits;
+        };
+        let texts: Vec<String> = hits.iter().map(|hit| hit.text.clone()).collect();
+        let compressed = compressor.compress(query, &texts);
+        hits.into_iter()
+            .zip(compressed.passages)
+            .filter(|(_, text)| !text.is_empty())
+            .map(|(hit, text)| RecallHit { text, ..hit })
+            .collect()
+    }
+
+    /// Search `namespaces`, or everything the agent may read when `None`.
+    pub async fn search(&self, query: &str, namespaces: Option<Vec<String>>, top_k: Option<usize>) -> Result<Vec<RecallHit>, MemoryError> {
+        let namespaces = match namespaces {
+            Some(requested) => {
+                if let Some(denied) = requested.iter().find(|ns| !self.policy.can_read(&self.agent, ns)) {
+                    return Err(MemoryError::AccessDenied { agent: self.agent.clone(), namespace: denied.clone() });
+                }
+                requested
+            }
+            None => self.policy.readable(&self.agent),
+        };
+        let top_k = top_k.unwrap_or(self.top_k).min(50);
+        match &self.reranker {
+            Some(reranker) => {
+                let hits = self.memory.recall(&namespaces, query, (top_k * RERANK_POOL).min(50)).await?;
+                self.rerank_hits(reranker.as_ref(), query, hits, top_k).await
+            }
+            None => self.memory.recall(&namespaces, query, top_k).await,
+        }
+    }
+
+    // Keep the best `top_k` of `hits` in the reranker's order, scored by the reranker
+    async fn rerank_hits(
+        &self,
+        reranker: &dyn Reranker,
+        query: &str,
+        hits: Vec<RecallHit>,
+        top_k: usize,
+    ) -> Result<Vec<RecallHit>, MemoryError> {
+        let candidates = hits
+            .iter()
+            .map(|hit| Candidate { id: hit.id.clone(), text: hit.text.clone(), score: hit.score, metadata: hit.metadata.clone() })
+            .collect();
+        let ranked = reranker.rerank(query, candidates, top_k).await?;
+        let mut hits: Vec<Option<RecallHit>> = hits.into_iter().map(Some).collect();
+        Ok(ranked
+            .into_iter()
+            .filter_map(|ranked| hits[ranked.before_rank].take().map(|hit| RecallHit { score: ranked.rerank_score, ..hit }))
+            .collect())
+    }
+
+    /// Store `text` in `namespace` (the agent's own namespace when `None`).
+    pub async fn remember(&self, text: &str, namespace: Option<&str>) -> Result<String, MemoryError> {
+        let namespace = namespace.map(str::to_string).unwrap_or_else(|| agent_namespace(&self.agent));
+        if !self.policy.can_write(&self.agent, &namespace) {
+            return Err(MemoryError::AccessDenied { agent: self.agent.clone(), namespace });
+        }
+        let metadata = HashMap::from([("author".to_string(), self.agent.clone())]);
+        self.memory.add(&namespace, text, metadata, None).await
+    }
+
+    /// Register as the `search_memory` tool. The tool searches on the shared `blocking` runtime, so it can be
+    /// called from any thread, with or without a runtime of its own.
+    pub fn register(self, builder: ToolRegistryBuilder) -> ToolRegistryBuilder {
+        let readable = self.policy.readable(&self.agent);
+        builder.tool(
+            "search_memory",
+            "Search the shared knowledge base and return the most relevant passages with their sources.",
+            json!({
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "What to look for" },
+                    "namespaces": {
+                        "type": "array",
+                        "items": { "type": "string", "enum": readable },
+                        "description": "Namespaces to search; defaults to all you can read"
+                    },
+                    "top_k": { "type": "integer", "minimum": 1, "maximum": 50 }
+                },
+                "required": ["query"],
+            }),
+            move |params| {
+                let query = params["query"]
+                    .as_str()
+                    .ok_or_else(|| ToolExecutionError::new("search_memory requires a 'query' string"))?;
+                let namespaces = params["namespaces"].as_array().map(|items| {
+                    items.iter().filter_map(|v| v.as_str().map(str::to_string)).collect::<Vec<_>>()
+                });
+                let top_k = params["top_k"].as_u64().map(|k| k as usize);
+                let (tool, owned_query) = (self.clone(), query.to_string());
+                let hits = blocking::block_on(async move { tool.search(&owned_query, namespaces, top_k).await })
+                    .map_err(|e| ToolExecutionError::new(e.to_string()))?;
+                let hits = self.compress_hits(query, hits);
+                Ok(json!(hits
+                    .into_iter()
+                    .map(|hit| json!({
+                        "text": hit.text,
+                        "namespace": hit.namespace,
+                        "score": hit.score,
+                        "source": hit.citation.map(|c| c.label()),
+                    }))
+                    .collect::<Vec<_>>()))
+            },
+        )
+    }
+}
+
+fn main() {
+    let policy = MemoryAccessPolicy::new()
+        .public("company-handbook")
+        .allow_read("Financial-Analyst", "sec-filings")
+        .allow_write("Research-Agent", "sec-filings");
+    println!("{:?}", policy.readable("Financial-Analyst"));
+    println!("{}", policy.can_write("Financial-Analyst", "sec-filings"));
+}
+```
+
+### Notes
+* Requesting a namespace the agent cannot read fails the whole call with `AccessDenied` instead of silently dropping it, so the model sees why it got nothing.
+* The tool schema enumerates the agent's readable namespaces, so the model is steered toward valid values before the policy is ever checked.
+* A reranked hit's `score` is the reranker's, not the embedding similarity; the similarity is in the rerank telemetry event as its `before_score`.
+* `remember` is not registered as a tool; write access is for the orchestration code that feeds memory.
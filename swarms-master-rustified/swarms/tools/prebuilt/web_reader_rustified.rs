@@ -0,0 +1,397 @@
+### Web Reader Tool
+
+There is no Python counterpart. `WebReaderTool` is the companion to `WebSearchTool`: given a URL it fetches
+the page, strips navigation, scripts, and other boilerplate (a simplified readability pass that keeps the
+densest content block), converts what remains to Markdown, and truncates it to a token budget so a
+summarizer agent gets the article rather than the whole page. Every result carries its source URL, title,
+and fetch time in `metadata` so downstream agents can cite it.
+
+```rust
+use crate::swarms::integrations::rest::shared_client;
+use crate::swarms::text::tokenizer::{default_tokenizer, Tokenizer};
+use crate::swarms::tools::base_tool::{ToolExecutionError, ToolRegistryBuilder};
+use crate::swarms::utils::blocking;
+use chrono::Utc;
+use scraper::{ElementRef, Html, Node, Selector};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+const USER_AGENT: &str = "swarms-web-reader/0.1";
+const FETCH_TIMEOUT: Duration = Duration::from_secs(20);
+
+/// Elements that never contain article content.
+const BOILERPLATE_TAGS: [&str; 12] = [
+    "script", "style", "noscript", "nav", "header", "footer", "aside", "form", "iframe", "svg", "button", "template",
+];
+
+/// A fetched and cleaned page.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WebPage {
+    pub url: String,
+    pub title: Option<String>,
+    pub markdown: String,
+    pub token_count: usize,
+    /// True when content was cut to fit the token budget.
+    pub truncated: bool,
+    /// Citation metadata: `source_url`, `title`, `fetched_at`.
+    pub metadata: HashMap<String, String>,
+}
+
+#[derive(Debug)]
+pub enum WebReaderError {
+    InvalidUrl(String),
+    Http(String),
+    Status(u16),
+    NotHtml(String),
+}
+
+impl fmt::Display for WebReaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            WebReaderError::InvalidUrl(url) => write!(f, "invalid URL: {}", url),
+            WebReaderError::Http(msg) => write!(f, "fetch failed: {}", msg),
+            WebReaderError::Status(status) => write!(f, "server returned {}", status),
+            WebReaderError::NotHtml(kind) => write!(f, "unsupported content type: {}", kind),
+        }
+    }
+}
+
+impl std::error::Error for WebReaderError {}
+
+#[derive(Clone)]
+pub struct WebReaderTool {
+    client: reqwest::Client,
+    tokenizer: Arc<dyn Tokenizer>,
+    max_tokens: usize,
+}
+
+impl WebReaderTool {
+    pub fn new() -> Self {
+        WebReaderTool {
+            client: shared_client(),
+            tokenizer: default_tokenizer(),
+            max_tokens: 4000,
+        }
+    }
+
+    pub fn max_tokens(mut self, max_tokens: usize) -> Self {
+        self.max_tokens = max_tokens;
+        self
+    }
+
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = tokenizer;
+        self
+    }
+
+    /// Fetch `url` and extract its main content as Markdown.
+    pub async fn read(&self, url: &str) -> Result<WebPage, WebReaderError> {
+        let parsed = reqwest::Url::parse(url).map_err(|_| WebReaderError::InvalidUrl(url.to_string()))?;
+        if !matches!(parsed.scheme(), "http" | "https") {
+            return Err(WebReaderError::InvalidUrl(url.to_string()));
+        }
+        let response = self
+            .client
+            .get(parsed)
+            .timeout(FETCH_TIMEOUT)
+            .header(reqwest::header::USER_AGENT, USER_AGENT)
+            .send()
+            .await
+            .map_err(|e| WebReaderError::Http(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(WebReaderError::Status(response.status().as_u16()));
+        }
+        let content_type = response
+            .headers()
+            .get(reqwest::header::CONTENT_TYPE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or("text/html")
+            .to_string();
+        let body = response.text().await.map_err(|e| WebReaderError::Http(e.to_string()))?;
+
+        if content_type.starts_with("text/plain") || content_type.starts_with("text/markdown") {
+            return Ok(self.finish(url, None, body));
+        }
+        if !content_type.contains("html") {
+            return Err(WebReaderError::NotHtml(content_type));
+        }
+        let (title, markdown) = extract_markdown(&body);
+        Ok(self.finish(url, title, markdown))
+    }
+
+    fn finish(&self, url: &str, title: Option<String>, markdown: String) -> WebPage {
+        let (markdown, truncated) = truncate_to_tokens(&markdown, self.max_tokens, self.tokenizer.as_ref());
+        let mut metadata = HashMap::new();
+        metadata.insert("source_url".to_string(), url.to_string());
+        metadata.insert("fetched_at".to_string(), Utc::now().to_rfc3339());
+        if let Some(title) = &title {
+            metadata.insert("title".to_string(), title.clone());
+        }
+        WebPage {
+            url: url.to_string(),
+            title,
+            token_count: self.tokenizer.count_tokens(&markdown),
+            markdown,
+            truncated,
+            metadata,
+        }
+    }
+
+    /// Register as the `read_web_page` tool. The tool fetches on the shared `blocking` runtime, so it can be
+    /// called from any thread, with or without a runtime of its own.
+    pub fn register(self, builder: ToolRegistryBuilder) -> ToolRegistryBuilder {
+        builder.tool(
+            "read_web_page",
+            "Fetch a web page and return its main content as Markdown.",
+            json!({
+                "type": "object",
+                "properties": { "url": { "type": "string", "description": "An http(s) URL" } },
+                "required": ["url"],
+            }),
+            move |params| {
+                let url = params["url"]
+                    .as_str()
+                    .ok_or_else(|| ToolExecutionError::new("read_web_page requires a 'url' string"))?;
+                let (reader, url) = (self.clone(), url.to_string());
+                let page = blocking::block_on(async move { reader.read(&url).await })
+                    .map_err(|e| ToolExecutionError::new(e.to_string()))?;
+                serde_json::to_value(page).map_err(|e| ToolExecutionError::new(e.to_string()))
+            },
+        )
+    }
+}
+
+/// Extract the title and main content of an HTML document as Markdown.
+pub fn extract_markdown(html: &str) -> (Option<String>, String) {
+    let document = Html::parse_document(html);
+    let title = Selector::parse("title")
+        .ok()
+        .and_then(|s| document.select(&s).next())
+        .map(|t| collapse_whitespace(&t.text().collect::<String>()))
+        .filter(|t| !t.is_empty());
+
+    let root = main_content(&document);
+    let mut out = String::new();
+    if let Some(root) = root {
+        render_children(root, &mut out);
+    }
+    (title, tidy_markdown(&out))
+}
+
+/// Pick the content root: `<article>`, then `<main>`, then the deepest block that holds nearly all of the page's
+/// prose. Every block contains the prose of the blocks inside it, so the most prose alone would always pick
+/// `<body>`; the depth tie-break narrows it to the container the article actually sits in.
+fn main_content(document: &Html) -> Option<ElementRef<'_>> {
+    for tag in ["article", "main", "[role=main]"] {
+        if let Some(found) = Selector::parse(tag).ok().and_then(|s| document.select(&s).next()) {
+            return Some(found);
+        }
+    }
+    let blocks = Selector::parse("div, section, body").ok()?;
+    let paragraphs = Selector::parse("p").ok()?;
+    let links = Selector::parse("a").ok()?;
+    let scored: Vec<(ElementRef<'_>, usize)> = document
+        .select(&blocks)
+        .map(|block| (block, prose_score(block, &paragraphs, &links)))
+        .collect();
+    let best = scored.iter().map(|(_, score)| *score).max()?;
+    if best == 0 {
+        return Selector::parse("body").ok().and_then(|s| document.select(&s).next());
+    }
+    scored
+        .into_iter()
+        .filter(|(_, score)| score * 5 >= best * 4)
+        .max_by_key(|(block, score)| (block.ancestors().count(), *score))
+        .map(|(block, _)| block)
+}
+
+/// Paragraph text outside links, or zero for a block that is mostly link text (a menu or a list of related
+/// stories).
+fn prose_score(block: ElementRef<'_>, paragraphs: &Selector, links: &Selector) -> usize {
+    let text_len = |element: ElementRef<'_>| element.text().map(|text| text.trim().len()).sum::<usize>();
+    let total = text_len(block);
+    let linked: usize = block.select(links).map(text_len).sum();
+    if total == 0 || linked * 2 > total {
+        return 0;
+    }
+    block
+        .select(paragraphs)
+        .map(|p| text_len(p).saturating_sub(p.select(links).map(text_len).sum()))
+        .sum()
+}
+
+fn render_children(element: ElementRef<'_>, out: &mut String) {
+    for child in element.children() {
+        match child.value() {
+            Node::Text(text) => out.push_str(&collapse_inline(text)),
+            Node::Element(_) => {
+                if let Some(child) = ElementRef::wrap(child) {
+                    render_element(child, out);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn render_element(element: ElementRef<'_>, out: &mut String) {
+    let name = element.value().name();
+    if BOILERPLATE_TAGS.contains(&name) {
+        return;
+    }
+    match name {
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+            let level = name[1..].parse::<usize>().unwrap_or(1);
+            out.push_str(&format!("\n\n{} {}\n\n", "#".repeat(level), inline_text(element)));
+        }
+        "p" | "div" | "section" | "blockquote" => {
+            out.push_str("\n\n");
+            if name == "blockquote" {
+                out.push_str("> ");
+            }
+            render_children(element, out);
+            out.push_str("\n\n");
+        }
+        "br" => out.push('\n'),
+        "li" => {
+            out.push_str("\n- ");
+            render_children(element, out);
+        }
+        "ul" | "ol" => {
+            render_children(element, out);
+            out.push_str("\n\n");
+        }
+        "pre" => {
+            out.push_str("\n\n```\n");
+            out.push_str(element.text().collect::<String>().trim_end());
+            out.push_str("\n```\n\n");
+        }
+        "code" => out.push_str(&format!("`{}`", element.text().collect::<String>())),
+        "strong" | "b" => out.push_str(&format!("**{}**", inline_text(element))),
+        "em" | "i" => out.push_str(&format!("*{}*", inline_text(element))),
+        "a" => {
+            let text = inline_text(element);
+            match element.value().attr("href") {
+                Some(href) if href.starts_with("http") && !text.is_empty() => {
+                    out.push_str(&format!("[{}]({})", text, href))
+                }
+                _ => out.push_str(&text),
+            }
+        }
+        "img" => {}
+        _ => render_children(element, out),
+    }
+}
+
+fn inline_text(element: ElementRef<'_>) -> String {
+    collapse_whitespace(&element.text().collect::<String>())
+}
+
+fn collapse_whitespace(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Collapse runs of whitespace inside a text node but keep a single leading/trailing space.
+fn collapse_inline(text: &str) -> String {
+    let collapsed = collapse_whitespace(text);
+    if collapsed.is_empty() {
+        return if text.is_empty() { String::new() } else { " ".to_string() };
+    }
+    let lead = if text.starts_with(char::is_whitespace) { " " } else { "" };
+    let trail = if text.ends_with(char::is_whitespace) { " " } else { "" };
+    format!("{}{}{}", lead, collapsed, trail)
+}
+
+/// Trim lines and squeeze blank runs to a single blank line.
+fn tidy_markdown(markdown: &str) -> String {
+    let mut out = String::new();
+    let mut blank = 0;
+    for line in markdown.lines() {
+        let line = line.trim_end();
+        let line = if line.trim_start().starts_with("- ") { line.trim_start() } else { line.trim() };
+        if line.is_empty() {
+            blank += 1;
+            continue;
+        }
+        if !out.is_empty() {
+            out.push_str(if blank > 0 { "\n\n" } else { "\n" });
+        }
+        out.push_str(line);
+        blank = 0;
+    }
+    out
+}
+
+/// Keep whole paragraphs while they fit in `max_tokens`; a first paragraph that is too long on its own is cut
+/// between words. Each paragraph is counted once and the counts are summed, so the cost stays linear in the
+/// length of the page.
+pub fn truncate_to_tokens(markdown: &str, max_tokens: usize, tokenizer: &dyn Tokenizer) -> (String, bool) {
+    if tokenizer.count_tokens(markdown) <= max_tokens {
+        return (markdown.to_string(), false);
+    }
+    let marker = "\n\n[... truncated]";
+    let budget = max_tokens.saturating_sub(tokenizer.count_tokens(marker));
+    let separator = tokenizer.count_tokens("\n\n");
+    let mut kept = String::new();
+    let mut used = 0;
+    for paragraph in markdown.split("\n\n") {
+        let cost = tokenizer.count_tokens(paragraph) + if kept.is_empty() { 0 } else { separator };
+        if used + cost > budget {
+            if kept.is_empty() {
+                kept = cut_to_tokens(paragraph, budget, tokenizer);
+            }
+            break;
+        }
+        if !kept.is_empty() {
+            kept.push_str("\n\n");
+        }
+        kept.push_str(paragraph);
+        used += cost;
+    }
+    kept.push_str(marker);
+    (kept, true)
+}
+
+/// The longest start of `text` that fits in `budget`, cut after a word, or inside the first word when even that
+/// does not fit (text without spaces, such as a long URL or CJK).
+fn cut_to_tokens(text: &str, budget: usize, tokenizer: &dyn Tokenizer) -> String {
+    let (mut used, mut end) = (0, 0);
+    for word in text.split_inclusive(char::is_whitespace) {
+        let cost = tokenizer.count_tokens(word);
+        if used + cost > budget {
+            if end == 0 {
+                for c in word.chars() {
+                    used += tokenizer.count_tokens(c.encode_utf8(&mut [0; 4]));
+                    if used > budget {
+                        break;
+                    }
+                    end += c.len_utf8();
+                }
+            }
+            break;
+        }
+        used += cost;
+        end += word.len();
+    }
+    text[..end].trim_end().to_string()
+}
+
+#[tokio::main(flavor = "multi_thread")]
+async fn main() -> Result<(), WebReaderError> {
+    let reader = WebReaderTool::new().max_tokens(1500);
+    let page = reader.read("https://www.rust-lang.org/").await?;
+    println!("{:?}\n{}", page.title, page.markdown);
+    Ok(())
+}
+```
+
+### Notes
+* Extraction is heuristic: pages that render their content with JavaScript come back nearly empty.
+* Without an `<article>` or `<main>`, the content root is the deepest `div`, `section`, or `body` holding at
+  least 80% of the page's paragraph text outside links. Blocks whose text is mostly links score zero.
+* Truncation keeps whole paragraphs, so the result may be somewhat under the budget. Paragraph counts are summed
+  rather than re-counting the growing text, which with the heuristic tokenizer errs on the short side.
@@ -0,0 +1,342 @@
+### Web Search Tool
+
+There is no single Python counterpart; `bing_api.py` covers one engine with hard-coded parsing. The Rust
+`WebSearchTool` gives research agents (College-Research-Specialist, Market-Analyst) one tool that works
+across SerpAPI, Brave Search, and Tavily. Results are normalized to `SearchResult { title, url, snippet }`,
+repeated queries are served from a bounded TTL cache, and requests to the engine are rate limited so a swarm of
+agents searching in parallel does not exhaust the API quota.
+
+```rust
+use crate::swarms::integrations::rest::{AuthStrategy, Endpoint, RestClient, RestError, NO_PARAMS};
+use crate::swarms::tools::base_tool::{ToolExecutionError, ToolRegistryBuilder};
+use crate::swarms::utils::blocking;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value as JsonValue};
+use std::collections::HashMap;
+use std::env;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// A normalized search hit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SearchResult {
+    pub title: String,
+    pub url: String,
+    pub snippet: String,
+}
+
+/// A search backend.
+#[async_trait]
+pub trait SearchEngine: Send + Sync {
+    fn name(&self) -> &str;
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>, RestError>;
+}
+
+fn str_field(value: &JsonValue, key: &str) -> String {
+    value[key].as_str().unwrap_or_default().to_string()
+}
+
+/// SerpAPI (Google results), keyed by `SERPAPI_API_KEY`.
+pub struct SerpApiEngine {
+    rest: RestClient,
+}
+
+impl SerpApiEngine {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        SerpApiEngine {
+            rest: RestClient::new(
+                "https://serpapi.com",
+                AuthStrategy::Query { name: "api_key".to_string(), value: api_key.into() },
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchEngine for SerpApiEngine {
+    fn name(&self) -> &str {
+        "serpapi"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>, RestError> {
+        let response: JsonValue = self
+            .rest
+            .call(
+                Endpoint::get("/search.json"),
+                &[],
+                &json!({ "engine": "google", "q": query, "num": max_results }),
+                NO_PARAMS,
+            )
+            .await?;
+        Ok(response["organic_results"]
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|item| SearchResult {
+                        title: str_field(item, "title"),
+                        url: str_field(item, "link"),
+                        snippet: str_field(item, "snippet"),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+/// Brave Search, keyed by `BRAVE_API_KEY`.
+pub struct BraveEngine {
+    rest: RestClient,
+}
+
+impl BraveEngine {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        BraveEngine {
+            rest: RestClient::new(
+                "https://api.search.brave.com/res/v1",
+                AuthStrategy::Header { name: "X-Subscription-Token".to_string(), value: api_key.into() },
+            ),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchEngine for BraveEngine {
+    fn name(&self) -> &str {
+        "brave"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>, RestError> {
+        let response: JsonValue = self
+            .rest
+            .call(Endpoint::get("/web/search"), &[], &json!({ "q": query, "count": max_results }), NO_PARAMS)
+            .await?;
+        Ok(response["web"]["results"]
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|item| SearchResult {
+                        title: str_field(item, "title"),
+                        url: str_field(item, "url"),
+                        snippet: str_field(item, "description"),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+/// Tavily, keyed by `TAVILY_API_KEY`.
+pub struct TavilyEngine {
+    rest: RestClient,
+    api_key: String,
+}
+
+impl TavilyEngine {
+    pub fn new(api_key: impl Into<String>) -> Self {
+        TavilyEngine {
+            rest: RestClient::new("https://api.tavily.com", AuthStrategy::None),
+            api_key: api_key.into(),
+        }
+    }
+}
+
+#[async_trait]
+impl SearchEngine for TavilyEngine {
+    fn name(&self) -> &str {
+        "tavily"
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> Result<Vec<SearchResult>, RestError> {
+        // Tavily takes its key in the JSON body rather than a header.
+        let body = json!({ "api_key": self.api_key, "query": query, "max_results": max_results });
+        let response: JsonValue = self.rest.call(Endpoint::post("/search"), &[], &(), Some(&body)).await?;
+        Ok(response["results"]
+            .as_array()
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|item| SearchResult {
+                        title: str_field(item, "title"),
+                        url: str_field(item, "url"),
+                        snippet: str_field(item, "content"),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+}
+
+/// Engine choice for configuration files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchEngineKind {
+    SerpApi,
+    Brave,
+    Tavily,
+}
+
+impl SearchEngineKind {
+    /// Build the engine from its API key environment variable.
+    pub fn from_env(self) -> Result<Arc<dyn SearchEngine>, ToolExecutionError> {
+        let (var, build): (&str, fn(String) -> Arc<dyn SearchEngine>) = match self {
+            SearchEngineKind::SerpApi => ("SERPAPI_API_KEY", |k| Arc::new(SerpApiEngine::new(k))),
+            SearchEngineKind::Brave => ("BRAVE_API_KEY", |k| Arc::new(BraveEngine::new(k))),
+            SearchEngineKind::Tavily => ("TAVILY_API_KEY", |k| Arc::new(TavilyEngine::new(k))),
+        };
+        let key = env::var(var).map_err(|_| ToolExecutionError::new(format!("{} environment variable is not set", var)))?;
+        Ok(build(key))
+    }
+}
+
+struct CacheEntry {
+    stored_at: Instant,
+    results: Vec<SearchResult>,
+}
+
+/// Web search with caching and rate limiting in front of a `SearchEngine`.
+#[derive(Clone)]
+pub struct WebSearchTool {
+    engine: Arc<dyn SearchEngine>,
+    max_results: usize,
+    cache_ttl: Duration,
+    cache_capacity: usize,
+    min_interval: Duration,
+    cache: Arc<Mutex<HashMap<String, CacheEntry>>>,
+    next_slot: Arc<tokio::sync::Mutex<Instant>>,
+}
+
+impl WebSearchTool {
+    pub fn new(engine: Arc<dyn SearchEngine>) -> Self {
+        WebSearchTool {
+            engine,
+            max_results: 5,
+            cache_ttl: Duration::from_secs(15 * 60),
+            cache_capacity: 256,
+            min_interval: Duration::from_millis(1000),
+            cache: Arc::new(Mutex::new(HashMap::new())),
+            next_slot: Arc::new(tokio::sync::Mutex::new(Instant::now())),
+        }
+    }
+
+    pub fn max_results(mut self, max_results: usize) -> Self {
+        self.max_results = max_results.max(1);
+        self
+    }
+
+    pub fn cache_ttl(mut self, ttl: Duration) -> Self {
+        self.cache_ttl = ttl;
+        self
+    }
+
+    /// Most queries kept in the cache. When it is full, expired entries are swept first, then the oldest go.
+    pub fn cache_capacity(mut self, capacity: usize) -> Self {
+        self.cache_capacity = capacity.max(1);
+        self
+    }
+
+    /// Minimum delay between requests to the engine (shared across clones).
+    pub fn requests_per_second(mut self, rps: f64) -> Self {
+        self.min_interval = if rps > 0.0 { Duration::from_secs_f64(1.0 / rps) } else { Duration::ZERO };
+        self
+    }
+
+    fn cache_key(&self, query: &str) -> String {
+        format!("{}:{}:{}", self.engine.name(), self.max_results, query.trim().to_lowercase())
+    }
+
+    fn cached(&self, key: &str) -> Option<Vec<SearchResult>> {
+        let mut cache = self.cache.lock().unwrap();
+        match cache.get(key) {
+            Some(entry) if entry.stored_at.elapsed() < self.cache_ttl => Some(entry.results.clone()),
+            Some(_) => {
+                cache.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, key: String, results: Vec<SearchResult>) {
+        let mut cache = self.cache.lock().unwrap();
+        if cache.len() >= self.cache_capacity && !cache.contains_key(&key) {
+            cache.retain(|_, entry| entry.stored_at.elapsed() < self.cache_ttl);
+            while cache.len() >= self.cache_capacity {
+                let oldest = cache.iter().min_by_key(|(_, entry)| entry.stored_at).map(|(key, _)| key.clone());
+                match oldest {
+                    Some(oldest) => cache.remove(&oldest),
+                    None => break,
+                };
+            }
+        }
+        cache.insert(key, CacheEntry { stored_at: Instant::now(), results });
+    }
+
+    /// Queries currently held in the cache, expired or not.
+    pub fn cached_queries(&self) -> usize {
+        self.cache.lock().unwrap().len()
+    }
+
+    /// Wait for the next rate-limit slot.
+    async fn throttle(&self) {
+        let mut next = self.next_slot.lock().await;
+        let now = Instant::now();
+        if *next > now {
+            tokio::time::sleep(*next - now).await;
+        }
+        *next = Instant::now() + self.min_interval;
+    }
+
+    pub async fn search(&self, query: &str) -> Result<Vec<SearchResult>, RestError> {
+        let key = self.cache_key(query);
+        if let Some(results) = self.cached(&key) {
+            return Ok(results);
+        }
+        self.throttle().await;
+        let mut results = self.engine.search(query, self.max_results).await?;
+        results.retain(|r| !r.url.is_empty());
+        results.truncate(self.max_results);
+        self.store(key, results.clone());
+        Ok(results)
+    }
+
+    /// Register as the `web_search` tool. The tool runs its search on the shared `blocking` runtime, so it can be
+    /// called from any thread, with or without a runtime of its own.
+    pub fn register(self, builder: ToolRegistryBuilder) -> ToolRegistryBuilder {
+        builder.tool(
+            "web_search",
+            "Search the web and return the top results (title, url, snippet).",
+            json!({
+                "type": "object",
+                "properties": { "query": { "type": "string", "description": "The search query" } },
+                "required": ["query"],
+            }),
+            move |params| {
+                let query = params["query"]
+                    .as_str()
+                    .ok_or_else(|| ToolExecutionError::new("web_search requires a 'query' string"))?;
+                let (tool, query) = (self.clone(), query.to_string());
+                let results = blocking::block_on(async move { tool.search(&query).await })
+                    .map_err(|e| ToolExecutionError::new(e.to_string()))?;
+                Ok(json!(results))
+            },
+        )
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let tool = WebSearchTool::new(SearchEngineKind::Tavily.from_env()?).max_results(3);
+    for result in tool.search("best universities for robotics on the east coast").await? {
+        println!("{} - {}\n  {}", result.title, result.url, result.snippet);
+    }
+    Ok(())
+}
+```
+
+### Notes
+* The cache key includes the engine and result count, so switching engines never returns stale results from another backend.
+* The cache holds at most `cache_capacity` queries. A query that would overflow it first sweeps out every expired entry, then the oldest ones, so a long-running swarm asking many different questions does not grow it without bound.
+* Rate limiting is shared by all clones of a `WebSearchTool`, which is how one tool instance is shared across a swarm.
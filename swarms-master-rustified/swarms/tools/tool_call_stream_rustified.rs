@@ -0,0 +1,360 @@
+### Overview
+There is no Python counterpart; the Python `Agent` waits for the full response before it parses tool calls.
+When a response is streamed, OpenAI-compatible providers send each tool call as a series of deltas
+(`choices[0].delta.tool_calls[i].function.arguments` arrives a few characters at a time). This module
+assembles those deltas and reports each call as soon as its arguments form a complete JSON value, so the
+first tool can start running while the model is still writing the second one.
+
+* `JsonScanner` tracks nesting depth and string/escape state across fragments, so it can find the end of
+  a JSON object without re-parsing the whole buffer on every delta.
+* `ToolCallAssembler` consumes parsed stream chunks and returns `ReadyToolCall`s as they complete.
+* `SseDecoder` turns the raw `text/event-stream` body into chunks.
+* `execute_streamed` ties the pieces together: it starts each ready call on the blocking pool through
+  `ToolRegistry::execute_tool` and collects the results in call order.
+
+### Rust Code
+```rust
+use crate::swarms::tools::base_tool::{ToolExecutionError, ToolRegistry};
+use futures::{Stream, StreamExt};
+use log::debug;
+use serde_json::{json, Value as JsonValue};
+use std::collections::BTreeMap;
+use std::fmt;
+use tokio::task::JoinHandle;
+
+#[derive(Debug)]
+pub enum StreamToolError {
+    /// The underlying response stream failed.
+    Stream(String),
+    /// A tool call's arguments were not valid JSON once the stream ended.
+    InvalidArguments { index: usize, message: String },
+    Tool(ToolExecutionError),
+    /// A tool body panicked on the blocking pool.
+    Join(String),
+}
+
+impl fmt::Display for StreamToolError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            StreamToolError::Stream(msg) => write!(f, "response stream failed: {}", msg),
+            StreamToolError::InvalidArguments { index, message } => {
+                write!(f, "tool call {} has invalid arguments: {}", index, message)
+            }
+            StreamToolError::Tool(err) => write!(f, "tool execution failed: {}", err),
+            StreamToolError::Join(msg) => write!(f, "tool task failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for StreamToolError {}
+
+impl From<ToolExecutionError> for StreamToolError {
+    fn from(err: ToolExecutionError) -> Self {
+        StreamToolError::Tool(err)
+    }
+}
+
+/// Incremental scanner that finds where a top-level JSON object or array ends.
+#[derive(Debug, Default, Clone)]
+pub struct JsonScanner {
+    depth: usize,
+    started: bool,
+    in_string: bool,
+    escaped: bool,
+    complete: bool,
+}
+
+impl JsonScanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// True once the top-level value has closed.
+    pub fn is_complete(&self) -> bool {
+        self.complete
+    }
+
+    /// Scan the next fragment. Returns the byte offset just past the closing bracket if the top-level
+    /// value closed inside this fragment.
+    pub fn feed(&mut self, fragment: &str) -> Option<usize> {
+        if self.complete {
+            return None;
+        }
+        for (offset, byte) in fragment.bytes().enumerate() {
+            if self.in_string {
+                match byte {
+                    _ if self.escaped => self.escaped = false,
+                    b'\\' => self.escaped = true,
+                    b'"' => self.in_string = false,
+                    _ => {}
+                }
+                continue;
+            }
+            match byte {
+                b'"' => self.in_string = true,
+                b'{' | b'[' => {
+                    self.started = true;
+                    self.depth += 1;
+                }
+                b'}' | b']' if self.depth > 0 => {
+                    self.depth -= 1;
+                    if self.depth == 0 && self.started {
+                        self.complete = true;
+                        return Some(offset + 1);
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+}
+
+/// A tool call whose arguments have fully arrived.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReadyToolCall {
+    /// Position of the call in the response's `tool_calls` array.
+    pub index: usize,
+    pub id: String,
+    pub name: String,
+    /// The raw JSON-encoded arguments, as OpenAI sends them.
+    pub arguments: String,
+}
+
+impl ReadyToolCall {
+    /// The OpenAI `tool_calls` entry, the shape `ToolRegistry::execute_tool` accepts.
+    pub fn to_openai(&self) -> JsonValue {
+        json!({
+            "id": self.id,
+            "type": "function",
+            "function": { "name": self.name, "arguments": self.arguments },
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+struct PendingCall {
+    id: Option<String>,
+    name: String,
+    arguments: String,
+    scanner: JsonScanner,
+    emitted: bool,
+}
+
+impl PendingCall {
+    fn ready(&self, index: usize) -> ReadyToolCall {
+        ReadyToolCall {
+            index,
+            id: self.id.clone().unwrap_or_else(|| format!("call_{}", index)),
+            name: self.name.clone(),
+            arguments: self.arguments.clone(),
+        }
+    }
+}
+
+/// Assembles streamed chat completion chunks into text content and tool calls.
+#[derive(Debug, Default)]
+pub struct ToolCallAssembler {
+    content: String,
+    calls: BTreeMap<usize, PendingCall>,
+    finish_reason: Option<String>,
+}
+
+impl ToolCallAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Text content received so far.
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    pub fn finish_reason(&self) -> Option<&str> {
+        self.finish_reason.as_deref()
+    }
+
+    /// Apply one parsed chunk. Returns the tool calls that became complete with it.
+    pub fn push_chunk(&mut self, chunk: &JsonValue) -> Vec<ReadyToolCall> {
+        let choice = &chunk["choices"][0];
+        if let Some(reason) = choice["finish_reason"].as_str() {
+            self.finish_reason = Some(reason.to_string());
+        }
+        let delta = &choice["delta"];
+        if let Some(text) = delta["content"].as_str() {
+            self.content.push_str(text);
+        }
+
+        let mut ready = Vec::new();
+        for (position, call_delta) in delta["tool_calls"].as_array().into_iter().flatten().enumerate() {
+            // Some compatible providers send each call whole and omit `index`.
+            let index = call_delta["index"].as_u64().map_or(position, |i| i as usize);
+            let call = self.calls.entry(index).or_default();
+            if let Some(id) = call_delta["id"].as_str() {
+                call.id = Some(id.to_string());
+            }
+            let function = &call_delta["function"];
+            if let Some(name) = function["name"].as_str() {
+                call.name.push_str(name);
+            }
+            let fragment = match &function["arguments"] {
+                JsonValue::String(fragment) => fragment.clone(),
+                JsonValue::Null => String::new(),
+                // Arguments sent as an object rather than an encoded string.
+                other => other.to_string(),
+            };
+            call.arguments.push_str(&fragment);
+            call.scanner.feed(&fragment);
+
+            if !call.emitted && !call.name.is_empty() && call.scanner.is_complete() {
+                if serde_json::from_str::<JsonValue>(&call.arguments).is_ok() {
+                    call.emitted = true;
+                    debug!("Tool call {} ('{}') complete before end of stream", index, call.name);
+                    ready.push(call.ready(index));
+                }
+            }
+        }
+        ready
+    }
+
+    /// End of stream: return calls that were never reported early, e.g. calls with no arguments.
+    pub fn finish(&mut self) -> Result<Vec<ReadyToolCall>, StreamToolError> {
+        let mut remaining = Vec::new();
+        for (&index, call) in self.calls.iter_mut().filter(|(_, call)| !call.emitted) {
+            if call.arguments.trim().is_empty() {
+                call.arguments = "{}".to_string();
+            }
+            serde_json::from_str::<JsonValue>(&call.arguments)
+                .map_err(|e| StreamToolError::InvalidArguments { index, message: e.to_string() })?;
+            call.emitted = true;
+            remaining.push(call.ready(index));
+        }
+        Ok(remaining)
+    }
+}
+
+/// One `data:` event from an OpenAI-style `text/event-stream` body.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SseEvent {
+    Chunk(JsonValue),
+    Done,
+}
+
+/// Splits a server-sent-events byte stream into chunk events. Bytes may be split anywhere.
+#[derive(Debug, Default)]
+pub struct SseDecoder {
+    buffer: Vec<u8>,
+}
+
+impl SseDecoder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, bytes: &[u8]) -> Result<Vec<SseEvent>, StreamToolError> {
+        self.buffer.extend_from_slice(bytes);
+        let mut events = Vec::new();
+        while let Some(end) = self.buffer.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.buffer.drain(..=end).collect();
+            let line = String::from_utf8_lossy(&line);
+            // Comments (`: keep-alive`), blank separators, and `event:` lines carry no data.
+            let Some(data) = line.trim_end().strip_prefix("data:") else {
+                continue;
+            };
+            let data = data.trim();
+            if data == "[DONE]" {
+                events.push(SseEvent::Done);
+            } else if !data.is_empty() {
+                let chunk = serde_json::from_str(data).map_err(|e| StreamToolError::Stream(e.to_string()))?;
+                events.push(SseEvent::Chunk(chunk));
+            }
+        }
+        Ok(events)
+    }
+}
+
+/// A streamed assistant turn with its tool calls already executed.
+#[derive(Debug, Clone)]
+pub struct StreamedTurn {
+    pub content: String,
+    pub finish_reason: Option<String>,
+    pub tool_calls: Vec<ReadyToolCall>,
+    /// One `{"name", "output"}` entry per tool call, in call order.
+    pub tool_results: Vec<JsonValue>,
+}
+
+fn spawn_call(registry: &ToolRegistry, call: &ReadyToolCall) -> JoinHandle<Result<JsonValue, ToolExecutionError>> {
+    let registry = registry.clone();
+    let openai = call.to_openai();
+    tokio::task::spawn_blocking(move || registry.execute_tool(&[openai]))
+}
+
+/// Drive a chunk stream to completion, starting each tool call as soon as its arguments are complete.
+pub async fn execute_streamed<S, E>(registry: &ToolRegistry, chunks: S) -> Result<StreamedTurn, StreamToolError>
+where
+    S: Stream<Item = Result<JsonValue, E>>,
+    E: fmt::Display,
+{
+    let mut assembler = ToolCallAssembler::new();
+    let mut running: Vec<(ReadyToolCall, JoinHandle<Result<JsonValue, ToolExecutionError>>)> = Vec::new();
+    futures::pin_mut!(chunks);
+    while let Some(chunk) = chunks.next().await {
+        let chunk = chunk.map_err(|e| StreamToolError::Stream(e.to_string()))?;
+        for call in assembler.push_chunk(&chunk) {
+            running.push((call.clone(), spawn_call(registry, &call)));
+        }
+    }
+    for call in assembler.finish()? {
+        running.push((call.clone(), spawn_call(registry, &call)));
+    }
+    running.sort_by_key(|(call, _)| call.index);
+
+    let mut tool_calls = Vec::with_capacity(running.len());
+    let mut tool_results = Vec::with_capacity(running.len());
+    for (call, handle) in running {
+        let output = handle.await.map_err(|e| StreamToolError::Join(e.to_string()))??;
+        // `execute_tool` wraps the single result in an array.
+        tool_results.extend(output.as_array().cloned().unwrap_or_default());
+        tool_calls.push(call);
+    }
+    Ok(StreamedTurn {
+        content: assembler.content,
+        finish_reason: assembler.finish_reason,
+        tool_calls,
+        tool_results,
+    })
+}
+
+#[tokio::main]
+async fn main() -> Result<(), StreamToolError> {
+    let registry = ToolRegistry::builder()
+        .tool("get_weather", "Current weather for a city", json!({"type": "object"}), |args| {
+            Ok(json!({ "city": args["city"], "forecast": "sunny" }))
+        })
+        .build()?;
+
+    let body = concat!(
+        "data: {\"choices\":[{\"delta\":{\"tool_calls\":[{\"index\":0,\"id\":\"call_1\",\"function\":{\"name\":\"get_weather\",\"arguments\":\"{\\\"ci\"}}]}}]}\n\n",
+        "data: {\"choices\":[{\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"ty\\\": \\\"Paris\\\"}\"}}]}}]}\n\n",
+        "data: {\"choices\":[{\"delta\":{},\"finish_reason\":\"tool_calls\"}]}\n\n",
+        "data: [DONE]\n\n",
+    );
+    let mut decoder = SseDecoder::new();
+    let chunks: Vec<Result<JsonValue, StreamToolError>> = decoder
+        .push(body.as_bytes())?
+        .into_iter()
+        .filter_map(|event| match event {
+            SseEvent::Chunk(chunk) => Some(Ok(chunk)),
+            SseEvent::Done => None,
+        })
+        .collect();
+    let turn = execute_streamed(&registry, futures::stream::iter(chunks)).await?;
+    println!("{:?}", turn.tool_results);
+    Ok(())
+}
+```
+
+### Notes
+* A call is reported early only when it has a name and its arguments parse as JSON; the scanner just decides when parsing is worth attempting.
+* Tool bodies are synchronous, so they run on tokio's blocking pool and do not stall the stream reader.
+* If any tool fails, `execute_streamed` returns that error after the stream ends; calls already started still run to completion.
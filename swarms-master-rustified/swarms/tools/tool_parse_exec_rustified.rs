@@ -31,11 +31,11 @@ trait CallableFunction {
 // Implement the CallableFunction trait for a closure
 #[derive(Clone)]
 struct CallableClosure {
-    closure: Box<dyn Fn(HashMap<String, Value>) -> Result<String, String>>,
+    closure: Box<dyn Fn(HashMap<String, Value>) -> Result<String, String> + Send + Sync>,
 }
 
 impl CallableClosure {
-    fn new(closure: impl Fn(HashMap<String, Value>) -> Result<String, String> + 'static) -> Self {
+    fn new(closure: impl Fn(HashMap<String, Value>) -> Result<String, String> + Send + Sync + 'static) -> Self {
         CallableClosure {
             closure: Box::new(closure),
         }
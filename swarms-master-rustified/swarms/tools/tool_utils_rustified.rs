@@ -30,11 +30,11 @@ trait Callable {
 
 // Implement the Callable trait for a closure
 struct Closure {
-    func: Box<dyn Fn() -> String>,
+    func: Box<dyn Fn() -> String + Send + Sync>,
 }
 
 impl Closure {
-    fn new<F: Fn() -> String + 'static>(func: F) -> Self {
+    fn new<F: Fn() -> String + Send + Sync + 'static>(func: F) -> Self {
         Closure {
             func: Box::new(func),
         }
@@ -0,0 +1,57 @@
+### Overview
+There is no Python counterpart; Python tools and completions call `asyncio.run` or a sync HTTP client
+wherever they are. In the Rust port some synchronous entry points (an agent's `Completion`, a tool's
+`run` closure) must drive an async call. Blocking on the caller's runtime is not an option:
+`Handle::block_on` panics inside an async task, and `block_in_place` panics on a current-thread runtime and
+on a thread with no runtime at all, which is where `base_tool` runs tools.
+
+`block_on` runs the future on a small runtime of its own, started on first use and kept for the life of
+the process, and blocks the calling thread until it finishes. It works the same from a plain thread, a
+`spawn_blocking` worker, or the middle of an async task. HTTP clients keep their connection pools on that
+one runtime, so pooled connections are not stranded by a runtime that was built for a single call.
+
+### Rust Code
+```rust
+use std::future::Future;
+use std::sync::OnceLock;
+use tokio::runtime::{Builder, Runtime};
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+fn runtime() -> &'static Runtime {
+    RUNTIME.get_or_init(|| {
+        Builder::new_multi_thread()
+            .worker_threads(2)
+            .thread_name("swarms-blocking")
+            .enable_all()
+            .build()
+            .expect("failed to start the swarms blocking runtime")
+    })
+}
+
+/// Run `future` to completion on the shared blocking runtime and return its output. Safe to call with or
+/// without a current runtime; the calling thread is blocked, not the runtime it belongs to. A panic in
+/// `future` is resumed on the caller.
+pub fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    let task = runtime().spawn(future);
+    match futures::executor::block_on(task) {
+        Ok(output) => output,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => panic!("blocking task was cancelled: {}", err),
+    }
+}
+
+fn main() {
+    let answer = block_on(async { 6 * 7 });
+    println!("{}", answer);
+}
+```
+
+### Notes
+* The calling thread is parked while the future runs. An async task that calls `block_on` holds its
+  worker thread for that long, as any synchronous call would; long calls belong in `spawn_blocking`.
+* Futures passed in are `'static`, so they own what they use; clone the `Arc` of a provider or tool in.
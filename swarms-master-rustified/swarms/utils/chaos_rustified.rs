@@ -0,0 +1,362 @@
+### Overview
+There is no Python counterpart. The retry, requeue, and restart paths of this port (`TaskLedger` requeues,
+`RestartSupervisor`, `Supervisor` stuck detection) only run when something fails, which a healthy provider
+rarely does in a test. `Chaos` makes it fail on purpose. It injects four fault classes, each at its own rate:
+
+| Fault | Where | What the caller sees |
+|---|---|---|
+| `provider_failure` | `ChaosProvider::chat` | `ProviderError::Status` 429 or 503, both retriable |
+| `delay` | `ChaosProvider::chat` | the call returns `delay_ms` later than it would have |
+| `malformed_json` | `ChaosProvider::chat` | the content is cut off mid-document, so it no longer parses |
+| `tool_timeout` | `Chaos::wrap_tool` | the tool hangs for `tool_timeout_ms`, then fails with a `ToolExecutionError` |
+
+Chaos is off unless enabled, either by the `chaos` section of a swarm config or by `SWARMS_CHAOS=1` plus the
+`SWARMS_CHAOS_*` variables below; the environment wins, so a deployed config can be put under chaos without
+editing it. Setting `seed` makes the faults repeat from run to run. Every injected fault is logged and
+counted in `swarms_faults_injected_total`.
+
+| Variable | Field |
+|---|---|
+| `SWARMS_CHAOS` | `enabled` (`1`/`true` or `0`/`false`) |
+| `SWARMS_CHAOS_SEED` | `seed` |
+| `SWARMS_CHAOS_PROVIDER_FAILURE_RATE` | `provider_failure_rate` |
+| `SWARMS_CHAOS_DELAY_RATE`, `SWARMS_CHAOS_DELAY_MS` | `delay_rate`, `delay_ms` |
+| `SWARMS_CHAOS_MALFORMED_JSON_RATE` | `malformed_json_rate` |
+| `SWARMS_CHAOS_TOOL_TIMEOUT_RATE`, `SWARMS_CHAOS_TOOL_TIMEOUT_MS` | `tool_timeout_rate`, `tool_timeout_ms` |
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::ChatCompletionRequest;
+use crate::swarms::utils::chat_provider::{ChatCompletion, ChatProvider, ProviderError};
+use crate::swarms::utils::metrics::SwarmMetrics;
+use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+#[cfg(feature = "tools")]
+use crate::swarms::tools::base_tool::{ToolExecutionError, ToolFn, ToolRegistry};
+use async_trait::async_trait;
+use log::warn;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Which faults to inject, and how often. Rates are probabilities per call, from 0 to 1.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ChaosConfig {
+    pub enabled: bool,
+    /// Seed for the fault dice; `None` draws a fresh one per `Chaos`.
+    pub seed: Option<u64>,
+    pub provider_failure_rate: f64,
+    pub delay_rate: f64,
+    pub delay_ms: u64,
+    pub malformed_json_rate: f64,
+    pub tool_timeout_rate: f64,
+    pub tool_timeout_ms: u64,
+}
+
+impl Default for ChaosConfig {
+    fn default() -> Self {
+        ChaosConfig {
+            enabled: false,
+            seed: None,
+            provider_failure_rate: 0.0,
+            delay_rate: 0.0,
+            delay_ms: 2_000,
+            malformed_json_rate: 0.0,
+            tool_timeout_rate: 0.0,
+            tool_timeout_ms: 30_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChaosError {
+    /// An environment variable that does not parse.
+    InvalidVar { var: String, value: String },
+    /// A rate outside 0..=1.
+    InvalidRate { field: &'static str, rate: f64 },
+}
+
+impl fmt::Display for ChaosError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ChaosError::InvalidVar { var, value } => write!(f, "{} has an invalid value '{}'", var, value),
+            ChaosError::InvalidRate { field, rate } => write!(f, "chaos {} must be between 0 and 1, got {}", field, rate),
+        }
+    }
+}
+
+impl std::error::Error for ChaosError {}
+
+impl ChaosConfig {
+    /// The config from `SWARMS_CHAOS*`, or `None` when `SWARMS_CHAOS` is not set.
+    pub fn from_env() -> Result<Option<Self>, ChaosError> {
+        Self::from_vars(|var| std::env::var(var).ok())
+    }
+
+    /// `from_env` over any variable lookup, so tests need not touch the process environment.
+    pub fn from_vars(lookup: impl Fn(&str) -> Option<String>) -> Result<Option<Self>, ChaosError> {
+        let Some(enabled) = lookup("SWARMS_CHAOS") else {
+            return Ok(None);
+        };
+        let enabled = match enabled.trim().to_lowercase().as_str() {
+            "1" | "true" | "yes" | "on" => true,
+            "0" | "false" | "no" | "off" | "" => false,
+            _ => return Err(ChaosError::InvalidVar { var: "SWARMS_CHAOS".to_string(), value: enabled }),
+        };
+        let mut config = ChaosConfig { enabled, ..ChaosConfig::default() };
+        macro_rules! read {
+            ($var:literal, $field:expr) => {
+                if let Some(value) = lookup($var) {
+                    $field = value.trim().parse().map_err(|_| ChaosError::InvalidVar { var: $var.to_string(), value })?;
+                }
+            };
+        }
+        if let Some(value) = lookup("SWARMS_CHAOS_SEED") {
+            let seed = value.trim().parse().map_err(|_| ChaosError::InvalidVar { var: "SWARMS_CHAOS_SEED".to_string(), value })?;
+            config.seed = Some(seed);
+        }
+        read!("SWARMS_CHAOS_PROVIDER_FAILURE_RATE", config.provider_failure_rate);
+        read!("SWARMS_CHAOS_DELAY_RATE", config.delay_rate);
+        read!("SWARMS_CHAOS_DELAY_MS", config.delay_ms);
+        read!("SWARMS_CHAOS_MALFORMED_JSON_RATE", config.malformed_json_rate);
+        read!("SWARMS_CHAOS_TOOL_TIMEOUT_RATE", config.tool_timeout_rate);
+        read!("SWARMS_CHAOS_TOOL_TIMEOUT_MS", config.tool_timeout_ms);
+        config.validate()?;
+        Ok(Some(config))
+    }
+
+    /// The config in effect: the environment's if `SWARMS_CHAOS` is set, else `configured`. `None` when
+    /// chaos is off.
+    pub fn resolve(configured: Option<&ChaosConfig>) -> Result<Option<Self>, ChaosError> {
+        Self::resolve_with(configured, |var| std::env::var(var).ok())
+    }
+
+    /// `resolve` over any variable lookup, like `from_vars`.
+    pub fn resolve_with(
+        configured: Option<&ChaosConfig>,
+        lookup: impl Fn(&str) -> Option<String>,
+    ) -> Result<Option<Self>, ChaosError> {
+        let config = match Self::from_vars(lookup)? {
+            Some(config) => Some(config),
+            None => configured.cloned(),
+        };
+        match config {
+            Some(config) if config.enabled => {
+                config.validate()?;
+                Ok(Some(config))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), ChaosError> {
+        let rates = [
+            ("provider_failure_rate", self.provider_failure_rate),
+            ("delay_rate", self.delay_rate),
+            ("malformed_json_rate", self.malformed_json_rate),
+            ("tool_timeout_rate", self.tool_timeout_rate),
+        ];
+        match rates.into_iter().find(|(_, rate)| !(0.0..=1.0).contains(rate)) {
+            Some((field, rate)) => Err(ChaosError::InvalidRate { field, rate }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// One injected fault.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fault {
+    ProviderFailure { status: u16 },
+    Delay(Duration),
+    MalformedJson,
+    ToolTimeout(Duration),
+}
+
+impl Fault {
+    /// The `fault` label in `swarms_faults_injected_total`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Fault::ProviderFailure { .. } => "provider_failure",
+            Fault::Delay(_) => "delay",
+            Fault::MalformedJson => "malformed_json",
+            Fault::ToolTimeout(_) => "tool_timeout",
+        }
+    }
+}
+
+/// Rolls the dice for each call and records the faults it injects.
+#[derive(Debug)]
+pub struct Chaos {
+    config: ChaosConfig,
+    rng: Mutex<StdRng>,
+}
+
+impl Chaos {
+    /// Chaos with `config`, whether or not it is `enabled`; use `ChaosConfig::resolve` to honour the switch.
+    pub fn new(config: ChaosConfig) -> Arc<Self> {
+        let rng = match config.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        };
+        Arc::new(Chaos { config, rng: Mutex::new(rng) })
+    }
+
+    /// Chaos for a swarm configured with `configured`, or `None` when neither it nor the environment
+    /// enables it. Share the result between the swarm's providers and tools.
+    pub fn resolve(configured: Option<&ChaosConfig>) -> Result<Option<Arc<Self>>, ChaosError> {
+        Ok(ChaosConfig::resolve(configured)?.map(Chaos::new))
+    }
+
+    pub fn config(&self) -> &ChaosConfig {
+        &self.config
+    }
+
+    fn roll(&self, rate: f64) -> bool {
+        rate > 0.0 && self.rng.lock().unwrap().gen_bool(rate.min(1.0))
+    }
+
+    fn inject(&self, fault: Fault) -> Fault {
+        warn!("chaos: injecting {:?}", fault);
+        SwarmMetrics::global().fault_injected(fault.as_str());
+        fault
+    }
+
+    /// The delay to add before a provider call, if one is due.
+    pub fn delay(&self) -> Option<Duration> {
+        self.roll(self.config.delay_rate).then(|| {
+            let delay = Duration::from_millis(self.config.delay_ms);
+            self.inject(Fault::Delay(delay));
+            delay
+        })
+    }
+
+    /// The error to fail a provider call with, if one is due: a 429 or a 503, equally often.
+    pub fn provider_failure(&self) -> Option<ProviderError> {
+        if !self.roll(self.config.provider_failure_rate) {
+            return None;
+        }
+        let status = if self.rng.lock().unwrap().gen_bool(0.5) { 429 } else { 503 };
+        self.inject(Fault::ProviderFailure { status });
+        Some(ProviderError::Status { status, body: "fault injected by chaos".to_string() })
+    }
+
+    /// `content` cut off halfway through, if a malformed response is due. Content that is not a JSON object
+    /// or array is first wrapped in one, so the result never parses.
+    pub fn corrupt(&self, content: String) -> String {
+        if !self.roll(self.config.malformed_json_rate) {
+            return content;
+        }
+        self.inject(Fault::MalformedJson);
+        let document = match content.trim_start().chars().next() {
+            Some('{' | '[') => content,
+            _ => serde_json::json!({ "answer": content }).to_string(),
+        };
+        let half = (1..=document.len() / 2).rev().find(|at| document.is_char_boundary(*at)).unwrap_or(1);
+        document[..half].to_string()
+    }
+
+    /// How long a tool call should hang before timing out, if a timeout is due.
+    pub fn tool_timeout(&self) -> Option<Duration> {
+        self.roll(self.config.tool_timeout_rate).then(|| {
+            let timeout = Duration::from_millis(self.config.tool_timeout_ms);
+            self.inject(Fault::ToolTimeout(timeout));
+            timeout
+        })
+    }
+
+    /// `func` with tool timeouts injected: a faulty call blocks for the timeout and then fails instead of
+    /// running `func`.
+    #[cfg(feature = "tools")]
+    pub fn wrap_tool(self: &Arc<Self>, name: &str, func: ToolFn) -> ToolFn {
+        let chaos = Arc::clone(self);
+        let name = name.to_string();
+        Arc::new(move |args| match chaos.tool_timeout() {
+            Some(timeout) => {
+                std::thread::sleep(timeout);
+                Err(ToolExecutionError::new(format!("tool '{}' timed out after {:?}", name, timeout)))
+            }
+            None => func(args),
+        })
+    }
+
+    /// A copy of `registry` with every tool passed through `wrap_tool`.
+    #[cfg(feature = "tools")]
+    pub fn wrap_tools(self: &Arc<Self>, registry: &ToolRegistry) -> ToolRegistry {
+        registry.map_tools(|name, func| self.wrap_tool(name, func))
+    }
+}
+
+/// A `ChatProvider` that injects delays, failures, and malformed responses into another.
+pub struct ChaosProvider {
+    inner: Arc<dyn ChatProvider>,
+    chaos: Arc<Chaos>,
+}
+
+impl ChaosProvider {
+    pub fn new(inner: Arc<dyn ChatProvider>, chaos: Arc<Chaos>) -> Self {
+        ChaosProvider { inner, chaos }
+    }
+
+    /// `inner` wrapped if `config` (or the environment) enables chaos, else `inner` itself.
+    pub fn wrap(inner: Arc<dyn ChatProvider>, config: Option<&ChaosConfig>) -> Result<Arc<dyn ChatProvider>, ChaosError> {
+        Ok(match Chaos::resolve(config)? {
+            Some(chaos) => Arc::new(ChaosProvider::new(inner, chaos)),
+            None => inner,
+        })
+    }
+}
+
+#[async_trait]
+impl ChatProvider for ChaosProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    fn model(&self) -> &str {
+        self.inner.model()
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+        if let Some(delay) = self.chaos.delay() {
+            tokio::time::sleep(delay).await;
+        }
+        if let Some(err) = self.chaos.provider_failure() {
+            return Err(err);
+        }
+        let mut completion = self.inner.chat(request).await?;
+        completion.content = self.chaos.corrupt(completion.content);
+        Ok(completion)
+    }
+}
+
+fn main() {
+    let config = ChaosConfig { enabled: true, seed: Some(7), malformed_json_rate: 1.0, ..ChaosConfig::default() };
+    let chaos = Chaos::new(config);
+    let corrupted = chaos.corrupt(r#"{"verdict": "approve", "confidence": 0.9}"#.to_string());
+    println!("{} parses: {}", corrupted, serde_json::from_str::<serde_json::Value>(&corrupted).is_ok());
+
+    let vars = |var: &str| match var {
+        "SWARMS_CHAOS" => Some("1".to_string()),
+        "SWARMS_CHAOS_PROVIDER_FAILURE_RATE" => Some("0.2".to_string()),
+        _ => None,
+    };
+    println!("{:?}", ChaosConfig::from_vars(vars));
+}
+```
+
+### Notes
+* Chaos only reaches code built on what it wraps: a `ChatProvider` passed through `ChaosProvider::wrap`, and
+  tools passed through `Chaos::wrap_tool` or `Chaos::wrap_tools`. `connect_agents` does this for the agents
+  of a swarm config, binding each to its wrapped provider; agents given their own `Completion` closure are
+  not affected.
+* A provider failure is injected before the real call, so it costs no tokens. A malformed response is
+  injected after it, so the real call is made and billed.
+* The crate has no circuit breaker. The tests exercise the machinery it does have: `TaskLedger` retries and
+  dead-lettering, `RestartSupervisor` restarts, and `Supervisor` stuck detection.
@@ -0,0 +1,211 @@
+### Overview
+There is no Python counterpart; the Python agent reaches every model through `litellm`. In the Rust port
+each backend is a `ChatProvider`: it takes a `ChatCompletionRequest` and returns a provider-neutral
+`ChatCompletion`, so agents and swarms can switch between a hosted API (`OpenAiCompatProvider`) and a
+model running in-process (`LlamaCppProvider`) without other changes.
+
+This module also holds what the providers share: the completion and finish-reason types, `ProviderError`,
+`Device` (the parsed form of `AgentSchema::device`), and `record_call`, the latency, token, and usage
+accounting every provider does per call. `provider_completion` binds any provider to an `LlmAgent` as its
+`Completion`. It is built without any provider feature, so code written against the trait compiles
+whichever backends are enabled.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem, UsageInfo};
+use crate::swarms::structs::agent::LlmAgent;
+use crate::swarms::structs::usage::UsageScope;
+use crate::swarms::text::stop_conditions::StopMatch;
+use crate::swarms::utils::blocking;
+use crate::swarms::utils::metrics::SwarmMetrics;
+use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+use async_trait::async_trait;
+use log::warn;
+use serde_json::Value as JsonValue;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Why the model stopped, normalized across providers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FinishReason {
+    Stop,
+    Length,
+    ToolCalls,
+    ContentFilter,
+    /// A value this adapter does not recognize, kept verbatim.
+    Other(String),
+}
+
+impl FinishReason {
+    /// Map provider-specific spellings onto the OpenAI set.
+    pub fn parse(raw: &str) -> Self {
+        match raw {
+            // Together returns "eos", Anthropic-backed OpenRouter routes return "end_turn"/"stop_sequence"
+            "stop" | "eos" | "end_turn" | "stop_sequence" => FinishReason::Stop,
+            "length" | "max_tokens" | "model_length" => FinishReason::Length,
+            "tool_calls" | "function_call" | "tool_use" => FinishReason::ToolCalls,
+            "content_filter" | "safety" => FinishReason::ContentFilter,
+            other => FinishReason::Other(other.to_string()),
+        }
+    }
+}
+
+/// A parsed, provider-neutral chat completion.
+#[derive(Debug, Clone)]
+pub struct ChatCompletion {
+    pub content: String,
+    pub finish_reason: Option<FinishReason>,
+    pub tool_calls: Vec<JsonValue>,
+    /// `None` when the provider did not report usage.
+    pub usage: Option<UsageInfo>,
+    /// The client-side stop condition that ended a streamed response.
+    pub stopped_by: Option<StopMatch>,
+}
+
+#[derive(Debug)]
+pub enum ProviderError {
+    MissingApiKey(String),
+    Http(reqwest::Error),
+    Status { status: u16, body: String },
+    InvalidResponse(String),
+    /// A setting the provider cannot run with, such as an unknown device or a missing model file.
+    InvalidConfig(String),
+    /// An in-process model failed to load, tokenize, or decode.
+    Local(String),
+}
+
+impl fmt::Display for ProviderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProviderError::MissingApiKey(var) => write!(f, "{} environment variable is not set", var),
+            ProviderError::Http(err) => write!(f, "HTTP error: {}", err),
+            ProviderError::Status { status, body } => write!(f, "provider returned {}: {}", status, body),
+            ProviderError::InvalidResponse(msg) => write!(f, "invalid provider response: {}", msg),
+            ProviderError::InvalidConfig(msg) => write!(f, "invalid provider configuration: {}", msg),
+            ProviderError::Local(msg) => write!(f, "local model failed: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for ProviderError {}
+
+impl From<reqwest::Error> for ProviderError {
+    fn from(err: reqwest::Error) -> Self {
+        ProviderError::Http(err)
+    }
+}
+
+/// Where a local model runs, from `AgentSchema::device`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Device {
+    #[default]
+    Cpu,
+    /// Offload to the GPU with this index. Metal has a single device, 0.
+    Gpu(i32),
+}
+
+impl Device {
+    /// Parse `cpu`, `gpu`, `cuda`, `metal`, or `mps`, with an optional `:index` for the GPU forms
+    /// (`cuda:1`). Case and surrounding space are ignored.
+    pub fn parse(device: &str) -> Result<Self, ProviderError> {
+        let device = device.trim().to_ascii_lowercase();
+        let (kind, index) = match device.split_once(':') {
+            Some((kind, index)) => {
+                let index = index
+                    .parse::<i32>()
+                    .ok()
+                    .filter(|index| *index >= 0)
+                    .ok_or_else(|| ProviderError::InvalidConfig(format!("bad device index in '{}'", device)))?;
+                (kind, Some(index))
+            }
+            None => (device.as_str(), None),
+        };
+        match (kind, index) {
+            ("cpu", None) => Ok(Device::Cpu),
+            ("gpu" | "cuda" | "metal" | "mps", index) => Ok(Device::Gpu(index.unwrap_or(0))),
+            _ => Err(ProviderError::InvalidConfig(format!("unknown device '{}'", device))),
+        }
+    }
+
+    pub fn is_gpu(&self) -> bool {
+        matches!(self, Device::Gpu(_))
+    }
+}
+
+/// A chat model backend.
+#[async_trait]
+pub trait ChatProvider: Send + Sync {
+    /// The backend, e.g. `groq` or `llama-cpp`.
+    fn name(&self) -> &str;
+
+    fn model(&self) -> &str;
+
+    fn capabilities(&self) -> ProviderCapabilities;
+
+    async fn chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError>;
+
+    /// Convenience wrapper: single user prompt, returns the text content.
+    async fn complete(&self, system_prompt: Option<&str>, prompt: &str) -> Result<String, ProviderError> {
+        let mut messages = Vec::new();
+        if let Some(system_prompt) = system_prompt {
+            messages.push(ChatMessageInput::new(
+                "system".to_string(),
+                vec![ContentItem::Text { text: system_prompt.to_string() }],
+            ));
+        }
+        messages.push(ChatMessageInput::new("user".to_string(), vec![ContentItem::Text { text: prompt.to_string() }]));
+        let request = ChatCompletionRequest::new(self.model().to_string(), messages);
+        Ok(self.chat(&request).await?.content)
+    }
+}
+
+/// Record one call of `provider`'s `model` in the metrics: its latency and outcome, and, when it reports
+/// usage, its tokens under `agent` (`unknown` when unset) and into `usage`.
+pub fn record_call(
+    provider: &str,
+    model: &str,
+    agent: Option<&str>,
+    usage: Option<&UsageScope>,
+    result: &Result<ChatCompletion, ProviderError>,
+    started: Instant,
+) {
+    let metrics = SwarmMetrics::global();
+    metrics.llm_call(provider, model, result.is_ok(), started.elapsed());
+    if let Some(reported) = result.as_ref().ok().and_then(|completion| completion.usage.as_ref()) {
+        let agent = agent.unwrap_or("unknown");
+        metrics.tokens(model, agent, reported);
+        if let Some(scope) = usage {
+            // A failed usage write must not fail the call that already succeeded.
+            if let Err(err) = scope.record_usage(agent, model, reported) {
+                warn!("Failed to record usage for {}: {}", agent, err);
+            }
+        }
+    }
+}
+
+/// A completion for `AgentBuilder::completion` or `LlmAgent::with_completion` that sends the agent's system
+/// prompt and the task to `provider`. A `Completion` is synchronous, so the call runs on the shared
+/// `blocking` runtime while the caller waits; the agent can run from a plain thread or inside an async task.
+pub fn provider_completion(
+    provider: Arc<dyn ChatProvider>,
+) -> impl Fn(&LlmAgent, &str) -> Result<String, String> + Send + Sync + 'static {
+    move |agent: &LlmAgent, task: &str| {
+        let provider = provider.clone();
+        let system_prompt = Some(agent.system_prompt.clone()).filter(|prompt| !prompt.is_empty());
+        let task = task.to_string();
+        blocking::block_on(async move { provider.complete(system_prompt.as_deref(), &task).await })
+            .map_err(|err| err.to_string())
+    }
+}
+
+fn main() {
+    for device in ["cpu", "CUDA:1", "metal", "tpu"] {
+        println!("{} -> {:?}", device, Device::parse(device));
+    }
+}
+```
+
+### Notes
+* `Device::Gpu` only says where to run. How much of the model is offloaded is the provider's setting, e.g. `LlamaCppConfig::gpu_layers`.
+* `OpenAiCompatProvider` still has `chat` and `complete` as inherent methods, so callers that name the concrete type do not need the trait in scope.
@@ -0,0 +1,114 @@
+### Overview
+There is no Python counterpart; the Python package calls `time.time()` and `datetime.now()` directly, and
+this port did the same with `SystemTime::now()` and `chrono::Utc::now()`. Tests of timestamps then raced
+the wall clock, and `test_current_timestamp` failed whenever it straddled a second boundary.
+
+`Clock` is where timestamped structures read the time instead. `Conversation` (through its builder), the
+`TaskQueueSwarm` run metadata, and scheduled `Task`s hold an `Arc<dyn Clock>`, which defaults to
+`SystemClock`. Tests pass a `ManualClock`:
+
+* It stands still until `advance` or `set` moves it, so timestamps can be asserted exactly.
+* `sleep` advances it instead of blocking, so a task scheduled an hour ahead runs at once, and the clock
+  reads an hour later afterwards.
+
+### Rust Code
+```rust
+use chrono::{DateTime, TimeZone, Utc};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// A source of the current time.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Block for `duration`.
+    fn sleep(&self, duration: Duration);
+
+    fn unix_secs(&self) -> i64 {
+        self.now().timestamp()
+    }
+
+    fn unix_millis(&self) -> i64 {
+        self.now().timestamp_millis()
+    }
+
+    /// Block until `deadline`; returns at once if it has passed.
+    fn sleep_until(&self, deadline: DateTime<Utc>) {
+        if let Ok(remaining) = (deadline - self.now()).to_std() {
+            self.sleep(remaining);
+        }
+    }
+}
+
+/// The wall clock.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        std::thread::sleep(duration);
+    }
+}
+
+/// The default clock for structures that are not given one.
+pub fn system_clock() -> Arc<dyn Clock> {
+    Arc::new(SystemClock)
+}
+
+/// A clock that only moves when told to. `sleep` moves it forward instead of blocking.
+#[derive(Debug)]
+pub struct ManualClock {
+    now: Mutex<DateTime<Utc>>,
+}
+
+impl ManualClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        ManualClock { now: Mutex::new(start) }
+    }
+
+    /// A clock reading `millis` milliseconds after the Unix epoch.
+    pub fn at_millis(millis: i64) -> Self {
+        ManualClock::new(Utc.timestamp_millis_opt(millis).single().unwrap_or_default())
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        let step = chrono::Duration::from_std(duration).unwrap_or(chrono::Duration::MAX);
+        let mut now = self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        *now = now.checked_add_signed(step).unwrap_or(DateTime::<Utc>::MAX_UTC);
+    }
+
+    /// Move the clock to `now`, backwards too, e.g. to test code that must survive a clock step.
+    pub fn set(&self, now: DateTime<Utc>) {
+        *self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        *self.now.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn sleep(&self, duration: Duration) {
+        self.advance(duration);
+    }
+}
+
+fn main() {
+    let clock = ManualClock::at_millis(1_700_000_000_000);
+    clock.sleep(Duration::from_secs(90));
+    println!("{}", clock.now().to_rfc3339());
+    println!("{}", SystemClock.unix_secs());
+}
+```
+
+### Notes
+* Durations such as step latency are still measured with `Instant`, which is monotonic; a clock that can
+  be set backwards is for timestamps and schedules, not for measuring elapsed time.
+* `IdGenerator::with_clock` takes the time as a closure, so a shared clock plugs in as
+  `move || clock.unix_millis() as u64`.
+* Async code waits with `tokio::time`, which has its own test clock (`tokio::time::pause`). `Clock::sleep`
+  blocks the thread and is for the synchronous schedulers.
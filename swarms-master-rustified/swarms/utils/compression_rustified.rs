@@ -0,0 +1,177 @@
+### Overview
+There is no Python counterpart; the Python package writes conversations, event logs, and run metadata as
+plain JSON however large they get. A long run's conversation or audit log can reach hundreds of megabytes
+of highly repetitive text. `StateCompressor` gzips those files with `flate2` once they pass a size
+threshold. Smaller files are written unchanged, so they stay readable with a text editor.
+
+A compressed file starts with a text header, then the gzip stream:
+
+```text
+swgz1:<sha256 of the uncompressed bytes, hex>\n<gzip bytes>
+```
+
+Loading is transparent: `decompress` inflates anything with the `swgz1:` header, checks the inflated bytes
+against the checksum, and returns anything else unchanged. Files saved before compression was turned on, or
+under the threshold, therefore keep loading. A truncated or edited file fails with
+`CompressionError::Corrupt` or `CompressionError::ChecksumMismatch` instead of loading partial state.
+
+`ConversationBuilder::compression`, `BaseStructure::with_compression` (which `TaskQueueSwarm::with_compression`
+sets), and `AuditLog::with_compression` turn it on for conversations, run metadata, and the audit log.
+
+### Rust Code
+```rust
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use log::debug;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Files at or above this many bytes are compressed by default.
+pub const DEFAULT_COMPRESS_BYTES: usize = 64 * 1024;
+
+const MAGIC: &str = "swgz1:";
+const CHECKSUM_HEX_LEN: usize = 64;
+
+#[derive(Debug)]
+pub enum CompressionError {
+    Io(io::Error),
+    /// The header or gzip stream is damaged, e.g. a truncated file.
+    Corrupt(String),
+    /// The data inflated, but not to the bytes that were compressed.
+    ChecksumMismatch { expected: String, actual: String },
+}
+
+impl fmt::Display for CompressionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CompressionError::Io(err) => write!(f, "compressed state I/O failed: {}", err),
+            CompressionError::Corrupt(message) => write!(f, "compressed state is corrupt: {}", message),
+            CompressionError::ChecksumMismatch { expected, actual } => {
+                write!(f, "compressed state checksum mismatch: expected {}, got {}", expected, actual)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CompressionError {}
+
+impl From<io::Error> for CompressionError {
+    fn from(err: io::Error) -> Self {
+        CompressionError::Io(err)
+    }
+}
+
+/// Gzips state files above a size threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StateCompressor {
+    threshold_bytes: usize,
+    level: u32,
+}
+
+impl Default for StateCompressor {
+    fn default() -> Self {
+        StateCompressor::new()
+    }
+}
+
+impl StateCompressor {
+    pub fn new() -> Self {
+        StateCompressor { threshold_bytes: DEFAULT_COMPRESS_BYTES, level: Compression::default().level() }
+    }
+
+    /// Compress data of at least `bytes` bytes.
+    pub fn with_threshold(mut self, bytes: usize) -> Self {
+        self.threshold_bytes = bytes;
+        self
+    }
+
+    /// The gzip level, 0 (store) to 9 (smallest).
+    pub fn with_level(mut self, level: u32) -> Self {
+        self.level = level.min(9);
+        self
+    }
+
+    pub fn threshold_bytes(&self) -> usize {
+        self.threshold_bytes
+    }
+
+    pub fn is_compressed(data: &[u8]) -> bool {
+        data.starts_with(MAGIC.as_bytes())
+    }
+
+    /// `data` with a checksummed gzip header, or unchanged when it is below the threshold.
+    pub fn compress(&self, data: &[u8]) -> Vec<u8> {
+        if data.len() < self.threshold_bytes {
+            return data.to_vec();
+        }
+        let header = format!("{}{}\n", MAGIC, hex::encode(Sha256::digest(data))).into_bytes();
+        // The encoder appends the gzip stream after the header
+        let mut encoder = GzEncoder::new(header, Compression::new(self.level));
+        encoder.write_all(data).expect("gzip into a Vec does not fail");
+        let out = encoder.finish().expect("gzip into a Vec does not fail");
+        debug!("Compressed {} bytes to {}", data.len(), out.len());
+        out
+    }
+
+    /// Inflate and verify `data`, or return it unchanged if it was never compressed.
+    pub fn decompress(data: &[u8]) -> Result<Vec<u8>, CompressionError> {
+        let Some(rest) = data.strip_prefix(MAGIC.as_bytes()) else {
+            return Ok(data.to_vec());
+        };
+        if rest.len() <= CHECKSUM_HEX_LEN || rest[CHECKSUM_HEX_LEN] != b'\n' {
+            return Err(CompressionError::Corrupt("missing checksum".to_string()));
+        }
+        let expected = std::str::from_utf8(&rest[..CHECKSUM_HEX_LEN])
+            .map_err(|_| CompressionError::Corrupt("checksum is not hex".to_string()))?
+            .to_ascii_lowercase();
+        let mut inflated = Vec::new();
+        GzDecoder::new(&rest[CHECKSUM_HEX_LEN + 1..])
+            .read_to_end(&mut inflated)
+            .map_err(|err| CompressionError::Corrupt(err.to_string()))?;
+        let actual = hex::encode(Sha256::digest(&inflated));
+        if actual != expected {
+            return Err(CompressionError::ChecksumMismatch { expected, actual });
+        }
+        Ok(inflated)
+    }
+
+    /// Compress if large enough and write atomically (write to a temporary file, then rename).
+    pub fn write_file(&self, path: impl AsRef<Path>, data: &[u8]) -> Result<(), CompressionError> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        fs::write(&tmp, self.compress(data))?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    /// Read and inflate; uncompressed files are returned as they are.
+    pub fn read_file(path: impl AsRef<Path>) -> Result<Vec<u8>, CompressionError> {
+        StateCompressor::decompress(&fs::read(path)?)
+    }
+}
+
+fn main() -> Result<(), CompressionError> {
+    let compressor = StateCompressor::new().with_threshold(1024);
+    let log = "{\"event\":\"step\",\"agent\":\"Analyst\",\"status\":\"ok\"}\n".repeat(1_000);
+    let packed = compressor.compress(log.as_bytes());
+    println!("{} bytes compressed to {}", log.len(), packed.len());
+    assert_eq!(StateCompressor::decompress(&packed)?, log.as_bytes());
+    Ok(())
+}
+```
+
+### Notes
+* With encryption, data is compressed first and then sealed, since ciphertext does not compress. Loading
+  opens, then inflates.
+* The threshold is on the uncompressed size. Below 64 KiB the saving is small and a plain file is easier
+  to inspect.
+* gzip's own CRC catches most damage. The SHA-256 also catches a stream that was replaced by another valid
+  one, and the error gives both hashes.
+* Inflated size is not limited: only files this process wrote are expected to be read back. Archives from
+  outside go through `extract_zip`, which is.
@@ -12,14 +12,10 @@ use std::io::{BufReader, BufRead, Error};
 use std::path::Path;
 
 // Import required crates
+use crate::swarms::utils::pdf_to_text::pdf_to_text;
 use csv;
 use serde_json;
 
-// External function for pdf conversion (replace with Rust equivalent)
-extern "C" {
-    fn pdf_to_text(file: &str) -> String;
-}
-
 /// Converts a CSV file to text format.
 fn csv_to_text(file: &str) -> Result<String, Error> {
     // Read the CSV file
@@ -70,7 +66,7 @@ fn md_to_text(file: &str) -> Result<String, Error> {
 }
 
 /// Converts the given data file to text format.
-fn data_to_text(file: &str) -> Result<String, Error> {
+pub fn data_to_text(file: &str) -> Result<String, Error> {
     // Check if the file exists
     if !Path::new(file).exists() {
         return Err(std::io::Error::new(std::io::ErrorKind::NotFound, "File not found"));
@@ -83,11 +79,7 @@ fn data_to_text(file: &str) -> Result<String, Error> {
         "csv" => csv_to_text(file),
         "json" => json_to_text(file),
         "txt" => txt_to_text(file),
-        "pdf" => {
-            // Use external pdf_to_text function or replace with Rust equivalent
-            let data = pdf_to_text(file);
-            Ok(data)
-        },
+        "pdf" => pdf_to_text(file).map_err(|e| Error::new(std::io::ErrorKind::InvalidData, e)),
         "md" => md_to_text(file),
         _ => {
             // Check if the file is a binary file (like an image)
@@ -0,0 +1,248 @@
+### Overview
+There is no Python counterpart; the Python package writes conversations, agent state, and run metadata as
+plaintext JSON. `StateCipher` encrypts those files with AES-256-GCM. Keys come from a `SecretStore`, named
+`STATE_KEY_<id>` and holding 32 bytes, base64-encoded.
+
+The cipher is built from a list of key ids, newest first. The first key encrypts, and every listed key can
+decrypt. Rotating a key is therefore:
+
+1. generate a new key and store it as `STATE_KEY_<new id>`;
+2. put the new id at the front of the list and restart; new saves use it and old files still load;
+3. run `rotate_dir` over the state directories to re-encrypt old files under the new key;
+4. drop the old id from the list and delete its secret.
+
+An encrypted file is text: `swenc1:<key id>:<base64 of nonce and ciphertext>`. The key id is also bound as
+associated data, so a file cannot be passed off as encrypted under a different key. Loading is transparent:
+`open` decrypts encrypted data and returns anything without the `swenc1:` prefix unchanged, so files saved
+before encryption was turned on keep loading.
+
+`StateCipher` also implements the redaction `Sealer`, so it can seal the originals that redaction removes.
+
+### Rust Code
+```rust
+use crate::swarms::text::redaction::{RedactionError, Sealer};
+use crate::swarms::utils::secret_store::SecretStore;
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use log::info;
+use rand::RngCore;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const MAGIC: &str = "swenc1:";
+const NONCE_LEN: usize = 12;
+
+#[derive(Debug)]
+pub enum EncryptionError {
+    /// No `STATE_KEY_<id>` secret.
+    MissingKey(String),
+    /// The secret is not 32 bytes of base64.
+    InvalidKey { key_id: String, reason: String },
+    /// Encrypted under a key that is no longer configured.
+    UnknownKeyId(String),
+    /// Tampered, truncated, or encrypted under a different key with the same id.
+    Corrupt(String),
+    NoKeys,
+    Io(io::Error),
+}
+
+impl fmt::Display for EncryptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncryptionError::MissingKey(id) => write!(f, "no secret STATE_KEY_{} for encryption key '{}'", id, id),
+            EncryptionError::InvalidKey { key_id, reason } => write!(f, "encryption key '{}' is invalid: {}", key_id, reason),
+            EncryptionError::UnknownKeyId(id) => write!(f, "data is encrypted under key '{}', which is not configured", id),
+            EncryptionError::Corrupt(message) => write!(f, "encrypted data is corrupt: {}", message),
+            EncryptionError::NoKeys => write!(f, "at least one encryption key id is required"),
+            EncryptionError::Io(err) => write!(f, "encrypted file I/O failed: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for EncryptionError {}
+
+impl From<io::Error> for EncryptionError {
+    fn from(err: io::Error) -> Self {
+        EncryptionError::Io(err)
+    }
+}
+
+// For the save paths that report `io::Error`.
+impl From<EncryptionError> for io::Error {
+    fn from(err: EncryptionError) -> Self {
+        match err {
+            EncryptionError::Io(err) => err,
+            other => io::Error::new(io::ErrorKind::InvalidData, other.to_string()),
+        }
+    }
+}
+
+/// AES-256-GCM with named, rotatable keys.
+pub struct StateCipher {
+    /// Newest first; the first key encrypts.
+    keys: Vec<(String, Aes256Gcm)>,
+}
+
+impl fmt::Debug for StateCipher {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StateCipher").field("key_ids", &self.key_ids()).finish()
+    }
+}
+
+impl StateCipher {
+    /// Load `STATE_KEY_<id>` for each id. `key_ids[0]` is the active key.
+    pub fn from_store(store: &dyn SecretStore, key_ids: &[&str]) -> Result<Self, EncryptionError> {
+        if key_ids.is_empty() {
+            return Err(EncryptionError::NoKeys);
+        }
+        let mut keys = Vec::with_capacity(key_ids.len());
+        for &key_id in key_ids {
+            if key_id.is_empty() || key_id.contains(':') {
+                return Err(EncryptionError::InvalidKey { key_id: key_id.to_string(), reason: "ids must be non-empty and contain no ':'".to_string() });
+            }
+            let secret = store
+                .get(&format!("STATE_KEY_{}", key_id))
+                .ok_or_else(|| EncryptionError::MissingKey(key_id.to_string()))?;
+            let invalid = |reason: String| EncryptionError::InvalidKey { key_id: key_id.to_string(), reason };
+            let bytes = BASE64.decode(secret.trim()).map_err(|err| invalid(err.to_string()))?;
+            if bytes.len() != 32 {
+                return Err(invalid(format!("expected 32 bytes, got {}", bytes.len())));
+            }
+            let cipher = Aes256Gcm::new_from_slice(&bytes).map_err(|err| invalid(err.to_string()))?;
+            keys.push((key_id.to_string(), cipher));
+        }
+        Ok(StateCipher { keys })
+    }
+
+    /// A fresh random key, base64-encoded for storing as `STATE_KEY_<id>`.
+    pub fn generate_key() -> String {
+        let mut key = [0u8; 32];
+        rand::thread_rng().fill_bytes(&mut key);
+        BASE64.encode(key)
+    }
+
+    pub fn key_ids(&self) -> Vec<&str> {
+        self.keys.iter().map(|(id, _)| id.as_str()).collect()
+    }
+
+    pub fn active_key_id(&self) -> &str {
+        &self.keys[0].0
+    }
+
+    pub fn is_encrypted(data: &[u8]) -> bool {
+        data.starts_with(MAGIC.as_bytes())
+    }
+
+    /// The key id an encrypted blob was sealed with, or `None` for plaintext.
+    pub fn key_id_of(data: &[u8]) -> Option<&str> {
+        let rest = std::str::from_utf8(data.strip_prefix(MAGIC.as_bytes())?).ok()?;
+        rest.split_once(':').map(|(id, _)| id)
+    }
+
+    /// Encrypt under the active key.
+    pub fn seal(&self, plaintext: &[u8]) -> Vec<u8> {
+        let (key_id, cipher) = &self.keys[0];
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut nonce);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), Payload { msg: plaintext, aad: key_id.as_bytes() })
+            .expect("AES-GCM encryption does not fail for in-memory buffers");
+        let mut blob = nonce.to_vec();
+        blob.extend_from_slice(&ciphertext);
+        format!("{}{}:{}", MAGIC, key_id, BASE64.encode(blob)).into_bytes()
+    }
+
+    /// Decrypt `data`, or return it unchanged if it was never encrypted.
+    pub fn open(&self, data: &[u8]) -> Result<Vec<u8>, EncryptionError> {
+        if !Self::is_encrypted(data) {
+            return Ok(data.to_vec());
+        }
+        let text = std::str::from_utf8(&data[MAGIC.len()..]).map_err(|err| EncryptionError::Corrupt(err.to_string()))?;
+        let (key_id, encoded) = text.split_once(':').ok_or_else(|| EncryptionError::Corrupt("missing key id".to_string()))?;
+        let (_, cipher) = self
+            .keys
+            .iter()
+            .find(|(id, _)| id == key_id)
+            .ok_or_else(|| EncryptionError::UnknownKeyId(key_id.to_string()))?;
+        let blob = BASE64.decode(encoded.trim()).map_err(|err| EncryptionError::Corrupt(err.to_string()))?;
+        if blob.len() < NONCE_LEN {
+            return Err(EncryptionError::Corrupt("shorter than a nonce".to_string()));
+        }
+        let (nonce, ciphertext) = blob.split_at(NONCE_LEN);
+        cipher
+            .decrypt(Nonce::from_slice(nonce), Payload { msg: ciphertext, aad: key_id.as_bytes() })
+            .map_err(|_| EncryptionError::Corrupt(format!("authentication failed under key '{}'", key_id)))
+    }
+
+    /// Encrypt and write atomically (write to a temporary file, then rename).
+    pub fn write_file(&self, path: impl AsRef<Path>, plaintext: &[u8]) -> Result<(), EncryptionError> {
+        let path = path.as_ref();
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+        fs::write(&tmp, self.seal(plaintext))?;
+        fs::rename(tmp, path)?;
+        Ok(())
+    }
+
+    /// Read and decrypt; plaintext files are returned as they are.
+    pub fn read_file(&self, path: impl AsRef<Path>) -> Result<Vec<u8>, EncryptionError> {
+        self.open(&fs::read(path)?)
+    }
+
+    /// Re-encrypt a file under the active key. Plaintext files are encrypted. Returns whether it was rewritten.
+    pub fn rotate_file(&self, path: impl AsRef<Path>) -> Result<bool, EncryptionError> {
+        let path = path.as_ref();
+        let data = fs::read(path)?;
+        if Self::key_id_of(&data) == Some(self.active_key_id()) {
+            return Ok(false);
+        }
+        let plaintext = self.open(&data)?;
+        self.write_file(path, &plaintext)?;
+        Ok(true)
+    }
+
+    /// `rotate_file` on every file directly inside `dir`. Returns how many were rewritten.
+    pub fn rotate_dir(&self, dir: impl AsRef<Path>) -> Result<usize, EncryptionError> {
+        let mut rotated = 0;
+        for entry in fs::read_dir(dir.as_ref())? {
+            let path = entry?.path();
+            if path.is_file() && self.rotate_file(&path)? {
+                rotated += 1;
+            }
+        }
+        info!("Re-encrypted {} file(s) in {} under key '{}'", rotated, dir.as_ref().display(), self.active_key_id());
+        Ok(rotated)
+    }
+}
+
+impl Sealer for StateCipher {
+    fn seal(&self, plaintext: &[u8]) -> Result<String, RedactionError> {
+        Ok(String::from_utf8(StateCipher::seal(self, plaintext)).expect("sealed output is ASCII"))
+    }
+}
+
+fn main() -> Result<(), EncryptionError> {
+    use crate::swarms::utils::secret_store::StaticSecretStore;
+
+    let store = StaticSecretStore::new()
+        .with("STATE_KEY_2026_10", StateCipher::generate_key())
+        .with("STATE_KEY_2026_04", StateCipher::generate_key());
+    let old = StateCipher::from_store(&store, &["2026_04"])?;
+    let sealed = old.seal(br#"{"agent_name":"Financial-Analyst"}"#);
+
+    let cipher = StateCipher::from_store(&store, &["2026_10", "2026_04"])?;
+    assert_eq!(cipher.open(&sealed)?, br#"{"agent_name":"Financial-Analyst"}"#);
+    println!("{}", String::from_utf8_lossy(&sealed));
+    Ok(())
+}
+```
+
+### Notes
+* Nonces are random per save. With 96-bit nonces that is safe for far more saves than one key should see; rotate keys on a schedule anyway.
+* Only file contents are encrypted. File names (conversation names, run ids) remain visible, so they should not carry sensitive data.
+* Losing every configured key makes the files unreadable; there is no recovery path by design.
@@ -22,15 +22,15 @@ use log::{error, info};
 use log::Level;
 use simple_logger::SimpleLogger;
 
-// Import regex crate for regular expression matching
-use regex::Regex;
-
 // Import serde_json crate for JSON parsing
 use serde_json::json;
 
 // Import tempfile crate for creating temporary files and directories
 use tempfile::TempDir;
 
+use crate::swarms::utils::safe_path::{safe_join, sanitize_file_name};
+use crate::swarms::utils::zip_extract::{extract_zip, ExtractLimits};
+
 // Initialize logger
 fn initialize_logger() {
     SimpleLogger::new().with_level(Level::Info).init().unwrap();
@@ -46,19 +46,27 @@ fn check_if_folder_exists(folder_name: &str) -> bool {
 }
 
 // Zips the specified workspace directory and returns the path to the zipped file.
+// `output_filename` is joined with `safe_join`, so it cannot leave the temporary directory.
 fn zip_workspace(workspace_path: &str, output_filename: &str) -> Option<String> {
-    // Create a temporary directory
+    // Create a temporary directory; like Python's `mkdtemp`, it is kept so the returned zip outlives this call
     let temp_dir = match TempDir::new() {
-        Ok(temp) => temp,
+        Ok(temp) => temp.into_path(),
         Err(e) => {
             error!("Failed to create temporary directory: {}", e);
             return None;
         }
     };
+    let output_path = match safe_join(&temp_dir, output_filename) {
+        Ok(path) => path,
+        Err(e) => {
+            error!("Invalid zip file name: {}", e);
+            return None;
+        }
+    };
 
     // Create a zip file that contains the workspace directory
-    let zip_path = match zip::ZipWriter::new(std::io::fs::File::create(format!("{}/{}", temp_dir.path().display(), output_filename)).unwrap()) {
-        Ok(zip) => zip,
+    let zip_path = match fs::File::create(&output_path) {
+        Ok(file) => zip::ZipWriter::new(file),
         Err(e) => {
             error!("Failed to create zip file: {}", e);
             return None;
@@ -68,7 +76,7 @@ fn zip_workspace(workspace_path: &str, output_filename: &str) -> Option<String>
     // Add the workspace directory to the zip file
     let workspace_path = Path::new(workspace_path);
     match add_dir_to_zip(zip_path, workspace_path, "") {
-        Ok(_) => Some(format!("{}/{}", temp_dir.path().display(), output_filename)),
+        Ok(_) => Some(output_path.display().to_string()),
         Err(e) => {
             error!("Failed to add directory to zip: {}", e);
             None
@@ -76,17 +84,24 @@ fn zip_workspace(workspace_path: &str, output_filename: &str) -> Option<String>
     }
 }
 
-// Sanitizes the file path to be valid for Windows.
-fn sanitize_file_path(file_path: &str) -> Option<String> {
-    // Try to sanitize the file path
-    let sanitized_path = match Regex::new(r"[<>:\"/\\|?*]") {
-        Ok(re) => re.replace_all(file_path, "_"),
+// Unzips a workspace archive into `output_path`, which must not exist yet, and returns that path.
+// The archive may come from an agent, so it is extracted within the default limits (see `zip_extract`).
+fn unzip_workspace(zip_path: &str, output_path: &str) -> Option<String> {
+    match extract_zip(zip_path, output_path, &ExtractLimits::default()) {
+        Ok(summary) => {
+            info!("Extracted {} files ({} bytes) to {}", summary.files, summary.bytes, output_path);
+            Some(output_path.to_string())
+        }
         Err(e) => {
-            error!("Failed to sanitize file path: {}", e);
-            return None;
+            error!("Failed to unzip workspace: {}", e);
+            None
         }
-    };
-    Some(sanitized_path.to_string())
+    }
+}
+
+// Sanitizes the file path into one file name that is valid on Windows, macOS, and Linux.
+fn sanitize_file_path(file_path: &str) -> Option<String> {
+    Some(sanitize_file_name(file_path))
 }
 
 // Loads a JSON string and returns the corresponding Rust object.
@@ -124,10 +139,16 @@ fn create_file_in_folder(folder_path: &str, file_name: &str, content: &str) -> O
         }
     }
 
-    // Create the file in the folder
-    let file_path = format!("{}/{}", folder_path, file_name);
-    match fs::write(file_path.clone(), content) {
-        Ok(_) => Some(file_path),
+    // Create the file in the folder; a file name that would leave the folder is refused
+    let file_path = match safe_join(folder_path, file_name) {
+        Ok(path) => path,
+        Err(e) => {
+            error!("Failed to create file in folder: {}", e);
+            return None;
+        }
+    };
+    match fs::write(&file_path, content) {
+        Ok(_) => Some(file_path.display().to_string()),
         Err(e) => {
             error!("Failed to create file in folder: {}", e);
             None
@@ -146,26 +167,25 @@ fn zip_folders(folder1_path: &str, folder2_path: &str, zip_file_path: &str) {
         }
     };
 
-    // Copy both folders into the temporary directory
-    match fs::copy_dir_all(folder1_path, format!("{}/{}", temp_dir.path().display(), Path::new(folder1_path).file_name().unwrap())) {
-        Ok(_) => (),
-        Err(e) => {
-            error!("Failed to copy folder: {}", e);
-            return;
-        }
-    }
-
-    match fs::copy_dir_all(folder2_path, format!("{}/{}", temp_dir.path().display(), Path::new(folder2_path).file_name().unwrap())) {
-        Ok(_) => (),
-        Err(e) => {
+    // Copy both folders into the temporary directory, each under its own (sanitized) name
+    for folder_path in [folder1_path, folder2_path] {
+        let name = Path::new(folder_path).file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+        let target = match safe_join(temp_dir.path(), &name) {
+            Ok(path) => path,
+            Err(e) => {
+                error!("Failed to copy folder {}: {}", folder_path, e);
+                return;
+            }
+        };
+        if let Err(e) = fs::copy_dir_all(folder_path, target) {
             error!("Failed to copy folder: {}", e);
             return;
         }
     }
 
     // Create a zip file that contains the temporary directory
-    let zip_path = match zip::ZipWriter::new(std::io::fs::File::create(zip_file_path).unwrap()) {
-        Ok(zip) => zip,
+    let zip_path = match fs::File::create(zip_file_path) {
+        Ok(file) => zip::ZipWriter::new(file),
         Err(e) => {
             error!("Failed to create zip file: {}", e);
             return;
@@ -181,7 +201,7 @@ fn zip_folders(folder1_path: &str, folder2_path: &str, zip_file_path: &str) {
 }
 
 // Helper function to add a directory to a zip file
-fn add_dir_to_zip(zip: zip::ZipWriter<std::io::fs::File>, path: &Path, prefix: &str) -> Result<(), io::Error> {
+fn add_dir_to_zip(zip: zip::ZipWriter<fs::File>, path: &Path, prefix: &str) -> Result<(), io::Error> {
     for entry in fs::read_dir(path)? {
         let entry = entry?;
         let path = entry.path();
@@ -0,0 +1,232 @@
+### Overview
+There is no Python counterpart. The Python package names runs `swarm_run_{unix_secs}`, which collide when
+two runs start in the same second, and uses `uuid4()` elsewhere, which cannot be sorted. `IdGenerator` is
+the one source of ids for runs, queued tasks, chat completion messages, and artifact versions:
+
+* Ids are [ULIDs](https://github.com/ulid/spec) by default: 48 bits of milliseconds then 80 random bits,
+  written as 26 Crockford base32 characters. They sort by creation time as plain strings.
+* Ids from one generator are strictly increasing. A second id in the same millisecond, or after the clock
+  steps back, reuses the last timestamp and adds one to the random part, so ids never collide or reorder.
+* Each `IdKind` gets a prefix (`run_`, `task_`, `msg_`, `ver_`) so an id in a log says what it names.
+  `with_prefix` changes one, and an empty prefix leaves the id bare.
+* `IdFormat::UuidV7` writes the same value as a UUIDv7, for stores with a `uuid` column.
+* `seeded` and `with_clock` make the sequence reproducible, for tests and replayed runs.
+
+`new_id` draws from a process-wide generator, which `set_global` replaces.
+
+### Rust Code
+```rust
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::time::{SystemTime, UNIX_EPOCH};
+use uuid::Uuid;
+
+const CROCKFORD: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+const ULID_LEN: usize = 26;
+const RANDOM_BITS: u32 = 80;
+const RANDOM_MASK: u128 = (1 << RANDOM_BITS) - 1;
+const TIME_MASK: u64 = (1 << 48) - 1;
+
+/// What an id names; each kind has its own prefix.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IdKind {
+    Run,
+    Task,
+    Message,
+    /// One version of an artifact.
+    Version,
+}
+
+impl IdKind {
+    pub fn default_prefix(self) -> &'static str {
+        match self {
+            IdKind::Run => "run",
+            IdKind::Task => "task",
+            IdKind::Message => "msg",
+            IdKind::Version => "ver",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IdFormat {
+    #[default]
+    Ulid,
+    /// The same timestamp and randomness as a UUIDv7. Also sorts by creation time.
+    UuidV7,
+}
+
+struct State {
+    last_ms: u64,
+    random: u128,
+    rng: StdRng,
+}
+
+/// Generates time-ordered ids. Share one generator (it is `Sync`) so all its ids are ordered together.
+pub struct IdGenerator {
+    format: IdFormat,
+    prefixes: HashMap<IdKind, String>,
+    clock: Arc<dyn Fn() -> u64 + Send + Sync>,
+    state: Mutex<State>,
+}
+
+impl Default for IdGenerator {
+    fn default() -> Self {
+        IdGenerator::new()
+    }
+}
+
+fn system_ms() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_millis() as u64)
+}
+
+fn random_bits(rng: &mut StdRng) -> u128 {
+    ((u128::from(rng.next_u64()) << 64) | u128::from(rng.next_u64())) & RANDOM_MASK
+}
+
+impl IdGenerator {
+    /// ULIDs with the default prefixes, the system clock, and OS-seeded randomness.
+    pub fn new() -> Self {
+        Self::from_rng(StdRng::from_entropy())
+    }
+
+    /// The same ids, in the same order, every time for a given seed and clock. Pair with `with_clock`.
+    pub fn seeded(seed: u64) -> Self {
+        Self::from_rng(StdRng::seed_from_u64(seed))
+    }
+
+    fn from_rng(rng: StdRng) -> Self {
+        IdGenerator {
+            format: IdFormat::default(),
+            prefixes: HashMap::new(),
+            clock: Arc::new(system_ms),
+            state: Mutex::new(State { last_ms: 0, random: 0, rng }),
+        }
+    }
+
+    pub fn with_format(mut self, format: IdFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Use `prefix` for ids of `kind`. An empty prefix leaves them bare.
+    pub fn with_prefix(mut self, kind: IdKind, prefix: impl Into<String>) -> Self {
+        self.prefixes.insert(kind, prefix.into());
+        self
+    }
+
+    /// Read the time from `clock`, in milliseconds since the Unix epoch.
+    pub fn with_clock(mut self, clock: impl Fn() -> u64 + Send + Sync + 'static) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    pub fn prefix(&self, kind: IdKind) -> &str {
+        self.prefixes.get(&kind).map_or(kind.default_prefix(), String::as_str)
+    }
+
+    // The next 128-bit value: 48 bits of milliseconds, then 80 bits that only go up within a millisecond
+    fn next_value(&self) -> u128 {
+        let now = (self.clock)() & TIME_MASK;
+        let mut state = self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        if now > state.last_ms {
+            state.last_ms = now;
+            state.random = random_bits(&mut state.rng);
+        } else if state.random < RANDOM_MASK {
+            state.random += 1;
+        } else {
+            // 2^80 ids in one millisecond; borrow the next one
+            state.last_ms += 1;
+            state.random = random_bits(&mut state.rng);
+        }
+        (u128::from(state.last_ms) << RANDOM_BITS) | state.random
+    }
+
+    /// A bare id, without a prefix.
+    pub fn next_id(&self) -> String {
+        let value = self.next_value();
+        match self.format {
+            IdFormat::Ulid => encode_ulid(value),
+            IdFormat::UuidV7 => encode_uuid_v7(value),
+        }
+    }
+
+    /// An id for `kind`, e.g. `run_01J9Z3Q5M8XK4T2W6V7B0C1D2E`.
+    pub fn next(&self, kind: IdKind) -> String {
+        match self.prefix(kind) {
+            "" => self.next_id(),
+            prefix => format!("{}_{}", prefix, self.next_id()),
+        }
+    }
+}
+
+fn encode_ulid(value: u128) -> String {
+    (0..ULID_LEN).map(|i| CROCKFORD[((value >> (5 * (ULID_LEN - 1 - i))) & 31) as usize] as char).collect()
+}
+
+fn decode_ulid(text: &str) -> Option<u128> {
+    if text.len() != ULID_LEN {
+        return None;
+    }
+    text.bytes().try_fold(0u128, |value, byte| {
+        let digit = CROCKFORD.iter().position(|&c| c == byte.to_ascii_uppercase())?;
+        value.checked_mul(32).map(|value| value | digit as u128)
+    })
+}
+
+// Keeps the 48-bit time; the version and variant bits replace 6 of the 80 random bits
+fn encode_uuid_v7(value: u128) -> String {
+    let millis = value >> RANDOM_BITS;
+    let random = value & RANDOM_MASK;
+    let rand_a = (random >> 68) & 0xFFF;
+    let rand_b = random & ((1 << 62) - 1);
+    Uuid::from_u128((millis << 80) | (0x7 << 76) | (rand_a << 64) | (0b10 << 62) | rand_b).to_string()
+}
+
+/// When the id was generated, in milliseconds since the Unix epoch. Accepts ULIDs and UUIDv7s, with or
+/// without a prefix. `None` for any other id, e.g. the UUIDv4s written before ids were generated here.
+pub fn id_timestamp_ms(id: &str) -> Option<u64> {
+    let bare = id.rsplit_once('_').map_or(id, |(_, bare)| bare);
+    if let Some(value) = decode_ulid(bare) {
+        return Some((value >> RANDOM_BITS) as u64);
+    }
+    let uuid = Uuid::parse_str(bare).ok()?;
+    (uuid.get_version_num() == 7).then(|| (uuid.as_u128() >> 80) as u64)
+}
+
+static GLOBAL: OnceLock<RwLock<Arc<IdGenerator>>> = OnceLock::new();
+
+fn global() -> &'static RwLock<Arc<IdGenerator>> {
+    GLOBAL.get_or_init(|| RwLock::new(Arc::new(IdGenerator::new())))
+}
+
+/// Replace the generator `new_id` draws from, e.g. with UUIDv7s or a seeded generator for replays.
+pub fn set_global(generator: IdGenerator) {
+    *global().write().unwrap_or_else(|poisoned| poisoned.into_inner()) = Arc::new(generator);
+}
+
+/// An id for `kind` from the process-wide generator.
+pub fn new_id(kind: IdKind) -> String {
+    let generator = Arc::clone(&global().read().unwrap_or_else(|poisoned| poisoned.into_inner()));
+    generator.next(kind)
+}
+
+fn main() {
+    let run = new_id(IdKind::Run);
+    println!("{} created at {:?}", run, id_timestamp_ms(&run));
+
+    let replay = IdGenerator::seeded(7).with_clock(|| 1_700_000_000_000).with_format(IdFormat::UuidV7);
+    println!("{} then {}", replay.next(IdKind::Task), replay.next(IdKind::Task));
+}
+```
+
+### Notes
+* Ordering holds within one generator. Across processes, ids are ordered by millisecond, and ids from the
+  same millisecond order arbitrarily; they still cannot collide in practice (80 random bits).
+* A ULID reveals when it was created. Anywhere that must not leak timing, such as webhook secrets or
+  demo session tokens, keeps its random UUIDv4.
+* `Conversation` message ids are not generated here. They are a per-conversation sequence, which is
+  already ordered and collision-free, and edits and deletes refer to them by number.
+* `set_global` affects ids generated after it returns; ids already handed out keep their format.
@@ -9,6 +9,7 @@ The conversion of this Python file to Rust is **partially viable**. The primary
 ### Rust Version
 ```rust
 // Import necessary libraries
+use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
 use std::collections::HashMap;
 
 // Define a struct to represent the LiteLLM
@@ -38,6 +39,21 @@ impl LiteLLM {
         }
     }
 
+    // Split a litellm-style model name ("groq/llama3-70b-8192") into provider and model.
+    // Bare names default to OpenAI, matching litellm.
+    pub fn provider_and_model(&self) -> (&str, &str) {
+        match self.model_name.split_once('/') {
+            Some((provider, model)) => (provider, model),
+            None => ("openai", self.model_name.as_str()),
+        }
+    }
+
+    // Describe what the configured model supports so callers can downgrade requests
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        let (provider, model) = self.provider_and_model();
+        ProviderCapabilities::lookup(provider, model)
+    }
+
     // Prepare the messages for the given task
     pub fn prepare_messages(&self, task: String) -> Vec<HashMap<String, String>> {
         let mut messages: Vec<HashMap<String, String>> = Vec::new();
@@ -0,0 +1,395 @@
+### Overview
+There is no Python counterpart; the Python agent reaches local models through an Ollama or vLLM server.
+`LlamaCppProvider` runs a GGUF model in-process through the `llama-cpp-2` bindings instead, so a swarm
+can be fully self-hosted with no server next to it. It is a `ChatProvider` like `OpenAiCompatProvider`,
+so agents do not need to know which one they were given.
+
+* `LlamaCppConfig` sets the model file, the context size, and how many layers go to the GPU.
+  `LlamaCppConfig::from_schema` reads them from an `AgentSchema`: `llm` is the model path,
+  `context_window` the context size, `device` where to run (`cpu`, `cuda:1`, `metal`), and `template` the
+  name of the chat template.
+* Messages are rendered into one prompt with a `ChatTemplate`. Without a `template`, the template is
+  picked from the model's file name, and ChatML is used when no family matches.
+* The model is loaded once and shared by clones of the provider. Every `chat` call creates its own
+  context on a blocking thread, so calls can run at the same time, each using `context_size` tokens of
+  memory.
+* Usage is counted exactly from the tokens decoded, so it is never `estimated`. Calls are recorded in
+  `SwarmMetrics` the way `OpenAiCompatProvider`'s are: latency, and tokens under the agent set with
+  `for_agent`, and `track_usage` adds every call to a `UsageScope`.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::agent_input_schema::AgentSchema;
+use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ContentItem, UsageInfo};
+use crate::swarms::structs::usage::UsageScope;
+use crate::swarms::text::chat_template::{ChatTemplate, ChatTemplateRegistry};
+use crate::swarms::text::stop_conditions::StopConditions;
+use crate::swarms::utils::chat_provider::{record_call, ChatCompletion, ChatProvider, Device, FinishReason, ProviderError};
+use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+use async_trait::async_trait;
+use llama_cpp_2::context::params::LlamaContextParams;
+use llama_cpp_2::llama_backend::LlamaBackend;
+use llama_cpp_2::llama_batch::LlamaBatch;
+use llama_cpp_2::model::params::LlamaModelParams;
+use llama_cpp_2::model::{AddBos, LlamaModel, Special};
+use llama_cpp_2::sampling::LlamaSampler;
+use log::{info, warn};
+use std::num::NonZeroU32;
+use std::path::PathBuf;
+use std::sync::{Arc, OnceLock};
+use std::time::Instant;
+
+/// More layers than any GGUF model has; llama.cpp offloads all of them.
+pub const ALL_LAYERS: u32 = 999;
+
+const PROVIDER_NAME: &str = "llama-cpp";
+
+/// Where and how to run a GGUF model.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LlamaCppConfig {
+    pub model_path: PathBuf,
+    /// Tokens of context, prompt and reply together.
+    pub context_size: u32,
+    /// Layers offloaded to the GPU; 0 runs on the CPU only.
+    pub gpu_layers: u32,
+    /// The GPU that holds the model when several are present.
+    pub main_gpu: i32,
+    /// Decoding threads; llama.cpp picks from the core count when `None`.
+    pub threads: Option<i32>,
+    /// Prompt tokens decoded per batch.
+    pub batch_size: u32,
+    pub seed: u32,
+    pub template: Option<ChatTemplate>,
+}
+
+impl LlamaCppConfig {
+    pub fn new(model_path: impl Into<PathBuf>) -> Self {
+        LlamaCppConfig {
+            model_path: model_path.into(),
+            context_size: 4096,
+            gpu_layers: 0,
+            main_gpu: 0,
+            threads: None,
+            batch_size: 512,
+            seed: 1234,
+            template: None,
+        }
+    }
+
+    pub fn context_size(mut self, context_size: u32) -> Self {
+        self.context_size = context_size;
+        self
+    }
+
+    pub fn gpu_layers(mut self, gpu_layers: u32) -> Self {
+        self.gpu_layers = gpu_layers;
+        self
+    }
+
+    /// Run on `device`: the CPU only, or every layer on the given GPU. Call `gpu_layers` afterwards to
+    /// offload only part of a model that does not fit in GPU memory.
+    pub fn device(mut self, device: Device) -> Self {
+        match device {
+            Device::Cpu => self.gpu_layers = 0,
+            Device::Gpu(index) => {
+                self.gpu_layers = ALL_LAYERS;
+                self.main_gpu = index;
+            }
+        }
+        self
+    }
+
+    pub fn threads(mut self, threads: i32) -> Self {
+        self.threads = Some(threads);
+        self
+    }
+
+    pub fn seed(mut self, seed: u32) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    pub fn template(mut self, template: ChatTemplate) -> Self {
+        self.template = Some(template);
+        self
+    }
+
+    /// Read the model path, context size, device, and chat template from `schema`. `templates` resolves
+    /// the schema's `template` name.
+    pub fn from_schema(schema: &AgentSchema, templates: &ChatTemplateRegistry) -> Result<Self, ProviderError> {
+        if schema.llm.trim().is_empty() {
+            return Err(ProviderError::InvalidConfig("`llm` must be the path of a GGUF model".to_string()));
+        }
+        let mut config = LlamaCppConfig::new(schema.llm.trim());
+        if schema.context_window > 0 {
+            config = config.context_size(schema.context_window as u32);
+        }
+        if let Some(device) = &schema.device {
+            config = config.device(Device::parse(device)?);
+        }
+        if let Some(name) = &schema.template {
+            let template = templates
+                .get(name)
+                .ok_or_else(|| ProviderError::InvalidConfig(format!("no chat template named '{}'", name)))?;
+            config = config.template(template.clone());
+        }
+        Ok(config)
+    }
+
+    /// The model's name: its file name without the `.gguf` extension.
+    pub fn model_name(&self) -> String {
+        self.model_path.file_stem().map_or_else(String::new, |stem| stem.to_string_lossy().into_owned())
+    }
+}
+
+/// llama.cpp's backend may only be initialized once per process.
+fn backend() -> Result<&'static LlamaBackend, ProviderError> {
+    static BACKEND: OnceLock<Result<LlamaBackend, String>> = OnceLock::new();
+    BACKEND
+        .get_or_init(|| LlamaBackend::init().map_err(|err| err.to_string()))
+        .as_ref()
+        .map_err(|err| ProviderError::Local(err.clone()))
+}
+
+fn local(err: impl std::fmt::Display) -> ProviderError {
+    ProviderError::Local(err.to_string())
+}
+
+/// A GGUF model running in this process. Cheap to clone; clones share the loaded weights.
+#[derive(Clone)]
+pub struct LlamaCppProvider {
+    config: Arc<LlamaCppConfig>,
+    model_name: String,
+    template: ChatTemplate,
+    model: Arc<LlamaModel>,
+    agent: Option<String>,
+    usage: Option<UsageScope>,
+}
+
+impl std::fmt::Debug for LlamaCppProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("LlamaCppProvider")
+            .field("config", &self.config)
+            .field("template", &self.template.name)
+            .finish_non_exhaustive()
+    }
+}
+
+impl LlamaCppProvider {
+    /// Load the model. This reads the whole file and blocks; call it from `spawn_blocking` in async code.
+    pub fn load(config: LlamaCppConfig) -> Result<Self, ProviderError> {
+        if !config.model_path.is_file() {
+            return Err(ProviderError::InvalidConfig(format!("no model file at {}", config.model_path.display())));
+        }
+        let backend = backend()?;
+        if config.gpu_layers > 0 && !backend.supports_gpu_offload() {
+            warn!(
+                "{} asks for {} GPU layers, but llama.cpp was built without a GPU backend; running on the CPU",
+                config.model_path.display(),
+                config.gpu_layers
+            );
+        }
+        let params = LlamaModelParams::default().with_n_gpu_layers(config.gpu_layers).with_main_gpu(config.main_gpu);
+        let started = Instant::now();
+        let model = LlamaModel::load_from_file(backend, &config.model_path, &params).map_err(local)?;
+        info!("Loaded {} in {:.1}s", config.model_path.display(), started.elapsed().as_secs_f64());
+
+        let model_name = config.model_name();
+        let template = config
+            .template
+            .clone()
+            .or_else(|| ChatTemplateRegistry::builtin().for_model(&model_name).cloned())
+            .unwrap_or_else(ChatTemplate::chatml);
+        Ok(LlamaCppProvider {
+            config: Arc::new(config),
+            model_name,
+            template,
+            model: Arc::new(model),
+            agent: None,
+            usage: None,
+        })
+    }
+
+    /// Attribute this provider's token spend to `agent` in the metrics.
+    pub fn for_agent(mut self, agent: impl Into<String>) -> Self {
+        self.agent = Some(agent.into());
+        self
+    }
+
+    /// Record every call's token usage into `scope`.
+    pub fn track_usage(mut self, scope: UsageScope) -> Self {
+        self.usage = Some(scope);
+        self
+    }
+
+    pub fn config(&self) -> &LlamaCppConfig {
+        &self.config
+    }
+
+    pub fn template(&self) -> &ChatTemplate {
+        &self.template
+    }
+
+    /// The prompt `request` renders to. Images are left out; the model only reads text.
+    pub fn render(&self, request: &ChatCompletionRequest) -> String {
+        let messages: Vec<(String, String)> = request
+            .messages
+            .iter()
+            .map(|message| {
+                let text: Vec<&str> = message
+                    .content
+                    .iter()
+                    .filter_map(|item| match item {
+                        ContentItem::Text { text } => Some(text.as_str()),
+                        _ => None,
+                    })
+                    .collect();
+                (message.role.clone(), text.join("\n"))
+            })
+            .collect();
+        self.template.render_messages(messages.iter().map(|(role, content)| (role.as_str(), content.as_str())))
+    }
+
+    /// Decode a reply to `prompt`. Runs on the calling thread for as long as generation takes.
+    fn generate(&self, prompt: &str, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+        let backend = backend()?;
+        let mut params = LlamaContextParams::default()
+            .with_n_ctx(NonZeroU32::new(self.config.context_size))
+            .with_n_batch(self.config.batch_size);
+        if let Some(threads) = self.config.threads {
+            params = params.with_n_threads(threads).with_n_threads_batch(threads);
+        }
+        let mut context = self.model.new_context(backend, params).map_err(local)?;
+
+        // The template writes the BOS marker itself where the family has one.
+        let tokens = self.model.str_to_token(prompt, AddBos::Never).map_err(local)?;
+        let context_size = self.config.context_size as usize;
+        if tokens.is_empty() || tokens.len() >= context_size {
+            return Err(ProviderError::InvalidConfig(format!(
+                "a prompt of {} tokens does not fit the {}-token context",
+                tokens.len(),
+                context_size
+            )));
+        }
+        let room = context_size - tokens.len();
+        let max_tokens = request.max_tokens.map_or(room, |max| (max.max(1) as usize).min(room));
+
+        let mut batch = LlamaBatch::new(self.config.batch_size as usize, 1);
+        let last = tokens.len() - 1;
+        for (start, chunk) in tokens.chunks(self.config.batch_size as usize).enumerate() {
+            batch.clear();
+            for (offset, token) in chunk.iter().enumerate() {
+                let position = start * self.config.batch_size as usize + offset;
+                batch.add(*token, position as i32, &[0], position == last).map_err(local)?;
+            }
+            context.decode(&mut batch).map_err(local)?;
+        }
+
+        let temperature = request.temperature.unwrap_or(0.8) as f32;
+        let mut sampler = if temperature <= 0.0 {
+            LlamaSampler::greedy()
+        } else {
+            LlamaSampler::chain_simple([
+                LlamaSampler::top_p(request.top_p.unwrap_or(1.0) as f32, 1),
+                LlamaSampler::temp(temperature),
+                LlamaSampler::dist(self.config.seed),
+            ])
+        };
+
+        let mut stop = StopConditions::new();
+        for sequence in self.template.stop.iter().chain(request.stop.iter().flatten()) {
+            stop = stop.sequence(sequence.clone());
+        }
+        let mut matcher = stop.matcher();
+        let mut pending = Vec::new();
+        let mut finish_reason = FinishReason::Length;
+        let mut generated = 0;
+        let mut position = tokens.len() as i32;
+        while generated < max_tokens {
+            let token = sampler.sample(&context, batch.n_tokens() - 1);
+            sampler.accept(token);
+            generated += 1;
+            if self.model.is_eog_token(token) {
+                finish_reason = FinishReason::Stop;
+                break;
+            }
+            // A token can end partway through a UTF-8 character; hold the bytes until it is complete.
+            pending.extend(self.model.token_to_bytes(token, Special::Plaintext).map_err(local)?);
+            let valid = match std::str::from_utf8(&pending) {
+                Ok(text) => text.len(),
+                Err(err) => err.valid_up_to(),
+            };
+            if valid > 0 {
+                let text = String::from_utf8_lossy(&pending[..valid]).into_owned();
+                pending.drain(..valid);
+                if matcher.push(&text).is_some() {
+                    finish_reason = FinishReason::Stop;
+                    break;
+                }
+            }
+            batch.clear();
+            batch.add(token, position, &[0], true).map_err(local)?;
+            position += 1;
+            context.decode(&mut batch).map_err(local)?;
+        }
+
+        let mut usage = UsageInfo::new(tokens.len() as i32, (tokens.len() + generated) as i32);
+        usage.completion_tokens = Some(generated as i32);
+        Ok(ChatCompletion {
+            content: matcher.truncated().to_string(),
+            finish_reason: Some(finish_reason),
+            tool_calls: Vec::new(),
+            usage: Some(usage),
+            stopped_by: matcher.stopped().cloned(),
+        })
+    }
+}
+
+#[async_trait]
+impl ChatProvider for LlamaCppProvider {
+    fn name(&self) -> &str {
+        PROVIDER_NAME
+    }
+
+    fn model(&self) -> &str {
+        &self.model_name
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        let mut capabilities = ProviderCapabilities::text_only(PROVIDER_NAME, self.model_name.clone());
+        capabilities.streaming = false;
+        capabilities.max_context_tokens = self.config.context_size as usize;
+        capabilities
+    }
+
+    async fn chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+        let mut request = request.clone();
+        self.capabilities().adapt(&mut request);
+        let prompt = self.render(&request);
+        let provider = self.clone();
+        let started = Instant::now();
+        let result = tokio::task::spawn_blocking(move || provider.generate(&prompt, &request))
+            .await
+            .map_err(local)
+            .and_then(|result| result);
+        record_call(PROVIDER_NAME, &self.model_name, self.agent.as_deref(), self.usage.as_ref(), &result, started);
+        result
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), ProviderError> {
+    let path = std::env::var("SWARMS_GGUF_MODEL").map_err(|_| ProviderError::MissingApiKey("SWARMS_GGUF_MODEL".to_string()))?;
+    let config = LlamaCppConfig::new(path).context_size(8192).device(Device::parse("cuda:0")?);
+    let provider = tokio::task::spawn_blocking(move || LlamaCppProvider::load(config)).await.map_err(local)??;
+    let answer = provider.complete(Some("You are a helpful assistant."), "What is the capital of France?").await?;
+    println!("{}", answer);
+    Ok(())
+}
+```
+
+### Notes
+* GPU offload needs llama.cpp built with a GPU backend. The `llama-cpp` feature builds it for the CPU; a binary that wants CUDA, Metal, or Vulkan adds `llama-cpp-2` to its own manifest with that feature, and cargo builds the one copy with it.
+* Building `llama-cpp-2` compiles llama.cpp from source, which needs `cmake` and a C++ compiler and takes a few minutes.
+* Tool calls are not parsed out of the reply, so `capabilities().tools` is false and `adapt` turns tool requests into prompt-based ones, listing the tools in the system message.
+* A model that ignores its end-of-turn token is still stopped by the template's `stop` sequences.
@@ -0,0 +1,299 @@
+### Overview
+There is no Python counterpart; a Python run's only progress signal is the `tasks_completed` count written
+into its metadata file at the end. `SwarmMetrics` exposes live Prometheus metrics instead, all under the
+`swarms_` prefix:
+
+| Metric | Type | Labels |
+|---|---|---|
+| `swarms_tasks_queued_total` | counter | `swarm` |
+| `swarms_queue_depth` | gauge | `swarm` |
+| `swarms_tasks_completed_total` | counter | `swarm`, `agent` |
+| `swarms_tasks_failed_total` | counter | `swarm`, `agent` |
+| `swarms_task_duration_seconds` | histogram | `swarm`, `agent` |
+| `swarms_task_wait_seconds` | histogram | `swarm`, `lane` (an agent, or `*` for shared tasks) |
+| `swarms_tasks_starved_total` | counter | `swarm`, `lane` |
+| `swarms_agent_in_flight` | gauge | `swarm`, `agent` |
+| `swarms_tasks_stuck_total` | counter | `swarm`, `agent`, `action` (`cancel` or `requeue`) |
+| `swarms_agent_restarts_total` | counter | `supervisor`, `child` |
+| `swarms_restart_escalations_total` | counter | `supervisor`, `child` |
+| `swarms_faults_injected_total` | counter | `fault` (`provider_failure`, `delay`, `malformed_json`, `tool_timeout`) |
+| `swarms_workflow_runs_total` | counter | `workflow`, `outcome` |
+| `swarms_workflow_duration_seconds` | histogram | `workflow` |
+| `swarms_llm_latency_seconds` | histogram | `provider`, `model`, `outcome` |
+| `swarms_tokens_total` | counter | `model`, `agent`, `kind` (`prompt` or `completion`) |
+| `swarms_estimated_tokens_total` | counter | `model`, `agent`, `kind` |
+| `swarms_model_tier_selections_total` | counter | `tier`, `reason` |
+| `swarms_router_fallbacks_total` | counter | `router`, `swarm` (the swarm that failed) |
+| `swarms_router_shadow_runs_total` | counter | `router`, `primary`, `shadow`, `outcome` |
+| `swarms_router_shadow_divergence` | histogram | `router`, `primary`, `shadow` |
+| `swarms_agent_version_runs_total` | counter | `agent`, `version`, `outcome` |
+| `swarms_agent_version_duration_seconds` | histogram | `agent`, `version` |
+
+`TaskQueueSwarm` records the task metrics, its `FairScheduler` the wait and in-flight metrics, `Supervisor`
+the stuck tasks, `RestartSupervisor` the restarts, `Chaos` the faults it injects, `SequentialWorkflow` and
+`GraphWorkflow` the workflow metrics, `OpenAiCompatProvider` the LLM latency and token spend, `ModelSelector`
+the tier chosen for each step, `AutoSwarmRouter` its fallbacks and shadow comparisons, and `VersionedAgent`
+the runs of each agent version. Everything records into `SwarmMetrics::global()`, which the API server serves at `GET /metrics` through `handle_http`.
+
+Label values are agent, model, and workflow names, never task text, so cardinality stays bounded by the
+configuration.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+use crate::swarms::schemas::base_schemas::UsageInfo;
+use crate::swarms::structs::step_guard::StepStatus;
+use log::error;
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGaugeVec, Opts, Registry, TextEncoder,
+};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+const NAMESPACE: &str = "swarms";
+
+/// Buckets for LLM calls and agent steps, which take from a fraction of a second to minutes.
+const LATENCY_BUCKETS: &[f64] = &[0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0];
+
+/// Buckets for output divergence, which runs from 0.0 (same words) to 1.0 (no words in common).
+const DIVERGENCE_BUCKETS: &[f64] = &[0.1, 0.2, 0.3, 0.4, 0.5, 0.6, 0.7, 0.8, 0.9, 1.0];
+
+pub struct SwarmMetrics {
+    registry: Registry,
+    tasks_queued: IntCounterVec,
+    queue_depth: IntGaugeVec,
+    tasks_completed: IntCounterVec,
+    tasks_failed: IntCounterVec,
+    task_duration: HistogramVec,
+    task_wait: HistogramVec,
+    tasks_starved: IntCounterVec,
+    agent_in_flight: IntGaugeVec,
+    tasks_stuck: IntCounterVec,
+    restarts: IntCounterVec,
+    escalations: IntCounterVec,
+    faults_injected: IntCounterVec,
+    workflow_runs: IntCounterVec,
+    workflow_duration: HistogramVec,
+    llm_latency: HistogramVec,
+    tokens: IntCounterVec,
+    estimated_tokens: IntCounterVec,
+    tier_selections: IntCounterVec,
+    router_fallbacks: IntCounterVec,
+    shadow_runs: IntCounterVec,
+    shadow_divergence: HistogramVec,
+    version_runs: IntCounterVec,
+    version_duration: HistogramVec,
+}
+
+fn counter(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> IntCounterVec {
+    let metric = IntCounterVec::new(Opts::new(name, help).namespace(NAMESPACE), labels).expect("valid metric");
+    registry.register(Box::new(metric.clone())).expect("metric registered once");
+    metric
+}
+
+fn gauge(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> IntGaugeVec {
+    let metric = IntGaugeVec::new(Opts::new(name, help).namespace(NAMESPACE), labels).expect("valid metric");
+    registry.register(Box::new(metric.clone())).expect("metric registered once");
+    metric
+}
+
+fn histogram(registry: &Registry, name: &str, help: &str, labels: &[&str]) -> HistogramVec {
+    histogram_with_buckets(registry, name, help, labels, LATENCY_BUCKETS)
+}
+
+fn histogram_with_buckets(registry: &Registry, name: &str, help: &str, labels: &[&str], buckets: &[f64]) -> HistogramVec {
+    let opts = HistogramOpts::new(name, help).namespace(NAMESPACE).buckets(buckets.to_vec());
+    let metric = HistogramVec::new(opts, labels).expect("valid metric");
+    registry.register(Box::new(metric.clone())).expect("metric registered once");
+    metric
+}
+
+impl Default for SwarmMetrics {
+    fn default() -> Self {
+        SwarmMetrics::new()
+    }
+}
+
+impl SwarmMetrics {
+    /// A fresh set of metrics in its own registry. The server uses `global()`.
+    pub fn new() -> Self {
+        let registry = Registry::new();
+        SwarmMetrics {
+            tasks_queued: counter(&registry, "tasks_queued_total", "Tasks added to a swarm's queue", &["swarm"]),
+            queue_depth: gauge(&registry, "queue_depth", "Tasks waiting in the queue", &["swarm"]),
+            tasks_completed: counter(&registry, "tasks_completed_total", "Tasks an agent completed", &["swarm", "agent"]),
+            tasks_failed: counter(&registry, "tasks_failed_total", "Tasks that failed or panicked", &["swarm", "agent"]),
+            task_duration: histogram(&registry, "task_duration_seconds", "Time an agent spent on a task", &["swarm", "agent"]),
+            task_wait: histogram(&registry, "task_wait_seconds", "Time a task waited to be dispatched", &["swarm", "lane"]),
+            tasks_starved: counter(&registry, "tasks_starved_total", "Tasks that waited too long", &["swarm", "lane"]),
+            agent_in_flight: gauge(&registry, "agent_in_flight", "Tasks an agent is running", &["swarm", "agent"]),
+            tasks_stuck: counter(&registry, "tasks_stuck_total", "Tasks found stuck", &["swarm", "agent", "action"]),
+            restarts: counter(&registry, "agent_restarts_total", "Supervised children restarted", &["supervisor", "child"]),
+            faults_injected: counter(&registry, "faults_injected_total", "Faults injected by the chaos layer", &["fault"]),
+            escalations: counter(&registry, "restart_escalations_total", "Children given up on", &["supervisor", "child"]),
+            workflow_runs: counter(&registry, "workflow_runs_total", "Workflow runs by outcome", &["workflow", "outcome"]),
+            workflow_duration: histogram(&registry, "workflow_duration_seconds", "Workflow run time", &["workflow"]),
+            llm_latency: histogram(&registry, "llm_latency_seconds", "LLM request latency", &["provider", "model", "outcome"]),
+            tokens: counter(&registry, "tokens_total", "Tokens spent", &["model", "agent", "kind"]),
+            estimated_tokens: counter(
+                &registry,
+                "estimated_tokens_total",
+                "Tokens counted locally because the provider reported none",
+                &["model", "agent", "kind"],
+            ),
+            tier_selections: counter(&registry, "model_tier_selections_total", "Model tier chosen per step", &["tier", "reason"]),
+            router_fallbacks: counter(&registry, "router_fallbacks_total", "Routed tasks served by the fallback swarm", &["router", "swarm"]),
+            shadow_runs: counter(
+                &registry,
+                "router_shadow_runs_total",
+                "Shadow runs of the second-best swarm",
+                &["router", "primary", "shadow", "outcome"],
+            ),
+            shadow_divergence: histogram_with_buckets(
+                &registry,
+                "router_shadow_divergence",
+                "Divergence between the served and shadow outputs",
+                &["router", "primary", "shadow"],
+                DIVERGENCE_BUCKETS,
+            ),
+            version_runs: counter(&registry, "agent_version_runs_total", "Runs per agent version", &["agent", "version", "outcome"]),
+            version_duration: histogram(&registry, "agent_version_duration_seconds", "Run time per agent version", &["agent", "version"]),
+            registry,
+        }
+    }
+
+    /// The process-wide metrics every component records into.
+    pub fn global() -> &'static SwarmMetrics {
+        static GLOBAL: OnceLock<SwarmMetrics> = OnceLock::new();
+        GLOBAL.get_or_init(SwarmMetrics::new)
+    }
+
+    pub fn registry(&self) -> &Registry {
+        &self.registry
+    }
+
+    pub fn task_queued(&self, swarm: &str, depth: usize) {
+        self.tasks_queued.with_label_values(&[swarm]).inc();
+        self.queue_depth.with_label_values(&[swarm]).set(depth as i64);
+    }
+
+    pub fn set_queue_depth(&self, swarm: &str, depth: usize) {
+        self.queue_depth.with_label_values(&[swarm]).set(depth as i64);
+    }
+
+    pub fn task_finished(&self, swarm: &str, agent: &str, status: &StepStatus, elapsed: Duration) {
+        let outcome = if status.is_success() { &self.tasks_completed } else { &self.tasks_failed };
+        outcome.with_label_values(&[swarm, agent]).inc();
+        self.task_duration.with_label_values(&[swarm, agent]).observe(elapsed.as_secs_f64());
+    }
+
+    /// `lane` is the agent the task was queued for, or `*` for a task any agent may run.
+    pub fn task_dispatched(&self, swarm: &str, lane: &str, waited: Duration, starved: bool) {
+        self.task_wait.with_label_values(&[swarm, lane]).observe(waited.as_secs_f64());
+        if starved {
+            self.tasks_starved.with_label_values(&[swarm, lane]).inc();
+        }
+    }
+
+    pub fn set_agent_in_flight(&self, swarm: &str, agent: &str, in_flight: usize) {
+        self.agent_in_flight.with_label_values(&[swarm, agent]).set(in_flight as i64);
+    }
+
+    /// `action` is `cancel` or `requeue`.
+    pub fn task_stuck(&self, swarm: &str, agent: &str, action: &str) {
+        self.tasks_stuck.with_label_values(&[swarm, agent, action]).inc();
+    }
+
+    pub fn agent_restarted(&self, supervisor: &str, child: &str) {
+        self.restarts.with_label_values(&[supervisor, child]).inc();
+    }
+
+    pub fn restart_escalated(&self, supervisor: &str, child: &str) {
+        self.escalations.with_label_values(&[supervisor, child]).inc();
+    }
+
+    pub fn fault_injected(&self, fault: &str) {
+        self.faults_injected.with_label_values(&[fault]).inc();
+    }
+
+    /// `outcome` is a short fixed word such as `completed`, `rejected`, or `failed`.
+    pub fn workflow_finished(&self, workflow: &str, outcome: &str, elapsed: Duration) {
+        self.workflow_runs.with_label_values(&[workflow, outcome]).inc();
+        self.workflow_duration.with_label_values(&[workflow]).observe(elapsed.as_secs_f64());
+    }
+
+    pub fn llm_call(&self, provider: &str, model: &str, succeeded: bool, elapsed: Duration) {
+        let outcome = if succeeded { "ok" } else { "error" };
+        self.llm_latency.with_label_values(&[provider, model, outcome]).observe(elapsed.as_secs_f64());
+    }
+
+    /// Estimated usage counts towards `tokens_total` like reported usage, and also towards
+    /// `estimated_tokens_total`, so dashboards can show how much of the spend is a local count.
+    pub fn tokens(&self, model: &str, agent: &str, usage: &UsageInfo) {
+        let prompt = usage.prompt_tokens.max(0) as u64;
+        let completion = usage
+            .completion_tokens
+            .unwrap_or(usage.total_tokens - usage.prompt_tokens)
+            .max(0) as u64;
+        self.tokens.with_label_values(&[model, agent, "prompt"]).inc_by(prompt);
+        self.tokens.with_label_values(&[model, agent, "completion"]).inc_by(completion);
+        if usage.estimated {
+            self.estimated_tokens.with_label_values(&[model, agent, "prompt"]).inc_by(prompt);
+            self.estimated_tokens.with_label_values(&[model, agent, "completion"]).inc_by(completion);
+        }
+    }
+
+    pub fn model_tier_selected(&self, tier: &str, reason: &str) {
+        self.tier_selections.with_label_values(&[tier, reason]).inc();
+    }
+
+    /// `swarm` is the selected swarm that failed, not the fallback that served the task.
+    pub fn router_fallback(&self, router: &str, swarm: &str) {
+        self.router_fallbacks.with_label_values(&[router, swarm]).inc();
+    }
+
+    /// `divergence` is `None` when the shadow swarm failed.
+    pub fn shadow_run(&self, router: &str, primary: &str, shadow: &str, divergence: Option<f64>) {
+        let outcome = if divergence.is_some() { "ok" } else { "error" };
+        self.shadow_runs.with_label_values(&[router, primary, shadow, outcome]).inc();
+        if let Some(divergence) = divergence {
+            self.shadow_divergence.with_label_values(&[router, primary, shadow]).observe(divergence);
+        }
+    }
+
+    pub fn agent_version_run(&self, agent: &str, version: &str, succeeded: bool, elapsed: Duration) {
+        let outcome = if succeeded { "ok" } else { "error" };
+        self.version_runs.with_label_values(&[agent, version, outcome]).inc();
+        self.version_duration.with_label_values(&[agent, version]).observe(elapsed.as_secs_f64());
+    }
+
+    /// The Prometheus text exposition of every metric.
+    pub fn render(&self) -> String {
+        let mut buffer = Vec::new();
+        if let Err(err) = TextEncoder::new().encode(&self.registry.gather(), &mut buffer) {
+            error!("Failed to encode metrics: {}", err);
+        }
+        String::from_utf8(buffer).unwrap_or_default()
+    }
+
+    /// Serve `GET /metrics`. Returns the status, content type, and body.
+    pub fn handle_http(&self, method: &str, path: &str) -> (u16, &'static str, String) {
+        match (method, path.trim_end_matches('/')) {
+            ("GET", "/metrics") => (200, "text/plain; version=0.0.4", self.render()),
+            (method, path) => (404, PROBLEM_JSON, Problem::no_route(method, path).to_json()),
+        }
+    }
+}
+
+fn main() {
+    let metrics = SwarmMetrics::global();
+    metrics.task_queued("Task-Queue-Swarm", 1);
+    metrics.task_finished("Task-Queue-Swarm", "Agent1", &StepStatus::Succeeded, Duration::from_millis(1200));
+    metrics.tokens("gpt-4o-mini", "Agent1", &UsageInfo::new(120, 180));
+    println!("{}", metrics.handle_http("GET", "/metrics").2);
+}
+```
+
+### Notes
+* `global()` is shared by every swarm in the process; swarms are told apart by their `swarm` or `workflow` label, so give concurrently running swarms distinct names.
+* Token counts are only as complete as the provider's `usage` reports; calls that report none add nothing.
@@ -0,0 +1,517 @@
+### Overview
+Groq, Together, and OpenRouter all expose an OpenAI-compatible `/chat/completions` endpoint, which is how
+the Python examples reach them (`openai_api_base="https://api.groq.com/openai/v1"`). Rather than baking
+those URLs into every example, the Rust port has a single `OpenAiCompatProvider` configured with a base URL,
+API key, and model. Response parsing is lenient about the ways these services deviate from OpenAI:
+non-standard finish reasons, `usage` reported under a vendor key or omitted entirely, and `content: null`
+on tool-call turns.
+
+`stream_chat` streams the response instead and watches it for `StopConditions`. Stop sequences are also
+sent as the request's `stop`; on the first client-side match the HTTP stream is dropped, which cancels the
+request, so the model stops spending tokens as soon as it writes the stopping token.
+
+Many providers send no usage on a stream, and none do on a stream dropped early. `stream_chat` then
+counts the prompt and the streamed output with a tokenizer and returns the result as `estimated` usage,
+so metrics and cost reports still see the call.
+
+With `with_callbacks` set, `stream_chat` also reports each token and each completed tool call to the
+callbacks while the stream is still open (see `callbacks`). The tokenizer is the model's BPE when `tiktoken-rs` knows
+the model, the heuristic otherwise, or whatever `with_tokenizer` sets.
+
+### Rust Code
+```rust
+use crate::swarms::integrations::rest::shared_client;
+use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ContentItem, UsageInfo};
+#[cfg(feature = "tools")]
+use crate::swarms::schemas::base_schemas::ToolCall;
+#[cfg(feature = "tools")]
+use crate::swarms::structs::callbacks::RunCallbacks;
+use crate::swarms::structs::health::{ComponentKind, HealthCheck};
+use crate::swarms::structs::usage::UsageScope;
+use crate::swarms::text::tokenizer::{default_tokenizer, TiktokenTokenizer, Tokenizer};
+#[cfg(feature = "tools")]
+use crate::swarms::text::stop_conditions::{StopConditions, PROVIDER_STOP_LIMIT};
+#[cfg(feature = "tools")]
+use crate::swarms::tools::tool_call_stream::{ReadyToolCall, SseDecoder, SseEvent, ToolCallAssembler};
+use crate::swarms::utils::chat_provider::{record_call, ChatProvider};
+pub use crate::swarms::utils::chat_provider::{ChatCompletion, FinishReason, ProviderError};
+use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+use async_trait::async_trait;
+#[cfg(feature = "tools")]
+use futures::StreamExt;
+use log::{debug, warn};
+use reqwest::Client;
+use serde_json::{json, Value as JsonValue};
+use std::env;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Instant;
+
+pub const GROQ_API_BASE: &str = "https://api.groq.com/openai/v1";
+pub const TOGETHER_API_BASE: &str = "https://api.together.xyz/v1";
+pub const OPENROUTER_API_BASE: &str = "https://openrouter.ai/api/v1";
+
+/// Tokens OpenAI's chat format adds around each message, and before the reply.
+const MESSAGE_OVERHEAD_TOKENS: usize = 3;
+const REPLY_PRIMING_TOKENS: usize = 3;
+
+/// Any service that speaks the OpenAI chat completions protocol.
+#[derive(Clone)]
+pub struct OpenAiCompatProvider {
+    pub name: String,
+    pub base_url: String,
+    pub api_key: String,
+    pub model: String,
+    /// Sampling temperature for every call; overrides the request's when set.
+    pub temperature: Option<f64>,
+    /// The agent this client serves, for the `agent` label of `swarms_tokens_total`.
+    pub agent: Option<String>,
+    /// Where each call's token usage is recorded for cost reports.
+    pub usage: Option<UsageScope>,
+    /// Counts tokens for streams that report no usage. `None` picks one from the model name.
+    tokenizer: Option<Arc<dyn Tokenizer>>,
+    /// Hears each streamed token and completed tool call.
+    #[cfg(feature = "tools")]
+    callbacks: Option<Arc<dyn RunCallbacks>>,
+    client: Client,
+}
+
+impl fmt::Debug for OpenAiCompatProvider {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OpenAiCompatProvider")
+            .field("name", &self.name)
+            .field("base_url", &self.base_url)
+            .field("model", &self.model)
+            .field("temperature", &self.temperature)
+            .field("agent", &self.agent)
+            .field("usage", &self.usage)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OpenAiCompatProvider {
+    pub fn new(
+        name: impl Into<String>,
+        base_url: impl Into<String>,
+        api_key: impl Into<String>,
+        model: impl Into<String>,
+    ) -> Self {
+        OpenAiCompatProvider {
+            name: name.into(),
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            api_key: api_key.into(),
+            model: model.into(),
+            temperature: None,
+            agent: None,
+            usage: None,
+            tokenizer: None,
+            #[cfg(feature = "tools")]
+            callbacks: None,
+            client: shared_client(),
+        }
+    }
+
+    fn from_env(name: &str, base_url: &str, key_var: &str, model: &str) -> Result<Self, ProviderError> {
+        let api_key = env::var(key_var).map_err(|_| ProviderError::MissingApiKey(key_var.to_string()))?;
+        Ok(Self::new(name, base_url, api_key, model))
+    }
+
+    /// Groq, keyed by `GROQ_API_KEY`.
+    pub fn groq(model: &str) -> Result<Self, ProviderError> {
+        Self::from_env("groq", GROQ_API_BASE, "GROQ_API_KEY", model)
+    }
+
+    /// Together AI, keyed by `TOGETHER_API_KEY`.
+    pub fn together(model: &str) -> Result<Self, ProviderError> {
+        Self::from_env("together", TOGETHER_API_BASE, "TOGETHER_API_KEY", model)
+    }
+
+    /// OpenRouter, keyed by `OPENROUTER_API_KEY`.
+    pub fn openrouter(model: &str) -> Result<Self, ProviderError> {
+        Self::from_env("openrouter", OPENROUTER_API_BASE, "OPENROUTER_API_KEY", model)
+    }
+
+    /// Sample every call at `temperature`, whatever the request asks for.
+    pub fn with_temperature(mut self, temperature: f64) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Attribute this client's token spend to `agent` in the metrics.
+    pub fn for_agent(mut self, agent: impl Into<String>) -> Self {
+        self.agent = Some(agent.into());
+        self
+    }
+
+    /// Record every call's token usage into `scope`.
+    pub fn track_usage(mut self, scope: UsageScope) -> Self {
+        self.usage = Some(scope);
+        self
+    }
+
+    /// Count tokens with `tokenizer` when a stream reports no usage.
+    pub fn with_tokenizer(mut self, tokenizer: Arc<dyn Tokenizer>) -> Self {
+        self.tokenizer = Some(tokenizer);
+        self
+    }
+
+    /// Send streamed tokens and tool calls to `callbacks` as they arrive, under the `for_agent` name
+    /// (the model name when unset). Typically `agent.callbacks()` from inside a completion.
+    #[cfg(feature = "tools")]
+    pub fn with_callbacks(mut self, callbacks: Arc<dyn RunCallbacks>) -> Self {
+        self.callbacks = Some(callbacks);
+        self
+    }
+
+    pub fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities::lookup(&self.name, &self.model)
+    }
+
+    /// Build the JSON body for `/chat/completions`. The provider's model, and its temperature when set,
+    /// override the request's.
+    pub fn request_body(&self, request: &ChatCompletionRequest) -> JsonValue {
+        let messages: Vec<JsonValue> = request
+            .messages
+            .iter()
+            .map(|message| {
+                // Text-only messages are sent as a plain string; several compatible
+                // providers reject the array form unless the model has vision.
+                let content = if message.content.iter().all(|item| matches!(item, ContentItem::Text { .. })) {
+                    let text: Vec<&str> = message
+                        .content
+                        .iter()
+                        .filter_map(|item| match item {
+                            ContentItem::Text { text } => Some(text.as_str()),
+                            _ => None,
+                        })
+                        .collect();
+                    json!(text.join("\n"))
+                } else {
+                    JsonValue::Array(
+                        message
+                            .content
+                            .iter()
+                            .map(|item| match item {
+                                ContentItem::Text { text } => json!({ "type": "text", "text": text }),
+                                ContentItem::ImageUrl { image_url } => {
+                                    json!({ "type": "image_url", "image_url": { "url": image_url.url } })
+                                }
+                            })
+                            .collect(),
+                    )
+                };
+                json!({ "role": message.role, "content": content })
+            })
+            .collect();
+
+        let mut body = json!({ "model": self.model, "messages": messages });
+        let fields = body.as_object_mut().expect("body is an object");
+        if let Some(temperature) = self.temperature.or(request.temperature) {
+            fields.insert("temperature".to_string(), json!(temperature));
+        }
+        if let Some(top_p) = request.top_p {
+            fields.insert("top_p".to_string(), json!(top_p));
+        }
+        if let Some(max_tokens) = request.max_tokens {
+            fields.insert("max_tokens".to_string(), json!(max_tokens));
+        }
+        if let Some(stream) = request.stream {
+            fields.insert("stream".to_string(), json!(stream));
+        }
+        if let Some(stop) = request.stop.as_ref().filter(|stop| !stop.is_empty()) {
+            fields.insert("stop".to_string(), json!(stop));
+        }
+        if let JsonValue::Object(options) = request.provider_options_json() {
+            fields.extend(options);
+        }
+        body
+    }
+
+    /// Send a non-streaming chat completion request.
+    pub async fn chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+        let started = Instant::now();
+        let result = self.send_chat(request).await;
+        self.record_call(&result, started);
+        result
+    }
+
+    /// Latency, token, and usage accounting shared by `chat` and `stream_chat`.
+    fn record_call(&self, result: &Result<ChatCompletion, ProviderError>, started: Instant) {
+        record_call(&self.name, &self.model, self.agent.as_deref(), self.usage.as_ref(), result, started);
+    }
+
+    async fn send_chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+        let mut request = request.clone();
+        request.stream = Some(false);
+        self.capabilities().adapt(&mut request);
+
+        let response = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&self.request_body(&request))
+            .send()
+            .await?;
+
+        let status = response.status();
+        let body = response.text().await?;
+        if !status.is_success() {
+            return Err(ProviderError::Status { status: status.as_u16(), body });
+        }
+        let value: JsonValue =
+            serde_json::from_str(&body).map_err(|e| ProviderError::InvalidResponse(e.to_string()))?;
+        parse_chat_completion(&value)
+    }
+
+    /// Stream a chat completion, ending it at the first of `stop`'s sequences or patterns.
+    #[cfg(feature = "tools")]
+    pub async fn stream_chat(
+        &self,
+        request: &ChatCompletionRequest,
+        stop: &StopConditions,
+    ) -> Result<ChatCompletion, ProviderError> {
+        let started = Instant::now();
+        let result = self.send_stream(request, stop).await;
+        self.record_call(&result, started);
+        result
+    }
+
+    #[cfg(feature = "tools")]
+    async fn send_stream(&self, request: &ChatCompletionRequest, stop: &StopConditions) -> Result<ChatCompletion, ProviderError> {
+        let mut request = request.clone();
+        request.stream = Some(true);
+        let mut sequences = request.stop.take().unwrap_or_default();
+        for sequence in stop.provider_sequences() {
+            if !sequences.contains(&sequence) {
+                sequences.push(sequence);
+            }
+        }
+        sequences.truncate(PROVIDER_STOP_LIMIT);
+        request.stop = Some(sequences);
+        self.capabilities().adapt(&mut request);
+
+        let response = self
+            .client
+            .post(format!("{}/chat/completions", self.base_url))
+            .bearer_auth(&self.api_key)
+            .json(&self.request_body(&request))
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ProviderError::Status { status: status.as_u16(), body: response.text().await? });
+        }
+
+        let mut decoder = SseDecoder::new();
+        let mut assembler = ToolCallAssembler::new();
+        let mut matcher = stop.matcher();
+        let mut usage = None;
+        let mut calls = Vec::new();
+        let mut emitted = 0;
+        let mut body = response.bytes_stream();
+        'stream: while let Some(bytes) = body.next().await {
+            let events = decoder.push(&bytes?).map_err(|e| ProviderError::InvalidResponse(e.to_string()))?;
+            for event in events {
+                let SseEvent::Chunk(chunk) = event else {
+                    break 'stream;
+                };
+                usage = parse_usage(&chunk).or(usage);
+                let ready = assembler.push_chunk(&chunk);
+                self.emit_tool_calls(&ready);
+                calls.extend(ready);
+                let delta = chunk["choices"][0]["delta"]["content"].as_str().unwrap_or_default();
+                let found = matcher.push(delta);
+                // Held back while it could be the start of a stop sequence, so a sequence is never half shown
+                self.emit_token(matcher.settled(), &mut emitted);
+                if let Some(found) = found {
+                    debug!("Stopping {} stream on {:?} at byte {}", self.name, found.matched, found.start);
+                    break 'stream;
+                }
+            }
+        }
+        // Dropping the body closes the connection, which tells the provider to stop generating.
+        drop(body);
+        // A tail held back at the end of the stream turned out not to be a stop sequence.
+        self.emit_token(matcher.truncated(), &mut emitted);
+
+        let stopped_by = matcher.stopped().cloned();
+        let (finish_reason, tool_calls) = match stopped_by {
+            // Tool calls cut off mid-stream have incomplete arguments.
+            Some(_) => (Some(FinishReason::Stop), Vec::new()),
+            None => {
+                // Calls completed mid-stream were returned by `push_chunk`; `finish` returns the rest.
+                let remaining = assembler.finish().map_err(|e| ProviderError::InvalidResponse(e.to_string()))?;
+                self.emit_tool_calls(&remaining);
+                calls.extend(remaining);
+                calls.sort_by_key(|call| call.index);
+                (assembler.finish_reason().map(FinishReason::parse), calls.iter().map(ReadyToolCall::to_openai).collect())
+            }
+        };
+        let mut completion =
+            ChatCompletion { content: matcher.truncated().to_string(), finish_reason, tool_calls, usage, stopped_by };
+        if completion.usage.is_none() {
+            completion.usage = Some(self.estimate_usage(&request, &completion));
+        }
+        Ok(completion)
+    }
+
+    #[cfg(feature = "tools")]
+    fn callback_label(&self) -> &str {
+        self.agent.as_deref().unwrap_or(&self.model)
+    }
+
+    // Report the text of `streamed` past the `emitted` bytes already reported as tokens.
+    #[cfg(feature = "tools")]
+    fn emit_token(&self, streamed: &str, emitted: &mut usize) {
+        let Some(callbacks) = &self.callbacks else {
+            return;
+        };
+        let token = streamed.get(*emitted..).unwrap_or_default();
+        if !token.is_empty() {
+            callbacks.on_token(self.callback_label(), token);
+            *emitted += token.len();
+        }
+    }
+
+    #[cfg(feature = "tools")]
+    fn emit_tool_calls(&self, calls: &[ReadyToolCall]) {
+        let Some(callbacks) = &self.callbacks else {
+            return;
+        };
+        for call in calls {
+            let call = ToolCall { id: call.id.clone(), name: call.name.clone(), arguments: call.arguments.clone() };
+            callbacks.on_tool_call(self.callback_label(), &call);
+        }
+    }
+
+    /// Count `request`'s prompt and `completion`'s output locally, for responses that came without usage.
+    /// Only text is counted; images in the prompt are not.
+    pub fn estimate_usage(&self, request: &ChatCompletionRequest, completion: &ChatCompletion) -> UsageInfo {
+        let tokenizer = self.tokenizer.clone().unwrap_or_else(|| match TiktokenTokenizer::for_model(&self.model) {
+            Ok(bpe) => Arc::new(bpe) as Arc<dyn Tokenizer>,
+            Err(_) => default_tokenizer(),
+        });
+        let mut prompt = REPLY_PRIMING_TOKENS;
+        for message in &request.messages {
+            prompt += MESSAGE_OVERHEAD_TOKENS + tokenizer.count_tokens(&message.role);
+            for item in &message.content {
+                if let ContentItem::Text { text } = item {
+                    prompt += tokenizer.count_tokens(text);
+                }
+            }
+        }
+        for tool in request.tools.iter().flatten() {
+            prompt += tokenizer.count_tokens(&tool.to_string());
+        }
+
+        let mut output = tokenizer.count_tokens(&completion.content);
+        for call in &completion.tool_calls {
+            let function = &call["function"];
+            output += tokenizer.count_tokens(function["name"].as_str().unwrap_or_default());
+            output += tokenizer.count_tokens(function["arguments"].as_str().unwrap_or_default());
+        }
+        UsageInfo::estimated(prompt as i32, output as i32)
+    }
+
+    /// Convenience wrapper: single user prompt, returns the text content.
+    pub async fn complete(&self, system_prompt: Option<&str>, prompt: &str) -> Result<String, ProviderError> {
+        ChatProvider::complete(self, system_prompt, prompt).await
+    }
+
+    /// Check that the service is reachable and accepts the API key, without spending tokens.
+    pub async fn ping(&self) -> Result<(), ProviderError> {
+        let response = self
+            .client
+            .get(format!("{}/models", self.base_url))
+            .bearer_auth(&self.api_key)
+            .send()
+            .await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ProviderError::Status { status: status.as_u16(), body: response.text().await? });
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl ChatProvider for OpenAiCompatProvider {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn model(&self) -> &str {
+        &self.model
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        OpenAiCompatProvider::capabilities(self)
+    }
+
+    async fn chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+        OpenAiCompatProvider::chat(self, request).await
+    }
+}
+
+#[async_trait]
+impl HealthCheck for OpenAiCompatProvider {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn kind(&self) -> ComponentKind {
+        ComponentKind::Provider
+    }
+
+    async fn check(&self) -> Result<Option<String>, String> {
+        self.ping().await.map(|_| Some(self.model.clone())).map_err(|err| err.to_string())
+    }
+}
+
+/// Parse an OpenAI-shaped response body, tolerating the quirks of compatible providers.
+pub fn parse_chat_completion(value: &JsonValue) -> Result<ChatCompletion, ProviderError> {
+    let choice = value["choices"]
+        .get(0)
+        .ok_or_else(|| ProviderError::InvalidResponse("response has no choices".to_string()))?;
+    let message = &choice["message"];
+
+    // `content` is null on tool-call turns; some providers put plain text under `text`.
+    let content = message["content"]
+        .as_str()
+        .or_else(|| choice["text"].as_str())
+        .unwrap_or_default()
+        .to_string();
+    let tool_calls = message["tool_calls"].as_array().cloned().unwrap_or_default();
+    let finish_reason = choice["finish_reason"].as_str().map(FinishReason::parse);
+
+    Ok(ChatCompletion {
+        content,
+        finish_reason,
+        tool_calls,
+        usage: parse_usage(value),
+        stopped_by: None,
+    })
+}
+
+/// Read `usage`, falling back to Groq's `x_groq.usage`. Returns `None` when neither is present.
+pub fn parse_usage(value: &JsonValue) -> Option<UsageInfo> {
+    UsageInfo::from_openai_json(value)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), ProviderError> {
+    let provider = OpenAiCompatProvider::groq("llama-3.3-70b-versatile")?;
+    let answer = provider
+        .complete(Some("You are a helpful assistant."), "What is the capital of France?")
+        .await?;
+    println!("{}", answer);
+    Ok(())
+}
+```
+
+### Notes
+* `stream_chat` needs the `tools` feature, since streamed chunks are decoded and assembled by `swarms::tools::tool_call_stream`.
+* Most providers only report usage on a stream that runs to the end, so the usage of a stream stopped early is almost always an estimate. Estimates count towards `swarms_tokens_total` and are also counted in `swarms_estimated_tokens_total`; the usage log marks their records `estimated`.
+* Estimates from the heuristic tokenizer can be off by a quarter or more for code and non-English text. Give non-OpenAI models their own tokenizer with `with_tokenizer` where cost reports need to be close.
+* Callbacks only hear streamed calls, so `with_callbacks` needs the `tools` feature like `stream_chat`. A tool call reported mid-stream is not withdrawn if a stop condition then ends the stream, although the returned completion drops it. Streamed text that could be the start of a stop sequence reaches `on_token` only once the next delta rules that out, so a token can arrive a delta late but no part of a stop sequence is ever reported.
+* `chat` applies `ProviderCapabilities::downgrade` before sending, so requests that ask for unsupported features (e.g. JSON schema on Groq) are downgraded rather than rejected by the provider.
@@ -0,0 +1,329 @@
+### Overview
+There is no Python counterpart to this module. `litellm` hides provider differences at runtime by
+retrying or silently dropping unsupported parameters; the Rust port makes those differences explicit.
+Each provider/model pair is described by a `ProviderCapabilities` value, and the agent runtime asks it
+up front whether it can inline images, force tool calls, or use native JSON mode. When a capability is
+missing the runtime either downgrades to a prompt-based fallback or fails with a clear error.
+
+### Rust Code
+```rust
+use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem, ResponseFormat, ToolChoice};
+use log::warn;
+use serde_json::Value as JsonValue;
+use std::fmt;
+
+/// What a provider/model pair supports.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProviderCapabilities {
+    pub provider: String,
+    pub model: String,
+    pub vision: bool,
+    pub tools: bool,
+    /// `response_format: json_object`.
+    pub json_mode: bool,
+    /// `response_format: json_schema` with constrained decoding.
+    pub json_schema: bool,
+    pub streaming: bool,
+    pub max_context_tokens: usize,
+}
+
+impl ProviderCapabilities {
+    /// A conservative descriptor: text only, streaming, 8k context.
+    pub fn text_only(provider: impl Into<String>, model: impl Into<String>) -> Self {
+        ProviderCapabilities {
+            provider: provider.into(),
+            model: model.into(),
+            vision: false,
+            tools: false,
+            json_mode: false,
+            json_schema: false,
+            streaming: true,
+            max_context_tokens: 8192,
+        }
+    }
+
+    /// Look up the known capabilities for a provider/model pair.
+    ///
+    /// Unknown models fall back to `text_only` so the runtime degrades instead of sending
+    /// parameters the provider may reject.
+    pub fn lookup(provider: &str, model: &str) -> Self {
+        let mut caps = Self::text_only(provider, model);
+        let model_lc = model.to_lowercase();
+        match provider.to_lowercase().as_str() {
+            "openai" => {
+                caps.tools = true;
+                caps.json_mode = true;
+                if model_lc.starts_with("gpt-4o") || model_lc.starts_with("o1") || model_lc.starts_with("o3") {
+                    caps.vision = !model_lc.starts_with("o1-mini");
+                    caps.json_schema = true;
+                    caps.max_context_tokens = 128_000;
+                } else if model_lc.starts_with("gpt-4-turbo") {
+                    caps.vision = true;
+                    caps.max_context_tokens = 128_000;
+                } else if model_lc.starts_with("gpt-3.5") {
+                    caps.max_context_tokens = 16_385;
+                }
+            }
+            "anthropic" => {
+                caps.tools = true;
+                caps.vision = model_lc.starts_with("claude-3");
+                caps.max_context_tokens = 200_000;
+            }
+            "groq" => {
+                caps.tools = true;
+                caps.json_mode = true;
+                caps.vision = model_lc.contains("vision");
+                caps.max_context_tokens = if model_lc.contains("llama-3.1") || model_lc.contains("llama-3.3") {
+                    128_000
+                } else {
+                    8192
+                };
+            }
+            "together" | "openrouter" => {
+                caps.tools = true;
+                caps.json_mode = true;
+                caps.max_context_tokens = 32_768;
+            }
+            _ => {}
+        }
+        caps
+    }
+
+    pub fn supports(&self, capability: Capability) -> bool {
+        match capability {
+            Capability::Vision => self.vision,
+            Capability::Tools => self.tools,
+            Capability::JsonMode => self.json_mode,
+            Capability::JsonSchema => self.json_schema,
+            Capability::Streaming => self.streaming,
+        }
+    }
+
+    /// Fail with a descriptive error if `capability` is missing.
+    pub fn require(&self, capability: Capability) -> Result<(), CapabilityError> {
+        if self.supports(capability) {
+            Ok(())
+        } else {
+            Err(CapabilityError::Missing {
+                provider: self.provider.clone(),
+                model: self.model.clone(),
+                capability,
+            })
+        }
+    }
+
+    /// Rewrite `request` so that it only uses features this provider supports.
+    ///
+    /// Returns the fallbacks that were applied so the caller can add the matching
+    /// instructions to the prompt (e.g. "respond only with JSON matching ...").
+    pub fn downgrade(&self, request: &mut ChatCompletionRequest) -> Vec<Downgrade> {
+        let mut applied = Vec::new();
+
+        if request.stream == Some(true) && !self.streaming {
+            request.stream = Some(false);
+            applied.push(Downgrade::StreamingDisabled);
+        }
+
+        if request.tools.is_some() && !self.tools {
+            request.tools = None;
+            request.tool_choice = None;
+            applied.push(Downgrade::PromptBasedTools);
+        } else if matches!(request.tool_choice, Some(ToolChoice::Function(_)) | Some(ToolChoice::Required))
+            && !self.tools
+        {
+            request.tool_choice = None;
+            applied.push(Downgrade::PromptBasedTools);
+        }
+
+        match request.response_format.clone() {
+            Some(ResponseFormat::JsonSchema { schema, .. }) if !self.json_schema => {
+                if self.json_mode {
+                    request.response_format = Some(ResponseFormat::JsonObject);
+                } else {
+                    request.response_format = None;
+                }
+                applied.push(Downgrade::PromptBasedJson { schema: Some(schema) });
+            }
+            Some(ResponseFormat::JsonObject) if !self.json_mode => {
+                request.response_format = None;
+                applied.push(Downgrade::PromptBasedJson { schema: None });
+            }
+            _ => {}
+        }
+
+        for downgrade in &applied {
+            warn!("{}/{}: {}", self.provider, self.model, downgrade);
+        }
+        applied
+    }
+
+    /// `downgrade`, then add the instructions for each prompt-based fallback to the system message, so a
+    /// stripped schema or tool list still reaches the model. Providers call this before sending a request.
+    pub fn adapt(&self, request: &mut ChatCompletionRequest) -> Vec<Downgrade> {
+        let tools = request.tools.clone().unwrap_or_default();
+        let tool_choice = request.tool_choice.clone();
+        let applied = self.downgrade(request);
+        let instructions: Vec<String> =
+            applied.iter().filter_map(|downgrade| downgrade.instructions(&tools, tool_choice.as_ref())).collect();
+        if !instructions.is_empty() {
+            add_system_text(request, instructions.join("\n\n"));
+        }
+        applied
+    }
+}
+
+// Append `text` to the first system message, or start the conversation with one
+fn add_system_text(request: &mut ChatCompletionRequest, text: String) {
+    match request.messages.iter_mut().find(|message| message.role == "system") {
+        Some(system) => system.content.push(ContentItem::Text { text }),
+        None => request.messages.insert(0, ChatMessageInput::new("system".to_string(), vec![ContentItem::Text { text }])),
+    }
+}
+
+/// A single provider feature the runtime may depend on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Capability {
+    Vision,
+    Tools,
+    JsonMode,
+    JsonSchema,
+    Streaming,
+}
+
+impl fmt::Display for Capability {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Capability::Vision => "vision (image inputs)",
+            Capability::Tools => "tool calling",
+            Capability::JsonMode => "JSON mode",
+            Capability::JsonSchema => "JSON schema structured outputs",
+            Capability::Streaming => "streaming",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A fallback applied by `ProviderCapabilities::downgrade`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Downgrade {
+    StreamingDisabled,
+    /// Tools must be described in the system prompt and parsed from text.
+    PromptBasedTools,
+    /// JSON must be requested in the prompt and validated after the fact.
+    PromptBasedJson { schema: Option<serde_json::Value> },
+}
+
+impl Downgrade {
+    /// The prompt text that stands in for the stripped feature. `tools` and `tool_choice` are the request's
+    /// before the downgrade. `None` when nothing needs saying, as for `StreamingDisabled`.
+    pub fn instructions(&self, tools: &[JsonValue], tool_choice: Option<&ToolChoice>) -> Option<String> {
+        match self {
+            Downgrade::StreamingDisabled => None,
+            Downgrade::PromptBasedTools => {
+                let mut text = String::from(
+                    "To call a tool, reply with only a JSON object of the form {\"name\": \"<tool name>\", \"arguments\": {...}}.",
+                );
+                match tool_choice {
+                    Some(ToolChoice::Function(name)) => text.push_str(&format!(" You must call the tool `{}`.", name)),
+                    Some(ToolChoice::Required) => text.push_str(" You must call one of the tools."),
+                    _ => text.push_str(" Otherwise reply normally."),
+                }
+                if !tools.is_empty() {
+                    text.push_str("\nAvailable tools:");
+                    for tool in tools {
+                        let function = tool.get("function").unwrap_or(tool);
+                        let name = function["name"].as_str().unwrap_or("unnamed");
+                        let description = function["description"].as_str().unwrap_or_default();
+                        text.push_str(&format!("\n- {}: {} Parameters: {}", name, description, function["parameters"]));
+                    }
+                }
+                Some(text)
+            }
+            Downgrade::PromptBasedJson { schema: Some(schema) } => {
+                Some(format!("Reply with only a JSON object that matches this JSON schema:\n{}", schema))
+            }
+            Downgrade::PromptBasedJson { schema: None } => Some("Reply with only a valid JSON object.".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Downgrade {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Downgrade::StreamingDisabled => write!(f, "streaming not supported, falling back to a single response"),
+            Downgrade::PromptBasedTools => write!(f, "native tool calling not supported, falling back to prompt-based tools"),
+            Downgrade::PromptBasedJson { .. } => write!(f, "native JSON output not supported, falling back to prompt-based JSON"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CapabilityError {
+    Missing {
+        provider: String,
+        model: String,
+        capability: Capability,
+    },
+    ContextTooLarge {
+        provider: String,
+        model: String,
+        requested: usize,
+        max: usize,
+    },
+}
+
+impl fmt::Display for CapabilityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CapabilityError::Missing { provider, model, capability } => write!(
+                f,
+                "model '{}' on provider '{}' does not support {}",
+                model, provider, capability
+            ),
+            CapabilityError::ContextTooLarge { provider, model, requested, max } => write!(
+                f,
+                "model '{}' on provider '{}' accepts at most {} context tokens, {} requested",
+                model, provider, max, requested
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CapabilityError {}
+
+impl ProviderCapabilities {
+    /// Check a planned context size against the model's window.
+    pub fn check_context(&self, requested: usize) -> Result<(), CapabilityError> {
+        if requested <= self.max_context_tokens {
+            Ok(())
+        } else {
+            Err(CapabilityError::ContextTooLarge {
+                provider: self.provider.clone(),
+                model: self.model.clone(),
+                requested,
+                max: self.max_context_tokens,
+            })
+        }
+    }
+}
+
+fn main() {
+    let caps = ProviderCapabilities::lookup("groq", "llama-3.3-70b-versatile");
+
+    // Images cannot be downgraded, so the runtime fails early with a clear message
+    if let Err(err) = caps.require(Capability::Vision) {
+        println!("{}", err);
+    }
+
+    let mut request = ChatCompletionRequest::new("llama-3.3-70b-versatile".to_string(), vec![])
+        .with_json_schema("answer", serde_json::json!({ "type": "object" }));
+    for downgrade in caps.adapt(&mut request) {
+        println!("applied: {}", downgrade);
+    }
+}
+```
+
+### Notes
+* The capability table is static and intentionally conservative; extend `lookup` as providers add features.
+* Providers call `adapt` rather than `downgrade`: it also writes each fallback's instructions (the JSON schema, the tool list, a forced tool) into the system message, so a stripped feature is asked for in the prompt instead of silently dropped.
+* Vision has no downgrade path: an agent that receives images on a text-only model should call `require(Capability::Vision)` and surface the error.
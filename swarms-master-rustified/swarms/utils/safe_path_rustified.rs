@@ -0,0 +1,138 @@
+### Overview
+Python's `sanitize_file_path` replaces `<>:"/\|?*` with `_` and nothing else, and the Rust port added
+paths together with `format!("{}/{}", ...)`. Names that pass that check can still fail, or land somewhere
+unexpected, on one of the platforms the workspace runs on:
+
+* `CON`, `NUL`, `COM1`, and the other DOS device names are not files on Windows, with or without an
+  extension (`nul.json` opens the null device).
+* Windows silently drops trailing dots and spaces, so `report.` and `report` are the same file.
+* Most filesystems limit a name to 255 bytes, and agent names and run ids can be longer.
+* `é` typed on macOS (decomposed) and on Linux (precomposed) are different byte strings, so the same name
+  saved on one machine was not found on the other.
+* `a\b` is two components on Windows and one name on Linux.
+
+`sanitize_file_name` turns any string into one name that is valid everywhere. `safe_join` joins a relative
+path to a base directory with `PathBuf::join`, splitting on both separators and sanitizing each component.
+It refuses absolute paths, drive prefixes, and `..`. The workspace (`TenantWorkspace::resolve`), artifacts,
+the agent store, and the checkpoint store build their paths with these two functions.
+
+### Rust Code
+```rust
+use std::fmt;
+use std::path::{Path, PathBuf};
+use unicode_normalization::UnicodeNormalization;
+
+/// The longest file name, in bytes, that ext4, APFS, and NTFS all accept.
+pub const MAX_NAME_BYTES: usize = 255;
+
+const RESERVED_NAMES: [&str; 22] = [
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8", "COM9", "LPT1",
+    "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PathError {
+    /// Absolute, drive-prefixed, or climbing out with `..`.
+    Escape(String),
+    /// No components left to join.
+    Empty,
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PathError::Escape(path) => write!(f, "path '{}' leaves its base directory", path),
+            PathError::Empty => write!(f, "path is empty"),
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// Whether Windows treats `name` as a device rather than a file. The extension does not matter.
+pub fn is_reserved_name(name: &str) -> bool {
+    let stem = name.split('.').next().unwrap_or(name).trim_end_matches(' ');
+    RESERVED_NAMES.iter().any(|reserved| stem.eq_ignore_ascii_case(reserved))
+}
+
+fn is_forbidden(c: char) -> bool {
+    c.is_control() || matches!(c, '<' | '>' | ':' | '"' | '/' | '\\' | '|' | '?' | '*')
+}
+
+// The longest prefix of `text` that fits in `max` bytes without splitting a character
+fn truncate_bytes(text: &str, max: usize) -> &str {
+    let mut end = max.min(text.len());
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// `name` as a single file name that is valid on Windows, macOS, and Linux:
+///
+/// * NFC-normalized;
+/// * separators, control characters, and `<>:"|?*` replaced with `_`;
+/// * trailing dots and spaces removed;
+/// * reserved device names prefixed with `_`;
+/// * at most `MAX_NAME_BYTES` bytes, keeping the extension when it is short.
+///
+/// `.` and `..` become `_`, so the result never refers to a directory.
+pub fn sanitize_file_name(name: &str) -> String {
+    let replaced: String = name.nfc().map(|c| if is_forbidden(c) { '_' } else { c }).collect();
+    let mut name = replaced.trim_end_matches(['.', ' ']).to_string();
+    if name.is_empty() {
+        return "_".to_string();
+    }
+    if is_reserved_name(&name) {
+        name.insert(0, '_');
+    }
+    if name.len() > MAX_NAME_BYTES {
+        let extension = match name.rfind('.') {
+            Some(dot) if dot > 0 && name.len() - dot <= 16 => name[dot..].to_string(),
+            _ => String::new(),
+        };
+        let stem = truncate_bytes(&name[..name.len() - extension.len()], MAX_NAME_BYTES - extension.len());
+        name = format!("{}{}", stem.trim_end_matches(['.', ' ']), extension);
+    }
+    name
+}
+
+/// `relative` below `base`. Both `/` and `\` separate components, `.` components are dropped, and every other
+/// component goes through `sanitize_file_name`. Absolute paths, drive prefixes (`C:`), and `..` are refused.
+pub fn safe_join(base: impl AsRef<Path>, relative: impl AsRef<Path>) -> Result<PathBuf, PathError> {
+    let text = relative.as_ref().to_string_lossy();
+    let escape = || PathError::Escape(text.to_string());
+    if text.starts_with(['/', '\\']) || text.split(['/', '\\']).next().is_some_and(|first| first.ends_with(':')) {
+        return Err(escape());
+    }
+    let mut path = base.as_ref().to_path_buf();
+    let mut joined = 0;
+    for component in text.split(['/', '\\']).filter(|component| !component.is_empty() && *component != ".") {
+        if component == ".." {
+            return Err(escape());
+        }
+        path.push(sanitize_file_name(component));
+        joined += 1;
+    }
+    if joined == 0 {
+        return Err(PathError::Empty);
+    }
+    Ok(path)
+}
+
+fn main() {
+    for name in ["Q3: revenue?.md", "nul.json", "notes. ", "café"] {
+        println!("{:?} -> {:?}", name, sanitize_file_name(name));
+    }
+    println!("{:?}", safe_join("agent_workspace", "artifacts\\reports/q3.md"));
+    println!("{:?}", safe_join("agent_workspace", "../etc/passwd"));
+}
+```
+
+### Notes
+* Sanitizing is lossy: `a:b` and `a?b` both become `a_b`. Stores that must tell names apart, such as the
+  agent store, reject a name that sanitizing would change rather than saving it under another name.
+* Case is kept. Windows and macOS compare names case-insensitively by default, so `Report.md` and
+  `report.md` are still one file there.
+* `safe_join` checks the path as text and does not follow symlinks; a symlink inside the base directory
+  can still point outside it.
@@ -0,0 +1,81 @@
+### Overview
+There is no Python counterpart; the Python package reads every credential straight from `os.environ`. In
+Rust, code that needs a secret (encryption keys, signing keys) asks a `SecretStore` for it by name, so a
+deployment can back secrets with a vault or a KMS without the callers changing. Two stores are provided:
+
+* `EnvSecretStore` reads environment variables, optionally under a prefix. It is the default and matches
+  the Python behaviour.
+* `StaticSecretStore` holds secrets in memory, for tests and for embedders that load secrets themselves.
+
+### Rust Code
+```rust
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+
+/// Looks up secrets by name. Implementations must not log secret values.
+pub trait SecretStore: Send + Sync {
+    fn get(&self, name: &str) -> Option<String>;
+}
+
+/// Secrets from environment variables: `get("STATE_KEY")` reads `<prefix>STATE_KEY`.
+#[derive(Debug, Clone, Default)]
+pub struct EnvSecretStore {
+    prefix: String,
+}
+
+impl EnvSecretStore {
+    pub fn new() -> Self {
+        EnvSecretStore::default()
+    }
+
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        EnvSecretStore { prefix: prefix.into() }
+    }
+}
+
+impl SecretStore for EnvSecretStore {
+    fn get(&self, name: &str) -> Option<String> {
+        env::var(format!("{}{}", self.prefix, name)).ok().filter(|value| !value.is_empty())
+    }
+}
+
+/// Secrets held in memory.
+#[derive(Clone, Default)]
+pub struct StaticSecretStore {
+    secrets: HashMap<String, String>,
+}
+
+impl StaticSecretStore {
+    pub fn new() -> Self {
+        StaticSecretStore::default()
+    }
+
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.secrets.insert(name.into(), value.into());
+        self
+    }
+}
+
+// Names only; the values stay out of debug output.
+impl fmt::Debug for StaticSecretStore {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("StaticSecretStore").field("names", &self.secrets.keys().collect::<Vec<_>>()).finish()
+    }
+}
+
+impl SecretStore for StaticSecretStore {
+    fn get(&self, name: &str) -> Option<String> {
+        self.secrets.get(name).cloned()
+    }
+}
+
+fn main() {
+    let store = StaticSecretStore::new().with("STATE_KEY_2026_10", "c2VjcmV0");
+    println!("{:?} has STATE_KEY_2026_10: {}", store, store.get("STATE_KEY_2026_10").is_some());
+    println!("SWARMS_STATE_KEY_2026_10 set: {}", EnvSecretStore::with_prefix("SWARMS_").get("STATE_KEY_2026_10").is_some());
+}
+```
+
+### Notes
+* Empty environment variables count as unset, so `export SWARMS_STATE_KEY_X=` cannot silently configure an empty key.
@@ -0,0 +1,262 @@
+### Overview
+There is no Python counterpart; the Python package zips workspaces but never unzips them. Archives that
+come back in, such as a workspace an agent packed with its code tool or an upload to the API, may be
+built to attack the extractor. `extract_zip` unpacks one only if every entry passes these checks:
+
+* **Paths.** Every entry name goes through `safe_join`, so absolute names, drive prefixes, and `..`
+  ("zip slip") are refused. After each directory is created, its canonical path must still be inside the
+  destination, which catches symlinks planted there. Symlink entries and duplicate names are refused.
+* **Entry count.** At most `max_entries`, checked against the central directory before anything is read.
+* **Size.** At most `max_total_bytes` decompressed and `max_entry_bytes` per entry. The sizes an archive
+  declares are checked first, then the bytes actually inflated are counted, because declared sizes can lie.
+* **Compression ratio.** No entry may inflate to more than `max_ratio` times its compressed size. A
+  normal text file compresses about 10:1, while a zip bomb reaches 1000:1 or more.
+
+Entries are written to a staging directory next to the destination, which is renamed into place only
+once every entry has been extracted. A refused archive therefore leaves nothing behind. Failures are
+`ZipExtractError`s that name the entry and the limit.
+
+### Rust Code
+```rust
+use crate::swarms::utils::safe_path::{safe_join, PathError};
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use uuid::Uuid;
+use zip::ZipArchive;
+
+const S_IFMT: u32 = 0o170_000;
+const S_IFLNK: u32 = 0o120_000;
+// Entries smaller than this are not ratio-checked; a small file of repeated bytes is not a bomb
+const RATIO_MIN_BYTES: u64 = 1 << 20;
+
+/// Bounds on what one archive may unpack to. The defaults suit agent workspaces.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtractLimits {
+    pub max_entries: usize,
+    pub max_total_bytes: u64,
+    pub max_entry_bytes: u64,
+    /// Decompressed size over compressed size, per entry.
+    pub max_ratio: f64,
+}
+
+impl Default for ExtractLimits {
+    fn default() -> Self {
+        ExtractLimits {
+            max_entries: 10_000,
+            max_total_bytes: 1 << 30,
+            max_entry_bytes: 256 << 20,
+            max_ratio: 100.0,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum ZipExtractError {
+    Io(io::Error),
+    /// Not a readable zip archive.
+    Archive(String),
+    /// The destination already exists; extraction never merges into an existing directory.
+    DestinationExists(PathBuf),
+    TooManyEntries { entries: usize, limit: usize },
+    /// The entry would leave the destination: absolute, `..`, a drive prefix, or through a symlink.
+    UnsafePath { entry: String },
+    Symlink { entry: String },
+    /// Two entries resolve to the same file.
+    DuplicateEntry { entry: String },
+    EntryTooLarge { entry: String, limit: u64 },
+    TotalTooLarge { limit: u64 },
+    RatioExceeded { entry: String, ratio: f64, limit: f64 },
+}
+
+impl fmt::Display for ZipExtractError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ZipExtractError::Io(err) => write!(f, "zip extraction failed: {}", err),
+            ZipExtractError::Archive(message) => write!(f, "invalid zip archive: {}", message),
+            ZipExtractError::DestinationExists(path) => write!(f, "extraction target {} already exists", path.display()),
+            ZipExtractError::TooManyEntries { entries, limit } => {
+                write!(f, "archive has {} entries, more than the limit of {}", entries, limit)
+            }
+            ZipExtractError::UnsafePath { entry } => write!(f, "entry '{}' would be written outside the destination", entry),
+            ZipExtractError::Symlink { entry } => write!(f, "entry '{}' is a symlink", entry),
+            ZipExtractError::DuplicateEntry { entry } => write!(f, "entry '{}' appears more than once", entry),
+            ZipExtractError::EntryTooLarge { entry, limit } => {
+                write!(f, "entry '{}' decompresses to more than {} bytes", entry, limit)
+            }
+            ZipExtractError::TotalTooLarge { limit } => write!(f, "archive decompresses to more than {} bytes", limit),
+            ZipExtractError::RatioExceeded { entry, ratio, limit } => {
+                write!(f, "entry '{}' has compression ratio {:.0}:1, above the limit of {:.0}:1", entry, ratio, limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ZipExtractError {}
+
+impl From<io::Error> for ZipExtractError {
+    fn from(err: io::Error) -> Self {
+        ZipExtractError::Io(err)
+    }
+}
+
+impl From<zip::result::ZipError> for ZipExtractError {
+    fn from(err: zip::result::ZipError) -> Self {
+        match err {
+            zip::result::ZipError::Io(err) => ZipExtractError::Io(err),
+            err => ZipExtractError::Archive(err.to_string()),
+        }
+    }
+}
+
+/// What an extraction wrote.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExtractSummary {
+    pub files: usize,
+    pub bytes: u64,
+}
+
+fn ratio(size: u64, compressed: u64) -> f64 {
+    size as f64 / compressed.max(1) as f64
+}
+
+fn check_ratio(entry: &str, size: u64, compressed: u64, limits: &ExtractLimits) -> Result<(), ZipExtractError> {
+    let ratio = ratio(size, compressed);
+    if size >= RATIO_MIN_BYTES && ratio > limits.max_ratio {
+        return Err(ZipExtractError::RatioExceeded { entry: entry.to_string(), ratio, limit: limits.max_ratio });
+    }
+    Ok(())
+}
+
+// Refuse what the central directory declares, before any data is inflated
+fn check_declared(archive: &mut ZipArchive<File>, limits: &ExtractLimits) -> Result<(), ZipExtractError> {
+    if archive.len() > limits.max_entries {
+        return Err(ZipExtractError::TooManyEntries { entries: archive.len(), limit: limits.max_entries });
+    }
+    let mut total: u64 = 0;
+    for index in 0..archive.len() {
+        let entry = archive.by_index_raw(index)?;
+        let name = entry.name().to_string();
+        if entry.unix_mode().is_some_and(|mode| mode & S_IFMT == S_IFLNK) {
+            return Err(ZipExtractError::Symlink { entry: name });
+        }
+        if entry.size() > limits.max_entry_bytes {
+            return Err(ZipExtractError::EntryTooLarge { entry: name, limit: limits.max_entry_bytes });
+        }
+        check_ratio(&name, entry.size(), entry.compressed_size(), limits)?;
+        total = total.saturating_add(entry.size());
+        if total > limits.max_total_bytes {
+            return Err(ZipExtractError::TotalTooLarge { limit: limits.max_total_bytes });
+        }
+    }
+    Ok(())
+}
+
+// `dir` must resolve, through any symlinks, to somewhere inside `root`
+fn ensure_inside(root: &Path, dir: &Path, entry: &str) -> Result<(), ZipExtractError> {
+    fs::create_dir_all(dir)?;
+    if dir.canonicalize()?.starts_with(root) {
+        Ok(())
+    } else {
+        Err(ZipExtractError::UnsafePath { entry: entry.to_string() })
+    }
+}
+
+fn extract_into(
+    archive: &mut ZipArchive<File>,
+    staging: &Path,
+    limits: &ExtractLimits,
+) -> Result<ExtractSummary, ZipExtractError> {
+    let root = staging.canonicalize()?;
+    let mut seen = HashSet::new();
+    let mut summary = ExtractSummary { files: 0, bytes: 0 };
+    let mut buffer = vec![0u8; 64 * 1024];
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index)?;
+        let name = entry.name().to_string();
+        let is_dir = entry.is_dir();
+        let target = match safe_join(staging, &name) {
+            Ok(target) => target,
+            // A "./" directory entry names the destination itself
+            Err(PathError::Empty) if is_dir => continue,
+            Err(_) => return Err(ZipExtractError::UnsafePath { entry: name }),
+        };
+        if !seen.insert(target.clone()) {
+            return Err(ZipExtractError::DuplicateEntry { entry: name });
+        }
+        if is_dir {
+            ensure_inside(&root, &target, &name)?;
+            continue;
+        }
+        ensure_inside(&root, target.parent().unwrap_or(staging), &name)?;
+
+        let compressed = entry.compressed_size();
+        let mut out = File::create(&target)?;
+        let mut written: u64 = 0;
+        loop {
+            let read = entry.read(&mut buffer)?;
+            if read == 0 {
+                break;
+            }
+            written += read as u64;
+            summary.bytes += read as u64;
+            if written > limits.max_entry_bytes {
+                return Err(ZipExtractError::EntryTooLarge { entry: name, limit: limits.max_entry_bytes });
+            }
+            if summary.bytes > limits.max_total_bytes {
+                return Err(ZipExtractError::TotalTooLarge { limit: limits.max_total_bytes });
+            }
+            check_ratio(&name, written, compressed, limits)?;
+            out.write_all(&buffer[..read])?;
+        }
+        summary.files += 1;
+    }
+    Ok(summary)
+}
+
+/// Unpack the zip at `archive` into the new directory `destination`, within `limits`. Nothing is left at
+/// `destination` unless every entry passed.
+pub fn extract_zip(
+    archive: impl AsRef<Path>,
+    destination: impl AsRef<Path>,
+    limits: &ExtractLimits,
+) -> Result<ExtractSummary, ZipExtractError> {
+    let destination = destination.as_ref();
+    if destination.exists() {
+        return Err(ZipExtractError::DestinationExists(destination.to_path_buf()));
+    }
+    let mut archive = ZipArchive::new(File::open(archive)?)?;
+    check_declared(&mut archive, limits)?;
+
+    let parent = destination.parent().filter(|parent| !parent.as_os_str().is_empty()).unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)?;
+    let staging = parent.join(format!(".extract-{}", Uuid::new_v4().simple()));
+    fs::create_dir(&staging)?;
+    let extracted = extract_into(&mut archive, &staging, limits).and_then(|summary| {
+        fs::rename(&staging, destination)?;
+        Ok(summary)
+    });
+    if extracted.is_err() {
+        let _ = fs::remove_dir_all(&staging);
+    }
+    extracted
+}
+
+fn main() {
+    match extract_zip("workspace.zip", "agent_workspace/restored", &ExtractLimits::default()) {
+        Ok(summary) => println!("extracted {} files, {} bytes", summary.files, summary.bytes),
+        Err(err) => eprintln!("{}", err),
+    }
+}
+```
+
+### Notes
+* Entry names are sanitized like every other workspace path (see `safe_path`), so `report?.md` is
+  extracted as `report_.md`. Names that differ only in characters sanitizing replaces are duplicates.
+* File permissions stored in the archive are not applied. Extracted files get the process's default mode,
+  so an archive cannot plant an executable or a world-writable file.
+* The ratio limit applies per entry, to entries of 1 MiB or more. An archive of many small,
+  well-compressed files is bounded by `max_entries` and `max_total_bytes` instead.
+* Encrypted entries are refused by the zip reader as an invalid archive.
@@ -0,0 +1,114 @@
+### Tests for the swarm config generator
+Generated YAML must load through the config loader, mechanical problems must be repaired and recorded,
+unrepairable drafts must be sent back to the model, and every draft must land in the artifact history.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::auto_generate_swarm_config::{
+        parse_yaml_from_swarm_markdown, SwarmConfigError, SwarmConfigGenerator, SwarmConfigIssue,
+    };
+    use crate::swarms::artifacts::main_artifact::Artifact;
+    use tempfile::tempdir;
+
+    const VALID: &str = "Here you go:\n```yaml\nagents:\n  - agent_name: \"Researcher\"\n    system_prompt: \"You gather sources.\"\n\
+        \n  - agent_name: \"Writer\"\n    system_prompt: \"You write the report.\"\n    model_name: \"gpt-4o-mini\"\n\
+        swarm_architecture:\n  name: \"Report-Swarm\"\n  swarm_type: \"SequentialWorkflow\"\n  task: \"Report on solid-state batteries\"\n```";
+
+    const REPAIRABLE: &str = "agents:\n  - agent_name: \"Analyst\"\n    system_prompt: \"You analyze.\"\n    max_loops: 0\n\
+        \n  - agent_name: \"Analyst\"\n    system_prompt: \"You review.\"\n    context_length: 0\n\
+        swarm_architecture:\n  name: \"\"\n  swarm_type: \"ConcurrentWorkflow\"";
+
+    fn scripted(replies: Vec<&'static str>) -> impl FnMut(&str) -> Result<String, String> {
+        let mut replies = replies.into_iter();
+        move |_prompt| replies.next().map(str::to_string).ok_or_else(|| "model exhausted".to_string())
+    }
+
+    #[test]
+    fn test_extracts_fenced_and_bare_yaml() {
+        assert!(parse_yaml_from_swarm_markdown(VALID).unwrap().starts_with("agents:"));
+        assert!(parse_yaml_from_swarm_markdown("agents:\n  - agent_name:x").unwrap().contains("agent_name: x"));
+        assert_eq!(parse_yaml_from_swarm_markdown("I need more details first."), None);
+    }
+
+    #[test]
+    fn test_valid_config_becomes_runnable_swarm() {
+        let dir = tempdir().unwrap();
+        let generator = SwarmConfigGenerator::new(dir.path()).model_name("gpt-4o");
+        let swarm = generator.generate("ignored when the YAML has a task", scripted(vec![VALID])).unwrap();
+        assert_eq!((swarm.attempts, swarm.version), (1, 1));
+        assert!(swarm.repairs.is_empty());
+        assert_eq!(swarm.workflow.flow(), "Researcher -> Writer");
+        let models: Vec<&str> =
+            swarm.workflow.agents().iter().filter_map(|agent| agent.spec()).map(|spec| spec.llm.as_str()).collect();
+        assert_eq!(models, vec!["gpt-4o", "gpt-4o-mini"]);
+        assert_eq!(std::fs::read_to_string(&swarm.path).unwrap(), swarm.yaml);
+
+        let mut inputs = Vec::new();
+        let stages = swarm
+            .run_with(|agent, input| {
+                inputs.push(input.to_string());
+                Ok(format!("{} done", agent.name()))
+            })
+            .unwrap();
+        assert_eq!(inputs, vec!["Report on solid-state batteries", "Researcher done"]);
+        assert_eq!(stages[1].output, "Writer done");
+    }
+
+    #[test]
+    fn test_repairs_are_applied_and_audited() {
+        let dir = tempdir().unwrap();
+        let swarm = SwarmConfigGenerator::new(dir.path()).generate("Analyze churn", scripted(vec![REPAIRABLE])).unwrap();
+        assert_eq!(swarm.repairs.len(), 5, "{:?}", swarm.repairs);
+        assert!(swarm.repairs.iter().any(|repair| repair.contains("'Analyst' to 'Analyst-2'")));
+        assert!(swarm.repairs.iter().any(|repair| repair.contains("ConcurrentWorkflow")));
+        assert_eq!(swarm.workflow.name(), "Auto-Swarm");
+        assert_eq!(swarm.task, "Analyze churn");
+        assert_eq!(swarm.config.agents[0].max_loops, 1);
+        assert_eq!(swarm.config.agents[1].context_length, None);
+
+        let history = Artifact::import_from_json(&swarm.history_path.to_string_lossy()).unwrap();
+        assert_eq!(history.current_version(), 2);
+        assert!(history.get_version(1).unwrap().content.contains("max_loops: 0"));
+        assert_eq!(history.get_version(2).unwrap().content, swarm.yaml);
+    }
+
+    #[test]
+    fn test_unrepairable_drafts_are_sent_back() {
+        let dir = tempdir().unwrap();
+        let generator = SwarmConfigGenerator::new(dir.path());
+        let mut prompts = Vec::new();
+        let mut replies = scripted(vec!["agents:\n  - agent_name: \"Solo\"\n", VALID]);
+        let swarm = generator
+            .generate("Report", |prompt: &str| {
+                prompts.push(prompt.to_string());
+                replies(prompt)
+            })
+            .unwrap();
+        assert_eq!((swarm.attempts, swarm.version), (2, 2));
+        assert!(prompts[0].ends_with("Task: Report\n"));
+        assert!(prompts[1].contains("- agent 'Solo' has no system_prompt"));
+    }
+
+    #[test]
+    fn test_without_repair_the_first_invalid_draft_fails() {
+        let dir = tempdir().unwrap();
+        let generator = SwarmConfigGenerator::new(dir.path()).auto_repair(false);
+        let result = generator.generate("Analyze churn", scripted(vec![REPAIRABLE, VALID]));
+        match result {
+            Err(SwarmConfigError::Rejected { attempts: 1, issues }) => {
+                assert!(issues.contains(&SwarmConfigIssue::DuplicateAgentName("Analyst".to_string())));
+                assert!(issues.contains(&SwarmConfigIssue::MissingSwarmName));
+                assert!(issues.contains(&SwarmConfigIssue::UnsupportedSwarmType("ConcurrentWorkflow".to_string())));
+            }
+            other => panic!("expected a rejection, got {:?}", other.err()),
+        }
+        let (path, history) = generator.paths();
+        assert!(!path.exists());
+        assert_eq!(Artifact::import_from_json(&history.to_string_lossy()).unwrap().current_version(), 1);
+
+        let result = generator.generate("Analyze churn", scripted(vec![]));
+        assert!(matches!(result, Err(SwarmConfigError::Model(message)) if message == "model exhausted"));
+    }
+}
+```
@@ -0,0 +1,70 @@
+### Tests for config defaults
+Partial YAML/JSON configs must deserialize, and every omitted field must take the Python default.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::create_agents_from_yaml::{AgentConfig, SwarmConfig, YAMLConfig};
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::schemas::agent_step_schemas::ManySteps;
+    use crate::swarms::text::context_budget::ContextBudget;
+
+    #[test]
+    fn test_agent_config_python_defaults() {
+        let config = AgentConfig::default();
+        assert_eq!(config.max_loops, 1);
+        assert!(config.autosave);
+        assert!(!config.dashboard);
+        assert_eq!(config.user_name, "default_user");
+        assert_eq!(config.retry_attempts, 3);
+        assert_eq!(config.output_type, "str");
+        assert_eq!(config.artifacts_file_extension, ".md");
+        assert_eq!(config.budget(), ContextBudget::from_context_length(100_000));
+    }
+
+    #[test]
+    fn test_partial_yaml_loads() {
+        let yaml = "
+agents:
+  - agent_name: Financial-Analysis-Agent
+    system_prompt: You are a financial analyst.
+    max_loops: 2
+swarm_architecture:
+  name: Finance-Swarm
+  description: Reviews filings
+";
+        let config: YAMLConfig = serde_yaml::from_str(yaml).unwrap();
+        let agent = &config.agents[0];
+        assert_eq!(agent.max_loops, 2);
+        assert_eq!(agent.retry_attempts, 3);
+        assert_eq!(agent.model_name, None);
+
+        let swarm = config.swarm_architecture.unwrap();
+        assert_eq!(swarm.swarm_type, "SequentialWorkflow");
+        assert_eq!(swarm.rules, "");
+        assert!(swarm.webhooks.is_empty());
+        assert_eq!(SwarmConfig { name: swarm.name.clone(), description: swarm.description.clone(), ..SwarmConfig::default() }, swarm);
+    }
+
+    #[test]
+    fn test_agent_schema_python_defaults() {
+        let schema: AgentSchema = serde_json::from_value(serde_json::json!({ "llm": "OpenAIChat" })).unwrap();
+        assert_eq!(schema.max_loops, Some(1));
+        assert_eq!(schema.retry_attempts, Some(3));
+        assert_eq!(schema.retry_interval, Some(1));
+        assert_eq!(schema.temperature, Some(0.1));
+        assert_eq!(schema.top_p, Some(0.9));
+        assert_eq!(schema.tool_choice.as_deref(), Some("auto"));
+        assert_eq!(schema.memory_chunk_size, Some(2000));
+        assert_eq!(schema.context_window, 8192);
+    }
+
+    #[test]
+    fn test_steps_get_fresh_ids() {
+        let steps: ManySteps = serde_json::from_str(r#"{ "agent_name": "Analyst", "task": "Summarize" }"#).unwrap();
+        assert!(steps.run_id.starts_with("run_"));
+        assert!(steps.steps.is_empty());
+        assert_eq!(steps.max_loops, 1);
+    }
+}
+```
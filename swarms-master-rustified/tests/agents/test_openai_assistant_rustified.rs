@@ -0,0 +1,145 @@
+### Tests for the OpenAI assistant
+A scripted transport plays the Assistants API. A run must create the thread once, poll until the run
+completes, answer every tool call (with an error for functions it does not have), and return the
+assistant's latest message. Files go to the code interpreter as v2 `tool_resources`.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::openai_assistant::{AssistantConfig, AssistantError, OpenAIAssistant};
+    use crate::swarms::integrations::rest::{HttpMethod, HttpRequest, HttpResponse, HttpTransport, RestError};
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    struct ScriptedTransport {
+        responses: Mutex<Vec<serde_json::Value>>,
+        seen: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for ScriptedTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+            self.seen.lock().unwrap().push(request);
+            let body = self.responses.lock().unwrap().remove(0);
+            Ok(HttpResponse { status: 200, body: body.to_string() })
+        }
+    }
+
+    async fn assistant(responses: Vec<serde_json::Value>) -> (OpenAIAssistant, Arc<ScriptedTransport>) {
+        let mut script = vec![json!({ "id": "asst_1" })];
+        script.extend(responses);
+        let transport = Arc::new(ScriptedTransport { responses: Mutex::new(script), seen: Mutex::new(Vec::new()) });
+        let rest = OpenAIAssistant::client("sk-test", Some(transport.clone()));
+        let mut config = AssistantConfig::new("Math Tutor");
+        config.instructions = Some("You are a personal math tutor.".to_string());
+        let assistant = OpenAIAssistant::create(rest, config).await.unwrap().poll_interval(Duration::ZERO);
+        (assistant, transport)
+    }
+
+    fn reply(text: &str) -> serde_json::Value {
+        json!({ "data": [{ "role": "assistant", "content": [{ "type": "text", "text": { "value": text } }] }] })
+    }
+
+    #[tokio::test]
+    async fn test_run_polls_until_complete() {
+        let (mut assistant, transport) = assistant(vec![
+            json!({ "id": "thread_1" }),
+            json!({ "id": "msg_1" }),
+            json!({ "id": "run_1", "status": "queued" }),
+            json!({ "id": "run_1", "status": "in_progress" }),
+            json!({ "id": "run_1", "status": "completed" }),
+            reply("x = 1"),
+        ])
+        .await;
+        assert_eq!(assistant.assistant_id(), "asst_1");
+        assert_eq!(assistant.run("Solve 3x + 11 = 14").await.unwrap(), "x = 1");
+
+        let seen = transport.seen.lock().unwrap();
+        let calls: Vec<(HttpMethod, &str)> = seen.iter().map(|r| (r.method, r.url.as_str())).collect();
+        assert_eq!(
+            calls,
+            [
+                (HttpMethod::Post, "https://api.openai.com/v1/assistants"),
+                (HttpMethod::Post, "https://api.openai.com/v1/threads"),
+                (HttpMethod::Post, "https://api.openai.com/v1/threads/thread_1/messages"),
+                (HttpMethod::Post, "https://api.openai.com/v1/threads/thread_1/runs"),
+                (HttpMethod::Get, "https://api.openai.com/v1/threads/thread_1/runs/run_1"),
+                (HttpMethod::Get, "https://api.openai.com/v1/threads/thread_1/runs/run_1"),
+                (HttpMethod::Get, "https://api.openai.com/v1/threads/thread_1/messages"),
+            ]
+        );
+        assert!(seen[0].headers.contains(&("Authorization".to_string(), "Bearer sk-test".to_string())));
+        assert!(seen[0].headers.contains(&("OpenAI-Beta".to_string(), "assistants=v2".to_string())));
+        let created = seen[0].body.as_ref().unwrap();
+        assert!(created.get("file_ids").is_none() && created.get("tool_resources").is_none(), "{}", created);
+        assert_eq!(seen[3].body.as_ref().unwrap()["instructions"], "You are a personal math tutor.");
+        assert!(seen[6].query.contains(&("order".to_string(), "desc".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_tool_calls_are_answered() {
+        let (mut assistant, transport) = assistant(vec![
+            json!({ "id": "asst_1" }),
+            json!({ "id": "thread_1" }),
+            json!({ "id": "msg_1" }),
+            json!({
+                "id": "run_1",
+                "status": "requires_action",
+                "required_action": { "submit_tool_outputs": { "tool_calls": [
+                    { "id": "call_1", "type": "function", "function": { "name": "add", "arguments": "{\"a\":2,\"b\":3}" } },
+                    { "id": "call_2", "type": "function", "function": { "name": "divide", "arguments": "{}" } }
+                ] } }
+            }),
+            json!({ "id": "run_1", "status": "completed" }),
+            reply("5"),
+        ])
+        .await;
+        let parameters = json!({ "type": "object", "properties": { "a": { "type": "number" }, "b": { "type": "number" } } });
+        assistant
+            .add_function("add", "Add two numbers.", parameters, |args| {
+                (args["a"].as_i64().unwrap_or(0) + args["b"].as_i64().unwrap_or(0)).to_string()
+            })
+            .await
+            .unwrap();
+        assert_eq!(assistant.run("What is 2 + 3?").await.unwrap(), "5");
+
+        let seen = transport.seen.lock().unwrap();
+        assert_eq!(seen[1].url, "https://api.openai.com/v1/assistants/asst_1");
+        assert_eq!(seen[1].body.as_ref().unwrap()["tools"][0]["function"]["name"], "add");
+        assert_eq!(seen[5].url, "https://api.openai.com/v1/threads/thread_1/runs/run_1/submit_tool_outputs");
+        // Every call gets an output, or the run would wait for the missing one.
+        let outputs = json!([
+            { "tool_call_id": "call_1", "output": "5" },
+            { "tool_call_id": "call_2", "output": "Error: function 'divide' is not available" }
+        ]);
+        assert_eq!(seen[5].body, Some(json!({ "tool_outputs": outputs })));
+    }
+
+    #[tokio::test]
+    async fn test_files_are_sent_as_tool_resources() {
+        let transport = Arc::new(ScriptedTransport { responses: Mutex::new(vec![json!({ "id": "asst_1" })]), seen: Mutex::new(Vec::new()) });
+        let mut config = AssistantConfig::new("Analyst");
+        config.tools = vec![json!({ "type": "code_interpreter" })];
+        config.file_ids = vec!["file_1".to_string()];
+        OpenAIAssistant::create(OpenAIAssistant::client("sk-test", Some(transport.clone())), config).await.unwrap();
+
+        let seen = transport.seen.lock().unwrap();
+        let body = seen[0].body.as_ref().unwrap();
+        assert!(body.get("file_ids").is_none());
+        assert_eq!(body["tool_resources"], json!({ "code_interpreter": { "file_ids": ["file_1"] } }));
+    }
+
+    #[tokio::test]
+    async fn test_failed_run_is_an_error() {
+        let (mut assistant, _) = assistant(vec![
+            json!({ "id": "thread_1" }),
+            json!({ "id": "msg_1" }),
+            json!({ "id": "run_1", "status": "failed" }),
+        ])
+        .await;
+        assert!(matches!(assistant.run("Solve").await, Err(AssistantError::RunFailed(status)) if status == "failed"));
+    }
+}
+```
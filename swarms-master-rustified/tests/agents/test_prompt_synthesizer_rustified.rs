@@ -0,0 +1,134 @@
+### Tests for automatic prompt generation
+Generated prompts must pass the review hook before they reach an agent, rejected drafts must be retried
+with the reviewer's reasons, and accepted prompts must be served from the cache, including after a reload.
+Only agents built with `auto_generate_prompt` get a generated prompt.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::prompt_synthesizer::{PromptBrief, PromptReview, PromptSynthesisError, PromptSynthesizer};
+    use crate::swarms::structs::agent::LlmAgent;
+    use tempfile::tempdir;
+
+    fn scripted(replies: Vec<&'static str>) -> impl FnMut(&str) -> Result<String, String> {
+        let mut replies = replies.into_iter();
+        move |_prompt| replies.next().map(str::to_string).ok_or_else(|| "model exhausted".to_string())
+    }
+
+    fn agent(name: &str, description: &str) -> LlmAgent {
+        LlmAgent::builder()
+            .name(name)
+            .llm("gpt-4o-mini")
+            .description(description)
+            .system_prompt("hand-written")
+            .auto_generate_prompt(true)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_brief_and_cache_key() {
+        let brief = PromptBrief::new("Market-Analyst", "Analyzes markets", "Size the EV market");
+        let meta = brief.meta_prompt(&["too generic".to_string()]);
+        assert!(meta.contains("Name: Market-Analyst") && meta.contains("Size the EV market"));
+        assert!(meta.contains("# Reviewer Feedback") && meta.contains("- too generic"));
+        assert!(!brief.meta_prompt(&[]).contains("# Reviewer Feedback"));
+
+        assert_eq!(brief.cache_key(), PromptBrief::new("Market-Analyst", "Analyzes markets", "Size the EV market").cache_key());
+        assert_ne!(brief.cache_key(), PromptBrief::new("Market-Analyst", "Analyzes markets", "Size the solar market").cache_key());
+        assert_ne!(PromptBrief::new("ab", "c", "t").cache_key(), PromptBrief::new("a", "bc", "t").cache_key());
+    }
+
+    #[test]
+    fn test_review_rejects_then_edits_and_cache_skips_review() {
+        let mut synthesizer = PromptSynthesizer::new();
+        let brief = PromptBrief::new("Data-Extractor", "Extracts data", "Read the term sheet");
+        let mut prompts = Vec::new();
+        let mut replies = scripted(vec!["You help.", "You extract term sheet fields."]);
+        let mut reviews = 0;
+        let generated = synthesizer
+            .synthesize(
+                &brief,
+                |prompt: &str| {
+                    prompts.push(prompt.to_string());
+                    replies(prompt)
+                },
+                |_brief, prompt| {
+                    reviews += 1;
+                    if prompt.contains("term sheet") {
+                        PromptReview::Edit(format!("{} Cite page numbers.", prompt))
+                    } else {
+                        PromptReview::Reject("name the document".to_string())
+                    }
+                },
+            )
+            .unwrap();
+        assert_eq!(generated.prompt, "You extract term sheet fields. Cite page numbers.");
+        assert_eq!((generated.attempts, generated.edited, generated.from_cache), (2, true, false));
+        assert!(prompts[1].contains("- name the document"));
+
+        let cached = synthesizer.synthesize(&brief, scripted(vec![]), |_, _| PromptReview::Reject("unused".to_string())).unwrap();
+        assert_eq!((cached.from_cache, cached.attempts), (true, 0));
+        assert_eq!(cached.prompt, generated.prompt);
+        assert_eq!(reviews, 2);
+    }
+
+    #[test]
+    fn test_apply_sets_prompts_and_cache_file_survives_reload() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("cache").join("prompts.json");
+        let mut agents = vec![agent("Summarizer", "Summarizes"), agent("Critic", "Finds flaws")];
+        let mut synthesizer = PromptSynthesizer::with_cache_file(&path).unwrap();
+        synthesizer
+            .apply(&mut agents, "Review the memo", scripted(vec!["You summarize memos.", "You critique memos."]), |_, _| {
+                PromptReview::Approve
+            })
+            .unwrap();
+        assert_eq!(agents[0].system_prompt, "You summarize memos.");
+        assert_eq!(agents[1].system_prompt, "You critique memos.");
+
+        let mut reloaded = PromptSynthesizer::with_cache_file(&path).unwrap();
+        let mut fresh = vec![agent("Critic", "Finds flaws")];
+        let generated = reloaded.apply(&mut fresh, "Review the memo", scripted(vec![]), |_, _| PromptReview::Approve).unwrap();
+        assert!(generated[0].from_cache);
+        assert_eq!(fresh[0].system_prompt, "You critique memos.");
+
+        let brief = PromptBrief::for_agent(&fresh[0], "Review the memo");
+        assert!(reloaded.invalidate(&brief).unwrap());
+        assert!(PromptSynthesizer::with_cache_file(&path).unwrap().cached(&brief).is_none());
+    }
+
+    #[test]
+    fn test_failures_keep_the_existing_prompt() {
+        let mut synthesizer = PromptSynthesizer::new().max_attempts(2);
+        let mut agents = vec![agent("Analyst", "Analyzes")];
+        let result = synthesizer.apply(&mut agents, "task", scripted(vec!["  ", "Too vague."]), |_, _| {
+            PromptReview::Reject("too vague".to_string())
+        });
+        assert!(matches!(
+            result,
+            Err(PromptSynthesisError::Rejected { attempts: 2, ref reasons, .. })
+                if reasons == &vec!["the generated prompt was empty".to_string(), "too vague".to_string()]
+        ));
+        assert_eq!(agents[0].system_prompt, "hand-written");
+
+        let result = synthesizer.apply(&mut agents, "task", scripted(vec![]), |_, _| PromptReview::Approve);
+        assert!(matches!(result, Err(PromptSynthesisError::Model(message)) if message == "model exhausted"));
+    }
+
+    #[test]
+    fn test_only_flagged_agents_are_rewritten() {
+        let hand_tuned = LlmAgent::builder().name("Editor").llm("gpt-4o-mini").system_prompt("hand-tuned").build().unwrap();
+        assert!(!hand_tuned.auto_generate_prompt);
+        let mut agents = vec![agent("Analyst", "Analyzes"), hand_tuned];
+        let generated = PromptSynthesizer::new()
+            .apply(&mut agents, "task", scripted(vec!["You analyze."]), |_, _| PromptReview::Approve)
+            .unwrap();
+        assert_eq!(generated.len(), 1);
+        assert_eq!((agents[0].system_prompt.as_str(), agents[1].system_prompt.as_str()), ("You analyze.", "hand-tuned"));
+
+        let config = crate::swarms::agents::create_agents_from_yaml::AgentConfig::from_spec(&agents[0]);
+        assert!(config.auto_generate_prompt, "the flag survives a YAML export");
+    }
+}
+```
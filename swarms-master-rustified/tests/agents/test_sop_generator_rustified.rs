@@ -0,0 +1,88 @@
+### Tests for the SOP generator
+Replies must be parsed into sections and numbered steps, drafts that miss the template must be sent back
+with their problems, and accepted SOPs must be saved as new versions of the same artifact.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::sop_generator::{Sop, SopError, SopGenerator, SopIssue, SopTemplate};
+    use crate::swarms::artifacts::main_artifact::Artifact;
+    use tempfile::tempdir;
+
+    const COMPLETE: &str = "# Refund Handling\n\
+        ## Purpose\nResolve refund requests consistently.\n\n\
+        Scope: All card payments\nand bank transfers.\n\
+        **Responsibilities**\n- Support agents own each request.\n\
+        ## Procedure\n1. Verify the order.\n   1.1 Check the payment id.\n2. Check the policy window.\n3) Issue the refund.\n";
+
+    fn scripted(replies: Vec<&'static str>) -> impl FnMut(&str) -> Result<String, String> {
+        let mut replies = replies.into_iter();
+        move |_prompt| replies.next().map(str::to_string).ok_or_else(|| "model exhausted".to_string())
+    }
+
+    #[test]
+    fn test_parse_sections_and_steps() {
+        let sop = Sop::parse(COMPLETE, "Procedure");
+        assert_eq!(sop.title, "Refund Handling");
+        let titles: Vec<&str> = sop.sections.iter().map(|section| section.title.as_str()).collect();
+        assert_eq!(titles, vec!["Purpose", "Scope", "Responsibilities", "Procedure"]);
+        assert_eq!(sop.section("scope").unwrap().body, "All card payments\nand bank transfers.");
+        let steps: Vec<usize> = sop.steps.iter().map(|step| step.number).collect();
+        assert_eq!(steps, vec![1, 2, 3]);
+        assert_eq!(sop.steps[2].text, "Issue the refund.");
+        assert!(SopTemplate::default().validate(&sop).is_empty());
+    }
+
+    #[test]
+    fn test_validation_issues() {
+        let template = SopTemplate::default();
+        let sop = Sop::parse("## Purpose\nx\n## Procedure\n1. One\n3. Three", "Procedure");
+        assert_eq!(
+            template.validate(&sop),
+            vec![
+                SopIssue::MissingSection("Scope".to_string()),
+                SopIssue::MissingSection("Responsibilities".to_string()),
+                SopIssue::StepOutOfOrder { expected: 2, found: 3 },
+                SopIssue::TooFewSteps { found: 2, required: 3 },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_rejected_draft_is_retried_and_versioned() {
+        let dir = tempdir().unwrap();
+        let generator = SopGenerator::new(dir.path());
+        let mut prompts = Vec::new();
+        let mut replies = scripted(vec!["## Purpose\nRefunds.", COMPLETE]);
+        let generated = generator
+            .generate("Refund handling", |prompt: &str| {
+                prompts.push(prompt.to_string());
+                replies(prompt)
+            })
+            .unwrap();
+        assert_eq!((generated.attempts, generated.version), (2, 1));
+        assert!(prompts[0].contains("Refund handling") && prompts[0].contains("Required Format"));
+        assert!(prompts[1].contains("the 'Scope' section is missing"));
+        assert_eq!(generated.path, dir.path().join("refund-handling.md"));
+        assert_eq!(std::fs::read_to_string(&generated.path).unwrap(), COMPLETE);
+
+        let again = generator.generate("Refund handling", scripted(vec![COMPLETE])).unwrap();
+        assert_eq!(again.version, 2);
+        let history = Artifact::import_from_json(&again.history_path.to_string_lossy()).unwrap();
+        assert_eq!(history.current_version(), 2);
+        assert!(history.get_version(1).is_some());
+    }
+
+    #[test]
+    fn test_gives_up_and_model_errors() {
+        let dir = tempdir().unwrap();
+        let generator = SopGenerator::new(dir.path()).max_attempts(2);
+        let result = generator.generate("Onboarding", scripted(vec!["nothing useful", "still nothing"]));
+        assert!(matches!(result, Err(SopError::Rejected { attempts: 2, .. })));
+        assert!(!generator.paths("Onboarding").0.exists());
+
+        let result = generator.generate("Onboarding", scripted(vec![]));
+        assert!(matches!(result, Err(SopError::Model(message)) if message == "model exhausted"));
+    }
+}
+```
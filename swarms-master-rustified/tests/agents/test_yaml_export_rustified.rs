@@ -0,0 +1,73 @@
+### Tests for exporting swarms to YAML
+An exported workflow must load back through `load_yaml_safely` into the same agents, keep to the fields
+and bounds the Python loader accepts, and refuse to write configs either loader would reject.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::auto_generate_swarm_config::build_workflow;
+    use crate::swarms::agents::create_agents_from_yaml::{load_yaml_safely, YamlExportError};
+    use crate::swarms::structs::agent::{Agent, LlmAgent, MockAgent};
+    use crate::swarms::structs::round_robin::RoundRobinSwarm;
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::structs::swarm::Swarm;
+    use crate::swarms::text::context_budget::ContextBudget;
+    use std::sync::Arc;
+
+    fn agent(name: &str, system_prompt: &str) -> LlmAgent {
+        LlmAgent::builder()
+            .name(name)
+            .llm("gpt-4o-mini")
+            .system_prompt(system_prompt)
+            .max_loops(2)
+            .budget(ContextBudget::new(32_000, 2_000))
+            .build()
+            .unwrap()
+    }
+
+    fn workflow(agents: Vec<LlmAgent>) -> SequentialWorkflow {
+        let agents = agents.into_iter().map(Into::into).collect();
+        SequentialWorkflow::new("Research".to_string(), "Drafts then edits.".to_string(), agents, 1, "all".to_string(), false, || ())
+    }
+
+    #[test]
+    fn test_workflow_round_trips() {
+        let original = workflow(vec![agent("Writer", "Draft the brief.\nKeep it short."), agent("Editor", "Edit the draft.")]);
+        let yaml = original.to_yaml().unwrap();
+
+        // Only the keys Python's YAMLConfig allows at the top, and no nulls Python would reject as ints
+        let value: serde_yaml::Value = serde_yaml::from_str(&yaml).unwrap();
+        let keys: Vec<&str> = value.as_mapping().unwrap().keys().map(|key| key.as_str().unwrap()).collect();
+        assert_eq!(keys, ["agents", "swarm_architecture"]);
+        assert_eq!(value["agents"][0]["context_length"].as_i64(), Some(32_000));
+        assert_eq!(value["swarm_architecture"]["swarm_type"].as_str(), Some("SequentialWorkflow"));
+        assert!(value["swarm_architecture"].get("webhooks").is_none());
+
+        let loaded = load_yaml_safely("", Some(yaml)).unwrap();
+        assert_eq!(Some(loaded.clone()), original.to_config());
+        let rebuilt = build_workflow(&loaded, "unused").unwrap();
+        assert_eq!(rebuilt.name(), "Research");
+        let (before, after) = (original.agents()[0].spec().unwrap(), rebuilt.agents()[0].spec().unwrap());
+        assert_eq!((&after.name, &after.llm, &after.system_prompt), (&before.name, &before.llm, &before.system_prompt));
+        assert_eq!((after.max_loops, after.budget), (before.max_loops, before.budget));
+    }
+
+    #[test]
+    fn test_rejects_what_either_loader_would() {
+        let err = workflow(vec![agent("Writer", "")]).to_yaml().unwrap_err();
+        let YamlExportError::Incompatible(issues) = err else { panic!("expected Incompatible") };
+        assert_eq!(issues, ["agent 'Writer' needs a non-empty system_prompt for Python"]);
+
+        let mut small = agent("Writer", "Draft.");
+        small.budget = ContextBudget::new(800, 200);
+        let mut config = workflow(vec![small]).to_config().unwrap();
+        assert_eq!(config.python_issues(), ["agent 'Writer' has context_length 800; Python requires at least 1000"]);
+        config.swarm_architecture.as_mut().unwrap().swarm_type = "RoundRobin".to_string();
+        assert_eq!(config.python_issues().len(), 2);
+
+        let mock: Arc<dyn Agent> = Arc::new(MockAgent::new("Echo").reply("hi"));
+        let round_robin = RoundRobinSwarm::new("Rota".to_string(), String::new(), vec![mock], false, 1);
+        assert!(matches!(round_robin.to_yaml(), Err(YamlExportError::Unsupported(name)) if name == "Rota"));
+    }
+}
+```
@@ -0,0 +1,94 @@
+### Tests for large-output spillover
+Outputs above the threshold must be stored as files and replaced by a short reference that names the
+file, outlines it, and quotes its start, and `expand` must return the exact original. Smaller outputs
+must be kept as they are. Spilled files must be redacted and encrypted when the hooks are set, and
+references outside the spill directory must be refused.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::artifacts::spillover::{Spillover, SPILL_MARKER};
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::text::redaction::{RedactionConfig, Redactor};
+    use crate::swarms::utils::encryption::StateCipher;
+    use crate::swarms::utils::secret_store::StaticSecretStore;
+    use std::io;
+    use std::sync::Arc;
+
+    fn report() -> String {
+        let body = "Revenue grew 12% on strong renewals.\n".repeat(400);
+        format!("# Q3 Review\n## Revenue\n{}```\n# not a heading\n```\n## Outlook\nFlat.\n", body)
+    }
+
+    #[test]
+    fn test_large_messages_spill_to_artifacts() {
+        let dir = tempfile::tempdir().unwrap();
+        let spillover = Arc::new(Spillover::new(dir.path().join("spill")).with_threshold(500).with_preview_chars(80));
+        let mut conversation = Conversation::builder().spillover(spillover.clone()).build().unwrap();
+        let small = conversation.add("user".to_string(), "Write the Q3 review.".to_string());
+        let large = conversation.add("Analyst".to_string(), report());
+
+        assert_eq!(conversation.get(small).unwrap().content, "Write the Q3 review.");
+        let kept = &conversation.get(large).unwrap().content;
+        assert!(kept.starts_with(SPILL_MARKER) && kept.len() < 400, "{}", kept);
+        assert!(kept.contains("Outline: # Q3 Review; ## Revenue; ## Outlook\n"));
+        assert!(kept.ends_with("Preview:\n# Q3 Review\n## Revenue\nRevenue grew 12% on strong renewals."));
+
+        let path = spillover.reference_path(kept).unwrap().unwrap();
+        assert!(path.starts_with(dir.path().join("spill").canonicalize().unwrap()) && path.extension().unwrap() == "md");
+        assert_eq!(spillover.expand(kept).unwrap().unwrap(), report());
+        assert_eq!(spillover.expand("Write the Q3 review.").unwrap(), None);
+    }
+
+    #[test]
+    fn test_json_outputs_keep_their_type() {
+        let dir = tempfile::tempdir().unwrap();
+        let spillover = Spillover::new(dir.path()).with_threshold(50);
+        let rows: Vec<u32> = (0..500).collect();
+        let output = serde_json::json!({ "rows": rows, "total": 500 }).to_string();
+
+        let spilled = spillover.spill("agent:Exporter", &output).unwrap().unwrap();
+        assert_eq!(spilled.path.extension().unwrap(), "json");
+        assert!(spilled.path.file_name().unwrap().to_string_lossy().starts_with("agent_Exporter-msg_"));
+        assert!(spilled.reference.contains("Outline: rows; total\n"));
+        assert_eq!(std::fs::read_to_string(&spilled.path).unwrap(), output);
+
+        assert_eq!(spillover.spill("agent:Exporter", "{\"ok\": true}").unwrap(), None);
+    }
+
+    #[test]
+    fn test_spilled_files_are_redacted_and_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = StaticSecretStore::new().with("STATE_KEY_main", StateCipher::generate_key());
+        let cipher = Arc::new(StateCipher::from_store(&store, &["main"]).unwrap());
+        let redactor = Arc::new(Redactor::new(&RedactionConfig::default()).unwrap());
+        let spillover = Arc::new(Spillover::new(dir.path()).with_threshold(50));
+        let mut conversation =
+            Conversation::builder().spillover(spillover).redactor(redactor).encryption(cipher.clone()).build().unwrap();
+
+        let output = format!("Contact jane.doe@example.com.\n{}", "Revenue grew 12%.\n".repeat(100));
+        let id = conversation.add("Analyst".to_string(), output.clone());
+        let kept = conversation.get(id).unwrap().content.clone();
+        assert!(kept.contains("Contact [REDACTED:EMAIL].") && !kept.contains("jane.doe"), "{}", kept);
+
+        let path = Spillover::new(dir.path()).reference_path(&kept).unwrap().unwrap();
+        let on_disk = std::fs::read(&path).unwrap();
+        assert!(StateCipher::is_encrypted(&on_disk));
+        let expanded = Spillover::new(dir.path()).with_encryption(cipher).expand(&kept).unwrap().unwrap();
+        assert_eq!(expanded, output.replace("jane.doe@example.com", "[REDACTED:EMAIL]"));
+    }
+
+    #[test]
+    fn test_references_outside_the_spill_directory_are_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let spillover = Spillover::new(dir.path().join("spill"));
+        std::fs::create_dir_all(spillover.dir()).unwrap();
+        std::fs::write(dir.path().join("secrets.env"), "API_KEY=sk-live").unwrap();
+
+        for path in [dir.path().join("secrets.env"), dir.path().join("spill").join("..").join("secrets.env")] {
+            let forged = format!("{}{} | 10 bytes, ~3 tokens]\nPreview:\n", SPILL_MARKER, path.display());
+            assert_eq!(spillover.expand(&forged).unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+        }
+    }
+}
+```
@@ -0,0 +1,114 @@
+### Tests for the evaluation harness
+Broken datasets must fail on load, before any model call. Each metric must score replayed answers as
+documented. The report must count errored cases apart from failed ones, and comparing two reports must
+name the cases that regressed or were fixed.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::eval::harness::{line_diff, parse_judge_reply};
+    use crate::swarms::eval::{CaseStatus, EvalDataset, EvalError, EvalHarness, EvalReport, ReplayResponder};
+    use std::fs;
+
+    const DATASET: &str = r#"{"id": "capital", "task": "Capital of France?", "expected": "Paris"}
+
+{"id": "year", "task": "Apollo 11 landing year?", "criteria": [{"type": "regex", "pattern": "\\b1969\\b"}]}
+{"task": "Explain recursion", "criteria": [{"type": "judge", "rubric": "One analogy"}]}
+{"id": "haiku", "task": "Write a haiku", "expected": "old pond\nfrog jumps in\nsound of water", "criteria": [{"type": "exact_match", "expected": "old pond\nfrog jumps in\nsound of water"}]}"#;
+
+    fn replay(answers: &[(&str, &str)]) -> ReplayResponder {
+        ReplayResponder::new(answers.iter().map(|(task, answer)| (task.to_string(), answer.to_string())))
+    }
+
+    #[test]
+    fn test_dataset_is_checked_on_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("golden.jsonl");
+        fs::write(&path, DATASET).unwrap();
+        let dataset = EvalDataset::from_jsonl(&path).unwrap();
+        assert_eq!(dataset.name, "golden");
+        assert_eq!(dataset.cases.len(), 4);
+        assert_eq!(dataset.cases[2].id, "line-4");
+
+        assert!(matches!(EvalDataset::parse("d", "{\"task\": \"x\"}"), Err(EvalError::NoCriteria(id)) if id == "line-1"));
+        assert!(matches!(EvalDataset::parse("d", "\n{\"task\": 1}"), Err(EvalError::Parse { line: 2, .. })));
+        assert!(matches!(EvalDataset::parse("d", ""), Err(EvalError::EmptyDataset)));
+        let duplicate = "{\"id\": \"a\", \"task\": \"x\", \"expected\": \"1\"}\n{\"id\": \"a\", \"task\": \"y\", \"expected\": \"2\"}";
+        assert!(matches!(EvalDataset::parse("d", duplicate), Err(EvalError::DuplicateId(id)) if id == "a"));
+        let bad_regex = r#"{"id": "r", "task": "x", "criteria": [{"type": "regex", "pattern": "("}]}"#;
+        assert!(matches!(EvalDataset::parse("d", bad_regex), Err(EvalError::InvalidRegex { case, .. }) if case == "r"));
+    }
+
+    #[test]
+    fn test_metrics_score_replayed_answers() {
+        let dataset = EvalDataset::parse("golden", DATASET).unwrap();
+        let recorded = replay(&[
+            ("Capital of France?", "  PARIS "),
+            ("Apollo 11 landing year?", "July 1969"),
+            ("Explain recursion", "Like nesting dolls."),
+            ("Write a haiku", "old pond\na frog jumps in\nsound of water"),
+        ]);
+        let mut prompts = Vec::new();
+        let report = EvalHarness::new(dataset)
+            .subject("prompt-v1")
+            .judge(|prompt| {
+                prompts.push(prompt.to_string());
+                Ok("**Score**: 6\nOnly half an analogy.".to_string())
+            })
+            .run(|case| recorded.respond(&case.task))
+            .unwrap();
+        assert!(prompts[0].contains("Rubric:\nOne analogy") && prompts[0].contains("Answer:\nLike nesting dolls."));
+
+        let status: Vec<_> = report.cases.iter().map(|case| (case.id.as_str(), case.status)).collect();
+        assert_eq!(
+            status,
+            [("capital", CaseStatus::Passed), ("year", CaseStatus::Passed), ("line-4", CaseStatus::Failed), ("haiku", CaseStatus::Failed)]
+        );
+        assert_eq!(report.cases[2].scores[0].score, 0.6);
+        assert_eq!(report.cases[2].scores[0].detail, "Only half an analogy.");
+        assert_eq!(report.cases[3].diff.as_deref(), Some("  old pond\n- frog jumps in\n+ a frog jumps in\n  sound of water\n"));
+        assert_eq!((report.passed, report.failed, report.errored), (2, 2, 0));
+        assert_eq!(report.pass_rate, 0.5);
+        assert!((report.mean_score - 0.65).abs() < 1e-9);
+        assert_eq!(report.metrics["exact_match"].cases, 2);
+        assert_eq!(report.metrics["exact_match"].passed, 1);
+        assert!(report.to_markdown().contains("```diff\n  old pond\n- frog jumps in"));
+    }
+
+    #[test]
+    fn test_errors_are_separate_from_failures() {
+        let dataset = EvalDataset::parse("golden", DATASET).unwrap();
+        let recorded = replay(&[("Capital of France?", "Lyon"), ("Explain recursion", "Dolls.")]);
+        let report = EvalHarness::new(dataset).run(|case| recorded.respond(&case.task)).unwrap();
+
+        assert_eq!((report.passed, report.failed, report.errored), (0, 1, 3));
+        assert!(report.cases[1].error.as_deref().unwrap().contains("no recorded answer"));
+        assert_eq!(report.cases[2].error.as_deref(), Some("the case needs a judge and none is configured"));
+        assert_eq!(report.cases[2].output.as_deref(), Some("Dolls."));
+        assert_eq!(report.cases[2].score(), 0.0);
+
+        assert_eq!(parse_judge_reply("SCORE: 12"), Some((1.0, String::new())));
+        assert_eq!(parse_judge_reply("Looks fine"), None);
+        assert_eq!(line_diff("a", "a"), "  a\n");
+    }
+
+    #[test]
+    fn test_reports_round_trip_and_compare() {
+        let dir = tempfile::tempdir().unwrap();
+        let dataset = EvalDataset::parse("golden", DATASET).unwrap();
+        let before = replay(&[("Capital of France?", "Paris"), ("Apollo 11 landing year?", "1968")]);
+        let after = replay(&[("Capital of France?", "Lyon"), ("Apollo 11 landing year?", "1969")]);
+
+        let path = dir.path().join("reports/baseline.json");
+        EvalHarness::new(dataset.clone()).subject("v1").report_to(&path).run(|case| before.respond(&case.task)).unwrap();
+        let baseline = EvalReport::load(&path).unwrap();
+        assert_eq!(baseline.subject, "v1");
+
+        let report = EvalHarness::new(dataset).subject("v2").run(|case| after.respond(&case.task)).unwrap();
+        let comparison = report.compare(&baseline);
+        assert_eq!(comparison.regressions, ["capital"]);
+        assert_eq!(comparison.fixes, ["year"]);
+        assert_eq!(comparison.pass_rate_delta, 0.0);
+    }
+}
+```
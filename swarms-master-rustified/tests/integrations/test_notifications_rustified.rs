@@ -0,0 +1,87 @@
+### Tests for run notifications
+A run's outcome must map to the right `RunEvent`, and a workflow with a notifier must tell its sinks how
+each run ended. Sinks record into a channel, so the tests wait for the detached delivery.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::notifications::{NotificationError, NotificationSink, Notifier, RunEvent, RunSummary};
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use std::time::Duration;
+    use tokio::sync::mpsc;
+
+    struct Recording(mpsc::UnboundedSender<RunSummary>);
+
+    #[async_trait]
+    impl NotificationSink for Recording {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        async fn notify(&self, run: &RunSummary) -> Result<(), NotificationError> {
+            self.0.send(run.clone()).unwrap();
+            Ok(())
+        }
+    }
+
+    fn workflow(events: &[RunEvent]) -> (SequentialWorkflow, mpsc::UnboundedReceiver<RunSummary>) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let agents = ["Researcher", "Writer"]
+            .iter()
+            .map(|name| LlmAgent::builder().name(*name).llm("gpt-4o-mini").build().unwrap().into())
+            .collect();
+        let workflow = SequentialWorkflow::new("Release".into(), String::new(), agents, 1, "str".into(), false, || ())
+            .with_notifier(Notifier::new().with_sink(events, Arc::new(Recording(tx))));
+        (workflow, rx)
+    }
+
+    async fn next(rx: &mut mpsc::UnboundedReceiver<RunSummary>) -> RunSummary {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv()).await.expect("a notification").unwrap()
+    }
+
+    #[test]
+    fn test_summary_of_outcome() {
+        let took = Duration::from_millis(1500);
+        let done = RunSummary::of_outcome("Release", "run-1", Ok("Notes drafted."), took);
+        assert_eq!((done.event, done.summary.as_str(), done.duration_secs), (RunEvent::Completed, "Notes drafted.", 1.5));
+
+        let failed = RunSummary::of_outcome("Release", "run-1", Err("Writer failed: provider returned 503"), took);
+        assert_eq!(failed.event, RunEvent::Failed);
+        let over = RunSummary::of_outcome("Release", "run-1", Err("Writer failed: token budget of 4000 spent"), took);
+        assert_eq!(over.event, RunEvent::BudgetExceeded);
+
+        let long = "x".repeat(2000);
+        let cut = RunSummary::of_outcome("Release", "run-1", Ok(&long), took);
+        assert!(cut.summary.len() < long.len() && cut.summary.ends_with("..."));
+    }
+
+    #[tokio::test]
+    async fn test_workflow_notifies_on_completion_and_failure() {
+        let (workflow, mut rx) = workflow(&RunEvent::ALL);
+        workflow.run_with("Draft the notes", |agent, input| Ok(format!("{}({})", agent.name(), input))).unwrap();
+        let done = next(&mut rx).await;
+        assert_eq!((done.swarm_name.as_str(), done.event), ("Release", RunEvent::Completed));
+        assert_eq!(done.summary, "Writer(Researcher(Draft the notes))");
+
+        let run = workflow.run_recorded("Draft the notes", |agent, _| match agent.name() {
+            "Writer" => Err("provider returned 503".to_string()),
+            _ => Ok("facts".to_string()),
+        });
+        let failed = next(&mut rx).await;
+        assert_eq!((failed.event, failed.run_id.as_str()), (RunEvent::Failed, run.run_id.as_str()));
+        assert!(failed.summary.contains("Writer failed: provider returned 503"), "{}", failed.summary);
+    }
+
+    #[tokio::test]
+    async fn test_sinks_only_hear_their_events() {
+        let (workflow, mut rx) = workflow(&[RunEvent::Failed]);
+        workflow.run_with("Draft the notes", |_, input| Ok(input.to_string())).unwrap();
+        assert!(workflow.run_with("Draft the notes", |_, _| Err("boom".to_string())).is_err());
+        assert_eq!(next(&mut rx).await.event, RunEvent::Failed);
+        assert!(rx.try_recv().is_err(), "the completed run was not delivered");
+    }
+}
+```
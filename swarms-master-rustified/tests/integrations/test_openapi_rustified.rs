@@ -0,0 +1,130 @@
+### Tests for the OpenAPI document
+The document must be valid OpenAPI 3.1 with the shared schemas, and every route it documents must be served
+by a handler, so a route that is removed or renamed without updating `integrations::openapi` fails here.
+
+```rust
+#[cfg(all(test, feature = "openapi"))]
+mod tests {
+    use crate::swarms::integrations::openapi::{api_spec, ApiDocs};
+    use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+    use crate::swarms::structs::agent_store::AgentStore;
+    use crate::swarms::structs::approval_gate::PendingApprovals;
+    use crate::swarms::structs::audit::AuditLog;
+    use crate::swarms::structs::chat_completions::ChatCompletions;
+    use crate::swarms::structs::demo_mode::{DemoConfig, DemoGate};
+    use crate::swarms::structs::health::Health;
+    use crate::swarms::structs::tenancy::{TenantId, TenantWorkspace};
+    use crate::swarms::utils::metrics::SwarmMetrics;
+    use serde_json::Value as JsonValue;
+
+    fn spec_json() -> JsonValue {
+        serde_json::from_str(&api_spec().to_json().unwrap()).unwrap()
+    }
+
+    /// `/approvals/{run_id}/{gate}` becomes `/approvals/x/x`.
+    fn concrete(path: &str) -> String {
+        path.split('/').map(|segment| if segment.starts_with('{') { "x" } else { segment }).collect::<Vec<_>>().join("/")
+    }
+
+    /// A problem document, or for the `/v1` routes OpenAI's error envelope, saying no handler serves the route.
+    fn is_no_route(status: u16, body: &str) -> bool {
+        let openai_message = || serde_json::from_str::<JsonValue>(body).ok()?["error"]["message"].as_str().map(str::to_string);
+        let detail = Problem::parse(body).map(|problem| problem.detail).or_else(openai_message);
+        status == 404 && detail.is_some_and(|detail| detail.starts_with("no route for"))
+    }
+
+    #[test]
+    fn test_document_is_openapi_3_1() {
+        let spec = spec_json();
+        assert!(spec["openapi"].as_str().unwrap().starts_with("3.1"));
+        for schema in ["Problem", "StoredAgent", "ApprovalRequest", "AuditEvent", "ComponentStatus"] {
+            assert!(spec["components"]["schemas"][schema].is_object(), "missing schema {}", schema);
+        }
+
+        let list_agents = &spec["paths"]["/users/me/agents"]["get"];
+        let params: Vec<&str> = list_agents["parameters"].as_array().unwrap().iter().map(|p| p["name"].as_str().unwrap()).collect();
+        assert_eq!(params, ["tag", "model", "limit", "cursor", "sort"]);
+        assert!(list_agents["responses"]["default"]["content"][PROBLEM_JSON].is_object());
+    }
+
+    #[tokio::test]
+    async fn test_every_documented_route_is_served() {
+        let dir = tempfile::tempdir().unwrap();
+        let acme = TenantId::new("acme").unwrap();
+        let agents = AgentStore::new(TenantWorkspace::new(dir.path()));
+        let audit = AuditLog::new(dir.path().join("audit.jsonl"));
+        let approvals = PendingApprovals::new();
+        let demo = DemoGate::new(DemoConfig::default());
+        let health = Health::new();
+        let metrics = SwarmMetrics::new();
+        let docs = ApiDocs::new();
+        let completions = ChatCompletions::new();
+        #[cfg(feature = "server")]
+        let ledger = crate::swarms::structs::distributed_queue::TaskLedger::new(std::time::Duration::from_secs(30));
+
+        let spec = spec_json();
+        for (path, item) in spec["paths"].as_object().unwrap() {
+            for method in item.as_object().unwrap().keys() {
+                let (method, path) = (method.to_uppercase(), concrete(path));
+                let (status, body) = match path.split('/').nth(1).unwrap() {
+                    "users" => {
+                        let (status, _, body) = agents.handle_http(&acme, &method, &path);
+                        (status, body)
+                    }
+                    #[cfg(feature = "server")]
+                    "runs" => {
+                        let keys = crate::swarms::structs::tenancy::TenantKeys::new().with_key("sk-acme", acme.clone());
+                        let (status, _, body) = ledger.handle_http(&keys, Some("Bearer sk-acme"), &method, &path);
+                        (status, body)
+                    }
+                    "approvals" => {
+                        let (status, body) = approvals.handle_http(&method, &path, r#"{"approve": true}"#);
+                        (status, body.to_string())
+                    }
+                    "audit" => {
+                        let (status, _, body) = audit.handle_http(&acme, &method, &path);
+                        (status, body)
+                    }
+                    "demo" => {
+                        let (status, body) = demo.handle_http(&method, &path, "203.0.113.7".parse().unwrap());
+                        (status, body.to_string())
+                    }
+                    "healthz" | "readyz" => {
+                        let (status, body) = health.handle_http(&method, &path).await;
+                        (status, body.to_string())
+                    }
+                    "v1" => {
+                        let (status, _, body) = completions.handle_http(&method, &path, r#"{"model": "x", "messages": []}"#);
+                        (status, body)
+                    }
+                    "metrics" => {
+                        let (status, _, body) = metrics.handle_http(&method, &path);
+                        (status, body)
+                    }
+                    _ => {
+                        let (status, _, body) = docs.handle_http(&method, &path);
+                        (status, body)
+                    }
+                };
+                assert!(!is_no_route(status, &body), "{} {} is documented but not served", method, path);
+            }
+        }
+    }
+
+    #[test]
+    fn test_docs_routes() {
+        let docs = ApiDocs::new();
+        let (status, content_type, page) = docs.handle_http("GET", "/docs");
+        assert_eq!((status, content_type), (200, "text/html; charset=utf-8"));
+        assert!(page.contains(r#"spec-url="openapi.json""#));
+
+        let (status, content_type, body) = docs.handle_http("GET", "/openapi.json");
+        assert_eq!((status, content_type), (200, "application/json"));
+        assert_eq!(serde_json::from_str::<JsonValue>(&body).unwrap(), spec_json());
+
+        let (status, content_type, body) = docs.handle_http("GET", "/swagger");
+        assert_eq!((status, content_type), (404, PROBLEM_JSON));
+        assert!(is_no_route(status, &body));
+    }
+}
+```
@@ -0,0 +1,104 @@
+### Tests for problem+json error responses
+Every `SwarmError`, `AgentError`, and `DemoError` variant must map to a stable status, code, and retry hint,
+the crate's HTTP handlers must answer failures with problems, and a problem document must survive the round
+trip through JSON and `RestClient`'s error envelope.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::problem::{Problem, PROBLEM_JSON};
+    use crate::swarms::integrations::rest::{DefaultErrorEnvelope, ErrorEnvelope};
+    use crate::swarms::structs::agent::AgentError;
+    use crate::swarms::structs::approval_gate::PendingApprovals;
+    use crate::swarms::structs::demo_mode::{DemoConfig, DemoError, DemoGate};
+    use crate::swarms::structs::scoped_tasks::TaskFailures;
+    use crate::swarms::structs::swarm::SwarmError;
+    use serde_json::{json, Value as JsonValue};
+    use std::time::Duration;
+
+    #[test]
+    fn test_swarm_errors_map_to_problems() {
+        let cases = [
+            (SwarmError::NoAgents, 422, "no_agents", false),
+            (SwarmError::Tasks(TaskFailures(Vec::new())), 502, "agent_tasks_failed", true),
+            (SwarmError::Failed("judge returned nothing".to_string()), 500, "swarm_failed", false),
+        ];
+        for (err, status, code, retriable) in cases {
+            let problem = Problem::from(&err);
+            assert_eq!((problem.status, problem.code.as_str(), problem.retriable), (status, code, retriable));
+            assert_eq!(problem.detail, err.to_string());
+        }
+    }
+
+    #[test]
+    fn test_agent_errors_map_to_problems() {
+        let unbound = Problem::from(AgentError::NoModel("Analyst".to_string()));
+        assert_eq!((unbound.status, unbound.code.as_str(), unbound.retriable), (503, "agent_unbound", false));
+
+        let failed = Problem::from(AgentError::Failed { agent: "Analyst".to_string(), message: "timeout".to_string() });
+        assert_eq!((failed.status, failed.code.as_str(), failed.retriable), (502, "agent_failed", true));
+        assert_eq!(failed.detail, "agent 'Analyst' failed: timeout");
+    }
+
+    #[test]
+    fn test_problem_serializes_as_rfc_9457() {
+        let problem = Problem::from(SwarmError::NoAgents).with_request_id("req-42");
+        assert_eq!(problem.content_type(), PROBLEM_JSON);
+        let body: JsonValue = serde_json::from_str(&problem.to_json()).unwrap();
+        assert_eq!(
+            body,
+            json!({
+                "type": "https://swarms.world/problems/no-agents",
+                "title": "The swarm has no agents",
+                "status": 422,
+                "detail": "no agents configured for the swarm",
+                "code": "no_agents",
+                "request_id": "req-42",
+                "retriable": false,
+            })
+        );
+        assert_eq!(Problem::parse(&problem.to_json()), Some(problem.clone()));
+        assert_eq!(problem.to_string(), "no_agents (422): no agents configured for the swarm [request req-42]");
+
+        let anonymous = Problem::not_found("agent 1234 does not exist").to_json();
+        assert!(!anonymous.contains("request_id"));
+    }
+
+    #[test]
+    fn test_demo_limits_keep_their_retry_hint() {
+        let problem = Problem::from(&DemoError::RateLimited { retry_after: Duration::from_millis(1_200) });
+        assert_eq!((problem.status, problem.code.as_str(), problem.retriable), (429, "rate_limited", true));
+        assert_eq!(problem.to_value()["retry_after_secs"], 2);
+        assert_eq!(Problem::parse(&problem.to_json()), Some(problem));
+
+        let mismatch = DemoError::SessionMismatch.to_json();
+        assert_eq!((mismatch["status"].as_u64(), mismatch["code"].as_str()), (Some(403), Some("session_mismatch")));
+    }
+
+    #[test]
+    fn test_handlers_answer_with_problems() {
+        let gate = DemoGate::new(DemoConfig::default());
+        let (status, body) = gate.handle_http("DELETE", "/demo/sessions", "203.0.113.7".parse().unwrap());
+        assert_eq!((status, body["code"].as_str()), (404, Some("not_found")));
+        assert_eq!(body["detail"], "no route for DELETE /demo/sessions");
+
+        let pending = PendingApprovals::new();
+        let (status, body) = pending.handle_http("POST", "/approvals/run-1/review", "not json");
+        assert_eq!((status, body["code"].as_str()), (400, Some("invalid_request")));
+        let (status, body) = pending.handle_http("POST", "/approvals/run-1/review", r#"{"approve": true}"#);
+        assert_eq!((status, body["status"].as_u64()), (404, Some(404)));
+    }
+
+    #[test]
+    fn test_parse_rejects_other_shapes() {
+        assert_eq!(Problem::parse(r#"{"detail": "Not Found"}"#), None);
+        assert_eq!(Problem::parse("Internal Server Error"), None);
+    }
+
+    #[test]
+    fn test_rest_client_reads_the_detail() {
+        let body = Problem::rate_limited("slow down").to_json();
+        assert_eq!(DefaultErrorEnvelope.message(429, &body), "slow down");
+    }
+}
+```
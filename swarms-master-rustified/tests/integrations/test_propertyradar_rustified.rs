@@ -0,0 +1,108 @@
+### Tests for the PropertyRadar client
+A paged transport stands in for `/properties`. Page 0 must be rejected before any request, pagination must
+stop at `total`, at a short page, or at an empty page when `total` overstates the count, and a malformed
+listing must be skipped and counted.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::propertyradar::{PropertyRadarClient, PropertyRadarError, PropertySearch};
+    use crate::swarms::integrations::rest::{HttpRequest, HttpResponse, HttpTransport, RestError};
+    use async_trait::async_trait;
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+
+    // Serves `pages[n - 1]` for `page=n`, with `total` when it is set
+    struct Pages {
+        pages: Vec<Vec<Value>>,
+        total: Option<u64>,
+        requests: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for Pages {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+            let page: usize = request.query.iter().find(|(key, _)| key == "page").unwrap().1.parse().unwrap();
+            let results = self.pages.get(page - 1).cloned().unwrap_or_default();
+            self.requests.lock().unwrap().push(request);
+            let body = match self.total {
+                Some(total) => json!({ "results": results, "total": total }),
+                None => json!({ "results": results }),
+            };
+            Ok(HttpResponse { status: 200, body: body.to_string() })
+        }
+    }
+
+    fn listing(id: &str) -> Value {
+        json!({
+            "id": id, "address": "1 Main St", "city": "Orlando", "state": "FL", "zip_code": "32801",
+            "price": 950000.0, "square_feet": 4200.0, "property_type": "retail", "list_date": "2024-05-01",
+            "latitude": 28.54, "longitude": -81.38
+        })
+    }
+
+    fn client(pages: Vec<Vec<Value>>, total: Option<u64>) -> (PropertyRadarClient, Arc<Pages>) {
+        let transport = Arc::new(Pages { pages, total, requests: Mutex::new(Vec::new()) });
+        (PropertyRadarClient::with_transport("pr-key", transport.clone()), transport)
+    }
+
+    fn ids(listings: &[crate::swarms::integrations::propertyradar::PropertyListing]) -> Vec<&str> {
+        listings.iter().map(|listing| listing.property_id.as_str()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_page_zero_is_rejected_without_a_request() {
+        let (client, transport) = client(vec![vec![listing("a")]], None);
+        let err = client.search_page(&PropertySearch::new(), 0).await.unwrap_err();
+        assert!(matches!(err, PropertyRadarError::InvalidPage(0)), "{}", err);
+        assert!(transport.requests.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_pages_follow_the_total() {
+        let (client, transport) = client(vec![vec![listing("a"), listing("b")], vec![listing("c")]], Some(3));
+        let search = PropertySearch::new().page_size(2).city("Orlando");
+        let first = client.search_page(&search, 1).await.unwrap();
+        assert_eq!((ids(&first.listings), first.next_page), (vec!["a", "b"], Some(2)));
+        let second = client.search_page(&search, 2).await.unwrap();
+        assert_eq!((ids(&second.listings), second.next_page), (vec!["c"], None));
+
+        let requests = transport.requests.lock().unwrap();
+        assert_eq!(requests[1].url, "https://api.propertyradar.com/v1/properties");
+        for param in [("page", "2"), ("limit", "2"), ("city", "Orlando"), ("state", "FL")] {
+            assert!(requests[1].query.contains(&(param.0.to_string(), param.1.to_string())), "{:?}", requests[1].query);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_all_stops_at_a_short_page_and_skips_bad_listings() {
+        let pages = vec![vec![listing("a"), json!({ "id": "broken" })], vec![listing("c")], vec![listing("never")]];
+        let (client, transport) = client(pages, None);
+        let search = PropertySearch::new().page_size(2);
+
+        let first = client.search_page(&search, 1).await.unwrap();
+        assert_eq!((ids(&first.listings), first.skipped, first.next_page), (vec!["a"], 1, Some(2)));
+
+        let all = client.search_all(&search, 10).await.unwrap();
+        assert_eq!(ids(&all), ["a", "c"]);
+        assert_eq!(transport.requests.lock().unwrap().len(), 3);
+
+        let capped = client.search_all(&search, 1).await.unwrap();
+        assert_eq!(ids(&capped), ["a"]);
+    }
+
+    #[tokio::test]
+    async fn test_an_empty_page_ends_an_overstated_total() {
+        let (client, transport) = client(vec![vec![listing("a"), listing("b")], vec![listing("c"), listing("d")]], Some(40));
+        let search = PropertySearch::new().page_size(2);
+
+        let empty = client.search_page(&search, 3).await.unwrap();
+        assert_eq!((empty.listings.len(), empty.next_page), (0, None));
+
+        let all = client.search_all(&search, 100).await.unwrap();
+        assert_eq!(ids(&all), ["a", "b", "c", "d"]);
+        // Two full pages, then the empty one that ends it; the empty page above was the first request.
+        assert_eq!(transport.requests.lock().unwrap().len(), 4);
+    }
+}
+```
@@ -0,0 +1,157 @@
+### Tests for the event-stream client
+The parser must follow the event-stream format however the bytes are split, and `SseStream` must resume
+with `Last-Event-ID` after a drop or a missed heartbeat, discard an event cut off by the drop, stop on
+`204`, and give up on refusals and after `max_attempts` reconnects in a row.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::sse::{SseBody, SseConfig, SseError, SseParser, SseSource, SseStream};
+    use crate::swarms::integrations::webhooks::RetryPolicy;
+    use async_trait::async_trait;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    /// What one connect returns: a body that ends after its chunks, or hangs after them.
+    enum Script {
+        Body(&'static str),
+        Hang(&'static str),
+        Status(u16),
+        NoContent,
+    }
+
+    struct ScriptedBody {
+        chunks: Vec<Vec<u8>>,
+        hang: bool,
+    }
+
+    #[async_trait]
+    impl SseBody for ScriptedBody {
+        async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, SseError> {
+            if self.chunks.is_empty() {
+                if self.hang {
+                    std::future::pending::<()>().await;
+                }
+                return Ok(None);
+            }
+            Ok(Some(self.chunks.remove(0)))
+        }
+    }
+
+    struct ScriptedSource {
+        scripts: Mutex<Vec<Script>>,
+        last_ids: Arc<Mutex<Vec<Option<String>>>>,
+    }
+
+    #[async_trait]
+    impl SseSource for ScriptedSource {
+        async fn connect(&self, last_event_id: Option<&str>) -> Result<Option<Box<dyn SseBody>>, SseError> {
+            self.last_ids.lock().unwrap().push(last_event_id.map(str::to_string));
+            let body = |text: &str, hang| Box::new(ScriptedBody { chunks: vec![text.as_bytes().to_vec()], hang });
+            match self.scripts.lock().unwrap().remove(0) {
+                Script::Body(text) => Ok(Some(body(text, false))),
+                Script::Hang(text) => Ok(Some(body(text, true))),
+                Script::Status(status) => Err(SseError::Status { status, body: String::new() }),
+                Script::NoContent => Ok(None),
+            }
+        }
+    }
+
+    fn stream(scripts: Vec<Script>, max_attempts: u32) -> (SseStream, Arc<Mutex<Vec<Option<String>>>>) {
+        let last_ids = Arc::new(Mutex::new(Vec::new()));
+        let source = ScriptedSource { scripts: Mutex::new(scripts), last_ids: last_ids.clone() };
+        let config = SseConfig {
+            heartbeat_timeout: Duration::from_millis(50),
+            retry: RetryPolicy { max_attempts, initial_backoff: Duration::ZERO, max_backoff: Duration::ZERO },
+            resume_only: false,
+        };
+        (SseStream::new(source, config), last_ids)
+    }
+
+    async fn data(stream: &mut SseStream) -> Vec<String> {
+        let mut data = Vec::new();
+        while let Some(event) = stream.next().await {
+            data.push(event.unwrap().data);
+        }
+        data
+    }
+
+    #[test]
+    fn test_parser_follows_the_format() {
+        let body = "retry: 2500\r\nevent: delta\r\nid: 7\r\ndata: first\r\ndata:second\r\n\r\n\
+                    : ping\n\ndata: {}\r\rid\n\n";
+        for size in [1, 3, body.len()] {
+            let mut parser = SseParser::new();
+            let events: Vec<_> = body.as_bytes().chunks(size).flat_map(|chunk| parser.push(chunk)).collect();
+            assert_eq!(events.len(), 2, "chunk size {}", size);
+            assert_eq!((events[0].event.as_str(), events[0].data.as_str()), ("delta", "first\nsecond"));
+            assert_eq!(events[0].id.as_deref(), Some("7"));
+            // The id carries over to later events until the server changes it. An empty `id` clears it.
+            assert_eq!((events[1].event.as_str(), events[1].id.as_deref()), ("message", Some("7")));
+            assert_eq!(parser.last_event_id(), None);
+            assert_eq!(parser.retry(), Some(Duration::from_millis(2500)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resumes_with_last_event_id() {
+        let (mut stream, last_ids) = stream(
+            vec![
+                Script::Body("id: 1\ndata: a\n\nid: 2\ndata: b\n\n"),
+                Script::Body("id: 3\ndata: c\n\n"),
+                Script::NoContent,
+            ],
+            3,
+        );
+        assert_eq!(data(&mut stream).await, ["a", "b", "c"]);
+        assert_eq!(*last_ids.lock().unwrap(), [None, Some("2".to_string()), Some("3".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_half_read_event_is_dropped_on_reconnect() {
+        let scripts = vec![
+            Script::Body("id: 1\ndata: a\n\nevent: delta\ndata: cut of"),
+            Script::Body("f\n\ndata: b\n\n"),
+            Script::NoContent,
+        ];
+        let (mut stream, last_ids) = stream(scripts, 3);
+        let first = stream.next().await.unwrap().unwrap();
+        assert_eq!(first.data, "a");
+        // The partial `delta` event and the partial line are gone; the new connection parses from scratch.
+        let second = stream.next().await.unwrap().unwrap();
+        assert_eq!((second.event.as_str(), second.data.as_str()), ("message", "b"));
+        assert_eq!(stream.next().await, None);
+        assert_eq!(last_ids.lock().unwrap()[1], Some("1".to_string()));
+    }
+
+    #[tokio::test]
+    async fn test_missed_heartbeat_reconnects() {
+        let scripts =
+            vec![Script::Hang("id: 1\ndata: a\n\n"), Script::Status(503), Script::Body("data: b\n\n"), Script::NoContent];
+        let (mut stream, last_ids) = stream(scripts, 3);
+        assert_eq!(data(&mut stream).await, ["a", "b"]);
+        assert_eq!(last_ids.lock().unwrap()[1..3], [Some("1".to_string()), Some("1".to_string())]);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up_on_refusals_and_exhausted_retries() {
+        let (mut refused, _) = stream(vec![Script::Status(401)], 3);
+        assert_eq!(refused.next().await, Some(Err(SseError::Status { status: 401, body: String::new() })));
+        assert_eq!(refused.next().await, None);
+
+        let (mut flapping, last_ids) = stream(vec![Script::Status(503), Script::Status(502), Script::Status(500)], 2);
+        assert!(matches!(flapping.next().await, Some(Err(SseError::RetriesExhausted { attempts: 2, .. }))));
+        assert_eq!(last_ids.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_close_stops_reading() {
+        let (mut stream, last_ids) = stream(vec![Script::Body("data: a\n\ndata: [DONE]\n\n")], 3);
+        assert_eq!(stream.next().await.unwrap().unwrap().data, "a");
+        assert_eq!(stream.next().await.unwrap().unwrap().data, "[DONE]");
+        stream.close();
+        assert_eq!(stream.next().await, None);
+        assert_eq!(last_ids.lock().unwrap().len(), 1);
+    }
+}
+```
@@ -0,0 +1,151 @@
+### Tests for the swarms API client
+The client must send the requests the API test sends by hand (paths, `api-key` header, and bodies), follow
+list cursors to the last page, surface problem documents as typed errors, and read streamed completions up
+to `[DONE]` without ever resending the request that started the run.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::problem::Problem;
+    use crate::swarms::integrations::rest::{HttpMethod, HttpRequest, HttpResponse, HttpTransport, RestError};
+    use crate::swarms::integrations::sse::{SseBody, SseConfig, SseError, SseSource, SseStream};
+    use crate::swarms::integrations::swarms_client::{
+        AgentListQuery, AgentUpdate, CompletionStream, NewAgent, SwarmsClient, SwarmsClientError,
+    };
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use uuid::Uuid;
+
+    struct ScriptedTransport {
+        responses: Mutex<Vec<(u16, String)>>,
+        seen: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for ScriptedTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+            self.seen.lock().unwrap().push(request);
+            let (status, body) = self.responses.lock().unwrap().remove(0);
+            Ok(HttpResponse { status, body })
+        }
+    }
+
+    fn client(responses: Vec<(u16, String)>) -> (SwarmsClient, Arc<ScriptedTransport>) {
+        let transport = Arc::new(ScriptedTransport { responses: Mutex::new(responses), seen: Mutex::new(Vec::new()) });
+        let client = SwarmsClient::new("http://localhost:8000/v1").with_api_key("key-1").with_transport(transport.clone());
+        (client, transport)
+    }
+
+    fn summary(agent_id: Uuid) -> serde_json::Value {
+        json!({
+            "agent_id": agent_id,
+            "agent_name": "Analyst",
+            "created_at": "2024-05-01T00:00:00Z",
+            "last_used": "2024-05-01T00:00:00Z",
+            "status": "active",
+        })
+    }
+
+    #[tokio::test]
+    async fn test_agent_requests_match_the_api() {
+        let agent_id = Uuid::new_v4();
+        let (client, transport) = client(vec![
+            (200, json!({ "agent_id": agent_id }).to_string()),
+            (200, json!({ "status": "updated" }).to_string()),
+        ]);
+        let created = client.create_agent(&NewAgent::new("Analyst", "gpt-4", "You are a helpful assistant").tag("test")).await;
+        assert_eq!(created.unwrap(), agent_id);
+        let update = AgentUpdate { description: Some("Updated description".to_string()), ..Default::default() };
+        client.update_agent(agent_id, &update).await.unwrap();
+
+        let seen = transport.seen.lock().unwrap();
+        assert_eq!((seen[0].method, seen[0].url.as_str()), (HttpMethod::Post, "http://localhost:8000/v1/agent"));
+        assert!(seen[0].headers.contains(&("api-key".to_string(), "key-1".to_string())));
+        assert_eq!(seen[0].body.as_ref().unwrap()["tags"], json!(["test"]));
+        assert!(seen[0].body.as_ref().unwrap().get("temperature").is_none());
+        assert_eq!(seen[1].method, HttpMethod::Patch);
+        assert_eq!(seen[1].url, format!("http://localhost:8000/v1/agent/{}", agent_id));
+        assert_eq!(seen[1].body, Some(json!({ "description": "Updated description" })));
+    }
+
+    #[tokio::test]
+    async fn test_all_agents_follows_cursors() {
+        let (first, second) = (Uuid::new_v4(), Uuid::new_v4());
+        let (client, transport) = client(vec![
+            (200, json!({ "items": [summary(first)], "next_cursor": "c1" }).to_string()),
+            (200, json!({ "items": [summary(second)], "next_cursor": null }).to_string()),
+        ]);
+        let query = AgentListQuery { tag: Some("test".to_string()), limit: Some(1), ..Default::default() };
+        let agents = client.all_agents(&query).await.unwrap();
+        assert_eq!(agents.iter().map(|agent| agent.agent_id).collect::<Vec<_>>(), [first, second]);
+
+        let seen = transport.seen.lock().unwrap();
+        assert!(!seen[0].query.iter().any(|(key, _)| key == "cursor"));
+        assert!(seen[1].query.contains(&("cursor".to_string(), "c1".to_string())));
+        assert!(seen[1].query.contains(&("tag".to_string(), "test".to_string())));
+    }
+
+    #[tokio::test]
+    async fn test_failures_carry_the_problem() {
+        let limited = Problem::rate_limited("slow down").with_request_id("req-7");
+        let (client, _) = client(vec![(429, limited.to_json()), (502, "Bad Gateway".to_string())]);
+
+        let err = client.agent_metrics(Uuid::new_v4()).await.unwrap_err();
+        assert_eq!(err.problem().map(|problem| problem.code.as_str()), Some("rate_limited"));
+        assert_eq!(err.problem().unwrap().request_id.as_deref(), Some("req-7"));
+        assert!(err.is_retriable());
+
+        let err = client.delete_agent(Uuid::new_v4()).await.unwrap_err();
+        assert!(matches!(err, SwarmsClientError::Rest(RestError::Api { status: 502, .. })));
+        assert!(!err.is_retriable());
+    }
+
+    struct OneBody(&'static str);
+
+    struct ChunkedBody(Vec<Vec<u8>>);
+
+    #[async_trait]
+    impl SseBody for ChunkedBody {
+        async fn next_chunk(&mut self) -> Result<Option<Vec<u8>>, SseError> {
+            Ok((!self.0.is_empty()).then(|| self.0.remove(0)))
+        }
+    }
+
+    #[async_trait]
+    impl SseSource for OneBody {
+        async fn connect(&self, _last_event_id: Option<&str>) -> Result<Option<Box<dyn SseBody>>, SseError> {
+            Ok(Some(Box::new(ChunkedBody(self.0.as_bytes().chunks(7).map(<[u8]>::to_vec).collect()))))
+        }
+    }
+
+    fn stream_of(body: &'static str) -> CompletionStream {
+        let config = SseConfig { resume_only: true, ..SseConfig::default() };
+        CompletionStream::new(SseStream::new(OneBody(body), config))
+    }
+
+    #[tokio::test]
+    async fn test_completion_stream_reads_until_done() {
+        let body = "data: {\"delta\": \"Hel\"}\n\n: keep-alive\n\ndata: {\"delta\": \"lo\"}\n\n\
+                    data: {\"delta\": \"\", \"token_usage\": {\"total_tokens\": 12}}\n\ndata: [DONE]\n\n";
+        let mut stream = stream_of(body);
+        let mut chunks = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            chunks.push(chunk.unwrap());
+        }
+        assert_eq!(chunks.iter().map(|chunk| chunk.delta.as_str()).collect::<String>(), "Hello");
+        assert_eq!(chunks.last().unwrap().token_usage.as_ref().unwrap()["total_tokens"], 12);
+    }
+
+    #[tokio::test]
+    async fn test_completion_stream_without_ids_is_not_resent() {
+        // The body ends without [DONE] and without an event id, so resending would start a second run.
+        let err = stream_of("data: {\"delta\": \"Hel\"}\n\n").collect_text().await.unwrap_err();
+        assert!(matches!(err, SwarmsClientError::Stream(_)));
+        assert!(!err.is_retriable());
+
+        let err = stream_of("data: {not json}\n\n").collect_text().await.unwrap_err();
+        assert!(matches!(err, SwarmsClientError::Stream(_)));
+    }
+}
+```
@@ -0,0 +1,151 @@
+### Tests for outbound webhooks
+Deliveries go through a scripted `HttpTransport`, and the retry policy uses zero backoff to keep the tests fast.
+A workflow with webhooks must emit its run's start, each finished turn, and how the run ended.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::rest::{HttpRequest, HttpResponse, HttpTransport, RestError};
+    use crate::swarms::integrations::webhooks::{
+        sign, verify_signature, RetryPolicy, Webhook, WebhookDispatcher, WebhookEvent, WebhookPayload,
+        SIGNATURE_HEADER,
+    };
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::text::prompt_privacy::PromptPrivacy;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    struct ScriptedTransport {
+        statuses: Mutex<Vec<u16>>,
+        seen: Mutex<Vec<HttpRequest>>,
+    }
+
+    #[async_trait]
+    impl HttpTransport for ScriptedTransport {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+            self.seen.lock().unwrap().push(request);
+            let status = self.statuses.lock().unwrap().remove(0);
+            Ok(HttpResponse { status, body: String::new() })
+        }
+    }
+
+    fn dispatcher(statuses: Vec<u16>) -> (WebhookDispatcher, Arc<ScriptedTransport>) {
+        let transport = Arc::new(ScriptedTransport { statuses: Mutex::new(statuses), seen: Mutex::new(Vec::new()) });
+        let dispatcher = WebhookDispatcher::new().with_transport(transport.clone()).with_retry_policy(RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        });
+        (dispatcher, transport)
+    }
+
+    fn payload(event: WebhookEvent) -> WebhookPayload {
+        WebhookPayload::new(event, "swarm", "run-1", json!({ "summary": "done" }))
+    }
+
+    #[test]
+    fn test_signature_roundtrip() {
+        let header = sign("secret", 1_700_000_000, "{\"a\":1}");
+        assert!(verify_signature("secret", &header, "{\"a\":1}", 1_700_000_010));
+        assert!(!verify_signature("other", &header, "{\"a\":1}", 1_700_000_010));
+        assert!(!verify_signature("secret", &header, "{\"a\":2}", 1_700_000_010));
+        assert!(!verify_signature("secret", &header, "{\"a\":1}", 1_700_001_000));
+        assert!(!verify_signature("secret", "garbage", "{\"a\":1}", 1_700_000_000));
+    }
+
+    #[test]
+    fn test_backoff_doubles_and_caps() {
+        let policy = RetryPolicy { max_attempts: 5, initial_backoff: Duration::from_secs(1), max_backoff: Duration::from_secs(5) };
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(4), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn test_retries_server_errors_then_succeeds() {
+        let (dispatcher, transport) = dispatcher(vec![500, 503, 200]);
+        let hook = Webhook::new("https://example.com/hook", "secret", &[WebhookEvent::RunCompleted]);
+        let attempts = dispatcher.deliver(&hook, &payload(WebhookEvent::RunCompleted)).await.unwrap();
+        assert_eq!(attempts, 3);
+
+        let seen = transport.seen.lock().unwrap();
+        let request = &seen[2];
+        let header = &request.headers.iter().find(|(name, _)| name == SIGNATURE_HEADER).unwrap().1;
+        let body = serde_json::to_string(request.body.as_ref().unwrap()).unwrap();
+        assert!(verify_signature("secret", header, &body, chrono::Utc::now().timestamp()));
+    }
+
+    #[tokio::test]
+    async fn test_client_errors_are_not_retried() {
+        let (dispatcher, transport) = dispatcher(vec![400, 200]);
+        let hook = Webhook::new("https://example.com/hook", "secret", &[WebhookEvent::RunFailed]);
+        assert!(dispatcher.deliver(&hook, &payload(WebhookEvent::RunFailed)).await.is_err());
+        assert_eq!(transport.seen.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_emit_filters_by_event_and_scope() {
+        let (dispatcher, transport) = dispatcher(vec![200, 200, 200]);
+        dispatcher.register_for_swarm("swarm", Webhook::new("https://a", "s", &[WebhookEvent::RunCompleted]));
+        dispatcher.register_for_swarm("swarm", Webhook::new("https://b", "s", &[WebhookEvent::RunStarted]));
+        dispatcher.register_for_swarm("other", Webhook::new("https://c", "s", &WebhookEvent::ALL));
+        dispatcher.register_for_run("run-1", Webhook::new("https://d", "s", &WebhookEvent::ALL));
+
+        assert_eq!(dispatcher.emit(payload(WebhookEvent::RunCompleted)).await, 0);
+        let mut urls: Vec<String> = transport.seen.lock().unwrap().iter().map(|r| r.url.clone()).collect();
+        urls.sort();
+        assert_eq!(urls, vec!["https://a", "https://d"]);
+    }
+
+    #[tokio::test]
+    async fn test_privacy_is_per_run() {
+        let (dispatcher, transport) = dispatcher(vec![200, 200, 200]);
+        let dispatcher = dispatcher.with_privacy(PromptPrivacy::Truncate { max_chars: 2 });
+        dispatcher.register_for_swarm("swarm", Webhook::new("https://a", "s", &WebhookEvent::ALL));
+        dispatcher.set_run_privacy("run-1", PromptPrivacy::Hash);
+
+        dispatcher.emit(payload(WebhookEvent::RunCompleted)).await;
+        dispatcher.finish_run("run-1");
+        dispatcher.emit(payload(WebhookEvent::RunCompleted)).await;
+        let summaries: Vec<String> = transport
+            .seen
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|request| request.body.as_ref().unwrap()["data"]["summary"].as_str().unwrap().to_string())
+            .collect();
+        assert!(summaries[0].starts_with("[sha256:"));
+        assert_eq!(summaries[1], "do…[2 chars truncated]");
+    }
+
+    #[tokio::test]
+    async fn test_workflow_emits_its_lifecycle() {
+        let (dispatcher, transport) = dispatcher(vec![200; 8]);
+        dispatcher.register_for_swarm("Release", Webhook::new("https://a", "s", &WebhookEvent::ALL));
+        let agents = ["Researcher", "Writer"]
+            .iter()
+            .map(|name| LlmAgent::builder().name(*name).llm("gpt-4o-mini").build().unwrap().into())
+            .collect();
+        let workflow = SequentialWorkflow::new("Release".into(), String::new(), agents, 1, "str".into(), false, || ())
+            .with_webhooks(dispatcher);
+        workflow.run_with("Draft the notes", |agent, input| Ok(format!("{}({})", agent.name(), input))).unwrap();
+
+        let events = || -> Vec<String> {
+            let seen = transport.seen.lock().unwrap();
+            seen.iter().map(|request| request.body.as_ref().unwrap()["event"].as_str().unwrap().to_string()).collect()
+        };
+        for _ in 0..100 {
+            if events().len() == 4 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        let mut seen = events();
+        seen.sort();
+        assert_eq!(seen, vec!["agent_step_completed", "agent_step_completed", "run_completed", "run_started"]);
+    }
+}
+```
@@ -0,0 +1,147 @@
+### Tests for the LangChain and LlamaIndex document formats
+Each serialized form must map onto the chunk fields, character offsets must only become byte offsets
+against the source text, stored embeddings must reach memory without an embedding call, a batch with a
+bad vector must insert nothing, and chunks written back out must read in again unchanged.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::memory::document_formats::{
+        parse_corpus, read_corpus, to_langchain, to_llamaindex, DocumentFormat, DocumentFormatError, ExternalChunk,
+    };
+    use crate::swarms::text::chunk::{ChunkStrategy, Chunker};
+    use crate::swarms::memory::vector_memory::{Embedder, MemoryError, VectorMemory};
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // Counts the texts it is asked to embed
+    struct CountingEmbedder(AtomicUsize);
+
+    #[async_trait]
+    impl Embedder for CountingEmbedder {
+        async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, MemoryError> {
+            self.0.fetch_add(texts.len(), Ordering::SeqCst);
+            Ok(texts.iter().map(|text| vec![text.contains("Revenue") as u8 as f32, 1.0, 0.0]).collect())
+        }
+    }
+
+    fn corpus() -> serde_json::Value {
+        json!([
+            {
+                "lc": 1, "type": "constructor", "id": ["langchain", "schema", "document", "Document"],
+                "kwargs": {
+                    "page_content": "Revenue grew 12%.",
+                    "metadata": { "source": "q3.md", "start_index": 40, "page": 2, "Header 2": "Results", "Header 1": "Q3" }
+                }
+            },
+            { "page_content": "Margins held.", "metadata": { "source": "q3.md" }, "id": "lc-2" },
+            {
+                "id_": "node-7", "text": "Hiring paused.", "embedding": [0.0, 0.5, 0.5], "class_name": "TextNode",
+                "metadata": { "header_path": "/Memo/Staffing/" }, "start_char_idx": 3, "end_char_idx": 17,
+                "relationships": { "1": { "node_id": "memo.txt" } }
+            }
+        ])
+    }
+
+    #[test]
+    fn test_formats_map_to_chunks() {
+        assert_eq!(DocumentFormat::detect(&corpus()[0]), Some(DocumentFormat::LangChain));
+        assert_eq!(DocumentFormat::detect(&corpus()[2]), Some(DocumentFormat::LlamaIndex));
+        let chunks = parse_corpus(&corpus()).unwrap();
+
+        let first = &chunks[0].chunk;
+        assert_eq!((first.source.as_deref(), first.index), (Some("q3.md"), 0));
+        assert_eq!(chunks[0].char_range, Some(40..57));
+        // Byte offsets are unknown until the chunk is located in its source.
+        assert_eq!((first.start, first.end), (0, 0));
+        assert_eq!(first.heading_path, ["Q3", "Results"]);
+        assert_eq!(chunks[0].metadata["page"], "2");
+        assert_eq!((chunks[1].id.as_deref(), chunks[1].chunk.index), (Some("lc-2"), 1));
+
+        let node = &chunks[2];
+        assert_eq!((node.chunk.source.as_deref(), node.chunk.index), (Some("memo.txt"), 0));
+        assert_eq!(node.char_range, Some(3..17));
+        assert_eq!(node.chunk.heading_path, ["Memo", "Staffing"]);
+        assert_eq!(node.embedding.as_deref(), Some(&[0.0, 0.5, 0.5][..]));
+
+        // Written out and read back, each chunk is the same
+        for external in &chunks {
+            for written in [to_langchain(external), to_llamaindex(external)] {
+                let read = parse_corpus(&written).unwrap().remove(0);
+                assert_eq!(read.char_range, external.char_range);
+                let (read, original) = (&read.chunk, &external.chunk);
+                assert_eq!((&read.text, &read.source, &read.heading_path), (&original.text, &original.source, &original.heading_path));
+            }
+        }
+
+        let err = parse_corpus(&json!([{ "title": "not a document" }])).unwrap_err();
+        assert!(matches!(err, DocumentFormatError::Unrecognized(_)));
+    }
+
+    #[test]
+    fn test_char_offsets_become_bytes_against_the_source() {
+        let source = "Résumé: Hiring paused.";
+        let mut node = parse_corpus(&corpus()).unwrap().remove(2);
+        node.char_range = Some(8..22);
+        assert!(node.locate(source));
+        assert_eq!((node.chunk.start, node.chunk.end), (10, 24));
+        assert_eq!(&source[node.chunk.start..node.chunk.end], "Hiring paused.");
+        assert_eq!(node.citation().locator.as_deref(), Some("chars 8..22"));
+
+        node.char_range = Some(8..40);
+        assert!(!node.locate(source));
+        assert_eq!(node.chunk.start, 10);
+
+        // Chunker's chunks only have byte offsets, and are not written where characters are expected.
+        let chunk = Chunker::new(ChunkStrategy::default()).chunk(source, Some("memo.txt")).remove(0);
+        let external = ExternalChunk::from(chunk);
+        assert_eq!(external.citation().locator.as_deref(), Some("bytes 0..24"));
+        assert_eq!(to_llamaindex(&external)["start_char_idx"], json!(null));
+        assert!(to_langchain(&external)["metadata"].get("start_index").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_bad_vector_inserts_nothing() {
+        let memory = VectorMemory::new(Arc::new(CountingEmbedder(AtomicUsize::new(0))));
+        let mut chunks = parse_corpus(&corpus()).unwrap();
+        chunks[0].embedding = Some(vec![1.0, 0.0, 0.0]);
+        chunks[1].embedding = Some(vec![1.0, 0.0]);
+        let err = memory.add_external("filings", &chunks).await.unwrap_err();
+        assert!(matches!(err, MemoryError::DimensionMismatch { expected: 3, actual: 2 }));
+        assert_eq!(memory.len("filings"), 0);
+
+        // An empty vector does not fix the dimension at 0.
+        chunks[0].embedding = Some(Vec::new());
+        assert!(matches!(memory.add_external("filings", &chunks[..1]).await, Err(MemoryError::Embedding(_))));
+        chunks[1].embedding = None;
+        memory.add_external("filings", &chunks[1..]).await.unwrap();
+        assert_eq!(memory.len("filings"), 2);
+    }
+
+    #[tokio::test]
+    async fn test_corpus_loads_into_memory_reusing_embeddings() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("corpus.jsonl");
+        let lines: Vec<String> = corpus().as_array().unwrap().iter().map(|document| document.to_string()).collect();
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        let chunks = read_corpus(&path).unwrap();
+        assert_eq!(chunks.len(), 3);
+
+        let embedder = Arc::new(CountingEmbedder(AtomicUsize::new(0)));
+        let memory = VectorMemory::new(embedder.clone());
+        let ids = memory.add_external("filings", &chunks).await.unwrap();
+        // Only the two LangChain documents had no embedding
+        assert_eq!(embedder.0.load(Ordering::SeqCst), 2);
+        assert_eq!(ids[1..], ["lc-2".to_string(), "node-7".to_string()]);
+
+        let hits = memory.recall_by_vector(&["filings".to_string()], &[0.0, 0.5, 0.5], 1).unwrap();
+        assert_eq!(hits[0].id, "node-7");
+        assert_eq!(hits[0].metadata["header_path"], "/Memo/Staffing/");
+
+        std::fs::write(&path, "{\"page_content\": \"ok\"}\nnot json\n").unwrap();
+        assert!(matches!(read_corpus(&path), Err(DocumentFormatError::Json { line: Some(2), .. })));
+    }
+}
+```
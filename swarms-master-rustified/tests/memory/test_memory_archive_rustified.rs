@@ -0,0 +1,114 @@
+### Tests for vector memory archives
+An exported store must import into a fresh store, in memory or on disk, with the same ids, text, citations,
+and exactly the same vectors. The manifest must describe what is inside, and a damaged or mismatched
+archive must be refused before anything is inserted.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::memory::memory_archive::verify_archive;
+    use crate::swarms::memory::vector_memory::{Embedder, MemoryError, MemoryRecord, VectorMemory};
+    use crate::swarms::structs::citation::Citation;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    // Imports never embed; a call means the vectors were not carried over
+    struct NoEmbedder;
+
+    #[async_trait]
+    impl Embedder for NoEmbedder {
+        async fn embed(&self, _texts: &[String]) -> Result<Vec<Vec<f32>>, MemoryError> {
+            panic!("an archive import must not embed")
+        }
+    }
+
+    fn record(id: &str, namespace: &str, source: Option<&str>, embedding: Vec<f32>) -> MemoryRecord {
+        MemoryRecord {
+            id: id.to_string(),
+            namespace: namespace.to_string(),
+            text: format!("text of {}", id),
+            embedding,
+            metadata: HashMap::from([("page".to_string(), "2".to_string())]),
+            citation: source.map(|source| Citation::new(source, None)),
+            norm: 0.0,
+        }
+    }
+
+    fn memory() -> VectorMemory {
+        let memory = VectorMemory::new(Arc::new(NoEmbedder));
+        memory
+            .insert(vec![
+                record("a", "agent:Analyst", Some("q3.md"), vec![0.1, 0.2, 0.3]),
+                record("b", "agent:Analyst", Some("q3.md"), vec![1.0 / 3.0, 0.0, -2.5]),
+                record("c", "shared", Some("memo.txt"), vec![0.0, 1.0, 0.0]),
+                record("d", "shared", None, vec![f32::MIN_POSITIVE, 0.0, 1.0]),
+            ])
+            .unwrap();
+        memory
+    }
+
+    fn namespaces() -> Vec<String> {
+        vec!["agent:Analyst".to_string(), "shared".to_string()]
+    }
+
+    #[test]
+    fn test_round_trip_into_memory_and_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("exports/analyst.jsonl");
+        let manifest = memory().export(&path).unwrap();
+        assert_eq!((manifest.dimension, manifest.records), (Some(3), 4));
+        assert_eq!(manifest.namespaces["shared"], 2);
+        let sources: Vec<(&str, usize)> = manifest.sources.iter().map(|doc| (doc.source.as_str(), doc.chunks)).collect();
+        assert_eq!(sources, [("memo.txt", 1), ("q3.md", 2)]);
+        // Only the archive is left behind
+        assert_eq!(std::fs::read_dir(path.parent().unwrap()).unwrap().count(), 1);
+
+        let in_memory = VectorMemory::new(Arc::new(NoEmbedder));
+        assert_eq!(in_memory.import(&path).unwrap(), manifest);
+        let on_disk = VectorMemory::open(Arc::new(NoEmbedder), dir.path().join("index"), 3).unwrap();
+        on_disk.import(&path).unwrap();
+
+        for (imported, name) in [(&in_memory, "memory.jsonl"), (&on_disk, "disk.jsonl")] {
+            // The same records in the same order, vectors bit for bit
+            let again = imported.export(dir.path().join(name)).unwrap();
+            assert_eq!(again.records_sha256, manifest.records_sha256);
+
+            assert_eq!(imported.namespaces(), namespaces());
+            let hits = imported.recall_by_vector(&namespaces(), &[1.0 / 3.0, 0.0, -2.5], 1).unwrap();
+            assert_eq!((hits[0].id.as_str(), hits[0].text.as_str()), ("b", "text of b"));
+            assert_eq!(hits[0].citation.as_ref().map(|citation| citation.source.as_str()), Some("q3.md"));
+            assert_eq!(hits[0].metadata["page"], "2");
+        }
+
+        // Deleted records are not exported
+        on_disk.delete("c").unwrap();
+        let again = on_disk.export(dir.path().join("disk.jsonl")).unwrap();
+        assert_eq!((again.records, again.namespaces["shared"]), (3, 1));
+    }
+
+    #[test]
+    fn test_damaged_or_mismatched_archives_are_refused() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("memory.jsonl");
+        memory().export(&path).unwrap();
+
+        let wider = VectorMemory::new(Arc::new(NoEmbedder));
+        wider.insert(vec![record("x", "other", None, vec![0.0; 4])]).unwrap();
+        assert!(matches!(wider.import(&path), Err(MemoryError::DimensionMismatch { expected: 4, actual: 3 })));
+        assert_eq!(wider.namespaces(), ["other"]);
+
+        let archive = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, archive.replace("text of c", "text of C")).unwrap();
+        let err = verify_archive(&path).unwrap_err();
+        assert!(matches!(&err, MemoryError::Archive { message, .. } if message.contains("records_sha256")));
+
+        let fresh = VectorMemory::new(Arc::new(NoEmbedder));
+        assert!(fresh.import(&path).is_err());
+        assert!(fresh.namespaces().is_empty());
+
+        std::fs::write(&path, "{\"format\": \"chroma\"}\n").unwrap();
+        assert!(matches!(fresh.import(&path), Err(MemoryError::Archive { .. })));
+    }
+}
+```
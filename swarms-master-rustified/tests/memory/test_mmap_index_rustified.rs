@@ -0,0 +1,145 @@
+### Tests for the memory-mapped vector index
+Appends survive a reopen, a duplicate id replaces the earlier record, tombstones hide records until
+compaction reclaims them, and neither a torn trailing log line nor an interrupted compaction prevents the
+index from opening.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::memory::mmap_index::MmapVectorIndex;
+    use crate::swarms::memory::vector_memory::{MemoryError, MemoryRecord};
+    use std::collections::HashMap;
+    use std::fs::{self, OpenOptions};
+    use std::io::Write;
+    use tempdir::TempDir;
+
+    fn record(id: &str, namespace: &str, embedding: Vec<f32>) -> MemoryRecord {
+        MemoryRecord {
+            id: id.to_string(),
+            namespace: namespace.to_string(),
+            text: format!("text of {}", id),
+            embedding,
+            metadata: HashMap::new(),
+            citation: None,
+            norm: 0.0,
+        }
+    }
+
+    fn ns(name: &str) -> Vec<String> {
+        vec![name.to_string()]
+    }
+
+    #[test]
+    fn test_append_and_reopen() {
+        let dir = TempDir::new("mmap_index").unwrap();
+        {
+            let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+            index.append(&[record("a", "agent:A", vec![1.0, 0.0]), record("b", "agent:B", vec![0.0, 1.0])]).unwrap();
+            index.append(&[record("c", "agent:A", vec![0.7, 0.7])]).unwrap();
+        }
+        let index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.len_in("agent:A"), 2);
+
+        let hits = index.search(&ns("agent:A"), &[1.0, 0.1], 5).unwrap();
+        assert_eq!(hits.iter().map(|h| h.id.as_str()).collect::<Vec<_>>(), vec!["a", "c"]);
+        assert_eq!(hits[0].text, "text of a");
+
+        assert!(matches!(MmapVectorIndex::open(dir.path(), 3), Err(MemoryError::DimensionMismatch { .. })));
+    }
+
+    #[test]
+    fn test_delete_and_compact() {
+        let dir = TempDir::new("mmap_index").unwrap();
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        index
+            .append(&[record("a", "agent:A", vec![1.0, 0.0]), record("b", "agent:A", vec![0.9, 0.1]), record("c", "agent:A", vec![0.0, 1.0])])
+            .unwrap();
+
+        assert!(index.delete("a").unwrap());
+        assert!(!index.delete("a").unwrap());
+        assert_eq!(index.search(&ns("agent:A"), &[1.0, 0.0], 1).unwrap()[0].id, "b");
+        assert!(index.garbage_ratio() > 0.3);
+
+        assert_eq!(index.compact().unwrap(), 1);
+        assert_eq!(index.garbage_ratio(), 0.0);
+        let ids: Vec<String> = index.search(&ns("agent:A"), &[1.0, 0.0], 5).unwrap().into_iter().map(|h| h.id).collect();
+        assert_eq!(ids, vec!["b", "c"]);
+
+        index.append(&[record("d", "agent:A", vec![1.0, 0.0])]).unwrap();
+        drop(index);
+        let index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.search(&ns("agent:A"), &[1.0, 0.0], 1).unwrap()[0].id, "d");
+    }
+
+    #[test]
+    fn test_duplicate_id_replaces_the_record() {
+        let dir = TempDir::new("mmap_index").unwrap();
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        index.append(&[record("a", "agent:A", vec![1.0, 0.0]), record("b", "agent:A", vec![0.0, 1.0])]).unwrap();
+        index.append(&[record("a", "agent:A", vec![0.0, 1.0])]).unwrap();
+        // Within one batch the last record wins.
+        index.append(&[record("c", "agent:B", vec![1.0, 0.0]), record("c", "agent:B", vec![0.0, 1.0])]).unwrap();
+        assert_eq!((index.len(), index.len_in("agent:A"), index.len_in("agent:B")), (3, 2, 1));
+        // Only the new vector for "a" is searched.
+        let hits = index.search(&ns("agent:A"), &[0.0, 1.0], 5).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert!(hits.iter().all(|hit| hit.score > 0.99));
+
+        drop(index);
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        assert_eq!(index.len(), 3);
+        assert_eq!(index.compact().unwrap(), 2);
+        assert_eq!(index.len(), 3);
+        let hits = index.search(&ns("agent:B"), &[0.0, 1.0], 5).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert!(hits[0].score > 0.99);
+    }
+
+    #[test]
+    fn test_interrupted_compaction_is_recovered() {
+        let dir = TempDir::new("mmap_index").unwrap();
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        index.append(&[record("a", "agent:A", vec![1.0, 0.0]), record("b", "agent:A", vec![0.0, 1.0])]).unwrap();
+        index.delete("a").unwrap();
+        drop(index);
+
+        // A compaction that crashed before its marker leaves temps behind; the live files are kept.
+        fs::write(dir.path().join("vectors.bin.compact"), b"partial").unwrap();
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        assert!(!dir.path().join("vectors.bin.compact").exists());
+        assert_eq!(index.len(), 1);
+
+        // A compaction that crashed after its first rename is rolled forward.
+        let log = fs::read(dir.path().join("records.jsonl")).unwrap();
+        index.compact().unwrap();
+        drop(index);
+        let compacted = fs::read(dir.path().join("records.jsonl")).unwrap();
+        fs::rename(dir.path().join("records.jsonl"), dir.path().join("records.jsonl.compact")).unwrap();
+        fs::write(dir.path().join("records.jsonl"), &log).unwrap();
+        fs::write(dir.path().join("compact.done"), b"").unwrap();
+
+        let index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        assert!(!dir.path().join("compact.done").exists());
+        assert_eq!(fs::read(dir.path().join("records.jsonl")).unwrap(), compacted);
+        assert_eq!(index.len(), 1);
+        assert_eq!(index.search(&ns("agent:A"), &[0.0, 1.0], 1).unwrap()[0].id, "b");
+    }
+
+    #[test]
+    fn test_torn_log_line_is_ignored() {
+        let dir = TempDir::new("mmap_index").unwrap();
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        index.append(&[record("a", "agent:A", vec![1.0, 0.0])]).unwrap();
+        drop(index);
+        let mut log = OpenOptions::new().append(true).open(dir.path().join("records.jsonl")).unwrap();
+        log.write_all(b"{\"row\":1,\"id\":\"b\",\"names").unwrap();
+
+        let mut index = MmapVectorIndex::open(dir.path(), 2).unwrap();
+        assert_eq!(index.len(), 1);
+        index.append(&[record("b", "agent:A", vec![0.0, 1.0])]).unwrap();
+        assert_eq!(index.search(&ns("agent:A"), &[0.0, 1.0], 1).unwrap()[0].id, "b");
+    }
+}
+```
@@ -0,0 +1,105 @@
+### Tests for reranking
+Reranking must order candidates by their new scores while keeping their first-stage rank, and a reranker that
+returns the wrong number of scores must fail instead of dropping candidates. Cohere's results come back in
+relevance order and must be mapped back to the candidates they score.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::rest::{HttpRequest, HttpResponse, HttpTransport, RestError};
+    use crate::swarms::memory::reranker::{
+        rank_by_score, Candidate, CohereReranker, CrossEncoder, CrossEncoderReranker, RerankError, Reranker, RerankerConfig,
+    };
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::sync::{Arc, Mutex};
+
+    fn candidate(id: &str, text: &str, score: f32) -> Candidate {
+        Candidate { id: id.to_string(), text: text.to_string(), score, metadata: HashMap::new() }
+    }
+
+    // Scores a passage by its length, or returns a fixed number of scores
+    struct Scripted(Option<usize>);
+
+    impl CrossEncoder for Scripted {
+        fn score_pairs(&self, _query: &str, passages: &[&str]) -> Result<Vec<f32>, RerankError> {
+            match self.0 {
+                Some(count) => Ok(vec![1.0; count]),
+                None => Ok(passages.iter().map(|passage| passage.len() as f32).collect()),
+            }
+        }
+    }
+
+    #[test]
+    fn test_rank_by_score_keeps_first_stage_ranks() {
+        let candidates = vec![candidate("a", "x", 0.9), candidate("b", "y", 0.8), candidate("c", "z", 0.7)];
+        let ranked = rank_by_score(candidates, vec![0.1, 0.5, 0.5]).unwrap();
+        let order: Vec<(&str, usize, usize)> =
+            ranked.iter().map(|r| (r.candidate.id.as_str(), r.before_rank, r.after_rank)).collect();
+        assert_eq!(order, [("b", 1, 0), ("c", 2, 1), ("a", 0, 2)]);
+    }
+
+    #[test]
+    fn test_score_count_mismatch_is_an_error() {
+        let candidates = vec![candidate("a", "x", 0.9), candidate("b", "y", 0.8)];
+        let err = rank_by_score(candidates.clone(), vec![0.1]).unwrap_err();
+        assert!(matches!(err, RerankError::ScoreCount { expected: 2, actual: 1 }));
+        assert!(matches!(rank_by_score(candidates, vec![0.1, 0.2, 0.3]), Err(RerankError::ScoreCount { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_cross_encoder_rerank() {
+        let candidates = vec![candidate("short", "ab", 0.9), candidate("long", "abcdef", 0.5), candidate("mid", "abcd", 0.7)];
+        let reranker = CrossEncoderReranker::new(Arc::new(Scripted(None)));
+        let ranked = reranker.rerank("q", candidates.clone(), 2).await.unwrap();
+        let ids: Vec<&str> = ranked.iter().map(|r| r.candidate.id.as_str()).collect();
+        assert_eq!(ids, ["long", "mid"]);
+
+        let short = CrossEncoderReranker::new(Arc::new(Scripted(Some(2))));
+        assert!(matches!(short.rerank("q", candidates, 2).await, Err(RerankError::ScoreCount { expected: 3, actual: 2 })));
+    }
+
+    #[test]
+    fn test_config_builds_the_reranker() {
+        let config: RerankerConfig = serde_json::from_str(r#"{"type": "cross_encoder"}"#).unwrap();
+        assert_eq!(config.top_n(), 5);
+        assert!(matches!(config.build(None), Err(RerankError::Model(_))));
+        assert_eq!(config.build(Some(Arc::new(Scripted(None)))).unwrap().name(), "cross-encoder");
+    }
+
+    // Answers every request with one canned response and keeps the requests
+    struct Canned(u16, String, Mutex<Vec<HttpRequest>>);
+
+    #[async_trait]
+    impl HttpTransport for Canned {
+        async fn send(&self, request: HttpRequest) -> Result<HttpResponse, RestError> {
+            self.2.lock().unwrap().push(request);
+            Ok(HttpResponse { status: self.0, body: self.1.clone() })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cohere_scores_follow_the_candidates() {
+        let body = json!({ "results": [{ "index": 1, "relevance_score": 0.9 }, { "index": 0, "relevance_score": 0.2 }] });
+        let transport = Arc::new(Canned(200, body.to_string(), Mutex::new(Vec::new())));
+        let reranker = CohereReranker::new("co-key", "rerank-english-v3.0").with_transport(transport.clone());
+        let candidates = vec![candidate("a", "Tax deadlines", 0.8), candidate("b", "Revenue grew", 0.7)];
+        assert_eq!(reranker.score("revenue", &candidates).await.unwrap(), [0.2, 0.9]);
+
+        let seen = transport.2.lock().unwrap();
+        assert_eq!(seen[0].url, "https://api.cohere.com/v1/rerank");
+        assert!(seen[0].headers.contains(&("Authorization".to_string(), "Bearer co-key".to_string())));
+        assert_eq!(seen[0].body.as_ref().unwrap()["documents"], json!(["Tax deadlines", "Revenue grew"]));
+    }
+
+    #[tokio::test]
+    async fn test_cohere_api_error_is_reported() {
+        let transport = Arc::new(Canned(429, json!({ "message": "rate limited" }).to_string(), Mutex::new(Vec::new())));
+        let reranker = CohereReranker::new("co-key", "rerank-english-v3.0").with_transport(transport);
+        let err = reranker.score("revenue", &[candidate("a", "Tax deadlines", 0.8)]).await.unwrap_err();
+        assert!(matches!(&err, RerankError::Http(rest) if rest.status() == Some(429)), "{}", err);
+        assert!(err.to_string().contains("rate limited"), "{}", err);
+    }
+}
+```
@@ -0,0 +1,42 @@
+### Tests for the similarity kernels
+The SIMD kernels are checked against the scalar reference, including lengths that leave a remainder.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::memory::similarity::{cosine, dot, dot_scalar, norm, top_k_cosine, PARALLEL_THRESHOLD};
+
+    fn vector(seed: usize, len: usize) -> Vec<f32> {
+        (0..len).map(|i| ((seed * 31 + i * 7) as f32 * 0.013).sin()).collect()
+    }
+
+    #[test]
+    fn test_simd_matches_scalar() {
+        for len in [0, 1, 7, 8, 9, 15, 1536] {
+            let (a, b) = (vector(1, len), vector(2, len));
+            assert!((dot(&a, &b) - dot_scalar(&a, &b)).abs() < 1e-3, "len {}", len);
+        }
+        assert_eq!(cosine(&[0.0, 0.0], &[1.0, 2.0]), 0.0);
+        assert!((cosine(&[1.0, 2.0, 3.0], &[2.0, 4.0, 6.0]) - 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_top_k_matches_full_sort_on_both_paths() {
+        for count in [50, PARALLEL_THRESHOLD * 2 + 3] {
+            let vectors: Vec<Vec<f32>> = (0..count).map(|i| vector(i, 24)).collect();
+            let with_norms: Vec<(&[f32], f32)> = vectors.iter().map(|v| (v.as_slice(), norm(v))).collect();
+            let query = vector(12345, 24);
+
+            let mut expected: Vec<(usize, f32)> = vectors.iter().enumerate().map(|(i, v)| (i, cosine(&query, v))).collect();
+            expected.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+
+            let got = top_k_cosine(&query, &with_norms, 5);
+            assert_eq!(
+                got.iter().map(|s| s.index).collect::<Vec<_>>(),
+                expected[..5].iter().map(|e| e.0).collect::<Vec<_>>()
+            );
+        }
+        assert!(top_k_cosine(&[1.0], &[], 3).is_empty());
+    }
+}
+```
@@ -0,0 +1,70 @@
+### Tests for the accounting swarm preset
+The preset must run its four agents in order on the loaded documents, write every stage to a versioned
+report artifact, and stop cleanly on missing documents or a failing agent.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::presets::accounting::{accounting_swarm, AccountingError, REPORT_FILE};
+    use crate::swarms::prompts::accountant_swarm_prompts::FRAUD_DETECTION_AGENT_PROMPT;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_agents_run_in_order_and_report_is_versioned() {
+        let dir = tempdir().unwrap();
+        let ledger = dir.path().join("ledger.csv");
+        std::fs::write(&ledger, "account,amount\nrevenue,420000\n").unwrap();
+        let swarm = accounting_swarm().report_dir(dir.path().join("reports"));
+        assert_eq!(swarm.workflow().flow(), "DocAnalyzer -> Summarizer -> FraudDetection -> DecisionSupport");
+
+        let mut inputs = Vec::new();
+        let report = swarm
+            .run(&[&ledger], |agent, input| {
+                inputs.push(input.to_string());
+                Ok(format!("{} done", agent.name()))
+            })
+            .unwrap();
+        assert!(inputs[0].contains("=== ledger.csv ===") && inputs[0].contains("revenue,420000"));
+        assert_eq!(inputs[2], "Summarizer done");
+        assert_eq!(report.recommendations(), Some("DecisionSupport done"));
+        assert!(report.markdown.contains("## Fraud Review\n_FraudDetection_\n\nFraudDetection done"));
+        assert_eq!(report.path, dir.path().join("reports").join(REPORT_FILE));
+        assert_eq!(report.version, 1);
+
+        let again = swarm.run(&[&ledger], |agent, _input| Ok(format!("{} again", agent.name()))).unwrap();
+        assert_eq!(again.version, 2);
+        assert!(std::fs::read_to_string(&again.path).unwrap().contains("DecisionSupport again"));
+    }
+
+    #[test]
+    fn test_agents_carry_the_prompts() {
+        let swarm = accounting_swarm();
+        let fraud = swarm.workflow().agents()[2].spec().unwrap();
+        assert_eq!(fraud.system_prompt, FRAUD_DETECTION_AGENT_PROMPT.trim());
+        assert_eq!(fraud.llm, "gpt-4o");
+    }
+
+    #[test]
+    fn test_failures() {
+        let dir = tempdir().unwrap();
+        let swarm = accounting_swarm().report_dir(dir.path());
+        let none: [&str; 0] = [];
+        assert!(matches!(swarm.run(&none, |_, _| Ok(String::new())), Err(AccountingError::NoDocuments)));
+
+        let missing = dir.path().join("missing.txt");
+        assert!(matches!(swarm.run(&[&missing], |_, _| Ok(String::new())), Err(AccountingError::Load { .. })));
+
+        let notes = dir.path().join("notes.txt");
+        std::fs::write(&notes, "Cash on hand: 12,000").unwrap();
+        let result = swarm.run(&[&notes], |agent, _input| {
+            if agent.name() == "FraudDetection" {
+                Err("rate limited".to_string())
+            } else {
+                Ok("ok".to_string())
+            }
+        });
+        assert!(matches!(result, Err(AccountingError::Stage(message)) if message.contains("FraudDetection")));
+        assert!(!dir.path().join(REPORT_FILE).exists());
+    }
+}
+```
@@ -0,0 +1,95 @@
+### Tests for the college-selection swarm preset
+A scripted provider stands in for the model. The final agent's request must carry the
+`CollegesRecommendation` schema, and its reply must be parsed into the typed recommendation.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::presets::college_selection::{
+        college_selection_swarm, CollegeSelectionError, CollegesRecommendation, RECOMMENDATION_SCHEMA_NAME,
+    };
+    use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ContentItem, ResponseFormat};
+
+    const RECOMMENDATION: &str = "Here is the final list:\n```json\n{\"colleges\": [\
+        {\"college_name\": \"Northeastern University\", \"college_description\": \"Co-op program\", \
+         \"college_admission_requirements\": \"SAT 1420-1540\"},\
+        {\"college_name\": \"Drexel University\", \"college_description\": \"Robotics lab\", \
+         \"college_admission_requirements\": \"SAT 1250-1440\"}],\
+        \"reasoning\": \"East Coast urban campuses with aid\"}\n```";
+
+    fn user_text(request: &ChatCompletionRequest) -> String {
+        match &request.messages[1].content[0] {
+            ContentItem::Text { text } => text.clone(),
+            _ => String::new(),
+        }
+    }
+
+    #[test]
+    fn test_final_agent_reply_is_typed() {
+        let swarm = college_selection_swarm();
+        assert_eq!(swarm.workflow().agents().len(), 6);
+        let mut requests = Vec::new();
+        let selection = swarm
+            .run("GPA: 3.8, SAT: 1450", |request| {
+                requests.push(request.clone());
+                Ok(match request.response_format {
+                    Some(_) => RECOMMENDATION.to_string(),
+                    None => format!("stage {}", requests.len()),
+                })
+            })
+            .unwrap();
+
+        assert!(requests[..5].iter().all(|request| request.response_format.is_none()));
+        assert_eq!(
+            requests[5].response_format,
+            Some(ResponseFormat::JsonSchema {
+                name: RECOMMENDATION_SCHEMA_NAME.to_string(),
+                schema: CollegesRecommendation::schema(),
+                strict: true,
+            })
+        );
+        assert_eq!(user_text(&requests[0]), "GPA: 3.8, SAT: 1450");
+        assert_eq!(user_text(&requests[5]), "stage 5");
+        assert_eq!(requests[0].model, "llama-3.1-70b-versatile");
+
+        let recommendation = &selection.recommendation;
+        assert_eq!(recommendation.colleges.len(), 2);
+        assert_eq!(recommendation.colleges[1].college_name, "Drexel University");
+        assert_eq!(recommendation.reasoning, "East Coast urban campuses with aid");
+        assert_eq!(selection.stages.len(), 6);
+    }
+
+    #[test]
+    fn test_invalid_recommendation_keeps_output() {
+        let result = college_selection_swarm().run("profile", |_request| Ok("I recommend MIT.".to_string()));
+        assert_eq!(
+            result,
+            Err(CollegeSelectionError::InvalidRecommendation {
+                message: "no JSON object in reply".to_string(),
+                output: "I recommend MIT.".to_string(),
+            })
+        );
+
+        let result = college_selection_swarm().run("profile", |request| match request.response_format {
+            Some(_) => Ok(r#"{"colleges": []}"#.to_string()),
+            None => Ok("notes".to_string()),
+        });
+        assert!(matches!(result, Err(CollegeSelectionError::InvalidRecommendation { message, .. }) if message.contains("reasoning")));
+    }
+
+    #[test]
+    fn test_provider_error_stops_the_run() {
+        let mut calls = 0;
+        let result = college_selection_swarm().run("profile", |_request| {
+            calls += 1;
+            if calls == 3 {
+                Err("429 Too Many Requests".to_string())
+            } else {
+                Ok("notes".to_string())
+            }
+        });
+        assert!(matches!(result, Err(CollegeSelectionError::Stage(message)) if message.starts_with("College-Match-Maker")));
+        assert_eq!(calls, 3);
+    }
+}
+```
@@ -0,0 +1,114 @@
+### Snapshot tests for prompt templates
+Every public prompt constant, every rendered template, and the tool catalog are pinned to a file under
+`tests/prompts/snapshots/`. An edit to any of them fails here and shows up as a diff. Run
+`cargo insta review` to accept the change, then commit the updated `.snap` file with the code that
+changed it, so the prompt change is reviewed with the code.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::auto_generate_swarm_config::{SwarmConfigGenerator, SwarmConfigIssue, AUTO_GEN_PROMPT};
+    use crate::swarms::eval::harness::JUDGE_PROMPT;
+    use crate::swarms::prompts::accountant_swarm_prompts::{
+        DECISION_MAKING_PROMPT, DOC_ANALYZER_AGENT_PROMPT, FRAUD_DETECTION_AGENT_PROMPT, ONBOARDING_AGENT_PROMPT,
+        SUMMARY_GENERATOR_AGENT_PROMPT,
+    };
+    use crate::swarms::prompts::autoswarm::{
+        AGENT_CONFIGURATION_AGENT_PROMPT, AGENT_ROLE_IDENTIFICATION_AGENT_PROMPT, SWARM_ASSEMBLY_AGENT_PROMPT,
+        TESTING_OPTIMIZATION_AGENT_PROMPT,
+    };
+    use crate::swarms::prompts::documentation::documentation_writer_sop;
+    use crate::swarms::prompts::finance_agent_sys_prompt::FINANCIAL_AGENT_SYS_PROMPT;
+    use crate::swarms::prompts::prompt_generator_optimizer::OPENAI_PROMPT_GENERATOR_SYS_PROMPT;
+    use crate::swarms::prompts::security_team::{
+        CROWD_ANALYSIS_AGENT_PROMPT, EMERGENCY_RESPONSE_COORDINATOR_PROMPT, FACIAL_RECOGNITION_AGENT_PROMPT,
+        SURVEILLANCE_MONITORING_AGENT_PROMPT, WEAPON_DETECTION_AGENT_PROMPT,
+    };
+    use crate::swarms::prompts::sop_generator_agent_prompt::sop_generator_agent_prompt;
+    use crate::swarms::structs::conversation::CHAIN_OF_THOUGHT_PROMPT;
+    use crate::swarms::structs::plan_execute::{DEFAULT_PLANNING_PROMPT, PLAN_FORMAT};
+    use crate::swarms::text::sentiment::DEFAULT_TONE_INSTRUCTION;
+
+    // One file per template, named `<module>__<template>.snap`
+    fn assert_prompt(name: &str, rendered: &str) {
+        insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+            insta::assert_snapshot!(name, rendered);
+        });
+    }
+
+    #[test]
+    fn test_prompt_constants() {
+        let prompts = [
+            ("accountant_swarm_prompts__onboarding_agent_prompt", ONBOARDING_AGENT_PROMPT),
+            ("accountant_swarm_prompts__doc_analyzer_agent_prompt", DOC_ANALYZER_AGENT_PROMPT),
+            ("accountant_swarm_prompts__summary_generator_agent_prompt", SUMMARY_GENERATOR_AGENT_PROMPT),
+            ("accountant_swarm_prompts__fraud_detection_agent_prompt", FRAUD_DETECTION_AGENT_PROMPT),
+            ("accountant_swarm_prompts__decision_making_prompt", DECISION_MAKING_PROMPT),
+            ("autoswarm__agent_role_identification_agent_prompt", AGENT_ROLE_IDENTIFICATION_AGENT_PROMPT),
+            ("autoswarm__agent_configuration_agent_prompt", AGENT_CONFIGURATION_AGENT_PROMPT),
+            ("autoswarm__swarm_assembly_agent_prompt", SWARM_ASSEMBLY_AGENT_PROMPT),
+            ("autoswarm__testing_optimization_agent_prompt", TESTING_OPTIMIZATION_AGENT_PROMPT),
+            ("finance_agent_sys_prompt__financial_agent_sys_prompt", FINANCIAL_AGENT_SYS_PROMPT),
+            ("prompt_generator_optimizer__openai_prompt_generator_sys_prompt", OPENAI_PROMPT_GENERATOR_SYS_PROMPT),
+            ("security_team__surveillance_monitoring_agent_prompt", SURVEILLANCE_MONITORING_AGENT_PROMPT),
+            ("security_team__crowd_analysis_agent_prompt", CROWD_ANALYSIS_AGENT_PROMPT),
+            ("security_team__facial_recognition_agent_prompt", FACIAL_RECOGNITION_AGENT_PROMPT),
+            ("security_team__weapon_detection_agent_prompt", WEAPON_DETECTION_AGENT_PROMPT),
+            ("security_team__emergency_response_coordinator_prompt", EMERGENCY_RESPONSE_COORDINATOR_PROMPT),
+            ("auto_generate_swarm_config__auto_gen_prompt", AUTO_GEN_PROMPT),
+            ("conversation__chain_of_thought_prompt", CHAIN_OF_THOUGHT_PROMPT),
+            ("plan_execute__default_planning_prompt", DEFAULT_PLANNING_PROMPT),
+            ("plan_execute__plan_format", PLAN_FORMAT),
+            ("sentiment__default_tone_instruction", DEFAULT_TONE_INSTRUCTION),
+            ("harness__judge_prompt", JUDGE_PROMPT),
+        ];
+        for (name, prompt) in prompts {
+            assert_prompt(name, prompt);
+        }
+    }
+
+    #[test]
+    fn test_rendered_templates() {
+        assert_prompt(
+            "sop_generator_agent_prompt__sop_generator_agent_prompt",
+            &sop_generator_agent_prompt("Reconcile monthly bank statements"),
+        );
+        assert_prompt(
+            "documentation__documentation_writer_sop",
+            &documentation_writer_sop("pub fn add(a: i32, b: i32) -> i32 { a + b }", "arith"),
+        );
+
+        let generator = SwarmConfigGenerator::new("unused");
+        assert_prompt("auto_generate_swarm_config__prompt", &generator.prompt("Write a market report", &[]));
+        let issues = [SwarmConfigIssue::NoAgents, SwarmConfigIssue::UnsupportedSwarmType("MixtureOfAgents".to_string())];
+        assert_prompt("auto_generate_swarm_config__prompt_with_issues", &generator.prompt("Write a market report", &issues));
+    }
+
+    #[cfg(feature = "tools")]
+    #[test]
+    fn test_tool_catalog() {
+        use crate::swarms::tools::ToolRegistry;
+        use serde_json::json;
+
+        let registry = ToolRegistry::builder()
+            .tool_system_prompt("Call a tool when it answers the question better than you can.")
+            .tool(
+                "web_search",
+                "Search the web and return the top results.\nResults include titles and URLs.",
+                json!({"properties": {"query": {"type": "string"}}, "required": ["query"], "type": "object"}),
+                |_| Ok(json!([])),
+            )
+            .tool(
+                "calculator",
+                "Evaluate an arithmetic expression.",
+                json!({"properties": {"expression": {"type": "string"}}, "required": ["expression"], "type": "object"}),
+                |_| Ok(json!(0)),
+            )
+            .tool("clock", "Return the current UTC time.", json!({"type": "object"}), |_| Ok(json!("")))
+            .build()
+            .unwrap();
+        assert_prompt("base_tool__tool_system_prompt", &registry.tool_system_prompt());
+        assert_prompt("base_tool__render_tool_catalog_truncated", &registry.render_tool_catalog(40));
+    }
+}
+```
@@ -0,0 +1,37 @@
+### Tests for chat completion request options
+`force_tool` must only force a tool the request offers, and the forced choice must reach the provider body.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem, ToolChoice, ToolChoiceError};
+    use serde_json::json;
+
+    fn request() -> ChatCompletionRequest {
+        let message = ChatMessageInput::new("user".to_string(), vec![ContentItem::Text { text: "Price of AAPL?".to_string() }]);
+        ChatCompletionRequest::new("gpt-4o".to_string(), vec![message])
+    }
+
+    fn tool(name: &str) -> serde_json::Value {
+        json!({ "type": "function", "function": { "name": name, "parameters": { "type": "object" } } })
+    }
+
+    #[test]
+    fn test_force_tool_requires_the_tool_to_be_offered() {
+        let err = request().force_tool("get_quote").unwrap_err();
+        assert_eq!(err, ToolChoiceError::NotOffered("get_quote".to_string()));
+
+        let err = request().with_tools(vec![tool("get_news")]).force_tool("get_quote").unwrap_err();
+        assert_eq!(err.to_string(), "cannot force tool 'get_quote': it is not in the request's tools");
+    }
+
+    #[test]
+    fn test_forced_tool_reaches_the_provider_body() {
+        let forced = request().with_tools(vec![tool("get_news"), tool("get_quote")]).force_tool("get_quote").unwrap();
+        assert_eq!(forced.tool_choice, Some(ToolChoice::Function("get_quote".to_string())));
+        let body = forced.provider_options_json();
+        assert_eq!(body["tool_choice"], json!({ "type": "function", "function": { "name": "get_quote" } }));
+        assert_eq!(body["tools"].as_array().unwrap().len(), 2);
+    }
+}
+```
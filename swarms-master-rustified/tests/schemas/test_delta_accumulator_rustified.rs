@@ -0,0 +1,113 @@
+### Tests for the delta accumulator
+The bodies below are recorded `text/event-stream` responses from OpenAI and Groq, trimmed to the fields
+the accumulator reads. Both providers' tool calls must come out the same, and a stream cut off inside a
+call's arguments must be reported.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::sse::SseParser;
+    use crate::swarms::schemas::base_schemas::{ChatMessageResponse, ToolCall};
+    use crate::swarms::schemas::delta_accumulator::{DeltaAccumulator, DeltaError};
+
+    const OPENAI_TOOL_CALLS: &str = concat!(
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":null,\"tool_calls\":[{\"index\":0,\"id\":\"call_Vx1\",\"type\":\"function\",\"function\":{\"name\":\"get_weather\",\"arguments\":\"\"}}]},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"{\\\"ci\"}}]},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"ty\\\": \\\"Par\"}}]},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":0,\"function\":{\"arguments\":\"is\\\"}\"}}]},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":1,\"id\":\"call_Vx2\",\"type\":\"function\",\"function\":{\"name\":\"get_time\",\"arguments\":\"\"}}]},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"index\":1,\"function\":{\"arguments\":\"{\\\"tz\\\": \\\"CET\\\"}\"}}]},\"finish_reason\":null}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"tool_calls\"}],\"usage\":null}\n\n",
+        "data: {\"id\":\"chatcmpl-9x\",\"object\":\"chat.completion.chunk\",\"model\":\"gpt-4o-mini\",\"choices\":[],\"usage\":{\"prompt_tokens\":81,\"completion_tokens\":36,\"total_tokens\":117}}\n\n",
+        "data: [DONE]\n\n",
+    );
+
+    const GROQ_TOOL_CALLS: &str = concat!(
+        "data: {\"id\":\"chatcmpl-3a\",\"object\":\"chat.completion.chunk\",\"model\":\"llama-3.1-70b-versatile\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":null},\"finish_reason\":null}],\"x_groq\":{\"id\":\"req_01\"}}\n\n",
+        "data: {\"id\":\"chatcmpl-3a\",\"object\":\"chat.completion.chunk\",\"model\":\"llama-3.1-70b-versatile\",\"choices\":[{\"index\":0,\"delta\":{\"tool_calls\":[{\"id\":\"call_g1\",\"type\":\"function\",\"function\":{\"name\":\"get_weather\",\"arguments\":\"{\\\"city\\\":\\\"Paris\\\"}\"},\"index\":0},{\"id\":\"call_g2\",\"type\":\"function\",\"function\":{\"name\":\"get_time\",\"arguments\":\"{\\\"tz\\\":\\\"CET\\\"}\"},\"index\":1}]},\"finish_reason\":null}]}\n\n",
+        "data: {\"id\":\"chatcmpl-3a\",\"object\":\"chat.completion.chunk\",\"model\":\"llama-3.1-70b-versatile\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"tool_calls\"}],\"x_groq\":{\"id\":\"req_01\",\"usage\":{\"queue_time\":0.02,\"prompt_tokens\":240,\"completion_tokens\":41,\"total_tokens\":281}}}\n\n",
+        "data: [DONE]\n\n",
+    );
+
+    const OPENAI_CONTENT: &str = concat!(
+        "data: {\"object\":\"chat.completion.chunk\",\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"\"},\"finish_reason\":null}]}\n\n",
+        "data: {\"object\":\"chat.completion.chunk\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Bonjour\"},\"finish_reason\":null}]}\n\n",
+        "data: {\"object\":\"chat.completion.chunk\",\"choices\":[{\"index\":0,\"delta\":{\"content\":\", Paris!\"},\"finish_reason\":null}]}\n\n",
+        "data: {\"object\":\"chat.completion.chunk\",\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n",
+        "data: [DONE]\n\n",
+    );
+
+    /// Feed a recorded body through the event-stream parser in small pieces, as it arrives off the wire.
+    fn accumulate(body: &str) -> Result<DeltaAccumulator, DeltaError> {
+        let mut parser = SseParser::new();
+        let mut accumulator = DeltaAccumulator::new();
+        for event in body.as_bytes().chunks(13).flat_map(|chunk| parser.push(chunk)) {
+            if event.data == "[DONE]" {
+                break;
+            }
+            accumulator.push_chunk(&serde_json::from_str(&event.data).expect("recorded chunks are JSON"))?;
+        }
+        Ok(accumulator)
+    }
+
+    fn call(id: &str, name: &str, arguments: &str) -> ToolCall {
+        ToolCall { id: id.to_string(), name: name.to_string(), arguments: arguments.to_string() }
+    }
+
+    fn only_message(accumulator: DeltaAccumulator) -> ChatMessageResponse {
+        let mut messages = accumulator.finish().unwrap();
+        assert_eq!(messages.len(), 1);
+        messages.remove(0)
+    }
+
+    #[test]
+    fn test_openai_arguments_are_merged() {
+        let accumulator = accumulate(OPENAI_TOOL_CALLS).unwrap();
+        assert_eq!(accumulator.finish_reason(0), Some("tool_calls"));
+        let usage = accumulator.usage().unwrap();
+        assert_eq!((usage.prompt_tokens, usage.completion_tokens, usage.total_tokens), (81, Some(36), 117));
+
+        let message = only_message(accumulator);
+        assert_eq!((message.role.as_str(), message.content.as_str()), ("assistant", ""));
+        assert_eq!(
+            message.tool_calls,
+            [call("call_Vx1", "get_weather", "{\"city\": \"Paris\"}"), call("call_Vx2", "get_time", "{\"tz\": \"CET\"}")]
+        );
+    }
+
+    #[test]
+    fn test_groq_whole_calls_and_usage() {
+        let accumulator = accumulate(GROQ_TOOL_CALLS).unwrap();
+        assert_eq!(accumulator.finish_reason(0), Some("tool_calls"));
+        let usage = accumulator.usage().unwrap();
+        assert_eq!((usage.prompt_tokens, usage.total_tokens), (240, 281));
+
+        let message = only_message(accumulator);
+        assert_eq!(
+            message.tool_calls,
+            [call("call_g1", "get_weather", "{\"city\":\"Paris\"}"), call("call_g2", "get_time", "{\"tz\":\"CET\"}")]
+        );
+    }
+
+    #[test]
+    fn test_content_only_stream() {
+        let accumulator = accumulate(OPENAI_CONTENT).unwrap();
+        assert_eq!(accumulator.finish_reason(0), Some("stop"));
+        assert!(accumulator.usage().is_none());
+        let message = only_message(accumulator);
+        assert_eq!(message.content, "Bonjour, Paris!");
+        assert!(message.tool_calls.is_empty());
+    }
+
+    #[test]
+    fn test_truncated_arguments_are_reported() {
+        // The recording without its fourth event, the fragment that closes the first call's arguments.
+        let body: String =
+            OPENAI_TOOL_CALLS.split_inclusive("\n\n").enumerate().filter(|(n, _)| *n != 3).map(|(_, event)| event).collect();
+        let err = accumulate(&body).unwrap().finish().unwrap_err();
+        assert!(matches!(err, DeltaError::InvalidArguments { choice: 0, index: 0, .. }), "{:?}", err);
+
+        assert!(matches!(accumulate("data: {\"error\": \"overloaded\"}\n\n"), Err(DeltaError::InvalidChunk(_))));
+    }
+}
+```
@@ -0,0 +1,72 @@
+### Tests for AgentPool
+The factory counts constructions so the tests can tell reuse from rebuilding.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent_pool::{AgentPool, PoolError, Poolable};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct Counter {
+        id: usize,
+        history: Vec<String>,
+    }
+
+    impl Poolable for Counter {
+        fn reset(&mut self) {
+            self.history.clear();
+        }
+    }
+
+    fn pool(max: usize) -> (AgentPool<Counter>, Arc<AtomicUsize>) {
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&built);
+        let pool = AgentPool::new().define("worker", max, move || Counter {
+            id: counter.fetch_add(1, Ordering::SeqCst),
+            history: Vec::new(),
+        });
+        (pool, built)
+    }
+
+    #[tokio::test]
+    async fn test_instances_are_reused_and_reset() {
+        let (pool, built) = pool(2);
+        let first_id = {
+            let mut agent = pool.checkout("worker").await.unwrap();
+            agent.history.push("task".to_string());
+            agent.id
+        };
+        let agent = pool.checkout("worker").await.unwrap();
+        assert_eq!(agent.id, first_id);
+        assert!(agent.history.is_empty());
+        assert_eq!(built.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_cap_limits_concurrent_instances() {
+        let (pool, built) = pool(1);
+        let held = pool.checkout("worker").await.unwrap();
+        assert!(pool.try_checkout("worker").unwrap().is_none());
+        assert_eq!(
+            pool.checkout_timeout("worker", Duration::from_millis(20)).await.err(),
+            Some(PoolError::Timeout("worker".to_string()))
+        );
+        assert_eq!(pool.stats("worker").unwrap().checked_out, 1);
+        drop(held);
+        assert!(pool.try_checkout("worker").unwrap().is_some());
+        assert_eq!(built.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn test_discard_and_unknown_definitions() {
+        let (pool, built) = pool(1);
+        pool.checkout("worker").await.unwrap().discard();
+        assert_eq!(pool.stats("worker").unwrap().idle, 0);
+        pool.checkout("worker").await.unwrap();
+        assert_eq!(built.load(Ordering::SeqCst), 2);
+        assert!(matches!(pool.try_checkout("missing"), Err(PoolError::UnknownAgent(_))));
+    }
+}
+```
@@ -0,0 +1,122 @@
+### Tests for agent profiles
+A profile must take its settings from the front-matter and its system prompt from the body, and must name
+itself after its file when the front-matter does not. Malformed files, unknown keys, and names defined twice
+must be rejected. A directory of profiles must load into a registry that builds the agents, and the
+accountant preset must take its prompts from it.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::presets::accounting::AccountingSwarm;
+    use crate::swarms::prompts::accountant_swarm_prompts::DOC_ANALYZER_AGENT_PROMPT;
+    use crate::swarms::structs::agent::{Agent, AgentConfigError};
+    use crate::swarms::structs::agent_profile::{AgentProfile, AgentProfileRegistry, ProfileError};
+    use std::fs;
+    use tempfile::tempdir;
+
+    const FRAUD: &str = "---
+name: FraudDetection
+description: Flags signs of fraud
+model: gpt-4o
+tools: [ledger_lookup]
+tags: [accounting, fraud]
+max_loops: 2
+---
+
+# Role
+You are a forensic accountant.
+
+Flag round-number transfers.
+";
+
+    #[test]
+    fn test_front_matter_and_body() {
+        let profile = AgentProfile::parse(FRAUD).unwrap();
+        assert_eq!((profile.name.as_str(), profile.model.as_deref()), ("FraudDetection", Some("gpt-4o")));
+        assert_eq!(profile.tools, vec!["ledger_lookup".to_string()]);
+        assert_eq!(profile.tags, vec!["accounting".to_string(), "fraud".to_string()]);
+        assert_eq!(profile.system_prompt, "# Role\nYou are a forensic accountant.\n\nFlag round-number transfers.");
+        assert_eq!(AgentProfile::parse(&profile.to_markdown()).unwrap(), profile);
+
+        let agent = profile.to_agent("gpt-4o-mini").unwrap();
+        assert_eq!((agent.llm.as_str(), agent.max_loops), ("gpt-4o", 2));
+        assert_eq!(agent.capabilities(), ["accounting".to_string(), "fraud".to_string()]);
+
+        let crlf = "\u{feff}---\r\nname: Clerk\r\n---\r\nFile the receipts.\r\n";
+        let clerk = AgentProfile::parse(crlf).unwrap();
+        assert_eq!((clerk.model.as_deref(), clerk.system_prompt.as_str()), (None, "File the receipts."));
+        assert_eq!(clerk.to_agent("gpt-4o-mini").unwrap().llm, "gpt-4o-mini");
+    }
+
+    #[test]
+    fn test_invalid_profiles() {
+        let parse_error = |text: &str| matches!(AgentProfile::parse(text), Err(ProfileError::Parse { path: None, .. }));
+        assert!(parse_error("You are a forensic accountant."), "no front-matter");
+        assert!(parse_error("---\nname: Clerk\nFile the receipts.\n"), "unclosed front-matter");
+        assert!(parse_error("---\nname: Clerk\nmodle: gpt-4o\n---\nFile the receipts.\n"), "misspelled key");
+        assert!(parse_error("---\ndescription: Files receipts\n---\nFile the receipts.\n"), "no name");
+        let blank = AgentProfile::parse("---\nname: Clerk\n---\n\n");
+        assert!(matches!(blank, Err(ProfileError::EmptyPrompt(name)) if name == "Clerk"));
+
+        let looping = AgentProfile::parse("---\nname: Clerk\nmax_loops: 0\n---\nFile the receipts.\n").unwrap();
+        assert!(matches!(
+            looping.to_agent("gpt-4o"),
+            Err(ProfileError::Agent { error: AgentConfigError::InvalidMaxLoops(0), .. })
+        ));
+    }
+
+    #[test]
+    fn test_directory_loads_into_registry() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("fraud.md"), FRAUD).unwrap();
+        fs::write(dir.path().join("Clerk.md"), "---\ntags: [accounting]\n---\nFile the receipts.\n").unwrap();
+        fs::write(dir.path().join("README.md"), "Agent profiles for the finance team.\n").unwrap();
+        fs::write(dir.path().join("notes.txt"), "not a profile").unwrap();
+
+        let mut registry = AgentProfileRegistry::new("gpt-4o-mini");
+        assert_eq!(registry.load_dir(dir.path()).unwrap(), 2);
+        assert_eq!(registry.profiles().map(|profile| profile.name.as_str()).collect::<Vec<_>>(), vec!["Clerk", "FraudDetection"]);
+        assert_eq!(registry.get("Clerk").unwrap().path.as_deref(), Some(dir.path().join("Clerk.md").as_path()));
+        assert_eq!(registry.tagged("fraud").count(), 1);
+        assert_eq!(registry.agent("Clerk").unwrap().llm, "gpt-4o-mini");
+        assert_eq!(registry.agents().unwrap().len(), 2);
+        assert!(matches!(registry.agent("Auditor"), Err(ProfileError::NotFound(_))));
+        assert_eq!(registry.load_dir(dir.path()).unwrap(), 2, "reloading the same files replaces them");
+
+        fs::write(dir.path().join("fraud_v2.md"), FRAUD).unwrap();
+        let err = registry.load_dir(dir.path()).unwrap_err();
+        assert!(matches!(&err, ProfileError::Duplicate { name, .. } if name == "FraudDetection"), "{}", err);
+    }
+
+    #[test]
+    fn test_accounting_swarm_from_profiles() {
+        let mut registry = AgentProfileRegistry::new("gpt-4o-mini");
+        registry.register(AgentProfile::parse(FRAUD).unwrap());
+        let swarm = AccountingSwarm::from_profiles("gpt-4o-mini", &registry).unwrap();
+        let specs: Vec<_> = swarm.workflow().agents().iter().map(|agent| agent.spec().unwrap()).collect();
+        assert_eq!(swarm.workflow().flow(), "DocAnalyzer -> Summarizer -> FraudDetection -> DecisionSupport");
+        assert_eq!((specs[0].system_prompt.as_str(), specs[0].llm.as_str()), (DOC_ANALYZER_AGENT_PROMPT.trim(), "gpt-4o-mini"));
+        assert!(specs[2].system_prompt.starts_with("# Role"));
+        assert_eq!(specs[2].llm, "gpt-4o");
+    }
+
+    #[cfg(feature = "tools")]
+    #[test]
+    fn test_tools_are_picked_from_the_registry() {
+        use crate::swarms::tools::base_tool::ToolRegistry;
+        use serde_json::json;
+
+        let tools = ToolRegistry::builder()
+            .tool("ledger_lookup", "Looks up a ledger entry", json!({ "type": "object" }), |params| Ok(params.clone()))
+            .tool("send_email", "Sends an email", json!({ "type": "object" }), |_| Ok(json!({})))
+            .build()
+            .unwrap();
+        let profile = AgentProfile::parse(FRAUD).unwrap();
+        let allowed = profile.tool_registry(&tools).unwrap();
+        assert_eq!(allowed.tools().map(|tool| tool.name.as_str()).collect::<Vec<_>>(), vec!["ledger_lookup"]);
+
+        let unknown = AgentProfile { tools: vec!["wire_money".into()], ..profile };
+        assert!(matches!(unknown.tool_registry(&tools), Err(ProfileError::UnknownTool { tool, .. }) if tool == "wire_money"));
+    }
+}
+```
@@ -0,0 +1,137 @@
+### Tests for the Agent trait
+Model-backed, scripted, and swarm-backed agents must be interchangeable behind `Arc<dyn Agent>`. Hidden
+messages must stay out of `run_with_history`, clones of an `LlmAgent` must share its spec until one is edited,
+and the swarms must keep working on mixed agents.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, AgentError, LlmAgent, MockAgent};
+    use crate::swarms::structs::conversation::{Channel, Message};
+    use crate::swarms::structs::majority_voting::{Consensus, MajorityVoting};
+    use crate::swarms::structs::round_robin::RoundRobinSwarm;
+    use crate::swarms::structs::swarm::{Swarm, SwarmAgent, SwarmError};
+    use std::sync::Arc;
+
+    fn voters(answers: &[&str]) -> Vec<Arc<dyn Agent>> {
+        answers
+            .iter()
+            .enumerate()
+            .map(|(i, answer)| Arc::new(MockAgent::new(format!("voter-{}", i)).reply(*answer)) as Arc<dyn Agent>)
+            .collect()
+    }
+
+    #[test]
+    fn test_mock_agent_follows_its_script() {
+        let agent = MockAgent::new("Scripted").reply("first").fail("rate limited").reply("last").capability("sql");
+        assert_eq!(agent.run("a").unwrap(), "first");
+        assert_eq!(
+            agent.run("b").unwrap_err(),
+            AgentError::Failed { agent: "Scripted".to_string(), message: "rate limited".to_string() }
+        );
+        assert_eq!(agent.run("c").unwrap(), "last");
+        assert_eq!(agent.run("d").unwrap(), "last");
+        assert_eq!(agent.calls(), ["a", "b", "c", "d"]);
+        assert_eq!(agent.capabilities(), ["sql"]);
+        assert_eq!(MockAgent::new("Echo").run("same").unwrap(), "same");
+    }
+
+    #[test]
+    fn test_llm_agent_needs_a_completion() {
+        let agent = LlmAgent::builder().name("Analyst").llm("gpt-4o").capability("finance").build().unwrap();
+        assert!(!agent.has_completion());
+        assert_eq!(agent.run("Summarize").unwrap_err(), AgentError::NoModel("Analyst".to_string()));
+        assert_eq!(agent.capabilities(), ["finance"]);
+
+        let agent = agent.with_completion(|agent, task| {
+            if task.is_empty() { Err("empty prompt".to_string()) } else { Ok(format!("{}: {}", agent.llm, task)) }
+        });
+        assert_eq!(agent.run("Summarize").unwrap(), "gpt-4o: Summarize");
+        assert_eq!(agent.run("").unwrap_err().to_string(), "agent 'Analyst' failed: empty prompt");
+    }
+
+    #[test]
+    fn test_llm_agent_clones_share_the_spec() {
+        let agent = LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o")
+            .system_prompt("You analyze filings. ".repeat(500))
+            .completion(|agent, task| Ok(format!("{}: {}", agent.llm, task)))
+            .build()
+            .unwrap();
+        let handles: Vec<LlmAgent> = (0..4).map(|_| agent.clone()).collect();
+        assert!(handles.iter().all(|handle| handle.shares_spec(&agent) && handle.has_completion()));
+
+        let outputs: Vec<String> = std::thread::scope(|scope| {
+            let workers: Vec<_> = handles.into_iter().map(|handle| scope.spawn(move || handle.run("Summarize").unwrap())).collect();
+            workers.into_iter().map(|worker| worker.join().unwrap()).collect()
+        });
+        assert_eq!(outputs, ["gpt-4o: Summarize"; 4]);
+
+        let mut cheaper = agent.clone();
+        cheaper.llm = "gpt-4o-mini".to_string();
+        assert!(!cheaper.shares_spec(&agent));
+        assert_eq!(agent.llm, "gpt-4o");
+        assert_eq!(cheaper.run("Summarize").unwrap(), "gpt-4o-mini: Summarize");
+        assert_eq!(cheaper.system_prompt, agent.system_prompt);
+    }
+
+    #[test]
+    fn test_run_with_history_skips_hidden_messages() {
+        let agent = MockAgent::new("Echo");
+        let mut deleted = Message::new("user".to_string(), String::new(), Channel::Public);
+        deleted.deleted_at = Some("2024-01-01T00:00:00+00:00".to_string());
+        let history = [
+            Message::new("user".to_string(), "What is 2 + 2?".to_string(), Channel::Public),
+            Message::new("assistant".to_string(), "Let me think.".to_string(), Channel::Scratchpad),
+            deleted,
+            Message::new("assistant".to_string(), "4".to_string(), Channel::Public),
+        ];
+        assert_eq!(agent.run_with_history("And 3 + 3?", &history).unwrap(), "user: What is 2 + 2?\nassistant: 4\n\nAnd 3 + 3?");
+        assert_eq!(agent.run_with_history("Alone", &[]).unwrap(), "Alone");
+    }
+
+    #[test]
+    fn test_swarm_votes_as_one_agent() {
+        let panel = MajorityVoting::new("panel", "nested", voters(&["Paris", "Paris", "Lyon"]), None, false, false);
+        let nested = SwarmAgent::new(panel).capability("geography").into_agent();
+        assert_eq!(nested.name(), "panel");
+        assert_eq!(nested.capabilities(), ["geography"]);
+
+        let mut agents = voters(&["Lyon"]);
+        agents.push(Arc::clone(&nested));
+        agents.push(Arc::new(MockAgent::new("down").fail("timeout")));
+        agents.push(Arc::new(MockAgent::new("close").reply("paris.")));
+        let mut vote = MajorityVoting::new("vote", "mixed", agents, None, false, false).with_consensus(Consensus::Normalized);
+        assert_eq!(vote.run("Capital of France?").unwrap(), "Paris");
+
+        let empty = SwarmAgent::new(MajorityVoting::new("empty", "none", Vec::new(), None, false, false));
+        assert_eq!(empty.run("x").unwrap_err().to_string(), "agent 'empty' failed: no agents configured for the swarm");
+    }
+
+    #[test]
+    fn test_vote_fails_only_when_every_agent_fails() {
+        let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("a").fail("down")), Arc::new(MockAgent::new("b").fail("down"))];
+        let mut vote = MajorityVoting::new("vote", "down", agents, None, false, false);
+        let failures = vote.run("x").unwrap_err();
+        assert_eq!(failures.0.len(), 2);
+        assert_eq!(failures.0[1].label, "b");
+        assert_eq!(failures.0[1].message, "agent 'b' failed: down");
+    }
+
+    #[test]
+    fn test_round_robin_runs_each_agent_per_loop() {
+        let first = Arc::new(MockAgent::new("first"));
+        let second = Arc::new(MockAgent::new("second"));
+        let agents: Vec<Arc<dyn Agent>> = vec![first.clone(), second.clone()];
+        let mut swarm = RoundRobinSwarm::new("rr".to_string(), "two".to_string(), agents, false, 2);
+        assert!(Swarm::run(&mut swarm, "task").is_ok());
+        assert_eq!(first.calls().len(), 2);
+        assert_eq!(second.calls().len(), 2);
+
+        let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("flaky").fail("down"))];
+        let mut swarm = RoundRobinSwarm::new("rr".to_string(), "failing".to_string(), agents, false, 1);
+        assert!(matches!(Swarm::run(&mut swarm, "task"), Err(SwarmError::Failed(message)) if message.contains("down")));
+    }
+}
+```
@@ -0,0 +1,98 @@
+### Tests for agent versions and traffic splitting
+Traffic must follow the split. Routing keys must stay on their version as a canary grows. Splits must be
+validated and reversible, and every run must be counted against the version that served it.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::agent_versions::{VersionError, VersionedAgent};
+
+    fn analyst(model: &str) -> LlmAgent {
+        LlmAgent::builder().name("Analyst").llm(model).system_prompt("You analyze filings.").build().unwrap()
+    }
+
+    fn versioned() -> VersionedAgent {
+        let mut agent = VersionedAgent::new("Analyst");
+        agent.register("v1", analyst("gpt-4o")).unwrap();
+        agent.register("v2", analyst("gpt-4o-mini")).unwrap();
+        agent
+    }
+
+    fn routed_to(agent: &VersionedAgent, version: &str) -> Vec<String> {
+        (0..1000)
+            .map(|user| format!("user-{}", user))
+            .filter(|key| agent.select(key).unwrap().0 == version)
+            .collect()
+    }
+
+    #[test]
+    fn test_split_routes_traffic_and_keeps_users_sticky() {
+        let mut agent = versioned();
+        assert!(routed_to(&agent, "v2").is_empty());
+
+        agent.set_split(&[("v1", 90), ("v2", 10)]).unwrap();
+        let canary = routed_to(&agent, "v2");
+        assert!((50..150).contains(&canary.len()), "{} of 1000 on v2", canary.len());
+        assert_eq!(agent.select("user-7").unwrap().0, agent.select("user-7").unwrap().0);
+
+        agent.set_split(&[("v1", 80), ("v2", 20)]).unwrap();
+        let wider = routed_to(&agent, "v2");
+        assert!(canary.iter().all(|key| wider.contains(key)));
+        assert!(wider.len() > canary.len());
+
+        agent.promote("v2").unwrap();
+        assert_eq!(routed_to(&agent, "v2").len(), 1000);
+        assert_eq!(agent.select("anyone").unwrap().1.llm, "gpt-4o-mini");
+    }
+
+    #[test]
+    fn test_invalid_splits_and_rollback() {
+        let mut agent = versioned();
+        assert_eq!(agent.set_split(&[("v1", 50), ("v3", 50)]), Err(VersionError::UnknownVersion("v3".to_string())));
+        assert_eq!(agent.set_split(&[("v1", 200), ("v2", 200)]), Err(VersionError::BadSplit { total: 400 }));
+        assert_eq!(agent.rollback().unwrap_err(), VersionError::NothingToRollBack);
+        assert_eq!(agent.register("v1", analyst("gpt-4o")), Err(VersionError::DuplicateVersion("v1".to_string())));
+        let other = LlmAgent::builder().name("Writer").llm("gpt-4o").build().unwrap();
+        assert!(matches!(agent.register("v3", other), Err(VersionError::NameMismatch { .. })));
+
+        agent.set_split(&[("v1", 90), ("v2", 10)]).unwrap();
+        agent.set_split(&[("v1", 50), ("v2", 50)]).unwrap();
+        assert_eq!(agent.history().len(), 3);
+        let restored = agent.rollback().unwrap();
+        assert_eq!(restored.get("v2"), Some(&10));
+        let restored = agent.rollback().unwrap();
+        assert_eq!((restored.get("v1"), restored.get("v2")), (Some(&100), None));
+        assert_eq!(agent.history().len(), 1);
+
+        assert_eq!(VersionedAgent::new("Empty").select("x").unwrap_err(), VersionError::NoVersions);
+    }
+
+    #[test]
+    fn test_runs_are_counted_per_version() {
+        let mut agent = versioned();
+        agent.set_split(&[("v1", 50), ("v2", 50)]).unwrap();
+        let mut served = Vec::new();
+        for user in 0..20 {
+            let result = agent
+                .run_with(&format!("user-{}", user), "Summarize", |agent, _task| {
+                    if agent.llm == "gpt-4o-mini" { Err("rate limited".to_string()) } else { Ok("summary".to_string()) }
+                })
+                .unwrap();
+            served.push(result.map(|run| run.version));
+        }
+
+        let stats = agent.stats();
+        let v1 = stats.get("v1").cloned().unwrap_or_default();
+        let v2 = stats.get("v2").cloned().unwrap_or_default();
+        assert_eq!(v1.runs + v2.runs, 20);
+        assert_eq!(v1.failures, 0);
+        assert_eq!(v2.failures, v2.runs);
+        assert_eq!(served.iter().filter(|result| result.is_err()).count() as u64, v2.runs);
+        assert!(served.iter().flatten().all(|version| version == "v1"));
+        if v2.runs > 0 {
+            assert_eq!(v2.failure_rate(), 1.0);
+        }
+    }
+}
+```
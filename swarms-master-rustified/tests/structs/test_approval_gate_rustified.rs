@@ -0,0 +1,301 @@
+### Tests for approval gates
+A gate must checkpoint the run while it waits, honour its mode and timeout policy, and accept decisions
+over the HTTP and WebSocket handlers. Both workflows must stop at a rejected gate, hand an edited output to
+the agent after the gate, and resume from the checkpoint a timed-out gate leaves behind.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, MockAgent};
+    use crate::swarms::structs::approval_gate::{
+        ApprovalChannel, ApprovalContext, ApprovalDecision, ApprovalError, ApprovalGate, ApprovalRequest,
+        CheckpointStore, GateMode, PendingApprovals, TimeoutPolicy,
+    };
+    use crate::swarms::structs::graph_workflow::{Edge, GraphWorkflow, Node, NodeType};
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    fn context(pending: &PendingApprovals, dir: &std::path::Path) -> ApprovalContext {
+        ApprovalContext {
+            run_id: "run-1".to_string(),
+            channel: Arc::new(pending.clone()),
+            store: CheckpointStore::new(dir),
+            audit: None,
+        }
+    }
+
+    // Answers every request with the same decision and keeps the requests it saw
+    struct Decides(ApprovalDecision, Mutex<Vec<ApprovalRequest>>);
+
+    #[async_trait]
+    impl ApprovalChannel for Decides {
+        async fn request(&self, request: &ApprovalRequest) -> Result<ApprovalDecision, ApprovalError> {
+            self.1.lock().unwrap().push(request.clone());
+            Ok(self.0.clone())
+        }
+    }
+
+    // Never answers, so a gate with a timeout gives up
+    struct Silent;
+
+    #[async_trait]
+    impl ApprovalChannel for Silent {
+        async fn request(&self, _request: &ApprovalRequest) -> Result<ApprovalDecision, ApprovalError> {
+            std::future::pending().await
+        }
+    }
+
+    fn deciding(channel: Arc<dyn ApprovalChannel>, dir: &std::path::Path) -> ApprovalContext {
+        ApprovalContext { run_id: "run-1".to_string(), channel, store: CheckpointStore::new(dir), audit: None }
+    }
+
+    fn approve(edited_output: Option<&str>) -> Arc<Decides> {
+        let decision = ApprovalDecision::Approved { by: "alice".into(), comment: None, edited_output: edited_output.map(Into::into) };
+        Arc::new(Decides(decision, Mutex::new(Vec::new())))
+    }
+
+    fn reject() -> Arc<Decides> {
+        let decision = ApprovalDecision::Rejected { by: "alice".into(), reason: "cite sources".into() };
+        Arc::new(Decides(decision, Mutex::new(Vec::new())))
+    }
+
+    // Writer -> gate -> Editor; the editor echoes what it is given
+    fn sequential(gate: ApprovalGate) -> (SequentialWorkflow, Arc<MockAgent>, Arc<MockAgent>) {
+        let writer = Arc::new(MockAgent::new("Writer").reply("draft with claims"));
+        let editor = Arc::new(MockAgent::new("Editor"));
+        let agents: Vec<Arc<dyn Agent>> = vec![writer.clone(), editor.clone()];
+        let mut workflow = SequentialWorkflow::new("Review".into(), String::new(), agents, 1, "all".into(), false, || ());
+        workflow.add_gate("Writer", gate).unwrap();
+        (workflow, writer, editor)
+    }
+
+    fn graph(gate: ApprovalGate) -> (GraphWorkflow, Arc<MockAgent>, Arc<MockAgent>) {
+        let writer = Arc::new(MockAgent::new("Writer").reply("draft with claims"));
+        let editor = Arc::new(MockAgent::new("Editor"));
+        let mut graph = GraphWorkflow::new();
+        graph.add_node(Node::new("Writer".to_string(), NodeType::Agent, None, Some(writer.clone() as Arc<dyn Agent>)));
+        graph.add_node(Node::gate("Review".to_string(), gate));
+        graph.add_node(Node::new("Editor".to_string(), NodeType::Agent, None, Some(editor.clone() as Arc<dyn Agent>)));
+        graph.add_edge(Edge { source: "Writer".to_string(), target: "Review".to_string() });
+        graph.add_edge(Edge { source: "Review".to_string(), target: "Editor".to_string() });
+        (graph, writer, editor)
+    }
+
+    #[tokio::test]
+    async fn test_auto_modes_skip_the_channel() {
+        let dir = tempfile::tempdir().unwrap();
+        let approvals = context(&PendingApprovals::new(), dir.path());
+        let gate = ApprovalGate::new("review").mode(GateMode::AutoApprove);
+        let request = gate.request("run-1", "SequentialWorkflow", "Writer", "draft");
+        let decision = gate.wait(request.clone(), json!({}), &approvals).await.unwrap();
+        assert!(matches!(decision, ApprovalDecision::Approved { by, .. } if by == "auto"));
+
+        let gate = gate.mode(GateMode::AutoReject);
+        let err = gate.wait(request, json!({}), &approvals).await.unwrap_err();
+        assert!(matches!(err, ApprovalError::Rejected { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_policies() {
+        let dir = tempfile::tempdir().unwrap();
+        let approvals = context(&PendingApprovals::new(), dir.path());
+        let request = ApprovalGate::new("review").request("run-1", "SequentialWorkflow", "Writer", "draft");
+
+        let gate = ApprovalGate::new("review").timeout(Duration::from_secs(0), TimeoutPolicy::Approve);
+        assert!(gate.wait(request.clone(), json!({}), &approvals).await.is_ok());
+
+        let gate = ApprovalGate::new("review").timeout(Duration::from_secs(0), TimeoutPolicy::Reject);
+        assert!(matches!(gate.wait(request.clone(), json!({}), &approvals).await, Err(ApprovalError::Rejected { .. })));
+        assert!(approvals.store.load("run-1").unwrap().is_none());
+
+        // `Fail` keeps the checkpoint so the run can be resumed.
+        let gate = ApprovalGate::new("review").timeout(Duration::from_secs(0), TimeoutPolicy::Fail);
+        let err = gate.wait(request, json!({ "next_agent": 1 }), &approvals).await.unwrap_err();
+        assert!(matches!(err, ApprovalError::TimedOut { .. }));
+        let checkpoint = approvals.store.load("run-1").unwrap().unwrap();
+        assert_eq!(checkpoint.state, json!({ "next_agent": 1 }));
+        assert_eq!(approvals.store.pending().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_checkpoint_is_kept_under_the_request_run_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let pending = PendingApprovals::new();
+        let approvals = context(&pending, dir.path());
+        let gate = ApprovalGate::new("review").timeout(Duration::from_millis(1500), TimeoutPolicy::Reject);
+        assert_eq!(gate.timeout, Some(Duration::from_millis(1500)));
+
+        // A request for another run is checkpointed and cleared under its own id.
+        let request = gate.request("run-2", "GraphWorkflow", "Writer", "draft");
+        let reviewer = pending.clone();
+        tokio::spawn(async move {
+            let request = reviewer.subscribe().recv().await.unwrap();
+            assert!(request.key().starts_with("run-2/"));
+            reviewer.decide(&request.key(), ApprovalDecision::Approved { by: "alice".into(), comment: None, edited_output: None })
+        });
+        tokio::task::yield_now().await;
+        assert!(gate.wait(request, json!({}), &approvals).await.is_ok());
+        assert!(approvals.store.pending().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_http_decision_resolves_the_gate() {
+        let dir = tempfile::tempdir().unwrap();
+        let pending = PendingApprovals::new();
+        let approvals = context(&pending, dir.path());
+        let gate = ApprovalGate::new("legal").timeout(Duration::from_secs(5), TimeoutPolicy::Fail);
+        let request = gate.request("run-1", "GraphWorkflow", "Writer", "draft");
+
+        let reviewer = pending.clone();
+        let reply = tokio::spawn(async move {
+            let mut announced = reviewer.subscribe();
+            let request = announced.recv().await.unwrap();
+            assert_eq!(reviewer.handle_http("GET", "/approvals", "").1[0]["gate"], "legal");
+            reviewer.handle_http("POST", &format!("/approvals/{}", request.key()), r#"{"approve": false, "reason": "cite sources"}"#)
+        });
+        tokio::task::yield_now().await;
+        let err = gate.wait(request, json!({}), &approvals).await.unwrap_err();
+        assert_eq!(err.to_string(), "gate 'legal' rejected by anonymous: cite sources");
+        assert_eq!(reply.await.unwrap().0, 200);
+        assert!(pending.list().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_edited_output_is_recorded_with_the_original() {
+        let dir = tempfile::tempdir().unwrap();
+        let pending = PendingApprovals::new();
+        let approvals = context(&pending, dir.path());
+        let gate = ApprovalGate::new("legal");
+        let request = gate.request("run-1", "SequentialWorkflow", "Writer", "draft with claims");
+
+        let reviewer = pending.clone();
+        tokio::spawn(async move {
+            let request = reviewer.subscribe().recv().await.unwrap();
+            let reply = reviewer.handle_ws_message(&format!(
+                r#"{{"type": "decide", "run_id": "{}", "gate": "{}", "approve": true, "by": "alice", "edited_output": "draft"}}"#,
+                request.run_id, request.gate
+            ));
+            assert_eq!(reply, r#"{"type":"ok"}"#);
+        });
+        tokio::task::yield_now().await;
+        let record = gate.review(request, json!({}), &approvals).await.unwrap();
+        assert_eq!(record.by, "alice");
+        assert_eq!(record.original_output, "draft with claims");
+        assert_eq!(record.edited_output.as_deref(), Some("draft"));
+        assert_eq!(record.output(), "draft");
+
+        // An unchanged "edit" is an ordinary approval.
+        let gate = gate.mode(GateMode::AutoApprove);
+        let record = gate.review(gate.request("run-1", "SequentialWorkflow", "Writer", "draft"), json!({}), &approvals).await.unwrap();
+        assert_eq!(record.edited_output, None);
+        assert_eq!(record.output(), "draft");
+    }
+
+    #[test]
+    fn test_unknown_decisions_are_reported() {
+        let pending = PendingApprovals::new();
+        let (status, _) = pending.handle_http("POST", "/approvals/run-9/review", r#"{"approve": true}"#);
+        assert_eq!(status, 404);
+        let (status, _) = pending.handle_http("POST", "/approvals/run-9/review", "not json");
+        assert_eq!(status, 400);
+        let reply = pending.handle_ws_message(r#"{"type": "decide", "run_id": "run-9", "gate": "review", "approve": true}"#);
+        assert!(reply.contains("no pending approval for run-9/review"));
+        assert_eq!(pending.handle_ws_message(r#"{"type": "list"}"#), r#"{"requests":[],"type":"pending"}"#);
+    }
+
+    #[tokio::test]
+    async fn test_sequential_gate_hands_the_edited_output_on() {
+        let dir = tempfile::tempdir().unwrap();
+        let (workflow, _, editor) = sequential(ApprovalGate::new("legal"));
+        let channel = approve(Some("draft"));
+        let run = workflow.run_with_approvals("Write the memo".to_string(), &deciding(channel.clone(), dir.path())).await.unwrap();
+        assert_eq!(channel.1.lock().unwrap()[0].output, "draft with claims");
+        assert_eq!(editor.calls(), ["draft"]);
+        assert_eq!(run.output, "draft");
+        assert_eq!(run.reviews.len(), 1);
+        assert_eq!(run.reviews[0].original_output, "draft with claims");
+        assert_eq!(run.reviews[0].edited_output.as_deref(), Some("draft"));
+
+        // Approved unchanged, the writer's own output goes through.
+        let (workflow, _, editor) = sequential(ApprovalGate::new("legal"));
+        let run = workflow.run_with_approvals("Write the memo".to_string(), &deciding(approve(None), dir.path())).await.unwrap();
+        assert_eq!(editor.calls(), ["draft with claims"]);
+        assert_eq!(run.reviews[0].edited_output, None);
+    }
+
+    #[tokio::test]
+    async fn test_sequential_gate_rejection_stops_the_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let (workflow, writer, editor) = sequential(ApprovalGate::new("legal"));
+        let approvals = deciding(reject(), dir.path());
+        let err = workflow.run_with_approvals("Write the memo".to_string(), &approvals).await.unwrap_err();
+        assert!(matches!(err, ApprovalError::Rejected { ref gate, .. } if gate == "legal"));
+        assert_eq!((writer.calls().len(), editor.calls().len()), (1, 0));
+        assert!(approvals.store.load("run-1").unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sequential_resumes_from_the_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let gate = ApprovalGate::new("legal").timeout(Duration::from_millis(10), TimeoutPolicy::Fail);
+        let (workflow, writer, editor) = sequential(gate);
+        let err = workflow.run_with_approvals("Write the memo".to_string(), &deciding(Arc::new(Silent), dir.path())).await.unwrap_err();
+        assert!(matches!(err, ApprovalError::TimedOut { .. }));
+        assert!(editor.calls().is_empty());
+
+        let approvals = deciding(approve(Some("draft")), dir.path());
+        assert!(approvals.store.load("run-1").unwrap().is_some());
+        let run = workflow.resume(&approvals).await.unwrap();
+        assert_eq!(run.output, "draft");
+        assert_eq!(writer.calls().len(), 1, "the writer is not run again");
+        assert_eq!(editor.calls(), ["draft"]);
+        assert!(approvals.store.load("run-1").unwrap().is_none());
+        assert!(matches!(workflow.resume(&approvals).await, Err(ApprovalError::NotPending(_))));
+    }
+
+    #[tokio::test]
+    async fn test_graph_gate_hands_the_edited_output_on() {
+        let dir = tempfile::tempdir().unwrap();
+        let (graph, _, editor) = graph(ApprovalGate::new("legal"));
+        assert!(matches!(graph.run("Write the memo"), Err(ApprovalError::NoContext { .. })));
+
+        let channel = approve(Some("draft"));
+        let (results, reviews) = graph.run_with_approvals("Write the memo", &deciding(channel.clone(), dir.path())).await.unwrap();
+        assert_eq!(channel.1.lock().unwrap()[0].output, "[Writer] draft with claims");
+        assert_eq!(editor.calls(), ["draft"]);
+        assert_eq!(results["Review"], "draft");
+        assert_eq!(results["Editor"], "draft");
+        assert_eq!(reviews[0].edited_output.as_deref(), Some("draft"));
+    }
+
+    #[tokio::test]
+    async fn test_graph_gate_rejection_stops_the_run() {
+        let dir = tempfile::tempdir().unwrap();
+        let (graph, writer, editor) = graph(ApprovalGate::new("legal"));
+        let err = graph.run_with_approvals("Write the memo", &deciding(reject(), dir.path())).await.unwrap_err();
+        assert!(matches!(err, ApprovalError::Rejected { ref reason, .. } if reason == "cite sources"));
+        assert_eq!((writer.calls().len(), editor.calls().len()), (1, 0));
+    }
+
+    #[tokio::test]
+    async fn test_graph_resumes_from_the_checkpoint() {
+        let dir = tempfile::tempdir().unwrap();
+        let gate = ApprovalGate::new("legal").timeout(Duration::from_millis(10), TimeoutPolicy::Fail);
+        let (graph, writer, editor) = graph(gate);
+        let err = graph.run_with_approvals("Write the memo", &deciding(Arc::new(Silent), dir.path())).await.unwrap_err();
+        assert!(matches!(err, ApprovalError::TimedOut { .. }));
+        assert!(editor.calls().is_empty());
+
+        let approvals = deciding(approve(None), dir.path());
+        let (results, reviews) = graph.resume(&approvals).await.unwrap();
+        assert_eq!(writer.calls().len(), 1, "the writer's result comes from the checkpoint");
+        assert_eq!(editor.calls(), ["[Writer] draft with claims"]);
+        assert_eq!(results["Writer"], "draft with claims");
+        assert_eq!(reviews.len(), 1);
+        assert!(approvals.store.load("run-1").unwrap().is_none());
+    }
+}
+```
@@ -0,0 +1,141 @@
+### Tests for the audit log
+Events must round-trip through the JSONL file, queries must stay inside the caller's tenant and compare
+times as instants, and agent changes, key revocations, and gate decisions must be audited.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::approval_gate::{ApprovalContext, ApprovalGate, CheckpointStore, GateMode, PendingApprovals};
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::agent_store::AgentStore;
+    use crate::swarms::structs::audit::{AuditAction, AuditEvent, AuditLog, AuditQuery, Auditor};
+    use crate::swarms::structs::tenancy::{TenantId, TenantKeys, TenantWorkspace};
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_query_filters_and_scopes_by_tenant() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let (acme, globex) = (TenantId::new("acme").unwrap(), TenantId::new("globex").unwrap());
+        log.log(&acme, "alice", Some("req-1"), AuditAction::AgentCreated, "Analyst", json!({})).unwrap();
+        log.log(&acme, "bob", Some("req-2"), AuditAction::KeyRevoked, "key-7", json!({})).unwrap();
+        log.log(&acme, "alice", None, AuditAction::AgentDeleted, "Analyst", json!({})).unwrap();
+        log.log(&globex, "alice", Some("req-3"), AuditAction::AgentCreated, "Analyst", json!({})).unwrap();
+
+        assert_eq!(log.query(&acme, &AuditQuery::default()).unwrap().len(), 3);
+        let query = AuditQuery::from_query_string("actor=alice&target=Analyst&limit=1").unwrap();
+        let events = log.query(&acme, &query).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AuditAction::AgentDeleted);
+        assert_eq!(events[0].request_id, None);
+
+        let query = AuditQuery::from_query_string("action=key_revoked").unwrap();
+        assert!(log.query(&globex, &query).unwrap().is_empty());
+        assert!(AuditQuery::from_query_string("action=rm_rf").is_err());
+        assert!(AuditQuery::from_query_string("tenant=globex").is_err());
+    }
+
+    #[test]
+    fn test_time_bounds_compare_instants() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let acme = TenantId::new("acme").unwrap();
+        for (event_id, timestamp) in [("utc", "2026-03-01T10:00:00.000Z"), ("offset", "2026-03-01T12:30:00+02:00")] {
+            let event = AuditEvent {
+                event_id: event_id.to_string(),
+                timestamp: timestamp.to_string(),
+                tenant_id: acme.clone(),
+                actor: "alice".to_string(),
+                request_id: None,
+                action: AuditAction::RunStarted,
+                target: "run-1".to_string(),
+                details: json!({}),
+            };
+            log.record(&event).unwrap();
+        }
+        let ids = |query: &str| -> Vec<String> {
+            let query = AuditQuery::from_query_string(query).unwrap();
+            log.query(&acme, &query).unwrap().into_iter().map(|event| event.event_id).collect()
+        };
+        // 12:30+02:00 is 10:30Z: before the bound, though it sorts after it as a string.
+        assert_eq!(ids("until=2026-03-01T11:00:00Z"), ["utc", "offset"]);
+        assert_eq!(ids("since=2026-03-01T10:15:00Z"), ["offset"]);
+        assert_eq!(ids("until=2026-03-01T12:00:00%2B02:00"), ["utc"]);
+        assert!(AuditQuery::from_query_string("since=yesterday").is_err());
+    }
+
+    #[test]
+    fn test_agent_and_key_actions_are_audited() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let (acme, globex) = (TenantId::new("acme").unwrap(), TenantId::new("globex").unwrap());
+        let auditor =
+            Auditor { log: log.clone(), tenant_id: acme.clone(), actor: "alice".to_string(), request_id: Some("req-1".into()) };
+
+        let store = AgentStore::new(TenantWorkspace::new(dir.path()));
+        let agent = LlmAgent::builder().name("Analyst").llm("gpt-4o").build().unwrap();
+        store.save_as(&auditor, &agent).unwrap();
+        store.save_as(&auditor, &agent).unwrap();
+        assert!(store.delete_as(&auditor, "Analyst").unwrap());
+        assert!(!store.delete_as(&auditor, "Analyst").unwrap());
+
+        let mut keys = TenantKeys::new().with_key("sk-acme", acme.clone()).with_key("sk-globex", globex);
+        assert!(!keys.revoke_as(&auditor, "sk-globex"), "another tenant's key");
+        assert!(keys.revoke_as(&auditor, "sk-acme"));
+
+        let events = log.query(&acme, &AuditQuery::default()).unwrap();
+        let actions: Vec<AuditAction> = events.iter().map(|event| event.action).collect();
+        assert_eq!(
+            actions,
+            [AuditAction::AgentCreated, AuditAction::AgentUpdated, AuditAction::AgentDeleted, AuditAction::KeyRevoked]
+        );
+        assert!(events.iter().all(|event| event.actor == "alice" && event.request_id.as_deref() == Some("req-1")));
+        assert_eq!(events[3].target, TenantKeys::key_id("sk-acme"));
+        assert!(!events[3].target.contains("sk-acme"));
+    }
+
+    #[test]
+    fn test_http_routes() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let acme = TenantId::new("acme").unwrap();
+        log.log(&acme, "alice", Some("req-1"), AuditAction::RunStarted, "run-1", json!({})).unwrap();
+        log.log(&acme, "alice", Some("req-2"), AuditAction::RunStarted, "run-2", json!({})).unwrap();
+
+        let (status, content_type, body) = log.handle_http(&acme, "GET", "/audit?target=run-2");
+        assert_eq!((status, content_type), (200, "application/json"));
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&body).unwrap()[0]["request_id"], "req-2");
+
+        let (status, content_type, body) = log.handle_http(&acme, "GET", "/audit/export");
+        assert_eq!((status, content_type), (200, "application/x-ndjson"));
+        assert_eq!(body.lines().count(), 2);
+
+        assert_eq!(log.handle_http(&acme, "DELETE", "/audit").0, 405);
+        let (status, content_type, body) = log.handle_http(&acme, "GET", "/audit?limit=many");
+        assert_eq!((status, content_type), (400, "application/problem+json"));
+        assert_eq!(serde_json::from_str::<serde_json::Value>(&body).unwrap()["code"], "invalid_request");
+    }
+
+    #[tokio::test]
+    async fn test_gate_decisions_are_audited() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let acme = TenantId::new("acme").unwrap();
+        let approvals = ApprovalContext {
+            run_id: "run-1".to_string(),
+            channel: Arc::new(PendingApprovals::new()),
+            store: CheckpointStore::new(dir.path().join("runs")),
+            audit: Some(Auditor { log: log.clone(), tenant_id: acme.clone(), actor: "alice".to_string(), request_id: None }),
+        };
+        let gate = ApprovalGate::new("legal").mode(GateMode::AutoReject);
+        assert!(gate.wait(gate.request("run-1", "SequentialWorkflow", "Writer", "draft"), json!({}), &approvals).await.is_err());
+
+        let events = log.query(&acme, &AuditQuery::default()).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].action, AuditAction::GateRejected);
+        assert_eq!(events[0].actor, "auto");
+        assert_eq!(events[0].target, "run-1/legal");
+    }
+}
+```
@@ -0,0 +1,110 @@
+### Tests for AutoSwarmRouter fallback and shadow routing
+A failing selected swarm must be replaced by the fallback, with the original error kept. A sampled task
+must also run on the runner-up swarm without changing the served output, and its divergence must be recorded.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::auto_swarm::{output_divergence, AutoSwarmRouter, ShadowConfig};
+    use crate::swarms::structs::swarm::{Swarm, SwarmError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // Answers with a fixed reply, or fails when the reply is None; counts its runs
+    struct Scripted {
+        name: &'static str,
+        reply: Option<&'static str>,
+        runs: Arc<AtomicUsize>,
+    }
+
+    impl Swarm for Scripted {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn run(&mut self, _task: &str) -> Result<String, SwarmError> {
+            self.runs.fetch_add(1, Ordering::SeqCst);
+            self.reply.map(str::to_string).ok_or_else(|| SwarmError::Failed(format!("{} is down", self.name)))
+        }
+    }
+
+    fn swarm(name: &'static str, reply: Option<&'static str>) -> (Box<dyn Swarm>, Arc<AtomicUsize>) {
+        let runs = Arc::new(AtomicUsize::new(0));
+        (Box::new(Scripted { name, reply, runs: runs.clone() }), runs)
+    }
+
+    fn prefer(order: &'static [&'static str]) -> impl Fn(&str, &str) -> f64 + Send {
+        move |_task, name| -(order.iter().position(|candidate| *candidate == name).unwrap_or(order.len()) as f64)
+    }
+
+    #[test]
+    fn test_fallback_serves_when_the_selected_swarm_errors() {
+        let (research, _) = swarm("research", None);
+        let (summary, _) = swarm("summary", Some("short answer"));
+        let (general, general_runs) = swarm("general", Some("general answer"));
+        let mut router = AutoSwarmRouter::new("router", vec![research, summary, general])
+            .scorer(prefer(&["research", "summary", "general"]))
+            .fallback("general");
+
+        let run = router.run_routed("find sources").unwrap();
+        assert_eq!((run.selected.as_str(), run.served_by.as_str()), ("research", "general"));
+        assert_eq!(run.output, "general answer");
+        assert_eq!(run.fallback_reason.as_deref(), Some("swarm failed: research is down"));
+        assert_eq!(general_runs.load(Ordering::SeqCst), 1);
+        assert!(run.shadow.is_none());
+
+        let (research, _) = swarm("research", None);
+        let (general, _) = swarm("general", None);
+        let mut router = AutoSwarmRouter::new("router", vec![research, general]).scorer(prefer(&["research"])).fallback("general");
+        let err = router.run("find sources").unwrap_err().to_string();
+        assert!(err.contains("research is down") && err.contains("fallback general failed"), "{}", err);
+
+        let (research, _) = swarm("research", None);
+        let mut router = AutoSwarmRouter::new("research", vec![research]).fallback("archive");
+        assert!(router.run("x").unwrap_err().to_string().contains("'archive' is not registered"));
+    }
+
+    #[test]
+    fn test_shadow_runs_the_runner_up_on_sampled_tasks() {
+        let (research, _) = swarm("research", Some("Paris is the capital of France"));
+        let (summary, summary_runs) = swarm("summary", Some("the capital is Paris"));
+        let mut router = AutoSwarmRouter::new("router", vec![summary, research])
+            .scorer(prefer(&["research", "summary"]))
+            .shadow(ShadowConfig { sample_rate: 1.0 });
+
+        let run = router.run_routed("capital of France?").unwrap();
+        assert_eq!(run.output, "Paris is the capital of France");
+        assert_eq!(summary_runs.load(Ordering::SeqCst), 1);
+        let shadow = run.shadow.unwrap();
+        assert_eq!((shadow.primary.as_str(), shadow.shadow.as_str()), ("research", "summary"));
+        let expected = output_divergence("Paris is the capital of France", "the capital is Paris");
+        assert_eq!(shadow.divergence, Some(expected));
+        assert_eq!(router.shadow_records().len(), 1);
+        assert_eq!(router.mean_divergence(), Some(expected));
+
+        let (research, _) = swarm("research", Some("answer"));
+        let (summary, summary_runs) = swarm("summary", Some("answer"));
+        let mut router = AutoSwarmRouter::new("research", vec![summary, research]).shadow(ShadowConfig { sample_rate: 0.0 });
+        for _ in 0..5 {
+            assert_eq!(router.run_routed("task").unwrap().selected, "research");
+        }
+        assert_eq!(summary_runs.load(Ordering::SeqCst), 0);
+        assert_eq!(router.mean_divergence(), None);
+    }
+
+    #[test]
+    fn test_failed_shadow_is_recorded_not_returned() {
+        let (research, _) = swarm("research", Some("answer"));
+        let (summary, _) = swarm("summary", None);
+        let mut router = AutoSwarmRouter::new("research", vec![research, summary]).shadow(ShadowConfig { sample_rate: 1.0 });
+        let run = router.run_routed("task").unwrap();
+        assert_eq!(run.output, "answer");
+        let shadow = run.shadow.unwrap();
+        assert_eq!((shadow.divergence, shadow.shadow_error.as_deref()), (None, Some("swarm failed: summary is down")));
+
+        assert_eq!(output_divergence("", ""), 0.0);
+        assert_eq!(output_divergence("A b", "b a"), 0.0);
+        assert_eq!(output_divergence("a", "b"), 1.0);
+    }
+}
+```
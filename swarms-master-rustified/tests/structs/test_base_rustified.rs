@@ -1,323 +1,124 @@
-### Viability Assessment
-
-The conversion of the provided Python file to Rust is **viable**. The code is primarily composed of tests for a `BaseStructure` class, which contains methods for saving, loading, and logging data. While Rust has a different standard library and syntax compared to Python, the core functionality of the code can be replicated.
-
-However, there are some potential challenges:
-
-1.  **Async/Await**: Rust's async/await syntax is similar to Python's, but the underlying mechanics are different. This might require some adjustments when converting the `async def` functions.
-2.  **File System Operations**: Rust's file system operations are handled through the `std::fs` and `std::path` modules. The equivalent functions for saving and loading files might need to be adapted.
-3.  **Threading**: Rust's threading model is different from Python's. The `run_in_thread` method would need to be reimplemented using Rust's `std::thread` module.
-4.  **JSON Serialization**: The `load_from_file` and `save_to_file` methods use JSON serialization. Rust has several JSON serialization libraries, such as `serde_json`, which can be used for this purpose.
-5.  **Compression**: The `compress_data` and `decompress_data` methods use compression algorithms. Rust has several compression libraries, such as `flate2`, which can be used for this purpose.
-
-### Converted Rust Code
-
-Here is the converted Rust code:
+### Tests for BaseStructure
+Metadata, errors, and artifacts must be written under their configured paths in the documented formats and
+read back, through compression and encryption when configured. Timestamps must come from the structure's
+clock.
 
 ```rust
-// test_base_structure.rs
-// Conversion viability: viable
-// Reasoning: The code is primarily composed of tests for a BaseStructure class.
-// Some challenges might arise from async/await, file system operations, threading,
-// JSON serialization, and compression, but overall the conversion is feasible.
-
-use std::fs::{self, File};
-use std::io::{self, BufReader, BufWriter};
-use std::path::Path;
-use std::thread;
-use std::time::SystemTime;
-
-use tokio;
-use serde_json::{self, Value};
-use flate2::read::GzDecoder;
-use flate2::write::GzEncoder;
-use log::{self, info};
-
-struct BaseStructure {
-    name: String,
-    description: String,
-    save_metadata: bool,
-    save_artifact_path: String,
-    save_metadata_path: String,
-    save_error_path: String,
-}
-
-impl BaseStructure {
-    fn new(
-        name: &str,
-        description: &str,
-        save_metadata: bool,
-        save_artifact_path: &str,
-        save_metadata_path: &str,
-        save_error_path: &str,
-    ) -> Self {
-        BaseStructure {
-            name: name.to_string(),
-            description: description.to_string(),
-            save_metadata,
-            save_artifact_path: save_artifact_path.to_string(),
-            save_metadata_path: save_metadata_path.to_string(),
-            save_error_path: save_error_path.to_string(),
-        }
-    }
-
-    // Save data to file
-    fn save_to_file(&self, data: Value, file_path: &str) -> io::Result<()> {
-        let file = File::create(file_path)?;
-        let writer = BufWriter::new(file);
-        serde_json::to_writer_pretty(writer, &data)?;
-        Ok(())
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::base_structure::{BaseStructure, BaseStructureError};
+    use crate::swarms::utils::clock::ManualClock;
+    use crate::swarms::utils::compression::StateCompressor;
+    use serde_json::{json, Value};
+    use std::io;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    fn structure(root: &Path) -> BaseStructure {
+        BaseStructure::new("TestStructure", "Test description")
+            .with_artifact_path(root.join("artifacts"))
+            .with_metadata_path(root.join("metadata"))
+            .with_error_path(root.join("errors"))
+            .with_clock(Arc::new(ManualClock::at_millis(1_700_000_000_999)))
     }
 
-    // Load data from file
-    fn load_from_file(&self, file_path: &str) -> io::Result<Value> {
-        let file = File::open(file_path)?;
-        let reader = BufReader::new(file);
-        serde_json::from_reader(reader)
+    #[test]
+    fn test_init() {
+        let base = BaseStructure::new("TestStructure", "Test description");
+        assert_eq!((base.name.as_str(), base.description.as_str()), ("TestStructure", "Test description"));
+        assert!(base.save_metadata);
+        assert_eq!(base.save_artifact_path, Path::new("./artifacts"));
+        assert_eq!(base.save_metadata_path, Path::new("./metadata"));
+        assert_eq!(base.save_error_path, Path::new("./errors"));
     }
 
-    // Compress data
-    fn compress_data(&self, data: Value) -> Vec<u8> {
-        let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::best());
-        serde_json::to_writer_pretty(&mut encoder, &data).unwrap();
-        encoder.finish().unwrap()
+    #[test]
+    fn test_save_to_file_and_load_from_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = structure(dir.path()).with_compression(StateCompressor::new().with_threshold(64));
+        let data = json!({ "key": "value", "rows": (0..100).collect::<Vec<u32>>() });
+        let path = dir.path().join("nested/test_file.json");
+        base.save_to_file(&data, &path).unwrap();
+        assert!(StateCompressor::is_compressed(&std::fs::read(&path).unwrap()));
+        assert_eq!(base.load_from_file::<Value>(&path).unwrap(), data);
     }
 
-    // Decompress data
-    fn decompress_data(&self, compressed_data: Vec<u8>) -> io::Result<Value> {
-        let mut decoder = GzDecoder::new(compressed_data.as_slice());
-        let mut decompressed_data = vec![];
-        decoder.read_to_end(&mut decompressed_data)?;
-        serde_json::from_slice(&decompressed_data)
+    #[test]
+    fn test_save_metadata_and_load_metadata() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = structure(dir.path());
+        assert_eq!(base.load_metadata::<Value>().unwrap(), None);
+
+        let metadata = json!({ "name": "Test", "description": "Test metadata" });
+        base.save_metadata(&metadata).unwrap();
+        assert_eq!(base.metadata_file(), dir.path().join("metadata/TestStructure_metadata.json"));
+        let saved: Value = serde_json::from_slice(&std::fs::read(base.metadata_file()).unwrap()).unwrap();
+        assert_eq!(saved["name"], "TestStructure");
+        assert_eq!(saved["saved_at"], "2023-11-14T22:13:20.999+00:00");
+        assert_eq!(base.load_metadata::<Value>().unwrap(), Some(metadata));
+
+        let off = structure(&dir.path().join("off")).with_save_metadata(false);
+        off.save_metadata(&json!({})).unwrap();
+        assert!(!off.metadata_file().exists());
     }
 
-    // Run function in thread
-    fn run_in_thread<F>(&self, func: F) -> thread::JoinHandle<String>
-    where
-        F: FnOnce() -> String + Send + 'static,
-    {
-        thread::spawn(func)
+    #[test]
+    fn test_log_error() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = structure(dir.path());
+        let timeout = io::Error::new(io::ErrorKind::TimedOut, "provider did not answer");
+        base.log_error(&BaseStructureError::Io(timeout)).unwrap();
+        base.record_error(&base.error_entry("Test error message")).unwrap();
+
+        assert_eq!(base.error_file(), dir.path().join("errors/TestStructure_errors.jsonl"));
+        let errors = base.errors().unwrap();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors[0].message, "state file I/O failed: provider did not answer");
+        assert_eq!(errors[0].causes, vec!["provider did not answer"]);
+        assert_eq!((errors[1].structure.as_str(), errors[1].message.as_str()), ("TestStructure", "Test error message"));
+        assert!(errors[1].causes.is_empty());
     }
 
-    // Log event
-    fn log_event(&self, event: &str, event_type: &str) {
-        info!("[{}] [{}] {}", self._current_timestamp(), event_type, event);
+    #[test]
+    fn test_save_artifact_and_load_artifact() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = structure(dir.path());
+        let artifact = json!({ "key": "value" });
+        let path = base.save_artifact(&artifact, "test_artifact").unwrap();
+        assert_eq!(path, dir.path().join("artifacts/test_artifact.json"));
+        assert_eq!(base.load_artifact::<Value>("test_artifact").unwrap(), artifact);
+        assert!(matches!(base.save_artifact(&artifact, "../escape"), Err(BaseStructureError::Path(_))));
     }
 
-    // Get current timestamp
-    fn _current_timestamp(&self) -> String {
-        SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            .to_string()
+    #[test]
+    fn test_current_timestamp() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(structure(dir.path()).current_timestamp(), "1700000000");
     }
 
-    async fn run_async<F>(&self, func: F) -> String
-    where
-        F: FnOnce() -> String + Send + 'static,
-    {
-        tokio::task::spawn(func).await.unwrap()
+    #[test]
+    fn test_log_event() {
+        let dir = tempfile::tempdir().unwrap();
+        structure(dir.path()).log_event("Test event", "INFO");
     }
-}
-
-#[tokio::test]
-async fn test_init() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    assert_eq!(base_structure.name, "TestStructure");
-    assert_eq!(base_structure.description, "Test description");
-    assert_eq!(base_structure.save_metadata, true);
-    assert_eq!(base_structure.save_artifact_path, "./test_artifacts");
-    assert_eq!(base_structure.save_metadata_path, "./test_metadata");
-    assert_eq!(base_structure.save_error_path, "./test_errors");
-}
-
-#[tokio::test]
-async fn test_save_to_file_and_load_from_file() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let data_to_save = serde_json::json!({"key": "value"});
-    let file_path = "test_file.json";
-
-    base_structure.save_to_file(data_to_save.clone(), file_path).unwrap();
-    let loaded_data = base_structure.load_from_file(file_path).unwrap();
-
-    assert_eq!(loaded_data, data_to_save);
-}
-
-#[tokio::test]
-async fn test_save_metadata_and_load_metadata() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let metadata = serde_json::json!({"name": "Test", "description": "Test metadata"});
-
-    base_structure.save_to_file(metadata.clone(), "metadata.json").unwrap();
-    let loaded_metadata = base_structure.load_from_file("metadata.json").unwrap();
-
-    assert_eq!(loaded_metadata, metadata);
-}
-
-#[tokio::test]
-async fn test_log_error() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let error_message = "Test error message";
-
-    let mut file = fs::File::create("error.log").unwrap();
-    let mut writer = BufWriter::new(file);
 
-    writeln!(writer, "{}", error_message).unwrap();
+    #[tokio::test]
+    async fn test_run_async() {
+        let dir = tempfile::tempdir().unwrap();
+        let result = structure(dir.path()).run_async(|| String::from("Async Test Result")).await.unwrap();
+        assert_eq!(result, "Async Test Result");
 
-    let file = fs::File::open("error.log").unwrap();
-    let reader = BufReader::new(file);
-
-    let mut lines = vec![];
-    for line in reader.lines() {
-        lines.push(line.unwrap());
+        let err = structure(dir.path()).run_async(|| -> () { panic!("worker crashed") }).await.unwrap_err();
+        assert!(matches!(err, BaseStructureError::Task(_)), "{}", err);
     }
 
-    assert_eq!(lines.len(), 1);
-    assert_eq!(lines[0], error_message);
-}
-
-#[tokio::test]
-async fn test_save_artifact_and_load_artifact() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let artifact = serde_json::json!({"key": "value"});
-    let artifact_name = "test_artifact";
-
-    base_structure.save_to_file(artifact.clone(), &format!("{}.json", artifact_name)).unwrap();
-    let loaded_artifact = base_structure.load_from_file(&format!("{}.json", artifact_name)).unwrap();
-
-    assert_eq!(loaded_artifact, artifact);
-}
-
-#[tokio::test]
-async fn test_current_timestamp() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let current_time = SystemTime::now()
-        .duration_since(SystemTime::UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-        .to_string();
-
-    assert_eq!(base_structure._current_timestamp(), current_time);
-}
-
-#[tokio::test]
-async fn test_log_event() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let event = "Test event";
-    let event_type = "INFO";
-
-    base_structure.log_event(event, event_type);
-}
-
-#[tokio::test]
-async fn test_run_async() {
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    async fn async_function() -> String {
-        String::from("Async Test Result")
+    #[test]
+    fn test_in_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let base = BaseStructure::in_workspace("Queue-Swarm", "Runs tasks", dir.path());
+        base.save_metadata(&json!({ "tasks_completed": 2 })).unwrap();
+        assert!(dir.path().join("Queue-Swarm_metadata.json").is_file());
+        assert_eq!((base.save_error_path, base.save_artifact_path), (dir.path().join("errors"), dir.path().join("artifacts")));
     }
-
-    let result = base_structure.run_async(async_function).await;
-
-    assert_eq!(result, "Async Test Result");
-}
-
-fn main() {
-    env_logger::init();
-    log::set_max_level(log::LevelFilter::Info);
-
-    let base_structure = BaseStructure::new(
-        "TestStructure",
-        "Test description",
-        true,
-        "./test_artifacts",
-        "./test_metadata",
-        "./test_errors",
-    );
-
-    let data_to_save = serde_json::json!({"key": "value"});
-
-    base_structure.save_to_file(data_to_save.clone(), "test_file.json").unwrap();
-    let loaded_data = base_structure.load_from_file("test_file.json").unwrap();
-
-    assert_eq!(loaded_data, data_to_save);
 }
 ```
-
-Please note that you will need to add the necessary dependencies in your `Cargo.toml` file:
-
-```toml
-[dependencies]
-serde = { version = "1.0", features = ["derive"] }
-serde_json = "1.0"
-flate2 = "1.0.20"
-log = "0.4.14"
-env_logger = "0.9.1"
-tokio = { version = "1", features = ["full"] }
-```
-
-Also note that some tests might need to be adjusted according to the actual behavior of your `BaseStructure` class.
\ No newline at end of file
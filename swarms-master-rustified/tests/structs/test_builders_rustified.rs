@@ -0,0 +1,44 @@
+### Tests for the Agent and Conversation builders
+Defaults must match the Python constructors, and `build()` must reject invalid combinations.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{AgentConfigError, LlmAgent};
+    use crate::swarms::structs::conversation::{Conversation, ConversationConfigError};
+    use crate::swarms::text::context_budget::ContextBudget;
+
+    #[test]
+    fn test_agent_defaults() {
+        let agent = LlmAgent::builder().name("Analyst").llm("gpt-4o-mini").build().unwrap();
+        assert_eq!(agent.max_loops, 1);
+        assert_eq!(agent.retry_attempts, 3);
+        assert_eq!(agent.user_name, "Human:");
+        assert_eq!(agent.output_type, "str");
+        assert_eq!(agent.saved_state_path, "Analyst_state.json");
+        assert_eq!(agent.budget, ContextBudget::default());
+        assert!(!agent.autosave);
+    }
+
+    #[test]
+    fn test_agent_validation() {
+        let err = LlmAgent::builder().name("Analyst").llm("gpt-4o-mini").max_loops(0).build().unwrap_err();
+        assert_eq!(err, AgentConfigError::InvalidMaxLoops(0));
+        let err = LlmAgent::builder().name(" ").llm("gpt-4o-mini").build().unwrap_err();
+        assert_eq!(err, AgentConfigError::EmptyName);
+        let err = LlmAgent::builder().llm("gpt-4o-mini").name("Analyst").budget(ContextBudget::new(4_096, 4_096)).build().unwrap_err();
+        assert_eq!(err, AgentConfigError::NoInputBudget);
+    }
+
+    #[test]
+    fn test_conversation_validation() {
+        assert_eq!(Conversation::builder().autosave(true).build().err(), Some(ConversationConfigError::MissingSavePath));
+        assert_eq!(
+            Conversation::builder().save_as_json().save_as_yaml(true).build().err(),
+            Some(ConversationConfigError::ConflictingSaveFormats)
+        );
+        let conversation = Conversation::builder().system_prompt("You are terse.").build().unwrap();
+        assert_eq!(conversation.return_history_as_string(), "System:: You are terse.");
+    }
+}
+```
@@ -0,0 +1,137 @@
+### Tests for run callbacks
+Agents must report each finished step to their callbacks, from `run` and from every loop of `run_loops`,
+and swarms must report each agent's turn once `set_callbacks` is called, or refuse it when they cannot. A
+`CallbackSet` must pass every event to each of its listeners.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::ToolCall;
+    use crate::swarms::structs::agent::{Agent, LlmAgent, MockAgent};
+    use crate::swarms::structs::callbacks::{CallbackSet, RunCallbacks, StepComplete};
+    use crate::swarms::structs::majority_voting::MajorityVoting;
+    use crate::swarms::structs::round_robin::RoundRobinSwarm;
+    use crate::swarms::structs::swarm::{Swarm, SwarmError};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Mutex<Vec<String>>,
+    }
+
+    impl Recorder {
+        fn events(&self) -> Vec<String> {
+            self.events.lock().unwrap().clone()
+        }
+    }
+
+    impl RunCallbacks for Recorder {
+        fn on_token(&self, agent: &str, token: &str) {
+            self.events.lock().unwrap().push(format!("token {} {}", agent, token));
+        }
+
+        fn on_tool_call(&self, agent: &str, call: &ToolCall) {
+            self.events.lock().unwrap().push(format!("tool {} {}", agent, call.name));
+        }
+
+        fn on_step_complete(&self, agent: &str, step: &StepComplete) {
+            self.events.lock().unwrap().push(format!("step {} {} {}", agent, step.index, step.output));
+        }
+    }
+
+    // Only counts steps; the other two methods keep their no-op defaults
+    #[derive(Default)]
+    struct StepCounter(Mutex<usize>);
+
+    impl RunCallbacks for StepCounter {
+        fn on_step_complete(&self, _agent: &str, _step: &StepComplete) {
+            *self.0.lock().unwrap() += 1;
+        }
+    }
+
+    fn agent(recorder: Arc<Recorder>) -> LlmAgent {
+        LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o-mini")
+            .max_loops(2)
+            .callbacks(recorder)
+            .completion(|agent, task| {
+                // A streaming completion would pass these on to its provider
+                let callbacks = agent.callbacks().expect("callbacks registered");
+                callbacks.on_token(&agent.name, "Revenue ");
+                callbacks.on_token(&agent.name, "grew.");
+                Ok(format!("answer to {}", task))
+            })
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_agent_reports_tokens_and_steps() {
+        let recorder = Arc::new(Recorder::default());
+        let agent = agent(recorder.clone());
+        assert_eq!(agent.run("Q3").unwrap(), "answer to Q3");
+        assert_eq!(
+            recorder.events(),
+            ["token Analyst Revenue ", "token Analyst grew.", "step Analyst 0 answer to Q3"]
+        );
+        assert!(format!("{:?}", agent).contains("RunCallbacks"));
+    }
+
+    #[test]
+    fn test_run_loops_reports_each_loop() {
+        let recorder = Arc::new(Recorder::default());
+        let agent = agent(recorder.clone());
+        let run = agent.run_loops("Q3", |step| format!("draft {}", step.index));
+        assert_eq!(run.outputs.len(), 2);
+        assert_eq!(recorder.events(), ["step Analyst 0 draft 0", "step Analyst 1 draft 1"]);
+    }
+
+    #[test]
+    fn test_swarms_report_turns() {
+        let recorder = Arc::new(Recorder::default());
+        let agents: Vec<Arc<dyn Agent>> =
+            vec![Arc::new(MockAgent::new("A").reply("yes")), Arc::new(MockAgent::new("B").reply("no"))];
+        let mut round_robin = RoundRobinSwarm::new("rr".to_string(), String::new(), agents.clone(), false, 1);
+        Swarm::set_callbacks(&mut round_robin, recorder.clone()).unwrap();
+        Swarm::run(&mut round_robin, "vote").unwrap();
+        assert_eq!(recorder.events(), ["step A 0 yes", "step B 1 no"]);
+
+        let counter = Arc::new(StepCounter::default());
+        let mut voting = MajorityVoting::new("vote", "", agents, None, false, false);
+        Swarm::set_callbacks(&mut voting, counter.clone()).unwrap();
+        Swarm::run(&mut voting, "vote").unwrap();
+        assert_eq!(*counter.0.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_unsupported_callbacks_are_refused() {
+        struct Plain;
+
+        impl Swarm for Plain {
+            fn name(&self) -> &str {
+                "Plain"
+            }
+
+            fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+                Ok(task.to_string())
+            }
+        }
+
+        let err = Plain.set_callbacks(Arc::new(StepCounter::default())).unwrap_err();
+        assert_eq!(err.to_string(), "swarm failed: Plain does not support run callbacks");
+    }
+
+    #[test]
+    fn test_callback_set_fans_out() {
+        let first = Arc::new(Recorder::default());
+        let second = Arc::new(Recorder::default());
+        let set = CallbackSet::new().with(first.clone()).with(second.clone());
+        assert_eq!(set.len(), 2);
+        let call = ToolCall { id: "call_0".to_string(), name: "search".to_string(), arguments: "{}".to_string() };
+        set.on_tool_call("Analyst", &call);
+        assert_eq!(first.events(), ["tool Analyst search"]);
+        assert_eq!(second.events(), first.events());
+    }
+}
+```
@@ -0,0 +1,118 @@
+### Tests for the OpenAI-compatible chat completions facade
+Responses, streams, and errors must have the shapes the OpenAI SDKs parse, `model` must pick the agent or
+swarm that runs, and the earlier messages must reach it as history.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, MockAgent};
+    use crate::swarms::structs::chat_completions::{ChatCompletions, EVENT_STREAM};
+    use crate::swarms::structs::round_robin::RoundRobinSwarm;
+    use serde_json::{json, Value as JsonValue};
+    use std::sync::Arc;
+
+    fn post(server: &ChatCompletions, body: JsonValue) -> (u16, &'static str, String) {
+        server.handle_http("POST", "/v1/chat/completions", &body.to_string())
+    }
+
+    fn server(analyst: Arc<MockAgent>) -> ChatCompletions {
+        let member: Arc<dyn Agent> = Arc::new(MockAgent::new("Member").reply("from the rota"));
+        ChatCompletions::new()
+            .with_agent(analyst)
+            .with_swarm(RoundRobinSwarm::new("Rota".to_string(), String::new(), vec![member], false, 1))
+    }
+
+    #[test]
+    fn test_model_selects_agent_or_swarm() {
+        let analyst = Arc::new(MockAgent::new("Analyst").reply("Revenue grew 12%."));
+        let server = server(analyst.clone());
+
+        let (status, _, body) = server.handle_http("GET", "/v1/models", "");
+        let listed: JsonValue = serde_json::from_str(&body).unwrap();
+        let ids: Vec<&str> = listed["data"].as_array().unwrap().iter().map(|model| model["id"].as_str().unwrap()).collect();
+        assert_eq!((status, ids), (200, vec!["Analyst", "Rota"]));
+        assert_eq!(server.handle_http("GET", "/v1/models/Rota", "").0, 200);
+
+        let (status, content_type, body) = post(&server, json!({
+            "model": "Analyst",
+            "temperature": 0.2,
+            "messages": [
+                { "role": "system", "content": "Answer briefly." },
+                { "role": "user", "content": "Hi" },
+                { "role": "assistant", "content": null },
+                {
+                    "role": "user",
+                    "content": [{ "type": "text", "text": "How did Q3 go?" }, { "type": "image_url", "image_url": {} }]
+                }
+            ]
+        }));
+        assert_eq!((status, content_type), (200, "application/json"));
+        let completion: JsonValue = serde_json::from_str(&body).unwrap();
+        assert_eq!(completion["object"], "chat.completion");
+        assert!(completion["id"].as_str().unwrap().starts_with("chatcmpl-"));
+        assert_eq!(completion["choices"][0]["message"], json!({ "role": "assistant", "content": "Revenue grew 12%." }));
+        assert_eq!(completion["choices"][0]["finish_reason"], "stop");
+        let usage = &completion["usage"];
+        let counted = usage["prompt_tokens"].as_u64().unwrap() + usage["completion_tokens"].as_u64().unwrap();
+        assert_eq!(usage["total_tokens"], counted);
+
+        // Earlier messages arrive as history; the last user message is the task
+        let task = &analyst.calls()[0];
+        assert!(task.starts_with("system: Answer briefly.\nuser: Hi\n"));
+        assert!(task.ends_with("How did Q3 go?"));
+
+        let (status, _, body) = post(&server, json!({ "model": "Rota", "messages": [{ "role": "user", "content": "Go" }] }));
+        let completion: JsonValue = serde_json::from_str(&body).unwrap();
+        assert_eq!((status, completion["model"].as_str()), (200, Some("Rota")));
+    }
+
+    #[test]
+    fn test_stream_is_chunk_events() {
+        let server = server(Arc::new(MockAgent::new("Analyst").reply("Revenue grew 12%.")));
+        let (status, content_type, body) = post(&server, json!({
+            "model": "Analyst",
+            "stream": true,
+            "stream_options": { "include_usage": true },
+            "messages": [{ "role": "user", "content": "How did Q3 go?" }]
+        }));
+        assert_eq!((status, content_type), (200, EVENT_STREAM));
+
+        let events: Vec<&str> = body.split("\n\n").filter_map(|event| event.strip_prefix("data: ")).collect();
+        assert_eq!(events.last(), Some(&"[DONE]"));
+        let chunks: Vec<JsonValue> = events[..events.len() - 1].iter().map(|data| serde_json::from_str(data).unwrap()).collect();
+        assert!(chunks.iter().all(|chunk| chunk["object"] == "chat.completion.chunk" && chunk["id"] == chunks[0]["id"]));
+
+        let content: String = chunks.iter().filter_map(|chunk| chunk["choices"][0]["delta"]["content"].as_str()).collect();
+        assert_eq!(content, "Revenue grew 12%.");
+        assert_eq!(chunks[0]["choices"][0]["delta"]["role"], "assistant");
+        assert_eq!(chunks[chunks.len() - 2]["choices"][0]["finish_reason"], "stop");
+        let last = chunks.last().unwrap();
+        assert_eq!(last["choices"], json!([]));
+        assert!(last["usage"]["total_tokens"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_errors_use_openai_envelope() {
+        let server = server(Arc::new(MockAgent::new("Analyst").fail("provider timed out")));
+        let error = |(status, content_type, body): (u16, &str, String)| {
+            assert_eq!(content_type, "application/json");
+            let body: JsonValue = serde_json::from_str(&body).unwrap();
+            let field = |name: &str| body["error"][name].as_str().unwrap().to_string();
+            (status, field("type"), field("code"))
+        };
+
+        let hi = json!([{ "role": "user", "content": "Hi" }]);
+        let (status, kind, code) = error(post(&server, json!({ "model": "gpt-4o", "messages": hi })));
+        assert_eq!((status, kind.as_str(), code.as_str()), (404, "invalid_request_error", "model_not_found"));
+
+        let trailing_assistant = json!({ "model": "Analyst", "messages": [{ "role": "assistant", "content": "Hi" }] });
+        assert_eq!(error(post(&server, trailing_assistant)).0, 400);
+        assert_eq!(error(server.handle_http("POST", "/v1/chat/completions", "{\"model\": 1}")).0, 400);
+
+        let (status, kind, code) = error(post(&server, json!({ "model": "Analyst", "messages": hi })));
+        assert_eq!((status, kind.as_str(), code.as_str()), (502, "server_error", "agent_failed"));
+
+        assert_eq!(error(server.handle_http("GET", "/v1/embeddings", "")).0, 404);
+    }
+}
+```
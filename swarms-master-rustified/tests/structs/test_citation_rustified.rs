@@ -0,0 +1,69 @@
+### Tests for citations through a workflow run
+A `SequentialWorkflow` run with citations must run the agents themselves and end its answer with the sources
+the task came with, and a step that cites what it used must add its sources after the task's, once each.
+Citation ids must not change between builds, since saved answers refer to them.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, MockAgent};
+    use crate::swarms::structs::citation::{Citation, CitationSet, Cited};
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use std::sync::Arc;
+
+    fn workflow(agents: Vec<Arc<dyn Agent>>) -> SequentialWorkflow {
+        SequentialWorkflow::new("Analysis".into(), String::new(), agents, 1, "all".into(), false, || ())
+    }
+
+    fn filing() -> Citation {
+        Citation::new("https://sec.gov/aapl-10k.htm", Some("page 23".to_string())).with_title("Apple 10-K")
+    }
+
+    #[test]
+    fn test_run_with_sources_runs_the_agents() {
+        let researcher = Arc::new(MockAgent::new("Researcher").reply("Revenue grew 8% [1]."));
+        let writer = Arc::new(MockAgent::new("Writer").reply("Apple revenue grew 8% [1]."));
+        let workflow = workflow(vec![researcher.clone(), writer.clone()]);
+
+        let sources: CitationSet = [filing()].into_iter().collect();
+        let answer = workflow.run_with_sources("Summarize Apple's 10-K".to_string(), sources).unwrap();
+        assert_eq!(answer, "Apple revenue grew 8% [1].\n\nSources:\n[1] Apple 10-K - https://sec.gov/aapl-10k.htm (page 23)\n");
+        assert_eq!(researcher.calls(), ["Summarize Apple's 10-K"]);
+        assert_eq!(writer.calls(), ["Revenue grew 8% [1]."]);
+    }
+
+    #[test]
+    fn test_run_with_citations_reports_a_failed_agent() {
+        let workflow = workflow(vec![Arc::new(MockAgent::new("Researcher").fail("provider returned 503"))]);
+        let err = workflow.run_with_citations(Cited::new("task".to_string(), CitationSet::new())).unwrap_err();
+        assert!(err.contains("provider returned 503"), "{}", err);
+    }
+
+    #[test]
+    fn test_run_cited_merges_step_citations() {
+        let workflow = workflow(vec![Arc::new(MockAgent::new("Researcher")), Arc::new(MockAgent::new("Writer"))]);
+        let margins = Citation::new("https://example.com/margins", None);
+        let task = Cited::new("Summarize".to_string(), [filing()].into_iter().collect());
+        let answer = workflow
+            .run_cited(task, |agent, input| {
+                // Both steps cite the filing again; the researcher also cites the margins page.
+                let mut cited: CitationSet = [filing()].into_iter().collect();
+                if agent.name() == "Researcher" {
+                    cited.add(margins.clone());
+                }
+                Ok(Cited::new(format!("{} > {}", input, agent.name()), cited))
+            })
+            .unwrap();
+        assert_eq!(answer.value, "Summarize > Researcher > Writer");
+        let sources: Vec<&str> = answer.citations.iter().map(|citation| citation.source.as_str()).collect();
+        assert_eq!(sources, ["https://sec.gov/aapl-10k.htm", "https://example.com/margins"]);
+    }
+
+    #[test]
+    fn test_citation_ids_are_stable() {
+        assert_eq!(filing().id, "ff6a83df0a16076f");
+        assert_eq!(Citation::new("ab", None).id, "969caaeb3626c0d5");
+        assert_eq!(Citation::new("a", Some("b".to_string())).id, "359a006bf43d7a98");
+    }
+}
+```
@@ -0,0 +1,111 @@
+### Tests for hot-reloading the swarm config
+A valid edit must replace the active config in one swap and list what changed. A snapshot taken before the
+swap must stay as it was. Broken or invalid files must leave the previous config active and be reported
+once, and TOML files must load like YAML.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::audit::{AuditAction, AuditLog, AuditQuery, Auditor};
+    use crate::swarms::structs::config_reload::{ConfigFileFormat, ConfigReloadError, LiveSwarmConfig, ReloadOutcome};
+    use crate::swarms::structs::tenancy::TenantId;
+    use std::fs;
+    use std::path::Path;
+
+    const V1: &str = "agents:\n  - agent_name: Researcher\n    system_prompt: You gather sources.\n\
+        swarm_architecture:\n  name: Reports\n  swarm_type: SequentialWorkflow\n";
+
+    const V2: &str = "agents:\n  - agent_name: Researcher\n    system_prompt: You gather peer-reviewed sources.\n\
+        \n  - agent_name: Writer\n    system_prompt: You write the report.\n\
+        swarm_architecture:\n  name: Reports\n  swarm_type: SequentialWorkflow\n  max_loops: 2\n";
+
+    #[test]
+    fn test_valid_edit_is_applied_atomically() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("swarm.yaml");
+        fs::write(&path, V1).unwrap();
+        let live = LiveSwarmConfig::load(&path, "gpt-4o").unwrap();
+        let running = live.current();
+        assert_eq!(live.reload(), ReloadOutcome::Unchanged);
+
+        fs::write(&path, V2).unwrap();
+        let outcome = live.reload();
+        assert_eq!(
+            outcome,
+            ReloadOutcome::Applied {
+                version: 2,
+                changes: vec![
+                    "updated the system prompt of 'Researcher'".to_string(),
+                    "added agent 'Writer'".to_string(),
+                    "updated swarm_architecture".to_string(),
+                ],
+            }
+        );
+        assert_eq!((running.version, running.agents.len()), (1, 1));
+        let current = live.current();
+        assert_eq!(current.workflow().flow(), "Researcher -> Writer");
+        assert_eq!(current.agent("Writer").unwrap().llm, "gpt-4o");
+
+        fs::write(&path, format!("# reformatted\n{}", V2)).unwrap();
+        assert_eq!(live.reload(), ReloadOutcome::Unchanged);
+        assert_eq!(live.current().version, 2);
+        assert_eq!(live.events().len(), 1);
+    }
+
+    #[test]
+    fn test_bad_files_keep_the_previous_config_and_are_reported_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("swarm.yaml");
+        fs::write(&path, V1).unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let tenant = TenantId::new("acme").unwrap();
+        let auditor = Auditor { log: log.clone(), tenant_id: tenant.clone(), actor: "daemon".to_string(), request_id: None };
+        let live = LiveSwarmConfig::load(&path, "gpt-4o").unwrap().audited(auditor);
+
+        fs::write(&path, "agents: [unterminated").unwrap();
+        assert!(matches!(live.reload(), ReloadOutcome::Rejected { reason } if reason.contains("does not parse")));
+        assert!(matches!(live.reload(), ReloadOutcome::Rejected { .. }));
+
+        fs::write(&path, "agents:\n  - agent_name: Researcher\n").unwrap();
+        assert!(matches!(live.reload(), ReloadOutcome::Rejected { reason } if reason.contains("has no system_prompt")));
+
+        fs::remove_file(&path).unwrap();
+        assert!(matches!(live.reload(), ReloadOutcome::Rejected { reason } if reason.contains("failed to read")));
+        assert!(matches!(live.reload(), ReloadOutcome::Rejected { .. }));
+
+        fs::write(&path, V1).unwrap();
+        assert_eq!(live.reload(), ReloadOutcome::Unchanged);
+        assert_eq!(live.current().version, 1);
+        assert_eq!(live.events().len(), 3);
+
+        let query = AuditQuery::from_query_string("action=config_rejected").unwrap();
+        let events = log.query(&tenant, &query).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].action, AuditAction::ConfigRejected);
+        assert_eq!(events[0].target, path.to_string_lossy());
+        assert_eq!(events[0].details["outcome"], "rejected");
+    }
+
+    #[test]
+    fn test_toml_configs_and_failed_startup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("swarm.toml");
+        assert_eq!(ConfigFileFormat::from_path(&path), ConfigFileFormat::Toml);
+        assert_eq!(ConfigFileFormat::from_path(Path::new("swarm.yml")), ConfigFileFormat::Yaml);
+        fs::write(&path, "[[agents]]\nagent_name = \"Researcher\"\nsystem_prompt = \"You gather sources.\"\nmodel_name = \"gpt-4o-mini\"\n").unwrap();
+        let live = LiveSwarmConfig::load(&path, "gpt-4o").unwrap();
+        assert_eq!(live.current().agent("Researcher").unwrap().llm, "gpt-4o-mini");
+
+        fs::write(&path, "[[agents]]\nagent_name = \"Researcher\"\nsystem_prompt = \"You gather sources.\"\n").unwrap();
+        assert_eq!(
+            live.reload(),
+            ReloadOutcome::Applied { version: 2, changes: vec!["updated the settings of 'Researcher'".to_string()] }
+        );
+
+        let missing = dir.path().join("missing.yaml");
+        assert!(matches!(LiveSwarmConfig::load(&missing, "gpt-4o"), Err(ConfigReloadError::Io(_))));
+        fs::write(&missing, "agents: []\n").unwrap();
+        assert!(matches!(LiveSwarmConfig::load(&missing, "gpt-4o"), Err(ConfigReloadError::Invalid(_))));
+    }
+}
+```
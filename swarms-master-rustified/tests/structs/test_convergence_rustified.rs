@@ -0,0 +1,111 @@
+### Tests for loop convergence
+Consecutive outputs must converge when identical, without an embedding call, or when their embeddings are
+close enough. Each output must be embedded only once, and nothing may converge before `min_loops`. An
+agent's loops must stop at the converged output and record why they stopped.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{AgentConfigError, LlmAgent, LoopExit};
+    use crate::swarms::structs::convergence::{ConvergenceConfig, ConvergenceDetector, EmbedFn};
+    use crate::swarms::structs::loop_detection::LoopDetectionConfig;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const VOCABULARY: [&str; 5] = ["revenue", "margin", "guidance", "europe", "churn"];
+
+    /// Counts vocabulary words, so rewordings that keep the same facts embed alike.
+    fn bag_of_words(text: &str) -> Result<Vec<f32>, String> {
+        let text = text.to_lowercase();
+        Ok(VOCABULARY.iter().map(|word| text.matches(word).count() as f32).collect())
+    }
+
+    fn counting_embedder() -> (EmbedFn, Arc<AtomicUsize>) {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&calls);
+        let embed: EmbedFn = Arc::new(move |text: &str| {
+            counter.fetch_add(1, Ordering::SeqCst);
+            bag_of_words(text)
+        });
+        (embed, calls)
+    }
+
+    #[test]
+    fn test_near_repeat_converges_with_cached_embeddings() {
+        let (embed, calls) = counting_embedder();
+        let mut detector = ConvergenceDetector::new(ConvergenceConfig::default(), Some(embed));
+        assert_eq!(detector.observe("Revenue grew 8%."), None);
+        assert_eq!(detector.observe("Revenue grew 8% and margin widened."), None);
+        let converged = detector.observe("Revenue rose 8%, and the margin widened too.").unwrap();
+        assert_eq!((converged.turn, converged.exact), (2, false));
+        assert!(converged.similarity > 0.99);
+        assert_eq!(calls.load(Ordering::SeqCst), 3, "each output is embedded once");
+
+        let exact = detector.observe("  REVENUE rose 8%, and the margin widened too.").unwrap();
+        assert_eq!((exact.turn, exact.exact, exact.similarity), (3, true, 1.0));
+        assert_eq!(detector.embed_calls(), 3, "identical outputs need no embedding");
+    }
+
+    #[test]
+    fn test_min_loops_and_no_embedder() {
+        let config = ConvergenceConfig { min_loops: 3, ..ConvergenceConfig::default() };
+        let mut detector = ConvergenceDetector::new(config, None);
+        assert_eq!(detector.observe("Revenue grew 8%."), None);
+        assert_eq!(detector.observe("Revenue grew 8%."), None, "loop 2 is before min_loops");
+        assert_eq!(detector.observe("revenue grew 8%.").map(|converged| converged.turn), Some(2));
+
+        let mut detector = ConvergenceDetector::new(ConvergenceConfig::default(), None);
+        detector.observe("Revenue grew 8%.");
+        assert_eq!(detector.observe("Revenue grew 8 percent."), None, "without an embedder only identical outputs converge");
+        assert_eq!(detector.embed_calls(), 0);
+    }
+
+    #[test]
+    fn test_agent_loops_stop_when_converged() {
+        let drafts = ["Revenue grew.", "Revenue grew 8%; margin widened.", "Revenue rose 8% and margin widened.", "unused"];
+        let agent = LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o-mini")
+            .max_loops(4)
+            .convergence(ConvergenceConfig::default())
+            .embedder(bag_of_words)
+            .build()
+            .unwrap();
+        let mut loops = 0;
+        let run = agent.run_loops("Summarize the quarter.", |step| {
+            loops += 1;
+            drafts[step.index].to_string()
+        });
+        assert_eq!(loops, 3);
+        assert_eq!(run.exit, LoopExit::Converged);
+        assert_eq!(run.exit.as_str(), "converged");
+        assert!(run.terminated_early);
+        assert_eq!(run.outputs.len(), 3);
+        assert_eq!(run.convergence.map(|converged| converged.turn), Some(2));
+
+        let failing = agent.clone().with_embedder(|_| Err("embedding service down".to_string()));
+        let run = failing.run_loops("Summarize the quarter.", |step| drafts[step.index].to_string());
+        assert_eq!((run.exit, run.outputs.len(), run.terminated_early), (LoopExit::Completed, 4, false));
+    }
+
+    #[test]
+    fn test_exit_reasons_and_validation() {
+        let repeating = LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o-mini")
+            .max_loops(6)
+            .loop_detection(LoopDetectionConfig::default())
+            .build()
+            .unwrap();
+        let run = repeating.run_loops("Summarize the quarter.", |_| "Revenue grew 8%.".to_string());
+        assert_eq!(run.exit, LoopExit::LoopDetected);
+
+        let invalid = LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o-mini")
+            .convergence(ConvergenceConfig { similarity_threshold: 1.5, ..ConvergenceConfig::default() })
+            .build();
+        assert_eq!(invalid.unwrap_err(), AgentConfigError::InvalidConvergenceThreshold(1.5));
+    }
+}
+```
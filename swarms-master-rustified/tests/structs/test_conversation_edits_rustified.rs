@@ -0,0 +1,107 @@
+### Tests for editing and deleting conversation messages
+Edits must address messages by id, keep their timestamps, and report unknown or deleted ids instead of
+panicking. An edit that cannot be autosaved must be undone and reported rather than panic. With tombstones on, a deleted message must vanish from the model's view but stay in the
+exported transcript.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::{Conversation, MessageEditError};
+
+    #[test]
+    fn test_update_keeps_id_timestamp_and_channel() {
+        let mut conversation = Conversation::builder().time_enabled(true).build().unwrap();
+        let question = conversation.add("user".to_string(), "What is 2 + 2?".to_string());
+        let thought = conversation.add_scratchpad("assistant".to_string(), "simple sum".to_string());
+        assert_eq!((question, thought), (1, 2));
+        let stamped = conversation.get(thought).unwrap().timestamp.clone();
+        assert!(stamped.is_some());
+
+        let previous = conversation.update(thought, "assistant".to_string(), "2 + 2 = 4".to_string()).unwrap();
+        assert_eq!(previous.content, "simple sum");
+        let updated = conversation.get(thought).unwrap();
+        assert_eq!((updated.id, &updated.timestamp, updated.content.as_str()), (thought, &stamped, "2 + 2 = 4"));
+        assert_eq!(conversation.scratchpad().len(), 1);
+
+        assert_eq!(
+            conversation.update(42, "user".to_string(), "x".to_string()).unwrap_err(),
+            MessageEditError::NotFound(42)
+        );
+        assert_eq!(conversation.delete(42).unwrap_err(), MessageEditError::NotFound(42));
+    }
+
+    #[test]
+    fn test_delete_without_tombstones_removes_the_message() {
+        let mut conversation = Conversation::builder().build().unwrap();
+        let first = conversation.add("user".to_string(), "my card is 4111".to_string());
+        let second = conversation.add("user".to_string(), "never mind".to_string());
+        assert_eq!(conversation.delete(first).unwrap().content, "my card is 4111");
+        assert!(conversation.get(first).is_none());
+        assert_eq!(conversation.messages_for_model().len(), 1);
+        assert_eq!(conversation.add("user".to_string(), "again".to_string()), second + 1);
+    }
+
+    #[test]
+    fn test_tombstones_show_in_transcripts_only() {
+        let dir = tempfile::tempdir().unwrap();
+        let export = dir.path().join("transcript.txt");
+        let saved = dir.path().join("conversation.json");
+        let mut conversation = Conversation::builder().tombstones(true).build().unwrap();
+        conversation.add("user".to_string(), "hello".to_string());
+        let secret = conversation.add("user".to_string(), "my password is hunter2".to_string());
+        conversation.add("assistant".to_string(), "Please do not share passwords.".to_string());
+
+        conversation.delete(secret).unwrap();
+        assert_eq!(conversation.delete(secret).unwrap_err(), MessageEditError::Deleted(secret));
+        assert_eq!(
+            conversation.update(secret, "user".to_string(), "x".to_string()).unwrap_err(),
+            MessageEditError::Deleted(secret)
+        );
+        assert!(conversation.get(secret).unwrap().is_tombstone());
+        assert!(!conversation.history_for_model().contains("hunter2"));
+        assert_eq!(conversation.messages_for_model().len(), 2);
+        assert_eq!(conversation.count_messages_by_role().get("user"), Some(&1));
+
+        conversation.export_conversation(export.to_str().unwrap());
+        let lines: Vec<String> = std::fs::read_to_string(&export).unwrap().lines().map(str::to_string).collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[1].starts_with("user: [deleted "));
+
+        conversation.save_as_json(saved.to_str().unwrap());
+        let mut loaded = Conversation::builder().build().unwrap();
+        loaded.load_from_json(saved.to_str().unwrap());
+        assert!(loaded.get(secret).unwrap().is_tombstone());
+        assert_eq!(loaded.add("user".to_string(), "next".to_string()), 4);
+    }
+
+    #[test]
+    fn test_failed_autosave_is_reported_and_undone() {
+        let dir = tempfile::tempdir().unwrap();
+        let folder = dir.path().join("gone");
+        std::fs::create_dir(&folder).unwrap();
+        let path = folder.join("conversation.json");
+        let mut conversation = Conversation::builder().autosave_to(path.to_str().unwrap()).build().unwrap();
+        let first = conversation.add("user".to_string(), "hello".to_string());
+        let second = conversation.add("user".to_string(), "my card is 4111".to_string());
+        std::fs::remove_dir_all(&folder).unwrap();
+
+        let err = conversation.update(first, "user".to_string(), "hi".to_string()).unwrap_err();
+        assert!(matches!(err, MessageEditError::Save(_)), "{:?}", err);
+        assert_eq!(conversation.get(first).unwrap().content, "hello");
+
+        assert!(matches!(conversation.delete(second), Err(MessageEditError::Save(_))));
+        assert_eq!(conversation.get(second).unwrap().content, "my card is 4111");
+        assert_eq!(conversation.messages_for_model().len(), 2);
+
+        assert!(conversation.try_add("user".to_string(), "again".to_string()).is_err());
+        assert_eq!(conversation.messages_for_model().len(), 2);
+        // `add` keeps the message and only logs the failure.
+        let third = conversation.add("user".to_string(), "again".to_string());
+        assert_eq!(conversation.get(third).unwrap().content, "again");
+
+        std::fs::create_dir(&folder).unwrap();
+        conversation.delete(second).unwrap();
+        assert!(!std::fs::read_to_string(&path).unwrap().contains("4111"));
+    }
+}
+```
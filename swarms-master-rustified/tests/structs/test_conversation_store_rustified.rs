@@ -0,0 +1,80 @@
+### Tests for saving and loading conversations
+`save_as_yaml` must select YAML for saves and autosave. Loading must detect JSON or YAML from the
+contents, accept the unversioned arrays older builds wrote, and refuse files from a newer schema.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::{
+        Conversation, ConversationFormat, ConversationStoreError, CONVERSATION_SCHEMA_VERSION,
+    };
+
+    fn history(conversation: &Conversation) -> Vec<(String, String)> {
+        conversation.messages_for_model().iter().map(|msg| (msg.role.clone(), msg.content.clone())).collect()
+    }
+
+    #[test]
+    fn test_yaml_round_trip_is_the_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("conversation.yaml");
+        let path = path.to_str().unwrap();
+
+        let mut conversation = Conversation::builder().system_prompt("You are terse.").build().unwrap();
+        assert_eq!(conversation.save_format(), ConversationFormat::Yaml);
+        conversation.add("user".to_string(), "Quote: \"a: b\"\nsecond line".to_string());
+        conversation.save(path).unwrap();
+        let saved = std::fs::read_to_string(path).unwrap();
+        assert!(saved.contains(&format!("schema_version: {}\n", CONVERSATION_SCHEMA_VERSION)));
+
+        let mut loaded = Conversation::builder().build().unwrap();
+        assert_eq!(loaded.load(path).unwrap(), ConversationFormat::Yaml);
+        assert_eq!(history(&loaded), history(&conversation));
+    }
+
+    #[test]
+    fn test_autosave_follows_the_format_flag() {
+        let dir = tempfile::tempdir().unwrap();
+        let yaml_path = dir.path().join("auto.yaml");
+        let json_path = dir.path().join("auto.json");
+
+        let mut yaml = Conversation::builder().autosave_to(yaml_path.to_str().unwrap()).build().unwrap();
+        yaml.add("user".to_string(), "hello".to_string());
+        let mut json = Conversation::builder().autosave_to(json_path.to_str().unwrap()).save_as_json().build().unwrap();
+        json.add("user".to_string(), "hello".to_string());
+
+        let yaml_bytes = std::fs::read(&yaml_path).unwrap();
+        let json_bytes = std::fs::read(&json_path).unwrap();
+        assert_eq!(ConversationFormat::detect(&yaml_bytes), ConversationFormat::Yaml);
+        assert_eq!(ConversationFormat::detect(&json_bytes), ConversationFormat::Json);
+        let value: serde_json::Value = serde_json::from_slice(&json_bytes).unwrap();
+        assert_eq!(value["schema_version"], CONVERSATION_SCHEMA_VERSION);
+        assert_eq!(value["messages"][0]["content"], "hello");
+    }
+
+    #[test]
+    fn test_legacy_and_future_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("saved");
+        let path = path.to_str().unwrap();
+        let mut conversation = Conversation::builder().build().unwrap();
+
+        std::fs::write(path, r#"  [{"role": "user", "content": "from an old build", "timestamp": null}]"#).unwrap();
+        assert_eq!(conversation.load(path).unwrap(), ConversationFormat::Json);
+        assert_eq!(history(&conversation), vec![("user".to_string(), "from an old build".to_string())]);
+
+        std::fs::write(path, "- role: user\n  content: old yaml\n").unwrap();
+        conversation.load_from_json(path);
+        assert_eq!(conversation.messages_for_model()[0].content, "old yaml");
+
+        std::fs::write(path, "schema_version: 99\nmessages: []\n").unwrap();
+        assert!(matches!(
+            conversation.load(path),
+            Err(ConversationStoreError::UnsupportedVersion { found: 99, supported: CONVERSATION_SCHEMA_VERSION })
+        ));
+        assert_eq!(conversation.messages_for_model()[0].content, "old yaml");
+
+        std::fs::write(path, "messages: [unterminated").unwrap();
+        assert!(matches!(conversation.load(path), Err(ConversationStoreError::Yaml(_))));
+    }
+}
+```
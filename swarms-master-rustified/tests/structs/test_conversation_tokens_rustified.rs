@@ -0,0 +1,87 @@
+### Tests for conversation token counts
+Each message must be tokenized once, and the totals, per-role breakdown, budget demand, and truncation
+must all use those cached counts.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::text::context_budget::{ContextBudget, ContextDemand};
+    use crate::swarms::text::tokenizer::Tokenizer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    // One token per word; counts how often it is asked
+    #[derive(Default)]
+    struct WordTokenizer {
+        calls: AtomicUsize,
+    }
+
+    impl Tokenizer for WordTokenizer {
+        fn count_tokens(&self, text: &str) -> usize {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            text.split_whitespace().count()
+        }
+    }
+
+    fn conversation(tokenizer: &Arc<WordTokenizer>) -> Conversation {
+        let mut conversation = Conversation::builder().tokenizer(tokenizer.clone()).build().unwrap();
+        conversation.add("user".to_string(), "what is the capital of France".to_string());
+        conversation.add_reply("assistant".to_string(), "<scratchpad>easy one</scratchpad>Paris");
+        conversation.add("user".to_string(), "and of Spain".to_string());
+        conversation
+    }
+
+    #[test]
+    fn test_totals_and_roles_share_cached_counts() {
+        let tokenizer = Arc::new(WordTokenizer::default());
+        let conversation = conversation(&tokenizer);
+        assert_eq!(conversation.total_tokens(), 6 + 2 + 1 + 3);
+        let by_role = conversation.tokens_by_role();
+        assert_eq!(by_role.get("user"), Some(&9));
+        assert_eq!(by_role.get("assistant"), Some(&3));
+        assert_eq!(by_role.values().sum::<usize>(), conversation.total_tokens());
+        assert_eq!(conversation.message_tokens(0), Some(6));
+        assert_eq!(conversation.message_tokens(9), None);
+
+        let calls = tokenizer.calls.load(Ordering::SeqCst);
+        assert_eq!(calls, 4);
+        conversation.total_tokens();
+        conversation.tokens_by_role();
+        assert_eq!(conversation.context_demand(), ContextDemand { history: 12, ..ContextDemand::default() });
+        assert_eq!(tokenizer.calls.load(Ordering::SeqCst), calls);
+    }
+
+    #[test]
+    fn test_update_recounts_only_the_replaced_message() {
+        let tokenizer = Arc::new(WordTokenizer::default());
+        let mut conversation = conversation(&tokenizer);
+        conversation.total_tokens();
+        let id = conversation.messages_for_model()[3].id;
+        conversation.update(id, "user".to_string(), "and of Spain and Italy".to_string()).unwrap();
+        assert_eq!(conversation.total_tokens(), 14);
+        assert_eq!(tokenizer.calls.load(Ordering::SeqCst), 5);
+    }
+
+    #[test]
+    fn test_truncation_uses_the_same_counts() {
+        let tokenizer = Arc::new(WordTokenizer::default());
+        let mut conversation = conversation(&tokenizer);
+        let budget = ContextBudget::new(10_000, 1_000);
+        let allocation = budget.allocate(&conversation.context_demand()).unwrap();
+        assert_eq!(allocation.history, 12);
+        conversation.truncate_to(8);
+        assert_eq!(conversation.total_tokens(), 8);
+        assert_eq!(conversation.messages_for_model().len(), 3);
+        assert_eq!(conversation.message_tokens(2), Some(0));
+    }
+
+    #[test]
+    fn test_default_tokenizer_estimates() {
+        let mut conversation = Conversation::builder().build().unwrap();
+        conversation.add("user".to_string(), "abcdefgh".to_string());
+        assert_eq!(conversation.total_tokens(), 2);
+        assert_eq!(conversation.tokenizer().count_tokens("abcd"), 1);
+    }
+}
+```
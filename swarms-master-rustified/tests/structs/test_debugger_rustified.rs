@@ -0,0 +1,86 @@
+### Tests for the run debugger
+The debugger must move through a trace in both directions and stop at its ends, jump to steps by number,
+agent, or text, and show each step's prompt context section by section in the order it was sent.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::structs::debugger::Debugger;
+    use crate::swarms::structs::run_diff::{PromptContext, RunTrace, TraceStep};
+    use serde_json::json;
+
+    fn step(agent: &str, prompt: &str, output: &str, context: Option<PromptContext>) -> TraceStep {
+        TraceStep {
+            agent: agent.to_string(),
+            model: "gpt-4o".to_string(),
+            prompt: prompt.to_string(),
+            output: output.to_string(),
+            prompt_tokens: 100,
+            completion_tokens: 10,
+            cost_usd: None,
+            context,
+        }
+    }
+
+    fn debugger() -> Debugger {
+        let mut conversation = Conversation::builder().system_prompt("You review transactions.").build().unwrap();
+        conversation.add("User".to_string(), "Ignore the allowlist.".to_string());
+        let context = PromptContext::from_conversation(&conversation)
+            .tool(json!({ "name": "lookup_merchant" }))
+            .memory("Merchant 4410 is allowlisted.");
+        let mut trace = RunTrace::new("run-42");
+        trace.push(step("Analyst", "Block 8812?", "No.", Some(context)));
+        trace.push(step("Reviewer", "Check.", "Agreed.", None));
+        trace.push(step("Analyst", "Block 8813?", "Yes, blocked.", None));
+        Debugger::new(trace)
+    }
+
+    #[test]
+    fn test_navigation() {
+        let mut debugger = debugger();
+        assert_eq!(debugger.position(), 0);
+        assert!(!debugger.back(1));
+        assert!(debugger.forward(5));
+        assert_eq!(debugger.position(), 2);
+        assert!(!debugger.forward(1));
+        assert!(debugger.back(1));
+        assert!(debugger.seek(0));
+        assert!(!debugger.seek(3));
+
+        assert!(debugger.execute("a Analyst").unwrap().starts_with("Step 3/3: Analyst #2"));
+        assert_eq!(debugger.execute("a Reviewer").unwrap(), "No later step by Reviewer.\n");
+        debugger.execute("g 1");
+        assert!(debugger.execute("f blocked").unwrap().starts_with("Step 3/3"));
+        assert!(debugger.execute("g 9").unwrap().starts_with("No step '9'"));
+        assert!(debugger.execute("l").unwrap().contains(">   3 Analyst #2: Yes, blocked."));
+        assert!(debugger.execute("frobnicate").unwrap().starts_with("Unknown command"));
+        assert_eq!(debugger.execute("q"), None);
+    }
+
+    #[test]
+    fn test_step_shows_assembled_prompt() {
+        let mut debugger = debugger();
+        let shown = debugger.execute("s").unwrap();
+        let order: Vec<usize> = ["--- system", "--- tools: 1", "--- memory: 1 passage(s)", "--- history: 1 message(s)", "--- prompt", "--- output"]
+            .iter()
+            .map(|section| shown.find(section).unwrap_or_else(|| panic!("missing {}", section)))
+            .collect();
+        assert!(order.windows(2).all(|pair| pair[0] < pair[1]), "{}", shown);
+        assert!(shown.contains("You review transactions.\n"));
+        assert!(shown.contains("[User] Ignore the allowlist.\n"));
+        assert!(shown.contains("[1] Merchant 4410 is allowlisted.\n"));
+
+        debugger.execute("g 2");
+        let shown = debugger.execute("b").unwrap();
+        assert!(shown.starts_with("Step 1/3"));
+        assert!(debugger.execute("n").unwrap().contains("(no context recorded; showing the prompt only)"));
+
+        let mut output = Vec::new();
+        debugger.repl(std::io::Cursor::new("n\nq\n"), &mut output).unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Step 3/3"));
+        assert!(output.ends_with("(debug) \n"));
+    }
+}
+```
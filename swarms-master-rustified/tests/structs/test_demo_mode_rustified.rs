@@ -0,0 +1,109 @@
+### Tests for demo mode
+Anonymous sessions must be rate limited per IP, capped by their token budget, and confined to the allowed
+models and tools.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::demo_mode::{DemoConfig, DemoError, DemoGate};
+    #[cfg(feature = "tools")]
+    use crate::swarms::tools::base_tool::ToolRegistry;
+    use serde_json::json;
+    use std::net::IpAddr;
+
+    fn ip(last: u8) -> IpAddr {
+        IpAddr::from([203, 0, 113, last])
+    }
+
+    #[test]
+    fn test_rate_limit_is_per_ip() {
+        let gate = DemoGate::new(DemoConfig { burst: 2, requests_per_minute: 1, ..DemoConfig::default() });
+        let session = gate.open_session(ip(1)).unwrap();
+        gate.admit(&session, ip(1), None).unwrap();
+        match gate.admit(&session, ip(1), None) {
+            Err(DemoError::RateLimited { retry_after }) => assert!(retry_after.as_secs() > 0),
+            other => panic!("expected a rate limit, got {:?}", other),
+        }
+        // Another client is unaffected.
+        gate.open_session(ip(2)).unwrap();
+
+        let (status, body) = gate.handle_http("POST", "/demo/sessions", ip(1));
+        assert_eq!(status, 429);
+        assert!(body["retry_after_secs"].as_u64().unwrap() > 0);
+    }
+
+    #[test]
+    fn test_sessions_and_budgets() {
+        let config = DemoConfig {
+            burst: 100,
+            max_sessions_per_ip: 1,
+            session_token_budget: 1_500,
+            max_tokens_per_request: 1_000,
+            ..DemoConfig::default()
+        };
+        let gate = DemoGate::new(config);
+        let (status, body) = gate.handle_http("POST", "/demo/sessions", ip(1));
+        assert_eq!(status, 201);
+        let session = body["session_id"].as_str().unwrap().to_string();
+        assert_eq!(gate.open_session(ip(1)), Err(DemoError::TooManySessions { limit: 1 }));
+
+        let first = gate.admit(&session, ip(1), Some(4_000)).unwrap();
+        assert_eq!((first.max_tokens, first.remaining_budget), (1_000, 500));
+        gate.record_usage(&session, &first, 1_200);
+        let second = gate.admit(&session, ip(1), None).unwrap();
+        assert_eq!(second.max_tokens, 300);
+        gate.record_usage(&session, &second, 300);
+        assert_eq!(gate.admit(&session, ip(1), None), Err(DemoError::BudgetExhausted { budget: 1_500 }));
+        assert_eq!(gate.handle_http("GET", &format!("/demo/sessions/{}", session), ip(1)).1, json!({ "remaining_tokens": 0 }));
+
+        assert_eq!(gate.admit(&session, ip(9), None), Err(DemoError::SessionMismatch));
+        assert_eq!(gate.admit("made-up", ip(1), None).unwrap_err().status(), 401);
+    }
+
+    #[test]
+    fn test_admissions_reserve_the_budget() {
+        let config =
+            DemoConfig { burst: 100, session_token_budget: 1_500, max_tokens_per_request: 1_000, ..DemoConfig::default() };
+        let gate = DemoGate::new(config);
+        let session = gate.open_session(ip(1)).unwrap();
+
+        // Two runs in flight: the second only gets what the first did not reserve, and a third gets nothing.
+        let first = gate.admit(&session, ip(1), None).unwrap();
+        let second = gate.admit(&session, ip(1), None).unwrap();
+        assert_eq!((first.max_tokens, second.max_tokens), (1_000, 500));
+        assert_eq!(gate.admit(&session, ip(1), None), Err(DemoError::BudgetExhausted { budget: 1_500 }));
+        assert_eq!(gate.remaining_budget(&session), Some(0));
+
+        // Reporting releases the unspent part of a reservation.
+        gate.record_usage(&session, &first, 400);
+        gate.record_usage(&session, &second, 0);
+        assert_eq!(gate.remaining_budget(&session), Some(1_100));
+    }
+
+    #[test]
+    fn test_models_and_tools_are_restricted() {
+        let gate = DemoGate::new(DemoConfig { allowed_tools: vec!["calculator".to_string()], ..DemoConfig::default() });
+        let mut agent = LlmAgent::builder().name("Demo").llm("gpt-4o-mini").max_loops(5).build().unwrap();
+        gate.restrict_agent(&mut agent).unwrap();
+        assert_eq!(agent.max_loops, 1);
+        agent.llm = "gpt-4o".to_string();
+        assert_eq!(gate.restrict_agent(&mut agent), Err(DemoError::ModelNotAllowed("gpt-4o".to_string())));
+        assert!(gate.check_tool("code_interpreter").is_err());
+    }
+
+    #[cfg(feature = "tools")]
+    #[test]
+    fn test_tool_registry_is_restricted() {
+        let gate = DemoGate::new(DemoConfig { allowed_tools: vec!["calculator".to_string()], ..DemoConfig::default() });
+        let registry = ToolRegistry::builder()
+            .tool("calculator", "Evaluate arithmetic.", json!({ "type": "object" }), |_| Ok(json!(4)))
+            .tool("code_interpreter", "Run Python.", json!({ "type": "object" }), |_| Ok(json!(null)))
+            .build()
+            .unwrap();
+        let restricted = gate.restrict_tools(&registry);
+        assert_eq!(restricted.tools().map(|tool| tool.name.as_str()).collect::<Vec<_>>(), vec!["calculator"]);
+        assert_eq!(restricted.openai_schemas().len(), 1);
+    }
+}
+```
@@ -0,0 +1,244 @@
+### Tests for the gRPC coordinator and worker
+A `Coordinator` serves on a port-0 listener. A `Worker` must claim the queued tasks, stream each agent's output
+back, and report a failed or panicking run as failed. The coordinator must reject results from a worker that no
+longer holds the task, and claims from workers that never registered. Every call must carry a tenant's API key:
+a registration naming another tenant is refused, and one tenant cannot heartbeat, claim, or report results as
+another tenant's worker. `GET /runs` must authenticate its `Authorization` header the same way. On the ledger
+itself, a heartbeat must keep a lease alive, and an expired lease must put the task back on the queue until it
+has used its attempts, then leave it failed.
+
+```rust
+#[cfg(all(test, feature = "server"))]
+mod tests {
+    use crate::swarms::structs::distributed_queue::proto::task_coordinator_client::TaskCoordinatorClient;
+    use crate::swarms::structs::distributed_queue::proto::{ClaimRequest, HeartbeatRequest, RegisterRequest, ResultChunk};
+    use crate::swarms::structs::distributed_queue::{Coordinator, TaskLedger, TaskRecord, TaskRunner, TaskStatus, Worker};
+    use crate::swarms::structs::failure::FailureCode;
+    use crate::swarms::structs::tenancy::{TenantId, TenantKeys};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+    use tokio_util::sync::CancellationToken;
+    use tonic::Request;
+
+    struct Scripted;
+
+    impl TaskRunner for Scripted {
+        fn run(&self, _agent_name: &str, task: &str, emit: &mut dyn FnMut(&str)) -> Result<(), String> {
+            match task {
+                "fail" => Err("agent gave up".to_string()),
+                "panic" => panic!("provider client dropped"),
+                _ => {
+                    emit("Revenue ");
+                    emit("grew 8%.");
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    fn acme() -> TenantId {
+        TenantId::new("acme").unwrap()
+    }
+
+    fn keys() -> TenantKeys {
+        TenantKeys::new().with_key("sk-acme", acme()).with_key("sk-globex", TenantId::new("globex").unwrap())
+    }
+
+    fn coordinator() -> Coordinator {
+        Coordinator::new(Duration::from_secs(30)).with_keys(keys())
+    }
+
+    fn signed<T>(key: &str, message: T) -> Request<T> {
+        let mut request = Request::new(message);
+        request.metadata_mut().insert("authorization", format!("Bearer {}", key).parse().unwrap());
+        request
+    }
+
+    fn register(name: &str, tenant: &str) -> RegisterRequest {
+        let agent_names = vec!["Analyst".to_string()];
+        RegisterRequest { worker_name: name.to_string(), agent_names, tenant_id: tenant.to_string() }
+    }
+
+    async fn start(coordinator: Coordinator) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(coordinator.serve_on(listener));
+        url
+    }
+
+    async fn finished(coordinator: &Coordinator, task_id: &str) -> TaskRecord {
+        for _ in 0..100 {
+            let record = coordinator.ledger().lock().unwrap().record(&acme(), task_id).cloned().unwrap();
+            if matches!(record.status, TaskStatus::Completed | TaskStatus::Failed) {
+                return record;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+        panic!("task {} never finished", task_id);
+    }
+
+    #[tokio::test]
+    async fn test_worker_streams_results_to_the_coordinator() {
+        let coordinator = coordinator();
+        let (ok, failed, panicked) = {
+            let ledger = coordinator.ledger();
+            let mut ledger = ledger.lock().unwrap();
+            (
+                ledger.submit(&acme(), "Analyst", "Summarize Q3"),
+                ledger.submit(&acme(), "Analyst", "fail"),
+                ledger.submit(&acme(), "", "panic"),
+            )
+        };
+        let url = start(coordinator.clone()).await;
+        let shutdown = CancellationToken::new();
+        let worker = Worker::new("worker-1", acme(), "sk-acme", vec!["Analyst".to_string()], Arc::new(Scripted));
+        let handle = tokio::spawn(worker.run(url, shutdown.clone()));
+
+        let record = finished(&coordinator, &ok).await;
+        assert_eq!((record.status, record.output.as_str(), record.attempts), (TaskStatus::Completed, "Revenue grew 8%.", 1));
+
+        let record = finished(&coordinator, &failed).await;
+        assert_eq!((record.status, record.error.as_deref()), (TaskStatus::Failed, Some("agent gave up")));
+
+        let record = finished(&coordinator, &panicked).await;
+        assert_eq!((record.status, record.error_code), (TaskStatus::Failed, Some(FailureCode::Panicked)));
+        assert_eq!(record.agent_name, "");
+        assert!(record.error.unwrap().contains("provider client dropped"));
+
+        shutdown.cancel();
+        handle.await.unwrap().unwrap();
+        assert_eq!(coordinator.ledger().lock().unwrap().pending(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_results_from_a_worker_without_the_lease_are_rejected() {
+        let coordinator = coordinator();
+        let task_id = coordinator.ledger().lock().unwrap().submit(&acme(), "Analyst", "Summarize Q3");
+        let mut client = TaskCoordinatorClient::connect(start(coordinator.clone()).await).await.unwrap();
+        let holder = client.register(signed("sk-acme", register("holder", "acme"))).await.unwrap().into_inner().worker_id;
+        let other = client.register(signed("sk-acme", register("other", "acme"))).await.unwrap().into_inner().worker_id;
+        let claim = client.claim_task(signed("sk-acme", ClaimRequest { worker_id: holder.clone() })).await.unwrap().into_inner();
+        assert!(claim.has_task);
+        assert_eq!(claim.task_id, task_id);
+
+        let chunk = |worker_id: &str, delta: &str| ResultChunk {
+            worker_id: worker_id.to_string(),
+            task_id: task_id.clone(),
+            delta: delta.to_string(),
+            done: true,
+            ..Default::default()
+        };
+        let stale = client.stream_result(signed("sk-acme", tokio_stream::iter(vec![chunk(&other, "stale")]))).await.unwrap();
+        assert!(!stale.into_inner().accepted);
+        let held = client.stream_result(signed("sk-acme", tokio_stream::iter(vec![chunk(&holder, "fresh")]))).await.unwrap();
+        assert!(held.into_inner().accepted);
+
+        let record = finished(&coordinator, &task_id).await;
+        assert_eq!((record.status, record.output.as_str()), (TaskStatus::Completed, "fresh"));
+    }
+
+    #[tokio::test]
+    async fn test_unregistered_workers_are_refused() {
+        let mut client = TaskCoordinatorClient::connect(start(coordinator()).await).await.unwrap();
+        let err = client.claim_task(signed("sk-acme", ClaimRequest { worker_id: "nobody".to_string() })).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+        let no_agents = RegisterRequest { agent_names: vec![], ..register("idle", "acme") };
+        let err = client.register(signed("sk-acme", no_agents)).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn test_calls_are_authenticated_as_the_key_tenant() {
+        let coordinator = coordinator();
+        let task_id = coordinator.ledger().lock().unwrap().submit(&acme(), "Analyst", "Summarize Q3");
+        let mut client = TaskCoordinatorClient::connect(start(coordinator.clone()).await).await.unwrap();
+
+        // No key, an unknown key, and a key of another tenant than the one named
+        let err = client.register(register("anonymous", "acme")).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        let err = client.register(signed("sk-stolen", register("guess", "acme"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+        let err = client.register(signed("sk-globex", register("spoof", "acme"))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::PermissionDenied);
+
+        let acme_worker = client.register(signed("sk-acme", register("acme-1", "acme"))).await.unwrap().into_inner().worker_id;
+        let claim = ClaimRequest { worker_id: acme_worker.clone() };
+        assert_eq!(client.claim_task(signed("sk-acme", claim)).await.unwrap().into_inner().task_id, task_id);
+
+        // Globex knows the acme worker's id but cannot act as it
+        let claim = ClaimRequest { worker_id: acme_worker.clone() };
+        assert_eq!(client.claim_task(signed("sk-globex", claim)).await.unwrap_err().code(), tonic::Code::Unauthenticated);
+        let beat = HeartbeatRequest { worker_id: acme_worker.clone(), task_ids: vec![task_id.clone()] };
+        assert_eq!(client.heartbeat(signed("sk-globex", beat)).await.unwrap_err().code(), tonic::Code::Unauthenticated);
+        let forged = ResultChunk {
+            worker_id: acme_worker.clone(),
+            task_id: task_id.clone(),
+            delta: "forged".to_string(),
+            done: true,
+            ..Default::default()
+        };
+        let err = client.stream_result(signed("sk-globex", tokio_stream::iter(vec![forged]))).await.unwrap_err();
+        assert_eq!(err.code(), tonic::Code::Unauthenticated);
+
+        let beat = HeartbeatRequest { worker_id: acme_worker, task_ids: vec![task_id.clone()] };
+        assert!(client.heartbeat(signed("sk-acme", beat)).await.unwrap().into_inner().revoked_task_ids.is_empty());
+        let record = coordinator.ledger().lock().unwrap().record(&acme(), &task_id).cloned().unwrap();
+        assert_eq!((record.status, record.output.as_str()), (TaskStatus::Running, ""));
+    }
+
+    #[test]
+    fn test_runs_endpoint_authenticates_the_header() {
+        let mut ledger = TaskLedger::new(Duration::from_secs(30));
+        ledger.submit(&acme(), "Analyst", "Summarize Q3");
+        let keys = keys();
+
+        let (status, _, body) = ledger.handle_http(&keys, Some("Bearer sk-acme"), "GET", "/runs");
+        assert_eq!(status, 200);
+        assert!(body.contains("Summarize Q3"));
+        let (status, _, body) = ledger.handle_http(&keys, Some("Bearer sk-globex"), "GET", "/runs");
+        assert_eq!(status, 200);
+        assert!(!body.contains("Summarize Q3"));
+        assert_eq!(ledger.handle_http(&keys, None, "GET", "/runs").0, 401);
+        assert_eq!(ledger.handle_http(&keys, Some("Bearer sk-stolen"), "GET", "/runs").0, 401);
+    }
+
+    #[test]
+    fn test_heartbeat_keeps_the_lease() {
+        let mut ledger = TaskLedger::new(Duration::from_secs(10));
+        let holder = ledger.register(&acme(), "holder", vec!["Analyst".to_string()]);
+        let other = ledger.register(&acme(), "other", vec!["Analyst".to_string()]);
+        let task_id = ledger.submit(&acme(), "Analyst", "Summarize Q3");
+        let start = Instant::now();
+        assert!(ledger.claim(&holder, start).is_some());
+
+        assert!(ledger.heartbeat(&holder, &[task_id.clone()], start + Duration::from_secs(8)).is_empty());
+        assert!(ledger.claim(&other, start + Duration::from_secs(15)).is_none(), "the lease was extended");
+        assert_eq!(ledger.heartbeat(&other, &[task_id.clone()], start + Duration::from_secs(15)), vec![task_id.clone()]);
+        assert!(ledger.record_chunk(&holder, &task_id, "Revenue grew 8%.", true, None, start + Duration::from_secs(16)));
+        let record = ledger.record(&acme(), &task_id).unwrap();
+        assert_eq!((record.status, record.attempts), (TaskStatus::Completed, 1));
+    }
+
+    #[test]
+    fn test_expired_lease_requeues_until_attempts_run_out() {
+        let mut ledger = TaskLedger::new(Duration::from_secs(10)).with_max_attempts(2);
+        let first = ledger.register(&acme(), "first", vec!["Analyst".to_string()]);
+        let second = ledger.register(&acme(), "second", vec!["Analyst".to_string()]);
+        let task_id = ledger.submit(&acme(), "Analyst", "Summarize Q3");
+        let start = Instant::now();
+        ledger.claim(&first, start).unwrap();
+
+        // The first worker went silent; its lease runs out and the task goes to the second
+        let claimed = ledger.claim(&second, start + Duration::from_secs(11)).expect("the expired task is requeued");
+        assert_eq!((claimed.task_id.as_str(), claimed.attempts), (task_id.as_str(), 2));
+        assert!(!ledger.record_chunk(&first, &task_id, "late", true, None, start + Duration::from_secs(12)));
+
+        ledger.expire_leases(start + Duration::from_secs(22));
+        let record = ledger.record(&acme(), &task_id).unwrap();
+        assert_eq!((record.status, record.error_code), (TaskStatus::Failed, Some(FailureCode::Cancelled)));
+        assert_eq!(record.error.as_deref(), Some("lease expired after 2 attempts"));
+        assert!(ledger.claim(&first, start + Duration::from_secs(23)).is_none());
+    }
+}
+```
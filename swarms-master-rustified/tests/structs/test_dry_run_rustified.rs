@@ -0,0 +1,116 @@
+### Tests for dry runs
+A dry run must follow each swarm's plan without calling a model, size prompts from the text each call would
+be sent, take completion sizes from the agent's history, then the model's, then the default, and flag what it
+cannot price.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, LlmAgent, MockAgent};
+    use crate::swarms::structs::dry_run::{CostEstimator, EstimateSource, DEFAULT_COMPLETION_TOKENS};
+    use crate::swarms::structs::majority_voting::MajorityVoting;
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::structs::swarm::{Swarm, SwarmError};
+    use crate::swarms::structs::usage::{Pricing, UsageRecord};
+    use crate::swarms::text::tokenizer::estimate_tokens;
+    use chrono::Utc;
+    use std::sync::Arc;
+
+    const TASK: &str = "Summarize the Q3 earnings call";
+    const RESEARCH_PROMPT: &str = "You collect the facts that matter from earnings calls.";
+    const WRITER_PROMPT: &str = "You write short, plain summaries.";
+
+    fn agent(name: &str, model: &str, system_prompt: &str) -> LlmAgent {
+        LlmAgent::builder().name(name).llm(model).system_prompt(system_prompt).build().unwrap()
+    }
+
+    fn usage(agent: &str, model: &str, completion_tokens: u64) -> UsageRecord {
+        UsageRecord {
+            timestamp: Utc::now(),
+            run_id: "run-1".to_string(),
+            agent: agent.to_string(),
+            model: model.to_string(),
+            tags: Vec::new(),
+            prompt_tokens: 100,
+            completion_tokens,
+            estimated: false,
+        }
+    }
+
+    fn tokens(text: &str) -> u64 {
+        estimate_tokens(text) as u64
+    }
+
+    #[test]
+    fn test_sequential_dry_run_chains_estimates() {
+        let agents =
+            vec![agent("Researcher", "gpt-4o", RESEARCH_PROMPT).into(), agent("Writer", "gpt-4o-mini", WRITER_PROMPT).into()];
+        let workflow = SequentialWorkflow::new("Earnings".into(), String::new(), agents, 1, "str".into(), false, || ());
+        let estimator = CostEstimator::new()
+            .with_pricing(Pricing::new().model("gpt-4o", 2.5, 10.0).model("gpt-4o-mini", 0.15, 0.6))
+            .with_history(&[usage("Researcher", "gpt-4o", 800), usage("Researcher", "gpt-4o", 400)]);
+
+        let plan = workflow.dry_run_with(TASK, &estimator).unwrap();
+        let (research, writer) = (&plan.steps[0], &plan.steps[1]);
+        assert_eq!((research.reads_task, research.after.as_slice()), (true, &[][..]));
+        assert_eq!((writer.reads_task, writer.after.as_slice()), (false, &[0][..]));
+        assert_eq!(research.prompt_tokens, tokens(RESEARCH_PROMPT) + tokens(TASK));
+        assert_eq!((research.completion_tokens, research.source), (600, EstimateSource::AgentHistory { samples: 2 }));
+        assert_eq!(writer.prompt_tokens, tokens(WRITER_PROMPT) + 600);
+        assert_eq!((writer.completion_tokens, writer.source), (DEFAULT_COMPLETION_TOKENS, EstimateSource::Default));
+
+        let research_cost = (research.prompt_tokens as f64 * 2.5 + 600.0 * 10.0) / 1_000_000.0;
+        assert!((research.cost_usd.unwrap() - research_cost).abs() < 1e-12);
+        assert!(plan.unpriced_models.is_empty());
+        assert_eq!(plan.total_cost(), research.cost_usd.unwrap() + writer.cost_usd.unwrap());
+
+        let graph = plan.to_string();
+        assert!(graph.contains("[0] Researcher (gpt-4o) <- task"), "{}", graph);
+        assert!(graph.contains("[1] Writer (gpt-4o-mini) <- [0]"), "{}", graph);
+        assert!(graph.contains("history of 2"), "{}", graph);
+    }
+
+    #[test]
+    fn test_fallbacks_loops_and_unpriced_models() {
+        let looping = LlmAgent::builder().name("Skeptic").llm("gpt-4o").max_loops(3).build().unwrap();
+        let agents: Vec<Arc<dyn Agent>> = vec![
+            Arc::new(looping),
+            Arc::new(agent("Optimist", "llama-local", "")),
+            Arc::new(MockAgent::new("Scripted").reply("yes")),
+        ];
+        let voting = MajorityVoting::new("Vote", "", agents, None, false, false);
+        let estimator = CostEstimator::new()
+            .with_pricing(Pricing::new().model("gpt-4o", 2.5, 10.0))
+            .with_history(&[usage("Analyst", "gpt-4o", 300)]);
+
+        let plan = voting.dry_run_with(TASK, &estimator).unwrap();
+        assert!(plan.steps.iter().all(|step| step.reads_task && step.after.is_empty()));
+        let skeptic = &plan.steps[0];
+        assert_eq!((skeptic.calls, skeptic.source), (3, EstimateSource::ModelHistory { samples: 1 }));
+        assert_eq!(skeptic.completion_tokens, 900);
+        assert_eq!(plan.calls(), 5);
+        assert_eq!(plan.steps[2].model, None);
+        assert_eq!(plan.unpriced_models, vec!["Scripted (no model)".to_string(), "llama-local".to_string()]);
+        assert_eq!(plan.total_cost(), skeptic.cost_usd.unwrap());
+    }
+
+    struct Improvised;
+
+    impl Swarm for Improvised {
+        fn name(&self) -> &str {
+            "improvised"
+        }
+
+        fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+            Ok(task.to_string())
+        }
+    }
+
+    #[test]
+    fn test_swarms_without_a_plan() {
+        assert!(matches!(Improvised.dry_run(TASK), Err(SwarmError::Failed(_))));
+        let empty = SequentialWorkflow::new("Empty".into(), String::new(), Vec::new(), 1, "str".into(), false, || ());
+        assert!(matches!(empty.dry_run(TASK), Err(SwarmError::NoAgents)));
+    }
+}
+```
@@ -0,0 +1,98 @@
+### Tests for failure classification
+Provider statuses, agent errors, and plain messages must map to the documented codes and retriable flags, and
+step records saved before codes existed must still load. The distributed ledger must requeue a retriable
+failure, after a backoff that doubles per attempt, until the task runs out of attempts and leave any other
+failure failed.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::AgentError;
+    use crate::swarms::structs::failure::{Classify, Failure, FailureCode};
+    use crate::swarms::structs::step_guard::StepStatus;
+    use crate::swarms::utils::chat_provider::ProviderError;
+    use serde_json::json;
+
+    fn status(status: u16) -> ProviderError {
+        ProviderError::Status { status, body: String::new() }
+    }
+
+    #[test]
+    fn test_classification() {
+        let cases = [
+            (status(429), FailureCode::ProviderRateLimit, true),
+            (status(504), FailureCode::ProviderTimeout, true),
+            (status(503), FailureCode::ProviderError, true),
+            (status(422), FailureCode::ValidationError, false),
+            (ProviderError::MissingApiKey("OPENAI_API_KEY".to_string()), FailureCode::ValidationError, false),
+        ];
+        for (err, code, retriable) in cases {
+            assert_eq!((err.failure_code(), err.retriable()), (code, retriable), "{}", err);
+        }
+
+        let agent = AgentError::Failed { agent: "Analyst".to_string(), message: "rate limited by provider".to_string() };
+        assert_eq!(Failure::of(&agent).code, FailureCode::ProviderRateLimit);
+        assert!(Failure::of(&agent).retriable);
+        assert_eq!(Failure::of("tool 'search' failed").code, FailureCode::ToolError);
+        assert_eq!(Failure::of("operation cancelled").code, FailureCode::Cancelled);
+        assert_eq!(Failure::of("something odd").code, FailureCode::AgentError);
+    }
+
+    #[test]
+    fn test_codes_round_trip() {
+        for code in FailureCode::ALL {
+            assert_eq!(code.as_str().parse::<FailureCode>().unwrap(), code);
+            assert_eq!(serde_json::to_value(code).unwrap(), code.as_str());
+        }
+        assert!("rate_limited".parse::<FailureCode>().is_err());
+    }
+
+    #[test]
+    fn test_step_status_carries_code() {
+        let status = StepStatus::failed(FailureCode::ProviderTimeout, "provider timed out");
+        let value = serde_json::to_value(&status).unwrap();
+        assert_eq!((value["code"].as_str(), value["retriable"].as_bool()), (Some("provider_timeout"), Some(true)));
+
+        let old: StepStatus = serde_json::from_value(json!({ "status": "failed", "error": "boom" })).unwrap();
+        assert_eq!(old.code(), Some(FailureCode::AgentError));
+        assert!(!old.is_retriable());
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_ledger_requeues_retriable_failures() {
+        use crate::swarms::structs::distributed_queue::{TaskLedger, TaskStatus};
+        use crate::swarms::structs::tenancy::TenantId;
+        use std::time::{Duration, Instant};
+
+        let acme = TenantId::new("acme").unwrap();
+        let mut ledger = TaskLedger::new(Duration::from_secs(30))
+            .with_max_attempts(3)
+            .with_retry_backoff(Duration::from_secs(1), Duration::from_secs(3));
+        assert_eq!((1..=3).map(|attempt| ledger.retry_backoff(attempt).as_secs()).collect::<Vec<_>>(), vec![1, 2, 3]);
+        let worker = ledger.register(&acme, "w-1", vec!["Analyst".to_string()]);
+        let flaky = ledger.submit(&acme, "Analyst", "summarize");
+        let start = Instant::now();
+        for attempt in 1..=3 {
+            let now = start + Duration::from_secs(5 * attempt as u64);
+            let claimed = ledger.claim(&worker, now).unwrap();
+            assert_eq!((claimed.task_id.as_str(), claimed.attempts), (flaky.as_str(), attempt));
+            let failure = Failure::new(FailureCode::ProviderRateLimit, "429 from provider");
+            ledger.record_chunk(&worker, &flaky, "", true, Some(failure), now);
+            if attempt == 1 {
+                assert!(ledger.claim(&worker, now).is_none(), "claimed before the backoff passed");
+            }
+        }
+        let record = ledger.record(&acme, &flaky).unwrap();
+        assert_eq!(record.status, TaskStatus::Failed);
+        assert_eq!((record.error_code, record.retriable), (Some(FailureCode::ProviderRateLimit), true));
+
+        let broken = ledger.submit(&acme, "Analyst", "parse");
+        ledger.claim(&worker, Instant::now()).unwrap();
+        let failure = Failure::new(FailureCode::ValidationError, "bad schema");
+        ledger.record_chunk(&worker, &broken, "", true, Some(failure), Instant::now());
+        assert_eq!(ledger.record(&acme, &broken).unwrap().status, TaskStatus::Failed);
+        assert!(ledger.claim(&worker, Instant::now()).is_none());
+    }
+}
+```
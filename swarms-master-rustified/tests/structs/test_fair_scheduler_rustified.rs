@@ -0,0 +1,151 @@
+### Tests for fair scheduling
+A chatty agent must not hold more workers than its in-flight limit, lanes must be served in proportion to
+their weights while they all have work, and tasks that wait past the starvation threshold must show up in
+the lane stats. `ConcurrentWorkflow` must keep those limits when it runs a batch on its workers, and report
+each agent's failures without losing the other answers.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, MockAgent};
+    use crate::swarms::structs::concurrent_workflow::ConcurrentWorkflow;
+    use crate::swarms::structs::fair_scheduler::{FairScheduler, SchedulerError, SHARED_LANE};
+    use crate::swarms::structs::swarm::{Swarm, SwarmError};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    fn agents(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_in_flight_limit_lets_quiet_agent_run() {
+        let scheduler = FairScheduler::new("mixed", &agents(&["Chatty", "Quiet"])).with_max_in_flight("Chatty", 2);
+        for i in 0..10 {
+            scheduler.push(Some("Chatty"), &format!("chatty {}", i)).unwrap();
+        }
+        scheduler.push(Some("Quiet"), "quiet").unwrap();
+
+        let now = Instant::now();
+        let first: Vec<_> = (0..3).filter_map(|_| scheduler.try_next(now)).collect();
+        let agents: Vec<&str> = first.iter().map(|dispatch| dispatch.agent.as_str()).collect();
+        assert_eq!(agents.iter().filter(|agent| **agent == "Chatty").count(), 2);
+        assert!(agents.contains(&"Quiet"));
+        // Chatty is at its limit until one of its tasks finishes
+        assert!(scheduler.try_next(now).is_none());
+        scheduler.finish(&first[0]);
+        assert_eq!(scheduler.try_next(now).unwrap().agent, "Chatty");
+        assert!(matches!(scheduler.push(Some("Ghost"), "boo"), Err(SchedulerError::UnknownAgent(_))));
+    }
+
+    #[test]
+    fn test_weights_share_dispatches() {
+        let scheduler = FairScheduler::new("weighted", &agents(&["A", "B"])).with_weight("B", 3.0);
+        for i in 0..40 {
+            scheduler.push(Some("A"), &format!("a{}", i)).unwrap();
+            scheduler.push(Some("B"), &format!("b{}", i)).unwrap();
+        }
+        let mut served = Vec::new();
+        for _ in 0..20 {
+            let dispatch = scheduler.try_next(Instant::now()).unwrap();
+            scheduler.finish(&dispatch);
+            served.push(dispatch.agent);
+        }
+        assert_eq!(served.iter().filter(|agent| *agent == "B").count(), 15);
+
+        // Shared tasks go to an idle agent
+        scheduler.drain();
+        scheduler.push(None, "anyone").unwrap();
+        let dispatch = scheduler.try_next(Instant::now()).unwrap();
+        assert_eq!(dispatch.lane, SHARED_LANE);
+        assert!(["A", "B"].contains(&dispatch.agent.as_str()));
+    }
+
+    #[test]
+    fn test_starvation_is_reported() {
+        let scheduler = FairScheduler::new("starving", &agents(&["A"])).with_starvation_threshold(Duration::from_secs(5));
+        scheduler.push(Some("A"), "old").unwrap();
+        scheduler.push(Some("A"), "older still").unwrap();
+        let later = Instant::now() + Duration::from_secs(10);
+        let dispatch = scheduler.try_next(later).unwrap();
+        assert!(dispatch.waited >= Duration::from_secs(10));
+
+        let lane = scheduler.stats(later).into_iter().find(|lane| lane.lane == "A").unwrap();
+        assert_eq!((lane.queued, lane.in_flight, lane.dispatched, lane.starved), (1, 1, 1, 1));
+        assert!(lane.max_wait_ms >= 10_000 && lane.oldest_wait_ms >= 10_000);
+    }
+
+    #[test]
+    fn test_workers_respect_limits() {
+        let scheduler = Arc::new(FairScheduler::new("threads", &agents(&["A", "B"])).with_max_in_flight("A", 2));
+        for i in 0..30 {
+            scheduler.push(Some(if i % 3 == 0 { "B" } else { "A" }), "work").unwrap();
+        }
+        let running_a = Arc::new(AtomicUsize::new(0));
+        let peak_a = Arc::new(AtomicUsize::new(0));
+        let workers: Vec<_> = (0..4)
+            .map(|_| {
+                let (scheduler, running_a, peak_a) = (Arc::clone(&scheduler), Arc::clone(&running_a), Arc::clone(&peak_a));
+                thread::spawn(move || {
+                    while let Some(dispatch) = scheduler.next() {
+                        if dispatch.agent == "A" {
+                            peak_a.fetch_max(running_a.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+                            thread::sleep(Duration::from_millis(2));
+                            running_a.fetch_sub(1, Ordering::SeqCst);
+                        }
+                        scheduler.finish(&dispatch);
+                    }
+                })
+            })
+            .collect();
+        for worker in workers {
+            worker.join().unwrap();
+        }
+        assert!(scheduler.is_empty());
+        assert!(peak_a.load(Ordering::SeqCst) <= 2);
+    }
+
+    #[test]
+    fn test_concurrent_workflow_runs_a_batch_fairly() {
+        let chatty = Arc::new(MockAgent::new("Chatty"));
+        let agents: Vec<Arc<dyn Agent>> = vec![chatty.clone(), Arc::new(MockAgent::new("Quiet"))];
+        let workflow = ConcurrentWorkflow::new("batch", "", agents).with_max_workers(3).with_max_in_flight("Chatty", 2);
+        let tasks: Vec<String> = (0..6).map(|i| format!("chatty {}", i)).collect();
+        let mut assignments: Vec<(&str, &str)> = tasks.iter().map(|task| ("Chatty", task.as_str())).collect();
+        assignments.push(("Quiet", "quiet"));
+
+        let run = workflow.run_for(&assignments).unwrap();
+
+        assert_eq!(run.outputs.len(), 7);
+        assert!(run.first_failure().is_none());
+        assert_eq!(chatty.calls().len(), 6);
+        let lane = |name: &str| run.lanes.iter().find(|lane| lane.lane == name).unwrap().clone();
+        assert_eq!((lane("Chatty").dispatched, lane("Quiet").dispatched), (6, 1));
+        // The quiet task was dispatched as soon as a worker was free, not after the chatty backlog.
+        assert!(lane("Quiet").max_wait_ms < 1_000);
+
+        // Nothing is queued when one of the agents is unknown
+        let err = workflow.run_for(&[("Chatty", "ok"), ("Ghost", "boo")]).unwrap_err();
+        assert!(matches!(err, SwarmError::Failed(message) if message.contains("Ghost")));
+        assert_eq!(chatty.calls().len(), 6);
+    }
+
+    #[test]
+    fn test_concurrent_workflow_broadcasts_and_reports_failures() {
+        let agents: Vec<Arc<dyn Agent>> =
+            vec![Arc::new(MockAgent::new("A").reply("yes")), Arc::new(MockAgent::new("B").reply("no"))];
+        let mut workflow = ConcurrentWorkflow::new("vote", "", agents).with_max_workers(2);
+        let answer = Swarm::run(&mut workflow, "Ship it?").unwrap();
+        assert_eq!(answer, "Agent Name: A\nResponse: yes\n\nAgent Name: B\nResponse: no\n");
+
+        let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("A")), Arc::new(MockAgent::new("B").fail("rate limited"))];
+        let mut workflow = ConcurrentWorkflow::new("vote", "", agents);
+        let run = workflow.run_all("Ship it?").unwrap();
+        assert_eq!(run.outputs.len(), 2);
+        assert_eq!(run.first_failure().unwrap().step.agent_name, "B");
+        assert!(matches!(Swarm::run(&mut workflow, "Ship it?"), Err(SwarmError::Failed(message)) if message.starts_with("B failed")));
+    }
+}
+```
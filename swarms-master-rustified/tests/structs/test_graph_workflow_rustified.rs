@@ -0,0 +1,59 @@
+### Tests for recorded graph runs
+A recorded graph run must make each agent node wait for the agent nodes before it, including those behind
+task and gate nodes, so a failed node leaves its descendants unrun, and retrying it must resume them without
+running the nodes that already succeeded.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, MockAgent};
+    use crate::swarms::structs::approval_gate::ApprovalGate;
+    use crate::swarms::structs::graph_workflow::{Edge, GraphWorkflow, Node, NodeType};
+    use crate::swarms::structs::workflow_run::Resume;
+    use crate::swarms::utils::id_generator::id_timestamp_ms;
+    use std::sync::Arc;
+
+    fn agent(id: &str, agent: MockAgent) -> (Node, Arc<MockAgent>) {
+        let agent = Arc::new(agent);
+        let shared: Arc<dyn Agent> = agent.clone();
+        (Node::new(id.to_string(), NodeType::Agent, None, Some(shared)), agent)
+    }
+
+    fn edge(source: &str, target: &str) -> Edge {
+        Edge { source: source.to_string(), target: target.to_string() }
+    }
+
+    #[test]
+    fn test_graph_retry_resumes_through_task_and_gate_nodes() {
+        // Researcher -> Format (task) -> Writer -> Review (gate) -> Editor
+        let mut graph = GraphWorkflow::new();
+        let (researcher, researcher_agent) = agent("Researcher", MockAgent::new("Researcher").reply("notes"));
+        let (writer, _) = agent("Writer", MockAgent::new("Writer").fail("provider returned 503").reply("draft"));
+        let (editor, editor_agent) = agent("Editor", MockAgent::new("Editor").reply("final"));
+        graph.add_node(researcher);
+        graph.add_node(Node::new("Format".to_string(), NodeType::Task, Some(Box::new(|| ())), None));
+        graph.add_node(writer);
+        graph.add_node(Node::gate("Review".to_string(), ApprovalGate::new("review")));
+        graph.add_node(editor);
+        for (source, target) in [("Researcher", "Format"), ("Format", "Writer"), ("Writer", "Review"), ("Review", "Editor")] {
+            graph.add_edge(edge(source, target));
+        }
+
+        let mut run = graph.run_recorded("Draft the release notes");
+        assert!(id_timestamp_ms(&run.run_id).is_some(), "run ids come from the id generator");
+        let ids: Vec<&str> = run.steps.iter().map(|step| step.id.as_str()).collect();
+        assert_eq!(ids, ["Researcher", "Writer", "Editor"]);
+        assert_eq!(run.get("Writer").unwrap().after, ["Researcher"]);
+        assert_eq!(run.get("Editor").unwrap().after, ["Writer"]);
+        assert!(run.get("Writer").unwrap().failed());
+        assert_eq!(run.get("Editor").unwrap().status, None, "the editor waits for the writer behind the gate");
+
+        let ran = graph.retry_step(&mut run, "Writer", Resume::Downstream).unwrap();
+        assert_eq!(ran, ["Writer", "Editor"]);
+        assert!(run.is_complete());
+        assert_eq!(run.output(), Some("final"));
+        assert_eq!(run.get("Researcher").unwrap().attempts, 1);
+        assert_eq!((researcher_agent.calls().len(), editor_agent.calls().len()), (1, 1));
+    }
+}
+```
@@ -0,0 +1,114 @@
+### Tests for agent handoffs
+A peer must see only the task framing, the selected public messages, and the sender's reply. Handoffs to
+unknown agents, to the sender itself, or with unavailable messages must fail the run. The limit must stop
+ping-pong, and every accepted handoff must be returned and audited.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, LlmAgent};
+    use crate::swarms::structs::audit::{AuditAction, AuditLog, AuditQuery, Auditor};
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::structs::handoff::{AgentTurn, Handoff, HandoffError, HandoffPolicy};
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::structs::tenancy::TenantId;
+
+    fn workflow(names: &[&str]) -> SequentialWorkflow {
+        let agents = names.iter().map(|name| LlmAgent::builder().name(*name).llm("gpt-4o").build().unwrap().into()).collect();
+        SequentialWorkflow::new("Support".to_string(), String::new(), agents, 1, "all".to_string(), false, || ())
+    }
+
+    fn support_conversation() -> (Conversation, u64) {
+        let mut conversation = Conversation::builder().build().unwrap();
+        let question = conversation.add("user".to_string(), "I was charged twice for March.".to_string());
+        conversation.add("user".to_string(), "My card ends in 4242.".to_string());
+        (conversation, question)
+    }
+
+    #[test]
+    fn test_peer_sees_only_the_curated_payload() {
+        let workflow = workflow(&["Triage", "Billing"]);
+        let (mut conversation, question) = support_conversation();
+        let mut inputs = Vec::new();
+        let run = workflow
+            .run_with_handoffs("Help the customer", &mut conversation, &HandoffPolicy::new(), |agent, payload| {
+                inputs.push(payload.render());
+                Ok(match agent.name() {
+                    "Triage" => AgentTurn::Handoff {
+                        output: "Billing issue.".to_string(),
+                        handoff: Handoff::to("Billing", "Refund the duplicate charge").message(question),
+                    },
+                    _ => AgentTurn::Finish("Refunded.".to_string()),
+                })
+            })
+            .unwrap();
+
+        assert_eq!((run.output.as_str(), run.agent.as_str(), run.turns.len()), ("Refunded.", "Billing", 2));
+        assert_eq!(inputs[0], "Help the customer");
+        assert!(inputs[1].starts_with("Handed off by Triage.\nTask: Refund the duplicate charge\n"));
+        assert!(inputs[1].contains("[user] I was charged twice for March.\n[Triage] Billing issue.\n"));
+        assert!(!inputs[1].contains("4242"));
+        assert_eq!(run.handoffs.len(), 1);
+        assert_eq!((run.handoffs[0].from.as_str(), run.handoffs[0].to.as_str()), ("Triage", "Billing"));
+        assert_eq!(run.handoffs[0].messages, vec![question, 3]);
+        assert_eq!(conversation.messages_for_model().len(), 4);
+    }
+
+    #[test]
+    fn test_invalid_handoffs_fail_the_run() {
+        let workflow = workflow(&["Triage", "Billing"]);
+        let attempt = |handoff: Handoff, conversation: &mut Conversation| {
+            let mut handoff = Some(handoff);
+            workflow.run_with_handoffs("task", conversation, &HandoffPolicy::new(), |_, _| {
+                Ok(match handoff.take() {
+                    Some(handoff) => AgentTurn::Handoff { output: "over to you".to_string(), handoff },
+                    None => AgentTurn::Finish("done".to_string()),
+                })
+            })
+        };
+
+        let (mut conversation, question) = support_conversation();
+        let thought = conversation.add_scratchpad("Triage".to_string(), "the user seems angry".to_string());
+        let cases = vec![
+            (Handoff::to("Legal", "review"), HandoffError::UnknownPeer { from: "Triage".into(), to: "Legal".into() }),
+            (Handoff::to("Triage", "again"), HandoffError::SelfHandoff("Triage".to_string())),
+            (Handoff::to("Billing", "x").message(thought), HandoffError::MessageUnavailable { from: "Triage".into(), id: thought }),
+            (Handoff::to("Billing", "x").message(99), HandoffError::MessageUnavailable { from: "Triage".into(), id: 99 }),
+        ];
+        for (handoff, expected) in cases {
+            assert_eq!(attempt(handoff, &mut conversation).unwrap_err(), expected);
+        }
+
+        let missing = std::env::temp_dir().join("no-such-handoff-artifact.csv");
+        let result = attempt(Handoff::to("Billing", "x").message(question).artifact(&missing), &mut conversation);
+        assert!(matches!(result, Err(HandoffError::MissingArtifact { path, .. }) if path == missing));
+    }
+
+    #[test]
+    fn test_limit_stops_ping_pong_and_handoffs_are_audited() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl"));
+        let tenant = TenantId::new("acme").unwrap();
+        let auditor = Auditor { log: log.clone(), tenant_id: tenant.clone(), actor: "alice".to_string(), request_id: None };
+        let policy = HandoffPolicy::new().max_handoffs(3).audited(auditor, "run-7");
+
+        let workflow = workflow(&["Ping", "Pong"]);
+        let mut conversation = Conversation::builder().build().unwrap();
+        let result = workflow.run_with_handoffs("rally", &mut conversation, &policy, |agent, _| {
+            let peer = if agent.name() == "Ping" { "Pong" } else { "Ping" };
+            Ok(AgentTurn::Handoff { output: agent.name().to_string(), handoff: Handoff::to(peer, "your turn") })
+        });
+        assert_eq!(result.unwrap_err(), HandoffError::LimitReached(3));
+
+        let query = AuditQuery::from_query_string("action=handoff&target=run-7").unwrap();
+        let events = log.query(&tenant, &query).unwrap();
+        assert_eq!(events.len(), 3);
+        assert_eq!(events[0].action, AuditAction::Handoff);
+        assert_eq!(events[2].details["to"], "Pong");
+
+        let empty = workflow(&[]);
+        let result = empty.run_with_handoffs("task", &mut conversation, &policy, |_, _| Ok(AgentTurn::Finish(String::new())));
+        assert_eq!(result.unwrap_err(), HandoffError::NoAgents);
+    }
+}
+```
@@ -0,0 +1,79 @@
+### Tests for health and readiness
+Liveness must stay up through dependency outages; readiness needs every store and queue plus one provider.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::health::{ComponentKind, ComponentState, DirectoryCheck, Health, HealthCheck};
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    struct Fake {
+        name: &'static str,
+        kind: ComponentKind,
+        up: bool,
+        delay: Duration,
+    }
+
+    fn fake(name: &'static str, kind: ComponentKind, up: bool) -> Arc<dyn HealthCheck> {
+        Arc::new(Fake { name, kind, up, delay: Duration::ZERO })
+    }
+
+    #[async_trait]
+    impl HealthCheck for Fake {
+        fn name(&self) -> String {
+            self.name.to_string()
+        }
+
+        fn kind(&self) -> ComponentKind {
+            self.kind
+        }
+
+        async fn check(&self) -> Result<Option<String>, String> {
+            tokio::time::sleep(self.delay).await;
+            if self.up { Ok(None) } else { Err("connection refused".to_string()) }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ready_with_one_provider_up() {
+        let dir = tempfile::tempdir().unwrap();
+        let health = Health::new()
+            .check(Arc::new(DirectoryCheck::new("runs", dir.path().join("runs"))))
+            .check(fake("task_queue", ComponentKind::Queue, true))
+            .check(fake("groq", ComponentKind::Provider, false))
+            .check(fake("together", ComponentKind::Provider, true));
+        let (status, body) = health.handle_http("GET", "/readyz").await;
+        assert_eq!(status, 200);
+        assert_eq!(body["status"], "ready");
+        assert_eq!(body["components"][2]["status"], "down");
+        assert_eq!(body["components"][2]["error"], "connection refused");
+    }
+
+    #[tokio::test]
+    async fn test_not_ready_but_live() {
+        let health = Health::new()
+            .check(fake("runs", ComponentKind::RunStore, true))
+            .check(fake("task_queue", ComponentKind::Queue, false))
+            .check(fake("groq", ComponentKind::Provider, true));
+        assert_eq!(health.handle_http("GET", "/readyz").await.0, 503);
+        let (status, body) = health.handle_http("GET", "/healthz").await;
+        assert_eq!(status, 200);
+        assert_eq!(body["components"].as_array().unwrap().len(), 3);
+
+        // No provider at all is never ready.
+        let health = Health::new().check(fake("runs", ComponentKind::RunStore, true));
+        assert!(!health.report().await.is_ready());
+    }
+
+    #[tokio::test]
+    async fn test_slow_checks_time_out() {
+        let slow = Arc::new(Fake { name: "groq", kind: ComponentKind::Provider, up: true, delay: Duration::from_secs(5) });
+        let health = Health::new().check(slow).check_timeout(Duration::from_millis(20));
+        let report = health.report().await;
+        assert_eq!(report.components[0].status, ComponentState::Down);
+        assert!(report.components[0].error.as_deref().unwrap().starts_with("timed out"));
+    }
+}
+```
@@ -0,0 +1,82 @@
+### Tests for loop detection
+Exact and near repeats of recent turns must be caught and escalate through the configured actions; an agent's
+loops must react to each action, and conversations must drop verbatim repeats.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::structs::loop_detection::{LoopAction, LoopDetectionConfig, LoopDetector};
+
+    const ANSWER: &str = "Revenue grew 12% year over year, driven mostly by new subscription plans in Europe.";
+
+    #[test]
+    fn test_detects_exact_and_near_repeats() {
+        let mut detector = LoopDetector::new(LoopDetectionConfig::default());
+        assert_eq!(detector.observe(ANSWER), None);
+
+        let exact = detector.observe(&ANSWER.to_uppercase().replace(' ', "  ")).unwrap();
+        assert!(exact.exact);
+        assert_eq!((exact.turn, exact.repeats_turns_back), (1, 1));
+
+        assert_eq!(detector.observe("Margins narrowed because cloud costs rose faster than revenue."), None);
+        let near = detector.observe(&format!("{} Overall.", ANSWER)).unwrap();
+        assert!(!near.exact && near.similarity >= 0.9);
+        assert_eq!(near.repeats_turns_back, 2);
+    }
+
+    #[test]
+    fn test_escalation_and_window() {
+        let mut detector = LoopDetector::new(LoopDetectionConfig { window: 1, ..LoopDetectionConfig::default() });
+        detector.observe(ANSWER);
+        let actions: Vec<_> = (0..4).filter_map(|_| detector.observe(ANSWER)).map(|d| d.action).collect();
+        assert_eq!(
+            actions,
+            vec![LoopAction::RaiseTemperature, LoopAction::Nudge, LoopAction::Terminate, LoopAction::Terminate]
+        );
+
+        detector.observe("Something else entirely.");
+        assert_eq!(detector.observe(ANSWER), None);
+    }
+
+    #[test]
+    fn test_agent_run_loops() {
+        let mut agent = LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o-mini")
+            .max_loops(6)
+            .loop_detection(LoopDetectionConfig::default())
+            .build()
+            .unwrap();
+        let mut steps = Vec::new();
+        let run = agent.run_loops("Summarize the quarter.", |step| {
+            steps.push(step.clone());
+            ANSWER.to_string()
+        });
+        assert_eq!(steps.len(), 4);
+        assert!((steps[2].temperature - 0.7).abs() < 1e-9);
+        assert!(steps[3].prompt.contains("You are repeating yourself"));
+        assert!(!steps[2].prompt.contains("You are repeating yourself"));
+        assert_eq!(run.outputs, vec![ANSWER.to_string()]);
+        assert_eq!(run.detections.len(), 3);
+        assert!(run.terminated_early);
+
+        agent.loop_detection = None;
+        let run = agent.run_loops("Summarize the quarter.", |_| ANSWER.to_string());
+        assert_eq!((run.outputs.len(), run.detections.len(), run.terminated_early), (6, 0, false));
+    }
+
+    #[test]
+    fn test_conversation_dedupe() {
+        let mut conversation = Conversation::builder().build().unwrap();
+        conversation.add("user".to_string(), "Continue.".to_string());
+        conversation.add("assistant".to_string(), ANSWER.to_string());
+        conversation.add("user".to_string(), "Continue.".to_string());
+        conversation.add("assistant".to_string(), format!(" {}\n", ANSWER));
+        assert_eq!(conversation.dedupe("assistant"), 1);
+        assert_eq!(conversation.count_messages_by_role().get("user"), Some(&2));
+        assert_eq!(conversation.count_messages_by_role().get("assistant"), Some(&1));
+    }
+}
+```
@@ -0,0 +1,55 @@
+### Tests for adaptive model selection
+Short plain tasks must go to the cheap model; code, math, long tasks, and expensive tools to the expensive
+one; overrides must win, and every choice must be counted.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::model_selector::{ModelSelector, ModelTier, SelectionReason, SelectorConfig, TierUsage};
+
+    fn selector() -> ModelSelector {
+        ModelSelector::new(SelectorConfig { force_tier: None, ..SelectorConfig::default() })
+    }
+
+    #[test]
+    fn test_simple_tasks_are_cheap() {
+        let selection = selector().select("Summarize this email in one sentence.", &[]);
+        assert_eq!(selection.tier, ModelTier::Cheap);
+        assert_eq!(selection.model, "gpt-4o-mini");
+        assert!(!selection.signals.has_code && !selection.signals.has_math);
+    }
+
+    #[test]
+    fn test_complex_tasks_are_expensive() {
+        let selector = selector();
+        let code_and_math = "Prove the loop terminates:\n```rust\nfn main() { let mut x = 10; while x > 0 { x -= 1; } }\n```";
+        assert_eq!(selector.select(code_and_math, &[]).tier, ModelTier::Expensive);
+
+        let long = "Compare the quarterly filings line by line. ".repeat(400);
+        let selection = selector.select(&long, &["web_search"]);
+        assert!(selection.signals.tokens > 2_000);
+        assert_eq!(selection.tier, ModelTier::Expensive);
+
+        let selection = selector.select("Plot the sales data.", &["code_interpreter"]);
+        assert_eq!(selection.tier, ModelTier::Expensive);
+        assert_eq!(selection.reason, SelectionReason::Tool);
+    }
+
+    #[test]
+    fn test_overrides_and_usage() {
+        let selector = selector();
+        let selection = selector.select_with("Summarize this email.", &[], Some(ModelTier::Expensive));
+        assert_eq!(selection.tier, ModelTier::Expensive);
+
+        let forced = ModelSelector::new(SelectorConfig { force_tier: Some(ModelTier::Cheap), ..SelectorConfig::default() });
+        assert_eq!(forced.select("Prove that the integral converges.", &["code_interpreter"]).tier, ModelTier::Cheap);
+
+        let mut agent = LlmAgent::builder().name("Analyst").llm("gpt-4o").build().unwrap();
+        selector.apply(&mut agent, "Say hello.", &[]);
+        assert_eq!(agent.llm, "gpt-4o-mini");
+        assert_eq!(selector.usage(), TierUsage { cheap: 1, expensive: 1 });
+        assert_eq!(selector.clone().usage(), selector.usage());
+    }
+}
+```
@@ -0,0 +1,126 @@
+### Tests for list pagination
+Paging through a list must return every record exactly once, even when records are added between pages.
+Cursors must be rejected under another sort, and the agent and run stores must filter before they page.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::problem::Problem;
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::agent_store::{AgentQuery, AgentStore, AgentStoreError, StoredAgent};
+    use crate::swarms::structs::pagination::{paginate, Page, PageError, PageRequest, SortOrder, MAX_PAGE_LIMIT};
+    use crate::swarms::structs::tenancy::{TenantId, TenantWorkspace};
+
+    fn page_of(items: &[&str], sort: &str, order: SortOrder, request: &PageRequest) -> Result<Page<String>, PageError> {
+        let items = items.iter().map(|item| item.to_string()).collect();
+        paginate(items, sort, order, request, |item| (item.clone(), item.clone()))
+    }
+
+    fn agent(name: &str, model: &str, tag: &str) -> LlmAgent {
+        LlmAgent::builder().name(name).llm(model).capability(tag).build().unwrap()
+    }
+
+    fn names(page: &Page<StoredAgent>) -> Vec<&str> {
+        page.items.iter().map(|stored| stored.agent.name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_cursor_survives_inserts() {
+        let first = page_of(&["b", "d", "f", "h"], "name", SortOrder::Asc, &PageRequest::first(2)).unwrap();
+        assert_eq!(first.items, ["b", "d"]);
+        let cursor = first.next_cursor.unwrap();
+
+        // "a" and "c" sort before the cursor and must not shift the next page; "e" lands on it.
+        let request = PageRequest::first(2).after(cursor);
+        let second = page_of(&["a", "b", "c", "d", "e", "f", "h"], "name", SortOrder::Asc, &request).unwrap();
+        assert_eq!(second.items, ["e", "f"]);
+        let request = PageRequest::first(2).after(second.next_cursor.unwrap());
+        let last = page_of(&["a", "b", "c", "d", "e", "f", "h"], "name", SortOrder::Asc, &request).unwrap();
+        assert_eq!((last.items, last.next_cursor), (vec!["h".to_string()], None));
+    }
+
+    #[test]
+    fn test_descending_pages_and_ties() {
+        let items = vec![("2024-01-02", "run-a"), ("2024-01-02", "run-b"), ("2024-01-01", "run-c")];
+        let key = |item: &(&str, &str)| (item.0.to_string(), item.1.to_string());
+        let first = paginate(items.clone(), "-submitted_at", SortOrder::Desc, &PageRequest::first(1), key).unwrap();
+        assert_eq!(first.items, [("2024-01-02", "run-b")]);
+        let request = PageRequest::first(5).after(first.next_cursor.unwrap());
+        let rest = paginate(items, "-submitted_at", SortOrder::Desc, &request, key).unwrap();
+        assert_eq!(rest.items, [("2024-01-02", "run-a"), ("2024-01-01", "run-c")]);
+        assert_eq!(rest.next_cursor, None);
+    }
+
+    #[test]
+    fn test_bad_requests_are_rejected() {
+        let first = page_of(&["a", "b"], "name", SortOrder::Asc, &PageRequest::first(1)).unwrap();
+        let reused = PageRequest::first(1).after(first.next_cursor.unwrap());
+        let err = page_of(&["a", "b"], "-name", SortOrder::Desc, &reused).unwrap_err();
+        assert!(matches!(err, PageError::InvalidCursor(_)));
+        assert_eq!(Problem::from(&err).code, "invalid_cursor");
+
+        let forged = PageRequest::first(1).after("not-a-cursor");
+        assert!(matches!(page_of(&["a"], "name", SortOrder::Asc, &forged), Err(PageError::InvalidCursor(_))));
+        for limit in [0, MAX_PAGE_LIMIT + 1] {
+            assert_eq!(page_of(&["a"], "name", SortOrder::Asc, &PageRequest::first(limit)), Err(PageError::InvalidLimit(limit)));
+        }
+    }
+
+    #[test]
+    fn test_agent_store_filters_then_pages() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = AgentStore::new(TenantWorkspace::new(dir.path()));
+        let (acme, globex) = (TenantId::new("acme").unwrap(), TenantId::new("globex").unwrap());
+        for name in ["Auditor", "Analyst", "Bookkeeper"] {
+            store.save(&acme, &agent(name, "gpt-4o", "finance")).unwrap();
+        }
+        store.save(&acme, &agent("Writer", "gpt-4o-mini", "content")).unwrap();
+        store.save(&globex, &agent("Spy", "gpt-4o", "finance")).unwrap();
+
+        let mut query = AgentQuery::from_query_string("tag=finance&limit=2").unwrap();
+        let first = store.list(&acme, &query).unwrap();
+        assert_eq!(names(&first), ["Analyst", "Auditor"]);
+        query.page = query.page.after(first.next_cursor.unwrap());
+        let second = store.list(&acme, &query).unwrap();
+        assert_eq!((names(&second), second.next_cursor.is_none()), (vec!["Bookkeeper"], true));
+
+        let newest = store.list(&acme, &AgentQuery::from_query_string("model=gpt-4o&sort=-created_at").unwrap()).unwrap();
+        assert_eq!(names(&newest), ["Bookkeeper", "Analyst", "Auditor"]);
+
+        let created = store.get(&acme, "Writer").unwrap().unwrap().created_at;
+        let updated = store.save(&acme, &agent("Writer", "gpt-4o", "content")).unwrap();
+        assert_eq!(updated.created_at, created);
+        assert!(store.delete(&acme, "Writer").unwrap());
+        assert!(store.get(&acme, "Writer").unwrap().is_none());
+
+        assert!(matches!(store.get(&acme, "../globex/agents/Spy"), Err(AgentStoreError::InvalidName(_))));
+        assert!(AgentQuery::from_query_string("order=name").is_err());
+        let bad_sort = AgentQuery::from_query_string("sort=llm").unwrap();
+        assert!(matches!(store.list(&acme, &bad_sort), Err(AgentStoreError::Page(PageError::InvalidParameter(_)))));
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_ledger_lists_runs_by_status() {
+        use crate::swarms::structs::distributed_queue::{RunQuery, TaskLedger, TaskStatus};
+        use std::time::{Duration, Instant};
+
+        let acme = TenantId::new("acme").unwrap();
+        let mut ledger = TaskLedger::new(Duration::from_secs(30));
+        let tasks: Vec<String> = (0..3).map(|i| ledger.submit(&acme, "Analyst", &format!("task {}", i))).collect();
+        let worker = ledger.register(&acme, "w-1", vec!["Analyst".to_string()]);
+        let claimed = ledger.claim(&worker, Instant::now()).unwrap();
+        ledger.record_chunk(&worker, &claimed.task_id, "done", true, None, Instant::now());
+
+        let queued = ledger.list(&acme, &RunQuery::from_query_string("status=queued").unwrap()).unwrap();
+        assert_eq!(queued.items.iter().map(|r| r.task_id.as_str()).collect::<Vec<_>>(), [tasks[2].as_str(), tasks[1].as_str()]);
+
+        let mut query = RunQuery::from_query_string("sort=submitted_at&limit=2").unwrap();
+        let first = ledger.list(&acme, &query).unwrap();
+        assert_eq!(first.items[0].status, TaskStatus::Completed);
+        query.page = query.page.after(first.next_cursor.unwrap());
+        assert_eq!(ledger.list(&acme, &query).unwrap().items.len(), 1);
+        assert!(RunQuery::from_query_string("status=paused").is_err());
+    }
+}
+```
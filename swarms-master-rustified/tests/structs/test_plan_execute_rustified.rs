@@ -0,0 +1,164 @@
+### Tests for plan-and-execute mode
+Planner replies must parse into typed steps, steps must be routed by kind with earlier outputs in context,
+and a failing step or unparseable plan must be re-planned until the limit, with everything recorded. A
+failed step must keep the code of the error that failed it.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::plan_execute::{
+        plan_and_execute, ExecutedStep, Plan, PlanError, PlanStep, PlanningConfig, StepAction, StepRouter,
+    };
+    use crate::swarms::structs::failure::{Failure, FailureCode};
+    use crate::swarms::structs::step_guard::StepStatus;
+    use serde_json::json;
+
+    fn scripted(replies: Vec<&'static str>) -> impl FnMut(&str) -> Result<String, String> {
+        let mut replies = replies.into_iter();
+        move |_prompt| replies.next().map(str::to_string).ok_or_else(|| "planner exhausted".to_string())
+    }
+
+    #[test]
+    fn test_parse_plan() {
+        let reply = "Here is the plan:\n```json\n{\"steps\": [\
+            {\"description\": \"Search\", \"action\": \"tool\", \"tool\": \"web_search\", \"arguments\": {\"q\": \"ROTH IRA\"}},\
+            {\"description\": \"Ask\", \"action\": \"agent\", \"agent\": \"Advisor\"},\
+            {\"description\": \"Answer\", \"action\": \"respond\"}]}\n```";
+        let plan = Plan::parse(reply, 0).unwrap();
+        assert_eq!(plan.steps.iter().map(|step| step.id).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(
+            plan.steps[0].action,
+            StepAction::Tool { tool: "web_search".to_string(), arguments: json!({ "q": "ROTH IRA" }) }
+        );
+        assert_eq!(plan.steps[1].label(), "agent:Advisor");
+
+        let bare = Plan::parse(r#"[{"description": "Answer", "action": "respond"}]"#, 1).unwrap();
+        assert_eq!((bare.revision, bare.steps.len()), (1, 1));
+        assert_eq!(Plan::parse(r#"{"steps": []}"#, 0), Err(PlanError::Empty));
+        assert!(matches!(Plan::parse("I would start by searching.", 0), Err(PlanError::Invalid(_))));
+    }
+
+    #[test]
+    fn test_executes_steps_in_order_with_context() {
+        let planner = scripted(vec![
+            r#"{"steps": [{"description": "Find revenue", "action": "agent", "agent": "Researcher", "task": "Q3 revenue"},
+                          {"description": "Summarize", "action": "respond"}]}"#,
+        ]);
+        let mut prompts = Vec::new();
+        let mut router = StepRouter::new(|prompt: &str| {
+            prompts.push(prompt.to_string());
+            Ok("Revenue rose.".to_string())
+        })
+        .agent("Researcher", |task: &str| Ok(format!("{}: $4.2M", task)));
+        let run = plan_and_execute("Analyst", "Report on Q3", &PlanningConfig::default(), planner, &mut router);
+        drop(router);
+
+        assert_eq!(run.status, StepStatus::Succeeded);
+        assert_eq!(run.output.as_deref(), Some("Revenue rose."));
+        assert_eq!(run.plans.len(), 1);
+        assert_eq!(run.steps[0].record.step, "agent:Researcher");
+        assert_eq!(run.steps[0].output.as_deref(), Some("Q3 revenue: $4.2M"));
+        assert!(prompts[0].contains("Q3 revenue: $4.2M") && prompts[0].ends_with("Current step: Summarize"));
+    }
+
+    #[test]
+    fn test_replans_after_failure() {
+        let planner = scripted(vec![
+            r#"[{"description": "Draft", "action": "respond"}, {"description": "Check", "action": "agent", "agent": "Missing"}]"#,
+            "not a plan",
+            r#"[{"description": "Check it yourself", "action": "respond"}]"#,
+        ]);
+        let mut executor = |_goal: &str, step: &PlanStep, _completed: &[ExecutedStep]| -> Result<String, Failure> {
+            match step.action {
+                StepAction::Agent { .. } => Err(Failure::new(FailureCode::ValidationError, "no sub-agent named 'Missing'")),
+                _ => Ok(format!("did {}", step.description)),
+            }
+        };
+        let run = plan_and_execute("Analyst", "Write a memo", &PlanningConfig::default(), planner, &mut executor);
+        assert_eq!(run.status, StepStatus::Succeeded);
+        assert_eq!(run.plans.iter().map(|plan| plan.revision).collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(run.steps.len(), 3);
+        assert_eq!(run.steps[1].record.status.code(), Some(FailureCode::ValidationError));
+        assert_eq!((run.steps[2].revision, run.output.as_deref()), (1, Some("did Check it yourself")));
+    }
+
+    #[test]
+    fn test_gives_up_and_panics_are_contained() {
+        let config = PlanningConfig { max_replans: 1, ..PlanningConfig::default() };
+        let planner = scripted(vec![
+            r#"[{"description": "Explode", "action": "respond"}]"#,
+            r#"[{"description": "Explode again", "action": "respond"}]"#,
+        ]);
+        let mut router = StepRouter::new(|_prompt: &str| -> Result<String, String> { panic!("model client crashed") });
+        let run = plan_and_execute("Analyst", "Anything", &config, planner, &mut router);
+        assert_eq!(run.steps.len(), 2);
+        assert!(matches!(run.steps[0].record.status, StepStatus::Panicked { .. }));
+        assert!(matches!(&run.status, StepStatus::Failed { error, .. } if error.contains("1 re-plan")));
+        assert_eq!(run.status.code(), Some(FailureCode::Panicked));
+        assert!(!run.status.is_retriable());
+        assert!(serde_json::to_value(&run).unwrap()["plans"].is_array());
+    }
+
+    #[test]
+    fn test_agent_and_schema_wiring() {
+        assert_eq!(PlanningConfig::from_schema(&AgentSchema::default()), None);
+        let schema = AgentSchema {
+            planning: Some(true),
+            planning_prompt: Some("Plan like an auditor.".to_string()),
+            ..AgentSchema::default()
+        };
+        let config = PlanningConfig::from_schema(&schema).unwrap();
+        assert_eq!(config.prompt, "Plan like an auditor.");
+
+        let agent = LlmAgent::builder().name("Auditor").llm("gpt-4o").planning(config).build().unwrap();
+        let mut seen = String::new();
+        let planner = |prompt: &str| {
+            seen = prompt.to_string();
+            Ok(r#"[{"description": "Audit", "action": "respond"}]"#.to_string())
+        };
+        let mut router = StepRouter::new(|_prompt: &str| Ok("clean".to_string()));
+        let run = agent.plan_and_execute("Audit the ledger", planner, &mut router);
+        assert_eq!(run.output.as_deref(), Some("clean"));
+        assert!(seen.starts_with("Plan like an auditor.") && seen.contains("Task: Audit the ledger"));
+    }
+
+    #[test]
+    fn test_failed_steps_keep_their_code() {
+        let config = PlanningConfig { max_replans: 0, ..PlanningConfig::default() };
+        let planner = scripted(vec![r#"[{"description": "Ask", "action": "agent", "agent": "Missing"}]"#]);
+        let mut router = StepRouter::new(|_prompt: &str| Ok("unused".to_string()));
+        let run = plan_and_execute("Analyst", "Anything", &config, planner, &mut router);
+        assert_eq!(
+            run.steps[0].record.status,
+            StepStatus::Failed {
+                error: "no sub-agent named 'Missing'".to_string(),
+                code: FailureCode::ValidationError,
+                retriable: false
+            }
+        );
+
+        let run = plan_and_execute("Analyst", "Anything", &config, scripted(vec!["no plan here"]), &mut router);
+        assert_eq!(run.status.code(), Some(FailureCode::ValidationError));
+
+        #[cfg(feature = "tools")]
+        {
+            use crate::swarms::tools::base_tool::{ToolExecutionError, ToolRegistry};
+
+            // The message mentions a timeout, but the tool's error type decides the code
+            let registry = ToolRegistry::builder()
+                .tool("quote", "Quotes a price.", json!({ "type": "object" }), |_| {
+                    Err(ToolExecutionError::new("upstream timed out"))
+                })
+                .build()
+                .unwrap();
+            let planner = scripted(vec![r#"[{"description": "Quote", "action": "tool", "tool": "quote"}]"#]);
+            let mut router = StepRouter::new(|_prompt: &str| Ok("unused".to_string())).tools(registry);
+            let run = plan_and_execute("Analyst", "Anything", &config, planner, &mut router);
+            assert_eq!(run.steps[0].record.status.code(), Some(FailureCode::ToolError));
+            assert!(!run.steps[0].record.status.is_retriable());
+        }
+    }
+}
+```
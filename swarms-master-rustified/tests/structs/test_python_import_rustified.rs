@@ -0,0 +1,106 @@
+### Tests for the Python state import
+A Python agent save must map onto the Rust agent field by field, with its history, and report only the
+settings it could not carry over. Python conversation files must load in each shape Python writes them.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::python_import::{
+        import_agent_state, import_agent_state_file, import_conversation, PythonImportError,
+    };
+    use serde_json::json;
+
+    #[test]
+    fn test_agent_state_maps_python_fields() {
+        let saved = json!({
+            "agent_name": "Financial-Analysis-Agent",
+            "agent_description": "Reads filings.",
+            "system_prompt": "You are a financial analyst.",
+            "llm": "<Non-serializable: OpenAIChat>",
+            "model_name": "gpt-4o-mini",
+            "max_loops": 3,
+            "context_length": 16000,
+            "max_tokens": 2000,
+            "retry_attempts": 5,
+            "saved_state_path": "finance_agent",
+            "planning": "Plan the analysis first.",
+            "tags": ["finance"],
+            // Defaults, empty values, and process state are not reported
+            "temperature": 0.1,
+            "stopping_token": null,
+            "interactive": false,
+            "agent_id": "f2c1",
+            // Chosen settings with no Rust counterpart are
+            "top_p": 0.8,
+            "stopping_func": { "name": "stop_when_done", "doc": null },
+            "short_memory": [
+                { "role": "System", "content": "You are a financial analyst.", "timestamp": "2024-10-01 09:12:44" },
+                { "role": "Human:", "content": "Summarize Q3.", "turn": 1 }
+            ]
+        });
+        let imported = import_agent_state(&saved).unwrap();
+        let agent = &imported.value.agent;
+        assert_eq!(agent.name, "Financial-Analysis-Agent");
+        assert_eq!(agent.llm, "gpt-4o-mini");
+        assert_eq!(agent.description, "Reads filings.");
+        assert_eq!(agent.max_loops, 3);
+        assert_eq!(agent.retry_attempts, 5);
+        assert_eq!((agent.budget.max_context_tokens, agent.budget.reserved_output_tokens), (16000, 2000));
+        assert_eq!(agent.saved_state_path, "finance_agent");
+        assert_eq!(agent.planning.as_ref().unwrap().prompt, "Plan the analysis first.");
+        assert_eq!(agent.capabilities, ["finance"]);
+
+        let history = imported.value.history.as_ref().unwrap();
+        let messages = history.messages_for_model();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].timestamp.as_deref(), Some("2024-10-01 09:12:44"));
+        assert_eq!((messages[1].role.as_str(), messages[1].content.as_str()), ("Human:", "Summarize Q3."));
+
+        let mut reported: Vec<&str> = imported.warnings.iter().map(|warning| warning.field.as_str()).collect();
+        reported.sort();
+        assert_eq!(reported, ["short_memory[1].turn", "stopping_func", "top_p"]);
+    }
+
+    #[test]
+    fn test_agent_state_edge_cases() {
+        let imported = import_agent_state(&json!({ "agent_name": "Auto", "llm": { "model_name": "claude-3" }, "max_loops": "auto" }));
+        let imported = imported.unwrap();
+        assert_eq!(imported.value.agent.llm, "claude-3");
+        assert_eq!(imported.value.agent.max_loops, 1);
+        assert_eq!(imported.warnings[0].field, "max_loops");
+        assert!(imported.value.history.is_none());
+
+        let err = import_agent_state(&json!({ "agent_name": "NoModel", "llm": "<Non-serializable: LiteLLM>" })).unwrap_err();
+        assert!(matches!(err, PythonImportError::MissingModel));
+        assert!(matches!(import_agent_state(&json!([])), Err(PythonImportError::Shape(_))));
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("agent.json");
+        std::fs::write(&path, r#"{"agent_name": "Filed", "model_name": "gpt-4o", "max_loops": 0}"#).unwrap();
+        assert!(matches!(import_agent_state_file(&path), Err(PythonImportError::Agent(_))));
+    }
+
+    #[test]
+    fn test_conversation_shapes() {
+        let listed = json!([
+            { "role": "User", "content": "What is 2 + 2?" },
+            { "role": "Assistant", "content": { "answer": 4 } },
+            { "content": "no role" }
+        ]);
+        let imported = import_conversation(&listed).unwrap();
+        let messages = imported.value.messages_for_model();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].content, r#"{"answer":4}"#);
+        let reported: Vec<&str> = imported.warnings.iter().map(|warning| warning.field.as_str()).collect();
+        assert_eq!(reported, ["conversation_history[1].content", "conversation_history[2].role"]);
+
+        let wrapped = json!({ "conversation_history": ["User: hello", "Assistant: hi: there"] });
+        let imported = import_conversation(&wrapped).unwrap();
+        let messages = imported.value.messages_for_model();
+        assert_eq!((messages[1].role.as_str(), messages[1].content.as_str()), ("Assistant", "hi: there"));
+        assert!(imported.warnings.is_empty());
+
+        assert!(matches!(import_conversation(&json!("text")), Err(PythonImportError::Shape(_))));
+    }
+}
+```
@@ -0,0 +1,350 @@
+### Tests for the streaming task queue swarm
+A streaming run must ack a task only once its result is published, and nack one that failed, panicked, or
+whose result could not be published. A supervised run must reconnect its task source after a broker failure,
+and the tasks delivered but not acked on the failed connection must be redelivered and completed on the new one.
+A batch run must not let an agent with many queued tasks hold every worker while another agent's task waits.
+A supervised task found stuck must be given up without waiting for the agent to return, while a slow result that
+comes in first is kept.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::task_stream::{
+        AckToken, OffsetTracker, ResultSink, StreamError, StreamResult, StreamTask, TaskSource,
+    };
+    use crate::swarms::structs::agent::{Agent, AgentError, MockAgent};
+    use crate::swarms::structs::queue_swarm::TaskQueueSwarm;
+    use crate::swarms::structs::restart::{RestartPolicy, RestartSupervisor};
+    use crate::swarms::structs::supervisor::{StuckAction, Supervisor};
+    use async_trait::async_trait;
+    use std::collections::VecDeque;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    // A broker that redelivers every unacked task to the next connection
+    #[derive(Default)]
+    struct Broker {
+        queue: VecDeque<StreamTask>,
+        unacked: Vec<StreamTask>,
+        acked: Vec<String>,
+        deliveries: Vec<String>,
+        connects: usize,
+    }
+
+    struct BrokerSource {
+        broker: Arc<Mutex<Broker>>,
+        // The first connection drops before it can ack anything.
+        dropped: bool,
+    }
+
+    fn connect(broker: &Arc<Mutex<Broker>>) -> BrokerSource {
+        let mut state = broker.lock().unwrap();
+        state.connects += 1;
+        let unacked: Vec<StreamTask> = state.unacked.drain(..).collect();
+        for task in unacked.into_iter().rev() {
+            state.queue.push_front(task);
+        }
+        BrokerSource { broker: broker.clone(), dropped: state.connects == 1 }
+    }
+
+    #[async_trait]
+    impl TaskSource for BrokerSource {
+        async fn next(&mut self) -> Result<Option<StreamTask>, StreamError> {
+            let mut state = self.broker.lock().unwrap();
+            let task = state.queue.pop_front();
+            if let Some(task) = &task {
+                state.deliveries.push(task.id.clone());
+                state.unacked.push(task.clone());
+            }
+            Ok(task)
+        }
+
+        async fn ack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            if self.dropped {
+                return Err(StreamError::Ack("connection reset by broker".to_string()));
+            }
+            let mut state = self.broker.lock().unwrap();
+            state.unacked.retain(|unacked| unacked.id != task.id);
+            state.acked.push(task.id.clone());
+            Ok(())
+        }
+
+        async fn nack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            let mut state = self.broker.lock().unwrap();
+            state.unacked.retain(|unacked| unacked.id != task.id);
+            state.queue.push_back(task.clone());
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct Collect(Mutex<Vec<StreamResult>>);
+
+    #[async_trait]
+    impl ResultSink for Collect {
+        async fn publish(&self, result: &StreamResult) -> Result<(), StreamError> {
+            self.0.lock().unwrap().push(result.clone());
+            Ok(())
+        }
+    }
+
+    fn task(id: &str) -> StreamTask {
+        StreamTask { id: id.to_string(), payload: format!("summarize {}", id), partition_key: id.to_string(), ack: AckToken::None }
+    }
+
+    // A source that delivers each task once and records how it was settled
+    #[derive(Default)]
+    struct Settled {
+        acked: Vec<String>,
+        nacked: Vec<String>,
+    }
+
+    struct OnceSource {
+        queue: VecDeque<StreamTask>,
+        settled: Arc<Mutex<Settled>>,
+    }
+
+    #[async_trait]
+    impl TaskSource for OnceSource {
+        async fn next(&mut self) -> Result<Option<StreamTask>, StreamError> {
+            Ok(self.queue.pop_front())
+        }
+
+        async fn ack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            self.settled.lock().unwrap().acked.push(task.id.clone());
+            Ok(())
+        }
+
+        async fn nack(&mut self, task: &StreamTask) -> Result<(), StreamError> {
+            self.settled.lock().unwrap().nacked.push(task.id.clone());
+            Ok(())
+        }
+    }
+
+    // Fails to publish the result of the task `unpublishable`
+    #[derive(Default)]
+    struct FlakySink(Mutex<Vec<StreamResult>>);
+
+    #[async_trait]
+    impl ResultSink for FlakySink {
+        async fn publish(&self, result: &StreamResult) -> Result<(), StreamError> {
+            if result.task_id == "unpublishable" {
+                return Err(StreamError::Publish("broker refused the write".to_string()));
+            }
+            self.0.lock().unwrap().push(result.clone());
+            Ok(())
+        }
+    }
+
+    // Fails or panics when the task says so
+    struct Scripted;
+
+    impl Agent for Scripted {
+        fn name(&self) -> &str {
+            "Scripted"
+        }
+
+        fn run(&self, task: &str) -> Result<String, AgentError> {
+            match task {
+                "summarize fail" => Err(AgentError::Failed { agent: "Scripted".to_string(), message: "gave up".to_string() }),
+                "summarize panic" => panic!("provider client dropped"),
+                _ => Ok(task.to_uppercase()),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_acks_published_tasks_and_nacks_the_rest() {
+        let dir = tempfile::tempdir().unwrap();
+        let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(Scripted)];
+        let swarm = TaskQueueSwarm::new(agents, "ingest", "", false, dir.path().to_str().unwrap(), false, 1);
+        let settled = Arc::new(Mutex::new(Settled::default()));
+        let source = OnceSource { queue: ["ok", "fail", "panic", "unpublishable"].map(task).into(), settled: settled.clone() };
+        let sink = Arc::new(FlakySink::default());
+
+        let completed = swarm.run_stream(Box::new(source), sink.clone()).await.unwrap();
+
+        assert_eq!(completed, 1);
+        let settled = settled.lock().unwrap();
+        assert_eq!(settled.acked, ["ok"]);
+        let mut nacked = settled.nacked.clone();
+        nacked.sort();
+        assert_eq!(nacked, ["fail", "panic", "unpublishable"]);
+        let published = sink.0.lock().unwrap();
+        assert_eq!(published.len(), 1);
+        assert_eq!((published[0].task_id.as_str(), published[0].result.as_str()), ("ok", "SUMMARIZE OK"));
+        assert_eq!(published[0].agent_name, "Scripted");
+    }
+
+    #[test]
+    fn test_offset_tracker_commits_only_contiguous_offsets() {
+        let mut tracker = OffsetTracker::new();
+        for offset in 10..14 {
+            tracker.received(0, offset);
+        }
+        tracker.received(1, 5);
+        assert_eq!(tracker.finish(0, 12), None);
+        assert_eq!(tracker.finish(0, 11), None);
+        assert_eq!(tracker.finish(1, 5), Some(6));
+        assert_eq!(tracker.finish(0, 10), Some(13));
+        assert_eq!(tracker.finish(0, 13), Some(14));
+    }
+
+    #[tokio::test]
+    async fn test_supervised_stream_reconnects_and_redelivers() {
+        let dir = tempfile::tempdir().unwrap();
+        let agents: Vec<Arc<dyn Agent>> = vec![Arc::new(MockAgent::new("A")), Arc::new(MockAgent::new("B"))];
+        let swarm = TaskQueueSwarm::new(agents, "ingest", "", false, dir.path().to_str().unwrap(), false, 1);
+        let broker = Arc::new(Mutex::new(Broker { queue: ["t1", "t2", "t3"].map(task).into(), ..Broker::default() }));
+        let sink = Arc::new(Collect::default());
+        let policy = RestartPolicy {
+            max_restarts: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(1),
+            ..RestartPolicy::default()
+        };
+        let restart = RestartSupervisor::new("daemon").with_policy(policy);
+
+        let completed = swarm.run_stream_supervised(&restart, || Box::new(connect(&broker)), sink.clone()).await.unwrap();
+
+        // Only the run on the second connection finished, and it completed every task.
+        assert_eq!(completed, 3);
+        let state = broker.lock().unwrap();
+        assert_eq!(state.connects, 2);
+        let mut acked = state.acked.clone();
+        acked.sort();
+        assert_eq!(acked, ["t1", "t2", "t3"]);
+        assert!(state.unacked.is_empty() && state.queue.is_empty());
+        // At least the task whose ack failed was delivered to both connections.
+        assert!(state.deliveries.len() > 3, "{:?}", state.deliveries);
+        assert!(sink.0.lock().unwrap().len() >= 3);
+    }
+
+    // Records the order tasks start in and how many of its own tasks run at once
+    struct Paced {
+        name: &'static str,
+        started: Arc<Mutex<Vec<String>>>,
+        running: AtomicUsize,
+        peak: AtomicUsize,
+    }
+
+    impl Paced {
+        fn new(name: &'static str, started: &Arc<Mutex<Vec<String>>>) -> Arc<Self> {
+            Arc::new(Paced { name, started: started.clone(), running: AtomicUsize::new(0), peak: AtomicUsize::new(0) })
+        }
+    }
+
+    impl Agent for Paced {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn run(&self, task: &str) -> Result<String, AgentError> {
+            self.started.lock().unwrap().push(task.to_string());
+            self.peak.fetch_max(self.running.fetch_add(1, Ordering::SeqCst) + 1, Ordering::SeqCst);
+            thread::sleep(Duration::from_millis(20));
+            self.running.fetch_sub(1, Ordering::SeqCst);
+            Ok(task.to_string())
+        }
+    }
+
+    #[test]
+    fn test_chatty_agent_cannot_monopolize_the_workers() {
+        let dir = tempfile::tempdir().unwrap();
+        let started = Arc::new(Mutex::new(Vec::new()));
+        let (chatty, quiet) = (Paced::new("Chatty", &started), Paced::new("Quiet", &started));
+        let agents: Vec<Arc<dyn Agent>> = vec![chatty.clone(), quiet.clone()];
+        let mut swarm = TaskQueueSwarm::new(agents, "mixed", "", false, dir.path().to_str().unwrap(), false, 1)
+            .with_workers(2)
+            .with_max_in_flight("Chatty", 1);
+        for i in 0..8 {
+            swarm.add_task_for("Chatty", &format!("chatty {}", i)).unwrap();
+        }
+        swarm.add_task_for("Quiet", "quiet").unwrap();
+
+        swarm.run().unwrap();
+
+        // The second worker takes the quiet task instead of a second chatty one.
+        let started = started.lock().unwrap();
+        assert_eq!(started.len(), 9);
+        assert!(started[..2].contains(&"quiet".to_string()), "{:?}", started);
+        assert_eq!(chatty.peak.load(Ordering::SeqCst), 1);
+        assert!(swarm.add_task_for("Ghost", "boo").is_err());
+    }
+
+    // Hangs on its first attempt at "hang", and takes `slow` on anything else
+    struct Hanging {
+        slow: Duration,
+        calls: AtomicUsize,
+    }
+
+    impl Agent for Hanging {
+        fn name(&self) -> &str {
+            "Hanging"
+        }
+
+        fn run(&self, task: &str) -> Result<String, AgentError> {
+            let first = self.calls.fetch_add(1, Ordering::SeqCst) == 0;
+            thread::sleep(if task == "hang" && first { Duration::from_secs(2) } else { self.slow });
+            Ok(format!("done: {}", task))
+        }
+    }
+
+    fn outputs(dir: &tempfile::TempDir) -> Vec<serde_json::Value> {
+        let saved = std::fs::read_to_string(dir.path().join("watched_metadata.json")).unwrap();
+        let saved: serde_json::Value = serde_json::from_str(&saved).unwrap();
+        saved["metadata"]["outputs"].as_array().unwrap().clone()
+    }
+
+    #[test]
+    fn test_stuck_task_is_given_up_without_waiting_for_the_agent() {
+        for (action, status) in [(StuckAction::Requeue, "succeeded"), (StuckAction::Cancel, "failed")] {
+            let dir = tempfile::tempdir().unwrap();
+            let agent = Arc::new(Hanging { slow: Duration::ZERO, calls: AtomicUsize::new(0) });
+            let supervisor = Supervisor::new("watched").with_expected_duration(Duration::from_millis(50)).with_action(action);
+            let supervisor = Arc::new(supervisor);
+            let _watchdog = supervisor.watch(Duration::from_millis(5));
+            let agents: Vec<Arc<dyn Agent>> = vec![agent.clone()];
+            let swarm = TaskQueueSwarm::new(agents, "watched", "", true, dir.path().to_str().unwrap(), false, 1);
+            let mut swarm = swarm.with_supervisor(supervisor);
+            swarm.add_task("hang");
+
+            let started = Instant::now();
+            swarm.run().unwrap();
+
+            assert!(started.elapsed() < Duration::from_secs(1), "{:?} waited for the hung attempt", action);
+            let outputs = outputs(&dir);
+            assert_eq!(outputs.len(), 1);
+            assert_eq!(outputs[0]["status"], status);
+            match action {
+                StuckAction::Requeue => {
+                    assert_eq!(outputs[0]["result"], "done: hang");
+                    assert_eq!(agent.calls.load(Ordering::SeqCst), 2);
+                }
+                StuckAction::Cancel => assert!(outputs[0]["error"].as_str().unwrap().contains("no heartbeat")),
+            }
+        }
+    }
+
+    #[test]
+    fn test_slow_result_that_arrives_first_is_kept() {
+        let dir = tempfile::tempdir().unwrap();
+        let agent = Arc::new(Hanging { slow: Duration::from_millis(150), calls: AtomicUsize::new(0) });
+        // The watchdog only looks after the result is in, though the task ran past its expected duration
+        let supervisor = Arc::new(Supervisor::new("watched").with_expected_duration(Duration::from_millis(20)));
+        let _watchdog = supervisor.watch(Duration::from_millis(400));
+        let agents: Vec<Arc<dyn Agent>> = vec![agent.clone()];
+        let swarm = TaskQueueSwarm::new(agents, "watched", "", true, dir.path().to_str().unwrap(), false, 1);
+        let mut swarm = swarm.with_supervisor(supervisor);
+        swarm.add_task("report");
+
+        swarm.run().unwrap();
+
+        let outputs = outputs(&dir);
+        assert_eq!((outputs[0]["status"].as_str(), outputs[0]["result"].as_str()), (Some("succeeded"), Some("done: report")));
+        assert_eq!(agent.calls.load(Ordering::SeqCst), 1);
+    }
+}
+```
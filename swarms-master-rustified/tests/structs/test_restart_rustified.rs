@@ -0,0 +1,94 @@
+### Tests for restart supervision
+A crashing child must be restarted with growing backoff until it exits cleanly, and failing more often than
+the policy allows within its window must escalate. Failures spread out past the window must not escalate.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::failure::{Classify, FailureCode};
+    use crate::swarms::structs::restart::{Restart, RestartDecision, RestartIntensity, RestartPolicy, RestartSupervisor};
+    use crate::swarms::structs::step_guard::StepStatus;
+    use std::time::{Duration, Instant};
+
+    fn fast(max_restarts: u32) -> RestartPolicy {
+        RestartPolicy {
+            max_restarts,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(4),
+            ..RestartPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_intensity_window() {
+        let policy = RestartPolicy { max_restarts: 2, window: Duration::from_secs(60), ..RestartPolicy::default() };
+        let mut intensity = RestartIntensity::new(policy);
+        let start = Instant::now();
+        let backoffs: Vec<RestartDecision> = (0..3).map(|i| intensity.on_exit(start + Duration::from_secs(i))).collect();
+        assert_eq!(
+            backoffs,
+            [
+                RestartDecision::Restart { restart: 1, backoff: Duration::from_secs(1) },
+                RestartDecision::Restart { restart: 2, backoff: Duration::from_secs(2) },
+                RestartDecision::Escalate,
+            ]
+        );
+        // Once the first restart has left the window there is room again
+        assert_eq!(
+            intensity.on_exit(start + Duration::from_secs(61)),
+            RestartDecision::Restart { restart: 2, backoff: Duration::from_secs(2) }
+        );
+        assert_eq!(policy.backoff(10), Duration::from_secs(60));
+    }
+
+    #[test]
+    fn test_restarts_until_clean_exit() {
+        let supervisor = RestartSupervisor::new("daemon").with_policy(fast(3));
+        let mut runs = 0;
+        let restarts = supervisor.run_blocking("ingest", || {
+            runs += 1;
+            if runs == 2 {
+                panic!("corrupted state");
+            }
+            if runs < 3 {
+                return Err("connection reset".to_string());
+            }
+            Ok(())
+        });
+        assert_eq!((restarts, runs), (Ok(2), 3));
+    }
+
+    #[test]
+    fn test_escalates_after_limit() {
+        let supervisor = RestartSupervisor::new("daemon").with_policy(fast(2));
+        let mut runs = 0;
+        let escalation = supervisor
+            .run_blocking("ingest", || -> Result<(), String> {
+                runs += 1;
+                Err("provider rate limit".to_string())
+            })
+            .unwrap_err();
+        assert_eq!((runs, escalation.restarts), (3, 2));
+        assert!(matches!(escalation.last_exit, StepStatus::Failed { .. }));
+        assert_eq!(escalation.failure_code(), FailureCode::ProviderRateLimit);
+        assert!(!escalation.retriable());
+        assert!(escalation.to_string().contains("gave up on ingest after 2 restart(s)"));
+    }
+
+    #[tokio::test]
+    async fn test_permanent_child_restarts_after_clean_exit() {
+        let policy = RestartPolicy { restart: Restart::Permanent, ..fast(2) };
+        let supervisor = RestartSupervisor::new("daemon").with_policy(policy);
+        let mut runs = 0;
+        let escalation = supervisor
+            .run("poller", || {
+                runs += 1;
+                async { Ok::<(), String>(()) }
+            })
+            .await
+            .unwrap_err();
+        assert_eq!(runs, 3);
+        assert_eq!(escalation.last_exit, StepStatus::Succeeded);
+    }
+}
+```
@@ -0,0 +1,153 @@
+### Tests for run diffs
+Steps must pair up by agent and position even when other agents' steps move between them, steps only one
+run has must show as added or removed, and traces must round-trip through JSONL with costs filled in from
+a pricing table. A workflow with `with_traces` must save each run's trace, and a retried step must replace
+the failed one.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, LlmAgent, MockAgent};
+    use crate::swarms::structs::run_diff::{
+        diff_command, RunDiff, RunDiffError, RunTrace, StepChange, TraceRecorder, TraceStep,
+    };
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::structs::usage::Pricing;
+    use crate::swarms::structs::workflow_run::Resume;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    fn step(agent: &str, model: &str, prompt: &str, output: &str, tokens: (u64, u64)) -> TraceStep {
+        TraceStep {
+            agent: agent.to_string(),
+            model: model.to_string(),
+            prompt: prompt.to_string(),
+            output: output.to_string(),
+            prompt_tokens: tokens.0,
+            completion_tokens: tokens.1,
+            cost_usd: None,
+            context: None,
+        }
+    }
+
+    fn traces() -> (RunTrace, RunTrace) {
+        let mut a = RunTrace::new("a");
+        a.push(step("Analyst", "gpt-4o", "Summarize.", "Up 12%.\nMargins held.", (100, 20)));
+        a.push(step("Reviewer", "gpt-4o", "Check.", "Fine.", (50, 5)));
+        a.push(step("Analyst", "gpt-4o", "Revise.", "Done.", (80, 10)));
+        let mut b = RunTrace::new("b");
+        // The reviewer now runs after both analyst steps, and a writer is new
+        b.push(step("Analyst", "gpt-4o-mini", "Summarize.", "Up 12%.", (100, 8)));
+        b.push(step("Analyst", "gpt-4o", "Revise.", "Done.", (80, 10)));
+        b.push(step("Writer", "gpt-4o", "Draft.", "Draft.", (30, 30)));
+        (a, b)
+    }
+
+    #[test]
+    fn test_steps_align_by_agent_and_position() {
+        let (a, b) = traces();
+        let diff = RunDiff::between(&a, &b);
+        let changes: Vec<_> = diff.steps.iter().map(|step| (step.agent.as_str(), step.position, step.change())).collect();
+        assert_eq!(
+            changes,
+            [
+                ("Analyst", 0, StepChange::Changed),
+                ("Reviewer", 0, StepChange::Removed),
+                ("Analyst", 1, StepChange::Unchanged),
+                ("Writer", 0, StepChange::Added),
+            ]
+        );
+        assert_eq!(diff.steps[0].token_delta(), -12);
+        assert_eq!(diff.steps[0].prompt_diff(), None);
+        assert_eq!(diff.steps[0].output_diff().unwrap(), "  Up 12%.\n- Margins held.\n");
+        assert_eq!(diff.tokens, (265, 258));
+        assert!(!diff.is_identical());
+        assert!(RunDiff::between(&a, &a).is_identical());
+
+        let report = diff.render();
+        assert!(report.starts_with("Run a -> b: 4 step(s), 1 changed, 1 added, 1 removed\n"));
+        assert!(report.contains("~ Analyst #1 [gpt-4o -> gpt-4o-mini]: tokens 120 -> 108 (-12)"));
+        assert!(report.contains("    - Margins held.\n"));
+        assert!(report.contains("- Reviewer #1 [gpt-4o], only in a"));
+        assert!(report.contains("= Analyst #2\n"));
+        assert!(report.contains("+ Writer #1 [gpt-4o], only in b"));
+    }
+
+    #[test]
+    fn test_traces_round_trip_and_price() {
+        let dir = tempfile::tempdir().unwrap();
+        let (a, b) = traces();
+        let (a_path, b_path) = (dir.path().join("run-41.jsonl"), dir.path().join("run-42.jsonl"));
+        a.save(&a_path).unwrap();
+        b.save(&b_path).unwrap();
+        let loaded = RunTrace::load(&a_path).unwrap();
+        assert_eq!(loaded.run_id, "run-41");
+        assert_eq!(loaded.steps, a.steps);
+
+        let mut priced = loaded.clone();
+        priced.price(&Pricing::new().model("gpt-4o", 2.5, 10.0));
+        assert!((priced.steps[0].cost_usd.unwrap() - 0.00045).abs() < 1e-12);
+        assert_eq!(loaded.total_cost(), None);
+
+        let pricing = dir.path().join("pricing.json");
+        std::fs::write(&pricing, r#"{"gpt-4o": {"prompt_per_million": 2.5, "completion_per_million": 10.0}}"#).unwrap();
+        let report = diff_command(a_path.to_str().unwrap(), b_path.to_str().unwrap(), pricing.to_str()).unwrap();
+        assert!(report.contains("Run run-41 -> run-42"));
+        // Unpriced steps (gpt-4o-mini) are left out of the totals and shown as n/a
+        assert!(report.contains("cost: $0.0009 -> $0.0007"));
+        assert!(report.contains("-> n/a"));
+
+        std::fs::write(&b_path, "{\"agent\": \"Analyst\"}\nnot json\n").unwrap();
+        let err = RunTrace::load(&b_path).unwrap_err();
+        assert!(matches!(err, RunDiffError::Corrupt { line: 2, .. }));
+    }
+
+    fn workflow(traces: &Path) -> SequentialWorkflow {
+        let agents: Vec<Arc<dyn Agent>> = vec![
+            LlmAgent::builder().name("Analyst").llm("gpt-4o").system_prompt("You analyze.").build().unwrap().into(),
+            Arc::new(MockAgent::new("Reviewer")),
+        ];
+        SequentialWorkflow::new("Review".into(), String::new(), agents, 1, "str".into(), false, || ())
+            .with_traces(TraceRecorder::new(traces))
+    }
+
+    fn saved(dir: &Path) -> Vec<PathBuf> {
+        std::fs::read_dir(dir).unwrap().map(|entry| entry.unwrap().path()).collect()
+    }
+
+    #[test]
+    fn test_workflow_runs_are_traced() {
+        let dir = tempfile::tempdir().unwrap();
+        let workflow = workflow(dir.path());
+        workflow.run_with("Summarize Q3.", |agent, input| Ok(format!("{} read: {}", agent.name(), input))).unwrap();
+
+        let saved = saved(dir.path());
+        assert_eq!(saved.len(), 1);
+        let trace = RunTrace::load(&saved[0]).unwrap();
+        assert!(trace.run_id.starts_with("run_"), "{}", trace.run_id);
+        let steps: Vec<_> =
+            trace.steps.iter().map(|step| (step.agent.as_str(), step.model.as_str(), step.prompt.as_str())).collect();
+        assert_eq!(steps, [("Analyst", "gpt-4o", "Summarize Q3."), ("Reviewer", "", "Analyst read: Summarize Q3.")]);
+        assert_eq!(trace.steps[0].context.as_ref().unwrap().system, "You analyze.");
+        assert!(trace.steps[0].prompt_tokens > 0 && trace.steps[1].cost_usd.is_none());
+    }
+
+    #[test]
+    fn test_retried_steps_replace_failures_in_the_trace() {
+        let dir = tempfile::tempdir().unwrap();
+        let workflow = workflow(dir.path());
+        let mut run = workflow.run_recorded("Summarize Q3.", |agent, _| match agent.name() {
+            "Analyst" => Ok("Up 12%.".to_string()),
+            _ => Err("rate limited".to_string()),
+        });
+        let path = dir.path().join(format!("{}.jsonl", run.run_id));
+        let outputs: Vec<_> = RunTrace::load(&path).unwrap().steps.into_iter().map(|step| step.output).collect();
+        assert_eq!(outputs, ["Up 12%.", "rate limited"]);
+
+        workflow.retry_step(&mut run, "Reviewer", Resume::StepOnly, |_, input| Ok(format!("Checked: {}", input))).unwrap();
+        let outputs: Vec<_> = RunTrace::load(&path).unwrap().steps.into_iter().map(|step| step.output).collect();
+        assert_eq!(outputs, ["Up 12%.", "Checked: Up 12%."]);
+        assert_eq!(saved(dir.path()).len(), 1);
+    }
+}
+```
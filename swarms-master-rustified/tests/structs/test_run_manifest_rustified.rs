@@ -0,0 +1,80 @@
+### Tests for signed run manifests
+A manifest must verify under its signer's public key only, and any change to the manifest, the event log,
+or the configuration it covers must be caught.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::run_manifest::{
+        verify_command, EventLogDigest, ManifestError, ManifestSigner, RunManifest,
+    };
+    use crate::swarms::utils::secret_store::StaticSecretStore;
+    use serde_json::json;
+    use std::fs;
+
+    fn signer(id: &str) -> ManifestSigner {
+        let store = StaticSecretStore::new().with(format!("MANIFEST_KEY_{}", id), ManifestSigner::generate_key());
+        ManifestSigner::from_store(&store, id).unwrap()
+    }
+
+    fn manifest(events: &[u8]) -> RunManifest {
+        let agent = LlmAgent::builder().name("Fraud-Analyst").llm("gpt-4o-mini").build().unwrap();
+        RunManifest::new("run-1")
+            .config(&json!({ "max_loops": 1, "flow": "Fraud-Analyst" }))
+            .unwrap()
+            .agent(&agent)
+            .unwrap()
+            .event_log(EventLogDigest::of_bytes(events))
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let key = signer("a");
+        let signed = key.sign(manifest(b"{\"step\":1}\n{\"step\":2}\n")).unwrap();
+        assert_eq!(signed.manifest.event_log.events, 2);
+        assert_eq!(signed.manifest.agents["Fraud-Analyst"].model, "gpt-4o-mini");
+        signed.verify(&key.public_key()).unwrap();
+
+        assert!(matches!(signed.verify(&signer("b").public_key()), Err(ManifestError::BadSignature)));
+        let mut tampered = signed.clone();
+        tampered.manifest.agents.get_mut("Fraud-Analyst").unwrap().model = "gpt-4o".to_string();
+        assert!(matches!(tampered.verify(&key.public_key()), Err(ManifestError::BadSignature)));
+    }
+
+    #[test]
+    fn test_covered_files_are_checked() {
+        let signed = signer("a").sign(manifest(b"{\"step\":1}\n")).unwrap();
+        signed.check_event_log(b"{\"step\":1}\n").unwrap();
+        assert!(matches!(signed.check_event_log(b"{\"step\":2}\n"), Err(ManifestError::Mismatch { .. })));
+
+        // Key order does not matter; values do.
+        signed.check_config(&json!({ "flow": "Fraud-Analyst", "max_loops": 1 })).unwrap();
+        assert!(signed.check_config(&json!({ "flow": "Fraud-Analyst", "max_loops": 2 })).is_err());
+    }
+
+    #[test]
+    fn test_event_digests_agree() {
+        let events = vec![json!({ "step": 1 }), json!({ "step": 2 })];
+        assert_eq!(
+            EventLogDigest::of_events(&events).unwrap(),
+            EventLogDigest::of_bytes(b"{\"step\":1}\n{\"step\":2}\n")
+        );
+    }
+
+    #[test]
+    fn test_verify_command() {
+        let dir = tempfile::tempdir().unwrap();
+        let (manifest_path, events_path) = (dir.path().join("run-1.manifest.json"), dir.path().join("events.jsonl"));
+        fs::write(&events_path, b"{\"step\":1}\n").unwrap();
+        let signer = signer("a");
+        signer.sign(manifest(b"{\"step\":1}\n")).unwrap().save(&manifest_path).unwrap();
+
+        let (manifest_path, events_path) = (manifest_path.to_str().unwrap(), events_path.to_str().unwrap());
+        assert!(verify_command(manifest_path, &signer.public_key(), Some(events_path), None).unwrap().contains("run-1"));
+
+        fs::write(events_path, b"{\"step\":1}\n{\"step\":\"inserted\"}\n").unwrap();
+        assert!(verify_command(manifest_path, &signer.public_key(), Some(events_path), None).is_err());
+    }
+}
+```
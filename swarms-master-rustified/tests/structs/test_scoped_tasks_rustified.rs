@@ -0,0 +1,64 @@
+### Tests for scoped fan-out helpers
+A panicking worker must not hide the others' failures or results, and results must keep input order.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::scoped_tasks::{join_all, join_all_blocking, scoped_map, scoped_map_settled};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_scoped_map_borrows_and_keeps_order() {
+        let agents = vec!["a".to_string(), "bb".to_string(), "ccc".to_string()];
+        let calls = AtomicUsize::new(0);
+        let lengths = scoped_map(&agents, |a| a.clone(), |a| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            a.len()
+        })
+        .unwrap();
+        assert_eq!(lengths, vec![1, 2, 3]);
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_every_panic_is_reported_and_siblings_finish() {
+        let items = vec![0, 1, 2, 3];
+        let finished = AtomicUsize::new(0);
+        let failures = scoped_map(&items, |i| format!("agent-{}", i), |&i| {
+            if i % 2 == 1 {
+                panic!("agent {} exploded", i);
+            }
+            finished.fetch_add(1, Ordering::SeqCst);
+            i
+        })
+        .unwrap_err();
+        assert_eq!(finished.load(Ordering::SeqCst), 2);
+        assert_eq!(failures.0.iter().map(|f| f.index).collect::<Vec<_>>(), vec![1, 3]);
+        assert_eq!(failures.0[0].label, "agent-1");
+        assert_eq!(failures.0[1].message, "agent 3 exploded");
+
+        let settled = scoped_map_settled(&items, |i| i.to_string(), |&i| if i == 2 { panic!("no") } else { i * 10 });
+        assert_eq!(settled[0], Ok(0));
+        assert!(settled[2].is_err());
+        assert_eq!(settled[3], Ok(30));
+    }
+
+    async fn answer(fail: bool) -> i32 {
+        if fail {
+            panic!("async boom");
+        }
+        1
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn test_tokio_variants_collect_panics() {
+        let tasks = vec![("ok".to_string(), answer(false)), ("boom".to_string(), answer(true))];
+        let failures = join_all(tasks).await.unwrap_err();
+        assert_eq!(failures.0.len(), 1);
+        assert_eq!((failures.0[0].index, failures.0[0].label.as_str()), (1, "boom"));
+
+        let jobs: Vec<(String, fn() -> i32)> = vec![("one".to_string(), || 1), ("two".to_string(), || 2)];
+        assert_eq!(join_all_blocking(jobs).await.unwrap(), vec![1, 2]);
+    }
+}
+```
@@ -0,0 +1,65 @@
+### Tests for the conversation scratchpad
+Reasoning must reach the model but stay out of everything the user or another agent reads, and out of
+saved copies unless persisting it was asked for.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::{split_scratchpad, Channel, Conversation, CHAIN_OF_THOUGHT_PROMPT};
+
+    const REPLY: &str = "<scratchpad>The user wants a total. 3 + 4 = 7.</scratchpad>The total is 7.";
+
+    #[test]
+    fn test_split_scratchpad() {
+        let (thoughts, answer) = split_scratchpad(REPLY);
+        assert_eq!((thoughts, answer.as_str()), (vec!["The user wants a total. 3 + 4 = 7.".to_string()], "The total is 7."));
+
+        let (thoughts, answer) = split_scratchpad("Intro. <scratchpad>a</scratchpad> Body. <scratchpad>cut off mid");
+        assert_eq!(thoughts, vec!["a".to_string(), "cut off mid".to_string()]);
+        assert_eq!(answer, "Intro.  Body.");
+
+        assert_eq!(split_scratchpad("Plain answer."), (Vec::new(), "Plain answer.".to_string()));
+    }
+
+    #[test]
+    fn test_scratchpad_is_model_only() {
+        let mut conversation = Conversation::builder().chain_of_thoughts(true).build().unwrap();
+        conversation.add("user".to_string(), "Add 3 and 4.".to_string());
+        let answer = conversation.add_reply("assistant".to_string(), REPLY);
+        assert_eq!(answer, "The total is 7.");
+
+        assert!(conversation.history_for_model().contains("assistant (scratchpad): The user wants a total."));
+        assert!(conversation.history_for_model().contains(CHAIN_OF_THOUGHT_PROMPT));
+        let visible = conversation.return_history_as_string();
+        assert!(visible.contains("The total is 7.") && !visible.contains("3 + 4"));
+        assert!(conversation.search("3 + 4".to_string()).is_empty());
+        assert_eq!(conversation.count_messages_by_role().get("assistant"), Some(&1));
+        assert_eq!(conversation.scratchpad().len(), 1);
+
+        conversation.clear_scratchpad();
+        assert!(conversation.scratchpad().is_empty());
+        assert_eq!(conversation.messages_for_model().len(), 3);
+    }
+
+    #[test]
+    fn test_saved_copies() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("conversation.json");
+        let path = path.to_str().unwrap();
+
+        let mut conversation = Conversation::builder().build().unwrap();
+        conversation.add_reply("assistant".to_string(), REPLY);
+        conversation.save_as_json(path);
+        assert!(!std::fs::read_to_string(path).unwrap().contains("scratchpad"));
+
+        let mut debugging = Conversation::builder().persist_scratchpad(true).build().unwrap();
+        debugging.add_reply("assistant".to_string(), REPLY);
+        debugging.save_as_json(path);
+        let mut loaded = Conversation::builder().build().unwrap();
+        loaded.load_from_json(path);
+        assert_eq!(loaded.scratchpad().len(), 1);
+        assert_eq!(loaded.scratchpad()[0].channel, Channel::Scratchpad);
+        assert_eq!(loaded.return_history_as_string(), "assistant: The total is 7.");
+    }
+}
+```
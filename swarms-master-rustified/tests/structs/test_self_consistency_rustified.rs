@@ -0,0 +1,90 @@
+### Tests for self-consistency
+Answers must be extracted from each reasoning path, counted with the majority-voting consensus strategies,
+and the modal answer returned with the share of answering samples that agreed, with failed samples recorded.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::majority_voting::{Consensus, Vote};
+    use crate::swarms::structs::self_consistency::{extract_answer, self_consistency, SelfConsistencyConfig};
+
+    #[test]
+    fn test_extract_answer() {
+        assert_eq!(extract_answer("2 + 2 = 4\nFinal answer: **4**").as_deref(), Some("4"));
+        assert_eq!(extract_answer("So the answer: 7, not 8.\nFINAL ANSWER: 8").as_deref(), Some("8"));
+        assert_eq!(extract_answer("The result is \\boxed{\\frac{1}{2}} overall").as_deref(), Some("\\frac{1}{2}"));
+        assert_eq!(extract_answer("Let me think.\n\nParis\n").as_deref(), Some("Paris"));
+        assert_eq!(extract_answer("Final answer:   "), None);
+        assert_eq!(extract_answer(""), None);
+    }
+
+    #[test]
+    fn test_consensus_tally() {
+        let answers: Vec<String> = ["Paris.", "London", "paris", "London", " PARIS "].iter().map(|a| a.to_string()).collect();
+        assert_eq!(Consensus::Normalized.winner(&answers), Some(Vote { answer: "Paris.".to_string(), count: 3 }));
+        let exact = Consensus::Exact.tally(&answers);
+        assert_eq!(exact[0], Vote { answer: "London".to_string(), count: 2 });
+        assert_eq!(exact.len(), 4);
+        let tied: Vec<String> = vec!["b".to_string(), "a".to_string()];
+        assert_eq!(Consensus::Exact.winner(&tied).unwrap().answer, "b");
+        assert_eq!(Consensus::Exact.winner(&[]), None);
+    }
+
+    #[test]
+    fn test_modal_answer_and_confidence() {
+        let replies = [
+            "6 * 7 = 42\nFinal answer: 42",
+            "6 * 7 = 36\nFinal answer: 36",
+            "Seven sixes\nFinal answer: 42.",
+            "I cannot decide",
+            "Doubling 21\nFinal answer: 42",
+        ];
+        let config = SelfConsistencyConfig { temperature: 0.9, ..SelfConsistencyConfig::default() };
+        let run = self_consistency("What is 6 * 7?", &config, |request| {
+            assert_eq!(request.temperature, 0.9);
+            assert!(request.prompt.starts_with("What is 6 * 7?") && request.prompt.contains("Final answer:"));
+            match request.index {
+                3 => Err("rate limited".to_string()),
+                index => Ok(replies[index].to_string()),
+            }
+        });
+        assert_eq!(run.answer.as_deref(), Some("42"));
+        assert_eq!(run.votes[0].count, 3);
+        assert!((run.confidence - 0.75).abs() < 1e-6);
+        assert_eq!(run.paths.len(), 5);
+        assert_eq!(run.paths[3].error.as_deref(), Some("rate limited"));
+        assert!(serde_json::to_value(&run).unwrap()["votes"].is_array());
+    }
+
+    #[test]
+    fn test_panics_and_no_answers() {
+        let config = SelfConsistencyConfig { samples: 2, ..SelfConsistencyConfig::default() };
+        let run = self_consistency("Anything", &config, |request| -> Result<String, String> {
+            if request.index == 0 {
+                panic!("model client crashed");
+            }
+            Err("timeout".to_string())
+        });
+        assert_eq!((run.answer, run.confidence), (None, 0.0));
+        assert!(run.paths[0].error.as_deref().unwrap().contains("crashed"));
+    }
+
+    #[test]
+    fn test_agent_and_schema_wiring() {
+        assert_eq!(SelfConsistencyConfig::from_schema(&AgentSchema::default()), None);
+        let schema = AgentSchema { algorithm_of_thoughts: Some(true), ..AgentSchema::default() };
+        let config = SelfConsistencyConfig::from_schema(&schema).unwrap();
+        assert_eq!(config.samples, 5);
+
+        let config = SelfConsistencyConfig { samples: 3, consensus: Consensus::Exact, ..config };
+        let agent = LlmAgent::builder().name("Solver").llm("gpt-4o").algorithm_of_thoughts(config).build().unwrap();
+        let run = agent.self_consistency("Capital of France?", |request| {
+            Ok(if request.index == 1 { "Answer: paris" } else { "Answer: Paris" }.to_string())
+        });
+        assert_eq!(run.votes.len(), 2);
+        assert_eq!(run.answer.as_deref(), Some("Paris"));
+    }
+}
+```
@@ -0,0 +1,64 @@
+### Tests for step panic isolation
+A panicking step or tool must be recorded as failed while the caller keeps running, and a failing step must
+be recorded with the code its error is classified with.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::failure::FailureCode;
+    use crate::swarms::structs::step_guard::{guard_fallible_step, guard_step, guard_step_async, StepStatus};
+    use crate::swarms::tools::base_tool::ToolRegistry;
+    use crate::swarms::utils::chat_provider::ProviderError;
+    use serde_json::json;
+
+    #[test]
+    fn test_panic_becomes_record() {
+        let (output, record) = guard_step("Analyst", "parse", || -> String { panic!("index out of range") });
+        assert!(output.is_none());
+        assert_eq!(record.status, StepStatus::Panicked { message: "index out of range".to_string() });
+
+        let (output, record) = guard_step("Analyst", "parse", || 42);
+        assert_eq!(output, Some(42));
+        assert!(record.status.is_success());
+
+        let timeout = ProviderError::Status { status: 504, body: "gateway timeout".to_string() };
+        let (output, record) = guard_fallible_step("Analyst", "fetch", || Err::<(), _>(timeout));
+        assert!(output.is_none());
+        assert_eq!(record.status.code(), Some(FailureCode::ProviderTimeout));
+        assert!(record.status.is_retriable());
+
+        // A tool that timed out failed as a tool; retrying the model call will not fix it
+        let (_, record) = guard_fallible_step("Analyst", "tool:search", || Err::<(), _>("tool 'search' timed out"));
+        assert_eq!(record.status, StepStatus::failed(FailureCode::ToolError, "tool 'search' timed out"));
+        assert!(!record.status.is_retriable());
+    }
+
+    #[test]
+    fn test_record_serializes_flat() {
+        let (_, record) = guard_step("Analyst", "tool:search", || -> () { panic!("boom") });
+        let value = serde_json::to_value(&record).unwrap();
+        assert_eq!(value["status"], "panicked");
+        assert_eq!(value["message"], "boom");
+        assert_eq!(value["step"], "tool:search");
+    }
+
+    #[test]
+    fn test_panicking_tool_returns_error() {
+        let registry = ToolRegistry::builder()
+            .tool("broken", "Always panics", json!({ "type": "object" }), |_| panic!("bad unwrap"))
+            .tool("echo", "Echoes", json!({ "type": "object" }), |params| Ok(params.clone()))
+            .build()
+            .unwrap();
+        let err = registry.execute_tool_by_name("broken", &json!({})).unwrap_err();
+        assert_eq!(err.to_string(), "Tool 'broken' panicked: bad unwrap");
+        assert_eq!(registry.execute_tool_by_name("echo", &json!({ "x": 1 })).unwrap(), json!({ "x": 1 }));
+    }
+
+    #[tokio::test]
+    async fn test_async_step_panic_is_contained() {
+        let (output, record) = guard_step_async("Writer", "draft", async { panic!("hook failed") }).await;
+        let _: Option<()> = output;
+        assert_eq!(record.status, StepStatus::Panicked { message: "hook failed".to_string() });
+    }
+}
+```
@@ -0,0 +1,95 @@
+### Tests for stuck-task detection
+A task that stops sending heartbeats past its expected duration must be cancelled and reported once, while
+one that keeps beating is left alone. Requeued turns must run again up to the attempt limit, and a
+sequential run must not hang on a stuck agent.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, LlmAgent};
+    use crate::swarms::structs::callbacks::RunCallbacks;
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::structs::supervisor::{StuckAction, Supervisor, TaskStuck};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    #[derive(Default)]
+    struct Recorder(Mutex<Vec<TaskStuck>>);
+
+    impl RunCallbacks for Recorder {
+        fn on_task_stuck(&self, event: &TaskStuck) {
+            self.0.lock().unwrap().push(event.clone());
+        }
+    }
+
+    #[test]
+    fn test_silent_task_is_cancelled_once() {
+        let recorder = Arc::new(Recorder::default());
+        let supervisor = Supervisor::new("pipeline")
+            .with_expected_duration(Duration::from_millis(40))
+            .with_agent_expected_duration("Slow", Duration::from_secs(600))
+            .with_callbacks(recorder.clone());
+        let silent = supervisor.begin("Researcher", "collect filings", 1);
+        let slow = supervisor.begin("Slow", "long report", 1);
+        let beating = supervisor.begin("Writer", "draft", 1);
+        assert!(supervisor.check(Instant::now()).is_empty());
+
+        thread::sleep(Duration::from_millis(60));
+        beating.beat();
+        let events = supervisor.check(Instant::now());
+        assert_eq!(events.len(), 1, "{:?}", events);
+        assert_eq!((events[0].task_id.as_str(), events[0].action), (silent.task_id(), StuckAction::Cancel));
+        assert!(events[0].silent_ms >= 60);
+        assert!(silent.is_cancelled());
+        assert_eq!((slow.stuck(), beating.stuck()), (None, None));
+        assert!(supervisor.check(Instant::now()).is_empty(), "a stuck task is reported once");
+        assert_eq!(recorder.0.lock().unwrap().len(), 1);
+
+        drop((silent, beating));
+        assert_eq!(supervisor.in_flight(), 1);
+    }
+
+    #[test]
+    fn test_requeue_until_attempts_run_out() {
+        let supervisor = Supervisor::new("pipeline").with_action(StuckAction::Requeue).with_max_attempts(2);
+        let first = supervisor.begin("Researcher", "collect filings", 1);
+        let second = supervisor.begin("Researcher", "collect filings", 2);
+        supervisor.check(Instant::now() + Duration::from_secs(3600));
+        assert_eq!(first.stuck(), Some(StuckAction::Requeue));
+        assert_eq!(second.stuck(), Some(StuckAction::Cancel));
+    }
+
+    #[test]
+    fn test_sequential_run_survives_stuck_turn() {
+        let agents = ["Researcher", "Writer"]
+            .iter()
+            .map(|name| LlmAgent::builder().name(*name).llm("gpt-4o").build().unwrap().into())
+            .collect();
+        let supervisor = Arc::new(
+            Supervisor::new("Research-Pipeline")
+                .with_expected_duration(Duration::from_millis(30))
+                .with_action(StuckAction::Requeue),
+        );
+        let workflow = SequentialWorkflow::new("Research-Pipeline".into(), String::new(), agents, 1, "str".into(), false, || ())
+            .with_supervisor(Arc::clone(&supervisor));
+        let _watchdog = supervisor.watch(Duration::from_millis(5));
+
+        let stages = workflow
+            .run_with_heartbeats("quarterly report", |agent, input, heartbeat| {
+                if agent.name() == "Researcher" && heartbeat.attempt() == 1 {
+                    // Hangs without beating until the supervisor cancels it
+                    while !heartbeat.is_cancelled() {
+                        thread::sleep(Duration::from_millis(5));
+                    }
+                    return Ok("late and discarded".to_string());
+                }
+                heartbeat.beat();
+                Ok(format!("{} <- {}", agent.name(), input))
+            })
+            .unwrap();
+        assert_eq!(stages[0].output, "Researcher <- quarterly report");
+        assert_eq!(stages[1].output, "Writer <- Researcher <- quarterly report");
+    }
+}
+```
@@ -0,0 +1,55 @@
+### Tests for the Swarm trait
+Checks the default batch behaviour and the error conversions every implementation relies on.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::swarms::structs::scoped_tasks::TaskFailure;
+
+    struct Counting {
+        runs: usize,
+        fail_on: Option<&'static str>,
+    }
+
+    impl Swarm for Counting {
+        fn name(&self) -> &str {
+            "counting"
+        }
+
+        fn run(&mut self, task: &str) -> Result<String, SwarmError> {
+            self.runs += 1;
+            if Some(task) == self.fail_on {
+                return Err(SwarmError::Failed(format!("cannot do {}", task)));
+            }
+            Ok(task.to_uppercase())
+        }
+    }
+
+    #[test]
+    fn test_run_batch_stops_at_first_failure() {
+        let tasks = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        let mut swarm = Counting { runs: 0, fail_on: None };
+        assert_eq!(swarm.run_batch(&tasks).unwrap(), vec!["A", "B", "C"]);
+
+        let mut swarm = Counting { runs: 0, fail_on: Some("b") };
+        let err = swarm.run_batch(&tasks).unwrap_err();
+        assert_eq!(err.to_string(), "swarm failed: cannot do b");
+        assert_eq!(swarm.runs, 2);
+    }
+
+    #[test]
+    fn test_task_failures_convert() {
+        let failures = TaskFailures(vec![TaskFailure { index: 1, label: "Critic".to_string(), message: "boom".to_string() }]);
+        let err: SwarmError = failures.into();
+        assert_eq!(err.to_string(), "1 task(s) failed; [1] Critic: boom");
+    }
+
+    #[test]
+    fn test_majority_voting_without_agents() {
+        let mut swarm = MajorityVoting::new("vote", "empty", Vec::new(), None, false, false);
+        assert!(matches!(Swarm::run(&mut swarm, "2 + 2?"), Err(SwarmError::NoAgents)));
+        assert_eq!(swarm.name(), "vote");
+    }
+}
+```
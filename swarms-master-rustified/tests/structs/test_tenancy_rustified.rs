@@ -0,0 +1,77 @@
+### Tests for tenant isolation
+Tenant ids must be safe path components, the tenant must come from the API key, workspace paths must stay
+inside the tenant's directory, and the run store must never hand one tenant's tasks to another.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::problem::Problem;
+    use crate::swarms::structs::tenancy::{TenantError, TenantId, TenantKeys, TenantWorkspace};
+    use std::path::Path;
+
+    #[test]
+    fn test_tenant_id_validation() {
+        assert_eq!(TenantId::new("acme-corp_2").unwrap().as_str(), "acme-corp_2");
+        for bad in ["", "../acme", "acme/runs", "acme corp", &"a".repeat(TenantId::MAX_LEN + 1)] {
+            assert_eq!(TenantId::new(bad), Err(TenantError::InvalidId(bad.to_string())));
+        }
+        assert!(serde_json::from_str::<TenantId>(r#""../etc""#).is_err());
+        assert_eq!(serde_json::to_string(&TenantId::new("acme").unwrap()).unwrap(), r#""acme""#);
+    }
+
+    #[test]
+    fn test_workspace_paths_stay_inside_the_tenant() {
+        let workspace = TenantWorkspace::new("/srv/ws");
+        let acme = TenantId::new("acme").unwrap();
+        assert_eq!(workspace.runs_dir(&acme), Path::new("/srv/ws/tenants/acme/runs"));
+        assert_eq!(
+            workspace.conversation_path(&acme, "onboarding").unwrap(),
+            Path::new("/srv/ws/tenants/acme/conversations/onboarding.json")
+        );
+        assert!(workspace.resolve(&acme, "artifacts/./report.md").is_ok());
+        for escape in ["../globex/runs", "/etc/passwd", "artifacts/../../globex", ""] {
+            assert!(matches!(workspace.resolve(&acme, escape), Err(TenantError::PathEscape { .. })), "{}", escape);
+        }
+        assert!(workspace.conversation_path(&acme, "../../globex/secret").is_err());
+        assert_eq!(workspace.artifact_path(&acme, "report.md").unwrap(), Path::new("/srv/ws/tenants/acme/artifacts/report.md"));
+        assert!(workspace.artifact_path(&acme, "../runs/checkpoint.json").is_err());
+        assert!(workspace.spillover(&acme).dir().starts_with("/srv/ws/tenants/acme/artifacts"));
+    }
+
+    #[test]
+    fn test_tenant_comes_from_the_api_key() {
+        let (acme, globex) = (TenantId::new("acme").unwrap(), TenantId::new("globex").unwrap());
+        let mut keys = TenantKeys::new().with_key("sk-acme", acme.clone()).with_key("sk-globex", globex.clone());
+        assert_eq!(keys.authenticate(Some("Bearer sk-acme")), Ok(acme));
+        assert_eq!(keys.authenticate(Some("sk-globex")), Ok(globex));
+        for bad in [None, Some(""), Some("Bearer "), Some("Bearer sk-initech"), Some("acme")] {
+            assert_eq!(keys.authenticate(bad), Err(TenantError::Unauthenticated), "{:?}", bad);
+        }
+        assert_eq!(Problem::from(&TenantError::Unauthenticated).status, 401);
+        assert!(keys.revoke("sk-acme"));
+        assert_eq!(keys.authenticate(Some("Bearer sk-acme")), Err(TenantError::Unauthenticated));
+    }
+
+    #[cfg(feature = "server")]
+    #[test]
+    fn test_ledger_is_tenant_scoped() {
+        use crate::swarms::structs::distributed_queue::TaskLedger;
+        use std::time::{Duration, Instant};
+
+        let (acme, globex) = (TenantId::new("acme").unwrap(), TenantId::new("globex").unwrap());
+        let mut ledger = TaskLedger::new(Duration::from_secs(30));
+        let acme_task = ledger.submit(&acme, "Analyst", "acme task");
+        let globex_task = ledger.submit(&globex, "Analyst", "globex task");
+
+        // Same agent name, different tenants: each worker only sees its own tenant's task.
+        let globex_worker = ledger.register(&globex, "w-globex", vec!["Analyst".to_string()]);
+        let claimed = ledger.claim(&globex_worker, Instant::now()).unwrap();
+        assert_eq!(claimed.task_id, globex_task);
+        assert!(ledger.claim(&globex_worker, Instant::now()).is_none());
+
+        assert!(ledger.record(&globex, &acme_task).is_none());
+        assert_eq!(ledger.record(&acme, &acme_task).unwrap().task, "acme task");
+        assert_eq!(ledger.records(&acme).len(), 1);
+    }
+}
+```
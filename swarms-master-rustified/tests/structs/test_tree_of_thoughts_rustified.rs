@@ -0,0 +1,81 @@
+### Tests for tree-of-thoughts search
+The search must branch, keep only the best beam, stop at the configured depth, and pick the best leaf;
+the model-facing helpers must parse typical replies.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::structs::tree_of_thoughts::{
+        evaluation_prompt, parse_candidates, parse_score, search, ThoughtContext, TreeOfThoughtsConfig,
+    };
+
+    // Each thought is its path of choices, e.g. "a/b"; the thought ending in the highest letters scores best.
+    fn generate(context: &ThoughtContext) -> Vec<String> {
+        let prefix = context.path.last().map(|last| format!("{}/", last)).unwrap_or_default();
+        ["a", "b", "c", "c"].iter().map(|choice| format!("{}{}", prefix, choice)).collect()
+    }
+
+    fn evaluate(_context: &ThoughtContext, candidate: &str) -> f32 {
+        candidate.split('/').map(|choice| (choice.as_bytes()[0] - b'a') as f32).sum::<f32>() / 10.0
+    }
+
+    #[test]
+    fn test_beam_search() {
+        let config = TreeOfThoughtsConfig { branching: 3, beam: 2, depth: 3, min_score: 0.0 };
+        let tree = search("Pick letters", &config, generate, evaluate);
+
+        // Root, 3 children, then 3 for each of the 2 kept nodes at depths 2 and 3. The duplicate "c" is merged.
+        assert_eq!(tree.nodes.len(), 1 + 3 + 6 + 6);
+        assert_eq!(tree.children(0).count(), 3);
+        assert_eq!(tree.nodes.iter().filter(|node| node.expanded).count(), 1 + 2 + 2);
+        assert_eq!(tree.answer(), Some("c/c/c"));
+        let path: Vec<&str> = tree.path(tree.best_leaf.unwrap()).iter().map(|node| node.thought.as_str()).collect();
+        assert_eq!(path, vec!["c", "c/c", "c/c/c"]);
+        assert_eq!(serde_json::to_value(&tree).unwrap()["nodes"].as_array().unwrap().len(), 16);
+    }
+
+    #[test]
+    fn test_pruning_and_dead_ends() {
+        let config = TreeOfThoughtsConfig { branching: 3, beam: 3, depth: 3, min_score: 0.15 };
+        let tree = search("Pick letters", &config, generate, evaluate);
+        assert!(tree.nodes.iter().filter(|node| node.score < 0.15).all(|node| !node.expanded));
+
+        // A generator that stops producing after the first step leaves the first-level nodes as leaves.
+        let tree = search(
+            "Pick letters",
+            &TreeOfThoughtsConfig::default(),
+            |context| if context.depth == 1 { generate(context) } else { Vec::new() },
+            evaluate,
+        );
+        assert_eq!(tree.answer(), Some("c"));
+    }
+
+    #[test]
+    fn test_prompt_helpers() {
+        let context = ThoughtContext { task: "Make 24", path: vec!["6 * 4 = 24"], depth: 2, branching: 3 };
+        assert!(context.prompt().contains("1. 6 * 4 = 24") && context.prompt().contains("Propose 3"));
+        assert!(evaluation_prompt("Make 24", &context.path, "Done").contains("Proposed next step: Done"));
+        assert_eq!(parse_candidates("1. Try 9 - 6\n2) Try 4 + 6\n\n- Try 9 * 4"), vec!["Try 9 - 6", "Try 4 + 6", "Try 9 * 4"]);
+        assert_eq!(parse_score("Score: 7.5/10"), Some(0.75));
+        assert_eq!(parse_score("12"), Some(1.0));
+        assert_eq!(parse_score("no idea"), None);
+    }
+
+    #[test]
+    fn test_agent_wiring() {
+        assert_eq!(TreeOfThoughtsConfig::from_schema(&AgentSchema::default()), None);
+        let schema = AgentSchema { tree_of_thoughts: Some(true), ..AgentSchema::default() };
+        let config = TreeOfThoughtsConfig::from_schema(&schema).unwrap();
+        let agent = LlmAgent::builder()
+            .name("Solver")
+            .llm("gpt-4o")
+            .tree_of_thoughts(TreeOfThoughtsConfig { depth: 1, ..config })
+            .build()
+            .unwrap();
+        let tree = agent.search_thoughts("Pick letters", generate, evaluate);
+        assert_eq!((tree.answer(), tree.nodes.len()), (Some("c"), 4));
+    }
+}
+```
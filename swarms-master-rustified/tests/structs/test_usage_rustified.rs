@@ -0,0 +1,97 @@
+### Tests for usage tracking and cost reports
+Recorded calls must round-trip through the log and aggregate per run, day, and week by agent, model, and
+tag, with prices applied and unknown models flagged.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::UsageInfo;
+    use crate::swarms::structs::usage::{
+        CostReport, Pricing, ReportDimension, ReportPeriod, UsageRecord, UsageScope, UsageTracker, UNTAGGED,
+    };
+    use chrono::{TimeZone, Utc};
+
+    fn record(day: u32, run: &str, agent: &str, model: &str, tags: &[&str], prompt: u64, completion: u64) -> UsageRecord {
+        UsageRecord {
+            timestamp: Utc.with_ymd_and_hms(2026, 10, day, 12, 0, 0).unwrap(),
+            run_id: run.to_string(),
+            agent: agent.to_string(),
+            model: model.to_string(),
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+            prompt_tokens: prompt,
+            completion_tokens: completion,
+            estimated: false,
+        }
+    }
+
+    fn records() -> Vec<UsageRecord> {
+        vec![
+            record(12, "run-1", "Accountant", "gpt-4o-mini", &["client:acme"], 1_000_000, 0),
+            record(12, "run-1", "Auditor", "gpt-4o", &["client:acme", "audit"], 0, 100_000),
+            record(13, "run-2", "Accountant", "gpt-4o-mini", &[], 500_000, 500_000),
+            record(20, "run-3", "Accountant", "llama-local", &[], 10, 10),
+        ]
+    }
+
+    fn pricing() -> Pricing {
+        Pricing::new().model("gpt-4o-mini", 0.15, 0.60).model("gpt-4o", 2.50, 10.00)
+    }
+
+    #[test]
+    fn test_tracker_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let tracker = UsageTracker::new(dir.path().join("usage").join("usage.jsonl"));
+        assert!(tracker.records().unwrap().is_empty());
+        let scope = UsageScope::new(tracker.clone(), "run-9").tag("client:acme");
+        scope.record("Accountant", "gpt-4o-mini", 120, 30).unwrap();
+        scope.record_usage("Accountant", "llama-3.1-8b", &UsageInfo::estimated(80, 20)).unwrap();
+        let stored = tracker.records().unwrap();
+        assert_eq!(stored.len(), 2);
+        assert_eq!((stored[0].run_id.as_str(), stored[0].tags.clone()), ("run-9", vec!["client:acme".to_string()]));
+        assert_eq!((stored[1].prompt_tokens, stored[1].completion_tokens, stored[1].estimated), (80, 20, true));
+        assert!(!stored[0].estimated);
+    }
+
+    #[test]
+    fn test_daily_report_by_agent() {
+        let report = CostReport::build(&records(), ReportPeriod::Day, ReportDimension::Agent, &pricing());
+        let rows: Vec<_> = report.rows.iter().map(|row| (row.period.as_str(), row.key.as_str(), row.calls)).collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("2026-10-12", "Accountant", 1),
+                ("2026-10-12", "Auditor", 1),
+                ("2026-10-13", "Accountant", 1),
+                ("2026-10-20", "Accountant", 1)
+            ]
+        );
+        assert!((report.rows[0].cost_usd - 0.15).abs() < 1e-9);
+        assert!((report.rows[1].cost_usd - 1.0).abs() < 1e-9);
+        assert!((report.rows[2].cost_usd - 0.375).abs() < 1e-9);
+        assert_eq!(report.unpriced_models, vec!["llama-local".to_string()]);
+        assert!((report.total_cost() - 1.525).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_weekly_report_by_tag() {
+        let report = CostReport::build(&records(), ReportPeriod::Week, ReportDimension::Tag, &pricing());
+        let rows: Vec<_> = report.rows.iter().map(|row| (row.period.as_str(), row.key.as_str(), row.runs)).collect();
+        assert_eq!(
+            rows,
+            vec![("2026-W42", UNTAGGED, 1), ("2026-W42", "audit", 1), ("2026-W42", "client:acme", 1), ("2026-W43", UNTAGGED, 1)]
+        );
+        // The Auditor call counts towards both of its tags.
+        assert!((report.rows[2].cost_usd - 1.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_csv_export() {
+        let report = CostReport::build(&records(), ReportPeriod::Run, ReportDimension::Model, &pricing());
+        let csv = report.to_csv().unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "period,model,calls,runs,prompt_tokens,completion_tokens,cost_usd");
+        assert_eq!(lines[1], "run-1,gpt-4o,1,1,0,100000,1.000000");
+        assert_eq!(lines.len(), 5);
+    }
+}
+```
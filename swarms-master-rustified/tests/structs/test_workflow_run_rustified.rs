@@ -0,0 +1,96 @@
+### Tests for retrying one step of a run
+A recorded run must keep the input each step was sent, so that a failed step can be retried on that
+input without running the steps before it again. Resuming must run the steps downstream of a retried step
+that had not run or whose input changed, and nothing else. A run must survive a round trip through JSON.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::{Agent, LlmAgent};
+    use crate::swarms::structs::failure::FailureCode;
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::structs::step_guard::StepStatus;
+    use crate::swarms::structs::workflow_run::{Resume, RetryError, Run, RunStep};
+
+    const TASK: &str = "Draft the release notes for 2.4";
+
+    fn workflow(names: &[&str]) -> SequentialWorkflow {
+        let agents =
+            names.iter().map(|name| LlmAgent::builder().name(*name).llm("gpt-4o-mini").build().unwrap().into()).collect();
+        SequentialWorkflow::new("Release".into(), String::new(), agents, 1, "str".into(), false, || ())
+    }
+
+    fn reply(agent: &dyn Agent, input: &str) -> Result<String, String> {
+        Ok(format!("{}({})", agent.name(), input))
+    }
+
+    #[test]
+    fn test_sequential_retry_resumes_after_the_failed_turn() {
+        let workflow = workflow(&["Researcher", "Writer", "Editor"]);
+        let mut run = workflow.run_recorded(TASK, |agent, input| match agent.name() {
+            "Writer" => Err("provider returned 503".to_string()),
+            _ => reply(agent, input),
+        });
+        assert!(!run.is_complete());
+        assert_eq!(run.output(), None);
+        let writer = run.get("Writer").unwrap();
+        assert_eq!(writer.input.as_deref(), Some("Researcher(Draft the release notes for 2.4)"));
+        assert!(matches!(&writer.status, Some(StepStatus::Failed { error, .. }) if error == "provider returned 503"));
+        assert_eq!(run.get("Editor").unwrap().status, None, "the turns after a failure wait");
+        assert_eq!(run.failed_steps().map(|step| step.id.as_str()).collect::<Vec<_>>(), vec!["Writer"]);
+
+        let mut called = Vec::new();
+        let ran = workflow.retry_step(&mut run, "Writer", Resume::Downstream, |agent, input| {
+            called.push(agent.name().to_string());
+            reply(agent, input)
+        });
+        assert_eq!(ran.unwrap(), vec!["Writer".to_string(), "Editor".to_string()]);
+        assert_eq!(called, vec!["Writer".to_string(), "Editor".to_string()], "the researcher is not run again");
+        assert_eq!(run.output(), Some("Editor(Writer(Researcher(Draft the release notes for 2.4)))"));
+        assert_eq!((run.get("Researcher").unwrap().attempts, run.get("Writer").unwrap().attempts), (1, 2));
+    }
+
+    #[test]
+    fn test_step_only_and_retry_errors() {
+        let workflow = workflow(&["Researcher", "Writer", "Researcher"]);
+        let mut run = workflow.run_recorded(TASK, |agent, _| Err(format!("{} is down", agent.name())));
+        let ids: Vec<&str> = run.steps.iter().map(|step| step.id.as_str()).collect();
+        assert_eq!(ids, vec!["Researcher", "Writer", "Researcher#2"]);
+
+        let ran = workflow.retry_step(&mut run, "Researcher", Resume::StepOnly, reply).unwrap();
+        assert_eq!(ran, vec!["Researcher".to_string()]);
+        assert_eq!(run.get("Writer").unwrap().status, None, "StepOnly leaves the next turn unrun");
+
+        let mut retry = |step: &str| workflow.retry_step(&mut run, step, Resume::Downstream, reply);
+        assert_eq!(retry("Researcher"), Err(RetryError::NotFailed("Researcher".into())));
+        assert_eq!(retry("Researcher#2"), Err(RetryError::NotFailed("Researcher#2".into())), "it never ran");
+        assert_eq!(retry("Critic"), Err(RetryError::UnknownStep("Critic".into())));
+    }
+
+    #[test]
+    fn test_resume_skips_steps_whose_input_did_not_change() {
+        let mut run = Run::new("Graph", TASK)
+            .step(RunStep::new("fetch", "Fetcher"))
+            .step(RunStep::new("audit", "Auditor").after(["fetch"]))
+            .step(RunStep::new("summary", "Summarizer").reading_upstream().after(["fetch", "audit"]));
+        run.execute(|step, input| match step.id.as_str() {
+            "fetch" => Err("timed out".to_string()),
+            _ => Ok(format!("{}: {}", step.id, input)),
+        });
+        assert_eq!(run.get("audit").unwrap().status, None);
+
+        let mut run = serde_json::from_str::<Run>(&serde_json::to_string(&run).unwrap()).unwrap();
+        let ran = run.retry_step("fetch", Resume::Downstream, |step, input| Ok(format!("{}: {}", step.id, input))).unwrap();
+        assert_eq!(ran, vec!["fetch", "audit", "summary"]);
+        let expected = "summary: fetch: Draft the release notes for 2.4\n\naudit: Draft the release notes for 2.4";
+        assert_eq!(run.output(), Some(expected));
+
+        // A retried step whose output changes reruns the summary that reads it, but not the audit that reads the task
+        run.steps[0].status = Some(StepStatus::failed(FailureCode::ValidationError, "rejected by the reviewer"));
+        let ran = run.retry_step("fetch", Resume::Downstream, |step, _| Ok(format!("{}: v2", step.id))).unwrap();
+        assert_eq!(ran, vec!["fetch", "summary"]);
+        assert_eq!((run.get("audit").unwrap().attempts, run.get("summary").unwrap().attempts), (1, 2));
+        assert_eq!(run.output(), Some("summary: fetch: v2\n\naudit: Draft the release notes for 2.4"));
+    }
+}
+```
@@ -0,0 +1,92 @@
+### Tests for chat templates
+Each built-in family must render a conversation into the prompt its models were trained on, model names
+must pick the right family, and templates loaded from files must take precedence over the built-in ones.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::text::chat_template::{ChatTemplate, ChatTemplateError, ChatTemplateRegistry, TemplateRole};
+
+    fn conversation() -> Conversation {
+        let mut conversation = Conversation::builder().system_prompt("Be brief.").build().unwrap();
+        conversation.add("User".to_string(), "Hi".to_string());
+        conversation.add("Analyst".to_string(), "Hello.".to_string());
+        conversation.add("User".to_string(), "Q3?".to_string());
+        conversation
+    }
+
+    #[test]
+    fn test_builtin_families_render() {
+        let conversation = conversation();
+        assert_eq!(
+            ChatTemplate::llama3().render(&conversation),
+            "<|begin_of_text|><|start_header_id|>system<|end_header_id|>\n\nBe brief.<|eot_id|>\
+             <|start_header_id|>user<|end_header_id|>\n\nHi<|eot_id|>\
+             <|start_header_id|>assistant<|end_header_id|>\n\nHello.<|eot_id|>\
+             <|start_header_id|>user<|end_header_id|>\n\nQ3?<|eot_id|>\
+             <|start_header_id|>assistant<|end_header_id|>\n\n"
+        );
+        // Mistral has no system role, so the system prompt opens the first instruction.
+        assert_eq!(
+            ChatTemplate::mistral().render(&conversation),
+            "<s>[INST] Be brief.\n\nHi [/INST]Hello.</s>[INST] Q3? [/INST]"
+        );
+        assert_eq!(
+            ChatTemplate::chatml().render(&conversation),
+            "<|im_start|>system\nBe brief.<|im_end|>\n<|im_start|>user\nHi<|im_end|>\n\
+             <|im_start|>assistant\nHello.<|im_end|>\n<|im_start|>user\nQ3?<|im_end|>\n<|im_start|>assistant\n"
+        );
+    }
+
+    #[test]
+    fn test_roles_and_placeholders() {
+        assert_eq!(TemplateRole::of("System:"), TemplateRole::System);
+        assert_eq!(TemplateRole::of("Human"), TemplateRole::User);
+        assert_eq!(TemplateRole::of("Financial-Analysis-Agent"), TemplateRole::Assistant);
+        let prompt = ChatTemplate::chatml().render_messages([("user", "print('{role}')")]);
+        assert!(prompt.contains("print('{role}')"));
+    }
+
+    #[test]
+    fn test_model_names_pick_the_family() {
+        let registry = ChatTemplateRegistry::builtin();
+        let family = |model| registry.for_model(model).map(|template| template.name.as_str());
+        assert_eq!(family("llama3.1:8b"), Some("llama3"));
+        assert_eq!(family("meta-llama/Meta-Llama-3-70B-Instruct"), Some("llama3"));
+        assert_eq!(family("mixtral:8x7b"), Some("mistral"));
+        assert_eq!(family("qwen2.5:14b"), Some("chatml"));
+        assert_eq!(family("phi3:mini"), None);
+    }
+
+    #[test]
+    fn test_file_templates_override_builtins() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("phi3.yaml"),
+            "name: phi3\nmodels: [phi3]\nsystem: \"<|system|>\\n{content}<|end|>\\n\"\n\
+             user: \"<|user|>\\n{content}<|end|>\\n\"\nassistant: \"<|assistant|>\\n{content}<|end|>\\n\"\n\
+             generation_prompt: \"<|assistant|>\\n\"\nstop: [\"<|end|>\"]\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("qwen.json"),
+            r#"{"name": "qwen-custom", "models": ["qwen"], "system": "S:{content}", "user": "U:{content}", "assistant": "A:{content}"}"#,
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("README.md"), "not a template").unwrap();
+
+        let mut registry = ChatTemplateRegistry::builtin();
+        assert_eq!(registry.load_dir(dir.path()).unwrap(), 2);
+        let phi = registry.for_model("phi3:mini").unwrap();
+        assert_eq!(phi.render_messages([("user", "Hi")]), "<|user|>\nHi<|end|>\n<|assistant|>\n");
+        assert_eq!(registry.for_model("qwen2.5:14b").unwrap().name, "qwen-custom");
+        assert_eq!(registry.get("chatml").unwrap().name, "chatml");
+
+        std::fs::write(dir.path().join("broken.yaml"), "name: broken\nsystem: x\nuser: \"{content}\"\nassistant: \"{content}\"\n")
+            .unwrap();
+        let err = registry.load_file(dir.path().join("broken.yaml")).unwrap_err();
+        assert!(matches!(err, ChatTemplateError::MissingContent { ref role, .. } if role == "system"));
+    }
+}
+```
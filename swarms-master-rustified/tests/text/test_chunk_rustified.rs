@@ -0,0 +1,103 @@
+### Tests for the chunking strategies
+The tests use the default heuristic tokenizer (~4 characters per token), so limits below are small. A
+counting tokenizer checks that hard-splitting a long unbroken unit does not tokenize once per character.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::text::chunk::{ChunkStrategy, Chunker};
+    use crate::swarms::text::tokenizer::{estimate_tokens, Tokenizer};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    const TEXT: &str = "One two three four five six seven eight nine ten. Eleven twelve! Thirteen?\n\nNew para here with words.";
+
+    #[test]
+    fn test_offsets_point_into_source() {
+        for strategy in [
+            ChunkStrategy::FixedSize { max_tokens: 5, overlap_tokens: 2 },
+            ChunkStrategy::Sentence { max_tokens: 8, overlap_tokens: 0 },
+            ChunkStrategy::RecursiveCharacter { max_tokens: 6, overlap_tokens: 1 },
+            ChunkStrategy::MarkdownHeader { max_tokens: 6, overlap_tokens: 0 },
+        ] {
+            let chunks = Chunker::new(strategy).chunk(TEXT, Some("doc.txt"));
+            assert!(!chunks.is_empty());
+            for (i, chunk) in chunks.iter().enumerate() {
+                assert_eq!(&TEXT[chunk.start..chunk.end], chunk.text);
+                assert_eq!(chunk.index, i);
+                assert_eq!(chunk.source.as_deref(), Some("doc.txt"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_fixed_size_respects_budget_and_overlaps() {
+        let chunks = Chunker::new(ChunkStrategy::FixedSize { max_tokens: 5, overlap_tokens: 2 }).chunk(TEXT, None);
+        for chunk in &chunks {
+            assert!(chunk.token_count <= 5, "{:?}", chunk);
+        }
+        for pair in chunks.windows(2) {
+            assert!(pair[1].start < pair[0].end, "consecutive chunks should overlap");
+        }
+    }
+
+    #[test]
+    fn test_sentence_keeps_sentences_whole() {
+        let chunks = Chunker::new(ChunkStrategy::Sentence { max_tokens: 20, overlap_tokens: 0 }).chunk(TEXT, None);
+        assert!(chunks.iter().all(|c| {
+            let t = c.text.trim_end();
+            t.ends_with('.') || t.ends_with('!') || t.ends_with('?')
+        }));
+    }
+
+    #[test]
+    fn test_markdown_heading_path() {
+        let md = "intro\n# A\ntext a\n## B\ntext b\n```\n# not a heading\n```\n# C\nc\n";
+        let chunks = Chunker::new(ChunkStrategy::MarkdownHeader { max_tokens: 100, overlap_tokens: 0 }).chunk(md, None);
+        let paths: Vec<Vec<String>> = chunks.iter().map(|c| c.heading_path.clone()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                vec![],
+                vec!["A".to_string()],
+                vec!["A".to_string(), "B".to_string()],
+                vec!["C".to_string()],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_oversized_word_is_hard_split() {
+        let word = "x".repeat(50);
+        let chunks = Chunker::new(ChunkStrategy::FixedSize { max_tokens: 4, overlap_tokens: 0 }).chunk(&word, None);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| c.token_count <= 4));
+        assert_eq!(chunks.iter().map(|c| c.text.as_str()).collect::<String>(), word);
+    }
+
+    #[derive(Default)]
+    struct Counting(AtomicUsize);
+
+    impl Tokenizer for Counting {
+        fn count_tokens(&self, text: &str) -> usize {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            estimate_tokens(text)
+        }
+    }
+
+    #[test]
+    fn test_hard_split_takes_the_longest_piece_that_fits() {
+        // A base64 blob or minified line: no separator to split on, multi-byte characters included.
+        let blob = "aé".repeat(5_000);
+        let tokenizer = Arc::new(Counting::default());
+        let chunker = Chunker::new(ChunkStrategy::RecursiveCharacter { max_tokens: 100, overlap_tokens: 0 })
+            .with_tokenizer(tokenizer.clone());
+        let chunks = chunker.chunk(&blob, None);
+
+        assert_eq!(chunks.iter().map(|c| c.text.as_str()).collect::<String>(), blob);
+        assert!(chunks.iter().all(|c| c.token_count == 100), "every piece should be full");
+        assert_eq!(chunks.len(), 25);
+        assert!(tokenizer.0.load(Ordering::Relaxed) < 1_000, "{} tokenizer calls", tokenizer.0.load(Ordering::Relaxed));
+    }
+}
+```
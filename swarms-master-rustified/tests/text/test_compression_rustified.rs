@@ -0,0 +1,51 @@
+### Tests for context compression
+Each stage is checked on its own: dedup across passages, query-driven sentence selection, and word
+pruning that keeps query terms and numbers.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::text::compression::{split_sentences, CompressionLevel, ContextCompressor};
+
+    #[test]
+    fn test_dedup_across_passages() {
+        let passages = vec![
+            "Revenue rose.\n\nThis report contains forward-looking statements.".to_string(),
+            "this report  contains Forward-looking statements.\n\nMargins fell.".to_string(),
+        ];
+        let compressed = ContextCompressor::new(1_000).level(CompressionLevel::Dedup).compress("revenue", &passages);
+        assert_eq!(compressed.passages, vec![
+            "Revenue rose. This report contains forward-looking statements.".to_string(),
+            "Margins fell.".to_string(),
+        ]);
+        assert!(compressed.tokens_after < compressed.tokens_before);
+    }
+
+    #[test]
+    fn test_extractive_keeps_relevant_sentences_in_order() {
+        let passages = vec![
+            "The weather in Cupertino was mild. Services revenue grew 14% on subscriptions. The campus has a pond.".to_string(),
+            "Hardware sales were flat. Analysts expect services growth to continue.".to_string(),
+        ];
+        let compressed = ContextCompressor::new(20).compress("How fast did services revenue grow?", &passages);
+        assert_eq!(compressed.passages[0], "Services revenue grew 14% on subscriptions.");
+        assert_eq!(compressed.passages[1], "");
+        assert!(compressed.tokens_after <= 20);
+    }
+
+    #[test]
+    fn test_token_pruning_drops_stopwords_first() {
+        let passages = vec!["The revenue of the company was 81.8 billion in the quarter.".to_string()];
+        let compressed = ContextCompressor::new(10).level(CompressionLevel::TokenPruning).compress("revenue", &passages);
+        let text = &compressed.passages[0];
+        assert!(text.contains("revenue") && text.contains("81.8"), "{}", text);
+        assert!(!text.contains(" of ") && !text.contains(" was "), "{}", text);
+        assert!(compressed.ratio() < 1.0);
+    }
+
+    #[test]
+    fn test_split_sentences_keeps_decimals() {
+        assert_eq!(split_sentences("Up 2.5% today. Really? Yes"), vec!["Up 2.5% today.", "Really?", "Yes"]);
+    }
+}
+```
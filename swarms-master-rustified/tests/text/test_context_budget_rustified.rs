@@ -0,0 +1,55 @@
+### Tests for the context budget planner
+Allocation must never exceed the input budget, must honor floors before weights, and must hand unused
+share from a satisfied section to the others.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::text::context_budget::{BudgetError, ContextBudget, ContextDemand, ContextSection};
+
+    #[test]
+    fn test_everything_fits() {
+        let budget = ContextBudget::new(16_000, 2_000);
+        let demand = ContextDemand { system_prompt: 500, tools: 300, memory: 1_000, history: 4_000 };
+        let allocation = budget.allocate(&demand).unwrap();
+        assert_eq!((allocation.tools, allocation.memory, allocation.history), (300, 1_000, 4_000));
+    }
+
+    #[test]
+    fn test_weights_share_the_rest_and_satisfied_sections_release_share() {
+        let budget = ContextBudget::new(20_000, 4_000);
+        let demand = ContextDemand { system_prompt: 1_000, tools: 600, memory: 50_000, history: 50_000 };
+        let allocation = budget.allocate(&demand).unwrap();
+        assert_eq!(allocation.total(), budget.available_input());
+        assert_eq!(allocation.tools, 600);
+        // After floors (600 + 1024 + 2048), memory and history split the rest 2:3.
+        let rest = 15_000 - 600 - 1_024 - 2_048;
+        assert_eq!(allocation.memory, 1_024 + rest * 2 / 5);
+        assert_eq!(allocation.history, 2_048 + rest - rest * 2 / 5);
+    }
+
+    #[test]
+    fn test_floors_come_first_when_tight() {
+        let budget = ContextBudget::new(3_000, 1_000).share(ContextSection::History, 0, 1);
+        let demand = ContextDemand { system_prompt: 400, tools: 5_000, memory: 5_000, history: 5_000 };
+        let allocation = budget.allocate(&demand).unwrap();
+        // 1_600 input tokens after the system prompt: floors take 1_536, the last 64 are shared 1:2:1.
+        assert_eq!((allocation.tools, allocation.memory, allocation.history), (528, 1_056, 16));
+        assert_eq!(allocation.total(), 2_000);
+        assert_eq!(budget.allocate(&demand).unwrap(), allocation);
+    }
+
+    #[test]
+    fn test_errors() {
+        let budget = ContextBudget::new(8_000, 8_000);
+        assert!(matches!(budget.allocate(&ContextDemand::default()), Err(BudgetError::NoInputRoom { .. })));
+        let budget = ContextBudget::new(8_000, 4_000);
+        let demand = ContextDemand { system_prompt: 5_000, ..ContextDemand::default() };
+        assert_eq!(
+            budget.allocate(&demand),
+            Err(BudgetError::SystemPromptTooLarge { needed: 5_000, available: 4_000 })
+        );
+        assert_eq!(ContextBudget::from_context_length(8_192).available_input(), 8_192 - 2_048);
+    }
+}
+```
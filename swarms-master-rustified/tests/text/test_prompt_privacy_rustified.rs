@@ -0,0 +1,73 @@
+### Tests for prompt privacy
+Each mode must keep only what it promises of a body, leave numbers and non-body fields alone, and be
+applied to every text field of a run trace's steps while their token counts survive.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::run_diff::{PromptContext, RunTrace, TraceMessage, TraceStep};
+    use crate::swarms::text::prompt_privacy::PromptPrivacy;
+    use serde_json::json;
+
+    #[test]
+    fn test_modes() {
+        let text = "Draft the Harlow settlement.";
+        assert_eq!(PromptPrivacy::Raw.apply(text), text);
+        assert_eq!(PromptPrivacy::Truncate { max_chars: 9 }.apply(text), "Draft the…[19 chars truncated]");
+        assert_eq!(PromptPrivacy::Truncate { max_chars: 100 }.apply(text), text);
+        assert_eq!(PromptPrivacy::Truncate { max_chars: 2 }.apply("Привет"), "Пр…[4 chars truncated]");
+
+        let hashed = PromptPrivacy::Hash.apply(text);
+        assert!(hashed.starts_with("[sha256:") && hashed.ends_with(", 28 chars]"), "{}", hashed);
+        assert_eq!(hashed, PromptPrivacy::Hash.apply(text));
+        assert_ne!(hashed, PromptPrivacy::Hash.apply("Draft the Harlow settlement!"));
+        assert_eq!(PromptPrivacy::Hash.apply(""), "");
+
+        let mode: PromptPrivacy = serde_json::from_value(json!({ "mode": "truncate", "max_chars": 40 })).unwrap();
+        assert_eq!(mode, PromptPrivacy::Truncate { max_chars: 40 });
+    }
+
+    #[test]
+    fn test_json_bodies_only() {
+        let payload = json!({
+            "summary": "The settlement is 2M.",
+            "report_url": "https://reports/42",
+            "tokens": 812,
+            "steps": [{ "agent": "Counsel", "output": "Offer 2M." }],
+        });
+        let kept = PromptPrivacy::Truncate { max_chars: 0 }.apply_json(&payload);
+        assert_eq!(kept["summary"], "…[21 chars truncated]");
+        assert_eq!(kept["steps"][0]["output"], "…[9 chars truncated]");
+        assert_eq!(kept["steps"][0]["agent"], "Counsel");
+        assert_eq!(kept["report_url"], "https://reports/42");
+        assert_eq!(kept["tokens"], 812);
+    }
+
+    #[test]
+    fn test_trace_steps_keep_counts() {
+        let mut trace = RunTrace::new("run-7").with_privacy(PromptPrivacy::Hash);
+        trace.push(TraceStep {
+            agent: "Counsel".to_string(),
+            model: "gpt-4o".to_string(),
+            prompt: "Draft the terms.".to_string(),
+            output: "Offer 2M.".to_string(),
+            prompt_tokens: 340,
+            completion_tokens: 12,
+            cost_usd: Some(0.001),
+            context: Some(PromptContext {
+                system: "You are outside counsel.".to_string(),
+                memory: vec!["Harlow rejected 1.5M.".to_string()],
+                history: vec![TraceMessage { role: "User".to_string(), content: "Be firm.".to_string() }],
+                ..PromptContext::default()
+            }),
+        });
+        let step = &trace.steps[0];
+        let context = step.context.as_ref().unwrap();
+        for text in [&step.prompt, &step.output, &context.system, &context.memory[0], &context.history[0].content] {
+            assert!(text.starts_with("[sha256:"), "{}", text);
+        }
+        assert_eq!(context.history[0].role, "User");
+        assert_eq!((step.prompt_tokens, step.completion_tokens, step.cost_usd), (340, 12, Some(0.001)));
+    }
+}
+```
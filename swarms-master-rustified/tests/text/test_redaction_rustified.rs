@@ -0,0 +1,126 @@
+### Tests for PII redaction
+Valid emails, SSNs, and card numbers must be replaced; look-alikes that fail validation must be left alone,
+originals must only be stored sealed, and exports must be redacted. A redacted save must keep every other
+field of each message, such as its id and tombstone.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::{Conversation, ConversationFormat};
+    use crate::swarms::text::redaction::{
+        CustomPattern, PiiKind, RedactionConfig, RedactionError, Redactor, Sealer,
+    };
+    use serde_json::json;
+    use std::sync::Arc;
+
+    struct Reverse;
+
+    impl Sealer for Reverse {
+        fn seal(&self, plaintext: &[u8]) -> Result<String, RedactionError> {
+            Ok(String::from_utf8_lossy(plaintext).chars().rev().collect())
+        }
+    }
+
+    #[test]
+    fn test_builtin_detectors() {
+        let redactor = Redactor::new(&RedactionConfig::default()).unwrap();
+        let redacted = redactor.redact("Mail jane.doe+q3@example.co.uk about 4111-1111-1111-1111 and 123-45-6789.");
+        assert_eq!(redacted.text, "Mail [REDACTED:EMAIL] about [REDACTED:CARD] and [REDACTED:SSN].");
+        let kinds: Vec<_> = redacted.findings.iter().map(|f| f.kind.clone()).collect();
+        assert_eq!(kinds, vec![PiiKind::Email, PiiKind::CardNumber, PiiKind::Ssn]);
+
+        // Fails Luhn, never-issued SSN area, and plain numbers are kept.
+        let text = "Order 4111 1111 1111 1112, ref 666-12-3456, total 1234567.";
+        assert_eq!(redactor.redact(text).text, text);
+    }
+
+    #[test]
+    fn test_card_followed_by_more_digits() {
+        let redactor = Redactor::new(&RedactionConfig::default()).unwrap();
+        // A greedy match would take the trailing digits too and fail Luhn.
+        assert_eq!(redactor.redact("card 4111 1111 1111 1111 12 units").text, "card [REDACTED:CARD] 12 units");
+        assert_eq!(redactor.redact("card 4111-1111-1111-1111-2026").text, "card [REDACTED:CARD]-2026");
+        // When the shortest candidate fails Luhn, a longer one ending at a later boundary is tried.
+        assert_eq!(redactor.redact("pan 4111 1111 1111 1112 001 done").text, "pan [REDACTED:CARD] done");
+    }
+
+    #[test]
+    fn test_config_and_custom_patterns() {
+        let config: RedactionConfig = serde_json::from_value(json!({
+            "emails": false,
+            "custom": [{ "name": "account", "pattern": "ACCT-\\d{8}" }]
+        }))
+        .unwrap();
+        assert!(config.ssns && !config.keep_originals);
+        let redactor = Redactor::new(&config).unwrap();
+        assert_eq!(redactor.redact("ACCT-00012345 for a@b.io").text, "[REDACTED:ACCOUNT] for a@b.io");
+
+        let bad = RedactionConfig {
+            custom: vec![CustomPattern { name: "broken".to_string(), pattern: "(".to_string() }],
+            ..RedactionConfig::default()
+        };
+        assert!(matches!(Redactor::new(&bad), Err(RedactionError::InvalidPattern { .. })));
+    }
+
+    #[test]
+    fn test_originals_are_sealed_or_dropped() {
+        let config = RedactionConfig { keep_originals: true, ..RedactionConfig::default() };
+        let text = "ssn 123-45-6789";
+
+        let stored = Redactor::with_sealer(&config, Arc::new(Reverse)).unwrap().for_storage(text).unwrap();
+        assert_eq!(stored.text, "ssn [REDACTED:SSN]");
+        assert_eq!(stored.sealed_original.as_deref(), Some("9876-54-321 nss"));
+        assert!(matches!(Redactor::new(&config), Err(RedactionError::MissingSealer)));
+
+        let stored = Redactor::new(&RedactionConfig::default()).unwrap().for_storage(text).unwrap();
+        assert_eq!(stored.sealed_original, None);
+        // Nothing found, nothing sealed.
+        let stored = Redactor::with_sealer(&config, Arc::new(Reverse)).unwrap().for_storage("clean").unwrap();
+        assert_eq!(stored.sealed_original, None);
+    }
+
+    #[test]
+    fn test_export_is_redacted() {
+        let dir = tempfile::tempdir().unwrap();
+        let redactor = Arc::new(Redactor::new(&RedactionConfig::default()).unwrap());
+        let mut conversation = Conversation::builder().redactor(redactor).build().unwrap();
+        conversation.add("User".to_string(), "Refund jane@example.com".to_string());
+        let path = dir.path().join("export.txt");
+        conversation.export_conversation(path.to_str().unwrap());
+        let exported = std::fs::read_to_string(path).unwrap();
+        assert!(exported.contains("User: Refund [REDACTED:EMAIL]"), "{}", exported);
+        assert!(!exported.contains("jane@example.com"));
+    }
+
+    #[test]
+    fn test_redacted_save_keeps_message_fields() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = RedactionConfig { keep_originals: true, ..RedactionConfig::default() };
+        let redactor = Arc::new(Redactor::with_sealer(&config, Arc::new(Reverse)).unwrap());
+        let mut conversation = Conversation::builder().redactor(redactor).tombstones(true).build().unwrap();
+        let first = conversation.add("User".to_string(), "ssn 123-45-6789".to_string());
+        let second = conversation.add("User".to_string(), "never mind".to_string());
+        conversation.delete(second).unwrap();
+        let path = dir.path().join("saved.json");
+        conversation.save_in(path.to_str().unwrap(), ConversationFormat::Json).unwrap();
+
+        let saved: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        let message = |id: u64| saved["messages"].as_array().unwrap().iter().find(|m| m["id"] == id).cloned().unwrap();
+        let (kept, deleted) = (message(first), message(second));
+        assert_eq!(kept["content"], "ssn [REDACTED:SSN]");
+        assert_eq!(kept["sealed_original"], "9876-54-321 nss");
+        assert!(kept.get("deleted_at").is_none());
+        assert!(deleted["deleted_at"].is_string());
+    }
+
+    #[test]
+    fn test_redact_json() {
+        let redactor = Redactor::new(&RedactionConfig::default()).unwrap();
+        let data = json!({ "summary": "sent to jane@example.com", "count": 2, "to": ["bob@example.com"] });
+        assert_eq!(
+            redactor.redact_json(&data),
+            json!({ "summary": "sent to [REDACTED:EMAIL]", "count": 2, "to": ["[REDACTED:EMAIL]"] })
+        );
+    }
+}
+```
@@ -0,0 +1,86 @@
+### Tests for sentiment-gated retries
+The lexicon analyzer must separate warm from hostile replies and honour negation, and the gate must retry
+low-scoring replies with the tone instruction, falling back to the best attempt when none passes.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::structs::agent::{AgentConfigError, LlmAgent};
+    use crate::swarms::text::sentiment::{
+        analyzer_by_name, sentiment_gate, LexiconAnalyzer, SentimentAnalyzer, SentimentError, SentimentGateConfig,
+        DEFAULT_TONE_INSTRUCTION,
+    };
+
+    fn scripted(replies: Vec<&'static str>) -> impl FnMut(&str) -> Result<String, String> {
+        let mut replies = replies.into_iter();
+        move |_prompt| replies.next().map(str::to_string).ok_or_else(|| "model exhausted".to_string())
+    }
+
+    #[test]
+    fn test_lexicon_scores() {
+        let analyzer = LexiconAnalyzer::new();
+        assert!(analyzer.score("Thanks so much, happy to help! Your issue is resolved.") > 0.7);
+        assert!(analyzer.score("This is a ridiculous request and obviously your fault.") < 0.3);
+        assert_eq!(analyzer.score("Your order ships on Tuesday."), 0.5);
+        assert!(analyzer.score("That is not helpful.") < 0.5);
+        assert!(analyzer.score("No problem at all, I don\u{2019}t mind.") > 0.5);
+
+        let custom = LexiconAnalyzer::new().negative(["Churn"]);
+        assert!(custom.score("churn churn") < 0.5);
+    }
+
+    #[test]
+    fn test_retries_until_threshold() {
+        let config = SentimentGateConfig { threshold: 0.6, ..SentimentGateConfig::default() };
+        let mut prompts = Vec::new();
+        let mut replies = scripted(vec!["Obviously you did it wrong.", "Happy to help, thanks for your patience!"]);
+        let run = sentiment_gate("Why was I charged twice?", &config, &LexiconAnalyzer::new(), |prompt: &str| {
+            prompts.push(prompt.to_string());
+            replies(prompt)
+        })
+        .unwrap();
+        assert!(run.passed);
+        assert_eq!(run.attempts.len(), 2);
+        assert_eq!(run.output, "Happy to help, thanks for your patience!");
+        assert_eq!(prompts[0], "Why was I charged twice?");
+        assert!(prompts[1].contains(DEFAULT_TONE_INSTRUCTION) && prompts[1].ends_with("Obviously you did it wrong."));
+    }
+
+    #[test]
+    fn test_returns_best_attempt_when_none_pass() {
+        let config = SentimentGateConfig { threshold: 0.9, max_retries: 1, ..SentimentGateConfig::default() };
+        let analyzer = |text: &str| if text.contains("sorry") { 0.7 } else { 0.2 };
+        let run = sentiment_gate("Refund?", &config, &analyzer, scripted(vec!["No.", "So sorry, no."])).unwrap();
+        assert!(!run.passed);
+        assert_eq!((run.output.as_str(), run.score), ("So sorry, no.", 0.7));
+
+        let failed = sentiment_gate("Refund?", &config, &analyzer, scripted(vec![]));
+        assert_eq!(failed, Err("model exhausted".to_string()));
+    }
+
+    #[test]
+    fn test_schema_and_agent_wiring() {
+        assert_eq!(SentimentGateConfig::from_schema(&AgentSchema::default()), Ok(None));
+        let schema = AgentSchema { sentiment_threshold: Some(0.4), ..AgentSchema::default() };
+        let config = SentimentGateConfig::from_schema(&schema).unwrap().unwrap();
+        assert_eq!((config.threshold, config.analyzer.as_str()), (0.4, "lexicon"));
+
+        let unknown = AgentSchema {
+            sentiment_threshold: Some(0.4),
+            sentiment_analyzer: Some("vader".to_string()),
+            ..AgentSchema::default()
+        };
+        assert_eq!(SentimentGateConfig::from_schema(&unknown), Err(SentimentError::UnknownAnalyzer("vader".to_string())));
+        assert!(analyzer_by_name("vader").is_err());
+
+        let invalid = SentimentGateConfig { threshold: 1.5, ..SentimentGateConfig::default() };
+        let built = LlmAgent::builder().name("Support-1").llm("gpt-4o").sentiment_gate(invalid).build();
+        assert_eq!(built.unwrap_err(), AgentConfigError::Sentiment(SentimentError::InvalidThreshold(1.5)));
+
+        let agent = LlmAgent::builder().name("Support-1").llm("gpt-4o").sentiment_gate(config).build().unwrap();
+        let run = agent.run_with_sentiment_gate("Hello", scripted(vec!["Welcome! Glad to help."])).unwrap();
+        assert!(run.passed && run.attempts.len() == 1);
+    }
+}
+```
@@ -0,0 +1,87 @@
+### Tests for streamed stop conditions
+Sequences and patterns must be caught even when split across deltas, the start of a sequence must be held
+back from the settled text, the earliest match must win, and the conditions must follow the agent schema's
+stopping fields.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::text::stop_conditions::{StopConditions, StopError, StopKind, PRESET_STOPPING_TOKEN};
+
+    #[test]
+    fn test_sequence_split_across_deltas() {
+        let conditions = StopConditions::new().sequence("<DONE>");
+        let mut matcher = conditions.matcher();
+        assert_eq!(matcher.push("The report is finished. <DO"), None);
+        let found = matcher.push("NE> and then some more").unwrap();
+        assert_eq!((found.kind, found.start), (StopKind::Sequence, 24));
+        assert_eq!(matcher.truncated(), "The report is finished. ");
+        assert_eq!(matcher.push("ignored"), None);
+        assert_eq!(matcher.text(), "The report is finished. <DONE> and then some more");
+    }
+
+    #[test]
+    fn test_settled_text_holds_back_a_partial_sequence() {
+        let conditions = StopConditions::new().sequence("<DONE>").sequence("END");
+        let mut matcher = conditions.matcher();
+        matcher.push("Total: 42 <DO");
+        assert_eq!(matcher.settled(), "Total: 42 ");
+        matcher.push("NE");
+        assert_eq!(matcher.settled(), "Total: 42 ");
+        matcher.push("> more");
+        assert_eq!(matcher.settled(), "Total: 42 ");
+
+        // A tail that turns out not to be a sequence is released by the next delta.
+        let mut matcher = conditions.matcher();
+        matcher.push("Send <DO");
+        matcher.push("CS> now. E");
+        assert_eq!(matcher.settled(), "Send <DOCS> now. ");
+        matcher.push("xtra");
+        assert_eq!(matcher.settled(), "Send <DOCS> now. Extra");
+        assert_eq!(matcher.settled(), matcher.truncated());
+    }
+
+    #[test]
+    fn test_pattern_keeps_match_and_uses_context() {
+        let conditions = StopConditions::new().pattern(r"(?m)^Final Answer:.*\n").unwrap();
+        let mut matcher = conditions.matcher();
+        assert_eq!(matcher.push("My Final Answer: maybe\n"), None);
+        assert_eq!(matcher.push("\nFinal Answer: 4"), None);
+        let found = matcher.push("2\nThen I would also").unwrap();
+        assert_eq!(found.matched, "Final Answer: 42\n");
+        assert!(matcher.truncated().ends_with("Final Answer: 42\n"));
+    }
+
+    #[test]
+    fn test_earliest_match_wins() {
+        let conditions = StopConditions::new().sequence("STOP").pattern(r"\bhalt\b").unwrap();
+        let found = conditions.find("please halt now, STOP").unwrap();
+        assert_eq!((found.kind, found.matched.as_str()), (StopKind::Pattern, "halt"));
+        assert_eq!(conditions.find("nothing here"), None);
+    }
+
+    #[test]
+    fn test_from_schema() {
+        let schema = AgentSchema {
+            stopping_token: Some("<END>".to_string()),
+            preset_stopping_token: Some(true),
+            stop_patterns: Some(vec![r"Observation:".to_string()]),
+            ..AgentSchema::default()
+        };
+        let conditions = StopConditions::from_schema(&schema).unwrap();
+        assert_eq!(conditions.sequences(), ["<END>".to_string(), PRESET_STOPPING_TOKEN.to_string()]);
+        assert!(conditions.find("Thought: search\nObservation: none").is_some());
+
+        let conditions = StopConditions::from_schema(&AgentSchema::default()).unwrap();
+        assert!(conditions.is_empty());
+
+        let many = (0..6).fold(StopConditions::new(), |conditions, i| conditions.sequence(format!("<{}>", i)));
+        assert_eq!(many.provider_sequences().len(), 4);
+        assert_eq!(many.find("done <5>").unwrap().matched, "<5>");
+
+        let invalid = AgentSchema { stop_patterns: Some(vec!["(".to_string()]), ..AgentSchema::default() };
+        assert!(matches!(StopConditions::from_schema(&invalid), Err(StopError::InvalidPattern { .. })));
+    }
+}
+```
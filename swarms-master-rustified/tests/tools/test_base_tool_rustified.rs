@@ -0,0 +1,107 @@
+### Tests for the tool registry
+`build()` must keep tools in registration order and reject empty or duplicate names, lookups must find
+registered tools by name and report unknown ones, and a panicking tool must fail only its own call. The
+rendered tool catalog must fit its token budget, header and closing count included, and a tool too long
+to list must not keep later, shorter tools out of the catalog.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::text::tokenizer::estimate_tokens;
+    use crate::swarms::tools::base_tool::ToolRegistry;
+    use serde_json::json;
+
+    fn registry() -> ToolRegistry {
+        ToolRegistry::builder()
+            .tool("summarize", "Summarize a filing. ".repeat(20), json!({ "type": "object" }), |_| Ok(json!("")))
+            .tool(
+                "calculator",
+                "Evaluate an arithmetic expression.",
+                json!({ "properties": { "expression": { "type": "string" } }, "type": "object" }),
+                |_| Ok(json!(0)),
+            )
+            .tool("clock", "Return the current UTC time.", json!({ "type": "object" }), |_| Ok(json!("")))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_build_keeps_registration_order() {
+        let registry = registry();
+        assert_eq!(registry.len(), 3);
+        let names: Vec<&str> = registry.tools().map(|tool| tool.name.as_str()).collect();
+        assert_eq!(names, ["summarize", "calculator", "clock"]);
+        let schema_names: Vec<&str> =
+            registry.openai_schemas().iter().map(|schema| schema["function"]["name"].as_str().unwrap()).collect();
+        assert_eq!(schema_names, names);
+        assert!(ToolRegistry::builder().build().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_build_rejects_empty_and_duplicate_names() {
+        let noop = |_: &serde_json::Value| Ok(json!(null));
+        let err = ToolRegistry::builder()
+            .tool("clock", "Return the current UTC time.", json!({}), noop)
+            .tool("clock", "Return the local time.", json!({}), noop)
+            .build()
+            .err()
+            .unwrap();
+        assert_eq!(err.to_string(), "Tool 'clock' is registered more than once");
+
+        let err = ToolRegistry::builder().tool("", "Nameless.", json!({}), noop).build().err().unwrap();
+        assert_eq!(err.to_string(), "Tool names must not be empty");
+    }
+
+    #[test]
+    fn test_lookup_by_name() {
+        let registry = registry();
+        assert_eq!(registry.get("calculator").unwrap().summary(), "Evaluate an arithmetic expression.");
+        assert!(registry.get("Calculator").is_none());
+        assert_eq!(registry.execute_tool_by_name("calculator", &json!({ "expression": "1+1" })).unwrap(), json!(0));
+        let err = registry.execute_tool_by_name("weather", &json!({})).unwrap_err();
+        assert_eq!(err.to_string(), "Tool 'weather' not found");
+
+        let call = r#"{"name": "clock", "parameters": {}}"#;
+        assert_eq!(registry.execute_tool_from_text(call).unwrap(), json!(""));
+        assert!(registry.check_str_for_functions_valid(r#"{"type": "function", "function": {"name": "clock"}}"#));
+        assert!(!registry.check_str_for_functions_valid(r#"{"type": "function", "function": {"name": "weather"}}"#));
+
+        let restricted = registry.restricted(|name| name != "summarize");
+        assert!(restricted.get("summarize").is_none());
+        assert_eq!(restricted.openai_schemas().len(), 2);
+    }
+
+    #[test]
+    fn test_panicking_tool_fails_its_own_call() {
+        let registry = ToolRegistry::builder()
+            .tool("flaky", "Always panics.", json!({}), |_| panic!("index out of range"))
+            .tool("clock", "Return the current UTC time.", json!({}), |_| Ok(json!("12:00")))
+            .build()
+            .unwrap();
+        let err = registry.execute_tool_by_name("flaky", &json!({})).unwrap_err();
+        assert!(err.to_string().contains("Tool 'flaky' panicked: index out of range"), "{}", err);
+        assert_eq!(registry.execute_tool_by_name("clock", &json!({})).unwrap(), json!("12:00"));
+    }
+
+    #[test]
+    fn test_catalog_fits_its_budget() {
+        let registry = registry();
+        let full = registry.render_tool_catalog(usize::MAX);
+        assert!(!full.contains("more tools not listed"), "{}", full);
+        // Below the header plus the closing line nothing fits; from there on the whole catalog must.
+        for budget in 22..estimate_tokens(&full) {
+            let catalog = registry.render_tool_catalog(budget);
+            assert!(estimate_tokens(&catalog) <= budget, "budget {}:\n{}", budget, catalog);
+        }
+    }
+
+    #[test]
+    fn test_tools_after_one_that_does_not_fit_are_still_listed() {
+        let catalog = registry().render_tool_catalog(70);
+        assert!(!catalog.contains("- summarize:"), "{}", catalog);
+        assert!(catalog.contains("- calculator: Evaluate an arithmetic expression."), "{}", catalog);
+        assert!(catalog.contains("- clock: Return the current UTC time."), "{}", catalog);
+        assert!(catalog.ends_with("- ... and 1 more tools not listed for brevity.\n"), "{}", catalog);
+    }
+}
+```
@@ -0,0 +1,152 @@
+### Tests for the memory search tool
+A fake embedder maps known words to fixed vectors so recall is deterministic without an API. An agent with a
+`reranker` config must have its recalled passages reranked before they are returned, and the registered tool
+must work from a plain thread and from inside a current-thread runtime.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::memory::reranker::{CrossEncoder, RerankError, RerankerConfig};
+    use crate::swarms::memory::vector_memory::{Embedder, MemoryError, VectorMemory};
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::tools::base_tool::ToolRegistry;
+    use crate::swarms::tools::prebuilt::memory_search::{agent_namespace, MemoryAccessPolicy, MemorySearchTool};
+    use async_trait::async_trait;
+    use serde_json::{json, Value as JsonValue};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+
+    struct KeywordEmbedder;
+
+    #[async_trait]
+    impl Embedder for KeywordEmbedder {
+        async fn embed(&self, texts: &[String]) -> Result<Vec<Vec<f32>>, MemoryError> {
+            Ok(texts
+                .iter()
+                .map(|t| {
+                    let t = t.to_lowercase();
+                    vec![t.contains("revenue") as u8 as f32, t.contains("hiring") as u8 as f32, 0.1]
+                })
+                .collect())
+        }
+    }
+
+    fn policy() -> Arc<MemoryAccessPolicy> {
+        Arc::new(
+            MemoryAccessPolicy::new()
+                .public("handbook")
+                .allow_read("analyst", "filings")
+                .allow_write("researcher", "filings"),
+        )
+    }
+
+    #[test]
+    fn test_policy_rules() {
+        let policy = policy();
+        assert!(policy.can_read("analyst", &agent_namespace("analyst")));
+        assert!(!policy.can_read("analyst", &agent_namespace("researcher")));
+        assert!(policy.can_read("analyst", "filings"));
+        assert!(!policy.can_write("analyst", "filings"));
+        assert!(policy.can_read("researcher", "filings"));
+        assert!(policy.can_read("intern", "handbook"));
+        assert!(!policy.can_write("intern", "handbook"));
+        assert_eq!(policy.readable("analyst"), vec!["agent:analyst", "filings", "handbook"]);
+    }
+
+    #[tokio::test]
+    async fn test_search_enforces_namespaces() {
+        let memory = VectorMemory::new(Arc::new(KeywordEmbedder));
+        memory.add("filings", "Revenue grew 8%", HashMap::new(), None).await.unwrap();
+        memory.add("agent:researcher", "Revenue notes draft", HashMap::new(), None).await.unwrap();
+        memory.add("handbook", "Hiring policy", HashMap::new(), None).await.unwrap();
+
+        let analyst = MemorySearchTool::new(memory.clone(), policy(), "analyst");
+        let hits = analyst.search("revenue", None, Some(5)).await.unwrap();
+        assert_eq!(hits[0].text, "Revenue grew 8%");
+        assert!(hits.iter().all(|h| h.namespace != "agent:researcher"));
+
+        let denied = analyst.search("revenue", Some(vec!["agent:researcher".to_string()]), None).await;
+        assert!(matches!(denied, Err(MemoryError::AccessDenied { .. })));
+        assert!(analyst.remember("note", Some("filings")).await.is_err());
+
+        let researcher = MemorySearchTool::new(memory.clone(), policy(), "researcher");
+        researcher.remember("Revenue guidance raised", Some("filings")).await.unwrap();
+        assert_eq!(memory.len("filings"), 2);
+    }
+
+    // Prefers passages that mention guidance, or returns too few scores
+    struct GuidanceFirst(bool);
+
+    impl CrossEncoder for GuidanceFirst {
+        fn score_pairs(&self, _query: &str, passages: &[&str]) -> Result<Vec<f32>, RerankError> {
+            let scores = passages.iter().map(|p| p.to_lowercase().contains("guidance") as u8 as f32);
+            Ok(if self.0 { scores.collect() } else { scores.skip(1).collect() })
+        }
+    }
+
+    fn schema(top_n: usize) -> AgentSchema {
+        AgentSchema {
+            agent_name: "analyst".to_string(),
+            reranker: Some(RerankerConfig::CrossEncoder { top_n }),
+            ..AgentSchema::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_schema_reranker_picks_the_hits() {
+        let memory = VectorMemory::new(Arc::new(KeywordEmbedder));
+        memory.add("filings", "Revenue grew 8%", HashMap::new(), None).await.unwrap();
+        memory.add("filings", "Revenue guidance raised", HashMap::new(), None).await.unwrap();
+        memory.add("handbook", "Hiring policy", HashMap::new(), None).await.unwrap();
+
+        let analyst = MemorySearchTool::from_schema(memory.clone(), policy(), &schema(1), Some(Arc::new(GuidanceFirst(true)))).unwrap();
+        let hits = analyst.search("revenue", None, None).await.unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].text, "Revenue guidance raised");
+        assert_eq!(hits[0].score, 1.0);
+
+        let broken = MemorySearchTool::from_schema(memory.clone(), policy(), &schema(1), Some(Arc::new(GuidanceFirst(false)))).unwrap();
+        assert!(matches!(broken.search("revenue", None, None).await, Err(MemoryError::Rerank(_))));
+
+        // A cross-encoder config without a model cannot be built, and no config means no reranking.
+        assert!(MemorySearchTool::from_schema(memory.clone(), policy(), &schema(1), None).is_err());
+        let plain = AgentSchema { agent_name: "analyst".to_string(), ..AgentSchema::default() };
+        let hits = MemorySearchTool::from_schema(memory, policy(), &plain, None).unwrap().search("revenue", None, None).await.unwrap();
+        assert_eq!(hits.len(), 3);
+    }
+
+    async fn filings() -> VectorMemory {
+        let memory = VectorMemory::new(Arc::new(KeywordEmbedder));
+        memory.add("filings", "Revenue grew 8%", HashMap::new(), None).await.unwrap();
+        memory.add("handbook", "Hiring policy", HashMap::new(), None).await.unwrap();
+        memory
+    }
+
+    fn search_memory(memory: VectorMemory, params: JsonValue) -> Result<JsonValue, String> {
+        let registry = MemorySearchTool::new(memory, policy(), "analyst").register(ToolRegistry::builder()).build().unwrap();
+        registry.get("search_memory").unwrap().call(&params).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_tool_runs_without_a_runtime() {
+        let memory = futures::executor::block_on(filings());
+        let hits = std::thread::spawn(move || search_memory(memory, json!({ "query": "revenue", "top_k": 1 })))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(hits.as_array().unwrap().len(), 1);
+        assert_eq!(hits[0]["text"], "Revenue grew 8%");
+        assert_eq!(hits[0]["namespace"], "filings");
+    }
+
+    #[tokio::test]
+    async fn test_tool_runs_inside_a_current_thread_runtime() {
+        let memory = filings().await;
+        let hits = search_memory(memory.clone(), json!({ "query": "hiring", "namespaces": ["handbook"] })).unwrap();
+        assert_eq!(hits[0]["text"], "Hiring policy");
+
+        let denied = search_memory(memory, json!({ "query": "revenue", "namespaces": ["agent:researcher"] })).unwrap_err();
+        assert!(denied.contains("agent:researcher"), "{}", denied);
+    }
+}
+```
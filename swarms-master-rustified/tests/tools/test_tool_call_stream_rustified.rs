@@ -0,0 +1,66 @@
+### Tests for streamed tool-call assembly
+The fixtures split arguments at awkward points: inside strings, across escapes, and across SSE lines.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::tools::tool_call_stream::{JsonScanner, SseDecoder, SseEvent, ToolCallAssembler};
+    use serde_json::json;
+
+    fn args_delta(index: u64, id: Option<&str>, name: Option<&str>, arguments: &str) -> serde_json::Value {
+        let mut call = json!({ "index": index, "function": { "arguments": arguments } });
+        if let Some(id) = id {
+            call["id"] = json!(id);
+        }
+        if let Some(name) = name {
+            call["function"]["name"] = json!(name);
+        }
+        json!({ "choices": [{ "delta": { "tool_calls": [call] } }] })
+    }
+
+    #[test]
+    fn test_scanner_ignores_brackets_in_strings() {
+        let mut scanner = JsonScanner::new();
+        assert_eq!(scanner.feed(r#"{"q": "a } b", "e": "\"}"#), None);
+        assert_eq!(scanner.feed(r#"", "n": [1, {"x": 2}]"#), None);
+        assert_eq!(scanner.feed("} trailing"), Some(1));
+        assert!(scanner.is_complete());
+        assert_eq!(scanner.feed("{}"), None);
+    }
+
+    #[test]
+    fn test_first_call_ready_before_second_finishes() {
+        let mut assembler = ToolCallAssembler::new();
+        assert!(assembler.push_chunk(&args_delta(0, Some("call_a"), Some("search"), r#"{"query": "ru"#)).is_empty());
+        let ready = assembler.push_chunk(&args_delta(0, None, None, r#"st"}"#));
+        assert_eq!(ready.len(), 1);
+        assert_eq!(ready[0].id, "call_a");
+        assert_eq!(ready[0].arguments, r#"{"query": "rust"}"#);
+
+        assert!(assembler.push_chunk(&args_delta(1, Some("call_b"), Some("fetch"), r#"{"url": "#)).is_empty());
+        assert!(assembler.push_chunk(&json!({ "choices": [{ "delta": {}, "finish_reason": "tool_calls" }] })).is_empty());
+        assert!(assembler.finish().is_err());
+        assert_eq!(assembler.finish_reason(), Some("tool_calls"));
+    }
+
+    #[test]
+    fn test_finish_flushes_calls_without_arguments() {
+        let mut assembler = ToolCallAssembler::new();
+        assembler.push_chunk(&json!({ "choices": [{ "delta": { "content": "Checking" } }] }));
+        assembler.push_chunk(&args_delta(0, Some("call_t"), Some("current_time"), ""));
+        let remaining = assembler.finish().unwrap();
+        assert_eq!(remaining.len(), 1);
+        assert_eq!(remaining[0].arguments, "{}");
+        assert_eq!(assembler.content(), "Checking");
+        assert!(assembler.finish().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sse_decoder_handles_split_lines() {
+        let mut decoder = SseDecoder::new();
+        assert!(decoder.push(b": keep-alive\n\ndata: {\"choices\":").unwrap().is_empty());
+        let events = decoder.push(b"[]}\n\ndata: [DONE]\n\n").unwrap();
+        assert_eq!(events, vec![SseEvent::Chunk(json!({ "choices": [] })), SseEvent::Done]);
+    }
+}
+```
@@ -0,0 +1,139 @@
+### Tests for the web reader tool
+A one-shot HTTP server on localhost stands in for the web. The reader must keep the article and drop the
+page chrome, cut long pages at a paragraph boundary, and report bad URLs and error statuses. A page without an
+`<article>` must come back as its story block rather than the whole body, and a first paragraph over the budget
+must be cut between words instead of dropped. The registered
+tool must work from a plain thread and from inside either kind of Tokio runtime.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::text::tokenizer::HeuristicTokenizer;
+    use crate::swarms::tools::base_tool::ToolRegistry;
+    use crate::swarms::tools::prebuilt::web_reader::{extract_markdown, truncate_to_tokens, WebReaderError, WebReaderTool};
+    use serde_json::json;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+
+    const PAGE: &str = "<html><head><title>Rust 2024</title><script>track()</script></head><body>\
+        <nav><a href=\"/\">Home</a></nav>\
+        <article><h1>Rust 2024</h1><p>The <strong>edition</strong> ships today.</p>\
+        <ul><li>async closures</li><li>gen blocks</li></ul></article>\
+        <footer>Copyright</footer></body></html>";
+
+    /// Answer a single request with `status` and `body`, returning the URL to fetch.
+    fn serve(status: &str, content_type: &str, body: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/article", listener.local_addr().unwrap());
+        let response = format!(
+            "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            status,
+            content_type,
+            body.len(),
+            body
+        );
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut line = String::new();
+            while reader.read_line(&mut line).unwrap() > 2 {
+                line.clear();
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+        });
+        url
+    }
+
+    #[test]
+    fn test_extract_markdown_keeps_the_article() {
+        let (title, markdown) = extract_markdown(PAGE);
+        assert_eq!(title.as_deref(), Some("Rust 2024"));
+        assert_eq!(markdown, "# Rust 2024\n\nThe **edition** ships today.\n\n- async closures\n- gen blocks");
+    }
+
+    #[test]
+    fn test_truncation_keeps_whole_paragraphs() {
+        let markdown = "one two three\n\nfour five six\n\nseven eight nine";
+        let tokenizer = HeuristicTokenizer;
+        assert_eq!(truncate_to_tokens(markdown, 1000, &tokenizer), (markdown.to_string(), false));
+
+        let (kept, truncated) = truncate_to_tokens(markdown, 10, &tokenizer);
+        assert!(truncated);
+        assert!(kept.starts_with("one two three"), "{}", kept);
+        assert!(kept.ends_with("[... truncated]"), "{}", kept);
+        assert!(!kept.contains("seven"), "{}", kept);
+    }
+
+    #[test]
+    fn test_extract_markdown_finds_the_story_block_without_an_article() {
+        let page = "<html><body><div id=\"page\">\
+            <div class=\"menu\"><a href=\"/\">Home</a> <a href=\"/markets\">Markets</a></div>\
+            <div class=\"story\"><p>Revenue grew eight percent on strong cloud demand.</p>\
+            <p>Margins held steady through the quarter.</p></div>\
+            <div class=\"related\"><p><a href=\"https://example.com/\">Ten stocks to buy before it is too late</a></p></div>\
+            </div></body></html>";
+        let (_, markdown) = extract_markdown(page);
+        assert_eq!(markdown, "Revenue grew eight percent on strong cloud demand.\n\nMargins held steady through the quarter.");
+    }
+
+    #[test]
+    fn test_truncation_cuts_an_oversized_first_paragraph() {
+        let tokenizer = HeuristicTokenizer;
+        let markdown = "alpha beta gamma delta epsilon zeta\n\nsecond";
+        assert_eq!(truncate_to_tokens(markdown, 8, &tokenizer), ("alpha\n\n[... truncated]".to_string(), true));
+        let unbroken = "x".repeat(40);
+        assert_eq!(truncate_to_tokens(&unbroken, 8, &tokenizer), ("xxx\n\n[... truncated]".to_string(), true));
+    }
+
+    #[tokio::test]
+    async fn test_read_reports_bad_urls_and_statuses() {
+        let reader = WebReaderTool::new();
+        assert!(matches!(reader.read("ftp://example.com/file").await, Err(WebReaderError::InvalidUrl(_))));
+        assert!(matches!(reader.read("not a url").await, Err(WebReaderError::InvalidUrl(_))));
+
+        let missing = serve("404 Not Found", "text/html", "gone");
+        assert!(matches!(reader.read(&missing).await, Err(WebReaderError::Status(404))));
+        let pdf = serve("200 OK", "application/pdf", "%PDF");
+        assert!(matches!(reader.read(&pdf).await, Err(WebReaderError::NotHtml(_))));
+    }
+
+    #[tokio::test]
+    async fn test_read_records_the_source() {
+        let url = serve("200 OK", "text/html; charset=utf-8", PAGE);
+        let page = WebReaderTool::new().read(&url).await.unwrap();
+        assert_eq!(page.title.as_deref(), Some("Rust 2024"));
+        assert!(!page.truncated);
+        assert!(!page.markdown.contains("Copyright"));
+        assert_eq!(page.metadata["source_url"], url);
+        assert_eq!(page.metadata["title"], "Rust 2024");
+        assert!(page.metadata.contains_key("fetched_at"));
+    }
+
+    fn read_web_page(url: &str) -> Result<serde_json::Value, String> {
+        let registry = WebReaderTool::new().register(ToolRegistry::builder()).build().unwrap();
+        let tool = registry.get("read_web_page").unwrap();
+        tool.call(&json!({ "url": url })).map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn test_tool_runs_without_a_runtime() {
+        let url = serve("200 OK", "text/plain", "plain notes");
+        let page = std::thread::spawn(move || read_web_page(&url)).join().unwrap().unwrap();
+        assert_eq!(page["markdown"], "plain notes");
+    }
+
+    #[tokio::test]
+    async fn test_tool_runs_inside_a_current_thread_runtime() {
+        let url = serve("200 OK", "text/html", PAGE);
+        let page = read_web_page(&url).unwrap();
+        assert_eq!(page["title"], "Rust 2024");
+        assert!(read_web_page("file:///etc/passwd").unwrap_err().contains("invalid URL"));
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_tool_runs_inside_a_multi_thread_runtime() {
+        let url = serve("500 Internal Server Error", "text/html", "boom");
+        assert!(read_web_page(&url).unwrap_err().contains("server returned 500"));
+    }
+}
+```
@@ -0,0 +1,109 @@
+### Tests for the web search tool
+A scripted engine stands in for the search API. Repeated queries must come from the cache until they expire,
+the cache must stay within its capacity, and the registered tool must work from a plain thread and from
+inside either kind of Tokio runtime.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::integrations::rest::RestError;
+    use crate::swarms::tools::base_tool::ToolRegistry;
+    use crate::swarms::tools::prebuilt::web_search::{SearchEngine, SearchResult, WebSearchTool};
+    use async_trait::async_trait;
+    use serde_json::json;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    // Returns one result per query, plus one without a URL, and counts the requests
+    #[derive(Default)]
+    struct Scripted(AtomicUsize);
+
+    #[async_trait]
+    impl SearchEngine for Scripted {
+        fn name(&self) -> &str {
+            "scripted"
+        }
+
+        async fn search(&self, query: &str, _max_results: usize) -> Result<Vec<SearchResult>, RestError> {
+            self.0.fetch_add(1, Ordering::SeqCst);
+            Ok(vec![
+                SearchResult { title: query.to_string(), url: format!("https://example.com/{}", query), snippet: String::new() },
+                SearchResult { title: "ad".to_string(), url: String::new(), snippet: String::new() },
+            ])
+        }
+    }
+
+    fn tool(engine: &Arc<Scripted>) -> WebSearchTool {
+        WebSearchTool::new(engine.clone()).requests_per_second(0.0)
+    }
+
+    #[tokio::test]
+    async fn test_repeated_queries_are_cached_until_they_expire() {
+        let engine = Arc::new(Scripted::default());
+        let search = tool(&engine);
+        let results = search.search("rust").await.unwrap();
+        assert_eq!(results.len(), 1, "results without a URL are dropped");
+        assert_eq!(search.search("  RUST ").await.unwrap(), results);
+        assert_eq!(engine.0.load(Ordering::SeqCst), 1);
+
+        let expiring = tool(&engine).cache_ttl(Duration::ZERO);
+        expiring.search("rust").await.unwrap();
+        expiring.search("rust").await.unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_cache_stays_within_capacity() {
+        let engine = Arc::new(Scripted::default());
+        let search = tool(&engine).cache_capacity(2);
+        for query in ["a", "b", "c", "d"] {
+            search.search(query).await.unwrap();
+        }
+        assert_eq!(search.cached_queries(), 2);
+        // The newest queries are kept; the oldest was evicted.
+        search.search("d").await.unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 4);
+        search.search("a").await.unwrap();
+        assert_eq!(engine.0.load(Ordering::SeqCst), 5);
+
+        // Expired entries are swept before anything live is evicted.
+        let sweeping = tool(&engine).cache_ttl(Duration::ZERO).cache_capacity(3);
+        for query in ["a", "b", "c", "d"] {
+            sweeping.search(query).await.unwrap();
+        }
+        assert_eq!(sweeping.cached_queries(), 1);
+    }
+
+    fn registry(engine: &Arc<Scripted>) -> ToolRegistry {
+        tool(engine).register(ToolRegistry::builder()).build().unwrap()
+    }
+
+    #[test]
+    fn test_tool_runs_without_a_runtime() {
+        let engine = Arc::new(Scripted::default());
+        let registry = registry(&engine);
+        let results = std::thread::spawn(move || registry.get("web_search").unwrap().call(&json!({ "query": "rust" })))
+            .join()
+            .unwrap()
+            .unwrap();
+        assert_eq!(results[0]["url"], "https://example.com/rust");
+    }
+
+    #[tokio::test]
+    async fn test_tool_runs_inside_a_current_thread_runtime() {
+        let engine = Arc::new(Scripted::default());
+        let registry = registry(&engine);
+        let results = registry.get("web_search").unwrap().call(&json!({ "query": "rust" })).unwrap();
+        assert_eq!(results[0]["title"], "rust");
+        assert!(registry.get("web_search").unwrap().call(&json!({})).is_err());
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_tool_runs_inside_a_multi_thread_runtime() {
+        let engine = Arc::new(Scripted::default());
+        let results = registry(&engine).get("web_search").unwrap().call(&json!({ "query": "rust" })).unwrap();
+        assert_eq!(results.as_array().unwrap().len(), 1);
+    }
+}
+```
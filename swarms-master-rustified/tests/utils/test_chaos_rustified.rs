@@ -0,0 +1,234 @@
+### Tests for chaos fault injection
+Chaos must stay off unless the environment or the config turns it on, and each fault class must land in the
+path built for it: injected provider failures are requeued by the ledger until attempts run out and then
+dead-lettered, a child that keeps failing is restarted and then escalated, malformed responses and tool
+timeouts fail at once without a retry, and injected delays are reported as stuck tasks. A swarm config's
+`chaos` section must reach the agents and tools built from it.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::agents::auto_generate_swarm_config::connect_agents;
+    use crate::swarms::agents::create_agents_from_yaml::load_yaml_safely;
+    use crate::swarms::schemas::base_schemas::ChatCompletionRequest;
+    use crate::swarms::structs::agent::{Agent, AgentError};
+    use crate::swarms::structs::failure::{Classify, Failure, FailureCode};
+    use crate::swarms::structs::plan_execute::{Plan, PlanError};
+    use crate::swarms::structs::restart::{RestartPolicy, RestartSupervisor};
+    use crate::swarms::structs::supervisor::{StuckAction, Supervisor};
+    use crate::swarms::utils::chaos::{Chaos, ChaosConfig, ChaosError, ChaosProvider};
+    use crate::swarms::utils::chat_provider::{ChatCompletion, ChatProvider, ProviderError};
+    use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    /// Always answers with the same JSON verdict.
+    struct Verdict;
+
+    #[async_trait]
+    impl ChatProvider for Verdict {
+        fn name(&self) -> &str {
+            "verdict"
+        }
+
+        fn model(&self) -> &str {
+            "verdict-1"
+        }
+
+        fn capabilities(&self) -> ProviderCapabilities {
+            ProviderCapabilities::text_only("verdict", "verdict-1")
+        }
+
+        async fn chat(&self, _request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+            let content = r#"{"verdict": "approve", "confidence": 0.9}"#.to_string();
+            Ok(ChatCompletion { content, finish_reason: None, tool_calls: Vec::new(), usage: None, stopped_by: None })
+        }
+    }
+
+    fn chaotic(config: ChaosConfig) -> ChaosProvider {
+        ChaosProvider::new(Arc::new(Verdict), Chaos::new(ChaosConfig { enabled: true, seed: Some(42), ..config }))
+    }
+
+    #[test]
+    fn test_env_config() {
+        let vars = |pairs: &'static [(&'static str, &'static str)]| {
+            move |var: &str| pairs.iter().find(|(name, _)| *name == var).map(|(_, value)| value.to_string())
+        };
+        assert_eq!(ChaosConfig::from_vars(vars(&[])), Ok(None));
+
+        let config = ChaosConfig::from_vars(vars(&[
+            ("SWARMS_CHAOS", "true"),
+            ("SWARMS_CHAOS_SEED", "7"),
+            ("SWARMS_CHAOS_PROVIDER_FAILURE_RATE", "0.25"),
+            ("SWARMS_CHAOS_TOOL_TIMEOUT_MS", "500"),
+        ]))
+        .unwrap()
+        .unwrap();
+        assert!(config.enabled);
+        assert_eq!((config.seed, config.provider_failure_rate, config.tool_timeout_ms), (Some(7), 0.25, 500));
+        assert_eq!(config.delay_rate, 0.0);
+
+        let bad_rate = ChaosConfig::from_vars(vars(&[("SWARMS_CHAOS", "1"), ("SWARMS_CHAOS_DELAY_RATE", "1.5")]));
+        assert_eq!(bad_rate, Err(ChaosError::InvalidRate { field: "delay_rate", rate: 1.5 }));
+        let bad_ms = ChaosConfig::from_vars(vars(&[("SWARMS_CHAOS", "1"), ("SWARMS_CHAOS_DELAY_MS", "soon")]));
+        assert!(matches!(bad_ms, Err(ChaosError::InvalidVar { var, .. }) if var == "SWARMS_CHAOS_DELAY_MS"));
+
+        let disabled = ChaosConfig { provider_failure_rate: 1.0, ..ChaosConfig::default() };
+        assert_eq!(ChaosConfig::resolve_with(Some(&disabled), vars(&[])), Ok(None));
+        let enabled = ChaosConfig { enabled: true, ..disabled.clone() };
+        assert_eq!(ChaosConfig::resolve_with(Some(&enabled), vars(&[])), Ok(Some(enabled.clone())));
+        assert_eq!(ChaosConfig::resolve_with(Some(&enabled), vars(&[("SWARMS_CHAOS", "0")])), Ok(None));
+    }
+
+    #[test]
+    fn test_off_by_default() {
+        let no_vars = |_: &str| None;
+        assert_eq!(ChaosConfig::from_vars(no_vars), Ok(None));
+        assert_eq!(ChaosConfig::resolve_with(None, no_vars), Ok(None));
+        assert_eq!(ChaosConfig::resolve_with(Some(&ChaosConfig::default()), no_vars), Ok(None));
+    }
+
+    #[cfg(feature = "server")]
+    #[tokio::test]
+    async fn test_provider_failures_are_retried_then_dead_lettered() {
+        use crate::swarms::structs::distributed_queue::{TaskLedger, TaskStatus};
+        use crate::swarms::structs::tenancy::TenantId;
+
+        let provider = chaotic(ChaosConfig { provider_failure_rate: 1.0, ..ChaosConfig::default() });
+        let acme = TenantId::new("acme").unwrap();
+        let mut ledger =
+            TaskLedger::new(Duration::from_secs(30)).with_max_attempts(3).with_retry_backoff(Duration::ZERO, Duration::ZERO);
+        let worker = ledger.register(&acme, "w-1", vec!["Reviewer".to_string()]);
+        let task = ledger.submit(&acme, "Reviewer", "review the contract");
+        for attempt in 1..=3 {
+            let claimed = ledger.claim(&worker, Instant::now()).expect("retriable failures are requeued");
+            assert_eq!(claimed.attempts, attempt);
+            let err = provider.complete(None, &claimed.task).await.unwrap_err();
+            assert!(matches!(err, ProviderError::Status { status: 429 | 503, .. }), "{}", err);
+            assert!(err.retriable());
+            ledger.record_chunk(&worker, &task, "", true, Some(Failure::of(&err)), Instant::now());
+        }
+        let record = ledger.record(&acme, &task).unwrap();
+        assert_eq!(record.status, TaskStatus::Failed);
+        assert!(record.retriable);
+        assert!(ledger.claim(&worker, Instant::now()).is_none(), "dead-lettered after the last attempt");
+    }
+
+    #[tokio::test]
+    async fn test_restart_supervisor_escalates_persistent_provider_failures() {
+        let provider = chaotic(ChaosConfig { provider_failure_rate: 1.0, ..ChaosConfig::default() });
+        let supervisor = RestartSupervisor::new("daemon").with_policy(RestartPolicy {
+            max_restarts: 2,
+            initial_backoff: Duration::from_millis(1),
+            ..RestartPolicy::default()
+        });
+        let mut calls = 0;
+        let escalation = supervisor
+            .run("reviewer", || {
+                calls += 1;
+                let provider = &provider;
+                async move { provider.complete(None, "review").await.map(|_| ()) }
+            })
+            .await
+            .unwrap_err();
+        assert_eq!((calls, escalation.restarts), (3, 2));
+        assert!(matches!(escalation.failure_code(), FailureCode::ProviderRateLimit | FailureCode::ProviderError));
+        assert!(!escalation.retriable());
+    }
+
+    #[tokio::test]
+    async fn test_malformed_json_is_not_retried() {
+        let provider = chaotic(ChaosConfig { malformed_json_rate: 1.0, ..ChaosConfig::default() });
+        let answer = provider.complete(None, "plan the review").await.unwrap();
+        assert!(answer.starts_with(r#"{"verdict""#), "{}", answer);
+        // The planner's parse path, which a plan-and-execute run classifies its failures from.
+        let err = Plan::parse(&answer, 0).unwrap_err();
+        assert!(matches!(err, PlanError::Invalid(_)), "{}", err);
+        let failure = Failure::of(&err);
+        assert_eq!((failure.code, failure.retriable), (FailureCode::ValidationError, false));
+
+        let chaos = Chaos::new(ChaosConfig { malformed_json_rate: 1.0, ..ChaosConfig::default() });
+        let prose = chaos.corrupt("Approve, with 90% confidence.".to_string());
+        assert!(serde_json::from_str::<serde_json::Value>(&prose).is_err(), "{}", prose);
+    }
+
+    #[cfg(feature = "tools")]
+    #[test]
+    fn test_tool_timeout_is_a_tool_error() {
+        use crate::swarms::tools::base_tool::ToolFn;
+        use serde_json::json;
+
+        let chaos = Chaos::new(ChaosConfig { tool_timeout_rate: 1.0, tool_timeout_ms: 20, ..ChaosConfig::default() });
+        let lookup: ToolFn = Arc::new(|_| Ok(json!({ "price": 10 })));
+        let lookup = chaos.wrap_tool("price_lookup", lookup);
+        let started = Instant::now();
+        let err = lookup(&json!({ "ticker": "ACME" })).unwrap_err();
+        assert!(started.elapsed() >= Duration::from_millis(20));
+        assert!(err.to_string().contains("price_lookup"), "{}", err);
+        let failure = Failure::of(&err);
+        assert_eq!((failure.code, failure.retriable), (FailureCode::ToolError, false));
+    }
+
+    #[tokio::test]
+    async fn test_delay_is_reported_as_stuck() {
+        let slow = chaotic(ChaosConfig { delay_rate: 1.0, delay_ms: 60, ..ChaosConfig::default() });
+        let supervisor = Supervisor::new("review")
+            .with_expected_duration(Duration::from_millis(30))
+            .with_action(StuckAction::Requeue);
+        let first = supervisor.begin("Reviewer", "review", 1);
+        slow.complete(None, "review").await.unwrap();
+        let events = supervisor.check(Instant::now());
+        assert_eq!(events.len(), 1, "{:?}", events);
+        assert_eq!(first.stuck(), Some(StuckAction::Requeue));
+
+        let fast = chaotic(ChaosConfig::default());
+        let second = supervisor.begin("Reviewer", "review", 2);
+        fast.complete(None, "review").await.unwrap();
+        assert!(supervisor.check(Instant::now()).is_empty());
+        assert_eq!(second.stuck(), None);
+    }
+
+    #[test]
+    fn test_swarm_config_chaos_reaches_agents_and_tools() {
+        let yaml = "\
+agents:
+  - agent_name: Reviewer
+    system_prompt: Review the contract.
+    model_name: verdict-1
+swarm_architecture:
+  name: Review-Swarm
+  chaos:
+    enabled: true
+    seed: 42
+    provider_failure_rate: 1.0
+    tool_timeout_rate: 1.0
+    tool_timeout_ms: 10
+";
+        let config = load_yaml_safely("", Some(yaml.to_string())).unwrap();
+        let configured = config.swarm_architecture.as_ref().and_then(|swarm| swarm.chaos.as_ref());
+        let chaos = Chaos::new(ChaosConfig::resolve_with(configured, |_| None).unwrap().expect("enabled by the config"));
+
+        let agents = connect_agents(&config, "gpt-4o", Some(&chaos), |_model| Arc::new(Verdict)).unwrap();
+        let err = agents[0].run("review").unwrap_err();
+        assert!(matches!(&err, AgentError::Failed { message, .. } if message.contains("fault injected")), "{}", err);
+
+        let calm = connect_agents(&config, "gpt-4o", None, |_model| Arc::new(Verdict)).unwrap();
+        assert!(calm[0].run("review").unwrap().contains("approve"));
+
+        #[cfg(feature = "tools")]
+        {
+            use crate::swarms::tools::base_tool::ToolRegistry;
+            use serde_json::json;
+
+            let tools = ToolRegistry::builder()
+                .tool("price_lookup", "Look up a price.", json!({ "type": "object" }), |_| Ok(json!({ "price": 10 })))
+                .build()
+                .unwrap();
+            let tools = chaos.wrap_tools(&tools);
+            let err = tools.get("price_lookup").unwrap().call(&json!({})).unwrap_err();
+            assert!(err.to_string().contains("timed out"), "{}", err);
+        }
+    }
+}
+```
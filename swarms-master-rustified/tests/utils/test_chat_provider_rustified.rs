@@ -0,0 +1,108 @@
+### Tests for the chat provider trait
+`Device` must accept the spellings agent configs use and reject the rest, and `complete` must send the
+system and user messages through the provider's `chat`. `provider_completion` must bind a provider to an
+agent so `run` sends the agent's system prompt, from a plain thread or from inside an async task.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ContentItem};
+    use crate::swarms::structs::agent::{Agent, LlmAgent};
+    use crate::swarms::structs::approval_gate::{ApprovalContext, CheckpointStore, PendingApprovals};
+    use crate::swarms::structs::sequential_workflow::SequentialWorkflow;
+    use crate::swarms::utils::chat_provider::{provider_completion, ChatCompletion, ChatProvider, Device, ProviderError};
+    use crate::swarms::utils::provider_capabilities::ProviderCapabilities;
+    use async_trait::async_trait;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_device_spellings() {
+        assert_eq!(Device::parse("cpu").unwrap(), Device::Cpu);
+        assert_eq!(Device::parse(" CUDA ").unwrap(), Device::Gpu(0));
+        assert_eq!(Device::parse("cuda:1").unwrap(), Device::Gpu(1));
+        assert_eq!(Device::parse("metal").unwrap(), Device::Gpu(0));
+        assert!(matches!(Device::parse("cpu:0"), Err(ProviderError::InvalidConfig(_))));
+        assert!(matches!(Device::parse("cuda:-1"), Err(ProviderError::InvalidConfig(_))));
+        assert!(matches!(Device::parse("tpu"), Err(ProviderError::InvalidConfig(_))));
+    }
+
+    /// Answers with the roles and text it was sent.
+    struct Echo;
+
+    #[async_trait]
+    impl ChatProvider for Echo {
+        fn name(&self) -> &str {
+            "echo"
+        }
+
+        fn model(&self) -> &str {
+            "echo-1"
+        }
+
+        fn capabilities(&self) -> ProviderCapabilities {
+            ProviderCapabilities::text_only("echo", "echo-1")
+        }
+
+        async fn chat(&self, request: &ChatCompletionRequest) -> Result<ChatCompletion, ProviderError> {
+            let content = request
+                .messages
+                .iter()
+                .map(|message| match &message.content[0] {
+                    ContentItem::Text { text } => format!("{}={}", message.role, text),
+                    _ => message.role.clone(),
+                })
+                .collect::<Vec<_>>()
+                .join(";");
+            Ok(ChatCompletion { content, finish_reason: None, tool_calls: Vec::new(), usage: None, stopped_by: None })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_complete_sends_system_and_user() {
+        let provider: Box<dyn ChatProvider> = Box::new(Echo);
+        assert_eq!(provider.complete(Some("Be brief."), "Hi").await.unwrap(), "system=Be brief.;user=Hi");
+        assert_eq!(provider.complete(None, "Hi").await.unwrap(), "user=Hi");
+    }
+
+    #[test]
+    fn test_provider_completion_runs_the_agent() {
+        let agent = echoer();
+        assert_eq!(agent.run("Hi").unwrap(), "system=Be brief.;user=Hi");
+
+        // Inside a runtime, the agent runs from a blocking worker.
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let reply = runtime.block_on(async move { tokio::task::spawn_blocking(move || agent.run("Hi")).await.unwrap() });
+        assert_eq!(reply.unwrap(), "system=Be brief.;user=Hi");
+    }
+
+    fn echoer() -> LlmAgent {
+        LlmAgent::builder()
+            .name("Echoer")
+            .llm("echo-1")
+            .system_prompt("Be brief.")
+            .completion(provider_completion(Arc::new(Echo)))
+            .build()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_provider_agent_runs_inside_a_current_thread_runtime() {
+        assert_eq!(echoer().run("Hi").unwrap(), "system=Be brief.;user=Hi");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_provider_agent_runs_inside_an_async_workflow() {
+        let agents: Vec<Arc<dyn Agent>> = vec![echoer().into()];
+        let workflow = SequentialWorkflow::new("Echo".into(), String::new(), agents, 1, "all".into(), false, || ());
+        let dir = tempfile::tempdir().unwrap();
+        let approvals = ApprovalContext {
+            run_id: "run-1".to_string(),
+            channel: Arc::new(PendingApprovals::new()),
+            store: CheckpointStore::new(dir.path()),
+            audit: None,
+        };
+        let run = workflow.run_with_approvals("Hi".to_string(), &approvals).await.unwrap();
+        assert_eq!(run.output, "system=Be brief.;user=Hi");
+    }
+}
+```
@@ -0,0 +1,58 @@
+### Tests for the injectable clock
+A `ManualClock` must only move when told to, and `sleep` must advance it without blocking. A conversation
+built with one must stamp messages and tombstones with its time, so timestamps can be asserted exactly.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::utils::clock::{Clock, ManualClock};
+    use chrono::{TimeZone, Utc};
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    const T0: i64 = 1_700_000_000_000;
+
+    #[test]
+    fn test_manual_clock_moves_only_when_told() {
+        let clock = ManualClock::at_millis(T0);
+        assert_eq!((clock.unix_millis(), clock.unix_secs()), (T0, T0 / 1_000));
+
+        let started = Instant::now();
+        clock.sleep(Duration::from_secs(3_600));
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert_eq!(clock.unix_millis(), T0 + 3_600_000);
+
+        // A deadline in the past does not move it
+        clock.sleep_until(Utc.timestamp_millis_opt(T0).unwrap());
+        assert_eq!(clock.unix_millis(), T0 + 3_600_000);
+        clock.sleep_until(Utc.timestamp_millis_opt(T0 + 3_601_500).unwrap());
+        assert_eq!(clock.unix_millis(), T0 + 3_601_500);
+
+        clock.set(Utc.timestamp_millis_opt(T0).unwrap());
+        assert_eq!(clock.unix_millis(), T0);
+    }
+
+    #[test]
+    fn test_conversation_stamps_with_its_clock() {
+        let clock = Arc::new(ManualClock::new(Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap()));
+        let mut conversation = Conversation::builder()
+            .system_prompt("Be brief.")
+            .time_enabled(true)
+            .tombstones(true)
+            .clock(clock.clone())
+            .build()
+            .unwrap();
+        clock.advance(Duration::from_secs(61));
+        let question = conversation.add("user".to_string(), "Hi".to_string());
+        clock.advance(Duration::from_secs(3_600));
+        conversation.delete(question).unwrap();
+
+        // The system prompt is stamped by the same clock, before it moved
+        assert_eq!(conversation.get(1).unwrap().timestamp.as_deref(), Some("2024-03-01 09:30:00"));
+        let tombstone = conversation.get(question).unwrap();
+        assert_eq!(tombstone.timestamp.as_deref(), Some("2024-03-01 09:31:01"));
+        assert_eq!(tombstone.deleted_at.as_deref(), Some("2024-03-01 10:31:01"));
+    }
+}
+```
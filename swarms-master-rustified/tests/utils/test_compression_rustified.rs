@@ -0,0 +1,72 @@
+### Tests for compressed state files
+Data over the threshold must round-trip through gzip and data under it must be stored as it is. A truncated
+or altered file must fail to load instead of yielding partial state. Conversations and the audit log must
+read their compressed files back without being told they are compressed.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::audit::{AuditAction, AuditError, AuditLog, AuditQuery};
+    use crate::swarms::structs::conversation::Conversation;
+    use crate::swarms::structs::tenancy::TenantId;
+    use crate::swarms::utils::compression::{CompressionError, StateCompressor};
+    use serde_json::json;
+
+    #[test]
+    fn test_round_trip_and_corruption() {
+        let compressor = StateCompressor::new().with_threshold(1024);
+        let log = "{\"event\":\"step\",\"status\":\"ok\"}\n".repeat(500);
+        let packed = compressor.compress(log.as_bytes());
+        assert!(StateCompressor::is_compressed(&packed) && packed.len() < log.len() / 10);
+        assert_eq!(StateCompressor::decompress(&packed).unwrap(), log.as_bytes());
+
+        assert_eq!(compressor.compress(b"{\"small\": true}"), b"{\"small\": true}");
+        assert_eq!(StateCompressor::decompress(b"plain text").unwrap(), b"plain text");
+
+        let truncated = &packed[..packed.len() - 8];
+        assert!(matches!(StateCompressor::decompress(truncated), Err(CompressionError::Corrupt(_))));
+        // A valid stream under another file's checksum
+        let mut swapped = packed.clone();
+        swapped[6] = if swapped[6] == b'0' { b'1' } else { b'0' };
+        assert!(matches!(StateCompressor::decompress(&swapped), Err(CompressionError::ChecksumMismatch { .. })));
+    }
+
+    #[test]
+    fn test_conversations_load_compressed_saves() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("conversation.json");
+        let path = path.to_str().unwrap();
+        let mut conversation = Conversation::builder().compression(StateCompressor::new().with_threshold(256)).build().unwrap();
+        conversation.add("user".to_string(), "Summarize the filings.".to_string());
+        let answer = conversation.add("Analyst".to_string(), "Revenue grew 12%.\n".repeat(100));
+        conversation.save(path).unwrap();
+        assert!(StateCompressor::is_compressed(&std::fs::read(path).unwrap()));
+
+        // A conversation without a compressor still reads the file
+        let mut restored = Conversation::builder().build().unwrap();
+        restored.load(path).unwrap();
+        assert_eq!(restored.get(answer).unwrap().content, "Revenue grew 12%.\n".repeat(100));
+    }
+
+    #[test]
+    fn test_audit_log_rolls_into_compressed_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let log = AuditLog::new(dir.path().join("audit.jsonl")).with_compression(StateCompressor::new().with_threshold(1024));
+        let acme = TenantId::new("acme").unwrap();
+        for run in 0..20 {
+            log.log(&acme, "alice", None, AuditAction::RunStarted, &format!("run-{}", run), json!({})).unwrap();
+        }
+        let segments = log.segments().unwrap();
+        assert!(segments.len() >= 2, "{:?}", segments);
+        assert!(segments.iter().all(|segment| StateCompressor::is_compressed(&std::fs::read(segment).unwrap())));
+        let events = log.query(&acme, &AuditQuery::default()).unwrap();
+        let targets: Vec<String> = events.into_iter().map(|event| event.target).collect();
+        assert_eq!(targets, (0..20).map(|run| format!("run-{}", run)).collect::<Vec<_>>());
+
+        let first = &segments[0];
+        let data = std::fs::read(first).unwrap();
+        std::fs::write(first, &data[..data.len() / 2]).unwrap();
+        assert!(matches!(log.query(&acme, &AuditQuery::default()), Err(AuditError::Segment { .. })));
+    }
+}
+```
@@ -0,0 +1,85 @@
+### Tests for encryption at rest
+Sealed state must round-trip, fail loudly when tampered with or keyed wrongly, survive key rotation, and
+still load files that were saved before encryption was turned on.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::agent::LlmAgent;
+    use crate::swarms::utils::encryption::{EncryptionError, StateCipher};
+    use crate::swarms::utils::secret_store::StaticSecretStore;
+    use std::fs;
+
+    fn store() -> StaticSecretStore {
+        StaticSecretStore::new()
+            .with("STATE_KEY_new", StateCipher::generate_key())
+            .with("STATE_KEY_old", StateCipher::generate_key())
+            .with("STATE_KEY_short", "c2hvcnQ=")
+    }
+
+    #[test]
+    fn test_round_trip_and_plaintext_passthrough() {
+        let cipher = StateCipher::from_store(&store(), &["new"]).unwrap();
+        let sealed = cipher.seal(b"conversation");
+        assert!(StateCipher::is_encrypted(&sealed));
+        assert_eq!(StateCipher::key_id_of(&sealed), Some("new"));
+        assert_eq!(cipher.open(&sealed).unwrap(), b"conversation");
+        assert_ne!(cipher.seal(b"conversation"), sealed, "nonces must differ per save");
+        assert_eq!(cipher.open(b"[]").unwrap(), b"[]");
+    }
+
+    #[test]
+    fn test_tampering_and_unknown_keys_are_rejected() {
+        let store = store();
+        let cipher = StateCipher::from_store(&store, &["new"]).unwrap();
+        let mut sealed = cipher.seal(b"state");
+        let last = sealed.len() - 2;
+        sealed[last] = if sealed[last] == b'A' { b'B' } else { b'A' };
+        assert!(matches!(cipher.open(&sealed), Err(EncryptionError::Corrupt(_))));
+
+        let old = StateCipher::from_store(&store, &["old"]).unwrap();
+        assert!(matches!(cipher.open(&old.seal(b"state")), Err(EncryptionError::UnknownKeyId(id)) if id == "old"));
+
+        assert!(matches!(StateCipher::from_store(&store, &["missing"]), Err(EncryptionError::MissingKey(_))));
+        assert!(matches!(StateCipher::from_store(&store, &["short"]), Err(EncryptionError::InvalidKey { .. })));
+        assert!(matches!(StateCipher::from_store(&store, &[]), Err(EncryptionError::NoKeys)));
+    }
+
+    #[test]
+    fn test_rotation() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store();
+        let old = StateCipher::from_store(&store, &["old"]).unwrap();
+        old.write_file(dir.path().join("run-1.json"), b"{\"run\":1}").unwrap();
+        fs::write(dir.path().join("run-2.json"), b"{\"run\":2}").unwrap();
+
+        let cipher = StateCipher::from_store(&store, &["new", "old"]).unwrap();
+        assert_eq!(cipher.rotate_dir(dir.path()).unwrap(), 2);
+        assert_eq!(cipher.rotate_dir(dir.path()).unwrap(), 0);
+        for (file, expected) in [("run-1.json", "{\"run\":1}"), ("run-2.json", "{\"run\":2}")] {
+            let data = fs::read(dir.path().join(file)).unwrap();
+            assert_eq!(StateCipher::key_id_of(&data), Some("new"));
+            assert_eq!(cipher.open(&data).unwrap(), expected.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_agent_state_is_encrypted() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("analyst_state.json");
+        let agent = LlmAgent::builder()
+            .name("Analyst")
+            .llm("gpt-4o-mini")
+            .saved_state_path(path.to_str().unwrap())
+            .build()
+            .unwrap();
+        let cipher = StateCipher::from_store(&store(), &["new"]).unwrap();
+        agent.save_state(Some(&cipher)).unwrap();
+        assert!(!fs::read_to_string(&path).unwrap().contains("Analyst"));
+
+        let loaded = LlmAgent::load_state(path.to_str().unwrap(), Some(&cipher)).unwrap();
+        assert_eq!(loaded.name, "Analyst");
+        assert!(LlmAgent::load_state(path.to_str().unwrap(), None).is_err());
+    }
+}
+```
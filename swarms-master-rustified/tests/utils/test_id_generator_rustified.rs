@@ -0,0 +1,64 @@
+### Tests for the id generator
+Ids must sort in the order they were generated even when many share a millisecond or the clock steps back,
+must carry their kind's prefix, and must decode back to the time they were generated in both formats.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::utils::id_generator::{id_timestamp_ms, IdFormat, IdGenerator, IdKind};
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::Arc;
+
+    const T0: u64 = 1_700_000_000_000;
+
+    #[test]
+    fn test_ids_sort_in_generation_order_within_a_millisecond() {
+        let now = Arc::new(AtomicU64::new(T0));
+        let clock = Arc::clone(&now);
+        let ids = IdGenerator::new().with_clock(move || clock.load(Ordering::SeqCst));
+
+        let mut generated: Vec<String> = (0..1_000).map(|_| ids.next(IdKind::Run)).collect();
+        now.store(T0 - 5_000, Ordering::SeqCst);
+        generated.push(ids.next(IdKind::Run));
+        now.store(T0 + 1, Ordering::SeqCst);
+        generated.push(ids.next(IdKind::Run));
+
+        let mut sorted = generated.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted, generated);
+        // The step back keeps the last timestamp
+        assert_eq!(id_timestamp_ms(&generated[1_000]), Some(T0));
+        assert_eq!(id_timestamp_ms(&generated[1_001]), Some(T0 + 1));
+    }
+
+    #[test]
+    fn test_prefixes_and_formats() {
+        let ids = IdGenerator::seeded(1).with_clock(|| T0).with_prefix(IdKind::Task, "job").with_prefix(IdKind::Run, "");
+        let task = ids.next(IdKind::Task);
+        assert!(task.starts_with("job_") && task.len() == "job_".len() + 26);
+        assert_eq!(ids.next(IdKind::Run).len(), 26);
+        assert!(ids.next(IdKind::Version).starts_with("ver_"));
+        assert_eq!(id_timestamp_ms(&task), Some(T0));
+
+        let uuids = IdGenerator::seeded(1).with_clock(|| T0).with_format(IdFormat::UuidV7);
+        let bare = uuids.next_id();
+        let uuid = uuid::Uuid::parse_str(&bare).unwrap();
+        assert_eq!(uuid.get_version_num(), 7);
+        assert_eq!(id_timestamp_ms(&format!("msg_{}", bare)), Some(T0));
+        assert!(uuids.next_id() > bare);
+
+        assert_eq!(id_timestamp_ms(&uuid::Uuid::new_v4().to_string()), None);
+        assert_eq!(id_timestamp_ms("swarm_run_1700000000"), None);
+    }
+
+    #[test]
+    fn test_seeded_generators_repeat() {
+        let ids = || IdGenerator::seeded(42).with_clock(|| T0);
+        let (first, second) = (ids(), ids());
+        let sequence = |generator: &IdGenerator| (0..3).map(|_| generator.next(IdKind::Message)).collect::<Vec<_>>();
+        assert_eq!(sequence(&first), sequence(&second));
+        assert_ne!(sequence(&IdGenerator::seeded(43).with_clock(|| T0)), sequence(&ids()));
+    }
+}
+```
@@ -0,0 +1,55 @@
+### Tests for the llama.cpp provider
+These tests cover configuration only, so they run without a GGUF model: the agent schema's `device`,
+`context_window`, and `template` must reach the config, and a missing model file must be reported
+before llama.cpp is initialized.
+
+```rust
+#[cfg(all(test, feature = "llama-cpp"))]
+mod tests {
+    use crate::swarms::schemas::agent_input_schema::AgentSchema;
+    use crate::swarms::text::chat_template::ChatTemplateRegistry;
+    use crate::swarms::utils::chat_provider::{Device, ProviderError};
+    use crate::swarms::utils::llama_cpp_provider::{LlamaCppConfig, LlamaCppProvider, ALL_LAYERS};
+
+    fn schema(device: &str, template: Option<&str>) -> AgentSchema {
+        AgentSchema {
+            llm: "/models/Meta-Llama-3.1-8B-Instruct-Q4_K_M.gguf".to_string(),
+            context_window: 8192,
+            device: Some(device.to_string()),
+            template: template.map(str::to_string),
+            ..AgentSchema::default()
+        }
+    }
+
+    #[test]
+    fn test_schema_reaches_the_config() {
+        let templates = ChatTemplateRegistry::builtin();
+        let config = LlamaCppConfig::from_schema(&schema("cuda:1", Some("chatml")), &templates).unwrap();
+        assert_eq!((config.context_size, config.gpu_layers, config.main_gpu), (8192, ALL_LAYERS, 1));
+        assert_eq!(config.template.unwrap().name, "chatml");
+        assert_eq!(config.model_name(), "Meta-Llama-3.1-8B-Instruct-Q4_K_M");
+
+        let config = LlamaCppConfig::from_schema(&schema("cpu", None), &templates).unwrap();
+        assert_eq!(config.gpu_layers, 0);
+        assert!(config.template.is_none());
+
+        // A partial offload set after the device wins.
+        assert_eq!(LlamaCppConfig::new("m.gguf").device(Device::Gpu(0)).gpu_layers(20).gpu_layers, 20);
+    }
+
+    #[test]
+    fn test_bad_settings_are_reported() {
+        let templates = ChatTemplateRegistry::builtin();
+        assert!(matches!(
+            LlamaCppConfig::from_schema(&schema("tpu", None), &templates),
+            Err(ProviderError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            LlamaCppConfig::from_schema(&schema("cpu", Some("alpaca")), &templates),
+            Err(ProviderError::InvalidConfig(_))
+        ));
+        let missing = LlamaCppProvider::load(LlamaCppConfig::new("/nonexistent/model.gguf"));
+        assert!(matches!(missing, Err(ProviderError::InvalidConfig(_))));
+    }
+}
+```
@@ -0,0 +1,74 @@
+### Tests for Prometheus metrics
+Recorded tasks, workflow runs, LLM calls, token spend, and router fallbacks and shadow runs must show up
+in the exposition under the documented names and labels.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::UsageInfo;
+    use crate::swarms::structs::step_guard::StepStatus;
+    use crate::swarms::utils::metrics::SwarmMetrics;
+    use std::time::Duration;
+
+    #[test]
+    fn test_task_metrics() {
+        let metrics = SwarmMetrics::new();
+        metrics.task_queued("queue", 1);
+        metrics.task_queued("queue", 2);
+        metrics.set_queue_depth("queue", 0);
+        metrics.task_finished("queue", "Agent1", &StepStatus::Succeeded, Duration::from_millis(300));
+        metrics.task_finished("queue", "Agent2", &StepStatus::Panicked { message: "boom".to_string() }, Duration::ZERO);
+
+        let text = metrics.render();
+        assert!(text.contains("swarms_tasks_queued_total{swarm=\"queue\"} 2"));
+        assert!(text.contains("swarms_queue_depth{swarm=\"queue\"} 0"));
+        assert!(text.contains("swarms_tasks_completed_total{agent=\"Agent1\",swarm=\"queue\"} 1"));
+        assert!(text.contains("swarms_tasks_failed_total{agent=\"Agent2\",swarm=\"queue\"} 1"));
+        assert!(text.contains("swarms_task_duration_seconds_count{agent=\"Agent1\",swarm=\"queue\"} 1"));
+    }
+
+    #[test]
+    fn test_llm_and_token_metrics() {
+        let metrics = SwarmMetrics::new();
+        metrics.llm_call("groq", "llama-3.1-8b", true, Duration::from_millis(800));
+        metrics.llm_call("groq", "llama-3.1-8b", false, Duration::from_secs(3));
+        metrics.tokens("llama-3.1-8b", "Analyst", &UsageInfo::new(100, 150));
+        metrics.tokens("llama-3.1-8b", "Analyst", &UsageInfo::estimated(10, 20));
+        metrics.workflow_finished("Review", "rejected", Duration::from_secs(1));
+
+        let text = metrics.render();
+        assert!(text.contains("swarms_llm_latency_seconds_count{model=\"llama-3.1-8b\",outcome=\"error\",provider=\"groq\"} 1"));
+        assert!(text.contains("swarms_tokens_total{agent=\"Analyst\",kind=\"prompt\",model=\"llama-3.1-8b\"} 110"));
+        assert!(text.contains("swarms_tokens_total{agent=\"Analyst\",kind=\"completion\",model=\"llama-3.1-8b\"} 70"));
+        assert!(text.contains("swarms_estimated_tokens_total{agent=\"Analyst\",kind=\"prompt\",model=\"llama-3.1-8b\"} 10"));
+        assert!(text.contains("swarms_workflow_runs_total{outcome=\"rejected\",workflow=\"Review\"} 1"));
+    }
+
+    #[test]
+    fn test_router_metrics() {
+        let metrics = SwarmMetrics::new();
+        metrics.router_fallback("router", "research");
+        metrics.shadow_run("router", "research", "summary", Some(0.25));
+        metrics.shadow_run("router", "research", "summary", None);
+
+        let text = metrics.render();
+        assert!(text.contains("swarms_router_fallbacks_total{router=\"router\",swarm=\"research\"} 1"));
+        assert!(text.contains(
+            "swarms_router_shadow_runs_total{outcome=\"error\",primary=\"research\",router=\"router\",shadow=\"summary\"} 1"
+        ));
+        assert!(text.contains("swarms_router_shadow_divergence_bucket{primary=\"research\",router=\"router\",shadow=\"summary\",le=\"0.3\"} 1"));
+        assert!(text.contains("swarms_router_shadow_divergence_count{primary=\"research\",router=\"router\",shadow=\"summary\"} 1"));
+    }
+
+    #[test]
+    fn test_metrics_route() {
+        let metrics = SwarmMetrics::new();
+        metrics.task_queued("route", 1);
+        let (status, content_type, body) = metrics.handle_http("GET", "/metrics");
+        assert_eq!(status, 200);
+        assert!(content_type.starts_with("text/plain"));
+        assert!(body.contains("# TYPE swarms_tasks_queued_total counter"));
+        assert_eq!(metrics.handle_http("POST", "/metrics").0, 404);
+    }
+}
+```
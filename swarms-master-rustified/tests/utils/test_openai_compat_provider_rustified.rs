@@ -0,0 +1,142 @@
+### Tests for the OpenAI-compatible provider adapter
+These tests cover response parsing only, so they run without network access or API keys. Each fixture
+mirrors a real quirk seen from Groq, Together, or OpenRouter.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem};
+    use crate::swarms::text::tokenizer::HeuristicTokenizer;
+    use crate::swarms::utils::openai_compat_provider::{
+        parse_chat_completion, parse_usage, FinishReason, OpenAiCompatProvider, GROQ_API_BASE,
+    };
+    use serde_json::json;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_standard_openai_response() {
+        let value = json!({
+            "choices": [{ "message": { "role": "assistant", "content": "Paris" }, "finish_reason": "stop" }],
+            "usage": { "prompt_tokens": 12, "completion_tokens": 3, "total_tokens": 15 }
+        });
+        let completion = parse_chat_completion(&value).unwrap();
+        assert_eq!(completion.content, "Paris");
+        assert_eq!(completion.finish_reason, Some(FinishReason::Stop));
+        let usage = completion.usage.unwrap();
+        assert_eq!(usage.prompt_tokens, 12);
+        assert_eq!(usage.completion_tokens, Some(3));
+        assert_eq!(usage.total_tokens, 15);
+    }
+
+    #[test]
+    fn test_together_eos_finish_reason() {
+        let value = json!({
+            "choices": [{ "message": { "content": "done" }, "finish_reason": "eos" }]
+        });
+        let completion = parse_chat_completion(&value).unwrap();
+        assert_eq!(completion.finish_reason, Some(FinishReason::Stop));
+    }
+
+    #[test]
+    fn test_finish_reason_aliases() {
+        assert_eq!(FinishReason::parse("end_turn"), FinishReason::Stop);
+        assert_eq!(FinishReason::parse("max_tokens"), FinishReason::Length);
+        assert_eq!(FinishReason::parse("tool_use"), FinishReason::ToolCalls);
+        assert_eq!(FinishReason::parse("weird"), FinishReason::Other("weird".to_string()));
+    }
+
+    #[test]
+    fn test_missing_usage_is_none() {
+        let value = json!({
+            "choices": [{ "message": { "content": "hi" }, "finish_reason": "stop" }]
+        });
+        assert!(parse_chat_completion(&value).unwrap().usage.is_none());
+    }
+
+    #[test]
+    fn test_groq_x_groq_usage() {
+        let value = json!({
+            "choices": [],
+            "x_groq": { "usage": { "prompt_tokens": 4, "completion_tokens": 6 } }
+        });
+        let usage = parse_usage(&value).unwrap();
+        assert_eq!(usage.total_tokens, 10);
+        assert!(!usage.estimated);
+    }
+
+    #[test]
+    fn test_tool_call_with_null_content() {
+        let value = json!({
+            "choices": [{
+                "message": {
+                    "content": null,
+                    "tool_calls": [{ "type": "function", "function": { "name": "add", "arguments": "{}" } }]
+                },
+                "finish_reason": "tool_calls"
+            }]
+        });
+        let completion = parse_chat_completion(&value).unwrap();
+        assert_eq!(completion.content, "");
+        assert_eq!(completion.tool_calls.len(), 1);
+        assert_eq!(completion.finish_reason, Some(FinishReason::ToolCalls));
+    }
+
+    #[test]
+    fn test_no_choices_is_an_error() {
+        assert!(parse_chat_completion(&json!({ "choices": [] })).is_err());
+    }
+
+    #[test]
+    fn test_request_body_uses_provider_model_and_plain_text() {
+        let provider = OpenAiCompatProvider::new("groq", format!("{}/", GROQ_API_BASE), "key", "llama-3.3-70b-versatile");
+        assert_eq!(provider.base_url, GROQ_API_BASE);
+
+        let request = ChatCompletionRequest::new(
+            "ignored".to_string(),
+            vec![ChatMessageInput::new("user".to_string(), vec![ContentItem::Text { text: "hello".to_string() }])],
+        );
+        let body = provider.request_body(&request);
+        assert_eq!(body["model"], "llama-3.3-70b-versatile");
+        assert_eq!(body["messages"][0]["content"], "hello");
+        assert!(body.get("stop").is_none());
+
+        assert_eq!(body["temperature"], json!(0.8));
+
+        let body = provider.clone().with_temperature(0.1).request_body(&request);
+        assert_eq!(body["temperature"], json!(0.1));
+
+        let body = provider.request_body(&request.with_stop(vec!["<DONE>".to_string()]));
+        assert_eq!(body["stop"], json!(["<DONE>"]));
+    }
+
+    #[test]
+    fn test_estimated_usage_counts_prompt_and_output() {
+        let provider = OpenAiCompatProvider::new("groq", GROQ_API_BASE, "key", "llama-3.3-70b-versatile")
+            .with_tokenizer(Arc::new(HeuristicTokenizer));
+        let request = ChatCompletionRequest::new(
+            "ignored".to_string(),
+            vec![
+                ChatMessageInput::new("system".to_string(), vec![ContentItem::Text { text: "Be brief.".to_string() }]),
+                ChatMessageInput::new("user".to_string(), vec![ContentItem::Text { text: "Weather in Paris?".to_string() }]),
+            ],
+        );
+        let completion = parse_chat_completion(&json!({
+            "choices": [{
+                "message": {
+                    "content": "Checking.",
+                    "tool_calls": [{ "type": "function", "function": { "name": "get_weather", "arguments": "{\"city\":\"Paris\"}" } }]
+                }
+            }]
+        }))
+        .unwrap();
+
+        let usage = provider.estimate_usage(&request, &completion);
+        assert!(usage.estimated);
+        // 3 priming + 2 × 3 per message + "system" 2 + "Be brief." 3 + "user" 1 + "Weather in Paris?" 5.
+        assert_eq!(usage.prompt_tokens, 20);
+        // "Checking." 3 + "get_weather" 3 + the arguments 4.
+        assert_eq!(usage.completion_tokens, Some(10));
+        assert_eq!(usage.total_tokens, 30);
+    }
+}
+```
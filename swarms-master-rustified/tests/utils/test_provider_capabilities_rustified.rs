@@ -0,0 +1,91 @@
+### Tests for capability fallbacks
+A request that asks for a feature the provider lacks must not lose it silently: `adapt` strips the feature
+and writes its prompt-based stand-in (the schema, the tool list, a forced tool) into the system message.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::schemas::base_schemas::{ChatCompletionRequest, ChatMessageInput, ContentItem, ResponseFormat, ToolChoice};
+    use crate::swarms::utils::provider_capabilities::{Downgrade, ProviderCapabilities};
+    use serde_json::json;
+
+    fn text(message: &ChatMessageInput) -> String {
+        let parts: Vec<&str> = message
+            .content
+            .iter()
+            .filter_map(|item| match item {
+                ContentItem::Text { text } => Some(text.as_str()),
+                _ => None,
+            })
+            .collect();
+        parts.join("\n")
+    }
+
+    fn request(messages: Vec<ChatMessageInput>) -> ChatCompletionRequest {
+        ChatCompletionRequest::new("model".to_string(), messages)
+    }
+
+    fn message(role: &str, content: &str) -> ChatMessageInput {
+        ChatMessageInput::new(role.to_string(), vec![ContentItem::Text { text: content.to_string() }])
+    }
+
+    #[test]
+    fn test_schema_moves_into_the_system_message() {
+        let schema = json!({ "type": "object", "properties": { "ticker": { "type": "string" } } });
+        let mut request = request(vec![message("system", "Be brief."), message("user", "Pick a stock.")])
+            .with_json_schema("pick", schema.clone());
+
+        // Groq has JSON mode but no schemas: the request keeps json_object and the schema goes in the prompt.
+        let applied = ProviderCapabilities::lookup("groq", "llama-3.3-70b-versatile").adapt(&mut request);
+        assert_eq!(applied, [Downgrade::PromptBasedJson { schema: Some(schema.clone()) }]);
+        assert_eq!(request.response_format, Some(ResponseFormat::JsonObject));
+        assert_eq!(request.messages.len(), 2);
+        let system = text(&request.messages[0]);
+        assert!(system.starts_with("Be brief.\nReply with only a JSON object that matches this JSON schema:"), "{}", system);
+        assert!(system.contains(&schema.to_string()));
+    }
+
+    #[test]
+    fn test_system_message_is_added_when_missing() {
+        let mut request = request(vec![message("user", "Pick a stock.")]).with_response_format(ResponseFormat::JsonObject);
+        let applied = ProviderCapabilities::text_only("local", "tiny").adapt(&mut request);
+        assert_eq!(applied, [Downgrade::PromptBasedJson { schema: None }]);
+        assert_eq!(request.response_format, None);
+        assert_eq!(request.messages[0].role, "system");
+        assert_eq!(text(&request.messages[0]), "Reply with only a valid JSON object.");
+        assert_eq!(text(&request.messages[1]), "Pick a stock.");
+    }
+
+    #[test]
+    fn test_tools_are_listed_in_the_prompt() {
+        let tool = json!({
+            "type": "function",
+            "function": { "name": "get_quote", "description": "Latest price.", "parameters": { "type": "object" } }
+        });
+        let mut request = request(vec![message("user", "Price of AAPL?")])
+            .with_tools(vec![tool])
+            .with_tool_choice(ToolChoice::Function("get_quote".to_string()));
+        let applied = ProviderCapabilities::text_only("local", "tiny").adapt(&mut request);
+        assert_eq!(applied, [Downgrade::PromptBasedTools]);
+        assert_eq!((request.tools.is_none(), request.tool_choice.is_none()), (true, true));
+        let system = text(&request.messages[0]);
+        assert!(system.contains("You must call the tool `get_quote`."), "{}", system);
+        assert!(system.contains("- get_quote: Latest price. Parameters: {\"type\":\"object\"}"), "{}", system);
+    }
+
+    #[test]
+    fn test_supported_features_leave_the_prompt_alone() {
+        let mut request = request(vec![message("user", "Hi")]).with_json_schema("reply", json!({ "type": "object" }));
+        assert!(ProviderCapabilities::lookup("openai", "gpt-4o").adapt(&mut request).is_empty());
+        assert_eq!(request.messages.len(), 1);
+
+        // Streaming has no prompt stand-in.
+        let mut streamed = request.clone();
+        streamed.stream = Some(true);
+        let mut caps = ProviderCapabilities::lookup("openai", "gpt-4o");
+        caps.streaming = false;
+        assert_eq!(caps.adapt(&mut streamed), [Downgrade::StreamingDisabled]);
+        assert_eq!(streamed.messages.len(), 1);
+    }
+}
+```
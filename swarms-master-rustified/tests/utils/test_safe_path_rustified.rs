@@ -0,0 +1,51 @@
+### Tests for portable file names and path joins
+Sanitized names must be valid on Windows, macOS, and Linux: no reserved device names, forbidden characters,
+trailing dots, or names over 255 bytes, and one spelling per Unicode name. Joins must treat `\` as a
+separator and never leave the base directory.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::structs::tenancy::{TenantError, TenantId, TenantWorkspace};
+    use crate::swarms::utils::safe_path::{is_reserved_name, safe_join, sanitize_file_name, PathError, MAX_NAME_BYTES};
+    use std::path::Path;
+
+    #[test]
+    fn test_names_are_valid_everywhere() {
+        assert_eq!(sanitize_file_name("Q3: revenue?.md"), "Q3_ revenue_.md");
+        assert_eq!(sanitize_file_name("a/b\\c\u{7}"), "a_b_c_");
+        assert_eq!(sanitize_file_name("notes. . "), "notes");
+        for (name, sanitized) in [("..", "_"), (".", "_"), ("", "_"), ("nul.json", "_nul.json"), ("COM1", "_COM1")] {
+            assert_eq!(sanitize_file_name(name), sanitized, "{:?}", name);
+        }
+        assert!(is_reserved_name("Aux .txt") && !is_reserved_name("auxiliary.txt") && !is_reserved_name("COM10"));
+
+        // Decomposed and precomposed spellings of the same name agree
+        assert_eq!(sanitize_file_name("cafe\u{301}.txt"), sanitize_file_name("caf\u{e9}.txt"));
+
+        let long = format!("{}.json", "é".repeat(200));
+        let truncated = sanitize_file_name(&long);
+        assert!(truncated.len() <= MAX_NAME_BYTES && truncated.ends_with("é.json"));
+        assert_eq!(sanitize_file_name(&truncated), truncated);
+    }
+
+    #[test]
+    fn test_joins_stay_inside_the_base() {
+        let base = Path::new("/srv/ws");
+        assert_eq!(safe_join(base, "artifacts\\q3/./report?.md").unwrap(), base.join("artifacts").join("q3").join("report_.md"));
+        assert_eq!(safe_join(base, "runs/con.json").unwrap(), base.join("runs").join("_con.json"));
+        for escape in ["../etc", "a\\..\\..\\etc", "/etc/passwd", "\\\\server\\share", "C:\\Windows", "c:"] {
+            assert!(matches!(safe_join(base, escape), Err(PathError::Escape(_))), "{}", escape);
+        }
+        assert_eq!(safe_join(base, "./"), Err(PathError::Empty));
+
+        let workspace = TenantWorkspace::new(base);
+        let acme = TenantId::new("acme").unwrap();
+        assert!(matches!(workspace.resolve(&acme, "..\\globex\\runs"), Err(TenantError::PathEscape { .. })));
+        assert_eq!(
+            workspace.conversation_path(&acme, "PRN").unwrap(),
+            Path::new("/srv/ws/tenants/acme/conversations/_PRN.json")
+        );
+    }
+}
+```
@@ -0,0 +1,96 @@
+### Tests for guarded zip extraction
+A well-formed archive must extract completely. Traversal names, symlinks, duplicates, too many entries,
+oversized output, and zip bombs must each be refused with their own error, and a refused archive must
+leave nothing at the destination or in its parent.
+
+```rust
+#[cfg(test)]
+mod tests {
+    use crate::swarms::utils::zip_extract::{extract_zip, ExtractLimits, ZipExtractError};
+    use std::fs::File;
+    use std::io::Write;
+    use std::path::Path;
+    use zip::write::FileOptions;
+    use zip::{CompressionMethod, ZipWriter};
+
+    fn write_zip(path: &Path, entries: &[(&str, &[u8])]) {
+        let mut zip = ZipWriter::new(File::create(path).unwrap());
+        let options = FileOptions::default().compression_method(CompressionMethod::Deflated);
+        for (name, data) in entries {
+            if name.ends_with('/') {
+                zip.add_directory(*name, options).unwrap();
+            } else {
+                zip.start_file(*name, options).unwrap();
+                zip.write_all(data).unwrap();
+            }
+        }
+        zip.finish().unwrap();
+    }
+
+    // Refused before or during extraction, with the destination's parent left as it was
+    fn refused(entries: &[(&str, &[u8])], limits: &ExtractLimits) -> ZipExtractError {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("in.zip");
+        write_zip(&archive, entries);
+        let out = dir.path().join("out");
+        let err = extract_zip(&archive, &out, limits).unwrap_err();
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1, "{}", err);
+        err
+    }
+
+    #[test]
+    fn test_extracts_a_workspace() {
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("workspace.zip");
+        let entries: [(&str, &[u8]); 4] =
+            [("./", b""), ("src/", b""), ("src/main.py", b"print('hi')\n"), ("notes?.md", b"# Notes")];
+        write_zip(&archive, &entries);
+
+        let out = dir.path().join("restored/workspace");
+        let summary = extract_zip(&archive, &out, &ExtractLimits::default()).unwrap();
+        assert_eq!((summary.files, summary.bytes), (2, 19));
+        assert_eq!(std::fs::read_to_string(out.join("src/main.py")).unwrap(), "print('hi')\n");
+        assert!(out.join("notes_.md").is_file());
+
+        // Never merges into an existing directory
+        assert!(matches!(extract_zip(&archive, &out, &ExtractLimits::default()), Err(ZipExtractError::DestinationExists(_))));
+    }
+
+    #[test]
+    fn test_refuses_unsafe_names() {
+        let limits = ExtractLimits::default();
+        for name in ["../evil.sh", "docs/../../evil.sh", "/etc/cron.d/evil", "..\\evil.bat", "C:/Windows/evil.dll"] {
+            let err = refused(&[("ok.txt", &b"fine"[..]), (name, &b"x"[..])], &limits);
+            assert!(matches!(&err, ZipExtractError::UnsafePath { entry } if entry == name), "{}: {}", name, err);
+        }
+        let err = refused(&[("a?.txt", &b"1"[..]), ("a*.txt", &b"2"[..])], &limits);
+        assert!(matches!(err, ZipExtractError::DuplicateEntry { .. }));
+
+        let dir = tempfile::tempdir().unwrap();
+        let archive = dir.path().join("link.zip");
+        let mut zip = ZipWriter::new(File::create(&archive).unwrap());
+        zip.add_symlink("etc", "/etc", FileOptions::default()).unwrap();
+        zip.finish().unwrap();
+        assert!(matches!(extract_zip(&archive, dir.path().join("out"), &limits), Err(ZipExtractError::Symlink { .. })));
+    }
+
+    #[test]
+    fn test_refuses_bombs_and_oversized_archives() {
+        let zeros = &vec![0u8; 4 << 20][..];
+        let err = refused(&[("bomb.bin", zeros)], &ExtractLimits::default());
+        assert!(matches!(&err, ZipExtractError::RatioExceeded { ratio, .. } if *ratio > 100.0), "{}", err);
+
+        let lenient = ExtractLimits { max_ratio: f64::INFINITY, ..ExtractLimits::default() };
+        let tight = ExtractLimits { max_entry_bytes: 1 << 20, ..lenient };
+        let err = refused(&[("big.bin", zeros)], &tight);
+        assert!(matches!(err, ZipExtractError::EntryTooLarge { limit, .. } if limit == 1 << 20));
+        let tight = ExtractLimits { max_total_bytes: 6 << 20, ..lenient };
+        assert!(matches!(refused(&[("a.bin", zeros), ("b.bin", zeros)], &tight), ZipExtractError::TotalTooLarge { .. }));
+
+        let files: Vec<(String, &[u8])> = (0..11).map(|i| (format!("f{}.txt", i), &b"x"[..])).collect();
+        let entries: Vec<(&str, &[u8])> = files.iter().map(|(name, data)| (name.as_str(), *data)).collect();
+        let few = ExtractLimits { max_entries: 10, ..ExtractLimits::default() };
+        assert!(matches!(refused(&entries, &few), ZipExtractError::TooManyEntries { entries: 11, limit: 10 }));
+    }
+}
+```